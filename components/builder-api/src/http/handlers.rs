@@ -26,10 +26,11 @@ use iron::prelude::*;
 use iron::status;
 use iron::typemap;
 use persistent;
-use protocol::jobsrv::{Job, JobGet, JobSpec};
+use protocol::jobsrv::{Job, JobGet, JobLog, JobLogGet, JobSpec};
 use protocol::vault::*;
 use protocol::net::{self, NetOk, ErrCode};
 use router::Router;
+use urlencoded::UrlEncodedQuery;
 
 define_event_log!();
 
@@ -133,6 +134,38 @@ pub fn job_show(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+/// Return the lines of a job's build log, starting from the line given by the `start` query
+/// parameter, or from the beginning of the log if it is not given.
+pub fn job_log(req: &mut Request) -> IronResult<Response> {
+    let start = match req.get_ref::<UrlEncodedQuery>() {
+        Ok(map) => {
+            match map.get("start").and_then(|v| v.first()) {
+                Some(start) => {
+                    match start.parse::<u64>() {
+                        Ok(start) => start,
+                        Err(_) => return Ok(Response::with(status::BadRequest)),
+                    }
+                }
+                None => 0,
+            }
+        }
+        Err(_) => 0,
+    };
+    let params = req.extensions.get::<Router>().unwrap();
+    let id = match params.find("id").unwrap().parse::<u64>() {
+        Ok(id) => id,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    let mut conn = Broker::connect().unwrap();
+    let mut request = JobLogGet::new();
+    request.set_id(id);
+    request.set_start(start);
+    match conn.route::<JobLogGet, JobLog>(&request) {
+        Ok(log) => Ok(render_json(status::Ok, &log)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
 /// Endpoint for determining availability of builder-api components.
 ///
 /// Returns a status 200 on success. Any non-200 responses are an outage or a partial outage.
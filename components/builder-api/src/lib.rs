@@ -37,6 +37,7 @@ extern crate serde_json;
 extern crate staticfile;
 extern crate toml;
 extern crate unicase;
+extern crate urlencoded;
 extern crate zmq;
 
 pub mod config;
@@ -33,21 +33,27 @@ extern crate url;
 
 pub mod error;
 pub use error::{Error, Result};
+pub use protocol::jobsrv::JobState;
 
+use std::collections::HashMap;
 use std::fs::{self, File};
 use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
 use std::string::ToString;
 
 use broadcast::BroadcastWriter;
+use hab_core::crypto::SigKeyPair;
+use hab_core::crypto::artifact::StreamVerifier;
+use hab_core::crypto::keys::parse_name_with_rev;
 use hab_core::package::{Identifiable, PackageArchive};
 use hab_http::ApiClient;
 use hyper::client::{Body, IntoUrl, Response, RequestBuilder};
 use hyper::status::StatusCode;
-use hyper::header::{Authorization, Bearer};
+use hyper::header::{Authorization, Bearer, ContentType};
 use hyper::Url;
 use protobuf::core::ProtobufEnum;
-use protocol::{depotsrv, net};
+use protobuf::RepeatedField;
+use protocol::{depotsrv, jobsrv, net};
 use rand::{Rng, thread_rng};
 use tee::TeeReader;
 
@@ -87,6 +93,62 @@ impl Into<depotsrv::OriginKeyIdent> for OriginKeyIdent {
     }
 }
 
+#[derive(Clone, Deserialize)]
+pub struct Job {
+    pub id: u64,
+    pub state: i32,
+    #[serde(default)]
+    pub error: Option<JobError>,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct JobError {
+    pub code: i32,
+    pub msg: String,
+}
+
+impl Into<jobsrv::Job> for Job {
+    fn into(self) -> jobsrv::Job {
+        let mut out = jobsrv::Job::new();
+        out.set_id(self.id);
+        let state = jobsrv::JobState::from_i32(self.state).unwrap_or(jobsrv::JobState::default());
+        out.set_state(state);
+        if let Some(error) = self.error {
+            let mut err = net::NetError::new();
+            if let Some(code) = net::ErrCode::from_i32(error.code) {
+                err.set_code(code);
+            }
+            err.set_msg(error.msg);
+            out.set_error(err);
+        }
+        out
+    }
+}
+
+#[derive(Clone, Deserialize)]
+pub struct JobLog {
+    pub start: u64,
+    pub stop: u64,
+    pub content: Vec<String>,
+    pub is_complete: bool,
+}
+
+impl Into<jobsrv::JobLog> for JobLog {
+    fn into(self) -> jobsrv::JobLog {
+        let mut out = jobsrv::JobLog::new();
+        out.set_start(self.start);
+        out.set_stop(self.stop);
+        out.set_content(RepeatedField::from_vec(self.content));
+        out.set_is_complete(self.is_complete);
+        out
+    }
+}
+
+#[derive(Serialize)]
+struct JobCreateReq<'a> {
+    project_id: &'a str,
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct OriginSecretKey {
     pub id: String,
@@ -323,6 +385,85 @@ impl Client {
         }
     }
 
+    /// Upload an origin secret to a remote Depot. The value must already be encrypted to the
+    /// origin's public key.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    /// * Secret name is invalid or the value isn't encrypted
+    pub fn put_origin_secret(&self,
+                             origin: &str,
+                             name: &str,
+                             encrypted: &str,
+                             token: &str)
+                             -> Result<()> {
+        let path = format!("origins/{}/secrets/{}", origin, name);
+        let res = try!(self.add_authz(self.inner.post(&path), token)
+            .body(encrypted)
+            .send());
+        if res.status != StatusCode::Created {
+            return Err(err_from_response(res));
+        }
+        Ok(())
+    }
+
+    /// Returns the names of an origin's secrets.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    pub fn list_origin_secrets(&self, origin: &str, token: &str) -> Result<Vec<String>> {
+        let mut res =
+            try!(self.add_authz(self.inner.get(&format!("origins/{}/secrets", origin)), token)
+                .send());
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        }
+        let mut encoded = String::new();
+        try!(res.read_to_string(&mut encoded));
+        let names = try!(serde_json::from_str(&encoded));
+        Ok(names)
+    }
+
+    /// Delete an origin secret from a remote Depot.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    /// * Secret does not exist
+    pub fn delete_origin_secret(&self, origin: &str, name: &str, token: &str) -> Result<()> {
+        let path = format!("origins/{}/secrets/{}", origin, name);
+        let res = try!(self.add_authz(self.inner.delete(&path), token).send());
+        if res.status != StatusCode::NoContent {
+            return Err(err_from_response(res));
+        }
+        Ok(())
+    }
+
+    /// Retrieve the encrypted values of all of an origin's secrets, keyed by name. Only build
+    /// workers are allowed to do this.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    pub fn fetch_origin_secrets(&self,
+                                origin: &str,
+                                token: &str)
+                                -> Result<HashMap<String, String>> {
+        let mut res =
+            try!(self.add_authz(self.inner.get(&format!("origins/{}/encrypted_secrets", origin)),
+                                token)
+                .send());
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        }
+        let mut encoded = String::new();
+        try!(res.read_to_string(&mut encoded));
+        let secrets = try!(serde_json::from_str(&encoded));
+        Ok(secrets)
+    }
+
     /// Download the latest release of a package.
     ///
     /// An optional version and release can be specified which, when provided, will increase
@@ -352,6 +493,48 @@ impl Client {
         }
     }
 
+    /// Download a package like `fetch_package`, verifying its signature and checksum as it is
+    /// written to disk rather than reading it back afterwards. The public origin key the package
+    /// was signed with is downloaded into `cache_key_path` if it isn't there already. A package
+    /// which fails verification is removed instead of being left in `dst_path`.
+    ///
+    /// # Failures
+    ///
+    /// * Package cannot be found
+    /// * Remote Depot is not available
+    /// * File cannot be created and written to
+    /// * Package's signature or checksum is invalid
+    pub fn fetch_package_verified<D, I, P1: ?Sized, P2: ?Sized>(&self,
+                                                                ident: &I,
+                                                                dst_path: &P1,
+                                                                cache_key_path: &P2,
+                                                                progress: Option<D>)
+                                                                -> Result<PackageArchive>
+        where P1: AsRef<Path>,
+              P2: AsRef<Path>,
+              I: Identifiable,
+              D: DisplayProgress + Sized
+    {
+        let mut verifier = StreamVerifier::new();
+        let (tmp_file_path, dst_file_path) =
+            try!(self.download_to_tmp(&format!("pkgs/{}/download", ident),
+                                      dst_path.as_ref(),
+                                      progress,
+                                      &mut verifier));
+        let verified = self.verify_download::<D>(verifier, cache_key_path.as_ref());
+        if let Err(e) = verified {
+            debug!("Removing {}, which failed verification",
+                   &tmp_file_path.display());
+            try!(fs::remove_file(&tmp_file_path));
+            return Err(e);
+        }
+        debug!("Moving {} to {}",
+               &tmp_file_path.display(),
+               &dst_file_path.display());
+        try!(fs::rename(&tmp_file_path, &dst_file_path));
+        Ok(PackageArchive::new(dst_file_path))
+    }
+
     /// Returns a package struct for the latest package.
     ///
     /// An optional version can be specified which will scope the release returned to the latest
@@ -437,6 +620,74 @@ impl Client {
         }
     }
 
+    /// Returns the identifiers of every release of every package in an origin, optionally only
+    /// those in the given channel. Pages of results are fetched until the listing is complete.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    /// * Origin or channel does not exist
+    pub fn list_packages(&self,
+                         origin: &str,
+                         channel: Option<&str>)
+                         -> Result<Vec<hab_core::package::PackageIdent>> {
+        let path = match channel {
+            Some(channel) => format!("channels/{}/pkgs/{}", channel, origin),
+            None => format!("pkgs/{}", origin),
+        };
+        self.list_package_idents(&path)
+    }
+
+    /// Returns the identifiers of every release of the package `ident` names, such as every
+    /// release of `core/glibc`, or of `core/glibc/2.22`.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    /// * Package does not exist
+    pub fn list_package_releases<I: Identifiable>(&self,
+                                                  ident: &I)
+                                                  -> Result<Vec<hab_core::package::PackageIdent>> {
+        let mut path = format!("pkgs/{}/{}", ident.origin(), ident.name());
+        if let Some(version) = ident.version() {
+            path = format!("{}/{}", path, version);
+        }
+        self.list_package_idents(&path)
+    }
+
+    /// Fetches pages of a listing of package identifiers until it's complete.
+    fn list_package_idents(&self, path: &str) -> Result<Vec<hab_core::package::PackageIdent>> {
+        let mut packages: Vec<hab_core::package::PackageIdent> = Vec::new();
+        let mut offset = 0;
+        loop {
+            let range = format!("range={}", offset);
+            let mut res = try!(self.inner
+                .get_with_custom_url(path, |url| url.set_query(Some(&range)))
+                .send());
+            let more = match res.status {
+                StatusCode::Ok => false,
+                StatusCode::PartialContent => true,
+                _ => return Err(err_from_response(res)),
+            };
+            let mut encoded = String::new();
+            try!(res.read_to_string(&mut encoded));
+            let results: PackageResults<hab_core::package::PackageIdent> =
+                try!(serde_json::from_str(&encoded));
+            offset += results.package_list.len();
+            let known = packages.len();
+            for ident in results.package_list.into_iter() {
+                if !packages.contains(&ident) {
+                    packages.push(ident);
+                }
+            }
+            // Channel listings aren't paginated, so a page with nothing new means we're done.
+            if !more || packages.len() == known {
+                break;
+            }
+        }
+        Ok(packages)
+    }
+
     /// Returns a vector of PackageIdent structs
     ///
     /// # Failures
@@ -461,6 +712,81 @@ impl Client {
         }
     }
 
+    /// Submit a build job for a project to Builder. A project is identified by its origin and
+    /// package name, for example `core/redis`.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Builder is not available
+    /// * Project does not exist
+    pub fn create_job(&self, project_id: &str, token: &str) -> Result<jobsrv::Job> {
+        let body = try!(serde_json::to_string(&JobCreateReq { project_id: project_id }));
+        let mut res = try!(self.add_authz(self.inner
+                                              .post_with_custom_url("", |url| {
+                                                  builder_api_path(url, "jobs")
+                                              }),
+                                          token)
+            .header(ContentType::json())
+            .body(&body)
+            .send());
+        if res.status != StatusCode::Created {
+            return Err(err_from_response(res));
+        }
+        let mut encoded = String::new();
+        try!(res.read_to_string(&mut encoded));
+        let job: Job = try!(serde_json::from_str(&encoded));
+        Ok(job.into())
+    }
+
+    /// Retrieve the current state of a build job from Builder.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Builder is not available
+    /// * Job does not exist
+    pub fn show_job(&self, id: u64, token: &str) -> Result<jobsrv::Job> {
+        let path = format!("jobs/{}", id);
+        let mut res = try!(self.add_authz(self.inner
+                                              .get_with_custom_url("", |url| {
+                                                  builder_api_path(url, &path)
+                                              }),
+                                          token)
+            .send());
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        }
+        let mut encoded = String::new();
+        try!(res.read_to_string(&mut encoded));
+        let job: Job = try!(serde_json::from_str(&encoded));
+        Ok(job.into())
+    }
+
+    /// Retrieve the lines of a build job's log from Builder, starting at line `start`. Pass the
+    /// returned log's `stop` as the next `start` to fetch only the lines which have been added
+    /// since.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Builder is not available
+    /// * Job does not exist
+    pub fn job_log(&self, id: u64, start: u64, token: &str) -> Result<jobsrv::JobLog> {
+        let path = format!("jobs/{}/log", id);
+        let mut res = try!(self.add_authz(self.inner
+                                              .get_with_custom_url("", |url| {
+                                                  builder_api_path(url, &path);
+                                                  url.set_query(Some(&format!("start={}", start)));
+                                              }),
+                                          token)
+            .send());
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        }
+        let mut encoded = String::new();
+        try!(res.read_to_string(&mut encoded));
+        let log: JobLog = try!(serde_json::from_str(&encoded));
+        Ok(log.into())
+    }
+
     fn add_authz<'a>(&'a self, rb: RequestBuilder<'a>, token: &str) -> RequestBuilder {
         rb.header(Authorization(Bearer { token: token.to_string() }))
     }
@@ -475,6 +801,26 @@ impl Client {
 
     fn download<D>(&self, path: &str, dst_path: &Path, progress: Option<D>) -> Result<PathBuf>
         where D: DisplayProgress + Sized
+    {
+        let (tmp_file_path, dst_file_path) =
+            try!(self.download_to_tmp(path, dst_path, progress, &mut io::sink()));
+        debug!("Moving {} to {}",
+               &tmp_file_path.display(),
+               &dst_file_path.display());
+        try!(fs::rename(&tmp_file_path, &dst_file_path));
+        Ok(dst_file_path)
+    }
+
+    /// Download into a temporary file in `dst_path`, writing everything downloaded to `tee` as
+    /// well. Returns the temporary file's path and the path it should be moved to.
+    fn download_to_tmp<D, W>(&self,
+                             path: &str,
+                             dst_path: &Path,
+                             progress: Option<D>,
+                             tee: &mut W)
+                             -> Result<(PathBuf, PathBuf)>
+        where D: DisplayProgress + Sized,
+              W: Write
     {
         let mut res = try!(self.inner.get(path).send());
         debug!("Response: {:?}", res);
@@ -495,22 +841,46 @@ impl Client {
         let dst_file_path = dst_path.join(file_name);
         debug!("Writing to {}", &tmp_file_path.display());
         let mut f = try!(File::create(&tmp_file_path));
+        let size: u64 = res.headers.get::<hyper::header::ContentLength>().map_or(0, |v| **v);
+        let mut reader = TeeReader::new(&mut res, tee);
         match progress {
             Some(mut progress) => {
-                let size: u64 =
-                    res.headers.get::<hyper::header::ContentLength>().map_or(0, |v| **v);
                 progress.size(size);
                 let mut writer = BroadcastWriter::new(&mut f, progress);
-                try!(io::copy(&mut res, &mut writer))
+                try!(io::copy(&mut reader, &mut writer))
             }
-            None => try!(io::copy(&mut res, &mut f)),
+            None => try!(io::copy(&mut reader, &mut f)),
         };
-        debug!("Moving {} to {}",
-               &tmp_file_path.display(),
-               &dst_file_path.display());
-        try!(fs::rename(&tmp_file_path, &dst_file_path));
-        Ok(dst_file_path)
+        Ok((tmp_file_path, dst_file_path))
     }
+
+    /// Verify a downloaded package, first downloading the public key it was signed with if it
+    /// isn't in `cache_key_path`.
+    fn verify_download<D>(&self, verifier: StreamVerifier, cache_key_path: &Path) -> Result<()>
+        where D: DisplayProgress + Sized
+    {
+        if let Some(name_with_rev) = verifier.signer() {
+            if SigKeyPair::get_public_key_path(name_with_rev, cache_key_path).is_err() {
+                let (name, rev) = try!(parse_name_with_rev(name_with_rev));
+                debug!("Downloading {} public origin key", name_with_rev);
+                try!(self.fetch_origin_key(&name, &rev, cache_key_path, None::<D>));
+            }
+        }
+        let (name_with_rev, hash) = try!(verifier.verify(cache_key_path));
+        debug!("Verified checksum {} signed with {}", hash, name_with_rev);
+        Ok(())
+    }
+}
+
+/// Builder's API is served alongside its Depot, so the jobs for the Depot at
+/// `https://bldr.example.com/v1/depot` are found at `https://bldr.example.com/v1/jobs`.
+fn builder_api_path(url: &mut Url, path: &str) {
+    let base = url.path()
+        .trim_right_matches('/')
+        .trim_right_matches("depot")
+        .trim_right_matches('/')
+        .to_string();
+    url.set_path(&format!("{}/{}", base, path));
 }
 
 fn err_from_response(mut response: hyper::client::Response) -> Error {
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::ops::Deref;
 use std::result;
 use std::str::FromStr;
@@ -32,6 +33,7 @@ pub struct DataStore {
     pub packages: PackagesTable,
     pub channels: ChannelsTable,
     pub origin_keys: OriginKeysTable,
+    pub origin_secrets: OriginSecretsTable,
 }
 
 impl DataStore {
@@ -43,14 +45,17 @@ impl DataStore {
         let pool1 = pool.clone();
         let pool2 = pool.clone();
         let pool3 = pool.clone();
+        let pool4 = pool.clone();
         let packages = PackagesTable::new(pool1);
         let channels = ChannelsTable::new(pool2);
         let origin_keys = OriginKeysTable::new(pool3);
+        let origin_secrets = OriginSecretsTable::new(pool4);
         Ok(DataStore {
             pool: pool,
             packages: packages,
             channels: channels,
             origin_keys: origin_keys,
+            origin_secrets: origin_secrets,
         })
     }
 
@@ -551,3 +556,53 @@ impl IndexSet for OriginKeysTable {
     type Key = String;
     type Value = String;
 }
+
+/// Contains the encrypted secrets of each origin, keyed by secret name. Values are encrypted to
+/// the origin's public key before they reach the Depot and are only ever stored encrypted.
+pub struct OriginSecretsTable {
+    pool: Arc<ConnectionPool>,
+}
+
+impl OriginSecretsTable {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        OriginSecretsTable { pool: pool }
+    }
+
+    /// Returns the encrypted value of every secret in the given origin.
+    pub fn all(&self, origin: &str) -> Result<HashMap<String, String>> {
+        let conn = self.pool().get().unwrap();
+        let secrets = try!(conn.hgetall(Self::key(&origin.to_string())));
+        Ok(secrets)
+    }
+
+    /// Returns the names of the secrets in the given origin, sorted.
+    pub fn names(&self, origin: &str) -> Result<Vec<String>> {
+        let conn = self.pool().get().unwrap();
+        let mut names: Vec<String> = try!(conn.hkeys(Self::key(&origin.to_string())));
+        names.sort();
+        Ok(names)
+    }
+
+    pub fn write(&self, origin: &str, name: &str, value: &str) -> Result<()> {
+        let conn = self.pool().get().unwrap();
+        try!(conn.hset(Self::key(&origin.to_string()), name, value));
+        Ok(())
+    }
+
+    /// Removes a secret, returning `false` if the origin had no secret with that name.
+    pub fn delete(&self, origin: &str, name: &str) -> Result<bool> {
+        let conn = self.pool().get().unwrap();
+        let removed: usize = try!(conn.hdel(Self::key(&origin.to_string()), name));
+        Ok(removed > 0)
+    }
+}
+
+impl Bucket for OriginSecretsTable {
+    fn pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+
+    fn prefix() -> &'static str {
+        "origin_secrets"
+    }
+}
@@ -22,7 +22,7 @@ use bodyparser;
 use dbcache::{self, BasicSet};
 use hab_core::package::{Identifiable, FromArchive, PackageArchive, PackageTarget};
 use hab_core::crypto::keys::{self, PairType};
-use hab_core::crypto::SigKeyPair;
+use hab_core::crypto::{SigKeyPair, ORIGIN_SECRET_FORMAT_VERSION};
 use hab_core::event::*;
 use bld_core::metrics::*;
 use hab_net::config::RouteAddrs;
@@ -477,6 +477,118 @@ fn upload_origin_secret_key(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn list_origin_secrets(req: &mut Request) -> IronResult<Response> {
+    let depot = req.get::<persistent::Read<Depot>>().unwrap();
+    let session = req.extensions.get::<Authenticated>().unwrap().clone();
+    let params = req.extensions.get::<Router>().unwrap().clone();
+    let origin = match params.find("origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let mut conn = Broker::connect().unwrap();
+    if !try!(check_origin_access(&mut conn, session.get_id(), origin)) {
+        return Ok(Response::with(status::Forbidden));
+    }
+    match depot.datastore.origin_secrets.names(origin) {
+        Ok(names) => {
+            let body = serde_json::to_string(&names).unwrap();
+            let mut response = Response::with((status::Ok, body));
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(e) => {
+            error!("list_origin_secrets:1, err={:?}", e);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
+fn upload_origin_secret(req: &mut Request) -> IronResult<Response> {
+    let depot = req.get::<persistent::Read<Depot>>().unwrap();
+    let session = req.extensions.get::<Authenticated>().unwrap().clone();
+    let params = req.extensions.get::<Router>().unwrap().clone();
+    let (origin, name) = match (params.find("origin"), params.find("name")) {
+        (Some(origin), Some(name)) => (origin, name),
+        _ => return Ok(Response::with(status::BadRequest)),
+    };
+    if !valid_secret_name(name) {
+        debug!("Invalid origin secret name: {}", name);
+        return Ok(Response::with(status::BadRequest));
+    }
+    let mut conn = Broker::connect().unwrap();
+    if !try!(check_origin_access(&mut conn, session.get_id(), origin)) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut content = String::new();
+    if let Err(e) = req.body.read_to_string(&mut content) {
+        debug!("Can't read origin secret content {}", e);
+        return Ok(Response::with(status::BadRequest));
+    }
+    // The Depot can't decrypt secrets, but it can refuse anything that wasn't encrypted with
+    // `hab origin secret upload` so a plaintext value never lands in the data store.
+    if content.lines().next() != Some(ORIGIN_SECRET_FORMAT_VERSION) {
+        debug!("Received an origin secret that isn't encrypted");
+        return Ok(Response::with(status::BadRequest));
+    }
+
+    match depot.datastore.origin_secrets.write(origin, name, &content) {
+        Ok(()) => Ok(Response::with(status::Created)),
+        Err(e) => {
+            error!("upload_origin_secret:1, err={:?}", e);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
+fn delete_origin_secret(req: &mut Request) -> IronResult<Response> {
+    let depot = req.get::<persistent::Read<Depot>>().unwrap();
+    let session = req.extensions.get::<Authenticated>().unwrap().clone();
+    let params = req.extensions.get::<Router>().unwrap().clone();
+    let (origin, name) = match (params.find("origin"), params.find("name")) {
+        (Some(origin), Some(name)) => (origin, name),
+        _ => return Ok(Response::with(status::BadRequest)),
+    };
+    let mut conn = Broker::connect().unwrap();
+    if !try!(check_origin_access(&mut conn, session.get_id(), origin)) {
+        return Ok(Response::with(status::Forbidden));
+    }
+    match depot.datastore.origin_secrets.delete(origin, name) {
+        Ok(true) => Ok(Response::with(status::NoContent)),
+        Ok(false) => Ok(Response::with(status::NotFound)),
+        Err(e) => {
+            error!("delete_origin_secret:1, err={:?}", e);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
+/// Returns every encrypted secret of an origin. Only build workers may call this; they hold the
+/// origin secret key needed to decrypt the values.
+fn download_origin_secrets(req: &mut Request) -> IronResult<Response> {
+    let depot = req.get::<persistent::Read<Depot>>().unwrap();
+    let params = req.extensions.get::<Router>().unwrap();
+    let origin = params.find("origin").unwrap();
+    match depot.datastore.origin_secrets.all(origin) {
+        Ok(secrets) => {
+            let body = serde_json::to_string(&secrets).unwrap();
+            let mut response = Response::with((status::Ok, body));
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(e) => {
+            error!("download_origin_secrets:1, err={:?}", e);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
+/// Secrets become environment variables in the build, so their names must be valid as such.
+fn valid_secret_name(name: &str) -> bool {
+    let re = Regex::new(r"^[A-Za-z_][A-Za-z0-9_]*$").unwrap();
+    re.is_match(name)
+}
+
 fn upload_package(req: &mut Request) -> IronResult<Response> {
     let depot = req.get::<persistent::Read<Depot>>().unwrap();
     let checksum_from_param = match extract_query_value("checksum", req) {
@@ -1168,6 +1280,22 @@ pub fn router(depot: Depot) -> Result<Chain> {
                 XHandler::new(download_latest_origin_secret_key).before(worker.clone())
             }
         },
+        origin_secrets: get "/origins/:origin/secrets" => {
+            XHandler::new(list_origin_secrets).before(basic.clone())
+        },
+        origin_secret_create: post "/origins/:origin/secrets/:name" => {
+            XHandler::new(upload_origin_secret).before(basic.clone())
+        },
+        origin_secret_delete: delete "/origins/:origin/secrets/:name" => {
+            XHandler::new(delete_origin_secret).before(basic.clone())
+        },
+        origin_encrypted_secrets: get "/origins/:origin/encrypted_secrets" => {
+            if depot.config.insecure {
+                XHandler::new(download_origin_secrets)
+            } else {
+                XHandler::new(download_origin_secrets).before(worker.clone())
+            }
+        },
         origin_invitation_create: post "/origins/:origin/users/:username/invitations" => {
             XHandler::new(invite_to_origin).before(basic.clone())
         },
@@ -156,6 +156,39 @@ impl DataStore {
                                 UPDATE jobs SET job_state=jstate, updated_at=now() WHERE id=jid;
                             END
                          $$ LANGUAGE plpgsql VOLATILE"#)?;
+
+        // The build output of each job, one row per line. Workers number the lines they send, so
+        // a chunk that arrives twice doesn't duplicate any output.
+        migrator.migrate("jobsrv",
+                         6,
+                         r#"CREATE TABLE job_logs (
+                                    job_id bigint,
+                                    line bigint,
+                                    content text,
+                                    PRIMARY KEY (job_id, line)
+                             )"#)?;
+
+        // Append a chunk of lines to a job's log, numbering them from the given start line.
+        migrator.migrate("jobsrv",
+                         7,
+                         r#"CREATE OR REPLACE FUNCTION insert_job_log_v1 (jid bigint, start bigint, lines text[]) RETURNS void AS $$
+                            BEGIN
+                                INSERT INTO job_logs (job_id, line, content)
+                                SELECT jid, start + l.n - 1, l.content
+                                FROM unnest(lines) WITH ORDINALITY AS l(content, n)
+                                ON CONFLICT DO NOTHING;
+                            END
+                         $$ LANGUAGE plpgsql VOLATILE"#)?;
+
+        // Get the lines of a job's log from the given start line onwards, in order.
+        migrator.migrate("jobsrv",
+                         8,
+                         r#"CREATE OR REPLACE FUNCTION get_job_log_v1 (jid bigint, start bigint) RETURNS SETOF job_logs AS $$
+                            BEGIN
+                              RETURN QUERY SELECT * FROM job_logs WHERE job_id = jid AND line >= start ORDER BY line ASC;
+                              RETURN;
+                            END
+                            $$ LANGUAGE plpgsql STABLE"#)?;
         Ok(())
     }
 
@@ -292,4 +325,44 @@ impl DataStore {
             .map_err(Error::JobSetState)?;
         Ok(())
     }
+
+    /// Append a chunk of build output to a job's log.
+    ///
+    /// # Errors
+    ///
+    /// * If a connection cannot be gotten from the pool
+    /// * If the lines cannot be inserted into the database
+    pub fn append_job_log(&self, chunk: &jobsrv::JobLogChunk) -> Result<()> {
+        let conn = self.pool.get()?;
+        conn.execute("SELECT insert_job_log_v1($1, $2, $3)",
+                     &[&(chunk.get_job_id() as i64),
+                       &(chunk.get_start() as i64),
+                       &chunk.get_content().to_vec()])
+            .map_err(Error::JobLogAppend)?;
+        Ok(())
+    }
+
+    /// Get the lines of a job's log from line `start` onwards. The log's `is_complete` flag is
+    /// left for the caller to set, as it depends on the state of the job.
+    ///
+    /// # Errors
+    ///
+    /// * If a connection cannot be gotten from the pool
+    /// * If the lines cannot be selected from the database
+    pub fn get_job_log(&self, id: u64, start: u64) -> Result<jobsrv::JobLog> {
+        let conn = self.pool.get()?;
+        let rows = &conn.query("SELECT * FROM get_job_log_v1($1, $2)",
+                               &[&(id as i64), &(start as i64)])
+            .map_err(Error::JobLogGet)?;
+        let mut log = jobsrv::JobLog::new();
+        let mut stop = start;
+        for row in rows {
+            let line: i64 = row.get("line");
+            stop = line as u64 + 1;
+            log.mut_content().push(row.get("content"));
+        }
+        log.set_start(start);
+        log.set_stop(stop);
+        Ok(log)
+    }
 }
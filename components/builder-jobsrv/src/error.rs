@@ -35,6 +35,8 @@ pub enum Error {
     IO(io::Error),
     JobCreate(postgres::error::Error),
     JobGet(postgres::error::Error),
+    JobLogAppend(postgres::error::Error),
+    JobLogGet(postgres::error::Error),
     JobPending(postgres::error::Error),
     JobSetState(postgres::error::Error),
     NetError(hab_net::Error),
@@ -59,6 +61,8 @@ impl fmt::Display for Error {
             Error::IO(ref e) => format!("{}", e),
             Error::JobCreate(ref e) => format!("Database error creating a new job, {}", e),
             Error::JobGet(ref e) => format!("Database error getting job data, {}", e),
+            Error::JobLogAppend(ref e) => format!("Database error appending to a job log, {}", e),
+            Error::JobLogGet(ref e) => format!("Database error getting a job log, {}", e),
             Error::JobPending(ref e) => format!("Database error getting pending jobs, {}", e),
             Error::JobSetState(ref e) => format!("Database error setting job state, {}", e),
             Error::NetError(ref e) => format!("{}", e),
@@ -82,6 +86,8 @@ impl error::Error for Error {
             Error::IO(ref err) => err.description(),
             Error::JobCreate(ref err) => err.description(),
             Error::JobGet(ref err) => err.description(),
+            Error::JobLogAppend(ref err) => err.description(),
+            Error::JobLogGet(ref err) => err.description(),
             Error::JobPending(ref err) => err.description(),
             Error::JobSetState(ref err) => err.description(),
             Error::NetError(ref err) => err.description(),
@@ -53,3 +53,44 @@ pub fn job_get(req: &mut Envelope, sock: &mut zmq::Socket, state: &mut ServerSta
     }
     Ok(())
 }
+
+pub fn job_log_get(req: &mut Envelope,
+                   sock: &mut zmq::Socket,
+                   state: &mut ServerState)
+                   -> Result<()> {
+    let msg: proto::JobLogGet = try!(req.parse_msg());
+    // Look the job up before its log so that a log marked complete holds every line the worker
+    // sent before finishing the job.
+    let job = match state.datastore().get_job(msg.get_id()) {
+        Ok(Some(job)) => job,
+        Ok(None) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "jb:job-log-get:1");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::DATA_STORE, "jb:job-log-get:2");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    };
+    match state.datastore().get_job_log(msg.get_id(), msg.get_start()) {
+        Ok(mut log) => {
+            let is_complete = match job.get_state() {
+                proto::JobState::Complete |
+                proto::JobState::Rejected |
+                proto::JobState::Failed => true,
+                _ => false,
+            };
+            log.set_is_complete(is_complete);
+            try!(req.reply_complete(sock, &log));
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::DATA_STORE, "jb:job-log-get:3");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
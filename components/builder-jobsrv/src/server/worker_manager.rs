@@ -237,11 +237,23 @@ impl WorkerMgr {
     fn process_job_status(&mut self) -> Result<()> {
         // Pop message delimiter
         try!(self.rq_sock.recv(&mut self.msg, 0));
+        // Pop message id
+        try!(self.rq_sock.recv(&mut self.msg, 0));
+        let message_id = self.msg.as_str().unwrap_or("").to_string();
         // Pop message body
         try!(self.rq_sock.recv(&mut self.msg, 0));
-        let job: jobsrv::Job = try!(parse_from_bytes(&self.msg));
-        debug!("job_status={:?}", job);
-        try!(self.datastore.set_job_state(&job));
+        match &message_id[..] {
+            "Job" => {
+                let job: jobsrv::Job = try!(parse_from_bytes(&self.msg));
+                debug!("job_status={:?}", job);
+                try!(self.datastore.set_job_state(&job));
+            }
+            "JobLogChunk" => {
+                let chunk: jobsrv::JobLogChunk = try!(parse_from_bytes(&self.msg));
+                try!(self.datastore.append_job_log(&chunk));
+            }
+            _ => warn!("unexpected message from worker: {:?}", message_id),
+        }
         Ok(())
     }
 }
@@ -54,6 +54,14 @@ impl Routable for JobGet {
     }
 }
 
+impl Routable for JobLogGet {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_id()))
+    }
+}
+
 impl Serialize for Job {
     fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error>
         where S: Serializer
@@ -68,6 +76,19 @@ impl Serialize for Job {
     }
 }
 
+impl Serialize for JobLog {
+    fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error>
+        where S: Serializer
+    {
+        let mut state = try!(serializer.serialize_struct("job_log", 4));
+        try!(serializer.serialize_struct_elt(&mut state, "start", self.get_start()));
+        try!(serializer.serialize_struct_elt(&mut state, "stop", self.get_stop()));
+        try!(serializer.serialize_struct_elt(&mut state, "content", self.get_content()));
+        try!(serializer.serialize_struct_elt(&mut state, "is_complete", self.get_is_complete()));
+        serializer.serialize_struct_end(state)
+    }
+}
+
 impl Default for JobState {
     fn default() -> JobState {
         JobState::Pending
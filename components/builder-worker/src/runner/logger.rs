@@ -14,32 +14,60 @@
 
 use std::fs::File;
 use std::io::{BufRead, BufReader, Write};
+use std::mem;
 use std::process;
+use std::time::{Duration, Instant};
+
+use hab_net::server::ZMQ_CONTEXT;
+use protobuf::{Message, RepeatedField};
+use protocol::jobsrv;
+use zmq;
 
 use super::workspace::Workspace;
 
+/// In-memory zmq address which the worker's server receives job log chunks on
+pub const LOG_INPROC_ADDR: &'static str = "inproc://runner-log";
+
 const EOL_MARKER: &'static str = "\n";
+/// Most lines to collect before sending them on to the JobSrv
+const CHUNK_MAX_LINES: usize = 100;
+/// Longest time to hold on to collected lines before sending them on to the JobSrv
+const CHUNK_MAX_WAIT_MS: u64 = 1_000;
 
 pub struct Logger {
     stdout: File,
     stderr: File,
+    job_id: u64,
+    sock: zmq::Socket,
+    lines: Vec<String>,
+    next_line: u64,
+    last_sent: Instant,
 }
 
 impl Logger {
     pub fn init(workspace: &Workspace) -> Self {
         let stdout = workspace.root().join("stdout.log");
         let stderr = workspace.root().join("stderr.log");
+        let sock = (**ZMQ_CONTEXT).as_mut().socket(zmq::DEALER).unwrap();
+        sock.connect(LOG_INPROC_ADDR).expect("Failed to connect job log stream");
         Logger {
             stdout: File::create(stdout).expect("Failed to initialize stdout log file"),
             stderr: File::create(stderr).expect("Failed to initialize stderr log file"),
+            job_id: workspace.job.get_id(),
+            sock: sock,
+            lines: Vec::new(),
+            next_line: 0,
+            last_sent: Instant::now(),
         }
     }
 
-    /// Stream stdout and stderr of the given child process into the appropriate log files
+    /// Stream stdout and stderr of the given child process into the appropriate log files and on
+    /// to the JobSrv, where the job's log can be followed
     pub fn pipe(&mut self, process: &mut process::Child) {
         if let Some(ref mut stdout) = process.stdout {
             for line in BufReader::new(stdout).lines() {
                 let mut l: String = line.unwrap();
+                self.stream(l.clone());
                 l = l + EOL_MARKER;
                 self.log_stdout(l.as_bytes());
             }
@@ -47,10 +75,39 @@ impl Logger {
         if let Some(ref mut stderr) = process.stderr {
             for line in BufReader::new(stderr).lines() {
                 let mut l: String = line.unwrap();
+                self.stream(l.clone());
                 l = l + EOL_MARKER;
                 self.log_stderr(l.as_bytes());
             }
         }
+        self.flush();
+    }
+
+    /// Queue a line to be sent to the JobSrv, sending the queue once it is big or old enough
+    fn stream(&mut self, line: String) {
+        self.lines.push(line);
+        if self.lines.len() >= CHUNK_MAX_LINES ||
+           self.last_sent.elapsed() >= Duration::from_millis(CHUNK_MAX_WAIT_MS) {
+            self.flush();
+        }
+    }
+
+    /// Send every queued line to the JobSrv. The log files remain the record of the job's output,
+    /// so a chunk which can't be sent is dropped rather than failing the job.
+    fn flush(&mut self) {
+        self.last_sent = Instant::now();
+        if self.lines.is_empty() {
+            return;
+        }
+        let lines = mem::replace(&mut self.lines, Vec::new());
+        let mut chunk = jobsrv::JobLogChunk::new();
+        chunk.set_job_id(self.job_id);
+        chunk.set_start(self.next_line);
+        self.next_line += lines.len() as u64;
+        chunk.set_content(RepeatedField::from_vec(lines));
+        if let Err(err) = self.sock.send(&chunk.write_to_bytes().unwrap(), 0) {
+            warn!("unable to stream job log, job={}, err={}", self.job_id, err);
+        }
     }
 
     /// Log message to stdout logfile
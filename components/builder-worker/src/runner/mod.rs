@@ -105,6 +105,7 @@ pub struct Runner {
     auth_token: String,
     logger: Option<Logger>,
     depot_cli: depot_client::Client,
+    secrets: Vec<(String, String)>,
 }
 
 impl Runner {
@@ -117,6 +118,7 @@ impl Runner {
             workspace: Workspace::new(config.data_path.clone(), job),
             logger: None,
             depot_cli: depot_cli,
+            secrets: Vec::new(),
         }
     }
 
@@ -157,6 +159,29 @@ impl Runner {
                 return self.fail(net::err(ErrCode::SECRET_KEY_FETCH, "wk:run:3"));
             }
         }
+        match self.depot_cli.fetch_origin_secrets(self.job().origin(), &self.auth_token) {
+            Ok(secrets) => {
+                let cache = crypto::default_cache_key_path(None);
+                for (name, encrypted) in secrets {
+                    match crypto::SigKeyPair::decrypt_secret(&encrypted, &cache)
+                        .map(String::from_utf8) {
+                        Ok(Ok(value)) => self.secrets.push((name, value)),
+                        Ok(Err(_)) => {
+                            error!("Origin secret {} is not valid UTF-8", name);
+                            return self.fail(net::err(ErrCode::SECRET_KEY_IMPORT, "wk:run:7"));
+                        }
+                        Err(err) => {
+                            error!("Unable to decrypt origin secret {}, err={}", name, err);
+                            return self.fail(net::err(ErrCode::SECRET_KEY_IMPORT, "wk:run:8"));
+                        }
+                    }
+                }
+            }
+            Err(err) => {
+                error!("Unable to retrieve origin secrets, err={}", err);
+                return self.fail(net::err(ErrCode::SECRET_KEY_FETCH, "wk:run:9"));
+            }
+        }
         if let Some(err) = self.job().vcs().clone(&self.workspace.src()).err() {
             error!("Unable to clone remote source repository, err={}", err);
             return self.fail(net::err(ErrCode::VCS_CLONE, "wk:run:4"));
@@ -197,10 +222,13 @@ impl Runner {
                             .unwrap())];
         let command = studio_cmd();
         debug!("building, cmd={:?}, args={:?}", command, args);
-        let mut child = Command::new(command)
-            .args(&args)
-            .env_clear()
-            .stdout(Stdio::piped())
+        let mut cmd = Command::new(command);
+        cmd.args(&args).env_clear();
+        // The Studio exposes these inside the build with the prefix removed.
+        for &(ref name, ref value) in self.secrets.iter() {
+            cmd.env(format!("HAB_STUDIO_SECRET_{}", name), value);
+        }
+        let mut child = cmd.stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()
             .expect("failed to spawn child");
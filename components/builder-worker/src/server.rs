@@ -23,6 +23,7 @@ use config::Config;
 use error::Result;
 use heartbeat::{HeartbeatCli, HeartbeatMgr};
 use runner::{RunnerCli, RunnerMgr};
+use runner::logger::LOG_INPROC_ADDR;
 
 enum State {
     Ready,
@@ -39,6 +40,8 @@ pub struct Server {
     config: Arc<RwLock<Config>>,
     /// Dealer Socket connected to JobSrv
     fe_sock: zmq::Socket,
+    /// Dealer Socket receiving job log chunks from the Job Runner
+    log_sock: zmq::Socket,
     hb_cli: HeartbeatCli,
     runner_cli: RunnerCli,
     state: State,
@@ -48,12 +51,14 @@ pub struct Server {
 impl Server {
     pub fn new(config: Config) -> Result<Self> {
         let fe_sock = try!((**ZMQ_CONTEXT).as_mut().socket(zmq::DEALER));
+        let log_sock = try!((**ZMQ_CONTEXT).as_mut().socket(zmq::DEALER));
         let hb_cli = HeartbeatCli::new();
         let runner_cli = RunnerCli::new();
         try!(fe_sock.set_identity(Self::net_ident().as_bytes()));
         Ok(Server {
             config: Arc::new(RwLock::new(config)),
             fe_sock: fe_sock,
+            log_sock: log_sock,
             hb_cli: hb_cli,
             runner_cli: runner_cli,
             state: State::default(),
@@ -62,6 +67,7 @@ impl Server {
     }
 
     pub fn run(&mut self) -> Result<()> {
+        try!(self.log_sock.bind(LOG_INPROC_ADDR));
         try!(HeartbeatMgr::start(self.config.clone()));
         try!(RunnerMgr::start(self.config.clone()));
         try!(self.hb_cli.connect());
@@ -75,9 +81,12 @@ impl Server {
         }
         let mut fe_msg = false;
         let mut runner_msg = false;
+        let mut log_msg = false;
         loop {
             {
-                let mut items = [self.fe_sock.as_poll_item(1), self.runner_cli.as_poll_item(1)];
+                let mut items = [self.fe_sock.as_poll_item(1),
+                                 self.runner_cli.as_poll_item(1),
+                                 self.log_sock.as_poll_item(1)];
                 try!(zmq::poll(&mut items, -1));
                 if items[0].get_revents() & zmq::POLLIN > 0 {
                     fe_msg = true;
@@ -85,10 +94,21 @@ impl Server {
                 if items[1].get_revents() & zmq::POLLIN > 0 {
                     runner_msg = true;
                 }
+                if items[2].get_revents() & zmq::POLLIN > 0 {
+                    log_msg = true;
+                }
+            }
+            if log_msg {
+                try!(self.forward_logs());
+                log_msg = false;
             }
             if runner_msg {
+                // The Runner streams a job's log before completing it, so send the rest of the
+                // log on ahead of the completed job.
+                try!(self.forward_logs());
                 {
                     let reply = try!(self.runner_cli.recv_complete());
+                    try!(self.fe_sock.send_str("Job", zmq::SNDMORE));
                     try!(self.fe_sock.send(reply, 0));
                 }
                 try!(self.set_ready());
@@ -102,6 +122,7 @@ impl Server {
                         try!(self.runner_cli.send(&self.msg));
                         {
                             let reply = try!(self.runner_cli.recv_ack());
+                            try!(self.fe_sock.send_str("Job", zmq::SNDMORE));
                             try!(self.fe_sock.send(reply, 0));
                         }
                         try!(self.set_busy());
@@ -109,6 +130,7 @@ impl Server {
                     State::Busy => {
                         let mut reply: protocol::jobsrv::Job = parse_from_bytes(&self.msg).unwrap();
                         reply.set_state(protocol::jobsrv::JobState::Rejected);
+                        try!(self.fe_sock.send_str("Job", zmq::SNDMORE));
                         try!(self.fe_sock.send(&reply.write_to_bytes().unwrap(), 0));
                     }
                 }
@@ -117,6 +139,20 @@ impl Server {
         }
     }
 
+    /// Send every job log chunk waiting on the log socket on to the JobSrv.
+    fn forward_logs(&mut self) -> Result<()> {
+        loop {
+            match self.log_sock.recv(&mut self.msg, zmq::DONTWAIT) {
+                Ok(()) => {
+                    try!(self.fe_sock.send_str("JobLogChunk", zmq::SNDMORE));
+                    try!(self.fe_sock.send(&self.msg, 0));
+                }
+                Err(zmq::Error::EAGAIN) => return Ok(()),
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+
     fn set_busy(&mut self) -> Result<()> {
         try!(self.hb_cli.set_busy());
         self.state = State::Busy;
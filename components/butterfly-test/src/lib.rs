@@ -37,6 +37,8 @@ use habitat_core::service::ServiceGroup;
 use habitat_core::package::{Identifiable, PackageIdent};
 use habitat_core::crypto::keys::sym_key::SymKey;
 use habitat_butterfly::trace::Trace;
+#[cfg(feature = "simulation")]
+use habitat_butterfly::simulation::Link;
 
 static SERVER_PORT: AtomicUsize = ATOMIC_USIZE_INIT;
 
@@ -345,6 +347,46 @@ impl SwimNet {
         }
     }
 
+    /// Slow down the messages `to_entry` gets from `from_entry` by `latency_ms`, and lose `loss`
+    /// of them (from 0.0 to 1.0).
+    #[cfg(feature = "simulation")]
+    pub fn degrade(&self, from_entry: usize, to_entry: usize, latency_ms: u64, loss: f64) {
+        let from = self.members.get(from_entry).unwrap();
+        let to = self.members.get(to_entry).unwrap();
+        trace_it!(TEST: to, format!("Degraded link from {} {}", from.name(), from.member_id()));
+        to.set_link(from.member_id().to_string(),
+                    Link::new(Duration::from_millis(latency_ms), loss));
+    }
+
+    /// Degrade the links between two ranges of members, both ways.
+    #[cfg(feature = "simulation")]
+    pub fn degrade_between(&self,
+                           left_range: Range<usize>,
+                           right_range: Range<usize>,
+                           latency_ms: u64,
+                           loss: f64) {
+        let right: Vec<usize> = right_range.collect();
+        for l in left_range {
+            for r in right.iter() {
+                if l == *r {
+                    continue;
+                }
+                self.degrade(l, *r, latency_ms, loss);
+                self.degrade(*r, l, latency_ms, loss);
+            }
+        }
+    }
+
+    /// Restore every degraded link between the members.
+    #[cfg(feature = "simulation")]
+    pub fn restore(&self) {
+        for to in self.members.iter() {
+            for from in self.members.iter() {
+                to.remove_link(from.member_id());
+            }
+        }
+    }
+
     pub fn wait_for_health_of(&self, from_entry: usize, to_check: usize, health: Health) -> bool {
         let rounds_in = self.rounds_in(self.max_rounds());
         loop {
@@ -16,15 +16,19 @@
 //!
 //! This will connect to a given butterfly members `Pull` thread, and inject a rumor.
 
-use habitat_core::crypto::SymKey;
+use habitat_core::crypto::{SigKeyPair, SymKey};
 use habitat_core::service::ServiceGroup;
 use zmq;
 
 use ZMQ_CONTEXT;
 use message;
 use rumor::Rumor;
-use rumor::service_config::ServiceConfig;
+use rumor::service_config::{MemberSelector, ServiceConfig};
 use rumor::service_file::ServiceFile;
+use rumor::service_restart::ServiceRestart;
+use rumor::service_scale::ServiceScale;
+use rumor::active_color::ActiveColor;
+use rumor::ring_command::RingCommand;
 use error::{Result, Error};
 
 /// Holds a ZMQ Push socket, and an optional ring encryption key.
@@ -65,9 +69,45 @@ impl Client {
                                config: Vec<u8>,
                                encrypted: bool)
                                -> Result<()> {
+        self.send_service_config_to(service_group,
+                                    incarnation,
+                                    config,
+                                    encrypted,
+                                    false,
+                                    &MemberSelector::default())
+    }
+
+    /// Create a service config patch, to be merged over the configuration of the incarnation
+    /// before it, and send it to the server.
+    pub fn send_service_config_patch(&mut self,
+                                     service_group: ServiceGroup,
+                                     incarnation: u64,
+                                     patch: Vec<u8>,
+                                     encrypted: bool)
+                                     -> Result<()> {
+        self.send_service_config_to(service_group,
+                                    incarnation,
+                                    patch,
+                                    encrypted,
+                                    true,
+                                    &MemberSelector::default())
+    }
+
+    /// Create a service configuration, or a patch if `patch`, for only the members of the service
+    /// group `selector` chooses, and send it to the server.
+    pub fn send_service_config_to(&mut self,
+                                  service_group: ServiceGroup,
+                                  incarnation: u64,
+                                  config: Vec<u8>,
+                                  encrypted: bool,
+                                  patch: bool,
+                                  selector: &MemberSelector)
+                                  -> Result<()> {
         let mut sc = ServiceConfig::new("butterflyclient", service_group, config);
         sc.set_incarnation(incarnation);
         sc.set_encrypted(encrypted);
+        sc.set_patch(patch);
+        sc.set_selector(selector);
         self.send(sc)
     }
 
@@ -85,10 +125,64 @@ impl Client {
         self.send(sf)
     }
 
+    /// Create a service scale and send it to the server.
+    pub fn send_service_scale(&mut self,
+                              service_group: ServiceGroup,
+                              incarnation: u64,
+                              desired_count: u64)
+                              -> Result<()> {
+        let mut ss = ServiceScale::new("butterflyclient", service_group, desired_count);
+        ss.set_incarnation(incarnation);
+        self.send(ss)
+    }
+
+    /// Create a service restart, asking every member of `service_group` to restart it, one at a
+    /// time if `rolling`, and send it to the server.
+    pub fn send_service_restart(&mut self,
+                                service_group: ServiceGroup,
+                                incarnation: u64,
+                                rolling: bool)
+                                -> Result<()> {
+        let mut sr = ServiceRestart::new("butterflyclient", service_group, rolling);
+        sr.set_incarnation(incarnation);
+        self.send(sr)
+    }
+
+    /// Create an active color, making `service_group` the live group of its service, and send
+    /// it to the server.
+    pub fn send_active_color(&mut self,
+                             service_group: ServiceGroup,
+                             incarnation: u64)
+                             -> Result<()> {
+        let service = match service_group.org() {
+            Some(org) => format!("{}@{}", service_group.service(), org),
+            None => service_group.service().to_string(),
+        };
+        let mut ac = ActiveColor::new("butterflyclient", service, service_group.group());
+        ac.set_incarnation(incarnation);
+        self.send(ac)
+    }
+
+    /// Create a ring command for every member to run, sign it with `pair`, and send it to the
+    /// server.
+    pub fn send_ring_command<S1, S2>(&mut self,
+                                     id: S1,
+                                     command: S2,
+                                     args: Vec<String>,
+                                     pair: &SigKeyPair)
+                                     -> Result<()>
+        where S1: Into<String>,
+              S2: Into<String>
+    {
+        let mut rc = RingCommand::new("butterflyclient", id, command, args);
+        try!(rc.sign(pair));
+        self.send(rc)
+    }
+
     /// Send any `Rumor` to the server.
     pub fn send<T: Rumor>(&mut self, rumor: T) -> Result<()> {
         let bytes = try!(rumor.write_to_bytes());
-        let wire_msg = try!(message::generate_wire(bytes, &self.ring_key));
+        let wire_msg = try!(message::generate_wire(bytes, &self.ring_key, None));
         self.socket.send(&wire_msg, 0).map_err(Error::ZmqSendError)
     }
 }
@@ -30,7 +30,9 @@ pub type Result<T> = result::Result<T, Error>;
 pub enum Error {
     BadMessage(String),
     CannotBind(io::Error),
+    CompressionError(&'static str, io::Error),
     HabitatCore(habitat_core::error::Error),
+    InvalidSignature(String),
     NonExistentRumor(String, String),
     ProtobufError(protobuf::ProtobufError),
     ServiceConfigDecode(String, Vec<toml::ParserError>),
@@ -38,6 +40,7 @@ pub enum Error {
     SocketSetReadTimeout(io::Error),
     SocketSetWriteTimeout(io::Error),
     SocketCloneError,
+    UnknownCompression(String),
     ZmqConnectError(zmq::Error),
     ZmqSendError(zmq::Error),
 }
@@ -47,7 +50,13 @@ impl fmt::Display for Error {
         let msg = match *self {
             Error::BadMessage(ref err) => format!("Bad Message: {:?}", err),
             Error::CannotBind(ref err) => format!("Cannot bind to port: {:?}", err),
+            Error::CompressionError(codec, ref err) => {
+                format!("Cannot compress or decompress a {} payload: {}", codec, err)
+            }
             Error::HabitatCore(ref err) => format!("{}", err),
+            Error::InvalidSignature(ref id) => {
+                format!("Ring command {} is not signed by its signer's key", id)
+            }
             Error::NonExistentRumor(ref member_id, ref rumor_id) => {
                 format!("Non existent rumor asked to be written to bytes: {} {}",
                         member_id,
@@ -67,6 +76,9 @@ impl fmt::Display for Error {
                 format!("Cannot set UDP socket write timeout: {}", err)
             }
             Error::SocketCloneError => format!("Cannot clone the underlying UDP socket"),
+            Error::UnknownCompression(ref codec) => {
+                format!("Unknown compression {}; use lz4 or zstd", codec)
+            }
             Error::ZmqConnectError(ref err) => format!("Cannot connect ZMQ socket: {}", err),
             Error::ZmqSendError(ref err) => {
                 format!("Cannot send message through ZMQ socket: {}", err)
@@ -82,7 +94,9 @@ impl error::Error for Error {
         match *self {
             Error::BadMessage(_) => "Bad Protobuf Message; should be Ping/Ack/PingReq",
             Error::CannotBind(_) => "Cannot bind to port",
+            Error::CompressionError(_, _) => "Cannot compress or decompress a payload",
             Error::HabitatCore(_) => "Habitat core error",
+            Error::InvalidSignature(_) => "Ring command is not signed by its signer's key",
             Error::NonExistentRumor(_, _) => {
                 "Cannot write rumor to bytes because it does not exist"
             }
@@ -92,6 +106,7 @@ impl error::Error for Error {
             Error::SocketSetReadTimeout(_) => "Cannot set UDP socket read timeout",
             Error::SocketSetWriteTimeout(_) => "Cannot set UDP socket write timeout",
             Error::SocketCloneError => "Cannot clone the underlying UDP socket",
+            Error::UnknownCompression(_) => "Unknown compression codec",
             Error::ZmqConnectError(_) => "Cannot connect ZMQ socket",
             Error::ZmqSendError(_) => "Cannot send message through ZMQ socket",
         }
@@ -25,11 +25,12 @@
 //! 1. Members can be marked "persistent", which means that they will always be taken through the
 //!    Probe cycle, regardless of their status. This allows networks to heal from partitions.
 //!
-//! The SWIM implementation has three working threads:
+//! The SWIM implementation has four working threads:
 //!
 //! 1. An inbound thread, handling receipt of SWIM messages.
 //! 1. An outbound thread, which handles the Ping->PingReq cycle and protocol timing.
 //! 1. An expire thread, which handles timing out suspected members.
+//! 1. A compact thread, which forgets members that departed long ago, along with their rumors.
 //!
 //! The Gossip implementation has two working threads:
 //!
@@ -45,15 +46,20 @@ extern crate habitat_core;
 extern crate lazy_static;
 #[macro_use]
 extern crate log;
+extern crate lz4;
 extern crate protobuf;
 extern crate rand;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
+#[cfg(test)]
+extern crate tempdir;
+extern crate threadpool;
 extern crate time;
 extern crate toml;
 extern crate uuid;
 extern crate zmq;
+extern crate zstd;
 
 #[macro_use]
 pub mod trace;
@@ -63,6 +69,8 @@ pub mod member;
 pub mod message;
 pub mod rumor;
 pub mod server;
+#[cfg(feature = "simulation")]
+pub mod simulation;
 
 use std::cell::UnsafeCell;
 
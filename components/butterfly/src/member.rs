@@ -22,11 +22,13 @@ use std::ops::{Deref, DerefMut};
 use std::sync::{Arc, RwLock};
 use std::sync::atomic::{AtomicUsize, Ordering};
 
+use protobuf::RepeatedField;
 use uuid::Uuid;
 use rand::{thread_rng, Rng};
-use time::SteadyTime;
+use time::{Duration, SteadyTime};
 
 use rumor::RumorKey;
+use message::compression;
 use message::swim::{Member as ProtoMember, Membership as ProtoMembership,
                     Membership_Health as ProtoMembership_Health, Rumor_Type};
 
@@ -90,11 +92,14 @@ pub struct Member {
 }
 
 impl Member {
-    /// Creates a new member with a unique UUID and an incarnation of zero.
+    /// Creates a new member with a unique UUID and an incarnation of zero, advertising every
+    /// compression codec it can decompress.
     pub fn new() -> Member {
         let mut proto_member = ProtoMember::new();
         proto_member.set_id(Uuid::new_v4().simple().to_string());
         proto_member.set_incarnation(0);
+        let codecs = compression::SUPPORTED.iter().map(|c| c.name().to_string()).collect();
+        proto_member.set_compression(RepeatedField::from_vec(codecs));
         Member { proto: proto_member }
     }
 
@@ -115,6 +120,17 @@ impl Member {
     }
 }
 
+/// Shows the member's alias alongside its id, or only its id if it has no alias.
+impl fmt::Display for Member {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.get_alias().is_empty() {
+            write!(f, "{}", self.get_id())
+        } else {
+            write!(f, "{} ({})", self.get_alias(), self.get_id())
+        }
+    }
+}
+
 impl Deref for Member {
     type Target = ProtoMember;
 
@@ -162,13 +178,16 @@ impl<'a> From<&'a &'a Member> for RumorKey {
 // This is a Uuid type turned to a string
 pub type UuidSimple = String;
 
-/// Tracks lists of members, their health, and how long they have been suspect.
+/// Tracks lists of members, their health, how long they have been suspect, and how long they have
+/// been confirmed departed.
 #[derive(Debug, Clone)]
 pub struct MemberList {
     members: Arc<RwLock<HashMap<UuidSimple, Member>>>,
     health: Arc<RwLock<HashMap<UuidSimple, Health>>>,
     suspect: Arc<RwLock<HashMap<UuidSimple, SteadyTime>>>,
+    departed: Arc<RwLock<HashMap<UuidSimple, SteadyTime>>>,
     initial_members: Arc<RwLock<Vec<Member>>>,
+    initial_members_counter: Arc<AtomicUsize>,
     update_counter: Arc<AtomicUsize>,
 }
 
@@ -179,7 +198,9 @@ impl MemberList {
             members: Arc::new(RwLock::new(HashMap::new())),
             health: Arc::new(RwLock::new(HashMap::new())),
             suspect: Arc::new(RwLock::new(HashMap::new())),
+            departed: Arc::new(RwLock::new(HashMap::new())),
             initial_members: Arc::new(RwLock::new(Vec::new())),
+            initial_members_counter: Arc::new(AtomicUsize::new(0)),
             update_counter: Arc::new(AtomicUsize::new(0)),
         }
     }
@@ -204,6 +225,20 @@ impl MemberList {
     pub fn add_initial_member(&self, member: Member) {
         let mut im = self.initial_members.write().expect("Initial members lock is poisoned");
         im.push(member);
+        self.initial_members_counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Replaces the initial members. The outbound thread starts pinging them again, until enough
+    /// of them have joined.
+    pub fn set_initial_members(&self, members: Vec<Member>) {
+        let mut im = self.initial_members.write().expect("Initial members lock is poisoned");
+        *im = members;
+        self.initial_members_counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Changes whenever the initial members do.
+    pub fn get_initial_members_counter(&self) -> usize {
+        self.initial_members_counter.load(Ordering::Relaxed)
     }
 
     pub fn with_initial_members<F>(&self, mut with_closure: F) -> ()
@@ -287,6 +322,7 @@ impl MemberList {
             if stop_suspicion == true {
                 self.suspect.write().expect("Suspect lock is poisoned").remove(member.get_id());
             }
+            self.track_departure(member.get_id(), health);
             self.members
                 .write()
                 .expect("Member list lock is poisoned")
@@ -360,6 +396,7 @@ impl MemberList {
             let mut sl = self.suspect.write().expect("Suspect lock is poisoned");
             sl.insert(String::from(member_id), SteadyTime::now());
         }
+        self.track_departure(member_id, health);
         self.health
             .write()
             .expect("Health write lock is poisoned")
@@ -458,6 +495,21 @@ impl MemberList {
         with_closure(member);
     }
 
+    /// Returns the alias of the member with the given ID, or `None` if there is no such member or
+    /// it has no alias.
+    pub fn alias_of(&self, member_id: &str) -> Option<String> {
+        self.members
+            .read()
+            .expect("Member list lock is poisoned")
+            .get(member_id)
+            .map(|member| member.get_alias())
+            .and_then(|alias| if alias.is_empty() {
+                None
+            } else {
+                Some(alias.to_string())
+            })
+    }
+
     /// Iterates over the member list, calling the function for each member.
     pub fn with_members<F>(&self, mut with_closure: F) -> ()
         where F: FnMut(&Member) -> ()
@@ -485,6 +537,49 @@ impl MemberList {
     pub fn contains_member(&self, member_id: &str) -> bool {
         self.members.read().expect("Member list lock is poisoned").contains_key(member_id)
     }
+
+    /// Records when a member was confirmed departed, or forgets it if the member is back.
+    fn track_departure(&self, member_id: &str, health: Health) {
+        let mut departed = self.departed.write().expect("Departed list lock is poisoned");
+        if health == Health::Confirmed {
+            departed.entry(String::from(member_id)).or_insert(SteadyTime::now());
+        } else {
+            departed.remove(member_id);
+        }
+    }
+
+    /// Returns how long each member has been confirmed departed. Persistent members are never
+    /// returned, since they are kept to heal partitions.
+    pub fn departed(&self) -> Vec<(UuidSimple, Duration)> {
+        let now = SteadyTime::now();
+        let members = self.members.read().expect("Member list lock is poisoned");
+        self.departed
+            .read()
+            .expect("Departed list lock is poisoned")
+            .iter()
+            .filter(|&(id, _)| members.get(id).map_or(true, |m| !m.get_persistent()))
+            .map(|(id, since)| (id.clone(), now - *since))
+            .collect()
+    }
+
+    /// Returns the members which have been confirmed departed for at least `ttl`. Persistent
+    /// members are never returned.
+    pub fn departed_for(&self, ttl: Duration) -> Vec<UuidSimple> {
+        self.departed()
+            .into_iter()
+            .filter(|&(_, departed)| departed >= ttl)
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// Forgets a member entirely. If it is still gossiped about, it is added again.
+    pub fn remove(&self, member_id: &str) {
+        self.members.write().expect("Member list lock is poisoned").remove(member_id);
+        self.health.write().expect("Health lock is poisoned").remove(member_id);
+        self.suspect.write().expect("Suspect lock is poisoned").remove(member_id);
+        self.departed.write().expect("Departed list lock is poisoned").remove(member_id);
+        self.increment_update_counter();
+    }
 }
 
 #[cfg(test)]
@@ -513,9 +608,21 @@ mod tests {
             let member: Member = proto.into();
             assert_eq!(proto2, member.proto);
         }
+
+        // Shows the alias, if there is one, next to the id
+        #[test]
+        fn display() {
+            let mut member = Member::new();
+            let id = String::from(member.get_id());
+            assert_eq!(member.to_string(), id);
+            member.set_alias(String::from("web-1"));
+            assert_eq!(member.to_string(), format!("web-1 ({})", id));
+        }
     }
 
     mod member_list {
+        use time::Duration;
+
         use member::{Member, MemberList, Health, PINGREQ_TARGETS};
 
         fn populated_member_list(size: u64) -> MemberList {
@@ -786,5 +893,22 @@ mod tests {
             assert!(ml.check_health_of(&mcheck_two, Health::Confirmed));
         }
 
+        #[test]
+        fn departed_for() {
+            let ml = populated_member_list(2);
+            let mut persistent = Member::new();
+            persistent.set_persistent(true);
+            ml.insert(persistent.clone(), Health::Confirmed);
+            let departed = Member::new();
+            ml.insert(departed.clone(), Health::Confirmed);
+            assert_eq!(ml.departed_for(Duration::hours(1)), Vec::<String>::new());
+            assert_eq!(ml.departed_for(Duration::zero()),
+                       vec![String::from(departed.get_id())]);
+
+            ml.remove(departed.get_id());
+            assert!(!ml.contains_member(departed.get_id()));
+            assert_eq!(ml.len(), 3);
+            assert_eq!(ml.departed_for(Duration::zero()), Vec::<String>::new());
+        }
     }
 }
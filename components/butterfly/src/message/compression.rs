@@ -0,0 +1,188 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compression of gossip payloads.
+//!
+//! Every member advertises the codecs it can decompress in its membership rumor, and a member
+//! only compresses what it gossips to another when that member has advertised the codec. Members
+//! which predate compression advertise nothing, so they keep receiving uncompressed payloads.
+//! Payloads are compressed before they are encrypted, and only when they are at least
+//! `COMPRESSION_THRESHOLD` bytes and compressing them makes them smaller.
+
+use std::fmt;
+use std::io::{self, Read};
+use std::result;
+use std::str::FromStr;
+
+use lz4;
+use zstd;
+
+use error::{Error, Result};
+
+/// Payloads smaller than this are never compressed; the savings wouldn't be worth the work.
+pub const COMPRESSION_THRESHOLD: usize = 512;
+
+/// The zstd compression level; low levels are nearly as fast as LZ4.
+const ZSTD_LEVEL: i32 = 3;
+
+/// Largest payload a member decompresses, so that a small compressed message can't make it
+/// allocate without bound.
+pub const MAX_DECOMPRESSED_BYTES: usize = 16 * 1024 * 1024;
+
+/// Every codec this member can decompress, in the order it prefers them.
+pub const SUPPORTED: &'static [Compression] = &[Compression::Lz4, Compression::Zstd];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compression {
+    Lz4,
+    Zstd,
+}
+
+impl Compression {
+    /// The name the codec is advertised and tagged with on the wire.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Compression::Lz4 => "lz4",
+            Compression::Zstd => "zstd",
+        }
+    }
+
+    pub fn compress(&self, payload: &[u8]) -> Result<Vec<u8>> {
+        let compressed = match *self {
+            Compression::Lz4 => lz4::block::compress(payload, None, true),
+            Compression::Zstd => zstd::encode_all(payload, ZSTD_LEVEL),
+        };
+        compressed.map_err(|e| Error::CompressionError(self.name(), e))
+    }
+
+    /// Decompresses a payload, refusing any which would decompress to more than
+    /// `MAX_DECOMPRESSED_BYTES` before allocating for it.
+    pub fn decompress(&self, payload: &[u8]) -> Result<Vec<u8>> {
+        let decompressed = match *self {
+            Compression::Lz4 => lz4_decompress(payload),
+            Compression::Zstd => zstd_decompress(payload),
+        };
+        decompressed.map_err(|e| Error::CompressionError(self.name(), e))
+    }
+}
+
+/// LZ4 blocks are sent with their decompressed size prepended, as a little-endian 32-bit integer,
+/// which the decompressor would otherwise allocate blindly.
+fn lz4_decompress(payload: &[u8]) -> io::Result<Vec<u8>> {
+    if payload.len() < 4 {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "payload is missing its size"));
+    }
+    let size = payload[..4]
+        .iter()
+        .rev()
+        .fold(0usize, |size, &byte| (size << 8) | byte as usize);
+    if size > MAX_DECOMPRESSED_BYTES {
+        return Err(too_large());
+    }
+    lz4::block::decompress(payload, None)
+}
+
+/// zstd frames are read through a limit, since their declared size is optional.
+fn zstd_decompress(payload: &[u8]) -> io::Result<Vec<u8>> {
+    let decoder = try!(zstd::stream::Decoder::new(payload));
+    let mut decompressed = Vec::new();
+    try!(decoder.take(MAX_DECOMPRESSED_BYTES as u64 + 1).read_to_end(&mut decompressed));
+    if decompressed.len() > MAX_DECOMPRESSED_BYTES {
+        return Err(too_large());
+    }
+    Ok(decompressed)
+}
+
+fn too_large() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData,
+                   format!("payload decompresses to more than {} bytes",
+                           MAX_DECOMPRESSED_BYTES))
+}
+
+impl fmt::Display for Compression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Compression {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value {
+            "lz4" => Ok(Compression::Lz4),
+            "zstd" => Ok(Compression::Zstd),
+            _ => Err(Error::UnknownCompression(value.to_string())),
+        }
+    }
+}
+
+/// Compresses a payload with the given codec, if that is worthwhile. Returns the codec used, if
+/// any, along with the payload to send.
+pub fn compress(payload: Vec<u8>,
+                compression: Option<Compression>)
+                -> Result<(Option<Compression>, Vec<u8>)> {
+    match compression {
+        Some(codec) if payload.len() >= COMPRESSION_THRESHOLD => {
+            let compressed = try!(codec.compress(&payload));
+            if compressed.len() < payload.len() {
+                Ok((Some(codec), compressed))
+            } else {
+                Ok((None, payload))
+            }
+        }
+        _ => Ok((None, payload)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{compress, Compression, COMPRESSION_THRESHOLD, MAX_DECOMPRESSED_BYTES, SUPPORTED};
+
+    #[test]
+    fn round_trip() {
+        let payload = "[redis]\nport = 6379\ntcp-keepalive = 0\n".repeat(64).into_bytes();
+        for codec in SUPPORTED {
+            let (used, compressed) = compress(payload.clone(), Some(*codec)).unwrap();
+            assert_eq!(used, Some(*codec));
+            assert!(compressed.len() < payload.len());
+            assert_eq!(codec.decompress(&compressed).unwrap(), payload);
+            assert_eq!(Compression::from_str(codec.name()).unwrap(), *codec);
+        }
+    }
+
+    #[test]
+    fn small_payloads_are_not_compressed() {
+        let payload = vec![0; COMPRESSION_THRESHOLD - 1];
+        let (used, sent) = compress(payload.clone(), Some(Compression::Lz4)).unwrap();
+        assert_eq!(used, None);
+        assert_eq!(sent, payload);
+        assert!(Compression::from_str("gzip").is_err());
+    }
+
+    #[test]
+    fn oversized_payloads_are_refused() {
+        let payload = vec![0; MAX_DECOMPRESSED_BYTES + 1];
+        for codec in SUPPORTED {
+            let compressed = codec.compress(&payload).unwrap();
+            assert!(codec.decompress(&compressed).is_err());
+        }
+        // An LZ4 block which claims a size past the limit is refused before it's decompressed.
+        let mut forged = vec![0xff, 0xff, 0xff, 0x7f];
+        forged.extend_from_slice(&[0; 16]);
+        assert!(Compression::Lz4.decompress(&forged).is_err());
+    }
+}
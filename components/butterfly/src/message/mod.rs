@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod compression;
 pub mod swim;
 
 use std::result;
@@ -23,12 +24,22 @@ use serde::{Serialize, Serializer};
 use toml;
 
 use error::Result;
+use message::compression::Compression;
 use message::swim::Wire;
 use rumor::service::SysInfo;
 use protobuf::{self, Message};
 
-pub fn generate_wire(payload: Vec<u8>, ring_key: &Option<SymKey>) -> Result<Vec<u8>> {
+/// Wraps a payload for the wire, compressing it with `compression` if that is worthwhile and
+/// encrypting it if there is a ring key.
+pub fn generate_wire(payload: Vec<u8>,
+                     ring_key: &Option<SymKey>,
+                     compression: Option<Compression>)
+                     -> Result<Vec<u8>> {
     let mut wire = Wire::new();
+    let (compression, payload) = try!(compression::compress(payload, compression));
+    if let Some(codec) = compression {
+        wire.set_compression(codec.name().to_string());
+    }
     if let Some(ref ring_key) = *ring_key {
         wire.set_encrypted(true);
         let (nonce, encrypted_payload) = try!(ring_key.encrypt(&payload));
@@ -42,10 +53,16 @@ pub fn generate_wire(payload: Vec<u8>, ring_key: &Option<SymKey>) -> Result<Vec<
 
 pub fn unwrap_wire(payload: &[u8], ring_key: &Option<SymKey>) -> Result<Vec<u8>> {
     let mut wire: Wire = try!(protobuf::parse_from_bytes(payload));
-    if let Some(ref ring_key) = *ring_key {
-        Ok(try!(ring_key.decrypt(wire.get_nonce(), wire.get_payload())))
+    let payload = if let Some(ref ring_key) = *ring_key {
+        try!(ring_key.decrypt(wire.get_nonce(), wire.get_payload()))
+    } else {
+        wire.take_payload()
+    };
+    if wire.has_compression() {
+        let codec = try!(wire.get_compression().parse::<Compression>());
+        codec.decompress(&payload)
     } else {
-        Ok(wire.take_payload())
+        Ok(payload)
     }
 }
 
@@ -69,13 +86,14 @@ impl Serialize for swim::Member {
     fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error>
         where S: Serializer
     {
-        let mut state = try!(serializer.serialize_struct("member", 6));
+        let mut state = try!(serializer.serialize_struct("member", 7));
         try!(serializer.serialize_struct_elt(&mut state, "id", self.get_id()));
         try!(serializer.serialize_struct_elt(&mut state, "incarnation", self.get_incarnation()));
         try!(serializer.serialize_struct_elt(&mut state, "address", self.get_address()));
         try!(serializer.serialize_struct_elt(&mut state, "swim_port", self.get_swim_port()));
         try!(serializer.serialize_struct_elt(&mut state, "gossip_port", self.get_gossip_port()));
         try!(serializer.serialize_struct_elt(&mut state, "persistent", self.get_persistent()));
+        try!(serializer.serialize_struct_elt(&mut state, "compression", self.get_compression()));
         serializer.serialize_struct_end(state)
     }
 }
@@ -95,7 +113,7 @@ impl Serialize for swim::Rumor {
     fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error>
         where S: Serializer
     {
-        let mut state = try!(serializer.serialize_struct("rumor", 8));
+        let mut state = try!(serializer.serialize_struct("rumor", 10));
         try!(serializer.serialize_struct_elt(&mut state, "type", self.get_field_type()));
         try!(serializer.serialize_struct_elt(&mut state, "tag", self.get_tag()));
         try!(serializer.serialize_struct_elt(&mut state, "from_id", self.get_from_id()));
@@ -118,6 +136,36 @@ impl Serialize for swim::Rumor {
         if self.has_election() {
             try!(serializer.serialize_struct_elt(&mut state, "election", self.get_election()));
         }
+        if self.has_service_scale() {
+            try!(serializer.serialize_struct_elt(&mut state,
+                                                 "service_scale",
+                                                 self.get_service_scale()));
+        }
+        if self.has_active_color() {
+            try!(serializer.serialize_struct_elt(&mut state,
+                                                 "active_color",
+                                                 self.get_active_color()));
+        }
+        if self.has_ring_command() {
+            try!(serializer.serialize_struct_elt(&mut state,
+                                                 "ring_command",
+                                                 self.get_ring_command()));
+        }
+        if self.has_ring_command_ack() {
+            try!(serializer.serialize_struct_elt(&mut state,
+                                                 "ring_command_ack",
+                                                 self.get_ring_command_ack()));
+        }
+        if self.has_service_restart() {
+            try!(serializer.serialize_struct_elt(&mut state,
+                                                 "service_restart",
+                                                 self.get_service_restart()));
+        }
+        if self.has_update_lock() {
+            try!(serializer.serialize_struct_elt(&mut state,
+                                                 "update_lock",
+                                                 self.get_update_lock()));
+        }
         serializer.serialize_struct_end(state)
     }
 }
@@ -141,6 +189,8 @@ impl Serialize for swim::Service {
         try!(serializer.serialize_struct_elt(&mut state, "cfg", &cfg));
         try!(serializer.serialize_struct_elt(&mut state, "sys", &sys));
         try!(serializer.serialize_struct_elt(&mut state, "initialized", self.get_initialized()));
+        try!(serializer.serialize_struct_elt(&mut state, "quarantined", self.get_quarantined()));
+        try!(serializer.serialize_struct_elt(&mut state, "departed_ttl", self.get_departed_ttl()));
         serializer.serialize_struct_end(state)
     }
 }
@@ -149,12 +199,16 @@ impl Serialize for swim::ServiceConfig {
     fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error>
         where S: Serializer
     {
-        let mut state = try!(serializer.serialize_struct("service_config", 4));
+        let mut state = try!(serializer.serialize_struct("service_config", 8));
         try!(serializer.serialize_struct_elt(&mut state,
                                              "service_group",
                                              self.get_service_group()));
         try!(serializer.serialize_struct_elt(&mut state, "incarnation", self.get_incarnation()));
         try!(serializer.serialize_struct_elt(&mut state, "encrypted", self.get_encrypted()));
+        try!(serializer.serialize_struct_elt(&mut state, "patch", self.get_patch()));
+        try!(serializer.serialize_struct_elt(&mut state, "member_ids", self.get_member_ids()));
+        try!(serializer.serialize_struct_elt(&mut state, "labels", self.get_labels()));
+        try!(serializer.serialize_struct_elt(&mut state, "percent", self.get_percent()));
         match str::from_utf8(self.get_config()) {
             Ok(c) => try!(serializer.serialize_struct_elt(&mut state, "config", c)),
             Err(_) => {
@@ -165,6 +219,92 @@ impl Serialize for swim::ServiceConfig {
     }
 }
 
+impl Serialize for swim::ServiceScale {
+    fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error>
+        where S: Serializer
+    {
+        let mut state = try!(serializer.serialize_struct("service_scale", 3));
+        try!(serializer.serialize_struct_elt(&mut state,
+                                             "service_group",
+                                             self.get_service_group()));
+        try!(serializer.serialize_struct_elt(&mut state, "incarnation", self.get_incarnation()));
+        try!(serializer.serialize_struct_elt(&mut state,
+                                             "desired_count",
+                                             self.get_desired_count()));
+        serializer.serialize_struct_end(state)
+    }
+}
+
+impl Serialize for swim::ActiveColor {
+    fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error>
+        where S: Serializer
+    {
+        let mut state = try!(serializer.serialize_struct("active_color", 3));
+        try!(serializer.serialize_struct_elt(&mut state, "service", self.get_service()));
+        try!(serializer.serialize_struct_elt(&mut state, "incarnation", self.get_incarnation()));
+        try!(serializer.serialize_struct_elt(&mut state, "group", self.get_group()));
+        serializer.serialize_struct_end(state)
+    }
+}
+
+impl Serialize for swim::RingCommand {
+    fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error>
+        where S: Serializer
+    {
+        let mut state = try!(serializer.serialize_struct("ring_command", 5));
+        try!(serializer.serialize_struct_elt(&mut state, "id", self.get_id()));
+        try!(serializer.serialize_struct_elt(&mut state, "command", self.get_command()));
+        try!(serializer.serialize_struct_elt(&mut state, "args", self.get_args()));
+        try!(serializer.serialize_struct_elt(&mut state, "issued_at", self.get_issued_at()));
+        try!(serializer.serialize_struct_elt(&mut state, "signer", self.get_signer()));
+        serializer.serialize_struct_end(state)
+    }
+}
+
+impl Serialize for swim::RingCommandAck {
+    fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error>
+        where S: Serializer
+    {
+        let mut state = try!(serializer.serialize_struct("ring_command_ack", 4));
+        try!(serializer.serialize_struct_elt(&mut state, "command_id", self.get_command_id()));
+        try!(serializer.serialize_struct_elt(&mut state, "member_id", self.get_member_id()));
+        try!(serializer.serialize_struct_elt(&mut state, "success", self.get_success()));
+        try!(serializer.serialize_struct_elt(&mut state, "message", self.get_message()));
+        serializer.serialize_struct_end(state)
+    }
+}
+
+impl Serialize for swim::ServiceRestart {
+    fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error>
+        where S: Serializer
+    {
+        let mut state = try!(serializer.serialize_struct("service_restart", 4));
+        try!(serializer.serialize_struct_elt(&mut state,
+                                             "service_group",
+                                             self.get_service_group()));
+        try!(serializer.serialize_struct_elt(&mut state, "incarnation", self.get_incarnation()));
+        try!(serializer.serialize_struct_elt(&mut state, "rolling", self.get_rolling()));
+        try!(serializer.serialize_struct_elt(&mut state, "issued_at", self.get_issued_at()));
+        serializer.serialize_struct_end(state)
+    }
+}
+
+impl Serialize for swim::UpdateLock {
+    fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error>
+        where S: Serializer
+    {
+        let mut state = try!(serializer.serialize_struct("update_lock", 5));
+        try!(serializer.serialize_struct_elt(&mut state,
+                                             "service_group",
+                                             self.get_service_group()));
+        try!(serializer.serialize_struct_elt(&mut state, "member_id", self.get_member_id()));
+        try!(serializer.serialize_struct_elt(&mut state, "incarnation", self.get_incarnation()));
+        try!(serializer.serialize_struct_elt(&mut state, "held", self.get_held()));
+        try!(serializer.serialize_struct_elt(&mut state, "requested_at", self.get_requested_at()));
+        serializer.serialize_struct_end(state)
+    }
+}
+
 impl Serialize for swim::ServiceFile {
     fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error>
         where S: Serializer
@@ -30,6 +30,8 @@ pub struct Member {
     swim_port: ::std::option::Option<i32>,
     gossip_port: ::std::option::Option<i32>,
     persistent: ::std::option::Option<bool>,
+    compression: ::protobuf::RepeatedField<::std::string::String>,
+    alias: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -248,6 +250,83 @@ impl Member {
     fn mut_persistent_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
         &mut self.persistent
     }
+
+    // repeated string compression = 7;
+
+    pub fn clear_compression(&mut self) {
+        self.compression.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_compression(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.compression = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_compression(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.compression
+    }
+
+    // Take field
+    pub fn take_compression(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.compression, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_compression(&self) -> &[::std::string::String] {
+        &self.compression
+    }
+
+    fn get_compression_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.compression
+    }
+
+    fn mut_compression_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.compression
+    }
+
+    // optional string alias = 8;
+
+    pub fn clear_alias(&mut self) {
+        self.alias.clear();
+    }
+
+    pub fn has_alias(&self) -> bool {
+        self.alias.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_alias(&mut self, v: ::std::string::String) {
+        self.alias = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_alias(&mut self) -> &mut ::std::string::String {
+        if self.alias.is_none() {
+            self.alias.set_default();
+        };
+        self.alias.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_alias(&mut self) -> ::std::string::String {
+        self.alias.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_alias(&self) -> &str {
+        match self.alias.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_alias_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.alias
+    }
+
+    fn mut_alias_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.alias
+    }
 }
 
 impl ::protobuf::Message for Member {
@@ -293,6 +372,12 @@ impl ::protobuf::Message for Member {
                     let tmp = is.read_bool()?;
                     self.persistent = ::std::option::Option::Some(tmp);
                 },
+                7 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.compression)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.alias)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -323,6 +408,12 @@ impl ::protobuf::Message for Member {
         if let Some(v) = self.persistent {
             my_size += 2;
         };
+        for value in &self.compression {
+            my_size += ::protobuf::rt::string_size(7, &value);
+        };
+        if let Some(v) = self.alias.as_ref() {
+            my_size += ::protobuf::rt::string_size(8, &v);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -347,6 +438,12 @@ impl ::protobuf::Message for Member {
         if let Some(v) = self.persistent {
             os.write_bool(6, v)?;
         };
+        for v in &self.compression {
+            os.write_string(7, &v)?;
+        };
+        if let Some(v) = self.alias.as_ref() {
+            os.write_string(8, &v)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -421,6 +518,16 @@ impl ::protobuf::MessageStatic for Member {
                     Member::get_persistent_for_reflect,
                     Member::mut_persistent_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "compression",
+                    Member::get_compression_for_reflect,
+                    Member::mut_compression_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "alias",
+                    Member::get_alias_for_reflect,
+                    Member::mut_alias_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Member>(
                     "Member",
                     fields,
@@ -439,6 +546,8 @@ impl ::protobuf::Clear for Member {
         self.clear_swim_port();
         self.clear_gossip_port();
         self.clear_persistent();
+        self.clear_compression();
+        self.clear_alias();
         self.unknown_fields.clear();
     }
 }
@@ -1976,6 +2085,10 @@ pub struct Service {
     pkg: ::protobuf::SingularField<::std::string::String>,
     cfg: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     sys: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    last_exit_code: ::std::option::Option<i32>,
+    restart_incarnation: ::std::option::Option<u64>,
+    quarantined: ::protobuf::RepeatedField<::std::string::String>,
+    departed_ttl: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -2272,6 +2385,120 @@ impl Service {
     fn mut_sys_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
         &mut self.sys
     }
+
+    // optional int32 last_exit_code = 13;
+
+    pub fn clear_last_exit_code(&mut self) {
+        self.last_exit_code = ::std::option::Option::None;
+    }
+
+    pub fn has_last_exit_code(&self) -> bool {
+        self.last_exit_code.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_exit_code(&mut self, v: i32) {
+        self.last_exit_code = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_last_exit_code(&self) -> i32 {
+        self.last_exit_code.unwrap_or(0)
+    }
+
+    fn get_last_exit_code_for_reflect(&self) -> &::std::option::Option<i32> {
+        &self.last_exit_code
+    }
+
+    fn mut_last_exit_code_for_reflect(&mut self) -> &mut ::std::option::Option<i32> {
+        &mut self.last_exit_code
+    }
+
+    // optional uint64 restart_incarnation = 14;
+
+    pub fn clear_restart_incarnation(&mut self) {
+        self.restart_incarnation = ::std::option::Option::None;
+    }
+
+    pub fn has_restart_incarnation(&self) -> bool {
+        self.restart_incarnation.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_restart_incarnation(&mut self, v: u64) {
+        self.restart_incarnation = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_restart_incarnation(&self) -> u64 {
+        self.restart_incarnation.unwrap_or(0)
+    }
+
+    fn get_restart_incarnation_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.restart_incarnation
+    }
+
+    fn mut_restart_incarnation_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.restart_incarnation
+    }
+
+    // repeated string quarantined = 15;
+
+    pub fn clear_quarantined(&mut self) {
+        self.quarantined.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_quarantined(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.quarantined = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_quarantined(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.quarantined
+    }
+
+    // Take field
+    pub fn take_quarantined(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.quarantined, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_quarantined(&self) -> &[::std::string::String] {
+        &self.quarantined
+    }
+
+    fn get_quarantined_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.quarantined
+    }
+
+    fn mut_quarantined_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.quarantined
+    }
+
+    // optional uint64 departed_ttl = 16;
+
+    pub fn clear_departed_ttl(&mut self) {
+        self.departed_ttl = ::std::option::Option::None;
+    }
+
+    pub fn has_departed_ttl(&self) -> bool {
+        self.departed_ttl.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_departed_ttl(&mut self, v: u64) {
+        self.departed_ttl = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_departed_ttl(&self) -> u64 {
+        self.departed_ttl.unwrap_or(0)
+    }
+
+    fn get_departed_ttl_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.departed_ttl
+    }
+
+    fn mut_departed_ttl_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.departed_ttl
+    }
 }
 
 impl ::protobuf::Message for Service {
@@ -2312,6 +2539,30 @@ impl ::protobuf::Message for Service {
                 12 => {
                     ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.sys)?;
                 },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_int32()?;
+                    self.last_exit_code = ::std::option::Option::Some(tmp);
+                },
+                14 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.restart_incarnation = ::std::option::Option::Some(tmp);
+                },
+                15 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.quarantined)?;
+                },
+                16 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.departed_ttl = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2345,6 +2596,18 @@ impl ::protobuf::Message for Service {
         if let Some(v) = self.sys.as_ref() {
             my_size += ::protobuf::rt::bytes_size(12, &v);
         };
+        if let Some(v) = self.last_exit_code {
+            my_size += ::protobuf::rt::value_size(13, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if let Some(v) = self.restart_incarnation {
+            my_size += ::protobuf::rt::value_size(14, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.quarantined {
+            my_size += ::protobuf::rt::string_size(15, &value);
+        };
+        if let Some(v) = self.departed_ttl {
+            my_size += ::protobuf::rt::value_size(16, v, ::protobuf::wire_format::WireTypeVarint);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2372,6 +2635,18 @@ impl ::protobuf::Message for Service {
         if let Some(v) = self.sys.as_ref() {
             os.write_bytes(12, &v)?;
         };
+        if let Some(v) = self.last_exit_code {
+            os.write_int32(13, v)?;
+        };
+        if let Some(v) = self.restart_incarnation {
+            os.write_uint64(14, v)?;
+        };
+        for v in &self.quarantined {
+            os.write_string(15, &v)?;
+        };
+        if let Some(v) = self.departed_ttl {
+            os.write_uint64(16, v)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2451,6 +2726,26 @@ impl ::protobuf::MessageStatic for Service {
                     Service::get_sys_for_reflect,
                     Service::mut_sys_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "last_exit_code",
+                    Service::get_last_exit_code_for_reflect,
+                    Service::mut_last_exit_code_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "restart_incarnation",
+                    Service::get_restart_incarnation_for_reflect,
+                    Service::mut_restart_incarnation_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "quarantined",
+                    Service::get_quarantined_for_reflect,
+                    Service::mut_quarantined_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "departed_ttl",
+                    Service::get_departed_ttl_for_reflect,
+                    Service::mut_departed_ttl_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Service>(
                     "Service",
                     fields,
@@ -2470,6 +2765,10 @@ impl ::protobuf::Clear for Service {
         self.clear_pkg();
         self.clear_cfg();
         self.clear_sys();
+        self.clear_last_exit_code();
+        self.clear_restart_incarnation();
+        self.clear_quarantined();
+        self.clear_departed_ttl();
         self.unknown_fields.clear();
     }
 }
@@ -2493,6 +2792,10 @@ pub struct ServiceConfig {
     incarnation: ::std::option::Option<u64>,
     encrypted: ::std::option::Option<bool>,
     config: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    patch: ::std::option::Option<bool>,
+    member_ids: ::protobuf::RepeatedField<::std::string::String>,
+    labels: ::protobuf::RepeatedField<::std::string::String>,
+    percent: ::std::option::Option<u32>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -2657,6 +2960,126 @@ impl ServiceConfig {
     fn mut_config_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
         &mut self.config
     }
+
+    // optional bool patch = 5;
+
+    pub fn clear_patch(&mut self) {
+        self.patch = ::std::option::Option::None;
+    }
+
+    pub fn has_patch(&self) -> bool {
+        self.patch.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_patch(&mut self, v: bool) {
+        self.patch = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_patch(&self) -> bool {
+        self.patch.unwrap_or(false)
+    }
+
+    fn get_patch_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.patch
+    }
+
+    fn mut_patch_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.patch
+    }
+
+    // repeated string member_ids = 6;
+
+    pub fn clear_member_ids(&mut self) {
+        self.member_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_member_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.member_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_member_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.member_ids
+    }
+
+    // Take field
+    pub fn take_member_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.member_ids, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_member_ids(&self) -> &[::std::string::String] {
+        &self.member_ids
+    }
+
+    fn get_member_ids_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.member_ids
+    }
+
+    fn mut_member_ids_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.member_ids
+    }
+
+    // repeated string labels = 7;
+
+    pub fn clear_labels(&mut self) {
+        self.labels.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_labels(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.labels = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_labels(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.labels
+    }
+
+    // Take field
+    pub fn take_labels(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.labels, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_labels(&self) -> &[::std::string::String] {
+        &self.labels
+    }
+
+    fn get_labels_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.labels
+    }
+
+    fn mut_labels_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.labels
+    }
+
+    // optional uint32 percent = 8;
+
+    pub fn clear_percent(&mut self) {
+        self.percent = ::std::option::Option::None;
+    }
+
+    pub fn has_percent(&self) -> bool {
+        self.percent.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_percent(&mut self, v: u32) {
+        self.percent = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_percent(&self) -> u32 {
+        self.percent.unwrap_or(0)
+    }
+
+    fn get_percent_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.percent
+    }
+
+    fn mut_percent_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.percent
+    }
 }
 
 impl ::protobuf::Message for ServiceConfig {
@@ -2688,6 +3111,26 @@ impl ::protobuf::Message for ServiceConfig {
                 4 => {
                     ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.config)?;
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_bool()?;
+                    self.patch = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.member_ids)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.labels)?;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint32()?;
+                    self.percent = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2712,6 +3155,18 @@ impl ::protobuf::Message for ServiceConfig {
         if let Some(v) = self.config.as_ref() {
             my_size += ::protobuf::rt::bytes_size(4, &v);
         };
+        if let Some(v) = self.patch {
+            my_size += 2;
+        };
+        for value in &self.member_ids {
+            my_size += ::protobuf::rt::string_size(6, &value);
+        };
+        for value in &self.labels {
+            my_size += ::protobuf::rt::string_size(7, &value);
+        };
+        if let Some(v) = self.percent {
+            my_size += ::protobuf::rt::value_size(8, v, ::protobuf::wire_format::WireTypeVarint);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2730,6 +3185,18 @@ impl ::protobuf::Message for ServiceConfig {
         if let Some(v) = self.config.as_ref() {
             os.write_bytes(4, &v)?;
         };
+        if let Some(v) = self.patch {
+            os.write_bool(5, v)?;
+        };
+        for v in &self.member_ids {
+            os.write_string(6, &v)?;
+        };
+        for v in &self.labels {
+            os.write_string(7, &v)?;
+        };
+        if let Some(v) = self.percent {
+            os.write_uint32(8, v)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2794,6 +3261,26 @@ impl ::protobuf::MessageStatic for ServiceConfig {
                     ServiceConfig::get_config_for_reflect,
                     ServiceConfig::mut_config_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "patch",
+                    ServiceConfig::get_patch_for_reflect,
+                    ServiceConfig::mut_patch_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "member_ids",
+                    ServiceConfig::get_member_ids_for_reflect,
+                    ServiceConfig::mut_member_ids_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "labels",
+                    ServiceConfig::get_labels_for_reflect,
+                    ServiceConfig::mut_labels_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "percent",
+                    ServiceConfig::get_percent_for_reflect,
+                    ServiceConfig::mut_percent_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<ServiceConfig>(
                     "ServiceConfig",
                     fields,
@@ -2810,6 +3297,10 @@ impl ::protobuf::Clear for ServiceConfig {
         self.clear_incarnation();
         self.clear_encrypted();
         self.clear_config();
+        self.clear_patch();
+        self.clear_member_ids();
+        self.clear_labels();
+        self.clear_percent();
         self.unknown_fields.clear();
     }
 }
@@ -3227,255 +3718,135 @@ impl ::protobuf::reflect::ProtobufValue for ServiceFile {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Swim {
+pub struct ServiceScale {
     // message fields
-    field_type: ::std::option::Option<Swim_Type>,
-    membership: ::protobuf::RepeatedField<Membership>,
-    // message oneof groups
-    payload: ::std::option::Option<Swim_oneof_payload>,
+    service_group: ::protobuf::SingularField<::std::string::String>,
+    incarnation: ::std::option::Option<u64>,
+    desired_count: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Swim {}
+unsafe impl ::std::marker::Sync for ServiceScale {}
 
-#[derive(Clone,PartialEq)]
-pub enum Swim_oneof_payload {
-    ping(Ping),
-    ack(Ack),
-    pingreq(PingReq),
-}
-
-impl Swim {
-    pub fn new() -> Swim {
+impl ServiceScale {
+    pub fn new() -> ServiceScale {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Swim {
-        static mut instance: ::protobuf::lazy::Lazy<Swim> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static ServiceScale {
+        static mut instance: ::protobuf::lazy::Lazy<ServiceScale> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Swim,
+            ptr: 0 as *const ServiceScale,
         };
         unsafe {
-            instance.get(Swim::new)
+            instance.get(ServiceScale::new)
         }
     }
 
-    // required .Swim.Type type = 1;
-
-    pub fn clear_field_type(&mut self) {
-        self.field_type = ::std::option::Option::None;
-    }
-
-    pub fn has_field_type(&self) -> bool {
-        self.field_type.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_field_type(&mut self, v: Swim_Type) {
-        self.field_type = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_field_type(&self) -> Swim_Type {
-        self.field_type.unwrap_or(Swim_Type::PING)
-    }
-
-    fn get_field_type_for_reflect(&self) -> &::std::option::Option<Swim_Type> {
-        &self.field_type
-    }
-
-    fn mut_field_type_for_reflect(&mut self) -> &mut ::std::option::Option<Swim_Type> {
-        &mut self.field_type
-    }
-
-    // optional .Ping ping = 2;
+    // optional string service_group = 1;
 
-    pub fn clear_ping(&mut self) {
-        self.payload = ::std::option::Option::None;
+    pub fn clear_service_group(&mut self) {
+        self.service_group.clear();
     }
 
-    pub fn has_ping(&self) -> bool {
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::ping(..)) => true,
-            _ => false,
-        }
+    pub fn has_service_group(&self) -> bool {
+        self.service_group.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_ping(&mut self, v: Ping) {
-        self.payload = ::std::option::Option::Some(Swim_oneof_payload::ping(v))
+    pub fn set_service_group(&mut self, v: ::std::string::String) {
+        self.service_group = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
-    pub fn mut_ping(&mut self) -> &mut Ping {
-        if let ::std::option::Option::Some(Swim_oneof_payload::ping(_)) = self.payload {
-        } else {
-            self.payload = ::std::option::Option::Some(Swim_oneof_payload::ping(Ping::new()));
-        }
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::ping(ref mut v)) => v,
-            _ => panic!(),
-        }
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_service_group(&mut self) -> &mut ::std::string::String {
+        if self.service_group.is_none() {
+            self.service_group.set_default();
+        };
+        self.service_group.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_ping(&mut self) -> Ping {
-        if self.has_ping() {
-            match self.payload.take() {
-                ::std::option::Option::Some(Swim_oneof_payload::ping(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Ping::new()
-        }
-    }
-
-    pub fn get_ping(&self) -> &Ping {
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::ping(ref v)) => v,
-            _ => Ping::default_instance(),
-        }
-    }
-
-    // optional .Ack ack = 3;
-
-    pub fn clear_ack(&mut self) {
-        self.payload = ::std::option::Option::None;
-    }
-
-    pub fn has_ack(&self) -> bool {
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::ack(..)) => true,
-            _ => false,
-        }
-    }
-
-    // Param is passed by value, moved
-    pub fn set_ack(&mut self, v: Ack) {
-        self.payload = ::std::option::Option::Some(Swim_oneof_payload::ack(v))
+    pub fn take_service_group(&mut self) -> ::std::string::String {
+        self.service_group.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_ack(&mut self) -> &mut Ack {
-        if let ::std::option::Option::Some(Swim_oneof_payload::ack(_)) = self.payload {
-        } else {
-            self.payload = ::std::option::Option::Some(Swim_oneof_payload::ack(Ack::new()));
-        }
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::ack(ref mut v)) => v,
-            _ => panic!(),
+    pub fn get_service_group(&self) -> &str {
+        match self.service_group.as_ref() {
+            Some(v) => &v,
+            None => "",
         }
     }
 
-    // Take field
-    pub fn take_ack(&mut self) -> Ack {
-        if self.has_ack() {
-            match self.payload.take() {
-                ::std::option::Option::Some(Swim_oneof_payload::ack(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Ack::new()
-        }
+    fn get_service_group_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.service_group
     }
 
-    pub fn get_ack(&self) -> &Ack {
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::ack(ref v)) => v,
-            _ => Ack::default_instance(),
-        }
+    fn mut_service_group_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.service_group
     }
 
-    // optional .PingReq pingreq = 4;
+    // optional uint64 incarnation = 2;
 
-    pub fn clear_pingreq(&mut self) {
-        self.payload = ::std::option::Option::None;
+    pub fn clear_incarnation(&mut self) {
+        self.incarnation = ::std::option::Option::None;
     }
 
-    pub fn has_pingreq(&self) -> bool {
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::pingreq(..)) => true,
-            _ => false,
-        }
+    pub fn has_incarnation(&self) -> bool {
+        self.incarnation.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_pingreq(&mut self, v: PingReq) {
-        self.payload = ::std::option::Option::Some(Swim_oneof_payload::pingreq(v))
+    pub fn set_incarnation(&mut self, v: u64) {
+        self.incarnation = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_pingreq(&mut self) -> &mut PingReq {
-        if let ::std::option::Option::Some(Swim_oneof_payload::pingreq(_)) = self.payload {
-        } else {
-            self.payload = ::std::option::Option::Some(Swim_oneof_payload::pingreq(PingReq::new()));
-        }
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::pingreq(ref mut v)) => v,
-            _ => panic!(),
-        }
+    pub fn get_incarnation(&self) -> u64 {
+        self.incarnation.unwrap_or(0)
     }
 
-    // Take field
-    pub fn take_pingreq(&mut self) -> PingReq {
-        if self.has_pingreq() {
-            match self.payload.take() {
-                ::std::option::Option::Some(Swim_oneof_payload::pingreq(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            PingReq::new()
-        }
+    fn get_incarnation_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.incarnation
     }
 
-    pub fn get_pingreq(&self) -> &PingReq {
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::pingreq(ref v)) => v,
-            _ => PingReq::default_instance(),
-        }
+    fn mut_incarnation_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.incarnation
     }
 
-    // repeated .Membership membership = 5;
-
-    pub fn clear_membership(&mut self) {
-        self.membership.clear();
-    }
+    // optional uint64 desired_count = 3;
 
-    // Param is passed by value, moved
-    pub fn set_membership(&mut self, v: ::protobuf::RepeatedField<Membership>) {
-        self.membership = v;
+    pub fn clear_desired_count(&mut self) {
+        self.desired_count = ::std::option::Option::None;
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_membership(&mut self) -> &mut ::protobuf::RepeatedField<Membership> {
-        &mut self.membership
+    pub fn has_desired_count(&self) -> bool {
+        self.desired_count.is_some()
     }
 
-    // Take field
-    pub fn take_membership(&mut self) -> ::protobuf::RepeatedField<Membership> {
-        ::std::mem::replace(&mut self.membership, ::protobuf::RepeatedField::new())
+    // Param is passed by value, moved
+    pub fn set_desired_count(&mut self, v: u64) {
+        self.desired_count = ::std::option::Option::Some(v);
     }
 
-    pub fn get_membership(&self) -> &[Membership] {
-        &self.membership
+    pub fn get_desired_count(&self) -> u64 {
+        self.desired_count.unwrap_or(0)
     }
 
-    fn get_membership_for_reflect(&self) -> &::protobuf::RepeatedField<Membership> {
-        &self.membership
+    fn get_desired_count_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.desired_count
     }
 
-    fn mut_membership_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Membership> {
-        &mut self.membership
+    fn mut_desired_count_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.desired_count
     }
 }
 
-impl ::protobuf::Message for Swim {
+impl ::protobuf::Message for ServiceScale {
     fn is_initialized(&self) -> bool {
-        if self.field_type.is_none() {
-            return false;
-        };
         true
     }
 
@@ -3484,32 +3855,21 @@ impl ::protobuf::Message for Swim {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = is.read_enum()?;
-                    self.field_type = ::std::option::Option::Some(tmp);
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.service_group)?;
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    self.payload = ::std::option::Option::Some(Swim_oneof_payload::ping(is.read_message()?));
+                    let tmp = is.read_uint64()?;
+                    self.incarnation = ::std::option::Option::Some(tmp);
                 },
                 3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    self.payload = ::std::option::Option::Some(Swim_oneof_payload::ack(is.read_message()?));
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    self.payload = ::std::option::Option::Some(Swim_oneof_payload::pingreq(is.read_message()?));
-                },
-                5 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.membership)?;
+                    let tmp = is.read_uint64()?;
+                    self.desired_count = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -3523,28 +3883,14 @@ impl ::protobuf::Message for Swim {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.field_type {
-            my_size += ::protobuf::rt::enum_size(1, v);
+        if let Some(v) = self.service_group.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
         };
-        for value in &self.membership {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        if let Some(v) = self.incarnation {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
         };
-        if let ::std::option::Option::Some(ref v) = self.payload {
-            match v {
-                &Swim_oneof_payload::ping(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &Swim_oneof_payload::ack(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &Swim_oneof_payload::pingreq(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-            };
+        if let Some(v) = self.desired_count {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -3552,32 +3898,14 @@ impl ::protobuf::Message for Swim {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.field_type {
-            os.write_enum(1, v.value())?;
+        if let Some(v) = self.service_group.as_ref() {
+            os.write_string(1, &v)?;
         };
-        for v in &self.membership {
-            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if let Some(v) = self.incarnation {
+            os.write_uint64(2, v)?;
         };
-        if let ::std::option::Option::Some(ref v) = self.payload {
-            match v {
-                &Swim_oneof_payload::ping(ref v) => {
-                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &Swim_oneof_payload::ack(ref v) => {
-                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &Swim_oneof_payload::pingreq(ref v) => {
-                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-            };
+        if let Some(v) = self.desired_count {
+            os.write_uint64(3, v)?;
         };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -3610,12 +3938,12 @@ impl ::protobuf::Message for Swim {
     }
 }
 
-impl ::protobuf::MessageStatic for Swim {
-    fn new() -> Swim {
-        Swim::new()
+impl ::protobuf::MessageStatic for ServiceScale {
+    fn new() -> ServiceScale {
+        ServiceScale::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Swim>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<ServiceScale>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -3623,33 +3951,23 @@ impl ::protobuf::MessageStatic for Swim {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Swim_Type>>(
-                    "type",
-                    Swim::get_field_type_for_reflect,
-                    Swim::mut_field_type_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Ping>(
-                    "ping",
-                    Swim::has_ping,
-                    Swim::get_ping,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Ack>(
-                    "ack",
-                    Swim::has_ack,
-                    Swim::get_ack,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "service_group",
+                    ServiceScale::get_service_group_for_reflect,
+                    ServiceScale::mut_service_group_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, PingReq>(
-                    "pingreq",
-                    Swim::has_pingreq,
-                    Swim::get_pingreq,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "incarnation",
+                    ServiceScale::get_incarnation_for_reflect,
+                    ServiceScale::mut_incarnation_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Membership>>(
-                    "membership",
-                    Swim::get_membership_for_reflect,
-                    Swim::mut_membership_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "desired_count",
+                    ServiceScale::get_desired_count_for_reflect,
+                    ServiceScale::mut_desired_count_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Swim>(
-                    "Swim",
+                ::protobuf::reflect::MessageDescriptor::new::<ServiceScale>(
+                    "ServiceScale",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3658,467 +3976,3042 @@ impl ::protobuf::MessageStatic for Swim {
     }
 }
 
-impl ::protobuf::Clear for Swim {
+impl ::protobuf::Clear for ServiceScale {
     fn clear(&mut self) {
-        self.clear_field_type();
-        self.clear_ping();
-        self.clear_ack();
-        self.clear_pingreq();
-        self.clear_membership();
+        self.clear_service_group();
+        self.clear_incarnation();
+        self.clear_desired_count();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Swim {
+impl ::std::fmt::Debug for ServiceScale {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Swim {
+impl ::protobuf::reflect::ProtobufValue for ServiceScale {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
-#[derive(Clone,PartialEq,Eq,Debug,Hash)]
-pub enum Swim_Type {
-    PING = 1,
-    ACK = 2,
-    PINGREQ = 3,
+#[derive(PartialEq,Clone,Default)]
+pub struct ActiveColor {
+    // message fields
+    service: ::protobuf::SingularField<::std::string::String>,
+    incarnation: ::std::option::Option<u64>,
+    group: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
 }
 
-impl ::protobuf::ProtobufEnum for Swim_Type {
-    fn value(&self) -> i32 {
-        *self as i32
-    }
-
-    fn from_i32(value: i32) -> ::std::option::Option<Swim_Type> {
-        match value {
-            1 => ::std::option::Option::Some(Swim_Type::PING),
-            2 => ::std::option::Option::Some(Swim_Type::ACK),
-            3 => ::std::option::Option::Some(Swim_Type::PINGREQ),
-            _ => ::std::option::Option::None
-        }
-    }
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ActiveColor {}
 
-    fn values() -> &'static [Self] {
-        static values: &'static [Swim_Type] = &[
-            Swim_Type::PING,
-            Swim_Type::ACK,
-            Swim_Type::PINGREQ,
-        ];
-        values
+impl ActiveColor {
+    pub fn new() -> ActiveColor {
+        ::std::default::Default::default()
     }
 
-    fn enum_descriptor_static(_: Option<Swim_Type>) -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static ActiveColor {
+        static mut instance: ::protobuf::lazy::Lazy<ActiveColor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+            ptr: 0 as *const ActiveColor,
         };
         unsafe {
-            descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("Swim_Type", file_descriptor_proto())
-            })
+            instance.get(ActiveColor::new)
         }
     }
-}
 
-impl ::std::marker::Copy for Swim_Type {
-}
+    // optional string service = 1;
 
-impl ::protobuf::reflect::ProtobufValue for Swim_Type {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    pub fn clear_service(&mut self) {
+        self.service.clear();
     }
-}
 
-#[derive(PartialEq,Clone,Default)]
-pub struct Rumor {
-    // message fields
-    field_type: ::std::option::Option<Rumor_Type>,
-    tag: ::protobuf::RepeatedField<::std::string::String>,
-    from_id: ::protobuf::SingularField<::std::string::String>,
-    // message oneof groups
-    payload: ::std::option::Option<Rumor_oneof_payload>,
-    // special fields
-    unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::protobuf::CachedSize,
-}
+    pub fn has_service(&self) -> bool {
+        self.service.is_some()
+    }
 
-// see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Rumor {}
+    // Param is passed by value, moved
+    pub fn set_service(&mut self, v: ::std::string::String) {
+        self.service = ::protobuf::SingularField::some(v);
+    }
 
-#[derive(Clone,PartialEq)]
-pub enum Rumor_oneof_payload {
-    member(Membership),
-    service(Service),
-    service_config(ServiceConfig),
-    service_file(ServiceFile),
-    election(Election),
-}
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_service(&mut self) -> &mut ::std::string::String {
+        if self.service.is_none() {
+            self.service.set_default();
+        };
+        self.service.as_mut().unwrap()
+    }
 
-impl Rumor {
-    pub fn new() -> Rumor {
-        ::std::default::Default::default()
+    // Take field
+    pub fn take_service(&mut self) -> ::std::string::String {
+        self.service.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn default_instance() -> &'static Rumor {
-        static mut instance: ::protobuf::lazy::Lazy<Rumor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Rumor,
-        };
-        unsafe {
-            instance.get(Rumor::new)
+    pub fn get_service(&self) -> &str {
+        match self.service.as_ref() {
+            Some(v) => &v,
+            None => "",
         }
     }
 
-    // required .Rumor.Type type = 1;
+    fn get_service_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.service
+    }
 
-    pub fn clear_field_type(&mut self) {
-        self.field_type = ::std::option::Option::None;
+    fn mut_service_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.service
     }
 
-    pub fn has_field_type(&self) -> bool {
-        self.field_type.is_some()
+    // optional uint64 incarnation = 2;
+
+    pub fn clear_incarnation(&mut self) {
+        self.incarnation = ::std::option::Option::None;
+    }
+
+    pub fn has_incarnation(&self) -> bool {
+        self.incarnation.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_field_type(&mut self, v: Rumor_Type) {
-        self.field_type = ::std::option::Option::Some(v);
+    pub fn set_incarnation(&mut self, v: u64) {
+        self.incarnation = ::std::option::Option::Some(v);
     }
 
-    pub fn get_field_type(&self) -> Rumor_Type {
-        self.field_type.unwrap_or(Rumor_Type::Member)
+    pub fn get_incarnation(&self) -> u64 {
+        self.incarnation.unwrap_or(0)
     }
 
-    fn get_field_type_for_reflect(&self) -> &::std::option::Option<Rumor_Type> {
-        &self.field_type
+    fn get_incarnation_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.incarnation
     }
 
-    fn mut_field_type_for_reflect(&mut self) -> &mut ::std::option::Option<Rumor_Type> {
-        &mut self.field_type
+    fn mut_incarnation_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.incarnation
     }
 
-    // repeated string tag = 2;
+    // optional string group = 3;
 
-    pub fn clear_tag(&mut self) {
-        self.tag.clear();
+    pub fn clear_group(&mut self) {
+        self.group.clear();
+    }
+
+    pub fn has_group(&self) -> bool {
+        self.group.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_tag(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.tag = v;
+    pub fn set_group(&mut self, v: ::std::string::String) {
+        self.group = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
-    pub fn mut_tag(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.tag
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_group(&mut self) -> &mut ::std::string::String {
+        if self.group.is_none() {
+            self.group.set_default();
+        };
+        self.group.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_group(&mut self) -> ::std::string::String {
+        self.group.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_group(&self) -> &str {
+        match self.group.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_group_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.group
+    }
+
+    fn mut_group_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.group
+    }
+}
+
+impl ::protobuf::Message for ActiveColor {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.service)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.incarnation = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.group)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.service.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        };
+        if let Some(v) = self.incarnation {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if let Some(v) = self.group.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.service.as_ref() {
+            os.write_string(1, &v)?;
+        };
+        if let Some(v) = self.incarnation {
+            os.write_uint64(2, v)?;
+        };
+        if let Some(v) = self.group.as_ref() {
+            os.write_string(3, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ActiveColor {
+    fn new() -> ActiveColor {
+        ActiveColor::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ActiveColor>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "service",
+                    ActiveColor::get_service_for_reflect,
+                    ActiveColor::mut_service_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "incarnation",
+                    ActiveColor::get_incarnation_for_reflect,
+                    ActiveColor::mut_incarnation_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "group",
+                    ActiveColor::get_group_for_reflect,
+                    ActiveColor::mut_group_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ActiveColor>(
+                    "ActiveColor",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ActiveColor {
+    fn clear(&mut self) {
+        self.clear_service();
+        self.clear_incarnation();
+        self.clear_group();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ActiveColor {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ActiveColor {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RingCommand {
+    // message fields
+    id: ::protobuf::SingularField<::std::string::String>,
+    command: ::protobuf::SingularField<::std::string::String>,
+    args: ::protobuf::RepeatedField<::std::string::String>,
+    issued_at: ::std::option::Option<i64>,
+    signer: ::protobuf::SingularField<::std::string::String>,
+    signature: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for RingCommand {}
+
+impl RingCommand {
+    pub fn new() -> RingCommand {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static RingCommand {
+        static mut instance: ::protobuf::lazy::Lazy<RingCommand> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RingCommand,
+        };
+        unsafe {
+            instance.get(RingCommand::new)
+        }
+    }
+
+    // optional string id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        if self.id.is_none() {
+            self.id.set_default();
+        };
+        self.id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        self.id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        match self.id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_id_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.id
+    }
+
+    fn mut_id_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.id
+    }
+
+    // optional string command = 2;
+
+    pub fn clear_command(&mut self) {
+        self.command.clear();
+    }
+
+    pub fn has_command(&self) -> bool {
+        self.command.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_command(&mut self, v: ::std::string::String) {
+        self.command = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_command(&mut self) -> &mut ::std::string::String {
+        if self.command.is_none() {
+            self.command.set_default();
+        };
+        self.command.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_command(&mut self) -> ::std::string::String {
+        self.command.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_command(&self) -> &str {
+        match self.command.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_command_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.command
+    }
+
+    fn mut_command_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.command
+    }
+
+    // repeated string args = 3;
+
+    pub fn clear_args(&mut self) {
+        self.args.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_args(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.args = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_args(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.args
+    }
+
+    // Take field
+    pub fn take_args(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.args, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_args(&self) -> &[::std::string::String] {
+        &self.args
+    }
+
+    fn get_args_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.args
+    }
+
+    fn mut_args_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.args
+    }
+
+    // optional int64 issued_at = 4;
+
+    pub fn clear_issued_at(&mut self) {
+        self.issued_at = ::std::option::Option::None;
+    }
+
+    pub fn has_issued_at(&self) -> bool {
+        self.issued_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_issued_at(&mut self, v: i64) {
+        self.issued_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_issued_at(&self) -> i64 {
+        self.issued_at.unwrap_or(0)
+    }
+
+    fn get_issued_at_for_reflect(&self) -> &::std::option::Option<i64> {
+        &self.issued_at
+    }
+
+    fn mut_issued_at_for_reflect(&mut self) -> &mut ::std::option::Option<i64> {
+        &mut self.issued_at
+    }
+
+    // optional string signer = 5;
+
+    pub fn clear_signer(&mut self) {
+        self.signer.clear();
+    }
+
+    pub fn has_signer(&self) -> bool {
+        self.signer.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signer(&mut self, v: ::std::string::String) {
+        self.signer = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signer(&mut self) -> &mut ::std::string::String {
+        if self.signer.is_none() {
+            self.signer.set_default();
+        };
+        self.signer.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_signer(&mut self) -> ::std::string::String {
+        self.signer.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_signer(&self) -> &str {
+        match self.signer.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_signer_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.signer
+    }
+
+    fn mut_signer_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.signer
+    }
+
+    // optional bytes signature = 6;
+
+    pub fn clear_signature(&mut self) {
+        self.signature.clear();
+    }
+
+    pub fn has_signature(&self) -> bool {
+        self.signature.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature(&mut self, v: ::std::vec::Vec<u8>) {
+        self.signature = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signature(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.signature.is_none() {
+            self.signature.set_default();
+        };
+        self.signature.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_signature(&mut self) -> ::std::vec::Vec<u8> {
+        self.signature.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_signature(&self) -> &[u8] {
+        match self.signature.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    fn get_signature_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.signature
+    }
+
+    fn mut_signature_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.signature
+    }
+}
+
+impl ::protobuf::Message for RingCommand {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.command)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.args)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_int64()?;
+                    self.issued_at = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.signer)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.signature)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.id.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        };
+        if let Some(v) = self.command.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        };
+        for value in &self.args {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        if let Some(v) = self.issued_at {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if let Some(v) = self.signer.as_ref() {
+            my_size += ::protobuf::rt::string_size(5, &v);
+        };
+        if let Some(v) = self.signature.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(6, &v);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id.as_ref() {
+            os.write_string(1, &v)?;
+        };
+        if let Some(v) = self.command.as_ref() {
+            os.write_string(2, &v)?;
+        };
+        for v in &self.args {
+            os.write_string(3, &v)?;
+        };
+        if let Some(v) = self.issued_at {
+            os.write_int64(4, v)?;
+        };
+        if let Some(v) = self.signer.as_ref() {
+            os.write_string(5, &v)?;
+        };
+        if let Some(v) = self.signature.as_ref() {
+            os.write_bytes(6, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for RingCommand {
+    fn new() -> RingCommand {
+        RingCommand::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<RingCommand>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "id",
+                    RingCommand::get_id_for_reflect,
+                    RingCommand::mut_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "command",
+                    RingCommand::get_command_for_reflect,
+                    RingCommand::mut_command_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "args",
+                    RingCommand::get_args_for_reflect,
+                    RingCommand::mut_args_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "issued_at",
+                    RingCommand::get_issued_at_for_reflect,
+                    RingCommand::mut_issued_at_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "signer",
+                    RingCommand::get_signer_for_reflect,
+                    RingCommand::mut_signer_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "signature",
+                    RingCommand::get_signature_for_reflect,
+                    RingCommand::mut_signature_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RingCommand>(
+                    "RingCommand",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for RingCommand {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_command();
+        self.clear_args();
+        self.clear_issued_at();
+        self.clear_signer();
+        self.clear_signature();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RingCommand {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RingCommand {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RingCommandAck {
+    // message fields
+    command_id: ::protobuf::SingularField<::std::string::String>,
+    member_id: ::protobuf::SingularField<::std::string::String>,
+    success: ::std::option::Option<bool>,
+    message: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for RingCommandAck {}
+
+impl RingCommandAck {
+    pub fn new() -> RingCommandAck {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static RingCommandAck {
+        static mut instance: ::protobuf::lazy::Lazy<RingCommandAck> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RingCommandAck,
+        };
+        unsafe {
+            instance.get(RingCommandAck::new)
+        }
+    }
+
+    // optional string command_id = 1;
+
+    pub fn clear_command_id(&mut self) {
+        self.command_id.clear();
+    }
+
+    pub fn has_command_id(&self) -> bool {
+        self.command_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_command_id(&mut self, v: ::std::string::String) {
+        self.command_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_command_id(&mut self) -> &mut ::std::string::String {
+        if self.command_id.is_none() {
+            self.command_id.set_default();
+        };
+        self.command_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_command_id(&mut self) -> ::std::string::String {
+        self.command_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_command_id(&self) -> &str {
+        match self.command_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_command_id_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.command_id
+    }
+
+    fn mut_command_id_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.command_id
+    }
+
+    // optional string member_id = 2;
+
+    pub fn clear_member_id(&mut self) {
+        self.member_id.clear();
+    }
+
+    pub fn has_member_id(&self) -> bool {
+        self.member_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_member_id(&mut self, v: ::std::string::String) {
+        self.member_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_member_id(&mut self) -> &mut ::std::string::String {
+        if self.member_id.is_none() {
+            self.member_id.set_default();
+        };
+        self.member_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_member_id(&mut self) -> ::std::string::String {
+        self.member_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_member_id(&self) -> &str {
+        match self.member_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_member_id_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.member_id
+    }
+
+    fn mut_member_id_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.member_id
+    }
+
+    // optional bool success = 3;
+
+    pub fn clear_success(&mut self) {
+        self.success = ::std::option::Option::None;
+    }
+
+    pub fn has_success(&self) -> bool {
+        self.success.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: bool) {
+        self.success = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_success(&self) -> bool {
+        self.success.unwrap_or(false)
+    }
+
+    fn get_success_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.success
+    }
+
+    fn mut_success_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.success
+    }
+
+    // optional string message = 4;
+
+    pub fn clear_message(&mut self) {
+        self.message.clear();
+    }
+
+    pub fn has_message(&self) -> bool {
+        self.message.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ::std::string::String) {
+        self.message = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut ::std::string::String {
+        if self.message.is_none() {
+            self.message.set_default();
+        };
+        self.message.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_message(&mut self) -> ::std::string::String {
+        self.message.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_message(&self) -> &str {
+        match self.message.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_message_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.message
+    }
+
+    fn mut_message_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.message
+    }
+}
+
+impl ::protobuf::Message for RingCommandAck {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.command_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.member_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_bool()?;
+                    self.success = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.message)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.command_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        };
+        if let Some(v) = self.member_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        };
+        if let Some(v) = self.success {
+            my_size += 2;
+        };
+        if let Some(v) = self.message.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.command_id.as_ref() {
+            os.write_string(1, &v)?;
+        };
+        if let Some(v) = self.member_id.as_ref() {
+            os.write_string(2, &v)?;
+        };
+        if let Some(v) = self.success {
+            os.write_bool(3, v)?;
+        };
+        if let Some(v) = self.message.as_ref() {
+            os.write_string(4, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for RingCommandAck {
+    fn new() -> RingCommandAck {
+        RingCommandAck::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<RingCommandAck>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "command_id",
+                    RingCommandAck::get_command_id_for_reflect,
+                    RingCommandAck::mut_command_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "member_id",
+                    RingCommandAck::get_member_id_for_reflect,
+                    RingCommandAck::mut_member_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    RingCommandAck::get_success_for_reflect,
+                    RingCommandAck::mut_success_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "message",
+                    RingCommandAck::get_message_for_reflect,
+                    RingCommandAck::mut_message_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RingCommandAck>(
+                    "RingCommandAck",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for RingCommandAck {
+    fn clear(&mut self) {
+        self.clear_command_id();
+        self.clear_member_id();
+        self.clear_success();
+        self.clear_message();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RingCommandAck {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RingCommandAck {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ServiceRestart {
+    // message fields
+    service_group: ::protobuf::SingularField<::std::string::String>,
+    incarnation: ::std::option::Option<u64>,
+    rolling: ::std::option::Option<bool>,
+    issued_at: ::std::option::Option<i64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ServiceRestart {}
+
+impl ServiceRestart {
+    pub fn new() -> ServiceRestart {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ServiceRestart {
+        static mut instance: ::protobuf::lazy::Lazy<ServiceRestart> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ServiceRestart,
+        };
+        unsafe {
+            instance.get(ServiceRestart::new)
+        }
+    }
+
+    // optional string service_group = 1;
+
+    pub fn clear_service_group(&mut self) {
+        self.service_group.clear();
+    }
+
+    pub fn has_service_group(&self) -> bool {
+        self.service_group.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service_group(&mut self, v: ::std::string::String) {
+        self.service_group = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_service_group(&mut self) -> &mut ::std::string::String {
+        if self.service_group.is_none() {
+            self.service_group.set_default();
+        };
+        self.service_group.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_service_group(&mut self) -> ::std::string::String {
+        self.service_group.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_service_group(&self) -> &str {
+        match self.service_group.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_service_group_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.service_group
+    }
+
+    fn mut_service_group_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.service_group
+    }
+
+    // optional uint64 incarnation = 2;
+
+    pub fn clear_incarnation(&mut self) {
+        self.incarnation = ::std::option::Option::None;
+    }
+
+    pub fn has_incarnation(&self) -> bool {
+        self.incarnation.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_incarnation(&mut self, v: u64) {
+        self.incarnation = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_incarnation(&self) -> u64 {
+        self.incarnation.unwrap_or(0)
+    }
+
+    fn get_incarnation_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.incarnation
+    }
+
+    fn mut_incarnation_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.incarnation
+    }
+
+    // optional bool rolling = 3;
+
+    pub fn clear_rolling(&mut self) {
+        self.rolling = ::std::option::Option::None;
+    }
+
+    pub fn has_rolling(&self) -> bool {
+        self.rolling.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rolling(&mut self, v: bool) {
+        self.rolling = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_rolling(&self) -> bool {
+        self.rolling.unwrap_or(false)
+    }
+
+    fn get_rolling_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.rolling
+    }
+
+    fn mut_rolling_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.rolling
+    }
+
+    // optional int64 issued_at = 4;
+
+    pub fn clear_issued_at(&mut self) {
+        self.issued_at = ::std::option::Option::None;
+    }
+
+    pub fn has_issued_at(&self) -> bool {
+        self.issued_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_issued_at(&mut self, v: i64) {
+        self.issued_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_issued_at(&self) -> i64 {
+        self.issued_at.unwrap_or(0)
+    }
+
+    fn get_issued_at_for_reflect(&self) -> &::std::option::Option<i64> {
+        &self.issued_at
+    }
+
+    fn mut_issued_at_for_reflect(&mut self) -> &mut ::std::option::Option<i64> {
+        &mut self.issued_at
+    }
+}
+
+impl ::protobuf::Message for ServiceRestart {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.service_group)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.incarnation = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_bool()?;
+                    self.rolling = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_int64()?;
+                    self.issued_at = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.service_group.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        };
+        if let Some(v) = self.incarnation {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if let Some(v) = self.rolling {
+            my_size += 2;
+        };
+        if let Some(v) = self.issued_at {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.service_group.as_ref() {
+            os.write_string(1, &v)?;
+        };
+        if let Some(v) = self.incarnation {
+            os.write_uint64(2, v)?;
+        };
+        if let Some(v) = self.rolling {
+            os.write_bool(3, v)?;
+        };
+        if let Some(v) = self.issued_at {
+            os.write_int64(4, v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ServiceRestart {
+    fn new() -> ServiceRestart {
+        ServiceRestart::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ServiceRestart>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "service_group",
+                    ServiceRestart::get_service_group_for_reflect,
+                    ServiceRestart::mut_service_group_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "incarnation",
+                    ServiceRestart::get_incarnation_for_reflect,
+                    ServiceRestart::mut_incarnation_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "rolling",
+                    ServiceRestart::get_rolling_for_reflect,
+                    ServiceRestart::mut_rolling_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "issued_at",
+                    ServiceRestart::get_issued_at_for_reflect,
+                    ServiceRestart::mut_issued_at_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ServiceRestart>(
+                    "ServiceRestart",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServiceRestart {
+    fn clear(&mut self) {
+        self.clear_service_group();
+        self.clear_incarnation();
+        self.clear_rolling();
+        self.clear_issued_at();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ServiceRestart {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ServiceRestart {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateLock {
+    // message fields
+    service_group: ::protobuf::SingularField<::std::string::String>,
+    member_id: ::protobuf::SingularField<::std::string::String>,
+    incarnation: ::std::option::Option<u64>,
+    held: ::std::option::Option<bool>,
+    requested_at: ::std::option::Option<i64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for UpdateLock {}
+
+impl UpdateLock {
+    pub fn new() -> UpdateLock {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static UpdateLock {
+        static mut instance: ::protobuf::lazy::Lazy<UpdateLock> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const UpdateLock,
+        };
+        unsafe {
+            instance.get(UpdateLock::new)
+        }
+    }
+
+    // optional string service_group = 1;
+
+    pub fn clear_service_group(&mut self) {
+        self.service_group.clear();
+    }
+
+    pub fn has_service_group(&self) -> bool {
+        self.service_group.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service_group(&mut self, v: ::std::string::String) {
+        self.service_group = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_service_group(&mut self) -> &mut ::std::string::String {
+        if self.service_group.is_none() {
+            self.service_group.set_default();
+        };
+        self.service_group.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_service_group(&mut self) -> ::std::string::String {
+        self.service_group.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_service_group(&self) -> &str {
+        match self.service_group.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_service_group_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.service_group
+    }
+
+    fn mut_service_group_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.service_group
+    }
+
+    // optional string member_id = 2;
+
+    pub fn clear_member_id(&mut self) {
+        self.member_id.clear();
+    }
+
+    pub fn has_member_id(&self) -> bool {
+        self.member_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_member_id(&mut self, v: ::std::string::String) {
+        self.member_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_member_id(&mut self) -> &mut ::std::string::String {
+        if self.member_id.is_none() {
+            self.member_id.set_default();
+        };
+        self.member_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_member_id(&mut self) -> ::std::string::String {
+        self.member_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_member_id(&self) -> &str {
+        match self.member_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_member_id_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.member_id
+    }
+
+    fn mut_member_id_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.member_id
+    }
+
+    // optional uint64 incarnation = 3;
+
+    pub fn clear_incarnation(&mut self) {
+        self.incarnation = ::std::option::Option::None;
+    }
+
+    pub fn has_incarnation(&self) -> bool {
+        self.incarnation.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_incarnation(&mut self, v: u64) {
+        self.incarnation = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_incarnation(&self) -> u64 {
+        self.incarnation.unwrap_or(0)
+    }
+
+    fn get_incarnation_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.incarnation
+    }
+
+    fn mut_incarnation_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.incarnation
+    }
+
+    // optional bool held = 4;
+
+    pub fn clear_held(&mut self) {
+        self.held = ::std::option::Option::None;
+    }
+
+    pub fn has_held(&self) -> bool {
+        self.held.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_held(&mut self, v: bool) {
+        self.held = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_held(&self) -> bool {
+        self.held.unwrap_or(false)
+    }
+
+    fn get_held_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.held
+    }
+
+    fn mut_held_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.held
+    }
+
+    // optional int64 requested_at = 5;
+
+    pub fn clear_requested_at(&mut self) {
+        self.requested_at = ::std::option::Option::None;
+    }
+
+    pub fn has_requested_at(&self) -> bool {
+        self.requested_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requested_at(&mut self, v: i64) {
+        self.requested_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_requested_at(&self) -> i64 {
+        self.requested_at.unwrap_or(0)
+    }
+
+    fn get_requested_at_for_reflect(&self) -> &::std::option::Option<i64> {
+        &self.requested_at
+    }
+
+    fn mut_requested_at_for_reflect(&mut self) -> &mut ::std::option::Option<i64> {
+        &mut self.requested_at
+    }
+}
+
+impl ::protobuf::Message for UpdateLock {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.service_group)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.member_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.incarnation = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_bool()?;
+                    self.held = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_int64()?;
+                    self.requested_at = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.service_group.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        };
+        if let Some(v) = self.member_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        };
+        if let Some(v) = self.incarnation {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if let Some(v) = self.held {
+            my_size += 2;
+        };
+        if let Some(v) = self.requested_at {
+            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.service_group.as_ref() {
+            os.write_string(1, &v)?;
+        };
+        if let Some(v) = self.member_id.as_ref() {
+            os.write_string(2, &v)?;
+        };
+        if let Some(v) = self.incarnation {
+            os.write_uint64(3, v)?;
+        };
+        if let Some(v) = self.held {
+            os.write_bool(4, v)?;
+        };
+        if let Some(v) = self.requested_at {
+            os.write_int64(5, v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for UpdateLock {
+    fn new() -> UpdateLock {
+        UpdateLock::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<UpdateLock>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "service_group",
+                    UpdateLock::get_service_group_for_reflect,
+                    UpdateLock::mut_service_group_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "member_id",
+                    UpdateLock::get_member_id_for_reflect,
+                    UpdateLock::mut_member_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "incarnation",
+                    UpdateLock::get_incarnation_for_reflect,
+                    UpdateLock::mut_incarnation_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "held",
+                    UpdateLock::get_held_for_reflect,
+                    UpdateLock::mut_held_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "requested_at",
+                    UpdateLock::get_requested_at_for_reflect,
+                    UpdateLock::mut_requested_at_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<UpdateLock>(
+                    "UpdateLock",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for UpdateLock {
+    fn clear(&mut self) {
+        self.clear_service_group();
+        self.clear_member_id();
+        self.clear_incarnation();
+        self.clear_held();
+        self.clear_requested_at();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateLock {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateLock {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Swim {
+    // message fields
+    field_type: ::std::option::Option<Swim_Type>,
+    membership: ::protobuf::RepeatedField<Membership>,
+    // message oneof groups
+    payload: ::std::option::Option<Swim_oneof_payload>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Swim {}
+
+#[derive(Clone,PartialEq)]
+pub enum Swim_oneof_payload {
+    ping(Ping),
+    ack(Ack),
+    pingreq(PingReq),
+}
+
+impl Swim {
+    pub fn new() -> Swim {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Swim {
+        static mut instance: ::protobuf::lazy::Lazy<Swim> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Swim,
+        };
+        unsafe {
+            instance.get(Swim::new)
+        }
+    }
+
+    // required .Swim.Type type = 1;
+
+    pub fn clear_field_type(&mut self) {
+        self.field_type = ::std::option::Option::None;
+    }
+
+    pub fn has_field_type(&self) -> bool {
+        self.field_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: Swim_Type) {
+        self.field_type = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_field_type(&self) -> Swim_Type {
+        self.field_type.unwrap_or(Swim_Type::PING)
+    }
+
+    fn get_field_type_for_reflect(&self) -> &::std::option::Option<Swim_Type> {
+        &self.field_type
+    }
+
+    fn mut_field_type_for_reflect(&mut self) -> &mut ::std::option::Option<Swim_Type> {
+        &mut self.field_type
+    }
+
+    // optional .Ping ping = 2;
+
+    pub fn clear_ping(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_ping(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::ping(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ping(&mut self, v: Ping) {
+        self.payload = ::std::option::Option::Some(Swim_oneof_payload::ping(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ping(&mut self) -> &mut Ping {
+        if let ::std::option::Option::Some(Swim_oneof_payload::ping(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Swim_oneof_payload::ping(Ping::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::ping(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_ping(&mut self) -> Ping {
+        if self.has_ping() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Swim_oneof_payload::ping(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Ping::new()
+        }
+    }
+
+    pub fn get_ping(&self) -> &Ping {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::ping(ref v)) => v,
+            _ => Ping::default_instance(),
+        }
+    }
+
+    // optional .Ack ack = 3;
+
+    pub fn clear_ack(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_ack(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::ack(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ack(&mut self, v: Ack) {
+        self.payload = ::std::option::Option::Some(Swim_oneof_payload::ack(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ack(&mut self) -> &mut Ack {
+        if let ::std::option::Option::Some(Swim_oneof_payload::ack(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Swim_oneof_payload::ack(Ack::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::ack(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_ack(&mut self) -> Ack {
+        if self.has_ack() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Swim_oneof_payload::ack(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Ack::new()
+        }
+    }
+
+    pub fn get_ack(&self) -> &Ack {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::ack(ref v)) => v,
+            _ => Ack::default_instance(),
+        }
+    }
+
+    // optional .PingReq pingreq = 4;
+
+    pub fn clear_pingreq(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_pingreq(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::pingreq(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pingreq(&mut self, v: PingReq) {
+        self.payload = ::std::option::Option::Some(Swim_oneof_payload::pingreq(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_pingreq(&mut self) -> &mut PingReq {
+        if let ::std::option::Option::Some(Swim_oneof_payload::pingreq(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Swim_oneof_payload::pingreq(PingReq::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::pingreq(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_pingreq(&mut self) -> PingReq {
+        if self.has_pingreq() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Swim_oneof_payload::pingreq(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PingReq::new()
+        }
+    }
+
+    pub fn get_pingreq(&self) -> &PingReq {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::pingreq(ref v)) => v,
+            _ => PingReq::default_instance(),
+        }
+    }
+
+    // repeated .Membership membership = 5;
+
+    pub fn clear_membership(&mut self) {
+        self.membership.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_membership(&mut self, v: ::protobuf::RepeatedField<Membership>) {
+        self.membership = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_membership(&mut self) -> &mut ::protobuf::RepeatedField<Membership> {
+        &mut self.membership
+    }
+
+    // Take field
+    pub fn take_membership(&mut self) -> ::protobuf::RepeatedField<Membership> {
+        ::std::mem::replace(&mut self.membership, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_membership(&self) -> &[Membership] {
+        &self.membership
+    }
+
+    fn get_membership_for_reflect(&self) -> &::protobuf::RepeatedField<Membership> {
+        &self.membership
+    }
+
+    fn mut_membership_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Membership> {
+        &mut self.membership
+    }
+}
+
+impl ::protobuf::Message for Swim {
+    fn is_initialized(&self) -> bool {
+        if self.field_type.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_enum()?;
+                    self.field_type = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.payload = ::std::option::Option::Some(Swim_oneof_payload::ping(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.payload = ::std::option::Option::Some(Swim_oneof_payload::ack(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.payload = ::std::option::Option::Some(Swim_oneof_payload::pingreq(is.read_message()?));
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.membership)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.field_type {
+            my_size += ::protobuf::rt::enum_size(1, v);
+        };
+        for value in &self.membership {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let ::std::option::Option::Some(ref v) = self.payload {
+            match v {
+                &Swim_oneof_payload::ping(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Swim_oneof_payload::ack(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Swim_oneof_payload::pingreq(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.field_type {
+            os.write_enum(1, v.value())?;
+        };
+        for v in &self.membership {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let ::std::option::Option::Some(ref v) = self.payload {
+            match v {
+                &Swim_oneof_payload::ping(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Swim_oneof_payload::ack(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Swim_oneof_payload::pingreq(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Swim {
+    fn new() -> Swim {
+        Swim::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Swim>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Swim_Type>>(
+                    "type",
+                    Swim::get_field_type_for_reflect,
+                    Swim::mut_field_type_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Ping>(
+                    "ping",
+                    Swim::has_ping,
+                    Swim::get_ping,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Ack>(
+                    "ack",
+                    Swim::has_ack,
+                    Swim::get_ack,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, PingReq>(
+                    "pingreq",
+                    Swim::has_pingreq,
+                    Swim::get_pingreq,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Membership>>(
+                    "membership",
+                    Swim::get_membership_for_reflect,
+                    Swim::mut_membership_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Swim>(
+                    "Swim",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Swim {
+    fn clear(&mut self) {
+        self.clear_field_type();
+        self.clear_ping();
+        self.clear_ack();
+        self.clear_pingreq();
+        self.clear_membership();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Swim {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Swim {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Swim_Type {
+    PING = 1,
+    ACK = 2,
+    PINGREQ = 3,
+}
+
+impl ::protobuf::ProtobufEnum for Swim_Type {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Swim_Type> {
+        match value {
+            1 => ::std::option::Option::Some(Swim_Type::PING),
+            2 => ::std::option::Option::Some(Swim_Type::ACK),
+            3 => ::std::option::Option::Some(Swim_Type::PINGREQ),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Swim_Type] = &[
+            Swim_Type::PING,
+            Swim_Type::ACK,
+            Swim_Type::PINGREQ,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<Swim_Type>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("Swim_Type", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for Swim_Type {
+}
+
+impl ::protobuf::reflect::ProtobufValue for Swim_Type {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Rumor {
+    // message fields
+    field_type: ::std::option::Option<Rumor_Type>,
+    tag: ::protobuf::RepeatedField<::std::string::String>,
+    from_id: ::protobuf::SingularField<::std::string::String>,
+    // message oneof groups
+    payload: ::std::option::Option<Rumor_oneof_payload>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Rumor {}
+
+#[derive(Clone,PartialEq)]
+pub enum Rumor_oneof_payload {
+    member(Membership),
+    service(Service),
+    service_config(ServiceConfig),
+    service_file(ServiceFile),
+    election(Election),
+    service_scale(ServiceScale),
+    active_color(ActiveColor),
+    ring_command(RingCommand),
+    ring_command_ack(RingCommandAck),
+    service_restart(ServiceRestart),
+    update_lock(UpdateLock),
+}
+
+impl Rumor {
+    pub fn new() -> Rumor {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Rumor {
+        static mut instance: ::protobuf::lazy::Lazy<Rumor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Rumor,
+        };
+        unsafe {
+            instance.get(Rumor::new)
+        }
+    }
+
+    // required .Rumor.Type type = 1;
+
+    pub fn clear_field_type(&mut self) {
+        self.field_type = ::std::option::Option::None;
+    }
+
+    pub fn has_field_type(&self) -> bool {
+        self.field_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: Rumor_Type) {
+        self.field_type = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_field_type(&self) -> Rumor_Type {
+        self.field_type.unwrap_or(Rumor_Type::Member)
+    }
+
+    fn get_field_type_for_reflect(&self) -> &::std::option::Option<Rumor_Type> {
+        &self.field_type
+    }
+
+    fn mut_field_type_for_reflect(&mut self) -> &mut ::std::option::Option<Rumor_Type> {
+        &mut self.field_type
+    }
+
+    // repeated string tag = 2;
+
+    pub fn clear_tag(&mut self) {
+        self.tag.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tag(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.tag = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tag(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tag
+    }
+
+    // Take field
+    pub fn take_tag(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.tag, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_tag(&self) -> &[::std::string::String] {
+        &self.tag
+    }
+
+    fn get_tag_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.tag
+    }
+
+    fn mut_tag_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tag
+    }
+
+    // optional string from_id = 3;
+
+    pub fn clear_from_id(&mut self) {
+        self.from_id.clear();
+    }
+
+    pub fn has_from_id(&self) -> bool {
+        self.from_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_from_id(&mut self, v: ::std::string::String) {
+        self.from_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_from_id(&mut self) -> &mut ::std::string::String {
+        if self.from_id.is_none() {
+            self.from_id.set_default();
+        };
+        self.from_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_from_id(&mut self) -> ::std::string::String {
+        self.from_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_from_id(&self) -> &str {
+        match self.from_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_from_id_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.from_id
+    }
+
+    fn mut_from_id_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.from_id
+    }
+
+    // optional .Membership member = 4;
+
+    pub fn clear_member(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_member(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::member(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_member(&mut self, v: Membership) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::member(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_member(&mut self) -> &mut Membership {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::member(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::member(Membership::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::member(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_member(&mut self) -> Membership {
+        if self.has_member() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::member(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Membership::new()
+        }
+    }
+
+    pub fn get_member(&self) -> &Membership {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::member(ref v)) => v,
+            _ => Membership::default_instance(),
+        }
+    }
+
+    // optional .Service service = 5;
+
+    pub fn clear_service(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_service(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service(&mut self, v: Service) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_service(&mut self) -> &mut Service {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::service(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service(Service::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_service(&mut self) -> Service {
+        if self.has_service() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::service(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Service::new()
+        }
+    }
+
+    pub fn get_service(&self) -> &Service {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service(ref v)) => v,
+            _ => Service::default_instance(),
+        }
+    }
+
+    // optional .ServiceConfig service_config = 6;
+
+    pub fn clear_service_config(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_service_config(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service_config(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service_config(&mut self, v: ServiceConfig) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_config(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_service_config(&mut self) -> &mut ServiceConfig {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::service_config(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_config(ServiceConfig::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service_config(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_service_config(&mut self) -> ServiceConfig {
+        if self.has_service_config() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::service_config(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ServiceConfig::new()
+        }
+    }
+
+    pub fn get_service_config(&self) -> &ServiceConfig {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service_config(ref v)) => v,
+            _ => ServiceConfig::default_instance(),
+        }
+    }
+
+    // optional .ServiceFile service_file = 7;
+
+    pub fn clear_service_file(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_service_file(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service_file(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service_file(&mut self, v: ServiceFile) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_file(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_service_file(&mut self) -> &mut ServiceFile {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::service_file(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_file(ServiceFile::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service_file(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_service_file(&mut self) -> ServiceFile {
+        if self.has_service_file() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::service_file(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ServiceFile::new()
+        }
+    }
+
+    pub fn get_service_file(&self) -> &ServiceFile {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service_file(ref v)) => v,
+            _ => ServiceFile::default_instance(),
+        }
+    }
+
+    // optional .Election election = 8;
+
+    pub fn clear_election(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_election(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::election(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_election(&mut self, v: Election) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::election(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_election(&mut self) -> &mut Election {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::election(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::election(Election::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::election(ref mut v)) => v,
+            _ => panic!(),
+        }
     }
 
     // Take field
-    pub fn take_tag(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.tag, ::protobuf::RepeatedField::new())
-    }
-
-    pub fn get_tag(&self) -> &[::std::string::String] {
-        &self.tag
-    }
-
-    fn get_tag_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
-        &self.tag
+    pub fn take_election(&mut self) -> Election {
+        if self.has_election() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::election(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Election::new()
+        }
     }
 
-    fn mut_tag_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.tag
+    pub fn get_election(&self) -> &Election {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::election(ref v)) => v,
+            _ => Election::default_instance(),
+        }
     }
 
-    // optional string from_id = 3;
+    // optional .ServiceScale service_scale = 9;
 
-    pub fn clear_from_id(&mut self) {
-        self.from_id.clear();
+    pub fn clear_service_scale(&mut self) {
+        self.payload = ::std::option::Option::None;
     }
 
-    pub fn has_from_id(&self) -> bool {
-        self.from_id.is_some()
+    pub fn has_service_scale(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service_scale(..)) => true,
+            _ => false,
+        }
     }
 
     // Param is passed by value, moved
-    pub fn set_from_id(&mut self, v: ::std::string::String) {
-        self.from_id = ::protobuf::SingularField::some(v);
+    pub fn set_service_scale(&mut self, v: ServiceScale) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_scale(v))
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_from_id(&mut self) -> &mut ::std::string::String {
-        if self.from_id.is_none() {
-            self.from_id.set_default();
-        };
-        self.from_id.as_mut().unwrap()
+    pub fn mut_service_scale(&mut self) -> &mut ServiceScale {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::service_scale(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_scale(ServiceScale::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service_scale(ref mut v)) => v,
+            _ => panic!(),
+        }
     }
 
     // Take field
-    pub fn take_from_id(&mut self) -> ::std::string::String {
-        self.from_id.take().unwrap_or_else(|| ::std::string::String::new())
-    }
-
-    pub fn get_from_id(&self) -> &str {
-        match self.from_id.as_ref() {
-            Some(v) => &v,
-            None => "",
+    pub fn take_service_scale(&mut self) -> ServiceScale {
+        if self.has_service_scale() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::service_scale(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ServiceScale::new()
         }
     }
 
-    fn get_from_id_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.from_id
-    }
-
-    fn mut_from_id_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.from_id
+    pub fn get_service_scale(&self) -> &ServiceScale {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::service_scale(ref v)) => v,
+            _ => ServiceScale::default_instance(),
+        }
     }
 
-    // optional .Membership member = 4;
+    // optional .ActiveColor active_color = 10;
 
-    pub fn clear_member(&mut self) {
+    pub fn clear_active_color(&mut self) {
         self.payload = ::std::option::Option::None;
     }
 
-    pub fn has_member(&self) -> bool {
+    pub fn has_active_color(&self) -> bool {
         match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::member(..)) => true,
+            ::std::option::Option::Some(Rumor_oneof_payload::active_color(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_member(&mut self, v: Membership) {
-        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::member(v))
+    pub fn set_active_color(&mut self, v: ActiveColor) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::active_color(v))
     }
 
     // Mutable pointer to the field.
-    pub fn mut_member(&mut self) -> &mut Membership {
-        if let ::std::option::Option::Some(Rumor_oneof_payload::member(_)) = self.payload {
+    pub fn mut_active_color(&mut self) -> &mut ActiveColor {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::active_color(_)) = self.payload {
         } else {
-            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::member(Membership::new()));
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::active_color(ActiveColor::new()));
         }
         match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::member(ref mut v)) => v,
+            ::std::option::Option::Some(Rumor_oneof_payload::active_color(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
     // Take field
-    pub fn take_member(&mut self) -> Membership {
-        if self.has_member() {
+    pub fn take_active_color(&mut self) -> ActiveColor {
+        if self.has_active_color() {
             match self.payload.take() {
-                ::std::option::Option::Some(Rumor_oneof_payload::member(v)) => v,
+                ::std::option::Option::Some(Rumor_oneof_payload::active_color(v)) => v,
                 _ => panic!(),
             }
         } else {
-            Membership::new()
+            ActiveColor::new()
         }
     }
 
-    pub fn get_member(&self) -> &Membership {
+    pub fn get_active_color(&self) -> &ActiveColor {
         match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::member(ref v)) => v,
-            _ => Membership::default_instance(),
+            ::std::option::Option::Some(Rumor_oneof_payload::active_color(ref v)) => v,
+            _ => ActiveColor::default_instance(),
         }
     }
 
-    // optional .Service service = 5;
+    // optional .RingCommand ring_command = 11;
 
-    pub fn clear_service(&mut self) {
+    pub fn clear_ring_command(&mut self) {
         self.payload = ::std::option::Option::None;
     }
 
-    pub fn has_service(&self) -> bool {
+    pub fn has_ring_command(&self) -> bool {
         match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::service(..)) => true,
+            ::std::option::Option::Some(Rumor_oneof_payload::ring_command(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_service(&mut self, v: Service) {
-        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service(v))
+    pub fn set_ring_command(&mut self, v: RingCommand) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::ring_command(v))
     }
 
     // Mutable pointer to the field.
-    pub fn mut_service(&mut self) -> &mut Service {
-        if let ::std::option::Option::Some(Rumor_oneof_payload::service(_)) = self.payload {
+    pub fn mut_ring_command(&mut self) -> &mut RingCommand {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::ring_command(_)) = self.payload {
         } else {
-            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service(Service::new()));
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::ring_command(RingCommand::new()));
         }
         match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::service(ref mut v)) => v,
+            ::std::option::Option::Some(Rumor_oneof_payload::ring_command(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
     // Take field
-    pub fn take_service(&mut self) -> Service {
-        if self.has_service() {
+    pub fn take_ring_command(&mut self) -> RingCommand {
+        if self.has_ring_command() {
             match self.payload.take() {
-                ::std::option::Option::Some(Rumor_oneof_payload::service(v)) => v,
+                ::std::option::Option::Some(Rumor_oneof_payload::ring_command(v)) => v,
                 _ => panic!(),
             }
         } else {
-            Service::new()
+            RingCommand::new()
         }
     }
 
-    pub fn get_service(&self) -> &Service {
+    pub fn get_ring_command(&self) -> &RingCommand {
         match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::service(ref v)) => v,
-            _ => Service::default_instance(),
+            ::std::option::Option::Some(Rumor_oneof_payload::ring_command(ref v)) => v,
+            _ => RingCommand::default_instance(),
         }
     }
 
-    // optional .ServiceConfig service_config = 6;
+    // optional .RingCommandAck ring_command_ack = 12;
 
-    pub fn clear_service_config(&mut self) {
+    pub fn clear_ring_command_ack(&mut self) {
         self.payload = ::std::option::Option::None;
     }
 
-    pub fn has_service_config(&self) -> bool {
+    pub fn has_ring_command_ack(&self) -> bool {
         match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::service_config(..)) => true,
+            ::std::option::Option::Some(Rumor_oneof_payload::ring_command_ack(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_service_config(&mut self, v: ServiceConfig) {
-        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_config(v))
+    pub fn set_ring_command_ack(&mut self, v: RingCommandAck) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::ring_command_ack(v))
     }
 
     // Mutable pointer to the field.
-    pub fn mut_service_config(&mut self) -> &mut ServiceConfig {
-        if let ::std::option::Option::Some(Rumor_oneof_payload::service_config(_)) = self.payload {
+    pub fn mut_ring_command_ack(&mut self) -> &mut RingCommandAck {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::ring_command_ack(_)) = self.payload {
         } else {
-            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_config(ServiceConfig::new()));
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::ring_command_ack(RingCommandAck::new()));
         }
         match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::service_config(ref mut v)) => v,
+            ::std::option::Option::Some(Rumor_oneof_payload::ring_command_ack(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
     // Take field
-    pub fn take_service_config(&mut self) -> ServiceConfig {
-        if self.has_service_config() {
+    pub fn take_ring_command_ack(&mut self) -> RingCommandAck {
+        if self.has_ring_command_ack() {
             match self.payload.take() {
-                ::std::option::Option::Some(Rumor_oneof_payload::service_config(v)) => v,
+                ::std::option::Option::Some(Rumor_oneof_payload::ring_command_ack(v)) => v,
                 _ => panic!(),
             }
         } else {
-            ServiceConfig::new()
+            RingCommandAck::new()
         }
     }
 
-    pub fn get_service_config(&self) -> &ServiceConfig {
+    pub fn get_ring_command_ack(&self) -> &RingCommandAck {
         match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::service_config(ref v)) => v,
-            _ => ServiceConfig::default_instance(),
+            ::std::option::Option::Some(Rumor_oneof_payload::ring_command_ack(ref v)) => v,
+            _ => RingCommandAck::default_instance(),
         }
     }
 
-    // optional .ServiceFile service_file = 7;
+    // optional .ServiceRestart service_restart = 13;
 
-    pub fn clear_service_file(&mut self) {
+    pub fn clear_service_restart(&mut self) {
         self.payload = ::std::option::Option::None;
     }
 
-    pub fn has_service_file(&self) -> bool {
+    pub fn has_service_restart(&self) -> bool {
         match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::service_file(..)) => true,
+            ::std::option::Option::Some(Rumor_oneof_payload::service_restart(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_service_file(&mut self, v: ServiceFile) {
-        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_file(v))
+    pub fn set_service_restart(&mut self, v: ServiceRestart) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_restart(v))
     }
 
     // Mutable pointer to the field.
-    pub fn mut_service_file(&mut self) -> &mut ServiceFile {
-        if let ::std::option::Option::Some(Rumor_oneof_payload::service_file(_)) = self.payload {
+    pub fn mut_service_restart(&mut self) -> &mut ServiceRestart {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::service_restart(_)) = self.payload {
         } else {
-            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_file(ServiceFile::new()));
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_restart(ServiceRestart::new()));
         }
         match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::service_file(ref mut v)) => v,
+            ::std::option::Option::Some(Rumor_oneof_payload::service_restart(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
     // Take field
-    pub fn take_service_file(&mut self) -> ServiceFile {
-        if self.has_service_file() {
+    pub fn take_service_restart(&mut self) -> ServiceRestart {
+        if self.has_service_restart() {
             match self.payload.take() {
-                ::std::option::Option::Some(Rumor_oneof_payload::service_file(v)) => v,
+                ::std::option::Option::Some(Rumor_oneof_payload::service_restart(v)) => v,
                 _ => panic!(),
             }
         } else {
-            ServiceFile::new()
+            ServiceRestart::new()
         }
     }
 
-    pub fn get_service_file(&self) -> &ServiceFile {
+    pub fn get_service_restart(&self) -> &ServiceRestart {
         match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::service_file(ref v)) => v,
-            _ => ServiceFile::default_instance(),
+            ::std::option::Option::Some(Rumor_oneof_payload::service_restart(ref v)) => v,
+            _ => ServiceRestart::default_instance(),
         }
     }
 
-    // optional .Election election = 8;
+    // optional .UpdateLock update_lock = 14;
 
-    pub fn clear_election(&mut self) {
+    pub fn clear_update_lock(&mut self) {
         self.payload = ::std::option::Option::None;
     }
 
-    pub fn has_election(&self) -> bool {
+    pub fn has_update_lock(&self) -> bool {
         match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::election(..)) => true,
+            ::std::option::Option::Some(Rumor_oneof_payload::update_lock(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_election(&mut self, v: Election) {
-        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::election(v))
+    pub fn set_update_lock(&mut self, v: UpdateLock) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::update_lock(v))
     }
 
     // Mutable pointer to the field.
-    pub fn mut_election(&mut self) -> &mut Election {
-        if let ::std::option::Option::Some(Rumor_oneof_payload::election(_)) = self.payload {
+    pub fn mut_update_lock(&mut self) -> &mut UpdateLock {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::update_lock(_)) = self.payload {
         } else {
-            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::election(Election::new()));
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::update_lock(UpdateLock::new()));
         }
         match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::election(ref mut v)) => v,
+            ::std::option::Option::Some(Rumor_oneof_payload::update_lock(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
     // Take field
-    pub fn take_election(&mut self) -> Election {
-        if self.has_election() {
+    pub fn take_update_lock(&mut self) -> UpdateLock {
+        if self.has_update_lock() {
             match self.payload.take() {
-                ::std::option::Option::Some(Rumor_oneof_payload::election(v)) => v,
+                ::std::option::Option::Some(Rumor_oneof_payload::update_lock(v)) => v,
                 _ => panic!(),
             }
         } else {
-            Election::new()
+            UpdateLock::new()
         }
     }
 
-    pub fn get_election(&self) -> &Election {
+    pub fn get_update_lock(&self) -> &UpdateLock {
         match self.payload {
-            ::std::option::Option::Some(Rumor_oneof_payload::election(ref v)) => v,
-            _ => Election::default_instance(),
+            ::std::option::Option::Some(Rumor_oneof_payload::update_lock(ref v)) => v,
+            _ => UpdateLock::default_instance(),
         }
     }
 }
@@ -4178,6 +7071,42 @@ impl ::protobuf::Message for Rumor {
                     };
                     self.payload = ::std::option::Option::Some(Rumor_oneof_payload::election(is.read_message()?));
                 },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_scale(is.read_message()?));
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::active_color(is.read_message()?));
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::ring_command(is.read_message()?));
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::ring_command_ack(is.read_message()?));
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_restart(is.read_message()?));
+                },
+                14 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::update_lock(is.read_message()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -4221,6 +7150,30 @@ impl ::protobuf::Message for Rumor {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
+                &Rumor_oneof_payload::service_scale(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Rumor_oneof_payload::active_color(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Rumor_oneof_payload::ring_command(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Rumor_oneof_payload::ring_command_ack(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Rumor_oneof_payload::service_restart(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Rumor_oneof_payload::update_lock(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
             };
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -4265,6 +7218,36 @@ impl ::protobuf::Message for Rumor {
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
+                &Rumor_oneof_payload::service_scale(ref v) => {
+                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Rumor_oneof_payload::active_color(ref v) => {
+                    os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Rumor_oneof_payload::ring_command(ref v) => {
+                    os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Rumor_oneof_payload::ring_command_ack(ref v) => {
+                    os.write_tag(12, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Rumor_oneof_payload::service_restart(ref v) => {
+                    os.write_tag(13, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Rumor_oneof_payload::update_lock(ref v) => {
+                    os.write_tag(14, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
             };
         };
         os.write_unknown_fields(self.get_unknown_fields())?;
@@ -4351,6 +7334,36 @@ impl ::protobuf::MessageStatic for Rumor {
                     Rumor::has_election,
                     Rumor::get_election,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ServiceScale>(
+                    "service_scale",
+                    Rumor::has_service_scale,
+                    Rumor::get_service_scale,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ActiveColor>(
+                    "active_color",
+                    Rumor::has_active_color,
+                    Rumor::get_active_color,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, RingCommand>(
+                    "ring_command",
+                    Rumor::has_ring_command,
+                    Rumor::get_ring_command,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, RingCommandAck>(
+                    "ring_command_ack",
+                    Rumor::has_ring_command_ack,
+                    Rumor::get_ring_command_ack,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ServiceRestart>(
+                    "service_restart",
+                    Rumor::has_service_restart,
+                    Rumor::get_service_restart,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, UpdateLock>(
+                    "update_lock",
+                    Rumor::has_update_lock,
+                    Rumor::get_update_lock,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Rumor>(
                     "Rumor",
                     fields,
@@ -4371,6 +7384,12 @@ impl ::protobuf::Clear for Rumor {
         self.clear_service_config();
         self.clear_service_file();
         self.clear_election();
+        self.clear_service_scale();
+        self.clear_active_color();
+        self.clear_ring_command();
+        self.clear_ring_command_ack();
+        self.clear_service_restart();
+        self.clear_update_lock();
         self.unknown_fields.clear();
     }
 }
@@ -4397,6 +7416,12 @@ pub enum Rumor_Type {
     Fake = 6,
     Fake2 = 7,
     ElectionUpdate = 8,
+    ServiceScale = 9,
+    ActiveColor = 10,
+    RingCommand = 11,
+    RingCommandAck = 12,
+    ServiceRestart = 13,
+    UpdateLock = 14,
 }
 
 impl ::protobuf::ProtobufEnum for Rumor_Type {
@@ -4414,6 +7439,12 @@ impl ::protobuf::ProtobufEnum for Rumor_Type {
             6 => ::std::option::Option::Some(Rumor_Type::Fake),
             7 => ::std::option::Option::Some(Rumor_Type::Fake2),
             8 => ::std::option::Option::Some(Rumor_Type::ElectionUpdate),
+            9 => ::std::option::Option::Some(Rumor_Type::ServiceScale),
+            10 => ::std::option::Option::Some(Rumor_Type::ActiveColor),
+            11 => ::std::option::Option::Some(Rumor_Type::RingCommand),
+            12 => ::std::option::Option::Some(Rumor_Type::RingCommandAck),
+            13 => ::std::option::Option::Some(Rumor_Type::ServiceRestart),
+            14 => ::std::option::Option::Some(Rumor_Type::UpdateLock),
             _ => ::std::option::Option::None
         }
     }
@@ -4428,6 +7459,12 @@ impl ::protobuf::ProtobufEnum for Rumor_Type {
             Rumor_Type::Fake,
             Rumor_Type::Fake2,
             Rumor_Type::ElectionUpdate,
+            Rumor_Type::ServiceScale,
+            Rumor_Type::ActiveColor,
+            Rumor_Type::RingCommand,
+            Rumor_Type::RingCommandAck,
+            Rumor_Type::ServiceRestart,
+            Rumor_Type::UpdateLock,
         ];
         values
     }
@@ -4460,6 +7497,7 @@ pub struct Wire {
     encrypted: ::std::option::Option<bool>,
     nonce: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     payload: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    compression: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -4597,6 +7635,50 @@ impl Wire {
     fn mut_payload_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
         &mut self.payload
     }
+
+    // optional string compression = 4;
+
+    pub fn clear_compression(&mut self) {
+        self.compression.clear();
+    }
+
+    pub fn has_compression(&self) -> bool {
+        self.compression.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_compression(&mut self, v: ::std::string::String) {
+        self.compression = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_compression(&mut self) -> &mut ::std::string::String {
+        if self.compression.is_none() {
+            self.compression.set_default();
+        };
+        self.compression.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_compression(&mut self) -> ::std::string::String {
+        self.compression.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_compression(&self) -> &str {
+        match self.compression.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_compression_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.compression
+    }
+
+    fn mut_compression_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.compression
+    }
 }
 
 impl ::protobuf::Message for Wire {
@@ -4621,6 +7703,9 @@ impl ::protobuf::Message for Wire {
                 3 => {
                     ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.payload)?;
                 },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.compression)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -4642,6 +7727,9 @@ impl ::protobuf::Message for Wire {
         if let Some(v) = self.payload.as_ref() {
             my_size += ::protobuf::rt::bytes_size(3, &v);
         };
+        if let Some(v) = self.compression.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -4657,6 +7745,9 @@ impl ::protobuf::Message for Wire {
         if let Some(v) = self.payload.as_ref() {
             os.write_bytes(3, &v)?;
         };
+        if let Some(v) = self.compression.as_ref() {
+            os.write_string(4, &v)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4716,6 +7807,11 @@ impl ::protobuf::MessageStatic for Wire {
                     Wire::get_payload_for_reflect,
                     Wire::mut_payload_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "compression",
+                    Wire::get_compression_for_reflect,
+                    Wire::mut_compression_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Wire>(
                     "Wire",
                     fields,
@@ -4731,6 +7827,7 @@ impl ::protobuf::Clear for Wire {
         self.clear_encrypted();
         self.clear_nonce();
         self.clear_payload();
+        self.clear_compression();
         self.unknown_fields.clear();
     }
 }
@@ -4749,7 +7846,7 @@ impl ::protobuf::reflect::ProtobufValue for Wire {
 
 static file_descriptor_proto_data: &'static [u8] = &[
     0x0a, 0x14, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x73, 0x2f, 0x73, 0x77, 0x69, 0x6d,
-    0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0xb9, 0x01, 0x0a, 0x06, 0x4d, 0x65, 0x6d, 0x62, 0x65,
+    0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0xf1, 0x01, 0x0a, 0x06, 0x4d, 0x65, 0x6d, 0x62, 0x65,
     0x72, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69,
     0x64, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e,
     0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74,
@@ -4761,426 +7858,676 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x0a, 0x67, 0x6f, 0x73, 0x73, 0x69, 0x70, 0x50, 0x6f, 0x72, 0x74, 0x12, 0x25, 0x0a, 0x0a, 0x70,
     0x65, 0x72, 0x73, 0x69, 0x73, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x08, 0x3a,
     0x05, 0x66, 0x61, 0x6c, 0x73, 0x65, 0x52, 0x0a, 0x70, 0x65, 0x72, 0x73, 0x69, 0x73, 0x74, 0x65,
-    0x6e, 0x74, 0x22, 0x4b, 0x0a, 0x04, 0x50, 0x69, 0x6e, 0x67, 0x12, 0x1b, 0x0a, 0x04, 0x66, 0x72,
-    0x6f, 0x6d, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65,
-    0x72, 0x52, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x12, 0x26, 0x0a, 0x0a, 0x66, 0x6f, 0x72, 0x77, 0x61,
-    0x72, 0x64, 0x5f, 0x74, 0x6f, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65,
-    0x6d, 0x62, 0x65, 0x72, 0x52, 0x09, 0x66, 0x6f, 0x72, 0x77, 0x61, 0x72, 0x64, 0x54, 0x6f, 0x22,
-    0x4a, 0x0a, 0x03, 0x41, 0x63, 0x6b, 0x12, 0x1b, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x01,
-    0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x04, 0x66,
-    0x72, 0x6f, 0x6d, 0x12, 0x26, 0x0a, 0x0a, 0x66, 0x6f, 0x72, 0x77, 0x61, 0x72, 0x64, 0x5f, 0x74,
-    0x6f, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72,
-    0x52, 0x09, 0x66, 0x6f, 0x72, 0x77, 0x61, 0x72, 0x64, 0x54, 0x6f, 0x22, 0x47, 0x0a, 0x07, 0x50,
-    0x69, 0x6e, 0x67, 0x52, 0x65, 0x71, 0x12, 0x1b, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x01,
-    0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x04, 0x66,
-    0x72, 0x6f, 0x6d, 0x12, 0x1f, 0x0a, 0x06, 0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x18, 0x02, 0x20,
-    0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x06, 0x74, 0x61,
-    0x72, 0x67, 0x65, 0x74, 0x22, 0x8a, 0x01, 0x0a, 0x0a, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73,
-    0x68, 0x69, 0x70, 0x12, 0x1f, 0x0a, 0x06, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x01, 0x20,
-    0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x06, 0x6d, 0x65,
-    0x6d, 0x62, 0x65, 0x72, 0x12, 0x2a, 0x0a, 0x06, 0x68, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x18, 0x02,
-    0x20, 0x01, 0x28, 0x0e, 0x32, 0x12, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69,
-    0x70, 0x2e, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x52, 0x06, 0x68, 0x65, 0x61, 0x6c, 0x74, 0x68,
-    0x22, 0x2f, 0x0a, 0x06, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x12, 0x09, 0x0a, 0x05, 0x41, 0x4c,
-    0x49, 0x56, 0x45, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x53, 0x55, 0x53, 0x50, 0x45, 0x43, 0x54,
-    0x10, 0x02, 0x12, 0x0d, 0x0a, 0x09, 0x43, 0x4f, 0x4e, 0x46, 0x49, 0x52, 0x4d, 0x45, 0x44, 0x10,
-    0x03, 0x22, 0xf5, 0x01, 0x0a, 0x08, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1b,
-    0x0a, 0x09, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
-    0x09, 0x52, 0x08, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x49, 0x64, 0x12, 0x23, 0x0a, 0x0d, 0x73,
-    0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x02, 0x20, 0x01,
+    0x6e, 0x74, 0x12, 0x20, 0x0a, 0x0b, 0x63, 0x6f, 0x6d, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f,
+    0x6e, 0x18, 0x07, 0x20, 0x03, 0x28, 0x09, 0x52, 0x0b, 0x63, 0x6f, 0x6d, 0x70, 0x72, 0x65, 0x73,
+    0x73, 0x69, 0x6f, 0x6e, 0x12, 0x14, 0x0a, 0x05, 0x61, 0x6c, 0x69, 0x61, 0x73, 0x18, 0x08, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x05, 0x61, 0x6c, 0x69, 0x61, 0x73, 0x22, 0x4b, 0x0a, 0x04, 0x50, 0x69,
+    0x6e, 0x67, 0x12, 0x1b, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x12,
+    0x26, 0x0a, 0x0a, 0x66, 0x6f, 0x72, 0x77, 0x61, 0x72, 0x64, 0x5f, 0x74, 0x6f, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x09, 0x66, 0x6f,
+    0x72, 0x77, 0x61, 0x72, 0x64, 0x54, 0x6f, 0x22, 0x4a, 0x0a, 0x03, 0x41, 0x63, 0x6b, 0x12, 0x1b,
+    0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d,
+    0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x12, 0x26, 0x0a, 0x0a, 0x66,
+    0x6f, 0x72, 0x77, 0x61, 0x72, 0x64, 0x5f, 0x74, 0x6f, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x09, 0x66, 0x6f, 0x72, 0x77, 0x61, 0x72,
+    0x64, 0x54, 0x6f, 0x22, 0x47, 0x0a, 0x07, 0x50, 0x69, 0x6e, 0x67, 0x52, 0x65, 0x71, 0x12, 0x1b,
+    0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d,
+    0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x12, 0x1f, 0x0a, 0x06, 0x74,
+    0x61, 0x72, 0x67, 0x65, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65,
+    0x6d, 0x62, 0x65, 0x72, 0x52, 0x06, 0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x22, 0x8a, 0x01, 0x0a,
+    0x0a, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x12, 0x1f, 0x0a, 0x06, 0x6d,
+    0x65, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65,
+    0x6d, 0x62, 0x65, 0x72, 0x52, 0x06, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x2a, 0x0a, 0x06,
+    0x68, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x12, 0x2e, 0x4d,
+    0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x2e, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68,
+    0x52, 0x06, 0x68, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x22, 0x2f, 0x0a, 0x06, 0x48, 0x65, 0x61, 0x6c,
+    0x74, 0x68, 0x12, 0x09, 0x0a, 0x05, 0x41, 0x4c, 0x49, 0x56, 0x45, 0x10, 0x01, 0x12, 0x0b, 0x0a,
+    0x07, 0x53, 0x55, 0x53, 0x50, 0x45, 0x43, 0x54, 0x10, 0x02, 0x12, 0x0d, 0x0a, 0x09, 0x43, 0x4f,
+    0x4e, 0x46, 0x49, 0x52, 0x4d, 0x45, 0x44, 0x10, 0x03, 0x22, 0xf5, 0x01, 0x0a, 0x08, 0x45, 0x6c,
+    0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1b, 0x0a, 0x09, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72,
+    0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x6d, 0x65, 0x6d, 0x62, 0x65,
+    0x72, 0x49, 0x64, 0x12, 0x23, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67,
+    0x72, 0x6f, 0x75, 0x70, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x73, 0x65, 0x72, 0x76,
+    0x69, 0x63, 0x65, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x12, 0x0a, 0x04, 0x74, 0x65, 0x72, 0x6d,
+    0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x04, 0x74, 0x65, 0x72, 0x6d, 0x12, 0x20, 0x0a, 0x0b,
+    0x73, 0x75, 0x69, 0x74, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x79, 0x18, 0x04, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x0b, 0x73, 0x75, 0x69, 0x74, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x79, 0x12, 0x28,
+    0x0a, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x10,
+    0x2e, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73,
+    0x52, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x6f, 0x74, 0x65,
+    0x73, 0x18, 0x06, 0x20, 0x03, 0x28, 0x09, 0x52, 0x05, 0x76, 0x6f, 0x74, 0x65, 0x73, 0x22, 0x31,
+    0x0a, 0x06, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x0b, 0x0a, 0x07, 0x52, 0x75, 0x6e, 0x6e,
+    0x69, 0x6e, 0x67, 0x10, 0x01, 0x12, 0x0c, 0x0a, 0x08, 0x4e, 0x6f, 0x51, 0x75, 0x6f, 0x72, 0x75,
+    0x6d, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x46, 0x69, 0x6e, 0x69, 0x73, 0x68, 0x65, 0x64, 0x10,
+    0x03, 0x22, 0xe1, 0x02, 0x0a, 0x07, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x1b, 0x0a,
+    0x09, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x08, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x49, 0x64, 0x12, 0x23, 0x0a, 0x0d, 0x73, 0x65,
+    0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x02, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12,
+    0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64,
+    0x18, 0x08, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0b, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x6c, 0x69,
+    0x7a, 0x65, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x70, 0x6b, 0x67, 0x18, 0x09, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x03, 0x70, 0x6b, 0x67, 0x12, 0x10, 0x0a, 0x03, 0x63, 0x66, 0x67, 0x18, 0x0a, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x03, 0x63, 0x66, 0x67, 0x12, 0x10, 0x0a, 0x03, 0x73, 0x79, 0x73, 0x18, 0x0c,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x73, 0x79, 0x73, 0x12, 0x24, 0x0a, 0x0e, 0x6c, 0x61, 0x73,
+    0x74, 0x5f, 0x65, 0x78, 0x69, 0x74, 0x5f, 0x63, 0x6f, 0x64, 0x65, 0x18, 0x0d, 0x20, 0x01, 0x28,
+    0x05, 0x52, 0x0c, 0x6c, 0x61, 0x73, 0x74, 0x45, 0x78, 0x69, 0x74, 0x43, 0x6f, 0x64, 0x65, 0x12,
+    0x2f, 0x0a, 0x13, 0x72, 0x65, 0x73, 0x74, 0x61, 0x72, 0x74, 0x5f, 0x69, 0x6e, 0x63, 0x61, 0x72,
+    0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x0e, 0x20, 0x01, 0x28, 0x04, 0x52, 0x12, 0x72, 0x65,
+    0x73, 0x74, 0x61, 0x72, 0x74, 0x49, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x12, 0x20, 0x0a, 0x0b, 0x71, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x69, 0x6e, 0x65, 0x64, 0x18,
+    0x0f, 0x20, 0x03, 0x28, 0x09, 0x52, 0x0b, 0x71, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x69, 0x6e,
+    0x65, 0x64, 0x12, 0x21, 0x0a, 0x0c, 0x64, 0x65, 0x70, 0x61, 0x72, 0x74, 0x65, 0x64, 0x5f, 0x74,
+    0x74, 0x6c, 0x18, 0x10, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x64, 0x65, 0x70, 0x61, 0x72, 0x74,
+    0x65, 0x64, 0x54, 0x74, 0x6c, 0x22, 0xf3, 0x01, 0x0a, 0x0d, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63,
+    0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x12, 0x23, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69,
+    0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c,
+    0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x20, 0x0a, 0x0b,
+    0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1c,
+    0x0a, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28,
+    0x08, 0x52, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x12, 0x16, 0x0a, 0x06,
+    0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x63, 0x6f,
+    0x6e, 0x66, 0x69, 0x67, 0x12, 0x14, 0x0a, 0x05, 0x70, 0x61, 0x74, 0x63, 0x68, 0x18, 0x05, 0x20,
+    0x01, 0x28, 0x08, 0x52, 0x05, 0x70, 0x61, 0x74, 0x63, 0x68, 0x12, 0x1d, 0x0a, 0x0a, 0x6d, 0x65,
+    0x6d, 0x62, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x73, 0x18, 0x06, 0x20, 0x03, 0x28, 0x09, 0x52, 0x09,
+    0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x49, 0x64, 0x73, 0x12, 0x16, 0x0a, 0x06, 0x6c, 0x61, 0x62,
+    0x65, 0x6c, 0x73, 0x18, 0x07, 0x20, 0x03, 0x28, 0x09, 0x52, 0x06, 0x6c, 0x61, 0x62, 0x65, 0x6c,
+    0x73, 0x12, 0x18, 0x0a, 0x07, 0x70, 0x65, 0x72, 0x63, 0x65, 0x6e, 0x74, 0x18, 0x08, 0x20, 0x01,
+    0x28, 0x0d, 0x52, 0x07, 0x70, 0x65, 0x72, 0x63, 0x65, 0x6e, 0x74, 0x22, 0xa2, 0x01, 0x0a, 0x0b,
+    0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x12, 0x23, 0x0a, 0x0d, 0x73,
+    0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x01, 0x20, 0x01,
     0x28, 0x09, 0x52, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x47, 0x72, 0x6f, 0x75, 0x70,
-    0x12, 0x12, 0x0a, 0x04, 0x74, 0x65, 0x72, 0x6d, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x04,
-    0x74, 0x65, 0x72, 0x6d, 0x12, 0x20, 0x0a, 0x0b, 0x73, 0x75, 0x69, 0x74, 0x61, 0x62, 0x69, 0x6c,
-    0x69, 0x74, 0x79, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x73, 0x75, 0x69, 0x74, 0x61,
-    0x62, 0x69, 0x6c, 0x69, 0x74, 0x79, 0x12, 0x28, 0x0a, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73,
-    0x18, 0x05, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x10, 0x2e, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f,
-    0x6e, 0x2e, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73,
-    0x12, 0x14, 0x0a, 0x05, 0x76, 0x6f, 0x74, 0x65, 0x73, 0x18, 0x06, 0x20, 0x03, 0x28, 0x09, 0x52,
-    0x05, 0x76, 0x6f, 0x74, 0x65, 0x73, 0x22, 0x31, 0x0a, 0x06, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73,
-    0x12, 0x0b, 0x0a, 0x07, 0x52, 0x75, 0x6e, 0x6e, 0x69, 0x6e, 0x67, 0x10, 0x01, 0x12, 0x0c, 0x0a,
-    0x08, 0x4e, 0x6f, 0x51, 0x75, 0x6f, 0x72, 0x75, 0x6d, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x46,
-    0x69, 0x6e, 0x69, 0x73, 0x68, 0x65, 0x64, 0x10, 0x03, 0x22, 0xc5, 0x01, 0x0a, 0x07, 0x53, 0x65,
-    0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x1b, 0x0a, 0x09, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x5f,
-    0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72,
-    0x49, 0x64, 0x12, 0x23, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72,
-    0x6f, 0x75, 0x70, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69,
-    0x63, 0x65, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72,
-    0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x69, 0x6e,
-    0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x69,
-    0x74, 0x69, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x18, 0x08, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0b,
-    0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x70,
-    0x6b, 0x67, 0x18, 0x09, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x70, 0x6b, 0x67, 0x12, 0x10, 0x0a,
-    0x03, 0x63, 0x66, 0x67, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x63, 0x66, 0x67, 0x12,
-    0x10, 0x0a, 0x03, 0x73, 0x79, 0x73, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x73, 0x79,
-    0x73, 0x22, 0x8c, 0x01, 0x0a, 0x0d, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43, 0x6f, 0x6e,
-    0x66, 0x69, 0x67, 0x12, 0x23, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67,
-    0x72, 0x6f, 0x75, 0x70, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x73, 0x65, 0x72, 0x76,
-    0x69, 0x63, 0x65, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61,
-    0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x69,
-    0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1c, 0x0a, 0x09, 0x65, 0x6e,
-    0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x09, 0x65,
-    0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x12, 0x16, 0x0a, 0x06, 0x63, 0x6f, 0x6e, 0x66,
-    0x69, 0x67, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67,
-    0x22, 0xa2, 0x01, 0x0a, 0x0b, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65,
+    0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18,
+    0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x12, 0x1c, 0x0a, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18,
+    0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64,
+    0x12, 0x1a, 0x0a, 0x08, 0x66, 0x69, 0x6c, 0x65, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x08, 0x66, 0x69, 0x6c, 0x65, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x12, 0x0a, 0x04,
+    0x62, 0x6f, 0x64, 0x79, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x62, 0x6f, 0x64, 0x79,
+    0x22, 0x7a, 0x0a, 0x0c, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x53, 0x63, 0x61, 0x6c, 0x65,
     0x12, 0x23, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f, 0x75,
     0x70, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
     0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61,
     0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x69, 0x6e, 0x63, 0x61,
-    0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1c, 0x0a, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79,
-    0x70, 0x74, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x09, 0x65, 0x6e, 0x63, 0x72,
-    0x79, 0x70, 0x74, 0x65, 0x64, 0x12, 0x1a, 0x0a, 0x08, 0x66, 0x69, 0x6c, 0x65, 0x6e, 0x61, 0x6d,
-    0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x66, 0x69, 0x6c, 0x65, 0x6e, 0x61, 0x6d,
-    0x65, 0x12, 0x12, 0x0a, 0x04, 0x62, 0x6f, 0x64, 0x79, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52,
-    0x04, 0x62, 0x6f, 0x64, 0x79, 0x22, 0xe3, 0x01, 0x0a, 0x04, 0x53, 0x77, 0x69, 0x6d, 0x12, 0x1e,
-    0x0a, 0x04, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0a, 0x2e, 0x53,
-    0x77, 0x69, 0x6d, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x04, 0x74, 0x79, 0x70, 0x65, 0x12, 0x1b,
-    0x0a, 0x04, 0x70, 0x69, 0x6e, 0x67, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x05, 0x2e, 0x50,
-    0x69, 0x6e, 0x67, 0x48, 0x00, 0x52, 0x04, 0x70, 0x69, 0x6e, 0x67, 0x12, 0x18, 0x0a, 0x03, 0x61,
-    0x63, 0x6b, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x04, 0x2e, 0x41, 0x63, 0x6b, 0x48, 0x00,
-    0x52, 0x03, 0x61, 0x63, 0x6b, 0x12, 0x24, 0x0a, 0x07, 0x70, 0x69, 0x6e, 0x67, 0x72, 0x65, 0x71,
-    0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x08, 0x2e, 0x50, 0x69, 0x6e, 0x67, 0x52, 0x65, 0x71,
-    0x48, 0x00, 0x52, 0x07, 0x70, 0x69, 0x6e, 0x67, 0x72, 0x65, 0x71, 0x12, 0x2b, 0x0a, 0x0a, 0x6d,
-    0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32,
-    0x0b, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x52, 0x0a, 0x6d, 0x65,
-    0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x22, 0x26, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65,
-    0x12, 0x08, 0x0a, 0x04, 0x50, 0x49, 0x4e, 0x47, 0x10, 0x01, 0x12, 0x07, 0x0a, 0x03, 0x41, 0x43,
-    0x4b, 0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07, 0x50, 0x49, 0x4e, 0x47, 0x52, 0x45, 0x51, 0x10, 0x03,
-    0x42, 0x09, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x22, 0xbc, 0x03, 0x0a, 0x05,
-    0x52, 0x75, 0x6d, 0x6f, 0x72, 0x12, 0x1f, 0x0a, 0x04, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20,
-    0x02, 0x28, 0x0e, 0x32, 0x0b, 0x2e, 0x52, 0x75, 0x6d, 0x6f, 0x72, 0x2e, 0x54, 0x79, 0x70, 0x65,
-    0x52, 0x04, 0x74, 0x79, 0x70, 0x65, 0x12, 0x10, 0x0a, 0x03, 0x74, 0x61, 0x67, 0x18, 0x02, 0x20,
-    0x03, 0x28, 0x09, 0x52, 0x03, 0x74, 0x61, 0x67, 0x12, 0x17, 0x0a, 0x07, 0x66, 0x72, 0x6f, 0x6d,
-    0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x66, 0x72, 0x6f, 0x6d, 0x49,
-    0x64, 0x12, 0x25, 0x0a, 0x06, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28,
-    0x0b, 0x32, 0x0b, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x48, 0x00,
-    0x52, 0x06, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x24, 0x0a, 0x07, 0x73, 0x65, 0x72, 0x76,
-    0x69, 0x63, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x08, 0x2e, 0x53, 0x65, 0x72, 0x76,
-    0x69, 0x63, 0x65, 0x48, 0x00, 0x52, 0x07, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x37,
-    0x0a, 0x0e, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67,
-    0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
-    0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x48, 0x00, 0x52, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63,
-    0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x12, 0x31, 0x0a, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69,
-    0x63, 0x65, 0x5f, 0x66, 0x69, 0x6c, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e,
-    0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x48, 0x00, 0x52, 0x0b, 0x73,
-    0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x12, 0x27, 0x0a, 0x08, 0x65, 0x6c,
-    0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x45,
-    0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x08, 0x65, 0x6c, 0x65, 0x63, 0x74,
-    0x69, 0x6f, 0x6e, 0x22, 0x7a, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x0a, 0x0a, 0x06, 0x4d,
-    0x65, 0x6d, 0x62, 0x65, 0x72, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x53, 0x65, 0x72, 0x76, 0x69,
-    0x63, 0x65, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e,
-    0x10, 0x03, 0x12, 0x11, 0x0a, 0x0d, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43, 0x6f, 0x6e,
-    0x66, 0x69, 0x67, 0x10, 0x04, 0x12, 0x0f, 0x0a, 0x0b, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
-    0x46, 0x69, 0x6c, 0x65, 0x10, 0x05, 0x12, 0x08, 0x0a, 0x04, 0x46, 0x61, 0x6b, 0x65, 0x10, 0x06,
-    0x12, 0x09, 0x0a, 0x05, 0x46, 0x61, 0x6b, 0x65, 0x32, 0x10, 0x07, 0x12, 0x12, 0x0a, 0x0e, 0x45,
-    0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x10, 0x08, 0x42,
-    0x09, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x22, 0x54, 0x0a, 0x04, 0x57, 0x69,
-    0x72, 0x65, 0x12, 0x1c, 0x0a, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18,
-    0x01, 0x20, 0x01, 0x28, 0x08, 0x52, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64,
-    0x12, 0x14, 0x0a, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52,
-    0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61,
-    0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64,
-    0x4a, 0xce, 0x25, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x6e, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c,
-    0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x02, 0x00, 0x09,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x02, 0x08, 0x0e, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x03, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x03, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x03, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x03, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x03,
-    0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x04, 0x02, 0x22, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x04, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x04, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x04, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x04, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12,
-    0x03, 0x05, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x05,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x05, 0x0b, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x05, 0x12, 0x19, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x05, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x06, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x03, 0x04, 0x12, 0x03, 0x06, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05,
-    0x12, 0x03, 0x06, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03,
-    0x06, 0x11, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x06, 0x1d,
-    0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x07, 0x02, 0x21, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x04, 0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x04, 0x05, 0x12, 0x03, 0x07, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x04, 0x01, 0x12, 0x03, 0x07, 0x11, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04,
-    0x03, 0x12, 0x03, 0x07, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03,
-    0x08, 0x02, 0x31, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x04, 0x12, 0x03, 0x08, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x05, 0x12, 0x03, 0x08, 0x0b, 0x0f, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x08, 0x10, 0x1a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x05, 0x03, 0x12, 0x03, 0x08, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x05, 0x08, 0x12, 0x03, 0x08, 0x1f, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x05, 0x07, 0x12, 0x03, 0x08, 0x2a, 0x2f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x0b,
-    0x00, 0x0e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0b, 0x08, 0x0c, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0c, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x0c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x00, 0x06, 0x12, 0x03, 0x0c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x0c, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x0c, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x02,
-    0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0d, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12, 0x03, 0x0d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0d, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0d, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12,
-    0x04, 0x10, 0x00, 0x13, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x10, 0x08,
-    0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x11, 0x02, 0x1b, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x11, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x11, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x00, 0x01, 0x12, 0x03, 0x11, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00,
-    0x03, 0x12, 0x03, 0x11, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03,
-    0x12, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x04, 0x12, 0x03, 0x12, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x06, 0x12, 0x03, 0x12, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x12, 0x12, 0x1c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x12, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
-    0x03, 0x12, 0x04, 0x15, 0x00, 0x18, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03,
-    0x15, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x16, 0x02, 0x1b,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x16, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x16, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x16, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x16, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01,
-    0x12, 0x03, 0x17, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03,
-    0x17, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x06, 0x12, 0x03, 0x17, 0x0b,
-    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x17, 0x12, 0x18, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x17, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a,
-    0x02, 0x04, 0x04, 0x12, 0x04, 0x1a, 0x00, 0x1f, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01,
-    0x12, 0x03, 0x1a, 0x08, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x04, 0x00, 0x12, 0x03, 0x1b,
-    0x02, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x04, 0x00, 0x01, 0x12, 0x03, 0x1b, 0x07, 0x0d,
-    0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x1b, 0x10, 0x1a, 0x0a,
-    0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1b, 0x10, 0x15, 0x0a,
-    0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x1b, 0x18, 0x19, 0x0a,
-    0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x1b, 0x1b, 0x27, 0x0a, 0x0e,
-    0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1b, 0x1b, 0x22, 0x0a, 0x0e,
-    0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x1b, 0x25, 0x26, 0x0a, 0x0d,
-    0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x1b, 0x28, 0x36, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1b, 0x28, 0x31, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x1b, 0x34, 0x35, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x1d, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x1d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00,
-    0x06, 0x12, 0x03, 0x1d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x1d, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1d,
-    0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x1e, 0x02, 0x1d, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12, 0x03, 0x1e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12, 0x03, 0x1e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1e, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x1e, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x21,
-    0x00, 0x2a, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x21, 0x08, 0x10, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x05, 0x04, 0x00, 0x12, 0x03, 0x22, 0x02, 0x3a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x05, 0x04, 0x00, 0x01, 0x12, 0x03, 0x22, 0x07, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05,
-    0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x22, 0x10, 0x1c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04,
-    0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x22, 0x10, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04,
-    0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x22, 0x1a, 0x1b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04,
-    0x00, 0x02, 0x01, 0x12, 0x03, 0x22, 0x1d, 0x2a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x22, 0x1d, 0x25, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00,
-    0x02, 0x01, 0x02, 0x12, 0x03, 0x22, 0x28, 0x29, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00,
-    0x02, 0x02, 0x12, 0x03, 0x22, 0x2b, 0x38, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02,
-    0x02, 0x01, 0x12, 0x03, 0x22, 0x2b, 0x33, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02,
-    0x02, 0x02, 0x12, 0x03, 0x22, 0x36, 0x37, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12,
-    0x03, 0x24, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x24,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x24, 0x0b, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x24, 0x12, 0x1b, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x24, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x25, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
-    0x01, 0x04, 0x12, 0x03, 0x25, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x05,
-    0x12, 0x03, 0x25, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03,
-    0x25, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x25, 0x22,
-    0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x02, 0x12, 0x03, 0x26, 0x02, 0x1b, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x04, 0x12, 0x03, 0x26, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x05, 0x02, 0x02, 0x05, 0x12, 0x03, 0x26, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
-    0x02, 0x02, 0x01, 0x12, 0x03, 0x26, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02,
-    0x03, 0x12, 0x03, 0x26, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x03, 0x12, 0x03,
-    0x27, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x04, 0x12, 0x03, 0x27, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x05, 0x12, 0x03, 0x27, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x01, 0x12, 0x03, 0x27, 0x12, 0x1d, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x05, 0x02, 0x03, 0x03, 0x12, 0x03, 0x27, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x05, 0x02, 0x04, 0x12, 0x03, 0x28, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04,
-    0x04, 0x12, 0x03, 0x28, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x06, 0x12,
-    0x03, 0x28, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x01, 0x12, 0x03, 0x28,
-    0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x03, 0x12, 0x03, 0x28, 0x1b, 0x1c,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x05, 0x12, 0x03, 0x29, 0x02, 0x1c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x05, 0x02, 0x05, 0x04, 0x12, 0x03, 0x29, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x05, 0x02, 0x05, 0x05, 0x12, 0x03, 0x29, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
-    0x05, 0x01, 0x12, 0x03, 0x29, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x03,
-    0x12, 0x03, 0x29, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x2c, 0x00, 0x34,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x2c, 0x08, 0x0f, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x2d, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x2d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x2d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x2d, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2d,
-    0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x2e, 0x02, 0x24, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x04, 0x12, 0x03, 0x2e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12, 0x03, 0x2e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x2e, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x2e, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x02, 0x12,
-    0x03, 0x2f, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x04, 0x12, 0x03, 0x2f,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x05, 0x12, 0x03, 0x2f, 0x0b, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x01, 0x12, 0x03, 0x2f, 0x12, 0x1d, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x03, 0x12, 0x03, 0x2f, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x06, 0x02, 0x03, 0x12, 0x03, 0x30, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
-    0x03, 0x04, 0x12, 0x03, 0x30, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x05,
-    0x12, 0x03, 0x30, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x01, 0x12, 0x03,
-    0x30, 0x10, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x03, 0x12, 0x03, 0x30, 0x1e,
-    0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x04, 0x12, 0x03, 0x31, 0x02, 0x1a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x04, 0x12, 0x03, 0x31, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x06, 0x02, 0x04, 0x05, 0x12, 0x03, 0x31, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
-    0x02, 0x04, 0x01, 0x12, 0x03, 0x31, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04,
-    0x03, 0x12, 0x03, 0x31, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x05, 0x12, 0x03,
-    0x32, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x04, 0x12, 0x03, 0x32, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x05, 0x12, 0x03, 0x32, 0x0b, 0x10, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x01, 0x12, 0x03, 0x32, 0x11, 0x14, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x05, 0x03, 0x12, 0x03, 0x32, 0x17, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x06, 0x02, 0x06, 0x12, 0x03, 0x33, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06,
-    0x04, 0x12, 0x03, 0x33, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x05, 0x12,
-    0x03, 0x33, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x01, 0x12, 0x03, 0x33,
-    0x11, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x03, 0x12, 0x03, 0x33, 0x17, 0x19,
-    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x36, 0x00, 0x3b, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x07, 0x01, 0x12, 0x03, 0x36, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00,
-    0x12, 0x03, 0x37, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x03,
-    0x37, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x37, 0x0b,
-    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x37, 0x12, 0x1f, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x37, 0x22, 0x23, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x07, 0x02, 0x01, 0x12, 0x03, 0x38, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
-    0x02, 0x01, 0x04, 0x12, 0x03, 0x38, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01,
-    0x05, 0x12, 0x03, 0x38, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01, 0x12,
-    0x03, 0x38, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03, 0x38,
-    0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12, 0x03, 0x39, 0x02, 0x1e, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x04, 0x12, 0x03, 0x39, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x07, 0x02, 0x02, 0x05, 0x12, 0x03, 0x39, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x07, 0x02, 0x02, 0x01, 0x12, 0x03, 0x39, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
-    0x02, 0x03, 0x12, 0x03, 0x39, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x03, 0x12,
-    0x03, 0x3a, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x04, 0x12, 0x03, 0x3a,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x05, 0x12, 0x03, 0x3a, 0x0b, 0x10,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x01, 0x12, 0x03, 0x3a, 0x11, 0x17, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x03, 0x12, 0x03, 0x3a, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02,
-    0x04, 0x08, 0x12, 0x04, 0x3d, 0x00, 0x43, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12,
-    0x03, 0x3d, 0x08, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x3e, 0x02,
-    0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x3e, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x3e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3e, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3e, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02,
-    0x01, 0x12, 0x03, 0x3f, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x04, 0x12,
-    0x03, 0x3f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x03, 0x3f,
-    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3f, 0x12, 0x1d,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x03, 0x3f, 0x20, 0x21, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x08, 0x02, 0x02, 0x12, 0x03, 0x40, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x08, 0x02, 0x02, 0x04, 0x12, 0x03, 0x40, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
-    0x02, 0x05, 0x12, 0x03, 0x40, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x01,
-    0x12, 0x03, 0x40, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x03, 0x12, 0x03,
-    0x40, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x03, 0x12, 0x03, 0x41, 0x02, 0x1f,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x04, 0x12, 0x03, 0x41, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x05, 0x12, 0x03, 0x41, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x08, 0x02, 0x03, 0x01, 0x12, 0x03, 0x41, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
-    0x02, 0x03, 0x03, 0x12, 0x03, 0x41, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x04,
-    0x12, 0x03, 0x42, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x04, 0x12, 0x03,
-    0x42, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x05, 0x12, 0x03, 0x42, 0x0b,
-    0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x01, 0x12, 0x03, 0x42, 0x11, 0x15, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x03, 0x12, 0x03, 0x42, 0x18, 0x19, 0x0a, 0x0a, 0x0a,
-    0x02, 0x04, 0x09, 0x12, 0x04, 0x45, 0x00, 0x50, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01,
-    0x12, 0x03, 0x45, 0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x04, 0x00, 0x12, 0x03, 0x46,
-    0x02, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x04, 0x00, 0x01, 0x12, 0x03, 0x46, 0x07, 0x0b,
-    0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x46, 0x0e, 0x17, 0x0a,
-    0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x46, 0x0e, 0x12, 0x0a,
-    0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x46, 0x15, 0x16, 0x0a,
-    0x0d, 0x0a, 0x06, 0x04, 0x09, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x46, 0x18, 0x20, 0x0a, 0x0e,
-    0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x46, 0x18, 0x1b, 0x0a, 0x0e,
-    0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x46, 0x1e, 0x1f, 0x0a, 0x0d,
-    0x0a, 0x06, 0x04, 0x09, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x46, 0x21, 0x2d, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x46, 0x21, 0x28, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x46, 0x2b, 0x2c, 0x0a, 0x33, 0x0a,
-    0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x49, 0x02, 0x19, 0x1a, 0x26, 0x20, 0x49, 0x64, 0x65,
-    0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x73, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x66, 0x69,
-    0x65, 0x6c, 0x64, 0x20, 0x69, 0x73, 0x20, 0x66, 0x69, 0x6c, 0x6c, 0x65, 0x64, 0x20, 0x69, 0x6e,
-    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x04, 0x12, 0x03, 0x49, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x06, 0x12, 0x03, 0x49, 0x0b, 0x0f, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12, 0x03, 0x49, 0x10, 0x14, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x03, 0x49, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x09,
-    0x08, 0x00, 0x12, 0x04, 0x4a, 0x02, 0x4e, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x08, 0x00,
-    0x01, 0x12, 0x03, 0x4a, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03,
-    0x4b, 0x04, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x06, 0x12, 0x03, 0x4b, 0x04,
-    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4b, 0x09, 0x0d, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x4b, 0x10, 0x11, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x09, 0x02, 0x02, 0x12, 0x03, 0x4c, 0x04, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
-    0x02, 0x02, 0x06, 0x12, 0x03, 0x4c, 0x04, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02,
-    0x01, 0x12, 0x03, 0x4c, 0x08, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x03, 0x12,
-    0x03, 0x4c, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x03, 0x12, 0x03, 0x4d, 0x04,
-    0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x06, 0x12, 0x03, 0x4d, 0x04, 0x0b, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x01, 0x12, 0x03, 0x4d, 0x0c, 0x13, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x09, 0x02, 0x03, 0x03, 0x12, 0x03, 0x4d, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x09, 0x02, 0x04, 0x12, 0x03, 0x4f, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04,
-    0x04, 0x12, 0x03, 0x4f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x06, 0x12,
-    0x03, 0x4f, 0x0b, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x01, 0x12, 0x03, 0x4f,
-    0x16, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x03, 0x12, 0x03, 0x4f, 0x23, 0x24,
-    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x04, 0x52, 0x00, 0x68, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x0a, 0x01, 0x12, 0x03, 0x52, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0a, 0x04, 0x00,
-    0x12, 0x04, 0x53, 0x02, 0x5c, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x04, 0x00, 0x01, 0x12,
-    0x03, 0x53, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03,
-    0x54, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x54, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03,
-    0x54, 0x0d, 0x0e, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x55,
-    0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x55,
-    0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x55,
-    0x0e, 0x0f, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x56, 0x04,
-    0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x56, 0x04,
-    0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x56, 0x0f,
-    0x10, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x57, 0x04, 0x16,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x57, 0x04, 0x11,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x57, 0x14, 0x15,
-    0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x58, 0x04, 0x14, 0x0a,
-    0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x58, 0x04, 0x0f, 0x0a,
-    0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x58, 0x12, 0x13, 0x0a,
-    0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x59, 0x04, 0x0d, 0x0a, 0x0e,
-    0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x59, 0x04, 0x08, 0x0a, 0x0e,
-    0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x03, 0x59, 0x0b, 0x0c, 0x0a, 0x0d,
-    0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x06, 0x12, 0x03, 0x5a, 0x04, 0x0e, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x5a, 0x04, 0x09, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x06, 0x02, 0x12, 0x03, 0x5a, 0x0c, 0x0d, 0x0a, 0x0d, 0x0a,
-    0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x07, 0x12, 0x03, 0x5b, 0x04, 0x17, 0x0a, 0x0e, 0x0a, 0x07,
-    0x04, 0x0a, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x5b, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07,
-    0x04, 0x0a, 0x04, 0x00, 0x02, 0x07, 0x02, 0x12, 0x03, 0x5b, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x0a, 0x02, 0x00, 0x12, 0x03, 0x5e, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
-    0x00, 0x04, 0x12, 0x03, 0x5e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x06,
-    0x12, 0x03, 0x5e, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x5e, 0x10, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x5e, 0x17,
-    0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x01, 0x12, 0x03, 0x5f, 0x02, 0x1a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x04, 0x12, 0x03, 0x5f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0a, 0x02, 0x01, 0x05, 0x12, 0x03, 0x5f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x5f, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01,
-    0x03, 0x12, 0x03, 0x5f, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x02, 0x12, 0x03,
-    0x60, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x04, 0x12, 0x03, 0x60, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x05, 0x12, 0x03, 0x60, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x01, 0x12, 0x03, 0x60, 0x12, 0x19, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0a, 0x02, 0x02, 0x03, 0x12, 0x03, 0x60, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x0a, 0x08, 0x00, 0x12, 0x04, 0x61, 0x02, 0x67, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x08,
-    0x00, 0x01, 0x12, 0x03, 0x61, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x03, 0x12,
-    0x03, 0x62, 0x04, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x06, 0x12, 0x03, 0x62,
-    0x04, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x03, 0x62, 0x0f, 0x15,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x03, 0x12, 0x03, 0x62, 0x18, 0x19, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x0a, 0x02, 0x04, 0x12, 0x03, 0x63, 0x04, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0a, 0x02, 0x04, 0x06, 0x12, 0x03, 0x63, 0x04, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
-    0x04, 0x01, 0x12, 0x03, 0x63, 0x0c, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x03,
-    0x12, 0x03, 0x63, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x05, 0x12, 0x03, 0x64,
-    0x04, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x06, 0x12, 0x03, 0x64, 0x04, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x03, 0x64, 0x12, 0x20, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x03, 0x12, 0x03, 0x64, 0x23, 0x24, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x0a, 0x02, 0x06, 0x12, 0x03, 0x65, 0x04, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
-    0x06, 0x06, 0x12, 0x03, 0x65, 0x04, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x01,
-    0x12, 0x03, 0x65, 0x10, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x03, 0x12, 0x03,
-    0x65, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x07, 0x12, 0x03, 0x66, 0x04, 0x1a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x07, 0x06, 0x12, 0x03, 0x66, 0x04, 0x0c, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x07, 0x01, 0x12, 0x03, 0x66, 0x0d, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0a, 0x02, 0x07, 0x03, 0x12, 0x03, 0x66, 0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0b,
-    0x12, 0x04, 0x6a, 0x00, 0x6e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x6a,
-    0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x6b, 0x02, 0x1e, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x04, 0x12, 0x03, 0x6b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0b, 0x02, 0x00, 0x05, 0x12, 0x03, 0x6b, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0b, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6b, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02,
-    0x00, 0x03, 0x12, 0x03, 0x6b, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x01, 0x12,
-    0x03, 0x6c, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x04, 0x12, 0x03, 0x6c,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x05, 0x12, 0x03, 0x6c, 0x0b, 0x10,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x01, 0x12, 0x03, 0x6c, 0x11, 0x16, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x03, 0x12, 0x03, 0x6c, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x0b, 0x02, 0x02, 0x12, 0x03, 0x6d, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02,
-    0x02, 0x04, 0x12, 0x03, 0x6d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x05,
-    0x12, 0x03, 0x6d, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x01, 0x12, 0x03,
-    0x6d, 0x11, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12, 0x03, 0x6d, 0x1b,
-    0x1c,
+    0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x23, 0x0a, 0x0d, 0x64, 0x65, 0x73, 0x69, 0x72,
+    0x65, 0x64, 0x5f, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0c,
+    0x64, 0x65, 0x73, 0x69, 0x72, 0x65, 0x64, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x22, 0x5f, 0x0a, 0x0b,
+    0x41, 0x63, 0x74, 0x69, 0x76, 0x65, 0x43, 0x6f, 0x6c, 0x6f, 0x72, 0x12, 0x18, 0x0a, 0x07, 0x73,
+    0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x73, 0x65,
+    0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x69, 0x6e, 0x63, 0x61,
+    0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x14, 0x0a, 0x05, 0x67, 0x72, 0x6f, 0x75, 0x70,
+    0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x22, 0x9e, 0x01,
+    0x0a, 0x0b, 0x52, 0x69, 0x6e, 0x67, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x12, 0x0e, 0x0a,
+    0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x18, 0x0a,
+    0x07, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07,
+    0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x61, 0x72, 0x67, 0x73, 0x18,
+    0x03, 0x20, 0x03, 0x28, 0x09, 0x52, 0x04, 0x61, 0x72, 0x67, 0x73, 0x12, 0x1b, 0x0a, 0x09, 0x69,
+    0x73, 0x73, 0x75, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x03, 0x52, 0x08,
+    0x69, 0x73, 0x73, 0x75, 0x65, 0x64, 0x41, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x69, 0x67, 0x6e,
+    0x65, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72,
+    0x12, 0x1c, 0x0a, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x06, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x22, 0x80,
+    0x01, 0x0a, 0x0e, 0x52, 0x69, 0x6e, 0x67, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x41, 0x63,
+    0x6b, 0x12, 0x1d, 0x0a, 0x0a, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x5f, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x49, 0x64,
+    0x12, 0x1b, 0x0a, 0x09, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x08, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x49, 0x64, 0x12, 0x18, 0x0a,
+    0x07, 0x73, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07,
+    0x73, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x12, 0x18, 0x0a, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61,
+    0x67, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67,
+    0x65, 0x22, 0x8e, 0x01, 0x0a, 0x0e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x52, 0x65, 0x73,
+    0x74, 0x61, 0x72, 0x74, 0x12, 0x23, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f,
+    0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x73, 0x65, 0x72,
+    0x76, 0x69, 0x63, 0x65, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63,
+    0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b,
+    0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x18, 0x0a, 0x07, 0x72,
+    0x6f, 0x6c, 0x6c, 0x69, 0x6e, 0x67, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07, 0x72, 0x6f,
+    0x6c, 0x6c, 0x69, 0x6e, 0x67, 0x12, 0x1b, 0x0a, 0x09, 0x69, 0x73, 0x73, 0x75, 0x65, 0x64, 0x5f,
+    0x61, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x03, 0x52, 0x08, 0x69, 0x73, 0x73, 0x75, 0x65, 0x64,
+    0x41, 0x74, 0x22, 0xa7, 0x01, 0x0a, 0x0a, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4c, 0x6f, 0x63,
+    0x6b, 0x12, 0x23, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f,
+    0x75, 0x70, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63,
+    0x65, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x1b, 0x0a, 0x09, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72,
+    0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x6d, 0x65, 0x6d, 0x62, 0x65,
+    0x72, 0x49, 0x64, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x12, 0x0a, 0x04, 0x68, 0x65, 0x6c, 0x64, 0x18, 0x04, 0x20,
+    0x01, 0x28, 0x08, 0x52, 0x04, 0x68, 0x65, 0x6c, 0x64, 0x12, 0x21, 0x0a, 0x0c, 0x72, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x03, 0x52,
+    0x0b, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x41, 0x74, 0x22, 0xe3, 0x01, 0x0a,
+    0x04, 0x53, 0x77, 0x69, 0x6d, 0x12, 0x1e, 0x0a, 0x04, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x0e, 0x32, 0x0a, 0x2e, 0x53, 0x77, 0x69, 0x6d, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x52,
+    0x04, 0x74, 0x79, 0x70, 0x65, 0x12, 0x1b, 0x0a, 0x04, 0x70, 0x69, 0x6e, 0x67, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x0b, 0x32, 0x05, 0x2e, 0x50, 0x69, 0x6e, 0x67, 0x48, 0x00, 0x52, 0x04, 0x70, 0x69,
+    0x6e, 0x67, 0x12, 0x18, 0x0a, 0x03, 0x61, 0x63, 0x6b, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x04, 0x2e, 0x41, 0x63, 0x6b, 0x48, 0x00, 0x52, 0x03, 0x61, 0x63, 0x6b, 0x12, 0x24, 0x0a, 0x07,
+    0x70, 0x69, 0x6e, 0x67, 0x72, 0x65, 0x71, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x08, 0x2e,
+    0x50, 0x69, 0x6e, 0x67, 0x52, 0x65, 0x71, 0x48, 0x00, 0x52, 0x07, 0x70, 0x69, 0x6e, 0x67, 0x72,
+    0x65, 0x71, 0x12, 0x2b, 0x0a, 0x0a, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70,
+    0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73,
+    0x68, 0x69, 0x70, 0x52, 0x0a, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x22,
+    0x26, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x08, 0x0a, 0x04, 0x50, 0x49, 0x4e, 0x47, 0x10,
+    0x01, 0x12, 0x07, 0x0a, 0x03, 0x41, 0x43, 0x4b, 0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07, 0x50, 0x49,
+    0x4e, 0x47, 0x52, 0x45, 0x51, 0x10, 0x03, 0x42, 0x09, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f,
+    0x61, 0x64, 0x22, 0xee, 0x06, 0x0a, 0x05, 0x52, 0x75, 0x6d, 0x6f, 0x72, 0x12, 0x1f, 0x0a, 0x04,
+    0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0b, 0x2e, 0x52, 0x75, 0x6d,
+    0x6f, 0x72, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x04, 0x74, 0x79, 0x70, 0x65, 0x12, 0x10, 0x0a,
+    0x03, 0x74, 0x61, 0x67, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x52, 0x03, 0x74, 0x61, 0x67, 0x12,
+    0x17, 0x0a, 0x07, 0x66, 0x72, 0x6f, 0x6d, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x06, 0x66, 0x72, 0x6f, 0x6d, 0x49, 0x64, 0x12, 0x25, 0x0a, 0x06, 0x6d, 0x65, 0x6d, 0x62,
+    0x65, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65,
+    0x72, 0x73, 0x68, 0x69, 0x70, 0x48, 0x00, 0x52, 0x06, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x12,
+    0x24, 0x0a, 0x07, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x08, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x48, 0x00, 0x52, 0x07, 0x73, 0x65,
+    0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x37, 0x0a, 0x0e, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
+    0x5f, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e,
+    0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x48, 0x00, 0x52,
+    0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x12, 0x31,
+    0x0a, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x66, 0x69, 0x6c, 0x65, 0x18, 0x07,
+    0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69,
+    0x6c, 0x65, 0x48, 0x00, 0x52, 0x0b, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69, 0x6c,
+    0x65, 0x12, 0x27, 0x0a, 0x08, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x08, 0x20,
+    0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x48, 0x00,
+    0x52, 0x08, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x34, 0x0a, 0x0d, 0x73, 0x65,
+    0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x73, 0x63, 0x61, 0x6c, 0x65, 0x18, 0x09, 0x20, 0x01, 0x28,
+    0x0b, 0x32, 0x0d, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x53, 0x63, 0x61, 0x6c, 0x65,
+    0x48, 0x00, 0x52, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x53, 0x63, 0x61, 0x6c, 0x65,
+    0x12, 0x31, 0x0a, 0x0c, 0x61, 0x63, 0x74, 0x69, 0x76, 0x65, 0x5f, 0x63, 0x6f, 0x6c, 0x6f, 0x72,
+    0x18, 0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x41, 0x63, 0x74, 0x69, 0x76, 0x65, 0x43,
+    0x6f, 0x6c, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x0b, 0x61, 0x63, 0x74, 0x69, 0x76, 0x65, 0x43, 0x6f,
+    0x6c, 0x6f, 0x72, 0x12, 0x31, 0x0a, 0x0c, 0x72, 0x69, 0x6e, 0x67, 0x5f, 0x63, 0x6f, 0x6d, 0x6d,
+    0x61, 0x6e, 0x64, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x52, 0x69, 0x6e, 0x67,
+    0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x48, 0x00, 0x52, 0x0b, 0x72, 0x69, 0x6e, 0x67, 0x43,
+    0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x12, 0x3b, 0x0a, 0x10, 0x72, 0x69, 0x6e, 0x67, 0x5f, 0x63,
+    0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x5f, 0x61, 0x63, 0x6b, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x0f, 0x2e, 0x52, 0x69, 0x6e, 0x67, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x41, 0x63,
+    0x6b, 0x48, 0x00, 0x52, 0x0e, 0x72, 0x69, 0x6e, 0x67, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
+    0x41, 0x63, 0x6b, 0x12, 0x3a, 0x0a, 0x0f, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x72,
+    0x65, 0x73, 0x74, 0x61, 0x72, 0x74, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x53,
+    0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x52, 0x65, 0x73, 0x74, 0x61, 0x72, 0x74, 0x48, 0x00, 0x52,
+    0x0e, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x52, 0x65, 0x73, 0x74, 0x61, 0x72, 0x74, 0x12,
+    0x2e, 0x0a, 0x0b, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x5f, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x0e,
+    0x20, 0x01, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4c, 0x6f, 0x63,
+    0x6b, 0x48, 0x00, 0x52, 0x0a, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4c, 0x6f, 0x63, 0x6b, 0x22,
+    0xe6, 0x01, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x0a, 0x0a, 0x06, 0x4d, 0x65, 0x6d, 0x62,
+    0x65, 0x72, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x10,
+    0x02, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x10, 0x03, 0x12,
+    0x11, 0x0a, 0x0d, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67,
+    0x10, 0x04, 0x12, 0x0f, 0x0a, 0x0b, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69, 0x6c,
+    0x65, 0x10, 0x05, 0x12, 0x08, 0x0a, 0x04, 0x46, 0x61, 0x6b, 0x65, 0x10, 0x06, 0x12, 0x09, 0x0a,
+    0x05, 0x46, 0x61, 0x6b, 0x65, 0x32, 0x10, 0x07, 0x12, 0x12, 0x0a, 0x0e, 0x45, 0x6c, 0x65, 0x63,
+    0x74, 0x69, 0x6f, 0x6e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x10, 0x08, 0x12, 0x10, 0x0a, 0x0c,
+    0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x53, 0x63, 0x61, 0x6c, 0x65, 0x10, 0x09, 0x12, 0x0f,
+    0x0a, 0x0b, 0x41, 0x63, 0x74, 0x69, 0x76, 0x65, 0x43, 0x6f, 0x6c, 0x6f, 0x72, 0x10, 0x0a, 0x12,
+    0x0f, 0x0a, 0x0b, 0x52, 0x69, 0x6e, 0x67, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x10, 0x0b,
+    0x12, 0x12, 0x0a, 0x0e, 0x52, 0x69, 0x6e, 0x67, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x41,
+    0x63, 0x6b, 0x10, 0x0c, 0x12, 0x12, 0x0a, 0x0e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x52,
+    0x65, 0x73, 0x74, 0x61, 0x72, 0x74, 0x10, 0x0d, 0x12, 0x0e, 0x0a, 0x0a, 0x55, 0x70, 0x64, 0x61,
+    0x74, 0x65, 0x4c, 0x6f, 0x63, 0x6b, 0x10, 0x0e, 0x42, 0x09, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c,
+    0x6f, 0x61, 0x64, 0x22, 0x76, 0x0a, 0x04, 0x57, 0x69, 0x72, 0x65, 0x12, 0x1c, 0x0a, 0x09, 0x65,
+    0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x08, 0x52, 0x09,
+    0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x6e, 0x6f, 0x6e,
+    0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x12,
+    0x18, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c,
+    0x52, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x12, 0x20, 0x0a, 0x0b, 0x63, 0x6f, 0x6d,
+    0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b,
+    0x63, 0x6f, 0x6d, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x4a, 0xa7, 0x38, 0x0a, 0x09,
+    0x0a, 0x00, 0x12, 0x05, 0x00, 0x00, 0x9f, 0x01, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03,
+    0x00, 0x00, 0x12, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x02, 0x00, 0x0a, 0x01, 0x0a,
+    0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x02, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x00, 0x02, 0x00, 0x12, 0x03, 0x03, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
+    0x04, 0x12, 0x03, 0x03, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12,
+    0x03, 0x03, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x03,
+    0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x03, 0x17, 0x18,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x04, 0x02, 0x22, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x04, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x04, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x04, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03,
+    0x12, 0x03, 0x04, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x05,
+    0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x05, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x05, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x05, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x05, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00,
+    0x02, 0x03, 0x12, 0x03, 0x06, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x04,
+    0x12, 0x03, 0x06, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03,
+    0x06, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x06, 0x11,
+    0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x06, 0x1d, 0x1e, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x07, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x04, 0x04, 0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x04, 0x05, 0x12, 0x03, 0x07, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04,
+    0x01, 0x12, 0x03, 0x07, 0x11, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12,
+    0x03, 0x07, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x08, 0x02,
+    0x31, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x04, 0x12, 0x03, 0x08, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x05, 0x12, 0x03, 0x08, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x08, 0x10, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x05, 0x03, 0x12, 0x03, 0x08, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x05, 0x08, 0x12, 0x03, 0x08, 0x1f, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x07,
+    0x12, 0x03, 0x08, 0x2a, 0x2f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x06, 0x12, 0x03, 0x09,
+    0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x04, 0x12, 0x03, 0x09, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x05, 0x12, 0x03, 0x09, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x09, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x06, 0x03, 0x12, 0x03, 0x09, 0x20, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01,
+    0x12, 0x04, 0x0c, 0x00, 0x0f, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0c,
+    0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0d, 0x02, 0x1b, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x0d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x0d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0d, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x0d, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12,
+    0x03, 0x0e, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0e,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12, 0x03, 0x0e, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0e, 0x12, 0x1c, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0e, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x02, 0x12, 0x04, 0x11, 0x00, 0x14, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12,
+    0x03, 0x11, 0x08, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x12, 0x02,
+    0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x12, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x12, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x12, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x12, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x13, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x04, 0x12,
+    0x03, 0x13, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x06, 0x12, 0x03, 0x13,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x13, 0x12, 0x1c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x13, 0x1f, 0x20, 0x0a, 0x0a,
+    0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x16, 0x00, 0x19, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03,
+    0x01, 0x12, 0x03, 0x16, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03,
+    0x17, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x17, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x17, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x17, 0x12, 0x16, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x17, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x03, 0x02, 0x01, 0x12, 0x03, 0x18, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01,
+    0x04, 0x12, 0x03, 0x18, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x06, 0x12,
+    0x03, 0x18, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x18,
+    0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x18, 0x1b, 0x1c,
+    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x1b, 0x00, 0x20, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x04, 0x01, 0x12, 0x03, 0x1b, 0x08, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x04, 0x00,
+    0x12, 0x03, 0x1c, 0x02, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x04, 0x00, 0x01, 0x12, 0x03,
+    0x1c, 0x07, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x1c,
+    0x10, 0x1a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1c,
+    0x10, 0x15, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x1c,
+    0x18, 0x19, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x1c, 0x1b,
+    0x27, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1c, 0x1b,
+    0x22, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x1c, 0x25,
+    0x26, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x1c, 0x28, 0x36,
+    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1c, 0x28, 0x31,
+    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x1c, 0x34, 0x35,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x1e, 0x02, 0x1d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x1e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x04, 0x02, 0x00, 0x06, 0x12, 0x03, 0x1e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x1e, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x1e, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x1f,
+    0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12, 0x03, 0x1f, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12, 0x03, 0x1f, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1f, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1f, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x05,
+    0x12, 0x04, 0x22, 0x00, 0x2b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x22,
+    0x08, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x04, 0x00, 0x12, 0x03, 0x23, 0x02, 0x3a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x04, 0x00, 0x01, 0x12, 0x03, 0x23, 0x07, 0x0d, 0x0a, 0x0d, 0x0a,
+    0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x23, 0x10, 0x1c, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x23, 0x10, 0x17, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x23, 0x1a, 0x1b, 0x0a, 0x0d, 0x0a, 0x06,
+    0x04, 0x05, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x23, 0x1d, 0x2a, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x23, 0x1d, 0x25, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x05, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x23, 0x28, 0x29, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
+    0x05, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x23, 0x2b, 0x38, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05,
+    0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x23, 0x2b, 0x33, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05,
+    0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x23, 0x36, 0x37, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05,
+    0x02, 0x00, 0x12, 0x03, 0x25, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04,
+    0x12, 0x03, 0x25, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x25, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x25, 0x12,
+    0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x25, 0x1e, 0x1f, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x26, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x01, 0x04, 0x12, 0x03, 0x26, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x01, 0x05, 0x12, 0x03, 0x26, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x26, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x26, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x02, 0x12, 0x03, 0x27, 0x02,
+    0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x04, 0x12, 0x03, 0x27, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x05, 0x12, 0x03, 0x27, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x27, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x27, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02,
+    0x03, 0x12, 0x03, 0x28, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x04, 0x12,
+    0x03, 0x28, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x05, 0x12, 0x03, 0x28,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x01, 0x12, 0x03, 0x28, 0x12, 0x1d,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x03, 0x12, 0x03, 0x28, 0x20, 0x21, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x05, 0x02, 0x04, 0x12, 0x03, 0x29, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x04, 0x04, 0x12, 0x03, 0x29, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x04, 0x06, 0x12, 0x03, 0x29, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x01,
+    0x12, 0x03, 0x29, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x03, 0x12, 0x03,
+    0x29, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x05, 0x12, 0x03, 0x2a, 0x02, 0x1c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x04, 0x12, 0x03, 0x2a, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x05, 0x12, 0x03, 0x2a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x05, 0x01, 0x12, 0x03, 0x2a, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x05, 0x03, 0x12, 0x03, 0x2a, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04,
+    0x2d, 0x00, 0x37, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x2d, 0x08, 0x0f,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x2e, 0x02, 0x20, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x00, 0x04, 0x12, 0x03, 0x2e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x2e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x2e, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x2e, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x2f,
+    0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x04, 0x12, 0x03, 0x2f, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12, 0x03, 0x2f, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x2f, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x2f, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06,
+    0x02, 0x02, 0x12, 0x03, 0x30, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x04,
+    0x12, 0x03, 0x30, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x05, 0x12, 0x03,
+    0x30, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x01, 0x12, 0x03, 0x30, 0x12,
+    0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x03, 0x12, 0x03, 0x30, 0x20, 0x21, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x03, 0x12, 0x03, 0x31, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x03, 0x04, 0x12, 0x03, 0x31, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x03, 0x05, 0x12, 0x03, 0x31, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03,
+    0x01, 0x12, 0x03, 0x31, 0x10, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x03, 0x12,
+    0x03, 0x31, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x04, 0x12, 0x03, 0x32, 0x02,
+    0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x04, 0x12, 0x03, 0x32, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x05, 0x12, 0x03, 0x32, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x04, 0x01, 0x12, 0x03, 0x32, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x04, 0x03, 0x12, 0x03, 0x32, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02,
+    0x05, 0x12, 0x03, 0x33, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x04, 0x12,
+    0x03, 0x33, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x05, 0x12, 0x03, 0x33,
+    0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x01, 0x12, 0x03, 0x33, 0x11, 0x14,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x03, 0x12, 0x03, 0x33, 0x17, 0x19, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x06, 0x02, 0x06, 0x12, 0x03, 0x34, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x06, 0x04, 0x12, 0x03, 0x34, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x06, 0x05, 0x12, 0x03, 0x34, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x01,
+    0x12, 0x03, 0x34, 0x11, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x03, 0x12, 0x03,
+    0x34, 0x17, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x07, 0x12, 0x03, 0x35, 0x02, 0x25,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x07, 0x04, 0x12, 0x03, 0x35, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x07, 0x05, 0x12, 0x03, 0x35, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x07, 0x01, 0x12, 0x03, 0x35, 0x11, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x07, 0x03, 0x12, 0x03, 0x35, 0x22, 0x24, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x08,
+    0x12, 0x03, 0x36, 0x02, 0x2b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x04, 0x12, 0x03,
+    0x36, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x05, 0x12, 0x03, 0x36, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x01, 0x12, 0x03, 0x36, 0x12, 0x25, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x03, 0x12, 0x03, 0x36, 0x28, 0x2a, 0x0a, 0x0a, 0x0a,
+    0x02, 0x04, 0x07, 0x12, 0x04, 0x39, 0x00, 0x3e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01,
+    0x12, 0x03, 0x39, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x3a,
+    0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x03, 0x3a, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x3a, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3a, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3a, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07,
+    0x02, 0x01, 0x12, 0x03, 0x3b, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x04,
+    0x12, 0x03, 0x3b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x05, 0x12, 0x03,
+    0x3b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3b, 0x12,
+    0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03, 0x3b, 0x20, 0x21, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12, 0x03, 0x3c, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x02, 0x04, 0x12, 0x03, 0x3c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x02, 0x05, 0x12, 0x03, 0x3c, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x3c, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x03, 0x12,
+    0x03, 0x3c, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x03, 0x12, 0x03, 0x3d, 0x02,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x04, 0x12, 0x03, 0x3d, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x05, 0x12, 0x03, 0x3d, 0x0b, 0x10, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x07, 0x02, 0x03, 0x01, 0x12, 0x03, 0x3d, 0x11, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x07, 0x02, 0x03, 0x03, 0x12, 0x03, 0x3d, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x08, 0x12,
+    0x04, 0x40, 0x00, 0x46, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x40, 0x08,
+    0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x41, 0x02, 0x24, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x41, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x41, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x41, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x41, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x03,
+    0x42, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x04, 0x12, 0x03, 0x42, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x03, 0x42, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03, 0x42, 0x12, 0x1d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x03, 0x42, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x08, 0x02, 0x02, 0x12, 0x03, 0x43, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02,
+    0x04, 0x12, 0x03, 0x43, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x05, 0x12,
+    0x03, 0x43, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x01, 0x12, 0x03, 0x43,
+    0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x03, 0x12, 0x03, 0x43, 0x1c, 0x1d,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x03, 0x12, 0x03, 0x44, 0x02, 0x1f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x03, 0x04, 0x12, 0x03, 0x44, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x03, 0x05, 0x12, 0x03, 0x44, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x03, 0x01, 0x12, 0x03, 0x44, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x03,
+    0x12, 0x03, 0x44, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x04, 0x12, 0x03, 0x45,
+    0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x04, 0x12, 0x03, 0x45, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x05, 0x12, 0x03, 0x45, 0x0b, 0x10, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x01, 0x12, 0x03, 0x45, 0x11, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x04, 0x03, 0x12, 0x03, 0x45, 0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x09,
+    0x12, 0x04, 0x48, 0x00, 0x4c, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x48,
+    0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x49, 0x02, 0x24, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x04, 0x12, 0x03, 0x49, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x00, 0x05, 0x12, 0x03, 0x49, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x00, 0x01, 0x12, 0x03, 0x49, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x49, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12,
+    0x03, 0x4a, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x04, 0x12, 0x03, 0x4a,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x05, 0x12, 0x03, 0x4a, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4a, 0x12, 0x1d, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x4a, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x09, 0x02, 0x02, 0x12, 0x03, 0x4b, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x02, 0x04, 0x12, 0x03, 0x4b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x05,
+    0x12, 0x03, 0x4b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x4b, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x03, 0x12, 0x03, 0x4b, 0x22,
+    0x23, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x04, 0x4e, 0x00, 0x52, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x4e, 0x08, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02,
+    0x00, 0x12, 0x03, 0x4f, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x04, 0x12,
+    0x03, 0x4f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x05, 0x12, 0x03, 0x4f,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4f, 0x12, 0x19,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4f, 0x1c, 0x1d, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x0a, 0x02, 0x01, 0x12, 0x03, 0x50, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x01, 0x04, 0x12, 0x03, 0x50, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
+    0x01, 0x05, 0x12, 0x03, 0x50, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x50, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x03, 0x12, 0x03,
+    0x50, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x02, 0x12, 0x03, 0x51, 0x02, 0x1c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x04, 0x12, 0x03, 0x51, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x05, 0x12, 0x03, 0x51, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x02, 0x01, 0x12, 0x03, 0x51, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x02, 0x03, 0x12, 0x03, 0x51, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x04,
+    0x54, 0x00, 0x5b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x54, 0x08, 0x13,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x55, 0x02, 0x19, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0b, 0x02, 0x00, 0x04, 0x12, 0x03, 0x55, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0b, 0x02, 0x00, 0x05, 0x12, 0x03, 0x55, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x55, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x55, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x01, 0x12, 0x03, 0x56,
+    0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x04, 0x12, 0x03, 0x56, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x05, 0x12, 0x03, 0x56, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x01, 0x12, 0x03, 0x56, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0b, 0x02, 0x01, 0x03, 0x12, 0x03, 0x56, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b,
+    0x02, 0x02, 0x12, 0x03, 0x57, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x04,
+    0x12, 0x03, 0x57, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x05, 0x12, 0x03,
+    0x57, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x01, 0x12, 0x03, 0x57, 0x12,
+    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12, 0x03, 0x57, 0x19, 0x1a, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x03, 0x12, 0x03, 0x58, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0b, 0x02, 0x03, 0x04, 0x12, 0x03, 0x58, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b,
+    0x02, 0x03, 0x05, 0x12, 0x03, 0x58, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x03,
+    0x01, 0x12, 0x03, 0x58, 0x11, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x03, 0x03, 0x12,
+    0x03, 0x58, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x04, 0x12, 0x03, 0x59, 0x02,
+    0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x04, 0x04, 0x12, 0x03, 0x59, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x04, 0x05, 0x12, 0x03, 0x59, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0b, 0x02, 0x04, 0x01, 0x12, 0x03, 0x59, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0b, 0x02, 0x04, 0x03, 0x12, 0x03, 0x59, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02,
+    0x05, 0x12, 0x03, 0x5a, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x05, 0x04, 0x12,
+    0x03, 0x5a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x05, 0x05, 0x12, 0x03, 0x5a,
+    0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x05, 0x01, 0x12, 0x03, 0x5a, 0x11, 0x1a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x05, 0x03, 0x12, 0x03, 0x5a, 0x1d, 0x1e, 0x0a, 0x0a,
+    0x0a, 0x02, 0x04, 0x0c, 0x12, 0x04, 0x5d, 0x00, 0x62, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0c,
+    0x01, 0x12, 0x03, 0x5d, 0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x00, 0x12, 0x03,
+    0x5e, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x04, 0x12, 0x03, 0x5e, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x05, 0x12, 0x03, 0x5e, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x01, 0x12, 0x03, 0x5e, 0x12, 0x1c, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0c, 0x02, 0x00, 0x03, 0x12, 0x03, 0x5e, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x0c, 0x02, 0x01, 0x12, 0x03, 0x5f, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01,
+    0x04, 0x12, 0x03, 0x5f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x05, 0x12,
+    0x03, 0x5f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5f,
+    0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x03, 0x12, 0x03, 0x5f, 0x1e, 0x1f,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x02, 0x12, 0x03, 0x60, 0x02, 0x1c, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0c, 0x02, 0x02, 0x04, 0x12, 0x03, 0x60, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0c, 0x02, 0x02, 0x05, 0x12, 0x03, 0x60, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x60, 0x10, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x02, 0x03,
+    0x12, 0x03, 0x60, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x03, 0x12, 0x03, 0x61,
+    0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x03, 0x04, 0x12, 0x03, 0x61, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x03, 0x05, 0x12, 0x03, 0x61, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0c, 0x02, 0x03, 0x01, 0x12, 0x03, 0x61, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0c, 0x02, 0x03, 0x03, 0x12, 0x03, 0x61, 0x1c, 0x1d, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0d,
+    0x12, 0x04, 0x64, 0x00, 0x69, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x03, 0x64,
+    0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x00, 0x12, 0x03, 0x65, 0x02, 0x24, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x04, 0x12, 0x03, 0x65, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0d, 0x02, 0x00, 0x05, 0x12, 0x03, 0x65, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0d, 0x02, 0x00, 0x01, 0x12, 0x03, 0x65, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x65, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x01, 0x12,
+    0x03, 0x66, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x04, 0x12, 0x03, 0x66,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x05, 0x12, 0x03, 0x66, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x01, 0x12, 0x03, 0x66, 0x12, 0x1d, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x03, 0x12, 0x03, 0x66, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x0d, 0x02, 0x02, 0x12, 0x03, 0x67, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02,
+    0x02, 0x04, 0x12, 0x03, 0x67, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x05,
+    0x12, 0x03, 0x67, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x67, 0x10, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x03, 0x12, 0x03, 0x67, 0x1a,
+    0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x03, 0x12, 0x03, 0x68, 0x02, 0x1f, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x04, 0x12, 0x03, 0x68, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0d, 0x02, 0x03, 0x05, 0x12, 0x03, 0x68, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d,
+    0x02, 0x03, 0x01, 0x12, 0x03, 0x68, 0x11, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03,
+    0x03, 0x12, 0x03, 0x68, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x04, 0x6b, 0x00,
+    0x76, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x03, 0x6b, 0x08, 0x0c, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x0e, 0x04, 0x00, 0x12, 0x03, 0x6c, 0x02, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0e, 0x04, 0x00, 0x01, 0x12, 0x03, 0x6c, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0e, 0x04,
+    0x00, 0x02, 0x00, 0x12, 0x03, 0x6c, 0x0e, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x6c, 0x0e, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00,
+    0x02, 0x00, 0x02, 0x12, 0x03, 0x6c, 0x15, 0x16, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0e, 0x04, 0x00,
+    0x02, 0x01, 0x12, 0x03, 0x6c, 0x18, 0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x6c, 0x18, 0x1b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02,
+    0x01, 0x02, 0x12, 0x03, 0x6c, 0x1e, 0x1f, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0e, 0x04, 0x00, 0x02,
+    0x02, 0x12, 0x03, 0x6c, 0x21, 0x2d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x6c, 0x21, 0x28, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x02,
+    0x02, 0x12, 0x03, 0x6c, 0x2b, 0x2c, 0x0a, 0x33, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x00, 0x12, 0x03,
+    0x6f, 0x02, 0x19, 0x1a, 0x26, 0x20, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x73,
+    0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x20, 0x69, 0x73, 0x20,
+    0x66, 0x69, 0x6c, 0x6c, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0e, 0x02, 0x00, 0x04, 0x12, 0x03, 0x6f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x00, 0x06, 0x12, 0x03, 0x6f, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x6f, 0x10, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x6f, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x08, 0x00, 0x12, 0x04, 0x70, 0x02, 0x74,
+    0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x08, 0x00, 0x01, 0x12, 0x03, 0x70, 0x08, 0x0f, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x01, 0x12, 0x03, 0x71, 0x04, 0x12, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0e, 0x02, 0x01, 0x06, 0x12, 0x03, 0x71, 0x04, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x71, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01,
+    0x03, 0x12, 0x03, 0x71, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x02, 0x12, 0x03,
+    0x72, 0x04, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x06, 0x12, 0x03, 0x72, 0x04,
+    0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x01, 0x12, 0x03, 0x72, 0x08, 0x0b, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x03, 0x12, 0x03, 0x72, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x0e, 0x02, 0x03, 0x12, 0x03, 0x73, 0x04, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x03, 0x06, 0x12, 0x03, 0x73, 0x04, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03,
+    0x01, 0x12, 0x03, 0x73, 0x0c, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x03, 0x12,
+    0x03, 0x73, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x04, 0x12, 0x03, 0x75, 0x02,
+    0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04, 0x04, 0x12, 0x03, 0x75, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04, 0x06, 0x12, 0x03, 0x75, 0x0b, 0x15, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0e, 0x02, 0x04, 0x01, 0x12, 0x03, 0x75, 0x16, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0e, 0x02, 0x04, 0x03, 0x12, 0x03, 0x75, 0x23, 0x24, 0x0a, 0x0b, 0x0a, 0x02, 0x04, 0x0f, 0x12,
+    0x05, 0x78, 0x00, 0x98, 0x01, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0f, 0x01, 0x12, 0x03, 0x78,
+    0x08, 0x0d, 0x0a, 0x0d, 0x0a, 0x04, 0x04, 0x0f, 0x04, 0x00, 0x12, 0x05, 0x79, 0x02, 0x87, 0x01,
+    0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x04, 0x00, 0x01, 0x12, 0x03, 0x79, 0x07, 0x0b, 0x0a,
+    0x0d, 0x0a, 0x06, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x7a, 0x04, 0x0f, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x7a, 0x04, 0x0a, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x7a, 0x0d, 0x0e, 0x0a, 0x0d,
+    0x0a, 0x06, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x7b, 0x04, 0x10, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x7b, 0x04, 0x0b, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x7b, 0x0e, 0x0f, 0x0a, 0x0d, 0x0a,
+    0x06, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x7c, 0x04, 0x11, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x0f, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x7c, 0x04, 0x0c, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x0f, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x7c, 0x0f, 0x10, 0x0a, 0x0d, 0x0a, 0x06,
+    0x04, 0x0f, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x7d, 0x04, 0x16, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x0f, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x7d, 0x04, 0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x0f, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x7d, 0x14, 0x15, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
+    0x0f, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x7e, 0x04, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0f,
+    0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x7e, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0f,
+    0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x7e, 0x12, 0x13, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0f,
+    0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x7f, 0x04, 0x0d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0f, 0x04,
+    0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x7f, 0x04, 0x08, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0f, 0x04,
+    0x00, 0x02, 0x05, 0x02, 0x12, 0x03, 0x7f, 0x0b, 0x0c, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0f, 0x04,
+    0x00, 0x02, 0x06, 0x12, 0x04, 0x80, 0x01, 0x04, 0x0e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04,
+    0x00, 0x02, 0x06, 0x01, 0x12, 0x04, 0x80, 0x01, 0x04, 0x09, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f,
+    0x04, 0x00, 0x02, 0x06, 0x02, 0x12, 0x04, 0x80, 0x01, 0x0c, 0x0d, 0x0a, 0x0e, 0x0a, 0x06, 0x04,
+    0x0f, 0x04, 0x00, 0x02, 0x07, 0x12, 0x04, 0x81, 0x01, 0x04, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x0f, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x04, 0x81, 0x01, 0x04, 0x12, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x0f, 0x04, 0x00, 0x02, 0x07, 0x02, 0x12, 0x04, 0x81, 0x01, 0x15, 0x16, 0x0a, 0x0e, 0x0a,
+    0x06, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x08, 0x12, 0x04, 0x82, 0x01, 0x04, 0x15, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x08, 0x01, 0x12, 0x04, 0x82, 0x01, 0x04, 0x10, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x08, 0x02, 0x12, 0x04, 0x82, 0x01, 0x13, 0x14, 0x0a,
+    0x0e, 0x0a, 0x06, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x09, 0x12, 0x04, 0x83, 0x01, 0x04, 0x15, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x09, 0x01, 0x12, 0x04, 0x83, 0x01, 0x04, 0x0f,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x09, 0x02, 0x12, 0x04, 0x83, 0x01, 0x12,
+    0x14, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x0a, 0x12, 0x04, 0x84, 0x01, 0x04,
+    0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x0a, 0x01, 0x12, 0x04, 0x84, 0x01,
+    0x04, 0x0f, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x0a, 0x02, 0x12, 0x04, 0x84,
+    0x01, 0x12, 0x14, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x0b, 0x12, 0x04, 0x85,
+    0x01, 0x04, 0x18, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x0b, 0x01, 0x12, 0x04,
+    0x85, 0x01, 0x04, 0x12, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x0b, 0x02, 0x12,
+    0x04, 0x85, 0x01, 0x15, 0x17, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x0c, 0x12,
+    0x04, 0x86, 0x01, 0x04, 0x18, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x0c, 0x01,
+    0x12, 0x04, 0x86, 0x01, 0x04, 0x12, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x0c,
+    0x02, 0x12, 0x04, 0x86, 0x01, 0x15, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12,
+    0x04, 0x89, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x04, 0x12, 0x04,
+    0x89, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x06, 0x12, 0x04, 0x89,
+    0x01, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x01, 0x12, 0x04, 0x89, 0x01,
+    0x10, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x03, 0x12, 0x04, 0x89, 0x01, 0x17,
+    0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x01, 0x12, 0x04, 0x8a, 0x01, 0x02, 0x1a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x04, 0x12, 0x04, 0x8a, 0x01, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x05, 0x12, 0x04, 0x8a, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0f, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8a, 0x01, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0f, 0x02, 0x01, 0x03, 0x12, 0x04, 0x8a, 0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x0f, 0x02, 0x02, 0x12, 0x04, 0x8b, 0x01, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02,
+    0x02, 0x04, 0x12, 0x04, 0x8b, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02,
+    0x05, 0x12, 0x04, 0x8b, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x01,
+    0x12, 0x04, 0x8b, 0x01, 0x12, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x03, 0x12,
+    0x04, 0x8b, 0x01, 0x1c, 0x1d, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x0f, 0x08, 0x00, 0x12, 0x06, 0x8c,
+    0x01, 0x02, 0x97, 0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x08, 0x00, 0x01, 0x12, 0x04,
+    0x8c, 0x01, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x03, 0x12, 0x04, 0x8d, 0x01,
+    0x04, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x06, 0x12, 0x04, 0x8d, 0x01, 0x04,
+    0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x01, 0x12, 0x04, 0x8d, 0x01, 0x0f, 0x15,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x03, 0x12, 0x04, 0x8d, 0x01, 0x18, 0x19, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x04, 0x12, 0x04, 0x8e, 0x01, 0x04, 0x18, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0f, 0x02, 0x04, 0x06, 0x12, 0x04, 0x8e, 0x01, 0x04, 0x0b, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0f, 0x02, 0x04, 0x01, 0x12, 0x04, 0x8e, 0x01, 0x0c, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0f, 0x02, 0x04, 0x03, 0x12, 0x04, 0x8e, 0x01, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f,
+    0x02, 0x05, 0x12, 0x04, 0x8f, 0x01, 0x04, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x05,
+    0x06, 0x12, 0x04, 0x8f, 0x01, 0x04, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x05, 0x01,
+    0x12, 0x04, 0x8f, 0x01, 0x12, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x05, 0x03, 0x12,
+    0x04, 0x8f, 0x01, 0x23, 0x24, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x06, 0x12, 0x04, 0x90,
+    0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x06, 0x06, 0x12, 0x04, 0x90, 0x01,
+    0x04, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x06, 0x01, 0x12, 0x04, 0x90, 0x01, 0x10,
+    0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x06, 0x03, 0x12, 0x04, 0x90, 0x01, 0x1f, 0x20,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x07, 0x12, 0x04, 0x91, 0x01, 0x04, 0x1a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0f, 0x02, 0x07, 0x06, 0x12, 0x04, 0x91, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0f, 0x02, 0x07, 0x01, 0x12, 0x04, 0x91, 0x01, 0x0d, 0x15, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0f, 0x02, 0x07, 0x03, 0x12, 0x04, 0x91, 0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x0f, 0x02, 0x08, 0x12, 0x04, 0x92, 0x01, 0x04, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02,
+    0x08, 0x06, 0x12, 0x04, 0x92, 0x01, 0x04, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x08,
+    0x01, 0x12, 0x04, 0x92, 0x01, 0x11, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x08, 0x03,
+    0x12, 0x04, 0x92, 0x01, 0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x09, 0x12, 0x04,
+    0x93, 0x01, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x09, 0x06, 0x12, 0x04, 0x93,
+    0x01, 0x04, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x09, 0x01, 0x12, 0x04, 0x93, 0x01,
+    0x10, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x09, 0x03, 0x12, 0x04, 0x93, 0x01, 0x1f,
+    0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x0a, 0x12, 0x04, 0x94, 0x01, 0x04, 0x22, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x0a, 0x06, 0x12, 0x04, 0x94, 0x01, 0x04, 0x0f, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0f, 0x02, 0x0a, 0x01, 0x12, 0x04, 0x94, 0x01, 0x10, 0x1c, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0f, 0x02, 0x0a, 0x03, 0x12, 0x04, 0x94, 0x01, 0x1f, 0x21, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x0f, 0x02, 0x0b, 0x12, 0x04, 0x95, 0x01, 0x04, 0x29, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f,
+    0x02, 0x0b, 0x06, 0x12, 0x04, 0x95, 0x01, 0x04, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02,
+    0x0b, 0x01, 0x12, 0x04, 0x95, 0x01, 0x13, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x0b,
+    0x03, 0x12, 0x04, 0x95, 0x01, 0x26, 0x28, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x0c, 0x12,
+    0x04, 0x96, 0x01, 0x04, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x0c, 0x06, 0x12, 0x04,
+    0x96, 0x01, 0x04, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x0c, 0x01, 0x12, 0x04, 0x96,
+    0x01, 0x13, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x0c, 0x03, 0x12, 0x04, 0x96, 0x01,
+    0x25, 0x27, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x10, 0x12, 0x06, 0x9a, 0x01, 0x00, 0x9f, 0x01, 0x01,
+    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x10, 0x01, 0x12, 0x04, 0x9a, 0x01, 0x08, 0x0c, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x10, 0x02, 0x00, 0x12, 0x04, 0x9b, 0x01, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x10, 0x02, 0x00, 0x04, 0x12, 0x04, 0x9b, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10,
+    0x02, 0x00, 0x05, 0x12, 0x04, 0x9b, 0x01, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0x9b, 0x01, 0x10, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0x9b, 0x01, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x01, 0x12,
+    0x04, 0x9c, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x04, 0x12, 0x04,
+    0x9c, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x05, 0x12, 0x04, 0x9c,
+    0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x01, 0x12, 0x04, 0x9c, 0x01,
+    0x11, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x03, 0x12, 0x04, 0x9c, 0x01, 0x19,
+    0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x02, 0x12, 0x04, 0x9d, 0x01, 0x02, 0x1d, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x04, 0x12, 0x04, 0x9d, 0x01, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x05, 0x12, 0x04, 0x9d, 0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x10, 0x02, 0x02, 0x01, 0x12, 0x04, 0x9d, 0x01, 0x11, 0x18, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x10, 0x02, 0x02, 0x03, 0x12, 0x04, 0x9d, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x10, 0x02, 0x03, 0x12, 0x04, 0x9e, 0x01, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02,
+    0x03, 0x04, 0x12, 0x04, 0x9e, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x03,
+    0x05, 0x12, 0x04, 0x9e, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x01,
+    0x12, 0x04, 0x9e, 0x01, 0x12, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x03, 0x12,
+    0x04, 0x9e, 0x01, 0x20, 0x21,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -0,0 +1,184 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The ActiveColor rumor.
+//!
+//! Holds which group of a service is live when two groups of it run side by side, such as
+//! `app.blue` and `app.green`, so binds and templates can follow traffic as it flips between them.
+//! The rumor is keyed by the service, with its organization if it has one (ex: `app@acme`).
+
+use std::cmp::Ordering;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+
+use protobuf::Message;
+
+use error::Result;
+use message::swim::{ActiveColor as ProtoActiveColor, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ActiveColor(ProtoRumor);
+
+impl PartialOrd for ActiveColor {
+    fn partial_cmp(&self, other: &ActiveColor) -> Option<Ordering> {
+        if self.get_service() != other.get_service() {
+            None
+        } else {
+            Some(self.get_incarnation().cmp(&other.get_incarnation()))
+        }
+    }
+}
+
+impl PartialEq for ActiveColor {
+    fn eq(&self, other: &ActiveColor) -> bool {
+        self.get_service() == other.get_service() &&
+        self.get_incarnation() == other.get_incarnation() &&
+        self.get_group() == other.get_group()
+    }
+}
+
+impl From<ProtoRumor> for ActiveColor {
+    fn from(pr: ProtoRumor) -> ActiveColor {
+        ActiveColor(pr)
+    }
+}
+
+impl From<ActiveColor> for ProtoRumor {
+    fn from(active_color: ActiveColor) -> ProtoRumor {
+        active_color.0
+    }
+}
+
+impl Deref for ActiveColor {
+    type Target = ProtoActiveColor;
+
+    fn deref(&self) -> &ProtoActiveColor {
+        self.0.get_active_color()
+    }
+}
+
+impl DerefMut for ActiveColor {
+    fn deref_mut(&mut self) -> &mut ProtoActiveColor {
+        self.0.mut_active_color()
+    }
+}
+
+impl ActiveColor {
+    /// Creates a new ActiveColor, making `group` the live group of `service`.
+    pub fn new<S1, S2, S3>(member_id: S1, service: S2, group: S3) -> Self
+        where S1: Into<String>,
+              S2: Into<String>,
+              S3: Into<String>
+    {
+        let mut rumor = ProtoRumor::new();
+        let from_id = member_id.into();
+        rumor.set_from_id(from_id);
+        rumor.set_field_type(ProtoRumor_Type::ActiveColor);
+
+        let mut proto = ProtoActiveColor::new();
+        proto.set_service(service.into());
+        proto.set_incarnation(0);
+        proto.set_group(group.into());
+
+        rumor.set_active_color(proto);
+        ActiveColor(rumor)
+    }
+}
+
+impl Rumor for ActiveColor {
+    /// Follows a simple pattern; if we have a newer incarnation than the one we already have, the
+    /// new one wins.
+    fn merge(&mut self, mut other: ActiveColor) -> bool {
+        if *self >= other {
+            false
+        } else {
+            mem::swap(self, &mut other);
+            true
+        }
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::ActiveColor
+    }
+
+    fn id(&self) -> &str {
+        "active_color"
+    }
+
+    fn key(&self) -> &str {
+        self.get_service()
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(try!(self.0.write_to_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use super::ActiveColor;
+    use rumor::Rumor;
+
+    fn create_active_color(member_id: &str, group: &str) -> ActiveColor {
+        ActiveColor::new(member_id, "app", group)
+    }
+
+    #[test]
+    fn identical_active_colors_are_equal() {
+        let s1 = create_active_color("adam", "blue");
+        let s2 = create_active_color("adam", "blue");
+        assert_eq!(s1, s2);
+    }
+
+    #[test]
+    fn active_colors_with_different_groups_are_not_equal() {
+        let s1 = create_active_color("adam", "blue");
+        let s2 = create_active_color("adam", "green");
+        assert!(s1 != s2);
+    }
+
+    #[test]
+    fn active_colors_with_different_incarnations_are_not_equal_via_cmp() {
+        let s1 = create_active_color("adam", "blue");
+        let mut s2 = create_active_color("adam", "blue");
+        s2.set_incarnation(1);
+        assert_eq!(s1.partial_cmp(&s2), Some(Ordering::Less));
+        assert_eq!(s2.partial_cmp(&s1), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn merge_chooses_the_higher_incarnation() {
+        let mut s1 = create_active_color("adam", "blue");
+        let mut s2 = create_active_color("adam", "green");
+        s2.set_incarnation(1);
+        let s2_check = s2.clone();
+        assert_eq!(s1.merge(s2), true);
+        assert_eq!(s1, s2_check);
+        assert_eq!(s1.get_group(), "green");
+    }
+
+    #[test]
+    fn merge_returns_false_if_nothing_changed() {
+        let mut s1 = create_active_color("adam", "blue");
+        s1.set_incarnation(1);
+        let s1_check = s1.clone();
+        let s2 = create_active_color("adam", "green");
+        assert_eq!(s1.merge(s2), false);
+        assert_eq!(s1, s1_check);
+    }
+}
@@ -23,15 +23,27 @@
 //! New rumors need to implement the `From` trait for `RumorKey`, and then can track the arrival of
 //! new rumors, and dispatch them according to their `kind`.
 
+pub mod active_color;
 pub mod election;
+pub mod ring_command;
+pub mod ring_command_ack;
 pub mod service;
 pub mod service_config;
 pub mod service_file;
+pub mod service_restart;
+pub mod service_scale;
+pub mod update_lock;
 
+pub use self::active_color::ActiveColor;
 pub use self::election::Election;
+pub use self::ring_command::RingCommand;
+pub use self::ring_command_ack::RingCommandAck;
 pub use self::service::Service;
 pub use self::service_config::ServiceConfig;
 pub use self::service_file::ServiceFile;
+pub use self::service_restart::ServiceRestart;
+pub use self::service_scale::ServiceScale;
+pub use self::update_lock::UpdateLock;
 
 use std::collections::HashMap;
 use std::collections::hash_map::Entry;
@@ -147,6 +159,12 @@ impl<T: Rumor + Clone> RumorStore<T> {
         self.update_counter.load(Ordering::Relaxed)
     }
 
+    /// Returns the total number of rumors in the store, across every key.
+    pub fn len(&self) -> usize {
+        let list = self.list.read().expect("Rumor store lock poisoned");
+        list.values().map(|r| r.len()).sum()
+    }
+
     pub fn len_for_key(&self, key: &str) -> usize {
         let list = self.list.read().expect("Rumor store lock poisoned");
         list.get(key).map_or(0, |r| r.len())
@@ -176,6 +194,29 @@ impl<T: Rumor + Clone> RumorStore<T> {
         list.get_mut(key).and_then(|mut r| r.remove(id));
     }
 
+    /// Removes every rumor with the given id, whatever its key, dropping keys left without
+    /// rumors. Returns how many rumors were removed.
+    pub fn remove_by_id(&self, id: &str) -> usize {
+        let mut list = self.list.write().expect("Rumor store lock poisoned");
+        let mut removed = 0;
+        let mut emptied = Vec::new();
+        for (key, rumors) in list.iter_mut() {
+            if rumors.remove(id).is_some() {
+                removed += 1;
+                if rumors.is_empty() {
+                    emptied.push(key.clone());
+                }
+            }
+        }
+        for key in emptied {
+            list.remove(&key);
+        }
+        if removed > 0 {
+            self.increment_update_counter();
+        }
+        removed
+    }
+
     pub fn with_keys<F>(&self, mut with_closure: F)
         where F: FnMut((&String, &HashMap<String, T>))
     {
@@ -250,6 +291,24 @@ impl RumorList {
         rumors.insert(rk, HashMap::new());
     }
 
+    /// Returns the number of rumors being shared.
+    pub fn len(&self) -> usize {
+        self.rumor_list.read().expect("Rumor map lock poisoned").len()
+    }
+
+    /// Stops sharing the rumors with the given id, and forgets how often rumors were shared with
+    /// the member of that id.
+    pub fn remove_by_id(&self, id: &str) {
+        let mut rumors = self.rumor_list.write().expect("Rumor map lock poisoned");
+        let gone: Vec<RumorKey> = rumors.keys().filter(|rk| rk.id == id).cloned().collect();
+        for rk in gone {
+            rumors.remove(&rk);
+        }
+        for heat_map in rumors.values_mut() {
+            heat_map.remove(id);
+        }
+    }
+
     /// Return a list of rumors, along with their current heat, sorted by heat. Lowest to highest.
     /// So all the "0" rumors sort higher than the "2" rumors.
     pub fn rumors(&self, id: &str) -> RumorVec {
@@ -458,6 +517,34 @@ mod tests {
             assert_eq!(rs.list.read().unwrap().get(&key).unwrap().len(), 2);
         }
 
+        #[test]
+        fn len_counts_rumors_for_every_key() {
+            let rs = create_rumor_store();
+            let f1 = FakeRumor::default();
+            let mut f2 = FakeRumor::default();
+            f2.key = String::from("other");
+            rs.insert(f1);
+            rs.insert(f2);
+            assert_eq!(rs.len(), 2);
+        }
+
+        #[test]
+        fn remove_by_id_removes_rumors_for_every_key() {
+            let rs = create_rumor_store();
+            let f1 = FakeRumor::default();
+            let mut f2 = f1.clone();
+            f2.key = String::from("other");
+            let f3 = FakeRumor::default();
+            let id = f1.id.clone();
+            rs.insert(f1);
+            rs.insert(f2);
+            rs.insert(f3);
+            assert_eq!(rs.remove_by_id(&id), 2);
+            assert_eq!(rs.len(), 1);
+            assert_eq!(rs.len_for_key("other"), 0);
+            assert!(!rs.list.read().unwrap().contains_key("other"));
+        }
+
         #[test]
         fn insert_returns_false_on_no_changes() {
             let rs = create_rumor_store();
@@ -588,5 +675,23 @@ mod tests {
             assert_eq!(rl.rumors(&String::from("fake")).len(), 5);
         }
 
+        #[test]
+        fn remove_by_id() {
+            let rl = RumorList::default();
+            let departed = FakeRumor::default();
+            let other = FakeRumor::default();
+            rl.insert(&departed);
+            rl.insert(&other);
+            let rumors = rl.take(&departed.id, 2);
+            rl.update_heat(&departed.id, &rumors);
+            rl.remove_by_id(&departed.id);
+            assert_eq!(rl.len(), 1);
+            assert!(rl.rumor_list
+                .read()
+                .unwrap()
+                .values()
+                .all(|heat_map| !heat_map.contains_key(&departed.id)));
+        }
+
     }
 }
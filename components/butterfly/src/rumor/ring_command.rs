@@ -0,0 +1,202 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The RingCommand rumor.
+//!
+//! An administrative command, such as restarting a service, for every member of the ring to run.
+//! Commands are signed with an origin's signing key, and members only run those signed by an
+//! origin they trust; each answers with a `RingCommandAck`. A command never changes once it is
+//! issued, so the rumor is keyed by its id alone.
+
+use std::ops::{Deref, DerefMut};
+use std::path::Path;
+
+use habitat_core::crypto::SigKeyPair;
+use habitat_core::crypto::keys::parse_name_with_rev;
+use protobuf::Message;
+use time;
+
+use error::{Error, Result};
+use message::swim::{RingCommand as ProtoRingCommand, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RingCommand(ProtoRumor);
+
+impl PartialEq for RingCommand {
+    fn eq(&self, other: &RingCommand) -> bool {
+        self.get_id() == other.get_id() && self.get_command() == other.get_command() &&
+        self.get_args() == other.get_args() &&
+        self.get_issued_at() == other.get_issued_at() &&
+        self.get_signer() == other.get_signer() &&
+        self.get_signature() == other.get_signature()
+    }
+}
+
+impl From<ProtoRumor> for RingCommand {
+    fn from(pr: ProtoRumor) -> RingCommand {
+        RingCommand(pr)
+    }
+}
+
+impl From<RingCommand> for ProtoRumor {
+    fn from(ring_command: RingCommand) -> ProtoRumor {
+        ring_command.0
+    }
+}
+
+impl Deref for RingCommand {
+    type Target = ProtoRingCommand;
+
+    fn deref(&self) -> &ProtoRingCommand {
+        self.0.get_ring_command()
+    }
+}
+
+impl DerefMut for RingCommand {
+    fn deref_mut(&mut self) -> &mut ProtoRingCommand {
+        self.0.mut_ring_command()
+    }
+}
+
+impl RingCommand {
+    /// Creates a new, unsigned RingCommand, issued now.
+    pub fn new<S1, S2, S3>(member_id: S1, id: S2, command: S3, args: Vec<String>) -> Self
+        where S1: Into<String>,
+              S2: Into<String>,
+              S3: Into<String>
+    {
+        let mut rumor = ProtoRumor::new();
+        let from_id = member_id.into();
+        rumor.set_from_id(from_id);
+        rumor.set_field_type(ProtoRumor_Type::RingCommand);
+
+        let mut proto = ProtoRingCommand::new();
+        proto.set_id(id.into());
+        proto.set_command(command.into());
+        proto.set_args(args.into());
+        proto.set_issued_at(time::now_utc().to_timespec().sec);
+
+        rumor.set_ring_command(proto);
+        RingCommand(rumor)
+    }
+
+    /// The origin whose key signed the command, if it is signed.
+    pub fn origin(&self) -> Option<String> {
+        parse_name_with_rev(self.get_signer()).ok().map(|(origin, _)| origin)
+    }
+
+    /// Signs the command with `pair`, which must hold a secret key.
+    pub fn sign(&mut self, pair: &SigKeyPair) -> Result<()> {
+        self.set_signer(pair.name_with_rev());
+        let signature = try!(pair.sign(&try!(self.signed_bytes())));
+        self.set_signature(signature);
+        Ok(())
+    }
+
+    /// Checks the signature against the signer's public key, which must be in `cache_key_path`.
+    pub fn verify<P: AsRef<Path> + ?Sized>(&self, cache_key_path: &P) -> Result<()> {
+        if self.get_signature().is_empty() {
+            return Err(Error::InvalidSignature(self.get_id().to_string()));
+        }
+        let pair = try!(SigKeyPair::get_pair_for(self.get_signer(), cache_key_path));
+        let signed = try!(pair.verify(self.get_signature()));
+        if signed == try!(self.signed_bytes()) {
+            Ok(())
+        } else {
+            Err(Error::InvalidSignature(self.get_id().to_string()))
+        }
+    }
+
+    /// The bytes the signature covers: every field of the command but the signature itself.
+    fn signed_bytes(&self) -> Result<Vec<u8>> {
+        let mut proto = (**self).clone();
+        proto.clear_signature();
+        Ok(try!(proto.write_to_bytes()))
+    }
+}
+
+impl Rumor for RingCommand {
+    /// Commands never change once issued, so there is nothing to merge.
+    fn merge(&mut self, _other: RingCommand) -> bool {
+        false
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::RingCommand
+    }
+
+    fn id(&self) -> &str {
+        "ring_command"
+    }
+
+    fn key(&self) -> &str {
+        self.get_id()
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(try!(self.0.write_to_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use habitat_core::crypto::SigKeyPair;
+    use tempdir::TempDir;
+
+    use super::RingCommand;
+    use rumor::Rumor;
+
+    fn create_ring_command(command: &str) -> RingCommand {
+        RingCommand::new("adam", "c0ffee", command, vec!["redis.default".to_string()])
+    }
+
+    #[test]
+    fn merge_never_changes_a_command() {
+        let mut c1 = create_ring_command("restart");
+        let c1_check = c1.clone();
+        let c2 = create_ring_command("stop");
+        assert_eq!(c1.merge(c2), false);
+        assert_eq!(c1, c1_check);
+    }
+
+    #[test]
+    fn signed_commands_verify() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("core", cache.path()).unwrap();
+        let mut c1 = create_ring_command("restart");
+        c1.sign(&pair).unwrap();
+        assert_eq!(c1.origin(), Some("core".to_string()));
+        assert!(c1.verify(cache.path()).is_ok());
+    }
+
+    #[test]
+    fn altered_commands_do_not_verify() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("core", cache.path()).unwrap();
+        let mut c1 = create_ring_command("restart");
+        c1.sign(&pair).unwrap();
+        c1.mut_args().push("postgres.default".to_string());
+        assert!(c1.verify(cache.path()).is_err());
+    }
+
+    #[test]
+    fn unsigned_commands_do_not_verify() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let c1 = create_ring_command("restart");
+        assert_eq!(c1.origin(), None);
+        assert!(c1.verify(cache.path()).is_err());
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The RingCommandAck rumor.
+//!
+//! A member's answer to a `RingCommand`: whether it ran the command, and what happened. The rumor
+//! is keyed by the command's id, with one per member.
+
+use std::ops::{Deref, DerefMut};
+
+use protobuf::Message;
+
+use error::Result;
+use message::swim::{RingCommandAck as ProtoRingCommandAck, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RingCommandAck(ProtoRumor);
+
+impl PartialEq for RingCommandAck {
+    fn eq(&self, other: &RingCommandAck) -> bool {
+        self.get_command_id() == other.get_command_id() &&
+        self.get_member_id() == other.get_member_id() &&
+        self.get_success() == other.get_success() &&
+        self.get_message() == other.get_message()
+    }
+}
+
+impl From<ProtoRumor> for RingCommandAck {
+    fn from(pr: ProtoRumor) -> RingCommandAck {
+        RingCommandAck(pr)
+    }
+}
+
+impl From<RingCommandAck> for ProtoRumor {
+    fn from(ring_command_ack: RingCommandAck) -> ProtoRumor {
+        ring_command_ack.0
+    }
+}
+
+impl Deref for RingCommandAck {
+    type Target = ProtoRingCommandAck;
+
+    fn deref(&self) -> &ProtoRingCommandAck {
+        self.0.get_ring_command_ack()
+    }
+}
+
+impl DerefMut for RingCommandAck {
+    fn deref_mut(&mut self) -> &mut ProtoRingCommandAck {
+        self.0.mut_ring_command_ack()
+    }
+}
+
+impl RingCommandAck {
+    /// Creates a new RingCommandAck, recording whether `member_id` ran the command.
+    pub fn new<S1, S2, S3>(member_id: S1, command_id: S2, success: bool, message: S3) -> Self
+        where S1: Into<String>,
+              S2: Into<String>,
+              S3: Into<String>
+    {
+        let mut rumor = ProtoRumor::new();
+        let from_id = member_id.into();
+        rumor.set_from_id(from_id.clone());
+        rumor.set_field_type(ProtoRumor_Type::RingCommandAck);
+
+        let mut proto = ProtoRingCommandAck::new();
+        proto.set_command_id(command_id.into());
+        proto.set_member_id(from_id);
+        proto.set_success(success);
+        proto.set_message(message.into());
+
+        rumor.set_ring_command_ack(proto);
+        RingCommandAck(rumor)
+    }
+}
+
+impl Rumor for RingCommandAck {
+    /// A member answers a command once, so there is nothing to merge.
+    fn merge(&mut self, _other: RingCommandAck) -> bool {
+        false
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::RingCommandAck
+    }
+
+    fn id(&self) -> &str {
+        self.get_member_id()
+    }
+
+    fn key(&self) -> &str {
+        self.get_command_id()
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(try!(self.0.write_to_bytes()))
+    }
+}
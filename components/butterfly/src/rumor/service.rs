@@ -17,6 +17,7 @@
 //! Service rumors declare that a given `Server` is running this Service.
 
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::mem;
 use std::ops::{Deref, DerefMut};
 
@@ -147,6 +148,9 @@ pub struct SysInfo {
     pub hostname: String,
     pub http_gateway_ip: String,
     pub http_gateway_port: u16,
+    /// Key-value tags the operator gave the Supervisor, such as the zone it runs in.
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
 }
 
 #[cfg(test)]
@@ -157,9 +161,12 @@ mod tests {
     use habitat_core::service::ServiceGroup;
     use habitat_core::package::{Identifiable, PackageIdent};
 
+    use std::str;
+
     use super::Service;
     use rumor::Rumor;
     use rumor::service::SysInfo;
+    use toml;
 
     fn create_service(member_id: &str) -> Service {
         let pkg = PackageIdent::from_str("core/neurosis/1.2.3/20161208121212").unwrap();
@@ -245,6 +252,26 @@ mod tests {
         assert_eq!(s1, s1_check);
     }
 
+    #[test]
+    fn sys_labels_round_trip() {
+        let pkg = PackageIdent::from_str("core/neurosis/1.2.3/20161208121212").unwrap();
+        let sg = ServiceGroup::new(pkg.name(), "production", None).unwrap();
+        let mut sys = SysInfo::default();
+        sys.labels.insert(String::from("zone"), String::from("us-east-1a"));
+        let s = Service::new("adam".to_string(), &pkg, &sg, &sys, None).unwrap();
+        let decoded: SysInfo = toml::decode_str(str::from_utf8(s.get_sys()).unwrap()).unwrap();
+        assert_eq!(decoded, sys);
+    }
+
+    #[test]
+    fn sys_without_labels_decodes() {
+        let sys: SysInfo = toml::decode_str("ip = \"10.0.0.1\"\nhostname = \"adam\"\n\
+                                             http_gateway_ip = \"0.0.0.0\"\n\
+                                             http_gateway_port = 9631\n")
+            .unwrap();
+        assert!(sys.labels.is_empty());
+    }
+
     #[test]
     #[should_panic]
     fn service_package_name_mismatch() {
@@ -15,8 +15,22 @@
 //! The ServiceConfig rumor.
 //!
 //! Holds the toml configuration injected for a service.
+//!
+//! A configuration is either the whole document, or a patch of the keys to change, which is
+//! merged over the configuration from the incarnation before it. Tables in a patch are merged key
+//! by key, any other value replaces the one it's given for, and the dotted keys listed in its
+//! top-level `_delete` array are removed before the rest of it is merged. Members compose a
+//! cleartext patch with the configuration they hold as the patch arrives; encrypted patches can't
+//! be read in transit, so they are passed on as they are and merged by the Supervisor.
+//!
+//! A configuration can also be for only some members of the group, chosen by a `MemberSelector`,
+//! so that a change can be tried on a few of them first. The others keep the configuration they
+//! have until an incarnation which selects them arrives. Such a configuration is kept apart from
+//! the one for the whole group, under `SELECTED_ID` rather than `GROUP_ID`, so members which join
+//! the group while a change is being tried still get the group's configuration.
 
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::mem;
 use std::ops::{Deref, DerefMut};
 use std::str::{self, FromStr};
@@ -31,6 +45,34 @@ use message::swim::{ServiceConfig as ProtoServiceConfig, Rumor as ProtoRumor,
                     Rumor_Type as ProtoRumor_Type};
 use rumor::Rumor;
 
+/// The top-level key of a patch which lists the dotted keys it removes.
+pub const DELETE_KEY: &'static str = "_delete";
+
+/// The rumor id of the configuration for the whole group.
+pub const GROUP_ID: &'static str = "service_config";
+
+/// The rumor id of the latest configuration for only some members of the group.
+pub const SELECTED_ID: &'static str = "service_config_selected";
+
+/// The members of a service group a configuration is for. A member is selected if it's one of
+/// `member_ids`, carries every one of `labels`, and falls within `percent`, leaving out any of
+/// them which are empty or zero; so the default selector is for every member.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MemberSelector {
+    pub member_ids: Vec<String>,
+    /// Labels as `key=value`.
+    pub labels: Vec<String>,
+    /// The share of the members, from 1 to 100, chosen by a hash of their id and the service
+    /// group. The same members stay chosen as the share grows.
+    pub percent: u32,
+}
+
+impl MemberSelector {
+    pub fn is_empty(&self) -> bool {
+        self.member_ids.is_empty() && self.labels.is_empty() && self.percent == 0
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct ServiceConfig(ProtoRumor);
 
@@ -49,6 +91,8 @@ impl PartialEq for ServiceConfig {
         self.get_service_group() == other.get_service_group() &&
         self.get_incarnation() == other.get_incarnation() &&
         self.get_encrypted() == other.get_encrypted() &&
+        self.get_patch() == other.get_patch() &&
+        self.selector() == other.selector() &&
         self.get_config() == other.get_config()
     }
 }
@@ -120,19 +164,138 @@ impl ServiceConfig {
         Ok(config)
     }
 
+    pub fn set_selector(&mut self, selector: &MemberSelector) {
+        self.set_member_ids(selector.member_ids.clone().into());
+        self.set_labels(selector.labels.clone().into());
+        if selector.percent > 0 {
+            self.set_percent(selector.percent);
+        } else {
+            self.clear_percent();
+        }
+    }
+
+    pub fn selector(&self) -> MemberSelector {
+        MemberSelector {
+            member_ids: self.get_member_ids().to_vec(),
+            labels: self.get_labels().to_vec(),
+            percent: self.get_percent(),
+        }
+    }
+
+    /// Returns true if the configuration is for only some members of the group.
+    pub fn is_selective(&self) -> bool {
+        !self.get_member_ids().is_empty() || !self.get_labels().is_empty() ||
+        self.get_percent() > 0
+    }
+
+    /// Returns true if the configuration is for the member with the given id and labels.
+    pub fn selects(&self, member_id: &str, labels: &BTreeMap<String, String>) -> bool {
+        if !self.get_member_ids().is_empty() &&
+           !self.get_member_ids().iter().any(|id| id == member_id) {
+            return false;
+        }
+        let has_labels = self.get_labels().iter().all(|label| {
+            let mut kv = label.splitn(2, '=');
+            match (kv.next(), kv.next()) {
+                (Some(key), Some(value)) => labels.get(key).map_or(false, |v| v == value),
+                _ => false,
+            }
+        });
+        if !has_labels {
+            return false;
+        }
+        let percent = self.get_percent();
+        percent == 0 || percent >= 100 ||
+        fnv1a(format!("{}/{}", self.get_service_group(), member_id).as_bytes()) % 100 <
+        percent as u64
+    }
+
     fn parse_config(&self, encoded: &str) -> Result<toml::Value> {
         toml::Value::from_str(encoded)
             .map_err(|e| Error::ServiceConfigDecode(self.get_service_group().to_string(), e))
     }
+
+    /// Composes this patch with `base`, the configuration from an earlier incarnation. Over a
+    /// whole document the result is a whole document; over another patch it's a patch which does
+    /// the work of both. Encrypted configurations are left alone.
+    fn patch_over(&mut self, base: &ServiceConfig) -> Result<()> {
+        if self.get_encrypted() || base.get_encrypted() {
+            return Ok(());
+        }
+        let mut config = try!(base.config()).as_table().cloned().unwrap_or_else(toml::Table::new);
+        let patch = try!(self.config()).as_table().cloned().unwrap_or_else(toml::Table::new);
+        let mut deleted = match (base.get_patch(), config.remove(DELETE_KEY)) {
+            (true, Some(toml::Value::Array(keys))) => keys,
+            _ => vec![],
+        };
+        if let Some(&toml::Value::Array(ref keys)) = patch.get(DELETE_KEY) {
+            deleted.extend(keys.iter().cloned());
+        }
+        apply_patch(&mut config, patch);
+        if base.get_patch() && !deleted.is_empty() {
+            config.insert(DELETE_KEY.to_string(), toml::Value::Array(deleted));
+        }
+        self.set_config(toml::encode_str(&toml::Value::Table(config)).into_bytes());
+        self.set_patch(base.get_patch());
+        Ok(())
+    }
+}
+
+/// A hash which, unlike the standard library's, is the same on every Supervisor.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, b| (hash ^ *b as u64).wrapping_mul(0x100000001b3))
+}
+
+/// Merges `patch` over `config`, after removing the keys the patch lists under `DELETE_KEY`.
+pub fn apply_patch(config: &mut toml::Table, mut patch: toml::Table) {
+    if let Some(toml::Value::Array(keys)) = patch.remove(DELETE_KEY) {
+        for key in keys.iter().filter_map(|k| k.as_str()) {
+            delete_key(config, key);
+        }
+    }
+    merge_tables(config, patch);
+}
+
+fn delete_key(config: &mut toml::Table, key: &str) {
+    let mut parts: Vec<&str> = key.split('.').collect();
+    let last = parts.pop().unwrap_or(key);
+    let mut table = config;
+    for part in parts {
+        table = match { table }.get_mut(part) {
+            Some(&mut toml::Value::Table(ref mut inner)) => inner,
+            _ => return,
+        };
+    }
+    table.remove(last);
+}
+
+fn merge_tables(config: &mut toml::Table, patch: toml::Table) {
+    for (key, value) in patch {
+        let value = match (config.remove(&key), value) {
+            (Some(toml::Value::Table(mut current)), toml::Value::Table(inner)) => {
+                merge_tables(&mut current, inner);
+                toml::Value::Table(current)
+            }
+            (_, value) => value,
+        };
+        config.insert(key, value);
+    }
 }
 
 impl Rumor for ServiceConfig {
     /// Follows a simple pattern; if we have a newer incarnation than the one we already have, the
-    /// new one wins. So far, these never change.
+    /// new one wins. A newer patch is first composed with the one we have.
     fn merge(&mut self, mut other: ServiceConfig) -> bool {
         if *self >= other {
             false
         } else {
+            if other.get_patch() {
+                if let Err(e) = other.patch_over(self) {
+                    warn!("Cannot merge the configuration patch for {}: {}",
+                          other.get_service_group(),
+                          e);
+                }
+            }
             mem::swap(self, &mut other);
             true
         }
@@ -143,7 +306,11 @@ impl Rumor for ServiceConfig {
     }
 
     fn id(&self) -> &str {
-        "service_config"
+        if self.is_selective() {
+            SELECTED_ID
+        } else {
+            GROUP_ID
+        }
     }
 
     fn key(&self) -> &str {
@@ -160,10 +327,12 @@ mod tests {
     use std::cmp::Ordering;
     use std::str::FromStr;
 
+    use std::collections::BTreeMap;
+
     use habitat_core::service::ServiceGroup;
     use toml;
 
-    use super::ServiceConfig;
+    use super::{GROUP_ID, SELECTED_ID, MemberSelector, ServiceConfig};
     use rumor::Rumor;
 
     fn create_service_config(member_id: &str, config: &str) -> ServiceConfig {
@@ -235,6 +404,73 @@ mod tests {
         assert_eq!(s1, s1_check);
     }
 
+    #[test]
+    fn merge_composes_a_patch_with_the_whole_config() {
+        let mut s1 = create_service_config("adam", "port = 80\n[tls]\ncert = \"a\"\nkey = \"b\"");
+        let mut s2 = create_service_config("adam", "_delete = [\"tls.key\"]\n[tls]\ncert = \"c\"");
+        s2.set_incarnation(1);
+        s2.set_patch(true);
+        assert_eq!(s1.merge(s2), true);
+        assert_eq!(s1.get_incarnation(), 1);
+        assert_eq!(s1.get_patch(), false);
+        assert_eq!(s1.config().unwrap(),
+                   toml::Value::from_str("port = 80\n[tls]\ncert = \"c\"").unwrap());
+    }
+
+    #[test]
+    fn merge_composes_two_patches_into_one() {
+        let mut s1 = create_service_config("adam", "_delete = [\"port\"]\nworkers = 2");
+        s1.set_patch(true);
+        let mut s2 = create_service_config("adam", "_delete = [\"workers\"]\nport = 81");
+        s2.set_incarnation(1);
+        s2.set_patch(true);
+        assert_eq!(s1.merge(s2), true);
+        assert_eq!(s1.get_patch(), true);
+        assert_eq!(s1.config().unwrap(),
+                   toml::Value::from_str("_delete = [\"port\", \"workers\"]\nport = 81")
+                       .unwrap());
+    }
+
+    #[test]
+    fn selectors_choose_members_by_id_and_label() {
+        let mut labels = BTreeMap::new();
+        labels.insert(String::from("zone"), String::from("1a"));
+        let mut s1 = create_service_config("adam", "yep");
+        assert!(s1.selects("a", &labels));
+        s1.set_selector(&MemberSelector {
+            member_ids: vec![String::from("a"), String::from("b")],
+            labels: vec![String::from("zone=1a")],
+            percent: 0,
+        });
+        assert!(s1.selects("a", &labels));
+        assert!(!s1.selects("c", &labels));
+        assert!(!s1.selects("b", &BTreeMap::new()));
+    }
+
+    #[test]
+    fn selectors_choose_the_same_members_as_the_percentage_grows() {
+        let labels = BTreeMap::new();
+        let members: Vec<String> = (0..200).map(|i| format!("member-{}", i)).collect();
+        let mut s1 = create_service_config("adam", "yep");
+        let mut chosen = Vec::new();
+        for percent in vec![10, 50, 100] {
+            s1.set_selector(&MemberSelector { percent: percent, ..MemberSelector::default() });
+            let now: Vec<&String> = members.iter().filter(|m| s1.selects(m, &labels)).collect();
+            assert!(chosen.iter().all(|m| now.contains(m)));
+            chosen = now;
+        }
+        assert_eq!(chosen.len(), members.len());
+    }
+
+    #[test]
+    fn selective_configs_are_kept_apart_from_the_group_config() {
+        let s1 = create_service_config("adam", "yep");
+        let mut s2 = create_service_config("adam", "yep");
+        s2.set_selector(&MemberSelector { percent: 10, ..MemberSelector::default() });
+        assert_eq!(s1.id(), GROUP_ID);
+        assert_eq!(s2.id(), SELECTED_ID);
+    }
+
     #[test]
     fn config_comes_back_as_a_toml_value() {
         let s1 = create_service_config("adam", "yep=1");
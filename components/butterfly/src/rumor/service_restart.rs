@@ -0,0 +1,176 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The ServiceRestart rumor.
+//!
+//! Asks every member of a service group to restart its service, either at once or one member at
+//! a time. Each member records the last restart it finished in its own Service rumor, which is
+//! how the members of a rolling restart know whose turn it is.
+
+use std::cmp::Ordering;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+
+use habitat_core::service::ServiceGroup;
+use protobuf::Message;
+use time;
+
+use error::Result;
+use message::swim::{ServiceRestart as ProtoServiceRestart, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ServiceRestart(ProtoRumor);
+
+impl PartialOrd for ServiceRestart {
+    fn partial_cmp(&self, other: &ServiceRestart) -> Option<Ordering> {
+        if self.get_service_group() != other.get_service_group() {
+            None
+        } else {
+            Some(self.get_incarnation().cmp(&other.get_incarnation()))
+        }
+    }
+}
+
+impl PartialEq for ServiceRestart {
+    fn eq(&self, other: &ServiceRestart) -> bool {
+        self.get_service_group() == other.get_service_group() &&
+        self.get_incarnation() == other.get_incarnation() &&
+        self.get_rolling() == other.get_rolling() &&
+        self.get_issued_at() == other.get_issued_at()
+    }
+}
+
+impl From<ProtoRumor> for ServiceRestart {
+    fn from(pr: ProtoRumor) -> ServiceRestart {
+        ServiceRestart(pr)
+    }
+}
+
+impl From<ServiceRestart> for ProtoRumor {
+    fn from(service_restart: ServiceRestart) -> ProtoRumor {
+        service_restart.0
+    }
+}
+
+impl Deref for ServiceRestart {
+    type Target = ProtoServiceRestart;
+
+    fn deref(&self) -> &ProtoServiceRestart {
+        self.0.get_service_restart()
+    }
+}
+
+impl DerefMut for ServiceRestart {
+    fn deref_mut(&mut self) -> &mut ProtoServiceRestart {
+        self.0.mut_service_restart()
+    }
+}
+
+impl ServiceRestart {
+    /// Creates a new ServiceRestart, issued now.
+    pub fn new<S1>(member_id: S1, service_group: ServiceGroup, rolling: bool) -> Self
+        where S1: Into<String>
+    {
+        let mut rumor = ProtoRumor::new();
+        let from_id = member_id.into();
+        rumor.set_from_id(from_id);
+        rumor.set_field_type(ProtoRumor_Type::ServiceRestart);
+
+        let mut proto = ProtoServiceRestart::new();
+        proto.set_service_group(format!("{}", service_group));
+        proto.set_incarnation(0);
+        proto.set_rolling(rolling);
+        proto.set_issued_at(time::now_utc().to_timespec().sec);
+
+        rumor.set_service_restart(proto);
+        ServiceRestart(rumor)
+    }
+}
+
+impl Rumor for ServiceRestart {
+    /// Follows a simple pattern; if we have a newer incarnation than the one we already have, the
+    /// new one wins.
+    fn merge(&mut self, mut other: ServiceRestart) -> bool {
+        if *self >= other {
+            false
+        } else {
+            mem::swap(self, &mut other);
+            true
+        }
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::ServiceRestart
+    }
+
+    fn id(&self) -> &str {
+        "service_restart"
+    }
+
+    fn key(&self) -> &str {
+        self.get_service_group()
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(try!(self.0.write_to_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use habitat_core::service::ServiceGroup;
+
+    use super::ServiceRestart;
+    use rumor::Rumor;
+
+    fn create_service_restart(member_id: &str, rolling: bool) -> ServiceRestart {
+        ServiceRestart::new(member_id,
+                            ServiceGroup::new("neurosis", "production", None).unwrap(),
+                            rolling)
+    }
+
+    #[test]
+    fn service_restarts_with_different_incarnations_are_not_equal_via_cmp() {
+        let s1 = create_service_restart("adam", true);
+        let mut s2 = s1.clone();
+        s2.set_incarnation(1);
+        assert_eq!(s1.partial_cmp(&s2), Some(Ordering::Less));
+        assert_eq!(s2.partial_cmp(&s1), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn merge_chooses_the_higher_incarnation() {
+        let mut s1 = create_service_restart("adam", false);
+        let mut s2 = create_service_restart("adam", true);
+        s2.set_incarnation(1);
+        let s2_check = s2.clone();
+        assert_eq!(s1.merge(s2), true);
+        assert_eq!(s1, s2_check);
+        assert_eq!(s1.get_rolling(), true);
+    }
+
+    #[test]
+    fn merge_returns_false_if_nothing_changed() {
+        let mut s1 = create_service_restart("adam", true);
+        s1.set_incarnation(1);
+        let s1_check = s1.clone();
+        let s2 = create_service_restart("adam", false);
+        assert_eq!(s1.merge(s2), false);
+        assert_eq!(s1, s1_check);
+    }
+}
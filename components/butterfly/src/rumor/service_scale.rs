@@ -0,0 +1,186 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The ServiceScale rumor.
+//!
+//! Holds the number of members a service group is meant to have, for whatever scales the group to
+//! reconcile against.
+
+use std::cmp::Ordering;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+
+use habitat_core::service::ServiceGroup;
+use protobuf::Message;
+
+use error::Result;
+use message::swim::{ServiceScale as ProtoServiceScale, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ServiceScale(ProtoRumor);
+
+impl PartialOrd for ServiceScale {
+    fn partial_cmp(&self, other: &ServiceScale) -> Option<Ordering> {
+        if self.get_service_group() != other.get_service_group() {
+            None
+        } else {
+            Some(self.get_incarnation().cmp(&other.get_incarnation()))
+        }
+    }
+}
+
+impl PartialEq for ServiceScale {
+    fn eq(&self, other: &ServiceScale) -> bool {
+        self.get_service_group() == other.get_service_group() &&
+        self.get_incarnation() == other.get_incarnation() &&
+        self.get_desired_count() == other.get_desired_count()
+    }
+}
+
+impl From<ProtoRumor> for ServiceScale {
+    fn from(pr: ProtoRumor) -> ServiceScale {
+        ServiceScale(pr)
+    }
+}
+
+impl From<ServiceScale> for ProtoRumor {
+    fn from(service_scale: ServiceScale) -> ProtoRumor {
+        service_scale.0
+    }
+}
+
+impl Deref for ServiceScale {
+    type Target = ProtoServiceScale;
+
+    fn deref(&self) -> &ProtoServiceScale {
+        self.0.get_service_scale()
+    }
+}
+
+impl DerefMut for ServiceScale {
+    fn deref_mut(&mut self) -> &mut ProtoServiceScale {
+        self.0.mut_service_scale()
+    }
+}
+
+impl ServiceScale {
+    /// Creates a new ServiceScale.
+    pub fn new<S1>(member_id: S1, service_group: ServiceGroup, desired_count: u64) -> Self
+        where S1: Into<String>
+    {
+        let mut rumor = ProtoRumor::new();
+        let from_id = member_id.into();
+        rumor.set_from_id(from_id);
+        rumor.set_field_type(ProtoRumor_Type::ServiceScale);
+
+        let mut proto = ProtoServiceScale::new();
+        proto.set_service_group(format!("{}", service_group));
+        proto.set_incarnation(0);
+        proto.set_desired_count(desired_count);
+
+        rumor.set_service_scale(proto);
+        ServiceScale(rumor)
+    }
+}
+
+impl Rumor for ServiceScale {
+    /// Follows a simple pattern; if we have a newer incarnation than the one we already have, the
+    /// new one wins.
+    fn merge(&mut self, mut other: ServiceScale) -> bool {
+        if *self >= other {
+            false
+        } else {
+            mem::swap(self, &mut other);
+            true
+        }
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::ServiceScale
+    }
+
+    fn id(&self) -> &str {
+        "service_scale"
+    }
+
+    fn key(&self) -> &str {
+        self.get_service_group()
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(try!(self.0.write_to_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use habitat_core::service::ServiceGroup;
+
+    use super::ServiceScale;
+    use rumor::Rumor;
+
+    fn create_service_scale(member_id: &str, desired_count: u64) -> ServiceScale {
+        ServiceScale::new(member_id,
+                          ServiceGroup::new("neurosis", "production", None).unwrap(),
+                          desired_count)
+    }
+
+    #[test]
+    fn identical_service_scales_are_equal() {
+        let s1 = create_service_scale("adam", 3);
+        let s2 = create_service_scale("adam", 3);
+        assert_eq!(s1, s2);
+    }
+
+    #[test]
+    fn service_scales_with_different_desired_counts_are_not_equal() {
+        let s1 = create_service_scale("adam", 3);
+        let s2 = create_service_scale("adam", 5);
+        assert!(s1 != s2);
+    }
+
+    #[test]
+    fn service_scales_with_different_incarnations_are_not_equal_via_cmp() {
+        let s1 = create_service_scale("adam", 3);
+        let mut s2 = create_service_scale("adam", 3);
+        s2.set_incarnation(1);
+        assert_eq!(s1.partial_cmp(&s2), Some(Ordering::Less));
+        assert_eq!(s2.partial_cmp(&s1), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn merge_chooses_the_higher_incarnation() {
+        let mut s1 = create_service_scale("adam", 3);
+        let mut s2 = create_service_scale("adam", 5);
+        s2.set_incarnation(1);
+        let s2_check = s2.clone();
+        assert_eq!(s1.merge(s2), true);
+        assert_eq!(s1, s2_check);
+        assert_eq!(s1.get_desired_count(), 5);
+    }
+
+    #[test]
+    fn merge_returns_false_if_nothing_changed() {
+        let mut s1 = create_service_scale("adam", 3);
+        s1.set_incarnation(1);
+        let s1_check = s1.clone();
+        let s2 = create_service_scale("adam", 5);
+        assert_eq!(s1.merge(s2), false);
+        assert_eq!(s1, s1_check);
+    }
+}
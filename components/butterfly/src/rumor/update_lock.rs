@@ -0,0 +1,197 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The UpdateLock rumor.
+//!
+//! A best-effort, ring-wide semaphore over the updates of a service group. A member with a new
+//! release to update to asks for a slot by gossiping a held lock, and gives the slot back by
+//! gossiping one that isn't held. Each member grants itself a slot once its request has been
+//! among the first the group allows, in the order they were made, for long enough to have reached
+//! the others. This is not consensus: a request that takes longer than that to spread, a clock
+//! that is off, or a partition can let more members than the group allows update together. The
+//! rumor is keyed by the service group, with one per member.
+
+use std::cmp::Ordering;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+
+use habitat_core::service::ServiceGroup;
+use protobuf::Message;
+use time;
+
+use error::Result;
+use message::swim::{UpdateLock as ProtoUpdateLock, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateLock(ProtoRumor);
+
+impl PartialOrd for UpdateLock {
+    fn partial_cmp(&self, other: &UpdateLock) -> Option<Ordering> {
+        if self.get_service_group() != other.get_service_group() ||
+           self.get_member_id() != other.get_member_id() {
+            None
+        } else {
+            Some(self.get_incarnation().cmp(&other.get_incarnation()))
+        }
+    }
+}
+
+impl PartialEq for UpdateLock {
+    fn eq(&self, other: &UpdateLock) -> bool {
+        self.get_service_group() == other.get_service_group() &&
+        self.get_member_id() == other.get_member_id() &&
+        self.get_incarnation() == other.get_incarnation() &&
+        self.get_held() == other.get_held() &&
+        self.get_requested_at() == other.get_requested_at()
+    }
+}
+
+impl From<ProtoRumor> for UpdateLock {
+    fn from(pr: ProtoRumor) -> UpdateLock {
+        UpdateLock(pr)
+    }
+}
+
+impl From<UpdateLock> for ProtoRumor {
+    fn from(update_lock: UpdateLock) -> ProtoRumor {
+        update_lock.0
+    }
+}
+
+impl Deref for UpdateLock {
+    type Target = ProtoUpdateLock;
+
+    fn deref(&self) -> &ProtoUpdateLock {
+        self.0.get_update_lock()
+    }
+}
+
+impl DerefMut for UpdateLock {
+    fn deref_mut(&mut self) -> &mut ProtoUpdateLock {
+        self.0.mut_update_lock()
+    }
+}
+
+impl UpdateLock {
+    /// Creates a new UpdateLock for `member_id`, not yet held.
+    pub fn new<S1>(member_id: S1, service_group: ServiceGroup) -> Self
+        where S1: Into<String>
+    {
+        let mut rumor = ProtoRumor::new();
+        let from_id = member_id.into();
+        rumor.set_from_id(from_id.clone());
+        rumor.set_field_type(ProtoRumor_Type::UpdateLock);
+
+        let mut proto = ProtoUpdateLock::new();
+        proto.set_service_group(format!("{}", service_group));
+        proto.set_member_id(from_id);
+        proto.set_incarnation(0);
+        proto.set_held(false);
+
+        rumor.set_update_lock(proto);
+        UpdateLock(rumor)
+    }
+
+    /// Asks for a slot, as of now.
+    pub fn request(&mut self) {
+        let incarnation = self.get_incarnation() + 1;
+        self.set_incarnation(incarnation);
+        self.set_held(true);
+        self.set_requested_at(time::get_time().sec);
+    }
+
+    /// Gives the slot back.
+    pub fn release(&mut self) {
+        let incarnation = self.get_incarnation() + 1;
+        self.set_incarnation(incarnation);
+        self.set_held(false);
+    }
+}
+
+impl Rumor for UpdateLock {
+    /// Follows a simple pattern; if we have a newer incarnation than the one we already have, the
+    /// new one wins.
+    fn merge(&mut self, mut other: UpdateLock) -> bool {
+        if *self >= other {
+            false
+        } else {
+            mem::swap(self, &mut other);
+            true
+        }
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::UpdateLock
+    }
+
+    fn id(&self) -> &str {
+        self.get_member_id()
+    }
+
+    fn key(&self) -> &str {
+        self.get_service_group()
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(try!(self.0.write_to_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use habitat_core::service::ServiceGroup;
+
+    use super::UpdateLock;
+    use rumor::Rumor;
+
+    fn create_update_lock(member_id: &str) -> UpdateLock {
+        UpdateLock::new(member_id,
+                        ServiceGroup::new("neurosis", "production", None).unwrap())
+    }
+
+    #[test]
+    fn update_locks_of_different_members_are_not_comparable() {
+        let s1 = create_update_lock("adam");
+        let s2 = create_update_lock("shanku");
+        assert_eq!(s1.partial_cmp(&s2), None);
+    }
+
+    #[test]
+    fn request_and_release_bump_the_incarnation() {
+        let mut s1 = create_update_lock("adam");
+        s1.request();
+        assert_eq!(s1.get_held(), true);
+        assert_eq!(s1.get_incarnation(), 1);
+        assert!(s1.get_requested_at() > 0);
+        s1.release();
+        assert_eq!(s1.get_held(), false);
+        assert_eq!(s1.get_incarnation(), 2);
+    }
+
+    #[test]
+    fn merge_chooses_the_higher_incarnation() {
+        let mut s1 = create_update_lock("adam");
+        let mut s2 = create_update_lock("adam");
+        s2.request();
+        let s2_check = s2.clone();
+        assert_eq!(s1.merge(s2), true);
+        assert_eq!(s1, s2_check);
+        assert_eq!(s1.partial_cmp(&s2_check), Some(Ordering::Equal));
+        assert_eq!(s1.merge(create_update_lock("adam")), false);
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compact the member list and rumor stores.
+//!
+//! Members which leave the ring are confirmed departed, but were otherwise remembered forever,
+//! along with the rumors they started. On rings with a lot of churn, such as autoscaling groups,
+//! that grows without bound. This thread periodically forgets members which have been departed
+//! for longer than the server's departed member TTL, see `Server::compact`.
+
+use std::thread;
+use std::time::Duration;
+
+use server::Server;
+
+/// How often the member list and rumor stores are compacted.
+pub const COMPACT_PERIOD_MS: u64 = 60_000;
+
+pub struct Compact<'a> {
+    pub server: &'a Server,
+}
+
+impl<'a> Compact<'a> {
+    /// Takes a reference to a server, returns you a Compact struct.
+    pub fn new(server: &'a Server) -> Compact {
+        Compact { server: server }
+    }
+
+    /// Run the compact thread.
+    pub fn run(&self) {
+        loop {
+            let removed = self.server.compact();
+            if removed > 0 {
+                debug!("Compacted {} departed members", removed);
+            }
+            thread::sleep(Duration::from_millis(COMPACT_PERIOD_MS));
+        }
+    }
+}
@@ -79,6 +79,17 @@ impl<'a> Inbound<'a> {
                         }
                     };
                     debug!("SWIM Message: {:?}", msg);
+                    let delivered = match msg.get_field_type() {
+                        Swim_Type::PING => self.server.deliver(msg.get_ping().get_from().get_id()),
+                        Swim_Type::ACK => self.server.deliver(msg.get_ack().get_from().get_id()),
+                        Swim_Type::PINGREQ => {
+                            self.server.deliver(msg.get_pingreq().get_from().get_id())
+                        }
+                    };
+                    if !delivered {
+                        debug!("Lost SWIM message on the simulated network");
+                        continue;
+                    }
                     match msg.get_field_type() {
                         Swim_Type::PING => {
                             if self.server.check_blacklist(msg.get_ping().get_from().get_id()) {
@@ -143,9 +154,12 @@ impl<'a> Inbound<'a> {
                     return;
                 }
             };
-            // Set the route-back address to the one we received the pingreq from
+            // Set the route-back address to the one we received the pingreq from, unless the
+            // sender advertised its own
             let mut from = msg.mut_pingreq().take_from();
-            from.set_address(format!("{}", addr.ip()));
+            if from.get_address().is_empty() {
+                from.set_address(format!("{}", addr.ip()));
+            }
             outbound::ping(self.server,
                            &self.socket,
                            target,
@@ -182,7 +196,9 @@ impl<'a> Inbound<'a> {
                       msg.get_ack().get_forward_to().get_id(),
                       msg.get_ack().get_forward_to().get_address(),
                       );
-                msg.mut_ack().mut_from().set_address(format!("{}", addr.ip()));
+                if msg.get_ack().get_from().get_address().is_empty() {
+                    msg.mut_ack().mut_from().set_address(format!("{}", addr.ip()));
+                }
                 outbound::forward_ack(self.server, &self.socket, forward_to_addr, msg);
                 return;
             }
@@ -218,11 +234,13 @@ impl<'a> Inbound<'a> {
         } else {
             outbound::ack(self.server, &self.socket, &target, addr, None);
         }
-        // Populate the member for this sender with its remote address
+        // Populate the member for this sender with its remote address, unless it advertised one
         let from = {
             let mut ping = msg.mut_ping();
             let mut from = ping.take_from();
-            from.set_address(format!("{}", addr.ip()));
+            if from.get_address().is_empty() {
+                from.set_address(format!("{}", addr.ip()));
+            }
             from
         };
         info!("Ping from {}@{}", from.get_id(), addr);
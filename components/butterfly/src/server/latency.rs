@@ -0,0 +1,163 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Round trip times of the failure detector's pings.
+//!
+//! Whenever the outbound thread pings a member directly and gets its ack, the time from sending
+//! the ping to handling the ack is recorded against the member, as is every ping that goes
+//! unanswered. Acks to a PingReq come back by way of another member, so they aren't timed. The
+//! outbound thread checks for acks every `PING_RECV_QUEUE_EMPTY_SLEEP_MS`, which bounds how
+//! precise the times are.
+
+use std::collections::{BTreeMap, HashMap};
+use std::result;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use serde::{Serialize, Serializer};
+
+use member::Member;
+
+/// How much each new round trip counts towards the smoothed one, as in TCP's smoothed RTT.
+const SMOOTHING: f64 = 0.125;
+
+/// The round trip times to one member.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct PeerLatency {
+    /// The member's alias, if it has one.
+    pub alias: Option<String>,
+    /// The latest round trip, in milliseconds. This and the other times are zero until the first
+    /// ack.
+    pub last_ms: f64,
+    /// A moving average of the round trips, in milliseconds.
+    pub smoothed_ms: f64,
+    pub min_ms: f64,
+    pub max_ms: f64,
+    /// The number of pings the member acked.
+    pub acks: u64,
+    /// The number of pings the member didn't ack in time.
+    pub timeouts: u64,
+}
+
+/// The round trip times to every member we have pinged, by member id.
+#[derive(Debug, Clone, Default)]
+pub struct LatencyMap {
+    peers: Arc<RwLock<HashMap<String, PeerLatency>>>,
+}
+
+impl LatencyMap {
+    /// Records that the member acked a ping after `rtt`.
+    pub fn record_ack(&self, member: &Member, rtt: Duration) {
+        let ms = rtt.as_secs() as f64 * 1000.0 + rtt.subsec_nanos() as f64 / 1_000_000.0;
+        let mut peers = self.peers.write().expect("Latency map lock is poisoned");
+        let peer = peer_entry(&mut peers, member);
+        if peer.acks == 0 {
+            peer.smoothed_ms = ms;
+            peer.min_ms = ms;
+            peer.max_ms = ms;
+        } else {
+            peer.smoothed_ms += SMOOTHING * (ms - peer.smoothed_ms);
+            peer.min_ms = peer.min_ms.min(ms);
+            peer.max_ms = peer.max_ms.max(ms);
+        }
+        peer.last_ms = ms;
+        peer.acks += 1;
+    }
+
+    /// Records that the member didn't ack a ping in time.
+    pub fn record_timeout(&self, member: &Member) {
+        let mut peers = self.peers.write().expect("Latency map lock is poisoned");
+        peer_entry(&mut peers, member).timeouts += 1;
+    }
+
+    /// Forgets a member's round trip times.
+    pub fn remove(&self, member_id: &str) {
+        self.peers.write().expect("Latency map lock is poisoned").remove(member_id);
+    }
+
+    pub fn get(&self, member_id: &str) -> Option<PeerLatency> {
+        self.peers.read().expect("Latency map lock is poisoned").get(member_id).cloned()
+    }
+
+    /// Returns the round trip times to every member, ordered by member id.
+    pub fn snapshot(&self) -> BTreeMap<String, PeerLatency> {
+        self.peers
+            .read()
+            .expect("Latency map lock is poisoned")
+            .iter()
+            .map(|(id, peer)| (id.clone(), peer.clone()))
+            .collect()
+    }
+}
+
+/// Returns the member's entry, with its alias brought up to date.
+fn peer_entry<'a>(peers: &'a mut HashMap<String, PeerLatency>,
+                  member: &Member)
+                  -> &'a mut PeerLatency {
+    let peer = peers.entry(String::from(member.get_id())).or_insert_with(PeerLatency::default);
+    if member.has_alias() {
+        peer.alias = Some(String::from(member.get_alias()));
+    }
+    peer
+}
+
+impl Serialize for LatencyMap {
+    fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error>
+        where S: Serializer
+    {
+        self.snapshot().serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use member::Member;
+    use super::LatencyMap;
+
+    #[test]
+    fn record_ack_tracks_round_trips() {
+        let latency = LatencyMap::default();
+        let mut a = Member::new();
+        a.set_alias(String::from("web-1"));
+        latency.record_ack(&a, Duration::from_millis(10));
+        latency.record_ack(&a, Duration::from_millis(18));
+        latency.record_ack(&a, Duration::from_millis(6));
+        let peer = latency.get(a.get_id()).unwrap();
+        assert_eq!(peer.alias, Some(String::from("web-1")));
+        assert_eq!(peer.last_ms, 6.0);
+        assert_eq!(peer.min_ms, 6.0);
+        assert_eq!(peer.max_ms, 18.0);
+        assert_eq!(peer.acks, 3);
+        // 10, then 10 + (18 - 10) / 8 = 11, then 11 + (6 - 11) / 8 = 10.375
+        assert_eq!(peer.smoothed_ms, 10.375);
+    }
+
+    #[test]
+    fn record_timeout_keeps_round_trips() {
+        let latency = LatencyMap::default();
+        let a = Member::new();
+        latency.record_timeout(&a);
+        latency.record_ack(&a, Duration::from_millis(4));
+        latency.record_timeout(&a);
+        let peer = latency.get(a.get_id()).unwrap();
+        assert_eq!(peer.timeouts, 2);
+        assert_eq!(peer.min_ms, 4.0);
+        assert_eq!(peer.smoothed_ms, 4.0);
+
+        latency.remove(a.get_id());
+        assert!(latency.snapshot().is_empty());
+    }
+}
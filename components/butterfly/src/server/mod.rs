@@ -15,25 +15,27 @@
 //! The Butterfly server.
 //!
 //! Creates `Server` structs, that hold everything we need to run the SWIM and Gossip protocol.
-//! Winds up with 5 separate threads - inbound (incoming connections), outbound (the Probe
-//! protocol), expire (turning Suspect members into Confirmed members), push (the fan-out rumors),
-//! and pull (the inbound receipt of rumors.).
+//! Winds up with 6 separate threads - inbound (incoming connections), outbound (the Probe
+//! protocol), expire (turning Suspect members into Confirmed members), compact (forgetting
+//! long departed members), push (the fan-out rumors), and pull (the inbound receipt of rumors.).
 
+pub mod compact;
 pub mod expire;
 pub mod inbound;
+pub mod latency;
 pub mod outbound;
 pub mod pull;
 pub mod push;
 pub mod timing;
 
-use std::collections::{HashSet, HashMap};
+use std::collections::{BTreeMap, HashSet, HashMap};
 use std::fmt;
 use std::io;
 use std::net::{ToSocketAddrs, UdpSocket, SocketAddr};
 use std::result;
 use std::str::FromStr;
 use std::sync::{Arc, RwLock};
-use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering};
 use std::sync::mpsc::channel;
 use std::time::Duration;
 use std::thread;
@@ -41,17 +43,39 @@ use std::thread;
 use habitat_core::service::ServiceGroup;
 use habitat_core::crypto::SymKey;
 use serde::{Serialize, Serializer};
+use time::{self, Duration as TimeDuration};
 use toml;
 
 use error::{Result, Error};
 use member::{Member, Health, MemberList};
 use trace::{Trace, TraceKind};
 use rumor::{Rumor, RumorStore, RumorList, RumorKey};
+use server::latency::LatencyMap;
 use rumor::service::Service;
-use rumor::service_config::ServiceConfig;
+use rumor::service_config::{self, ServiceConfig};
 use rumor::service_file::ServiceFile;
+use rumor::service_restart::ServiceRestart;
+use rumor::service_scale::ServiceScale;
+use rumor::update_lock::UpdateLock;
+use rumor::active_color::ActiveColor;
+use rumor::ring_command::RingCommand;
+use rumor::ring_command_ack::RingCommandAck;
 use rumor::election::{Election, ElectionUpdate};
+#[cfg(feature = "simulation")]
+use simulation::{Link, Network};
 use message;
+use message::compression::Compression;
+
+/// How long a member stays in the member list after it is confirmed departed, by default.
+pub const DEFAULT_DEPARTED_MEMBER_TTL_SECS: usize = 24 * 60 * 60;
+
+/// How long a request for an update slot has to reach the rest of the ring before it is granted,
+/// so that every member is likely to see the same requests ahead of it.
+pub const UPDATE_LOCK_SETTLE_SECS: i64 = 10;
+
+/// The suitability a member stands with in an election it forces; members otherwise stand with
+/// none.
+const FORCED_ELECTION_SUITABILITY: u64 = 1;
 
 /// The server struct. Is thread-safe.
 #[derive(Debug, Clone)]
@@ -60,35 +84,71 @@ pub struct Server {
     pub member_id: Arc<String>,
     pub member: Arc<RwLock<Member>>,
     pub member_list: MemberList,
-    pub ring_key: Arc<Option<SymKey>>,
+    pub ring_key: Arc<RwLock<Option<SymKey>>>,
+    /// The key the ring used before the last call to `set_ring_key`, which messages from members
+    /// that haven't switched yet are still decrypted with.
+    pub previous_ring_key: Arc<RwLock<Option<SymKey>>>,
     pub rumor_list: RumorList,
     pub service_store: RumorStore<Service>,
     pub service_config_store: RumorStore<ServiceConfig>,
     pub service_file_store: RumorStore<ServiceFile>,
+    pub service_scale_store: RumorStore<ServiceScale>,
+    pub active_color_store: RumorStore<ActiveColor>,
+    pub ring_command_store: RumorStore<RingCommand>,
+    pub ring_command_ack_store: RumorStore<RingCommandAck>,
+    pub service_restart_store: RumorStore<ServiceRestart>,
+    pub update_lock_store: RumorStore<UpdateLock>,
     pub election_store: RumorStore<Election>,
     pub update_store: RumorStore<ElectionUpdate>,
     pub swim_addr: Arc<RwLock<SocketAddr>>,
     pub gossip_addr: Arc<RwLock<SocketAddr>>,
+    pub push_workers: Arc<AtomicUsize>,
+    pub fanout: Arc<AtomicUsize>,
+    pub compression: Arc<RwLock<Option<Compression>>>,
+    pub gossip_payload_bytes: Arc<AtomicUsize>,
+    pub gossip_wire_bytes: Arc<AtomicUsize>,
+    pub departed_member_ttl: Arc<AtomicUsize>,
+    /// Round trip times of the failure detector's pings to each member.
+    pub latency: LatencyMap,
     // These are all here for testing support
     pub pause: Arc<AtomicBool>,
     pub trace: Arc<RwLock<Trace>>,
     pub swim_rounds: Arc<AtomicIsize>,
     pub gossip_rounds: Arc<AtomicIsize>,
     pub blacklist: Arc<RwLock<HashSet<String>>>,
+    #[cfg(feature = "simulation")]
+    pub network: Arc<RwLock<Network>>,
 }
 
 impl Serialize for Server {
     fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error>
         where S: Serializer
     {
-        let mut state = try!(serializer.serialize_struct("butterfly", 5));
+        let mut state = try!(serializer.serialize_struct("butterfly", 11));
         try!(serializer.serialize_struct_elt(&mut state, "service", &self.service_store));
         try!(serializer.serialize_struct_elt(&mut state,
                                              "service_config",
                                              &self.service_config_store));
         try!(serializer.serialize_struct_elt(&mut state, "service_file", &self.service_file_store));
+        try!(serializer.serialize_struct_elt(&mut state,
+                                             "service_scale",
+                                             &self.service_scale_store));
+        try!(serializer.serialize_struct_elt(&mut state,
+                                             "active_color",
+                                             &self.active_color_store));
+        try!(serializer.serialize_struct_elt(&mut state,
+                                             "ring_command",
+                                             &self.ring_command_store));
+        try!(serializer.serialize_struct_elt(&mut state,
+                                             "ring_command_ack",
+                                             &self.ring_command_ack_store));
+        try!(serializer.serialize_struct_elt(&mut state,
+                                             "service_restart",
+                                             &self.service_restart_store));
+        try!(serializer.serialize_struct_elt(&mut state, "update_lock", &self.update_lock_store));
         try!(serializer.serialize_struct_elt(&mut state, "election", &self.election_store));
         try!(serializer.serialize_struct_elt(&mut state, "election_update", &self.update_store));
+        try!(serializer.serialize_struct_elt(&mut state, "latency", &self.latency));
         serializer.serialize_struct_end(state)
     }
 }
@@ -108,20 +168,39 @@ impl Server {
 
         match (maybe_swim_socket_addr, maybe_gossip_socket_addr) {
             (Ok(Some(swim_socket_addr)), Ok(Some(gossip_socket_addr))) => {
+                let name = name.unwrap_or(String::from(member.get_id()));
                 Ok(Server {
-                    name: Arc::new(name.unwrap_or(String::from(member.get_id()))),
+                    // The simulated network is seeded with the name, before it is moved
+                    #[cfg(feature = "simulation")]
+                    network: Arc::new(RwLock::new(Network::new(&name))),
+                    name: Arc::new(name),
                     member_id: Arc::new(String::from(member.get_id())),
                     member: Arc::new(RwLock::new(member)),
                     member_list: MemberList::new(),
-                    ring_key: Arc::new(ring_key),
+                    ring_key: Arc::new(RwLock::new(ring_key)),
+                    previous_ring_key: Arc::new(RwLock::new(None)),
                     rumor_list: RumorList::default(),
                     service_store: RumorStore::default(),
                     service_config_store: RumorStore::default(),
                     service_file_store: RumorStore::default(),
+                    service_scale_store: RumorStore::default(),
+                    active_color_store: RumorStore::default(),
+                    ring_command_store: RumorStore::default(),
+                    ring_command_ack_store: RumorStore::default(),
+                    service_restart_store: RumorStore::default(),
+                    update_lock_store: RumorStore::default(),
                     election_store: RumorStore::default(),
                     update_store: RumorStore::default(),
                     swim_addr: Arc::new(RwLock::new(swim_socket_addr)),
                     gossip_addr: Arc::new(RwLock::new(gossip_socket_addr)),
+                    push_workers: Arc::new(AtomicUsize::new(0)),
+                    fanout: Arc::new(AtomicUsize::new(push::FANOUT)),
+                    compression: Arc::new(RwLock::new(None)),
+                    gossip_payload_bytes: Arc::new(AtomicUsize::new(0)),
+                    gossip_wire_bytes: Arc::new(AtomicUsize::new(0)),
+                    departed_member_ttl:
+                        Arc::new(AtomicUsize::new(DEFAULT_DEPARTED_MEMBER_TTL_SECS)),
+                    latency: LatencyMap::default(),
                     pause: Arc::new(AtomicBool::new(false)),
                     trace: Arc::new(RwLock::new(trace)),
                     swim_rounds: Arc::new(AtomicIsize::new(0)),
@@ -247,6 +326,12 @@ impl Server {
             panic!("You should never, ever get here, liu");
         });
 
+        let server_f = self.clone();
+        let _ = thread::Builder::new().name(format!("compact-{}", self.name())).spawn(move || {
+            compact::Compact::new(&server_f).run();
+            panic!("You should never, ever get here, fiona");
+        });
+
         Ok(())
     }
 
@@ -268,6 +353,207 @@ impl Server {
         blacklist.contains(member_id)
     }
 
+    /// Simulate the link messages from `member_id` arrive over.
+    #[cfg(feature = "simulation")]
+    pub fn set_link(&self, member_id: String, link: Link) {
+        let mut network = self.network.write().expect("Write lock for network is poisoned");
+        network.set_link(member_id, link);
+    }
+
+    /// Stop simulating the link from `member_id`, so its messages arrive at once again.
+    #[cfg(feature = "simulation")]
+    pub fn remove_link(&self, member_id: &str) {
+        let mut network = self.network.write().expect("Write lock for network is poisoned");
+        network.remove_link(member_id);
+    }
+
+    /// Simulate the link messages from members without a link of their own arrive over, or stop
+    /// simulating it with `None`.
+    #[cfg(feature = "simulation")]
+    pub fn set_default_link(&self, link: Option<Link>) {
+        let mut network = self.network.write().expect("Write lock for network is poisoned");
+        network.set_default_link(link);
+    }
+
+    /// Check whether a message from `member_id` arrives over the simulated network, after
+    /// waiting for as long as it takes to.
+    #[cfg(feature = "simulation")]
+    pub fn deliver(&self, member_id: &str) -> bool {
+        let delay = self.network
+            .write()
+            .expect("Write lock for network is poisoned")
+            .transmit(member_id);
+        match delay {
+            Some(latency) => {
+                if latency > Duration::from_millis(0) {
+                    thread::sleep(latency);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Without a simulated network, every message arrives.
+    #[cfg(not(feature = "simulation"))]
+    #[inline]
+    pub fn deliver(&self, _member_id: &str) -> bool {
+        true
+    }
+
+    /// Set the number of threads the push thread uses to send rumors, rather than a new thread for
+    /// each member it sends to. Takes effect when the server is started.
+    pub fn set_push_workers(&self, workers: usize) {
+        self.push_workers.store(workers, Ordering::Relaxed);
+    }
+
+    /// Set how many members the push thread sends rumors to in each gossip period. Takes effect
+    /// when the server is started.
+    pub fn set_fanout(&self, fanout: usize) {
+        self.fanout.store(fanout, Ordering::Relaxed);
+    }
+
+    /// Set the codec gossip is compressed with, for members which advertise it. `None` sends all
+    /// gossip uncompressed.
+    pub fn set_compression(&self, compression: Option<Compression>) {
+        *self.compression.write().expect("Compression lock is poisoned") = compression;
+    }
+
+    /// Switches the key the ring's messages are encrypted with. Messages encrypted with the key
+    /// it replaces are still accepted, so members can switch one at a time.
+    pub fn set_ring_key(&self, ring_key: Option<SymKey>) {
+        let mut current = self.ring_key.write().expect("Ring key lock is poisoned");
+        *self.previous_ring_key.write().expect("Ring key lock is poisoned") = current.take();
+        *current = ring_key;
+    }
+
+    /// Returns the codec to compress gossip sent to `member` with: ours, if they can decompress
+    /// it.
+    pub fn compression_for(&self, member: &Member) -> Option<Compression> {
+        match *self.compression.read().expect("Compression lock is poisoned") {
+            Some(codec) if member.get_compression().iter().any(|name| name == codec.name()) => {
+                Some(codec)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the bytes of gossip this server has sent before and after compression, counting
+    /// encryption and framing in the latter.
+    pub fn gossip_bytes(&self) -> (usize, usize) {
+        (self.gossip_payload_bytes.load(Ordering::Relaxed),
+         self.gossip_wire_bytes.load(Ordering::Relaxed))
+    }
+
+    /// Set how long a member stays in the member list after it is confirmed departed.
+    pub fn set_departed_member_ttl(&self, secs: usize) {
+        self.departed_member_ttl.store(secs, Ordering::Relaxed);
+    }
+
+    /// Forgets members which have been confirmed departed for longer than their departed member
+    /// TTL, along with the rumors they started, and stops sharing rumors about them. Rumors which
+    /// belong to a service group, such as its configuration, are kept, but elections the members
+    /// were leading are started over. Returns how many members were forgotten.
+    pub fn compact(&self) -> usize {
+        let ring_ttl = self.departed_member_ttl.load(Ordering::Relaxed) as u64;
+        let departed: Vec<String> = self.member_list
+            .departed()
+            .into_iter()
+            .filter(|&(ref id, departed)| {
+                departed >= TimeDuration::seconds(self.departed_ttl_of(id, ring_ttl) as i64)
+            })
+            .map(|(id, _)| id)
+            .collect();
+        for member_id in departed.iter() {
+            debug!("Forgetting departed member {}", member_id);
+            self.member_list.remove(member_id);
+            self.latency.remove(member_id);
+            self.service_store.remove_by_id(member_id);
+            self.ring_command_ack_store.remove_by_id(member_id);
+            self.update_lock_store.remove_by_id(member_id);
+            self.rumor_list.remove_by_id(member_id);
+            self.restart_elections_led_by(member_id);
+        }
+        departed.len()
+    }
+
+    /// Returns how long the member is kept once it is confirmed departed, in seconds: the longest
+    /// of the departed member TTLs its services were started with, where a service started
+    /// without one, or a member without services, is kept for the ring's.
+    fn departed_ttl_of(&self, member_id: &str, ring_ttl: u64) -> u64 {
+        let mut ttl = None;
+        self.service_store.with_keys(|(_, rumors)| {
+            if let Some(service) = rumors.get(member_id) {
+                let service_ttl = match service.get_departed_ttl() {
+                    0 => ring_ttl,
+                    secs => secs,
+                };
+                ttl = Some(ttl.map_or(service_ttl, |ttl: u64| ttl.max(service_ttl)));
+            }
+        });
+        ttl.unwrap_or(ring_ttl)
+    }
+
+    /// Starts over the elections of our service groups which a forgotten member was leading, with
+    /// a new term, and drops those of the groups we aren't in. With the member gone, nothing else
+    /// would notice their leader is dead.
+    fn restart_elections_led_by(&self, member_id: &str) {
+        let mut elections = Vec::new();
+        self.election_store.with_keys(|(service_group, rumors)| {
+            if let Some(election) = rumors.get("election") {
+                if election.get_member_id() == member_id {
+                    elections.push((service_group.clone(), election.get_term()));
+                }
+            }
+        });
+        let mut update_elections = Vec::new();
+        self.update_store.with_keys(|(service_group, rumors)| {
+            if let Some(election) = rumors.get("election") {
+                if election.get_member_id() == member_id {
+                    update_elections.push((service_group.clone(), election.get_term()));
+                }
+            }
+        });
+
+        for (service_group, term) in elections {
+            self.election_store.remove(&service_group, "election");
+            if !self.service_store.contains_rumor(&service_group, self.member_id()) {
+                continue;
+            }
+            match ServiceGroup::from_str(&service_group) {
+                Ok(sg) => {
+                    warn!("Starting a new election for {} {}, as its leader {} was forgotten",
+                          sg,
+                          term + 1,
+                          member_id);
+                    self.start_election(sg, 0, term + 1);
+                }
+                Err(e) => error!("Failed to process service group from string '{}': {}",
+                                 service_group,
+                                 e),
+            }
+        }
+        for (service_group, term) in update_elections {
+            self.update_store.remove(&service_group, "election");
+            if !self.service_store.contains_rumor(&service_group, self.member_id()) {
+                continue;
+            }
+            match ServiceGroup::from_str(&service_group) {
+                Ok(sg) => {
+                    warn!("Starting a new update election for {} {}, as its leader {} was \
+                           forgotten",
+                          sg,
+                          term + 1,
+                          member_id);
+                    self.start_update_election(sg, 0, term + 1);
+                }
+                Err(e) => error!("Failed to process service group from string '{}': {}",
+                                 service_group,
+                                 e),
+            }
+        }
+    }
+
     /// Stop the outbound and inbound threads from processing work.
     pub fn pause(&mut self) {
         self.pause.compare_and_swap(false, true, Ordering::Relaxed);
@@ -416,6 +702,114 @@ impl Server {
         }
     }
 
+    /// Insert a service scale rumor into the service scale store.
+    pub fn insert_service_scale(&self, service_scale: ServiceScale) {
+        let rk = RumorKey::from(&service_scale);
+        if self.service_scale_store.insert(service_scale) {
+            self.rumor_list.insert(rk);
+        }
+    }
+
+    /// Insert an active color rumor into the active color store.
+    pub fn insert_active_color(&self, active_color: ActiveColor) {
+        let rk = RumorKey::from(&active_color);
+        if self.active_color_store.insert(active_color) {
+            self.rumor_list.insert(rk);
+        }
+    }
+
+    /// Insert a ring command rumor into the ring command store.
+    pub fn insert_ring_command(&self, ring_command: RingCommand) {
+        let rk = RumorKey::from(&ring_command);
+        if self.ring_command_store.insert(ring_command) {
+            self.rumor_list.insert(rk);
+        }
+    }
+
+    /// Insert a ring command ack rumor into the ring command ack store.
+    pub fn insert_ring_command_ack(&self, ring_command_ack: RingCommandAck) {
+        let rk = RumorKey::from(&ring_command_ack);
+        if self.ring_command_ack_store.insert(ring_command_ack) {
+            self.rumor_list.insert(rk);
+        }
+    }
+
+    /// Insert a service restart rumor into the service restart store.
+    pub fn insert_service_restart(&self, service_restart: ServiceRestart) {
+        let rk = RumorKey::from(&service_restart);
+        if self.service_restart_store.insert(service_restart) {
+            self.rumor_list.insert(rk);
+        }
+    }
+
+    /// Insert an update lock rumor into the update lock store.
+    pub fn insert_update_lock(&self, update_lock: UpdateLock) {
+        let rk = RumorKey::from(&update_lock);
+        if self.update_lock_store.insert(update_lock) {
+            self.rumor_list.insert(rk);
+        }
+    }
+
+    /// Asks for one of the service group's `limit` update slots, if we haven't already, and
+    /// returns whether we have one. Requests are granted in the order they were made, ties going
+    /// to the lower member id; ours is granted once it has had `UPDATE_LOCK_SETTLE_SECS` to reach
+    /// the ring and fewer than `limit` requests are ahead of it. Requests of members confirmed
+    /// dead don't count, so a member that dies mid-update doesn't hold up the group.
+    ///
+    /// This is best-effort: each member decides from the requests it has heard of, so when
+    /// gossip is slow to spread, clocks disagree, or the ring is partitioned, more than `limit`
+    /// members can hold a slot at once.
+    pub fn request_update_lock(&self, service_group: &ServiceGroup, limit: usize) -> bool {
+        let mut update_lock = self.update_lock(service_group);
+        let requested_at = if update_lock.get_held() {
+            update_lock.get_requested_at()
+        } else {
+            update_lock.request();
+            let requested_at = update_lock.get_requested_at();
+            self.insert_update_lock(update_lock);
+            requested_at
+        };
+        if time::get_time().sec - requested_at < UPDATE_LOCK_SETTLE_SECS {
+            return false;
+        }
+        self.update_lock_queue(&service_group.to_string())
+            .iter()
+            .take(limit)
+            .any(|member_id| member_id == self.member_id())
+    }
+
+    /// Gives back our update slot of the service group, or our request for one.
+    pub fn release_update_lock(&self, service_group: &ServiceGroup) {
+        let mut update_lock = self.update_lock(service_group);
+        if update_lock.get_held() {
+            update_lock.release();
+            self.insert_update_lock(update_lock);
+        }
+    }
+
+    /// Returns the ids of the members holding or waiting for an update slot of the service
+    /// group, in the order their requests are granted.
+    pub fn update_lock_queue(&self, service_group: &str) -> Vec<String> {
+        let mut requests = vec![];
+        self.update_lock_store.with_rumors(service_group, |ul| {
+            if ul.get_held() &&
+               !self.member_list.check_health_of_by_id(ul.get_member_id(), Health::Confirmed) {
+                requests.push((ul.get_requested_at(), String::from(ul.get_member_id())));
+            }
+        });
+        requests.sort();
+        requests.into_iter().map(|(_, member_id)| member_id).collect()
+    }
+
+    /// Returns our update lock rumor for the service group, or a new one if we have none.
+    fn update_lock(&self, service_group: &ServiceGroup) -> UpdateLock {
+        let mut update_lock = None;
+        self.update_lock_store.with_rumor(&service_group.to_string(),
+                                          self.member_id(),
+                                          |maybe_ul| update_lock = maybe_ul.cloned());
+        update_lock.unwrap_or_else(|| UpdateLock::new(self.member_id(), service_group.clone()))
+    }
+
     /// Get all the Member ID's who are present in a given service group.
     pub fn get_electorate(&self, key: &str) -> Vec<String> {
         let mut electorate = vec![];
@@ -471,6 +865,22 @@ impl Server {
         self.rumor_list.insert(ek);
     }
 
+    /// Starts over the election of a service group with the next term, whether or not its leader
+    /// is alive, and returns that term. Every other member joins the new term as it would after
+    /// the death of a leader, but with no suitability; this member stands with some, so it wins
+    /// the election as long as the group has quorum, rather than the member whose id sorts
+    /// highest, which may well be the old leader.
+    pub fn force_election(&self, sg: ServiceGroup) -> u64 {
+        let mut term = 0;
+        self.election_store.with_rumor(&sg.to_string(), "election", |election| {
+            term = election.map_or(0, |e| e.get_term()) + 1;
+        });
+        warn!("Forcing a new election for {} {}", sg, term);
+        self.election_store.remove(&sg.to_string(), "election");
+        self.start_election(sg, FORCED_ELECTION_SUITABILITY, term);
+        term
+    }
+
     /// Check to see if this server needs to restart a given election. This happens when:
     ///
     /// a) We are the leader, and we have lost quorum with the rest of the group.
@@ -746,35 +1156,111 @@ impl Server {
         service_files
     }
 
-    /// Returns (incarnation, config) if the service group has a configuration.
+    /// Returns (incarnation, config, patch) if the service group has a configuration. When
+    /// `patch` is true the config is a patch to merge over the configuration already in use,
+    /// because we never held the configuration it patches, or couldn't read it. Of the group's
+    /// configuration and the latest one for only some of its members, the newer one which selects
+    /// this member, carrying `labels`, is returned.
     pub fn service_config_for(&self,
                               service_group: &str,
-                              incarnation: Option<u64>)
-                              -> Option<(u64, toml::Value)> {
+                              incarnation: Option<u64>,
+                              labels: &BTreeMap<String, String>)
+                              -> Option<(u64, toml::Value, bool)> {
+        let mut newest: Option<ServiceConfig> = None;
+        for id in &[service_config::GROUP_ID, service_config::SELECTED_ID] {
+            self.service_config_store.with_rumor(service_group, id, |maybe_sc| {
+                if let Some(sc) = maybe_sc {
+                    let newer = newest.as_ref()
+                        .map_or(true, |n| sc.get_incarnation() > n.get_incarnation());
+                    if newer && sc.selects(self.member_id(), labels) {
+                        newest = Some(sc.clone());
+                    }
+                }
+            });
+        }
+        match newest {
+            Some(ref sc) if incarnation.map_or(true, |i| sc.get_incarnation() > i) => {
+                match sc.config() {
+                    Ok(config) => Some((sc.get_incarnation(), config, sc.get_patch())),
+                    Err(err) => {
+                        warn!("{}", err);
+                        None
+                    }
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns (incarnation, desired_count) if the service group has a desired count.
+    pub fn service_scale_for(&self, service_group: &str) -> Option<(u64, u64)> {
         let mut result = None;
-        self.service_config_store.with_rumor(service_group,
-                                             "service_config",
-                                             |maybe_sc| if let Some(sc) = maybe_sc {
-                                                 if incarnation.is_none() ||
-                                                    sc.get_incarnation() > incarnation.unwrap() {
-                                                     match sc.config() {
-                                                         Ok(config) => {
-                                                             result = Some((sc.get_incarnation(),
-                                                                            config))
-                                                         }
-                                                         Err(err) => warn!("{}", err),
-                                                     }
-                                                 }
-                                             });
+        self.service_scale_store.with_rumor(service_group,
+                                            "service_scale",
+                                            |maybe_ss| if let Some(ss) = maybe_ss {
+                                                result = Some((ss.get_incarnation(),
+                                                               ss.get_desired_count()))
+                                            });
+        result
+    }
+
+    /// Returns (incarnation, group) if the service has an active color. The service includes its
+    /// organization, if it has one (ex: `app@acme`).
+    pub fn active_color_for(&self, service: &str) -> Option<(u64, String)> {
+        let mut result = None;
+        self.active_color_store.with_rumor(service,
+                                           "active_color",
+                                           |maybe_ac| if let Some(ac) = maybe_ac {
+                                               result = Some((ac.get_incarnation(),
+                                                              ac.get_group().to_string()))
+                                           });
+        result
+    }
+
+    /// Returns (incarnation, rolling) if the service group has been asked to restart.
+    pub fn service_restart_for(&self, service_group: &str) -> Option<(u64, bool)> {
+        let mut result = None;
+        self.service_restart_store.with_rumor(service_group,
+                                              "service_restart",
+                                              |maybe_sr| if let Some(sr) = maybe_sr {
+                                                  result = Some((sr.get_incarnation(),
+                                                                 sr.get_rolling()))
+                                              });
         result
     }
 
     fn generate_wire(&self, payload: Vec<u8>) -> Result<Vec<u8>> {
-        message::generate_wire(payload, &self.ring_key)
+        message::generate_wire(payload,
+                               &*self.ring_key.read().expect("Ring key lock is poisoned"),
+                               None)
+    }
+
+    fn generate_gossip_wire(&self, payload: Vec<u8>, member: &Member) -> Result<Vec<u8>> {
+        let payload_len = payload.len();
+        let wire = {
+            let ring_key = self.ring_key.read().expect("Ring key lock is poisoned");
+            try!(message::generate_wire(payload, &*ring_key, self.compression_for(member)))
+        };
+        self.gossip_payload_bytes.fetch_add(payload_len, Ordering::Relaxed);
+        self.gossip_wire_bytes.fetch_add(wire.len(), Ordering::Relaxed);
+        Ok(wire)
     }
 
     fn unwrap_wire(&self, payload: &[u8]) -> Result<Vec<u8>> {
-        message::unwrap_wire(payload, &self.ring_key)
+        let result = {
+            let ring_key = self.ring_key.read().expect("Ring key lock is poisoned");
+            message::unwrap_wire(payload, &*ring_key)
+        };
+        if result.is_ok() {
+            return result;
+        }
+        let previous = self.previous_ring_key.read().expect("Ring key lock is poisoned");
+        if previous.is_some() {
+            if let Ok(unwrapped) = message::unwrap_wire(payload, &*previous) {
+                return Ok(unwrapped);
+            }
+        }
+        result
     }
 }
 
@@ -791,9 +1277,16 @@ impl fmt::Display for Server {
 #[cfg(test)]
 mod tests {
     mod server {
+        use std::str::FromStr;
+
+        use habitat_core::package::{Identifiable, PackageIdent};
+        use habitat_core::service::ServiceGroup;
+
         use server::Server;
         use server::timing::Timing;
-        use member::Member;
+        use member::{Health, Member};
+        use rumor::election::Election;
+        use rumor::service::{Service, SysInfo};
         use trace::Trace;
         use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
 
@@ -843,5 +1336,81 @@ mod tests {
             let server = start_server();
             server.start(Timing::default()).expect("Server failed to start");
         }
+
+        fn create_service(member_id: &str, name: &str) -> Service {
+            let pkg = PackageIdent::from_str(&format!("core/{}/1.2.3/20161208121212", name))
+                .unwrap();
+            let sg = ServiceGroup::new(pkg.name(), "production", None).unwrap();
+            Service::new(member_id.to_string(), &pkg, &sg, &SysInfo::default(), None).unwrap()
+        }
+
+        #[test]
+        fn compact_keeps_members_for_the_longest_ttl_of_their_services() {
+            let server = start_server();
+            server.set_departed_member_ttl(0);
+            let short = Member::new();
+            let long = Member::new();
+            server.member_list.insert(short.clone(), Health::Confirmed);
+            server.member_list.insert(long.clone(), Health::Confirmed);
+            let mut service = create_service(long.get_id(), "neurosis");
+            service.set_departed_ttl(3600);
+            server.insert_service(service);
+            server.insert_service(create_service(long.get_id(), "overwatch"));
+            assert_eq!(server.departed_ttl_of(long.get_id(), 0), 3600);
+            assert_eq!(server.departed_ttl_of(short.get_id(), 60), 60);
+
+            assert_eq!(server.compact(), 1);
+            assert!(!server.member_list.contains_member(short.get_id()));
+            assert!(server.member_list.contains_member(long.get_id()));
+            assert_eq!(server.service_store.len_for_key("neurosis.production"), 1);
+        }
+
+        #[test]
+        fn compact_restarts_elections_led_by_forgotten_members() {
+            let server = start_server();
+            server.set_departed_member_ttl(0);
+            let leader = Member::new();
+            server.member_list.insert(leader.clone(), Health::Confirmed);
+            let ours = ServiceGroup::new("neurosis", "production", None).unwrap();
+            let theirs = ServiceGroup::new("overwatch", "production", None).unwrap();
+            server.insert_service(create_service(server.member_id(), "neurosis"));
+            server.insert_service(create_service(leader.get_id(), "neurosis"));
+            server.insert_service(create_service(leader.get_id(), "overwatch"));
+            let mut election = Election::new(leader.get_id(), ours.clone(), 0);
+            election.finish();
+            server.election_store.insert(election);
+            server.election_store.insert(Election::new(leader.get_id(), theirs.clone(), 0));
+
+            assert_eq!(server.compact(), 1);
+            server.election_store.with_rumor(&ours.to_string(), "election", |election| {
+                let election = election.expect("Election wasn't restarted");
+                assert_eq!(election.get_member_id(), server.member_id());
+                assert_eq!(election.get_term(), 1);
+            });
+            assert!(!server.election_store.contains_rumor(&theirs.to_string(), "election"));
+        }
+
+        #[test]
+        fn force_election_starts_the_next_term_with_us_standing() {
+            let server = start_server();
+            let leader = Member::new();
+            server.member_list.insert(leader.clone(), Health::Alive);
+            let sg = ServiceGroup::new("neurosis", "production", None).unwrap();
+            server.insert_service(create_service(server.member_id(), "neurosis"));
+            server.insert_service(create_service(leader.get_id(), "neurosis"));
+            let mut election = Election::new(leader.get_id(), sg.clone(), 0);
+            election.set_term(4);
+            election.finish();
+            server.election_store.insert(election);
+
+            assert_eq!(server.force_election(sg.clone()), 5);
+            server.election_store.with_rumor(&sg.to_string(), "election", |election| {
+                let election = election.expect("Election wasn't forced");
+                assert_eq!(election.get_member_id(), server.member_id());
+                assert_eq!(election.get_term(), 5);
+                assert!(!election.is_finished());
+                assert!(election.get_suitability() > 0);
+            });
+        }
     }
 }
@@ -20,7 +20,7 @@ use std::sync::atomic::Ordering;
 use std::sync::mpsc;
 use std::net::{SocketAddr, UdpSocket};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use std::fmt;
 
 use time::SteadyTime;
@@ -81,8 +81,14 @@ impl<'a> Outbound<'a> {
     /// period to finish before starting the next probe.
     pub fn run(&mut self) {
         let mut have_members = false;
-        let num_initial = self.server.member_list.len_initial_members();
+        let mut initial_counter = self.server.member_list.get_initial_members_counter();
         loop {
+            let counter = self.server.member_list.get_initial_members_counter();
+            if counter != initial_counter {
+                initial_counter = counter;
+                have_members = false;
+            }
+            let num_initial = self.server.member_list.len_initial_members();
             if !have_members && num_initial != 0 {
                 // The minimum that's strictly more than half
                 let min_to_start = num_initial / 2 + 1;
@@ -168,12 +174,15 @@ impl<'a> Outbound<'a> {
         trace_it!(PROBE: &self.server, TraceKind::ProbeBegin, member.get_id(), addr);
 
         // Ping the member, and wait for the ack.
+        let sent = Instant::now();
         ping(self.server, &self.socket, &member, addr, None);
         if self.recv_ack(&member, addr, AckFrom::Ping) {
+            self.server.latency.record_ack(&member, sent.elapsed());
             trace_it!(PROBE: &self.server, TraceKind::ProbeAckReceived, member.get_id(), addr);
             trace_it!(PROBE: &self.server, TraceKind::ProbeComplete, member.get_id(), addr);
             return;
         }
+        self.server.latency.record_timeout(&member);
 
         self.server
             .member_list
@@ -188,7 +197,7 @@ impl<'a> Outbound<'a> {
             // We mark as suspect when we fail to get a response from the PingReq. That moves us
             // into the suspicion phase, where anyone marked as suspect has a certain number of
             // protocol periods to recover.
-            warn!("Marking {} as Suspect", member.get_id());
+            warn!("Marking {} as Suspect", member);
             trace_it!(PROBE: &self.server, TraceKind::ProbeSuspect, member.get_id(), addr);
             trace_it!(PROBE: &self.server, TraceKind::ProbeComplete, member.get_id(), addr);
             self.server.insert_member(member, Health::Suspect);
@@ -216,8 +225,9 @@ impl<'a> Outbound<'a> {
                         continue;
                     }
                     // If this was forwarded to us, we want to retain the address of the member who
-                    // sent the ack, not the one we received on the socket.
-                    if !swim.get_ack().has_forward_to() {
+                    // sent the ack, not the one we received on the socket. The same goes for a
+                    // member which advertises its own address.
+                    if !swim.get_ack().has_forward_to() && ack_from.get_address().is_empty() {
                         ack_from.set_address(format!("{}", real_addr.ip()));
                     }
                     let ack_from_member: Member = ack_from.into();
@@ -227,9 +237,7 @@ impl<'a> Outbound<'a> {
                 }
                 Err(mpsc::TryRecvError::Empty) => {
                     if SteadyTime::now() > timeout {
-                        warn!("Timed out waiting for Ack from {}@{}",
-                              member.get_id(),
-                              addr);
+                        warn!("Timed out waiting for Ack from {}@{}", member, addr);
                         return false;
                     }
                     thread::sleep(Duration::from_millis(PING_RECV_QUEUE_EMPTY_SLEEP_MS));
@@ -72,7 +72,7 @@ impl<'a> Pull<'a> {
                     continue;
                 }
             };
-            let mut proto: Rumor = match protobuf::parse_from_bytes(&payload) {
+            let proto: Rumor = match protobuf::parse_from_bytes(&payload) {
                 Ok(proto) => proto,
                 Err(e) => {
                     error!("Error parsing protobuf: {:?}", e);
@@ -84,31 +84,58 @@ impl<'a> Pull<'a> {
                       proto.get_from_id());
                 continue 'recv;
             }
-            trace_it!(GOSSIP: &self.server, TraceKind::RecvRumor, proto.get_from_id(), &proto);
-            match proto.get_field_type() {
-                Rumor_Type::Member => {
-                    let member = proto.mut_member().take_member().into();
-                    let health = proto.mut_member().get_health().into();
-                    self.server.insert_member_from_rumor(member, health);
-                }
-                Rumor_Type::Service => {
-                    self.server.insert_service(proto.into());
-                }
-                Rumor_Type::ServiceConfig => {
-                    self.server.insert_service_config(proto.into());
-                }
-                Rumor_Type::ServiceFile => {
-                    self.server.insert_service_file(proto.into());
-                }
-                Rumor_Type::Election => {
-                    self.server.insert_election(proto.into());
-                }
-                Rumor_Type::ElectionUpdate => {
-                    self.server.insert_update_election(proto.into());
-                }
-                Rumor_Type::Fake |
-                Rumor_Type::Fake2 => debug!("Nothing to do for fake rumor types"),
+            if !self.server.deliver(proto.get_from_id()) {
+                debug!("Lost message from {} on the simulated network", proto.get_from_id());
+                continue 'recv;
             }
+            trace_it!(GOSSIP: &self.server, TraceKind::RecvRumor, proto.get_from_id(), &proto);
+            insert_rumor(&self.server, proto);
+        }
+    }
+}
+
+/// Inserts a rumor received from another member into the matching store.
+pub fn insert_rumor(server: &Server, mut proto: Rumor) {
+    match proto.get_field_type() {
+        Rumor_Type::Member => {
+            let member = proto.mut_member().take_member().into();
+            let health = proto.mut_member().get_health().into();
+            server.insert_member_from_rumor(member, health);
+        }
+        Rumor_Type::Service => {
+            server.insert_service(proto.into());
+        }
+        Rumor_Type::ServiceConfig => {
+            server.insert_service_config(proto.into());
+        }
+        Rumor_Type::ServiceFile => {
+            server.insert_service_file(proto.into());
+        }
+        Rumor_Type::ServiceScale => {
+            server.insert_service_scale(proto.into());
+        }
+        Rumor_Type::ActiveColor => {
+            server.insert_active_color(proto.into());
+        }
+        Rumor_Type::RingCommand => {
+            server.insert_ring_command(proto.into());
+        }
+        Rumor_Type::RingCommandAck => {
+            server.insert_ring_command_ack(proto.into());
+        }
+        Rumor_Type::ServiceRestart => {
+            server.insert_service_restart(proto.into());
+        }
+        Rumor_Type::UpdateLock => {
+            server.insert_update_lock(proto.into());
+        }
+        Rumor_Type::Election => {
+            server.insert_election(proto.into());
+        }
+        Rumor_Type::ElectionUpdate => {
+            server.insert_update_election(proto.into());
         }
+        Rumor_Type::Fake |
+        Rumor_Type::Fake2 => debug!("Nothing to do for fake rumor types"),
     }
 }
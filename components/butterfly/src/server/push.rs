@@ -14,18 +14,23 @@
 
 //! The push thread.
 //!
-//! This is the thread for distributing rumors to members. It distributes to `FANOUT` members, no
-//! more often than `Timing::GOSSIP_PERIOD_DEFAULT_MS`.
+//! This is the thread for distributing rumors to members. It distributes to `FANOUT` members by
+//! default (see `Server::set_fanout`), no more often than `Timing::GOSSIP_PERIOD_DEFAULT_MS`.
+//! Rumors are sent from a new thread for each member on every gossip round, or, if the server was
+//! given a number of push workers (see `Server::set_push_workers`), by a fixed pool of that many
+//! workers which live as long as the push thread.
 
 use std::sync::atomic::Ordering;
 use std::thread;
 use std::time::Duration;
 
 use protobuf::Message;
+use threadpool::ThreadPool;
 use time::SteadyTime;
 use zmq;
 
 use ZMQ_CONTEXT;
+use error::Error;
 use message::swim::{Rumor as ProtoRumor, Rumor_Type as ProtoRumor_Type, Member as ProtoMember,
                     Membership as ProtoMembership};
 use rumor::{RumorKey, RumorVec};
@@ -34,7 +39,7 @@ use server::Server;
 use server::timing::Timing;
 use trace::TraceKind;
 
-const FANOUT: usize = 5;
+pub const FANOUT: usize = 5;
 
 /// The Push server
 #[derive(Debug)]
@@ -53,10 +58,17 @@ impl<'a> Push<'a> {
     }
 
     /// Executes the Push thread. Gets a list of members to talk to that are not Confirmed; then
-    /// proceeds to process the list in `FANOUT` sized chunks. If we finish sending the messages to
-    /// all FANOUT targets faster than `Timing::GOSSIP_PERIOD_DEFAULT_MS`, we will block until we
+    /// proceeds to process the list in fanout sized chunks. If we finish sending the messages to
+    /// all fanout targets faster than `Timing::GOSSIP_PERIOD_DEFAULT_MS`, we will block until we
     /// exceed that time.
     pub fn run(&mut self) {
+        let workers = self.server.push_workers.load(Ordering::Relaxed);
+        let fanout = self.server.fanout.load(Ordering::Relaxed);
+        let pool = if workers > 0 {
+            Some(ThreadPool::with_name(String::from("push-worker"), workers))
+        } else {
+            None
+        };
         'send: loop {
             if self.server.pause.load(Ordering::Relaxed) {
                 thread::sleep(Duration::from_millis(100));
@@ -69,12 +81,12 @@ impl<'a> Push<'a> {
             let long_wait = self.timing.gossip_timeout();
 
             'fanout: loop {
-                let mut thread_list = Vec::with_capacity(FANOUT);
+                let mut thread_list = Vec::with_capacity(fanout);
                 if check_list.len() == 0 {
                     break 'fanout;
                 }
-                let drain_length = if check_list.len() >= FANOUT {
-                    FANOUT
+                let drain_length = if check_list.len() >= fanout {
+                    fanout
                 } else {
                     check_list.len()
                 };
@@ -93,7 +105,12 @@ impl<'a> Push<'a> {
                         let rumors = self.server.rumor_list.rumors(member.get_id());
                         if rumors.len() > 0 {
                             let sc = self.server.clone();
-
+                            if let Some(ref pool) = pool {
+                                pool.execute(move || {
+                                    PushWorker::new(sc).send_rumors(member, rumors);
+                                });
+                                continue;
+                            }
                             let guard =
                                 match thread::Builder::new()
                                     .name(String::from("push-worker"))
@@ -110,6 +127,9 @@ impl<'a> Push<'a> {
                         }
                     }
                 }
+                if let Some(ref pool) = pool {
+                    pool.join();
+                }
                 let num_threads = thread_list.len();
                 for guard in thread_list.drain(0..num_threads) {
                     let _ = guard.join().map_err(|e| println!("Push worker died: {:?}", e));
@@ -167,112 +187,11 @@ impl PushWorker {
             }
         }
         'rumorlist: for &(ref rumor_key, ref _heat) in rumors.iter() {
-            let rumor_as_bytes = match rumor_key.kind {
-                ProtoRumor_Type::Member => {
-                    let send_rumor = self.create_member_rumor(&rumor_key);
-                    trace_it!(
-                        GOSSIP: &self.server,
-                        TraceKind::SendRumor,
-                        member.get_id(),
-                        &send_rumor);
-                    match send_rumor.write_to_bytes() {
-                        Ok(bytes) => bytes,
-                        Err(e) => {
-                            println!("Could not write our own rumor to bytes; abandoning \
-                                            sending rumor: {:?}",
-                                     e);
-                            continue 'rumorlist;
-                        }
-                    }
-                }
-                ProtoRumor_Type::Service => {
-                    // trace_it!(GOSSIP: &self.server,
-                    //           TraceKind::SendRumor,
-                    //           member.get_id(),
-                    //           &send_rumor);
-                    match self.server
-                        .service_store
-                        .write_to_bytes(&rumor_key.key, &rumor_key.id) {
-                        Ok(bytes) => bytes,
-                        Err(e) => {
-                            println!("Could not write our own rumor to bytes; abandoning \
-                                            sending rumor: {:?}",
-                                     e);
-                            continue 'rumorlist;
-                        }
-                    }
-                }
-                ProtoRumor_Type::ServiceConfig => {
-                    // trace_it!(GOSSIP: &self.server,
-                    //           TraceKind::SendRumor,
-                    //           member.get_id(),
-                    //           &send_rumor);
-                    match self.server
-                        .service_config_store
-                        .write_to_bytes(&rumor_key.key, &rumor_key.id) {
-                        Ok(bytes) => bytes,
-                        Err(e) => {
-                            println!("Could not write our own rumor to bytes; abandoning \
-                                            sending rumor: {:?}",
-                                     e);
-                            continue 'rumorlist;
-                        }
-                    }
-                }
-                ProtoRumor_Type::ServiceFile => {
-                    // trace_it!(GOSSIP: &self.server,
-                    //           TraceKind::SendRumor,
-                    //           member.get_id(),
-                    //           &send_rumor);
-                    match self.server
-                        .service_file_store
-                        .write_to_bytes(&rumor_key.key, &rumor_key.id) {
-                        Ok(bytes) => bytes,
-                        Err(e) => {
-                            println!("Could not write our own rumor to bytes; abandoning \
-                                            sending rumor: {:?}",
-                                     e);
-                            continue 'rumorlist;
-                        }
-                    }
-                }
-                ProtoRumor_Type::Election => {
-                    // trace_it!(GOSSIP: &self.server,
-                    //           TraceKind::SendRumor,
-                    //           member.get_id(),
-                    //           &send_rumor);
-                    match self.server
-                        .election_store
-                        .write_to_bytes(&rumor_key.key, &rumor_key.id) {
-                        Ok(bytes) => bytes,
-                        Err(e) => {
-                            println!("Could not write our own rumor to bytes; abandoning \
-                                            sending rumor: {:?}",
-                                     e);
-                            continue 'rumorlist;
-                        }
-                    }
-                }
-                ProtoRumor_Type::ElectionUpdate => {
-                    match self.server
-                        .update_store
-                        .write_to_bytes(&rumor_key.key, &rumor_key.id) {
-                        Ok(bytes) => bytes,
-                        Err(e) => {
-                            println!("Could not write our own rumor to bytes; abandoning sending \
-                                      rumor: {:?}",
-                                     e);
-                            continue 'rumorlist;
-                        }
-                    }
-                }
-                ProtoRumor_Type::Fake |
-                ProtoRumor_Type::Fake2 => {
-                    debug!("You have fake rumors; how odd!");
-                    continue 'rumorlist;
-                }
+            let rumor_as_bytes = match rumor_as_bytes(&self.server, member.get_id(), rumor_key) {
+                Some(bytes) => bytes,
+                None => continue 'rumorlist,
             };
-            let payload = match self.server.generate_wire(rumor_as_bytes) {
+            let payload = match self.server.generate_gossip_wire(rumor_as_bytes, &member) {
                 Ok(payload) => payload,
                 Err(e) => {
                     error!("Generating protobuf failed: {}", e);
@@ -286,22 +205,79 @@ impl PushWorker {
         }
         self.server.rumor_list.update_heat(member.get_id(), &rumors);
     }
+}
 
-    /// Given a rumorkey, creates a protobuf rumor for sharing.
-    fn create_member_rumor(&self, rumor_key: &RumorKey) -> ProtoRumor {
-        let mut member: ProtoMember = ProtoMember::new();
-        self.server.member_list.with_member(&rumor_key.key(), |m| {
-            // TODO: This should not stand
-            member = m.unwrap().proto.clone();
-        });
-        let mut membership = ProtoMembership::new();
-        membership.set_member(member);
-        membership.set_health(
-            self.server.member_list.health_of_by_id(&rumor_key.key()).unwrap().into());
-        let mut rumor = ProtoRumor::new();
-        rumor.set_field_type(ProtoRumor_Type::Member);
-        rumor.set_member(membership);
-        rumor.set_from_id(String::from(self.server.member_id()));
-        rumor
+/// Returns the rumor with the given key as the bytes sent to the member `to`, or None if it can't
+/// be sent.
+pub fn rumor_as_bytes(server: &Server, to: &str, rumor_key: &RumorKey) -> Option<Vec<u8>> {
+    let result = match rumor_key.kind {
+        ProtoRumor_Type::Member => {
+            let send_rumor = create_member_rumor(server, rumor_key);
+            trace_it!(GOSSIP: server, TraceKind::SendRumor, to, &send_rumor);
+            send_rumor.write_to_bytes().map_err(Error::from)
+        }
+        ProtoRumor_Type::Service => {
+            server.service_store.write_to_bytes(&rumor_key.key, &rumor_key.id)
+        }
+        ProtoRumor_Type::ServiceConfig => {
+            server.service_config_store.write_to_bytes(&rumor_key.key, &rumor_key.id)
+        }
+        ProtoRumor_Type::ServiceFile => {
+            server.service_file_store.write_to_bytes(&rumor_key.key, &rumor_key.id)
+        }
+        ProtoRumor_Type::ServiceScale => {
+            server.service_scale_store.write_to_bytes(&rumor_key.key, &rumor_key.id)
+        }
+        ProtoRumor_Type::ActiveColor => {
+            server.active_color_store.write_to_bytes(&rumor_key.key, &rumor_key.id)
+        }
+        ProtoRumor_Type::RingCommand => {
+            server.ring_command_store.write_to_bytes(&rumor_key.key, &rumor_key.id)
+        }
+        ProtoRumor_Type::RingCommandAck => {
+            server.ring_command_ack_store.write_to_bytes(&rumor_key.key, &rumor_key.id)
+        }
+        ProtoRumor_Type::ServiceRestart => {
+            server.service_restart_store.write_to_bytes(&rumor_key.key, &rumor_key.id)
+        }
+        ProtoRumor_Type::UpdateLock => {
+            server.update_lock_store.write_to_bytes(&rumor_key.key, &rumor_key.id)
+        }
+        ProtoRumor_Type::Election => {
+            server.election_store.write_to_bytes(&rumor_key.key, &rumor_key.id)
+        }
+        ProtoRumor_Type::ElectionUpdate => {
+            server.update_store.write_to_bytes(&rumor_key.key, &rumor_key.id)
+        }
+        ProtoRumor_Type::Fake |
+        ProtoRumor_Type::Fake2 => {
+            debug!("You have fake rumors; how odd!");
+            return None;
+        }
+    };
+    match result {
+        Ok(bytes) => Some(bytes),
+        Err(e) => {
+            println!("Could not write our own rumor to bytes; abandoning sending rumor: {:?}",
+                     e);
+            None
+        }
     }
 }
+
+/// Given a rumorkey, creates a protobuf rumor for sharing.
+fn create_member_rumor(server: &Server, rumor_key: &RumorKey) -> ProtoRumor {
+    let mut member: ProtoMember = ProtoMember::new();
+    server.member_list.with_member(&rumor_key.key(), |m| {
+        // TODO: This should not stand
+        member = m.unwrap().proto.clone();
+    });
+    let mut membership = ProtoMembership::new();
+    membership.set_member(member);
+    membership.set_health(server.member_list.health_of_by_id(&rumor_key.key()).unwrap().into());
+    let mut rumor = ProtoRumor::new();
+    rumor.set_field_type(ProtoRumor_Type::Member);
+    rumor.set_member(membership);
+    rumor.set_from_id(String::from(server.member_id()));
+    rumor
+}
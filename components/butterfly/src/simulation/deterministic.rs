@@ -0,0 +1,406 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A ring gossiping in a single thread, in an order drawn from a seed.
+//!
+//! The servers of a `Simulation` are never started: instead of push and pull threads talking over
+//! sockets, the simulation does what they would, one event at a time. Every round, each member
+//! gossips its hot rumors to the members it thinks are alive, at a random point in the round, and
+//! each message arrives after its link's latency plus a random jitter, so messages sent in the
+//! same round can arrive in any order, or rounds later. Rumors are inserted exactly as the pull
+//! thread inserts them, so elections and merges run the real code.
+//!
+//! The SWIM failure detector isn't simulated. Partitioning the ring marks the members on each
+//! side as confirmed dead to the other side, as the failure detector eventually would, and
+//! healing it marks them alive again.
+//!
+//! Every random choice is drawn from the seed, so two simulations with the same seed, driven the
+//! same way, deliver the same messages in the same order. When a property fails for a seed, that
+//! seed replays the failing run, and `schedule` shows what was delivered when.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ops::{Index, Range};
+use std::str::FromStr;
+use std::time::Duration;
+
+use habitat_core::package::{Identifiable, PackageIdent};
+use habitat_core::service::ServiceGroup;
+use protobuf::{self, ProtobufEnum};
+use rand::{Rng, XorShiftRng};
+
+use member::{Health, Member};
+use message::swim::{Election_Status, Rumor as ProtoRumor, Rumor_Type};
+use rumor::RumorKey;
+use rumor::service::{Service, SysInfo};
+use server::{pull, push, Server};
+use server::timing::Timing;
+use trace::Trace;
+use super::{seeded_rng, Link};
+
+/// A message the simulation sent.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Delivery {
+    /// When the message was sent, in milliseconds since the simulation started.
+    pub sent_at: u64,
+    /// When it arrived, or None if it was lost.
+    pub arrived_at: Option<u64>,
+    pub from: usize,
+    pub to: usize,
+    pub kind: Rumor_Type,
+    /// The key of the rumor, as in `RumorKey::key`.
+    pub rumor: String,
+}
+
+enum Event {
+    Gossip(usize),
+    Arrive(usize, usize, Vec<u8>, usize),
+}
+
+pub struct Simulation {
+    servers: Vec<Server>,
+    indexes: HashMap<String, usize>,
+    seed: u64,
+    rng: XorShiftRng,
+    /// The simulated time, in milliseconds since the simulation started.
+    now: u64,
+    round_ms: u64,
+    jitter_ms: u64,
+    rounds: u64,
+    /// The events to come, in the order they happen. Events at the same time happen in the order
+    /// they were scheduled.
+    events: BTreeMap<(u64, u64), Event>,
+    next_event: u64,
+    links: HashMap<(usize, usize), Link>,
+    partitioned: HashSet<(usize, usize)>,
+    schedule: Vec<Delivery>,
+}
+
+impl Index<usize> for Simulation {
+    type Output = Server;
+
+    fn index(&self, index: usize) -> &Server {
+        &self.servers[index]
+    }
+}
+
+impl Simulation {
+    /// Creates a fully meshed ring of `count` members. Members are named by their index, and
+    /// their ids sort in the same order.
+    pub fn new(count: usize, seed: u64) -> Simulation {
+        let mut servers = Vec::with_capacity(count);
+        let mut indexes = HashMap::new();
+        for x in 0..count {
+            let mut member = Member::new();
+            member.set_id(format!("member-{:03}", x));
+            indexes.insert(String::from(member.get_id()), x);
+            let server = Server::new("127.0.0.1:0",
+                                     "127.0.0.1:0",
+                                     member,
+                                     Trace::default(),
+                                     None,
+                                     Some(format!("{}", x)))
+                .expect("Cannot create a simulated server");
+            servers.push(server);
+        }
+        for server in servers.iter() {
+            for other in servers.iter() {
+                if server.member_id() != other.member_id() {
+                    let member = other.member.read().expect("Member lock is poisoned").clone();
+                    server.insert_member(member, Health::Alive);
+                }
+            }
+        }
+        let round_ms = Timing::default().gossip_period_ms as u64;
+        Simulation {
+            servers: servers,
+            indexes: indexes,
+            seed: seed,
+            rng: seeded_rng(&format!("simulation-{}", seed)),
+            now: 0,
+            round_ms: round_ms,
+            jitter_ms: round_ms,
+            rounds: 0,
+            events: BTreeMap::new(),
+            next_event: 0,
+            links: HashMap::new(),
+            partitioned: HashSet::new(),
+            schedule: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.servers.len()
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// The number of rounds run so far.
+    pub fn rounds(&self) -> u64 {
+        self.rounds
+    }
+
+    /// Every message sent so far, in the order they were sent or lost.
+    pub fn schedule(&self) -> &[Delivery] {
+        &self.schedule
+    }
+
+    /// Sets the most a message can be held up on top of its link's latency. Defaults to one
+    /// round.
+    pub fn set_jitter(&mut self, jitter: Duration) {
+        self.jitter_ms = duration_ms(jitter);
+    }
+
+    /// Sets how messages from `from` reach `to`.
+    pub fn set_link(&mut self, from: usize, to: usize, link: Link) {
+        self.links.insert((from, to), link);
+    }
+
+    pub fn remove_link(&mut self, from: usize, to: usize) {
+        self.links.remove(&(from, to));
+    }
+
+    /// Cuts the members in `left` off from the members in `right`. Messages between them, even
+    /// those already sent, are lost, and each side sees the other as confirmed dead.
+    pub fn partition(&mut self, left: Range<usize>, right: Range<usize>) {
+        self.set_partition(left, right, true);
+    }
+
+    /// Undoes `partition`; each side sees the other as alive again.
+    pub fn heal(&mut self, left: Range<usize>, right: Range<usize>) {
+        self.set_partition(left, right, false);
+    }
+
+    /// Gossips that `member` runs `package` in the `prod` group.
+    pub fn add_service(&self, member: usize, package: &str) {
+        let ident = PackageIdent::from_str(package)
+            .expect("package needs to be a fully qualified package identifier");
+        let sg = ServiceGroup::new(ident.name(), "prod", None).unwrap();
+        let service = Service::new(self[member].member_id().to_string(),
+                                   &ident,
+                                   &sg,
+                                   &SysInfo::default(),
+                                   None)
+            .unwrap();
+        self[member].insert_service(service);
+    }
+
+    pub fn start_election(&self, member: usize, service_group: &str, suitability: u64) {
+        let sg = ServiceGroup::from_str(service_group).expect("invalid service group");
+        self[member].start_election(sg, suitability, 0);
+    }
+
+    /// The term and leader of the finished election for `service_group`, as each member sees it.
+    pub fn leaders(&self, service_group: &str) -> Vec<Option<(u64, String)>> {
+        self.servers
+            .iter()
+            .map(|server| {
+                let mut leader = None;
+                server.election_store.with_rumor(service_group, "election", |e| {
+                    if let Some(e) = e {
+                        if e.get_status() == Election_Status::Finished {
+                            leader = Some((e.get_term(), String::from(e.get_member_id())));
+                        }
+                    }
+                });
+                leader
+            })
+            .collect()
+    }
+
+    /// Runs one gossip round: every member gossips once, and the messages due by the end of the
+    /// round arrive.
+    pub fn run_round(&mut self) {
+        for x in 0..self.servers.len() {
+            let at = self.now + self.rng.gen_range(0, self.round_ms);
+            self.schedule_event(at, Event::Gossip(x));
+        }
+        let end = self.now + self.round_ms;
+        loop {
+            let next = match self.events.keys().next() {
+                Some(&(at, seq)) if at < end => (at, seq),
+                _ => break,
+            };
+            let event = self.events.remove(&next).unwrap();
+            self.now = next.0;
+            match event {
+                Event::Gossip(from) => self.gossip(from),
+                Event::Arrive(from, to, bytes, delivery) => self.arrive(from, to, bytes, delivery),
+            }
+        }
+        self.now = end;
+        self.rounds += 1;
+    }
+
+    /// Runs rounds until `done` is true, for at most `max_rounds`. Returns whether `done` became
+    /// true.
+    pub fn run_until<F>(&mut self, max_rounds: u64, mut done: F) -> bool
+        where F: FnMut(&Simulation) -> bool
+    {
+        for _ in 0..max_rounds {
+            if done(self) {
+                return true;
+            }
+            self.run_round();
+        }
+        done(self)
+    }
+
+    /// Sends `from`'s hot rumors to every member it would push to.
+    fn gossip(&mut self, from: usize) {
+        let mut targets = {
+            let server = &self.servers[from];
+            server.member_list.check_list(server.member_id())
+        };
+        // The check list comes shuffled by the thread's generator; put it back in order before
+        // shuffling it with ours.
+        targets.sort_by(|a, b| a.get_id().cmp(b.get_id()));
+        self.rng.shuffle(&mut targets);
+        for target in targets {
+            if !self.servers[from].member_list.pingable(&target) ||
+               self.servers[from].member_list.persistent_and_confirmed(&target) {
+                continue;
+            }
+            let to = match self.indexes.get(target.get_id()) {
+                Some(to) => *to,
+                None => continue,
+            };
+            let mut rumors = self.servers[from].rumor_list.rumors(target.get_id());
+            rumors.sort_by(|&(ref a, a_heat), &(ref b, b_heat)| {
+                b_heat.cmp(&a_heat)
+                    .then((a.kind.value(), &a.key, &a.id).cmp(&(b.kind.value(), &b.key, &b.id)))
+            });
+            for &(ref rumor_key, _) in rumors.iter() {
+                if let Some(bytes) = push::rumor_as_bytes(&self.servers[from],
+                                                          target.get_id(),
+                                                          rumor_key) {
+                    self.send(from, to, rumor_key, bytes);
+                }
+            }
+            self.servers[from].rumor_list.update_heat(target.get_id(), &rumors);
+        }
+    }
+
+    fn send(&mut self, from: usize, to: usize, rumor_key: &RumorKey, bytes: Vec<u8>) {
+        let link = self.links
+            .get(&(from, to))
+            .cloned()
+            .unwrap_or_else(|| Link::new(Duration::from_millis(0), 0.0));
+        let lost = self.partitioned.contains(&(from, to)) ||
+                   (link.loss > 0.0 && self.rng.next_f64() < link.loss);
+        self.schedule.push(Delivery {
+            sent_at: self.now,
+            arrived_at: None,
+            from: from,
+            to: to,
+            kind: rumor_key.kind,
+            rumor: rumor_key.key(),
+        });
+        if lost {
+            return;
+        }
+        let delivery = self.schedule.len() - 1;
+        let at = self.now + duration_ms(link.latency) + self.rng.gen_range(0, self.jitter_ms + 1);
+        self.schedule_event(at, Event::Arrive(from, to, bytes, delivery));
+    }
+
+    fn arrive(&mut self, from: usize, to: usize, bytes: Vec<u8>, delivery: usize) {
+        if self.partitioned.contains(&(from, to)) {
+            return;
+        }
+        let proto: ProtoRumor = match protobuf::parse_from_bytes(&bytes) {
+            Ok(proto) => proto,
+            Err(e) => {
+                error!("Error parsing protobuf: {:?}", e);
+                return;
+            }
+        };
+        self.schedule[delivery].arrived_at = Some(self.now);
+        pull::insert_rumor(&self.servers[to], proto);
+    }
+
+    fn schedule_event(&mut self, at: u64, event: Event) {
+        self.events.insert((at, self.next_event), event);
+        self.next_event += 1;
+    }
+
+    fn set_partition(&mut self, left: Range<usize>, right: Range<usize>, cut: bool) {
+        let right: Vec<usize> = right.collect();
+        let health = if cut { Health::Confirmed } else { Health::Alive };
+        for l in left {
+            for r in right.iter().cloned() {
+                if l == r {
+                    continue;
+                }
+                for &(from, to) in [(l, r), (r, l)].iter() {
+                    if cut {
+                        self.partitioned.insert((from, to));
+                    } else {
+                        self.partitioned.remove(&(from, to));
+                    }
+                    let member =
+                        self.servers[to].member.read().expect("Member lock is poisoned").clone();
+                    self.servers[from].insert_health(&member, health);
+                }
+            }
+        }
+    }
+}
+
+fn duration_ms(duration: Duration) -> u64 {
+    duration.as_secs() * 1_000 + (duration.subsec_nanos() / 1_000_000) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use member::Health;
+    use simulation::Link;
+    use super::Simulation;
+
+    fn run(seed: u64) -> Simulation {
+        let mut sim = Simulation::new(3, seed);
+        sim.set_link(0, 1, Link::new(Duration::from_millis(300), 0.2));
+        for x in 0..3 {
+            sim.add_service(x, "core/witcher/1.2.3/20161208121212");
+        }
+        sim.start_election(0, "witcher.prod", 0);
+        for _ in 0..10 {
+            sim.run_round();
+        }
+        sim
+    }
+
+    #[test]
+    fn the_same_seed_replays_the_same_schedule() {
+        assert_eq!(run(7).schedule(), run(7).schedule());
+    }
+
+    #[test]
+    fn different_seeds_draw_different_schedules() {
+        assert!(run(7).schedule() != run(8).schedule());
+    }
+
+    #[test]
+    fn partitioned_members_see_each_other_as_confirmed() {
+        let mut sim = Simulation::new(3, 0);
+        sim.partition(0..1, 1..3);
+        assert!(sim[1].member_list.check_health_of_by_id(sim[0].member_id(), Health::Confirmed));
+        assert!(sim[0].member_list.check_health_of_by_id(sim[2].member_id(), Health::Confirmed));
+        sim.heal(0..1, 1..3);
+        assert!(sim[1].member_list.check_health_of_by_id(sim[0].member_id(), Health::Alive));
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Simulated networks, for testing how a ring behaves when the links between its members are
+//! slow, lossy or cut. Only built with the `simulation` feature.
+//!
+//! A `Network` sits in front of a running server. Faults are applied by the receiving server, to
+//! the messages it gets from a given member: a lost message is dropped before it is processed,
+//! and a slow one holds up the thread which received it. Partitions are simulated with the
+//! server's blacklist instead. Losses are drawn from a random number generator seeded with the
+//! server's name, so a test sees the same losses on every run, as long as its messages arrive in
+//! the same order - which, with real threads and sockets, they don't always.
+//!
+//! A `Simulation` runs a whole ring in a single thread instead, without sockets, so the order
+//! messages arrive in is drawn from the seed as well, and a run can be replayed exactly. See the
+//! `deterministic` module.
+
+pub mod deterministic;
+
+pub use self::deterministic::{Delivery, Simulation};
+
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+
+use rand::{Rng, SeedableRng, XorShiftRng};
+
+/// How messages from one member reach another.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Link {
+    /// How long each message takes to arrive.
+    pub latency: Duration,
+    /// The share of messages which never arrive, from 0.0 (none) to 1.0 (all of them).
+    pub loss: f64,
+}
+
+impl Link {
+    pub fn new(latency: Duration, loss: f64) -> Link {
+        Link {
+            latency: latency,
+            loss: loss,
+        }
+    }
+}
+
+/// The links into a server, keyed by the member id of the sender. Messages from members without a
+/// link of their own go over the default link, if there is one, and otherwise arrive at once.
+pub struct Network {
+    links: HashMap<String, Link>,
+    default: Option<Link>,
+    rng: XorShiftRng,
+}
+
+impl fmt::Debug for Network {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Network {{ links: {:?}, default: {:?} }}",
+               self.links,
+               self.default)
+    }
+}
+
+impl Network {
+    pub fn new(name: &str) -> Network {
+        Network {
+            links: HashMap::new(),
+            default: None,
+            rng: seeded_rng(name),
+        }
+    }
+
+    pub fn set_link(&mut self, from: String, link: Link) {
+        self.links.insert(from, link);
+    }
+
+    pub fn remove_link(&mut self, from: &str) {
+        self.links.remove(from);
+    }
+
+    pub fn set_default_link(&mut self, link: Option<Link>) {
+        self.default = link;
+    }
+
+    /// Returns how long a message from `from` takes to arrive, or None if it is lost.
+    pub fn transmit(&mut self, from: &str) -> Option<Duration> {
+        match self.links.get(from).or(self.default.as_ref()) {
+            Some(link) => {
+                if link.loss > 0.0 && self.rng.next_f64() < link.loss {
+                    None
+                } else {
+                    Some(link.latency)
+                }
+            }
+            None => Some(Duration::from_millis(0)),
+        }
+    }
+}
+
+/// A random number generator which always draws the same numbers for the same seed.
+fn seeded_rng(seed: &str) -> XorShiftRng {
+    let mut state = [0x193a6754, 0xa8a7d469, 0x97830e05, 0x113ba7bb];
+    for (i, byte) in seed.bytes().enumerate() {
+        state[i % 4] = state[i % 4].wrapping_mul(31).wrapping_add(byte as u32);
+    }
+    XorShiftRng::from_seed(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Link, Network};
+
+    #[test]
+    fn messages_without_a_link_arrive_at_once() {
+        let mut network = Network::new("0");
+        assert_eq!(network.transmit("adam"), Some(Duration::from_millis(0)));
+    }
+
+    #[test]
+    fn messages_over_a_slow_link_are_delayed() {
+        let mut network = Network::new("0");
+        network.set_link("adam".to_string(), Link::new(Duration::from_millis(50), 0.0));
+        assert_eq!(network.transmit("adam"), Some(Duration::from_millis(50)));
+        network.remove_link("adam");
+        assert_eq!(network.transmit("adam"), Some(Duration::from_millis(0)));
+    }
+
+    #[test]
+    fn members_without_a_link_use_the_default_link() {
+        let mut network = Network::new("0");
+        network.set_default_link(Some(Link::new(Duration::from_millis(50), 0.0)));
+        network.set_link("adam".to_string(), Link::new(Duration::from_millis(10), 0.0));
+        assert_eq!(network.transmit("adam"), Some(Duration::from_millis(10)));
+        assert_eq!(network.transmit("eve"), Some(Duration::from_millis(50)));
+        network.set_default_link(None);
+        assert_eq!(network.transmit("eve"), Some(Duration::from_millis(0)));
+    }
+
+    #[test]
+    fn losses_are_the_same_on_every_run() {
+        let mut left = Network::new("0");
+        let mut right = Network::new("0");
+        for network in vec![&mut left, &mut right] {
+            network.set_link("adam".to_string(), Link::new(Duration::from_millis(0), 0.5));
+        }
+        let left_losses: Vec<bool> = (0..100).map(|_| left.transmit("adam").is_none()).collect();
+        let right_losses: Vec<bool> = (0..100).map(|_| right.transmit("adam").is_none()).collect();
+        assert_eq!(left_losses, right_losses);
+        assert!(left_losses.iter().any(|lost| *lost));
+        assert!(left_losses.iter().any(|lost| !*lost));
+    }
+}
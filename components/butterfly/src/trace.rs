@@ -377,6 +377,43 @@ macro_rules! trace_it {
                                 $payload.get_service_file().get_encrypted(),
                                 $payload.get_service_file().get_filename())
                     }
+                    Rumor_Type::ServiceScale => {
+                        format!("{}-{}-{}",
+                                $payload.get_service_scale().get_service_group(),
+                                $payload.get_service_scale().get_incarnation(),
+                                $payload.get_service_scale().get_desired_count())
+                    }
+                    Rumor_Type::ActiveColor => {
+                        format!("{}-{}-{}",
+                                $payload.get_active_color().get_service(),
+                                $payload.get_active_color().get_incarnation(),
+                                $payload.get_active_color().get_group())
+                    }
+                    Rumor_Type::RingCommand => {
+                        format!("{}-{}-{}",
+                                $payload.get_ring_command().get_id(),
+                                $payload.get_ring_command().get_command(),
+                                $payload.get_ring_command().get_signer())
+                    }
+                    Rumor_Type::RingCommandAck => {
+                        format!("{}-{}-{}",
+                                $payload.get_ring_command_ack().get_command_id(),
+                                $payload.get_ring_command_ack().get_member_id(),
+                                $payload.get_ring_command_ack().get_success())
+                    }
+                    Rumor_Type::ServiceRestart => {
+                        format!("{}-{}-{}",
+                                $payload.get_service_restart().get_service_group(),
+                                $payload.get_service_restart().get_incarnation(),
+                                $payload.get_service_restart().get_rolling())
+                    }
+                    Rumor_Type::UpdateLock => {
+                        format!("{}-{}-{}-{}",
+                                $payload.get_update_lock().get_service_group(),
+                                $payload.get_update_lock().get_member_id(),
+                                $payload.get_update_lock().get_incarnation(),
+                                $payload.get_update_lock().get_held())
+                    }
                     Rumor_Type::Election | Rumor_Type::ElectionUpdate => {
                         format!("{}-{}-{}-{}-{:?}-{:?}",
                                 $payload.get_election().get_member_id(),
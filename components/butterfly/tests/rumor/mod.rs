@@ -12,7 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod active_color;
+pub mod ring_command;
 pub mod service;
 pub mod service_config;
 pub mod service_file;
+pub mod service_restart;
+pub mod service_scale;
+pub mod update_lock;
 pub mod election;
@@ -0,0 +1,45 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use btest;
+use habitat_butterfly::client::Client;
+use habitat_butterfly::rumor::{RingCommand, RingCommandAck};
+
+#[test]
+fn ring_command_via_client() {
+    let mut net = btest::SwimNet::new(2);
+    net.mesh();
+
+    net.wait_for_gossip_rounds(1);
+    let mut client = Client::new(net[0].gossip_addr(), None)
+        .expect("Cannot create Butterfly Client");
+    let rc = RingCommand::new("butterflyclient",
+                              "c0ffee",
+                              "restart",
+                              vec!["witcher.default".to_string()]);
+    client.send(rc).expect("Cannot send the ring command");
+    net.wait_for_gossip_rounds(1);
+    assert!(net[1].ring_command_store.contains_rumor("c0ffee", "ring_command"));
+}
+
+#[test]
+fn ring_command_acks_reach_every_member() {
+    let mut net = btest::SwimNet::new(2);
+    net.mesh();
+
+    let member_id = net[1].member_id().to_string();
+    net[1].insert_ring_command_ack(RingCommandAck::new(member_id.clone(), "c0ffee", true, ""));
+    net.wait_for_gossip_rounds(1);
+    assert!(net[0].ring_command_ack_store.contains_rumor("c0ffee", &member_id));
+}
@@ -0,0 +1,32 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use btest;
+use habitat_core::service::ServiceGroup;
+
+#[test]
+fn update_lock_requests_are_gossiped() {
+    let mut net = btest::SwimNet::new(3);
+    net.mesh();
+
+    let sg = ServiceGroup::new("witcher", "prod", None).unwrap();
+    assert_eq!(net[0].request_update_lock(&sg, 1), false);
+    net.wait_for_gossip_rounds(1);
+    assert_eq!(net[2].update_lock_queue("witcher.prod"),
+               vec![net[0].member_id().to_string()]);
+
+    net[0].release_update_lock(&sg);
+    net.wait_for_gossip_rounds(1);
+    assert!(net[2].update_lock_queue("witcher.prod").is_empty());
+}
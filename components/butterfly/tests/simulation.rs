@@ -0,0 +1,123 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Properties of the gossip protocol, checked against many deterministic runs. Run with
+//! `cargo test --features simulation`. A failure names its seed; `Simulation::new` with that seed
+//! replays the run.
+
+#![cfg(feature = "simulation")]
+
+extern crate habitat_butterfly;
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use habitat_butterfly::simulation::{Link, Simulation};
+
+const SEEDS: u64 = 50;
+const WITCHER: &'static str = "core/witcher/1.2.3/20161208121212";
+
+fn start(count: usize, seed: u64) -> Simulation {
+    let sim = Simulation::new(count, seed);
+    for x in 0..count {
+        sim.add_service(x, WITCHER);
+    }
+    sim
+}
+
+/// No two members may see different leaders finish the same term.
+fn check_no_split_brain(sim: &Simulation) {
+    let mut terms: HashMap<u64, String> = HashMap::new();
+    for leader in sim.leaders("witcher.prod").into_iter() {
+        if let Some((term, leader)) = leader {
+            let first = terms.entry(term).or_insert(leader.clone());
+            assert!(*first == leader,
+                    "seed {}: term {} finished with both {} and {} as leader, after round {}",
+                    sim.seed(),
+                    term,
+                    first,
+                    leader,
+                    sim.rounds());
+        }
+    }
+}
+
+fn agreed_leader(sim: &Simulation) -> bool {
+    let leaders = sim.leaders("witcher.prod");
+    leaders[0].is_some() && leaders.iter().all(|l| *l == leaders[0])
+}
+
+#[test]
+fn concurrent_elections_converge_on_one_leader() {
+    for seed in 0..SEEDS {
+        let mut sim = start(3, seed);
+        for x in 0..3 {
+            sim.start_election(x, "witcher.prod", 0);
+        }
+        let converged = sim.run_until(30, |sim| {
+            check_no_split_brain(sim);
+            agreed_leader(sim)
+        });
+        assert!(converged, "seed {}: no agreed leader after 30 rounds", seed);
+    }
+}
+
+#[test]
+fn elections_over_slow_lossy_links_never_split_the_brain() {
+    for seed in 0..SEEDS {
+        let mut sim = start(5, seed);
+        sim.set_link(0, 4, Link::new(Duration::from_millis(1500), 0.3));
+        sim.set_link(4, 0, Link::new(Duration::from_millis(1500), 0.3));
+        sim.set_link(2, 3, Link::new(Duration::from_millis(0), 0.5));
+        sim.start_election(0, "witcher.prod", 0);
+        sim.start_election(4, "witcher.prod", 1);
+        for _ in 0..30 {
+            sim.run_round();
+            check_no_split_brain(&sim);
+        }
+    }
+}
+
+#[test]
+fn a_healed_partition_converges_on_one_leader() {
+    for seed in 0..SEEDS {
+        let mut sim = start(5, seed);
+        sim.start_election(0, "witcher.prod", 0);
+        assert!(sim.run_until(30, agreed_leader),
+                "seed {}: no agreed leader after 30 rounds",
+                seed);
+
+        sim.partition(0..2, 2..5);
+        for _ in 0..20 {
+            for x in 0..5 {
+                sim[x].restart_elections();
+            }
+            sim.run_round();
+            check_no_split_brain(&sim);
+        }
+
+        sim.heal(0..2, 2..5);
+        let converged = sim.run_until(50, |sim| {
+            for x in 0..5 {
+                sim[x].restart_elections();
+            }
+            check_no_split_brain(sim);
+            agreed_leader(sim)
+        });
+        assert!(converged,
+                "seed {}: no agreed leader 50 rounds after the partition healed: {:?}",
+                seed,
+                sim.leaders("witcher.prod"));
+    }
+}
@@ -28,33 +28,54 @@
 //!
 //! This would install the `3.0.1` version of redis.
 //!
+//! Artifacts can also be installed from the local filesystem, by path, by `file://` URL, or
+//! as every artifact in a directory or matching a glob:
+//!
+//! ```bash
+//! $ hab pkg install 'results/*.hart'
+//! ```
+//!
+//! Dependencies which are among those artifacts, or beside one of them, are installed from the
+//! local filesystem without contacting the depot.
+//!
+//! When the host has a license policy, at `/hab/etc/license-policy.toml`, nothing is installed
+//! if any package which would be is distributed under a license the policy doesn't allow.
+//!
 //! # Internals
 //!
 //! * Download the artifact
 //! * Verify it is un-altered
+//! * Check its licenses against the license policy
 //! * Unpack it
 //!
 
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use depot_client::{self, Client};
+use glob;
 use hcore;
 use hcore::fs::{am_i_root, cache_key_path};
 use hcore::crypto::{artifact, SigKeyPair};
 use hcore::crypto::keys::parse_name_with_rev;
-use hcore::package::{Identifiable, PackageArchive, PackageIdent, Target, PackageInstall};
+use hcore::package::{ArtifactCache, ArtifactScanner, Identifiable, LicensePolicy, PackageArchive,
+                     PackageIdent, Target, PackageInstall};
 use hyper::status::StatusCode;
 
 use error::{Error, Result};
 use ui::{Status, UI};
 
 use retry::retry;
+use url::Url;
 
 pub const RETRIES: u64 = 5;
 pub const RETRY_WAIT: u64 = 3000;
 
+/// Installs the package or artifact named by `ident_or_archive`, returning the ident of the
+/// package installed. When a directory or glob names several artifacts, this is the ident of the
+/// last one installed, which is never a dependency of the others.
 pub fn start<P1: ?Sized, P2: ?Sized>(ui: &mut UI,
                                      url: &str,
                                      ident_or_archive: &str,
@@ -66,6 +87,31 @@ pub fn start<P1: ?Sized, P2: ?Sized>(ui: &mut UI,
                                      -> Result<PackageIdent>
     where P1: AsRef<Path>,
           P2: AsRef<Path>
+{
+    let mut idents = try!(start_all(ui,
+                                    url,
+                                    ident_or_archive,
+                                    product,
+                                    version,
+                                    fs_root_path,
+                                    cache_artifact_path,
+                                    ignore_target));
+    Ok(idents.pop().expect("an install names at least one package"))
+}
+
+/// Installs the package or artifacts named by `ident_or_archive`, returning the idents of every
+/// package it names in the order they were installed.
+pub fn start_all<P1: ?Sized, P2: ?Sized>(ui: &mut UI,
+                                         url: &str,
+                                         ident_or_archive: &str,
+                                         product: &str,
+                                         version: &str,
+                                         fs_root_path: &P1,
+                                         cache_artifact_path: &P2,
+                                         ignore_target: bool)
+                                         -> Result<Vec<PackageIdent>>
+    where P1: AsRef<Path>,
+          P2: AsRef<Path>
 {
     if !am_i_root() {
         try!(ui.warn("Installing a package requires root or administrator privileges. Please retry \
@@ -86,19 +132,105 @@ pub fn start<P1: ?Sized, P2: ?Sized>(ui: &mut UI,
                                      &cache_key_path,
                                      ignore_target));
 
-    if Path::new(ident_or_archive).is_file() {
-        task.from_artifact(ui, &Path::new(ident_or_archive))
+    match try!(local_artifacts(ident_or_archive)) {
+        Some(artifact_paths) => task.from_artifacts(ui, &artifact_paths),
+        None => {
+            let ident = try!(task.from_ident(ui, try!(PackageIdent::from_str(ident_or_archive))));
+            Ok(vec![ident])
+        }
+    }
+}
+
+/// Returns the paths of the artifacts `ident_or_archive` names, or `None` if it's a package
+/// identifier instead. Artifacts are named by a path or `file://` URL to an artifact or to a
+/// directory of artifacts, or by a glob such as `results/*.hart`.
+pub fn local_artifacts(ident_or_archive: &str) -> Result<Option<Vec<PathBuf>>> {
+    let is_url = ident_or_archive.starts_with("file://");
+    let path = if is_url {
+        match Url::parse(ident_or_archive).ok().and_then(|url| url.to_file_path().ok()) {
+            Some(path) => path,
+            None => return Err(Error::NoLocalArtifacts(ident_or_archive.to_string())),
+        }
     } else {
-        task.from_ident(ui, try!(PackageIdent::from_str(ident_or_archive)))
+        PathBuf::from(ident_or_archive)
+    };
+
+    if path.is_file() {
+        return Ok(Some(vec![path]));
+    }
+    let pattern = if path.is_dir() {
+        path.join("*.hart")
+    } else if ident_or_archive.contains(|c| c == '*' || c == '?' || c == '[') {
+        path
+    } else if is_url {
+        return Err(Error::NoLocalArtifacts(ident_or_archive.to_string()));
+    } else {
+        return Ok(None);
+    };
+
+    let mut artifact_paths = Vec::new();
+    for entry in try!(glob::glob(&pattern.to_string_lossy())) {
+        match entry {
+            Ok(path) => {
+                if path.is_file() && path.extension().map_or(false, |ext| ext == "hart") {
+                    artifact_paths.push(path);
+                }
+            }
+            Err(e) => debug!("Skipping unreadable path while matching artifacts: {}", e),
+        }
+    }
+    if artifact_paths.is_empty() {
+        return Err(Error::NoLocalArtifacts(ident_or_archive.to_string()));
+    }
+    Ok(Some(artifact_paths))
+}
+
+/// Artifacts on the local filesystem, which are installed in place of fetching their packages
+/// from the depot.
+#[derive(Default)]
+struct LocalArtifacts {
+    /// The artifacts being installed, by the ident of the package each holds.
+    artifacts: HashMap<PackageIdent, PathBuf>,
+    /// The directories holding those artifacts, where dependencies are looked for by archive
+    /// name.
+    dirs: Vec<PathBuf>,
+}
+
+impl LocalArtifacts {
+    fn new(artifact_paths: &[PathBuf]) -> Result<Self> {
+        let mut local = LocalArtifacts::default();
+        for path in artifact_paths {
+            let ident = try!(PackageArchive::new(path.clone()).ident());
+            if let Some(dir) = path.parent() {
+                if !local.dirs.iter().any(|d| d == dir) {
+                    local.dirs.push(dir.to_path_buf());
+                }
+            }
+            local.artifacts.insert(ident, path.clone());
+        }
+        Ok(local)
+    }
+
+    fn find(&self, ident: &PackageIdent) -> Option<PathBuf> {
+        if let Some(path) = self.artifacts.get(ident) {
+            return Some(path.clone());
+        }
+        let name = match ident.archive_name() {
+            Some(name) => name,
+            None => return None,
+        };
+        self.dirs.iter().map(|dir| dir.join(&name)).find(|path| path.is_file())
     }
 }
 
 struct InstallTask<'a> {
     depot_client: Client,
     fs_root_path: &'a Path,
-    cache_artifact_path: &'a Path,
+    artifact_cache: ArtifactCache,
     cache_key_path: &'a Path,
     ignore_target: bool,
+    license_policy: Option<LicensePolicy>,
+    scanner: Option<ArtifactScanner>,
 }
 
 impl<'a> InstallTask<'a> {
@@ -110,12 +242,16 @@ impl<'a> InstallTask<'a> {
                cache_key_path: &'a Path,
                ignore_target: bool)
                -> Result<Self> {
+        let policy_path = LicensePolicy::default_path(Some(fs_root_path));
+        let scanner_path = ArtifactScanner::default_path(Some(fs_root_path));
         Ok(InstallTask {
             depot_client: try!(Client::new(url, product, version, Some(fs_root_path))),
             fs_root_path: fs_root_path,
-            cache_artifact_path: cache_artifact_path,
+            artifact_cache: ArtifactCache::new(cache_artifact_path),
             cache_key_path: cache_key_path,
             ignore_target: ignore_target,
+            license_policy: try!(LicensePolicy::load(policy_path)),
+            scanner: try!(ArtifactScanner::load(scanner_path)),
         })
     }
 
@@ -133,11 +269,38 @@ impl<'a> InstallTask<'a> {
             return Ok(ident);
         }
 
-        self.install_package(ui, ident, None)
+        self.install_package(ui, ident, &LocalArtifacts::default())
+    }
+
+    /// Installs a set of artifacts, each after any of the others it depends on.
+    pub fn from_artifacts(&self,
+                          ui: &mut UI,
+                          artifact_paths: &[PathBuf])
+                          -> Result<Vec<PackageIdent>> {
+        let local = try!(LocalArtifacts::new(artifact_paths));
+        // A package's transitive dependencies include those of each of its dependencies, so
+        // installing the packages with the fewest first puts every dependency before its
+        // dependents.
+        let mut ordered = Vec::new();
+        for (ident, path) in local.artifacts.iter() {
+            let tdeps = try!(PackageArchive::new(path.clone()).tdeps());
+            ordered.push((tdeps.len(), ident.clone(), path.clone()));
+        }
+        ordered.sort_by_key(|&(tdeps, _, _)| tdeps);
+
+        let mut idents = Vec::new();
+        for (_, ident, path) in ordered {
+            idents.push(try!(self.from_artifact(ui, ident, &path, &local)));
+        }
+        Ok(idents)
     }
 
-    pub fn from_artifact(&self, ui: &mut UI, artifact_path: &Path) -> Result<PackageIdent> {
-        let ident = try!(PackageArchive::new(artifact_path).ident());
+    fn from_artifact(&self,
+                     ui: &mut UI,
+                     ident: PackageIdent,
+                     artifact_path: &Path,
+                     local: &LocalArtifacts)
+                     -> Result<PackageIdent> {
         if try!(self.is_package_installed(&ident)) {
             try!(ui.status(Status::Using, &ident));
             try!(ui.end(format!("Install of {} complete with {} new packages installed.",
@@ -145,28 +308,46 @@ impl<'a> InstallTask<'a> {
                                 0)));
             return Ok(ident);
         }
-        try!(self.cache_artifact(&ident, artifact_path));
-        let src_path = artifact_path.parent().unwrap();
+        {
+            let _lock = try!(self.artifact_cache.lock(&ident));
+            try!(self.cache_artifact(&ident, artifact_path));
+        }
 
-        self.install_package(ui, ident, Some(src_path))
+        self.install_package(ui, ident, local)
     }
 
     fn install_package(&self,
                        ui: &mut UI,
                        ident: PackageIdent,
-                       src_path: Option<&Path>)
+                       local: &LocalArtifacts)
                        -> Result<PackageIdent> {
-        let mut artifact = try!(self.get_cached_artifact(ui, ident.clone(), src_path));
+        let mut artifact = try!(self.get_cached_artifact(ui, ident.clone(), local));
         let mut artifacts: Vec<PackageArchive> = Vec::new();
 
         for ident in try!(artifact.tdeps()) {
             if try!(self.is_package_installed(&ident)) {
                 try!(ui.status(Status::Using, &ident));
             } else {
-                artifacts.push(try!(self.get_cached_artifact(ui, ident, src_path)));
+                artifacts.push(try!(self.get_cached_artifact(ui, ident, local)));
             }
         }
         artifacts.push(artifact);
+        if let Some(ref policy) = self.license_policy {
+            for artifact in artifacts.iter_mut() {
+                let ident = try!(artifact.ident());
+                try!(policy.check(&ident, &try!(artifact.licenses())));
+            }
+        }
+        if let Some(ref scanner) = self.scanner {
+            for artifact in artifacts.iter_mut() {
+                let ident = try!(artifact.ident());
+                try!(ui.status(Status::Custom('☛', String::from("Scanning")),
+                               format!("{} with {}", ident, scanner.command)));
+                if let Some(finding) = try!(scanner.scan(&ident, &artifact.path)) {
+                    try!(ui.warn(finding));
+                }
+            }
+        }
 
         let num_installed = artifacts.len();
         for mut artifact in artifacts {
@@ -181,29 +362,36 @@ impl<'a> InstallTask<'a> {
     fn get_cached_artifact(&self,
                            ui: &mut UI,
                            ident: PackageIdent,
-                           src_path: Option<&Path>)
+                           local: &LocalArtifacts)
                            -> Result<PackageArchive> {
-        if try!(self.is_artifact_cached(&ident)) {
+        // Another install, or a Supervisor, may be caching the same artifact; waiting for it to
+        // finish means the artifact is downloaded once and never read half written.
+        let _lock = try!(self.artifact_cache.lock(&ident));
+        let mut verified = false;
+        if try!(self.artifact_cache.contains(&ident)) {
             debug!("Found {} in artifact cache, skipping remote download",
                    &ident);
         } else {
-            if retry(RETRIES,
-                     RETRY_WAIT,
-                     || self.fetch_artifact(ui, &ident, src_path),
-                     |res| res.is_ok())
-                .is_err() {
-                return Err(Error::from(depot_client::Error::DownloadFailed(format!("We tried {} \
-                                                                                    times but \
-                                                                                    could not \
-                                                                                    download {}. \
-                                                                                    Giving up.",
-                                                                                   RETRIES,
-                                                                                   &ident))));
+            // Only failures to reach the depot or to finish downloading are worth another try; an
+            // artifact which fails verification would fail it again, so that error is returned
+            // as it is.
+            match retry(RETRIES,
+                        RETRY_WAIT,
+                        || self.fetch_artifact(ui, &ident, local),
+                        |res| res.as_ref().err().map_or(true, |e| !is_transport_error(e))) {
+                Ok(Ok(fetched_verified)) => verified = fetched_verified,
+                Ok(Err(e)) => return Err(e),
+                Err(_) => {
+                    let msg = format!("We tried {} times but could not download {}. Giving up.",
+                                      RETRIES,
+                                      &ident);
+                    return Err(Error::from(depot_client::Error::DownloadFailed(msg)));
+                }
             }
         }
 
-        let mut artifact = PackageArchive::new(try!(self.cached_artifact_path(&ident)));
-        try!(self.verify_artifact(ui, &ident, &mut artifact));
+        let mut artifact = PackageArchive::new(try!(self.artifact_cache.artifact_path(&ident)));
+        try!(self.verify_artifact(ui, &ident, &mut artifact, verified));
         Ok(artifact)
     }
 
@@ -221,49 +409,36 @@ impl<'a> InstallTask<'a> {
         }
     }
 
-    fn is_artifact_cached(&self, ident: &PackageIdent) -> Result<bool> {
-        Ok(try!(self.cached_artifact_path(ident)).is_file())
-    }
-
-    fn cached_artifact_path(&self, ident: &PackageIdent) -> Result<PathBuf> {
-        let name = match ident.archive_name() {
-            Some(n) => n,
-            None => {
-                return Err(Error::HabitatCore(hcore::Error::InvalidPackageIdent(ident.to_string())))
-            }
-        };
-        Ok(self.cache_artifact_path.join(name))
-    }
-
     fn fetch_latest_pkg_ident_for(&self, fuzzy_ident: &PackageIdent) -> Result<PackageIdent> {
         Ok(try!(self.depot_client.show_package(fuzzy_ident)).into())
     }
 
+    /// Put the artifact in the artifact cache, from the local filesystem if it's there and from
+    /// the Depot otherwise. Returns whether the artifact's signature was verified along the way,
+    /// which it is while it downloads.
     fn fetch_artifact(&self,
                       ui: &mut UI,
                       ident: &PackageIdent,
-                      src_path: Option<&Path>)
-                      -> Result<()> {
-        if let Some(src_path) = src_path {
-            let name = match ident.archive_name() {
-                Some(n) => n,
-                None => return Err(
-                    Error::HabitatCore(hcore::Error::InvalidPackageIdent(ident.to_string()))),
-            };
-            let local_artifact = src_path.join(name);
-            if local_artifact.is_file() {
-                try!(self.cache_artifact(ident, &local_artifact));
-                return Ok(());
-            }
+                      local: &LocalArtifacts)
+                      -> Result<bool> {
+        if let Some(local_artifact) = local.find(ident) {
+            try!(ui.verbose(format!("Using local artifact {}", local_artifact.display())));
+            try!(self.cache_artifact(ident, &local_artifact));
+            return Ok(false);
         }
 
         try!(ui.status(Status::Downloading, ident));
-        match self.depot_client.fetch_package(ident, self.cache_artifact_path, ui.progress()) {
-            Ok(_) => Ok(()),
+        try!(ui.verbose(format!("Caching artifact in {}",
+                                self.artifact_cache.path().display())));
+        match self.depot_client.fetch_package_verified(ident,
+                                                       self.artifact_cache.path(),
+                                                       self.cache_key_path,
+                                                       ui.progress()) {
+            Ok(_) => Ok(true),
             Err(depot_client::Error::APIError(StatusCode::NotImplemented, _)) => {
-                println!("Host platform or architecture not supported by the targted depot; \
-                          skipping.");
-                Ok(())
+                try!(ui.warn("Host platform or architecture not supported by the targeted depot; \
+                              skipping."));
+                Ok(false)
             }
             Err(e) => Err(Error::from(e)),
         }
@@ -279,22 +454,21 @@ impl<'a> InstallTask<'a> {
         Ok(())
     }
 
+    /// Copy a local artifact into the artifact cache. The caller holds the artifact's lock.
     fn cache_artifact(&self, ident: &PackageIdent, artifact_path: &Path) -> Result<()> {
-        let name = match ident.archive_name() {
-            Some(n) => n,
-            None => {
-                return Err(Error::HabitatCore(hcore::Error::InvalidPackageIdent(ident.to_string())))
-            }
-        };
-        try!(fs::create_dir_all(self.cache_artifact_path));
-        try!(fs::copy(artifact_path, self.cache_artifact_path.join(name)));
+        let cached_path = try!(self.artifact_cache.artifact_path(ident));
+        try!(fs::create_dir_all(self.artifact_cache.path()));
+        try!(fs::copy(artifact_path, cached_path));
         Ok(())
     }
 
+    /// Check the artifact is the package asked for and runs on this platform, and verify its
+    /// signature unless `signature_verified` says that was done while it downloaded.
     fn verify_artifact(&self,
                        ui: &mut UI,
                        ident: &PackageIdent,
-                       artifact: &mut PackageArchive)
+                       artifact: &mut PackageArchive,
+                       signature_verified: bool)
                        -> Result<()> {
         let artifact_ident = try!(artifact.ident());
         if ident != &artifact_ident {
@@ -310,6 +484,9 @@ impl<'a> InstallTask<'a> {
             try!(artifact_target.validate());
         }
 
+        if signature_verified {
+            return Ok(());
+        }
 
         let nwr = try!(artifact::artifact_signer(&artifact.path));
         if let Err(_) = SigKeyPair::get_public_key_path(&nwr, self.cache_key_path) {
@@ -321,3 +498,72 @@ impl<'a> InstallTask<'a> {
         Ok(())
     }
 }
+
+/// Whether a failed fetch failed on its way from the depot, rather than on what it fetched.
+fn is_transport_error(err: &Error) -> bool {
+    match *err {
+        Error::DepotClient(depot_client::Error::HyperError(_)) |
+        Error::DepotClient(depot_client::Error::HabitatHttpClient(_)) |
+        Error::DepotClient(depot_client::Error::IO(_)) => true,
+        Error::DepotClient(depot_client::Error::APIError(ref code, _)) => code.is_server_error(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use tempdir::TempDir;
+
+    use super::local_artifacts;
+
+    fn artifact_dir() -> TempDir {
+        let dir = TempDir::new("artifacts").unwrap();
+        for name in &["core-a-1.0.0-20170101000000-x86_64-linux.hart",
+                      "core-b-1.0.0-20170101000000-x86_64-linux.hart",
+                      "README"] {
+            File::create(dir.path().join(name)).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn ident_is_not_local() {
+        assert_eq!(local_artifacts("core/redis").unwrap(), None);
+    }
+
+    #[test]
+    fn directory_names_its_artifacts() {
+        let dir = artifact_dir();
+        let mut paths = local_artifacts(&dir.path().to_string_lossy()).unwrap().unwrap();
+        paths.sort();
+        assert_eq!(paths,
+                   vec![dir.path().join("core-a-1.0.0-20170101000000-x86_64-linux.hart"),
+                        dir.path().join("core-b-1.0.0-20170101000000-x86_64-linux.hart")]);
+    }
+
+    #[test]
+    fn glob_names_matching_artifacts() {
+        let dir = artifact_dir();
+        let glob = dir.path().join("core-b-*.hart");
+        let paths = local_artifacts(&glob.to_string_lossy()).unwrap().unwrap();
+        assert_eq!(paths,
+                   vec![dir.path().join("core-b-1.0.0-20170101000000-x86_64-linux.hart")]);
+    }
+
+    #[test]
+    fn file_url_names_an_artifact() {
+        let dir = artifact_dir();
+        let path = dir.path().join("core-a-1.0.0-20170101000000-x86_64-linux.hart");
+        let url = format!("file://{}", path.display());
+        assert_eq!(local_artifacts(&url).unwrap().unwrap(), vec![path]);
+    }
+
+    #[test]
+    fn glob_without_artifacts_is_an_error() {
+        let dir = artifact_dir();
+        let glob = dir.path().join("core-c-*.hart");
+        assert!(local_artifacts(&glob.to_string_lossy()).is_err());
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pins the releases a service or composite runs in a lockfile, and installs from one.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg lock acme/shop 'core/redis >=3.2 <4'
+//! ```
+//!
+//! Resolves the requirements as `hab pkg solve` does, and writes the releases chosen along with
+//! every one of their transitive dependencies to `habitat.lock`.
+//!
+//! ```bash
+//! $ hab pkg install --locked habitat.lock acme/shop
+//! ```
+//!
+//! Installs the release of `acme/shop` the lockfile pins, refusing to if any of its dependencies
+//! aren't pinned.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::str::FromStr;
+
+use depot_client::Client;
+use hcore;
+use hcore::package::{LockFile, PackageArchive, PackageIdent, PackageRequirement};
+use hcore::package::solver::PackageUniverse;
+
+use command::package::install;
+use command::package::solve::{self, PackageReleases};
+use error::{Error, Result};
+use ui::{Status, UI};
+
+/// Resolves the requirements and writes a lockfile to `lock_path` pinning the releases chosen
+/// and their transitive dependencies.
+pub fn start<P1: ?Sized, P2: ?Sized>(ui: &mut UI,
+                                     url: Option<&str>,
+                                     requirements: &[&str],
+                                     product: &str,
+                                     version: &str,
+                                     fs_root_path: &P1,
+                                     lock_path: &P2)
+                                     -> Result<LockFile>
+    where P1: AsRef<Path>,
+          P2: AsRef<Path>
+{
+    let roots = try!(solve::start(ui, url, requirements, product, version, fs_root_path));
+    let fs_root_path = fs_root_path.as_ref();
+    let depot_client = match url {
+        Some(url) => Some(try!(Client::new(url, product, version, Some(fs_root_path)))),
+        None => None,
+    };
+    let universe = PackageReleases::new(fs_root_path, depot_client);
+    let mut packages = BTreeSet::new();
+    for root in roots.iter() {
+        packages.extend(try!(universe.tdeps(root)));
+    }
+    let packages: Vec<PackageIdent> = packages.into_iter().collect();
+    let lock = LockFile::new(&roots, &packages);
+    try!(lock.write(lock_path));
+    try!(ui.status(Status::Custom('✓', String::from("Locked")),
+                   format!("{} releases in {}",
+                           lock.packages.len(),
+                           lock_path.as_ref().display())));
+    Ok(lock)
+}
+
+/// Replaces each of `idents_or_archives` which is a package identifier or requirement with the
+/// release `lock` pins for it. Artifacts are returned as they are.
+///
+/// # Failures
+///
+/// * A package isn't pinned by the lockfile
+/// * A package or artifact depends on a release the lockfile doesn't pin
+pub fn pin_locked<P: ?Sized>(ui: &mut UI,
+                             url: &str,
+                             idents_or_archives: &[&str],
+                             lock: &LockFile,
+                             product: &str,
+                             version: &str,
+                             fs_root_path: &P)
+                             -> Result<Vec<String>>
+    where P: AsRef<Path>
+{
+    let fs_root_path = fs_root_path.as_ref();
+    let depot_client = try!(Client::new(url, product, version, Some(fs_root_path)));
+    let universe = PackageReleases::new(fs_root_path, Some(depot_client));
+    let mut pinned = Vec::with_capacity(idents_or_archives.len());
+    for ident_or_archive in idents_or_archives {
+        if let Some(artifact_paths) = try!(install::local_artifacts(ident_or_archive)) {
+            for path in artifact_paths {
+                let mut archive = PackageArchive::new(path);
+                let mut idents = try!(archive.tdeps());
+                idents.push(try!(archive.ident()));
+                try!(lock.check(&idents));
+            }
+            pinned.push(ident_or_archive.to_string());
+            continue;
+        }
+        let requirement = try!(PackageRequirement::from_str(ident_or_archive));
+        let release = try!(lock.release_for(&requirement.ident));
+        if !try!(requirement.satisfied_by(&release)) {
+            let msg = format!("{} is pinned by the lockfile, which doesn't satisfy {}",
+                              release,
+                              requirement);
+            return Err(Error::from(hcore::Error::PackageNotLocked(msg)));
+        }
+        let mut idents = try!(universe.tdeps(&release));
+        idents.push(release.clone());
+        try!(lock.check(&idents));
+        try!(ui.status(Status::Using, format!("{} from the lockfile", release)));
+        pinned.push(release.to_string());
+    }
+    Ok(pinned)
+}
@@ -13,3 +13,5 @@
 // limitations under the License.
 
 pub mod install;
+pub mod lock;
+pub mod solve;
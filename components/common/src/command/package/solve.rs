@@ -0,0 +1,150 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Resolves package requirements, such as `core/glibc >=2.27 <3`, to the releases which satisfy
+//! them together, from the packages installed and those in a [depot](../depot).
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg solve 'core/curl' 'core/openssl >=1.0.2 <1.1'
+//! ```
+//!
+//! Prints the release chosen for each requirement, one per line, in the order they were given.
+//! The release of `core/curl` chosen is the newest which was built against a `core/openssl` that
+//! satisfies the second requirement.
+
+use std::path::Path;
+use std::str::FromStr;
+
+use depot_client::{self, Client};
+use hcore;
+use hcore::package::{PackageIdent, PackageInstall, PackageRequirement};
+use hcore::package::solver::{self, InstalledPackages, PackageUniverse};
+use hyper::status::StatusCode;
+
+use error::{Error, Result};
+use ui::{Status, UI};
+
+/// Returns the release chosen for each requirement, in the same order. Releases in the depot at
+/// `url` are considered along with those installed, unless `url` is `None`.
+pub fn start<P: ?Sized>(ui: &mut UI,
+                        url: Option<&str>,
+                        requirements: &[&str],
+                        product: &str,
+                        version: &str,
+                        fs_root_path: &P)
+                        -> Result<Vec<PackageIdent>>
+    where P: AsRef<Path>
+{
+    let mut parsed = Vec::with_capacity(requirements.len());
+    for requirement in requirements {
+        parsed.push(try!(PackageRequirement::from_str(requirement)));
+    }
+    try!(ui.begin(format!("Resolving {}", requirements.join(", "))));
+    let fs_root_path = fs_root_path.as_ref();
+    let depot_client = match url {
+        Some(url) => Some(try!(Client::new(url, product, version, Some(fs_root_path)))),
+        None => None,
+    };
+    let universe = PackageReleases::new(fs_root_path, depot_client);
+    let solved = try!(solver::solve(&universe, &parsed));
+    for (requirement, ident) in parsed.iter().zip(solved.iter()) {
+        try!(ui.status(Status::Using, format!("{} for {}", ident, requirement)));
+    }
+    try!(ui.end("Resolved every requirement."));
+    Ok(solved)
+}
+
+/// Replaces each of `idents_or_archives` which is a requirement with a version constraint, such
+/// as `core/glibc >=2.27 <3`, with the release chosen for it. Those requirements are resolved
+/// together; package identifiers and artifacts are returned as they are.
+pub fn pin_requirements<P: ?Sized>(ui: &mut UI,
+                                   url: &str,
+                                   idents_or_archives: &[&str],
+                                   product: &str,
+                                   version: &str,
+                                   fs_root_path: &P)
+                                   -> Result<Vec<String>>
+    where P: AsRef<Path>
+{
+    let constrained: Vec<&str> = idents_or_archives.iter()
+        .cloned()
+        .filter(|s| is_constrained(s))
+        .collect();
+    let mut pinned = if constrained.is_empty() {
+        Vec::new().into_iter()
+    } else {
+        try!(start(ui, Some(url), &constrained, product, version, fs_root_path)).into_iter()
+    };
+    Ok(idents_or_archives.iter()
+        .map(|s| if is_constrained(s) {
+            pinned.next().unwrap().to_string()
+        } else {
+            s.to_string()
+        })
+        .collect())
+}
+
+/// Returns whether `ident_or_archive` is a package requirement with a version constraint, rather
+/// than a package identifier or the path to an artifact.
+fn is_constrained(ident_or_archive: &str) -> bool {
+    !Path::new(ident_or_archive).exists() && ident_or_archive.trim().contains(char::is_whitespace)
+}
+
+/// The packages installed under a filesystem root, along with those in a depot if there is one.
+pub struct PackageReleases<'a> {
+    installed: InstalledPackages,
+    fs_root_path: &'a Path,
+    depot_client: Option<Client>,
+}
+
+impl<'a> PackageReleases<'a> {
+    pub fn new(fs_root_path: &'a Path, depot_client: Option<Client>) -> Self {
+        PackageReleases {
+            installed: InstalledPackages::new(fs_root_path),
+            fs_root_path: fs_root_path,
+            depot_client: depot_client,
+        }
+    }
+}
+
+impl<'a> PackageUniverse for PackageReleases<'a> {
+    type Error = Error;
+
+    fn releases(&self, ident: &PackageIdent) -> Result<Vec<PackageIdent>> {
+        let mut releases = try!(self.installed.releases(ident));
+        if let Some(ref client) = self.depot_client {
+            match client.list_package_releases(ident) {
+                Ok(found) => releases.extend(found),
+                Err(depot_client::Error::APIError(StatusCode::NotFound, _)) => (),
+                Err(e) => return Err(Error::from(e)),
+            }
+        }
+        Ok(releases)
+    }
+
+    fn tdeps(&self, ident: &PackageIdent) -> Result<Vec<PackageIdent>> {
+        if let Ok(package) = PackageInstall::load(ident, Some(self.fs_root_path)) {
+            return Ok(try!(package.tdeps()));
+        }
+        match self.depot_client {
+            Some(ref client) => {
+                let package = try!(client.show_package(ident));
+                Ok(package.get_tdeps().iter().cloned().map(|i| i.into()).collect())
+            }
+            None => Err(Error::from(hcore::Error::PackageNotFound(ident.clone()))),
+        }
+    }
+}
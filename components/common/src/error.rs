@@ -20,6 +20,7 @@ use std::str;
 use std::string;
 
 use depot_client;
+use glob;
 use hcore;
 
 pub type Result<T> = result::Result<T, Error>;
@@ -32,8 +33,11 @@ pub enum Error {
     GossipFileRelativePath(String),
     DepotClient(depot_client::Error),
     FileNameError,
+    GlobPattern(glob::PatternError),
     HabitatCore(hcore::Error),
     InvalidTomlError(String),
+    /// Occurs when an artifact path, `file://` URL, or glob doesn't name any artifacts.
+    NoLocalArtifacts(String),
     /// Occurs when making lower level IO calls.
     IO(io::Error),
     RootRequired,
@@ -61,9 +65,11 @@ impl fmt::Display for Error {
             }
             Error::DepotClient(ref err) => format!("{}", err),
             Error::FileNameError => format!("Failed to extract a filename"),
+            Error::GlobPattern(ref e) => format!("Invalid artifact glob: {}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::InvalidTomlError(ref e) => format!("Invalid TOML: {}", e),
             Error::IO(ref err) => format!("{}", err),
+            Error::NoLocalArtifacts(ref s) => format!("No Habitat Artifacts found at {}", s),
             Error::RootRequired => {
                 "Root or administrator permissions required to complete operation".to_string()
             }
@@ -88,9 +94,11 @@ impl error::Error for Error {
             }
             Error::DepotClient(ref err) => err.description(),
             Error::FileNameError => "Failed to extract a filename from a path",
+            Error::GlobPattern(_) => "Invalid artifact glob",
             Error::HabitatCore(ref err) => err.description(),
             Error::InvalidTomlError(_) => "Invalid TOML",
             Error::IO(ref err) => err.description(),
+            Error::NoLocalArtifacts(_) => "No Habitat Artifacts found at the given path",
             Error::RootRequired => {
                 "Root or administrator permissions required to complete operation"
             }
@@ -107,6 +115,12 @@ impl From<depot_client::Error> for Error {
     }
 }
 
+impl From<glob::PatternError> for Error {
+    fn from(err: glob::PatternError) -> Self {
+        Error::GlobPattern(err)
+    }
+}
+
 impl From<hcore::Error> for Error {
     fn from(err: hcore::Error) -> Self {
         Error::HabitatCore(err)
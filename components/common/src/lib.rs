@@ -16,17 +16,20 @@ extern crate habitat_builder_protocol as protocol;
 extern crate habitat_core as hcore;
 extern crate habitat_depot_client as depot_client;
 extern crate ansi_term;
+extern crate glob;
 extern crate hyper;
 #[macro_use]
 extern crate log;
 extern crate pbr;
 extern crate regex;
 extern crate retry;
+extern crate serde_json;
 #[cfg(test)]
 extern crate tempdir;
 extern crate term;
 extern crate time;
 extern crate toml;
+extern crate url;
 
 pub use self::error::{Error, Result};
 
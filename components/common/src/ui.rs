@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt;
 use std::io::{self, BufRead, BufReader, Read, Write};
 use std::process;
@@ -19,6 +20,7 @@ use std::process;
 use ansi_term::Colour;
 use depot_client::DisplayProgress;
 use pbr;
+use serde_json;
 use term::terminfo::TermInfo;
 use term::{Terminal, TerminfoTerminal};
 
@@ -71,21 +73,126 @@ impl Status {
     }
 }
 
+/// How much a command prints. Warnings and errors are always printed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    Quiet,
+    Normal,
+    Verbose,
+}
+
+/// The form a command's messages are printed in. As JSON, each message is an object on a line of
+/// its own, with its `type`, `message`, and for statuses, the `status`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
 pub struct UI {
     shell: Shell,
+    verbosity: Verbosity,
+    format: OutputFormat,
 }
 
 impl UI {
     pub fn default_with(coloring: Coloring, isatty: Option<bool>) -> Self {
-        UI { shell: Shell::default_with(coloring, isatty) }
+        UI::new(coloring, isatty, Verbosity::Normal, OutputFormat::Text)
+    }
+
+    pub fn new(coloring: Coloring,
+               isatty: Option<bool>,
+               verbosity: Verbosity,
+               format: OutputFormat)
+               -> Self {
+        let coloring = match format {
+            OutputFormat::Text => coloring,
+            OutputFormat::Json => Coloring::Never,
+        };
+        UI {
+            shell: Shell::default_with(coloring, isatty),
+            verbosity: verbosity,
+            format: format,
+        }
+    }
+
+    pub fn verbosity(&self) -> Verbosity {
+        self.verbosity
+    }
+
+    pub fn format(&self) -> OutputFormat {
+        self.format
     }
 
     pub fn begin<T: ToString>(&mut self, message: T) -> Result<()> {
-        Self::write_heading(&mut self.shell.out, Colour::Yellow, '»', message)
+        if self.verbosity == Verbosity::Quiet {
+            return Ok(());
+        }
+        match self.format {
+            OutputFormat::Text => {
+                Self::write_heading(&mut self.shell.out, Colour::Yellow, '»', message)
+            }
+            OutputFormat::Json => {
+                Self::write_json(&mut self.shell.out, "begin", None, &message.to_string())
+            }
+        }
     }
 
     pub fn end<T: ToString>(&mut self, message: T) -> Result<()> {
-        Self::write_heading(&mut self.shell.out, Colour::Blue, '★', message)
+        if self.verbosity == Verbosity::Quiet {
+            return Ok(());
+        }
+        match self.format {
+            OutputFormat::Text => {
+                Self::write_heading(&mut self.shell.out, Colour::Blue, '★', message)
+            }
+            OutputFormat::Json => {
+                Self::write_json(&mut self.shell.out, "end", None, &message.to_string())
+            }
+        }
+    }
+
+    /// Print a detail which is only of interest when asked for with `--verbose`.
+    pub fn verbose<T: fmt::Display>(&mut self, message: T) -> Result<()> {
+        if self.verbosity != Verbosity::Verbose {
+            return Ok(());
+        }
+        match self.format {
+            OutputFormat::Text => {
+                let ref mut stream = self.shell.out;
+                match stream.is_colored() {
+                    true => {
+                        try!(write!(stream,
+                                    "{}\n",
+                                    Colour::White.dimmed().paint(format!("· {}", message))))
+                    }
+                    false => try!(write!(stream, "· {}\n", message)),
+                }
+                try!(stream.flush());
+                Ok(())
+            }
+            OutputFormat::Json => {
+                Self::write_json(&mut self.shell.out, "verbose", None, &message.to_string())
+            }
+        }
+    }
+
+    /// Print a line of output produced elsewhere, such as a build log, as it is.
+    pub fn line<T: fmt::Display>(&mut self, line: T) -> Result<()> {
+        if self.verbosity == Verbosity::Quiet {
+            return Ok(());
+        }
+        match self.format {
+            OutputFormat::Text => {
+                let ref mut stream = self.shell.out;
+                try!(write!(stream, "{}\n", line));
+                try!(stream.flush());
+                Ok(())
+            }
+            OutputFormat::Json => {
+                Self::write_json(&mut self.shell.out, "line", None, &line.to_string())
+            }
+        }
     }
 
     pub fn is_a_tty(&self) -> bool {
@@ -93,8 +200,14 @@ impl UI {
     }
 
     pub fn status<T: fmt::Display>(&mut self, status: Status, message: T) -> Result<()> {
+        if self.verbosity == Verbosity::Quiet {
+            return Ok(());
+        }
         let ref mut stream = self.shell.out;
         let (symbol, status_str, color) = status.parts();
+        if self.format == OutputFormat::Json {
+            return Self::write_json(stream, "status", Some(&status_str), &message.to_string());
+        }
         match stream.is_colored() {
             true => {
                 try!(write!(stream,
@@ -116,6 +229,9 @@ impl UI {
 
     pub fn warn<T: fmt::Display>(&mut self, message: T) -> Result<()> {
         let ref mut stream = self.shell.err;
+        if self.format == OutputFormat::Json {
+            return Self::write_json(stream, "warn", None, &message.to_string());
+        }
         match stream.is_colored() {
             true => {
                 try!(write!(stream,
@@ -132,6 +248,9 @@ impl UI {
 
     pub fn fatal<T: fmt::Display>(&mut self, message: T) -> Result<()> {
         let ref mut stream = self.shell.err;
+        if self.format == OutputFormat::Json {
+            return Self::write_json(stream, "fatal", None, &message.to_string());
+        }
         let formatted_message = message.to_string()
             .lines()
             .map(|line| format!("✗✗✗ {}", line))
@@ -153,16 +272,26 @@ impl UI {
         Ok(())
     }
 
+    /// A progress bar for a transfer. When output isn't a terminal, progress is printed as a line
+    /// at each quarter of the transfer instead, and not at all when quiet or printing JSON.
     pub fn progress(&mut self) -> Option<ProgressBar> {
-        if self.shell.out.is_a_terminal() {
+        if self.verbosity == Verbosity::Quiet || self.format == OutputFormat::Json {
+            None
+        } else if self.shell.out.is_a_terminal() {
             Some(ProgressBar::default())
         } else {
-            None
+            Some(ProgressBar::plain())
         }
     }
 
     pub fn title(&mut self, text: &str) -> Result<()> {
+        if self.verbosity == Verbosity::Quiet {
+            return Ok(());
+        }
         let ref mut stream = self.shell.out;
+        if self.format == OutputFormat::Json {
+            return Self::write_json(stream, "title", None, text);
+        }
         match stream.is_colored() {
             true => {
                 try!(write!(stream, "{}\n", Colour::Green.bold().paint(text)));
@@ -183,7 +312,13 @@ impl UI {
     }
 
     pub fn heading(&mut self, text: &str) -> Result<()> {
+        if self.verbosity == Verbosity::Quiet {
+            return Ok(());
+        }
         let ref mut stream = self.shell.out;
+        if self.format == OutputFormat::Json {
+            return Self::write_json(stream, "heading", None, text);
+        }
         match stream.is_colored() {
             true => {
                 try!(write!(stream, "{}\n\n", Colour::Green.bold().paint(text)));
@@ -197,10 +332,19 @@ impl UI {
     }
 
     pub fn para(&mut self, text: &str) -> Result<()> {
-        Self::print_wrapped(&mut self.shell.out, text, 75, 2)
+        if self.verbosity == Verbosity::Quiet {
+            return Ok(());
+        }
+        match self.format {
+            OutputFormat::Text => Self::print_wrapped(&mut self.shell.out, text, 75, 2),
+            OutputFormat::Json => Self::write_json(&mut self.shell.out, "para", None, text),
+        }
     }
 
     pub fn br(&mut self) -> Result<()> {
+        if self.verbosity == Verbosity::Quiet || self.format == OutputFormat::Json {
+            return Ok(());
+        }
         let ref mut stream = self.shell.out;
         try!(write!(stream, "\n"));
         try!(stream.flush());
@@ -333,6 +477,24 @@ impl UI {
         Ok(())
     }
 
+    fn write_json(stream: &mut OutputStream,
+                  kind: &str,
+                  status: Option<&str>,
+                  message: &str)
+                  -> Result<()> {
+        let mut object = BTreeMap::new();
+        object.insert("type", kind);
+        if let Some(status) = status {
+            object.insert("status", status);
+        }
+        object.insert("message", message);
+        try!(write!(stream,
+                    "{}\n",
+                    serde_json::to_string(&object).expect("A map of strings is valid JSON")));
+        try!(stream.flush());
+        Ok(())
+    }
+
     fn print_wrapped(stream: &mut OutputStream,
                      text: &str,
                      wrap_width: usize,
@@ -629,52 +791,82 @@ mod tty {
 /// number of bytes representing the total download/upload/transfer size) and will be a generic
 /// writer (i.e. implementing the `Write` trait) as a means to increase progress towards
 /// completion.
+///
+/// Without a terminal to redraw the bar on, a plain progress bar prints a line each time another
+/// quarter of the task is done, so logs of non-interactive runs still show progress.
 pub struct ProgressBar {
-    bar: pbr::ProgressBar,
+    bar: Option<pbr::ProgressBar>,
     total: u64,
     current: u64,
+    /// The last quarter a plain progress bar printed a line for.
+    quarter: u64,
 }
 
 impl Default for ProgressBar {
     fn default() -> Self {
         ProgressBar {
-            bar: pbr::ProgressBar::new(0),
+            bar: Some(pbr::ProgressBar::new(0)),
             total: 0,
             current: 0,
+            quarter: 0,
+        }
+    }
+}
+
+impl ProgressBar {
+    pub fn plain() -> Self {
+        ProgressBar {
+            bar: None,
+            total: 0,
+            current: 0,
+            quarter: 0,
         }
     }
 }
 
 impl DisplayProgress for ProgressBar {
     fn size(&mut self, size: u64) {
-        self.bar = pbr::ProgressBar::new(size);
-        self.bar.set_units(pbr::Units::Bytes);
-        self.bar.show_tick = true;
-        self.bar.message("    ");
+        if let Some(ref mut bar) = self.bar {
+            *bar = pbr::ProgressBar::new(size);
+            bar.set_units(pbr::Units::Bytes);
+            bar.show_tick = true;
+            bar.message("    ");
+        }
         self.total = size;
     }
 
     fn finish(&mut self) {
-        println!("");
-        io::stdout().flush().ok().expect("flush() fail");
+        if self.bar.is_some() {
+            println!("");
+            io::stdout().flush().ok().expect("flush() fail");
+        }
     }
 }
 
 impl Write for ProgressBar {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        match self.bar.write(buf) {
-            Ok(n) => {
-                self.current += n as u64;
-                if self.current == self.total {
-                    self.finish();
-                }
-                Ok(n)
+        let n = match self.bar {
+            Some(ref mut bar) => try!(bar.write(buf)),
+            None => buf.len(),
+        };
+        self.current += n as u64;
+        if self.bar.is_none() && self.total > 0 {
+            let quarter = self.current * 4 / self.total;
+            if quarter > self.quarter {
+                self.quarter = quarter;
+                println!("    {}% of {} bytes", quarter * 25, self.total);
             }
-            Err(e) => Err(e),
         }
+        if self.current == self.total {
+            self.finish();
+        }
+        Ok(n)
     }
 
     fn flush(&mut self) -> io::Result<()> {
-        self.bar.flush()
+        match self.bar {
+            Some(ref mut bar) => bar.flush(),
+            None => io::stdout().flush(),
+        }
     }
 }
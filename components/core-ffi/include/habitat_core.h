@@ -0,0 +1,87 @@
+/*
+ * Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * C bindings to Habitat package identifiers and artifacts, implemented by
+ * libhabitat_core_ffi.
+ *
+ * Functions which can fail return HAB_OK on success and HAB_ERROR on failure;
+ * hab_last_error returns why the last failing call on the calling thread
+ * failed. Every string returned is owned by the caller, and is freed with
+ * hab_string_free or the free function of the struct holding it. Strings
+ * passed in must be NUL terminated UTF-8, and are only borrowed for the call.
+ */
+
+#ifndef HABITAT_CORE_H
+#define HABITAT_CORE_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define HAB_OK 0
+#define HAB_ERROR (-1)
+
+/* A package identifier; version and release are NULL when absent. */
+typedef struct {
+    char *origin;
+    char *name;
+    char *version;
+    char *release;
+} HabIdent;
+
+/* The header of an artifact, which precedes its compressed payload. */
+typedef struct {
+    char *format_version;
+    char *key_name;
+    char *hash_type;
+    char *signature;
+} HabArtifactHeader;
+
+/* Why the last failing call on this thread failed, or NULL if none has. */
+char *hab_last_error(void);
+
+/* Frees a returned string. Freeing NULL does nothing. */
+void hab_string_free(char *s);
+
+/* Parses an identifier such as "core/redis/3.2.4/20170514150022". */
+int hab_ident_parse(const char *ident, HabIdent *out);
+void hab_ident_free(HabIdent *ident);
+
+/* 1 if valid, 0 if not, HAB_ERROR if ident is NULL or not UTF-8. */
+int hab_ident_is_valid(const char *ident);
+
+/* 1 if the identifier has a version and release, 0 if not, HAB_ERROR if it
+ * isn't a valid identifier. */
+int hab_ident_is_fully_qualified(const char *ident);
+
+/* Reads an artifact's header, without verifying its signature. */
+int hab_artifact_header(const char *path, HabArtifactHeader *out);
+void hab_artifact_header_free(HabArtifactHeader *header);
+
+/* Verifies an artifact's signature with the origin keys in cache_key_path.
+ * On success the signing key's name and revision, and the artifact's
+ * checksum, are stored in signer and checksum unless they are NULL. */
+int hab_artifact_verify(const char *path,
+                        const char *cache_key_path,
+                        char **signer,
+                        char **checksum);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* HABITAT_CORE_H */
@@ -0,0 +1,328 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! C bindings to Habitat package identifiers and artifacts.
+//!
+//! Tooling written in other languages, such as Go or Python scripts in a release pipeline, can
+//! link against `libhabitat_core_ffi` to parse identifiers, read artifact headers and verify
+//! artifact signatures exactly as `hab` does, rather than re-implementing the artifact format.
+//! The functions are declared in `include/habitat_core.h`.
+//!
+//! The conventions are the same for every function:
+//!
+//! * Functions which can fail return `HAB_OK` (0) on success and `HAB_ERROR` (-1) on failure.
+//!   The reason for the last failure on the calling thread is returned by `hab_last_error`.
+//! * Every string returned to the caller, directly or in a struct, is owned by the caller and
+//!   freed with `hab_string_free`, or with the struct's own free function.
+//! * Strings passed in must be NUL terminated UTF-8, and are only borrowed for the call.
+//! * A panic never unwinds into the caller; it is reported as a failure.
+
+extern crate habitat_core as hcore;
+extern crate libc;
+
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::str::FromStr;
+
+use hcore::crypto::artifact;
+use hcore::package::{Identifiable, PackageIdent};
+use libc::{c_char, c_int};
+
+pub const HAB_OK: c_int = 0;
+pub const HAB_ERROR: c_int = -1;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<String>> = RefCell::new(None);
+}
+
+/// A package identifier. `version` and `release` are null when the identifier doesn't have them.
+#[repr(C)]
+pub struct HabIdent {
+    pub origin: *mut c_char,
+    pub name: *mut c_char,
+    pub version: *mut c_char,
+    pub release: *mut c_char,
+}
+
+/// The header of an artifact, which precedes its compressed payload.
+#[repr(C)]
+pub struct HabArtifactHeader {
+    pub format_version: *mut c_char,
+    pub key_name: *mut c_char,
+    pub hash_type: *mut c_char,
+    pub signature: *mut c_char,
+}
+
+/// Returns the reason the last failing call on this thread failed, or null if none has.
+#[no_mangle]
+pub extern "C" fn hab_last_error() -> *mut c_char {
+    LAST_ERROR.with(|e| match *e.borrow() {
+        Some(ref msg) => into_c_string(msg.clone()),
+        None => ptr::null_mut(),
+    })
+}
+
+/// Frees a string returned by any of these functions. Freeing null does nothing.
+#[no_mangle]
+pub unsafe extern "C" fn hab_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Parses a package identifier such as `core/redis/3.2.4/20170514150022` into `out`, which is
+/// then freed with `hab_ident_free`.
+#[no_mangle]
+pub unsafe extern "C" fn hab_ident_parse(ident: *const c_char, out: *mut HabIdent) -> c_int {
+    guard(|| {
+        if out.is_null() {
+            return Err(String::from("out is null"));
+        }
+        let ident = try!(parse_ident(ident));
+        *out = HabIdent {
+            origin: into_c_string(ident.origin),
+            name: into_c_string(ident.name),
+            version: ident.version.map_or(ptr::null_mut(), into_c_string),
+            release: ident.release.map_or(ptr::null_mut(), into_c_string),
+        };
+        Ok(HAB_OK)
+    })
+}
+
+/// Frees the strings of an identifier filled in by `hab_ident_parse`, and nulls them.
+#[no_mangle]
+pub unsafe extern "C" fn hab_ident_free(ident: *mut HabIdent) {
+    if let Some(ident) = ident.as_mut() {
+        for s in &mut [&mut ident.origin, &mut ident.name, &mut ident.version, &mut ident.release] {
+            hab_string_free(**s);
+            **s = ptr::null_mut();
+        }
+    }
+}
+
+/// Returns 1 if `ident` is a valid package identifier, 0 if it isn't, and `HAB_ERROR` if it
+/// isn't a string.
+#[no_mangle]
+pub unsafe extern "C" fn hab_ident_is_valid(ident: *const c_char) -> c_int {
+    guard(|| {
+        let ident = try!(borrow_str(ident, "ident"));
+        match PackageIdent::from_str(ident) {
+            Ok(ref ident) if ident.valid() => Ok(1),
+            _ => Ok(0),
+        }
+    })
+}
+
+/// Returns 1 if `ident` is a fully qualified package identifier, with a version and release, 0
+/// if it isn't, and `HAB_ERROR` if it isn't a valid identifier.
+#[no_mangle]
+pub unsafe extern "C" fn hab_ident_is_fully_qualified(ident: *const c_char) -> c_int {
+    guard(|| {
+        let ident = try!(parse_ident(ident));
+        Ok(if ident.fully_qualified() { 1 } else { 0 })
+    })
+}
+
+/// Reads the header of the artifact at `path` into `out`, which is then freed with
+/// `hab_artifact_header_free`. The signature isn't verified.
+#[no_mangle]
+pub unsafe extern "C" fn hab_artifact_header(path: *const c_char,
+                                             out: *mut HabArtifactHeader)
+                                             -> c_int {
+    guard(|| {
+        if out.is_null() {
+            return Err(String::from("out is null"));
+        }
+        let path = try!(borrow_str(path, "path"));
+        let header = try!(artifact::get_artifact_header(path).map_err(|e| e.to_string()));
+        *out = HabArtifactHeader {
+            format_version: into_c_string(header.format_version),
+            key_name: into_c_string(header.key_name),
+            hash_type: into_c_string(header.hash_type),
+            signature: into_c_string(header.signature_raw),
+        };
+        Ok(HAB_OK)
+    })
+}
+
+/// Frees the strings of a header filled in by `hab_artifact_header`, and nulls them.
+#[no_mangle]
+pub unsafe extern "C" fn hab_artifact_header_free(header: *mut HabArtifactHeader) {
+    if let Some(header) = header.as_mut() {
+        for s in &mut [&mut header.format_version,
+                       &mut header.key_name,
+                       &mut header.hash_type,
+                       &mut header.signature] {
+            hab_string_free(**s);
+            **s = ptr::null_mut();
+        }
+    }
+}
+
+/// Verifies the signature of the artifact at `path` with the origin keys in `cache_key_path`.
+/// On success, the name and revision of the key which signed it is stored in `signer` and its
+/// checksum in `checksum`, unless either is null.
+#[no_mangle]
+pub unsafe extern "C" fn hab_artifact_verify(path: *const c_char,
+                                             cache_key_path: *const c_char,
+                                             signer: *mut *mut c_char,
+                                             checksum: *mut *mut c_char)
+                                             -> c_int {
+    guard(|| {
+        let path = try!(borrow_str(path, "path"));
+        let cache_key_path = try!(borrow_str(cache_key_path, "cache_key_path"));
+        let (name_with_rev, hash) = try!(artifact::verify(path, cache_key_path)
+            .map_err(|e| e.to_string()));
+        if !signer.is_null() {
+            *signer = into_c_string(name_with_rev);
+        }
+        if !checksum.is_null() {
+            *checksum = into_c_string(hash);
+        }
+        Ok(HAB_OK)
+    })
+}
+
+/// Runs `f`, recording why it failed, or panicked, as the thread's last error.
+fn guard<F>(f: F) -> c_int
+    where F: FnOnce() -> Result<c_int, String>
+{
+    let msg = match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(code)) => return code,
+        Ok(Err(msg)) => msg,
+        Err(_) => String::from("habitat_core_ffi panicked"),
+    };
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(msg));
+    HAB_ERROR
+}
+
+unsafe fn borrow_str<'a>(s: *const c_char, what: &str) -> Result<&'a str, String> {
+    if s.is_null() {
+        return Err(format!("{} is null", what));
+    }
+    CStr::from_ptr(s).to_str().map_err(|_| format!("{} isn't valid UTF-8", what))
+}
+
+unsafe fn parse_ident(ident: *const c_char) -> Result<PackageIdent, String> {
+    let ident = try!(borrow_str(ident, "ident"));
+    match PackageIdent::from_str(ident) {
+        Ok(ident) => {
+            if ident.valid() {
+                Ok(ident)
+            } else {
+                Err(format!("Invalid package identifier: {}", ident))
+            }
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Hands a string to the caller. Strings from Habitat never contain NUL, but if one somehow does
+/// it is truncated there rather than failing.
+fn into_c_string(s: String) -> *mut c_char {
+    let s = match CString::new(s) {
+        Ok(s) => s,
+        Err(e) => {
+            let nul = e.nul_position();
+            let mut bytes = e.into_vec();
+            bytes.truncate(nul);
+            CString::new(bytes).expect("Truncated at the first NUL")
+        }
+    };
+    s.into_raw()
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::{CStr, CString};
+    use std::path::PathBuf;
+    use std::ptr;
+
+    use super::*;
+
+    fn fixture(name: &str) -> CString {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../core/tests/fixtures")
+            .join(name);
+        CString::new(path.to_string_lossy().into_owned()).unwrap()
+    }
+
+    unsafe fn take(s: *mut c_char) -> String {
+        let owned = CStr::from_ptr(s).to_string_lossy().into_owned();
+        hab_string_free(s);
+        owned
+    }
+
+    #[test]
+    fn ident_parse() {
+        unsafe {
+            let mut ident = HabIdent {
+                origin: ptr::null_mut(),
+                name: ptr::null_mut(),
+                version: ptr::null_mut(),
+                release: ptr::null_mut(),
+            };
+            let s = CString::new("core/redis/3.2.4").unwrap();
+            assert_eq!(hab_ident_parse(s.as_ptr(), &mut ident), HAB_OK);
+            assert_eq!(CStr::from_ptr(ident.origin).to_str().unwrap(), "core");
+            assert_eq!(CStr::from_ptr(ident.name).to_str().unwrap(), "redis");
+            assert_eq!(CStr::from_ptr(ident.version).to_str().unwrap(), "3.2.4");
+            assert!(ident.release.is_null());
+            hab_ident_free(&mut ident);
+            assert!(ident.origin.is_null());
+
+            assert_eq!(hab_ident_is_fully_qualified(s.as_ptr()), 0);
+            let bad = CString::new("core/red is").unwrap();
+            assert_eq!(hab_ident_is_valid(bad.as_ptr()), 0);
+            assert_eq!(hab_ident_parse(bad.as_ptr(), &mut ident), HAB_ERROR);
+            assert!(take(hab_last_error()).contains("core/red is"));
+            assert_eq!(hab_ident_is_valid(ptr::null()), HAB_ERROR);
+        }
+    }
+
+    #[test]
+    fn artifact_verify() {
+        unsafe {
+            let hart = fixture("happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart");
+            let keys = fixture("");
+            let mut header = HabArtifactHeader {
+                format_version: ptr::null_mut(),
+                key_name: ptr::null_mut(),
+                hash_type: ptr::null_mut(),
+                signature: ptr::null_mut(),
+            };
+            assert_eq!(hab_artifact_header(hart.as_ptr(), &mut header), HAB_OK);
+            assert_eq!(CStr::from_ptr(header.key_name).to_str().unwrap(),
+                       "happyhumans-20160424223347");
+            hab_artifact_header_free(&mut header);
+
+            let mut signer = ptr::null_mut();
+            assert_eq!(hab_artifact_verify(hart.as_ptr(),
+                                           keys.as_ptr(),
+                                           &mut signer,
+                                           ptr::null_mut()),
+                       HAB_OK);
+            assert_eq!(take(signer), "happyhumans-20160424223347");
+
+            let unhappy = fixture("unhappyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart");
+            assert_eq!(hab_artifact_verify(unhappy.as_ptr(),
+                                           keys.as_ptr(),
+                                           ptr::null_mut(),
+                                           ptr::null_mut()),
+                       HAB_ERROR);
+        }
+    }
+}
@@ -23,6 +23,7 @@ use sodiumoxide::crypto::sign;
 
 use error::{Error, Result};
 use super::{HART_FORMAT_VERSION, SIG_HASH_TYPE, SigKeyPair};
+use super::hash::Hasher;
 use super::keys::parse_name_with_rev;
 
 /// Generate and sign a package
@@ -224,6 +225,107 @@ pub fn verify<P1: ?Sized, P2: ?Sized>(src: &P1, cache_key_path: &P2) -> Result<(
     }
 }
 
+/// The longest a header line may be before a stream is considered corrupt.
+const MAX_HEADER_LINE_LEN: usize = 4096;
+
+/// Verifies an artifact as it is written, so that a download can be verified without reading it
+/// back from disk. The header is parsed as it arrives and everything after it is hashed. Once the
+/// whole artifact has been written, `verify` checks the signature against the hash.
+pub struct StreamVerifier {
+    header: Vec<String>,
+    line: Vec<u8>,
+    hasher: Hasher,
+}
+
+impl StreamVerifier {
+    pub fn new() -> Self {
+        StreamVerifier {
+            header: Vec::new(),
+            line: Vec::new(),
+            hasher: Hasher::new(),
+        }
+    }
+
+    /// The name with revision of the key the artifact was signed with, once that much of the
+    /// header has been written.
+    pub fn signer(&self) -> Option<&str> {
+        self.header.get(1).map(|name_with_rev| name_with_rev.as_str())
+    }
+
+    /// Check the header and signature of everything written against the public keys in
+    /// `cache_key_path`, with the same checks as `verify`, returning the signer's name with
+    /// revision and the artifact's hash.
+    pub fn verify<P: AsRef<Path> + ?Sized>(self,
+                                            cache_key_path: &P)
+                                            -> Result<(String, String)> {
+        if self.header.len() < 5 {
+            return Err(Error::CryptoError("Corrupt payload, can't find end of header"
+                .to_string()));
+        }
+        if self.header[0] != HART_FORMAT_VERSION {
+            let msg = format!("Unsupported format version: {}", &self.header[0]);
+            return Err(Error::CryptoError(msg));
+        }
+        let pair = try!(SigKeyPair::get_pair_for(&self.header[1], cache_key_path));
+        if self.header[2] != SIG_HASH_TYPE {
+            let msg = format!("Unsupported signature type: {}", &self.header[2]);
+            return Err(Error::CryptoError(msg));
+        }
+        let signature = try!(base64::decode(&self.header[3])
+            .map_err(|e| Error::CryptoError(format!("Can't decode signature: {}", e))));
+        let expected_hash = match sign::verify(signature.as_slice(), try!(pair.public())) {
+            Ok(signed_data) => {
+                try!(String::from_utf8(signed_data).map_err(|_| {
+                    Error::CryptoError("Error parsing artifact signature".to_string())
+                }))
+            }
+            Err(_) => return Err(Error::CryptoError("Verification failed".to_string())),
+        };
+        let computed_hash = self.hasher.finish();
+        if computed_hash == expected_hash {
+            Ok((pair.name_with_rev(), expected_hash))
+        } else {
+            let msg = format!("Habitat artifact is invalid, \
+                              hashes don't match (expected: {}, computed: {})",
+                              expected_hash,
+                              computed_hash);
+            Err(Error::CryptoError(msg))
+        }
+    }
+}
+
+impl Write for StreamVerifier {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut rest = buf;
+        // The header is five lines, the last of them empty.
+        while self.header.len() < 5 && !rest.is_empty() {
+            match rest.iter().position(|b| *b == b'\n') {
+                Some(end) => {
+                    self.line.extend_from_slice(&rest[..end]);
+                    let line = String::from_utf8_lossy(&self.line).trim().to_string();
+                    self.header.push(line);
+                    self.line.clear();
+                    rest = &rest[end + 1..];
+                }
+                None => {
+                    self.line.extend_from_slice(rest);
+                    rest = &[];
+                }
+            }
+            if self.line.len() > MAX_HEADER_LINE_LEN {
+                return Err(io::Error::new(io::ErrorKind::InvalidData,
+                                          "Corrupt payload, header line is too long"));
+            }
+        }
+        self.hasher.update(rest);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 pub fn artifact_signer<P: AsRef<Path>>(src: &P) -> Result<String> {
     let f = try!(File::open(src));
     let mut reader = BufReader::new(f);
@@ -281,6 +383,28 @@ mod test {
         assert!(true);
     }
 
+    #[test]
+    fn stream_verifier_verifies_what_is_written() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let dst = cache.path().join("signed.dat");
+        sign(&fixture("signme.dat"), &dst, &pair).unwrap();
+
+        let mut data = Vec::new();
+        File::open(&dst).unwrap().read_to_end(&mut data).unwrap();
+        let mut verifier = StreamVerifier::new();
+        for chunk in data.chunks(13) {
+            verifier.write_all(chunk).unwrap();
+        }
+        assert_eq!(verifier.signer(), Some(pair.name_with_rev().as_str()));
+        assert_eq!(verifier.verify(cache.path()).unwrap(),
+                   verify(&dst, cache.path()).unwrap());
+
+        let mut verifier = StreamVerifier::new();
+        verifier.write_all(&data[..data.len() - 1]).unwrap();
+        assert!(verifier.verify(cache.path()).is_err());
+    }
+
     #[test]
     #[should_panic(expected = "Secret key is required but not present for")]
     fn sign_missing_private_key() {
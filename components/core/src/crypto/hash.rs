@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use std::fs::File;
-use std::io::{BufReader, Read};
+use std::io::{self, BufReader, Read, Write};
 use std::mem;
 use std::path::Path;
 use std::ptr;
@@ -90,6 +90,59 @@ pub fn hash_reader(reader: &mut BufReader<File>) -> Result<String> {
     Ok(out.to_hex())
 }
 
+/// Calculates the BLAKE2b hash of data as it is written, so a stream can be hashed without being
+/// read back from disk.
+pub struct Hasher {
+    state: Vec<u8>,
+}
+
+impl Hasher {
+    pub fn new() -> Self {
+        let mut state = vec![0u8; (unsafe { libsodium_sys::crypto_generichash_statebytes() })];
+        unsafe {
+            libsodium_sys::crypto_generichash_init(Self::state_ptr(&mut state),
+                                                   ptr::null_mut(),
+                                                   0,
+                                                   libsodium_sys::crypto_generichash_BYTES);
+        }
+        Hasher { state: state }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        unsafe {
+            libsodium_sys::crypto_generichash_update(Self::state_ptr(&mut self.state),
+                                                     data.as_ptr(),
+                                                     data.len() as u64);
+        }
+    }
+
+    /// Return the hash of everything written, as a hex string.
+    pub fn finish(mut self) -> String {
+        let mut out = [0u8; libsodium_sys::crypto_generichash_BYTES];
+        unsafe {
+            libsodium_sys::crypto_generichash_final(Self::state_ptr(&mut self.state),
+                                                    out.as_mut_ptr(),
+                                                    out.len());
+        }
+        out.to_hex()
+    }
+
+    fn state_ptr(state: &mut Vec<u8>) -> *mut libsodium_sys::crypto_generichash_state {
+        state.as_mut_ptr() as *mut libsodium_sys::crypto_generichash_state
+    }
+}
+
+impl Write for Hasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::env;
@@ -142,6 +195,17 @@ mod test {
         assert_eq!(computed, expected);
     }
 
+    #[test]
+    fn hasher_matches_hash_file() {
+        let mut data = Vec::new();
+        File::open(fixture("signme.dat")).unwrap().read_to_end(&mut data).unwrap();
+        let mut hasher = Hasher::new();
+        for chunk in data.chunks(7) {
+            hasher.write_all(chunk).unwrap();
+        }
+        assert_eq!(hasher.finish(), hash_file(&fixture("signme.dat")).unwrap());
+    }
+
     #[test]
     #[cfg(feature = "functional")]
     fn hash_file_large_binary() {
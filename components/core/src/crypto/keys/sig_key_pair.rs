@@ -17,6 +17,11 @@ use std::path::{Path, PathBuf};
 
 use base64;
 use hex::ToHex;
+use libsodium_sys;
+use sodiumoxide::crypto::box_;
+use sodiumoxide::crypto::box_::curve25519xsalsa20poly1305::PublicKey as BoxPublicKey;
+use sodiumoxide::crypto::box_::curve25519xsalsa20poly1305::SecretKey as BoxSecretKey;
+use sodiumoxide::crypto::box_::curve25519xsalsa20poly1305::Nonce;
 use sodiumoxide::crypto::sign;
 use sodiumoxide::crypto::sign::ed25519::SecretKey as SigSecretKey;
 use sodiumoxide::crypto::sign::ed25519::PublicKey as SigPublicKey;
@@ -25,8 +30,8 @@ use sodiumoxide::randombytes::randombytes;
 use error::{Error, Result};
 use super::{get_key_revisions, mk_key_filename, mk_revision_string, parse_name_with_rev,
             read_key_bytes, write_keypair_files, KeyPair, KeyType, PairType, TmpKeyfile};
-use super::super::{PUBLIC_KEY_SUFFIX, PUBLIC_SIG_KEY_VERSION, SECRET_SIG_KEY_SUFFIX,
-                   SECRET_SIG_KEY_VERSION, hash};
+use super::super::{ORIGIN_SECRET_FORMAT_VERSION, PUBLIC_KEY_SUFFIX, PUBLIC_SIG_KEY_VERSION,
+                   SECRET_SIG_KEY_SUFFIX, SECRET_SIG_KEY_VERSION, hash};
 
 pub type SigKeyPair = KeyPair<SigPublicKey, SigSecretKey>;
 
@@ -152,6 +157,123 @@ impl SigKeyPair {
         Ok(path)
     }
 
+    /// Signs `data` with the secret key, returning the signed message.
+    pub fn sign(&self, data: &[u8]) -> Result<Vec<u8>> {
+        Ok(sign::sign(data, try!(self.secret())))
+    }
+
+    /// Checks a message signed by `sign` against the public key, returning the data it signs.
+    pub fn verify(&self, signed: &[u8]) -> Result<Vec<u8>> {
+        sign::verify(signed, try!(self.public()))
+            .map_err(|_| Error::CryptoError("Verification failed".to_string()))
+    }
+
+    /// Encrypt a secret value so that only holders of this origin's secret key can read it.
+    ///
+    /// Origin keys are signing keys, so the public key is converted to its curve25519 equivalent
+    /// and the value is sealed to it with a throwaway box key. The origin key name, the throwaway
+    /// public key, and the nonce are embedded in the payload.
+    pub fn encrypt_secret(&self, data: &[u8]) -> Result<String> {
+        let receiver = try!(Self::box_public_key(try!(self.public())));
+        let (sender_pk, sender_sk) = box_::gen_keypair();
+        let nonce = box_::gen_nonce();
+        let ciphertext = box_::seal(data, &nonce, &receiver, &sender_sk);
+        Ok(format!("{}\n{}\n{}\n{}\n{}",
+                   ORIGIN_SECRET_FORMAT_VERSION,
+                   &self.name_with_rev(),
+                   base64::encode(&sender_pk[..]),
+                   base64::encode(&nonce[..]),
+                   base64::encode(&ciphertext)))
+    }
+
+    /// Decrypt a secret value produced by `encrypt_secret`. The origin secret key named in the
+    /// payload must be present in the key cache.
+    pub fn decrypt_secret<P: AsRef<Path> + ?Sized>(payload: &str,
+                                                   cache_key_path: &P)
+                                                   -> Result<Vec<u8>> {
+        let mut lines = payload.lines();
+        match lines.next() {
+            Some(val) if val == ORIGIN_SECRET_FORMAT_VERSION => (),
+            Some(val) => return Err(Error::CryptoError(format!("Unsupported version: {}", val))),
+            None => {
+                return Err(Error::CryptoError("Corrupt payload, can't read version".to_string()))
+            }
+        }
+        let receiver = match lines.next() {
+            Some(val) => try!(Self::get_pair_for(val, cache_key_path)),
+            None => {
+                return Err(Error::CryptoError("Corrupt payload, can't read origin key name"
+                    .to_string()));
+            }
+        };
+        let sender = match lines.next() {
+            Some(val) => {
+                let decoded = try!(base64::decode(val)
+                    .map_err(|e| Error::CryptoError(format!("Can't decode sender key: {}", e))));
+                match BoxPublicKey::from_slice(&decoded) {
+                    Some(key) => key,
+                    None => return Err(Error::CryptoError("Invalid size of sender key".to_string())),
+                }
+            }
+            None => {
+                return Err(Error::CryptoError("Corrupt payload, can't read sender key"
+                    .to_string()));
+            }
+        };
+        let nonce = match lines.next() {
+            Some(val) => {
+                let decoded = try!(base64::decode(val)
+                    .map_err(|e| Error::CryptoError(format!("Can't decode nonce: {}", e))));
+                match Nonce::from_slice(&decoded) {
+                    Some(nonce) => nonce,
+                    None => return Err(Error::CryptoError("Invalid size of nonce".to_string())),
+                }
+            }
+            None => {
+                return Err(Error::CryptoError("Corrupt payload, can't read nonce".to_string()));
+            }
+        };
+        let ciphertext = match lines.next() {
+            Some(val) => {
+                try!(base64::decode(val)
+                    .map_err(|e| Error::CryptoError(format!("Can't decode ciphertext: {}", e))))
+            }
+            None => {
+                return Err(Error::CryptoError("Corrupt payload, can't read ciphertext"
+                    .to_string()));
+            }
+        };
+        let secret = try!(Self::box_secret_key(try!(receiver.secret())));
+        box_::open(&ciphertext, &nonce, &sender, &secret).map_err(|_| {
+            Error::CryptoError("Origin secret key and nonce could not decrypt ciphertext"
+                .to_string())
+        })
+    }
+
+    fn box_public_key(key: &SigPublicKey) -> Result<BoxPublicKey> {
+        let mut out = [0u8; box_::PUBLICKEYBYTES];
+        let rc = unsafe {
+            libsodium_sys::crypto_sign_ed25519_pk_to_curve25519(&mut out, &key.0)
+        };
+        if rc != 0 {
+            return Err(Error::CryptoError("Can't convert origin public key for encryption"
+                .to_string()));
+        }
+        Ok(BoxPublicKey(out))
+    }
+
+    fn box_secret_key(key: &SigSecretKey) -> Result<BoxSecretKey> {
+        let mut out = [0u8; box_::SECRETKEYBYTES];
+        let rc = unsafe {
+            libsodium_sys::crypto_sign_ed25519_sk_to_curve25519(&mut out, &key.0)
+        };
+        if rc != 0 {
+            return Err(Error::CryptoError("Can't convert origin secret key for decryption"
+                .to_string()));
+        }
+        Ok(BoxSecretKey(out))
+    }
+
     /// Writes a sig key (public or secret) to the key cache from the contents of a string slice.
     ///
     /// The return is a `Result` of a `String` containing the key's name with revision.
@@ -464,6 +586,35 @@ mod test {
         assert!(cache.path().join(format!("{}.sig.key", pair.name_with_rev())).exists());
     }
 
+    #[test]
+    fn encrypt_and_decrypt_secret() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let payload = pair.encrypt_secret("magic".as_bytes()).unwrap();
+
+        let value = SigKeyPair::decrypt_secret(&payload, cache.path()).unwrap();
+        assert_eq!(value, "magic".as_bytes());
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let signed = pair.sign("magic".as_bytes()).unwrap();
+
+        assert_eq!(pair.verify(&signed).unwrap(), "magic".as_bytes());
+        let other = SigKeyPair::generate_pair_for_origin("dragon", cache.path()).unwrap();
+        assert!(other.verify(&signed).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported version")]
+    fn decrypt_secret_wrong_version() {
+        let cache = TempDir::new("key_cache").unwrap();
+
+        SigKeyPair::decrypt_secret("BOX-1\nunicorn-123\n", cache.path()).unwrap();
+    }
+
     #[test]
     fn get_pairs_for() {
         let cache = TempDir::new("key_cache").unwrap();
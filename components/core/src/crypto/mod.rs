@@ -257,6 +257,7 @@ static SECRET_KEY_PERMISSIONS: u32 = 0o400;
 
 pub static HART_FORMAT_VERSION: &'static str = "HART-1";
 pub static BOX_FORMAT_VERSION: &'static str = "BOX-1";
+pub static ORIGIN_SECRET_FORMAT_VERSION: &'static str = "ORIGIN-SECRET-1";
 
 pub const PUBLIC_SIG_KEY_VERSION: &'static str = "SIG-PUB-1";
 pub const SECRET_SIG_KEY_VERSION: &'static str = "SIG-SEC-1";
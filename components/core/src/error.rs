@@ -32,6 +32,10 @@ pub type Result<T> = result::Result<T, Error>;
 pub enum Error {
     /// Occurs when a `habitat_core::package::PackageArchive` is being read.
     ArchiveError(libarchive::error::ArchiveError),
+    /// Occurs when the artifact scanner rejects an artifact, or can't be run.
+    ArtifactRejected(String),
+    /// Occurs when the artifact scanner's configuration can't be parsed.
+    ArtifactScannerMalformed(String),
     /// An invalid path to a keyfile was given.
     BadKeyPath(String),
     /// Error reading raw contents of configuration file.
@@ -50,6 +54,8 @@ pub enum Error {
     ConfigInvalidTargetString(&'static str),
     /// Crypto library error
     CryptoError(String),
+    /// Occurs when packages in a dependency closure export conflicting environment variables.
+    EnvironmentConflict(String),
     /// Occurs when a file that should exist does not or could not be read.
     FileNotFound(String),
     /// Occurs when a package identifier string cannot be successfully parsed.
@@ -58,12 +64,26 @@ pub enum Error {
     InvalidPackageTarget(String),
     /// Occurs when validating a package target for an unsupported architecture.
     InvalidArchitecture(String),
+    /// Occurs when a service declares a capability this platform doesn't have.
+    InvalidCapability(String),
     /// Occurs when validating a package target for an unsupported platform.
     InvalidPlatform(String),
     /// Occurs when a service group string cannot be successfully parsed.
     InvalidServiceGroup(String),
+    /// Occurs when a version constraint, such as `>=2.27 <3`, cannot be successfully parsed.
+    InvalidVersionConstraint(String),
+    /// Occurs when a version can't be compared with another, as it isn't in a form we understand.
+    InvalidVersion(String),
+    /// Occurs when a package's seccomp filter isn't a valid compiled BPF program.
+    InvalidSeccompFilter(String),
     /// Occurs when making lower level IO calls.
     IO(io::Error),
+    /// Occurs when a package is distributed under a license the license policy doesn't allow.
+    LicenseNotAllowed(String),
+    /// Occurs when a license policy can't be parsed.
+    LicensePolicyMalformed(String),
+    /// Occurs when a lockfile can't be parsed.
+    LockFileMalformed(String),
     /// Occurs when a package metadata file cannot be opened, read, or parsed.
     MetaFileMalformed(package::MetaFile),
     /// Occurs when a particular package metadata file is not found.
@@ -74,12 +94,16 @@ pub enum Error {
     NoOutboundAddr,
     /// Occurs when a suitable installed package cannot be found.
     PackageNotFound(package::PackageIdent),
+    /// Occurs when a release of a package isn't pinned by a lockfile.
+    PackageNotLocked(String),
     /// When an error occurs parsing an integer.
     ParseIntError(num::ParseIntError),
     /// Occurs when setting ownership or permissions on a file or directory fails.
     PermissionFailed(String),
     /// Error parsing the contents of a plan file were incomplete or malformed.
     PlanMalformed,
+    /// Occurs when the mounts to make read-only to a service's process can't be worked out.
+    ReadOnlyViewFailed(String),
     /// When an error occurs parsing or compiling a regular expression.
     RegexParse(regex::Error),
     /// When an error occurs converting a `String` from a UTF-8 byte vector.
@@ -88,6 +112,8 @@ pub enum Error {
     TargetMatchError(String),
     /// Occurs when a `uname` libc call returns an error.
     UnameFailed(String),
+    /// Occurs when no releases of packages satisfy a set of requirements together.
+    UnsatisfiableRequirements(String),
     /// Occurs when a `waitpid` libc call returns an error.
     WaitpidFailed(String),
     /// Occurs when a `kill` libc call returns an error.
@@ -100,12 +126,18 @@ pub enum Error {
     TerminateProcessFailed(String),
     /// When an error occurs attempting to interpret a sequence of u8 as a string.
     Utf8Error(str::Utf8Error),
+    /// Occurs when a vulnerability database can't be parsed.
+    VulnerabilityDbMalformed(String),
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
             Error::ArchiveError(ref err) => format!("{}", err),
+            Error::ArtifactRejected(ref e) => format!("{}", e),
+            Error::ArtifactScannerMalformed(ref e) => {
+                format!("Malformed artifact scanner configuration: {}", e)
+            }
             Error::BadKeyPath(ref e) => {
                 format!("Invalid keypath: {}. Specify an absolute path to a file on disk.",
                         e)
@@ -134,6 +166,9 @@ impl fmt::Display for Error {
                 format!("Invalid target string value in config, field={}.", f)
             }
             Error::CryptoError(ref e) => format!("Crypto error: {}", e),
+            Error::EnvironmentConflict(ref e) => {
+                format!("Conflicting package environment variables: {}", e)
+            }
             Error::FileNotFound(ref e) => format!("File not found at: {}", e),
             Error::InvalidPackageIdent(ref e) => {
                 format!("Invalid package identifier: {:?}. A valid identifier is in the form \
@@ -146,13 +181,28 @@ impl fmt::Display for Error {
                         e)
             }
             Error::InvalidArchitecture(ref e) => format!("Invalid architecture: {}.", e),
+            Error::InvalidCapability(ref e) => format!("Unknown capability: {}.", e),
             Error::InvalidPlatform(ref e) => format!("Invalid platform: {}.", e),
             Error::InvalidServiceGroup(ref e) => {
                 format!("Invalid service group: {}. A valid service group string is in the form \
                          service.group (example: redis.production)",
                         e)
             }
+            Error::InvalidSeccompFilter(ref e) => format!("Invalid seccomp filter: {}", e),
+            Error::InvalidVersionConstraint(ref e) => {
+                format!("Invalid version constraint: {}. A valid constraint is a list of \
+                         comparisons (example: >=2.27 <3)",
+                        e)
+            }
+            Error::InvalidVersion(ref e) => {
+                format!("Invalid version: {}. A valid version is numbers separated by dots, \
+                         optionally followed by letters and a -extension (example: 1.0.2k)",
+                        e)
+            }
             Error::IO(ref err) => format!("{}", err),
+            Error::LicenseNotAllowed(ref e) => format!("{}", e),
+            Error::LicensePolicyMalformed(ref e) => format!("Malformed license policy: {}", e),
+            Error::LockFileMalformed(ref e) => format!("Malformed lockfile: {}", e),
             Error::MetaFileMalformed(ref e) => {
                 format!("MetaFile: {:?}, didn't contain a valid UTF-8 string", e)
             }
@@ -166,13 +216,18 @@ impl fmt::Display for Error {
                     format!("Cannot find a release of package: {}", pkg)
                 }
             }
+            Error::PackageNotLocked(ref e) => format!("{}", e),
             Error::ParseIntError(ref e) => format!("{}", e),
             Error::PlanMalformed => format!("Failed to read or parse contents of Plan file"),
             Error::PermissionFailed(ref e) => format!("{}", e),
+            Error::ReadOnlyViewFailed(ref e) => {
+                format!("Can't make the filesystem read-only to the process: {}", e)
+            }
             Error::RegexParse(ref e) => format!("{}", e),
             Error::StringFromUtf8Error(ref e) => format!("{}", e),
             Error::TargetMatchError(ref e) => format!("{}", e),
             Error::UnameFailed(ref e) => format!("{}", e),
+            Error::UnsatisfiableRequirements(ref e) => format!("{}", e),
             Error::WaitpidFailed(ref e) => format!("{}", e),
             Error::SignalFailed(ref e) => {
                 format!("Failed to send a signal to the child process: {}", e)
@@ -181,6 +236,9 @@ impl fmt::Display for Error {
             Error::GetHabChildFailed(ref e) => format!("{}", e),
             Error::TerminateProcessFailed(ref e) => format!("{}", e),
             Error::Utf8Error(ref e) => format!("{}", e),
+            Error::VulnerabilityDbMalformed(ref e) => {
+                format!("Malformed vulnerability database: {}", e)
+            }
         };
         write!(f, "{}", msg)
     }
@@ -190,6 +248,8 @@ impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
             Error::ArchiveError(ref err) => err.description(),
+            Error::ArtifactRejected(_) => "Artifact was rejected by the artifact scanner",
+            Error::ArtifactScannerMalformed(_) => "Artifact scanner configuration is malformed",
             Error::BadKeyPath(_) => "An absolute path to a file on disk is required",
             Error::ConfigFileIO(_) => "Unable to read the raw contents of a configuration file",
             Error::ConfigFileSyntax(_) => "Error parsing contents of configuration file",
@@ -209,6 +269,7 @@ impl error::Error for Error {
                 "Invalid target string value encountered while parsing a configuration file"
             }
             Error::CryptoError(_) => "Crypto error",
+            Error::EnvironmentConflict(_) => "Packages export conflicting environment variables",
             Error::FileNotFound(_) => "File not found",
             Error::InvalidPackageIdent(_) => {
                 "Package identifiers must be in origin/name format (example: acme/redis)"
@@ -217,29 +278,44 @@ impl error::Error for Error {
                 "Package targets must be in architecture-platform format (example: x86_64-linux)"
             }
             Error::InvalidArchitecture(_) => "Unsupported target architecture supplied.",
+            Error::InvalidCapability(_) => "Unknown capability for a service to keep",
             Error::InvalidPlatform(_) => "Unsupported target platform supplied.",
             Error::InvalidServiceGroup(_) => {
                 "Service group strings must be in service.group format (example: redis.production)"
             }
+            Error::InvalidSeccompFilter(_) => "Seccomp filter isn't a compiled BPF program",
+            Error::InvalidVersionConstraint(_) => {
+                "Version constraints must be a list of comparisons (example: >=2.27 <3)"
+            }
+            Error::InvalidVersion(_) => "Version can't be compared with another",
             Error::IO(ref err) => err.description(),
+            Error::LicenseNotAllowed(_) => "Package license isn't allowed by the license policy",
+            Error::LicensePolicyMalformed(_) => "License policy is malformed",
+            Error::LockFileMalformed(_) => "Lockfile is malformed",
             Error::MetaFileMalformed(_) => "MetaFile didn't contain a valid UTF-8 string",
             Error::MetaFileNotFound(_) => "Failed to read an archive's metafile",
             Error::MetaFileIO(_) => "MetaFile could not be read or written to",
             Error::NoOutboundAddr => "Failed to discover the outbound IP address",
             Error::PackageNotFound(_) => "Cannot find a package",
+            Error::PackageNotLocked(_) => "Package release isn't pinned by the lockfile",
             Error::ParseIntError(_) => "Failed to parse an integer from a string!",
             Error::PermissionFailed(_) => "Failed to set permissions",
             Error::PlanMalformed => "Failed to read or parse contents of Plan file",
+            Error::ReadOnlyViewFailed(_) => "Failed to make the filesystem read-only",
             Error::RegexParse(_) => "Failed to parse a regular expression",
             Error::StringFromUtf8Error(_) => "Failed to convert a string from a Vec<u8> as UTF-8",
             Error::TargetMatchError(_) => "System target does not match package target",
             Error::UnameFailed(_) => "uname failed",
+            Error::UnsatisfiableRequirements(_) => {
+                "No releases of packages satisfy the requirements together"
+            }
             Error::SignalFailed(_) => "Failed to send a signal to the child process",
             Error::WaitpidFailed(_) => "waitpid failed",
             Error::GetExitCodeProcessFailed(_) => "GetExitCodeProcess failed",
             Error::GetHabChildFailed(_) => "Failed to return a HabChild",
             Error::TerminateProcessFailed(_) => "Failed to call TerminateProcess",
             Error::Utf8Error(_) => "Failed to interpret a sequence of bytes as a string",
+            Error::VulnerabilityDbMalformed(_) => "Vulnerability database is malformed",
         }
     }
 }
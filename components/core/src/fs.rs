@@ -12,6 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! The layout of the filesystem Habitat manages.
+//!
+//! Everything Habitat installs lives under a `hab` directory at the filesystem root, `/` by
+//! default, or the path in the `FS_ROOT` environment variable. The functions taking an
+//! `fs_root_path` return paths under the given root instead, which lets tools work with a
+//! filesystem other than the running one, such as a container image being built.
+
 use std::env;
 use std::path::{Path, PathBuf};
 
@@ -22,6 +29,10 @@ use error::Result;
 use super::package::PackageInstall;
 
 
+/// The environment variable naming the filesystem root Habitat lives under, in place of the
+/// default. Lets tests, installs without root, and several isolated Habitat trees on one host
+/// each keep their own `hab` directory.
+pub const FS_ROOT_ENVVAR: &'static str = "FS_ROOT";
 /// The default filesystem root path
 #[cfg(not(target_os="windows"))]
 pub const FS_ROOT_PATH: &'static str = "/";
@@ -39,65 +50,80 @@ pub const CACHE_KEY_PATH: &'static str = "hab/cache/keys";
 pub const CACHE_SRC_PATH: &'static str = "hab/cache/src";
 /// The default path where SSL-related artifacts are placed
 pub const CACHE_SSL_PATH: &'static str = "hab/cache/ssl";
+/// The default path where the vulnerability database is kept
+pub const CACHE_VULNDB_PATH: &'static str = "hab/cache/vulndb";
 /// The root path containing all locally installed packages
 pub const PKG_PATH: &'static str = "hab/pkgs";
 /// The root path containing all runtime service directories and files
 const SVC_PATH: &'static str = "hab/svc";
+/// The root path of the Supervisor's own files
+const SUP_PATH: &'static str = "hab/sup";
 
 lazy_static! {
     static ref EUID: u32 = users::get_effective_uid();
 
     static ref MY_CACHE_ANALYTICS_PATH: PathBuf = {
         if *EUID == 0u32 {
-            PathBuf::from(CACHE_ANALYTICS_PATH)
+            layout_path(CACHE_ANALYTICS_PATH)
         } else {
             match env::home_dir() {
-                Some(home) => home.join(format!(".{}", CACHE_ANALYTICS_PATH)),
-                None => PathBuf::from(CACHE_ANALYTICS_PATH),
+                Some(home) => home.join(layout_path(&format!(".{}", CACHE_ANALYTICS_PATH))),
+                None => layout_path(CACHE_ANALYTICS_PATH),
             }
         }
     };
 
     static ref MY_CACHE_ARTIFACT_PATH: PathBuf = {
         if *EUID == 0u32 {
-            PathBuf::from(CACHE_ARTIFACT_PATH)
+            layout_path(CACHE_ARTIFACT_PATH)
         } else {
             match env::home_dir() {
-                Some(home) => home.join(format!(".{}", CACHE_ARTIFACT_PATH)),
-                None => PathBuf::from(CACHE_ARTIFACT_PATH),
+                Some(home) => home.join(layout_path(&format!(".{}", CACHE_ARTIFACT_PATH))),
+                None => layout_path(CACHE_ARTIFACT_PATH),
             }
         }
     };
 
     static ref MY_CACHE_KEY_PATH: PathBuf = {
         if *EUID == 0u32 {
-            PathBuf::from(CACHE_KEY_PATH)
+            layout_path(CACHE_KEY_PATH)
         } else {
             match env::home_dir() {
-                Some(home) => home.join(format!(".{}", CACHE_KEY_PATH)),
-                None => PathBuf::from(CACHE_KEY_PATH),
+                Some(home) => home.join(layout_path(&format!(".{}", CACHE_KEY_PATH))),
+                None => layout_path(CACHE_KEY_PATH),
             }
         }
     };
 
     static ref MY_CACHE_SRC_PATH: PathBuf = {
         if *EUID == 0u32 {
-            PathBuf::from(CACHE_SRC_PATH)
+            layout_path(CACHE_SRC_PATH)
         } else {
             match env::home_dir() {
-                Some(home) => home.join(format!(".{}", CACHE_SRC_PATH)),
-                None => PathBuf::from(CACHE_SRC_PATH),
+                Some(home) => home.join(layout_path(&format!(".{}", CACHE_SRC_PATH))),
+                None => layout_path(CACHE_SRC_PATH),
+            }
+        }
+    };
+
+    static ref MY_CACHE_VULNDB_PATH: PathBuf = {
+        if *EUID == 0u32 {
+            layout_path(CACHE_VULNDB_PATH)
+        } else {
+            match env::home_dir() {
+                Some(home) => home.join(layout_path(&format!(".{}", CACHE_VULNDB_PATH))),
+                None => layout_path(CACHE_VULNDB_PATH),
             }
         }
     };
 
     static ref MY_CACHE_SSL_PATH: PathBuf = {
         if *EUID == 0u32 {
-            PathBuf::from(CACHE_SSL_PATH)
+            layout_path(CACHE_SSL_PATH)
         } else {
             match env::home_dir() {
-                Some(home) => home.join(format!(".{}", CACHE_SSL_PATH)),
-                None => PathBuf::from(CACHE_SSL_PATH),
+                Some(home) => home.join(layout_path(&format!(".{}", CACHE_SSL_PATH))),
+                None => layout_path(CACHE_SSL_PATH),
             }
         }
     };
@@ -107,7 +133,7 @@ lazy_static! {
 pub fn cache_analytics_path(fs_root_path: Option<&Path>) -> PathBuf {
     match fs_root_path {
         Some(fs_root_path) => Path::new(fs_root_path).join(&*MY_CACHE_ANALYTICS_PATH),
-        None => Path::new(FS_ROOT_PATH).join(&*MY_CACHE_ANALYTICS_PATH),
+        None => self::fs_root_path().join(&*MY_CACHE_ANALYTICS_PATH),
     }
 }
 
@@ -115,7 +141,7 @@ pub fn cache_analytics_path(fs_root_path: Option<&Path>) -> PathBuf {
 pub fn cache_artifact_path(fs_root_path: Option<&Path>) -> PathBuf {
     match fs_root_path {
         Some(fs_root_path) => Path::new(fs_root_path).join(&*MY_CACHE_ARTIFACT_PATH),
-        None => Path::new(FS_ROOT_PATH).join(&*MY_CACHE_ARTIFACT_PATH),
+        None => self::fs_root_path().join(&*MY_CACHE_ARTIFACT_PATH),
     }
 }
 
@@ -123,7 +149,7 @@ pub fn cache_artifact_path(fs_root_path: Option<&Path>) -> PathBuf {
 pub fn cache_key_path(fs_root_path: Option<&Path>) -> PathBuf {
     match fs_root_path {
         Some(fs_root_path) => Path::new(fs_root_path).join(&*MY_CACHE_KEY_PATH),
-        None => Path::new(FS_ROOT_PATH).join(&*MY_CACHE_KEY_PATH),
+        None => self::fs_root_path().join(&*MY_CACHE_KEY_PATH),
     }
 }
 
@@ -131,7 +157,16 @@ pub fn cache_key_path(fs_root_path: Option<&Path>) -> PathBuf {
 pub fn cache_src_path(fs_root_path: Option<&Path>) -> PathBuf {
     match fs_root_path {
         Some(fs_root_path) => Path::new(fs_root_path).join(&*MY_CACHE_SRC_PATH),
-        None => Path::new(FS_ROOT_PATH).join(&*MY_CACHE_SRC_PATH),
+        None => self::fs_root_path().join(&*MY_CACHE_SRC_PATH),
+    }
+}
+
+/// Returns the path to the vulnerability database cache, optionally taking a custom filesystem
+/// root.
+pub fn cache_vulndb_path(fs_root_path: Option<&Path>) -> PathBuf {
+    match fs_root_path {
+        Some(fs_root_path) => Path::new(fs_root_path).join(&*MY_CACHE_VULNDB_PATH),
+        None => self::fs_root_path().join(&*MY_CACHE_VULNDB_PATH),
     }
 }
 
@@ -139,18 +174,56 @@ pub fn cache_src_path(fs_root_path: Option<&Path>) -> PathBuf {
 pub fn cache_ssl_path(fs_root_path: Option<&Path>) -> PathBuf {
     match fs_root_path {
         Some(fs_root_path) => Path::new(fs_root_path).join(&*MY_CACHE_SSL_PATH),
-        None => Path::new(FS_ROOT_PATH).join(&*MY_CACHE_SSL_PATH),
+        None => self::fs_root_path().join(&*MY_CACHE_SSL_PATH),
+    }
+}
+
+/// Returns the filesystem root Habitat lives under: the path named by `FS_ROOT_ENVVAR` if it is
+/// set, and otherwise `/`, or the system drive, such as `C:\`, on Windows.
+pub fn fs_root_path() -> PathBuf {
+    match henv::var(FS_ROOT_ENVVAR) {
+        Ok(ref root) if !root.is_empty() => PathBuf::from(root),
+        _ => default_fs_root_path(),
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn default_fs_root_path() -> PathBuf {
+    PathBuf::from(FS_ROOT_PATH)
+}
+
+#[cfg(target_os = "windows")]
+fn default_fs_root_path() -> PathBuf {
+    // The drive is looked up when we run, as `FS_ROOT_PATH` is the drive of the build host.
+    match henv::var("SYSTEMDRIVE") {
+        Ok(drive) => PathBuf::from(format!("{}\\", drive)),
+        Err(_) => PathBuf::from(FS_ROOT_PATH),
+    }
+}
+
+/// Returns one of the Habitat filesystem's paths, such as `PKG_PATH`, as a relative path with the
+/// platform's separators.
+pub fn layout_path(path: &str) -> PathBuf {
+    path.split('/').collect()
+}
+
+/// Returns the root path containing all installed packages, optionally taking a custom filesystem
+/// root.
+pub fn pkg_root_path(fs_root_path: Option<&Path>) -> PathBuf {
+    match fs_root_path {
+        Some(fs_root_path) => fs_root_path.join(layout_path(PKG_PATH)),
+        None => self::fs_root_path().join(layout_path(PKG_PATH)),
     }
 }
 
 /// Returns the root path containing all runtime service directories and files
 pub fn svc_root() -> PathBuf {
-    Path::new("/").join(SVC_PATH).to_path_buf()
+    fs_root_path().join(layout_path(SVC_PATH))
 }
 
 /// Returns the root path for a given service's configuration, files, and data.
 pub fn svc_path(service_name: &str) -> PathBuf {
-    Path::new("/").join(SVC_PATH).join(service_name)
+    svc_root().join(service_name)
 }
 
 /// Returns the path to a given service's configuration.
@@ -186,6 +259,11 @@ pub fn svc_var_path(service_name: &str) -> PathBuf {
     svc_path(service_name).join("var")
 }
 
+/// Returns the path of the Supervisor's private data, such as diagnostics it writes.
+pub fn sup_data_path() -> PathBuf {
+    fs_root_path().join(layout_path(SUP_PATH)).join("data")
+}
+
 /// Returns the absolute path for a given command, if it exists, by searching the `PATH`
 /// environment variable.
 ///
@@ -316,6 +394,25 @@ pub fn am_i_root() -> bool {
     *EUID == 0u32
 }
 
+#[cfg(test)]
+mod test_layout {
+    use std::path::Path;
+
+    use super::{layout_path, pkg_root_path, PKG_PATH};
+
+    #[test]
+    fn layout_path_uses_platform_separators() {
+        assert_eq!(layout_path(PKG_PATH), Path::new("hab").join("pkgs"));
+    }
+
+    #[test]
+    fn pkg_root_path_with_custom_root() {
+        let root = Path::new("tmp").join("root");
+        assert_eq!(pkg_root_path(Some(&root)),
+                   root.join("hab").join("pkgs"));
+    }
+}
+
 #[cfg(test)]
 mod test_find_command {
 
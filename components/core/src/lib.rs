@@ -12,6 +12,45 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! The types and logic Habitat is built on: package identifiers, artifacts (`.hart` files),
+//! signing keys, and the layout of the `/hab` directory.
+//!
+//! Besides Habitat's own components, this crate is meant to be used by other Rust tools which
+//! work with Habitat packages, so that they don't need to shell out to `hab`. For example, to
+//! check an artifact's signature and read what it contains:
+//!
+//! ```no_run
+//! use habitat_core::crypto::default_cache_key_path;
+//! use habitat_core::package::PackageArchive;
+//!
+//! let mut archive = PackageArchive::new("core-redis-3.2.4-20170514150022-x86_64-linux.hart");
+//! let (signer, checksum) = archive.verify(&default_cache_key_path(None)).unwrap();
+//! println!("{} was signed by {}; its checksum is {}",
+//!          archive.ident().unwrap(),
+//!          signer,
+//!          checksum);
+//! for dep in archive.deps().unwrap() {
+//!     println!("depends on {}", dep);
+//! }
+//! ```
+//!
+//! # Stability
+//!
+//! The modules documented here are the crate's public API, and change according to [Semantic
+//! Versioning](http://semver.org): a release which removes or changes anything in them bumps the
+//! minor version while the crate is below 1.0, and the major version after. They are:
+//!
+//! * `package`: package identifiers, version requirements, artifacts, installed packages, and the
+//!   artifact cache
+//! * `crypto`: signing and encryption keys, and signing and verifying artifacts
+//! * `fs`: where things live under the filesystem root, such as packages and the caches
+//! * `service`: service group names
+//! * `url`: the default Depot
+//! * `error`: the errors returned by all of the above
+//!
+//! Everything else, which is hidden from the documentation, supports Habitat's own components,
+//! and may change in any release.
+
 extern crate base64;
 extern crate errno;
 extern crate hex;
@@ -48,17 +87,24 @@ extern crate winapi;
 
 pub use self::error::{Error, Result};
 
+#[doc(hidden)]
 pub mod config;
+#[doc(hidden)]
 pub mod env;
 pub mod error;
 pub mod fs;
 pub mod package;
 pub mod service;
 pub mod url;
+#[doc(hidden)]
 pub mod util;
 pub mod crypto;
+#[doc(hidden)]
 pub mod os;
+#[doc(hidden)]
 pub mod event;
 
+#[doc(hidden)]
 pub use os::filesystem;
+#[doc(hidden)]
 pub use os::users;
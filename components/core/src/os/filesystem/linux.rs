@@ -16,6 +16,9 @@ use libc::{self, c_int, c_char, mode_t};
 
 pub use std::os::unix::fs::symlink;
 use std::ffi::CString;
+use std::fs::File;
+use std::io;
+use std::os::unix::io::AsRawFd;
 
 use error::{Result, Error};
 
@@ -61,3 +64,17 @@ pub fn chmod(path: &str, mode: u32) -> Result<c_int> {
         Ok(res)
     }
 }
+
+/// Takes an exclusive lock on an open file, waiting until no other open file holds one. The lock
+/// is released when the file is closed.
+pub fn lock_exclusive(file: &File) -> io::Result<()> {
+    loop {
+        if unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX) } == 0 {
+            return Ok(());
+        }
+        let err = io::Error::last_os_error();
+        if err.kind() != io::ErrorKind::Interrupted {
+            return Err(err);
+        }
+    }
+}
@@ -18,10 +18,10 @@ mod windows;
 
 
 #[cfg(windows)]
-pub use self::windows::{chown, chmod, symlink};
+pub use self::windows::{chown, chmod, lock_exclusive, symlink};
 
 #[cfg(not(windows))]
 mod linux;
 
 #[cfg(not(windows))]
-pub use self::linux::{chown, chmod, symlink};
+pub use self::linux::{chown, chmod, lock_exclusive, symlink};
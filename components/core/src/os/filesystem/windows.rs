@@ -13,10 +13,24 @@
 // limitations under the License.
 
 use libc::c_int;
+use std::fs::File;
+use std::mem;
+use std::os::windows::io::AsRawHandle;
 use std::path::Path;
 use std::io;
 
-use error::Result;
+use habitat_win_users::acl::{self, Ace};
+use habitat_win_users::sid::Sid;
+use kernel32;
+use winapi::{self, DWORD};
+
+use error::{Error, Result};
+
+/// The well known SIDs of the local system account and the local administrators group, who
+/// keep full control of anything we change the permissions of.
+const LOCAL_SYSTEM_SID: &'static str = "S-1-5-18";
+const ADMINISTRATORS_SID: &'static str = "S-1-5-32-544";
+const EVERYONE_SID: &'static str = "S-1-1-0";
 
 pub fn path_exists(path: &str) -> Result<c_int> {
     match Path::new(path).exists() {
@@ -25,14 +39,107 @@ pub fn path_exists(path: &str) -> Result<c_int> {
     }
 }
 
-pub fn chown(path: &str, uid: String, gid: String) -> Result<c_int> {
-    path_exists(path)
+/// Makes the account with the given SID the owner of a path. Files have no owning group on
+/// Windows, so `gid` is ignored.
+pub fn chown(path: &str, uid: String, _gid: String) -> Result<c_int> {
+    if try!(path_exists(path)) != 0 {
+        return Ok(1);
+    }
+    let owner = match Sid::from_string(&uid) {
+        Some(sid) => sid,
+        None => return Ok(1),
+    };
+    match acl::set_owner(path, &owner) {
+        Ok(()) => Ok(0),
+        Err(e) => {
+            Err(Error::PermissionFailed(format!("Can't change owner of {} to {}: {}",
+                                                path,
+                                                uid,
+                                                e)))
+        }
+    }
 }
 
+/// Replaces the access control list of a path with one granting the path's owner and everyone
+/// else the access described by the user and other bits of a Unix mode. The group bits are
+/// ignored, and the local system account and administrators always keep full control.
+/// Directories pass their entries on to everything created beneath them.
 pub fn chmod(path: &str, mode: u32) -> Result<c_int> {
-    path_exists(path)
+    if try!(path_exists(path)) != 0 {
+        return Ok(1);
+    }
+    let owner = match acl::owner(path) {
+        Ok(sid) => sid,
+        Err(e) => {
+            return Err(Error::PermissionFailed(format!("Can't read owner of {}: {}", path, e)))
+        }
+    };
+    let mut aces = vec![Ace {
+                            sid: Sid::from_string(LOCAL_SYSTEM_SID).unwrap(),
+                            access_mask: acl::FILE_ALL_ACCESS,
+                        },
+                        Ace {
+                            sid: Sid::from_string(ADMINISTRATORS_SID).unwrap(),
+                            access_mask: acl::FILE_ALL_ACCESS,
+                        }];
+    let owner_mask = access_mask(mode >> 6);
+    if owner_mask != 0 {
+        aces.push(Ace {
+            sid: owner,
+            access_mask: owner_mask,
+        });
+    }
+    let other_mask = access_mask(mode);
+    if other_mask != 0 {
+        aces.push(Ace {
+            sid: Sid::from_string(EVERYONE_SID).unwrap(),
+            access_mask: other_mask,
+        });
+    }
+    match acl::set_dacl(path, &aces, Path::new(path).is_dir()) {
+        Ok(()) => Ok(0),
+        Err(e) => {
+            Err(Error::PermissionFailed(format!("Can't set permissions on {} to {:o}: {}",
+                                                path,
+                                                mode,
+                                                e)))
+        }
+    }
+}
+
+/// Maps the lowest three bits of a Unix mode to the file rights they grant.
+fn access_mask(bits: u32) -> DWORD {
+    let mut mask = 0;
+    if bits & 0o4 != 0 {
+        mask |= acl::FILE_GENERIC_READ;
+    }
+    if bits & 0o2 != 0 {
+        mask |= acl::FILE_GENERIC_WRITE | acl::DELETE;
+    }
+    if bits & 0o1 != 0 {
+        mask |= acl::FILE_GENERIC_EXECUTE;
+    }
+    mask
 }
 
 pub fn symlink<P: AsRef<Path>, Q: AsRef<Path>>(src: P, dst: Q) -> io::Result<()> {
     unimplemented!();
 }
+
+/// Takes an exclusive lock on an open file, waiting until no other open file holds one. The lock
+/// is released when the file is closed.
+pub fn lock_exclusive(file: &File) -> io::Result<()> {
+    unsafe {
+        let mut overlapped: winapi::OVERLAPPED = mem::zeroed();
+        let ret = kernel32::LockFileEx(file.as_raw_handle(),
+                                       winapi::LOCKFILE_EXCLUSIVE_LOCK,
+                                       0,
+                                       !0,
+                                       !0,
+                                       &mut overlapped);
+        if ret == 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
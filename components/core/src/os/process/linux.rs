@@ -12,213 +12,526 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! Process supervision on Linux. Besides what POSIX provides, a service's process may be confined
+//! to a set of capabilities, filtered with a seccomp program, started in a network namespace made
+//! by `new_network_namespace`, and given a mount namespace in which the filesystem is read-only
+//! outside a few paths.
+
 use libc;
-use std::ffi::OsString;
-use std::path::PathBuf;
-use std::os::unix::process::CommandExt;
-use std::process::{self, Command};
-use time::{Duration, SteadyTime};
+use std::ffi::CString;
+use std::fs::File;
+use std::io::{self, Read};
+use std::mem;
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::thread;
 
 use error::{Error, Result};
 
-use super::{HabExitStatus, ExitStatusExt, ShutdownMethod};
+use super::{Confinement, ProcessPlatform};
+
+/// Capabilities in the order the kernel numbers them, named as in `capabilities(7)` without their
+/// `CAP_` prefix.
+const CAPABILITIES: [&'static str; 38] = ["chown",
+                                          "dac_override",
+                                          "dac_read_search",
+                                          "fowner",
+                                          "fsetid",
+                                          "kill",
+                                          "setgid",
+                                          "setuid",
+                                          "setpcap",
+                                          "linux_immutable",
+                                          "net_bind_service",
+                                          "net_broadcast",
+                                          "net_admin",
+                                          "net_raw",
+                                          "ipc_lock",
+                                          "ipc_owner",
+                                          "sys_module",
+                                          "sys_rawio",
+                                          "sys_chroot",
+                                          "sys_ptrace",
+                                          "sys_pacct",
+                                          "sys_admin",
+                                          "sys_boot",
+                                          "sys_nice",
+                                          "sys_resource",
+                                          "sys_time",
+                                          "sys_tty_config",
+                                          "mknod",
+                                          "lease",
+                                          "audit_write",
+                                          "audit_control",
+                                          "setfcap",
+                                          "mac_override",
+                                          "mac_admin",
+                                          "syslog",
+                                          "wake_alarm",
+                                          "block_suspend",
+                                          "audit_read"];
+
+const PR_SET_KEEPCAPS: libc::c_int = 8;
+const PR_SET_SECCOMP: libc::c_int = 22;
+const PR_CAPBSET_DROP: libc::c_int = 24;
+const PR_SET_NO_NEW_PRIVS: libc::c_int = 38;
+const PR_CAP_AMBIENT: libc::c_int = 47;
+const PR_CAP_AMBIENT_RAISE: libc::c_ulong = 2;
+const SECCOMP_MODE_FILTER: libc::c_ulong = 2;
+const LINUX_CAPABILITY_VERSION_3: u32 = 0x20080522;
+/// The most instructions the kernel accepts in a BPF program.
+const BPF_MAXINSNS: usize = 4096;
+const SIOCGIFFLAGS: libc::c_ulong = 0x8913;
+const SIOCSIFFLAGS: libc::c_ulong = 0x8914;
+const IFF_UP: libc::c_short = 0x1;
+/// Pseudo filesystems which are left as they are when the filesystem is made read-only.
+const KERNEL_MOUNTS: [&'static str; 3] = ["/dev", "/proc", "/sys"];
+
+// These structures are only read by the kernel.
+#[allow(dead_code)]
+#[repr(C)]
+struct CapUserHeader {
+    version: u32,
+    pid: libc::c_int,
+}
 
-pub fn become_command(command: PathBuf, args: Vec<OsString>) -> Result<()> {
-    become_exec_command(command, args)
+#[allow(dead_code)]
+#[repr(C)]
+struct CapUserData {
+    effective: u32,
+    permitted: u32,
+    inheritable: u32,
 }
 
-/// send a Unix signal to a pid
-fn send_signal(pid: u32, sig: libc::c_int) -> Result<()> {
-    unsafe {
-        match libc::kill(pid as i32, sig) {
-            0 => Ok(()),
-            e => return Err(Error::SignalFailed(e)),
-        }
-    }
+#[allow(dead_code)]
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct SockFilter {
+    code: u16,
+    jt: u8,
+    jf: u8,
+    k: u32,
 }
 
-/// Makes an `execvp(3)` system call to become a new program.
-///
-/// Note that if successful, this function will not return.
-///
-/// # Failures
-///
-/// * If the system call fails the error will be returned, otherwise this function does not return
-fn become_exec_command(command: PathBuf, args: Vec<OsString>) -> Result<()> {
-    debug!("Calling execvp(): ({:?}) {:?}", command.display(), &args);
-    let error_if_failed = Command::new(command).args(&args).exec();
-    // The only possible return for the above function is an `Error` so return it, meaning that we
-    // failed to exec to our target program
-    return Err(error_if_failed.into());
+/// `struct ifreq` as used to get and set an interface's flags.
+#[allow(dead_code)]
+#[repr(C)]
+struct IfReqFlags {
+    name: [libc::c_char; 16],
+    flags: libc::c_short,
+    padding: [u8; 22],
 }
 
-pub struct Child {
-    pid: u32,
-    last_status: Option<i32>,
+#[allow(dead_code)]
+#[repr(C)]
+struct SockFprog {
+    len: libc::c_ushort,
+    filter: *const SockFilter,
 }
 
-impl Child {
-    pub fn new(child: &mut process::Child) -> Result<Child> {
-        Ok(Child {
-            pid: child.id(),
-            last_status: None,
-        })
-    }
+pub struct Linux;
 
-    pub fn id(&self) -> u32 {
-        self.pid
-    }
+pub type Current = Linux;
 
-    pub fn status(&mut self) -> Result<HabExitStatus> {
-        match self.last_status {
-            Some(status) => Ok(HabExitStatus { status: Some(status as u32) }),
-            None => {
-                let mut exit_status: i32 = 0;
+pub struct PreparedConfinement {
+    /// A bit for each capability the process keeps, or None to leave them as they are.
+    capabilities: Option<u64>,
+    seccomp_filter: Option<Vec<SockFilter>>,
+    network_namespace: Option<libc::c_int>,
+    read_only: Option<ReadOnlyView>,
+}
 
-                match unsafe { libc::waitpid(self.pid as i32, &mut exit_status, libc::WNOHANG) } {
-                    0 => Ok(HabExitStatus { status: None }),
-                    -1 => {
-                        Err(Error::WaitpidFailed(format!("Error calling waitpid on pid: {}",
-                                                         self.pid)))
-                    }
-                    _ => {
-                        self.last_status = Some(exit_status);
-                        Ok(HabExitStatus { status: Some(exit_status as u32) })
-                    }
+/// The mounts to make read-only, and the paths to leave writable, ready to hand to `mount(2)`.
+pub struct ReadOnlyView {
+    writable: Vec<CString>,
+    /// Each mount point with the flags it must keep, as remounting replaces them.
+    mounts: Vec<(CString, libc::c_ulong)>,
+}
+
+// The filter's instructions are plain data; the raw pointer only appears once it is installed.
+unsafe impl Send for PreparedConfinement {}
+unsafe impl Sync for PreparedConfinement {}
+
+impl ProcessPlatform for Linux {
+    type PreparedConfinement = PreparedConfinement;
+
+    fn prepare_confinement(confinement: &Confinement) -> Result<Option<PreparedConfinement>> {
+        if confinement.is_empty() {
+            return Ok(None);
+        }
+        let capabilities = match confinement.capabilities {
+            // A process which isn't root has no capabilities to give up, and can't give them to
+            // its children.
+            Some(_) if unsafe { libc::getuid() } != 0 => {
+                warn!("Not restricting capabilities, as the Supervisor isn't running as root");
+                None
+            }
+            Some(ref names) => Some(try!(capability_mask(names))),
+            None => None,
+        };
+        let seccomp_filter = match confinement.seccomp_filter {
+            Some(ref program) => Some(try!(parse_filter(program))),
+            None => None,
+        };
+        let read_only = match confinement.writable_paths {
+            // Only root may create a mount namespace.
+            Some(_) if unsafe { libc::getuid() } != 0 => {
+                warn!("Not making the filesystem read-only, as the Supervisor isn't running as \
+                       root");
+                None
+            }
+            Some(ref writable) => Some(try!(read_only_view(writable))),
+            None => None,
+        };
+        Ok(Some(PreparedConfinement {
+            capabilities: capabilities,
+            seccomp_filter: seccomp_filter,
+            network_namespace: confinement.network_namespace,
+            read_only: read_only,
+        }))
+    }
+
+    fn confine_before_user_change(confinement: &PreparedConfinement) -> io::Result<()> {
+        if let Some(namespace) = confinement.network_namespace {
+            if unsafe { libc::setns(namespace, libc::CLONE_NEWNET) } != 0 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        if let Some(ref view) = confinement.read_only {
+            try!(make_read_only(view));
+        }
+        let keep = match confinement.capabilities {
+            Some(keep) => keep,
+            None => return Ok(()),
+        };
+        // A capability dropped from the bounding set can't be regained, not even by executing a
+        // setuid program.
+        for cap in 0..64 {
+            if keep & (1 << cap) != 0 {
+                continue;
+            }
+            if unsafe { libc::prctl(PR_CAPBSET_DROP, cap as libc::c_ulong, 0, 0, 0) } != 0 {
+                let err = io::Error::last_os_error();
+                // The kernel has no capabilities numbered this high.
+                if err.raw_os_error() == Some(libc::EINVAL) {
+                    break;
                 }
+                return Err(err);
             }
         }
+        // Otherwise changing to a user other than root clears the permitted capabilities, and the
+        // kept ones couldn't be raised again.
+        try!(prctl(PR_SET_KEEPCAPS, 1));
+        Ok(())
     }
 
-    pub fn kill(&mut self) -> Result<ShutdownMethod> {
-        try!(send_signal(self.pid, libc::SIGTERM));
-
-        let stop_time = SteadyTime::now() + Duration::seconds(8);
-        loop {
-            match self.status() {
-                Ok(status) => {
-                    if !status.no_status() {
+    fn confine_after_user_change(confinement: &PreparedConfinement) -> io::Result<()> {
+        if let Some(keep) = confinement.capabilities {
+            let header = CapUserHeader {
+                version: LINUX_CAPABILITY_VERSION_3,
+                pid: 0,
+            };
+            let data = [CapUserData {
+                            effective: keep as u32,
+                            permitted: keep as u32,
+                            inheritable: keep as u32,
+                        },
+                        CapUserData {
+                            effective: (keep >> 32) as u32,
+                            permitted: (keep >> 32) as u32,
+                            inheritable: (keep >> 32) as u32,
+                        }];
+            if unsafe { libc::syscall(libc::SYS_capset, &header, data.as_ptr()) } != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            // Ambient capabilities are what survive the exec of a program without file
+            // capabilities as a user other than root. Kernels before 4.3 don't have them.
+            for cap in 0..64 {
+                if keep & (1 << cap) == 0 {
+                    continue;
+                }
+                if unsafe {
+                    libc::prctl(PR_CAP_AMBIENT, PR_CAP_AMBIENT_RAISE, cap as libc::c_ulong, 0, 0)
+                } != 0 {
+                    let err = io::Error::last_os_error();
+                    if err.raw_os_error() == Some(libc::EINVAL) {
                         break;
                     }
+                    return Err(err);
                 }
-                _ => {}
             }
-
-            if SteadyTime::now() > stop_time {
-                try!(send_signal(self.pid, libc::SIGKILL));
-                return Ok(ShutdownMethod::Killed);
+        }
+        if let Some(ref filter) = confinement.seccomp_filter {
+            // Required to install a filter without CAP_SYS_ADMIN, and keeps the process from
+            // gaining privileges the filter wasn't written for.
+            try!(prctl(PR_SET_NO_NEW_PRIVS, 1));
+            let program = SockFprog {
+                len: filter.len() as libc::c_ushort,
+                filter: filter.as_ptr(),
+            };
+            if unsafe {
+                libc::prctl(PR_SET_SECCOMP,
+                            SECCOMP_MODE_FILTER,
+                            &program as *const SockFprog as libc::c_ulong,
+                            0,
+                            0)
+            } != 0 {
+                return Err(io::Error::last_os_error());
             }
         }
-        Ok(ShutdownMethod::GracefulTermination)
+        Ok(())
     }
 }
 
-impl ExitStatusExt for HabExitStatus {
-    fn code(&self) -> Option<u32> {
-        unsafe {
-            match self.status {
-                None => None,
-                Some(status) if libc::WIFEXITED(status as libc::c_int) => {
-                    Some(libc::WEXITSTATUS(status as libc::c_int) as u32)
-                }
-                _ => None,
+/// Creates a network namespace with nothing but a loopback interface, which is brought up, and
+/// returns a file open on it. The namespace lasts for as long as the file is open or a process is
+/// in it; processes are started in it by passing the file's descriptor as
+/// `Confinement::network_namespace`.
+pub fn new_network_namespace() -> Result<File> {
+    // Unsharing moves the calling thread into the new namespace, so it's done on a thread which
+    // ends once it has opened the namespace.
+    let handle = try!(thread::Builder::new()
+        .name(String::from("netns"))
+        .spawn(|| -> io::Result<File> {
+            if unsafe { libc::unshare(libc::CLONE_NEWNET) } != 0 {
+                return Err(io::Error::last_os_error());
             }
+            try!(loopback_up());
+            let tid = unsafe { libc::syscall(libc::SYS_gettid) };
+            File::open(format!("/proc/self/task/{}/ns/net", tid))
+        }));
+    match handle.join() {
+        Ok(namespace) => Ok(try!(namespace)),
+        Err(_) => {
+            Err(Error::from(io::Error::new(io::ErrorKind::Other,
+                                           "creating a network namespace panicked")))
         }
     }
+}
 
-    fn signal(&self) -> Option<u32> {
-        unsafe {
-            match self.status {
-                None => None,
-                Some(status) if !libc::WIFEXITED(status as libc::c_int) => {
-                    Some(libc::WTERMSIG(status as libc::c_int) as u32)
-                }
-                _ => None,
-            }
-        }
+fn prctl(option: libc::c_int, arg: libc::c_ulong) -> io::Result<()> {
+    match unsafe { libc::prctl(option, arg, 0, 0, 0) } {
+        0 => Ok(()),
+        _ => Err(io::Error::last_os_error()),
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use libc;
-    use std::process::Command;
-    use super::super::*;
-
-    #[test]
-    fn running_process_returns_no_exit_status() {
-        let mut cmd = Command::new("/bin/bash");
-        cmd.arg("-c").arg("while : ; do /bin/sleep 1; done");
-        let mut child = cmd.spawn().unwrap();
+/// Brings up the loopback interface, which starts out down in a new network namespace.
+fn loopback_up() -> io::Result<()> {
+    let sock = unsafe { libc::socket(libc::AF_INET, libc::SOCK_DGRAM, 0) };
+    if sock < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let mut req = IfReqFlags {
+        name: [0; 16],
+        flags: 0,
+        padding: [0; 22],
+    };
+    for (dst, src) in req.name.iter_mut().zip(b"lo".iter()) {
+        *dst = *src as libc::c_char;
+    }
+    let result = unsafe {
+        if libc::ioctl(sock, SIOCGIFFLAGS, &mut req) != 0 {
+            -1
+        } else {
+            req.flags |= IFF_UP;
+            libc::ioctl(sock, SIOCSIFFLAGS, &req)
+        }
+    };
+    let err = io::Error::last_os_error();
+    unsafe { libc::close(sock) };
+    match result {
+        0 => Ok(()),
+        _ => Err(err),
+    }
+}
 
-        let mut hab_child = HabChild::from(&mut child).unwrap();
+/// Gives the process a mount namespace of its own, in which every mount is read-only apart from
+/// the pseudo filesystems and the writable paths.
+fn make_read_only(view: &ReadOnlyView) -> io::Result<()> {
+    if unsafe { libc::unshare(libc::CLONE_NEWNS) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // Otherwise the changes would propagate back to the host's mounts.
+    try!(mount(None, b"/\0", libc::MS_REC | libc::MS_PRIVATE));
+    // A writable path is bound over itself, so it's a mount of its own which the read-only
+    // mount above it doesn't reach.
+    for path in view.writable.iter() {
+        try!(mount(Some(path.as_bytes_with_nul()),
+                   path.as_bytes_with_nul(),
+                   libc::MS_BIND | libc::MS_REC));
+    }
+    for &(ref point, flags) in view.mounts.iter() {
+        try!(mount(None,
+                   point.as_bytes_with_nul(),
+                   libc::MS_BIND | libc::MS_REMOUNT | libc::MS_RDONLY | flags));
+    }
+    Ok(())
+}
 
-        assert!(hab_child.status().unwrap().no_status())
+fn mount(source: Option<&[u8]>, target: &[u8], flags: libc::c_ulong) -> io::Result<()> {
+    let source = source.map_or(ptr::null(), |s| s.as_ptr() as *const libc::c_char);
+    match unsafe {
+        libc::mount(source,
+                    target.as_ptr() as *const libc::c_char,
+                    ptr::null(),
+                    flags,
+                    ptr::null())
+    } {
+        0 => Ok(()),
+        _ => Err(io::Error::last_os_error()),
     }
+}
 
-    #[test]
-    fn successfully_run_process_exits_zero() {
-        let mut cmd = Command::new("/bin/bash");
-        cmd.arg("-c").arg("a='b'");
-        let mut child = cmd.spawn().unwrap();
+fn read_only_view(writable: &[PathBuf]) -> Result<ReadOnlyView> {
+    let mut table = String::new();
+    try!(File::open("/proc/self/mounts")
+        .and_then(|mut f| f.read_to_string(&mut table))
+        .map_err(|e| Error::ReadOnlyViewFailed(e.to_string())));
+    let mut view = ReadOnlyView {
+        writable: Vec::new(),
+        mounts: Vec::new(),
+    };
+    for path in writable {
+        view.writable.push(try!(c_path(path)));
+    }
+    for (point, flags) in mounts_to_protect(&table, writable) {
+        view.mounts.push((try!(c_path(&point)), flags));
+    }
+    Ok(view)
+}
 
-        let mut hab_child = HabChild::from(&mut child).unwrap();
-        let mut exit = hab_child.status().unwrap();
+fn c_path(path: &Path) -> Result<CString> {
+    CString::new(path.as_os_str().as_bytes())
+        .map_err(|_| Error::ReadOnlyViewFailed(format!("{} is not a valid path", path.display())))
+}
 
-        while exit.no_status() {
-            exit = hab_child.status().unwrap();
+/// Returns the mount points listed in a mount table, in the format of `/proc/self/mounts`, which
+/// must be made read-only, along with the flags each must keep. Mounts of pseudo filesystems, and
+/// mounts within the writable paths, are left alone.
+fn mounts_to_protect(table: &str, writable: &[PathBuf]) -> Vec<(PathBuf, libc::c_ulong)> {
+    let mut mounts = Vec::new();
+    for line in table.lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 4 {
+            continue;
         }
-
-        assert_eq!(exit.code(), Some(0))
+        let point = PathBuf::from(unescape_mount_path(fields[1]));
+        if KERNEL_MOUNTS.iter().any(|m| point.starts_with(m)) ||
+           writable.iter().any(|w| point.starts_with(w)) {
+            continue;
+        }
+        let mut flags = 0;
+        for option in fields[3].split(',') {
+            flags |= match option {
+                "nosuid" => libc::MS_NOSUID,
+                "nodev" => libc::MS_NODEV,
+                "noexec" => libc::MS_NOEXEC,
+                _ => 0,
+            };
+        }
+        mounts.retain(|&(ref p, _)| p != &point);
+        mounts.push((point, flags));
     }
+    mounts
+}
 
-    #[test]
-    fn terminated_process_returns_sigterm() {
-        let mut cmd = Command::new("/bin/bash");
-        cmd.arg("-c").arg("while : ; do /bin/sleep 1; done");
-        let mut child = cmd.spawn().unwrap();
-
-        let mut hab_child = HabChild::from(&mut child).unwrap();
-        let _ = hab_child.kill();
+/// Mount tables escape spaces, tabs, newlines and backslashes in paths as octal.
+fn unescape_mount_path(path: &str) -> String {
+    let mut unescaped = String::with_capacity(path.len());
+    let mut chars = path.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        let digits: String = chars.clone().take(3).collect();
+        match u8::from_str_radix(&digits, 8) {
+            Ok(byte) if digits.len() == 3 => {
+                unescaped.push(byte as char);
+                chars.nth(2);
+            }
+            _ => unescaped.push(c),
+        }
+    }
+    unescaped
+}
 
-        let mut exit = hab_child.status().unwrap();
-        while exit.no_status() {
-            exit = hab_child.status().unwrap();
+/// Returns a bit for each of the named capabilities, which may be given with or without their
+/// `CAP_` prefix, in either case.
+fn capability_mask(names: &[String]) -> Result<u64> {
+    let mut mask = 0;
+    for name in names {
+        let lower = name.to_lowercase();
+        let bare = lower.trim_left_matches("cap_");
+        match CAPABILITIES.iter().position(|c| *c == bare) {
+            Some(cap) => mask |= 1 << cap,
+            None => return Err(Error::InvalidCapability(name.clone())),
         }
+    }
+    Ok(mask)
+}
 
-        assert_eq!(exit.signal(), Some(libc::SIGTERM as u32))
+/// Reads a compiled BPF program, which is an array of `struct sock_filter` in the host's byte
+/// order.
+fn parse_filter(program: &[u8]) -> Result<Vec<SockFilter>> {
+    let size = mem::size_of::<SockFilter>();
+    if program.is_empty() || program.len() % size != 0 {
+        return Err(Error::InvalidSeccompFilter(format!("{} bytes is not a whole number of \
+                                                        instructions",
+                                                       program.len())));
     }
+    let len = program.len() / size;
+    if len > BPF_MAXINSNS {
+        return Err(Error::InvalidSeccompFilter(format!("{} instructions is more than the \
+                                                        kernel's limit of {}",
+                                                       len,
+                                                       BPF_MAXINSNS)));
+    }
+    let mut filter: Vec<SockFilter> = Vec::with_capacity(len);
+    unsafe {
+        ptr::copy_nonoverlapping(program.as_ptr(), filter.as_mut_ptr() as *mut u8, program.len());
+        filter.set_len(len);
+    }
+    Ok(filter)
+}
 
-    #[test]
-    fn calling_wait_multiple_times_after_exit_returns_same_status() {
-        let mut cmd = Command::new("/bin/bash");
-        cmd.arg("-c").arg("exit 5");
-        let mut child = cmd.spawn().unwrap();
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
 
-        let mut hab_child = HabChild::from(&mut child).unwrap();
-        let mut exit = hab_child.status().unwrap();
+    use libc;
 
-        while exit.no_status() {
-            exit = hab_child.status().unwrap();
-        }
-        let next_exit = hab_child.status().unwrap();
+    use super::{capability_mask, mounts_to_protect, parse_filter};
 
-        assert_eq!(next_exit.code(), exit.code())
+    #[test]
+    fn capability_names() {
+        let names = vec!["net_bind_service".to_string(), "CAP_SYS_NICE".to_string()];
+        assert_eq!(capability_mask(&names).unwrap(), 1 << 10 | 1 << 23);
+        assert!(capability_mask(&["net_bind_anything".to_string()]).is_err());
     }
 
     #[test]
-    fn process_that_exits_with_specific_code_has_same_exit_code() {
-        let mut cmd = Command::new("/bin/bash");
-        cmd.arg("-c").arg("exit 5");
-        let mut child = cmd.spawn().unwrap();
-
-        let mut hab_child = HabChild::from(&mut child).unwrap();
-        let mut exit = hab_child.status().unwrap();
-
-        while exit.no_status() {
-            exit = hab_child.status().unwrap();
-        }
+    fn filter_must_be_whole_instructions() {
+        // A single `ret ALLOW` instruction.
+        let allow = [0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x7f];
+        assert_eq!(parse_filter(&allow).unwrap().len(), 1);
+        assert!(parse_filter(&allow[..5]).is_err());
+        assert!(parse_filter(&[]).is_err());
+    }
 
-        assert_eq!(exit.code(), Some(5))
+    #[test]
+    fn mounts_to_protect_skips_kernel_and_writable_mounts() {
+        let table = "/dev/sda1 / ext4 rw,relatime 0 0\n\
+                     proc /proc proc rw,nosuid,nodev,noexec 0 0\n\
+                     tmpfs /tmp tmpfs rw,nosuid,nodev 0 0\n\
+                     /dev/sdb1 /hab/svc/redis/data ext4 rw 0 0\n\
+                     /dev/sdc1 /mnt/my\\040disk ext4 rw,noexec 0 0\n";
+        let writable = vec![PathBuf::from("/hab/svc/redis")];
+        assert_eq!(mounts_to_protect(table, &writable),
+                   vec![(PathBuf::from("/"), 0),
+                        (PathBuf::from("/tmp"), libc::MS_NOSUID | libc::MS_NODEV),
+                        (PathBuf::from("/mnt/my disk"), libc::MS_NOEXEC)]);
     }
 }
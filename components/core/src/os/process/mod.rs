@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::fmt;
+use std::path::PathBuf;
 use std::process::Child;
 
 use error::Result;
@@ -22,11 +23,107 @@ use error::Result;
 #[path = "windows.rs"]
 mod imp;
 
-#[cfg(not(windows))]
-#[path = "linux.rs"]
+#[cfg(unix)]
+#[path = "unix.rs"]
 mod imp;
 
+#[cfg(target_os = "linux")]
+#[path = "linux.rs"]
+mod platform;
+
 pub use self::imp::become_command;
+#[cfg(unix)]
+pub use self::imp::{ProcessPlatform, run_as, set_limits, signal, signal_from_number,
+                    signal_number};
+#[cfg(target_os = "linux")]
+pub use self::platform::{Current, new_network_namespace};
+#[cfg(all(unix, not(target_os = "linux")))]
+pub use self::imp::Posix as Current;
+
+/// The signals the Supervisor traps or sends to its services. Each platform numbers them
+/// differently, so use `signal_number` rather than casting one to an integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal {
+    /// terminate process - terminal line hangup
+    SIGHUP,
+    /// terminate process - interrupt program
+    SIGINT,
+    /// create core image - quit program
+    SIGQUIT,
+    /// Kill a process
+    SIGKILL,
+    /// terminate process - real-time timer expired
+    SIGALRM,
+    /// terminate process - software termination signal
+    SIGTERM,
+    /// terminate process - User defined signal 1
+    SIGUSR1,
+    /// terminate process - User defined signal 2
+    SIGUSR2,
+}
+
+impl Signal {
+    pub fn all() -> &'static [Signal] {
+        static ALL: [Signal; 8] = [Signal::SIGHUP,
+                                   Signal::SIGINT,
+                                   Signal::SIGQUIT,
+                                   Signal::SIGKILL,
+                                   Signal::SIGALRM,
+                                   Signal::SIGTERM,
+                                   Signal::SIGUSR1,
+                                   Signal::SIGUSR2];
+        &ALL
+    }
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Hardening applied to a service's process as it starts, on platforms which support it. Where
+/// they don't, the process is started unconfined.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Confinement {
+    /// The only capabilities the process keeps, such as `net_bind_service`, or None to leave them
+    /// as they are.
+    pub capabilities: Option<Vec<String>>,
+    /// A compiled seccomp BPF program the process is filtered with.
+    pub seccomp_filter: Option<Vec<u8>>,
+    /// A file descriptor open on the network namespace the process joins, such as one made by
+    /// `new_network_namespace`, or None to share the Supervisor's. The descriptor must stay open
+    /// until the process has started.
+    pub network_namespace: Option<i32>,
+    /// When set, the filesystem is read-only to the process except for these paths.
+    pub writable_paths: Option<Vec<PathBuf>>,
+}
+
+impl Confinement {
+    pub fn is_empty(&self) -> bool {
+        self.capabilities.is_none() && self.seccomp_filter.is_none() &&
+        self.network_namespace.is_none() && self.writable_paths.is_none()
+    }
+}
+
+/// The resource limits a service's process may be started with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    /// The number of files the process may have open.
+    OpenFiles,
+    /// The number of processes its user may run.
+    Processes,
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            Limit::OpenFiles => "nofile",
+            Limit::Processes => "nproc",
+        };
+        write!(f, "{}", name)
+    }
+}
 
 pub enum ShutdownMethod {
     AlreadyExited,
@@ -0,0 +1,502 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use libc;
+use std::ffi::{CString, OsString};
+use std::io;
+use std::path::PathBuf;
+use std::os::unix::process::CommandExt;
+use std::process::{self, Command};
+use time::{Duration, SteadyTime};
+
+use error::{Error, Result};
+
+use super::{Confinement, Current, HabExitStatus, ExitStatusExt, Limit, ShutdownMethod, Signal};
+
+/// The parts of supervising a process which differ between Unix platforms. What is plain POSIX is
+/// provided here, so a platform only implements what it does differently. The implementation in
+/// use is re-exported as `Current`.
+pub trait ProcessPlatform: Sized + 'static {
+    /// A `Confinement` checked and converted into the form this platform applies it in, so that
+    /// nothing is allocated or validated between `fork` and `exec`.
+    type PreparedConfinement: Send + Sync + 'static;
+
+    /// Returns the `setrlimit(2)` resource for `limit`, or None if this platform can't limit it.
+    fn rlimit_resource(limit: Limit) -> Option<libc::c_int> {
+        match limit {
+            Limit::OpenFiles => Some(libc::RLIMIT_NOFILE),
+            Limit::Processes => rlimit_nproc(),
+        }
+    }
+
+    /// Checks `confinement` and prepares it for the child process. Platforms which can't confine a
+    /// process return None.
+    ///
+    /// # Failures
+    ///
+    /// * The confinement names something this platform doesn't have
+    #[allow(unused_variables)]
+    fn prepare_confinement(confinement: &Confinement)
+                           -> Result<Option<Self::PreparedConfinement>> {
+        Ok(None)
+    }
+
+    /// Applies the parts of a confinement which need privileges the process is about to give up.
+    /// Called in the child before it changes user.
+    #[allow(unused_variables)]
+    fn confine_before_user_change(confinement: &Self::PreparedConfinement) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Applies the rest of a confinement. Called in the child after it changes user, as the last
+    /// thing before `exec`.
+    #[allow(unused_variables)]
+    fn confine_after_user_change(confinement: &Self::PreparedConfinement) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Returns this platform's number for `signal`.
+    fn signal_number(signal: Signal) -> libc::c_int {
+        match signal {
+            Signal::SIGHUP => libc::SIGHUP,
+            Signal::SIGINT => libc::SIGINT,
+            Signal::SIGQUIT => libc::SIGQUIT,
+            Signal::SIGKILL => libc::SIGKILL,
+            Signal::SIGALRM => libc::SIGALRM,
+            Signal::SIGTERM => libc::SIGTERM,
+            Signal::SIGUSR1 => libc::SIGUSR1,
+            Signal::SIGUSR2 => libc::SIGUSR2,
+        }
+    }
+
+    /// Prepares `cmd` so that the process it starts runs as the given user and group along with
+    /// the user's supplementary groups. The groups are looked up here, before `fork`, as reading
+    /// the group database isn't async-signal-safe; the child only sets them.
+    ///
+    /// The process is also confined as `confinement` asks, if this platform is able to.
+    fn run_as(cmd: &mut Command,
+              user: &str,
+              uid: u32,
+              gid: u32,
+              confinement: &Confinement)
+              -> Result<()> {
+        let prepared = try!(Self::prepare_confinement(confinement));
+        if prepared.is_none() && !confinement.is_empty() {
+            warn!("Starting {}'s process unconfined, as this platform can't confine it", user);
+        }
+        // Only root may change its groups; otherwise we can only be running as ourselves.
+        let groups = if unsafe { libc::getuid() } == 0 {
+            Some(try!(supplementary_groups(user, gid as libc::gid_t)))
+        } else {
+            None
+        };
+        cmd.before_exec(move || {
+            if let Some(ref groups) = groups {
+                let set = unsafe { setgroups(groups.len() as GroupCount, groups.as_ptr()) };
+                if set != 0 {
+                    return Err(io::Error::last_os_error());
+                }
+            }
+            if let Some(ref confinement) = prepared {
+                try!(Self::confine_before_user_change(confinement));
+            }
+            if unsafe { libc::setgid(gid as libc::gid_t) } != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            if unsafe { libc::setuid(uid as libc::uid_t) } != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            if let Some(ref confinement) = prepared {
+                try!(Self::confine_after_user_change(confinement));
+            }
+            Ok(())
+        });
+        Ok(())
+    }
+}
+
+/// The Unix platforms without anything of their own to add: FreeBSD, macOS and illumos. Signal
+/// numbers differ between them (`SIGUSR1` is 16 on illumos, 30 on the BSDs), which the provided
+/// `signal_number` takes care of through `libc`.
+#[cfg(not(target_os = "linux"))]
+pub struct Posix;
+
+#[cfg(not(target_os = "linux"))]
+impl ProcessPlatform for Posix {
+    type PreparedConfinement = ();
+}
+
+#[cfg(target_os = "linux")]
+type GroupCount = libc::size_t;
+#[cfg(not(target_os = "linux"))]
+type GroupCount = libc::c_int;
+
+// macOS passes groups to `getgrouplist(3)` as `int`s, where everyone else uses `gid_t`; the two
+// are the same size.
+#[cfg(target_os = "macos")]
+type GroupListEntry = libc::c_int;
+#[cfg(not(target_os = "macos"))]
+type GroupListEntry = libc::gid_t;
+
+extern "C" {
+    fn getgrouplist(user: *const libc::c_char,
+                    group: GroupListEntry,
+                    groups: *mut GroupListEntry,
+                    ngroups: *mut libc::c_int)
+                    -> libc::c_int;
+    fn setgroups(ngroups: GroupCount, groups: *const libc::gid_t) -> libc::c_int;
+}
+
+/// Looks up `user`'s supplementary groups in the group database, along with `gid`.
+fn supplementary_groups(user: &str, gid: libc::gid_t) -> Result<Vec<libc::gid_t>> {
+    let name = try!(CString::new(user).map_err(io::Error::from));
+    let mut len: libc::c_int = 16;
+    loop {
+        let mut groups: Vec<GroupListEntry> = vec![0; len as usize];
+        let found = unsafe {
+            getgrouplist(name.as_ptr(),
+                         gid as GroupListEntry,
+                         groups.as_mut_ptr(),
+                         &mut len)
+        };
+        if found >= 0 {
+            groups.truncate(len as usize);
+            return Ok(groups.into_iter().map(|g| g as libc::gid_t).collect());
+        }
+        // Too small a list; glibc sets `len` to the size needed, others leave it as it was.
+        if len as usize <= groups.len() {
+            if len >= libc::c_int::max_value() / 2 {
+                return Err(Error::from(io::Error::new(io::ErrorKind::Other,
+                                                      format!("Too many groups for {}", user))));
+            }
+            len *= 2;
+        }
+    }
+}
+
+#[cfg(any(target_os = "solaris", target_os = "illumos"))]
+fn rlimit_nproc() -> Option<libc::c_int> {
+    // There is no per-user process limit; illumos caps processes with resource controls on the
+    // project or zone instead.
+    None
+}
+
+#[cfg(not(any(target_os = "solaris", target_os = "illumos")))]
+fn rlimit_nproc() -> Option<libc::c_int> {
+    Some(libc::RLIMIT_NPROC)
+}
+
+pub fn become_command(command: PathBuf, args: Vec<OsString>) -> Result<()> {
+    become_exec_command(command, args)
+}
+
+/// Prepares `cmd` to start its process as the given user and group, confined as `confinement`
+/// asks. See `ProcessPlatform::run_as`.
+pub fn run_as(cmd: &mut Command,
+              user: &str,
+              uid: u32,
+              gid: u32,
+              confinement: &Confinement)
+              -> Result<()> {
+    Current::run_as(cmd, user, uid, gid, confinement)
+}
+
+/// Prepares `cmd` so the process it starts has the given file mode creation mask and resource
+/// limits. A limit above the current hard limit raises it too, which only root may do. Limits this
+/// platform doesn't have are skipped with a warning.
+pub fn set_limits(cmd: &mut Command, umask: Option<u32>, limits: &[(Limit, u64)]) -> Result<()> {
+    let mut resources = Vec::new();
+    for &(limit, value) in limits {
+        match Current::rlimit_resource(limit) {
+            Some(resource) => {
+                let value = if value == u64::max_value() {
+                    libc::RLIM_INFINITY
+                } else {
+                    value as libc::rlim_t
+                };
+                resources.push((resource, value));
+            }
+            None => warn!("Ignoring the {} limit, which this platform does not support", limit),
+        }
+    }
+    cmd.before_exec(move || {
+        if let Some(mask) = umask {
+            unsafe { libc::umask(mask as libc::mode_t) };
+        }
+        for &(resource, value) in resources.iter() {
+            let mut rlim = libc::rlimit {
+                rlim_cur: 0,
+                rlim_max: 0,
+            };
+            if unsafe { libc::getrlimit(resource, &mut rlim) } != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            rlim.rlim_cur = value;
+            if rlim.rlim_max != libc::RLIM_INFINITY &&
+               (value == libc::RLIM_INFINITY || value > rlim.rlim_max) {
+                rlim.rlim_max = value;
+            }
+            if unsafe { libc::setrlimit(resource, &rlim) } != 0 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        Ok(())
+    });
+    Ok(())
+}
+
+/// Returns this platform's number for `signal`.
+pub fn signal_number(signal: Signal) -> libc::c_int {
+    Current::signal_number(signal)
+}
+
+/// Returns the signal this platform numbers `num`, if it is one the Supervisor knows about.
+pub fn signal_from_number(num: libc::c_int) -> Option<Signal> {
+    Signal::all().iter().cloned().find(|s| signal_number(*s) == num)
+}
+
+/// Sends `signal` to the process `pid`.
+pub fn signal(pid: u32, signal: Signal) -> Result<()> {
+    send_signal(pid as libc::pid_t, signal_number(signal))
+}
+
+fn send_signal(pid: libc::pid_t, sig: libc::c_int) -> Result<()> {
+    unsafe {
+        match libc::kill(pid, sig) {
+            0 => Ok(()),
+            e => return Err(Error::SignalFailed(e)),
+        }
+    }
+}
+
+/// Makes an `execvp(3)` system call to become a new program.
+///
+/// Note that if successful, this function will not return.
+///
+/// # Failures
+///
+/// * If the system call fails the error will be returned, otherwise this function does not return
+fn become_exec_command(command: PathBuf, args: Vec<OsString>) -> Result<()> {
+    debug!("Calling execvp(): ({:?}) {:?}", command.display(), &args);
+    let error_if_failed = Command::new(command).args(&args).exec();
+    // The only possible return for the above function is an `Error` so return it, meaning that we
+    // failed to exec to our target program
+    return Err(error_if_failed.into());
+}
+
+pub struct Child {
+    pid: u32,
+    last_status: Option<i32>,
+}
+
+impl Child {
+    pub fn new(child: &mut process::Child) -> Result<Child> {
+        Ok(Child {
+            pid: child.id(),
+            last_status: None,
+        })
+    }
+
+    pub fn id(&self) -> u32 {
+        self.pid
+    }
+
+    pub fn status(&mut self) -> Result<HabExitStatus> {
+        match self.last_status {
+            Some(status) => Ok(HabExitStatus { status: Some(status as u32) }),
+            None => {
+                let mut exit_status: i32 = 0;
+
+                match unsafe { libc::waitpid(self.pid as i32, &mut exit_status, libc::WNOHANG) } {
+                    0 => Ok(HabExitStatus { status: None }),
+                    -1 => {
+                        Err(Error::WaitpidFailed(format!("Error calling waitpid on pid: {}",
+                                                         self.pid)))
+                    }
+                    _ => {
+                        self.last_status = Some(exit_status);
+                        Ok(HabExitStatus { status: Some(exit_status as u32) })
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn kill(&mut self) -> Result<ShutdownMethod> {
+        try!(self.send(Signal::SIGTERM));
+
+        let stop_time = SteadyTime::now() + Duration::seconds(8);
+        loop {
+            match self.status() {
+                Ok(status) => {
+                    if !status.no_status() {
+                        break;
+                    }
+                }
+                _ => {}
+            }
+
+            if SteadyTime::now() > stop_time {
+                try!(self.send(Signal::SIGKILL));
+                return Ok(ShutdownMethod::Killed);
+            }
+        }
+        Ok(ShutdownMethod::GracefulTermination)
+    }
+
+    fn send(&self, sig: Signal) -> Result<()> {
+        signal(self.pid, sig)
+    }
+}
+
+impl ExitStatusExt for HabExitStatus {
+    fn code(&self) -> Option<u32> {
+        unsafe {
+            match self.status {
+                None => None,
+                Some(status) if libc::WIFEXITED(status as libc::c_int) => {
+                    Some(libc::WEXITSTATUS(status as libc::c_int) as u32)
+                }
+                _ => None,
+            }
+        }
+    }
+
+    fn signal(&self) -> Option<u32> {
+        unsafe {
+            match self.status {
+                None => None,
+                Some(status) if !libc::WIFEXITED(status as libc::c_int) => {
+                    Some(libc::WTERMSIG(status as libc::c_int) as u32)
+                }
+                _ => None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libc;
+    use std::process::Command;
+    use super::super::*;
+
+    #[test]
+    fn running_process_returns_no_exit_status() {
+        let mut cmd = Command::new("/bin/bash");
+        cmd.arg("-c").arg("while : ; do /bin/sleep 1; done");
+        let mut child = cmd.spawn().unwrap();
+
+        let mut hab_child = HabChild::from(&mut child).unwrap();
+
+        assert!(hab_child.status().unwrap().no_status())
+    }
+
+    #[test]
+    fn successfully_run_process_exits_zero() {
+        let mut cmd = Command::new("/bin/bash");
+        cmd.arg("-c").arg("a='b'");
+        let mut child = cmd.spawn().unwrap();
+
+        let mut hab_child = HabChild::from(&mut child).unwrap();
+        let mut exit = hab_child.status().unwrap();
+
+        while exit.no_status() {
+            exit = hab_child.status().unwrap();
+        }
+
+        assert_eq!(exit.code(), Some(0))
+    }
+
+    #[test]
+    fn terminated_process_returns_sigterm() {
+        let mut cmd = Command::new("/bin/bash");
+        cmd.arg("-c").arg("while : ; do /bin/sleep 1; done");
+        let mut child = cmd.spawn().unwrap();
+
+        let mut hab_child = HabChild::from(&mut child).unwrap();
+        let _ = hab_child.kill();
+
+        let mut exit = hab_child.status().unwrap();
+        while exit.no_status() {
+            exit = hab_child.status().unwrap();
+        }
+
+        assert_eq!(exit.signal(), Some(libc::SIGTERM as u32))
+    }
+
+    #[test]
+    fn calling_wait_multiple_times_after_exit_returns_same_status() {
+        let mut cmd = Command::new("/bin/bash");
+        cmd.arg("-c").arg("exit 5");
+        let mut child = cmd.spawn().unwrap();
+
+        let mut hab_child = HabChild::from(&mut child).unwrap();
+        let mut exit = hab_child.status().unwrap();
+
+        while exit.no_status() {
+            exit = hab_child.status().unwrap();
+        }
+        let next_exit = hab_child.status().unwrap();
+
+        assert_eq!(next_exit.code(), exit.code())
+    }
+
+    #[test]
+    fn process_that_exits_with_specific_code_has_same_exit_code() {
+        let mut cmd = Command::new("/bin/bash");
+        cmd.arg("-c").arg("exit 5");
+        let mut child = cmd.spawn().unwrap();
+
+        let mut hab_child = HabChild::from(&mut child).unwrap();
+        let mut exit = hab_child.status().unwrap();
+
+        while exit.no_status() {
+            exit = hab_child.status().unwrap();
+        }
+
+        assert_eq!(exit.code(), Some(5))
+    }
+
+    #[test]
+    fn supplementary_groups_include_the_primary_group() {
+        let groups = super::supplementary_groups("root", 0).unwrap();
+        assert!(groups.contains(&0));
+    }
+
+    #[test]
+    fn signal_numbers_round_trip() {
+        for sig in Signal::all() {
+            assert_eq!(signal_from_number(signal_number(*sig)), Some(*sig));
+        }
+        assert_eq!(signal_number(Signal::SIGTERM), libc::SIGTERM);
+        assert_eq!(signal_number(Signal::SIGUSR1), libc::SIGUSR1);
+    }
+
+    #[test]
+    fn process_is_started_with_umask_and_limits() {
+        let mut cmd = Command::new("/bin/bash");
+        cmd.arg("-c").arg("[ \"$(umask)\" = 0027 ] && [ \"$(ulimit -n)\" = 64 ]");
+        set_limits(&mut cmd, Some(0o027), &[(Limit::OpenFiles, 64)]).unwrap();
+        let mut child = cmd.spawn().unwrap();
+
+        let mut hab_child = HabChild::from(&mut child).unwrap();
+        let mut exit = hab_child.status().unwrap();
+        while exit.no_status() {
+            exit = hab_child.status().unwrap();
+        }
+
+        assert_eq!(exit.code(), Some(0))
+    }
+}
@@ -53,6 +53,8 @@ pub enum Platform {
     Linux,
     Windows,
     MacOS,
+    FreeBSD,
+    Illumos,
 }
 
 impl fmt::Display for Platform {
@@ -83,6 +85,8 @@ impl FromStr for Platform {
             "linux" => Ok(Platform::Linux),
             "windows" => Ok(Platform::Windows),
             "macos" => Ok(Platform::MacOS),
+            "freebsd" => Ok(Platform::FreeBSD),
+            "illumos" => Ok(Platform::Illumos),
             _ => return Err(Error::InvalidPlatform(value.to_string())),
         }
     }
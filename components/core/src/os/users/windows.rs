@@ -32,7 +32,12 @@ pub fn get_uid_by_name(owner: &str) -> Option<String> {
     get_sid_by_name(owner)
 }
 
+// groups don't own files on windows, so the empty group we report as
+// the current one maps to an empty gid
 pub fn get_gid_by_name(group: &str) -> Option<String> {
+    if group.is_empty() {
+        return Some(String::new());
+    }
     get_sid_by_name(group)
 }
 
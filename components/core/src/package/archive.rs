@@ -25,7 +25,7 @@ use regex::Regex;
 
 use error::{Error, Result};
 use crypto::{artifact, hash};
-use package::{Identifiable, PackageIdent, PackageTarget, MetaFile};
+use package::{license, Identifiable, PackageIdent, PackageTarget, MetaFile};
 
 lazy_static! {
     static ref METAFILE_REGXS: HashMap<MetaFile, Regex> = {
@@ -54,6 +54,9 @@ lazy_static! {
         map.insert(MetaFile::LdFlags,
                    Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
                               MetaFile::LdFlags)).unwrap());
+        map.insert(MetaFile::Licenses,
+                   Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
+                              MetaFile::Licenses)).unwrap());
         map.insert(MetaFile::Manifest,
                    Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
                               MetaFile::Manifest)).unwrap());
@@ -165,6 +168,15 @@ impl PackageArchive {
         }
     }
 
+    /// Returns the licenses the package is distributed under. Packages built before licenses
+    /// were recorded in a `LICENSES` metafile have them read from their `MANIFEST`.
+    pub fn licenses(&mut self) -> Result<Vec<String>> {
+        if let Some(body) = try!(self.read_metadata(MetaFile::Licenses)) {
+            return Ok(license::parse_licenses(body));
+        }
+        Ok(license::manifest_licenses(&try!(self.manifest())))
+    }
+
     pub fn manifest(&mut self) -> Result<String> {
         match self.read_metadata(MetaFile::Manifest) {
             Ok(None) => Err(Error::MetaFileNotFound(MetaFile::Manifest)),
@@ -211,17 +223,40 @@ impl PackageArchive {
     /// * If the package cannot be unpacked
     pub fn unpack(&self, fs_root_path: Option<&Path>) -> Result<()> {
         let root = fs_root_path.unwrap_or(Path::new("/"));
+        let mut extract_options = ExtractOptions::new();
+        extract_options.add(ExtractOption::Time);
+        extract_options.add(ExtractOption::Owner);
+        extract_options.add(ExtractOption::Permissions);
+        self.unpack_with(root, &extract_options)
+    }
+
+    /// Unpack the package's files under the given directory for inspection, without installing
+    /// it. The files keep their paths within the package, such as `hab/pkgs/core/redis/...`, but
+    /// are owned by the current user. The signature is not verified, so entries which would land
+    /// outside of `dst`, by way of `..`, an absolute path or a symlink, are refused.
+    ///
+    /// # Failures
+    ///
+    /// * If the package cannot be unpacked
+    /// * If the package has an entry which would be written outside of `dst`
+    pub fn extract(&self, dst: &Path) -> Result<()> {
+        let mut extract_options = ExtractOptions::new();
+        extract_options.add(ExtractOption::Time);
+        extract_options.add(ExtractOption::Permissions);
+        extract_options.add(ExtractOption::SecureNoDotDot);
+        extract_options.add(ExtractOption::SecureSymlinks);
+        extract_options.add(ExtractOption::SecureNoAbsolutePaths);
+        self.unpack_with(dst, &extract_options)
+    }
+
+    fn unpack_with(&self, root: &Path, extract_options: &ExtractOptions) -> Result<()> {
         let tar_reader = try!(artifact::get_archive_reader(&self.path));
         let mut builder = reader::Builder::new();
         try!(builder.support_format(ReadFormat::Gnutar));
         try!(builder.support_filter(ReadFilter::Xz));
         let mut reader = try!(builder.open_stream(tar_reader));
         let writer = writer::Disk::new();
-        let mut extract_options = ExtractOptions::new();
-        extract_options.add(ExtractOption::Time);
-        extract_options.add(ExtractOption::Owner);
-        extract_options.add(ExtractOption::Permissions);
-        try!(writer.set_options(&extract_options));
+        try!(writer.set_options(extract_options));
         try!(writer.set_standard_lookup());
         try!(writer.write(&mut reader, Some(root.to_string_lossy().as_ref())));
         try!(writer.close());
@@ -0,0 +1,135 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The artifact cache, shared by `hab pkg install` and the Supervisor.
+//!
+//! Anything putting an artifact in the cache holds that artifact's lock while it does, so that
+//! two processes installing the same package at once don't both download it, and neither reads
+//! an artifact the other is still writing. Locks are taken on files in the cache's `.locks`
+//! directory, one per artifact, and are released when the `ArtifactLock` is dropped or the
+//! process exits.
+
+use std::fs::{self, File, OpenOptions};
+use std::path::{Path, PathBuf};
+
+use error::{Error, Result};
+use os::filesystem;
+use package::PackageIdent;
+
+/// The directory in the artifact cache which holds the lock files.
+const LOCKS_DIR: &'static str = ".locks";
+
+#[derive(Clone, Debug)]
+pub struct ArtifactCache {
+    path: PathBuf,
+}
+
+impl ArtifactCache {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        ArtifactCache { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns where the artifact of a fully qualified package is cached.
+    ///
+    /// # Failures
+    ///
+    /// * The package identifier isn't fully qualified
+    pub fn artifact_path(&self, ident: &PackageIdent) -> Result<PathBuf> {
+        match ident.archive_name() {
+            Some(name) => Ok(self.path.join(name)),
+            None => Err(Error::InvalidPackageIdent(ident.to_string())),
+        }
+    }
+
+    /// Returns whether the artifact of a package is in the cache. Only meaningful while holding
+    /// the artifact's lock.
+    pub fn contains(&self, ident: &PackageIdent) -> Result<bool> {
+        Ok(try!(self.artifact_path(ident)).is_file())
+    }
+
+    /// Takes the lock on a package's artifact, waiting for whoever holds it to let it go.
+    ///
+    /// # Failures
+    ///
+    /// * The package identifier isn't fully qualified
+    /// * The lock file can't be created, or locked
+    pub fn lock(&self, ident: &PackageIdent) -> Result<ArtifactLock> {
+        let name = match ident.archive_name() {
+            Some(name) => name,
+            None => return Err(Error::InvalidPackageIdent(ident.to_string())),
+        };
+        let locks = self.path.join(LOCKS_DIR);
+        try!(fs::create_dir_all(&locks));
+        let path = locks.join(format!("{}.lock", name));
+        let file = try!(OpenOptions::new().write(true).create(true).open(&path));
+        debug!("Waiting for the lock on {}", path.display());
+        try!(filesystem::lock_exclusive(&file));
+        Ok(ArtifactLock { _file: file })
+    }
+}
+
+/// The lock on an artifact in the cache, released when dropped.
+pub struct ArtifactLock {
+    _file: File,
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::str::FromStr;
+    use std::sync::mpsc::channel;
+    use std::thread;
+    use std::time::Duration;
+
+    use tempdir::TempDir;
+
+    use package::PackageIdent;
+    use super::ArtifactCache;
+
+    #[test]
+    fn lock_waits_for_holder() {
+        let dir = TempDir::new("artifact-cache").unwrap();
+        let cache = ArtifactCache::new(dir.path());
+        let ident = PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap();
+        let lock = cache.lock(&ident).unwrap();
+
+        let (tx, rx) = channel();
+        let waiter = {
+            let cache = cache.clone();
+            let ident = ident.clone();
+            thread::spawn(move || {
+                let _lock = cache.lock(&ident).unwrap();
+                tx.send(cache.contains(&ident).unwrap()).unwrap();
+            })
+        };
+        thread::sleep(Duration::from_millis(100));
+        assert!(rx.try_recv().is_err());
+        File::create(cache.artifact_path(&ident).unwrap()).unwrap();
+        drop(lock);
+        assert!(rx.recv().unwrap());
+        waiter.join().unwrap();
+    }
+
+    #[test]
+    fn artifact_path_needs_fully_qualified_ident() {
+        let cache = ArtifactCache::new("/hab/cache/artifacts");
+        let ident = PackageIdent::from_str("core/redis").unwrap();
+        assert!(cache.artifact_path(&ident).is_err());
+        assert!(cache.lock(&ident).is_err());
+    }
+}
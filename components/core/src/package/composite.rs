@@ -0,0 +1,169 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Composite packages, which bundle the services of other packages to be run as one unit.
+//!
+//! A composite is built from the `pkg_services`, `pkg_service_groups` and `pkg_bind_map` plan
+//! variables into a SERVICES metafile. Each line names the fully qualified package of one service,
+//! followed by the name it runs under if that isn't its package's, the group it runs in, and its
+//! binds on the composite's other services, which they name by the names they run under:
+//!
+//! ```text
+//! core/redis/3.2.4/20170514150022 name=cache group=cache
+//! core/redis/3.2.4/20170514150022 name=sessions
+//! acme/app/1.0.0/20170501101010 bind=cache:cache bind=sessions:sessions
+//! ```
+//!
+//! Naming its services lets a composite run the same package more than once under one Supervisor,
+//! each with its own directory in `/hab/svc`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use error::{Error, Result};
+use package::{MetaFile, PackageIdent};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum PackageType {
+    Standalone,
+    Composite,
+}
+
+impl Default for PackageType {
+    fn default() -> PackageType {
+        PackageType::Standalone
+    }
+}
+
+impl fmt::Display for PackageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match *self {
+            PackageType::Standalone => "standalone",
+            PackageType::Composite => "composite",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+impl FromStr for PackageType {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "standalone" => Ok(PackageType::Standalone),
+            "composite" => Ok(PackageType::Composite),
+            _ => Err(Error::MetaFileMalformed(MetaFile::Type)),
+        }
+    }
+}
+
+/// One of the services of a composite package.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompositeService {
+    pub ident: PackageIdent,
+    /// The name the service runs under, if not its package's name.
+    pub name: Option<String>,
+    /// The group the service runs in, if not the one the composite is started in.
+    pub group: Option<String>,
+    /// Each bind's name, and the name of the composite's service it binds to.
+    pub binds: Vec<(String, String)>,
+}
+
+impl FromStr for CompositeService {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self> {
+        let mut fields = line.split_whitespace();
+        let ident = match fields.next().map(PackageIdent::from_str) {
+            Some(Ok(ref ident)) if ident.fully_qualified() => ident.clone(),
+            _ => return Err(Error::MetaFileMalformed(MetaFile::Services)),
+        };
+        let mut service = CompositeService {
+            ident: ident,
+            name: None,
+            group: None,
+            binds: Vec::new(),
+        };
+        for field in fields {
+            let mut parts = field.splitn(2, '=');
+            match (parts.next(), parts.next()) {
+                (Some("name"), Some(name)) => service.name = Some(name.to_string()),
+                (Some("group"), Some(group)) => service.group = Some(group.to_string()),
+                (Some("bind"), Some(bind)) => {
+                    let mut bind_parts = bind.splitn(2, ':');
+                    match (bind_parts.next(), bind_parts.next()) {
+                        (Some(name), Some(target)) => {
+                            service.binds.push((name.to_string(), target.to_string()))
+                        }
+                        _ => return Err(Error::MetaFileMalformed(MetaFile::Services)),
+                    }
+                }
+                _ => return Err(Error::MetaFileMalformed(MetaFile::Services)),
+            }
+        }
+        Ok(service)
+    }
+}
+
+impl CompositeService {
+    /// Returns the name the service runs under, which is its package's name unless it has been
+    /// given another.
+    pub fn service_name(&self) -> &str {
+        self.name.as_ref().map(|n| n.as_str()).unwrap_or(&self.ident.name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::{CompositeService, PackageType};
+
+    #[test]
+    fn package_type_from_str() {
+        assert_eq!(PackageType::from_str("composite").unwrap(), PackageType::Composite);
+        assert_eq!(PackageType::from_str("standalone").unwrap(), PackageType::Standalone);
+        assert!(PackageType::from_str("bundle").is_err());
+    }
+
+    #[test]
+    fn composite_service_from_str() {
+        let service = CompositeService::from_str("acme/app/1.0.0/20170501101010 group=prod \
+                                                   bind=database:redis bind=cache:memcached")
+            .unwrap();
+        assert_eq!(service.ident.to_string(), "acme/app/1.0.0/20170501101010");
+        assert_eq!(service.group, Some("prod".to_string()));
+        assert_eq!(service.binds,
+                   vec![("database".to_string(), "redis".to_string()),
+                        ("cache".to_string(), "memcached".to_string())]);
+
+        let service = CompositeService::from_str("core/redis/3.2.4/20170514150022").unwrap();
+        assert_eq!(service.group, None);
+        assert!(service.binds.is_empty());
+        assert_eq!(service.service_name(), "redis");
+
+        let service = CompositeService::from_str("core/redis/3.2.4/20170514150022 name=cache")
+            .unwrap();
+        assert_eq!(service.name, Some("cache".to_string()));
+        assert_eq!(service.service_name(), "cache");
+    }
+
+    #[test]
+    fn composite_service_from_str_rejects_malformed_lines() {
+        assert!(CompositeService::from_str("core/redis").is_err());
+        assert!(CompositeService::from_str("core/redis/3.2.4/20170514150022 port=6379").is_err());
+        assert!(CompositeService::from_str("acme/app/1.0.0/20170501101010 bind=database")
+            .is_err());
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The environment variables packages export to whatever runs them, or builds against them.
+//!
+//! A plan exports variables with `pkg_env`, and marks those which are lists, such as
+//! `PYTHONPATH`, with the separator of their entries in `pkg_env_sep`. They are recorded in the
+//! package's `ENVIRONMENT` and `ENVIRONMENT_SEP` metafiles.
+//!
+//! The environment of a package is composed from the variables of the package and every package
+//! in its dependency closure, visited in the order its `PATH` is: the package, its direct
+//! dependencies in the order they were declared, then the rest of its transitive dependencies.
+//!
+//! * A list variable gets every package's entries appended in that order, without duplicates.
+//! * Any other variable takes the package's own value, if it exports one. Otherwise each
+//!   dependency exporting it must export the same value.
+//!
+//! Dependencies exporting different values for a variable, or packages separating a list
+//! variable's entries differently, are a conflict, and the environment can't be composed.
+
+use std::collections::{BTreeMap, HashMap};
+
+use error::{Error, Result};
+use package::PackageIdent;
+
+/// The variables one package exports.
+#[derive(Debug, Clone, Default)]
+pub struct PackageEnv {
+    pub ident: PackageIdent,
+    pub vars: HashMap<String, String>,
+    /// The separator of each list variable's entries.
+    pub separators: HashMap<String, String>,
+}
+
+/// Composes the environment of the first package from its variables and those of its
+/// dependencies, which follow it in the order they are visited.
+pub fn compose(packages: &[PackageEnv]) -> Result<BTreeMap<String, String>> {
+    let mut separators: HashMap<&str, (&str, &PackageIdent)> = HashMap::new();
+    for package in packages.iter() {
+        for (name, sep) in package.separators.iter() {
+            match separators.get(name.as_str()) {
+                Some(&(other_sep, other)) if other_sep != sep.as_str() => {
+                    let conflict = format!("{} separates the entries of {} with '{}', but {} \
+                                            with '{}'",
+                                           other,
+                                           name,
+                                           other_sep,
+                                           package.ident,
+                                           sep);
+                    return Err(Error::EnvironmentConflict(conflict));
+                }
+                Some(_) => {}
+                None => {
+                    separators.insert(name, (sep, &package.ident));
+                }
+            }
+        }
+    }
+
+    let mut lists: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    let mut values: BTreeMap<&str, (&str, &PackageIdent)> = BTreeMap::new();
+    for package in packages.iter() {
+        for (name, value) in package.vars.iter() {
+            if let Some(&(sep, _)) = separators.get(name.as_str()) {
+                let entries = lists.entry(name).or_insert_with(Vec::new);
+                for entry in value.split(sep).filter(|e| !e.is_empty()) {
+                    if !entries.contains(&entry) {
+                        entries.push(entry);
+                    }
+                }
+                continue;
+            }
+            match values.get(name.as_str()) {
+                // The package itself overrides its dependencies.
+                Some(&(_, owner)) if owner == &packages[0].ident => continue,
+                Some(&(other_value, other)) if other_value != value.as_str() => {
+                    let conflict = format!("{} sets {}={}, but {} sets {}={}",
+                                           other,
+                                           name,
+                                           other_value,
+                                           package.ident,
+                                           name,
+                                           value);
+                    return Err(Error::EnvironmentConflict(conflict));
+                }
+                Some(_) => continue,
+                None => {}
+            }
+            values.insert(name, (value, &package.ident));
+        }
+    }
+
+    let mut env: BTreeMap<String, String> = values.into_iter()
+        .map(|(name, (value, _))| (name.to_string(), value.to_string()))
+        .collect();
+    for (name, entries) in lists.into_iter() {
+        let sep = separators[name].0;
+        env.insert(name.to_string(), entries.join(sep));
+    }
+    Ok(env)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    use package::PackageIdent;
+    use super::{compose, PackageEnv};
+
+    fn package_env(ident: &str, vars: &[(&str, &str)], separators: &[(&str, &str)]) -> PackageEnv {
+        let to_map = |pairs: &[(&str, &str)]| {
+            pairs.iter()
+                .map(|&(k, v)| (k.to_string(), v.to_string()))
+                .collect::<HashMap<String, String>>()
+        };
+        PackageEnv {
+            ident: PackageIdent::from_str(ident).unwrap(),
+            vars: to_map(vars),
+            separators: to_map(separators),
+        }
+    }
+
+    #[test]
+    fn compose_appends_list_variables_in_order() {
+        let packages = vec![package_env("acme/app/1.0.0/20170101000000",
+                                        &[("PYTHONPATH", "/app/lib")],
+                                        &[("PYTHONPATH", ":")]),
+                            package_env("core/python/3.6.0/20170101000000",
+                                        &[("PYTHONPATH", "/python/lib:/app/lib"),
+                                          ("PYTHONHOME", "/python")],
+                                        &[("PYTHONPATH", ":")]),
+                            package_env("core/six/1.10.0/20170101000000",
+                                        &[("PYTHONPATH", "/six/lib")],
+                                        &[])];
+        let env = compose(&packages).unwrap();
+        assert_eq!(env["PYTHONPATH"], "/app/lib:/python/lib:/six/lib");
+        assert_eq!(env["PYTHONHOME"], "/python");
+        assert_eq!(env.len(), 2);
+    }
+
+    #[test]
+    fn compose_lets_the_package_override_its_dependencies() {
+        let packages = vec![package_env("acme/app/1.0.0/20170101000000",
+                                        &[("JAVA_HOME", "/app/jre")],
+                                        &[]),
+                            package_env("core/jdk8/8.0.0/20170101000000",
+                                        &[("JAVA_HOME", "/jdk8")],
+                                        &[]),
+                            package_env("core/jdk9/9.0.0/20170101000000",
+                                        &[("JAVA_HOME", "/jdk9")],
+                                        &[])];
+        assert_eq!(compose(&packages).unwrap()["JAVA_HOME"], "/app/jre");
+    }
+
+    #[test]
+    fn compose_rejects_conflicting_dependencies() {
+        let packages = vec![package_env("acme/app/1.0.0/20170101000000", &[], &[]),
+                            package_env("core/jdk8/8.0.0/20170101000000",
+                                        &[("JAVA_HOME", "/jdk8")],
+                                        &[]),
+                            package_env("core/jdk9/9.0.0/20170101000000",
+                                        &[("JAVA_HOME", "/jdk9")],
+                                        &[])];
+        assert!(compose(&packages).is_err());
+
+        let packages = vec![package_env("acme/app/1.0.0/20170101000000",
+                                        &[("CLASSPATH", "/app/lib")],
+                                        &[("CLASSPATH", ":")]),
+                            package_env("core/jdk8/8.0.0/20170101000000",
+                                        &[("CLASSPATH", "/jdk8/lib")],
+                                        &[("CLASSPATH", ";")])];
+        assert!(compose(&packages).is_err());
+    }
+}
@@ -314,6 +314,51 @@ pub fn version_sort(a_version: &str, b_version: &str) -> Result<Ordering> {
     }
 }
 
+/// Compares two versions like `version_sort`, except that letters directly after the version
+/// number count too, as in OpenSSL's `1.0.2j` and `1.0.2k`. A version with letters sorts after
+/// the same version without, and letters are compared by length and then alphabetically, so
+/// `0.9.8z` comes before `0.9.8za`.
+///
+/// Where `version_sort` ignores whatever it can't make sense of, this is strict: each version must
+/// be numbers separated by dots, then any letters, then an optional `-` and extension. Use it
+/// wherever a version is checked against a bound, such as a version constraint or the versions a
+/// vulnerability affects, rather than only sorted.
+///
+/// # Failures
+///
+/// * Either version isn't in that form
+pub fn version_cmp(a_version: &str, b_version: &str) -> Result<Ordering> {
+    let (a_number, a_letters, a_extension) = try!(split_version_strict(a_version));
+    let (b_number, b_letters, b_extension) = try!(split_version_strict(b_version));
+    match try!(version_sort(a_number, b_number)) {
+        Ordering::Equal => (),
+        ordering => return Ok(ordering),
+    }
+    match (a_letters.len(), a_letters).cmp(&(b_letters.len(), b_letters)) {
+        Ordering::Equal => (),
+        ordering => return Ok(ordering),
+    }
+    // As with `version_sort`, a version without an extension is greater than one with.
+    match (a_extension, b_extension) {
+        (Some(a), Some(b)) => Ok(a.cmp(b)),
+        (Some(_), None) => Ok(Ordering::Less),
+        (None, Some(_)) => Ok(Ordering::Greater),
+        (None, None) => Ok(Ordering::Equal),
+    }
+}
+
+/// Splits a version into its number, the letters following it, and its extension.
+fn split_version_strict(version: &str) -> Result<(&str, &str, Option<&str>)> {
+    let re = try!(Regex::new(r"^(\d+(?:\.\d+)*)([a-zA-Z]*)(?:-(.+))?$"));
+    let caps = match re.captures(version) {
+        Some(caps) => caps,
+        None => return Err(Error::InvalidVersion(version.to_string())),
+    };
+    Ok((caps.get(1).unwrap().as_str(),
+        caps.get(2).map_or("", |m| m.as_str()),
+        caps.get(3).map(|m| m.as_str())))
+}
+
 fn split_version(version: &str) -> Result<(Vec<&str>, Option<String>)> {
     let re = try!(Regex::new(r"([\d\.]+)(-.+)?"));
     let caps = match re.captures(version) {
@@ -417,6 +462,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn version_cmp_compares_letters() {
+        assert_eq!(version_cmp("1.0.2j", "1.0.2k").unwrap(), Ordering::Less);
+        assert_eq!(version_cmp("1.0.2k", "1.0.2").unwrap(), Ordering::Greater);
+        assert_eq!(version_cmp("0.9.8z", "0.9.8za").unwrap(), Ordering::Less);
+        assert_eq!(version_cmp("1.0.2k", "1.0.3").unwrap(), Ordering::Less);
+        assert_eq!(version_cmp("1.0.0-alpha1", "1.0.0").unwrap(), Ordering::Less);
+        assert_eq!(version_cmp("1.2", "1.2.0").unwrap(), Ordering::Equal);
+        assert!(version_cmp("master", "1.0").is_err());
+        assert!(version_cmp("1.0", "v1.0").is_err());
+    }
+
     #[test]
     fn split_version_returns_both_parts() {
         let svr = split_version("1.2.3-beta16");
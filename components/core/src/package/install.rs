@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use std;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::cmp::{Ordering, PartialOrd};
 use std::env;
 use std::fs::{DirEntry, File};
@@ -23,9 +23,10 @@ use std::str::FromStr;
 
 use toml;
 
-use super::{Identifiable, MetaFile, PackageIdent, Target, PackageTarget};
+use super::{environment, license, CompositeService, Identifiable, MetaFile, PackageEnv,
+            PackageIdent, PackageType, Target, PackageTarget};
 use error::{Error, Result};
-use fs::{self, PKG_PATH};
+use fs;
 use util;
 
 pub const DEFAULT_CFG_FILE: &'static str = "default.toml";
@@ -36,6 +37,10 @@ pub struct PackageInstall {
     pub fs_root_path: PathBuf,
     pub package_root_path: PathBuf,
     pub installed_path: PathBuf,
+    /// The name the package runs under as a service, if not its own. It names the service's
+    /// directory in `/hab/svc`, so one package can run as several services.
+    #[serde(default)]
+    pub svc_name: Option<String>,
 }
 
 impl PackageInstall {
@@ -73,6 +78,17 @@ impl PackageInstall {
         }
     }
 
+    /// Returns the idents of all packages installed under the given filesystem root, which is `/`
+    /// (or the system drive on Windows) if not specified. If no packages have been installed yet
+    /// the list is empty.
+    pub fn installed_idents(fs_root_path: Option<&Path>) -> Result<Vec<PackageIdent>> {
+        let package_root_path = fs::pkg_root_path(fs_root_path);
+        if !package_root_path.exists() {
+            return Ok(vec![]);
+        }
+        Self::package_list(&package_root_path)
+    }
+
     /// Read and return the decoded contents of the packages default configuration.
     pub fn default_cfg(&self) -> Option<toml::Table> {
         match File::open(self.installed_path.join(DEFAULT_CFG_FILE)) {
@@ -90,8 +106,8 @@ impl PackageInstall {
     fn resolve_package_install(ident: &PackageIdent,
                                fs_root_path: Option<&Path>)
                                -> Result<PackageInstall> {
-        let fs_root_path = fs_root_path.unwrap_or(Path::new("/"));
-        let package_root_path = fs_root_path.join(PKG_PATH);
+        let fs_root_path = fs_root_path.map(|p| p.to_path_buf()).unwrap_or(fs::fs_root_path());
+        let package_root_path = fs::pkg_root_path(Some(&fs_root_path));
         if !package_root_path.exists() {
             return Err(Error::PackageNotFound(ident.clone()));
         }
@@ -103,6 +119,7 @@ impl PackageInstall {
                     fs_root_path: PathBuf::from(fs_root_path),
                     package_root_path: package_root_path.clone(),
                     installed_path: try!(Self::calc_installed_path(ident, &package_root_path)),
+                    svc_name: None,
                 })
             } else {
                 Err(Error::PackageNotFound(ident.clone()))
@@ -127,6 +144,7 @@ impl PackageInstall {
                     fs_root_path: PathBuf::from(fs_root_path),
                     package_root_path: package_root_path.clone(),
                     installed_path: try!(Self::calc_installed_path(&id, &package_root_path)),
+                    svc_name: None,
                 })
             } else {
                 Err(Error::PackageNotFound(ident.clone()))
@@ -149,8 +167,8 @@ impl PackageInstall {
             ident.clone()
         };
 
-        let fs_root_path = fs_root_path.unwrap_or(Path::new("/"));
-        let package_root_path = fs_root_path.join(PKG_PATH);
+        let fs_root_path = fs_root_path.map(|p| p.to_path_buf()).unwrap_or(fs::fs_root_path());
+        let package_root_path = fs::pkg_root_path(Some(&fs_root_path));
         if !package_root_path.exists() {
             return Err(Error::PackageNotFound(ident.clone()));
         }
@@ -179,6 +197,7 @@ impl PackageInstall {
                     fs_root_path: PathBuf::from(fs_root_path),
                     package_root_path: package_root_path.clone(),
                     installed_path: try!(Self::calc_installed_path(&id, &package_root_path)),
+                    svc_name: None,
                 })
             }
             None => Err(Error::PackageNotFound(ident.clone())),
@@ -195,6 +214,7 @@ impl PackageInstall {
             fs_root_path: fs_root_path,
             package_root_path: package_root_path,
             installed_path: installed_path,
+            svc_name: None,
         }
     }
 
@@ -303,43 +323,118 @@ impl PackageInstall {
         Ok(p.into_string().expect("Failed to convert path to utf8 string"))
     }
 
+    /// Returns the environment variables the package exports, as declared by the `pkg_env` plan
+    /// variable. An empty map is returned if the package doesn't contain an ENVIRONMENT Metafile.
+    pub fn environment(&self) -> Result<HashMap<String, String>> {
+        self.read_metafile_map(MetaFile::Environment)
+    }
+
+    /// Returns the separator of the entries of each list variable the package exports, as
+    /// declared by the `pkg_env_sep` plan variable. An empty map is returned if the package
+    /// doesn't contain an ENVIRONMENT_SEP Metafile.
+    pub fn environment_separators(&self) -> Result<HashMap<String, String>> {
+        self.read_metafile_map(MetaFile::EnvironmentSep)
+    }
+
+    /// Returns the environment variables exported by this package and its dependencies, composed
+    /// as described in the `environment` module, in the same order as `runtime_path`. `PATH`
+    /// isn't among them; it comes from `runtime_path`.
+    ///
+    /// # Failures
+    ///
+    /// * A package in the dependency closure couldn't be loaded, or its metafiles read
+    /// * Packages in the dependency closure export conflicting values
+    pub fn runtime_environment(&self) -> Result<BTreeMap<String, String>> {
+        let mut idents = HashSet::new();
+        let mut packages = vec![try!(self.package_env())];
+        idents.insert(self.ident().clone());
+        for dep in try!(self.load_deps()).iter().chain(try!(self.load_tdeps()).iter()) {
+            if idents.insert(dep.ident().clone()) {
+                packages.push(try!(dep.package_env()));
+            }
+        }
+        environment::compose(&packages)
+    }
+
+    fn package_env(&self) -> Result<PackageEnv> {
+        Ok(PackageEnv {
+            ident: self.ident().clone(),
+            vars: try!(self.environment()),
+            separators: try!(self.environment_separators()),
+        })
+    }
+
     pub fn installed_path(&self) -> &PathBuf {
         &self.installed_path
     }
 
+    /// Returns the name the package runs under as a service, which is the package's name unless
+    /// it has been given another.
+    pub fn service_name(&self) -> &str {
+        self.svc_name.as_ref().map(|n| n.as_str()).unwrap_or(&self.ident.name)
+    }
+
     /// Returns the root path for service configuration, files, and data.
     pub fn svc_path(&self) -> PathBuf {
-        fs::svc_path(&self.ident.name)
+        fs::svc_path(self.service_name())
     }
 
     /// Returns the path to the service configuration.
     pub fn svc_config_path(&self) -> PathBuf {
-        fs::svc_config_path(&self.ident.name)
+        fs::svc_config_path(self.service_name())
     }
 
     /// Returns the path to the service data.
     pub fn svc_data_path(&self) -> PathBuf {
-        fs::svc_data_path(&self.ident.name)
+        fs::svc_data_path(self.service_name())
     }
 
     /// Returns the path to the service's gossiped config files.
     pub fn svc_files_path(&self) -> PathBuf {
-        fs::svc_files_path(&self.ident.name)
+        fs::svc_files_path(self.service_name())
     }
 
     /// Returns the path to the service hooks.
     pub fn svc_hooks_path(&self) -> PathBuf {
-        fs::svc_hooks_path(&self.ident.name)
+        fs::svc_hooks_path(self.service_name())
     }
 
     /// Returns the path to the service static content.
     pub fn svc_static_path(&self) -> PathBuf {
-        fs::svc_static_path(&self.ident.name)
+        fs::svc_static_path(self.service_name())
     }
 
     /// Returns the path to the service variable state.
     pub fn svc_var_path(&self) -> PathBuf {
-        fs::svc_var_path(&self.ident.name)
+        fs::svc_var_path(self.service_name())
+    }
+
+    /// Returns the contents of the package's MANIFEST, the human readable summary of its build.
+    pub fn manifest(&self) -> Result<String> {
+        self.read_metafile(MetaFile::Manifest)
+    }
+
+    /// Returns the licenses the package is distributed under, as declared by the `pkg_license`
+    /// plan variable. Packages built before licenses were recorded in a LICENSES Metafile have
+    /// them read from their MANIFEST.
+    pub fn licenses(&self) -> Result<Vec<String>> {
+        match self.read_metafile(MetaFile::Licenses) {
+            Ok(body) => Ok(license::parse_licenses(&body)),
+            Err(Error::MetaFileNotFound(MetaFile::Licenses)) => {
+                Ok(license::manifest_licenses(&try!(self.manifest())))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the software bill of materials recorded when the package was built
+    /// or None if the package doesn't contain a SBOM Metafile
+    pub fn sbom(&self) -> Result<Option<String>> {
+        match self.read_metafile(MetaFile::Sbom) {
+            Ok(body) => Ok(Some(body)),
+            Err(Error::MetaFileNotFound(MetaFile::Sbom)) => Ok(None),
+            Err(e) => Err(e),
+        }
     }
 
     /// Returns the user that the package is specified to run as
@@ -362,6 +457,155 @@ impl PackageInstall {
         }
     }
 
+    /// Returns the name of the template engine the package's templates are written for
+    /// or None if the package doesn't contain a SVC_TEMPLATE_ENGINE Metafile
+    pub fn svc_template_engine(&self) -> Result<Option<String>> {
+        match self.read_metafile(MetaFile::SvcTemplateEngine) {
+            Ok(body) => Ok(Some(body)),
+            Err(Error::MetaFileNotFound(MetaFile::SvcTemplateEngine)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the version of the template data the package's templates and hooks are written for
+    /// or None if the package doesn't contain a SVC_DATA_VERSION Metafile
+    pub fn svc_data_version(&self) -> Result<Option<String>> {
+        match self.read_metafile(MetaFile::SvcDataVersion) {
+            Ok(body) => Ok(Some(body)),
+            Err(Error::MetaFileNotFound(MetaFile::SvcDataVersion)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the name of the interpreter the package's hooks are run with by default
+    /// or None if the package doesn't contain a SVC_HOOK_INTERPRETER Metafile
+    pub fn svc_hook_interpreter(&self) -> Result<Option<String>> {
+        match self.read_metafile(MetaFile::SvcHookInterpreter) {
+            Ok(body) => Ok(Some(body)),
+            Err(Error::MetaFileNotFound(MetaFile::SvcHookInterpreter)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns a Rust representation of the mappings defined by the `pkg_svc_exit_codes` plan
+    /// variable.
+    ///
+    /// Each key is a process exit code and each value is the name of the action the Supervisor
+    /// should take when the service exits with that code. An empty map is returned if the package
+    /// doesn't contain a SVC_EXIT_CODES Metafile.
+    pub fn svc_exit_codes(&self) -> Result<HashMap<String, String>> {
+        self.read_metafile_map(MetaFile::SvcExitCodes)
+    }
+
+    /// Returns a Rust representation of the health probe defined by the `pkg_svc_health_probe`
+    /// plan variable.
+    ///
+    /// The map holds the probe's `url` and, optionally, the HTTP `status` it expects and its
+    /// `timeout` in seconds. An empty map is returned if the package doesn't contain a
+    /// SVC_HEALTH_PROBE Metafile.
+    pub fn svc_health_probe(&self) -> Result<HashMap<String, String>> {
+        self.read_metafile_map(MetaFile::SvcHealthProbe)
+    }
+
+    /// Returns a Rust representation of the resource limits defined by the `pkg_svc_limits` plan
+    /// variable.
+    ///
+    /// Each key is the name of a resource, such as `nofile`, and each value is the limit the
+    /// service's process is started with. An empty map is returned if the package doesn't contain
+    /// a SVC_LIMITS Metafile.
+    pub fn svc_limits(&self) -> Result<HashMap<String, String>> {
+        self.read_metafile_map(MetaFile::SvcLimits)
+    }
+
+    /// Returns a Rust representation of the managed directories defined by the `pkg_svc_dirs`
+    /// plan variable.
+    ///
+    /// Each key is the name of a directory beneath the service's own, such as `cache`, and each
+    /// value holds the options the Supervisor manages it with, such as `tmpfs clear=restart`. An
+    /// empty map is returned if the package doesn't contain a SVC_DIRS Metafile.
+    pub fn svc_dirs(&self) -> Result<HashMap<String, String>> {
+        self.read_metafile_map(MetaFile::SvcDirs)
+    }
+
+    /// Returns the octal file mode creation mask the service's process is started with
+    /// or None if the package doesn't contain a SVC_UMASK Metafile
+    pub fn svc_umask(&self) -> Result<Option<String>> {
+        match self.read_metafile(MetaFile::SvcUmask) {
+            Ok(body) => Ok(Some(body)),
+            Err(Error::MetaFileNotFound(MetaFile::SvcUmask)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the capabilities the service's process keeps, as declared by the
+    /// `pkg_svc_capabilities` plan variable, or None if the package doesn't contain a
+    /// SVC_CAPABILITIES Metafile. An empty list means the process keeps no capabilities at all.
+    pub fn svc_capabilities(&self) -> Result<Option<Vec<String>>> {
+        match self.read_metafile(MetaFile::SvcCapabilities) {
+            Ok(ref body) if body == "none" => Ok(Some(Vec::new())),
+            Ok(body) => Ok(Some(body.split_whitespace().map(|c| c.to_string()).collect())),
+            Err(Error::MetaFileNotFound(MetaFile::SvcCapabilities)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the ways the service's hooks are sandboxed, as declared by the
+    /// `pkg_svc_hook_sandbox` plan variable. An empty list is returned if the package doesn't
+    /// contain a SVC_HOOK_SANDBOX Metafile.
+    pub fn svc_hook_sandbox(&self) -> Result<Vec<String>> {
+        match self.read_metafile(MetaFile::SvcHookSandbox) {
+            Ok(body) => Ok(body.split_whitespace().map(|s| s.to_string()).collect()),
+            Err(Error::MetaFileNotFound(MetaFile::SvcHookSandbox)) => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the compiled seccomp filter the service's process is started with, or None if the
+    /// package doesn't contain a SVC_SECCOMP Metafile. Unlike other metafiles it is binary: a BPF
+    /// program in the form written by libseccomp's `seccomp_export_bpf(3)`.
+    pub fn svc_seccomp_filter(&self) -> Result<Option<Vec<u8>>> {
+        let filepath = self.installed_path.join(MetaFile::SvcSeccomp.to_string());
+        if !filepath.is_file() {
+            return Ok(None);
+        }
+        let mut data = Vec::new();
+        match File::open(&filepath) {
+            Ok(mut f) => try!(f.read_to_end(&mut data).map_err(Error::MetaFileIO)),
+            Err(e) => return Err(Error::MetaFileIO(e)),
+        };
+        Ok(Some(data))
+    }
+
+    /// Returns a Rust representation of the mappings defined by the `pkg_svc_scheduled_hooks`
+    /// plan variable.
+    ///
+    /// Each key is the name of a hook and each value is the cron style schedule it runs on. An
+    /// empty map is returned if the package doesn't contain a SVC_SCHEDULED_HOOKS Metafile.
+    pub fn svc_scheduled_hooks(&self) -> Result<HashMap<String, String>> {
+        self.read_metafile_map(MetaFile::SvcScheduledHooks)
+    }
+
+    /// Returns the kind of package, as declared by the `pkg_type` plan variable. Packages which
+    /// don't contain a TYPE Metafile are standalone.
+    pub fn pkg_type(&self) -> Result<PackageType> {
+        match self.read_metafile(MetaFile::Type) {
+            Ok(body) => PackageType::from_str(&body),
+            Err(Error::MetaFileNotFound(MetaFile::Type)) => Ok(PackageType::Standalone),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the services of a composite package, as declared by the `pkg_services`,
+    /// `pkg_service_groups` and `pkg_bind_map` plan variables. An empty list is returned if the
+    /// package doesn't contain a SERVICES Metafile.
+    pub fn services(&self) -> Result<Vec<CompositeService>> {
+        match self.read_metafile(MetaFile::Services) {
+            Ok(body) => body.lines().map(CompositeService::from_str).collect(),
+            Err(Error::MetaFileNotFound(MetaFile::Services)) => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn target(&self) -> Result<PackageTarget> {
         match self.read_metafile(MetaFile::Target) {
             Ok(body) => PackageTarget::from_str(&body),
@@ -395,6 +639,29 @@ impl PackageInstall {
         }
     }
 
+    /// Reads metafiles containing `key=value` pairs separated by new lines. An empty map is
+    /// returned if the metafile doesn't exist.
+    fn read_metafile_map(&self, file: MetaFile) -> Result<HashMap<String, String>> {
+        match self.read_metafile(file.clone()) {
+            Ok(body) => {
+                let mut m = HashMap::<String, String>::new();
+                for line in body.lines() {
+                    let mut parts = line.splitn(2, '=');
+                    let key = try!(parts.next()
+                        .and_then(|p| Some(p.trim().to_string()))
+                        .ok_or_else(|| Error::MetaFileMalformed(file.clone())));
+                    let value = try!(parts.next()
+                        .and_then(|p| Some(p.trim().to_string()))
+                        .ok_or_else(|| Error::MetaFileMalformed(file.clone())));
+                    m.insert(key, value);
+                }
+                Ok(m)
+            }
+            Err(Error::MetaFileNotFound(_)) => Ok(HashMap::<String, String>::new()),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Reads metafiles containing dependencies represented by package identifiers separated by new
     /// lines.
     ///
@@ -0,0 +1,208 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The licenses packages are distributed under, and policies restricting which may be installed.
+//!
+//! A license policy is a TOML file, by default `/hab/etc/license-policy.toml`, listing the
+//! licenses which may be installed, those which may not, or both:
+//!
+//! ```toml
+//! allow = ["Apache-2.0", "BSD-3-Clause", "MIT"]
+//! deny = ["AGPL-3.0"]
+//! ```
+//!
+//! A package is allowed when none of its licenses are denied and, if there is an `allow` list,
+//! every one of them is on it. Packages which record no license at all are only allowed when
+//! there is no `allow` list. Licenses are compared without regard to case.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use toml;
+
+use error::{Error, Result};
+use fs;
+use package::PackageIdent;
+
+/// Where the license policy lives, relative to the filesystem root.
+pub const LICENSE_POLICY_PATH: &'static str = "hab/etc/license-policy.toml";
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct LicensePolicy {
+    /// The only licenses packages may be distributed under. Empty to allow any license which
+    /// isn't denied.
+    #[serde(default)]
+    pub allow: Vec<String>,
+    /// Licenses packages may not be distributed under.
+    #[serde(default)]
+    pub deny: Vec<String>,
+}
+
+impl LicensePolicy {
+    /// Returns the path of the license policy, optionally taking a custom filesystem root.
+    pub fn default_path(fs_root_path: Option<&Path>) -> PathBuf {
+        match fs_root_path {
+            Some(fs_root_path) => fs_root_path.join(LICENSE_POLICY_PATH),
+            None => fs::fs_root_path().join(LICENSE_POLICY_PATH),
+        }
+    }
+
+    /// Read the policy at the given path, or return `None` if there is no file there.
+    ///
+    /// # Failures
+    ///
+    /// * The file can't be read
+    /// * The file isn't a valid policy
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Option<Self>> {
+        let path = path.as_ref();
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let mut content = String::new();
+        try!(try!(File::open(path)).read_to_string(&mut content));
+        Self::from_toml(&content).map(Some)
+    }
+
+    pub fn from_toml(content: &str) -> Result<Self> {
+        let mut parser = toml::Parser::new(content);
+        let table = match parser.parse() {
+            Some(table) => table,
+            None => {
+                let errors: Vec<String> = parser.errors.iter().map(|e| e.desc.clone()).collect();
+                return Err(Error::LicensePolicyMalformed(errors.join(", ")));
+            }
+        };
+        match toml::decode(toml::Value::Table(table)) {
+            Some(policy) => Ok(policy),
+            None => {
+                Err(Error::LicensePolicyMalformed(String::from("allow and deny must be lists \
+                                                                of licenses")))
+            }
+        }
+    }
+
+    /// Returns those of a package's licenses which this policy doesn't allow. An empty string
+    /// stands in for a package which records no license but needs one to be allowed.
+    pub fn disallowed(&self, licenses: &[String]) -> Vec<String> {
+        if licenses.is_empty() {
+            return if self.allow.is_empty() {
+                vec![]
+            } else {
+                vec![String::new()]
+            };
+        }
+        licenses.iter()
+            .filter(|l| {
+                contains(&self.deny, l) || (!self.allow.is_empty() && !contains(&self.allow, l))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Check that the given package's licenses are allowed.
+    ///
+    /// # Failures
+    ///
+    /// * One of the licenses is denied or, with an `allow` list, isn't on it
+    /// * The package records no license and there is an `allow` list
+    pub fn check(&self, ident: &PackageIdent, licenses: &[String]) -> Result<()> {
+        let disallowed = self.disallowed(licenses);
+        if disallowed.is_empty() {
+            return Ok(());
+        }
+        let msg = if licenses.is_empty() {
+            format!("{} records no license, and the license policy only allows {}",
+                    ident,
+                    self.allow.join(", "))
+        } else {
+            format!("{} is distributed under {}, which the license policy doesn't allow",
+                    ident,
+                    disallowed.join(", "))
+        };
+        Err(Error::LicenseNotAllowed(msg))
+    }
+}
+
+/// The licenses in a `LICENSES` metafile, one to a line.
+pub fn parse_licenses(body: &str) -> Vec<String> {
+    body.lines().map(|l| l.trim()).filter(|l| !l.is_empty()).map(|l| l.to_string()).collect()
+}
+
+/// The licenses listed in a `MANIFEST`, from its `* __License__: MIT Apache-2.0` line.
+pub fn manifest_licenses(manifest: &str) -> Vec<String> {
+    let prefix = "* __License__:";
+    match manifest.lines().find(|l| l.starts_with(prefix)) {
+        Some(line) => line[prefix.len()..].split_whitespace().map(|l| l.to_string()).collect(),
+        None => vec![],
+    }
+}
+
+fn contains(licenses: &[String], license: &str) -> bool {
+    licenses.iter().any(|l| l.to_lowercase() == license.to_lowercase())
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use package::PackageIdent;
+    use super::{manifest_licenses, parse_licenses, LicensePolicy};
+
+    fn licenses(licenses: &[&str]) -> Vec<String> {
+        licenses.iter().map(|l| l.to_string()).collect()
+    }
+
+    #[test]
+    fn from_toml() {
+        let policy = LicensePolicy::from_toml("allow = [\"MIT\"]\ndeny = [\"AGPL-3.0\"]").unwrap();
+        assert_eq!(policy.allow, licenses(&["MIT"]));
+        assert_eq!(policy.deny, licenses(&["AGPL-3.0"]));
+        assert!(LicensePolicy::from_toml("").unwrap().allow.is_empty());
+        assert!(LicensePolicy::from_toml("allow = \"MIT\"").is_err());
+        assert!(LicensePolicy::from_toml("allow = ").is_err());
+    }
+
+    #[test]
+    fn disallowed_licenses() {
+        let deny = LicensePolicy::from_toml("deny = [\"AGPL-3.0\"]").unwrap();
+        assert!(deny.disallowed(&licenses(&["MIT"])).is_empty());
+        assert!(deny.disallowed(&[]).is_empty());
+        assert_eq!(deny.disallowed(&licenses(&["MIT", "agpl-3.0"])),
+                   licenses(&["agpl-3.0"]));
+
+        let allow = LicensePolicy::from_toml("allow = [\"MIT\", \"Apache-2.0\"]").unwrap();
+        assert!(allow.disallowed(&licenses(&["MIT", "Apache-2.0"])).is_empty());
+        assert_eq!(allow.disallowed(&licenses(&["MIT", "GPL-2.0"])), licenses(&["GPL-2.0"]));
+        assert_eq!(allow.disallowed(&[]), licenses(&[""]));
+    }
+
+    #[test]
+    fn check_names_the_package() {
+        let policy = LicensePolicy::from_toml("deny = [\"GPL-2.0\"]").unwrap();
+        let ident = PackageIdent::from_str("core/busybox/1.24.2/20160708162350").unwrap();
+        assert!(policy.check(&ident, &licenses(&["BSD"])).is_ok());
+        let err = policy.check(&ident, &licenses(&["GPL-2.0"])).unwrap_err();
+        assert!(err.to_string().contains("core/busybox/1.24.2/20160708162350"));
+    }
+
+    #[test]
+    fn licenses_from_metafiles() {
+        assert_eq!(parse_licenses("MIT\nPublic Domain\n"), licenses(&["MIT", "Public Domain"]));
+        let manifest = "# core / redis\n\n* __Maintainer__: The Habitat Maintainers\n\
+                        * __License__: BSD-3-Clause MIT\n";
+        assert_eq!(manifest_licenses(manifest), licenses(&["BSD-3-Clause", "MIT"]));
+        assert!(manifest_licenses("# core / redis\n").is_empty());
+    }
+}
@@ -0,0 +1,241 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A lockfile pins the release of every package a deployment runs.
+//!
+//! It's a TOML file listing the packages that were asked for, the *roots*, and every release
+//! they resolved to along with their transitive dependencies. Since a composite depends on its
+//! services, locking a composite pins its services and their dependencies too:
+//!
+//! ```toml
+//! roots = ["core/redis/3.2.4/20170514150022"]
+//! packages = [
+//!   "core/glibc/2.22/20170513201042",
+//!   "core/redis/3.2.4/20170514150022",
+//! ]
+//! ```
+//!
+//! Installing or running a package against a lockfile resolves it to the release the lockfile
+//! pins, and refuses any release whose dependencies aren't pinned by it.
+
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+use toml;
+
+use error::{Error, Result};
+use package::{Identifiable, PackageIdent};
+
+/// The name of a lockfile, when none is given.
+pub const LOCK_FILE: &'static str = "habitat.lock";
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LockFile {
+    /// The releases the packages asked for resolved to.
+    #[serde(default)]
+    pub roots: Vec<String>,
+    /// Every pinned release, the roots included.
+    #[serde(default)]
+    pub packages: Vec<String>,
+}
+
+impl LockFile {
+    /// Creates a lockfile pinning the given releases. The roots are pinned along with the rest.
+    pub fn new(roots: &[PackageIdent], packages: &[PackageIdent]) -> Self {
+        let roots: BTreeSet<String> = roots.iter().map(|i| i.to_string()).collect();
+        let packages: BTreeSet<String> = packages.iter()
+            .map(|i| i.to_string())
+            .chain(roots.iter().cloned())
+            .collect();
+        LockFile {
+            roots: roots.into_iter().collect(),
+            packages: packages.into_iter().collect(),
+        }
+    }
+
+    /// Read the lockfile at the given path.
+    ///
+    /// # Failures
+    ///
+    /// * The file doesn't exist or can't be read
+    /// * The file isn't a valid lockfile
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let mut file = match File::open(path) {
+            Ok(file) => file,
+            Err(_) => return Err(Error::FileNotFound(path.to_string_lossy().into_owned())),
+        };
+        let mut content = String::new();
+        try!(file.read_to_string(&mut content));
+        Self::from_toml(&content)
+    }
+
+    pub fn from_toml(content: &str) -> Result<Self> {
+        let mut parser = toml::Parser::new(content);
+        let table = match parser.parse() {
+            Some(table) => table,
+            None => {
+                let errors: Vec<String> = parser.errors.iter().map(|e| e.desc.clone()).collect();
+                return Err(Error::LockFileMalformed(errors.join(", ")));
+            }
+        };
+        let lock: LockFile = match toml::decode(toml::Value::Table(table)) {
+            Some(lock) => lock,
+            None => {
+                return Err(Error::LockFileMalformed(String::from("roots and packages must be \
+                                                                  lists of package \
+                                                                  identifiers")))
+            }
+        };
+        for ident in lock.roots.iter().chain(lock.packages.iter()) {
+            match PackageIdent::from_str(ident) {
+                Ok(ref i) if i.fully_qualified() => (),
+                _ => {
+                    return Err(Error::LockFileMalformed(format!("{} isn't a fully qualified \
+                                                                 package identifier",
+                                                                ident)))
+                }
+            }
+        }
+        for root in lock.roots.iter() {
+            if !lock.packages.contains(root) {
+                return Err(Error::LockFileMalformed(format!("root {} isn't among the pinned \
+                                                             packages",
+                                                            root)));
+            }
+        }
+        Ok(lock)
+    }
+
+    pub fn to_toml(&self) -> String {
+        let quote = |s: &String| format!("\"{}\"", s);
+        let mut toml = String::from("# Releases pinned by `hab pkg lock`.\n");
+        toml.push_str(&format!("roots = [{}]\n",
+                               self.roots.iter().map(quote).collect::<Vec<_>>().join(", ")));
+        toml.push_str("packages = [\n");
+        for ident in self.packages.iter() {
+            toml.push_str(&format!("  {},\n", quote(ident)));
+        }
+        toml.push_str("]\n");
+        toml
+    }
+
+    /// Write the lockfile to the given path, replacing any file there.
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let mut file = try!(File::create(path.as_ref()));
+        try!(file.write_all(self.to_toml().as_bytes()));
+        Ok(())
+    }
+
+    /// Returns the pinned releases.
+    pub fn idents(&self) -> Vec<PackageIdent> {
+        self.packages.iter().filter_map(|i| PackageIdent::from_str(i).ok()).collect()
+    }
+
+    /// Returns the release the lockfile pins for the given package. A root is preferred over a
+    /// dependency when both satisfy it; otherwise the newest pinned release is returned.
+    ///
+    /// # Failures
+    ///
+    /// * No pinned release satisfies the given package
+    pub fn release_for(&self, ident: &PackageIdent) -> Result<PackageIdent> {
+        let satisfying = |list: &Vec<String>| {
+            list.iter()
+                .filter_map(|i| PackageIdent::from_str(i).ok())
+                .filter(|i| i.satisfies(ident))
+                .max()
+        };
+        match satisfying(&self.roots).or_else(|| satisfying(&self.packages)) {
+            Some(release) => Ok(release),
+            None => Err(Error::PackageNotLocked(format!("{} isn't pinned by the lockfile", ident))),
+        }
+    }
+
+    /// Checks that every one of the given releases is pinned.
+    ///
+    /// # Failures
+    ///
+    /// * A release isn't pinned by the lockfile, naming each one which isn't
+    pub fn check(&self, idents: &[PackageIdent]) -> Result<()> {
+        let drifted: Vec<String> = idents.iter()
+            .map(|i| i.to_string())
+            .filter(|i| !self.packages.contains(i))
+            .collect();
+        if drifted.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::PackageNotLocked(format!("{} drift from the lockfile",
+                                                drifted.join(", "))))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use package::PackageIdent;
+    use super::LockFile;
+
+    fn ident(s: &str) -> PackageIdent {
+        PackageIdent::from_str(s).unwrap()
+    }
+
+    fn lock() -> LockFile {
+        LockFile::new(&[ident("core/redis/3.2.4/20170514150022")],
+                      &[ident("core/glibc/2.22/20170513201042"),
+                        ident("core/redis/3.2.3/20170101000000"),
+                        ident("core/openssl/1.0.2k/20170513201042")])
+    }
+
+    #[test]
+    fn roundtrips_through_toml() {
+        let lock = lock();
+        assert_eq!(lock.packages.len(), 4);
+        assert_eq!(LockFile::from_toml(&lock.to_toml()).unwrap(), lock);
+    }
+
+    #[test]
+    fn from_toml_rejects_unpinned_idents() {
+        assert!(LockFile::from_toml("packages = [\"core/redis\"]").is_err());
+        assert!(LockFile::from_toml("roots = [\"core/redis/3.2.4/20170514150022\"]").is_err());
+        assert!(LockFile::from_toml("packages = ").is_err());
+    }
+
+    #[test]
+    fn release_for_prefers_roots() {
+        let lock = lock();
+        assert_eq!(lock.release_for(&ident("core/redis")).unwrap(),
+                   ident("core/redis/3.2.4/20170514150022"));
+        assert_eq!(lock.release_for(&ident("core/redis/3.2.3")).unwrap(),
+                   ident("core/redis/3.2.3/20170101000000"));
+        assert_eq!(lock.release_for(&ident("core/glibc")).unwrap(),
+                   ident("core/glibc/2.22/20170513201042"));
+        assert!(lock.release_for(&ident("core/redis/3.0.7")).is_err());
+        assert!(lock.release_for(&ident("core/zlib")).is_err());
+    }
+
+    #[test]
+    fn check_names_drifted_releases() {
+        let lock = lock();
+        assert!(lock.check(&[ident("core/glibc/2.22/20170513201042")]).is_ok());
+        let err = lock.check(&[ident("core/glibc/2.22/20170513201042"),
+                               ident("core/zlib/1.2.8/20170513201911")])
+            .unwrap_err();
+        assert!(err.to_string().contains("core/zlib/1.2.8/20170513201911"));
+    }
+}
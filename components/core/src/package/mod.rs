@@ -12,17 +12,44 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! Habitat packages, from their identifiers to their artifacts and installations.
+//!
+//! * A `PackageIdent` names a package, as `origin/name[/version[/release]]`; one with every part
+//!   is fully qualified, and names exactly one build of a package. A `PackageRequirement` is an
+//!   identifier with a version constraint.
+//! * A `PackageArchive` is a package's artifact, a signed `.hart` file, whose metadata can be
+//!   read and which can be verified and unpacked without installing it.
+//! * A `PackageInstall` is a package installed under the filesystem root.
+//! * An `ArtifactCache` is the directory artifacts are downloaded to before being installed.
+
 pub mod archive;
+pub mod cache;
+pub mod composite;
+pub mod environment;
 pub mod ident;
 pub mod install;
+pub mod license;
+pub mod lock;
 pub mod plan;
+pub mod requirement;
+pub mod scanner;
+pub mod solver;
 pub mod target;
+pub mod vulnerability;
 
 pub use self::archive::{FromArchive, PackageArchive};
+pub use self::cache::ArtifactCache;
+pub use self::composite::{CompositeService, PackageType};
+pub use self::environment::PackageEnv;
 pub use self::ident::{Identifiable, PackageIdent};
 pub use self::install::PackageInstall;
+pub use self::license::LicensePolicy;
+pub use self::lock::LockFile;
 pub use self::plan::Plan;
+pub use self::requirement::{PackageRequirement, VersionConstraint};
+pub use self::scanner::ArtifactScanner;
 pub use self::target::{Target, PackageTarget};
+pub use self::vulnerability::{Vulnerability, VulnerabilityDb};
 
 use std::fmt;
 
@@ -32,16 +59,34 @@ pub enum MetaFile {
     Config,
     Deps,
     TDeps,
+    Environment,
+    EnvironmentSep,
     Exports,
     Exposes,
     Ident,
     LdRunPath,
     LdFlags,
+    Licenses,
     Manifest,
     Path,
+    Sbom,
+    Services,
+    SvcCapabilities,
     SvcUser,
     SvcGroup,
+    SvcDataVersion,
+    SvcDirs,
+    SvcExitCodes,
+    SvcHealthProbe,
+    SvcHookInterpreter,
+    SvcHookSandbox,
+    SvcLimits,
+    SvcScheduledHooks,
+    SvcSeccomp,
+    SvcTemplateEngine,
+    SvcUmask,
     Target,
+    Type,
 }
 
 impl fmt::Display for MetaFile {
@@ -51,16 +96,34 @@ impl fmt::Display for MetaFile {
             MetaFile::Config => "default.toml",
             MetaFile::Deps => "DEPS",
             MetaFile::TDeps => "TDEPS",
+            MetaFile::Environment => "ENVIRONMENT",
+            MetaFile::EnvironmentSep => "ENVIRONMENT_SEP",
             MetaFile::Exports => "EXPORTS",
             MetaFile::Exposes => "EXPOSES",
             MetaFile::Ident => "IDENT",
             MetaFile::LdRunPath => "LD_RUN_PATH",
             MetaFile::LdFlags => "LDFLAGS",
+            MetaFile::Licenses => "LICENSES",
             MetaFile::Manifest => "MANIFEST",
             MetaFile::Path => "PATH",
+            MetaFile::Sbom => "SBOM",
+            MetaFile::Services => "SERVICES",
+            MetaFile::SvcCapabilities => "SVC_CAPABILITIES",
             MetaFile::SvcUser => "SVC_USER",
             MetaFile::SvcGroup => "SVC_GROUP",
+            MetaFile::SvcDataVersion => "SVC_DATA_VERSION",
+            MetaFile::SvcDirs => "SVC_DIRS",
+            MetaFile::SvcExitCodes => "SVC_EXIT_CODES",
+            MetaFile::SvcHealthProbe => "SVC_HEALTH_PROBE",
+            MetaFile::SvcHookInterpreter => "SVC_HOOK_INTERPRETER",
+            MetaFile::SvcHookSandbox => "SVC_HOOK_SANDBOX",
+            MetaFile::SvcLimits => "SVC_LIMITS",
+            MetaFile::SvcScheduledHooks => "SVC_SCHEDULED_HOOKS",
+            MetaFile::SvcSeccomp => "SVC_SECCOMP",
+            MetaFile::SvcTemplateEngine => "SVC_TEMPLATE_ENGINE",
+            MetaFile::SvcUmask => "SVC_UMASK",
             MetaFile::Target => "TARGET",
+            MetaFile::Type => "TYPE",
         };
         write!(f, "{}", id)
     }
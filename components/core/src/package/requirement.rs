@@ -0,0 +1,347 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Package requirements, which name a package along with constraints on the versions of it which
+//! satisfy a dependency, such as `core/glibc >=2.27 <3`.
+//!
+//! A constraint is a list of comparisons which a version must all pass. The operators are `=`,
+//! `>`, `>=`, `<` and `<=`, plus `~1.2`, which allows any version from `1.2` up to but not
+//! including `1.3`, and `^1.2`, which allows any version from `1.2` up to but not including
+//! `2`. A comparison without an operator is an `=` comparison. Versions are compared with
+//! `version_cmp`, so letters after the version number count (`1.0.2j` is older than `1.0.2k`),
+//! and a version which can't be compared is an error rather than a failed comparison.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::result;
+use std::str::FromStr;
+
+use error::{Error, Result};
+use package::{Identifiable, PackageIdent};
+use package::ident::version_cmp;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    Tilde,
+    Caret,
+}
+
+impl Op {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            Op::Eq => "=",
+            Op::Gt => ">",
+            Op::Ge => ">=",
+            Op::Lt => "<",
+            Op::Le => "<=",
+            Op::Tilde => "~",
+            Op::Caret => "^",
+        }
+    }
+}
+
+/// A single comparison against a version, such as `>=2.27`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Comparator {
+    op: Op,
+    version: String,
+}
+
+impl Comparator {
+    fn matches(&self, version: &str) -> Result<bool> {
+        let ordering = try!(version_cmp(version, &self.version));
+        let matches = match self.op {
+            Op::Eq => ordering == Ordering::Equal,
+            Op::Gt => ordering == Ordering::Greater,
+            Op::Ge => ordering != Ordering::Less,
+            Op::Lt => ordering == Ordering::Less,
+            Op::Le => ordering != Ordering::Greater,
+            Op::Tilde | Op::Caret => {
+                ordering != Ordering::Less &&
+                try!(version_cmp(version, &self.upper_bound())) == Ordering::Less
+            }
+        };
+        Ok(matches)
+    }
+
+    /// Returns the first version a `~` or `^` comparison no longer allows.
+    fn upper_bound(&self) -> String {
+        let number = self.version
+            .split(|c: char| !(c.is_digit(10) || c == '.'))
+            .next()
+            .unwrap_or("");
+        let mut parts: Vec<u64> = number.split('.')
+            .filter(|p| !p.is_empty())
+            .map(|p| p.parse().unwrap_or(0))
+            .collect();
+        if parts.is_empty() {
+            parts.push(0);
+        }
+        let bump = match self.op {
+            // `~1.2.3` and `~1.2` allow patch releases, `~1` allows minor releases
+            Op::Tilde => if parts.len() > 1 { 1 } else { 0 },
+            // `^1.2.3` allows anything short of the next major version, but for a `0` major
+            // version the first non-zero part is the one which mustn't change
+            _ => {
+                parts.iter()
+                    .position(|p| *p != 0)
+                    .unwrap_or(parts.len() - 1)
+            }
+        };
+        parts.truncate(bump + 1);
+        parts[bump] += 1;
+        parts.iter().map(|p| p.to_string()).collect::<Vec<String>>().join(".")
+    }
+}
+
+impl fmt::Display for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.op.as_str(), self.version)
+    }
+}
+
+impl FromStr for Comparator {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        let ops = [(">=", Op::Ge), ("<=", Op::Le), (">", Op::Gt), ("<", Op::Lt), ("=", Op::Eq),
+                   ("~", Op::Tilde), ("^", Op::Caret)];
+        let (op, version) = match ops.iter().find(|&&(prefix, _)| value.starts_with(prefix)) {
+            Some(&(prefix, op)) => (op, &value[prefix.len()..]),
+            None => (Op::Eq, value),
+        };
+        if version_cmp(version, version).is_err() {
+            return Err(Error::InvalidVersionConstraint(value.to_string()));
+        }
+        Ok(Comparator {
+            op: op,
+            version: version.to_string(),
+        })
+    }
+}
+
+/// The versions of a package which satisfy a dependency. An empty constraint allows any version.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VersionConstraint {
+    comparators: Vec<Comparator>,
+}
+
+impl VersionConstraint {
+    pub fn is_empty(&self) -> bool {
+        self.comparators.is_empty()
+    }
+
+    /// Returns whether `version` passes every comparison.
+    ///
+    /// # Failures
+    ///
+    /// * The version can't be compared, see `version_cmp`
+    pub fn matches(&self, version: &str) -> Result<bool> {
+        for comparator in self.comparators.iter() {
+            if !try!(comparator.matches(version)) {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+impl fmt::Display for VersionConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let comparators: Vec<String> = self.comparators.iter().map(|c| c.to_string()).collect();
+        write!(f, "{}", comparators.join(" "))
+    }
+}
+
+impl FromStr for VersionConstraint {
+    type Err = Error;
+
+    /// Parses comparisons separated by whitespace or commas, such as `>=2.27 <3`.
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        let mut comparators = Vec::new();
+        for comparison in value.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|c| !c.is_empty()) {
+            comparators.push(try!(comparison.parse()));
+        }
+        Ok(VersionConstraint { comparators: comparators })
+    }
+}
+
+/// A package identifier, which may be fuzzy, along with a constraint on its version.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PackageRequirement {
+    pub ident: PackageIdent,
+    pub constraint: VersionConstraint,
+}
+
+impl PackageRequirement {
+    /// Returns whether `ident` names the same package as this requirement, regardless of whether
+    /// it's a release which satisfies it.
+    pub fn names<I: Identifiable>(&self, ident: &I) -> bool {
+        self.ident.origin() == ident.origin() && self.ident.name() == ident.name()
+    }
+
+    /// Returns whether the release `ident` satisfies this requirement.
+    ///
+    /// # Failures
+    ///
+    /// * The requirement has a constraint, and the release's version can't be compared with it
+    pub fn satisfied_by<I: Identifiable>(&self, ident: &I) -> Result<bool> {
+        if !ident.satisfies(&self.ident) {
+            return Ok(false);
+        }
+        match ident.version() {
+            Some(version) if !self.constraint.is_empty() => self.constraint.matches(version),
+            Some(_) => Ok(true),
+            None => Ok(self.constraint.is_empty()),
+        }
+    }
+}
+
+impl fmt::Display for PackageRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.constraint.is_empty() {
+            write!(f, "{}", self.ident)
+        } else {
+            write!(f, "{} {}", self.ident, self.constraint)
+        }
+    }
+}
+
+impl From<PackageIdent> for PackageRequirement {
+    fn from(ident: PackageIdent) -> Self {
+        PackageRequirement {
+            ident: ident,
+            constraint: VersionConstraint::default(),
+        }
+    }
+}
+
+impl FromStr for PackageRequirement {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        let value = value.trim();
+        let (ident, constraint) = match value.find(char::is_whitespace) {
+            Some(i) => (&value[..i], &value[i..]),
+            None => (value, ""),
+        };
+        Ok(PackageRequirement {
+            ident: try!(ident.parse()),
+            constraint: try!(constraint.parse()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use package::PackageIdent;
+    use super::{PackageRequirement, VersionConstraint};
+
+    fn constraint(value: &str) -> VersionConstraint {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn range_constraint() {
+        let c = constraint(">=2.27 <3");
+        assert!(c.matches("2.27").unwrap());
+        assert!(c.matches("2.30.1").unwrap());
+        assert!(!c.matches("2.26").unwrap());
+        assert!(!c.matches("3.0").unwrap());
+    }
+
+    #[test]
+    fn bare_version_is_exact() {
+        let c = constraint("1.2.3");
+        assert!(c.matches("1.2.3").unwrap());
+        assert!(!c.matches("1.2.4").unwrap());
+    }
+
+    #[test]
+    fn tilde_constraint() {
+        let c = constraint("~1.2.3");
+        assert!(c.matches("1.2.9").unwrap());
+        assert!(!c.matches("1.3.0").unwrap());
+        assert!(constraint("~1").matches("1.9").unwrap());
+        assert!(!constraint("~1").matches("2.0").unwrap());
+    }
+
+    #[test]
+    fn caret_constraint() {
+        let c = constraint("^1.2");
+        assert!(c.matches("1.9.0").unwrap());
+        assert!(!c.matches("2.0.0").unwrap());
+        assert!(!c.matches("1.1").unwrap());
+        assert!(constraint("^0.2.3").matches("0.2.9").unwrap());
+        assert!(!constraint("^0.2.3").matches("0.3.0").unwrap());
+    }
+
+    #[test]
+    fn commas_separate_comparisons() {
+        assert_eq!(constraint(">=1, <2"), constraint(">=1 <2"));
+    }
+
+    #[test]
+    fn invalid_constraint() {
+        assert!(">=".parse::<VersionConstraint>().is_err());
+        assert!(">=banana".parse::<VersionConstraint>().is_err());
+        assert!("1.0 v2".parse::<VersionConstraint>().is_err());
+    }
+
+    #[test]
+    fn letter_suffixes_are_compared() {
+        let c = constraint(">=1.0.2k");
+        assert!(!c.matches("1.0.2j").unwrap());
+        assert!(c.matches("1.0.2k").unwrap());
+        assert!(c.matches("1.0.2za").unwrap());
+        assert!(constraint("~1.0.2k").matches("1.0.9").unwrap());
+        assert!(!constraint("~1.0.2k").matches("1.1.0").unwrap());
+    }
+
+    #[test]
+    fn unreadable_versions_are_errors() {
+        assert!(constraint(">=2.27").matches("unstable").is_err());
+        let req: PackageRequirement = "core/glibc >=2.27".parse().unwrap();
+        let odd = PackageIdent::new("core", "glibc", Some("master"), Some("20170513201042"));
+        assert!(req.satisfied_by(&odd).is_err());
+        let any: PackageRequirement = "core/glibc".parse().unwrap();
+        assert!(any.satisfied_by(&odd).unwrap());
+    }
+
+    #[test]
+    fn parse_requirement() {
+        let req: PackageRequirement = "core/glibc >=2.27 <3".parse().unwrap();
+        assert_eq!(req.ident, PackageIdent::new("core", "glibc", None, None));
+        assert_eq!(req.to_string(), "core/glibc >=2.27 <3");
+        let req: PackageRequirement = "core/glibc".parse().unwrap();
+        assert!(req.constraint.is_empty());
+    }
+
+    #[test]
+    fn requirement_satisfied_by() {
+        let req: PackageRequirement = "core/glibc >=2.27 <3".parse().unwrap();
+        let ok = PackageIdent::new("core", "glibc", Some("2.27"), Some("20170513201042"));
+        let old = PackageIdent::new("core", "glibc", Some("2.22"), Some("20170513201042"));
+        let other = PackageIdent::new("acme", "glibc", Some("2.27"), Some("20170513201042"));
+        assert!(req.satisfied_by(&ok).unwrap());
+        assert!(!req.satisfied_by(&old).unwrap());
+        assert!(!req.satisfied_by(&other).unwrap());
+    }
+}
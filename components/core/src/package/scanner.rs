@@ -0,0 +1,194 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An external command which scans artifacts before they are installed.
+//!
+//! Hosts which must scan everything they run for malware or leaked secrets can name a scanner in
+//! a TOML file, by default `/hab/etc/artifact-scanner.toml`:
+//!
+//! ```toml
+//! command = "/usr/bin/clamscan"
+//! args = ["--no-summary"]
+//! ```
+//!
+//! Every artifact is passed to the scanner, as its last argument, before it is unpacked. The
+//! package's identifier is in its `HAB_ARTIFACT_IDENT` environment variable. An artifact the
+//! scanner exits nonzero for is rejected, unless `warn_only = true`, in which case the finding is
+//! reported and the artifact is installed anyway. A scanner which can't be run rejects every
+//! artifact.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use toml;
+
+use error::{Error, Result};
+use fs;
+use package::PackageIdent;
+
+/// Where the artifact scanner is configured, relative to the filesystem root.
+pub const ARTIFACT_SCANNER_PATH: &'static str = "hab/etc/artifact-scanner.toml";
+
+/// The environment variable holding the identifier of the package being scanned.
+pub const ARTIFACT_IDENT_ENVVAR: &'static str = "HAB_ARTIFACT_IDENT";
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ArtifactScanner {
+    /// The scanner to run.
+    pub command: String,
+    /// Arguments given to the scanner before the artifact's path.
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Whether an artifact the scanner exits nonzero for is installed anyway, with a warning.
+    #[serde(default)]
+    pub warn_only: bool,
+}
+
+impl ArtifactScanner {
+    /// Returns the path of the scanner's configuration, optionally taking a custom filesystem
+    /// root.
+    pub fn default_path(fs_root_path: Option<&Path>) -> PathBuf {
+        match fs_root_path {
+            Some(fs_root_path) => fs_root_path.join(ARTIFACT_SCANNER_PATH),
+            None => fs::fs_root_path().join(ARTIFACT_SCANNER_PATH),
+        }
+    }
+
+    /// Read the scanner configured at the given path, or return `None` if there is no file there.
+    ///
+    /// # Failures
+    ///
+    /// * The file can't be read
+    /// * The file doesn't configure a scanner
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Option<Self>> {
+        let path = path.as_ref();
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let mut content = String::new();
+        try!(try!(File::open(path)).read_to_string(&mut content));
+        Self::from_toml(&content).map(Some)
+    }
+
+    pub fn from_toml(content: &str) -> Result<Self> {
+        let mut parser = toml::Parser::new(content);
+        let table = match parser.parse() {
+            Some(table) => table,
+            None => {
+                let errors: Vec<String> = parser.errors.iter().map(|e| e.desc.clone()).collect();
+                return Err(Error::ArtifactScannerMalformed(errors.join(", ")));
+            }
+        };
+        match toml::decode::<ArtifactScanner>(toml::Value::Table(table)) {
+            Some(ref scanner) if scanner.command.is_empty() => {
+                Err(Error::ArtifactScannerMalformed(String::from("command can't be empty")))
+            }
+            Some(scanner) => Ok(scanner),
+            None => {
+                Err(Error::ArtifactScannerMalformed(String::from("command must be a string, \
+                                                                  args a list of strings and \
+                                                                  warn_only true or false")))
+            }
+        }
+    }
+
+    /// Scan the artifact of the given package. Returns what the scanner found if it exited
+    /// nonzero and `warn_only` is set, and nothing if the artifact is clean.
+    ///
+    /// # Failures
+    ///
+    /// * The scanner can't be run
+    /// * The scanner exits nonzero, and `warn_only` isn't set
+    pub fn scan(&self, ident: &PackageIdent, artifact_path: &Path) -> Result<Option<String>> {
+        debug!("Scanning {} with {}", artifact_path.display(), self.command);
+        let output = match Command::new(&self.command)
+            .args(&self.args)
+            .arg(artifact_path)
+            .env(ARTIFACT_IDENT_ENVVAR, ident.to_string())
+            .output() {
+            Ok(output) => output,
+            Err(e) => {
+                let msg = format!("Can't scan {}: running {} failed: {}",
+                                  ident,
+                                  self.command,
+                                  e);
+                return Err(Error::ArtifactRejected(msg));
+            }
+        };
+        if output.status.success() {
+            return Ok(None);
+        }
+        let mut finding = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !stderr.trim().is_empty() {
+            if !finding.is_empty() {
+                finding.push('\n');
+            }
+            finding.push_str(stderr.trim());
+        }
+        let msg = format!("{} failed the scan by {} ({}){}{}",
+                          ident,
+                          self.command,
+                          output.status,
+                          if finding.is_empty() { "" } else { ":\n" },
+                          finding);
+        if self.warn_only {
+            Ok(Some(msg))
+        } else {
+            Err(Error::ArtifactRejected(msg))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use package::PackageIdent;
+    use super::ArtifactScanner;
+
+    #[test]
+    fn from_toml() {
+        let scanner = ArtifactScanner::from_toml("command = \"/usr/bin/clamscan\"\n\
+                                                  args = [\"--no-summary\"]")
+            .unwrap();
+        assert_eq!(scanner.command, "/usr/bin/clamscan");
+        assert_eq!(scanner.args, vec![String::from("--no-summary")]);
+        assert!(!scanner.warn_only);
+        assert!(ArtifactScanner::from_toml("").is_err());
+        assert!(ArtifactScanner::from_toml("command = \"\"").is_err());
+        assert!(ArtifactScanner::from_toml("command = [\"clamscan\"]").is_err());
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn scan_exit_status() {
+        let ident = PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap();
+        let artifact = Path::new("/hab/cache/artifacts/core-redis.hart");
+        let clean = ArtifactScanner::from_toml("command = \"true\"").unwrap();
+        assert_eq!(clean.scan(&ident, artifact).unwrap(), None);
+
+        let mut dirty = ArtifactScanner::from_toml("command = \"false\"").unwrap();
+        let err = dirty.scan(&ident, artifact).unwrap_err();
+        assert!(err.to_string().contains("core/redis/3.2.4/20170514150022"));
+        dirty.warn_only = true;
+        assert!(dirty.scan(&ident, artifact).unwrap().is_some());
+
+        let missing = ArtifactScanner::from_toml("command = \"/nonexistent/scanner\"").unwrap();
+        assert!(missing.scan(&ident, artifact).is_err());
+    }
+}
@@ -0,0 +1,333 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Resolves a set of package requirements to releases which satisfy them together.
+//!
+//! Each requirement resolves to one release, newest first. A release was built against exact
+//! releases of its dependencies, so choosing it brings its whole transitive closure along; a set
+//! of choices satisfies the requirements when every release in their closure which a requirement
+//! names satisfies that requirement. When a choice leaves a later requirement with no release to
+//! choose, the search backs up and tries the next newest release, so that, say, `core/curl` falls
+//! back to an older release when its newest was built against a `core/openssl` which another
+//! requirement rules out. If no set of choices works, the error explains which releases
+//! conflicted with which requirements.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::result;
+
+use error::{Error, Result};
+use package::{Identifiable, PackageIdent, PackageInstall};
+use package::requirement::PackageRequirement;
+
+/// The most releases of a package to list when explaining why none of them would do.
+const MAX_LISTED_RELEASES: usize = 10;
+
+/// Somewhere releases of packages come from, such as the packages installed on this system or
+/// a depot.
+pub trait PackageUniverse {
+    /// The error looking up releases fails with, which the solver's own errors convert into.
+    type Error: From<Error>;
+
+    /// Returns the fully qualified identifiers of the releases of the package `ident` names.
+    fn releases(&self, ident: &PackageIdent) -> result::Result<Vec<PackageIdent>, Self::Error>;
+
+    /// Returns the transitive dependencies of a release.
+    fn tdeps(&self, ident: &PackageIdent) -> result::Result<Vec<PackageIdent>, Self::Error>;
+}
+
+/// The packages installed under a filesystem root.
+pub struct InstalledPackages {
+    fs_root_path: PathBuf,
+    installed: RefCell<Option<Vec<PackageIdent>>>,
+}
+
+impl InstalledPackages {
+    pub fn new(fs_root_path: &Path) -> Self {
+        InstalledPackages {
+            fs_root_path: fs_root_path.to_path_buf(),
+            installed: RefCell::new(None),
+        }
+    }
+}
+
+impl PackageUniverse for InstalledPackages {
+    type Error = Error;
+
+    fn releases(&self, ident: &PackageIdent) -> Result<Vec<PackageIdent>> {
+        let mut installed = self.installed.borrow_mut();
+        if installed.is_none() {
+            *installed = Some(try!(PackageInstall::installed_idents(Some(&self.fs_root_path))));
+        }
+        Ok(installed.as_ref()
+            .unwrap()
+            .iter()
+            .filter(|i| i.satisfies(ident))
+            .cloned()
+            .collect())
+    }
+
+    fn tdeps(&self, ident: &PackageIdent) -> Result<Vec<PackageIdent>> {
+        try!(PackageInstall::load(ident, Some(&self.fs_root_path))).tdeps()
+    }
+}
+
+/// Returns the release chosen for each of the requirements, in the same order. Requirements
+/// which name the same package resolve to the same release.
+pub fn solve<U: PackageUniverse>(universe: &U,
+                                 requirements: &[PackageRequirement])
+                                 -> result::Result<Vec<PackageIdent>, U::Error> {
+    let mut candidates = Vec::with_capacity(requirements.len());
+    for requirement in requirements {
+        let mut releases = try!(universe.releases(&requirement.ident));
+        releases.sort();
+        releases.dedup();
+        let available: Vec<String> = releases.iter()
+            .rev()
+            .filter_map(|r| r.version.clone())
+            .collect();
+        // Every requirement naming the package constrains the release chosen for it. A release
+        // whose version can't be compared with a constraint fails the solve rather than being
+        // quietly passed over.
+        let mut satisfying = Vec::with_capacity(releases.len());
+        for release in releases.into_iter() {
+            if try!(satisfies_all(requirements, &release)) {
+                satisfying.push(release);
+            }
+        }
+        let mut releases = satisfying;
+        if releases.is_empty() {
+            let msg = no_release(requirement, requirements, available);
+            return Err(U::Error::from(Error::UnsatisfiableRequirements(msg)));
+        }
+        releases.reverse();
+        candidates.push(releases);
+    }
+
+    let mut search = Search {
+        universe: universe,
+        requirements: requirements,
+        candidates: &candidates,
+        tdeps: HashMap::new(),
+        conflicts: Vec::new(),
+    };
+    let mut chosen = Vec::with_capacity(requirements.len());
+    if try!(search.choose(&mut chosen)) {
+        return Ok(chosen);
+    }
+    let mut msg = String::from("No combination of releases satisfies every requirement:");
+    for conflict in search.conflicts {
+        msg.push_str("\n  ");
+        msg.push_str(&conflict);
+    }
+    Err(U::Error::from(Error::UnsatisfiableRequirements(msg)))
+}
+
+struct Search<'a, U: 'a + PackageUniverse> {
+    universe: &'a U,
+    requirements: &'a [PackageRequirement],
+    candidates: &'a [Vec<PackageIdent>],
+    tdeps: HashMap<PackageIdent, Vec<PackageIdent>>,
+    conflicts: Vec<String>,
+}
+
+impl<'a, U: PackageUniverse> Search<'a, U> {
+    /// Chooses a release for the next requirement without one, and the requirements after it,
+    /// returning whether it managed to.
+    fn choose(&mut self, chosen: &mut Vec<PackageIdent>) -> result::Result<bool, U::Error> {
+        let index = chosen.len();
+        if index == self.requirements.len() {
+            return Ok(true);
+        }
+        let requirements = self.requirements;
+        let candidates = self.candidates;
+        // A package named by an earlier requirement already has its release.
+        let earlier = requirements[..index]
+            .iter()
+            .position(|q| q.names(&requirements[index].ident))
+            .map(|i| chosen[i].clone());
+        for candidate in candidates[index].iter() {
+            if let Some(ref earlier) = earlier {
+                if earlier != candidate {
+                    continue;
+                }
+            }
+            if !try!(self.closure_satisfies(candidate)) {
+                continue;
+            }
+            chosen.push(candidate.clone());
+            if try!(self.choose(chosen)) {
+                return Ok(true);
+            }
+            chosen.pop();
+        }
+        Ok(false)
+    }
+
+    /// Returns whether every release `candidate` depends on satisfies the requirements naming
+    /// it, noting the conflict if one doesn't.
+    fn closure_satisfies(&mut self,
+                         candidate: &PackageIdent)
+                         -> result::Result<bool, U::Error> {
+        if !self.tdeps.contains_key(candidate) {
+            let tdeps = try!(self.universe.tdeps(candidate));
+            self.tdeps.insert(candidate.clone(), tdeps);
+        }
+        for tdep in self.tdeps[candidate].iter() {
+            for requirement in self.requirements.iter().filter(|q| q.names(tdep)) {
+                if try!(requirement.satisfied_by(tdep)) {
+                    continue;
+                }
+                let conflict = format!("{} depends on {}, which doesn't satisfy {}",
+                                       candidate,
+                                       tdep,
+                                       requirement);
+                if !self.conflicts.contains(&conflict) {
+                    self.conflicts.push(conflict);
+                }
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Returns whether `release` satisfies every requirement naming its package.
+fn satisfies_all(requirements: &[PackageRequirement], release: &PackageIdent) -> Result<bool> {
+    for requirement in requirements.iter().filter(|q| q.names(release)) {
+        if !try!(requirement.satisfied_by(release)) {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Explains why no release of the package `requirement` names satisfies every requirement
+/// naming it.
+fn no_release(requirement: &PackageRequirement,
+              requirements: &[PackageRequirement],
+              available: Vec<String>)
+              -> String {
+    let naming: Vec<String> = requirements.iter()
+        .filter(|q| q.names(&requirement.ident))
+        .map(|q| q.to_string())
+        .collect();
+    let mut msg = format!("No release of {}/{} satisfies {}",
+                          requirement.ident.origin,
+                          requirement.ident.name,
+                          naming.join(" and "));
+    if available.is_empty() {
+        msg.push_str("; there are no releases of it");
+    } else {
+        let mut versions: Vec<String> = Vec::new();
+        for version in available {
+            if !versions.contains(&version) {
+                versions.push(version);
+            }
+        }
+        let more = versions.len() > MAX_LISTED_RELEASES;
+        versions.truncate(MAX_LISTED_RELEASES);
+        msg.push_str(&format!("; available versions are {}{}",
+                              versions.join(", "),
+                              if more { ", ..." } else { "" }));
+    }
+    msg
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use error::{Error, Result};
+    use package::{Identifiable, PackageIdent};
+    use package::requirement::PackageRequirement;
+    use super::{solve, PackageUniverse};
+
+    struct Universe {
+        tdeps: HashMap<PackageIdent, Vec<PackageIdent>>,
+    }
+
+    impl Universe {
+        fn new(releases: &[(&str, &[&str])]) -> Self {
+            let mut tdeps = HashMap::new();
+            for &(release, deps) in releases {
+                tdeps.insert(release.parse().unwrap(),
+                             deps.iter().map(|d| d.parse().unwrap()).collect());
+            }
+            Universe { tdeps: tdeps }
+        }
+    }
+
+    impl PackageUniverse for Universe {
+        type Error = Error;
+
+        fn releases(&self, ident: &PackageIdent) -> Result<Vec<PackageIdent>> {
+            Ok(self.tdeps.keys().filter(|r| r.satisfies(ident)).cloned().collect())
+        }
+
+        fn tdeps(&self, ident: &PackageIdent) -> Result<Vec<PackageIdent>> {
+            Ok(self.tdeps[ident].clone())
+        }
+    }
+
+    fn universe() -> Universe {
+        Universe::new(&[("core/openssl/1.0.2/20170101000000", &[]),
+                        ("core/openssl/1.1.0/20170601000000", &[]),
+                        ("core/curl/7.52.0/20170101000000",
+                         &["core/openssl/1.0.2/20170101000000"]),
+                        ("core/curl/7.54.0/20170601000000",
+                         &["core/openssl/1.1.0/20170601000000"])])
+    }
+
+    fn requirements(reqs: &[&str]) -> Vec<PackageRequirement> {
+        reqs.iter().map(|r| r.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn chooses_newest_release() {
+        let solved = solve(&universe(), &requirements(&["core/curl"])).unwrap();
+        assert_eq!(solved[0].to_string(), "core/curl/7.54.0/20170601000000");
+    }
+
+    #[test]
+    fn backs_up_to_satisfy_closure() {
+        let solved = solve(&universe(), &requirements(&["core/curl", "core/openssl <1.1"]))
+            .unwrap();
+        assert_eq!(solved[0].to_string(), "core/curl/7.52.0/20170101000000");
+        assert_eq!(solved[1].to_string(), "core/openssl/1.0.2/20170101000000");
+    }
+
+    #[test]
+    fn explains_unsatisfiable_constraint() {
+        match solve(&universe(), &requirements(&["core/openssl >=2"])) {
+            Err(Error::UnsatisfiableRequirements(msg)) => {
+                assert!(msg.contains("core/openssl >=2"));
+                assert!(msg.contains("1.1.0, 1.0.2"));
+            }
+            other => panic!("expected unsatisfiable requirements, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn explains_closure_conflict() {
+        match solve(&universe(), &requirements(&["core/curl >=7.54", "core/openssl <1.1"])) {
+            Err(Error::UnsatisfiableRequirements(msg)) => {
+                assert!(msg.contains("core/curl/7.54.0/20170601000000 depends on \
+                                      core/openssl/1.1.0/20170601000000"));
+            }
+            other => panic!("expected unsatisfiable requirements, got {:?}", other),
+        }
+    }
+}
@@ -51,6 +51,10 @@ impl PackageTarget {
             Platform::Linux
         } else if cfg!(target_os = "macos") {
             Platform::MacOS
+        } else if cfg!(target_os = "freebsd") {
+            Platform::FreeBSD
+        } else if cfg!(any(target_os = "solaris", target_os = "illumos")) {
+            Platform::Illumos
         } else {
             unreachable!("binary built for an unknown platform")
         }
@@ -127,6 +131,10 @@ mod tests {
             assert_eq!(target.platform, Platform::Linux);
         } else if cfg!(target_os = "macos") {
             assert_eq!(target.platform, Platform::MacOS);
+        } else if cfg!(target_os = "freebsd") {
+            assert_eq!(target.platform, Platform::FreeBSD);
+        } else if cfg!(any(target_os = "solaris", target_os = "illumos")) {
+            assert_eq!(target.platform, Platform::Illumos);
         } else {
             unreachable!("Platform not defined for target_os! Fix this by adding a conditional \
                           compilation to PackageTarget::current_platform()");
@@ -177,6 +185,10 @@ mod tests {
             PackageTarget::from_str("x86_64-linux").unwrap()
         } else if cfg!(target_os = "macos") {
             PackageTarget::from_str("x86_64-macos").unwrap()
+        } else if cfg!(target_os = "freebsd") {
+            PackageTarget::from_str("x86_64-freebsd").unwrap()
+        } else if cfg!(any(target_os = "solaris", target_os = "illumos")) {
+            PackageTarget::from_str("x86_64-illumos").unwrap()
         } else {
             unreachable!("Test case not defined for target_os! Fix this by adding a conditional \
                           compilation to tests::current_platform_target()");
@@ -188,7 +200,7 @@ mod tests {
             PackageTarget::from_str("x86_64-linux").unwrap()
         } else if cfg!(target_os = "linux") {
             PackageTarget::from_str("x86_64-windows").unwrap()
-        } else if cfg!(target_os = "macos") {
+        } else if cfg!(unix) {
             PackageTarget::from_str("x86_64-windows").unwrap()
         } else {
             unreachable!("Test case not defined for target_os! Fix this by adding a conditional \
@@ -0,0 +1,224 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A database of known vulnerabilities in packages.
+//!
+//! The database is a TOML file kept in the vulnerability cache, so it can be updated by copying a
+//! new one onto a host which has no network access. Each entry names a package by origin and
+//! name, along with the range of versions it affects:
+//!
+//! ```toml
+//! updated = "2017-05-01"
+//!
+//! [[vulnerability]]
+//! id = "CVE-2016-2105"
+//! package = "core/openssl"
+//! introduced = "1.0.0"
+//! fixed = "1.0.2"
+//! severity = "high"
+//! summary = "EVP_EncodeUpdate overflow"
+//! ```
+//!
+//! A vulnerability affects every version from `introduced` up to, but not including, `fixed`.
+//! Either may be left out to leave that end of the range open. Versions are compared with
+//! `version_cmp`, so letters after the version number count: `1.0.2j` comes before `1.0.2k`.
+
+use std::cmp::Ordering;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use toml;
+
+use error::{Error, Result};
+use fs::cache_vulndb_path;
+use package::PackageIdent;
+use package::ident::version_cmp;
+
+/// The name of the database file in the vulnerability cache.
+pub const VULNERABILITY_DB_FILE: &'static str = "vulnerabilities.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Vulnerability {
+    pub id: String,
+    /// The `origin/name` of the affected package.
+    pub package: String,
+    pub introduced: Option<String>,
+    pub fixed: Option<String>,
+    pub severity: String,
+    pub summary: String,
+}
+
+impl Vulnerability {
+    /// Returns true if the given package is a version this vulnerability affects. A version which
+    /// can't be compared with the range is taken to be affected, as is a package without a
+    /// version, since missing a vulnerability is worse than reporting one which isn't there.
+    pub fn affects(&self, ident: &PackageIdent) -> bool {
+        if self.package != format!("{}/{}", ident.origin, ident.name) {
+            return false;
+        }
+        let version = match ident.version {
+            Some(ref version) => version,
+            None => return true,
+        };
+        if let Some(ref introduced) = self.introduced {
+            if let Ok(Ordering::Less) = version_cmp(version, introduced) {
+                return false;
+            }
+        }
+        if let Some(ref fixed) = self.fixed {
+            match version_cmp(version, fixed) {
+                Ok(Ordering::Less) | Err(_) => (),
+                Ok(_) => return false,
+            }
+        }
+        true
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct VulnerabilityDb {
+    /// When the database was last updated, as given by whoever published it.
+    pub updated: Option<String>,
+    #[serde(default, rename = "vulnerability")]
+    pub vulnerabilities: Vec<Vulnerability>,
+}
+
+impl VulnerabilityDb {
+    /// Returns the path of the database in the vulnerability cache, optionally taking a custom
+    /// filesystem root.
+    pub fn default_path(fs_root_path: Option<&Path>) -> PathBuf {
+        cache_vulndb_path(fs_root_path).join(VULNERABILITY_DB_FILE)
+    }
+
+    /// Read the database at the given path.
+    ///
+    /// # Failures
+    ///
+    /// * The file doesn't exist or can't be read
+    /// * The file isn't a valid database
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let mut file = match File::open(path) {
+            Ok(file) => file,
+            Err(_) => return Err(Error::FileNotFound(path.to_string_lossy().into_owned())),
+        };
+        let mut content = String::new();
+        try!(file.read_to_string(&mut content));
+        Self::from_toml(&content)
+    }
+
+    pub fn from_toml(content: &str) -> Result<Self> {
+        let mut parser = toml::Parser::new(content);
+        let table = match parser.parse() {
+            Some(table) => table,
+            None => {
+                let errors: Vec<String> = parser.errors.iter().map(|e| e.desc.clone()).collect();
+                return Err(Error::VulnerabilityDbMalformed(errors.join(", ")));
+            }
+        };
+        match toml::decode(toml::Value::Table(table)) {
+            Some(db) => Ok(db),
+            None => {
+                Err(Error::VulnerabilityDbMalformed(String::from("entries must have an id, \
+                                                                  package, severity and \
+                                                                  summary")))
+            }
+        }
+    }
+
+    /// Returns every vulnerability affecting the given package.
+    pub fn affecting(&self, ident: &PackageIdent) -> Vec<&Vulnerability> {
+        self.vulnerabilities.iter().filter(|v| v.affects(ident)).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use package::PackageIdent;
+    use super::VulnerabilityDb;
+
+    const DB: &'static str = r#"
+        updated = "2017-05-01"
+
+        [[vulnerability]]
+        id = "CVE-2016-2105"
+        package = "core/openssl"
+        introduced = "1.0.0"
+        fixed = "1.0.2"
+        severity = "high"
+        summary = "EVP_EncodeUpdate overflow"
+
+        [[vulnerability]]
+        id = "CVE-2017-3731"
+        package = "core/openssl"
+        introduced = "1.0.2a"
+        fixed = "1.0.2k"
+        severity = "moderate"
+        summary = "Truncated packet could crash via OOB read"
+
+        [[vulnerability]]
+        id = "CVE-2016-8339"
+        package = "core/redis"
+        fixed = "3.2.4"
+        severity = "critical"
+        summary = "CONFIG SET buffer overflow"
+        "#;
+
+    fn affected(db: &VulnerabilityDb, ident: &str) -> Vec<String> {
+        db.affecting(&PackageIdent::from_str(ident).unwrap())
+            .iter()
+            .map(|v| v.id.clone())
+            .collect()
+    }
+
+    #[test]
+    fn from_toml() {
+        let db = VulnerabilityDb::from_toml(DB).unwrap();
+        assert_eq!(db.updated, Some("2017-05-01".to_string()));
+        assert_eq!(db.vulnerabilities.len(), 3);
+        assert!(VulnerabilityDb::from_toml("[[vulnerability]]\nid = \"CVE-1\"\n").is_err());
+        assert!(VulnerabilityDb::from_toml("updated = ").is_err());
+    }
+
+    #[test]
+    fn affecting_matches_version_ranges() {
+        let db = VulnerabilityDb::from_toml(DB).unwrap();
+        assert_eq!(affected(&db, "core/openssl/1.0.1/20160708162350"),
+                   vec!["CVE-2016-2105"]);
+        assert!(affected(&db, "core/openssl/0.9.8/20160708162350").is_empty());
+        assert!(affected(&db, "core/openssl/1.0.2/20160708162350").is_empty());
+        assert_eq!(affected(&db, "core/redis/3.0.7/20160614174426"), vec!["CVE-2016-8339"]);
+        assert!(affected(&db, "core/redis/3.2.4/20161215055911").is_empty());
+        assert!(affected(&db, "acme/redis/3.0.7/20160614174426").is_empty());
+    }
+
+    #[test]
+    fn affecting_compares_letter_suffixes() {
+        let db = VulnerabilityDb::from_toml(DB).unwrap();
+        assert_eq!(affected(&db, "core/openssl/1.0.2j/20161208223438"),
+                   vec!["CVE-2017-3731"]);
+        assert!(affected(&db, "core/openssl/1.0.2k/20170209223438").is_empty());
+    }
+
+    #[test]
+    fn unreadable_versions_are_affected() {
+        let db = VulnerabilityDb::from_toml(DB).unwrap();
+        assert_eq!(affected(&db, "core/redis/unstable/20160614174426"),
+                   vec!["CVE-2016-8339"]);
+        assert_eq!(affected(&db, "core/redis"), vec!["CVE-2016-8339"]);
+    }
+}
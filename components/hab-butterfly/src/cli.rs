@@ -15,7 +15,7 @@
 use std::path::Path;
 use std::result;
 
-use clap::App;
+use clap::{App, Arg};
 use hcore::service::ServiceGroup;
 
 pub fn get() -> App<'static, 'static> {
@@ -52,11 +52,29 @@ pub fn get() -> App<'static, 'static> {
                     "Ring key name, which will encrypt communication messages")
             )
         )
+        (@subcommand ring =>
+            (about: "Commands relating to Habitat rings")
+            (aliases: &["r", "ri", "rin"])
+            (@setting ArgRequiredElseHelp)
+            (subcommand: sub_ring_exec())
+        )
+        (@subcommand scale =>
+            (about: "Commands relating to the desired size of service groups")
+            (@setting ArgRequiredElseHelp)
+            (subcommand: sub_scale_set())
+        )
+        (@subcommand service =>
+            (about: "Commands relating to Habitat services")
+            (aliases: &["se", "ser", "serv", "servi", "servic", "svc"])
+            (@setting ArgRequiredElseHelp)
+            (subcommand: sub_service_restart())
+            (subcommand: sub_service_switch())
+        )
     )
 }
 
 fn sub_config_apply() -> App<'static, 'static> {
-    clap_app!(@subcommand apply =>
+    let sub = clap_app!(@subcommand apply =>
         (about: "Applies a configuration to a group of Habitat Supervisors")
         (@arg PEER: -p --peer +takes_value
             "A comma-delimited list of one or more Habitat Supervisor peers \
@@ -68,9 +86,135 @@ fn sub_config_apply() -> App<'static, 'static> {
         (@arg VERSION_NUMBER: +required
             "A version number (positive integer) for this configuration (ex: 42)")
         (@arg FILE: {file_exists_or_stdin}
-            "Path to local file on disk (ex: /tmp/config.toml, default: <stdin>)")
+            "Path to local file on disk (ex: /tmp/config.toml, default: <stdin>, or none when \
+            only deleting keys)")
         (@arg ORG: --org +takes_value "Name of service organization to use for encryption")
         (@arg USER: -u --user +takes_value "Name of a user key to use for encryption")
+        (@arg PATCH: --patch
+            "Send only the keys to change, to be merged over the previous version's configuration")
+    );
+
+    sub.arg(Arg::with_name("DELETE")
+            .help("A dotted key for the patch to remove (ex: tls.key); may be given more than once")
+            .long("delete")
+            .value_name("KEY")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(1)
+            .requires("PATCH"))
+        .arg(Arg::with_name("MEMBER")
+            .help("Apply only to the member with this id; may be given more than once")
+            .long("member")
+            .value_name("MEMBER_ID")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(1))
+        .arg(Arg::with_name("LABEL")
+            .help("Apply only to members started with this label (ex: zone=us-east-1a); may be \
+                   given more than once")
+            .long("label")
+            .value_name("KEY=VALUE")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(1)
+            .validator(valid_label))
+        .arg(Arg::with_name("PERCENT")
+            .help("Apply only to this percentage of the members, the same ones each time (ex: 10)")
+            .long("percent")
+            .takes_value(true)
+            .validator(valid_percent))
+        .arg(Arg::with_name("DRY_RUN")
+            .help("Have one Supervisor render the configuration and run the package's \
+                   validate_config hook against it, and show what would change, without applying \
+                   it")
+            .long("dry-run"))
+        .arg(Arg::with_name("SUP_ADDR")
+            .help("Address of the HTTP gateway of the Supervisor which makes the dry run \
+                   [default: 127.0.0.1:9631]")
+            .long("remote-sup")
+            .takes_value(true)
+            .requires("DRY_RUN"))
+}
+
+fn sub_ring_exec() -> App<'static, 'static> {
+    let sub = clap_app!(@subcommand exec =>
+        (about: "Runs a command on every Supervisor in the ring which trusts the signing origin, \
+            and reports each one's result")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+        (@arg ORIGIN: -o --origin +takes_value
+            "The origin whose latest signing key signs the command")
+        (@arg WAIT: -w --wait +takes_value {valid_count}
+            "Seconds to wait for acknowledgements (default: 10)")
+    );
+    sub.arg(Arg::with_name("SUP_ADDR")
+            .help("Address of the HTTP gateway of the Supervisor acknowledgements are read \
+                  from [default: 127.0.0.1:9631]")
+            .long("remote-sup")
+            .takes_value(true))
+        .arg(Arg::with_name("COMMAND")
+            .help("The command to run")
+            .required(true)
+            .possible_values(&["restart", "log-level"]))
+        .arg(Arg::with_name("ARGS")
+            .help("The service groups to restart (ex: redis.default), or the level and optional \
+                  subsystem to log at (ex: debug gossip)")
+            .multiple(true))
+}
+
+fn sub_scale_set() -> App<'static, 'static> {
+    clap_app!(@subcommand set =>
+        (about: "Sets the number of members a service group should have")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg VERSION_NUMBER: +required
+            "A version number (positive integer) for this desired count (ex: 42)")
+        (@arg COUNT: +required "The number of members the service group should have (ex: 3)")
+        (@arg ORG: --org +takes_value "Name of service organization")
+    )
+}
+
+fn sub_service_restart() -> App<'static, 'static> {
+    clap_app!(@subcommand restart =>
+        (about: "Restarts a service group on every Supervisor running it, one member at a time \
+            with --rolling")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Service group to restart (ex: redis.default)")
+        (@arg VERSION_NUMBER: +required
+            "A version number (positive integer) for this restart (ex: 42)")
+        (@arg ROLLING: --rolling
+            "Restart members one at a time, leader last, each once the one before it is healthy")
+        (@arg ORG: --org +takes_value "Name of service organization")
+    )
+}
+
+fn sub_service_switch() -> App<'static, 'static> {
+    clap_app!(@subcommand switch =>
+        (about: "Makes a service group the active color of its service, so that binds to the \
+            service follow it")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Service group to make active (ex: app.green)")
+        (@arg VERSION_NUMBER: +required
+            "A version number (positive integer) for this switch (ex: 42)")
+        (@arg ORG: --org +takes_value "Name of service organization")
     )
 }
 
@@ -86,6 +230,27 @@ fn file_exists_or_stdin(val: String) -> result::Result<(), String> {
     if val == "-" { Ok(()) } else { file_exists(val) }
 }
 
+fn valid_count(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("'{}' is not a whole number", &val)),
+    }
+}
+
+fn valid_label(val: String) -> result::Result<(), String> {
+    match val.find('=') {
+        Some(i) if i > 0 => Ok(()),
+        _ => Err(format!("LABEL: '{}' is invalid, must be in key=value format", &val)),
+    }
+}
+
+fn valid_percent(val: String) -> result::Result<(), String> {
+    match val.parse::<u32>() {
+        Ok(n) if n >= 1 && n <= 100 => Ok(()),
+        _ => Err(format!("PERCENT: '{}' is invalid, must be from 1 to 100", &val)),
+    }
+}
+
 fn valid_service_group(val: String) -> result::Result<(), String> {
     match ServiceGroup::validate(&val) {
         Ok(()) => Ok(()),
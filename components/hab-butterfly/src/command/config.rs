@@ -21,7 +21,9 @@ pub mod apply {
     use std::time;
 
     use butterfly::client::Client;
+    use butterfly::rumor::service_config::{MemberSelector, DELETE_KEY};
     use common::ui::{Status, UI};
+    use hab::command::sup;
     use hcore::crypto::{SymKey, BoxKeyPair};
     use hcore::service::ServiceGroup;
     use toml;
@@ -32,12 +34,22 @@ pub mod apply {
                  sg: &ServiceGroup,
                  number: u64,
                  file_path: Option<&Path>,
+                 patch: bool,
+                 deletes: &[&str],
+                 selector: &MemberSelector,
+                 dry_run: Option<&str>,
                  peers: &Vec<String>,
                  ring_key: Option<&SymKey>,
                  user_pair: Option<&BoxKeyPair>,
                  service_pair: Option<&BoxKeyPair>)
                  -> Result<()> {
-        try!(ui.begin(format!("Applying configuration for {} incarnation {}", sg, number,)));
+        let what = if patch { "configuration patch" } else { "configuration" };
+        let verb = if dry_run.is_some() { "Checking" } else { "Applying" };
+        try!(ui.begin(format!("{} {} for {} incarnation {}", verb, what, sg, number,)));
+        if !selector.is_empty() {
+            try!(ui.status(Status::Custom('◎', String::from("Targeting")),
+                           describe(selector)));
+        }
 
         try!(ui.status(Status::Creating, format!("service configuration")));
 
@@ -48,6 +60,8 @@ pub mod apply {
                 let mut file = try!(File::open(&p));
                 try!(file.read_to_end(&mut body));
             }
+            // A patch which only deletes keys needs no document.
+            None if !deletes.is_empty() => (),
             None => {
                 try!(io::stdin().read_to_end(&mut body));
             }
@@ -55,12 +69,15 @@ pub mod apply {
 
         // We want to expire the borrow of body before we check to see if we need
         // to encrypt the contents.
-        {
+        let mut config = {
             let toml_str = try!(str::from_utf8(&body)
                 .map_err(|e| Error::Utf8Error(format!("{}", e))));
             let mut parser = toml::Parser::new(toml_str);
             match parser.parse() {
-                Some(_) => try!(ui.status(Status::Verified, "this configuration is valid TOML")),
+                Some(config) => {
+                    try!(ui.status(Status::Verified, "this configuration is valid TOML"));
+                    config
+                }
                 None => {
                     for err in parser.errors.iter() {
                         try!(ui.fatal("Invalid TOML"));
@@ -71,8 +88,23 @@ pub mod apply {
                         try!(ui.para(&toml_str[err.lo..]));
                         return Err(Error::TomlError);
                     }
+                    return Err(Error::TomlError);
                 }
             }
+        };
+
+        if !deletes.is_empty() {
+            let mut keys = match config.remove(DELETE_KEY) {
+                Some(toml::Value::Array(keys)) => keys,
+                _ => vec![],
+            };
+            keys.extend(deletes.iter().map(|k| toml::Value::String(k.to_string())));
+            config.insert(DELETE_KEY.to_string(), toml::Value::Array(keys));
+            body = toml::encode_str(&toml::Value::Table(config)).into_bytes();
+        }
+
+        if let Some(sup_addr) = dry_run {
+            return check(ui, sg, &body, patch, sup_addr);
         }
 
         let mut encrypted = false;
@@ -89,7 +121,12 @@ pub mod apply {
             try!(ui.status(Status::Applying, format!("to peer {}", peer)));
             let mut client = try!(Client::new(peer, ring_key.map(|k| k.clone()))
                 .map_err(|e| Error::ButterflyError(format!("{}", e))));
-            try!(client.send_service_config(sg.clone(), number, body.clone(), encrypted)
+            try!(client.send_service_config_to(sg.clone(),
+                                               number,
+                                               body.clone(),
+                                               encrypted,
+                                               patch,
+                                               selector)
                 .map_err(|e| Error::ButterflyError(format!("{}", e))));
 
             // please take a moment to weep over the following line
@@ -98,7 +135,60 @@ pub mod apply {
             // see https://github.com/zeromq/libzmq/issues/1264
             thread::sleep(time::Duration::from_millis(100));
         }
-        try!(ui.end("Applied configuration"));
+        try!(ui.end(format!("Applied {}", what)));
+        Ok(())
+    }
+
+    /// Has the Supervisor at `sup_addr` render the configuration and validate it, and shows what
+    /// it would change. Nothing is gossiped, so the configuration is sent to the Supervisor
+    /// unencrypted.
+    fn check(ui: &mut UI,
+             sg: &ServiceGroup,
+             body: &[u8],
+             patch: bool,
+             sup_addr: &str)
+             -> Result<()> {
+        try!(ui.status(Status::Custom('◎', String::from("Dry run")),
+                       format!("on the Supervisor at {}", sup_addr)));
+        let result = try!(try!(sup::client(sup_addr)).config_dry_run(sg, body, patch));
+        if let Some(e) = result.error {
+            try!(ui.fatal("The configuration can't be rendered"));
+            return Err(Error::ConfigInvalid(e));
+        }
+        for file in result.files.iter() {
+            if file.changed {
+                try!(ui.status(Status::Custom('±', String::from("Changes")), &file.name));
+                print!("{}", file.diff);
+            } else {
+                try!(ui.status(Status::Custom('=', String::from("Unchanged")), &file.name));
+            }
+        }
+        if let Some(validation) = result.validation {
+            for line in validation.output.lines() {
+                println!("validate_config: {}", line);
+            }
+            if validation.exit_code != 0 {
+                let e = format!("the validate_config hook exited with {}", validation.exit_code);
+                try!(ui.fatal("The service rejected the configuration"));
+                return Err(Error::ConfigInvalid(e));
+            }
+            try!(ui.status(Status::Verified, "the validate_config hook accepted it"));
+        }
+        try!(ui.end("Dry run passed; nothing was applied"));
         Ok(())
     }
+
+    fn describe(selector: &MemberSelector) -> String {
+        let mut parts = Vec::new();
+        if !selector.member_ids.is_empty() {
+            parts.push(format!("members {}", selector.member_ids.join(", ")));
+        }
+        if !selector.labels.is_empty() {
+            parts.push(format!("members labeled {}", selector.labels.join(", ")));
+        }
+        if selector.percent > 0 {
+            parts.push(format!("{}% of members", selector.percent));
+        }
+        format!("only {}", parts.join(" and "))
+    }
 }
@@ -14,3 +14,6 @@
 
 pub mod config;
 pub mod file;
+pub mod ring;
+pub mod scale;
+pub mod service;
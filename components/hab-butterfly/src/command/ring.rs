@@ -0,0 +1,103 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod exec {
+    use std::collections::HashSet;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use butterfly::client::Client;
+    use common::ui::{Status, UI};
+    use hcore::crypto::{SigKeyPair, SymKey};
+    use sup_client::Client as SupClient;
+    use uuid::Uuid;
+
+    use {PRODUCT, VERSION};
+    use error::{Error, Result};
+
+    /// How often the Supervisor is asked for new acknowledgements.
+    const POLL_INTERVAL_MS: u64 = 500;
+
+    /// Signs a command with `pair` and sends it to the ring through `peers`, then reports the
+    /// acknowledgements which reach the Supervisor at `sup_addr` within `wait` seconds.
+    pub fn start(ui: &mut UI,
+                 command: &str,
+                 args: &[String],
+                 pair: &SigKeyPair,
+                 peers: &Vec<String>,
+                 ring_key: Option<&SymKey>,
+                 sup_addr: &str,
+                 wait: u64)
+                 -> Result<()> {
+        let id = Uuid::new_v4().simple().to_string();
+        try!(ui.begin(format!("Sending ring command {} ({} {}) signed by {}",
+                              id,
+                              command,
+                              args.join(" "),
+                              pair.name_with_rev())));
+        for peer in peers.iter() {
+            try!(ui.status(Status::Applying, format!("to peer {}", peer)));
+            let mut client = try!(Client::new(peer, ring_key.map(|k| k.clone()))
+                .map_err(|e| Error::ButterflyError(format!("{}", e))));
+            try!(client.send_ring_command(id.clone(), command, args.to_vec(), pair)
+                .map_err(|e| Error::ButterflyError(format!("{}", e))));
+
+            // Give the message time to be sent before the socket is freed, as in `config apply`.
+            // see https://github.com/zeromq/libzmq/issues/1264
+            thread::sleep(Duration::from_millis(100));
+        }
+
+        let sup = try!(SupClient::new(sup_addr, PRODUCT, VERSION));
+        let deadline = Instant::now() + Duration::from_secs(wait);
+        let mut reported = HashSet::new();
+        let mut failed = 0;
+        loop {
+            for rc in try!(sup.ring_commands(Some(&id))) {
+                for ack in rc.acks {
+                    if !reported.insert(ack.member_id.clone()) {
+                        continue;
+                    }
+                    if ack.success {
+                        try!(ui.status(Status::Custom('✓', "Ran".to_string()),
+                                       format!("on {}: {}", ack.member_name(), ack.message)));
+                    } else {
+                        failed += 1;
+                        try!(ui.warn(format!("Failed on {}: {}",
+                                             ack.member_name(),
+                                             ack.message)));
+                    }
+                }
+            }
+            if Instant::now() >= deadline {
+                break;
+            }
+            thread::sleep(Duration::from_millis(POLL_INTERVAL_MS));
+        }
+        if reported.is_empty() {
+            return Err(Error::ButterflyError(format!("No member acknowledged ring command {} \
+                                                      within {} seconds",
+                                                     id,
+                                                     wait)));
+        }
+        if failed > 0 {
+            return Err(Error::ButterflyError(format!("{} of {} members failed to run ring \
+                                                      command {}",
+                                                     failed,
+                                                     reported.len(),
+                                                     id)));
+        }
+        try!(ui.end(format!("{} members ran ring command {}", reported.len(), id)));
+        Ok(())
+    }
+}
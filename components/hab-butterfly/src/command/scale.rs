@@ -0,0 +1,51 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod set {
+    use std::thread;
+    use std::time;
+
+    use butterfly::client::Client;
+    use common::ui::{Status, UI};
+    use hcore::crypto::SymKey;
+    use hcore::service::ServiceGroup;
+
+    use error::{Error, Result};
+
+    pub fn start(ui: &mut UI,
+                 sg: &ServiceGroup,
+                 number: u64,
+                 count: u64,
+                 peers: &Vec<String>,
+                 ring_key: Option<&SymKey>)
+                 -> Result<()> {
+        try!(ui.begin(format!("Setting the desired count of {} to {} incarnation {}",
+                              sg,
+                              count,
+                              number)));
+        for peer in peers.iter() {
+            try!(ui.status(Status::Applying, format!("to peer {}", peer)));
+            let mut client = try!(Client::new(peer, ring_key.map(|k| k.clone()))
+                .map_err(|e| Error::ButterflyError(format!("{}", e))));
+            try!(client.send_service_scale(sg.clone(), number, count)
+                .map_err(|e| Error::ButterflyError(format!("{}", e))));
+
+            // Give the message time to be sent before the socket is freed, as in `config apply`.
+            // see https://github.com/zeromq/libzmq/issues/1264
+            thread::sleep(time::Duration::from_millis(100));
+        }
+        try!(ui.end("Applied desired count"));
+        Ok(())
+    }
+}
@@ -18,6 +18,7 @@ extern crate habitat_common as common;
 extern crate habitat_depot_client as depot_client;
 extern crate habitat_http_client as http_client;
 extern crate habitat_butterfly as butterfly;
+extern crate habitat_sup_client as sup_client;
 
 extern crate ansi_term;
 #[macro_use]
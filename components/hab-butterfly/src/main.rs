@@ -17,8 +17,10 @@ extern crate hab;
 extern crate clap;
 extern crate env_logger;
 extern crate hab_butterfly;
+extern crate habitat_butterfly as butterfly;
 extern crate habitat_core as hcore;
 extern crate habitat_common as common;
+extern crate habitat_sup_client as sup_client;
 #[macro_use]
 extern crate log;
 
@@ -28,15 +30,17 @@ use std::path::Path;
 use std::str::FromStr;
 use std::thread;
 
+use butterfly::rumor::service_config::MemberSelector;
 use clap::ArgMatches;
 
 use common::ui::{Coloring, UI, NOCOLORING_ENVVAR, NONINTERACTIVE_ENVVAR};
 use hcore::env as henv;
-use hcore::crypto::{init, default_cache_key_path, BoxKeyPair, SymKey};
+use hcore::crypto::{init, default_cache_key_path, BoxKeyPair, SigKeyPair, SymKey};
 use hcore::fs::FS_ROOT_PATH;
 use hcore::service::ServiceGroup;
 
-use hab_butterfly::{analytics, cli, command};
+use hab::ORIGIN_ENVVAR;
+use hab_butterfly::{analytics, cli, command, config};
 use hab_butterfly::error::{Error, Result};
 
 
@@ -51,6 +55,9 @@ const HABITAT_BUTTERFLY_PORT: u64 = 9638;
 
 const MAX_FILE_UPLOAD_SIZE_BYTES: u64 = 4096;
 
+/// Seconds `ring exec` waits for acknowledgements when --wait isn't given.
+const DEFAULT_RING_EXEC_WAIT: u64 = 10;
+
 fn main() {
     env_logger::init().unwrap();
     let mut ui = ui();
@@ -83,6 +90,25 @@ fn start(ui: &mut UI) -> Result<()> {
                 _ => unreachable!(),
             }
         }
+        ("ring", Some(matches)) => {
+            match matches.subcommand() {
+                ("exec", Some(m)) => try!(sub_ring_exec(ui, m)),
+                _ => unreachable!(),
+            }
+        }
+        ("scale", Some(matches)) => {
+            match matches.subcommand() {
+                ("set", Some(m)) => try!(sub_scale_set(ui, m)),
+                _ => unreachable!(),
+            }
+        }
+        ("service", Some(matches)) => {
+            match matches.subcommand() {
+                ("restart", Some(m)) => try!(sub_service_restart(ui, m)),
+                ("switch", Some(m)) => try!(sub_service_switch(ui, m)),
+                _ => unreachable!(),
+            }
+        }
         _ => unreachable!(),
     };
     Ok(())
@@ -104,6 +130,16 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         Some("-") | None => None,
         Some(p) => Some(Path::new(p)),
     };
+    let deletes: Vec<&str> = m.values_of("DELETE").map(|v| v.collect()).unwrap_or(vec![]);
+    let selector = MemberSelector {
+        member_ids: m.values_of("MEMBER").map(|v| v.map(String::from).collect()).unwrap_or(vec![]),
+        labels: m.values_of("LABEL").map(|v| v.map(String::from).collect()).unwrap_or(vec![]),
+        percent: if m.is_present("PERCENT") {
+            value_t!(m, "PERCENT", u32).unwrap_or_else(|e| e.exit())
+        } else {
+            0
+        },
+    };
 
     init();
     let cache = default_cache_key_path(fs_root_path);
@@ -125,10 +161,19 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         Some(username) => Some(try!(BoxKeyPair::get_latest_pair_for(username, &cache))),
         None => None,
     };
+    let dry_run = if m.is_present("DRY_RUN") {
+        Some(m.value_of("SUP_ADDR").unwrap_or(sup_client::DEFAULT_SUP_ADDR))
+    } else {
+        None
+    };
     command::config::apply::start(ui,
                                   &sg,
                                   number,
                                   file_path,
+                                  m.is_present("PATCH"),
+                                  &deletes,
+                                  &selector,
+                                  dry_run,
                                   &peers,
                                   ring_key.as_ref(),
                                   user_pair.as_ref(),
@@ -192,6 +237,133 @@ fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
                                  service_pair.as_ref())
 }
 
+fn sub_ring_exec(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
+        }
+    }
+    let command = m.value_of("COMMAND").unwrap(); // Required via clap
+    let args: Vec<String> = match m.values_of("ARGS") {
+        Some(args) => args.map(|a| a.to_string()).collect(),
+        None => vec![],
+    };
+    let wait = match m.value_of("WAIT") {
+        Some(_) => value_t!(m, "WAIT", u64).unwrap_or_else(|e| e.exit()),
+        None => DEFAULT_RING_EXEC_WAIT,
+    };
+    let sup_addr = m.value_of("SUP_ADDR").unwrap_or(sup_client::DEFAULT_SUP_ADDR);
+
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+    let origin = try!(origin_param_or_env(&m));
+    let pair = try!(SigKeyPair::get_latest_pair_for(&origin, &cache));
+    command::ring::exec::start(ui,
+                               command,
+                               &args,
+                               &pair,
+                               &peers,
+                               ring_key.as_ref(),
+                               sup_addr,
+                               wait)
+}
+
+fn sub_scale_set(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
+        }
+    }
+    let number = value_t!(m, "VERSION_NUMBER", u64).unwrap_or_else(|e| e.exit());
+    let count = value_t!(m, "COUNT", u64).unwrap_or_else(|e| e.exit());
+
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+
+    let mut sg = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap()));
+    if let Some(org) = org_param_or_env(&m) {
+        sg.set_org(org);
+    }
+    command::scale::set::start(ui, &sg, number, count, &peers, ring_key.as_ref())
+}
+
+fn sub_service_restart(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
+        }
+    }
+    let number = value_t!(m, "VERSION_NUMBER", u64).unwrap_or_else(|e| e.exit());
+
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+
+    let mut sg = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap()));
+    if let Some(org) = org_param_or_env(&m) {
+        sg.set_org(org);
+    }
+    command::service::restart::start(ui,
+                                     &sg,
+                                     number,
+                                     m.is_present("ROLLING"),
+                                     &peers,
+                                     ring_key.as_ref())
+}
+
+fn sub_service_switch(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
+        }
+    }
+    let number = value_t!(m, "VERSION_NUMBER", u64).unwrap_or_else(|e| e.exit());
+
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+
+    let mut sg = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap()));
+    if let Some(org) = org_param_or_env(&m) {
+        sg.set_org(org);
+    }
+    command::service::switch::start(ui, &sg, number, &peers, ring_key.as_ref())
+}
+
 fn ui() -> UI {
     let isatty = if henv::var(NONINTERACTIVE_ENVVAR).map(|val| val == "true").unwrap_or(false) {
         Some(false)
@@ -224,6 +396,24 @@ fn raw_parse_args() -> (Vec<OsString>, Vec<OsString>) {
     }
 }
 
+fn origin_param_or_env(m: &ArgMatches) -> Result<String> {
+    match m.value_of("ORIGIN") {
+        Some(o) => Ok(o.to_string()),
+        None => {
+            match henv::var(ORIGIN_ENVVAR) {
+                Ok(v) => Ok(v),
+                Err(_) => {
+                    let config = try!(config::load());
+                    match config.origin {
+                        Some(v) => Ok(v),
+                        None => return Err(Error::CryptoCLI("No origin specified".to_string())),
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// Check to see if the user has passed in an ORG param.
 /// If not, check the HABITAT_ORG env var. If that's
 /// empty too, then error.
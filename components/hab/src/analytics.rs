@@ -145,7 +145,8 @@
 //! ## `ec=invoke`
 //!
 //! The [Event Category][ec] which corresponds to the type of event being sent. Currently there are
-//! only 2 possible values: `"invoke"` for subcommand invocations and `"clierror"` for CLI errors.
+//! 3 possible values: `"invoke"` for subcommand invocations, `"clierror"` for CLI errors, and
+//! `"failure"` for subcommands which fail.
 //!
 //! [ec]: https://developers.google.com/analytics/devguides/collection/protocol/v1/parameters#ec
 //!
@@ -158,7 +159,10 @@
 //! the value is the type of CLI error followed by a double dash and terminated with the subcommand
 //! which was invoked (also containing no further arguments, options, or flags). As before any
 //! spaces in the subcommand are replaced with a double dash, as in:
-//! `"InvalidSubcommand--hab-whoops"`.
+//! `"InvalidSubcommand--hab-whoops"`. For failures (where `"ec=failure"`), the value is the stable
+//! error code also printed by `--json-errors`, followed by a double dash and the top level
+//! subcommand which failed, as in: `"package-not-found--hab--pkg"`. The error message itself is
+//! never transmitted.
 //!
 //! [ea]: https://developers.google.com/analytics/devguides/collection/protocol/v1/parameters#ea
 //!
@@ -185,22 +189,34 @@
 //! ```text
 //! hab/0.6.0/20160606153031 (x86_64-darwin; 14.5.0)
 //! ```
+//!
+//! # Auditing and Turning Off Analytics
+//!
+//! Events are sent to the Google Analytics API unless the `HAB_ANALYTICS_URL` environment variable
+//! names another endpoint, such as a proxy which inspects or filters them. Running
+//! `hab cli analytics status` prints whether analytics are enabled, the endpoint, and every event
+//! which is waiting to be sent, decoded field by field. `hab cli analytics off` opts out and
+//! deletes any events which are waiting, and `hab cli analytics on` opts back in.
 
 use std::env;
 use std::fs::{self, File};
 use std::io::{Read, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::{UNIX_EPOCH, SystemTime};
 
 use clap;
 use common::ui::{Status, UI};
 use hcore;
+use hcore::env as henv;
 use http_client::ApiClient;
 use url::Url;
 use url::percent_encoding::{utf8_percent_encode, PATH_SEGMENT_ENCODE_SET};
 use uuid::Uuid;
 
-use error::Result;
+use error::{Error, Result};
+
+/// Names an endpoint to send events to in place of the Google Analytics API.
+pub const ANALYTICS_URL_ENVVAR: &'static str = "HAB_ANALYTICS_URL";
 
 /// The Google Analytics [Tracking
 /// ID](https://developers.google.com/analytics/devguides/collection/protocol/v1/parameters#tid)
@@ -228,6 +244,8 @@ enum Event {
     /// Occurs when a CLI error occurs, such as when a required argument is missing, an invalid
     /// subcommand is invoked, etc.
     CliError,
+    /// Occurs when a subcommand fails, reporting only the kind of failure.
+    Failure,
     /// Occurs when a pre-selected subcommand will be invoked, representing a user's intention to
     /// use this subcommand.
     Subcommand,
@@ -334,6 +352,30 @@ pub fn instrument_clap_error(err: &clap::Error) {
     }
 }
 
+/// Reports the kind of failure when a subcommand fails, with no environment, arguments,
+/// parameters, or error message being captured.
+///
+/// The kinds of failures users run into, and the subcommands they run into them with, help the
+/// project and the maintainers to see where users get stuck after their command line was
+/// accepted. The event is only saved to disk, as the program is about to exit; it is sent along
+/// with the other pending events by a later subcommand which hits the network.
+pub fn instrument_error(err: &Error) {
+    // If analytics are not enabled, return early, we're done.
+    if !analytics_enabled() {
+        return;
+    }
+
+    // Get the first program argument after the program name to determine the top level
+    // subcommand which failed. A global flag given before the subcommand is not reported.
+    let arg1 = env::args().nth(1).unwrap_or_default();
+    if arg1.starts_with("-") {
+        record_event(Event::Failure, &format!("{}--{}", err.code(), PRODUCT));
+    } else {
+        record_event(Event::Failure,
+                     &format!("{}--{}--{}", err.code(), PRODUCT, arg1));
+    }
+}
+
 /// Explicitly opts in to reporting analytics.
 ///
 /// This function is designed to be triggered from another subcommand and can be a blocking call.
@@ -347,6 +389,38 @@ pub fn instrument_clap_error(err: &clap::Error) {
 /// * If an opt-out if exists but cannot be deleted
 /// * If an opt-in file cannot be created
 pub fn opt_in(ui: &mut UI, analytics_path: &Path, origin_generated: bool) -> Result<()> {
+    try!(write_opt_in(ui, analytics_path));
+    // Record an event that the setup subcommand was invoked
+    record_event(Event::Subcommand,
+                 &format!("{}--{}--{}", PRODUCT, "cli", "setup"));
+    // If an origin key was generated in the setup subcommand, record an event as well
+    if origin_generated {
+        record_event(Event::Subcommand,
+                     &format!("{}--{}--{}--{}", PRODUCT, "origin", "key", "generate"));
+    }
+    // Send any pending events to the Google Analytics API
+    send_pending();
+    // Return an empty Ok, representing a successful operation
+    Ok(())
+}
+
+/// Explicitly opts in to reporting analytics outside of `hab setup`, from
+/// `hab cli analytics on`. No event is recorded for the subcommand itself.
+///
+/// # Errors
+///
+/// * If the parent directory cannot be created
+/// * If an opt-out if exists but cannot be deleted
+/// * If an opt-in file cannot be created
+pub fn turn_on(ui: &mut UI, analytics_path: &Path) -> Result<()> {
+    try!(write_opt_in(ui, analytics_path));
+    // Send any pending events to the Google Analytics API
+    send_pending();
+    Ok(())
+}
+
+/// Replaces any opt-out file with an opt-in file.
+fn write_opt_in(ui: &mut UI, analytics_path: &Path) -> Result<()> {
     try!(ui.begin("Opting in to analytics"));
     // Create the parent directory which will contain the opt-in file
     try!(fs::create_dir_all(analytics_path));
@@ -363,17 +437,6 @@ pub fn opt_in(ui: &mut UI, analytics_path: &Path, origin_generated: bool) -> Res
     // Create the opt-in file
     let _ = try!(File::create(opt_in_path));
     try!(ui.end("Analytics opted in, thank you!"));
-    // Record an event that the setup subcommand was invoked
-    record_event(Event::Subcommand,
-                 &format!("{}--{}--{}", PRODUCT, "cli", "setup"));
-    // If an origin key was generated in the setup subcommand, record an event as well
-    if origin_generated {
-        record_event(Event::Subcommand,
-                     &format!("{}--{}--{}--{}", PRODUCT, "origin", "key", "generate"));
-    }
-    // Send any pending events to the Google Analytics API
-    send_pending();
-    // Return an empty Ok, representing a successful operation
     Ok(())
 }
 
@@ -384,11 +447,15 @@ pub fn opt_in(ui: &mut UI, analytics_path: &Path, origin_generated: bool) -> Res
 /// we can use more Rust idioms such as `try!` and `Result` to make for a much more terse and
 /// direct function body.
 ///
+/// Any events which are waiting to be sent are deleted, so nothing recorded before opting out is
+/// sent later.
+///
 /// # Errors
 ///
 /// * If the parent directory cannot be created
 /// * If an opt-in if exists but cannot be deleted
 /// * If an opt-out file cannot be created
+/// * If a pending event cannot be deleted
 pub fn opt_out(ui: &mut UI, analytics_path: &Path) -> Result<()> {
     try!(ui.begin("Opting out of analytics"));
     // Create the parent directory which will contain the opt-in file
@@ -405,6 +472,11 @@ pub fn opt_out(ui: &mut UI, analytics_path: &Path) -> Result<()> {
     try!(ui.status(Status::Creating, opt_out_path.display()));
     // Create the opt-out file
     let _ = try!(File::create(opt_out_path));
+    // Delete every pending event file from disk
+    for (event_path, _) in pending_events(analytics_path) {
+        try!(ui.status(Status::Deleting, event_path.display()));
+        try!(fs::remove_file(&event_path));
+    }
     try!(ui.end("Analytics opted out, we salute you just the same!"));
     // Return an empty Ok, representing a successful operation
     Ok(())
@@ -424,6 +496,38 @@ pub fn is_opted_in(analytics_path: &Path) -> Option<bool> {
     }
 }
 
+/// Returns the URL events are sent to.
+pub fn endpoint() -> String {
+    henv::var(ANALYTICS_URL_ENVVAR).unwrap_or(GOOGLE_ANALYTICS_URL.to_string())
+}
+
+/// Returns the path and payload of each event which is waiting to be sent.
+pub fn pending_events(analytics_path: &Path) -> Vec<(PathBuf, String)> {
+    let mut events = Vec::new();
+    // If the cache directory can't be read there are no events we could send either, so report
+    // and return an empty list.
+    let entries = match analytics_path.read_dir() {
+        Ok(rd) => rd,
+        Err(e) => {
+            debug!("Cannot read directory entries in {}: {}",
+                   analytics_path.display(),
+                   e);
+            return events;
+        }
+    };
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let path = entry.path();
+        if path.is_file() && entry.file_name().to_string_lossy().as_ref().starts_with("event-") {
+            let payload = read_file(&path);
+            events.push((path, payload));
+        }
+    }
+    // Event file names start with the time they were saved, so sorting by path keeps them roughly
+    // in the order they were recorded.
+    events.sort();
+    events
+}
+
 /// Returns true if analytics are enabled and false otherwise.
 fn analytics_enabled() -> bool {
     match is_opted_in(&hcore::fs::cache_analytics_path(None)) {
@@ -441,6 +545,7 @@ fn record_event(kind: Event, action: &str) {
     // types are missing in the future.
     let category = match kind {
         Event::CliError => "clierror",
+        Event::Failure => "failure",
         Event::Subcommand => "invoke",
     };
     // Craft the Google Analytics payload body which resembles a URL query string, even requiring
@@ -487,7 +592,8 @@ fn should_send() -> bool {
     }
 }
 
-/// Sends the event to Google Analytics via an HTTP/POST.
+/// Sends the event to Google Analytics, or the endpoint set in `HAB_ANALYTICS_URL`, via an
+/// HTTP/POST.
 ///
 /// This function returns true if the event was sent and false if an error occurred along the way.
 /// The presence of a `false` return value is enough to assume that we want to save this event to
@@ -495,7 +601,8 @@ fn should_send() -> bool {
 fn send_event(payload: &str) -> bool {
     // Take the analytics URL string slice and parse it into a `Url` struct. Despite the fact that
     // the string value is a constant, this could still fail and therefore has to be accounted for.
-    let url = match Url::parse(GOOGLE_ANALYTICS_URL) {
+    let endpoint = endpoint();
+    let url = match Url::parse(&endpoint) {
         Ok(url) => url,
         Err(e) => {
             debug!("Error parsing URL: {}", e);
@@ -528,7 +635,7 @@ fn send_event(payload: &str) -> bool {
     let response = match request.send() {
         Ok(r) => r,
         Err(e) => {
-            debug!("Error posting payload to {}: {}", GOOGLE_ANALYTICS_URL, e);
+            debug!("Error posting payload to {}: {}", endpoint, e);
             return false;
         }
     };
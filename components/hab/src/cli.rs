@@ -42,12 +42,43 @@ pub fn get() -> App<'static, 'static> {
         (author: "\nAuthors: The Habitat Maintainers <humans@habitat.sh>\n")
         (@setting VersionlessSubcommands)
         (@setting ArgRequiredElseHelp)
+        (@subcommand bldr =>
+            (about: "Commands relating to Habitat Builder")
+            (aliases: &["bl", "bld"])
+            (@setting ArgRequiredElseHelp)
+            (@subcommand job =>
+                (about: "Commands relating to Builder build jobs")
+                (aliases: &["j", "jo"])
+                (@setting ArgRequiredElseHelp)
+                (@subcommand start =>
+                    (about: "Submit a build job for a package to Builder")
+                    (aliases: &["s", "st", "sta", "star"])
+                    (@arg PROJECT: +required {valid_origin_and_name}
+                        "The origin and name of the package to build (ex: core/redis)")
+                    (@arg FOLLOW: -f --follow "Wait for the job to finish, streaming its build log")
+                    (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                        "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
+                )
+                (@subcommand status =>
+                    (about: "Report the status of a Builder build job")
+                    (aliases: &["stat", "statu"])
+                    (@arg JOB_ID: +required {valid_job_id} "The id of the job (ex: 1234)")
+                    (@arg FOLLOW: -f --follow "Wait for the job to finish, streaming its build log")
+                    (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                        "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
+                )
+            )
+        )
         (@subcommand cli =>
             (about: "Commands relating to Habitat runtime config")
             (aliases: &["cl"])
             (@setting ArgRequiredElseHelp)
             (subcommand: sub_cli_setup().aliases(&["s", "se", "set", "setu"]))
+            (subcommand: sub_cli_analytics().aliases(&["a", "an", "ana"]))
             (subcommand: sub_cli_completers().aliases(&["c", "co", "com", "comp"]))
+            (subcommand: sub_cli_complete())
         )
         (@subcommand config =>
             (about: "Commands relating to Habitat runtime config")
@@ -55,6 +86,28 @@ pub fn get() -> App<'static, 'static> {
             (@setting ArgRequiredElseHelp)
             (subcommand: sub_config_apply().aliases(&["a", "ap", "app", "appl"]))
         )
+        (@subcommand depot =>
+            (about: "Commands relating to Habitat Depots")
+            (aliases: &["d", "de", "dep", "depo"])
+            (@setting ArgRequiredElseHelp)
+            (@subcommand sync =>
+                (about: "Mirrors origins from an upstream Depot into a local directory, \
+                    downloading only the keys and releases it doesn't already have")
+                (aliases: &["s", "sy", "syn"])
+                (@arg ORIGIN: +required +multiple "One or more origins to mirror (ex: core)")
+                (@arg DEST_DIR: -d --dest +takes_value +required
+                    "Directory the origins are mirrored into (ex: /mnt/mirror)")
+                (@arg CHANNEL: -c --channel +takes_value
+                    "Only mirror releases in this channel (ex: stable)")
+                (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                    "Use a specific upstream Depot URL (ex: http://depot.example.com/v1/depot)")
+                (@arg TARGET_URL: --to +takes_value {valid_url}
+                    "After syncing, upload anything missing to this Depot \
+                    (ex: http://depot.example.com/v1/depot)")
+                (@arg AUTH_TOKEN: -z --auth +takes_value
+                    "Authentication token for the Depot given with --to")
+            )
+        )
         (@subcommand file =>
             (about: "Commands relating to Habitat files")
             (aliases: &["f", "fi", "fil"])
@@ -77,7 +130,7 @@ pub fn get() -> App<'static, 'static> {
             )
         )
         (@subcommand origin =>
-            (about: "Commands relating to Habitat origin keys")
+            (about: "Commands relating to Habitat origin keys and secrets")
             (aliases: &["o", "or", "ori", "orig", "origi"])
             (@setting ArgRequiredElseHelp)
             (@subcommand key =>
@@ -127,11 +180,47 @@ pub fn get() -> App<'static, 'static> {
                     (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for the Depot")
                 )
             )
+            (@subcommand secret =>
+                (about: "Commands relating to secrets available to an origin's Builder builds")
+                (aliases: &["s", "se", "sec", "secr", "secre"])
+                (@setting ArgRequiredElseHelp)
+                (@subcommand upload =>
+                    (about: "Encrypt a secret with the origin key and upload it to the depot")
+                    (aliases: &["u", "up", "upl", "uplo", "uploa"])
+                    (@arg NAME: +required {valid_secret_name}
+                        "The name of the secret, exposed as an environment variable (ex: TOKEN)")
+                    (@arg FILE: {file_exists_or_stdin}
+                        "Path to a file holding the secret value, so it stays off the command \
+                        line (default: <stdin>)")
+                    (@arg ORIGIN: -o --origin +takes_value "The origin the secret belongs to")
+                    (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                        "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for the Depot")
+                )
+                (@subcommand list =>
+                    (about: "List the names of an origin's secrets")
+                    (aliases: &["l", "li", "lis"])
+                    (@arg ORIGIN: -o --origin +takes_value "The origin the secrets belong to")
+                    (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                        "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for the Depot")
+                )
+                (@subcommand delete =>
+                    (about: "Delete a secret from an origin")
+                    (aliases: &["d", "de", "del", "dele", "delet"])
+                    (@arg NAME: +required "The name of the secret")
+                    (@arg ORIGIN: -o --origin +takes_value "The origin the secret belongs to")
+                    (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                        "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for the Depot")
+                )
+            )
         )
         (@subcommand pkg =>
             (about: "Commands relating to Habitat packages")
             (aliases: &["p", "pk", "package"])
             (@setting ArgRequiredElseHelp)
+            (subcommand: sub_pkg_audit())
             (@subcommand binlink =>
                 (about: "Creates a symlink for a package binary in a common 'PATH' location")
                 (aliases: &["bi", "bin", "binl", "binli", "binlin"])
@@ -149,6 +238,22 @@ pub fn get() -> App<'static, 'static> {
                     "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
             )
             (subcommand: sub_pkg_build())
+            (@subcommand bulkupload =>
+                (about: "Uploads every Habitat Artifact and public origin key in a directory \
+                    tree to a Depot, skipping any the Depot already has")
+                (aliases: &["bu", "bul", "bulk"])
+                (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                    "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
+                (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for the Depot")
+                (@arg UPLOAD_DIRECTORY: +required {dir_exists}
+                    "Directory containing the artifacts and keys to upload (ex: /mnt/mirror)")
+            )
+            (@subcommand env =>
+                (about: "Prints the environment an installed package and its dependencies run \
+                    in under the Supervisor, as shell exports")
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+            )
             (@subcommand exec =>
                 (about: "Executes a command using the 'PATH' context of an installed package")
                 (aliases: &["exe"])
@@ -163,9 +268,32 @@ pub fn get() -> App<'static, 'static> {
                 (about: "Exports the package to the specified format")
                 (aliases: &["exp"])
                 (@arg FORMAT: +required +takes_value
-                    "The export format (ex: docker, aci, mesos, or tar)")
+                    "The export format (ex: docker, aci, mesos, tar, or vm)")
                 (@arg PKG_IDENT: +required +takes_value
                     "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@setting SubcommandsNegateReqs)
+                (@subcommand sign-bundle =>
+                    (about: "Writes a signed provenance manifest into an exported file system")
+                    (@setting Hidden)
+                    (@arg ROOT: +required {dir_exists} "Root of the exported file system")
+                    (@arg ORIGIN: --origin +takes_value "Origin key used to sign the manifest")
+                )
+                (@subcommand verify-bundle =>
+                    (about: "Verifies an exported file system against its signed provenance \
+                        manifest")
+                    (@arg ROOT: +required {dir_exists}
+                        "Root of the extracted tarball or container file system (ex: /tmp/redis)")
+                )
+            )
+            (@subcommand extract =>
+                (about: "Unpacks a Habitat Artifact into a directory for inspection, without \
+                    verifying or installing it")
+                (aliases: &["extr", "extra", "extrac"])
+                (@arg SOURCE: +required {file_exists}
+                    "A path to a Habitat Artifact \
+                    (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
+                (@arg DEST_DIR: +required +takes_value
+                    "The directory to unpack the artifact's files into (ex: /tmp/redis)")
             )
             (@subcommand hash =>
                 (about: "Generates a blake2b hashsum from a target at any given filepath")
@@ -174,6 +302,22 @@ pub fn get() -> App<'static, 'static> {
             )
             (subcommand: sub_pkg_install().aliases(
                 &["i", "in", "ins", "inst", "insta", "instal"]))
+            (@subcommand license =>
+                (about: "Prints the licenses of an installed package and its dependencies")
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+            )
+            (@subcommand lock =>
+                (about: "Writes a lockfile pinning the releases that package requirements resolve \
+                    to, along with every one of their dependencies")
+                (@arg REQUIREMENT: +required +multiple
+                    "One or more package identifiers with optional version constraints \
+                    (ex: acme/shop, 'core/redis >=3.2 <4')")
+                (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                    "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
+                (@arg OUTPUT: -o --output +takes_value
+                    "The lockfile to write [default: habitat.lock]")
+            )
             (@subcommand path =>
                 (about: "Prints the path to a specific installed release of a package")
                 (aliases: &["p", "pa", "pat"])
@@ -189,6 +333,14 @@ pub fn get() -> App<'static, 'static> {
                     (ex: core/busybox-static/1.24.2/20160708162350)")
                 (@arg FULL_PATHS: -p "Show full path to file")
             )
+            (@subcommand sbom =>
+                (about: "Prints the software bill of materials of an installed package and its \
+                    dependencies")
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg FORMAT: --format +takes_value {valid_sbom_format}
+                    "Document format: cyclonedx or spdx (default: cyclonedx)")
+            )
             (@subcommand search =>
                 (about: "Search for a package on a Depot")
                 (@arg SEARCH_TERM: +required +takes_value "Search term")
@@ -206,6 +358,17 @@ pub fn get() -> App<'static, 'static> {
                     "The destination path to the signed Habitat Artifact \
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
             )
+            (@subcommand solve =>
+                (about: "Resolves package requirements to the releases which satisfy them \
+                    together, printing one per line")
+                (@arg REQUIREMENT: +required +multiple
+                    "One or more package identifiers with optional version constraints \
+                    (ex: core/redis, 'core/glibc >=2.27 <3')")
+                (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                    "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
+                (@arg INSTALLED: --installed
+                    "Only consider the packages which are installed")
+            )
             (@subcommand upload =>
                 (about: "Uploads a local Habitat Artifact to a Depot")
                 (aliases: &["u", "up", "upl", "uplo", "uploa"])
@@ -224,9 +387,8 @@ pub fn get() -> App<'static, 'static> {
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
             )
             (@subcommand header =>
-                (about: "Returns the Habitat Artifact header")
+                (about: "Prints the Habitat Artifact header, signing key and package identifier")
                 (aliases: &["hea", "head", "heade", "header"])
-                (@setting Hidden)
                 (@arg SOURCE: +required {file_exists}
                     "A path to a Habitat Artifact \
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
@@ -248,6 +410,16 @@ pub fn get() -> App<'static, 'static> {
                 (@arg NO_CALLBACKS: --nocallbacks -f
                     "Do not include callback functions in template")
             )
+            (@subcommand check =>
+                (about: "Checks a plan for common mistakes without building it")
+                (aliases: &["c", "ch", "che", "chec"])
+                (@arg PLAN_CONTEXT: {dir_exists}
+                    "A directory containing a `plan.sh` file \
+                    or a `habitat/` directory which contains the `plan.sh` file (default: .)")
+                (@arg FORMAT: --format +takes_value {valid_check_format}
+                    "Output format for the findings: text or json (default: text)")
+                (@arg STRICT: --strict "Fail if there are any warnings, not only errors")
+            )
         )
         (@subcommand ring =>
             (about: "Commands relating to Habitat rings")
@@ -273,10 +445,18 @@ pub fn get() -> App<'static, 'static> {
                     (@arg RING: +required +takes_value "Ring key name")
                 )
             )
+            (subcommand: sub_ring_exec())
+        )
+        (@subcommand scale =>
+            (about: "Commands relating to the desired size of service groups")
+            (aliases: &["sc", "sca", "scal"])
+            (@setting ArgRequiredElseHelp)
+            (subcommand: sub_scale_set())
+            (subcommand: sub_scale_status().aliases(&["st", "sta", "stat", "statu"]))
         )
         (@subcommand service =>
             (about: "Commands relating to Habitat services")
-            (aliases: &["se", "ser", "serv", "servi", "servic"])
+            (aliases: &["se", "ser", "serv", "servi", "servic", "svc"])
             (@setting ArgRequiredElseHelp)
             (@subcommand key =>
                 (about: "Commands relating to Habitat service keys")
@@ -290,6 +470,11 @@ pub fn get() -> App<'static, 'static> {
                     (@arg ORG: "The service organization")
                 )
             )
+            (subcommand: sub_service_elect().aliases(&["e", "el", "ele", "elec"]))
+            (subcommand: sub_service_logs().aliases(&["l", "lo", "log"]))
+            (subcommand: sub_service_status().aliases(&["st", "sta", "stat", "statu"]))
+            (subcommand: sub_service_restart())
+            (subcommand: sub_service_switch())
         )
         (@subcommand studio =>
             (about: "Commands relating to Habitat Studios")
@@ -326,6 +511,29 @@ pub fn get() -> App<'static, 'static> {
             \n"
         )
     )
+    .arg(Arg::with_name("VERBOSE")
+        .help("Prints more output, where a command has more to say")
+        .short("v")
+        .long("verbose")
+        .global(true))
+    .arg(Arg::with_name("QUIET")
+        .help("Prints only warnings and errors")
+        .short("q")
+        .long("quiet")
+        .conflicts_with("VERBOSE")
+        .global(true))
+    .arg(Arg::with_name("NO_COLOR")
+        .help("Turns ANSI color off")
+        .long("no-color")
+        .global(true))
+    .arg(Arg::with_name("JSON")
+        .help("Prints messages as JSON objects, one per line")
+        .long("json")
+        .global(true))
+    .arg(Arg::with_name("JSON_ERRORS")
+        .help("Prints failures as JSON objects with a stable error code")
+        .long("json-errors")
+        .global(true))
 }
 
 fn alias_start() -> App<'static, 'static> {
@@ -341,6 +549,16 @@ fn sub_cli_setup() -> App<'static, 'static> {
     )
 }
 
+fn sub_cli_analytics() -> App<'static, 'static> {
+    let sub = clap_app!(@subcommand analytics =>
+        (about: "Turns anonymous usage reporting on or off, or shows what would be reported"));
+
+    sub.arg(Arg::with_name("ACTION")
+        .help("Whether to turn analytics on or off, or show their status [default: status]")
+        .takes_value(true)
+        .possible_values(&["on", "off", "status"]))
+}
+
 fn sub_cli_completers() -> App<'static, 'static> {
     let sub = clap_app!(@subcommand completers =>
         (about: "Creates command-line completers for your shell."));
@@ -361,8 +579,25 @@ fn sub_cli_completers() -> App<'static, 'static> {
         .possible_values(&supported_shells))
 }
 
+fn sub_cli_complete() -> App<'static, 'static> {
+    let sub = clap_app!(@subcommand complete =>
+        (about: "Lists candidates for the dynamic parts of the command-line completers")
+        (@setting Hidden));
+
+    sub.arg(Arg::with_name("KIND")
+            .help("What to list")
+            .required(true)
+            .takes_value(true)
+            .possible_values(&["packages", "services"]))
+        .arg(Arg::with_name("SUP_ADDR")
+            .help("Address of the Supervisor's HTTP gateway [default: 127.0.0.1:9631]")
+            .short("r")
+            .long("remote-sup")
+            .takes_value(true))
+}
+
 fn sub_config_apply() -> App<'static, 'static> {
-    clap_app!(@subcommand apply =>
+    let sub = clap_app!(@subcommand apply =>
         (about: "Applies a configuration to a group of Habitat Supervisors")
         (@arg PEER: -p --peer +takes_value
             "A comma-delimited list of one or more Habitat Supervisor peers to infect \
@@ -374,11 +609,166 @@ fn sub_config_apply() -> App<'static, 'static> {
         (@arg VERSION_NUMBER: +required
             "A version number (positive integer) for this configuration (ex: 42)")
         (@arg FILE: {file_exists_or_stdin}
-            "Path to local file on disk (ex: /tmp/config.toml, default: <stdin>)")
+            "Path to local file on disk (ex: /tmp/config.toml, default: <stdin>, or none when \
+            only deleting keys)")
+        (@arg ORG: --org +takes_value "Name of service organization")
+        (@arg PATCH: --patch
+            "Send only the keys to change, to be merged over the previous version's configuration")
+    );
+
+    sub.arg(Arg::with_name("DELETE")
+            .help("A dotted key for the patch to remove (ex: tls.key); may be given more than once")
+            .long("delete")
+            .value_name("KEY")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(1)
+            .requires("PATCH"))
+        .arg(Arg::with_name("MEMBER")
+            .help("Apply only to the member with this id; may be given more than once")
+            .long("member")
+            .value_name("MEMBER_ID")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(1))
+        .arg(Arg::with_name("LABEL")
+            .help("Apply only to members started with this label (ex: zone=us-east-1a); may be \
+                   given more than once")
+            .long("label")
+            .value_name("KEY=VALUE")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(1)
+            .validator(valid_label))
+        .arg(Arg::with_name("PERCENT")
+            .help("Apply only to this percentage of the members, the same ones each time (ex: 10)")
+            .long("percent")
+            .takes_value(true)
+            .validator(valid_percent))
+        .arg(Arg::with_name("DRY_RUN")
+            .help("Have one Supervisor render the configuration and run the package's \
+                   validate_config hook against it, and show what would change, without applying \
+                   it")
+            .long("dry-run"))
+        .arg(Arg::with_name("SUP_ADDR")
+            .help("Address of the HTTP gateway of the Supervisor which makes the dry run \
+                   [default: 127.0.0.1:9631]")
+            .long("remote-sup")
+            .takes_value(true)
+            .requires("DRY_RUN"))
+}
+
+fn sub_ring_exec() -> App<'static, 'static> {
+    let sub = clap_app!(@subcommand exec =>
+        (about: "Runs a command on every Supervisor in the ring which trusts the signing origin, \
+            and reports each one's result")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers to infect \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+        (@arg ORIGIN: -o --origin +takes_value
+            "The origin whose latest signing key signs the command")
+        (@arg WAIT: -w --wait +takes_value {valid_count}
+            "Seconds to wait for acknowledgements (default: 10)")
+    );
+    sub.arg(Arg::with_name("SUP_ADDR")
+            .help("Address of the HTTP gateway of the Supervisor acknowledgements are read \
+                  from [default: 127.0.0.1:9631]")
+            .long("remote-sup")
+            .takes_value(true))
+        .arg(Arg::with_name("COMMAND")
+            .help("The command to run")
+            .required(true)
+            .possible_values(&["restart", "log-level"]))
+        .arg(Arg::with_name("ARGS")
+            .help("The service groups to restart (ex: redis.default), or the level and optional \
+                  subsystem to log at (ex: debug gossip)")
+            .multiple(true))
+}
+
+fn sub_scale_set() -> App<'static, 'static> {
+    clap_app!(@subcommand set =>
+        (about: "Sets the number of members a service group should have")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers to infect \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg VERSION_NUMBER: +required
+            "A version number (positive integer) for this desired count (ex: 42)")
+        (@arg COUNT: +required "The number of members the service group should have (ex: 3)")
+        (@arg ORG: --org +takes_value "Name of service organization")
+    )
+}
+
+fn sub_service_restart() -> App<'static, 'static> {
+    clap_app!(@subcommand restart =>
+        (about: "Restarts a service group on every Supervisor running it, one member at a time \
+            with --rolling")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers to infect \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Service group to restart (ex: redis.default)")
+        (@arg VERSION_NUMBER: +required
+            "A version number (positive integer) for this restart (ex: 42)")
+        (@arg ROLLING: --rolling
+            "Restart members one at a time, leader last, each once the one before it is healthy")
+        (@arg ORG: --org +takes_value "Name of service organization")
+    )
+}
+
+fn sub_service_switch() -> App<'static, 'static> {
+    clap_app!(@subcommand switch =>
+        (about: "Makes a service group the active color of its service, so that binds to the \
+            service follow it")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers to infect \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Service group to make active (ex: app.green)")
+        (@arg VERSION_NUMBER: +required
+            "A version number (positive integer) for this switch (ex: 42)")
         (@arg ORG: --org +takes_value "Name of service organization")
     )
 }
 
+fn sub_scale_status() -> App<'static, 'static> {
+    let sub = clap_app!(@subcommand status =>
+        (about: "Report the desired and alive member counts of service groups")
+        (@arg SERVICE_GROUP: +takes_value {valid_service_group}
+            "Only report this service group (ex: redis.default)")
+    );
+    sub.arg(Arg::with_name("SUP_ADDR")
+        .help("Address of the Supervisor's HTTP gateway [default: 127.0.0.1:9631]")
+        .short("r")
+        .long("remote-sup")
+        .takes_value(true))
+}
+
+fn sub_pkg_audit() -> App<'static, 'static> {
+    let sub = clap_app!(@subcommand audit =>
+        (about: "Audits installed packages for known vulnerabilities")
+        (@arg DB: --db +takes_value {file_exists}
+            "Audits against this vulnerability database instead of the installed one")
+        (@arg UPDATE: --update +takes_value {file_exists} conflicts_with[DB]
+            "Installs the vulnerability database at this path, replacing the current one")
+    );
+    sub.arg(Arg::with_name("SUP_ADDR")
+        .help("Address of the HTTP gateway of the Supervisor whose services are audited \
+              [default: 127.0.0.1:9631]")
+        .short("r")
+        .long("remote-sup")
+        .takes_value(true))
+}
+
 fn sub_pkg_build() -> App<'static, 'static> {
     let sub = clap_app!(@subcommand build =>
         (about: "Builds a Plan using a Studio")
@@ -389,6 +779,9 @@ fn sub_pkg_build() -> App<'static, 'static> {
             "Sets the Studio root (default: /hab/studios/<DIR_NAME>)")
         (@arg SRC_PATH: -s --src +takes_value
             "Sets the source path (default: $PWD)")
+        (@arg REPRODUCIBLE: --reproducible
+            "Builds reproducibly, pinning the build time to $SOURCE_DATE_EPOCH \
+            (default: the time of the last commit to the Plan)")
         (@arg PLAN_CONTEXT: +required +takes_value
             "A directory containing a `plan.sh` file \
             or a `habitat/` directory which contains the `plan.sh` file")
@@ -411,9 +804,13 @@ fn sub_pkg_install() -> App<'static, 'static> {
         (@arg DEPOT_URL: -u --url +takes_value {valid_url}
             "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
         (@arg PKG_IDENT_OR_ARTIFACT: +required +multiple
-            "One or more Habitat package identifiers (ex: acme/redis) and/or filepaths \
-            to a Habitat Artifact (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
+            "One or more Habitat package identifiers (ex: acme/redis) and/or filepaths, \
+            file:// URLs, directories or globs of Habitat Artifacts \
+            (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart, 'results/*.hart')")
         (@arg BINLINK: -b --binlink "Binlink all binaries from installed package(s)")
+        (@arg LOCKED: --locked +takes_value {file_exists}
+            "Install the releases this lockfile pins, refusing any package with a dependency it \
+            doesn't pin (ex: habitat.lock)")
     );
     sub.arg(Arg::with_name("IGNORE_TARGET")
         .help("Skips target validation for package installation.")
@@ -422,6 +819,69 @@ fn sub_pkg_install() -> App<'static, 'static> {
         .hidden(true))
 }
 
+fn sub_service_elect() -> App<'static, 'static> {
+    let sub = clap_app!(@subcommand elect =>
+        (about: "Starts a new leader election for a service group, in which the Supervisor \
+            asked stands to become the leader")
+        (@arg SERVICE_GROUP: +required +takes_value {valid_service_group}
+            "The service group to elect a leader of (ex: redis.default)")
+    );
+    sub.arg(Arg::with_name("FORCE")
+            .help("Start the election even though the group's leader is alive, to replace a \
+                   leader which is degraded")
+            .long("force"))
+        .arg(Arg::with_name("SUP_ADDR")
+            .help("Address of the control gateway of a Supervisor in the group other than the \
+                   leader [default: 127.0.0.1:9632]")
+            .short("r")
+            .long("remote-sup")
+            .takes_value(true))
+}
+
+fn sub_service_logs() -> App<'static, 'static> {
+    let sub = clap_app!(@subcommand logs =>
+        (about: "Print the output a service running under a Supervisor has written")
+        (@arg SERVICE_GROUP: +required +takes_value {valid_service_group}
+            "The service group to print the output of (ex: redis.default)")
+    );
+    sub.arg(Arg::with_name("LINES")
+            .help("Only print the last <count> lines")
+            .short("n")
+            .long("lines")
+            .value_name("count")
+            .validator(valid_count))
+        .arg(Arg::with_name("SINCE")
+            .help("Only print lines written in the last <seconds>")
+            .long("since")
+            .value_name("seconds")
+            .validator(valid_count))
+        .arg(Arg::with_name("FOLLOW")
+            .help("Keep printing lines as they are written")
+            .short("f")
+            .long("follow"))
+        .arg(Arg::with_name("SUP_ADDR")
+            .help("Address of the Supervisor's HTTP gateway [default: 127.0.0.1:9631]")
+            .short("r")
+            .long("remote-sup")
+            .takes_value(true))
+}
+
+fn sub_service_status() -> App<'static, 'static> {
+    let sub = clap_app!(@subcommand status =>
+        (about: "Report the state of the services running under a Supervisor")
+        (@arg SERVICE_GROUP: +takes_value {valid_service_group}
+            "Only report this service group (ex: redis.default)")
+    );
+    sub.arg(Arg::with_name("FOLLOW_HOOKS")
+            .help("Print the output of the services' hooks as they run")
+            .long("follow-hooks"))
+        .arg(Arg::with_name("SUP_ADDR")
+            .help("Address of the Supervisor's HTTP gateway [default: 127.0.0.1:9631]")
+            .short("r")
+            .long("remote-sup")
+            .takes_value(true))
+}
+
 fn file_exists(val: String) -> result::Result<(), String> {
     if Path::new(&val).is_file() {
         Ok(())
@@ -430,10 +890,78 @@ fn file_exists(val: String) -> result::Result<(), String> {
     }
 }
 
+fn dir_exists(val: String) -> result::Result<(), String> {
+    if Path::new(&val).is_dir() {
+        Ok(())
+    } else {
+        Err(format!("Directory: '{}' cannot be found", &val))
+    }
+}
+
 fn file_exists_or_stdin(val: String) -> result::Result<(), String> {
     if val == "-" { Ok(()) } else { file_exists(val) }
 }
 
+fn valid_job_id(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("JOB_ID: '{}' is not a valid job id", &val)),
+    }
+}
+
+fn valid_count(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("'{}' is not a whole number", &val)),
+    }
+}
+
+fn valid_check_format(val: String) -> result::Result<(), String> {
+    match val.as_str() {
+        "text" | "json" => Ok(()),
+        _ => Err(format!("FORMAT: '{}' is not a valid format, must be text or json", &val)),
+    }
+}
+
+fn valid_sbom_format(val: String) -> result::Result<(), String> {
+    match val.as_str() {
+        "cyclonedx" | "spdx" => Ok(()),
+        _ => Err(format!("FORMAT: '{}' is not a valid format, must be cyclonedx or spdx", &val)),
+    }
+}
+
+fn valid_secret_name(val: String) -> result::Result<(), String> {
+    let regex = Regex::new(r"^[A-Za-z_][A-Za-z0-9_]*$").unwrap();
+    if regex.is_match(&val) {
+        Ok(())
+    } else {
+        Err(format!("NAME: '{}' is invalid, must be a valid environment variable name", &val))
+    }
+}
+
+fn valid_origin_and_name(val: String) -> result::Result<(), String> {
+    let regex = Regex::new(r"^[A-Za-z0-9_-]+/[A-Za-z0-9_-]+$").unwrap();
+    if regex.is_match(&val) {
+        Ok(())
+    } else {
+        Err(format!("PROJECT: '{}' is invalid, must be in origin/name format", &val))
+    }
+}
+
+fn valid_label(val: String) -> result::Result<(), String> {
+    match val.find('=') {
+        Some(i) if i > 0 => Ok(()),
+        _ => Err(format!("LABEL: '{}' is invalid, must be in key=value format", &val)),
+    }
+}
+
+fn valid_percent(val: String) -> result::Result<(), String> {
+    match val.parse::<u32>() {
+        Ok(n) if n >= 1 && n <= 100 => Ok(()),
+        _ => Err(format!("PERCENT: '{}' is invalid, must be from 1 to 100", &val)),
+    }
+}
+
 fn valid_pair_type(val: String) -> result::Result<(), String> {
     match PairType::from_str(&val) {
         Ok(_) => Ok(()),
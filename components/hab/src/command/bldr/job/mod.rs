@@ -0,0 +1,51 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod start;
+pub mod status;
+
+use std::thread;
+use std::time::Duration;
+
+use common::ui::UI;
+use depot_client::{Client, JobState};
+
+use error::{Error, Result};
+
+/// How long to wait between checks for new lines of a job's log when following it.
+const FOLLOW_INTERVAL_SECS: u64 = 2;
+
+// shared between bldr::job::start and bldr::job::status
+fn follow(ui: &mut UI, depot_client: &Client, id: u64, token: &str) -> Result<()> {
+    let mut next_line = 0;
+    loop {
+        let log = try!(depot_client.job_log(id, next_line, token));
+        for line in log.get_content() {
+            try!(ui.line(line));
+        }
+        next_line = log.get_stop();
+        if !log.get_is_complete() {
+            thread::sleep(Duration::from_secs(FOLLOW_INTERVAL_SECS));
+            continue;
+        }
+        let job = try!(depot_client.show_job(id, token));
+        return match job.get_state() {
+            JobState::Complete => {
+                try!(ui.end(format!("Job {} complete.", id)));
+                Ok(())
+            }
+            _ => Err(Error::JobFailed(id, job.get_error().get_msg().to_string())),
+        };
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common::ui::{Status, UI};
+use depot_client::Client;
+
+use super::follow;
+use {PRODUCT, VERSION};
+use error::Result;
+
+pub fn start(ui: &mut UI, url: &str, id: u64, token: &str, watch: bool) -> Result<()> {
+    let depot_client = try!(Client::new(url, PRODUCT, VERSION, None));
+    if watch {
+        try!(ui.begin(format!("Following build job {}", id)));
+        return follow(ui, &depot_client, id, token);
+    }
+    let job = try!(depot_client.show_job(id, token));
+    try!(ui.status(Status::Custom('→', format!("{:?}", job.get_state())),
+                   format!("job {}", job.get_id())));
+    if job.has_error() {
+        try!(ui.warn(job.get_error().get_msg()));
+    }
+    Ok(())
+}
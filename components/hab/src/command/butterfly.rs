@@ -80,6 +80,8 @@ mod inner {
                 ("config", _) => "config",
                 ("file", "upload") => "file upload",
                 ("file", _) => "file",
+                ("scale", "set") => "scale set",
+                (_, "switch") => "svc switch",
                 (_, _) => unreachable!(),
             };
         try!(ui.warn(format!("Running `{}` on this operating system is not currently \
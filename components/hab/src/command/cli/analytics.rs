@@ -0,0 +1,68 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Turns analytics on or off, or shows exactly what would be sent.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab cli analytics status
+//! ```
+//!
+//! Will print whether analytics are enabled, where events are sent, and each event which is
+//! waiting to be sent, decoded field by field.
+
+use std::path::Path;
+
+use common::ui::UI;
+use url::percent_encoding::percent_decode;
+
+use analytics;
+use error::Result;
+
+pub fn start(ui: &mut UI, analytics_path: &Path, action: &str) -> Result<()> {
+    match action {
+        "on" => analytics::turn_on(ui, analytics_path),
+        "off" => analytics::opt_out(ui, analytics_path),
+        _ => status(analytics_path),
+    }
+}
+
+fn status(analytics_path: &Path) -> Result<()> {
+    let state = match analytics::is_opted_in(analytics_path) {
+        Some(true) => "on",
+        Some(false) => "off",
+        None => "off (never set up)",
+    };
+    println!("Analytics: {}", state);
+    println!("Endpoint: {}", analytics::endpoint());
+    let events = analytics::pending_events(analytics_path);
+    if events.is_empty() {
+        println!("No events are waiting to be sent");
+        return Ok(());
+    }
+    println!("{} events are waiting to be sent:", events.len());
+    for (path, payload) in events {
+        println!("");
+        println!("{}", path.display());
+        for field in payload.split('&') {
+            let mut pair = field.splitn(2, '=');
+            let key = pair.next().unwrap_or_default();
+            let value = percent_decode(pair.next().unwrap_or_default().as_bytes())
+                .decode_utf8_lossy();
+            println!("    {}={}", key, value);
+        }
+    }
+    Ok(())
+}
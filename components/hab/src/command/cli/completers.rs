@@ -0,0 +1,156 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generates shell completion scripts for `hab`.
+//!
+//! The static part of each script comes from the clap definitions. For bash, zsh and fish a
+//! dynamic part is added which completes package identifiers and service groups by calling back
+//! into `hab cli complete`, so completions reflect what is installed and running at the time.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab cli completers --shell bash > /etc/bash_completion.d/hab
+//! ```
+
+use std::io::{self, Write};
+use std::path::Path;
+
+use clap::Shell;
+use hcore::package::PackageInstall;
+
+use command;
+use cli;
+use error::{Error, Result};
+
+const BASH_DYNAMIC: &'static str = r#"
+_hab_dynamic() {
+    local cur="${COMP_WORDS[COMP_CWORD]}"
+    local kind=""
+    case "${COMP_WORDS[1]} ${COMP_WORDS[2]}" in
+        "pkg binlink"|"pkg config"|"pkg exec"|"pkg path"|"sup start")
+            [[ ${COMP_CWORD} -eq 3 ]] && kind=packages ;;
+        "svc status"|"svc logs"|"service status"|"service logs"|"scale status")
+            [[ ${COMP_CWORD} -eq 3 ]] && kind=services ;;
+        "start "*)
+            [[ ${COMP_CWORD} -eq 2 ]] && kind=packages ;;
+    esac
+    if [[ -n "${kind}" ]]; then
+        COMPREPLY=( $(compgen -W "$(hab cli complete ${kind} 2>/dev/null)" -- "${cur}") )
+        return 0
+    fi
+    _hab "$@"
+}
+
+complete -F _hab_dynamic -o bashdefault -o default hab
+"#;
+
+const ZSH_DYNAMIC: &'static str = r#"
+_hab() {
+    local kind
+    case "${words[2]} ${words[3]}" in
+        (pkg\ binlink|pkg\ config|pkg\ exec|pkg\ path|sup\ start)
+            (( CURRENT == 4 )) && kind=packages ;;
+        (svc\ status|svc\ logs|service\ status|service\ logs|scale\ status)
+            (( CURRENT == 4 )) && kind=services ;;
+        (start\ *)
+            (( CURRENT == 3 )) && kind=packages ;;
+    esac
+    if [[ -n "$kind" ]]; then
+        local -a candidates
+        candidates=(${(f)"$(hab cli complete $kind 2>/dev/null)"})
+        compadd -a candidates
+        return
+    fi
+    _hab_static "$@"
+}
+
+_hab "$@"
+"#;
+
+const FISH_DYNAMIC: &'static str = r#"
+complete -c hab -f -a '(hab cli complete packages)' \
+    -n "__fish_seen_subcommand_from pkg; and __fish_seen_subcommand_from binlink config exec path"
+complete -c hab -f -a '(hab cli complete packages)' -n "__fish_seen_subcommand_from start"
+complete -c hab -f -a '(hab cli complete services)' \
+    -n "__fish_seen_subcommand_from svc service scale; and __fish_seen_subcommand_from status logs"
+"#;
+
+/// Print the completion script for the given shell.
+pub fn start(shell: &str) -> Result<()> {
+    let shell = try!(shell.parse::<Shell>()
+        .map_err(|_| Error::ArgumentError("Unsupported shell for completers")));
+    let mut script = Vec::new();
+    cli::get().gen_completions_to("hab", shell, &mut script);
+    let script = String::from_utf8_lossy(&script).into_owned();
+    let script = match shell {
+        Shell::Bash => script + BASH_DYNAMIC,
+        Shell::Zsh => with_zsh_dynamic(script),
+        Shell::Fish => script + FISH_DYNAMIC,
+        Shell::PowerShell => script,
+    };
+    try!(io::stdout().write_all(script.as_bytes()));
+    Ok(())
+}
+
+/// Print the identifiers of the installed packages, one per line.
+pub fn packages(fs_root_path: Option<&Path>) -> Result<()> {
+    for ident in try!(PackageInstall::installed_idents(fs_root_path)) {
+        println!("{}", ident);
+    }
+    Ok(())
+}
+
+/// Print the service groups loaded by the Supervisor, one per line. Nothing is printed if the
+/// Supervisor can't be reached, since there is nothing to complete.
+pub fn services(sup_addr: &str) -> Result<()> {
+    let client = try!(command::sup::client(sup_addr));
+    let services = match client.services() {
+        Ok(services) => services,
+        Err(e) => {
+            debug!("Cannot list services for completion: {}", e);
+            return Ok(());
+        }
+    };
+    for service in services {
+        println!("{}", service.service_group);
+    }
+    Ok(())
+}
+
+/// Rename clap's completion function to `_hab_static` and put a wrapper in front of it which
+/// completes package identifiers and service groups. If the script isn't laid out as expected,
+/// it is left as it is.
+fn with_zsh_dynamic(script: String) -> String {
+    let trimmed = script.trim_right();
+    if !script.contains("\n_hab() {") || !trimmed.ends_with("_hab \"$@\"") {
+        return script;
+    }
+    let body = &trimmed[..trimmed.len() - "_hab \"$@\"".len()];
+    body.replacen("\n_hab() {", "\n_hab_static() {", 1) + ZSH_DYNAMIC
+}
+
+#[cfg(test)]
+mod test {
+    use super::with_zsh_dynamic;
+
+    #[test]
+    fn zsh_completion_function_is_wrapped() {
+        let script = "#compdef hab\n\n_hab() {\n    _arguments\n}\n\n_hab \"$@\"".to_string();
+        let wrapped = with_zsh_dynamic(script);
+        assert!(wrapped.contains("\n_hab_static() {\n    _arguments\n}"));
+        assert!(wrapped.contains("_hab_static \"$@\""));
+        assert!(wrapped.trim_right().ends_with("\n_hab \"$@\""));
+    }
+}
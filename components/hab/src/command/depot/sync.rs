@@ -0,0 +1,117 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mirrors origins from an upstream [Depot](../depot) into a local directory.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab depot sync core acme -d /mnt/mirror -c stable
+//! ```
+//!
+//! Will download every public origin key and every package release in the `stable` channel of
+//! the `core` and `acme` origins which isn't already in `/mnt/mirror`. Each origin is mirrored
+//! into its own directory, and the files already present are what make a sync incremental, so
+//! running it again only fetches what was released since. The resulting tree can be carried
+//! into an air-gapped network and uploaded with `hab pkg bulkupload`.
+
+use std::path::Path;
+
+use common::ui::{Status, UI};
+use common::command::package::install::{RETRIES, RETRY_WAIT};
+use depot_client::{self, Client};
+use hcore::package::PackageIdent;
+
+use {PRODUCT, VERSION};
+use error::{Error, Result};
+
+use retry::retry;
+
+pub fn start<P: AsRef<Path>>(ui: &mut UI,
+                             upstream: &str,
+                             origins: &[&str],
+                             channel: Option<&str>,
+                             dest: P)
+                             -> Result<()> {
+    let depot_client = try!(Client::new(upstream, PRODUCT, VERSION, None));
+    let mut fetched = 0;
+    for origin in origins {
+        let origin_dir = dest.as_ref().join(origin);
+        match channel {
+            Some(channel) => {
+                try!(ui.begin(format!("Syncing origin {} in channel {} from {}",
+                                      origin,
+                                      channel,
+                                      upstream)))
+            }
+            None => try!(ui.begin(format!("Syncing origin {} from {}", origin, upstream))),
+        }
+        fetched += try!(sync_keys(ui, &depot_client, origin, &origin_dir));
+        let idents = try!(depot_client.list_packages(origin, channel));
+        for ident in idents.iter() {
+            if try!(sync_package(ui, &depot_client, ident, &origin_dir)) {
+                fetched += 1;
+            }
+        }
+    }
+    try!(ui.end(format!("Sync complete, {} new files in {}.", fetched, dest.as_ref().display())));
+    Ok(())
+}
+
+fn sync_keys(ui: &mut UI, depot_client: &Client, origin: &str, dir: &Path) -> Result<usize> {
+    let mut fetched = 0;
+    for key in try!(depot_client.show_origin_keys(origin)).iter() {
+        let name_with_rev = format!("{}-{}", key.get_origin(), key.get_revision());
+        if dir.join(format!("{}.pub", &name_with_rev)).is_file() {
+            try!(ui.status(Status::Cached, format!("public origin key {}", &name_with_rev)));
+            continue;
+        }
+        try!(ui.status(Status::Downloading, format!("public origin key {}", &name_with_rev)));
+        try!(depot_client.fetch_origin_key(key.get_origin(),
+                                           key.get_revision(),
+                                           dir,
+                                           ui.progress()));
+        fetched += 1;
+    }
+    Ok(fetched)
+}
+
+/// Downloads a package release unless it's already in the mirror, returning whether it was
+/// downloaded.
+fn sync_package(ui: &mut UI,
+                depot_client: &Client,
+                ident: &PackageIdent,
+                dir: &Path)
+                -> Result<bool> {
+    // Depot listings only contain fully qualified identifiers
+    let archive_name = ident.archive_name().unwrap();
+    if dir.join(&archive_name).is_file() {
+        try!(ui.status(Status::Cached, ident));
+        return Ok(false);
+    }
+    try!(ui.status(Status::Downloading, ident));
+    if retry(RETRIES,
+             RETRY_WAIT,
+             || depot_client.fetch_package(ident, dir, ui.progress()),
+             |res| res.is_ok())
+        .is_err() {
+        return Err(Error::from(depot_client::Error::DownloadFailed(format!("We tried {} times \
+                                                                            but could not \
+                                                                            download {}. Giving \
+                                                                            up.",
+                                                                           RETRIES,
+                                                                           ident))));
+    }
+    Ok(true)
+}
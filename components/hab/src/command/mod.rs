@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod bldr;
 pub mod butterfly;
 pub mod cli;
+pub mod depot;
 pub mod pkg;
 pub mod plan;
 pub mod origin;
 pub mod ring;
+pub mod scale;
 pub mod service;
 pub mod studio;
 pub mod sup;
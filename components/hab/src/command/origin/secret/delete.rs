@@ -0,0 +1,27 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common::ui::{Status, UI};
+use depot_client::Client;
+
+use {PRODUCT, VERSION};
+use error::Result;
+
+pub fn start(ui: &mut UI, depot: &str, token: &str, origin: &str, name: &str) -> Result<()> {
+    let depot_client = try!(Client::new(depot, PRODUCT, VERSION, None));
+    try!(ui.status(Status::Deleting, format!("origin secret {} from {}", name, origin)));
+    try!(depot_client.delete_origin_secret(origin, name, token));
+    try!(ui.end(format!("Deleted origin secret {}.", name)));
+    Ok(())
+}
@@ -0,0 +1,26 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use depot_client::Client;
+
+use {PRODUCT, VERSION};
+use error::Result;
+
+pub fn start(depot: &str, token: &str, origin: &str) -> Result<()> {
+    let depot_client = try!(Client::new(depot, PRODUCT, VERSION, None));
+    for name in try!(depot_client.list_origin_secrets(origin, token)) {
+        println!("{}", name);
+    }
+    Ok(())
+}
@@ -0,0 +1,43 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use common::ui::{Status, UI};
+use depot_client::Client;
+use hcore::crypto::SigKeyPair;
+
+use {PRODUCT, VERSION};
+use error::Result;
+
+pub fn start(ui: &mut UI,
+             depot: &str,
+             token: &str,
+             origin: &str,
+             name: &str,
+             value: &str,
+             cache: &Path)
+             -> Result<()> {
+    let depot_client = try!(Client::new(depot, PRODUCT, VERSION, None));
+    try!(ui.begin(format!("Uploading origin secret {} to {}", name, origin)));
+    let pair = try!(SigKeyPair::get_latest_pair_for(origin, cache));
+    try!(ui.status(Status::Encrypting,
+                   format!("value with origin key {}", pair.name_with_rev())));
+    let encrypted = try!(pair.encrypt_secret(value.as_bytes()));
+    try!(ui.status(Status::Uploading, name));
+    try!(depot_client.put_origin_secret(origin, name, &encrypted, token));
+    try!(ui.status(Status::Uploaded, name));
+    try!(ui.end(format!("Upload of origin secret {} complete.", name)));
+    Ok(())
+}
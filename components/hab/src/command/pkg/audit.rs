@@ -0,0 +1,248 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Audits installed packages against a database of known vulnerabilities.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg audit
+//! ```
+//!
+//! Will list every installed package with a known vulnerability, along with the services running
+//! under the local Supervisor which include one.
+//!
+//! ```bash
+//! $ hab pkg audit --update /media/usb/vulnerabilities.toml
+//! ```
+//!
+//! Will install a new vulnerability database, such as one carried onto a host without network
+//! access.
+
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use common::ui::{Status, UI};
+use hcore::package::{PackageIdent, Vulnerability, VulnerabilityDb};
+use serde_json;
+use sup_client::Service;
+
+use command;
+use command::pkg::bundle;
+use error::{Error, Result};
+
+/// An installed package with a known vulnerability.
+#[derive(Serialize)]
+struct Finding<'a> {
+    package: PackageIdent,
+    vulnerability: &'a Vulnerability,
+}
+
+/// A service which runs a vulnerable package, or has one in its dependencies.
+#[derive(Serialize)]
+struct AffectedService {
+    service_group: String,
+    package: PackageIdent,
+    vulnerable: Vec<PackageIdent>,
+}
+
+#[derive(Serialize)]
+struct Report<'a> {
+    database_updated: Option<&'a str>,
+    findings: Vec<Finding<'a>>,
+    /// None if there is no Supervisor to ask about its services.
+    services: Option<Vec<AffectedService>>,
+}
+
+/// Install the vulnerability database at `src` into the vulnerability cache, replacing the
+/// current one.
+pub fn update(ui: &mut UI, src: &Path, fs_root_path: &Path) -> Result<()> {
+    try!(ui.begin(format!("Updating the vulnerability database from {}", src.display())));
+    let db = try!(VulnerabilityDb::load(src));
+    let dst = VulnerabilityDb::default_path(Some(fs_root_path));
+    if let Some(parent) = dst.parent() {
+        try!(fs::create_dir_all(parent));
+    }
+    try!(fs::copy(src, &dst));
+    let updated = db.updated.as_ref().map(|u| u.as_str()).unwrap_or("at an unknown time");
+    try!(ui.status(Status::Installed,
+                   format!("{} known vulnerabilities, updated {}",
+                           db.vulnerabilities.len(),
+                           updated)));
+    try!(ui.end(format!("Vulnerability database installed at {}.", dst.display())));
+    Ok(())
+}
+
+pub fn start(ui: &mut UI,
+             fs_root_path: &Path,
+             db_path: Option<&Path>,
+             sup_addr: &str,
+             json: bool)
+             -> Result<()> {
+    let db_path = match db_path {
+        Some(path) => path.to_path_buf(),
+        None => VulnerabilityDb::default_path(Some(fs_root_path)),
+    };
+    let db = try!(VulnerabilityDb::load(&db_path));
+    let mut findings = Vec::new();
+    for ident in try!(bundle::installed_packages(fs_root_path)).keys() {
+        let ident = try!(PackageIdent::from_str(ident));
+        for vulnerability in db.affecting(&ident) {
+            findings.push(Finding {
+                package: ident.clone(),
+                vulnerability: vulnerability,
+            });
+        }
+    }
+    let services = match running_services(sup_addr) {
+        Ok(services) => Some(affected_services(services, &findings)),
+        Err(e) => {
+            debug!("Not auditing services, err={}", e);
+            None
+        }
+    };
+    let report = Report {
+        database_updated: db.updated.as_ref().map(|u| u.as_str()),
+        findings: findings,
+        services: services,
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report).unwrap());
+    } else {
+        try!(print_report(ui, &report, &db_path, sup_addr));
+    }
+    if report.findings.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::AuditFailed(report.findings.len()))
+    }
+}
+
+fn print_report(ui: &mut UI, report: &Report, db_path: &Path, sup_addr: &str) -> Result<()> {
+    try!(ui.begin(format!("Auditing installed packages against {}", db_path.display())));
+    for finding in report.findings.iter() {
+        let v = finding.vulnerability;
+        println!("{}\t{}\t{}\t{}",
+                 v.id,
+                 v.severity,
+                 finding.package,
+                 v.fixed
+                     .as_ref()
+                     .map(|f| format!("fixed in {}", f))
+                     .unwrap_or(String::from("no fix")));
+    }
+    match report.services {
+        Some(ref services) => {
+            for service in services {
+                try!(ui.warn(format!("Service {} ({}) includes vulnerable packages: {}",
+                                     service.service_group,
+                                     service.package,
+                                     service.vulnerable
+                                         .iter()
+                                         .map(|i| i.to_string())
+                                         .collect::<Vec<_>>()
+                                         .join(", "))));
+            }
+        }
+        None => {
+            try!(ui.warn(format!("No Supervisor answered at {}, so services were not audited",
+                                 sup_addr)))
+        }
+    }
+    if report.findings.is_empty() {
+        try!(ui.end("No known vulnerabilities found in installed packages."));
+    }
+    Ok(())
+}
+
+fn running_services(sup_addr: &str) -> Result<Vec<Service>> {
+    let client = try!(command::sup::client(sup_addr));
+    Ok(try!(client.services()))
+}
+
+fn affected_services(services: Vec<Service>, findings: &[Finding]) -> Vec<AffectedService> {
+    let mut affected = Vec::new();
+    for service in services {
+        let ident = service.package.ident();
+        let tdeps = service.package.tdeps;
+        let mut vulnerable: Vec<PackageIdent> = findings.iter()
+            .map(|f| &f.package)
+            .filter(|p| **p == ident || tdeps.contains(p))
+            .cloned()
+            .collect();
+        vulnerable.dedup();
+        if !vulnerable.is_empty() {
+            affected.push(AffectedService {
+                service_group: service.service_group,
+                package: ident,
+                vulnerable: vulnerable,
+            });
+        }
+    }
+    affected
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::package::{PackageIdent, VulnerabilityDb};
+
+    use sup_client::{Service, ServicePackage, Supervisor};
+
+    use super::{affected_services, Finding};
+
+    #[test]
+    fn affected_services_include_vulnerable_dependencies() {
+        let db = VulnerabilityDb::from_toml(r#"
+            [[vulnerability]]
+            id = "CVE-2016-2105"
+            package = "core/openssl"
+            fixed = "1.0.2"
+            severity = "high"
+            summary = "EVP_EncodeUpdate overflow"
+            "#)
+            .unwrap();
+        let openssl = PackageIdent::from_str("core/openssl/1.0.1/20160708162350").unwrap();
+        let findings = vec![Finding {
+                                package: openssl.clone(),
+                                vulnerability: &db.vulnerabilities[0],
+                            }];
+        let service = |name: &str, tdeps: Vec<PackageIdent>| {
+            Service {
+                service_group: format!("{}.default", name),
+                package: ServicePackage {
+                    origin: "core".to_string(),
+                    name: name.to_string(),
+                    version: "1.0.0".to_string(),
+                    release: "20170101000000".to_string(),
+                    tdeps: tdeps,
+                },
+                supervisor: Supervisor {
+                    pid: None,
+                    state: "Up".to_string(),
+                },
+                binds: vec![],
+            }
+        };
+        let affected = affected_services(vec![service("nginx", vec![openssl.clone()]),
+                                              service("redis", vec![])],
+                                         &findings);
+        assert_eq!(affected.len(), 1);
+        assert_eq!(affected[0].service_group, "nginx.default");
+        assert_eq!(affected[0].vulnerable, vec![openssl]);
+    }
+}
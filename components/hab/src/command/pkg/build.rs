@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::env;
 use std::ffi::OsString;
 
 use common::ui::UI;
@@ -24,8 +25,13 @@ pub fn start(ui: &mut UI,
              root: Option<&str>,
              src: Option<&str>,
              keys: Option<&str>,
-             reuse: bool)
+             reuse: bool,
+             reproducible: bool)
              -> Result<()> {
+    if reproducible {
+        // The Studio passes this through to the build program, along with `$SOURCE_DATE_EPOCH`.
+        env::set_var("HAB_REPRODUCIBLE_BUILD", "true");
+    }
     let mut args: Vec<OsString> = Vec::new();
     if let Some(root) = root {
         args.push("-r".into());
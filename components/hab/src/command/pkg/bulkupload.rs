@@ -0,0 +1,188 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Uploads a directory tree of packages and public origin keys to a [Depot](../depot).
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg bulkupload /mnt/mirror -u http://depot.example.com/v1/depot
+//! ```
+//!
+//! Will upload every public origin key and `.hart` file found under `/mnt/mirror` which the
+//! Depot doesn't already have. Packages are uploaded after any of their dependencies found in
+//! the same tree, so a fresh Depot can be seeded in one pass.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use common::ui::{Status, UI};
+use common::command::package::install::{RETRIES, RETRY_WAIT};
+use depot_client::{self, Client};
+use hcore::crypto::keys::parse_name_with_rev;
+use hcore::crypto::PUBLIC_SIG_KEY_VERSION;
+use hcore::package::{PackageArchive, PackageIdent};
+use hyper::status::StatusCode;
+use walkdir::WalkDir;
+
+use {PRODUCT, VERSION};
+use command::origin::key::get_name_with_rev;
+use command::pkg::upload::upload_into_depot;
+use error::{Error, Result};
+
+use retry::retry;
+
+pub fn start<P: AsRef<Path>>(ui: &mut UI, url: &str, token: &str, upload_dir: P) -> Result<()> {
+    let depot_client = try!(Client::new(url, PRODUCT, VERSION, None));
+    let mut keys = Vec::new();
+    let mut archives = Vec::new();
+    for entry in WalkDir::new(upload_dir.as_ref()).into_iter().filter_map(|e| e.ok()) {
+        let path = entry.path();
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("pub") => keys.push(path.to_path_buf()),
+            Some("hart") => archives.push(path.to_path_buf()),
+            _ => (),
+        }
+    }
+
+    try!(ui.begin(format!("Uploading {} public origin keys from {}",
+                          keys.len(),
+                          upload_dir.as_ref().display())));
+    for keyfile in keys.iter() {
+        try!(upload_key(ui, &depot_client, token, keyfile));
+    }
+
+    try!(ui.begin(format!("Determining upload order of {} packages", archives.len())));
+    let mut packages = HashMap::new();
+    for path in archives.into_iter() {
+        let mut archive = PackageArchive::new(path);
+        let ident = try!(archive.ident());
+        let deps = try!(archive.deps());
+        packages.insert(ident, (archive, deps));
+    }
+    let order = upload_order(packages.iter().map(|(ident, &(_, ref deps))| (ident, deps)));
+
+    let mut uploaded = 0;
+    for ident in order.into_iter() {
+        let archive = &mut packages.get_mut(&ident).unwrap().0;
+        match depot_client.show_package(&ident) {
+            Ok(_) => try!(ui.status(Status::Using, format!("existing {}", &ident))),
+            Err(depot_client::Error::APIError(StatusCode::NotFound, _)) => {
+                if retry(RETRIES,
+                         RETRY_WAIT,
+                         || upload_into_depot(ui, &depot_client, token, &ident, archive),
+                         |res| res.is_ok())
+                    .is_err() {
+                    return Err(Error::from(depot_client::Error::UploadFailed(format!("We tried \
+                                                                                      {} times \
+                                                                                      but could \
+                                                                                      not upload \
+                                                                                      {}. Giving \
+                                                                                      up.",
+                                                                                     RETRIES,
+                                                                                     &ident))));
+                }
+                uploaded += 1;
+            }
+            Err(e) => return Err(Error::from(e)),
+        }
+    }
+    try!(ui.end(format!("Bulk upload complete, {} packages uploaded.", uploaded)));
+    Ok(())
+}
+
+fn upload_key(ui: &mut UI, depot_client: &Client, token: &str, keyfile: &Path) -> Result<()> {
+    let name_with_rev = try!(get_name_with_rev(keyfile, PUBLIC_SIG_KEY_VERSION));
+    let (name, rev) = try!(parse_name_with_rev(&name_with_rev));
+    match depot_client.put_origin_key(&name, &rev, keyfile, token, ui.progress()) {
+        Ok(()) => {
+            try!(ui.status(Status::Uploaded,
+                           format!("public origin key {}", &name_with_rev)));
+        }
+        Err(depot_client::Error::APIError(StatusCode::Conflict, _)) => {
+            try!(ui.status(Status::Using,
+                           format!("existing public origin key {}", &name_with_rev)));
+        }
+        Err(err) => return Err(Error::from(err)),
+    }
+    Ok(())
+}
+
+/// Orders packages so that each comes after any of its dependencies which are also being
+/// uploaded. Dependencies outside the given set are expected to already be in the Depot.
+fn upload_order<'a, I>(packages: I) -> Vec<PackageIdent>
+    where I: Iterator<Item = (&'a PackageIdent, &'a Vec<PackageIdent>)>
+{
+    let graph: HashMap<&PackageIdent, &Vec<PackageIdent>> = packages.collect();
+    let mut idents: Vec<&PackageIdent> = graph.keys().map(|i| *i).collect();
+    // Sorting first keeps the order stable between runs over the same tree.
+    idents.sort();
+    let mut visited = HashSet::new();
+    let mut order = Vec::new();
+    for ident in idents {
+        visit(ident, &graph, &mut visited, &mut order);
+    }
+    order
+}
+
+fn visit<'a>(ident: &'a PackageIdent,
+             graph: &HashMap<&'a PackageIdent, &'a Vec<PackageIdent>>,
+             visited: &mut HashSet<&'a PackageIdent>,
+             order: &mut Vec<PackageIdent>) {
+    if !visited.insert(ident) {
+        return;
+    }
+    for dep in graph[ident].iter() {
+        if graph.contains_key(dep) {
+            visit(dep, graph, visited, order);
+        }
+    }
+    order.push(ident.clone());
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+
+    use super::upload_order;
+
+    fn ident(s: &str) -> PackageIdent {
+        PackageIdent::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn dependencies_come_first() {
+        let glibc = ident("core/glibc/2.22/20160612063629");
+        let openssl = ident("core/openssl/1.0.2h/20160612081127");
+        let redis = ident("core/redis/3.2.1/20160612084237");
+        let none = vec![];
+        let openssl_deps = vec![glibc.clone()];
+        let redis_deps = vec![openssl.clone(), glibc.clone()];
+        let packages = vec![(&redis, &redis_deps), (&glibc, &none), (&openssl, &openssl_deps)];
+
+        let order = upload_order(packages.into_iter());
+        assert_eq!(order, vec![glibc.clone(), openssl.clone(), redis.clone()]);
+    }
+
+    #[test]
+    fn dependencies_outside_the_tree_are_skipped() {
+        let redis = ident("core/redis/3.2.1/20160612084237");
+        let redis_deps = vec![ident("core/glibc/2.22/20160612063629")];
+        let packages = vec![(&redis, &redis_deps)];
+
+        assert_eq!(upload_order(packages.into_iter()), vec![redis.clone()]);
+    }
+}
@@ -0,0 +1,232 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Provenance manifests for exported bundles.
+//!
+//! An exporter installs a set of packages into a bare root file system and ships that tree as a
+//! tarball or container image. Before it does, `sign` writes a manifest to `hab/PROVENANCE`
+//! which lists every package in the tree along with the checksum of the package's signed `FILES`
+//! metadata and the origin key revision that signed it. The manifest itself is signed with the
+//! exporting user's origin key.
+//!
+//! On the receiving side `verify` checks the manifest's signature, then checks that the bundle
+//! contains exactly the packages the manifest lists and that none of their `FILES` metadata has
+//! changed. It then checks each package's `FILES` signature, and the checksum `FILES` records for
+//! every file in the package, which ties the contents of the bundle back to the packages' original
+//! builds.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+
+use common::ui::{Status, UI};
+use hcore::crypto::{artifact, hash, SigKeyPair};
+use hcore::package::PackageIdent;
+
+use error::{Error, Result};
+
+/// Location of the manifest, relative to the root of a bundle.
+pub const PROVENANCE_FILE: &'static str = "hab/PROVENANCE";
+
+/// One package's entry in a manifest; its `FILES` checksum and the key which signed `FILES`.
+#[derive(Debug, PartialEq)]
+struct Provenance {
+    checksum: String,
+    signer: String,
+}
+
+/// Write a manifest of the packages installed under `root`, signed with `pair`.
+pub fn sign(ui: &mut UI, root: &Path, pair: &SigKeyPair) -> Result<()> {
+    try!(ui.begin(format!("Writing provenance manifest for {}", root.display())));
+    let packages = try!(installed_packages(root));
+    let mut content = String::new();
+    for (ident, path) in packages.iter() {
+        let provenance = try!(provenance_for(path));
+        content.push_str(&format!("{} {} {}\n", ident, provenance.checksum, provenance.signer));
+    }
+    let dst = root.join(PROVENANCE_FILE);
+    let unsigned = dst.with_extension("unsigned");
+    {
+        let mut file = try!(File::create(&unsigned));
+        try!(file.write_all(content.as_bytes()));
+    }
+    try!(ui.status(Status::Signing,
+                   format!("manifest of {} packages with {}",
+                           packages.len(),
+                           &pair.name_with_rev())));
+    let signed = artifact::sign(&unsigned, &dst, pair);
+    try!(fs::remove_file(&unsigned));
+    try!(signed);
+    try!(ui.end(format!("Signed provenance manifest {}.", dst.display())));
+    Ok(())
+}
+
+/// Verify that the bundle rooted at `root` matches its signed manifest, and that every file of
+/// every package matches its package's signed `FILES`. The public keys of the origin which signed
+/// the manifest, and of every package's origin, must be in `cache`.
+pub fn verify(ui: &mut UI, root: &Path, cache: &Path) -> Result<()> {
+    try!(ui.begin(format!("Verifying bundle {}", root.display())));
+    let manifest_path = root.join(PROVENANCE_FILE);
+    if !manifest_path.is_file() {
+        return Err(Error::BundleInvalid(format!("no manifest found at {}",
+                                                manifest_path.display())));
+    }
+    let (signer, _) = try!(artifact::verify(&manifest_path, cache));
+    try!(ui.status(Status::Verified, format!("manifest signed with {}", &signer)));
+
+    let mut content = String::new();
+    try!(try!(artifact::get_archive_reader(&manifest_path)).read_to_string(&mut content));
+    let mut expected = try!(parse_manifest(&content));
+    for (ident, path) in try!(installed_packages(root)).into_iter() {
+        let recorded = match expected.remove(&ident) {
+            Some(recorded) => recorded,
+            None => {
+                return Err(Error::BundleInvalid(format!("{} is not in the manifest", ident)))
+            }
+        };
+        if try!(provenance_for(&path)) != recorded {
+            return Err(Error::BundleInvalid(format!("{} does not match the manifest", ident)));
+        }
+        let files = try!(verify_files(root, &ident, &path, cache));
+        try!(ui.status(Status::Verified,
+                       format!("{} signed with {}, {} files", ident, recorded.signer, files)));
+    }
+    if let Some(ident) = expected.keys().next() {
+        return Err(Error::BundleInvalid(format!("{} is in the manifest but not the bundle",
+                                                ident)));
+    }
+    try!(ui.end(format!("Verified bundle {}.", root.display())));
+    Ok(())
+}
+
+/// Returns every package installed under `root`, keyed by identifier.
+pub fn installed_packages(root: &Path) -> Result<BTreeMap<String, PathBuf>> {
+    let mut packages = BTreeMap::new();
+    let pkgs = root.join("hab").join("pkgs");
+    if !pkgs.is_dir() {
+        return Ok(packages);
+    }
+    for origin in try!(fs::read_dir(&pkgs)) {
+        let origin = try!(origin);
+        for name in try!(fs::read_dir(origin.path())) {
+            let name = try!(name);
+            for version in try!(fs::read_dir(name.path())) {
+                let version = try!(version);
+                for release in try!(fs::read_dir(version.path())) {
+                    let release = try!(release);
+                    let ident = PackageIdent::new(origin.file_name().to_string_lossy(),
+                                                  name.file_name().to_string_lossy(),
+                                                  Some(version.file_name().to_string_lossy()),
+                                                  Some(release.file_name().to_string_lossy()));
+                    packages.insert(ident.to_string(), release.path());
+                }
+            }
+        }
+    }
+    Ok(packages)
+}
+
+fn provenance_for(pkg_path: &Path) -> Result<Provenance> {
+    let files = pkg_path.join("FILES");
+    Ok(Provenance {
+        checksum: try!(hash::hash_file(&files)),
+        signer: try!(artifact::artifact_signer(&files)),
+    })
+}
+
+/// Checks the signature of the package's `FILES`, and every file it lists against the checksum
+/// it records. The paths in `FILES` are absolute, so they are looked up under `root`. Returns how
+/// many files were checked.
+fn verify_files(root: &Path, ident: &str, pkg_path: &Path, cache: &Path) -> Result<usize> {
+    let files_path = pkg_path.join("FILES");
+    try!(artifact::verify(&files_path, cache).map_err(|e| {
+        Error::BundleInvalid(format!("the FILES signature of {} is invalid: {}", ident, e))
+    }));
+    let mut content = String::new();
+    try!(try!(artifact::get_archive_reader(&files_path)).read_to_string(&mut content));
+    let mut count = 0;
+    for line in content.lines().filter(|l| !l.trim().is_empty()) {
+        let (checksum, path) = try!(parse_files_entry(line));
+        let file = root.join(path.trim_left_matches('/'));
+        if !file.is_file() {
+            return Err(Error::BundleInvalid(format!("{} of {} is missing", path, ident)));
+        }
+        if try!(hash::hash_file(&file)) != checksum {
+            return Err(Error::BundleInvalid(format!("{} of {} does not match its checksum",
+                                                    path,
+                                                    ident)));
+        }
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Splits a line of `FILES`, a checksum and a path separated by two spaces as `b2sum` writes them.
+fn parse_files_entry(line: &str) -> Result<(&str, &str)> {
+    let mut fields = line.splitn(2, "  ");
+    match (fields.next(), fields.next()) {
+        (Some(checksum), Some(path)) if !checksum.is_empty() && !path.is_empty() => {
+            Ok((checksum, path))
+        }
+        _ => Err(Error::BundleInvalid(format!("malformed FILES entry '{}'", line))),
+    }
+}
+
+fn parse_manifest(content: &str) -> Result<BTreeMap<String, Provenance>> {
+    let mut entries = BTreeMap::new();
+    for line in content.lines().filter(|l| !l.trim().is_empty()) {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() != 3 {
+            return Err(Error::BundleInvalid(format!("malformed manifest entry '{}'", line)));
+        }
+        entries.insert(fields[0].to_string(),
+                       Provenance {
+                           checksum: fields[1].to_string(),
+                           signer: fields[2].to_string(),
+                       });
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_files_entry, parse_manifest, Provenance};
+
+    #[test]
+    fn parse_manifest_entries() {
+        let entries = parse_manifest("core/redis/3.2.4/20161215055911 abc123 \
+                                      core-20160810182414\n\n")
+            .unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries["core/redis/3.2.4/20161215055911"],
+                   Provenance {
+                       checksum: "abc123".to_string(),
+                       signer: "core-20160810182414".to_string(),
+                   });
+    }
+
+    #[test]
+    fn parse_manifest_rejects_malformed_entries() {
+        assert!(parse_manifest("core/redis/3.2.4/20161215055911 abc123\n").is_err());
+    }
+
+    #[test]
+    fn parse_files_entries() {
+        assert_eq!(parse_files_entry("abc123  /hab/pkgs/core/redis/3.2.4/20161215055911/bin/a b")
+                       .unwrap(),
+                   ("abc123", "/hab/pkgs/core/redis/3.2.4/20161215055911/bin/a b"));
+        assert!(parse_files_entry("abc123 /hab/pkgs/core/redis").is_err());
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Prints the environment an installed package runs in under the Supervisor: the `PATH` it
+//! composes for the package's processes, the variables the package and its dependencies export,
+//! and the variables the Supervisor sets itself. Useful for reproducing the environment outside
+//! the Supervisor, where something that works as a service fails.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use hcore::env as henv;
+use hcore::fs;
+use hcore::package::{Identifiable, PackageIdent, PackageInstall};
+use serde_json;
+
+use error::Result;
+
+/// The Supervisor's package, whose interpreter dependency gives services their basic commands.
+const SUP_PACKAGE_IDENT: &'static str = "core/hab-sup";
+#[cfg(unix)]
+const INTERPRETER_IDENT: &'static str = "core/busybox-static";
+#[cfg(target_os = "windows")]
+const INTERPRETER_IDENT: &'static str = "core/powershell";
+/// Set by the Supervisor to the path of the service's census file.
+const CENSUS_ENVVAR: &'static str = "HAB_CENSUS_FILE";
+const CENSUS_FILENAME: &'static str = "census.json";
+/// Variables the Supervisor passes on even to hooks which get a constructed environment.
+#[cfg(unix)]
+const PASSTHROUGH_ENV: &'static [&'static str] = &["LANG", "LC_ALL", "TZ"];
+#[cfg(target_os = "windows")]
+const PASSTHROUGH_ENV: &'static [&'static str] = &["SYSTEMROOT", "SYSTEMDRIVE", "WINDIR",
+                                                   "COMSPEC", "PATHEXT", "TZ"];
+
+pub fn start(ident: &PackageIdent, fs_root_path: &Path, json: bool) -> Result<()> {
+    let pkg_install = try!(PackageInstall::load(ident, Some(fs_root_path)));
+    let mut env = try!(pkg_install.runtime_environment());
+    env.insert(String::from("PATH"), try!(run_path(&pkg_install, fs_root_path)));
+    let census_file = fs::svc_path(pkg_install.service_name()).join(CENSUS_FILENAME);
+    env.insert(String::from(CENSUS_ENVVAR),
+               census_file.to_string_lossy().into_owned());
+    // Hooks sandboxed with `env` keep their home and temporary files in the service's var
+    // directory, rather than the Supervisor's.
+    if try!(pkg_install.svc_hook_sandbox()).iter().any(|s| s == "env") {
+        let var_path = pkg_install.svc_var_path().to_string_lossy().into_owned();
+        env.insert(String::from("HOME"), var_path.clone());
+        env.insert(String::from("TMPDIR"), var_path);
+    }
+    for name in PASSTHROUGH_ENV.iter() {
+        if let Ok(value) = henv::var(name) {
+            env.insert(name.to_string(), value);
+        }
+    }
+    if json {
+        println!("{}", serde_json::to_string_pretty(&env).unwrap());
+    } else {
+        for (name, value) in env.iter() {
+            println!("export {}={}", name, shell_quote(value));
+        }
+    }
+    Ok(())
+}
+
+/// Returns the `PATH` the Supervisor gives the package's processes: the package's runtime path,
+/// then the paths of the Supervisor's interpreter, then the `PATH` the Supervisor was started
+/// with, which here is our own.
+fn run_path(pkg_install: &PackageInstall, fs_root_path: &Path) -> Result<String> {
+    let mut paths: Vec<PathBuf> = env::split_paths(&try!(pkg_install.runtime_path())).collect();
+    paths.extend(try!(interpreter_paths(fs_root_path)));
+    if let Some(path) = env::var_os("PATH") {
+        paths.extend(env::split_paths(&path));
+    }
+    let joined = env::join_paths(paths).expect("Failed to build path string");
+    Ok(joined.to_string_lossy().into_owned())
+}
+
+/// Returns the paths of the interpreter the Supervisor would use: the release the installed
+/// Supervisor depends on, or else the latest release installed. None are returned if neither is
+/// installed, in which case the Supervisor finds the interpreter on its own `PATH`.
+fn interpreter_paths(fs_root_path: &Path) -> Result<Vec<PathBuf>> {
+    let interpreter = PackageIdent::from_str(INTERPRETER_IDENT).unwrap();
+    let sup = PackageIdent::from_str(SUP_PACKAGE_IDENT).unwrap();
+    let ident = match PackageInstall::load(&sup, Some(fs_root_path)) {
+        Ok(sup) => {
+            try!(sup.deps())
+                .into_iter()
+                .find(|dep| dep.satisfies(&interpreter))
+                .unwrap_or(interpreter)
+        }
+        Err(_) => interpreter,
+    };
+    match PackageInstall::load(&ident, Some(fs_root_path)) {
+        Ok(pkg_install) => Ok(try!(pkg_install.paths())),
+        Err(_) => Ok(vec![]),
+    }
+}
+
+/// Quotes a value so a POSIX shell reads it back verbatim.
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace("'", "'\\''"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::shell_quote;
+
+    #[test]
+    fn shell_quote_escapes_single_quotes() {
+        assert_eq!(shell_quote("/hab/pkgs/core/redis/bin"), "'/hab/pkgs/core/redis/bin'");
+        assert_eq!(shell_quote("it's"), "'it'\\''s'");
+    }
+}
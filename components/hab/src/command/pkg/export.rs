@@ -45,12 +45,11 @@ pub fn format_for(ui: &mut UI, value: &str) -> Result<ExportFormat> {
 #[cfg(target_os = "linux")]
 mod inner {
     use std::ffi::OsString;
-    use std::path::Path;
     use std::str::FromStr;
 
     use common::command::package::install;
     use common::ui::{Status, UI};
-    use hcore::fs::{cache_artifact_path, FS_ROOT_PATH};
+    use hcore::fs::{cache_artifact_path, fs_root_path};
     use hcore::package::{PackageIdent, PackageInstall};
     use hcore::url::default_depot_url;
 
@@ -89,6 +88,13 @@ mod inner {
                 };
                 Ok(format)
             }
+            "vm" => {
+                let format = ExportFormat {
+                    pkg_ident: try!(PackageIdent::from_str("core/hab-pkg-vmize")),
+                    cmd: "hab-pkg-vmize".to_string(),
+                };
+                Ok(format)
+            }
             _ => Err(Error::UnsupportedExportFormat(value.to_string())),
         }
     }
@@ -104,7 +110,7 @@ mod inner {
                                     &format_ident.to_string(),
                                     PRODUCT,
                                     VERSION,
-                                    Path::new(FS_ROOT_PATH),
+                                    &fs_root_path(),
                                     &cache_artifact_path(None),
                                     false));
             }
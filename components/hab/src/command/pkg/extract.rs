@@ -0,0 +1,42 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unpacks a Habitat Artifact into a directory so its contents can be inspected.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg extract ./core-redis-3.2.4-20170514150022-x86_64-linux.hart /tmp/redis
+//! ```
+//!
+//! Will unpack the package's files under `/tmp/redis/hab/pkgs/core/redis/...`. The artifact's
+//! signature is not verified and nothing is installed.
+
+use std::fs;
+use std::path::Path;
+
+use common::ui::UI;
+use hcore::package::PackageArchive;
+
+use error::Result;
+
+pub fn start(ui: &mut UI, src: &Path, dst: &Path) -> Result<()> {
+    try!(ui.begin(format!("Extracting {}", src.display())));
+    let mut archive = PackageArchive::new(src);
+    let ident = try!(archive.ident());
+    try!(fs::create_dir_all(dst));
+    try!(archive.extract(dst));
+    try!(ui.end(format!("Extracted {} into {}", ident, dst.display())));
+    Ok(())
+}
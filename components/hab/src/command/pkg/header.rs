@@ -16,22 +16,33 @@ use std::path::Path;
 
 use common::ui::UI;
 use hcore::crypto::artifact;
-use std::io::{self, Write};
+use hcore::crypto::keys::parse_name_with_rev;
+use hcore::package::PackageArchive;
 
 use error::Result;
 
+/// Print the artifact's header and the identifier and target recorded inside it, without
+/// verifying or installing it.
 pub fn start(ui: &mut UI, src: &Path) -> Result<()> {
     try!(ui.begin(format!("Reading package header for {}", &src.display())));
     try!(ui.para(""));
-    if let Ok(header) = artifact::get_artifact_header(src) {
-        try!(io::stdout().write(format!("Package        : {}\n", &src.display()).as_bytes()));
-        try!(io::stdout()
-            .write(format!("Format Version : {}\n", header.format_version).as_bytes()));
-        try!(io::stdout().write(format!("Key Name       : {}\n", header.key_name).as_bytes()));
-        try!(io::stdout().write(format!("Hash Type      : {}\n", header.hash_type).as_bytes()));
-        try!(io::stdout().write(format!("Raw Signature  : {}\n", header.signature_raw).as_bytes()));
-    } else {
-        try!(ui.warn("Failed to read package header."));
+    let header = try!(artifact::get_artifact_header(src));
+    let mut archive = PackageArchive::new(src);
+    let ident = try!(archive.ident());
+    let target = try!(archive.target());
+    println!("Package        : {}", &src.display());
+    println!("Format Version : {}", header.format_version);
+    println!("Key Name       : {}", header.key_name);
+    match parse_name_with_rev(&header.key_name) {
+        Ok((origin, rev)) => {
+            println!("Key Origin     : {}", origin);
+            println!("Key Revision   : {}", rev);
+        }
+        Err(_) => try!(ui.warn(format!("Key name {} has no revision", header.key_name))),
     }
+    println!("Hash Type      : {}", header.hash_type);
+    println!("Raw Signature  : {}", header.signature_raw);
+    println!("Ident          : {}", ident);
+    println!("Target         : {}", target);
     Ok(())
 }
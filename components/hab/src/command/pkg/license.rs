@@ -0,0 +1,53 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Prints the licenses of an installed package and every package in its runtime dependency
+//! closure.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg license core/redis
+//! ```
+//!
+//! Will print each package in the closure of the latest installed `core/redis` along with the
+//! licenses it is distributed under. When the host has a license policy, the licenses it doesn't
+//! allow are marked.
+
+use std::path::Path;
+
+use hcore::package::{LicensePolicy, PackageIdent, PackageInstall};
+
+use error::Result;
+
+pub fn start(ident: &PackageIdent, fs_root_path: &Path) -> Result<()> {
+    let policy = try!(LicensePolicy::load(LicensePolicy::default_path(Some(fs_root_path))));
+    let pkg_install = try!(PackageInstall::load(ident, Some(fs_root_path)));
+    let mut idents = vec![pkg_install.ident().clone()];
+    idents.extend(try!(pkg_install.tdeps()));
+    for ident in idents {
+        let licenses = try!(try!(PackageInstall::load(&ident, Some(fs_root_path))).licenses());
+        let disallowed = policy.as_ref().map_or(vec![], |p| p.disallowed(&licenses));
+        let mut line = if licenses.is_empty() {
+            String::from("(none)")
+        } else {
+            licenses.join(", ")
+        };
+        if !disallowed.is_empty() {
+            line.push_str(" [not allowed by the license policy]");
+        }
+        println!("{}: {}", ident, line);
+    }
+    Ok(())
+}
@@ -0,0 +1,38 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use common::command::package::lock;
+use common::ui::UI;
+
+use error::Result;
+use {PRODUCT, VERSION};
+
+/// Writes a lockfile to `lock_path` pinning the releases the requirements resolve to.
+pub fn start(ui: &mut UI,
+             url: &str,
+             requirements: &[&str],
+             fs_root_path: &Path,
+             lock_path: &Path)
+             -> Result<()> {
+    try!(lock::start(ui,
+                     Some(url),
+                     requirements,
+                     PRODUCT,
+                     VERSION,
+                     fs_root_path,
+                     lock_path));
+    Ok(())
+}
@@ -12,16 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod audit;
 pub mod binlink;
 pub mod build;
+pub mod bundle;
+pub mod bulkupload;
 pub mod config;
+pub mod env;
 pub mod exec;
 pub mod export;
+pub mod extract;
 pub mod hash;
 pub mod header;
+pub mod license;
+pub mod lock;
 pub mod path;
 pub mod provides;
+pub mod sbom;
 pub mod search;
 pub mod sign;
+pub mod solve;
 pub mod upload;
 pub mod verify;
@@ -0,0 +1,438 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Software bills of materials for installed packages.
+//!
+//! A bill of materials lists a package and every package in its runtime dependency closure, along
+//! with the licenses and source each one was built from, as recorded in their metadata. Builds
+//! record a CycloneDX bill of materials in the package's `SBOM` metafile, which is printed as is.
+//! For packages built before that, and for SPDX, the bill of materials is generated from the
+//! installed packages.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg sbom core/redis --format spdx
+//! ```
+//!
+//! Will print an SPDX document for the latest installed `core/redis` and its dependencies.
+
+use std::path::Path;
+use std::result;
+use std::str::FromStr;
+
+use hcore::package::{PackageIdent, PackageInstall};
+use serde_json;
+
+use VERSION;
+use error::{Error, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    CycloneDx,
+    Spdx,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value {
+            "cyclonedx" => Ok(Format::CycloneDx),
+            "spdx" => Ok(Format::Spdx),
+            _ => Err(Error::ArgumentError("SBOM format must be cyclonedx or spdx")),
+        }
+    }
+}
+
+/// A package in a bill of materials.
+#[derive(Debug)]
+struct Component {
+    ident: PackageIdent,
+    licenses: Vec<String>,
+    source: Option<String>,
+    deps: Vec<PackageIdent>,
+}
+
+impl Component {
+    fn load(pkg_install: &PackageInstall) -> Result<Component> {
+        let manifest = try!(pkg_install.manifest());
+        Ok(Component {
+            ident: pkg_install.ident().clone(),
+            licenses: try!(pkg_install.licenses()),
+            source: manifest_source(&manifest),
+            deps: try!(pkg_install.deps()),
+        })
+    }
+
+    fn version(&self) -> &str {
+        self.ident.version.as_ref().map(|v| v.as_str()).unwrap_or("")
+    }
+
+    fn release(&self) -> &str {
+        self.ident.release.as_ref().map(|r| r.as_str()).unwrap_or("")
+    }
+
+    /// A package URL, the identifier both formats use to refer to a package outside the document.
+    fn purl(&self) -> String {
+        purl(&self.ident)
+    }
+}
+
+pub fn start(ident: &PackageIdent, format: Format, fs_root_path: &Path) -> Result<()> {
+    let pkg_install = try!(PackageInstall::load(ident, Some(fs_root_path)));
+    if format == Format::CycloneDx {
+        if let Some(sbom) = try!(pkg_install.sbom()) {
+            println!("{}", sbom);
+            return Ok(());
+        }
+    }
+    let mut components = vec![try!(Component::load(&pkg_install))];
+    for dep in try!(pkg_install.tdeps()) {
+        let dep_install = try!(PackageInstall::load(&dep, Some(fs_root_path)));
+        components.push(try!(Component::load(&dep_install)));
+    }
+    let document = match format {
+        Format::CycloneDx => serde_json::to_string_pretty(&cyclonedx(&components)),
+        Format::Spdx => serde_json::to_string_pretty(&spdx(&components)),
+    };
+    println!("{}", document.unwrap());
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct CdxBom {
+    #[serde(rename = "bomFormat")]
+    bom_format: &'static str,
+    #[serde(rename = "specVersion")]
+    spec_version: &'static str,
+    version: u32,
+    metadata: CdxMetadata,
+    components: Vec<CdxComponent>,
+    dependencies: Vec<CdxDependency>,
+}
+
+#[derive(Serialize)]
+struct CdxMetadata {
+    timestamp: String,
+    tools: Vec<CdxTool>,
+    component: CdxComponent,
+}
+
+#[derive(Serialize)]
+struct CdxTool {
+    vendor: &'static str,
+    name: &'static str,
+    version: &'static str,
+}
+
+#[derive(Serialize)]
+struct CdxComponent {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    #[serde(rename = "bom-ref")]
+    bom_ref: String,
+    group: String,
+    name: String,
+    version: String,
+    purl: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    licenses: Vec<CdxLicenseChoice>,
+    #[serde(rename = "externalReferences", skip_serializing_if = "Vec::is_empty")]
+    external_references: Vec<CdxReference>,
+}
+
+#[derive(Serialize)]
+struct CdxLicenseChoice {
+    license: CdxLicense,
+}
+
+#[derive(Serialize)]
+struct CdxLicense {
+    name: String,
+}
+
+#[derive(Serialize)]
+struct CdxReference {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    url: String,
+}
+
+#[derive(Serialize)]
+struct CdxDependency {
+    #[serde(rename = "ref")]
+    bom_ref: String,
+    #[serde(rename = "dependsOn")]
+    depends_on: Vec<String>,
+}
+
+fn cyclonedx(components: &[Component]) -> CdxBom {
+    let cdx_component = |component: &Component, kind: &'static str| {
+        CdxComponent {
+            kind: kind,
+            bom_ref: component.purl(),
+            group: component.ident.origin.clone(),
+            name: component.ident.name.clone(),
+            version: component.version().to_string(),
+            purl: component.purl(),
+            licenses: component.licenses
+                .iter()
+                .map(|l| CdxLicenseChoice { license: CdxLicense { name: l.clone() } })
+                .collect(),
+            external_references: component.source
+                .iter()
+                .map(|s| {
+                    CdxReference {
+                        kind: "distribution",
+                        url: s.clone(),
+                    }
+                })
+                .collect(),
+        }
+    };
+    CdxBom {
+        bom_format: "CycloneDX",
+        spec_version: "1.2",
+        version: 1,
+        metadata: CdxMetadata {
+            timestamp: release_timestamp(components[0].release()),
+            tools: vec![CdxTool {
+                            vendor: "Habitat",
+                            name: "hab",
+                            version: VERSION,
+                        }],
+            component: cdx_component(&components[0], "application"),
+        },
+        components: components[1..].iter().map(|c| cdx_component(c, "library")).collect(),
+        dependencies: components.iter()
+            .map(|c| {
+                CdxDependency {
+                    bom_ref: c.purl(),
+                    depends_on: c.deps.iter().map(purl).collect(),
+                }
+            })
+            .collect(),
+    }
+}
+
+#[derive(Serialize)]
+struct SpdxDocument {
+    #[serde(rename = "spdxVersion")]
+    spdx_version: &'static str,
+    #[serde(rename = "dataLicense")]
+    data_license: &'static str,
+    #[serde(rename = "SPDXID")]
+    spdx_id: &'static str,
+    name: String,
+    #[serde(rename = "documentNamespace")]
+    document_namespace: String,
+    #[serde(rename = "creationInfo")]
+    creation_info: SpdxCreationInfo,
+    packages: Vec<SpdxPackage>,
+    relationships: Vec<SpdxRelationship>,
+}
+
+#[derive(Serialize)]
+struct SpdxCreationInfo {
+    created: String,
+    creators: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct SpdxPackage {
+    #[serde(rename = "SPDXID")]
+    spdx_id: String,
+    name: String,
+    #[serde(rename = "versionInfo")]
+    version_info: String,
+    #[serde(rename = "downloadLocation")]
+    download_location: String,
+    #[serde(rename = "filesAnalyzed")]
+    files_analyzed: bool,
+    #[serde(rename = "licenseConcluded")]
+    license_concluded: &'static str,
+    #[serde(rename = "licenseDeclared")]
+    license_declared: String,
+    #[serde(rename = "copyrightText")]
+    copyright_text: &'static str,
+    #[serde(rename = "externalRefs")]
+    external_refs: Vec<SpdxExternalRef>,
+}
+
+#[derive(Serialize)]
+struct SpdxExternalRef {
+    #[serde(rename = "referenceCategory")]
+    reference_category: &'static str,
+    #[serde(rename = "referenceType")]
+    reference_type: &'static str,
+    #[serde(rename = "referenceLocator")]
+    reference_locator: String,
+}
+
+#[derive(Serialize)]
+struct SpdxRelationship {
+    #[serde(rename = "spdxElementId")]
+    element: String,
+    #[serde(rename = "relationshipType")]
+    kind: &'static str,
+    #[serde(rename = "relatedSpdxElement")]
+    related_element: String,
+}
+
+fn spdx(components: &[Component]) -> SpdxDocument {
+    let root = &components[0];
+    let mut relationships = vec![SpdxRelationship {
+                                     element: "SPDXRef-DOCUMENT".to_string(),
+                                     kind: "DESCRIBES",
+                                     related_element: spdx_id(&root.ident),
+                                 }];
+    for component in components {
+        for dep in component.deps.iter() {
+            relationships.push(SpdxRelationship {
+                element: spdx_id(&component.ident),
+                kind: "DEPENDS_ON",
+                related_element: spdx_id(dep),
+            });
+        }
+    }
+    SpdxDocument {
+        spdx_version: "SPDX-2.2",
+        data_license: "CC0-1.0",
+        spdx_id: "SPDXRef-DOCUMENT",
+        name: root.ident.to_string(),
+        document_namespace: format!("https://habitat.sh/spdx/{}", root.ident),
+        creation_info: SpdxCreationInfo {
+            created: release_timestamp(root.release()),
+            creators: vec![format!("Tool: hab-{}", VERSION)],
+        },
+        packages: components.iter()
+            .map(|c| {
+                SpdxPackage {
+                    spdx_id: spdx_id(&c.ident),
+                    name: format!("{}/{}", c.ident.origin, c.ident.name),
+                    version_info: format!("{}/{}", c.version(), c.release()),
+                    download_location: c.source
+                        .clone()
+                        .unwrap_or(String::from("NOASSERTION")),
+                    files_analyzed: false,
+                    license_concluded: "NOASSERTION",
+                    license_declared: if c.licenses.is_empty() {
+                        String::from("NOASSERTION")
+                    } else {
+                        c.licenses.join(" AND ")
+                    },
+                    copyright_text: "NOASSERTION",
+                    external_refs: vec![SpdxExternalRef {
+                                            reference_category: "PACKAGE-MANAGER",
+                                            reference_type: "purl",
+                                            reference_locator: c.purl(),
+                                        }],
+                }
+            })
+            .collect(),
+        relationships: relationships,
+    }
+}
+
+fn purl(ident: &PackageIdent) -> String {
+    let mut purl = format!("pkg:generic/{}/{}", ident.origin, ident.name);
+    if let Some(ref version) = ident.version {
+        purl.push_str(&format!("@{}", version));
+    }
+    if let Some(ref release) = ident.release {
+        purl.push_str(&format!("?release={}", release));
+    }
+    purl
+}
+
+/// SPDX identifiers may only contain letters, numbers, `.` and `-`.
+fn spdx_id(ident: &PackageIdent) -> String {
+    let id: String = ident.to_string()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '.' { c } else { '-' })
+        .collect();
+    format!("SPDXRef-{}", id)
+}
+
+/// The time a package was built, from its `YYYYMMDDhhmmss` release. Using this rather than the
+/// current time keeps the bill of materials of a reproducible build reproducible.
+fn release_timestamp(release: &str) -> String {
+    if release.len() != 14 || !release.chars().all(|c| c.is_digit(10)) {
+        return String::from("1970-01-01T00:00:00Z");
+    }
+    format!("{}-{}-{}T{}:{}:{}Z",
+            &release[0..4],
+            &release[4..6],
+            &release[6..8],
+            &release[8..10],
+            &release[10..12],
+            &release[12..14])
+}
+
+/// The source a package was built from, from its `MANIFEST`'s `* __Source__: [url](url)` line.
+fn manifest_source(manifest: &str) -> Option<String> {
+    manifest_field(manifest, "Source").and_then(|value| {
+        let url = value.trim_left_matches('[').split(']').next().unwrap_or("").trim();
+        if url.is_empty() {
+            None
+        } else {
+            Some(url.to_string())
+        }
+    })
+}
+
+fn manifest_field<'a>(manifest: &'a str, field: &str) -> Option<&'a str> {
+    let prefix = format!("* __{}__:", field);
+    manifest.lines()
+        .find(|l| l.starts_with(&prefix))
+        .map(|l| l[prefix.len()..].trim())
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+
+    use super::{manifest_source, purl, release_timestamp, spdx_id};
+
+    const MANIFEST: &'static str = "# core / redis\n\
+                                    Persistent key-value database\n\
+                                    \n\
+                                    * __Maintainer__: The Habitat Maintainers\n\
+                                    * __License__: BSD-3-Clause MIT \n\
+                                    * __Source__: [http://download.redis.io/releases/redis-3.2.4.\
+                                    tar.gz](http://download.redis.io/releases/redis-3.2.4.tar.gz)\n";
+
+    #[test]
+    fn manifest_fields() {
+        assert_eq!(manifest_source(MANIFEST),
+                   Some("http://download.redis.io/releases/redis-3.2.4.tar.gz".to_string()));
+        assert_eq!(manifest_source("* __Source__: []()\n"), None);
+    }
+
+    #[test]
+    fn package_identifiers() {
+        let ident = PackageIdent::from_str("core/redis/3.2.4/20161215055911").unwrap();
+        assert_eq!(purl(&ident), "pkg:generic/core/redis@3.2.4?release=20161215055911");
+        assert_eq!(spdx_id(&ident), "SPDXRef-core-redis-3.2.4-20161215055911");
+    }
+
+    #[test]
+    fn release_timestamps() {
+        assert_eq!(release_timestamp("20161215055911"), "2016-12-15T05:59:11Z");
+        assert_eq!(release_timestamp("latest"), "1970-01-01T00:00:00Z");
+    }
+}
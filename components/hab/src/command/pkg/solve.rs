@@ -0,0 +1,34 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use common::command::package::solve;
+use common::ui::UI;
+
+use error::Result;
+use {PRODUCT, VERSION};
+
+/// Prints the release chosen for each requirement, one per line, in the order they were given.
+pub fn start(ui: &mut UI,
+             url: Option<&str>,
+             requirements: &[&str],
+             fs_root_path: &Path)
+             -> Result<()> {
+    let solved = try!(solve::start(ui, url, requirements, PRODUCT, VERSION, fs_root_path));
+    for ident in solved {
+        println!("{}", ident);
+    }
+    Ok(())
+}
@@ -142,12 +142,12 @@ pub fn start<P: AsRef<Path>>(ui: &mut UI,
     }
 }
 
-fn upload_into_depot(ui: &mut UI,
-                     depot_client: &Client,
-                     token: &str,
-                     ident: &PackageIdent,
-                     mut archive: &mut PackageArchive)
-                     -> Result<()> {
+pub fn upload_into_depot(ui: &mut UI,
+                         depot_client: &Client,
+                         token: &str,
+                         ident: &PackageIdent,
+                         mut archive: &mut PackageArchive)
+                         -> Result<()> {
     try!(ui.status(Status::Uploading, archive.path.display()));
     match depot_client.put_package(&mut archive, token, ui.progress()) {
         Ok(_) => (),
@@ -0,0 +1,892 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Checks a plan for mistakes without building it.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab plan check ./redis --format json
+//! ```
+//!
+//! Will print every finding for the plan in `./redis` (or `./redis/habitat`) as JSON, and fail if
+//! any of them is an error.
+//!
+//! The plan is never sourced; its top level `pkg_*` assignments are read as text, so values built
+//! from other variables or set in callbacks aren't checked.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+
+use handlebars::Handlebars;
+use regex::Regex;
+use serde_json;
+use toml;
+use walkdir::WalkDir;
+
+use common::ui::UI;
+use error::{Error, Result};
+use hcore::package::VersionConstraint;
+
+const HOOKS: &'static [&'static str] = &["init",
+                                         "run",
+                                         "health_check",
+                                         "reconfigure",
+                                         "file_updated",
+                                         "under_provisioned",
+                                         "over_provisioned",
+                                         "pre_update_backup",
+                                         "post_update_restore"];
+const TEMPLATE_ENGINES: &'static [&'static str] = &["handlebars", "liquid"];
+const HOOK_INTERPRETERS: &'static [&'static str] = &["exec", "sh", "powershell", "python"];
+const DATA_VERSIONS: &'static [&'static str] = &["1", "2"];
+const EXIT_CODE_ACTIONS: &'static [&'static str] = &["complete", "reload", "restart"];
+const LIMITS: &'static [&'static str] = &["nofile", "nproc"];
+const RESERVED_SVC_DIRS: &'static [&'static str] =
+    &["config", "data", "files", "hooks", "static", "toml"];
+const HOOK_SANDBOXES: &'static [&'static str] = &["env", "readonly", "limits"];
+const HEALTH_PROBE_SETTINGS: &'static [&'static str] = &["url", "status", "timeout"];
+const PACKAGE_TYPES: &'static [&'static str] = &["standalone", "composite"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Severity {
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "warning")]
+    Warning,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Finding {
+    pub severity: Severity,
+    /// A short, stable name for the kind of mistake, for CI tooling to filter on.
+    pub code: &'static str,
+    pub file: String,
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Scalar(String),
+    Array(Vec<String>),
+}
+
+#[derive(Debug)]
+struct Var {
+    line: usize,
+    value: Value,
+}
+
+impl Var {
+    fn values(&self) -> Vec<&str> {
+        match self.value {
+            Value::Scalar(ref s) => vec![s.as_str()],
+            Value::Array(ref a) => a.iter().map(|s| s.as_str()).collect(),
+        }
+    }
+
+    /// The entries of an associative array, such as `pkg_exports=([port]=server.port)`.
+    fn entries(&self) -> Vec<(&str, &str)> {
+        self.values()
+            .into_iter()
+            .filter_map(|entry| {
+                if !entry.starts_with('[') {
+                    return None;
+                }
+                entry[1..].find("]=").map(|idx| (&entry[1..idx + 1], &entry[idx + 3..]))
+            })
+            .collect()
+    }
+}
+
+/// The top level `pkg_*` assignments of a plan.
+#[derive(Debug, Default)]
+struct PlanVars {
+    vars: HashMap<String, Var>,
+    /// Whether the version is computed when the plan is built, with `update_pkg_version`.
+    dynamic_version: bool,
+}
+
+impl PlanVars {
+    fn parse(source: &str) -> PlanVars {
+        let assignment = Regex::new(r"^(pkg_[A-Za-z0-9_]+)=(.*)$").unwrap();
+        let mut plan = PlanVars::default();
+        let mut lines = source.lines().enumerate();
+        while let Some((idx, line)) = lines.next() {
+            if line.contains("update_pkg_version") {
+                plan.dynamic_version = true;
+            }
+            let (name, rest) = match assignment.captures(line) {
+                Some(caps) => (caps[1].to_string(), caps[2].to_string()),
+                None => continue,
+            };
+            let value = if rest.starts_with('(') {
+                let mut body = rest[1..].to_string();
+                let mut words = scan_words(&body, true);
+                while words.is_none() {
+                    match lines.next() {
+                        Some((_, next)) => {
+                            body.push('\n');
+                            body.push_str(next);
+                            words = scan_words(&body, true);
+                        }
+                        // An array which is never closed; take what there is.
+                        None => words = scan_words(&format!("{})", body), true),
+                    }
+                }
+                Value::Array(words.unwrap())
+            } else {
+                Value::Scalar(scan_words(&rest, false)
+                    .and_then(|words| words.into_iter().next())
+                    .unwrap_or_default())
+            };
+            plan.vars.insert(name,
+                             Var {
+                                 line: idx + 1,
+                                 value: value,
+                             });
+        }
+        plan
+    }
+
+    fn get(&self, name: &str) -> Option<&Var> {
+        self.vars.get(name)
+    }
+}
+
+/// Split shell words, honouring quotes and comments. When `array` is set, scanning stops at the
+/// closing parenthesis of an array and `None` is returned if there isn't one yet.
+fn scan_words(input: &str, array: bool) -> Option<Vec<String>> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut quote: Option<char> = None;
+    let mut chars = input.chars();
+    while let Some(c) = chars.next() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => word.push(c),
+            None => {
+                match c {
+                    '"' | '\'' => {
+                        quote = Some(c);
+                        in_word = true;
+                    }
+                    '#' if !in_word => {
+                        while let Some(c) = chars.next() {
+                            if c == '\n' {
+                                break;
+                            }
+                        }
+                    }
+                    ')' if array => {
+                        if in_word {
+                            words.push(word);
+                        }
+                        return Some(words);
+                    }
+                    c if c.is_whitespace() => {
+                        if in_word {
+                            words.push(word.clone());
+                            word.clear();
+                            in_word = false;
+                        }
+                        if !array && c == '\n' {
+                            break;
+                        }
+                    }
+                    c => {
+                        word.push(c);
+                        in_word = true;
+                    }
+                }
+            }
+        }
+    }
+    if array {
+        return None;
+    }
+    if in_word {
+        words.push(word);
+    }
+    Some(words)
+}
+
+pub fn start(ui: &mut UI, plan_context: &Path, json: bool, strict: bool) -> Result<()> {
+    let context = try!(find_plan_context(plan_context));
+    let findings = try!(check(&context));
+    let errors = findings.iter().filter(|f| f.severity == Severity::Error).count();
+    let warnings = findings.len() - errors;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&findings).unwrap());
+    } else {
+        try!(ui.begin(format!("Checking plan in {}", context.display())));
+        for finding in findings.iter() {
+            let location = match finding.line {
+                Some(line) => format!("{}:{}", finding.file, line),
+                None => finding.file.clone(),
+            };
+            let severity = match finding.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+            };
+            println!("{}: {}[{}]: {}",
+                     location,
+                     severity,
+                     finding.code,
+                     finding.message);
+        }
+        try!(ui.end(format!("{} errors, {} warnings", errors, warnings)));
+    }
+    if errors > 0 || (strict && warnings > 0) {
+        return Err(Error::PlanCheckFailed(errors, warnings));
+    }
+    Ok(())
+}
+
+/// The directory holding `plan.sh`, which is either the given directory or its `habitat/`
+/// subdirectory.
+fn find_plan_context(path: &Path) -> Result<PathBuf> {
+    for candidate in &[path.to_path_buf(), path.join("habitat")] {
+        if candidate.join("plan.sh").is_file() {
+            return Ok(candidate.clone());
+        }
+    }
+    Err(Error::FileNotFound(path.join("plan.sh").to_string_lossy().into_owned()))
+}
+
+/// Returns every finding for the plan in `context`, in the order the files were checked.
+pub fn check(context: &Path) -> Result<Vec<Finding>> {
+    let mut checker = Checker {
+        context: context.to_path_buf(),
+        findings: Vec::new(),
+    };
+    let plan = PlanVars::parse(&try!(read(&context.join("plan.sh"))));
+    checker.metadata(&plan);
+    checker.deps(&plan);
+    checker.svc_settings(&plan);
+    checker.composite(&plan);
+    let default_toml = checker.default_toml();
+    checker.exports(&plan, default_toml.as_ref());
+    checker.hooks(&plan);
+    try!(checker.templates(&plan, default_toml.as_ref()));
+    Ok(checker.findings)
+}
+
+struct Checker {
+    context: PathBuf,
+    findings: Vec<Finding>,
+}
+
+impl Checker {
+    fn report(&mut self,
+              severity: Severity,
+              code: &'static str,
+              path: &Path,
+              line: Option<usize>,
+              message: String) {
+        let file = path.strip_prefix(&self.context).unwrap_or(path).to_string_lossy().into_owned();
+        self.findings.push(Finding {
+            severity: severity,
+            code: code,
+            file: file,
+            line: line,
+            message: message,
+        });
+    }
+
+    fn plan_path(&self) -> PathBuf {
+        self.context.join("plan.sh")
+    }
+
+    fn metadata(&mut self, plan: &PlanVars) {
+        let plan_path = self.plan_path();
+        let required = [("pkg_name", Severity::Error),
+                        ("pkg_origin", Severity::Warning),
+                        ("pkg_version", Severity::Error),
+                        ("pkg_maintainer", Severity::Warning),
+                        ("pkg_license", Severity::Warning)];
+        for &(name, severity) in required.iter() {
+            if name == "pkg_version" && plan.dynamic_version {
+                continue;
+            }
+            let missing = plan.get(name).map_or(true, |v| v.values().iter().all(|s| s.is_empty()));
+            if missing {
+                let message = match name {
+                    "pkg_origin" => {
+                        format!("{} is not set; HAB_ORIGIN must be set when building", name)
+                    }
+                    _ => format!("{} is not set", name),
+                };
+                self.report(severity, "missing-metadata", &plan_path, None, message);
+            }
+        }
+        if let Some(var) = plan.get("pkg_name") {
+            let valid = Regex::new(r"^[A-Za-z0-9_-]+$").unwrap();
+            for name in var.values() {
+                if !name.is_empty() && !name.contains('$') && !valid.is_match(name) {
+                    self.report(Severity::Error,
+                                "invalid-name",
+                                &plan_path,
+                                Some(var.line),
+                                format!("pkg_name {} may only contain letters, numbers, _ and -",
+                                        name));
+                }
+            }
+        }
+    }
+
+    fn deps(&mut self, plan: &PlanVars) {
+        let plan_path = self.plan_path();
+        for name in &["pkg_deps", "pkg_build_deps", "pkg_services"] {
+            let var = match plan.get(name) {
+                Some(var) => var,
+                None => continue,
+            };
+            for dep in var.values() {
+                if dep.contains('$') {
+                    continue;
+                }
+                // A composite's services may be named, as in `core/redis:cache`.
+                let dep = if *name == "pkg_services" {
+                    dep.splitn(2, ':').next().unwrap_or(dep)
+                } else {
+                    dep
+                };
+                // Dependencies may carry a version constraint, as in `core/glibc >=2.27 <3`.
+                let (ident, constraint) = match dep.find(char::is_whitespace) {
+                    Some(i) => (&dep[..i], dep[i..].trim()),
+                    None => (dep, ""),
+                };
+                let parts = ident.split('/').count();
+                if parts < 2 || parts > 4 || ident.split('/').any(|p| p.is_empty()) {
+                    self.report(Severity::Error,
+                                "invalid-dep",
+                                &plan_path,
+                                Some(var.line),
+                                format!("{} entry {} is not a package identifier", name, dep));
+                } else if *name == "pkg_services" && !constraint.is_empty() {
+                    self.report(Severity::Error,
+                                "invalid-dep",
+                                &plan_path,
+                                Some(var.line),
+                                format!("pkg_services entry {} can't have a version constraint",
+                                        dep));
+                } else if let Err(e) = constraint.parse::<VersionConstraint>() {
+                    self.report(Severity::Error,
+                                "invalid-dep",
+                                &plan_path,
+                                Some(var.line),
+                                format!("{} entry {}: {}", name, dep, e));
+                } else if *name == "pkg_deps" && parts == 2 && constraint.is_empty() {
+                    self.report(Severity::Warning,
+                                "unpinned-dep",
+                                &plan_path,
+                                Some(var.line),
+                                format!("runtime dependency {} has no version; the package will \
+                                         use whatever release is newest when it's built",
+                                        dep));
+                }
+            }
+        }
+    }
+
+    fn svc_settings(&mut self, plan: &PlanVars) {
+        let plan_path = self.plan_path();
+        let settings = [("pkg_type", PACKAGE_TYPES),
+                        ("pkg_svc_template_engine", TEMPLATE_ENGINES),
+                        ("pkg_svc_hook_interpreter", HOOK_INTERPRETERS),
+                        ("pkg_svc_data_version", DATA_VERSIONS)];
+        for &(name, valid) in settings.iter() {
+            if let Some(var) = plan.get(name) {
+                for value in var.values() {
+                    if !value.is_empty() && !valid.contains(&value) {
+                        self.report(Severity::Error,
+                                    "invalid-value",
+                                    &plan_path,
+                                    Some(var.line),
+                                    format!("{} must be one of {}, not {}",
+                                            name,
+                                            valid.join(", "),
+                                            value));
+                    }
+                }
+            }
+        }
+        if let Some(var) = plan.get("pkg_svc_exit_codes") {
+            for (code, action) in var.entries() {
+                if !EXIT_CODE_ACTIONS.contains(&action) {
+                    self.report(Severity::Error,
+                                "invalid-value",
+                                &plan_path,
+                                Some(var.line),
+                                format!("pkg_svc_exit_codes action for {} must be one of {}, \
+                                         not {}",
+                                        code,
+                                        EXIT_CODE_ACTIONS.join(", "),
+                                        action));
+                }
+            }
+        }
+        if let Some(var) = plan.get("pkg_svc_umask") {
+            for value in var.values() {
+                let valid = match u32::from_str_radix(value, 8) {
+                    Ok(mask) => mask <= 0o777,
+                    Err(_) => false,
+                };
+                if !value.is_empty() && !valid {
+                    self.report(Severity::Error,
+                                "invalid-value",
+                                &plan_path,
+                                Some(var.line),
+                                format!("pkg_svc_umask must be an octal umask, not {}", value));
+                }
+            }
+        }
+        if let Some(var) = plan.get("pkg_svc_health_probe") {
+            let entries = var.entries();
+            if !entries.iter().any(|&(setting, url)| {
+                setting == "url" && (url.starts_with("http://") || url.starts_with("tcp://"))
+            }) {
+                self.report(Severity::Error,
+                            "invalid-value",
+                            &plan_path,
+                            Some(var.line),
+                            String::from("pkg_svc_health_probe url must be an http:// or tcp:// \
+                                          URL"));
+            }
+            for (setting, value) in entries {
+                if !HEALTH_PROBE_SETTINGS.contains(&setting) {
+                    self.report(Severity::Error,
+                                "invalid-value",
+                                &plan_path,
+                                Some(var.line),
+                                format!("pkg_svc_health_probe setting {} must be one of {}",
+                                        setting,
+                                        HEALTH_PROBE_SETTINGS.join(", ")));
+                } else if setting != "url" && value.parse::<u64>().is_err() {
+                    self.report(Severity::Error,
+                                "invalid-value",
+                                &plan_path,
+                                Some(var.line),
+                                format!("pkg_svc_health_probe {} must be a number, not {}",
+                                        setting,
+                                        value));
+                }
+            }
+        }
+        if let Some(var) = plan.get("pkg_svc_hook_sandbox") {
+            for sandbox in var.values() {
+                if !HOOK_SANDBOXES.contains(&sandbox) {
+                    self.report(Severity::Error,
+                                "invalid-value",
+                                &plan_path,
+                                Some(var.line),
+                                format!("pkg_svc_hook_sandbox must be one or more of {}, not {}",
+                                        HOOK_SANDBOXES.join(", "),
+                                        sandbox));
+                }
+            }
+        }
+        if let Some(var) = plan.get("pkg_svc_limits") {
+            for (limit, value) in var.entries() {
+                if !LIMITS.contains(&limit) {
+                    self.report(Severity::Error,
+                                "invalid-value",
+                                &plan_path,
+                                Some(var.line),
+                                format!("pkg_svc_limits limit {} must be one of {}",
+                                        limit,
+                                        LIMITS.join(", ")));
+                } else if value != "unlimited" && value.parse::<u64>().is_err() {
+                    self.report(Severity::Error,
+                                "invalid-value",
+                                &plan_path,
+                                Some(var.line),
+                                format!("pkg_svc_limits {} must be a number or unlimited, not {}",
+                                        limit,
+                                        value));
+                }
+            }
+        }
+        if let Some(var) = plan.get("pkg_svc_dirs") {
+            let option = Regex::new(r"^(tmpfs|workdir|quota=[0-9]+[KMG]?|clear=(restart|update))$")
+                .unwrap();
+            for (dir, options) in var.entries() {
+                if RESERVED_SVC_DIRS.contains(&dir) {
+                    self.report(Severity::Error,
+                                "invalid-value",
+                                &plan_path,
+                                Some(var.line),
+                                format!("pkg_svc_dirs can't name {}, which the Supervisor \
+                                         manages already",
+                                        dir));
+                }
+                for opt in options.split_whitespace().filter(|o| !option.is_match(o)) {
+                    self.report(Severity::Error,
+                                "invalid-value",
+                                &plan_path,
+                                Some(var.line),
+                                format!("pkg_svc_dirs {} has an unknown option {}", dir, opt));
+                }
+            }
+        }
+    }
+
+    /// Checks that a composite names its services, and that its groups and binds only refer to
+    /// them.
+    fn composite(&mut self, plan: &PlanVars) {
+        let plan_path = self.plan_path();
+        let composite = plan.get("pkg_type").map_or(false, |v| v.values() == ["composite"]);
+        if !composite {
+            return;
+        }
+        let services: Vec<&str> = match plan.get("pkg_services") {
+            Some(var) => var.values().into_iter().filter_map(composite_service_name).collect(),
+            None => Vec::new(),
+        };
+        for (i, name) in services.iter().enumerate() {
+            if services[..i].contains(name) {
+                self.report(Severity::Error,
+                            "duplicate-service",
+                            &plan_path,
+                            plan.get("pkg_services").map(|var| var.line),
+                            format!("pkg_services runs more than one service named {}; give the \
+                                     others names of their own, as in core/redis:sessions",
+                                    name));
+            }
+        }
+        if services.is_empty() {
+            self.report(Severity::Error,
+                        "missing-metadata",
+                        &plan_path,
+                        None,
+                        String::from("pkg_services is not set; a composite must name its \
+                                      services"));
+            return;
+        }
+        if let Some(var) = plan.get("pkg_service_groups") {
+            for (name, _) in var.entries() {
+                if !services.contains(&name) {
+                    self.report(Severity::Error,
+                                "unknown-service",
+                                &plan_path,
+                                Some(var.line),
+                                format!("pkg_service_groups names {}, which is not in \
+                                         pkg_services",
+                                        name));
+                }
+            }
+        }
+        if let Some(var) = plan.get("pkg_bind_map") {
+            for (name, binds) in var.entries() {
+                let targets = binds.split_whitespace().map(|b| b.splitn(2, ':').nth(1));
+                for target in Some(Some(name)).into_iter().chain(targets) {
+                    match target {
+                        Some(target) if services.contains(&target) => {}
+                        _ => {
+                            self.report(Severity::Error,
+                                        "unknown-service",
+                                        &plan_path,
+                                        Some(var.line),
+                                        format!("pkg_bind_map entry for {} refers to a service \
+                                                 which is not in pkg_services",
+                                                name))
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn default_toml(&mut self) -> Option<toml::Value> {
+        let path = self.context.join("default.toml");
+        let source = match read(&path) {
+            Ok(source) => source,
+            Err(_) => return None,
+        };
+        let mut parser = toml::Parser::new(&source);
+        match parser.parse() {
+            Some(table) => Some(toml::Value::Table(table)),
+            None => {
+                for err in parser.errors.iter() {
+                    let (line, _) = parser.to_linecol(err.lo);
+                    self.report(Severity::Error,
+                                "invalid-toml",
+                                &path,
+                                Some(line + 1),
+                                err.desc.clone());
+                }
+                None
+            }
+        }
+    }
+
+    fn exports(&mut self, plan: &PlanVars, default_toml: Option<&toml::Value>) {
+        let plan_path = self.plan_path();
+        let mut exported = Vec::new();
+        if let Some(var) = plan.get("pkg_exports") {
+            for (key, path) in var.entries() {
+                exported.push(key.to_string());
+                if default_toml.map_or(true, |t| t.lookup(path).is_none()) {
+                    self.report(Severity::Error,
+                                "invalid-export",
+                                &plan_path,
+                                Some(var.line),
+                                format!("pkg_exports key {} refers to {}, which isn't set in \
+                                         default.toml",
+                                        key,
+                                        path));
+                }
+            }
+        }
+        if let Some(var) = plan.get("pkg_exposes") {
+            for key in var.values() {
+                if !key.is_empty() && !exported.iter().any(|e| e == key) {
+                    self.report(Severity::Error,
+                                "invalid-expose",
+                                &plan_path,
+                                Some(var.line),
+                                format!("pkg_exposes entry {} has no matching pkg_exports key",
+                                        key));
+                }
+            }
+        }
+        let is_service = plan.get("pkg_svc_run").is_some() ||
+                         self.context.join("hooks").join("run").is_file();
+        if is_service && exported.is_empty() {
+            self.report(Severity::Warning,
+                        "missing-exports",
+                        &plan_path,
+                        None,
+                        "pkg_exports is not set; services bound to this one won't see any of \
+                         its configuration"
+                            .to_string());
+        }
+    }
+
+    fn hooks(&mut self, plan: &PlanVars) {
+        let scheduled: Vec<String> = plan.get("pkg_svc_scheduled_hooks")
+            .map_or(Vec::new(),
+                    |v| v.entries().iter().map(|&(name, _)| name.to_string()).collect());
+        let interpreter_set = plan.get("pkg_svc_hook_interpreter").is_some();
+        let hooks_dir = self.context.join("hooks");
+        let plan_path = self.plan_path();
+        for name in scheduled.iter() {
+            if !hooks_dir.join(name).is_file() {
+                self.report(Severity::Error,
+                            "missing-hook",
+                            &plan_path,
+                            plan.get("pkg_svc_scheduled_hooks").map(|v| v.line),
+                            format!("scheduled hook {} has no file in hooks/", name));
+            }
+        }
+        let mut entries: Vec<PathBuf> = match fs::read_dir(&hooks_dir) {
+            Ok(entries) => entries.filter_map(|e| e.ok()).map(|e| e.path()).collect(),
+            Err(_) => return,
+        };
+        entries.sort();
+        for path in entries {
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            if !path.is_file() {
+                continue;
+            }
+            if !HOOKS.contains(&name.as_str()) && !scheduled.contains(&name) {
+                self.report(Severity::Warning,
+                            "unknown-hook",
+                            &path,
+                            None,
+                            format!("{} is not a hook the Supervisor runs, and isn't listed in \
+                                     pkg_svc_scheduled_hooks",
+                                    name));
+            }
+            let first_line = read(&path)
+                .ok()
+                .and_then(|s| s.lines().next().map(|l| l.to_string()))
+                .unwrap_or_default();
+            if !first_line.starts_with("#!") && !interpreter_set {
+                self.report(Severity::Warning,
+                            "missing-shebang",
+                            &path,
+                            Some(1),
+                            "hook has no shebang line, so it will be run with sh (powershell \
+                             on Windows); add one or set pkg_svc_hook_interpreter"
+                                .to_string());
+            }
+            if !is_executable(&path) {
+                self.report(Severity::Warning,
+                            "hook-permissions",
+                            &path,
+                            None,
+                            "hook is not executable".to_string());
+            }
+        }
+    }
+
+    fn templates(&mut self, plan: &PlanVars, default_toml: Option<&toml::Value>) -> Result<()> {
+        let engine = plan.get("pkg_svc_template_engine")
+            .and_then(|v| v.values().into_iter().next().map(|s| s.to_string()))
+            .unwrap_or("handlebars".to_string());
+        let cfg_reference = Regex::new(r"\bcfg\.([A-Za-z0-9_-]+(?:\.[A-Za-z0-9_-]+)*)").unwrap();
+        let mut paths = Vec::new();
+        for dir in &["config", "hooks"] {
+            for entry in WalkDir::new(self.context.join(dir)).into_iter().filter_map(|e| e.ok()) {
+                if entry.file_type().is_file() {
+                    paths.push(entry.path().to_path_buf());
+                }
+            }
+        }
+        paths.sort();
+        for path in paths {
+            let source = match read(&path) {
+                Ok(source) => source,
+                // Binary files can't be templates.
+                Err(Error::Utf8Error(_)) => continue,
+                Err(e) => return Err(e),
+            };
+            if engine == "handlebars" {
+                let mut handlebars = Handlebars::new();
+                if let Err(e) = handlebars.register_template_string("template", source.clone()) {
+                    self.report(Severity::Error,
+                                "template-syntax",
+                                &path,
+                                e.line_no,
+                                format!("{}", e));
+                    continue;
+                }
+            }
+            let mut undefined = Vec::new();
+            for (idx, line) in source.lines().enumerate() {
+                for caps in cfg_reference.captures_iter(line) {
+                    let key = caps[1].to_string();
+                    if !defined(default_toml, &key) && !undefined.contains(&key) {
+                        undefined.push(key.clone());
+                        self.report(Severity::Warning,
+                                    "undefined-config",
+                                    &path,
+                                    Some(idx + 1),
+                                    format!("cfg.{} isn't set in default.toml", key));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns true if `key`, or the value it's nested within, is set in `default.toml`.
+fn defined(default_toml: Option<&toml::Value>, key: &str) -> bool {
+    let mut current = match default_toml {
+        Some(value) => value,
+        None => return false,
+    };
+    for segment in key.split('.') {
+        current = match *current {
+            toml::Value::Table(ref table) => {
+                match table.get(segment) {
+                    Some(value) => value,
+                    None => return false,
+                }
+            }
+            // Anything beneath a value which isn't a table is a helper or a property of the
+            // value, such as `cfg.servers.length`.
+            _ => return true,
+        };
+    }
+    true
+}
+
+fn read(path: &Path) -> Result<String> {
+    let mut bytes = Vec::new();
+    try!(try!(File::open(path)).read_to_end(&mut bytes));
+    String::from_utf8(bytes).map_err(|e| Error::Utf8Error(e.to_string()))
+}
+
+#[cfg(not(windows))]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    fs::metadata(path).map(|m| m.permissions().mode() & 0o111 != 0).unwrap_or(false)
+}
+
+#[cfg(windows)]
+fn is_executable(_path: &Path) -> bool {
+    true
+}
+
+/// Returns the name a `pkg_services` entry runs under: the one given after a colon, as in
+/// `core/redis:cache`, or else its package's name.
+fn composite_service_name(entry: &str) -> Option<&str> {
+    match entry.find(':') {
+        Some(i) => Some(&entry[i + 1..]),
+        None => entry.split('/').nth(1),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{composite_service_name, PlanVars, Value};
+
+    #[test]
+    fn parse_plan_vars() {
+        let plan = PlanVars::parse(r#"
+pkg_name=redis
+pkg_version="3.2.4" # the version
+pkg_deps=(core/glibc
+  # comment
+  "core/openssl/1.0.2j")
+pkg_exports=(
+  [port]=server.port
+  [host]='server.host'
+)
+
+do_build() {
+  pkg_name=other
+}
+"#);
+        assert_eq!(plan.get("pkg_name").unwrap().value,
+                   Value::Scalar("redis".to_string()));
+        assert_eq!(plan.get("pkg_version").unwrap().value,
+                   Value::Scalar("3.2.4".to_string()));
+        assert_eq!(plan.get("pkg_deps").unwrap().values(),
+                   vec!["core/glibc", "core/openssl/1.0.2j"]);
+        assert_eq!(plan.get("pkg_deps").unwrap().line, 4);
+        assert_eq!(plan.get("pkg_exports").unwrap().entries(),
+                   vec![("port", "server.port"), ("host", "server.host")]);
+        assert!(!plan.dynamic_version);
+    }
+
+    #[test]
+    fn parse_single_line_array() {
+        let plan = PlanVars::parse("pkg_license=('Apache-2.0' MIT)\npkg_bin_dirs=(bin)\n");
+        assert_eq!(plan.get("pkg_license").unwrap().values(), vec!["Apache-2.0", "MIT"]);
+        assert_eq!(plan.get("pkg_bin_dirs").unwrap().values(), vec!["bin"]);
+    }
+
+    #[test]
+    fn parse_constrained_deps() {
+        let plan = PlanVars::parse("pkg_deps=(\"core/glibc >=2.27 <3\" core/zlib)\n");
+        assert_eq!(plan.get("pkg_deps").unwrap().values(),
+                   vec!["core/glibc >=2.27 <3", "core/zlib"]);
+    }
+
+    #[test]
+    fn composite_services_run_under_their_given_names() {
+        assert_eq!(composite_service_name("core/redis"), Some("redis"));
+        assert_eq!(composite_service_name("core/redis:cache"), Some("cache"));
+        assert_eq!(composite_service_name("core/redis/3.2.4:cache"), Some("cache"));
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reports how many members each service group should have against how many are alive, as seen
+//! by a Supervisor.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab scale status redis.default
+//! ```
+//!
+//! Will print the desired and alive member counts of the `redis.default` service group, and
+//! whether it is under or over provisioned.
+
+use common::ui::UI;
+
+use command;
+use error::Result;
+
+pub fn start(ui: &mut UI, sup_addr: &str, service_group: Option<&str>) -> Result<()> {
+    let client = try!(command::sup::client(sup_addr));
+    let scales = try!(client.scale());
+    let mut found = false;
+    for scale in scales {
+        if service_group.map_or(false, |sg| sg != scale.service_group) {
+            continue;
+        }
+        found = true;
+        println!("{}\t{}\t{}\t{}",
+                 scale.service_group,
+                 scale.desired_count,
+                 scale.alive_count,
+                 scale.provisioning);
+    }
+    if !found {
+        match service_group {
+            Some(sg) => try!(ui.warn(format!("No desired count is set for {}", sg))),
+            None => try!(ui.warn("No desired counts are set")),
+        }
+    }
+    Ok(())
+}
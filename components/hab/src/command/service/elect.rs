@@ -0,0 +1,56 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Starts a new leader election for a service group, by way of the control gateway of a
+//! Supervisor in the group. The Supervisor gossips the election with the next term, standing to
+//! win it, and the rest of the group joins in as it would after its leader died.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab svc elect redis.default --force --remote-sup 10.0.0.12:9632
+//! ```
+//!
+//! Will have the Supervisor at `10.0.0.12` replace the leader of `redis.default`, even though
+//! the leader is alive.
+//!
+//! The control gateway is only spoken by a `hab` built with the `control-gateway` feature.
+
+use common::ui::UI;
+use hcore::service::ServiceGroup;
+pub use sup_client::DEFAULT_CONTROL_ADDR;
+
+#[cfg(feature = "control-gateway")]
+use command;
+#[cfg(not(feature = "control-gateway"))]
+use error::Error;
+use error::Result;
+
+#[cfg(feature = "control-gateway")]
+pub fn start(ui: &mut UI, sup_addr: &str, service_group: &ServiceGroup, force: bool) -> Result<()> {
+    let client = try!(command::sup::control_client(sup_addr));
+    try!(ui.begin(format!("Starting a new election for {} from {}", service_group, sup_addr)));
+    let term = try!(client.force_election(service_group, force));
+    try!(ui.end(format!("Started the election for {}, term {}.", service_group, term)));
+    Ok(())
+}
+
+#[cfg(not(feature = "control-gateway"))]
+pub fn start(_ui: &mut UI,
+             _sup_addr: &str,
+             _service_group: &ServiceGroup,
+             _force: bool)
+             -> Result<()> {
+    Err(Error::FeatureNotBuilt("hab svc elect", "control-gateway"))
+}
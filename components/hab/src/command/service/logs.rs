@@ -0,0 +1,75 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Prints the output a service has written, as captured by its Supervisor and served by the
+//! Supervisor's HTTP gateway.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab svc logs redis.default --lines 20 --follow
+//! ```
+//!
+//! Will print the last 20 lines the `redis.default` service wrote, then print new lines as they
+//! are written until interrupted.
+
+use std::thread;
+use std::time::Duration;
+
+use hcore::service::ServiceGroup;
+use sup_client::{LogQuery, FOLLOW_WAIT_SECS};
+
+use command;
+use error::Result;
+
+/// How long to pause after a request returns no output, which it does at once when the
+/// Supervisor's gateway has no worker to spare for waiting.
+const FOLLOW_RETRY_SECS: u64 = 1;
+
+pub fn start(sup_addr: &str,
+             service_group: &ServiceGroup,
+             lines: Option<&str>,
+             since: Option<&str>,
+             follow: bool)
+             -> Result<()> {
+    let client = try!(command::sup::client(sup_addr));
+    // Both are validated as counts by clap.
+    let query = LogQuery {
+        lines: lines.and_then(|l| l.parse().ok()),
+        newer_than: since.and_then(|s| s.parse().ok()),
+        ..LogQuery::default()
+    };
+    let output = try!(client.logs(service_group, &query));
+    for line in output.lines {
+        println!("{}", line.line);
+    }
+    if !follow {
+        return Ok(());
+    }
+    let mut query = LogQuery {
+        since: output.next,
+        wait: FOLLOW_WAIT_SECS,
+        ..LogQuery::default()
+    };
+    loop {
+        let output = try!(client.logs(service_group, &query));
+        if output.lines.is_empty() {
+            thread::sleep(Duration::from_secs(FOLLOW_RETRY_SECS));
+        }
+        for line in output.lines {
+            println!("{}", line.line);
+        }
+        query.since = output.next;
+    }
+}
@@ -12,4 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod elect;
 pub mod key;
+pub mod logs;
+pub mod status;
@@ -0,0 +1,88 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reports the services running under a Supervisor, by way of its HTTP gateway.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab svc status redis.default --follow-hooks
+//! ```
+//!
+//! Will print the state of the `redis.default` service, then print the output of its hooks as
+//! they run until interrupted.
+
+use std::thread;
+use std::time::Duration;
+
+use common::ui::UI;
+use sup_client::{Client, HookOutput, FOLLOW_WAIT_SECS};
+pub use sup_client::DEFAULT_SUP_ADDR;
+
+use command;
+use error::Result;
+
+/// How long to pause after a request returns no output, which it does at once when the
+/// Supervisor's gateway has no worker to spare for waiting.
+const FOLLOW_RETRY_SECS: u64 = 1;
+
+pub fn start(ui: &mut UI,
+             sup_addr: &str,
+             service_group: Option<&str>,
+             follow_hooks: bool)
+             -> Result<()> {
+    let client = try!(command::sup::client(sup_addr));
+    let services = try!(client.services());
+    let mut found = false;
+    for service in services {
+        if service_group.map_or(false, |sg| sg != service.service_group) {
+            continue;
+        }
+        found = true;
+        println!("{}\t{}\t{}",
+                 service.service_group,
+                 service.supervisor.state,
+                 service.supervisor.pid.map(|p| p.to_string()).unwrap_or(String::from("-")));
+    }
+    if !found {
+        match service_group {
+            Some(sg) => try!(ui.warn(format!("Service group {} is not loaded", sg))),
+            None => try!(ui.warn("No services are loaded")),
+        }
+    }
+    if follow_hooks {
+        try!(ui.begin("Following hook output"));
+        try!(follow(ui, &client, service_group));
+    }
+    Ok(())
+}
+
+/// Print hook output as it arrives, forever. Only output produced from now on is shown.
+fn follow(ui: &mut UI, client: &Client, service_group: Option<&str>) -> Result<()> {
+    let mut since = try!(client.hook_output(0, service_group, 0)).next;
+    loop {
+        let output: HookOutput = try!(client.hook_output(since, service_group, FOLLOW_WAIT_SECS));
+        if output.skipped > 0 {
+            // The count covers every service group's lines, so with a filter it's an upper bound.
+            try!(ui.warn(format!("Missed up to {} lines of hook output", output.skipped)));
+        }
+        if output.lines.is_empty() {
+            thread::sleep(Duration::from_secs(FOLLOW_RETRY_SECS));
+        }
+        for line in output.lines {
+            println!("{} hook[{}]: {}", line.service_group, line.hook, line.line);
+        }
+        since = output.next;
+    }
+}
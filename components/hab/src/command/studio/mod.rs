@@ -300,7 +300,12 @@ mod inner {
             cmd_args.extend_from_slice(opts.as_slice());
         }
 
-        let env_vars = vec!["HAB_DEPOT_URL", "HAB_ORIGIN", "http_proxy", "https_proxy"];
+        let env_vars = vec!["HAB_DEPOT_URL",
+                            "HAB_ORIGIN",
+                            "HAB_REPRODUCIBLE_BUILD",
+                            "SOURCE_DATE_EPOCH",
+                            "http_proxy",
+                            "https_proxy"];
         for var in env_vars {
             if let Ok(val) = henv::var(var) {
                 debug!("Propagating environment variable into container: {}={}",
@@ -15,13 +15,39 @@
 use std::ffi::OsString;
 
 use common::ui::UI;
+use hcore::env as henv;
+use sup_client::Client;
+#[cfg(feature = "control-gateway")]
+use sup_client::control_gateway;
 
+use {PRODUCT, SUP_GATEWAY_AUTH_TOKEN_ENVVAR, VERSION};
 use error::Result;
 
 pub fn start(ui: &mut UI, args: Vec<OsString>) -> Result<()> {
     inner::start(ui, args)
 }
 
+/// Returns a client of the HTTP gateway at `sup_addr`, which presents the token in
+/// `HAB_SUP_GATEWAY_AUTH_TOKEN` if it's set.
+pub fn client(sup_addr: &str) -> Result<Client> {
+    let client = try!(Client::new(sup_addr, PRODUCT, VERSION));
+    match henv::var(SUP_GATEWAY_AUTH_TOKEN_ENVVAR) {
+        Ok(token) => Ok(client.with_auth_token(&token)),
+        Err(_) => Ok(client),
+    }
+}
+
+/// Returns a client of the control gateway at `addr`, which presents the token in
+/// `HAB_SUP_GATEWAY_AUTH_TOKEN` if it's set.
+#[cfg(feature = "control-gateway")]
+pub fn control_client(addr: &str) -> Result<control_gateway::Client> {
+    let client = try!(control_gateway::Client::new(addr));
+    match henv::var(SUP_GATEWAY_AUTH_TOKEN_ENVVAR) {
+        Ok(token) => Ok(client.with_auth_token(&token)),
+        Err(_) => Ok(client),
+    }
+}
+
 #[cfg(target_os = "linux")]
 mod inner {
     use std::ffi::OsString;
@@ -19,7 +19,7 @@ use std::path::PathBuf;
 
 use hcore::config::{ConfigFile, ParseInto};
 use hcore::env as henv;
-use hcore::fs::{am_i_root, FS_ROOT_PATH};
+use hcore::fs::{am_i_root, fs_root_path};
 use hcore::os::users;
 use toml;
 
@@ -104,5 +104,5 @@ fn cli_config_path(use_sudo_user: bool) -> PathBuf {
         }
     }
 
-    PathBuf::from(FS_ROOT_PATH).join(CLI_CONFIG_PATH)
+    fs_root_path().join(CLI_CONFIG_PATH)
 }
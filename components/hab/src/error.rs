@@ -23,15 +23,55 @@ use depot_client;
 use common;
 use hcore;
 use handlebars;
+use http_client;
+use sup_client;
+use hyper::status::StatusCode;
 
 pub type Result<T> = result::Result<T, Error>;
 
+// Exit statuses, by the kind of failure. Automation depends on these, so a status must never be
+// given a different meaning. Where one fits, the numbers are those of sysexits(3).
+
+/// A failure which fits none of the other kinds.
+pub const EXIT_FAILURE: i32 = 1;
+/// A check ran and found problems, such as `hab pkg audit` finding vulnerabilities.
+pub const EXIT_CHECK_FAILED: i32 = 2;
+/// The command was used incorrectly.
+pub const EXIT_USAGE: i32 = 64;
+/// Input data, such as a TOML file or a package archive, was malformed.
+pub const EXIT_DATA: i32 = 65;
+/// A file, package, command or image which was asked for doesn't exist.
+pub const EXIT_NOT_FOUND: i32 = 66;
+/// A service `hab` depends on, such as a Depot, a Supervisor or Docker, couldn't be reached.
+pub const EXIT_UNAVAILABLE: i32 = 69;
+/// Reading or writing a file failed.
+pub const EXIT_IO: i32 = 74;
+/// The user lacks the permissions or credentials the command needs.
+pub const EXIT_PERMISSION: i32 = 77;
+/// Something outside of `hab` is misconfigured.
+pub const EXIT_CONFIG: i32 = 78;
+
+/// A failure described for automation, as printed by `--json-errors`.
+#[derive(Debug, Serialize)]
+pub struct ErrorReport {
+    /// The stable name of the kind of failure, such as `package-not-found`.
+    pub code: &'static str,
+    pub exit_code: i32,
+    /// The part of `hab`, or of what it talks to, the failure came from.
+    pub subsystem: &'static str,
+    pub message: String,
+    pub remediation: Option<&'static str>,
+}
+
 #[derive(Debug)]
 #[allow(dead_code)]
 pub enum Error {
     ArgumentError(&'static str),
+    AuditFailed(usize),
+    BundleInvalid(String),
     ButterflyError(String),
     CommandNotFoundInPkg((String, String)),
+    ConfigInvalid(String),
     CryptoCLI(String),
     DepotClient(depot_client::Error),
     DockerDaemonDown,
@@ -39,17 +79,22 @@ pub enum Error {
     DockerImageNotFound(String),
     DockerNetworkDown(String),
     ExecCommandNotFound(String),
+    FeatureNotBuilt(&'static str, &'static str),
     FFINulError(ffi::NulError),
     FileNotFound(String),
     HabitatCommon(common::Error),
     HabitatCore(hcore::Error),
     HandlebarsRenderError(handlebars::TemplateRenderError),
+    HttpClient(http_client::Error),
     IO(io::Error),
+    JobFailed(u64, String),
     PackageArchiveMalformed(String),
     PathPrefixError(path::StripPrefixError),
+    PlanCheckFailed(usize, usize),
     ProvidesError(String),
     RootRequired,
     SubcommandNotSupported(String),
+    SupervisorRequestFailed(String),
     UnsupportedExportFormat(String),
     TomlError,
     Utf8Error(String),
@@ -59,12 +104,17 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
             Error::ArgumentError(ref e) => format!("{}", e),
+            Error::AuditFailed(n) => {
+                format!("Found {} known vulnerabilities in installed packages", n)
+            }
+            Error::BundleInvalid(ref e) => format!("Export bundle failed verification: {}", e),
             Error::ButterflyError(ref e) => format!("{}", e),
             Error::CommandNotFoundInPkg((ref p, ref c)) => {
                 format!("`{}' was not found under any 'PATH' directories in the {} package",
                         c,
                         p)
             }
+            Error::ConfigInvalid(ref e) => format!("The configuration is invalid: {}", e),
             Error::CryptoCLI(ref e) => format!("{}", e),
             Error::DepotClient(ref err) => format!("{}", err),
             Error::DockerDaemonDown => {
@@ -97,17 +147,28 @@ impl fmt::Display for Error {
             Error::ExecCommandNotFound(ref c) => {
                 format!("`{}' was not found on the filesystem or in PATH", c)
             }
+            Error::FeatureNotBuilt(ref command, ref feature) => {
+                format!("`{}' needs hab to be built with the `{}' feature", command, feature)
+            }
             Error::FFINulError(ref e) => format!("{}", e),
             Error::FileNotFound(ref e) => format!("File not found at: {}", e),
             Error::HabitatCommon(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::HandlebarsRenderError(ref e) => format!("{}", e),
+            Error::HttpClient(ref e) => format!("{}", e),
             Error::IO(ref err) => format!("{}", err),
+            Error::JobFailed(ref id, ref e) if e.len() > 0 => {
+                format!("Build job {} failed: {}", id, e)
+            }
+            Error::JobFailed(ref id, _) => format!("Build job {} failed", id),
             Error::PackageArchiveMalformed(ref e) => {
                 format!("Package archive was unreadable or contained unexpected contents: {:?}",
                         e)
             }
             Error::PathPrefixError(ref err) => format!("{}", err),
+            Error::PlanCheckFailed(errors, warnings) => {
+                format!("Plan check found {} errors and {} warnings", errors, warnings)
+            }
             Error::ProvidesError(ref err) => format!("Can't find {}", err),
             Error::RootRequired => {
                 "Root or administrator permissions required to complete operation".to_string()
@@ -115,6 +176,9 @@ impl fmt::Display for Error {
             Error::SubcommandNotSupported(ref e) => {
                 format!("Subcommand `{}' not supported on this operating system", e)
             }
+            Error::SupervisorRequestFailed(ref e) => {
+                format!("Request to the Supervisor's HTTP gateway failed: {}", e)
+            }
             Error::UnsupportedExportFormat(ref e) => format!("Unsupported export format: {}", e),
             Error::TomlError => format!("Invalid TOML"),
             Error::Utf8Error(ref e) => format!("Error processing a string as UTF-8: {}", e),
@@ -127,10 +191,13 @@ impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
             Error::ArgumentError(_) => "There was an error parsing an error or with it's value",
+            Error::AuditFailed(_) => "Found known vulnerabilities in installed packages",
+            Error::BundleInvalid(_) => "Export bundle contents don't match its signed manifest",
             Error::ButterflyError(_) => "Butterfly has had an error",
             Error::CommandNotFoundInPkg(_) => {
                 "Command was not found under any 'PATH' directories in the package"
             }
+            Error::ConfigInvalid(_) => "The service rejected the configuration",
             Error::CryptoCLI(_) => "A cryptographic error has occurred",
             Error::DepotClient(ref err) => err.description(),
             Error::DockerDaemonDown => "The Docker daemon could not be found.",
@@ -138,16 +205,20 @@ impl error::Error for Error {
             Error::DockerImageNotFound(_) => "The Docker image was not found.",
             Error::DockerNetworkDown(_) => "The Docker registry is unreachable.",
             Error::ExecCommandNotFound(_) => "Exec command was not found on filesystem or in PATH",
+            Error::FeatureNotBuilt(_, _) => "Subcommand needs a feature hab wasn't built with",
             Error::FFINulError(ref err) => err.description(),
             Error::FileNotFound(_) => "File not found",
             Error::HabitatCommon(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
             Error::HandlebarsRenderError(ref err) => err.description(),
+            Error::HttpClient(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
+            Error::JobFailed(_, _) => "Build job failed",
             Error::PackageArchiveMalformed(_) => {
                 "Package archive was unreadable or had unexpected contents"
             }
             Error::PathPrefixError(ref err) => err.description(),
+            Error::PlanCheckFailed(_, _) => "Plan check found problems with the plan",
             Error::ProvidesError(_) => {
                 "Can't find a package that provides the given search parameter"
             }
@@ -155,6 +226,7 @@ impl error::Error for Error {
                 "Root or administrator permissions required to complete operation"
             }
             Error::SubcommandNotSupported(_) => "Subcommand not supported on this operating system",
+            Error::SupervisorRequestFailed(_) => "Request to the Supervisor's HTTP gateway failed",
             Error::UnsupportedExportFormat(_) => "Unsupported export format",
             Error::TomlError => "Invalid TOML",
             Error::Utf8Error(_) => "Error processing string as UTF-8",
@@ -162,6 +234,140 @@ impl error::Error for Error {
     }
 }
 
+impl Error {
+    /// The stable name of the kind of failure, its exit status and its subsystem.
+    fn kind(&self) -> (&'static str, i32, &'static str) {
+        match *self {
+            Error::ArgumentError(_) => ("invalid-argument", EXIT_USAGE, "cli"),
+            Error::AuditFailed(_) => ("vulnerabilities-found", EXIT_CHECK_FAILED, "audit"),
+            Error::BundleInvalid(_) => ("bundle-invalid", EXIT_CHECK_FAILED, "export"),
+            Error::ButterflyError(_) => ("gossip-failed", EXIT_UNAVAILABLE, "butterfly"),
+            Error::CommandNotFoundInPkg(_) => ("command-not-found", EXIT_NOT_FOUND, "package"),
+            Error::ConfigInvalid(_) => ("config-invalid", EXIT_CHECK_FAILED, "config"),
+            Error::CryptoCLI(_) => ("crypto-failed", EXIT_FAILURE, "crypto"),
+            Error::DepotClient(depot_client::Error::APIError(StatusCode::Unauthorized, _)) |
+            Error::DepotClient(depot_client::Error::APIError(StatusCode::Forbidden, _)) => {
+                ("depot-unauthorized", EXIT_PERMISSION, "depot")
+            }
+            Error::DepotClient(depot_client::Error::APIError(StatusCode::NotFound, _)) => {
+                ("depot-not-found", EXIT_NOT_FOUND, "depot")
+            }
+            Error::DepotClient(_) => ("depot-failed", EXIT_UNAVAILABLE, "depot"),
+            Error::DockerDaemonDown => ("docker-unavailable", EXIT_UNAVAILABLE, "studio"),
+            Error::DockerFileSharingNotEnabled => {
+                ("docker-file-sharing-disabled", EXIT_CONFIG, "studio")
+            }
+            Error::DockerImageNotFound(_) => ("docker-image-not-found", EXIT_NOT_FOUND, "studio"),
+            Error::DockerNetworkDown(_) => {
+                ("docker-registry-unavailable", EXIT_UNAVAILABLE, "studio")
+            }
+            Error::ExecCommandNotFound(_) => ("command-not-found", EXIT_NOT_FOUND, "cli"),
+            Error::FeatureNotBuilt(_, _) => ("unsupported-subcommand", EXIT_USAGE, "cli"),
+            Error::FFINulError(_) => ("invalid-string", EXIT_DATA, "cli"),
+            Error::FileNotFound(_) => ("file-not-found", EXIT_NOT_FOUND, "cli"),
+            Error::HabitatCommon(_) => ("failed", EXIT_FAILURE, "common"),
+            Error::HabitatCore(hcore::Error::PackageNotFound(_)) => {
+                ("package-not-found", EXIT_NOT_FOUND, "package")
+            }
+            Error::HabitatCore(hcore::Error::PackageNotLocked(_)) |
+            Error::HabitatCommon(common::Error::HabitatCore(hcore::Error::PackageNotLocked(_))) => {
+                ("package-not-locked", EXIT_CHECK_FAILED, "package")
+            }
+            Error::HabitatCore(hcore::Error::PermissionFailed(_)) => {
+                ("permission-denied", EXIT_PERMISSION, "core")
+            }
+            Error::HabitatCore(hcore::Error::UnsatisfiableRequirements(_)) |
+            Error::HabitatCommon(common::Error::HabitatCore(
+                hcore::Error::UnsatisfiableRequirements(_))) => {
+                ("unsatisfiable-requirements", EXIT_DATA, "package")
+            }
+            Error::HabitatCore(hcore::Error::CryptoError(_)) => {
+                ("crypto-failed", EXIT_FAILURE, "crypto")
+            }
+            Error::HabitatCore(_) => ("failed", EXIT_FAILURE, "core"),
+            Error::HandlebarsRenderError(_) => ("template-render-failed", EXIT_DATA, "cli"),
+            Error::HttpClient(_) => ("http-failed", EXIT_UNAVAILABLE, "http"),
+            Error::IO(ref err) if err.kind() == io::ErrorKind::PermissionDenied => {
+                ("permission-denied", EXIT_PERMISSION, "io")
+            }
+            Error::IO(_) => ("io-failed", EXIT_IO, "io"),
+            Error::JobFailed(_, _) => ("job-failed", EXIT_FAILURE, "builder"),
+            Error::PackageArchiveMalformed(_) => {
+                ("package-archive-malformed", EXIT_DATA, "package")
+            }
+            Error::PathPrefixError(_) => ("invalid-path", EXIT_FAILURE, "cli"),
+            Error::PlanCheckFailed(_, _) => ("plan-check-failed", EXIT_CHECK_FAILED, "plan"),
+            Error::ProvidesError(_) => ("no-package-provides", EXIT_NOT_FOUND, "package"),
+            Error::RootRequired => ("root-required", EXIT_PERMISSION, "cli"),
+            Error::SubcommandNotSupported(_) => ("unsupported-subcommand", EXIT_USAGE, "cli"),
+            Error::SupervisorRequestFailed(_) => {
+                ("supervisor-request-failed", EXIT_UNAVAILABLE, "supervisor")
+            }
+            Error::UnsupportedExportFormat(_) => {
+                ("unsupported-export-format", EXIT_USAGE, "export")
+            }
+            Error::TomlError => ("invalid-toml", EXIT_DATA, "cli"),
+            Error::Utf8Error(_) => ("invalid-utf8", EXIT_DATA, "cli"),
+        }
+    }
+
+    /// The stable name of the kind of failure, such as `package-not-found`.
+    pub fn code(&self) -> &'static str {
+        self.kind().0
+    }
+
+    /// The status `hab` exits with when the command fails with this error.
+    pub fn exit_code(&self) -> i32 {
+        self.kind().1
+    }
+
+    /// The part of `hab`, or of what it talks to, the error came from.
+    pub fn subsystem(&self) -> &'static str {
+        self.kind().2
+    }
+
+    /// A hint at how to fix the failure, if there is a common fix.
+    pub fn remediation(&self) -> Option<&'static str> {
+        match self.kind().0 {
+            "invalid-argument" |
+            "unsupported-subcommand" |
+            "unsupported-export-format" => Some("Run the command with --help to see its usage"),
+            "vulnerabilities-found" => Some("Update the affected packages, then audit again"),
+            "plan-check-failed" => Some("Fix the problems reported and check the plan again"),
+            "config-invalid" => Some("Fix the configuration and try the dry run again"),
+            "depot-unauthorized" => {
+                Some("Pass a valid token with --auth, or set it in HAB_AUTH_TOKEN")
+            }
+            "docker-unavailable" => Some("Start the Docker daemon, then try again"),
+            "docker-file-sharing-disabled" => Some("Enable file sharing in the Docker preferences"),
+            "package-not-found" => Some("Install the package with hab pkg install"),
+            "package-not-locked" => {
+                Some("Lock the packages again with hab pkg lock to move to new releases")
+            }
+            "unsatisfiable-requirements" => {
+                Some("Loosen the version constraints, or build releases which satisfy them")
+            }
+            "permission-denied" |
+            "root-required" => Some("Run the command again as root or an administrator"),
+            "supervisor-request-failed" => {
+                Some("Check that the Supervisor is running, and that --remote-sup names its HTTP \
+                      gateway")
+            }
+            _ => None,
+        }
+    }
+
+    pub fn report(&self) -> ErrorReport {
+        ErrorReport {
+            code: self.code(),
+            exit_code: self.exit_code(),
+            subsystem: self.subsystem(),
+            message: self.to_string(),
+            remediation: self.remediation(),
+        }
+    }
+}
+
 impl From<common::Error> for Error {
     fn from(err: common::Error) -> Error {
         Error::HabitatCommon(err)
@@ -192,6 +398,23 @@ impl From<handlebars::TemplateRenderError> for Error {
     }
 }
 
+impl From<http_client::Error> for Error {
+    fn from(err: http_client::Error) -> Error {
+        Error::HttpClient(err)
+    }
+}
+
+impl From<sup_client::Error> for Error {
+    fn from(err: sup_client::Error) -> Error {
+        match err {
+            sup_client::Error::InvalidAddress(_) => {
+                Error::ArgumentError("Invalid Supervisor address")
+            }
+            err => Error::SupervisorRequestFailed(err.to_string()),
+        }
+    }
+}
+
 impl From<io::Error> for Error {
     fn from(err: io::Error) -> Error {
         Error::IO(err)
@@ -203,3 +426,26 @@ impl From<path::StripPrefixError> for Error {
         Error::PathPrefixError(err)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::io;
+
+    use hcore;
+    use hcore::package::PackageIdent;
+
+    use super::*;
+
+    #[test]
+    fn errors_map_to_exit_codes_by_kind() {
+        assert_eq!(Error::ArgumentError("bad").exit_code(), EXIT_USAGE);
+        assert_eq!(Error::AuditFailed(2).exit_code(), EXIT_CHECK_FAILED);
+        let ident = PackageIdent::new("core", "redis", None, None);
+        let err = Error::HabitatCore(hcore::Error::PackageNotFound(ident));
+        assert_eq!(err.exit_code(), EXIT_NOT_FOUND);
+        assert_eq!(err.code(), "package-not-found");
+        let err = Error::IO(io::Error::new(io::ErrorKind::PermissionDenied, "denied"));
+        assert_eq!(err.exit_code(), EXIT_PERMISSION);
+        assert_eq!(Error::TomlError.report().remediation, None);
+    }
+}
@@ -16,6 +16,7 @@ extern crate habitat_core as hcore;
 extern crate habitat_common as common;
 extern crate habitat_depot_client as depot_client;
 extern crate habitat_http_client as http_client;
+extern crate habitat_sup_client as sup_client;
 extern crate handlebars;
 
 extern crate ansi_term;
@@ -30,6 +31,7 @@ extern crate retry;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
+extern crate serde_json;
 extern crate toml;
 extern crate url;
 extern crate uuid;
@@ -46,3 +48,4 @@ pub const PRODUCT: &'static str = "hab";
 pub const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"));
 pub const AUTH_TOKEN_ENVVAR: &'static str = "HAB_AUTH_TOKEN";
 pub const ORIGIN_ENVVAR: &'static str = "HAB_ORIGIN";
+pub const SUP_GATEWAY_AUTH_TOKEN_ENVVAR: &'static str = "HAB_SUP_GATEWAY_AUTH_TOKEN";
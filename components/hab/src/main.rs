@@ -21,61 +21,90 @@ extern crate habitat_common as common;
 extern crate handlebars;
 #[macro_use]
 extern crate log;
+extern crate serde_json;
 
 use std::env;
 use std::ffi::OsString;
-use std::io::{self, Read};
+use std::fs::File;
+use std::io::{self, Read, Write};
 use std::path::Path;
 use std::str::FromStr;
 use std::thread;
 
-use clap::{ArgMatches, Shell};
+use clap::ArgMatches;
 
-use common::ui::{Coloring, UI, NOCOLORING_ENVVAR, NONINTERACTIVE_ENVVAR};
+use common::ui::{Coloring, OutputFormat, UI, Verbosity, NOCOLORING_ENVVAR,
+                 NONINTERACTIVE_ENVVAR};
 use hcore::env as henv;
 use hcore::crypto::{init, default_cache_key_path, SigKeyPair};
 use hcore::crypto::keys::PairType;
-use hcore::fs::{cache_artifact_path, cache_analytics_path, cache_key_path, FS_ROOT_PATH};
+use hcore::fs::{cache_artifact_path, cache_analytics_path, cache_key_path, fs_root_path};
 use hcore::service::ServiceGroup;
-use hcore::package::PackageIdent;
+use hcore::package::{LockFile, PackageIdent};
+use hcore::package::lock::LOCK_FILE;
 use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 
 use hab::{analytics, cli, command, config, AUTH_TOKEN_ENVVAR, ORIGIN_ENVVAR, PRODUCT, VERSION};
-use hab::error::{Error, Result};
+use hab::error::{Error, ErrorReport, Result, EXIT_USAGE};
 
 /// Makes the --org CLI param optional when this env var is set
 const HABITAT_ORG_ENVVAR: &'static str = "HAB_ORG";
 
-const FS_ROOT_ENVVAR: &'static str = "FS_ROOT";
-
 const DEFAULT_BINLINK_DIR: &'static str = "/bin";
 
 fn main() {
     env_logger::init().unwrap();
-    let mut ui = ui();
+    let mut ui = build_ui(None);
+    let mut json_errors = false;
     thread::spawn(|| analytics::instrument_subcommand());
-    if let Err(e) = start(&mut ui) {
-        ui.fatal(e).unwrap();
-        std::process::exit(1)
+    if let Err(e) = start(&mut ui, &mut json_errors) {
+        analytics::instrument_error(&e);
+        if json_errors {
+            print_error_report(&e.report());
+        } else {
+            ui.fatal(&e).unwrap();
+        }
+        std::process::exit(e.exit_code())
     }
 }
 
-fn start(ui: &mut UI) -> Result<()> {
+fn start(ui: &mut UI, json_errors: &mut bool) -> Result<()> {
     try!(exec_subcommand_if_called(ui));
 
     let (args, remaining_args) = raw_parse_args();
     debug!("clap cli args: {:?}", &args);
     debug!("remaining cli args: {:?}", &remaining_args);
-    let app_matches =
-        cli::get().get_matches_from_safe_borrow(&mut args.iter()).unwrap_or_else(|e| {
-            analytics::instrument_clap_error(&e);
-            e.exit();
-        });
+    let app_matches = match cli::get().get_matches_from_safe_borrow(&mut args.iter()) {
+        Ok(matches) => matches,
+        Err(e) => exit_with_usage_error(e),
+    };
+    *ui = build_ui(Some(&app_matches));
+    *json_errors = global_flag(&app_matches, "JSON_ERRORS");
     match app_matches.subcommand() {
+        ("bldr", Some(matches)) => {
+            match matches.subcommand() {
+                ("job", Some(m)) => {
+                    match m.subcommand() {
+                        ("start", Some(sc)) => try!(sub_bldr_job_start(ui, sc)),
+                        ("status", Some(sc)) => try!(sub_bldr_job_status(ui, sc)),
+                        _ => unreachable!(),
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
         ("cli", Some(matches)) => {
             match matches.subcommand() {
                 ("setup", Some(_)) => try!(sub_cli_setup(ui)),
+                ("analytics", Some(m)) => try!(sub_cli_analytics(ui, m)),
                 ("completers", Some(m)) => try!(sub_cli_completers(m)),
+                ("complete", Some(m)) => try!(sub_cli_complete(m)),
+                _ => unreachable!(),
+            }
+        }
+        ("depot", Some(matches)) => {
+            match matches.subcommand() {
+                ("sync", Some(m)) => try!(sub_depot_sync(ui, m)),
                 _ => unreachable!(),
             }
         }
@@ -92,22 +121,38 @@ fn start(ui: &mut UI) -> Result<()> {
                         _ => unreachable!(),
                     }
                 }
+                ("secret", Some(m)) => {
+                    match m.subcommand() {
+                        ("delete", Some(sc)) => try!(sub_origin_secret_delete(ui, sc)),
+                        ("list", Some(sc)) => try!(sub_origin_secret_list(sc)),
+                        ("upload", Some(sc)) => try!(sub_origin_secret_upload(ui, sc)),
+                        _ => unreachable!(),
+                    }
+                }
                 _ => unreachable!(),
             }
         }
         ("pkg", Some(matches)) => {
             match matches.subcommand() {
+                ("audit", Some(m)) => try!(sub_pkg_audit(ui, m)),
                 ("binlink", Some(m)) => try!(sub_pkg_binlink(ui, m)),
                 ("build", Some(m)) => try!(sub_pkg_build(ui, m)),
+                ("bulkupload", Some(m)) => try!(sub_pkg_bulkupload(ui, m)),
                 ("config", Some(m)) => try!(sub_pkg_config(m)),
+                ("env", Some(m)) => try!(sub_pkg_env(m)),
                 ("exec", Some(m)) => try!(sub_pkg_exec(m, remaining_args)),
                 ("export", Some(m)) => try!(sub_pkg_export(ui, m)),
+                ("extract", Some(m)) => try!(sub_pkg_extract(ui, m)),
                 ("hash", Some(m)) => try!(sub_pkg_hash(m)),
                 ("install", Some(m)) => try!(sub_pkg_install(ui, m)),
+                ("license", Some(m)) => try!(sub_pkg_license(m)),
+                ("lock", Some(m)) => try!(sub_pkg_lock(ui, m)),
                 ("path", Some(m)) => try!(sub_pkg_path(m)),
                 ("provides", Some(m)) => try!(sub_pkg_provides(m)),
+                ("sbom", Some(m)) => try!(sub_pkg_sbom(m)),
                 ("search", Some(m)) => try!(sub_pkg_search(m)),
                 ("sign", Some(m)) => try!(sub_pkg_sign(ui, m)),
+                ("solve", Some(m)) => try!(sub_pkg_solve(ui, m)),
                 ("upload", Some(m)) => try!(sub_pkg_upload(ui, m)),
                 ("verify", Some(m)) => try!(sub_pkg_verify(ui, m)),
                 ("header", Some(m)) => try!(sub_pkg_header(ui, m)),
@@ -117,6 +162,7 @@ fn start(ui: &mut UI) -> Result<()> {
         ("plan", Some(matches)) => {
             match matches.subcommand() {
                 ("init", Some(m)) => try!(sub_plan_init(ui, m)),
+                ("check", Some(m)) => try!(sub_plan_check(ui, m)),
                 _ => unreachable!(),
             }
         }
@@ -133,6 +179,12 @@ fn start(ui: &mut UI) -> Result<()> {
                 _ => unreachable!(),
             }
         }
+        ("scale", Some(matches)) => {
+            match matches.subcommand() {
+                ("status", Some(m)) => try!(sub_scale_status(ui, m)),
+                _ => unreachable!(),
+            }
+        }
         ("service", Some(matches)) => {
             match matches.subcommand() {
                 ("key", Some(m)) => {
@@ -141,6 +193,9 @@ fn start(ui: &mut UI) -> Result<()> {
                         _ => unreachable!(),
                     }
                 }
+                ("elect", Some(m)) => try!(sub_service_elect(ui, m)),
+                ("logs", Some(m)) => try!(sub_service_logs(m)),
+                ("status", Some(m)) => try!(sub_service_status(ui, m)),
                 _ => unreachable!(),
             }
         }
@@ -161,8 +216,24 @@ fn start(ui: &mut UI) -> Result<()> {
     Ok(())
 }
 
+fn sub_bldr_job_start(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let project = m.value_of("PROJECT").unwrap(); // Required via clap
+    let token = try!(auth_token_param_or_env(&m));
+    command::bldr::job::start::start(ui, &url, &project, &token, m.is_present("FOLLOW"))
+}
+
+fn sub_bldr_job_status(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let id = m.value_of("JOB_ID").unwrap().parse::<u64>().unwrap(); // Validated via clap
+    let token = try!(auth_token_param_or_env(&m));
+    command::bldr::job::status::start(ui, &url, id, &token, m.is_present("FOLLOW"))
+}
+
 fn sub_cli_setup(ui: &mut UI) -> Result<()> {
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Some(Path::new(&fs_root));
     init();
 
@@ -171,14 +242,48 @@ fn sub_cli_setup(ui: &mut UI) -> Result<()> {
                                &cache_analytics_path(fs_root_path))
 }
 
+fn sub_cli_analytics(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = fs_root_path();
+    let fs_root_path = Some(Path::new(&fs_root));
+    command::cli::analytics::start(ui,
+                                   &cache_analytics_path(fs_root_path),
+                                   m.value_of("ACTION").unwrap_or("status"))
+}
+
 fn sub_cli_completers(m: &ArgMatches) -> Result<()> {
     let shell = m.value_of("SHELL").expect("Missing Shell; A shell is required");
-    cli::get().gen_completions_to("hab", shell.parse::<Shell>().unwrap(), &mut io::stdout());
+    command::cli::completers::start(shell)
+}
+
+fn sub_cli_complete(m: &ArgMatches) -> Result<()> {
+    match m.value_of("KIND").unwrap() {
+        "services" => {
+            let sup_addr = m.value_of("SUP_ADDR")
+                .unwrap_or(command::service::status::DEFAULT_SUP_ADDR);
+            command::cli::completers::services(sup_addr)
+        }
+        _ => {
+            let fs_root = fs_root_path();
+            command::cli::completers::packages(Some(Path::new(&fs_root)))
+        }
+    }
+}
+
+fn sub_depot_sync(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let origins: Vec<&str> = m.values_of("ORIGIN").unwrap().collect(); // Required via clap
+    let dest = Path::new(m.value_of("DEST_DIR").unwrap()); // Required via clap
+    try!(command::depot::sync::start(ui, url, &origins, m.value_of("CHANNEL"), dest));
+    if let Some(target) = m.value_of("TARGET_URL") {
+        let token = try!(auth_token_param_or_env(&m));
+        try!(command::pkg::bulkupload::start(ui, target, &token, dest));
+    }
     Ok(())
 }
 
 fn sub_origin_key_download(ui: &mut UI, m: &ArgMatches) -> Result<()> {
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Some(Path::new(&fs_root));
     let origin = m.value_of("ORIGIN").unwrap(); // Required via clap
     let revision = m.value_of("REVISION");
@@ -193,7 +298,7 @@ fn sub_origin_key_download(ui: &mut UI, m: &ArgMatches) -> Result<()> {
 }
 
 fn sub_origin_key_export(m: &ArgMatches) -> Result<()> {
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Some(Path::new(&fs_root));
     let origin = m.value_of("ORIGIN").unwrap(); // Required via clap
     let pair_type = try!(PairType::from_str(m.value_of("PAIR_TYPE").unwrap()));
@@ -203,7 +308,7 @@ fn sub_origin_key_export(m: &ArgMatches) -> Result<()> {
 }
 
 fn sub_origin_key_generate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Some(Path::new(&fs_root));
     let origin = try!(origin_param_or_env(&m));
     init();
@@ -212,7 +317,7 @@ fn sub_origin_key_generate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
 }
 
 fn sub_origin_key_import(ui: &mut UI) -> Result<()> {
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Some(Path::new(&fs_root));
     let mut content = String::new();
     try!(io::stdin().read_to_string(&mut content));
@@ -223,7 +328,7 @@ fn sub_origin_key_import(ui: &mut UI) -> Result<()> {
 
 fn sub_origin_key_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
 
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Some(Path::new(&fs_root));
 
     let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
@@ -249,8 +354,56 @@ fn sub_origin_key_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     }
 }
 
+fn sub_origin_secret_delete(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let token = try!(auth_token_param_or_env(&m));
+    let origin = try!(origin_param_or_env(&m));
+    let name = m.value_of("NAME").unwrap(); // Required via clap
+    command::origin::secret::delete::start(ui, url, &token, &origin, name)
+}
+
+fn sub_origin_secret_list(m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let token = try!(auth_token_param_or_env(&m));
+    let origin = try!(origin_param_or_env(&m));
+    command::origin::secret::list::start(url, &token, &origin)
+}
+
+fn sub_origin_secret_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = fs_root_path();
+    let fs_root_path = Some(Path::new(&fs_root));
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let token = try!(auth_token_param_or_env(&m));
+    let origin = try!(origin_param_or_env(&m));
+    let name = m.value_of("NAME").unwrap(); // Required via clap
+    let mut value = String::new();
+    match m.value_of("FILE") {
+        Some("-") | None => try!(io::stdin().read_to_string(&mut value)),
+        Some(p) => try!(File::open(p).and_then(|mut f| f.read_to_string(&mut value))),
+    };
+    // Drop the newline ending the value, as left by `echo` or an editor.
+    if value.ends_with('\n') {
+        value.pop();
+        if value.ends_with('\r') {
+            value.pop();
+        }
+    }
+    init();
+
+    command::origin::secret::upload::start(ui,
+                                           url,
+                                           &token,
+                                           &origin,
+                                           name,
+                                           &value,
+                                           &default_cache_key_path(fs_root_path))
+}
+
 fn sub_pkg_binlink(ui: &mut UI, m: &ArgMatches) -> Result<()> {
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Path::new(&fs_root);
     let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
     let binary = m.value_of("BINARY").unwrap(); // Required via clap
@@ -259,8 +412,23 @@ fn sub_pkg_binlink(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::pkg::binlink::start(ui, &ident, &binary, &dest_dir, &fs_root_path)
 }
 
+fn sub_pkg_audit(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = fs_root_path();
+    let fs_root_path = Path::new(&fs_root);
+
+    if let Some(src) = m.value_of("UPDATE") {
+        return command::pkg::audit::update(ui, Path::new(src), &fs_root_path);
+    }
+    let sup_addr = m.value_of("SUP_ADDR").unwrap_or(command::service::status::DEFAULT_SUP_ADDR);
+    command::pkg::audit::start(ui,
+                               &fs_root_path,
+                               m.value_of("DB").map(|db| Path::new(db)),
+                               sup_addr,
+                               m.is_present("JSON"))
+}
+
 fn sub_pkg_build(ui: &mut UI, m: &ArgMatches) -> Result<()> {
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Some(Path::new(&fs_root));
 
     let plan_context = m.value_of("PLAN_CONTEXT").unwrap(); // Required via clap
@@ -284,18 +452,27 @@ fn sub_pkg_build(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         None => None,
     };
     let reuse = m.is_present("REUSE");
+    let reproducible = m.is_present("REPRODUCIBLE");
 
-    command::pkg::build::start(ui, plan_context, root, src, keys, reuse)
+    command::pkg::build::start(ui, plan_context, root, src, keys, reuse, reproducible)
 }
 
 fn sub_pkg_config(m: &ArgMatches) -> Result<()> {
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Path::new(&fs_root);
     let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
 
     command::pkg::config::start(&ident, &fs_root_path)
 }
 
+fn sub_pkg_env(m: &ArgMatches) -> Result<()> {
+    let fs_root = fs_root_path();
+    let fs_root_path = Path::new(&fs_root);
+    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
+
+    command::pkg::env::start(&ident, &fs_root_path, m.is_present("JSON"))
+}
+
 fn sub_pkg_exec(m: &ArgMatches, cmd_args: Vec<OsString>) -> Result<()> {
     let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())); // Required via clap
     let cmd = m.value_of("CMD").unwrap(); // Required via clap
@@ -304,12 +481,37 @@ fn sub_pkg_exec(m: &ArgMatches, cmd_args: Vec<OsString>) -> Result<()> {
 }
 
 fn sub_pkg_export(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    match m.subcommand() {
+        ("sign-bundle", Some(sc)) => return sub_pkg_export_sign_bundle(ui, sc),
+        ("verify-bundle", Some(sc)) => return sub_pkg_export_verify_bundle(ui, sc),
+        _ => (),
+    }
     let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())); // Required via clap
     let format = &m.value_of("FORMAT").unwrap(); // Required via clap
     let export_fmt = try!(command::pkg::export::format_for(ui, &format));
     command::pkg::export::start(ui, &ident, &export_fmt)
 }
 
+fn sub_pkg_export_sign_bundle(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = fs_root_path();
+    let fs_root_path = Some(Path::new(&fs_root));
+    let root = Path::new(m.value_of("ROOT").unwrap()); // Required via clap
+    init();
+    let pair = try!(SigKeyPair::get_latest_pair_for(&try!(origin_param_or_env(&m)),
+                                                    &default_cache_key_path(fs_root_path)));
+
+    command::pkg::bundle::sign(ui, &root, &pair)
+}
+
+fn sub_pkg_export_verify_bundle(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = fs_root_path();
+    let fs_root_path = Some(Path::new(&fs_root));
+    let root = Path::new(m.value_of("ROOT").unwrap()); // Required via clap
+    init();
+
+    command::pkg::bundle::verify(ui, &root, &default_cache_key_path(fs_root_path))
+}
+
 fn sub_pkg_hash(m: &ArgMatches) -> Result<()> {
     let source = m.value_of("SOURCE").unwrap(); // Required via clap
 
@@ -324,12 +526,19 @@ fn sub_plan_init(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::plan::init::start(ui, origin, include_callbacks, name)
 }
 
+fn sub_plan_check(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let plan_context = Path::new(m.value_of("PLAN_CONTEXT").unwrap_or("."));
+    let json = m.value_of("FORMAT") == Some("json");
+    command::plan::check::start(ui, plan_context, json, m.is_present("STRICT"))
+}
+
 fn sub_pkg_install(ui: &mut UI, m: &ArgMatches) -> Result<()> {
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Some(Path::new(&fs_root));
     let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
     let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
-    let ident_or_artifacts = m.values_of("PKG_IDENT_OR_ARTIFACT").unwrap(); // Required via clap
+    // Required via clap
+    let ident_or_artifacts: Vec<&str> = m.values_of("PKG_IDENT_OR_ARTIFACT").unwrap().collect();
     let ignore_target = if m.is_present("IGNORE_TARGET") {
         true
     } else {
@@ -337,10 +546,33 @@ fn sub_pkg_install(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     };
     init();
 
+    let ident_or_artifacts = match m.value_of("LOCKED") {
+        // Everything installed must be a release the lockfile pins.
+        Some(lock_path) => {
+            let lock = try!(LockFile::load(lock_path));
+            try!(common::command::package::lock::pin_locked(ui,
+                                                            url,
+                                                            &ident_or_artifacts,
+                                                            &lock,
+                                                            PRODUCT,
+                                                            VERSION,
+                                                            Path::new(&fs_root)))
+        }
+        // Requirements with version constraints are resolved together before anything's
+        // installed.
+        None => {
+            try!(common::command::package::solve::pin_requirements(ui,
+                                                                   url,
+                                                                   &ident_or_artifacts,
+                                                                   PRODUCT,
+                                                                   VERSION,
+                                                                   Path::new(&fs_root)))
+        }
+    };
     for ident_or_artifact in ident_or_artifacts {
-        let pkg_ident = try!(common::command::package::install::start(ui,
+        let pkg_idents = try!(common::command::package::install::start_all(ui,
                                                       url,
-                                                      ident_or_artifact,
+                                                      &ident_or_artifact,
                                                       PRODUCT,
                                                       VERSION,
                                                       Path::new(&fs_root),
@@ -348,17 +580,38 @@ fn sub_pkg_install(ui: &mut UI, m: &ArgMatches) -> Result<()> {
                                                       ignore_target));
         if m.is_present("BINLINK") {
             let dest_dir = Path::new(m.value_of("DEST_DIR").unwrap_or(DEFAULT_BINLINK_DIR));
-            command::pkg::binlink::binlink_all_in_pkg(ui,
-                                                      &pkg_ident,
-                                                      dest_dir,
-                                                      &Path::new(&fs_root))?;
+            for pkg_ident in pkg_idents {
+                command::pkg::binlink::binlink_all_in_pkg(ui,
+                                                          &pkg_ident,
+                                                          dest_dir,
+                                                          &Path::new(&fs_root))?;
+            }
         }
     }
     Ok(())
 }
 
+fn sub_pkg_license(m: &ArgMatches) -> Result<()> {
+    let fs_root = fs_root_path();
+    let fs_root_path = Path::new(&fs_root);
+    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
+
+    command::pkg::license::start(&ident, &fs_root_path)
+}
+
+fn sub_pkg_lock(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = fs_root_path();
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    // Required via clap
+    let requirements: Vec<&str> = m.values_of("REQUIREMENT").unwrap().collect();
+    let lock_path = Path::new(m.value_of("OUTPUT").unwrap_or(LOCK_FILE));
+
+    command::pkg::lock::start(ui, url, &requirements, Path::new(&fs_root), lock_path)
+}
+
 fn sub_pkg_path(m: &ArgMatches) -> Result<()> {
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Path::new(&fs_root);
     let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
 
@@ -366,7 +619,7 @@ fn sub_pkg_path(m: &ArgMatches) -> Result<()> {
 }
 
 fn sub_pkg_provides(m: &ArgMatches) -> Result<()> {
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Path::new(&fs_root);
     let filename = m.value_of("FILE").unwrap(); // Required via clap
 
@@ -376,6 +629,16 @@ fn sub_pkg_provides(m: &ArgMatches) -> Result<()> {
     command::pkg::provides::start(&filename, &fs_root_path, full_releases, full_paths)
 }
 
+fn sub_pkg_sbom(m: &ArgMatches) -> Result<()> {
+    let fs_root = fs_root_path();
+    let fs_root_path = Path::new(&fs_root);
+    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
+    let format = try!(command::pkg::sbom::Format::from_str(m.value_of("FORMAT")
+        .unwrap_or("cyclonedx")));
+
+    command::pkg::sbom::start(&ident, format, &fs_root_path)
+}
+
 fn sub_pkg_search(m: &ArgMatches) -> Result<()> {
     let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
     let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
@@ -384,7 +647,7 @@ fn sub_pkg_search(m: &ArgMatches) -> Result<()> {
 }
 
 fn sub_pkg_sign(ui: &mut UI, m: &ArgMatches) -> Result<()> {
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Some(Path::new(&fs_root));
     let src = Path::new(m.value_of("SOURCE").unwrap()); // Required via clap
     let dst = Path::new(m.value_of("DEST").unwrap()); // Required via clap
@@ -395,9 +658,23 @@ fn sub_pkg_sign(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::pkg::sign::start(ui, &pair, &src, &dst)
 }
 
+fn sub_pkg_solve(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = fs_root_path();
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = if m.is_present("INSTALLED") {
+        None
+    } else {
+        Some(m.value_of("DEPOT_URL").unwrap_or(&env_or_default))
+    };
+    // Required via clap
+    let requirements: Vec<&str> = m.values_of("REQUIREMENT").unwrap().collect();
+
+    command::pkg::solve::start(ui, url, &requirements, Path::new(&fs_root))
+}
+
 fn sub_pkg_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Some(Path::new(&fs_root));
     let key_path = cache_key_path(fs_root_path);
     // don't use a pathbuf, as the P generic param for upload::start below is bound to a &str
@@ -411,8 +688,16 @@ fn sub_pkg_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     Ok(())
 }
 
+fn sub_pkg_bulkupload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let token = try!(auth_token_param_or_env(&m));
+    let upload_dir = Path::new(m.value_of("UPLOAD_DIRECTORY").unwrap()); // Required via clap
+    command::pkg::bulkupload::start(ui, &url, &token, upload_dir)
+}
+
 fn sub_pkg_verify(ui: &mut UI, m: &ArgMatches) -> Result<()> {
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Some(Path::new(&fs_root));
     let src = Path::new(m.value_of("SOURCE").unwrap()); // Required via clap
     init();
@@ -420,6 +705,13 @@ fn sub_pkg_verify(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::pkg::verify::start(ui, &src, &default_cache_key_path(fs_root_path))
 }
 
+fn sub_pkg_extract(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let src = Path::new(m.value_of("SOURCE").unwrap()); // Required via clap
+    let dst = Path::new(m.value_of("DEST_DIR").unwrap()); // Required via clap
+
+    command::pkg::extract::start(ui, &src, &dst)
+}
+
 fn sub_pkg_header(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let src = Path::new(m.value_of("SOURCE").unwrap()); // Required via clap
     init();
@@ -428,7 +720,7 @@ fn sub_pkg_header(ui: &mut UI, m: &ArgMatches) -> Result<()> {
 }
 
 fn sub_ring_key_export(m: &ArgMatches) -> Result<()> {
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Some(Path::new(&fs_root));
     let ring = m.value_of("RING").unwrap(); // Required via clap
     init();
@@ -437,7 +729,7 @@ fn sub_ring_key_export(m: &ArgMatches) -> Result<()> {
 }
 
 fn sub_ring_key_generate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Some(Path::new(&fs_root));
     let ring = m.value_of("RING").unwrap(); // Required via clap
     init();
@@ -446,7 +738,7 @@ fn sub_ring_key_generate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
 }
 
 fn sub_ring_key_import(ui: &mut UI) -> Result<()> {
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Some(Path::new(&fs_root));
     let mut content = String::new();
     try!(io::stdin().read_to_string(&mut content));
@@ -456,7 +748,7 @@ fn sub_ring_key_import(ui: &mut UI) -> Result<()> {
 }
 
 fn sub_service_key_generate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Some(Path::new(&fs_root));
     let org = try!(org_param_or_env(&m));
     let service_group = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap()));
@@ -468,8 +760,37 @@ fn sub_service_key_generate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
                                            &default_cache_key_path(fs_root_path))
 }
 
+fn sub_scale_status(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let sup_addr = m.value_of("SUP_ADDR").unwrap_or(command::service::status::DEFAULT_SUP_ADDR);
+    command::scale::status::start(ui, sup_addr, m.value_of("SERVICE_GROUP"))
+}
+
+fn sub_service_elect(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let sup_addr = m.value_of("SUP_ADDR").unwrap_or(command::service::elect::DEFAULT_CONTROL_ADDR);
+    let service_group = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap()));
+    command::service::elect::start(ui, sup_addr, &service_group, m.is_present("FORCE"))
+}
+
+fn sub_service_logs(m: &ArgMatches) -> Result<()> {
+    let sup_addr = m.value_of("SUP_ADDR").unwrap_or(command::service::status::DEFAULT_SUP_ADDR);
+    let service_group = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap()));
+    command::service::logs::start(sup_addr,
+                                  &service_group,
+                                  m.value_of("LINES"),
+                                  m.value_of("SINCE"),
+                                  m.is_present("FOLLOW"))
+}
+
+fn sub_service_status(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let sup_addr = m.value_of("SUP_ADDR").unwrap_or(command::service::status::DEFAULT_SUP_ADDR);
+    command::service::status::start(ui,
+                                    sup_addr,
+                                    m.value_of("SERVICE_GROUP"),
+                                    m.is_present("FOLLOW_HOOKS"))
+}
+
 fn sub_user_key_generate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
-    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root = fs_root_path();
     let fs_root_path = Some(Path::new(&fs_root));
     let user = m.value_of("USER").unwrap(); // Required via clap
     init();
@@ -477,18 +798,73 @@ fn sub_user_key_generate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::user::key::generate::start(ui, user, &default_cache_key_path(fs_root_path))
 }
 
-fn ui() -> UI {
+/// Builds the UI from the environment and, once the command line has been parsed, the global
+/// output flags in `matches`. Commands which are handed off to other programs without being
+/// parsed leave their flags to those programs, and get a UI built from the environment alone.
+fn build_ui(matches: Option<&ArgMatches>) -> UI {
+    let flag = |name: &str| matches.map_or(false, |m| global_flag(m, name));
     let isatty = if henv::var(NONINTERACTIVE_ENVVAR).map(|val| val == "true").unwrap_or(false) {
         Some(false)
     } else {
         None
     };
-    let coloring = if henv::var(NOCOLORING_ENVVAR).map(|val| val == "true").unwrap_or(false) {
+    let coloring = if flag("NO_COLOR") ||
+                      henv::var(NOCOLORING_ENVVAR).map(|val| val == "true").unwrap_or(false) {
         Coloring::Never
     } else {
         Coloring::Auto
     };
-    UI::default_with(coloring, isatty)
+    let verbosity = if flag("QUIET") {
+        Verbosity::Quiet
+    } else if flag("VERBOSE") {
+        Verbosity::Verbose
+    } else {
+        Verbosity::Normal
+    };
+    let format = if flag("JSON") {
+        OutputFormat::Json
+    } else {
+        OutputFormat::Text
+    };
+    UI::new(coloring, isatty, verbosity, format)
+}
+
+/// Whether a global flag was given, before the subcommand or after any of its levels.
+fn global_flag(matches: &ArgMatches, name: &str) -> bool {
+    matches.is_present(name) || matches.subcommand().1.map_or(false, |m| global_flag(m, name))
+}
+
+/// Prints the help or version asked for and exits successfully, or reports a command line which
+/// doesn't parse and exits with `EXIT_USAGE`, rather than clap's exit code of 1.
+fn exit_with_usage_error(e: clap::Error) -> ! {
+    analytics::instrument_clap_error(&e);
+    if !e.use_stderr() {
+        e.exit();
+    }
+    if raw_json_errors() {
+        print_error_report(&ErrorReport {
+            code: "invalid-usage",
+            exit_code: EXIT_USAGE,
+            subsystem: "cli",
+            message: e.message.clone(),
+            remediation: Some("Run the command with --help to see its usage"),
+        });
+    } else {
+        writeln!(io::stderr(), "{}", e.message).unwrap();
+    }
+    std::process::exit(EXIT_USAGE)
+}
+
+/// Whether `--json-errors` was given, for a command line which doesn't parse. With nothing
+/// parsed, it's looked for in the raw arguments, up to any `--`.
+fn raw_json_errors() -> bool {
+    env::args().skip(1).take_while(|arg| arg != "--").any(|arg| arg == "--json-errors")
+}
+
+/// Print a failure as a single line of JSON on stderr, for `--json-errors`.
+fn print_error_report(report: &ErrorReport) {
+    let json = serde_json::to_string(report).expect("An error report is valid JSON");
+    writeln!(io::stderr(), "{}", json).unwrap();
 }
 
 fn exec_subcommand_if_called(ui: &mut UI) -> Result<()> {
@@ -500,7 +876,18 @@ fn exec_subcommand_if_called(ui: &mut UI) -> Result<()> {
             args.insert(0, OsString::from("config"));
             command::butterfly::start(ui, args)
         }
-        ("config", _) | ("file", _) => {
+        ("config", _) | ("file", _) | ("scale", "set") => {
+            command::butterfly::start(ui, env::args_os().skip(1).collect())
+        }
+        ("r", "exec") | ("ri", "exec") | ("rin", "exec") | ("ring", "exec") => {
+            command::butterfly::start(ui, env::args_os().skip(1).collect())
+        }
+        ("se", "switch") | ("ser", "switch") | ("serv", "switch") | ("servi", "switch") |
+        ("servic", "switch") | ("service", "switch") | ("svc", "switch") => {
+            command::butterfly::start(ui, env::args_os().skip(1).collect())
+        }
+        ("se", "restart") | ("ser", "restart") | ("serv", "restart") | ("servi", "restart") |
+        ("servic", "restart") | ("service", "restart") | ("svc", "restart") => {
             command::butterfly::start(ui, env::args_os().skip(1).collect())
         }
         ("stu", _) | ("stud", _) | ("studi", _) | ("studio", _) => {
@@ -73,7 +73,30 @@ impl ApiClient {
                -> Result<Self> {
         Ok(ApiClient {
             endpoint: endpoint.clone(),
-            inner: try!(new_hyper_client(Some(endpoint), fs_root_path)),
+            inner: try!(new_hyper_client(Some(endpoint), fs_root_path, None)),
+            proxy: try!(proxy_unless_domain_exempted(Some(endpoint))),
+            target_scheme: endpoint.scheme().to_string(),
+            user_agent_header: try!(user_agent(product, version)),
+        })
+    }
+
+    /// Creates and returns a new `ApiClient` instance which only trusts the certificates in
+    /// `ca_file`, for endpoints with their own certificate authority such as a cluster's API.
+    ///
+    /// # Errors
+    ///
+    /// * If the underlying Hyper client cannot be created
+    /// * If the certificates in `ca_file` cannot be read
+    /// * If an HTTP proxy cannot be correctly setup
+    /// * If a `User-Agent` HTTP header string cannot be constructed
+    pub fn with_ca_file(endpoint: &Url,
+                        product: &str,
+                        version: &str,
+                        ca_file: &Path)
+                        -> Result<Self> {
+        Ok(ApiClient {
+            endpoint: endpoint.clone(),
+            inner: try!(new_hyper_client(Some(endpoint), None, Some(ca_file))),
             proxy: try!(proxy_unless_domain_exempted(Some(endpoint))),
             target_scheme: endpoint.scheme().to_string(),
             user_agent_header: try!(user_agent(product, version)),
@@ -231,8 +254,11 @@ impl ApiClient {
 /// library will default to using this on the Mac. Therefore the behavior on the Mac remains
 /// unchanged and will use the system's certificates.
 ///
-fn new_hyper_client(for_domain: Option<&Url>, fs_root_path: Option<&Path>) -> Result<HyperClient> {
-    let connector = try!(ssl_connector(fs_root_path));
+fn new_hyper_client(for_domain: Option<&Url>,
+                    fs_root_path: Option<&Path>,
+                    ca_file: Option<&Path>)
+                    -> Result<HyperClient> {
+    let connector = try!(ssl_connector(fs_root_path, ca_file));
     let ssl_client = OpensslClient::from(connector);
     let timeout = Some(Duration::from_secs(CLIENT_SOCKET_RW_TIMEOUT));
 
@@ -293,13 +319,16 @@ fn user_agent(product: &str, version: &str) -> Result<UserAgent> {
     Ok(UserAgent(ua))
 }
 
-fn ssl_connector(fs_root_path: Option<&Path>) -> Result<SslConnector> {
+fn ssl_connector(fs_root_path: Option<&Path>, ca_file: Option<&Path>) -> Result<SslConnector> {
     let mut conn = try!(SslConnectorBuilder::new(SslMethod::tls()));
     let mut options = SslOption::empty();
     options.toggle(SSL_OP_NO_SSLV2);
     options.toggle(SSL_OP_NO_SSLV3);
     options.toggle(SSL_OP_NO_COMPRESSION);
-    try!(ssl::set_ca(conn.builder_mut(), fs_root_path));
+    match ca_file {
+        Some(ca_file) => try!(conn.builder_mut().set_ca_file(ca_file)),
+        None => try!(ssl::set_ca(conn.builder_mut(), fs_root_path)),
+    }
     conn.builder_mut().set_options(options);
     try!(conn.builder_mut().set_cipher_list("ALL!EXPORT!EXPORT40!EXPORT56!aNULL!LOW!RC4@STRENGTH"));
     Ok(conn.build())
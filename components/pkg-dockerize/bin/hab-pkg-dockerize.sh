@@ -87,6 +87,28 @@ find_system_commands() {
   fi
 }
 
+# **Internal** Writes a signed provenance manifest of the exported packages into
+# the root file system, which can be checked on the receiving side with
+# `hab pkg export verify-bundle`. If no origin key is available to sign with,
+# the export carries on without a manifest.
+sign_bundle() {
+  local root="$1"
+  if ! hab pkg export sign-bundle "$root"; then
+    echo "WARN: Unable to sign a provenance manifest; this export can't be verified"
+  fi
+}
+
+# **Internal** Writes the software bill of materials of the exported package and
+# its dependencies into the root file system, at `hab/SBOM.json`.
+write_sbom() {
+  local root="$1"
+  local pkg="$2"
+  if ! env FS_ROOT="$root" hab pkg sbom "$pkg" > "$root/hab/SBOM.json"; then
+    rm -f "$root/hab/SBOM.json"
+    echo "WARN: Unable to write a software bill of materials for $pkg"
+  fi
+}
+
 # Wraps `dockerfile` to ensure that a Docker image build is being executed in a
 # clean directory with native filesystem permissions which is outside the
 # source code tree.
@@ -111,6 +133,18 @@ package_exposes() {
   fi
 }
 
+# Prints a `LABEL` instruction naming the licenses the package is distributed
+# under, if it records any.
+package_license_label() {
+  local pkg="$1"
+  local licenses_file=$(find $DOCKER_CONTEXT/rootfs/$HAB_ROOT_PATH/pkgs/$pkg \
+    -maxdepth 3 -type f -name LICENSES)
+  if [ -f "$licenses_file" ]; then
+    local licenses="$(awk 'NR > 1 { printf " AND " } { printf "%s", $0 }' "$licenses_file")"
+    echo "LABEL org.opencontainers.image.licenses=\"${licenses}\""
+  fi
+}
+
 package_version_tag() {
   local pkg="$1"
   local ident_file=$(find $DOCKER_CONTEXT/rootfs/$HAB_ROOT_PATH/pkgs/$pkg -name IDENT)
@@ -129,6 +163,8 @@ docker_image() {
   local version_tag=$(package_version_tag $1)
   local latest_tag=$(package_latest_tag $1)
   echo "$1" > $DOCKER_CONTEXT/rootfs/.hab_pkg
+  write_sbom $DOCKER_CONTEXT/rootfs $1
+  sign_bundle $DOCKER_CONTEXT/rootfs
   cat <<EOT > $DOCKER_CONTEXT/Dockerfile
 FROM scratch
 ENV $(cat $DOCKER_CONTEXT/rootfs/init.sh | grep PATH= | cut -d' ' -f2-)
@@ -136,6 +172,7 @@ WORKDIR /
 ADD rootfs /
 VOLUME $HAB_ROOT_PATH/svc/${pkg_name}/data $HAB_ROOT_PATH/svc/${pkg_name}/config
 EXPOSE 9631 $(package_exposes $1)
+$(package_license_label $1)
 ENTRYPOINT ["/init.sh"]
 CMD ["start", "$1"]
 EOT
@@ -116,14 +116,45 @@ parse_options() {
   fi
 }
 
+# **Internal** Writes a signed provenance manifest of the exported packages into
+# the root file system, which can be checked on the receiving side with
+# `hab pkg export verify-bundle`. If no origin key is available to sign with,
+# the export carries on without a manifest.
+sign_bundle() {
+  local root="$1"
+  if ! hab pkg export sign-bundle "$root"; then
+    echo "WARN: Unable to sign a provenance manifest; this export can't be verified"
+  fi
+}
+
+# **Internal** Writes the software bill of materials of the exported package and
+# its dependencies into the root file system, at `hab/SBOM.json`.
+write_sbom() {
+  local root="$1"
+  local pkg="$2"
+  if ! env FS_ROOT="$root" hab pkg sbom "$pkg" > "$root/hab/SBOM.json"; then
+    rm -f "$root/hab/SBOM.json"
+    echo "WARN: Unable to write a software bill of materials for $pkg"
+  fi
+}
+
 # Create a hab studio baseimage and populate it with the application
 build_tarball_image() {
   TARBALL_CONTEXT="$($_mktemp_cmd -t -d "${program}-XXXX")"
   pushd $TARBALL_CONTEXT > /dev/null
   env PKGS="$PKG" NO_MOUNT=1 hab studio -r $TARBALL_CONTEXT -t bare new
   echo $PKG > $TARBALL_CONTEXT/.hab_pkg
+  write_sbom $TARBALL_CONTEXT $PKG
+  sign_bundle $TARBALL_CONTEXT
   popd > /dev/null
-  tar -cpzf $(package_name_with_version $PKG).tar.gz -C $TARBALL_CONTEXT ./hab/pkgs ./hab/bin
+  local contents="./hab/pkgs ./hab/bin"
+  if [ -f "$TARBALL_CONTEXT/hab/PROVENANCE" ]; then
+    contents="$contents ./hab/PROVENANCE"
+  fi
+  if [ -f "$TARBALL_CONTEXT/hab/SBOM.json" ]; then
+    contents="$contents ./hab/SBOM.json"
+  fi
+  tar -cpzf $(package_name_with_version $PKG).tar.gz -C $TARBALL_CONTEXT $contents
 }
 
 package_name_with_version() {
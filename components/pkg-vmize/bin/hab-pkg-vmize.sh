@@ -0,0 +1,358 @@
+#!/bin/bash
+#
+# # Usage
+#
+# ```
+# $ hab-pkg-vmize [PKG ...]
+# ```
+#
+# # Synopsis
+#
+# Create a bundle which provisions a plain virtual machine to run a set of
+# Habitat packages as services.
+#
+# # License and Copyright
+#
+# ```
+# Copyright: Copyright (c) 2016-2017 Chef Software, Inc.
+# License: Apache License, Version 2.0
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#      http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+# ```
+
+# defaults for the application
+: ${PKG:="unknown"}
+
+# Fail if there are any unset variables and whenever a command returns a
+# non-zero exit code.
+set -eu
+
+# If the variable `$DEBUG` is set, then print the shell commands as we execute.
+if [ -n "${DEBUG:-}" ]; then
+  set -x
+  export DEBUG
+fi
+
+# ## Help
+
+# **Internal** Prints help
+print_help() {
+  printf -- "$program $version
+
+$author
+
+Habitat Package VMize - Create a bundle which provisions a virtual machine to run Habitat services
+
+USAGE:
+  $program [FLAGS] <PKG_IDENT>
+
+FLAGS:
+    --help           Prints help information
+
+ARGS:
+    <PKG_IDENT>      Habitat package identifier (ex: acme/redis)
+
+ENVIRONMENT VARIABLES:
+    SERVICES         More packages to run as services on the machine (ex: \"core/nginx core/redis\")
+    PEERS            Supervisors to join, as IP[:PORT] (ex: \"10.0.0.10 10.0.0.11:9638\")
+    RING             Ring key name; the latest revision of the key is put in the bundle's keys/,
+                     but never in user-data, so it must be delivered to the machine separately
+    GROUP            The service group of every service (default: default)
+    TOPOLOGY         The topology of every service (ex: leader)
+    STRATEGY         The update strategy of every service (ex: rolling)
+"
+}
+
+# **Internal** Exit the program with an error message and a status code.
+#
+# ```sh
+# exit_with "Something bad went down" 55
+# ```
+exit_with() {
+  if [ "${HAB_NOCOLORING:-}" = "true" ]; then
+    printf -- "ERROR: $1\n"
+  else
+    case "${TERM:-}" in
+      *term | xterm-* | rxvt | screen | screen-*)
+        printf -- "\033[1;31mERROR: \033[1;37m$1\033[0m\n"
+        ;;
+      *)
+        printf -- "ERROR: $1\n"
+        ;;
+    esac
+  fi
+  exit $2
+}
+
+find_system_commands() {
+  if $(mktemp --version 2>&1 | grep -q 'GNU coreutils'); then
+    _mktemp_cmd=$(command -v mktemp)
+  else
+    if $(/bin/mktemp --version 2>&1 | grep -q 'GNU coreutils'); then
+      _mktemp_cmd=/bin/mktemp
+    else
+      exit_with "We require GNU mktemp to build VM bundles; aborting" 1
+    fi
+  fi
+}
+
+# parse the CLI flags and options
+parse_options() {
+  for i in "$@"
+  do
+    case $i in
+      --help)
+        print_help
+        exit
+        ;;
+      *)
+        PKG=${i}
+        ;;
+    esac
+  done
+  if [ "$PKG" == "unknown" ]; then
+    print_help
+    exit_with "You must specify a Habitat package to run on the machine." 1
+  fi
+}
+
+# **Internal** Prints the fully qualified identifier of a package installed in
+# the bundle's root file system.
+fully_qualified_ident() {
+  local ident_file=$(find $VM_CONTEXT/$HAB_ROOT_PATH/pkgs/$1 -name IDENT | sort -r | head -1)
+  if [ -z "$ident_file" ]; then
+    exit_with "$1 wasn't installed into the bundle" 1
+  fi
+  cat $ident_file
+}
+
+# **Internal** Copies the public keys of every origin in the bundle, and the
+# latest revision of the ring key, if there is one, into the bundle's `keys/`.
+seed_keys() {
+  local keys="$1"
+  mkdir -p $keys
+  for origin in $(ls $VM_CONTEXT/$HAB_ROOT_PATH/pkgs); do
+    for key in $(ls $HAB_CACHE_KEY_PATH/${origin}-*.pub 2>/dev/null); do
+      cp $key $keys/
+    done
+  done
+  if [ -n "${RING:-}" ]; then
+    local ring_key=$(ls $HAB_CACHE_KEY_PATH/${RING}-*.sym.key 2>/dev/null | sort -r | head -1)
+    if [ -z "$ring_key" ]; then
+      exit_with "No ring key named $RING is in $HAB_CACHE_KEY_PATH" 1
+    fi
+    cp $ring_key $keys/
+  fi
+}
+
+# **Internal** Writes the script which provisions the machine, whether it runs
+# while an image is built, from a Vagrant shell provisioner, or from cloud-init
+# at first boot. The bundle's settings are written at the top of it.
+write_provision_script() {
+  local script="$1"
+  cat <<EOF > $script
+#!/bin/sh
+#
+# Provisions this machine to run Habitat services. Generated by $program $version.
+#
+# Run it as root from the bundle it came with. Each service gets a Supervisor
+# of its own, run by systemd, which starts it at boot; pass \`--now\` to start
+# them right away as well.
+
+set -eu
+
+VM_SERVICES="$VM_SERVICES"
+VM_HAB="$VM_HAB"
+VM_SUP="$VM_SUP"
+VM_PEERS="${PEERS:-}"
+VM_RING="${RING:-}"
+VM_GROUP="${GROUP:-}"
+VM_TOPOLOGY="${TOPOLOGY:-}"
+VM_STRATEGY="${STRATEGY:-}"
+EOF
+  cat <<'EOF' >> $script
+
+bundle=$(cd "$(dirname "$0")" && pwd)
+now=""
+if [ "${1:-}" = "--now" ]; then
+  now="true"
+fi
+
+# Install the Supervisor and the services: from the bundle's packages if it has
+# them, and otherwise from the Depot, at exactly the releases exported. The hab
+# CLI is installed at the version exported, with the install script of that
+# release.
+if [ -f "$bundle/hab.tar.gz" ]; then
+  tar -xzf "$bundle/hab.tar.gz" -C /
+  ln -sf /hab/bin/hab /bin/hab
+else
+  if ! command -v hab > /dev/null; then
+    hab_version=$(echo $VM_HAB | cut -d/ -f3)
+    curl -sSL "https://raw.githubusercontent.com/habitat-sh/habitat/$hab_version/components/hab/install.sh" \
+      | sh -s -- -v "$hab_version"
+  fi
+  hab pkg install $VM_HAB
+  ln -sf /hab/pkgs/$VM_HAB/bin/hab /bin/hab
+  hab pkg install $VM_SUP
+  for svc in $VM_SERVICES; do
+    hab pkg install $svc
+  done
+fi
+
+# Seed the key cache with the origin keys the packages are signed with, and the
+# ring key, so the Supervisors can verify updates and join an encrypted ring.
+# Booted from user-data alone, the machine has no ring key until it is copied
+# to /hab/cache/keys; the Supervisors are only started once it is there.
+mkdir -p /hab/cache/keys
+if [ -d "$bundle/keys" ]; then
+  cp "$bundle"/keys/* /hab/cache/keys/ 2>/dev/null || true
+fi
+chmod 600 /hab/cache/keys/*.sym.key 2>/dev/null || true
+ring_condition=""
+if [ -n "$VM_RING" ]; then
+  ring_condition="ConditionPathExistsGlob=/hab/cache/keys/$VM_RING-*.sym.key"
+  if ! ls /hab/cache/keys/$VM_RING-*.sym.key > /dev/null 2>&1; then
+    echo "The ring key $VM_RING is not in /hab/cache/keys; copy it there, then start the" \
+      "Supervisors with: systemctl start 'hab-sup-*'" >&2
+  fi
+fi
+
+if ! getent group hab > /dev/null; then
+  groupadd -r hab
+fi
+if ! getent passwd hab > /dev/null; then
+  useradd -r -g hab -d /hab -s /bin/false hab
+fi
+
+# The first Supervisor listens on the default ports; every other one listens
+# ten ports further along, and joins the ring through the first.
+i=0
+for svc in $VM_SERVICES; do
+  name=$(echo $svc | cut -d/ -f2)
+  args="--listen-gossip 0.0.0.0:$((9638 + i * 10)) --listen-http 0.0.0.0:$((9631 + i * 10))"
+  for peer in $VM_PEERS; do
+    args="$args --peer $peer"
+  done
+  if [ $i -gt 0 ]; then
+    args="$args --peer 127.0.0.1:9638"
+  fi
+  [ -n "$VM_RING" ] && args="$args --ring $VM_RING"
+  [ -n "$VM_GROUP" ] && args="$args --group $VM_GROUP"
+  [ -n "$VM_TOPOLOGY" ] && args="$args --topology $VM_TOPOLOGY"
+  [ -n "$VM_STRATEGY" ] && args="$args --strategy $VM_STRATEGY"
+
+  cat <<UNIT > /etc/systemd/system/hab-sup-$name.service
+[Unit]
+Description=Habitat Supervisor for $svc
+After=network-online.target
+Wants=network-online.target
+$ring_condition
+
+[Service]
+ExecStart=/bin/hab sup start $svc $args
+Restart=on-failure
+
+[Install]
+WantedBy=multi-user.target
+UNIT
+  systemctl daemon-reload
+  systemctl enable hab-sup-$name.service
+  if [ -n "$now" ]; then
+    systemctl start hab-sup-$name.service
+  fi
+  i=$((i + 1))
+done
+EOF
+  chmod 755 $script
+}
+
+# **Internal** Writes cloud-init user-data which carries the provisioning script
+# and public keys, and runs the script at first boot. User-data is too small to
+# carry packages, so a machine booted with it alone installs them from the
+# Depot. The ring key is left out: user-data is readable by anything on the
+# machine which can reach the metadata service, and is kept by cloud providers
+# and cloud-init alike.
+write_user_data() {
+  local bundle="$1"
+  {
+    echo "#cloud-config"
+    echo "write_files:"
+    echo "  - path: /var/lib/hab-vm/provision.sh"
+    echo "    permissions: '0755'"
+    echo "    encoding: b64"
+    echo "    content: $(base64 -w 0 $bundle/provision.sh)"
+    for key in $(ls $bundle/keys | grep -v '\.sym\.key$'); do
+      echo "  - path: /var/lib/hab-vm/keys/$key"
+      echo "    permissions: '0600'"
+      echo "    encoding: b64"
+      echo "    content: $(base64 -w 0 $bundle/keys/$key)"
+    done
+    echo "runcmd:"
+    echo "  - [/var/lib/hab-vm/provision.sh, --now]"
+  } > $bundle/user-data
+}
+
+# Create a hab studio baseimage, populate it with the services and the
+# Supervisor, and write the bundle around it
+build_vm_bundle() {
+  VM_CONTEXT="$($_mktemp_cmd -t -d "${program}-XXXX")"
+  pushd $VM_CONTEXT > /dev/null
+  env PKGS="$PKG ${SERVICES:-}" NO_MOUNT=1 hab studio -r $VM_CONTEXT -t bare new
+  popd > /dev/null
+
+  VM_SERVICES=""
+  for svc in $PKG ${SERVICES:-}; do
+    VM_SERVICES="$VM_SERVICES $(fully_qualified_ident $svc)"
+  done
+  VM_SERVICES="${VM_SERVICES# }"
+  VM_HAB="$(fully_qualified_ident core/hab)"
+  VM_SUP="$(fully_qualified_ident core/hab-sup)"
+
+  local bundle="$(fully_qualified_ident $PKG | awk 'BEGIN { FS = "/" }; \
+    { print $1 "-" $2 "-" $3 "-" $4 }')-vm"
+  rm -rf $bundle
+  mkdir -p $bundle
+  tar -cpzf $bundle/hab.tar.gz -C $VM_CONTEXT ./hab/pkgs ./hab/bin
+  seed_keys $bundle/keys
+  write_provision_script $bundle/provision.sh
+  write_user_data $bundle
+  echo "Wrote $bundle/"
+  if [ -n "${RING:-}" ]; then
+    echo "WARNING: $bundle/keys/ holds the secret ring key $RING; keep the bundle as"
+    echo "WARNING: private as the key itself. user-data does not carry it, so a machine"
+    echo "WARNING: booted with user-data alone needs it copied to /hab/cache/keys."
+  fi
+}
+
+# The root of the filesystem. If the program is running on a separate
+# filesystem or chroot environment, this environment variable may need to be
+# set.
+: ${FS_ROOT:=}
+# The root path of the Habitat file system. If the `$HAB_ROOT_PATH` environment
+# variable is set, this value is overridden, otherwise it is set to its default
+: ${HAB_ROOT_PATH:=$FS_ROOT/hab}
+# The key cache the origin and ring keys are seeded from
+: ${HAB_CACHE_KEY_PATH:=$HAB_ROOT_PATH/cache/keys}
+
+# The current version of Habitat Studio
+version='@version@'
+# The author of this program
+author='@author@'
+# The short version of the program name which is used in logging output
+program=$(basename $0)
+
+find_system_commands
+
+parse_options $@
+build_vm_bundle
+rm -rf $VM_CONTEXT
@@ -1060,6 +1060,7 @@ $(Get-Content "$PLAN_CONTEXT\plan.ps1" -Raw)
 # * `$pkg_prefix/FILES` - blake2b checksums of all files in the package
 # * `$pkg_prefix/LDFLAGS` - Any LDFLAGS for things that link against us
 # * `$pkg_prefix/LD_RUN_PATH` - The LD_RUN_PATH for things that link against us
+# * `$pkg_prefix/LICENSES` - The licenses the package is distributed under, one per line
 # * `$pkg_prefix/PATH` - Any PATH entries for things that link against us
 function _Write-Metadata {
     Write-BuildLine "Building pacakge metadata"
@@ -1088,6 +1089,10 @@ function _Write-Metadata {
             Out-File "$pkg_prefix\EXPOSES" -Encoding ascii
     }
 
+    if ($pkg_license.Length -gt 0) {
+        $pkg_license | Out-File "$pkg_prefix\LICENSES" -Encoding ascii
+    }
+
     foreach ($export in $pkg_exports.GetEnumerator()) {
         "$($export.Key)=$($export.Value)" | Out-File "$pkg_prefix\EXPORTS" -Encoding ascii -Append
     }
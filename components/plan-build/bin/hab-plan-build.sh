@@ -183,6 +183,29 @@
 # )
 # ```
 #
+# ### pkg_env
+# An associative array of environment variables exported to whatever runs the package, or builds
+# against it. The Supervisor starts the service and its hooks with the variables of the package and
+# every package in its dependency closure, and the build of a package depending on this one starts
+# with them. `PATH` can't be set here; it is built from `pkg_bin_dirs`.
+# ```
+# pkg_env=(
+#   [JAVA_HOME]="/hab/pkgs/core/jdk8/8u131/20170622181030"
+#   [PYTHONPATH]="lib/python3.6/site-packages"
+# )
+# ```
+#
+# ### pkg_env_sep
+# An associative array marking the variables of `pkg_env` which are lists, with the separator of
+# their entries. The entries of list variables from every package are appended, the package's own
+# first, and entries which aren't absolute paths are relative to the package. Any other variable
+# takes the package's own value, and dependencies setting it to different values is an error.
+# ```
+# pkg_env_sep=(
+#   [PYTHONPATH]=":"
+# )
+# ```
+#
 # ### pkg_exposes
 # An array of `pkg_exports` keys containing default values for which ports that this package
 # exposes. These values are used as sensible defaults for other tools. For example, when exporting
@@ -191,6 +214,165 @@
 # pkg_exposes=(port)
 # ```
 #
+# ### pkg_svc_exit_codes
+# An associative array mapping exit codes of the service's run hook to the action the Supervisor
+# should take when the process exits with that code. Valid actions are `complete` (the service
+# finished its work and is not restarted), `reload` (configuration is re-rendered before the
+# service is restarted), and `restart`. Exit codes which are not listed are treated as failures
+# and the service is restarted.
+# ```
+# pkg_svc_exit_codes=(
+#   [0]=complete
+#   [3]=reload
+# )
+# ```
+#
+# ### pkg_svc_health_probe
+# An associative array describing an HTTP or TCP probe the Supervisor runs to check the service's
+# health, in place of a `health_check` hook. An `http://` URL is healthy when it answers with the
+# expected `status` (default `200`); a `tcp://host:port` URL is healthy when it accepts a
+# connection. The probe fails if it takes longer than `timeout` seconds (default `5`). Probing
+# doesn't start a process, so it's cheap enough to run on every health check of a busy host.
+# ```
+# pkg_svc_health_probe=(
+#   [url]="http://localhost:8080/health"
+#   [status]=204
+#   [timeout]=2
+# )
+# ```
+#
+# ### pkg_svc_umask
+# The octal file mode creation mask the service's run hook is started with. Optional; the run hook
+# inherits the Supervisor's umask if this is not set.
+# ```
+# pkg_svc_umask=0027
+# ```
+#
+# ### pkg_svc_limits
+# An associative array of resource limits the service's run hook is started with, so a service
+# which needs more open files than the default doesn't need a wrapper script to raise them. Valid
+# limits are `nofile` (open files) and `nproc` (processes for the service's user), and each value
+# is a number or `unlimited`. A limit above the host's hard limit raises it, which requires the
+# Supervisor to run as root.
+# ```
+# pkg_svc_limits=(
+#   [nofile]=65536
+#   [nproc]=4096
+# )
+# ```
+#
+# ### pkg_svc_dirs
+# An associative array of extra directories beneath the service's own, such as
+# `/hab/svc/redis/cache`, which the Supervisor creates and gives to the service's user. Each value
+# is a list of options: `tmpfs` backs the directory with memory (Linux, when the Supervisor runs as
+# root), `quota=SIZE` caps it at a size such as `512M` or `2G`, `clear=restart` empties it whenever
+# the service starts and `clear=update` whenever the service's package is updated, and `workdir`
+# makes it the directory the run hook starts in. Naming a directory `var` manages the service's
+# own `var` directory instead. Optional.
+# ```
+# pkg_svc_dirs=(
+#   [var]="tmpfs quota=64M clear=restart"
+#   [cache]="quota=2G clear=update"
+# )
+# ```
+#
+# ### pkg_svc_hook_sandbox
+# The ways the service's hooks, other than its run hook, are sandboxed, so they behave the same on
+# every host. With `env` a hook starts with only the environment the Supervisor constructs for it,
+# rather than the Supervisor's own. With `readonly` the filesystem is read-only to a hook outside
+# the service's own directory. With `limits` a hook starts with the service's `pkg_svc_umask` and
+# `pkg_svc_limits`. Optional; hooks are not sandboxed if this is unset.
+# ```
+# pkg_svc_hook_sandbox=(env readonly limits)
+# ```
+#
+# ### pkg_svc_capabilities
+# The Linux capabilities the service's run hook keeps; every other capability is dropped, for
+# good, before it starts. Capabilities are named as in `capabilities(7)`, with or without their
+# `CAP_` prefix. Use `(none)` to drop them all. Optional; if unset the run hook keeps whatever
+# capabilities its user would have.
+# ```
+# pkg_svc_capabilities=(net_bind_service)
+# ```
+#
+# ### pkg_svc_seccomp_profile
+# The path, relative to the plan, of a compiled seccomp BPF program the service's run hook is
+# filtered with on Linux, such as one written by libseccomp's `seccomp_export_bpf(3)`. Optional.
+# ```
+# pkg_svc_seccomp_profile=seccomp.bpf
+# ```
+#
+# ### pkg_svc_scheduled_hooks
+# An associative array mapping the names of hooks in the plan's `hooks/` directory to cron style
+# schedules (minute, hour, day of month, month, day of week). The Supervisor renders each hook
+# with the same configuration and census data as the other hooks and runs it whenever its schedule
+# is due.
+# ```
+# pkg_svc_scheduled_hooks=(
+#   [backup]="30 2 * * *"
+# )
+# ```
+#
+# ### pkg_svc_data_version
+# The version of the template data (`sys`, `pkg`, `cfg`, `svc` and `bind`) the plan's
+# configuration templates and hooks are written for. Keys renamed in later versions stay
+# available under their old names. Defaults to `1`.
+# ```
+# pkg_svc_data_version=2
+# ```
+#
+# ### pkg_svc_hook_interpreter
+# The interpreter the Supervisor runs the plan's hooks with, unless a hook names its own with a
+# shebang line. Valid interpreters are `sh`, `powershell`, `python` and `exec`, which runs the
+# hook as an executable. Defaults to `sh` on Linux and `powershell` on Windows.
+# ```
+# pkg_svc_hook_interpreter=python
+# ```
+#
+# ### pkg_svc_template_engine
+# The template engine the Supervisor uses to render the plan's configuration templates and
+# hooks. Valid engines are `handlebars` and `liquid`. Defaults to `handlebars`.
+# ```
+# pkg_svc_template_engine=liquid
+# ```
+#
+# ### pkg_type
+# The kind of package: `standalone` for a package of software, or `composite` for a bundle of
+# other packages' services which the Supervisor starts, and updates, together. A composite has no
+# source and nothing is built for it; it's made of its `pkg_services`, `pkg_service_groups` and
+# `pkg_bind_map`. Defaults to `standalone`.
+# ```
+# pkg_type=composite
+# ```
+#
+# ### pkg_services
+# An array of the packages whose services a composite runs. Each is resolved to the fully
+# qualified package installed when the composite is built, so a service is only updated by a new
+# release of the composite. A service runs under its package's name, unless it's given another
+# after a colon, so the same package can run more than once; `pkg_service_groups` and
+# `pkg_bind_map` refer to services by these names.
+# ```
+# pkg_services=(core/redis:cache core/redis:sessions acme/app)
+# ```
+#
+# ### pkg_service_groups
+# An associative array mapping the names of a composite's services to the group each runs in.
+# Services which aren't listed run in the group the composite is started in.
+# ```
+# pkg_service_groups=(
+#   [redis]=cache
+# )
+# ```
+#
+# ### pkg_bind_map
+# An associative array mapping the names of a composite's services to a space separated list of
+# their binds, each of which names another service of the composite.
+# ```
+# pkg_bind_map=(
+#   [app]="database:redis"
+# )
+# ```
+#
 # ### pkg_origin
 # A string to use for the origin. The origin is used to denote a particular upstream of a
 # package; when we resolve dependencies, we consider a version of a package to be equal
@@ -359,6 +541,10 @@ pkg_target="${pkg_arch}-${pkg_sys}"
 pkg_origin=""
 # Each release is a timestamp - `YYYYMMDDhhmmss`
 pkg_release=$(date -u +%Y%m%d%H%M%S)
+# If `$HAB_REPRODUCIBLE_BUILD` is set, the build is pinned to `$SOURCE_DATE_EPOCH`
+# and the artifact is normalized so that building the same Plan against the
+# same dependencies produces the same artifact
+: ${HAB_REPRODUCIBLE_BUILD:=}
 # The default build deps setting - an empty array
 pkg_build_deps=()
 # The default runtime deps setting - an empty array
@@ -376,10 +562,46 @@ pkg_pconfig_dirs=()
 pkg_svc_run=''
 pkg_exposes=()
 declare -A pkg_exports
+# Environment variables for things that run or build against the package
+declare -A pkg_env
+# The separators of the entries of list variables in `pkg_env`
+declare -A pkg_env_sep
 # The user to run the service as
 pkg_svc_user=hab
 # The group to run the service as
 pkg_svc_group=$pkg_svc_user
+# Mappings of service exit codes to Supervisor actions
+declare -A pkg_svc_exit_codes
+# Mappings of hook names to the cron style schedules the Supervisor runs them on
+declare -A pkg_svc_scheduled_hooks
+# An HTTP or TCP probe run in place of the health_check hook
+declare -A pkg_svc_health_probe
+# The file mode creation mask the service is started with
+pkg_svc_umask=
+# Resource limits the service is started with
+declare -A pkg_svc_limits
+# Extra directories the Supervisor creates and manages for the service
+declare -A pkg_svc_dirs
+# The ways the service's hooks are sandboxed
+pkg_svc_hook_sandbox=()
+# The only Linux capabilities the service keeps
+pkg_svc_capabilities=()
+# A compiled seccomp filter the service is started with
+pkg_svc_seccomp_profile=
+# The template engine used to render configuration templates and hooks
+pkg_svc_template_engine=handlebars
+# The interpreter hooks without a shebang line are run with
+pkg_svc_hook_interpreter=
+# The version of the template data the templates and hooks are written for
+pkg_svc_data_version=
+# The kind of package, either standalone or composite
+pkg_type=standalone
+# The packages whose services a composite runs
+pkg_services=()
+# Mappings of a composite's services to the groups they run in
+declare -A pkg_service_groups
+# Mappings of a composite's services to their binds on the composite's other services
+declare -A pkg_bind_map
 
 # Initially set $pkg_svc_* variables. This happens before the Plan is sourced,
 # meaning that `$pkg_name` is not yet set. However, `$pkg_svc_run` wants
@@ -669,6 +891,51 @@ _install_dependency() {
   return 0
 }
 
+# **Internal** Pins every dependency in `$pkg_build_deps` and `$pkg_deps` to a
+# release when any of them carries a version constraint, such as
+# `core/glibc >=2.27 <3`. The dependencies are resolved together with
+# `hab pkg solve`, from the installed packages and the depot, or only the
+# installed packages if `$NO_INSTALL_DEPS` is set, so that the releases chosen
+# depend on releases which satisfy the constraints too.
+#
+# ```
+# pkg_deps=("core/glibc >=2.22 <3" core/zlib)
+# _solve_dependencies
+# # pkg_deps=(core/glibc/2.22/20160612063629 core/zlib/1.2.8/20161118033245)
+# ```
+#
+# Exits with a message explaining which constraints conflict if no releases
+# satisfy them together.
+_solve_dependencies() {
+  local dep
+  local constrained
+  for dep in "${pkg_build_deps[@]}" "${pkg_deps[@]}"; do
+    if [[ "$dep" == *" "* ]]; then
+      constrained=true
+    fi
+  done
+  if [[ -z "${constrained:-}" ]]; then
+    return 0
+  fi
+
+  local source_flags
+  if [[ -n "${NO_INSTALL_DEPS:-}" ]]; then
+    source_flags="--installed"
+  else
+    source_flags="-u $HAB_DEPOT_URL"
+  fi
+  local build_count=${#pkg_build_deps[@]}
+  local solved
+  if ! solved=($($HAB_BIN -q pkg solve $source_flags \
+      "${pkg_build_deps[@]}" "${pkg_deps[@]}")); then
+    exit_with "Resolving the version constraints of the dependencies failed" 1
+  fi
+  pkg_build_deps=("${solved[@]:0:$build_count}")
+  pkg_deps=("${solved[@]:$build_count}")
+  build_line "Pinned dependencies satisfying their version constraints"
+  return 0
+}
+
 # **Internal** Returns (on stdout) the `TDEPS` file contents of another locally
 # installed package which contain the set of all direct and transitive run
 # dependencies. An empty set could be returned as whitespace and/or newlines.
@@ -1487,6 +1754,7 @@ do_default_begin() {
 #    dependency. Further details below in the function.
 _resolve_dependencies() {
   build_line "Resolving dependencies"
+  _solve_dependencies
   local resolved
   local dep
   local tdep
@@ -1716,6 +1984,10 @@ _build_environment() {
   # respectively (build or run). If the software to be built requires the path
   # to headers or shared libraries, it must be a direct dependency, not
   # transitive.
+  #
+  # The dependency which set each `pkg_env` variable, and the separator of each
+  # list variable, are kept to find conflicts between dependencies.
+  local -A env_set_by env_seps
   for dep_path in "${pkg_all_deps_resolved[@]}"; do
     if [[ -f "$dep_path/CFLAGS" ]]; then
       local data=$(cat $dep_path/CFLAGS)
@@ -1780,6 +2052,44 @@ _build_environment() {
         export PKG_CONFIG_PATH="$trimmed"
       fi
     fi
+
+    # Export the variables each direct dependency (build or run) declares with
+    # `pkg_env`, appending the entries of list variables. Dependencies which
+    # set different values for a variable which isn't a list, or separate a
+    # list variable's entries differently, fail the build, as the Supervisor
+    # couldn't compose the package's environment at runtime either. The
+    # exception is a variable this plan sets itself with `pkg_env`, which
+    # overrides its dependencies' at runtime; then the first one wins here.
+    if [[ -f "$dep_path/ENVIRONMENT" ]]; then
+      local line key value sep
+      while IFS= read -r line; do
+        key="${line%%=*}"
+        value="${line#*=}"
+        sep=""
+        if [[ -f "$dep_path/ENVIRONMENT_SEP" ]]; then
+          sep="$(grep "^${key}=" "$dep_path/ENVIRONMENT_SEP" | cut -d= -f2-)"
+        fi
+        if [[ -n "$sep" && -n "${env_seps[$key]:-}" && "${env_seps[$key]}" != "$sep" ]]; then
+          exit_with "${env_set_by[$key]} separates ${key} with '${env_seps[$key]}', but ${dep_path} with '${sep}'" 1
+        fi
+        if [[ -n "$sep" && -n "${env_set_by[$key]:-}" ]]; then
+          export "${key}=${!key}${sep}${value}"
+        elif [[ -n "${env_set_by[$key]:-}" && "${!key}" != "$value" ]]; then
+          if [[ -z "${pkg_env[$key]:-}" ]]; then
+            exit_with "${env_set_by[$key]} sets ${key}=${!key}, but ${dep_path} sets ${key}=${value}; set ${key} in pkg_env to choose" 1
+          fi
+          warn "Not setting ${key}=${value} from ${dep_path}; it is already ${!key}"
+          continue
+        else
+          export "${key}=${value}"
+          env_set_by[$key]="$dep_path"
+        fi
+        if [[ -n "$sep" ]]; then
+          env_seps[$key]="$sep"
+        fi
+        build_line "Setting ${key}=${!key}"
+      done < "$dep_path/ENVIRONMENT"
+    fi
   done
 
   # Create a working directory if it doesn't already exist from `do_unpack()`
@@ -1918,14 +2228,33 @@ do_default_install() {
 # **Internal** Write out the package data to files:
 #
 # * `$pkg_prefix/BUILD_DEPS` - Any dependencies we need build the package
+# * `$pkg_prefix/BUILD_INPUTS` - The inputs of a reproducible build
 # * `$pkg_prefix/CFLAGS` - Any CFLAGS for things that link against us
 # * `$pkg_prefix/PKG_CONFIG_PATH` - Any PKG_CONFIG_PATH entries for things that depend on us
 # * `$pkg_prefix/DEPS` - Any dependencies we need to use the package at runtime
+# * `$pkg_prefix/ENVIRONMENT` - Environment variables for things that run or build against us
+# * `$pkg_prefix/ENVIRONMENT_SEP` - The separators of the entries of list variables in ENVIRONMENT
 # * `$pkg_prefix/EXPOSES` - Any ports we expose
 # * `$pkg_prefix/FILES` - blake2b checksums of all files in the package
 # * `$pkg_prefix/LDFLAGS` - Any LDFLAGS for things that link against us
 # * `$pkg_prefix/LD_RUN_PATH` - The LD_RUN_PATH for things that link against us
+# * `$pkg_prefix/LICENSES` - The licenses the package is distributed under, one per line
 # * `$pkg_prefix/PATH` - Any PATH entries for things that link against us
+# * `$pkg_prefix/SBOM` - A CycloneDX software bill of materials of the package and its dependencies
+# * `$pkg_prefix/SERVICES` - The services of a composite, with their groups and binds
+# * `$pkg_prefix/SVC_CAPABILITIES` - The only Linux capabilities the service keeps
+# * `$pkg_prefix/SVC_DATA_VERSION` - The version of the template data templates are written for
+# * `$pkg_prefix/SVC_DIRS` - Extra directories the Supervisor creates and manages for the service
+# * `$pkg_prefix/SVC_EXIT_CODES` - Mappings of service exit codes to Supervisor actions
+# * `$pkg_prefix/SVC_HEALTH_PROBE` - An HTTP or TCP probe run in place of the health_check hook
+# * `$pkg_prefix/SVC_HOOK_INTERPRETER` - The interpreter hooks without a shebang line are run with
+# * `$pkg_prefix/SVC_HOOK_SANDBOX` - The ways the service's hooks are sandboxed
+# * `$pkg_prefix/SVC_LIMITS` - Resource limits the service is started with
+# * `$pkg_prefix/SVC_SCHEDULED_HOOKS` - Mappings of hook names to the schedules they run on
+# * `$pkg_prefix/SVC_SECCOMP` - A compiled seccomp filter the service is started with
+# * `$pkg_prefix/SVC_TEMPLATE_ENGINE` - The template engine used to render templates and hooks
+# * `$pkg_prefix/SVC_UMASK` - The file mode creation mask the service is started with
+# * `$pkg_prefix/TYPE` - The kind of package, if it's a composite
 _build_metadata() {
   build_line "Building package metadata"
   local ld_run_path_part=""
@@ -2024,6 +2353,37 @@ _build_metadata() {
     echo $path_part > $pkg_prefix/PATH
   fi
 
+  local env_var
+  for env_var in $(printf '%s\n' "${!pkg_env_sep[@]}" | sort); do
+    if [[ ! "${pkg_env_sep[$env_var]}" =~ ^[[:punct:]]$ ]]; then
+      exit_with "Bad value in pkg_env_sep; Separator of ${env_var} isn't a punctuation character" 1
+    fi
+    echo "$env_var=${pkg_env_sep[$env_var]}" >> $pkg_prefix/ENVIRONMENT_SEP
+  done
+  for env_var in $(printf '%s\n' "${!pkg_env[@]}" | sort); do
+    if [[ ! "$env_var" =~ ^[A-Za-z_][A-Za-z0-9_]*$ ]]; then
+      exit_with "Bad value in pkg_env; Not a variable name: ${env_var}" 1
+    fi
+    if [[ "$env_var" == "PATH" ]]; then
+      exit_with "Bad value in pkg_env; PATH is built from pkg_bin_dirs" 1
+    fi
+    local env_value="${pkg_env[$env_var]}"
+    local env_sep="${pkg_env_sep[$env_var]:-}"
+    if [[ -n "$env_sep" ]]; then
+      # Entries of list variables which aren't absolute are relative to the package.
+      local entries entry
+      IFS="$env_sep" read -r -a entries <<< "$env_value"
+      env_value=""
+      for entry in "${entries[@]}"; do
+        if [[ "$entry" != /* ]]; then
+          entry="${pkg_prefix}/${entry}"
+        fi
+        env_value="${env_value:+${env_value}${env_sep}}${entry}"
+      done
+    fi
+    echo "$env_var=$env_value" >> $pkg_prefix/ENVIRONMENT
+  done
+
   for export in "${!pkg_exports[@]}"; do
     echo "$export=${pkg_exports[$export]}" >> $pkg_prefix/EXPORTS
   done
@@ -2055,6 +2415,10 @@ _build_metadata() {
       | sed "s|^|${pkg_prefix}/|" > $pkg_prefix/INTERPRETERS
   fi
 
+  if [[ ${#pkg_license[@]} -gt 0 ]]; then
+    printf '%s\n' "${pkg_license[@]}" > $pkg_prefix/LICENSES
+  fi
+
   local cutn="$(($(echo $HAB_PKG_PATH | grep -o '/' | wc -l)+2))"
   local deps
 
@@ -2085,6 +2449,174 @@ _build_metadata() {
   echo "$pkg_svc_user" > $pkg_prefix/SVC_USER
   echo "$pkg_svc_group" > $pkg_prefix/SVC_GROUP
 
+  case "$pkg_svc_template_engine" in
+    handlebars|liquid)
+      echo "$pkg_svc_template_engine" > $pkg_prefix/SVC_TEMPLATE_ENGINE
+      ;;
+    *)
+      exit_with "Bad value in pkg_svc_template_engine; Unknown template engine: ${pkg_svc_template_engine}" 1
+      ;;
+  esac
+
+  case "$pkg_svc_hook_interpreter" in
+    "")
+      ;;
+    exec|sh|powershell|python)
+      echo "$pkg_svc_hook_interpreter" > $pkg_prefix/SVC_HOOK_INTERPRETER
+      ;;
+    *)
+      exit_with "Bad value in pkg_svc_hook_interpreter; Unknown hook interpreter: ${pkg_svc_hook_interpreter}" 1
+      ;;
+  esac
+
+  case "$pkg_svc_data_version" in
+    "")
+      ;;
+    1|2)
+      echo "$pkg_svc_data_version" > $pkg_prefix/SVC_DATA_VERSION
+      ;;
+    *)
+      exit_with "Bad value in pkg_svc_data_version; Unsupported template data version: ${pkg_svc_data_version}" 1
+      ;;
+  esac
+
+  for code in "${!pkg_svc_exit_codes[@]}"; do
+    case "${pkg_svc_exit_codes[$code]}" in
+      complete|reload|restart)
+        echo "$code=${pkg_svc_exit_codes[$code]}" >> $pkg_prefix/SVC_EXIT_CODES
+        ;;
+      *)
+        exit_with "Bad value in pkg_svc_exit_codes; Unknown action for exit code ${code}: ${pkg_svc_exit_codes[$code]}" 1
+        ;;
+    esac
+  done
+
+  if [[ ${#pkg_svc_health_probe[@]} -gt 0 ]]; then
+    if [[ ! "${pkg_svc_health_probe[url]:-}" =~ ^(http|tcp):// ]]; then
+      exit_with "Bad value in pkg_svc_health_probe; url must be an http:// or tcp:// URL: ${pkg_svc_health_probe[url]:-}" 1
+    fi
+    local key
+    for key in "${!pkg_svc_health_probe[@]}"; do
+      case "$key" in
+        url)
+          ;;
+        status|timeout)
+          if [[ ! "${pkg_svc_health_probe[$key]}" =~ ^[0-9]+$ ]]; then
+            exit_with "Bad value in pkg_svc_health_probe; ${key} must be a number: ${pkg_svc_health_probe[$key]}" 1
+          fi
+          ;;
+        *)
+          exit_with "Bad value in pkg_svc_health_probe; Unknown setting: ${key}" 1
+          ;;
+      esac
+      echo "$key=${pkg_svc_health_probe[$key]}" >> $pkg_prefix/SVC_HEALTH_PROBE
+    done
+  fi
+
+  if [[ -n "$pkg_svc_umask" ]]; then
+    if [[ ! "$pkg_svc_umask" =~ ^0?[0-7]{3}$ ]]; then
+      exit_with "Bad value in pkg_svc_umask; Not an octal umask: ${pkg_svc_umask}" 1
+    fi
+    echo "$pkg_svc_umask" > $pkg_prefix/SVC_UMASK
+  fi
+
+  for limit in "${!pkg_svc_limits[@]}"; do
+    case "$limit" in
+      nofile|nproc)
+        ;;
+      *)
+        exit_with "Bad value in pkg_svc_limits; Unknown limit: ${limit}" 1
+        ;;
+    esac
+    if [[ ! "${pkg_svc_limits[$limit]}" =~ ^([0-9]+|unlimited)$ ]]; then
+      exit_with "Bad value in pkg_svc_limits; Limit ${limit} must be a number or unlimited: ${pkg_svc_limits[$limit]}" 1
+    fi
+    echo "$limit=${pkg_svc_limits[$limit]}" >> $pkg_prefix/SVC_LIMITS
+  done
+
+  local dir
+  for dir in "${!pkg_svc_dirs[@]}"; do
+    case "$dir" in
+      config|data|files|hooks|static|toml)
+        exit_with "Bad value in pkg_svc_dirs; ${dir} is managed by the Supervisor already" 1
+        ;;
+    esac
+    if [[ ! "$dir" =~ ^[A-Za-z0-9_-]+$ ]]; then
+      exit_with "Bad value in pkg_svc_dirs; Not a directory name: ${dir}" 1
+    fi
+    local opt
+    for opt in ${pkg_svc_dirs[$dir]}; do
+      if [[ ! "$opt" =~ ^(tmpfs|workdir|quota=[0-9]+[KMG]?|clear=(restart|update))$ ]]; then
+        exit_with "Bad value in pkg_svc_dirs; Unknown option for ${dir}: ${opt}" 1
+      fi
+    done
+    echo "$dir=${pkg_svc_dirs[$dir]}" >> $pkg_prefix/SVC_DIRS
+  done
+
+  if [[ ${#pkg_svc_hook_sandbox[@]} -gt 0 ]]; then
+    local sandbox
+    for sandbox in "${pkg_svc_hook_sandbox[@]}"; do
+      case "$sandbox" in
+        env|readonly|limits)
+          ;;
+        *)
+          exit_with "Bad value in pkg_svc_hook_sandbox; Unknown sandbox: ${sandbox}" 1
+          ;;
+      esac
+    done
+    echo "${pkg_svc_hook_sandbox[*]}" > $pkg_prefix/SVC_HOOK_SANDBOX
+  fi
+
+  if [[ ${#pkg_svc_capabilities[@]} -gt 0 ]]; then
+    if [[ "${pkg_svc_capabilities[*]}" == "none" ]]; then
+      echo "none" > $pkg_prefix/SVC_CAPABILITIES
+    else
+      local cap
+      local known_caps=" chown dac_override dac_read_search fowner fsetid kill setgid setuid setpcap linux_immutable net_bind_service net_broadcast net_admin net_raw ipc_lock ipc_owner sys_module sys_rawio sys_chroot sys_ptrace sys_pacct sys_admin sys_boot sys_nice sys_resource sys_time sys_tty_config mknod lease audit_write audit_control setfcap mac_override mac_admin syslog wake_alarm block_suspend audit_read "
+      for cap in "${pkg_svc_capabilities[@]}"; do
+        cap="$(echo "$cap" | tr '[:upper:]' '[:lower:]')"
+        cap="${cap#cap_}"
+        if [[ "$known_caps" != *" $cap "* ]]; then
+          exit_with "Bad value in pkg_svc_capabilities; Unknown capability: ${cap}" 1
+        fi
+      done
+      echo "${pkg_svc_capabilities[*]}" > $pkg_prefix/SVC_CAPABILITIES
+    fi
+  fi
+
+  if [[ -n "$pkg_svc_seccomp_profile" ]]; then
+    if [[ ! -f "$PLAN_CONTEXT/$pkg_svc_seccomp_profile" ]]; then
+      exit_with "Bad value in pkg_svc_seccomp_profile; No file at $PLAN_CONTEXT/$pkg_svc_seccomp_profile" 1
+    fi
+    if [[ $(( $(stat -c %s "$PLAN_CONTEXT/$pkg_svc_seccomp_profile") % 8 )) -ne 0 ]]; then
+      exit_with "Bad value in pkg_svc_seccomp_profile; ${pkg_svc_seccomp_profile} is not a compiled BPF program" 1
+    fi
+    cp "$PLAN_CONTEXT/$pkg_svc_seccomp_profile" $pkg_prefix/SVC_SECCOMP
+  fi
+
+  for hook in "${!pkg_svc_scheduled_hooks[@]}"; do
+    if [[ ! -f "$PLAN_CONTEXT/hooks/$hook" ]]; then
+      exit_with "Bad value in pkg_svc_scheduled_hooks; No hook named ${hook} in $PLAN_CONTEXT/hooks" 1
+    fi
+    echo "$hook=${pkg_svc_scheduled_hooks[$hook]}" >> $pkg_prefix/SVC_SCHEDULED_HOOKS
+  done
+
+  if [[ "$pkg_type" == "composite" ]]; then
+    _build_composite_metadata
+  fi
+
+  if [[ -n "$HAB_REPRODUCIBLE_BUILD" ]]; then
+    _build_inputs
+  fi
+
+  # The bill of materials is generated from the metadata written above and the
+  # MANIFESTs of the package and its installed dependencies.
+  build_line "Generating software bill of materials"
+  rm -f $pkg_prefix/SBOM
+  $HAB_BIN pkg sbom --format cyclonedx \
+    ${pkg_origin}/${pkg_name}/${pkg_version}/${pkg_release} > $pkg_prefix/SBOM.tmp
+  mv $pkg_prefix/SBOM.tmp $pkg_prefix/SBOM
+
   # Generate the blake2b hashes of all the files in the package. This
   # is not in the resulting MANIFEST because MANIFEST is included!
   pushd "$HAB_CACHE_SRC_PATH/$pkg_dirname" > /dev/null
@@ -2099,6 +2631,97 @@ _build_metadata() {
   return 0
 }
 
+# **Internal** Returns the name a `pkg_services` entry runs under: the one
+# given after a colon, or else its package's name.
+_composite_service_name() {
+  if [[ "$1" == *:* ]]; then
+    echo "${1#*:}"
+  else
+    echo "$1" | cut -d "/" -f 2
+  fi
+}
+
+# **Internal** Write out the `TYPE` and `SERVICES` metadata of a composite.
+# Each line of `SERVICES` is the fully qualified package of one service,
+# followed by its `name=` if it isn't its package's, its `group=` and any
+# `bind=` entries, for example:
+#
+# ```
+# core/redis/3.2.4/20170514150022 name=cache
+# acme/app/1.0.0/20170501101010 group=prod bind=database:cache
+# ```
+_build_composite_metadata() {
+  local cutn="$(($(echo $HAB_PKG_PATH | grep -o '/' | wc -l)+2))"
+  local names=" "
+  local svc
+  local name
+  local line
+  local bind
+
+  echo "$pkg_type" > $pkg_prefix/TYPE
+  for svc in "${pkg_services[@]}"; do
+    name="$(_composite_service_name "$svc")"
+    if [[ -z "$name" || "$name" == *[./@]* ]]; then
+      exit_with "Bad value in pkg_services; ${svc} must run under a name without '.', '/' or '@'" 1
+    fi
+    if [[ "$names" == *" $name "* ]]; then
+      exit_with "Bad value in pkg_services; more than one service is named ${name}, give the others names of their own, as in core/redis:sessions" 1
+    fi
+    names="$names$name "
+  done
+  for name in "${!pkg_service_groups[@]}" "${!pkg_bind_map[@]}"; do
+    if [[ "$names" != *" $name "* ]]; then
+      exit_with "Bad value in pkg_service_groups or pkg_bind_map; ${name} is not in pkg_services" 1
+    fi
+  done
+  for svc in "${pkg_services[@]}"; do
+    name="$(_composite_service_name "$svc")"
+    line="$(_resolve_dependency ${svc%%:*} | cut -d "/" -f ${cutn}-)"
+    if [[ "$name" != "$(echo "$line" | cut -d "/" -f 2)" ]]; then
+      line="$line name=$name"
+    fi
+    if [[ -n "${pkg_service_groups[$name]+abc}" ]]; then
+      line="$line group=${pkg_service_groups[$name]}"
+    fi
+    for bind in ${pkg_bind_map[$name]:-}; do
+      if [[ "$names" != *" ${bind#*:} "* ]]; then
+        exit_with "Bad value in pkg_bind_map; ${name} binds to ${bind#*:}, which is not in pkg_services" 1
+      fi
+      line="$line bind=$bind"
+    done
+    echo "$line" >> $pkg_prefix/SERVICES
+  done
+}
+
+# **Internal** Write out `$pkg_prefix/BUILD_INPUTS`, the list of everything a
+# reproducible build depends on: the Plan's files, the source, and the fully
+# resolved set of dependencies. Two builds with the same inputs produce the same
+# artifact, and the sha256 of this file identifies the set of inputs.
+_build_inputs() {
+  build_line "Recording build inputs"
+  local f
+  local dep
+
+  echo "source_date_epoch $SOURCE_DATE_EPOCH" > $pkg_prefix/BUILD_INPUTS
+  pushd "$PLAN_CONTEXT" > /dev/null
+  for f in $(find plan.sh default.toml config hooks -type f 2> /dev/null \
+      | $_sort_cmd); do
+    echo "plan $($_shasum_cmd "$f" | cut -d " " -f 1) $f" >> $pkg_prefix/BUILD_INPUTS
+  done
+  popd > /dev/null
+  if [[ -n "${pkg_source:-}" ]]; then
+    echo "source ${pkg_shasum:-} $pkg_source" >> $pkg_prefix/BUILD_INPUTS
+  fi
+  for dep in "${pkg_build_tdeps_resolved[@]}"; do
+    echo "build_dep ${dep#$HAB_PKG_PATH/}" >> $pkg_prefix/BUILD_INPUTS
+  done
+  for dep in "${pkg_tdeps_resolved[@]}"; do
+    echo "dep ${dep#$HAB_PKG_PATH/}" >> $pkg_prefix/BUILD_INPUTS
+  done
+  _pkg_inputs_sha256sum=$($_shasum_cmd $pkg_prefix/BUILD_INPUTS | cut -d " " -f 1)
+  return 0
+}
+
 # Copy the `./config` directory, relative to the Plan, to `$pkg_prefix/config`.
 # Do the same with `default.toml`. Delegates most of the implementation to the
 # `do_default_build_config()` function.
@@ -2330,8 +2953,17 @@ _generate_artifact() {
 
   mkdir -pv "$(dirname "$pkg_artifact")"
   rm -fv $tarf $xzf $pkg_artifact
-  $_tar_cmd -cf $tarf $pkg_prefix
-  $_xz_cmd --compress -6 --threads=0 $tarf
+  if [[ -n "$HAB_REPRODUCIBLE_BUILD" ]]; then
+    # Store the files in name order with the pinned timestamp and no owner,
+    # and compress on a single thread, so that the artifact only depends on
+    # the contents of the files.
+    $_tar_cmd --format=gnu --sort=name --mtime="@$SOURCE_DATE_EPOCH" \
+      --owner=0 --group=0 --numeric-owner -cf $tarf $pkg_prefix
+    $_xz_cmd --compress -6 --threads=1 $tarf
+  else
+    $_tar_cmd -cf $tarf $pkg_prefix
+    $_xz_cmd --compress -6 --threads=0 $tarf
+  fi
   $HAB_BIN pkg sign --origin $pkg_origin $xzf $pkg_artifact
   rm -f $tarf $xzf
 }
@@ -2352,6 +2984,34 @@ pkg_artifact=$(basename $pkg_artifact)
 pkg_sha256sum=$_pkg_sha256sum
 pkg_blake2bsum=$_pkg_blake2bsum
 EOF
+  if [[ -n "$HAB_REPRODUCIBLE_BUILD" ]]; then
+    cat <<-EOF >> "$pkg_output_path"/last_build.env
+pkg_source_date_epoch=$SOURCE_DATE_EPOCH
+pkg_inputs_sha256sum=$_pkg_inputs_sha256sum
+EOF
+  fi
+}
+
+# **Internal** Pin the clock of a reproducible build. `$SOURCE_DATE_EPOCH`
+# defaults to the time of the last commit to the Plan, and the release is
+# derived from it so that rebuilding the same Plan yields the same release.
+# Build tools which honor `$SOURCE_DATE_EPOCH` use it in place of the current
+# time.
+_pin_source_date_epoch() {
+  if [[ -z "${SOURCE_DATE_EPOCH:-}" ]]; then
+    SOURCE_DATE_EPOCH="$(git -C "$PLAN_CONTEXT" log -1 --format=%ct -- . 2> /dev/null || true)"
+    if [[ -z "$SOURCE_DATE_EPOCH" ]]; then
+      exit_with "Reproducible builds require SOURCE_DATE_EPOCH to be set, or the Plan to be in a git repository" 1
+    fi
+    build_line "Setting SOURCE_DATE_EPOCH=$SOURCE_DATE_EPOCH from the last commit to the Plan"
+  fi
+  if [[ ! "$SOURCE_DATE_EPOCH" =~ ^[0-9]+$ ]]; then
+    exit_with "SOURCE_DATE_EPOCH must be a number of seconds since the epoch, got '$SOURCE_DATE_EPOCH'" 1
+  fi
+  export SOURCE_DATE_EPOCH
+  pkg_release=$(date -u -d "@$SOURCE_DATE_EPOCH" +%Y%m%d%H%M%S)
+  build_line "Reproducible build, release $pkg_release"
+  return 0
 }
 
 # A function for cleaning up after yourself. Delegates most of the
@@ -2439,9 +3099,28 @@ build_line "Validating plan metadata"
 required_variables=(
   pkg_name
   pkg_origin
-  pkg_source
   pkg_version
 )
+case "$pkg_type" in
+  standalone)
+    required_variables+=(pkg_source)
+    ;;
+  composite)
+    if [[ ${#pkg_services[@]} -eq 0 ]]; then
+      exit_with "Failed to build. A composite package must set 'pkg_services'." 1
+    fi
+    # The services of a composite are installed along with it, once for each
+    # package however many services it runs as
+    for svc in "${pkg_services[@]}"; do
+      if [[ " ${pkg_deps[*]} " != *" ${svc%%:*} "* ]]; then
+        pkg_deps+=("${svc%%:*}")
+      fi
+    done
+    ;;
+  *)
+    exit_with "Failed to build. Unknown pkg_type '${pkg_type}'; must be standalone or composite." 1
+    ;;
+esac
 for var in "${required_variables[@]}"
 do
   if [[ -z "${!var}" ]] ; then
@@ -2473,6 +3152,11 @@ if [[ -z "${pkg_dirname+xxx}" ]]; then
   pkg_dirname="${pkg_name}-${pkg_version}"
 fi
 
+# Pin the release and build time of a reproducible build
+if [[ -n "$HAB_REPRODUCIBLE_BUILD" ]]; then
+  _pin_source_date_epoch
+fi
+
 # Set `$pkg_prefix` if not already set by the `plan.sh`.
 if [[ -z "${pkg_prefix+xxx}" ]]; then
   pkg_prefix=$HAB_PKG_PATH/${pkg_origin}/${pkg_name}/${pkg_version}/${pkg_release}
@@ -2514,45 +3198,50 @@ _resolve_dependencies
 
 _set_path
 
-# Download the source
-mkdir -pv "$HAB_CACHE_SRC_PATH"
-do_download
+if [[ "$pkg_type" == "composite" ]]; then
+  # A composite has no source to build, only metadata
+  mkdir -pv "$HAB_CACHE_SRC_PATH/$pkg_dirname" "$pkg_prefix"
+else
+  # Download the source
+  mkdir -pv "$HAB_CACHE_SRC_PATH"
+  do_download
 
-# Verify the source
-do_verify
+  # Verify the source
+  do_verify
 
-# Clean the cache
-do_clean
+  # Clean the cache
+  do_clean
 
-# Unpack the source
-do_unpack
+  # Unpack the source
+  do_unpack
 
-# Set up the build environment
-_build_environment
+  # Set up the build environment
+  _build_environment
 
-# Fix any libtool scripts in the source
-_fix_libtool
+  # Fix any libtool scripts in the source
+  _fix_libtool
 
-# Prepare the source
-do_prepare_wrapper
+  # Prepare the source
+  do_prepare_wrapper
 
-# Build the source
-do_build_wrapper
+  # Build the source
+  do_build_wrapper
 
-# Check the source
-do_check_wrapper
+  # Check the source
+  do_check_wrapper
 
-# Install the source
-do_install_wrapper
+  # Install the source
+  do_install_wrapper
 
-# Copy the configuration
-do_build_config
+  # Copy the configuration
+  do_build_config
 
-# Copy the service management scripts
-do_build_service
+  # Copy the service management scripts
+  do_build_service
 
-# Strip the binaries
-do_strip
+  # Strip the binaries
+  do_strip
+fi
 
 # Write the manifest
 _build_manifest
@@ -2578,6 +3267,9 @@ build_line "Artifact: $pkg_output_path/$(basename "$pkg_artifact")"
 build_line "Build Report: $pkg_output_path/last_build.env"
 build_line "SHA256 Checksum: $_pkg_sha256sum"
 build_line "Blake2b Checksum: $_pkg_blake2bsum"
+if [[ -n "$HAB_REPRODUCIBLE_BUILD" ]]; then
+  build_line "Build Inputs Checksum: $_pkg_inputs_sha256sum"
+fi
 
 # Exit cleanly
 build_line
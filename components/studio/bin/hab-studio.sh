@@ -87,12 +87,14 @@ ENVIRONMENT VARIABLES:
     HAB_NONINTERACTIVE  Disables interactive progress bars despite tty
     HAB_ORIGIN          Propagates this variable into any studios
     HAB_ORIGIN_KEYS     Installs secret keys (\`-k' option overrides)
+    HAB_REPRODUCIBLE_BUILD  Propagates this variable into any studios
     HAB_STUDIOS_HOME    Sets a home path for all Studios (default: /hab/studios)
     HAB_STUDIO_ROOT     Sets a Studio root (\`-r' option overrides)
     NO_SRC_PATH         If set, do not mount source path (\`-n' flag overrides)
     QUIET               Prints less output (\`-q' flag overrides)
     SRC_PATH            Sets the source path (\`-s' option overrides)
     STUDIO_TYPE         Sets a Studio type when creating (\`-t' option overrides)
+    SOURCE_DATE_EPOCH   Propagates this variable into any studios
     VERBOSE             Prints more verbose output (\`-v' flag overrides)
     http_proxy          Sets an http_proxy environment variable inside the Studio
     https_proxy         Sets an https_proxy environment variable inside the Studio
@@ -670,6 +672,7 @@ enter_studio() {
   fi
 
   local env="$(chroot_env "$studio_path" "$studio_enter_environment")"
+  local args="$*"
 
   info "Entering Studio at $HAB_STUDIO_ROOT ($STUDIO_TYPE)"
   report_env_vars
@@ -680,8 +683,9 @@ enter_studio() {
   fi
 
   # Become the `chroot` process
+  eval "set -- $env"
   exec $bb chroot "$HAB_STUDIO_ROOT" \
-    $studio_env_command -i $env $studio_enter_command $*
+    $studio_env_command -i "$@" $studio_enter_command $args
 }
 
 # **Internal** Run a build command using a Studio.
@@ -706,6 +710,7 @@ build_studio() {
   fi
 
   local env="$(chroot_env "$studio_path" "$studio_build_environment")"
+  local args="$*"
 
   info "Building '$*' in Studio at $HAB_STUDIO_ROOT ($STUDIO_TYPE)"
   report_env_vars
@@ -715,8 +720,9 @@ build_studio() {
   fi
 
   # Run the build command in the `chroot` environment
-  echo $studio_build_command $* | $bb chroot "$HAB_STUDIO_ROOT" \
-    $studio_env_command -i $env $studio_run_command
+  eval "set -- $env"
+  echo $studio_build_command $args | $bb chroot "$HAB_STUDIO_ROOT" \
+    $studio_env_command -i "$@" $studio_run_command
 }
 
 # **Internal** Run an arbitrary command in a Studio.
@@ -735,6 +741,7 @@ run_studio() {
   fi
 
   local env="$(chroot_env "$studio_path" "$studio_run_environment")"
+  local args="$*"
 
   info "Running '$*' in Studio at $HAB_STUDIO_ROOT ($STUDIO_TYPE)"
 
@@ -743,8 +750,9 @@ run_studio() {
   fi
 
   # Run the command in the `chroot` environment
-  echo $* | $bb chroot "$HAB_STUDIO_ROOT" \
-    $studio_env_command -i $env $studio_run_command
+  eval "set -- $env"
+  echo $args | $bb chroot "$HAB_STUDIO_ROOT" \
+    $studio_env_command -i "$@" $studio_run_command
 }
 
 # **Internal** Destroy a Studio.
@@ -891,53 +899,71 @@ exit_with() {
 }
 
 # **Internal** Builds up the environment set to pass to an `env(1)` command for
-# use in a `chroot` environment which is printed on stdout.
+# use in a `chroot` environment which is printed on stdout. Each assignment is
+# quoted as its own word, so the output is meant to be `eval`ed into a
+# command's arguments, as in `eval "set -- $env"`.
 chroot_env() {
   local studio_path="$1"
   local extra_env="$2"
+  local e
 
   # Set the environment which will be passed to `env(1)` to initialize the
   # session.
-  env="LC_ALL=POSIX HOME=/root TERM=${TERM:-} PATH=$studio_path"
+  env="$(quote_arg "LC_ALL=POSIX") $(quote_arg "HOME=/root")"
+  env="$env $(quote_arg "TERM=${TERM:-}") $(quote_arg "PATH=$studio_path")"
   # Add `STUDIO_TYPE` to the environment
-  env="$env STUDIO_TYPE=$STUDIO_TYPE"
+  env="$env $(quote_arg "STUDIO_TYPE=$STUDIO_TYPE")"
   # Add any additional environment variables from the Studio config, based on
   # type
-  if [ -n "$extra_env" ]; then
-    env="$env $extra_env"
-  fi
+  for e in $extra_env; do
+    env="$env $(quote_arg "$e")"
+  done
   # If a Habitat config filetype ignore string is set, then propagate it
   # into the Studio's environment.
   if [ -n "${HAB_CONFIG_EXCLUDE:-}" ]; then
-    env="$env HAB_CONFIG_EXCLUDE=$HAB_CONFIG_EXCLUDE"
+    env="$env $(quote_arg "HAB_CONFIG_EXCLUDE=$HAB_CONFIG_EXCLUDE")"
   fi
   # If a Habitat Depot URL is set, then propagate it into the Studio's
   # environment.
   if [ -n "${HAB_DEPOT_URL:-}" ]; then
-    env="$env HAB_DEPOT_URL=$HAB_DEPOT_URL"
+    env="$env $(quote_arg "HAB_DEPOT_URL=$HAB_DEPOT_URL")"
   fi
   # If a no coloring environment variable is set, then propagate it into the Studio's
   # environment.
   if [ -n "${HAB_NOCOLORING:-}" ]; then
-    env="$env HAB_NOCOLORING=$HAB_NOCOLORING"
+    env="$env $(quote_arg "HAB_NOCOLORING=$HAB_NOCOLORING")"
   fi
   # If a noninteractive environment variable is set, then propagate it into the Studio's
   # environment.
   if [ -n "${HAB_NONINTERACTIVE:-}" ]; then
-    env="$env HAB_NONINTERACTIVE=$HAB_NONINTERACTIVE"
+    env="$env $(quote_arg "HAB_NONINTERACTIVE=$HAB_NONINTERACTIVE")"
   fi
   # If a Habitat origin name is set, then propagate it into the Studio's
   # environment.
   if [ -n "${HAB_ORIGIN:-}" ]; then
-    env="$env HAB_ORIGIN=$HAB_ORIGIN"
+    env="$env $(quote_arg "HAB_ORIGIN=$HAB_ORIGIN")"
+  fi
+  # If a reproducible build is requested, then propagate the request and the
+  # pinned build time into the Studio's environment.
+  if [ -n "${HAB_REPRODUCIBLE_BUILD:-}" ]; then
+    env="$env $(quote_arg "HAB_REPRODUCIBLE_BUILD=$HAB_REPRODUCIBLE_BUILD")"
   fi
+  if [ -n "${SOURCE_DATE_EPOCH:-}" ]; then
+    env="$env $(quote_arg "SOURCE_DATE_EPOCH=$SOURCE_DATE_EPOCH")"
+  fi
+  # Propagate any origin secrets into the Studio's environment with the
+  # `HAB_STUDIO_SECRET_` prefix removed, so `HAB_STUDIO_SECRET_TOKEN` is
+  # available to a plan as `$TOKEN`.
+  for secret in $(env | $bb grep '^HAB_STUDIO_SECRET_' | $bb cut -d= -f1); do
+    env="$env $(quote_arg "${secret#HAB_STUDIO_SECRET_}=$($bb printenv "$secret")")"
+  done
   # If HTTP proxy variables are detected in the current environment, propagate
   # them into the Studio's environment.
   if [ -n "${http_proxy:-}" ]; then
-    env="$env http_proxy=$http_proxy"
+    env="$env $(quote_arg "http_proxy=$http_proxy")"
   fi
   if [ -n "${https_proxy:-}" ]; then
-    env="$env https_proxy=$https_proxy"
+    env="$env $(quote_arg "https_proxy=$https_proxy")"
   fi
   if [ -n "${no_proxy:-}" ]; then
     # If you pass whitespace here, bash will loose its mind when we do expansion
@@ -945,13 +971,19 @@ chroot_env() {
     # and take care of that little whitespace problem for you.
     #
     # Thanks, Docker, for passing unnecessary spaces. You're a peach.
-    env="$env no_proxy=$(echo $no_proxy | $bb sed 's/, /,/g')"
+    env="$env $(quote_arg "no_proxy=$(echo $no_proxy | $bb sed 's/, /,/g')")"
   fi
 
-  echo "$env"
+  printf "%s\n" "$env"
   return 0
 }
 
+# **Internal** Prints the given argument wrapped in single quotes, with any
+# single quotes inside it escaped, so that `eval` reads it back as one word.
+quote_arg() {
+  printf "'%s'" "$(printf '%s' "$1" | $bb sed "s/'/'\\\\''/g")"
+}
+
 # **Internal** Prints out any important environment variables that will be used
 # inside the Studio.
 report_env_vars() {
@@ -970,6 +1002,12 @@ report_env_vars() {
   if [ -n "${HAB_NONINTERACTIVE:-}" ]; then
     info "Exported: HAB_NONINTERACTIVE=$HAB_NONINTERACTIVE"
   fi
+  if [ -n "${HAB_REPRODUCIBLE_BUILD:-}" ]; then
+    info "Exported: HAB_REPRODUCIBLE_BUILD=$HAB_REPRODUCIBLE_BUILD"
+  fi
+  if [ -n "${SOURCE_DATE_EPOCH:-}" ]; then
+    info "Exported: SOURCE_DATE_EPOCH=$SOURCE_DATE_EPOCH"
+  fi
   if [ -n "${http_proxy:-}" ]; then
     info "Exported: http_proxy=$http_proxy"
   fi
@@ -0,0 +1,24 @@
+// With the `control-gateway` feature, generates a client of the Supervisor's gRPC control
+// service from its `protocols/control.proto`. Generating it requires `protoc`.
+
+#[cfg(feature = "control-gateway")]
+extern crate protoc_rust_grpc;
+
+fn main() {
+    generate_protocols();
+}
+
+#[cfg(feature = "control-gateway")]
+fn generate_protocols() {
+    println!("cargo:rerun-if-changed=../sup/protocols/control.proto");
+    protoc_rust_grpc::run(protoc_rust_grpc::Args {
+            out_dir: "src/control_gateway",
+            includes: &["../sup/protocols"],
+            input: &["../sup/protocols/control.proto"],
+            rust_protobuf: true,
+        })
+        .expect("Unable to generate the gRPC control client, is protoc installed?");
+}
+
+#[cfg(not(feature = "control-gateway"))]
+fn generate_protocols() {}
@@ -0,0 +1,180 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A blocking client of the Supervisor's gRPC control gateway, which it serves when started with
+//! `--listen-grpc`. It's built with the `control-gateway` feature.
+//!
+//! The messages are generated from the Supervisor's `protocols/control.proto` into the `control`
+//! module. Unlike the HTTP gateway, the control gateway streams the census and a service's
+//! output as they change, rather than being polled.
+
+pub mod control;
+pub mod control_grpc;
+
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+use bytes::Bytes;
+use futures::Stream;
+use grpc;
+use hab_core::service::ServiceGroup;
+
+use error::{Error, Result};
+use self::control::*;
+use self::control_grpc::{Control, ControlClient};
+
+/// A stream of messages, which ends when the Supervisor closes it.
+pub type Messages<T> = Box<Iterator<Item = Result<T>>>;
+
+pub use DEFAULT_CONTROL_ADDR;
+
+pub struct Client {
+    control: ControlClient,
+    auth_token: Option<String>,
+}
+
+impl Client {
+    /// Creates a client of the control gateway listening at `addr`, such as `127.0.0.1:9632`.
+    ///
+    /// # Errors
+    ///
+    /// * The address isn't a valid IP address and port
+    /// * The underlying gRPC client cannot be created
+    pub fn new(addr: &str) -> Result<Self> {
+        let addr = try!(SocketAddr::from_str(addr)
+            .map_err(|_| Error::InvalidAddress(addr.to_string())));
+        let client = try!(ControlClient::new_plain(&addr.ip().to_string(),
+                                                   addr.port(),
+                                                   Default::default()));
+        Ok(Client {
+            control: client,
+            auth_token: None,
+        })
+    }
+
+    /// Presents `token` with every request, for a gateway started with
+    /// `--gateway-auth-from-file`.
+    pub fn with_auth_token(mut self, token: &str) -> Self {
+        self.auth_token = Some(token.to_string());
+        self
+    }
+
+    pub fn services(&self) -> Result<Vec<Service>> {
+        let list = try!(self.control
+            .list_services(self.options(), ListServicesRequest::new())
+            .wait_drop_metadata());
+        Ok(list.get_services().to_vec())
+    }
+
+    pub fn health(&self, service_group: &ServiceGroup) -> Result<HealthCheck> {
+        let reply = try!(self.control
+            .get_health(self.options(), service_group_request(service_group))
+            .wait_drop_metadata());
+        Ok(reply.get_health())
+    }
+
+    /// Returns the configuration the service was last rendered with, as TOML.
+    pub fn config(&self, service_group: &ServiceGroup) -> Result<String> {
+        let mut reply = try!(self.control
+            .get_config(self.options(), service_group_request(service_group))
+            .wait_drop_metadata());
+        Ok(reply.take_config())
+    }
+
+    /// Sets the Supervisor's log level, optionally only for one subsystem or while working on
+    /// one service group.
+    pub fn set_log_level(&self,
+                         level: &str,
+                         subsystem: Option<&str>,
+                         service_group: Option<&ServiceGroup>)
+                         -> Result<()> {
+        let mut req = LogLevelRequest::new();
+        req.set_level(level.to_string());
+        if let Some(subsystem) = subsystem {
+            req.set_subsystem(subsystem.to_string());
+        }
+        if let Some(sg) = service_group {
+            req.set_service_group(sg.to_string());
+        }
+        try!(self.control.set_log_level(self.options(), req).wait_drop_metadata());
+        Ok(())
+    }
+
+    /// Returns the census of every service group the Supervisor knows of, or of one.
+    pub fn census(&self, service_group: Option<&ServiceGroup>) -> Result<Census> {
+        let census = try!(self.control
+            .get_census(self.options(), census_request(service_group))
+            .wait_drop_metadata());
+        Ok(census)
+    }
+
+    /// Returns the census, then the census again each time it changes.
+    pub fn watch_census(&self, service_group: Option<&ServiceGroup>) -> Messages<Census> {
+        let stream = self.control
+            .watch_census(self.options(), census_request(service_group))
+            .drop_metadata();
+        Box::new(stream.wait().map(|msg| msg.map_err(Error::from)))
+    }
+
+    /// Returns a service's output from the `since` sequence number on, or only the last `lines`
+    /// of it, then each line as it is written.
+    pub fn follow_logs(&self,
+                       service_group: &ServiceGroup,
+                       since: u64,
+                       lines: Option<u64>)
+                       -> Messages<LogLine> {
+        let mut req = LogsRequest::new();
+        req.set_service_group(service_group.to_string());
+        req.set_since(since);
+        if let Some(lines) = lines {
+            req.set_lines(lines);
+        }
+        let stream = self.control.follow_logs(self.options(), req).drop_metadata();
+        Box::new(stream.wait().map(|msg| msg.map_err(Error::from)))
+    }
+
+    /// Starts a new leader election for a service group the Supervisor runs, in which it stands
+    /// to become the leader, and returns the election's term. With `force`, the election is
+    /// started even though the group's leader is alive, such as when it's degraded.
+    pub fn force_election(&self, service_group: &ServiceGroup, force: bool) -> Result<u64> {
+        let mut req = ElectionRequest::new();
+        req.set_service_group(service_group.to_string());
+        req.set_force(force);
+        let reply = try!(self.control.force_election(self.options(), req).wait_drop_metadata());
+        Ok(reply.get_term())
+    }
+
+    fn options(&self) -> grpc::RequestOptions {
+        let mut options = grpc::RequestOptions::new();
+        if let Some(ref token) = self.auth_token {
+            options.metadata.add(grpc::MetadataKey::from("authorization"),
+                                 Bytes::from(format!("Bearer {}", token)));
+        }
+        options
+    }
+}
+
+fn service_group_request(service_group: &ServiceGroup) -> ServiceGroupRequest {
+    let mut req = ServiceGroupRequest::new();
+    req.set_service_group(service_group.to_string());
+    req
+}
+
+fn census_request(service_group: Option<&ServiceGroup>) -> CensusRequest {
+    let mut req = CensusRequest::new();
+    if let Some(sg) = service_group {
+        req.set_service_group(sg.to_string());
+    }
+    req
+}
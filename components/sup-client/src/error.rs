@@ -0,0 +1,127 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::io;
+use std::fmt;
+use std::result;
+
+use butterfly;
+#[cfg(feature = "control-gateway")]
+use grpc;
+use hab_core;
+use hyper;
+use serde_json;
+
+use hab_http;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The gateway answered with a status the request didn't expect, along with the path
+    /// requested and any message the gateway gave.
+    APIError(hyper::status::StatusCode, String),
+    Butterfly(butterfly::error::Error),
+    #[cfg(feature = "control-gateway")]
+    Grpc(grpc::Error),
+    HabitatCore(hab_core::Error),
+    HabitatHttpClient(hab_http::Error),
+    HyperError(hyper::error::Error),
+    InvalidAddress(String),
+    /// Configuration to apply which isn't valid TOML, and why.
+    InvalidConfig(String),
+    IO(io::Error),
+    Json(serde_json::Error),
+}
+
+pub type Result<T> = result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let msg = match *self {
+            Error::APIError(ref c, ref m) => format!("[{}] {}", c, m),
+            Error::Butterfly(ref e) => format!("{}", e),
+            #[cfg(feature = "control-gateway")]
+            Error::Grpc(ref e) => format!("{}", e),
+            Error::HabitatCore(ref e) => format!("{}", e),
+            Error::HabitatHttpClient(ref e) => format!("{}", e),
+            Error::HyperError(ref err) => format!("{}", err),
+            Error::InvalidAddress(ref a) => format!("Invalid Supervisor address: {}", a),
+            Error::InvalidConfig(ref e) => format!("Invalid configuration: {}", e),
+            Error::IO(ref e) => format!("{}", e),
+            Error::Json(ref e) => format!("{}", e),
+        };
+        write!(f, "{}", msg)
+    }
+}
+
+impl error::Error for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::APIError(_, _) => "Received an unexpected response code from the gateway",
+            Error::Butterfly(ref err) => err.description(),
+            #[cfg(feature = "control-gateway")]
+            Error::Grpc(ref err) => err.description(),
+            Error::HabitatCore(ref err) => err.description(),
+            Error::HabitatHttpClient(ref err) => err.description(),
+            Error::HyperError(ref err) => err.description(),
+            Error::InvalidAddress(_) => "Invalid Supervisor address",
+            Error::InvalidConfig(_) => "Configuration must be valid TOML",
+            Error::IO(ref err) => err.description(),
+            Error::Json(ref err) => err.description(),
+        }
+    }
+}
+
+impl From<butterfly::error::Error> for Error {
+    fn from(err: butterfly::error::Error) -> Error {
+        Error::Butterfly(err)
+    }
+}
+
+#[cfg(feature = "control-gateway")]
+impl From<grpc::Error> for Error {
+    fn from(err: grpc::Error) -> Error {
+        Error::Grpc(err)
+    }
+}
+
+impl From<hab_core::Error> for Error {
+    fn from(err: hab_core::Error) -> Error {
+        Error::HabitatCore(err)
+    }
+}
+
+impl From<hab_http::Error> for Error {
+    fn from(err: hab_http::Error) -> Error {
+        Error::HabitatHttpClient(err)
+    }
+}
+
+impl From<hyper::error::Error> for Error {
+    fn from(err: hyper::error::Error) -> Error {
+        Error::HyperError(err)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        Error::IO(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Error {
+        Error::Json(err)
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Changes made by gossiping rumors to the ring, as `hab config apply` does.
+//!
+//! Configuration isn't applied through either gateway, but by gossiping it to any Supervisor in
+//! the ring, which spreads it to every member of the service group. The rumor is sent to the
+//! Supervisor's gossip port, 9638 by default, rather than to its gateway.
+
+use std::str;
+use std::thread;
+use std::time::Duration;
+
+use butterfly::client::Client as ButterflyClient;
+use hab_core::crypto::{BoxKeyPair, SymKey};
+use hab_core::service::ServiceGroup;
+use toml;
+
+use error::{Error, Result};
+
+/// How long to wait after sending a rumor before closing the socket, so that it isn't lost.
+const SEND_LINGER_MS: u64 = 100;
+
+/// The keys configuration is encrypted with: the user's pair, and the service's public key.
+pub struct Encryption<'a> {
+    pub user_pair: &'a BoxKeyPair,
+    pub service_pair: &'a BoxKeyPair,
+}
+
+/// Gossips `config`, a TOML document, to each of `peers` as the given incarnation of the service
+/// group's configuration. Incarnations must increase; a Supervisor ignores configuration older
+/// than what it already has.
+///
+/// # Errors
+///
+/// * `config` isn't valid TOML
+/// * The configuration can't be encrypted
+/// * A peer can't be sent the rumor
+pub fn apply_config(peers: &[String],
+                    ring_key: Option<&SymKey>,
+                    service_group: &ServiceGroup,
+                    incarnation: u64,
+                    config: &[u8],
+                    encryption: Option<Encryption>)
+                    -> Result<()> {
+    send_config(peers, ring_key, service_group, incarnation, config, encryption, false)
+}
+
+/// Gossips `patch`, a TOML document of only the keys to change, to each of `peers`. Supervisors
+/// merge it over the configuration of the incarnation before it, after removing the dotted keys
+/// listed in its top-level `_delete` array, such as `_delete = ["tls.key"]`.
+///
+/// # Errors
+///
+/// * `patch` isn't valid TOML
+/// * The patch can't be encrypted
+/// * A peer can't be sent the rumor
+pub fn apply_config_patch(peers: &[String],
+                          ring_key: Option<&SymKey>,
+                          service_group: &ServiceGroup,
+                          incarnation: u64,
+                          patch: &[u8],
+                          encryption: Option<Encryption>)
+                          -> Result<()> {
+    send_config(peers, ring_key, service_group, incarnation, patch, encryption, true)
+}
+
+fn send_config(peers: &[String],
+               ring_key: Option<&SymKey>,
+               service_group: &ServiceGroup,
+               incarnation: u64,
+               config: &[u8],
+               encryption: Option<Encryption>,
+               patch: bool)
+               -> Result<()> {
+    try!(validate_config(config));
+    let (body, encrypted) = match encryption {
+        Some(keys) => (try!(keys.user_pair.encrypt(config, keys.service_pair)), true),
+        None => (config.to_vec(), false),
+    };
+    for peer in peers.iter() {
+        debug!("Applying configuration for {} incarnation {} to {}",
+               service_group,
+               incarnation,
+               peer);
+        let mut client = try!(ButterflyClient::new(peer, ring_key.map(|k| k.clone())));
+        if patch {
+            try!(client.send_service_config_patch(service_group.clone(),
+                                                  incarnation,
+                                                  body.clone(),
+                                                  encrypted));
+        } else {
+            try!(client.send_service_config(service_group.clone(),
+                                            incarnation,
+                                            body.clone(),
+                                            encrypted));
+        }
+        thread::sleep(Duration::from_millis(SEND_LINGER_MS));
+    }
+    Ok(())
+}
+
+fn validate_config(config: &[u8]) -> Result<()> {
+    let config = try!(str::from_utf8(config)
+        .map_err(|e| Error::InvalidConfig(format!("it isn't UTF-8: {}", e))));
+    let mut parser = toml::Parser::new(config);
+    match parser.parse() {
+        Some(_) => Ok(()),
+        None => {
+            let errors: Vec<String> = parser.errors.iter().map(|e| e.to_string()).collect();
+            Err(Error::InvalidConfig(errors.join(", ")))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_config;
+
+    #[test]
+    fn config_must_be_toml() {
+        assert!(validate_config(b"[redis]\nport = 6379\n").is_ok());
+        assert!(validate_config(b"[redis\nport = 6379\n").is_err());
+        assert!(validate_config(&[0xff, 0xfe]).is_err());
+    }
+}
@@ -0,0 +1,658 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Typed clients of the Supervisor, for tooling such as operators and dashboards.
+//!
+//! * `Client` talks to the HTTP gateway. Each method corresponds to an operation of the OpenAPI
+//!   description the gateway serves at `/api-doc`, and each type to one of its schemas. Only the
+//!   fields tooling relies on are deserialized, so newer Supervisors which add fields remain
+//!   readable. A service's output and hook output can be followed as they are written.
+//! * `control_gateway::Client`, built with the `control-gateway` feature, talks to the gRPC
+//!   control gateway, which also streams changes to the census.
+//! * `gossip::apply_config` applies configuration to a service group through the ring, and
+//!   `gossip::apply_config_patch` changes only some of its keys.
+//!
+//! The services a Supervisor runs are the ones it was started with; neither gateway loads or
+//! unloads services.
+
+#[cfg(feature = "control-gateway")]
+extern crate bytes;
+#[cfg(feature = "control-gateway")]
+extern crate futures;
+#[cfg(feature = "control-gateway")]
+extern crate grpc;
+extern crate habitat_butterfly as butterfly;
+extern crate habitat_core as hab_core;
+extern crate habitat_http_client as hab_http;
+extern crate hyper;
+#[macro_use]
+extern crate log;
+#[cfg(feature = "control-gateway")]
+extern crate protobuf;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+extern crate toml;
+extern crate url;
+
+#[cfg(feature = "control-gateway")]
+pub mod control_gateway;
+pub mod error;
+pub mod gossip;
+pub use error::{Error, Result};
+
+use std::collections::VecDeque;
+use std::io::Read;
+
+use hab_core::package::PackageIdent;
+use hab_core::service::ServiceGroup;
+use hab_http::ApiClient;
+use hyper::client::{RequestBuilder, Response};
+use hyper::header::{Authorization, Bearer};
+use hyper::status::StatusCode;
+use serde::Deserialize;
+use url::Url;
+
+/// The default address of a Supervisor's HTTP gateway.
+pub const DEFAULT_SUP_ADDR: &'static str = "127.0.0.1:9631";
+
+/// The address the control gateway is usually served on. It's defined whether or not the client
+/// of the control gateway is built.
+pub const DEFAULT_CONTROL_ADDR: &'static str = "127.0.0.1:9632";
+
+/// How long a follower waits on the gateway for new lines before asking again. It must be less
+/// than the gateway's request timeout, and than the client's socket read timeout, which are both
+/// 30 seconds.
+pub const FOLLOW_WAIT_SECS: u64 = 20;
+
+/// A service running under the Supervisor.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Service {
+    pub service_group: String,
+    pub package: ServicePackage,
+    pub supervisor: Supervisor,
+    /// The service's binds, each as `name:service_group`.
+    #[serde(default)]
+    pub binds: Vec<String>,
+}
+
+/// The release of the package a service runs.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServicePackage {
+    pub origin: String,
+    pub name: String,
+    pub version: String,
+    pub release: String,
+    pub tdeps: Vec<PackageIdent>,
+}
+
+impl ServicePackage {
+    pub fn ident(&self) -> PackageIdent {
+        PackageIdent::new(self.origin.clone(),
+                          self.name.clone(),
+                          Some(self.version.clone()),
+                          Some(self.release.clone()))
+    }
+}
+
+/// The process supervising a service.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Supervisor {
+    pub pid: Option<u32>,
+    pub state: String,
+}
+
+/// Output of hooks, from a sequence number on.
+#[derive(Clone, Debug, Deserialize)]
+pub struct HookOutput {
+    /// The sequence number to ask for next.
+    pub next: u64,
+    pub lines: Vec<HookLine>,
+    /// How many lines were dropped by the Supervisor before they could be returned.
+    #[serde(default)]
+    pub skipped: u64,
+    /// The hooks running at the time of the request.
+    #[serde(default)]
+    pub running: Vec<RunningHook>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct HookLine {
+    pub seq: u64,
+    pub service_group: String,
+    pub hook: String,
+    pub stream: String,
+    pub line: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RunningHook {
+    pub service_group: String,
+    pub hook: String,
+}
+
+/// Output a service wrote, from a sequence number on.
+#[derive(Clone, Debug, Deserialize)]
+pub struct LogOutput {
+    /// The sequence number to ask for next.
+    pub next: u64,
+    pub lines: Vec<LogLine>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct LogLine {
+    pub seq: u64,
+    /// When the line was written, in seconds since the epoch.
+    pub time: i64,
+    pub line: String,
+}
+
+/// Which of a service's output lines to return.
+#[derive(Clone, Debug, Default)]
+pub struct LogQuery {
+    /// Only lines from this sequence number on.
+    pub since: u64,
+    /// Only the last this many lines.
+    pub lines: Option<u64>,
+    /// Only lines written in the last this many seconds.
+    pub newer_than: Option<u64>,
+    /// Wait up to this many seconds for new lines if there are none yet.
+    pub wait: u64,
+}
+
+/// A service group's alive members alongside the number it should have.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Scale {
+    pub service_group: String,
+    pub desired_count: u64,
+    pub alive_count: u64,
+    /// One of `UnderProvisioned`, `OverProvisioned` or `Provisioned`.
+    pub provisioning: String,
+}
+
+/// A command sent to the ring with `hab ring exec`, and the acknowledgements of it so far.
+#[derive(Clone, Debug, Deserialize)]
+pub struct RingCommand {
+    pub id: String,
+    pub command: String,
+    pub args: Vec<String>,
+    /// When the command was issued, in seconds since the epoch.
+    pub issued_at: i64,
+    pub signer: String,
+    pub acks: Vec<RingCommandAck>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RingCommandAck {
+    pub member_id: String,
+    #[serde(default)]
+    pub alias: Option<String>,
+    /// Whether the member ran the command successfully.
+    pub success: bool,
+    pub message: String,
+}
+
+impl RingCommandAck {
+    /// The member's alias and id, or just its id if it has no alias.
+    pub fn member_name(&self) -> String {
+        match self.alias {
+            Some(ref alias) => format!("{} ({})", alias, self.member_id),
+            None => self.member_id.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Liveness {
+    pub alive: bool,
+    /// Seconds since the Supervisor's main loop last ticked.
+    pub last_tick_secs: i64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Readiness {
+    pub ready: bool,
+    pub services: Vec<ServiceReadiness>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServiceReadiness {
+    pub service_group: String,
+    pub ready: bool,
+    /// Why the service isn't ready, if it isn't.
+    pub reason: Option<String>,
+}
+
+/// A configuration file as a service was last rendered with it, from `Client::config_files`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ConfigFile {
+    pub name: String,
+    /// The BLAKE2b hash of the file, as hex.
+    pub hash: String,
+    /// The file's contents, if they were asked for.
+    #[serde(default)]
+    pub contents: Option<String>,
+}
+
+/// What a configuration would change and whether the service accepts it, from
+/// `Client::config_dry_run`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct DryRun {
+    pub valid: bool,
+    pub files: Vec<DryRunFile>,
+    /// How the package's `validate_config` hook exited, if it has one.
+    pub validation: Option<DryRunValidation>,
+    /// Why the configuration couldn't be rendered, if it couldn't.
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DryRunFile {
+    pub name: String,
+    pub changed: bool,
+    /// The changes to the file as a unified diff.
+    pub diff: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DryRunValidation {
+    pub exit_code: i32,
+    pub output: String,
+}
+
+/// A change of a service's process state or health, from `Client::history`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Transition {
+    pub seq: u64,
+    /// When the transition happened, in seconds since the epoch.
+    pub time: i64,
+    /// `state` or `health`.
+    pub kind: String,
+    /// What it was before, or `None` for the first transition of its kind.
+    pub from: Option<String>,
+    pub to: String,
+    pub cause: String,
+}
+
+/// The result of a service's health check, as told by the status the gateway answers with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Health {
+    /// The check passed, or passed with a warning.
+    Ok,
+    Critical,
+    Unknown,
+}
+
+pub struct Client {
+    api: ApiClient,
+    auth_token: Option<String>,
+}
+
+impl Client {
+    /// Creates a client of the gateway listening at `sup_addr`, such as `127.0.0.1:9631`, or
+    /// `https://sup.example.com:9631` for a gateway serving TLS.
+    ///
+    /// # Errors
+    ///
+    /// * The address isn't a valid host and port, or URL
+    /// * The underlying HTTP client cannot be created
+    pub fn new(sup_addr: &str, product: &str, version: &str) -> Result<Self> {
+        let url = if sup_addr.contains("://") {
+            Url::parse(sup_addr)
+        } else {
+            Url::parse(&format!("http://{}", sup_addr))
+        };
+        let url = match url {
+            Ok(url) => url,
+            Err(_) => return Err(Error::InvalidAddress(sup_addr.to_string())),
+        };
+        Ok(Client {
+            api: try!(ApiClient::new(&url, product, version, None)),
+            auth_token: None,
+        })
+    }
+
+    /// Presents `token` with every request, for a gateway started with
+    /// `--gateway-auth-from-file`.
+    pub fn with_auth_token(mut self, token: &str) -> Self {
+        self.auth_token = Some(token.to_string());
+        self
+    }
+
+    /// Returns the OpenAPI description of the gateway.
+    pub fn api_doc(&self) -> Result<serde_json::Value> {
+        self.get_json("api-doc", &[], &[StatusCode::Ok])
+    }
+
+    /// Returns the Supervisor's view of the census of every service group it knows of.
+    pub fn census(&self) -> Result<serde_json::Value> {
+        self.get_json("census", &[], &[StatusCode::Ok])
+    }
+
+    /// Returns `limit` census entries from `offset` on, of the given service group or of every
+    /// service group, along with how many entries there are in all.
+    pub fn census_page(&self,
+                       service_group: Option<&ServiceGroup>,
+                       offset: usize,
+                       limit: usize)
+                       -> Result<serde_json::Value> {
+        let offset = offset.to_string();
+        let limit = limit.to_string();
+        let service_group = service_group.map(|sg| sg.to_string());
+        let mut query = vec![("offset", offset.as_str()), ("limit", limit.as_str())];
+        if let Some(ref sg) = service_group {
+            query.push(("service_group", sg.as_str()));
+        }
+        self.get_json("census", &query, &[StatusCode::Ok])
+    }
+
+    pub fn services(&self) -> Result<Vec<Service>> {
+        self.get_json("services", &[], &[StatusCode::Ok])
+    }
+
+    /// Returns the configuration the service was last rendered with, as TOML.
+    pub fn config(&self, service_group: &ServiceGroup) -> Result<String> {
+        let path = service_path(service_group, "config");
+        let mut res = try!(self.authorize(self.api.get(&path)).send());
+        let body = try!(read_body(&mut res, &path, &[StatusCode::Ok]));
+        Ok(body)
+    }
+
+    /// Returns the configuration files the service was last rendered with, and their hashes, with
+    /// their contents if `contents` is set. Only a gateway which requires an auth token serves
+    /// them.
+    pub fn config_files(&self,
+                        service_group: &ServiceGroup,
+                        contents: bool)
+                        -> Result<Vec<ConfigFile>> {
+        let pairs: Vec<(&str, &str)> = if contents {
+            vec![("contents", "true")]
+        } else {
+            vec![]
+        };
+        self.get_json(&service_path(service_group, "config-files"),
+                      &pairs,
+                      &[StatusCode::Ok])
+    }
+
+    /// Renders the service's configuration files with `config`, a TOML document, or with it
+    /// patched over the service's configuration from gossip if `patch` is set, and runs the
+    /// package's `validate_config` hook against them. Nothing is applied.
+    pub fn config_dry_run(&self,
+                          service_group: &ServiceGroup,
+                          config: &[u8],
+                          patch: bool)
+                          -> Result<DryRun> {
+        let path = service_path(service_group, "dry-run");
+        let mut pairs = vec![];
+        if patch {
+            pairs.push(("patch", "true"));
+        }
+        let req = self.api.post_with_custom_url(&path, |url| set_query(url, &pairs)).body(config);
+        let mut res = try!(self.authorize(req).send());
+        let body = try!(read_body(&mut res, &path, &[StatusCode::Ok]));
+        Ok(try!(serde_json::from_str(&body)))
+    }
+
+    pub fn health(&self, service_group: &ServiceGroup) -> Result<Health> {
+        let path = service_path(service_group, "health");
+        let res = try!(self.authorize(self.api.get(&path)).send());
+        match res.status {
+            StatusCode::Ok => Ok(Health::Ok),
+            StatusCode::ServiceUnavailable => Ok(Health::Critical),
+            StatusCode::InternalServerError => Ok(Health::Unknown),
+            status => Err(Error::APIError(status, path)),
+        }
+    }
+
+    /// Returns the service's recent state and health transitions, oldest first.
+    pub fn history(&self, service_group: &ServiceGroup) -> Result<Vec<Transition>> {
+        self.get_json(&service_path(service_group, "history"), &[], &[StatusCode::Ok])
+    }
+
+    /// Returns the output the service wrote which `query` selects.
+    pub fn logs(&self, service_group: &ServiceGroup, query: &LogQuery) -> Result<LogOutput> {
+        let since = query.since.to_string();
+        let wait = query.wait.to_string();
+        let lines = query.lines.map(|l| l.to_string());
+        let newer_than = query.newer_than.map(|n| n.to_string());
+        let mut pairs = vec![("since", since.as_str()), ("wait", wait.as_str())];
+        if let Some(ref lines) = lines {
+            pairs.push(("lines", lines));
+        }
+        if let Some(ref newer_than) = newer_than {
+            pairs.push(("newer_than", newer_than));
+        }
+        self.get_json(&service_path(service_group, "logs"), &pairs, &[StatusCode::Ok])
+    }
+
+    /// Follows a service's output from the `since` sequence number on, returning each line as it
+    /// is written. The iterator only ends if the service group stops running.
+    pub fn follow_logs(&self, service_group: &ServiceGroup, since: u64) -> LogFollower {
+        LogFollower {
+            client: self,
+            service_group: service_group.clone(),
+            next: since,
+            lines: VecDeque::new(),
+        }
+    }
+
+    /// Returns the schedule of a service's updates, as its scheduler reports it.
+    pub fn schedule(&self, service_group: &ServiceGroup) -> Result<serde_json::Value> {
+        self.get_json(&service_path(service_group, "schedule"), &[], &[StatusCode::Ok])
+    }
+
+    /// Returns hook output from the `since` sequence number on, optionally for one service
+    /// group, waiting up to `wait` seconds for new lines if there are none yet.
+    pub fn hook_output(&self,
+                       since: u64,
+                       service_group: Option<&str>,
+                       wait: u64)
+                       -> Result<HookOutput> {
+        let since = since.to_string();
+        let wait = wait.to_string();
+        let mut pairs = vec![("since", since.as_str()), ("wait", wait.as_str())];
+        if let Some(sg) = service_group {
+            pairs.push(("service_group", sg));
+        }
+        self.get_json("hooks/output", &pairs, &[StatusCode::Ok])
+    }
+
+    /// Follows hook output from the `since` sequence number on, optionally for one service group,
+    /// returning each line as it is written. The iterator never ends on its own.
+    pub fn follow_hook_output(&self, since: u64, service_group: Option<&str>) -> HookFollower {
+        HookFollower {
+            client: self,
+            service_group: service_group.map(|sg| sg.to_string()),
+            next: since,
+            lines: VecDeque::new(),
+        }
+    }
+
+    pub fn scale(&self) -> Result<Vec<Scale>> {
+        self.get_json("scale", &[], &[StatusCode::Ok])
+    }
+
+    /// Returns the commands sent to the ring, newest first, or only the one with the given id.
+    pub fn ring_commands(&self, id: Option<&str>) -> Result<Vec<RingCommand>> {
+        let pairs: Vec<(&str, &str)> = id.into_iter().map(|id| ("id", id)).collect();
+        self.get_json("ring-commands", &pairs, &[StatusCode::Ok])
+    }
+
+    pub fn liveness(&self) -> Result<Liveness> {
+        self.get_json("liveness",
+                      &[],
+                      &[StatusCode::Ok, StatusCode::ServiceUnavailable])
+    }
+
+    pub fn readiness(&self) -> Result<Readiness> {
+        self.get_json("readiness",
+                      &[],
+                      &[StatusCode::Ok, StatusCode::ServiceUnavailable])
+    }
+
+    /// Sets the Supervisor's log level, optionally only for one subsystem or while working on
+    /// one service group.
+    pub fn set_log_level(&self,
+                         level: &str,
+                         subsystem: Option<&str>,
+                         service_group: Option<&ServiceGroup>)
+                         -> Result<()> {
+        let service_group = service_group.map(|sg| sg.to_string());
+        let mut pairs = vec![("level", level)];
+        if let Some(subsystem) = subsystem {
+            pairs.push(("subsystem", subsystem));
+        }
+        if let Some(ref sg) = service_group {
+            pairs.push(("service", sg));
+        }
+        let req = self.api.post_with_custom_url("log-level", |url| set_query(url, &pairs));
+        let mut res = try!(self.authorize(req).send());
+        try!(read_body(&mut res, "log-level", &[StatusCode::Ok]));
+        Ok(())
+    }
+
+    /// Fetches a JSON document from the gateway, which must answer with one of `expected`.
+    pub fn get_json<T: Deserialize>(&self,
+                                    path: &str,
+                                    query: &[(&str, &str)],
+                                    expected: &[StatusCode])
+                                    -> Result<T> {
+        let req = self.api.get_with_custom_url(path, |url| set_query(url, query));
+        let mut res = try!(self.authorize(req).send());
+        let body = try!(read_body(&mut res, path, expected));
+        Ok(try!(serde_json::from_str(&body)))
+    }
+
+    fn authorize<'a>(&self, req: RequestBuilder<'a>) -> RequestBuilder<'a> {
+        match self.auth_token {
+            Some(ref token) => req.header(Authorization(Bearer { token: token.clone() })),
+            None => req,
+        }
+    }
+}
+
+/// Each line of a service's output as it is written, from `Client::follow_logs`.
+pub struct LogFollower<'a> {
+    client: &'a Client,
+    service_group: ServiceGroup,
+    next: u64,
+    lines: VecDeque<LogLine>,
+}
+
+impl<'a> Iterator for LogFollower<'a> {
+    type Item = Result<LogLine>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.lines.is_empty() {
+            let query = LogQuery {
+                since: self.next,
+                wait: FOLLOW_WAIT_SECS,
+                ..Default::default()
+            };
+            match self.client.logs(&self.service_group, &query) {
+                Ok(output) => {
+                    self.next = output.next;
+                    self.lines.extend(output.lines);
+                }
+                Err(Error::APIError(StatusCode::NotFound, _)) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        self.lines.pop_front().map(Ok)
+    }
+}
+
+/// Each line of hook output as it is written, from `Client::follow_hook_output`.
+pub struct HookFollower<'a> {
+    client: &'a Client,
+    service_group: Option<String>,
+    next: u64,
+    lines: VecDeque<HookLine>,
+}
+
+impl<'a> Iterator for HookFollower<'a> {
+    type Item = Result<HookLine>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.lines.is_empty() {
+            let sg = self.service_group.as_ref().map(|sg| sg.as_str());
+            match self.client.hook_output(self.next, sg, FOLLOW_WAIT_SECS) {
+                Ok(output) => {
+                    self.next = output.next;
+                    self.lines.extend(output.lines);
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        self.lines.pop_front().map(Ok)
+    }
+}
+
+/// Returns the path of one of a service's endpoints.
+fn service_path(service_group: &ServiceGroup, endpoint: &str) -> String {
+    match service_group.org() {
+        Some(org) => {
+            format!("services/{}/{}/{}/{}",
+                    service_group.service(),
+                    service_group.group(),
+                    org,
+                    endpoint)
+        }
+        None => {
+            format!("services/{}/{}/{}",
+                    service_group.service(),
+                    service_group.group(),
+                    endpoint)
+        }
+    }
+}
+
+fn set_query(url: &mut Url, pairs: &[(&str, &str)]) {
+    if pairs.is_empty() {
+        return;
+    }
+    url.query_pairs_mut().extend_pairs(pairs.iter());
+}
+
+fn read_body(res: &mut Response, path: &str, expected: &[StatusCode]) -> Result<String> {
+    let mut body = String::new();
+    try!(res.read_to_string(&mut body));
+    if !expected.contains(&res.status) {
+        debug!("{} returned {}: {}", path, res.status, body);
+        let msg = match body.trim() {
+            "" => path.to_string(),
+            message => format!("{}: {}", path, message),
+        };
+        return Err(Error::APIError(res.status, msg));
+    }
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use hab_core::service::ServiceGroup;
+    use super::service_path;
+
+    #[test]
+    fn service_paths() {
+        let sg = ServiceGroup::from_str("redis.default").unwrap();
+        assert_eq!(service_path(&sg, "logs"), "services/redis/default/logs");
+        let sg = ServiceGroup::from_str("redis.default@acme").unwrap();
+        assert_eq!(service_path(&sg, "health"), "services/redis/default/acme/health");
+    }
+}
@@ -0,0 +1,50 @@
+// Writes VERSION as the shared `../build.rs` does, and with the `grpc-gateway` feature generates
+// the gRPC control service from `protocols/control.proto`. Generating it requires `protoc`.
+
+#[cfg(feature = "grpc-gateway")]
+extern crate protoc_rust_grpc;
+
+use std::env;
+use std::fs::File;
+use std::io::BufReader;
+use std::io::prelude::*;
+use std::path::Path;
+
+fn main() {
+    let version = match env::var("PLAN_VERSION") {
+        Ok(ver) => ver,
+        _ => read_version(),
+    };
+    let mut f = File::create(Path::new(&env::var("OUT_DIR").unwrap()).join("VERSION")).unwrap();
+    f.write_all(version.trim().as_bytes()).unwrap();
+    generate_protocols();
+}
+
+fn read_version() -> String {
+    let ver_file = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap())
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join("VERSION");
+    let f = File::open(ver_file).unwrap();
+    let mut reader = BufReader::new(f);
+    let mut ver = String::new();
+    reader.read_line(&mut ver).unwrap();
+    ver
+}
+
+#[cfg(feature = "grpc-gateway")]
+fn generate_protocols() {
+    println!("cargo:rerun-if-changed=protocols/control.proto");
+    protoc_rust_grpc::run(protoc_rust_grpc::Args {
+            out_dir: "src/grpc_gateway",
+            includes: &["protocols"],
+            input: &["protocols/control.proto"],
+            rust_protobuf: true,
+        })
+        .expect("Unable to generate the gRPC control service, is protoc installed?");
+}
+
+#[cfg(not(feature = "grpc-gateway"))]
+fn generate_protocols() {}
@@ -0,0 +1,550 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The OpenAPI description of the HTTP gateway, served at `/api-doc`.
+//!
+//! The description is built from the gateway's routes, so every endpoint served is described and
+//! nothing else is. What each route takes and returns is documented here by its route id; the
+//! parameters in a route's path are described from the path itself.
+
+use std::collections::BTreeMap;
+
+use VERSION;
+
+const OPENAPI_VERSION: &'static str = "3.0.0";
+
+#[derive(Debug, Serialize)]
+pub struct ApiDoc {
+    openapi: &'static str,
+    info: Info,
+    /// Operations by path, then by method.
+    paths: BTreeMap<String, BTreeMap<&'static str, Operation>>,
+    components: Components,
+}
+
+impl ApiDoc {
+    /// Describes the given routes, each as its route id, method and path.
+    pub fn new<I>(routes: I) -> Self
+        where I: IntoIterator<Item = (&'static str, &'static str, &'static str)>
+    {
+        let mut paths = BTreeMap::new();
+        for (id, method, path) in routes {
+            let mut op = operation(id).unwrap_or_else(|| Operation::new(""));
+            op.operation_id = id;
+            let mut parameters: Vec<Parameter> = path.split('/')
+                .filter(|s| s.starts_with(':'))
+                .map(|s| path_parameter(&s[1..]))
+                .collect();
+            parameters.extend(op.parameters.drain(..));
+            op.parameters = parameters;
+            paths.entry(openapi_path(path)).or_insert_with(BTreeMap::new).insert(method, op);
+        }
+        ApiDoc {
+            openapi: OPENAPI_VERSION,
+            info: Info {
+                title: "Habitat Supervisor HTTP gateway",
+                version: VERSION.to_string(),
+            },
+            paths: paths,
+            components: Components { schemas: schemas() },
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Info {
+    title: &'static str,
+    version: String,
+}
+
+#[derive(Debug, Serialize)]
+struct Components {
+    schemas: BTreeMap<&'static str, Schema>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Operation {
+    #[serde(rename = "operationId")]
+    operation_id: &'static str,
+    summary: &'static str,
+    parameters: Vec<Parameter>,
+    #[serde(rename = "requestBody", skip_serializing_if = "Option::is_none")]
+    request_body: Option<RequestBody>,
+    /// Responses by status code.
+    responses: BTreeMap<String, Response>,
+}
+
+impl Operation {
+    fn new(summary: &'static str) -> Self {
+        Operation {
+            operation_id: "",
+            summary: summary,
+            parameters: Vec::new(),
+            request_body: None,
+            responses: BTreeMap::new(),
+        }
+    }
+
+    fn query(mut self, name: &'static str, kind: &'static str, description: &'static str) -> Self {
+        self.parameters.push(Parameter {
+            name: name,
+            location: "query",
+            required: false,
+            description: description,
+            schema: Schema::of(kind),
+        });
+        self
+    }
+
+    fn request(mut self,
+               description: &'static str,
+               media_type: &'static str,
+               schema: Schema)
+               -> Self {
+        let mut content = BTreeMap::new();
+        content.insert(media_type, MediaType { schema: schema });
+        self.request_body = Some(RequestBody {
+            description: description,
+            required: true,
+            content: content,
+        });
+        self
+    }
+
+    fn status(mut self, code: u16, description: &'static str) -> Self {
+        self.responses.insert(code.to_string(),
+                              Response {
+                                  description: description,
+                                  content: None,
+                              });
+        self
+    }
+
+    fn body(mut self,
+            code: u16,
+            description: &'static str,
+            media_type: &'static str,
+            schema: Schema)
+            -> Self {
+        let mut content = BTreeMap::new();
+        content.insert(media_type, MediaType { schema: schema });
+        self.responses.insert(code.to_string(),
+                              Response {
+                                  description: description,
+                                  content: Some(content),
+                              });
+        self
+    }
+
+    fn json(self, code: u16, description: &'static str, schema: Schema) -> Self {
+        self.body(code, description, "application/json", schema)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Parameter {
+    name: &'static str,
+    #[serde(rename = "in")]
+    location: &'static str,
+    required: bool,
+    description: &'static str,
+    schema: Schema,
+}
+
+#[derive(Debug, Serialize)]
+struct RequestBody {
+    description: &'static str,
+    required: bool,
+    content: BTreeMap<&'static str, MediaType>,
+}
+
+#[derive(Debug, Serialize)]
+struct Response {
+    description: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<BTreeMap<&'static str, MediaType>>,
+}
+
+#[derive(Debug, Serialize)]
+struct MediaType {
+    schema: Schema,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Schema {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    kind: Option<&'static str>,
+    #[serde(rename = "$ref", skip_serializing_if = "Option::is_none")]
+    reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    items: Option<Box<Schema>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<BTreeMap<&'static str, Schema>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nullable: Option<bool>,
+}
+
+impl Schema {
+    fn of(kind: &'static str) -> Self {
+        Schema { kind: Some(kind), ..Schema::default() }
+    }
+
+    fn named(name: &str) -> Self {
+        Schema { reference: Some(format!("#/components/schemas/{}", name)), ..Schema::default() }
+    }
+
+    fn array(items: Schema) -> Self {
+        Schema {
+            kind: Some("array"),
+            items: Some(Box::new(items)),
+            ..Schema::default()
+        }
+    }
+
+    fn object(properties: Vec<(&'static str, Schema)>) -> Self {
+        Schema {
+            kind: Some("object"),
+            properties: Some(properties.into_iter().collect()),
+            ..Schema::default()
+        }
+    }
+
+    fn nullable(mut self) -> Self {
+        self.nullable = Some(true);
+        self
+    }
+}
+
+/// Returns what the route with the given id takes and returns, or `None` if it isn't documented.
+/// A service's endpoints are documented once for both the routes with an organization and those
+/// without.
+pub fn operation(id: &str) -> Option<Operation> {
+    let op = match id.trim_right_matches("_org") {
+        "api_doc" => {
+            Operation::new("This description of the gateway")
+                .json(200, "An OpenAPI document", Schema::of("object"))
+        }
+        "audit" => {
+            Operation::new("Known vulnerabilities in each service's packages")
+                .json(200, "Vulnerabilities by service", Schema::of("object"))
+                .status(404, "No vulnerability database is installed")
+        }
+        "bastion" => {
+            Operation::new("Whether the Supervisor is a bastion, and how it gossips")
+                .json(200, "The bastion and the members it knows of", Schema::of("object"))
+                .status(404, "The Supervisor isn't a bastion")
+        }
+        "butterfly" => {
+            Operation::new("The gossip layer's view of the ring")
+                .json(200, "Members, services and rumors", Schema::of("object"))
+        }
+        "census" => {
+            Operation::new("The census of every service group the Supervisor knows of")
+                .query("service_group", "string", "Only the entries of this service group")
+                .query("offset", "integer", "Skip this many entries")
+                .query("limit", "integer", "Entries on the page, at most 1000 [default: 100]")
+                .query("fields", "string", "Only these comma separated fields of each entry")
+                .json(200,
+                      "Census entries by service group, or a page of entries",
+                      Schema::of("object"))
+                .status(304, "The census hasn't changed since the given entity tag")
+        }
+        "diagnostics" => {
+            Operation::new("Thread, memory and queue diagnostics of the Supervisor")
+                .json(200, "Diagnostics", Schema::of("object"))
+        }
+        "envoy_clusters" => {
+            Operation::new("The census as clusters, for Envoy's cluster discovery service")
+                .json(200, "A v2 DiscoveryResponse of clusters", Schema::of("object"))
+        }
+        "envoy_endpoints" => {
+            Operation::new("The census as endpoints, for Envoy's endpoint discovery service")
+                .json(200,
+                      "A v2 DiscoveryResponse of cluster load assignments",
+                      Schema::of("object"))
+        }
+        "memory_maps" => {
+            Operation::new("Write the Supervisor's memory mappings, from /proc/self/smaps")
+                .body(200, "The path of the file written", "text/plain", Schema::of("string"))
+                .status(403, "The gateway doesn't require an auth token")
+                .status(500, "The file couldn't be written")
+        }
+        "hook_output" => {
+            Operation::new("Output of hooks, from a sequence number on")
+                .query("since", "integer", "Only lines from this sequence number on")
+                .query("service_group", "string", "Only the hooks of this service group")
+                .query("wait", "integer", "Seconds to wait for new lines if there are none yet")
+                .json(200, "Hook output", Schema::named("HookOutput"))
+                .status(400, "A parameter isn't a number")
+        }
+        "liveness" => {
+            Operation::new("Whether the Supervisor's main loop is running")
+                .json(200, "The Supervisor is alive", Schema::named("Liveness"))
+                .json(503, "The main loop has stalled", Schema::named("Liveness"))
+        }
+        "chaos" => {
+            Operation::new("The faults being injected, if built with the chaos feature")
+                .json(200, "The faults", Schema::named("Faults"))
+        }
+        "set_chaos" => {
+            Operation::new("Inject faults, if built with the chaos feature")
+                .query("gossip_loss", "number", "Share of incoming gossip to drop, 0.0 to 1.0")
+                .query("hook_delay", "integer", "Seconds to hold every hook up before it runs")
+                .query("update_failure", "boolean", "Whether package downloads fail")
+                .status(200, "The faults replace those injected so far")
+                .status(400, "A fault isn't valid")
+        }
+        "log_level" => {
+            Operation::new("Set the Supervisor's log level")
+                .query("level", "string", "The level to log at (ex: debug)")
+                .query("subsystem", "string", "Only change the level of this subsystem")
+                .query("service", "string", "Only while working on this service group")
+                .status(200, "The level was changed")
+                .status(400, "The level, subsystem or service group isn't valid")
+        }
+        "metrics" => {
+            Operation::new("Metrics of the gateway in the Prometheus text format")
+                .body(200, "Metrics", "text/plain", Schema::of("string"))
+        }
+        "readiness" => {
+            Operation::new("Whether every service is up and healthy")
+                .json(200, "Every service is ready", Schema::named("Readiness"))
+                .json(503, "A service isn't ready", Schema::named("Readiness"))
+        }
+        "ring_commands" => {
+            Operation::new("Commands sent to the ring with `hab ring exec`, newest first")
+                .query("id", "string", "Only the command with this id")
+                .json(200,
+                      "Commands and their acknowledgements",
+                      Schema::array(Schema::named("RingCommand")))
+        }
+        "scale" => {
+            Operation::new("Alive and desired members of each service group with a desired count")
+                .json(200,
+                      "Scales by service group",
+                      Schema::array(Schema::named("Scale")))
+        }
+        "services" => {
+            Operation::new("The services running under the Supervisor")
+                .json(200, "Services", Schema::array(Schema::named("Service")))
+                .status(304, "The services haven't changed since the given entity tag")
+        }
+        "service_config" => {
+            Operation::new("The configuration the service was last rendered with")
+                .body(200, "The configuration", "text/plain", Schema::of("string"))
+                .status(404, "No such service is running")
+        }
+        "service_config_files" => {
+            Operation::new("The configuration files the service was last rendered with")
+                .query("contents", "boolean", "Whether to include each file's contents")
+                .json(200,
+                      "Each file's name, hash and contents",
+                      Schema::array(Schema::named("ConfigFile")))
+                .status(403, "The gateway doesn't require an auth token")
+                .status(404, "No such service is running")
+        }
+        "service_dry_run" => {
+            Operation::new("Render and validate a configuration without applying it")
+                .query("patch", "boolean", "Whether the body patches the gossiped configuration")
+                .request("The configuration", "application/toml", Schema::of("string"))
+                .json(200, "The rendered files' changes and validation", Schema::named("DryRun"))
+                .status(400, "The body isn't valid TOML")
+                .status(403, "The gateway doesn't require an auth token")
+                .status(404, "No such service is running")
+        }
+        "service_health" => {
+            Operation::new("Run the service's health check")
+                .status(200, "The check passed, possibly with a warning")
+                .status(404, "No such service is running")
+                .status(500, "The check's result is unknown")
+                .status(503, "The check failed critically")
+        }
+        "service_history" => {
+            Operation::new("The service's recent state and health transitions, oldest first")
+                .json(200,
+                      "The transitions",
+                      Schema::array(Schema::named("ServiceTransition")))
+                .status(404, "No such service is running")
+        }
+        "service_logs" => {
+            Operation::new("Output the service wrote, from a sequence number on")
+                .query("since", "integer", "Only lines from this sequence number on")
+                .query("lines", "integer", "Only the last this many lines")
+                .query("newer_than", "integer", "Only lines written in the last this many seconds")
+                .query("wait", "integer", "Seconds to wait for new lines if there are none yet")
+                .json(200, "Service output", Schema::named("LogOutput"))
+                .status(400, "A parameter isn't a number")
+                .status(404, "No such service is running")
+        }
+        "service_schedule" => {
+            Operation::new("When the service's scheduled runs and hooks last ran and next run")
+                .json(200, "The schedule", Schema::of("object"))
+                .status(404, "No such service is running")
+        }
+        _ => return None,
+    };
+    Some(op)
+}
+
+fn path_parameter(name: &'static str) -> Parameter {
+    let description = match name {
+        "svc" => "The name of the service",
+        "group" => "The group of the service",
+        "org" => "The organization of the service group",
+        _ => "",
+    };
+    Parameter {
+        name: name,
+        location: "path",
+        required: true,
+        description: description,
+        schema: Schema::of("string"),
+    }
+}
+
+/// Converts a route's path to an OpenAPI path, such as `/services/:svc/:group/logs` to
+/// `/services/{svc}/{group}/logs`.
+fn openapi_path(path: &str) -> String {
+    path.split('/')
+        .map(|s| if s.starts_with(':') {
+            format!("{{{}}}", &s[1..])
+        } else {
+            s.to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn schemas() -> BTreeMap<&'static str, Schema> {
+    let string = || Schema::of("string");
+    let integer = || Schema::of("integer");
+    let boolean = || Schema::of("boolean");
+    let mut schemas = BTreeMap::new();
+    schemas.insert("Service",
+                   Schema::object(vec![("service_group", string()),
+                                       ("package", Schema::named("ServicePackage")),
+                                       ("supervisor", Schema::named("Supervisor")),
+                                       ("binds", Schema::array(string()))]));
+    schemas.insert("ServicePackage",
+                   Schema::object(vec![("origin", string()),
+                                       ("name", string()),
+                                       ("version", string()),
+                                       ("release", string()),
+                                       ("tdeps", Schema::array(Schema::of("object")))]));
+    schemas.insert("Supervisor",
+                   Schema::object(vec![("pid", integer().nullable()), ("state", string())]));
+    schemas.insert("HookOutput",
+                   Schema::object(vec![("next", integer()),
+                                       ("lines", Schema::array(Schema::named("HookLine"))),
+                                       ("running", Schema::array(Schema::named("RunningHook")))]));
+    schemas.insert("HookLine",
+                   Schema::object(vec![("seq", integer()),
+                                       ("service_group", string()),
+                                       ("hook", string()),
+                                       ("stream", string()),
+                                       ("line", string())]));
+    schemas.insert("RunningHook",
+                   Schema::object(vec![("service_group", string()), ("hook", string())]));
+    schemas.insert("LogOutput",
+                   Schema::object(vec![("next", integer()),
+                                       ("lines", Schema::array(Schema::named("LogLine")))]));
+    schemas.insert("LogLine",
+                   Schema::object(vec![("seq", integer()),
+                                       ("time", integer()),
+                                       ("line", string())]));
+    schemas.insert("Scale",
+                   Schema::object(vec![("service_group", string()),
+                                       ("desired_count", integer()),
+                                       ("alive_count", integer()),
+                                       ("provisioning", string())]));
+    schemas.insert("RingCommand",
+                   Schema::object(vec![("id", string()),
+                                       ("command", string()),
+                                       ("args", Schema::array(string())),
+                                       ("issued_at", integer()),
+                                       ("signer", string()),
+                                       ("acks", Schema::array(Schema::named("RingCommandAck")))]));
+    schemas.insert("RingCommandAck",
+                   Schema::object(vec![("member_id", string()),
+                                       ("alias", string().nullable()),
+                                       ("success", boolean()),
+                                       ("message", string())]));
+    schemas.insert("Faults",
+                   Schema::object(vec![("gossip_loss", Schema::of("number")),
+                                       ("hook_delay", integer()),
+                                       ("update_failure", boolean())]));
+    schemas.insert("ConfigFile",
+                   Schema::object(vec![("name", string()),
+                                       ("hash", string()),
+                                       ("contents", string())]));
+    schemas.insert("DryRun",
+                   Schema::object(vec![("valid", boolean()),
+                                       ("files", Schema::array(Schema::named("DryRunFile"))),
+                                       ("validation",
+                                        Schema::named("DryRunValidation").nullable()),
+                                       ("error", string().nullable())]));
+    schemas.insert("DryRunFile",
+                   Schema::object(vec![("name", string()),
+                                       ("changed", boolean()),
+                                       ("diff", string())]));
+    schemas.insert("DryRunValidation",
+                   Schema::object(vec![("exit_code", integer()), ("output", string())]));
+    schemas.insert("ServiceTransition",
+                   Schema::object(vec![("seq", integer()),
+                                       ("time", integer()),
+                                       ("kind", string()),
+                                       ("from", string().nullable()),
+                                       ("to", string()),
+                                       ("cause", string())]));
+    schemas.insert("Liveness",
+                   Schema::object(vec![("alive", boolean()), ("last_tick_secs", integer())]));
+    schemas.insert("Readiness",
+                   Schema::object(vec![("ready", boolean()),
+                                       ("services",
+                                        Schema::array(Schema::named("ServiceReadiness")))]));
+    schemas.insert("ServiceReadiness",
+                   Schema::object(vec![("service_group", string()),
+                                       ("ready", boolean()),
+                                       ("reason", string().nullable())]));
+    schemas
+}
+
+#[cfg(test)]
+mod tests {
+    use http_gateway::routes;
+    use super::{openapi_path, operation, ApiDoc};
+
+    #[test]
+    fn every_route_is_documented() {
+        for route in routes() {
+            assert!(operation(route.id).is_some(), "{} isn't documented", route.id);
+        }
+    }
+
+    #[test]
+    fn openapi_paths() {
+        assert_eq!(openapi_path("/services"), "/services");
+        assert_eq!(openapi_path("/services/:svc/:group/:org/logs"),
+                   "/services/{svc}/{group}/{org}/logs");
+    }
+
+    #[test]
+    fn path_parameters_come_first() {
+        let doc = ApiDoc::new(vec![("service_logs", "get", "/services/:svc/:group/logs")]);
+        let op = &doc.paths["/services/{svc}/{group}/logs"]["get"];
+        let names: Vec<&str> = op.parameters.iter().map(|p| p.name).collect();
+        assert_eq!(names, vec!["svc", "group", "since", "lines", "newer_than", "wait"]);
+        assert_eq!(op.operation_id, "service_logs");
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Known vulnerabilities in the packages of the Supervisor's services.
+//!
+//! The vulnerability database is the one installed on the host with `hab pkg audit --update`. A
+//! service is affected by a vulnerability in its own package or in any of its runtime
+//! dependencies.
+
+use hcore::package::{Vulnerability, VulnerabilityDb};
+
+use manager;
+
+#[derive(Debug, Serialize)]
+pub struct Audit<'a> {
+    /// When the vulnerability database was last updated, as given by whoever published it.
+    pub database_updated: Option<&'a str>,
+    pub services: Vec<ServiceAudit<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ServiceAudit<'a> {
+    pub service_group: String,
+    pub package: String,
+    pub vulnerabilities: Vec<Finding<'a>>,
+}
+
+/// A vulnerability, and the package of the service it was found in.
+#[derive(Debug, Serialize)]
+pub struct Finding<'a> {
+    pub package: String,
+    pub vulnerability: &'a Vulnerability,
+}
+
+impl<'a> Audit<'a> {
+    pub fn new(db: &'a VulnerabilityDb, state: &manager::State) -> Self {
+        let services = state.services.read().expect("Services lock is poisoned!");
+        Audit {
+            database_updated: db.updated.as_ref().map(|u| u.as_str()),
+            services: services.iter()
+                .map(|service| {
+                    let pkg = &service.package;
+                    let mut findings = Vec::new();
+                    for ident in Some(pkg.ident()).into_iter().chain(pkg.tdeps.iter()) {
+                        for vulnerability in db.affecting(ident) {
+                            findings.push(Finding {
+                                package: ident.to_string(),
+                                vulnerability: vulnerability,
+                            });
+                        }
+                    }
+                    ServiceAudit {
+                        service_group: service.service_group_str(),
+                        package: pkg.ident().to_string(),
+                        vulnerabilities: findings,
+                    }
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fault injection, for rehearsing failure modes in staging.
+//!
+//! Only built with the `chaos` feature, so a Supervisor built for production can't inject faults
+//! however it is configured. The faults are set through the HTTP gateway's `/chaos` endpoint and
+//! last until they are set again or the Supervisor restarts:
+//!
+//! * `gossip_loss` drops that share of the gossip and SWIM messages this Supervisor receives.
+//! * `hook_delay` holds every hook up for that many seconds before it runs.
+//! * `update_failure` makes downloading an updated package fail.
+
+use std::result;
+use std::sync::RwLock;
+use std::thread;
+use std::time::Duration;
+
+use butterfly;
+use butterfly::simulation::Link;
+use hcore::package::PackageIdent;
+use hcore::service::ServiceGroup;
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "CH";
+
+lazy_static! {
+    static ref FAULTS: RwLock<Faults> = RwLock::new(Faults::default());
+}
+
+/// The faults being injected. The default injects none.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Faults {
+    /// The share of incoming gossip and SWIM messages dropped, from 0.0 to 1.0.
+    pub gossip_loss: f64,
+    /// How many seconds every hook is held up before it runs.
+    pub hook_delay: u64,
+    /// Whether downloading an updated package fails.
+    pub update_failure: bool,
+}
+
+impl Faults {
+    /// Parses faults from a query string such as `gossip_loss=0.2&hook_delay=5`. The faults not
+    /// given are not injected.
+    pub fn from_query(query: &str) -> result::Result<Faults, String> {
+        let mut faults = Faults::default();
+        for pair in query.split('&').filter(|p| !p.is_empty()) {
+            let mut kv = pair.splitn(2, '=');
+            match (kv.next(), kv.next()) {
+                (Some("gossip_loss"), Some(v)) => {
+                    match v.parse::<f64>() {
+                        Ok(loss) if loss >= 0.0 && loss <= 1.0 => faults.gossip_loss = loss,
+                        _ => {
+                            return Err(format!("gossip_loss must be from 0.0 to 1.0, not {}", v))
+                        }
+                    }
+                }
+                (Some("hook_delay"), Some(v)) => {
+                    match v.parse() {
+                        Ok(delay) => faults.hook_delay = delay,
+                        Err(_) => return Err(format!("hook_delay must be seconds, not {}", v)),
+                    }
+                }
+                (Some("update_failure"), Some(v)) => {
+                    match v.parse() {
+                        Ok(fail) => faults.update_failure = fail,
+                        Err(_) => {
+                            return Err(format!("update_failure must be true or false, not {}", v))
+                        }
+                    }
+                }
+                (Some(key), None) => return Err(format!("{} needs a value", key)),
+                (Some(key), _) => return Err(format!("Unknown fault {}", key)),
+                (None, _) => {}
+            }
+        }
+        Ok(faults)
+    }
+}
+
+/// The faults being injected.
+pub fn current() -> Faults {
+    FAULTS.read().expect("Faults lock is poisoned").clone()
+}
+
+/// Starts injecting `faults` instead of the faults injected so far.
+pub fn set(faults: Faults, butterfly: &butterfly::Server) {
+    outputln!("Injecting faults: {:?}", faults);
+    if faults.gossip_loss > 0.0 {
+        butterfly.set_default_link(Some(Link::new(Duration::from_millis(0), faults.gossip_loss)));
+    } else {
+        butterfly.set_default_link(None);
+    }
+    *FAULTS.write().expect("Faults lock is poisoned") = faults;
+}
+
+/// Holds a hook up for the injected delay, if there is one.
+pub fn delay_hook(service_group: &ServiceGroup, hook: &str) {
+    let delay = current().hook_delay;
+    if delay > 0 {
+        outputln!(preamble service_group.to_string(), "Delaying hook {} by {}s", hook, delay);
+        thread::sleep(Duration::from_secs(delay));
+    }
+}
+
+/// Fails the download of `package` if update failures are being injected.
+pub fn check_download(package: &PackageIdent) -> Result<()> {
+    if current().update_failure {
+        return Err(sup_error!(Error::InjectedFault(format!("download of {} failed", package))));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Faults;
+
+    #[test]
+    fn faults_from_query() {
+        let faults = Faults::from_query("gossip_loss=0.25&hook_delay=5&update_failure=true")
+            .unwrap();
+        assert_eq!(faults,
+                   Faults {
+                       gossip_loss: 0.25,
+                       hook_delay: 5,
+                       update_failure: true,
+                   });
+        assert_eq!(Faults::from_query("").unwrap(), Faults::default());
+    }
+
+    #[test]
+    fn invalid_faults_from_query() {
+        assert!(Faults::from_query("gossip_loss=2").is_err());
+        assert!(Faults::from_query("hook_delay=soon").is_err());
+        assert!(Faults::from_query("update_failure=maybe").is_err());
+        assert!(Faults::from_query("disk_full=true").is_err());
+    }
+}
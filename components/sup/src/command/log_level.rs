@@ -0,0 +1,60 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Changes the log level of a running Supervisor, by way of its HTTP gateway.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab-sup log-level debug
+//! ```
+//!
+//! Will log debug messages from everything the Supervisor does.
+//!
+//! ```bash
+//! $ hab-sup log-level trace --subsystem gossip
+//! ```
+//!
+//! Will additionally log trace messages from gossip, leaving the rest of the Supervisor as it was.
+//!
+//! ```bash
+//! $ hab-sup log-level debug --service redis.default
+//! ```
+//!
+//! Will log debug messages while the Supervisor works on the `redis.default` service.
+
+use std::str::FromStr;
+
+use hcore::service::ServiceGroup;
+use sup_client::Client;
+
+use {PRODUCT, VERSION};
+use error::Result;
+
+static LOGKEY: &'static str = "LL";
+
+pub fn start(sup_addr: &str,
+             level: &str,
+             subsystem: Option<&str>,
+             service_group: Option<&str>)
+             -> Result<()> {
+    let service_group = match service_group {
+        Some(sg) => Some(try!(ServiceGroup::from_str(sg))),
+        None => None,
+    };
+    let client = try!(Client::new(sup_addr, PRODUCT, VERSION));
+    try!(client.set_log_level(level, subsystem, service_group.as_ref()));
+    outputln!("Log level set to {}", level);
+    Ok(())
+}
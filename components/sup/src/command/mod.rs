@@ -18,5 +18,8 @@
 //! 1:1 to the actual command line arguments, with one exception - `_` is translated to `-` on the
 //! CLI.
 
+pub mod log_level;
+pub mod quarantine;
 pub mod start;
 pub mod shell;
+pub mod state;
@@ -0,0 +1,83 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Shows and clears the releases a service failed to update to.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab-sup quarantine list redis
+//! ```
+//!
+//! Will list every release the `redis` service failed to update to, how many times, and why the
+//! last attempt failed.
+//!
+//! ```bash
+//! $ hab-sup quarantine clear redis core/redis/3.2.4/20170514150022
+//! ```
+//!
+//! Will forget that release's failures, so the running Supervisor tries to update to it again
+//! the next time it checks for updates. Without a release, every release is forgotten.
+
+use std::str::FromStr;
+
+use hcore::package::PackageIdent;
+use time;
+
+use error::Result;
+use manager::quarantine::{self, Quarantine};
+
+static LOGKEY: &'static str = "QR";
+
+pub fn list(service_name: &str) -> Result<()> {
+    let quarantine = try!(Quarantine::load(service_name));
+    if quarantine.release.is_empty() {
+        outputln!("{} has no failed releases", service_name);
+        return Ok(());
+    }
+    for release in quarantine.release.iter() {
+        let state = match release.quarantined_at {
+            Some(at) => {
+                let at = time::at_utc(time::Timespec::new(at, 0));
+                format!("quarantined since {}", at.rfc3339())
+            }
+            None => String::from("will be retried"),
+        };
+        outputln!("{}: failed {} time(s), {}; last failure: {}",
+                  release.ident,
+                  release.failures,
+                  state,
+                  release.reason);
+    }
+    Ok(())
+}
+
+pub fn clear(service_name: &str, ident: Option<&str>) -> Result<()> {
+    let ident = match ident {
+        Some(ident) => Some(try!(PackageIdent::from_str(ident))),
+        None => None,
+    };
+    let mut cleared = vec![];
+    try!(quarantine::update(service_name, |q| {
+        cleared = q.clear(ident.as_ref());
+        !cleared.is_empty()
+    }));
+    if cleared.is_empty() {
+        outputln!("Nothing to clear for {}", service_name);
+    }
+    for ident in cleared {
+        outputln!("Cleared {} for {}", ident, service_name);
+    }
+    Ok(())
+}
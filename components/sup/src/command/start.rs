@@ -44,6 +44,38 @@
 //!
 //! Will start the `redis` service using the `leader` topology in the `production` group.
 //!
+//! ```bash
+//! $ hab-sup start acme/redis --service-name sessions --listen-gossip 0.0.0.0:9648 \
+//!     --listen-http 0.0.0.0:9641
+//! ```
+//!
+//! Will start another service from the same package, named `sessions` in the `default` group,
+//! with its own directory in `/hab/svc`. Each Supervisor needs ports of its own.
+//!
+//! ```bash
+//! $ hab-sup start acme/shop -g production
+//! ```
+//!
+//! Where `acme/shop` is a composite package, will start each of the services it bundles, in the
+//! groups and with the binds it declares; services without a group of their own run in the
+//! `production` group. Updates to the composite update its services together. A composite which
+//! names its services, as in `pkg_services=(core/redis:cache core/redis:sessions)`, runs the
+//! same package more than once under the one Supervisor.
+//!
+//! ```bash
+//! $ hab-sup start acme/shop --locked habitat.lock --strategy at-once
+//! ```
+//!
+//! Will start the release of `acme/shop` that `habitat.lock` pins, and update the services only
+//! to the releases it pins when the lockfile changes, rather than to the latest in the Depot.
+//!
+//! ```bash
+//! $ hab-sup run --bastion --peer 10.0.0.11 --peer 10.0.0.12
+//! ```
+//!
+//! Will start a bastion, which runs no service and anchors the ring as a permanent peer; see
+//! `manager::bastion`. Rings commonly run three, which list each other as peers.
+//!
 //! See the [documentation on topologies](../topology) for a deeper discussion of how they function.
 //!
 
@@ -51,11 +83,12 @@ use std::env;
 use std::path::Path;
 
 use ansi_term::Colour::Yellow;
-use common::command::package::install;
+use common::command::package::{install, lock};
 use common::ui::UI;
 use depot_client::Client;
-use hcore::fs::{am_i_root, cache_artifact_path, FS_ROOT_PATH};
-use hcore::package::PackageIdent;
+use hcore::fs::{am_i_root, cache_artifact_path};
+use hcore::package::{LockFile, PackageIdent, PackageType};
+use hcore::service::ServiceGroup;
 
 use {PRODUCT, VERSION};
 use error::{Error, Result};
@@ -63,6 +96,8 @@ use config::gconfig;
 use package::Package;
 use manager::Manager;
 use manager::{Service, UpdateStrategy};
+use mirrors;
+use util::privileges;
 
 static LOGKEY: &'static str = "CS";
 
@@ -76,21 +111,31 @@ static LOGKEY: &'static str = "CS";
 /// * Fails if an unknown topology was specified on the command line
 pub fn package() -> Result<()> {
     let mut ui = UI::default();
-    if !am_i_root() {
+    if gconfig().unprivileged() {
+        try!(ui.warn(format!("Running unprivileged under {}; these features are disabled:",
+                             gconfig().fs_root().display())));
+        for feature in privileges::disabled_features() {
+            try!(ui.warn(format!("  * {}", feature)));
+        }
+        try!(ui.br());
+    } else if !am_i_root() {
         try!(ui.warn("Running the Habitat Supervisor requires root or administrator privileges. \
                       Please retry this command as a super user or use a privilege-granting \
-                      facility such as sudo."));
+                      facility such as sudo, or start it with --unprivileged."));
         try!(ui.br());
         return Err(sup_error!(Error::RootRequired));
     }
 
+    if let Some(lock_path) = gconfig().lock_file() {
+        return start_locked(&mut ui, lock_path);
+    }
+
     match Package::load(gconfig().package(), None) {
         Ok(mut package) => {
             let update_strategy = gconfig().update_strategy();
             match update_strategy {
                 UpdateStrategy::None => {}
                 _ => {
-                    let url = gconfig().url();
                     outputln!("Checking Depot for newer versions...");
                     // It is important to pass `gconfig().package()` to `show_package()` instead
                     // of the package identifier of the loaded package. This will ensure that
@@ -100,19 +145,23 @@ pub fn package() -> Result<()> {
                     // If the operator does not specify a version number they will
                     // automatically receive updates for any releases, regardless of version
                     // number, for the started  package.
-                    let depot_client = try!(Client::new(url, PRODUCT, VERSION, None));
-                    let latest_pkg_data = try!(depot_client.show_package(gconfig().package()));
+                    let latest_pkg_data = try!(mirrors::with_failover(|url| {
+                        let depot_client = try!(Client::new(url, PRODUCT, VERSION, None));
+                        Ok(try!(depot_client.show_package(gconfig().package())))
+                    }));
                     let latest_ident: PackageIdent = latest_pkg_data.get_ident().clone().into();
                     if &latest_ident > package.ident() {
                         outputln!("Downloading latest version from Depot: {}", latest_ident);
-                        let new_pkg_data = try!(install::start(&mut ui,
-                                                               url,
-                                                               &latest_ident.to_string(),
-                                                               PRODUCT,
-                                                               VERSION,
-                                                               Path::new(FS_ROOT_PATH),
-                                                               &cache_artifact_path(None),
-                                                               false));
+                        let new_pkg_data = try!(mirrors::with_failover(|url| {
+                            Ok(try!(install::start(&mut ui,
+                                                   url,
+                                                   &latest_ident.to_string(),
+                                                   PRODUCT,
+                                                   VERSION,
+                                                   &gconfig().fs_root(),
+                                                   &cache_artifact_path(None),
+                                                   false)))
+                        }));
                         package = try!(Package::load(&new_pkg_data, None));
                     } else {
                         outputln!("Already running latest.");
@@ -124,49 +173,142 @@ pub fn package() -> Result<()> {
         Err(_) => {
             outputln!("{} is not installed",
                       Yellow.bold().paint(gconfig().package().to_string()));
-            let url = gconfig().url();
-            let new_pkg_data = match gconfig().local_artifact() {
-                Some(artifact) => {
-                    try!(install::start(&mut ui,
-                                        url,
-                                        &artifact,
-                                        PRODUCT,
-                                        VERSION,
-                                        Path::new(FS_ROOT_PATH),
-                                        &cache_artifact_path(None),
-                                        false))
-                }
+            let ident_or_artifact = match gconfig().local_artifact() {
+                Some(artifact) => artifact.to_string(),
                 None => {
                     outputln!("Searching for {} in remote {}",
                               Yellow.bold().paint(gconfig().package().to_string()),
-                              url);
-                    try!(install::start(&mut ui,
-                                        url,
-                                        &gconfig().package().to_string(),
-                                        PRODUCT,
-                                        VERSION,
-                                        Path::new(FS_ROOT_PATH),
-                                        &cache_artifact_path(None),
-                                        false))
+                              gconfig().url());
+                    gconfig().package().to_string()
                 }
             };
+            let new_pkg_data = try!(mirrors::with_failover(|url| {
+                Ok(try!(install::start(&mut ui,
+                                       url,
+                                       &ident_or_artifact,
+                                       PRODUCT,
+                                       VERSION,
+                                       &gconfig().fs_root(),
+                                       &cache_artifact_path(None),
+                                       false)))
+            }));
             let package = try!(Package::load(&new_pkg_data, None));
             start_package(package)
         }
     }
 }
 
-fn start_package(package: Package) -> Result<()> {
+/// Starts the release of the package the lockfile pins, installing it if need be. An artifact is
+/// only started if the lockfile pins it and its dependencies.
+/// Runs a bastion, a Supervisor which loads no service and only gossips.
+pub fn bastion() -> Result<()> {
+    let mut manager = try!(Manager::new());
+    manager.run()
+}
+
+fn start_locked(ui: &mut UI, lock_path: &Path) -> Result<()> {
+    let lock = try!(LockFile::load(lock_path));
+    let (release, ident_or_artifact) = match gconfig().local_artifact() {
+        Some(artifact) => (gconfig().package().clone(), artifact.to_string()),
+        None => {
+            let release = try!(lock.release_for(gconfig().package()));
+            let ident = release.to_string();
+            (release, ident)
+        }
+    };
+    try!(mirrors::with_failover(|url| {
+        Ok(try!(lock::pin_locked(ui,
+                                 url,
+                                 &[&ident_or_artifact],
+                                 &lock,
+                                 PRODUCT,
+                                 VERSION,
+                                 &gconfig().fs_root())))
+    }));
+    let package = match Package::load(&release, None) {
+        Ok(package) => package,
+        Err(_) => {
+            outputln!("{} is not installed", Yellow.bold().paint(release.to_string()));
+            let new_pkg_data = try!(mirrors::with_failover(|url| {
+                Ok(try!(install::start(ui,
+                                       url,
+                                       &ident_or_artifact,
+                                       PRODUCT,
+                                       VERSION,
+                                       &gconfig().fs_root(),
+                                       &cache_artifact_path(None),
+                                       false)))
+            }));
+            try!(Package::load(&new_pkg_data, None))
+        }
+    };
+    start_package(package)
+}
+
+fn start_package(mut package: Package) -> Result<()> {
     let run_path = try!(package.run_path());
     debug!("Setting the PATH to {}", run_path);
     env::set_var("PATH", &run_path);
 
+    let services = match try!(package.pkg_install.pkg_type()) {
+        PackageType::Composite => try!(composite_services(&package)),
+        PackageType::Standalone => {
+            package.pkg_install.svc_name = gconfig().service_name().cloned();
+            package.strict_render = gconfig().strict_render();
+            vec![try!(Service::new(package,
+                                   gconfig().group(),
+                                   gconfig().organization(),
+                                   gconfig().bind(),
+                                   gconfig().topology(),
+                                   gconfig().update_strategy(),
+                                   gconfig().kind(),
+                                   gconfig().schedule().cloned()))]
+        }
+    };
     let mut manager = try!(Manager::new());
-    let service = try!(Service::new(package,
-                                    gconfig().group(),
-                                    gconfig().organization().clone(),
-                                    gconfig().topology(),
-                                    gconfig().update_strategy()));
-    try!(manager.add_service(service));
+    try!(manager.add_services(services));
     manager.run()
 }
+
+/// Creates a service for each of the services of a composite package. Every service is loaded
+/// before any is added to the Supervisor, which adds them all or none, so a composite is either
+/// started whole or not at all.
+fn composite_services(composite: &Package) -> Result<Vec<Service>> {
+    let members = try!(composite.pkg_install.services());
+    let group_of = |name: &str| {
+        members.iter()
+            .find(|m| m.service_name() == name)
+            .and_then(|m| m.group.clone())
+            .unwrap_or(gconfig().group().to_string())
+    };
+    let mut services = Vec::with_capacity(members.len());
+    for member in members.iter() {
+        outputln!("Loading {} as {} from {}",
+                  Yellow.bold().paint(member.ident.to_string()),
+                  member.service_name(),
+                  composite.ident());
+        let mut package = try!(Package::load(&member.ident, None));
+        package.pkg_install.svc_name = member.name.clone();
+        package.composite = Some(composite.ident().clone());
+        package.strict_render = gconfig().strict_render();
+        let binds = member.binds
+            .iter()
+            .map(|&(ref name, ref service)| {
+                format!("{}:{}",
+                        name,
+                        ServiceGroup::format(service,
+                                             group_of(service),
+                                             gconfig().organization()))
+            })
+            .collect();
+        services.push(try!(Service::new(package,
+                                        group_of(member.service_name()),
+                                        gconfig().organization(),
+                                        binds,
+                                        gconfig().topology(),
+                                        gconfig().update_strategy(),
+                                        gconfig().kind(),
+                                        gconfig().schedule().cloned())));
+    }
+    Ok(services)
+}
@@ -0,0 +1,439 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Moves a Supervisor's services to another host.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab-sup state export /tmp/redis.state
+//! ```
+//!
+//! Will ask the Supervisor running on this host which services it runs and how it started them,
+//! and write that to `/tmp/redis.state`, along with each service's applied configuration
+//! (`user.toml` and `gossip.toml`), its service files, and the keys in the key cache. The bundle
+//! contains secret keys, so it is only readable by its owner.
+//!
+//! ```bash
+//! $ hab-sup state import /tmp/redis.state
+//! ```
+//!
+//! Will restore the keys and service directories on a replacement host, install the exact
+//! releases the services ran, and print the `hab-sup start` command which starts them again.
+//! Keys which are already in the cache are left as they are.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use base64;
+use common::command::package::install;
+use common::ui::UI;
+use hcore::crypto::default_cache_key_path;
+use hcore::fs::{self as hfs, am_i_root, cache_artifact_path};
+use hcore::package::{PackageIdent, PackageInstall};
+use hcore::service::ServiceGroup;
+use hcore::util::perm::set_permissions;
+use hyper::status::StatusCode;
+use serde_json;
+use sup_client::Client;
+
+use {PRODUCT, VERSION};
+use config::gconfig;
+use error::{Error, Result};
+use manager::service::{ServiceKind, Topology, UpdateStrategy};
+use util;
+
+static LOGKEY: &'static str = "ST";
+
+/// The version of the bundle format written by `export`.
+const BUNDLE_VERSION: u64 = 1;
+/// The files of a service's directory which are carried over, besides its service files.
+const SVC_CONFIG_FILES: &'static [&'static str] = &["user.toml", "gossip.toml"];
+/// The service files directory, relative to a service's directory.
+const SVC_FILES_DIR: &'static str = "files";
+
+/// Everything needed to start a Supervisor's services again on another host.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Bundle {
+    pub version: u64,
+    pub start: StartSpec,
+    /// Each service's files, by service name and then by path relative to its directory in
+    /// `/hab/svc`, base64 encoded.
+    pub files: BTreeMap<String, BTreeMap<String, String>>,
+    /// The key cache, by file name, base64 encoded.
+    pub keys: BTreeMap<String, String>,
+    /// The packages the services ran, which are installed before they start again.
+    pub packages: Vec<PackageIdent>,
+}
+
+/// How the Supervisor was started; what `hab-sup start` needs to start the same services.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StartSpec {
+    /// The fully qualified package, or composite package, which was started.
+    pub ident: PackageIdent,
+    pub group: String,
+    pub organization: Option<String>,
+    pub service_name: Option<String>,
+    pub binds: Vec<String>,
+    pub topology: Topology,
+    pub update_strategy: UpdateStrategy,
+    pub kind: ServiceKind,
+    pub schedule: Option<String>,
+}
+
+impl StartSpec {
+    /// The arguments to `hab-sup start` which start the same services.
+    pub fn args(&self) -> Vec<String> {
+        let mut args = vec!["start".to_string(), self.ident.to_string()];
+        {
+            let mut opt = |name: &str, value: &str| {
+                args.push(format!("--{}", name));
+                args.push(value.to_string());
+            };
+            opt("group", &self.group);
+            if let Some(ref org) = self.organization {
+                opt("org", org);
+            }
+            if let Some(ref name) = self.service_name {
+                opt("service-name", name);
+            }
+            match self.topology {
+                Topology::Standalone => {}
+                Topology::Leader => opt("topology", "leader"),
+                Topology::Initializer => opt("topology", "initializer"),
+            }
+            match self.update_strategy {
+                UpdateStrategy::None => {}
+                UpdateStrategy::AtOnce => opt("strategy", "at-once"),
+                UpdateStrategy::Rolling => opt("strategy", "rolling"),
+            }
+            if self.kind == ServiceKind::Oneshot {
+                opt("kind", "oneshot");
+            }
+            if let Some(ref schedule) = self.schedule {
+                opt("schedule", schedule);
+            }
+            for bind in self.binds.iter() {
+                opt("bind", bind);
+            }
+        }
+        args
+    }
+}
+
+/// A service as the HTTP gateway reports it.
+#[derive(Deserialize)]
+struct RunningService {
+    package: RunningPackage,
+    service_group: ServiceGroup,
+    binds: Vec<String>,
+    topology: Topology,
+    update_strategy: UpdateStrategy,
+    kind: ServiceKind,
+    schedule: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct RunningPackage {
+    origin: String,
+    name: String,
+    version: String,
+    release: String,
+    #[serde(default)]
+    composite: Option<PackageIdent>,
+}
+
+impl RunningPackage {
+    fn ident(&self) -> PackageIdent {
+        PackageIdent::new(self.origin.clone(),
+                          self.name.clone(),
+                          Some(self.version.clone()),
+                          Some(self.release.clone()))
+    }
+}
+
+/// Write the state of the Supervisor whose HTTP gateway listens on `sup_addr` to `path`.
+pub fn export(sup_addr: &str, path: &Path) -> Result<()> {
+    let mut ui = UI::default();
+    if !am_i_root() {
+        try!(ui.warn("Exporting the Supervisor's state reads its secret keys, which requires \
+                      root or administrator privileges."));
+        return Err(sup_error!(Error::RootRequired));
+    }
+    let services = try!(running_services(sup_addr));
+    let start = try!(start_spec(&services));
+    let mut files = BTreeMap::new();
+    for service in services.iter() {
+        let name = service.service_group.service();
+        files.insert(name.to_string(), try!(svc_files(name)));
+    }
+    let mut packages: Vec<PackageIdent> = services.iter().map(|s| s.package.ident()).collect();
+    if let Some(composite) = services.iter().filter_map(|s| s.package.composite.clone()).next() {
+        packages.push(composite);
+    }
+    let bundle = Bundle {
+        version: BUNDLE_VERSION,
+        start: start,
+        files: files,
+        keys: try!(cache_keys(&default_cache_key_path(None))),
+        packages: packages,
+    };
+    let json = try!(serde_json::to_string_pretty(&bundle)
+        .map_err(|e| sup_error!(Error::InvalidStateBundle(e.to_string()))));
+    {
+        let mut file = try!(util::create_private(path, 0o600));
+        try!(file.write_all(json.as_bytes()));
+    }
+    outputln!("Exported {} services, {} keys to {}",
+              bundle.files.len(),
+              bundle.keys.len(),
+              path.display());
+    try!(ui.warn(format!("{} contains secret keys; keep it safe and remove it once imported",
+                         path.display())));
+    Ok(())
+}
+
+/// Restore the state in the bundle at `path`, installing its packages from the depot at `url`.
+pub fn import(url: &str, path: &Path) -> Result<()> {
+    let mut ui = UI::default();
+    if !am_i_root() {
+        try!(ui.warn("Importing the Supervisor's state writes to its key cache and service \
+                      directories, which requires root or administrator privileges."));
+        return Err(sup_error!(Error::RootRequired));
+    }
+    let mut json = String::new();
+    try!(try!(File::open(path)).read_to_string(&mut json));
+    let bundle: Bundle = try!(serde_json::from_str(&json)
+        .map_err(|e| sup_error!(Error::InvalidStateBundle(e.to_string()))));
+    if bundle.version != BUNDLE_VERSION {
+        let msg = format!("unsupported version {}", bundle.version);
+        return Err(sup_error!(Error::InvalidStateBundle(msg)));
+    }
+
+    let key_path = default_cache_key_path(None);
+    try!(fs::create_dir_all(&key_path));
+    for (name, content) in bundle.keys.iter() {
+        let dst = try!(bundle_path(&key_path, name));
+        if dst.exists() {
+            outputln!("Key {} is already in the cache, skipping", name);
+            continue;
+        }
+        try!(write_file(&dst, &try!(decode(name, content)), 0o600));
+    }
+    for (service, files) in bundle.files.iter() {
+        if service.is_empty() || service.contains(|c| c == '.' || c == '/' || c == '\\') {
+            return Err(sup_error!(Error::InvalidStateBundle(format!("bad service name {}",
+                                                                    service))));
+        }
+        let svc_path = hfs::svc_path(service);
+        try!(fs::create_dir_all(svc_path.join(SVC_FILES_DIR)));
+        for (name, content) in files.iter() {
+            let dst = try!(bundle_path(&svc_path, name));
+            try!(write_file(&dst, &try!(decode(name, content)), 0o640));
+        }
+        outputln!("Restored {} files of {}", files.len(), service);
+    }
+    for ident in bundle.packages.iter() {
+        try!(install::start(&mut ui,
+                            url,
+                            &ident.to_string(),
+                            PRODUCT,
+                            VERSION,
+                            &gconfig().fs_root(),
+                            &cache_artifact_path(None),
+                            false));
+    }
+    outputln!("Imported {}. Start its services with:", path.display());
+    println!("    hab-sup {}", shell_words(&bundle.start.args()).join(" "));
+    Ok(())
+}
+
+fn running_services(sup_addr: &str) -> Result<Vec<RunningService>> {
+    let client = try!(Client::new(sup_addr, PRODUCT, VERSION));
+    let services: Vec<RunningService> =
+        try!(client.get_json("services", &[], &[StatusCode::Ok]));
+    if services.is_empty() {
+        let msg = String::from("the Supervisor is not running any services");
+        return Err(sup_error!(Error::SupervisorRequestFailed(msg)));
+    }
+    Ok(services)
+}
+
+/// Work out how the Supervisor was started from the services it runs. A Supervisor runs either
+/// one standalone package or the services of one composite; for a composite, the group it was
+/// started in is the group of any of its services which doesn't declare one of its own.
+fn start_spec(services: &[RunningService]) -> Result<StartSpec> {
+    let first = &services[0];
+    let sg = &first.service_group;
+    let mut spec = StartSpec {
+        ident: first.package.ident(),
+        group: sg.group().to_string(),
+        organization: sg.org().map(|org| org.to_string()),
+        service_name: None,
+        binds: first.binds.clone(),
+        topology: first.topology,
+        update_strategy: first.update_strategy,
+        kind: first.kind,
+        schedule: first.schedule.clone(),
+    };
+    match first.package.composite {
+        Some(ref composite) => {
+            let members = try!(try!(PackageInstall::load(composite, None)).services());
+            let group = members.iter()
+                .filter(|m| m.group.is_none())
+                .filter_map(|m| {
+                    services.iter().find(|s| s.service_group.service() == m.service_name())
+                })
+                .map(|s| s.service_group.group().to_string())
+                .next();
+            spec.ident = composite.clone();
+            spec.group = group.unwrap_or(String::from("default"));
+            spec.binds = Vec::new();
+        }
+        None => {
+            if sg.service() != first.package.name {
+                spec.service_name = Some(sg.service().to_string());
+            }
+        }
+    }
+    Ok(spec)
+}
+
+/// The applied configuration and service files of a service, relative to its directory.
+fn svc_files(service: &str) -> Result<BTreeMap<String, String>> {
+    let svc_path = hfs::svc_path(service);
+    let mut files = BTreeMap::new();
+    for name in SVC_CONFIG_FILES {
+        let path = svc_path.join(name);
+        if path.is_file() {
+            files.insert(name.to_string(), try!(encode(&path)));
+        }
+    }
+    let files_path = svc_path.join(SVC_FILES_DIR);
+    if files_path.is_dir() {
+        for entry in try!(fs::read_dir(&files_path)) {
+            let entry = try!(entry);
+            if !try!(entry.file_type()).is_file() {
+                continue;
+            }
+            let name = entry.file_name().to_string_lossy().into_owned();
+            files.insert(format!("{}/{}", SVC_FILES_DIR, name),
+                         try!(encode(&entry.path())));
+        }
+    }
+    Ok(files)
+}
+
+/// The keys in the key cache. Origin signing keys are left out; they sign packages, which a
+/// Supervisor never does, and don't belong on the hosts it runs on.
+fn cache_keys(key_path: &Path) -> Result<BTreeMap<String, String>> {
+    let mut keys = BTreeMap::new();
+    if !key_path.is_dir() {
+        return Ok(keys);
+    }
+    for entry in try!(fs::read_dir(key_path)) {
+        let entry = try!(entry);
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if !try!(entry.file_type()).is_file() || name.ends_with(".sig.key") {
+            continue;
+        }
+        keys.insert(name, try!(encode(&entry.path())));
+    }
+    Ok(keys)
+}
+
+fn encode(path: &Path) -> Result<String> {
+    let mut content = Vec::new();
+    try!(try!(File::open(path)).read_to_end(&mut content));
+    Ok(base64::encode(&content))
+}
+
+fn decode(name: &str, content: &str) -> Result<Vec<u8>> {
+    base64::decode(content)
+        .map_err(|e| sup_error!(Error::InvalidStateBundle(format!("{}: {}", name, e))))
+}
+
+/// The path a file named in the bundle is restored to, which must stay within `root`.
+fn bundle_path(root: &Path, name: &str) -> Result<PathBuf> {
+    let escapes = name.is_empty() || name.starts_with('/') || name.contains('\\') ||
+                  name.split('/').any(|part| part.is_empty() || part == "." || part == "..");
+    if escapes {
+        return Err(sup_error!(Error::InvalidStateBundle(format!("bad file name {}", name))));
+    }
+    Ok(root.join(name))
+}
+
+/// Writes a restored file, which only ever has `mode`'s permissions. A file already there is
+/// replaced rather than written through.
+fn write_file(path: &Path, content: &[u8], mode: u32) -> Result<()> {
+    if fs::symlink_metadata(path).is_ok() {
+        try!(fs::remove_file(path));
+    }
+    let mut file = try!(util::create_private(path, mode));
+    try!(set_permissions(path, mode));
+    try!(file.write_all(content));
+    Ok(())
+}
+
+/// Quote the arguments which a shell would split or expand.
+fn shell_words(args: &[String]) -> Vec<String> {
+    args.iter()
+        .map(|arg| if arg.chars().all(|c| c.is_alphanumeric() || "-_./:@=".contains(c)) {
+            arg.clone()
+        } else {
+            format!("'{}'", arg.replace('\'', "'\\''"))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+
+    use manager::service::{ServiceKind, Topology, UpdateStrategy};
+    use super::{bundle_path, shell_words, StartSpec};
+
+    #[test]
+    fn start_spec_args() {
+        let spec = StartSpec {
+            ident: PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap(),
+            group: String::from("prod"),
+            organization: None,
+            service_name: Some(String::from("sessions")),
+            binds: vec![String::from("backend:redis.prod")],
+            topology: Topology::Leader,
+            update_strategy: UpdateStrategy::None,
+            kind: ServiceKind::Oneshot,
+            schedule: Some(String::from("0 3 * * *")),
+        };
+        assert_eq!(shell_words(&spec.args()).join(" "),
+                   "start core/redis/3.2.4/20170514150022 --group prod --service-name sessions \
+                    --topology leader --kind oneshot --schedule '0 3 * * *' \
+                    --bind backend:redis.prod");
+    }
+
+    #[test]
+    fn bundle_paths_stay_within_root() {
+        let root = Path::new("/hab/svc/redis");
+        assert_eq!(bundle_path(root, "files/ca.pem").unwrap(), root.join("files/ca.pem"));
+        assert!(bundle_path(root, "../nginx/user.toml").is_err());
+        assert!(bundle_path(root, "/etc/passwd").is_err());
+        assert!(bundle_path(root, "files//x").is_err());
+    }
+}
@@ -20,19 +20,26 @@
 //!
 //! See the [Config](struct.Config.html) struct for the specific options available.
 
+use std::collections::BTreeMap;
 use std::io;
 use std::mem;
 use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs, SocketAddr, SocketAddrV4};
 use std::ops::{Deref, DerefMut};
 use std::option;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::{Once, ONCE_INIT};
 
+use butterfly::message::compression::Compression;
+use hcore::fs as hfs;
 use hcore::package::PackageIdent;
 
 use error::{Error, Result, SupError};
 use http_gateway;
-use manager::service::{Topology, UpdateStrategy};
+use manager::quarantine;
+use manager::schedule::Schedule;
+use manager::service::{PortMapping, ServiceKind, Topology, UpdateStrategy};
+use mirrors::Mirror;
 
 static LOGKEY: &'static str = "CFG";
 
@@ -66,6 +73,11 @@ pub enum Command {
     Start,
     ShellBash,
     ShellSh,
+    LogLevel,
+    StateExport,
+    StateImport,
+    QuarantineList,
+    QuarantineClear,
 }
 
 #[derive(PartialEq, Eq, Debug)]
@@ -126,6 +138,11 @@ impl FromStr for Command {
             "bash" => Ok(Command::ShellBash),
             "sh" => Ok(Command::ShellSh),
             "start" => Ok(Command::Start),
+            "log-level" => Ok(Command::LogLevel),
+            "state-export" => Ok(Command::StateExport),
+            "state-import" => Ok(Command::StateImport),
+            "quarantine-list" => Ok(Command::QuarantineList),
+            "quarantine-clear" => Ok(Command::QuarantineClear),
             _ => Err(sup_error!(Error::CommandNotImplemented)),
         }
     }
@@ -142,20 +159,64 @@ impl Default for Command {
 #[derive(Default, Debug, PartialEq, Eq)]
 pub struct Config {
     pub http_listen_addr: http_gateway::ListenAddr,
+    grpc_listen_addr: Option<SocketAddr>,
     pub gossip_listen: GossipListenAddr,
+    gossip_advertise: Option<SocketAddr>,
     command: Command,
     package: PackageIdent,
     local_artifact: Option<String>,
+    lock_file: Option<PathBuf>,
     url: String,
+    mirrors: Vec<Mirror>,
     topology: Topology,
     group: String,
+    service_name: Option<String>,
     bind: Vec<String>,
     gossip_peer: Vec<String>,
     gossip_permanent: bool,
+    gossip_discover: bool,
+    bastion: bool,
+    peer_provider: Vec<String>,
+    peer_watch_file: Option<PathBuf>,
+    kubernetes: bool,
+    envoy_xds_dir: Option<PathBuf>,
+    vault_addr: Option<String>,
+    aws_config_path: Option<String>,
+    aws_config_interval: Option<u64>,
+    bootstrap_expect: Option<usize>,
+    labels: BTreeMap<String, String>,
+    redact: Vec<String>,
+    strict_render: bool,
+    http_threads: Option<usize>,
+    http_disable: Vec<String>,
+    http_local_only: Vec<String>,
+    http_cors_origins: Vec<String>,
+    http_tls_cert_file: Option<PathBuf>,
+    http_tls_key_file: Option<PathBuf>,
+    gateway_auth_file: Option<PathBuf>,
+    http_max_request_bytes: Option<u64>,
+    http_request_timeout: Option<u64>,
+    gossip_threads: Option<usize>,
+    gossip_compression: Option<Compression>,
+    gossip_departed_ttl: Option<usize>,
+    departed_ttl: Option<u64>,
+    gossip_alias: Option<String>,
+    hook_threads: Option<usize>,
+    reconfigure_debounce: u64,
     update_strategy: UpdateStrategy,
+    update_concurrency: Option<usize>,
+    update_attempts: Option<u32>,
+    kind: ServiceKind,
+    schedule: Option<Schedule>,
+    isolate_network: bool,
+    ports: Vec<PortMapping>,
     organization: Option<String>,
     ring: Option<String>,
+    ring_exec_origin: Vec<String>,
+    ring_key_file: Option<PathBuf>,
     config_from: Option<String>,
+    fs_root: Option<PathBuf>,
+    unprivileged: bool,
 }
 
 impl Config {
@@ -185,6 +246,72 @@ impl Config {
         self.update_strategy
     }
 
+    /// Set the most members of the service group that may update at once
+    pub fn set_update_concurrency(&mut self, count: usize) -> &mut Config {
+        self.update_concurrency = Some(count);
+        self
+    }
+
+    /// Return the most members of the service group that may update at once, if it was set
+    pub fn update_concurrency(&self) -> Option<usize> {
+        self.update_concurrency
+    }
+
+    /// Set how many times updating to a release may fail before the release is quarantined
+    pub fn set_update_attempts(&mut self, count: u32) -> &mut Config {
+        self.update_attempts = Some(count);
+        self
+    }
+
+    /// Return how many times updating to a release may fail before the release is quarantined
+    pub fn update_attempts(&self) -> u32 {
+        self.update_attempts.unwrap_or(quarantine::DEFAULT_UPDATE_ATTEMPTS)
+    }
+
+    /// Set the kind of service
+    pub fn set_kind(&mut self, kind: ServiceKind) -> &mut Config {
+        self.kind = kind;
+        self
+    }
+
+    /// Return the kind of service
+    pub fn kind(&self) -> ServiceKind {
+        self.kind
+    }
+
+    /// Set the schedule a oneshot service runs on
+    pub fn set_schedule(&mut self, schedule: Option<Schedule>) -> &mut Config {
+        self.schedule = schedule;
+        self
+    }
+
+    /// Return the schedule a oneshot service runs on
+    pub fn schedule(&self) -> Option<&Schedule> {
+        self.schedule.as_ref()
+    }
+
+    /// Set whether the service runs in a network namespace of its own
+    pub fn set_isolate_network(&mut self, isolate: bool) -> &mut Config {
+        self.isolate_network = isolate;
+        self
+    }
+
+    /// Return whether the service runs in a network namespace of its own
+    pub fn isolate_network(&self) -> bool {
+        self.isolate_network
+    }
+
+    /// Set the host ports forwarded to an isolated service
+    pub fn set_ports(&mut self, ports: Vec<PortMapping>) -> &mut Config {
+        self.ports = ports;
+        self
+    }
+
+    /// Return the host ports forwarded to an isolated service
+    pub fn ports(&self) -> &[PortMapping] {
+        &self.ports
+    }
+
     /// Set the `Command` we used
     pub fn set_command(&mut self, command: Command) -> &mut Config {
         self.command = command;
@@ -207,6 +334,17 @@ impl Config {
         &self.group
     }
 
+    /// Set the name to run the service under, in place of the package's name
+    pub fn set_service_name(&mut self, name: String) -> &mut Config {
+        self.service_name = Some(name);
+        self
+    }
+
+    /// Return the name to run the service under, if not the package's name
+    pub fn service_name(&self) -> Option<&String> {
+        self.service_name.as_ref()
+    }
+
     /// Set the bindings
     pub fn set_bind(&mut self, bind: Vec<String>) -> &mut Config {
         self.bind = bind;
@@ -229,6 +367,27 @@ impl Config {
         &self.url
     }
 
+    /// Set the mirrors of the Depot at the url
+    pub fn set_mirrors(&mut self, mirrors: Vec<Mirror>) -> &mut Config {
+        self.mirrors = mirrors;
+        self
+    }
+
+    /// Return the mirrors of the Depot at the url
+    pub fn mirrors(&self) -> &[Mirror] {
+        &self.mirrors
+    }
+
+    /// Return every Depot packages may be installed from, in the order they are tried: the url
+    /// first, then its mirrors by priority
+    pub fn depots(&self) -> Vec<Mirror> {
+        let mut depots = vec![Mirror::new(self.url.clone(), 0)];
+        depots.extend(self.mirrors.iter().cloned());
+        depots.sort_by_key(|d| d.priority);
+        depots.retain(|d| !d.url.is_empty());
+        depots
+    }
+
     /// Set the topology
     pub fn set_topology(&mut self, topology: Topology) -> &mut Config {
         self.topology = topology;
@@ -249,6 +408,17 @@ impl Config {
         self
     }
 
+    /// Return the address advertised to other members, if it differs from the listen address
+    pub fn gossip_advertise(&self) -> Option<&SocketAddr> {
+        self.gossip_advertise.as_ref()
+    }
+
+    /// Set the address advertised to other members
+    pub fn set_gossip_advertise(&mut self, addr: Option<SocketAddr>) -> &mut Config {
+        self.gossip_advertise = addr;
+        self
+    }
+
     pub fn http_listen_addr(&self) -> &SocketAddr {
         &self.http_listen_addr
     }
@@ -263,6 +433,17 @@ impl Config {
         self
     }
 
+    /// Return the address the gRPC control service listens on, if it should be served
+    pub fn grpc_listen_addr(&self) -> Option<&SocketAddr> {
+        self.grpc_listen_addr.as_ref()
+    }
+
+    /// Set the address the gRPC control service listens on
+    pub fn set_grpc_listen_addr(&mut self, addr: Option<SocketAddr>) -> &mut Config {
+        self.grpc_listen_addr = addr;
+        self
+    }
+
     pub fn gossip_permanent(&self) -> bool {
         self.gossip_permanent
     }
@@ -272,6 +453,325 @@ impl Config {
         self
     }
 
+    /// Return whether this Supervisor is a bastion, which runs no service and anchors the ring
+    pub fn bastion(&self) -> bool {
+        self.bastion
+    }
+
+    /// Set whether this Supervisor is a bastion
+    pub fn set_bastion(&mut self, bastion: bool) -> &mut Config {
+        self.bastion = bastion;
+        self
+    }
+
+    /// Return whether peers on the local network are discovered over mDNS
+    pub fn gossip_discover(&self) -> bool {
+        self.gossip_discover
+    }
+
+    /// Set whether peers on the local network are discovered over mDNS
+    pub fn set_gossip_discover(&mut self, discover: bool) -> &mut Config {
+        self.gossip_discover = discover;
+        self
+    }
+
+    /// Return the cloud peer provider configurations used to find initial peers
+    pub fn peer_provider(&self) -> &[String] {
+        &self.peer_provider
+    }
+
+    /// Set the cloud peer provider configurations used to find initial peers
+    pub fn set_peer_provider(&mut self, providers: Vec<String>) -> &mut Config {
+        self.peer_provider = providers;
+        self
+    }
+
+    /// Return the file of peer addresses which is watched for changes
+    pub fn peer_watch_file(&self) -> Option<&Path> {
+        self.peer_watch_file.as_ref().map(|p| p.as_path())
+    }
+
+    /// Set the file of peer addresses which is watched for changes
+    pub fn set_peer_watch_file(&mut self, path: Option<PathBuf>) -> &mut Config {
+        self.peer_watch_file = path;
+        self
+    }
+
+    /// Return whether census summaries are written to, and desired state read from, the
+    /// Kubernetes cluster the Supervisor runs in
+    pub fn kubernetes(&self) -> bool {
+        self.kubernetes
+    }
+
+    /// Set whether the Supervisor bridges its ring with the Kubernetes cluster it runs in
+    pub fn set_kubernetes(&mut self, kubernetes: bool) -> &mut Config {
+        self.kubernetes = kubernetes;
+        self
+    }
+
+    /// Return the directory Envoy's cluster and endpoint discovery files are written to
+    pub fn envoy_xds_dir(&self) -> Option<&Path> {
+        self.envoy_xds_dir.as_ref().map(|p| p.as_path())
+    }
+
+    /// Set the directory Envoy's cluster and endpoint discovery files are written to
+    pub fn set_envoy_xds_dir(&mut self, dir: Option<PathBuf>) -> &mut Config {
+        self.envoy_xds_dir = dir;
+        self
+    }
+
+    /// Return the address of the Vault server templates read secrets from
+    pub fn vault_addr(&self) -> Option<&str> {
+        self.vault_addr.as_ref().map(|a| a.as_str())
+    }
+
+    /// Set the address of the Vault server templates read secrets from
+    pub fn set_vault_addr(&mut self, addr: Option<String>) -> &mut Config {
+        self.vault_addr = addr;
+        self
+    }
+
+    /// Return the path in AWS Parameter Store and Secrets Manager service configuration is read
+    /// from
+    pub fn aws_config_path(&self) -> Option<&str> {
+        self.aws_config_path.as_ref().map(|p| p.as_str())
+    }
+
+    /// Set the path in AWS Parameter Store and Secrets Manager service configuration is read from
+    pub fn set_aws_config_path(&mut self, path: Option<String>) -> &mut Config {
+        self.aws_config_path = path;
+        self
+    }
+
+    /// Return how often, in seconds, service configuration is read from AWS
+    pub fn aws_config_interval(&self) -> Option<u64> {
+        self.aws_config_interval
+    }
+
+    /// Set how often, in seconds, service configuration is read from AWS
+    pub fn set_aws_config_interval(&mut self, secs: u64) -> &mut Config {
+        self.aws_config_interval = Some(secs);
+        self
+    }
+
+    /// Return how many members must be alive before elections start
+    pub fn bootstrap_expect(&self) -> Option<usize> {
+        self.bootstrap_expect
+    }
+
+    /// Set how many members must be alive before elections start
+    pub fn set_bootstrap_expect(&mut self, count: Option<usize>) -> &mut Config {
+        self.bootstrap_expect = count;
+        self
+    }
+
+    /// Return the key-value tags gossiped with this Supervisor's services
+    pub fn labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+
+    /// Set the key-value tags gossiped with this Supervisor's services
+    pub fn set_labels(&mut self, labels: BTreeMap<String, String>) -> &mut Config {
+        self.labels = labels;
+        self
+    }
+
+    /// Return the patterns redacted from the output of services and hooks
+    pub fn redact(&self) -> &[String] {
+        &self.redact
+    }
+
+    /// Set the patterns redacted from the output of services and hooks
+    pub fn set_redact(&mut self, patterns: Vec<String>) -> &mut Config {
+        self.redact = patterns;
+        self
+    }
+
+    /// Return whether templates fail to render when they reference missing data
+    pub fn strict_render(&self) -> bool {
+        self.strict_render
+    }
+
+    /// Set whether templates fail to render when they reference missing data
+    pub fn set_strict_render(&mut self, strict: bool) -> &mut Config {
+        self.strict_render = strict;
+        self
+    }
+
+    /// Return the number of threads serving HTTP gateway requests, if it was set
+    pub fn http_threads(&self) -> Option<usize> {
+        self.http_threads
+    }
+
+    /// Set the number of threads serving HTTP gateway requests
+    pub fn set_http_threads(&mut self, threads: usize) -> &mut Config {
+        self.http_threads = Some(threads);
+        self
+    }
+
+    /// Return the gateway endpoints which are not served at all
+    pub fn http_disable(&self) -> &[String] {
+        &self.http_disable
+    }
+
+    /// Set the gateway endpoints which are not served at all
+    pub fn set_http_disable(&mut self, endpoints: Vec<String>) -> &mut Config {
+        self.http_disable = endpoints;
+        self
+    }
+
+    /// Return the gateway endpoints which are only served to clients on the loopback interface
+    pub fn http_local_only(&self) -> &[String] {
+        &self.http_local_only
+    }
+
+    /// Set the gateway endpoints which are only served to clients on the loopback interface
+    pub fn set_http_local_only(&mut self, endpoints: Vec<String>) -> &mut Config {
+        self.http_local_only = endpoints;
+        self
+    }
+
+    /// Return the origins which browsers may make cross-origin gateway requests from
+    pub fn http_cors_origins(&self) -> &[String] {
+        &self.http_cors_origins
+    }
+
+    /// Set the origins which browsers may make cross-origin gateway requests from
+    pub fn set_http_cors_origins(&mut self, origins: Vec<String>) -> &mut Config {
+        self.http_cors_origins = origins;
+        self
+    }
+
+    /// Return the certificate and key files the HTTP API serves TLS with, which are watched for
+    /// changes
+    pub fn http_tls_files(&self) -> Option<(&Path, &Path)> {
+        match (self.http_tls_cert_file.as_ref(), self.http_tls_key_file.as_ref()) {
+            (Some(cert), Some(key)) => Some((cert.as_path(), key.as_path())),
+            _ => None,
+        }
+    }
+
+    /// Set the certificate and key files the HTTP API serves TLS with
+    pub fn set_http_tls_files(&mut self, cert: PathBuf, key: PathBuf) -> &mut Config {
+        self.http_tls_cert_file = Some(cert);
+        self.http_tls_key_file = Some(key);
+        self
+    }
+
+    /// Return the file holding the token clients of the gateways must present, which is watched
+    /// for changes
+    pub fn gateway_auth_file(&self) -> Option<&Path> {
+        self.gateway_auth_file.as_ref().map(|p| p.as_path())
+    }
+
+    /// Set the file holding the token clients of the gateways must present
+    pub fn set_gateway_auth_file(&mut self, path: Option<PathBuf>) -> &mut Config {
+        self.gateway_auth_file = path;
+        self
+    }
+
+    /// Return the largest gateway request accepted, in bytes, if it was set
+    pub fn http_max_request_bytes(&self) -> Option<u64> {
+        self.http_max_request_bytes
+    }
+
+    /// Set the largest gateway request accepted, in bytes
+    pub fn set_http_max_request_bytes(&mut self, bytes: u64) -> &mut Config {
+        self.http_max_request_bytes = Some(bytes);
+        self
+    }
+
+    /// Return how long the gateway waits on a client's connection, in seconds, if it was set
+    pub fn http_request_timeout(&self) -> Option<u64> {
+        self.http_request_timeout
+    }
+
+    /// Set how long the gateway waits on a client's connection, in seconds
+    pub fn set_http_request_timeout(&mut self, seconds: u64) -> &mut Config {
+        self.http_request_timeout = Some(seconds);
+        self
+    }
+
+    /// Return the number of threads sending gossip to other members, if it was set
+    pub fn gossip_threads(&self) -> Option<usize> {
+        self.gossip_threads
+    }
+
+    /// Set the number of threads sending gossip to other members
+    pub fn set_gossip_threads(&mut self, threads: usize) -> &mut Config {
+        self.gossip_threads = Some(threads);
+        self
+    }
+
+    /// Return the codec gossip is compressed with, for members which support it
+    pub fn gossip_compression(&self) -> Option<Compression> {
+        self.gossip_compression
+    }
+
+    /// Set the codec gossip is compressed with; `None` sends it uncompressed
+    pub fn set_gossip_compression(&mut self, compression: Option<Compression>) -> &mut Config {
+        self.gossip_compression = compression;
+        self
+    }
+
+    /// Return how many seconds departed members are remembered for, if it was set
+    pub fn gossip_departed_ttl(&self) -> Option<usize> {
+        self.gossip_departed_ttl
+    }
+
+    /// Set how many seconds departed members are remembered for
+    pub fn set_gossip_departed_ttl(&mut self, secs: usize) -> &mut Config {
+        self.gossip_departed_ttl = Some(secs);
+        self
+    }
+
+    /// Return how many seconds members of the service group are remembered for once they depart,
+    /// if it was set
+    pub fn departed_ttl(&self) -> Option<u64> {
+        self.departed_ttl
+    }
+
+    /// Set how many seconds members of the service group are remembered for once they depart
+    pub fn set_departed_ttl(&mut self, secs: u64) -> &mut Config {
+        self.departed_ttl = Some(secs);
+        self
+    }
+
+    /// Return the name this member is gossiped under alongside its id, if it was set
+    pub fn gossip_alias(&self) -> Option<&str> {
+        self.gossip_alias.as_ref().map(|a| a.as_str())
+    }
+
+    /// Set the name this member is gossiped under alongside its id
+    pub fn set_gossip_alias(&mut self, alias: String) -> &mut Config {
+        self.gossip_alias = Some(alias);
+        self
+    }
+
+    /// Return the maximum number of scheduled hooks which may run at once, if it was set
+    pub fn hook_threads(&self) -> Option<usize> {
+        self.hook_threads
+    }
+
+    /// Set the maximum number of scheduled hooks which may run at once
+    pub fn set_hook_threads(&mut self, threads: usize) -> &mut Config {
+        self.hook_threads = Some(threads);
+        self
+    }
+
+    /// Return the number of seconds the census must be quiet before a change to it reconfigures
+    /// services
+    pub fn reconfigure_debounce(&self) -> u64 {
+        self.reconfigure_debounce
+    }
+
+    /// Set the number of seconds the census must be quiet before a change to it reconfigures
+    /// services
+    pub fn set_reconfigure_debounce(&mut self, seconds: u64) -> &mut Config {
+        self.reconfigure_debounce = seconds;
+        self
+    }
+
     pub fn gossip_peer(&self) -> &[String] {
         &self.gossip_peer
     }
@@ -304,6 +804,38 @@ impl Config {
         self.local_artifact.as_ref().map(String::as_ref)
     }
 
+    /// Set the lockfile which pins the releases the service runs
+    pub fn set_lock_file(&mut self, path: PathBuf) -> &mut Config {
+        self.lock_file = Some(path);
+        self
+    }
+
+    /// Return the lockfile which pins the releases the service runs
+    pub fn lock_file(&self) -> Option<&PathBuf> {
+        self.lock_file.as_ref()
+    }
+
+    pub fn set_fs_root(&mut self, path: PathBuf) -> &mut Config {
+        self.fs_root = Some(path);
+        self
+    }
+
+    /// Return the filesystem root the `hab` directory lives under
+    pub fn fs_root(&self) -> PathBuf {
+        self.fs_root.clone().unwrap_or_else(hfs::fs_root_path)
+    }
+
+    /// Set whether the Supervisor runs entirely as the user who started it
+    pub fn set_unprivileged(&mut self, unprivileged: bool) -> &mut Config {
+        self.unprivileged = unprivileged;
+        self
+    }
+
+    /// Return whether the Supervisor runs entirely as the user who started it
+    pub fn unprivileged(&self) -> bool {
+        self.unprivileged
+    }
+
     pub fn set_organization(&mut self, org: String) -> &mut Config {
         self.organization = Some(org);
         self
@@ -323,11 +855,36 @@ impl Config {
     pub fn ring(&self) -> Option<&str> {
         self.ring.as_ref().map(|v| &**v)
     }
+
+    /// Return the origins whose signed ring commands are run
+    pub fn ring_exec_origin(&self) -> &[String] {
+        &self.ring_exec_origin
+    }
+
+    /// Set the origins whose signed ring commands are run
+    pub fn set_ring_exec_origin(&mut self, origins: Vec<String>) -> &mut Config {
+        self.ring_exec_origin = origins;
+        self
+    }
+
+    /// Return the file the ring key was read from, which is watched for changes
+    pub fn ring_key_file(&self) -> Option<&Path> {
+        self.ring_key_file.as_ref().map(|p| p.as_path())
+    }
+
+    /// Set the file the ring key was read from
+    pub fn set_ring_key_file(&mut self, path: Option<PathBuf>) -> &mut Config {
+        self.ring_key_file = path;
+        self
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
     use manager::service::Topology;
+    use mirrors::Mirror;
     use super::{Config, Command};
 
     #[test]
@@ -350,10 +907,36 @@ mod tests {
         assert_eq!(c.url(), "http://foolio.com");
     }
 
+    #[test]
+    fn depots() {
+        let mut c = Config::new();
+        c.set_url(String::from("http://foolio.com"));
+        c.set_mirrors(vec![Mirror::new(String::from("http://far.foolio.com"), 5),
+                           Mirror::new(String::from("http://near.foolio.com"), 1)]);
+        let urls: Vec<String> = c.depots().into_iter().map(|d| d.url).collect();
+        assert_eq!(urls,
+                   vec!["http://foolio.com", "http://near.foolio.com", "http://far.foolio.com"]);
+    }
+
     #[test]
     fn topology() {
         let mut c = Config::new();
         c.set_topology(Topology::Leader);
         assert_eq!(c.topology(), Topology::Leader);
     }
+
+    #[test]
+    fn fs_root() {
+        let mut c = Config::new();
+        c.set_fs_root(PathBuf::from("/tmp/hab-root"));
+        assert_eq!(c.fs_root(), PathBuf::from("/tmp/hab-root"));
+    }
+
+    #[test]
+    fn unprivileged() {
+        let mut c = Config::new();
+        assert!(!c.unprivileged());
+        c.set_unprivileged(true);
+        assert!(c.unprivileged());
+    }
 }
@@ -0,0 +1,201 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Self-diagnostics for the Supervisor process.
+//!
+//! Process statistics are read from `/proc/self` and are only available on Linux; on other
+//! platforms they are reported as `null`.
+
+use std::fs::{DirBuilder, File};
+use std::io::prelude::*;
+#[cfg(unix)]
+use std::os::unix::fs::DirBuilderExt;
+use std::path::PathBuf;
+
+use hcore::fs as hfs;
+use libc;
+use time;
+
+use error::Result;
+use manager;
+use util;
+
+#[derive(Debug, Default, Serialize)]
+pub struct ProcessStats {
+    pub pid: u32,
+    pub rss_bytes: Option<u64>,
+    pub virtual_bytes: Option<u64>,
+    pub threads: Option<u64>,
+    pub cpu_user_ms: Option<u64>,
+    pub cpu_system_ms: Option<u64>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct RumorStats {
+    pub service: usize,
+    pub service_config: usize,
+    pub service_file: usize,
+    pub election: usize,
+    pub election_update: usize,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct QueueStats {
+    /// Rumors which are still being gossiped to at least one member.
+    pub gossip_rumors: usize,
+    /// Scheduled hooks which are currently running.
+    pub scheduled_hooks_running: usize,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct Diagnostics {
+    pub process: ProcessStats,
+    pub members: usize,
+    pub census_groups: usize,
+    pub services: usize,
+    pub rumors: RumorStats,
+    pub queues: QueueStats,
+}
+
+impl Diagnostics {
+    /// Take a snapshot of the Supervisor's resource usage and internal state.
+    pub fn new(state: &manager::State) -> Self {
+        let butterfly = &state.butterfly;
+        let services = state.services.read().expect("Services lock is poisoned!");
+        let scheduled_hooks_running = services.iter()
+            .flat_map(|s| s.scheduler.hooks.iter())
+            .filter(|h| h.running)
+            .count();
+        Diagnostics {
+            process: ProcessStats::new(),
+            members: butterfly.member_list.len(),
+            census_groups: state.census_list.read().expect("Census list lock is poisoned!").len(),
+            services: services.len(),
+            rumors: RumorStats {
+                service: butterfly.service_store.len(),
+                service_config: butterfly.service_config_store.len(),
+                service_file: butterfly.service_file_store.len(),
+                election: butterfly.election_store.len(),
+                election_update: butterfly.update_store.len(),
+            },
+            queues: QueueStats {
+                gossip_rumors: butterfly.rumor_list.len(),
+                scheduled_hooks_running: scheduled_hooks_running,
+            },
+        }
+    }
+}
+
+impl ProcessStats {
+    pub fn new() -> Self {
+        let mut stats = ProcessStats { pid: pid(), ..ProcessStats::default() };
+        if let Ok(status) = read_proc_file("status") {
+            stats.rss_bytes = status_kb(&status, "VmRSS:").map(|kb| kb * 1024);
+            stats.virtual_bytes = status_kb(&status, "VmSize:").map(|kb| kb * 1024);
+            stats.threads = status_kb(&status, "Threads:");
+        }
+        if let Ok(stat) = read_proc_file("stat") {
+            let ticks = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+            if ticks > 0 {
+                let (utime, stime) = stat_cpu_ticks(&stat);
+                stats.cpu_user_ms = utime.map(|t| t * 1000 / ticks as u64);
+                stats.cpu_system_ms = stime.map(|t| t * 1000 / ticks as u64);
+            }
+        }
+        stats
+    }
+}
+
+/// Write the Supervisor's current memory mappings, as `/proc/self/smaps` lists them with the size
+/// and resident memory of each, to a timestamped file in the Supervisor's data directory for
+/// offline analysis. Returns the file's path. This is not a profile of heap allocations.
+///
+/// The mappings reveal the layout of the process, so the directory and file are only readable by
+/// the Supervisor's user, and the file must not exist already, nor is a symlink in its place
+/// followed.
+pub fn write_memory_maps() -> Result<PathBuf> {
+    let smaps = try!(read_proc_file("smaps"));
+    let dir = hfs::sup_data_path();
+    try!(private_dir_builder().create(&dir));
+    let path = dir.join(format!("hab-sup-{}-{}.smaps", pid(), time::now_utc().to_timespec().sec));
+    let mut file = try!(util::create_private(&path, 0o600));
+    try!(file.write_all(smaps.as_bytes()));
+    Ok(path)
+}
+
+#[cfg(unix)]
+fn private_dir_builder() -> DirBuilder {
+    let mut builder = DirBuilder::new();
+    builder.recursive(true).mode(0o700);
+    builder
+}
+
+#[cfg(not(unix))]
+fn private_dir_builder() -> DirBuilder {
+    let mut builder = DirBuilder::new();
+    builder.recursive(true);
+    builder
+}
+
+
+fn pid() -> u32 {
+    unsafe { libc::getpid() as u32 }
+}
+
+fn read_proc_file(name: &str) -> Result<String> {
+    let mut body = String::new();
+    let mut file = try!(File::open(PathBuf::from("/proc/self").join(name)));
+    try!(file.read_to_string(&mut body));
+    Ok(body)
+}
+
+/// Returns the numeric value of a `/proc/self/status` field, such as `VmRSS:   1024 kB`.
+fn status_kb(status: &str, field: &str) -> Option<u64> {
+    status.lines()
+        .find(|l| l.starts_with(field))
+        .and_then(|l| l[field.len()..].split_whitespace().next())
+        .and_then(|v| v.parse().ok())
+}
+
+/// Returns the user and system CPU time, in clock ticks, from `/proc/self/stat`.
+fn stat_cpu_ticks(stat: &str) -> (Option<u64>, Option<u64>) {
+    // The process name is wrapped in parentheses and may contain spaces, so fields are counted
+    // from the closing parenthesis. utime and stime are the 14th and 15th fields.
+    let rest = match stat.rfind(')') {
+        Some(idx) => &stat[idx + 1..],
+        None => return (None, None),
+    };
+    let fields: Vec<&str> = rest.split_whitespace().collect();
+    (fields.get(11).and_then(|v| v.parse().ok()), fields.get(12).and_then(|v| v.parse().ok()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{stat_cpu_ticks, status_kb};
+
+    #[test]
+    fn parses_status_fields() {
+        let status = "Name:\thab-sup\nVmSize:\t  204800 kB\nVmRSS:\t   10240 kB\nThreads:\t12\n";
+        assert_eq!(status_kb(status, "VmRSS:"), Some(10240));
+        assert_eq!(status_kb(status, "VmSize:"), Some(204800));
+        assert_eq!(status_kb(status, "Threads:"), Some(12));
+        assert_eq!(status_kb(status, "VmSwap:"), None);
+    }
+
+    #[test]
+    fn parses_cpu_ticks() {
+        let stat = "42 (hab sup) S 1 42 42 0 -1 4194560 100 0 0 0 250 75 0 0 20 0 12 0";
+        assert_eq!(stat_cpu_ticks(stat), (Some(250), Some(75)));
+    }
+}
@@ -0,0 +1,336 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The census as Envoy's cluster and endpoint discovery services (CDS and EDS) describe it.
+//!
+//! Every service group in the census is a cluster named after the group, such as `redis.default`,
+//! whose endpoints are its alive members, at the address they gossip and the `port` their
+//! service exports. Members which export no port aren't endpoints. An Envoy in front of a service
+//! can then route to the groups it binds to without a separate control plane, in either of two
+//! ways:
+//!
+//! * With `--envoy-xds-dir`, the Supervisor writes `cds.json` and `eds.json` to a directory
+//!   whenever the census changes, and Envoy is pointed at `cds.json` as a file config source.
+//! * The HTTP gateway serves both at `/v2/discovery:clusters` and `/v2/discovery:endpoints`, for
+//!   a REST config source. The gateway must be a static cluster named `XDS_CLUSTER` in Envoy's
+//!   bootstrap, since that's where the clusters say their endpoints come from.
+//!
+//! Responses are JSON `DiscoveryResponse`s of the v2 API, versioned by their content.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fs::{self, File};
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde_json;
+use toml;
+
+use error::Result;
+use manager::census::{Census, CensusEntry, CensusList};
+
+/// The name Envoy's bootstrap must give the cluster of the HTTP gateway, when endpoints are
+/// discovered over REST.
+pub const XDS_CLUSTER: &'static str = "habitat_xds";
+
+const CLUSTER_TYPE: &'static str = "type.googleapis.com/envoy.api.v2.Cluster";
+const ENDPOINTS_TYPE: &'static str = "type.googleapis.com/envoy.api.v2.ClusterLoadAssignment";
+
+/// How long Envoy waits to connect to an endpoint.
+const CONNECT_TIMEOUT: &'static str = "1s";
+
+/// How often Envoy polls the gateway for endpoints.
+const REFRESH_DELAY: &'static str = "1s";
+
+/// Where clusters tell Envoy to discover their endpoints.
+pub enum EndpointSource {
+    /// The `eds.json` written to this directory.
+    Dir(PathBuf),
+    /// The HTTP gateway, as the `XDS_CLUSTER` cluster.
+    Rest,
+}
+
+/// The clusters and endpoints of the census at one moment.
+pub struct Snapshot {
+    version: String,
+    clusters: Vec<Cluster>,
+    endpoints: Vec<ClusterLoadAssignment>,
+}
+
+#[derive(Serialize)]
+struct DiscoveryResponse<'a, T: 'a> {
+    version_info: &'a str,
+    resources: &'a [T],
+    type_url: &'static str,
+}
+
+#[derive(Serialize)]
+struct Cluster {
+    #[serde(rename = "@type")]
+    type_url: &'static str,
+    name: String,
+    #[serde(rename = "type")]
+    discovery_type: &'static str,
+    connect_timeout: &'static str,
+    eds_cluster_config: EdsClusterConfig,
+}
+
+#[derive(Serialize)]
+struct EdsClusterConfig {
+    eds_config: ConfigSource,
+}
+
+#[derive(Serialize)]
+struct ConfigSource {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    api_config_source: Option<ApiConfigSource>,
+}
+
+#[derive(Serialize)]
+struct ApiConfigSource {
+    api_type: &'static str,
+    cluster_names: Vec<&'static str>,
+    refresh_delay: &'static str,
+}
+
+#[derive(Serialize)]
+struct ClusterLoadAssignment {
+    #[serde(rename = "@type")]
+    type_url: &'static str,
+    cluster_name: String,
+    endpoints: Vec<LocalityLbEndpoints>,
+}
+
+#[derive(Serialize)]
+struct LocalityLbEndpoints {
+    lb_endpoints: Vec<LbEndpoint>,
+}
+
+#[derive(Serialize)]
+struct LbEndpoint {
+    endpoint: Endpoint,
+}
+
+#[derive(Serialize)]
+struct Endpoint {
+    address: Address,
+}
+
+#[derive(Serialize)]
+struct Address {
+    socket_address: SocketAddress,
+}
+
+#[derive(Serialize)]
+struct SocketAddress {
+    address: String,
+    port_value: u16,
+}
+
+impl Snapshot {
+    pub fn from_census(census_list: &CensusList, source: &EndpointSource) -> Snapshot {
+        let mut censuses: Vec<(&String, &Census)> = census_list.iter().collect();
+        censuses.sort_by(|a, b| a.0.cmp(b.0));
+        let mut clusters = Vec::with_capacity(censuses.len());
+        let mut endpoints = Vec::with_capacity(censuses.len());
+        for (group, census) in censuses {
+            clusters.push(Cluster {
+                type_url: CLUSTER_TYPE,
+                name: group.clone(),
+                discovery_type: "EDS",
+                connect_timeout: CONNECT_TIMEOUT,
+                eds_cluster_config: EdsClusterConfig { eds_config: config_source(source) },
+            });
+            let lb_endpoints = census.alive_members_ordered()
+                .into_iter()
+                .filter_map(socket_address)
+                .map(|addr| {
+                    LbEndpoint { endpoint: Endpoint { address: Address { socket_address: addr } } }
+                })
+                .collect();
+            endpoints.push(ClusterLoadAssignment {
+                type_url: ENDPOINTS_TYPE,
+                cluster_name: group.clone(),
+                endpoints: vec![LocalityLbEndpoints { lb_endpoints: lb_endpoints }],
+            });
+        }
+        let mut hasher = DefaultHasher::new();
+        serde_json::to_string(&clusters).unwrap().hash(&mut hasher);
+        serde_json::to_string(&endpoints).unwrap().hash(&mut hasher);
+        Snapshot {
+            version: format!("{:016x}", hasher.finish()),
+            clusters: clusters,
+            endpoints: endpoints,
+        }
+    }
+
+    /// Returns the version of the snapshot, which only changes when its content does.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Returns the CDS `DiscoveryResponse`.
+    pub fn clusters_json(&self) -> String {
+        serde_json::to_string(&DiscoveryResponse {
+                version_info: &self.version,
+                resources: &self.clusters,
+                type_url: CLUSTER_TYPE,
+            })
+            .unwrap()
+    }
+
+    /// Returns the EDS `DiscoveryResponse`.
+    pub fn endpoints_json(&self) -> String {
+        serde_json::to_string(&DiscoveryResponse {
+                version_info: &self.version,
+                resources: &self.endpoints,
+                type_url: ENDPOINTS_TYPE,
+            })
+            .unwrap()
+    }
+
+    /// Writes `cds.json` and `eds.json` to `dir`. Each is written to a temporary file and renamed
+    /// into place, which is the change Envoy watches for.
+    ///
+    /// # Failures
+    ///
+    /// * The directory can't be created, or the files written
+    pub fn write_dir(&self, dir: &Path) -> Result<()> {
+        try!(fs::create_dir_all(dir));
+        try!(write_atomically(&dir.join("eds.json"), &self.endpoints_json()));
+        try!(write_atomically(&dir.join("cds.json"), &self.clusters_json()));
+        Ok(())
+    }
+}
+
+fn config_source(source: &EndpointSource) -> ConfigSource {
+    match *source {
+        EndpointSource::Dir(ref dir) => {
+            ConfigSource {
+                path: Some(dir.join("eds.json").to_string_lossy().into_owned()),
+                api_config_source: None,
+            }
+        }
+        EndpointSource::Rest => {
+            ConfigSource {
+                path: None,
+                api_config_source: Some(ApiConfigSource {
+                    api_type: "REST",
+                    cluster_names: vec![XDS_CLUSTER],
+                    refresh_delay: REFRESH_DELAY,
+                }),
+            }
+        }
+    }
+}
+
+/// Returns where a member's service is reached, or `None` if it exports no port.
+fn socket_address(member: &CensusEntry) -> Option<SocketAddress> {
+    let port = match member.cfg.get("port") {
+        Some(&toml::Value::Integer(port)) if port > 0 && port <= u16::max_value() as i64 => {
+            port as u16
+        }
+        Some(&toml::Value::String(ref port)) => {
+            match port.parse() {
+                Ok(port) => port,
+                Err(_) => return None,
+            }
+        }
+        _ => return None,
+    };
+    let address = if member.sys.ip.is_empty() {
+        member.address.clone()
+    } else {
+        member.sys.ip.clone()
+    };
+    if address.is_empty() {
+        return None;
+    }
+    Some(SocketAddress {
+        address: address,
+        port_value: port,
+    })
+}
+
+fn write_atomically(path: &Path, content: &str) -> Result<()> {
+    let tmp = path.with_extension("json.tmp");
+    {
+        let mut file = try!(File::create(&tmp));
+        try!(file.write_all(content.as_bytes()));
+    }
+    try!(fs::rename(&tmp, path));
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use serde_json::{self, Value};
+    use toml;
+
+    use manager::census::{CensusEntry, CensusList};
+    use super::{EndpointSource, Snapshot};
+
+    fn entry(member_id: &str, ip: &str, port: Option<i64>, alive: bool) -> CensusEntry {
+        let mut entry = CensusEntry::default();
+        entry.member_id = member_id.to_string();
+        entry.service = String::from("redis");
+        entry.group = String::from("default");
+        entry.sys.ip = ip.to_string();
+        entry.alive = Some(alive);
+        if let Some(port) = port {
+            entry.cfg.insert(String::from("port"), toml::Value::Integer(port));
+        }
+        entry
+    }
+
+    #[test]
+    fn snapshot_from_census() {
+        let mut census_list = CensusList::new();
+        census_list.insert(String::from("a"), entry("a", "10.0.0.1", Some(6379), true));
+        census_list.insert(String::from("a"), entry("b", "10.0.0.2", Some(6379), false));
+        census_list.insert(String::from("a"), entry("c", "10.0.0.3", None, true));
+        let source = EndpointSource::Dir(PathBuf::from("/hab/envoy"));
+        let snapshot = Snapshot::from_census(&census_list, &source);
+
+        let clusters: Value = serde_json::from_str(&snapshot.clusters_json()).unwrap();
+        let cluster = &clusters.find("resources").unwrap().as_array().unwrap()[0];
+        assert_eq!(cluster.find("name").unwrap().as_str(), Some("redis.default"));
+        assert_eq!(cluster.lookup("eds_cluster_config.eds_config.path").unwrap().as_str(),
+                   Some("/hab/envoy/eds.json"));
+
+        let endpoints: Value = serde_json::from_str(&snapshot.endpoints_json()).unwrap();
+        let lb_endpoints = endpoints.find("resources").unwrap().as_array().unwrap()[0]
+            .find("endpoints")
+            .unwrap()
+            .as_array()
+            .unwrap()[0]
+            .find("lb_endpoints")
+            .unwrap()
+            .as_array()
+            .unwrap()
+            .clone();
+        assert_eq!(lb_endpoints.len(), 1);
+        assert_eq!(lb_endpoints[0].lookup("endpoint.address.socket_address.address").unwrap(),
+                   &Value::String(String::from("10.0.0.1")));
+
+        let again = Snapshot::from_census(&census_list, &source);
+        assert_eq!(snapshot.version(), again.version());
+        census_list.insert(String::from("a"), entry("d", "10.0.0.4", Some(6379), true));
+        assert!(Snapshot::from_census(&census_list, &source).version() != snapshot.version());
+    }
+}
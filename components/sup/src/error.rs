@@ -50,13 +50,18 @@ use std::sync::mpsc;
 
 use ansi_term::Colour::Red;
 use handlebars;
+use liquid;
 use hcore::package::Identifiable;
 use butterfly;
 use toml;
 
 use common;
 use depot_client;
+use http_client;
 use hcore::{self, package};
+use http_gateway;
+use logger;
+use manager::service::data_version;
 use output::StructuredOutput;
 use package::HookType;
 use PROGRAM_NAME;
@@ -99,6 +104,7 @@ impl SupError {
 /// All the kinds of errors we produce.
 #[derive(Debug)]
 pub enum Error {
+    AwsFailed(String),
     ButterflyError(butterfly::error::Error),
     CommandNotImplemented,
     DbInvalidPath,
@@ -106,6 +112,7 @@ pub enum Error {
     EnvJoinPathsError(env::JoinPathsError),
     ExecCommandNotFound(String),
     FileNotFound(String),
+    GrpcGatewayNotBuilt,
     HabitatCommon(common::Error),
     HabitatCore(hcore::Error),
     TemplateFileError(handlebars::TemplateFileError),
@@ -115,24 +122,64 @@ pub enum Error {
     /// A hook failed to successfully execute. This error contains the type of hook which failed
     /// to run and the exit code.
     HookFailed(HookType, i32),
+    HttpClient(http_client::Error),
+    InjectedFault(String),
+    InterfaceNotFound(String),
+    InvalidAttemptCount(String),
     InvalidBinding(String),
+    InvalidDuration(String),
+    InvalidExitCodes(String),
+    InvalidHealthProbe(String),
+    InvalidHookSandbox(String),
+    InvalidHttpEndpoint(String),
+    InvalidHttpLimit(String),
+    InvalidHttpOrigin(String),
     InvalidKeyParameter(String),
+    InvalidLabel(String),
+    InvalidLogLevel(String),
+    InvalidLogSubsystem(String),
+    InvalidMemberCount(String),
+    InvalidMirror(String),
+    InvalidPeerProvider(String),
+    InvalidProcessLimits(String),
+    InvalidRedactPattern(String),
+    InvalidSecretFile(String),
+    InvalidServiceDirs(String),
+    InvalidServiceName(String),
     InvalidPidFile,
+    InvalidQuarantine(String),
     InvalidPort(num::ParseIntError),
+    InvalidPortMapping(String),
+    InvalidSchedule(String),
     InvalidServiceGroupString(String),
+    InvalidStateBundle(String),
+    InvalidThreadCount(String),
     Io(io::Error),
     IPFailed,
     KeyNotFound(String),
+    KubernetesFailed(String),
+    LiquidError(liquid::Error),
     MetaFileIO(io::Error),
+    /// A template rendered in strict mode referenced data which doesn't exist. This error
+    /// contains the name of the template and the missing variable.
+    MissingTemplateVariable(String, String),
     NameLookup(io::Error),
     NetParseError(net::AddrParseError),
+    NoDepotAvailable,
     NoRunFile,
     NulError(ffi::NulError),
     PackageArchiveMalformed(String),
     PackageNotFound(package::PackageIdent),
+    /// A peer provider could not list instances. This error contains the provider name and why.
+    PeerProviderFailed(String, String),
+    SupervisorRequestFailed(String),
     Permissions(String),
     RemotePackageNotFound(package::PackageIdent),
     RootRequired,
+    SecretsFailed(String),
+    /// A managed service directory which asks for a tmpfs couldn't have one mounted. This error
+    /// contains the directory and why.
+    ServiceDirMountFailed(String, io::Error),
     SignalFailed,
     SignalNotifierStarted,
     StrFromUtf8Error(str::Utf8Error),
@@ -141,8 +188,13 @@ pub enum Error {
     TomlMergeError(String),
     TomlParser(Vec<toml::ParserError>),
     TryRecvError(mpsc::TryRecvError),
+    UnknownHookInterpreter(String),
+    UnknownPeerProvider(String),
+    UnknownTemplateEngine(String),
     UnknownTopology(String),
     UnpackFailed,
+    UnsupportedDataVersion(String),
+    UnsupportedPlatform(String),
 }
 
 impl fmt::Display for SupError {
@@ -150,6 +202,7 @@ impl fmt::Display for SupError {
     // verbose on, and print it.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let content = match self.err {
+            Error::AwsFailed(ref e) => format!("AWS request failed: {}", e),
             Error::ButterflyError(ref err) => format!("Butterfly error: {}", err),
             Error::ExecCommandNotFound(ref c) => {
                 format!("`{}' was not found on the filesystem or in PATH", c)
@@ -164,31 +217,111 @@ impl fmt::Display for SupError {
             Error::DepotClient(ref err) => format!("{}", err),
             Error::EnvJoinPathsError(ref err) => format!("{}", err),
             Error::FileNotFound(ref e) => format!("File not found at: {}", e),
+            Error::GrpcGatewayNotBuilt => {
+                format!("This Supervisor was built without gRPC support; build it with the \
+                         grpc-gateway feature to use --listen-grpc")
+            }
             Error::HealthCheckBadExit(ref e) => {
                 format!("Health check exited with an unknown status code, {}", e)
             }
             Error::HookFailed(ref hook, ref code) => {
                 format!("{} hook failed to run with exit code {}", hook, code)
             }
+            Error::HttpClient(ref err) => format!("{}", err),
+            Error::InjectedFault(ref fault) => format!("Injected fault: {}", fault),
+            Error::InvalidAttemptCount(ref e) => {
+                format!("Invalid attempt count: {}, must be a number greater than 0", e)
+            }
             Error::InvalidBinding(ref binding) => {
                 format!("Invalid binding - must be ':' delimited: {}", binding)
             }
+            Error::InvalidDuration(ref e) => {
+                format!("Invalid duration: {}, must be a whole number of seconds", e)
+            }
+            Error::InvalidExitCodes(ref e) => format!("Invalid service exit code mapping: {}", e),
+            Error::InvalidHealthProbe(ref e) => format!("Invalid service health probe: {}", e),
+            Error::InvalidHookSandbox(ref e) => {
+                format!("Unknown hook sandbox {}; it must be env, readonly or limits", e)
+            }
+            Error::InvalidProcessLimits(ref e) => format!("Invalid service process limits: {}", e),
+            Error::InvalidServiceDirs(ref e) => format!("Invalid service directories: {}", e),
+            Error::InvalidServiceName(ref n) => {
+                format!("Invalid service name {}; it can't be empty or contain '.', '/' or '@'",
+                        n)
+            }
             Error::InvalidKeyParameter(ref e) => {
                 format!("Invalid parameter for key generation: {:?}", e)
             }
             Error::InvalidPort(ref e) => {
                 format!("Invalid port number in package expose metadata: {}", e)
             }
+            Error::InvalidPortMapping(ref e) => {
+                format!("Invalid port mapping: {}, must be a port or host_port:service_port", e)
+            }
+            Error::InterfaceNotFound(ref e) => {
+                format!("Network interface {} not found or has no IP address", e)
+            }
+            Error::InvalidHttpEndpoint(ref e) => {
+                format!("Invalid HTTP gateway endpoint: {}, must be one of {}",
+                        e,
+                        http_gateway::ENDPOINTS.join(", "))
+            }
+            Error::InvalidHttpLimit(ref e) => {
+                format!("Invalid HTTP gateway limit: {}, must be a number greater than 0", e)
+            }
+            Error::InvalidHttpOrigin(ref e) => {
+                format!("Invalid HTTP gateway CORS origin: {}, must be * or a \
+                         scheme://host[:port] origin",
+                        e)
+            }
+            Error::InvalidSchedule(ref e) => format!("Invalid schedule: {}", e),
+            Error::InvalidStateBundle(ref e) => format!("Invalid state bundle: {}", e),
+            Error::InvalidLabel(ref e) => {
+                format!("Invalid label: {}, must be key=value with a key of letters, digits, \
+                         '_' and '-'",
+                        e)
+            }
+            Error::InvalidLogLevel(ref e) => {
+                format!("Invalid log level: {}, must be one of {}",
+                        e,
+                        logger::LEVELS.join(", "))
+            }
+            Error::InvalidLogSubsystem(ref e) => {
+                format!("Invalid log subsystem: {}, must be one of {}",
+                        e,
+                        logger::SUBSYSTEM_NAMES.join(", "))
+            }
+            Error::InvalidMemberCount(ref e) => {
+                format!("Invalid member count: {}, must be a number greater than 0", e)
+            }
+            Error::InvalidMirror(ref e) => format!("Invalid Depot mirror: {}", e),
+            Error::InvalidPeerProvider(ref e) => {
+                format!("Invalid peer provider: {}, must be space separated key=value pairs \
+                         including provider=",
+                        e)
+            }
             Error::InvalidPidFile => format!("Invalid child process PID file"),
+            Error::InvalidQuarantine(ref e) => format!("Invalid quarantine file: {}", e),
+            Error::InvalidRedactPattern(ref e) => format!("Invalid redaction pattern: {}", e),
+            Error::InvalidSecretFile(ref e) => format!("Invalid secret file: {}", e),
             Error::InvalidServiceGroupString(ref e) => {
                 format!("Invalid service group string: {}", e)
             }
+            Error::InvalidThreadCount(ref e) => {
+                format!("Invalid thread count: {}, must be a number greater than 0", e)
+            }
             Error::Io(ref err) => format!("{}", err),
             Error::IPFailed => format!("Failed to discover this hosts outbound IP address"),
             Error::KeyNotFound(ref e) => format!("Key not found in key cache: {}", e),
+            Error::KubernetesFailed(ref e) => format!("Kubernetes API request failed: {}", e),
+            Error::LiquidError(ref e) => format!("Liquid template error: {}", e),
+            Error::MissingTemplateVariable(ref template, ref var) => {
+                format!("Template {} referenced missing variable {}", template, var)
+            }
             Error::MetaFileIO(ref e) => format!("IO error while accessing MetaFile: {:?}", e),
             Error::NameLookup(ref e) => format!("Error resolving a name or IP address: {}", e),
             Error::NetParseError(ref e) => format!("Can't parse ip:port: {}", e),
+            Error::NoDepotAvailable => format!("No Depot is configured to install packages from"),
             Error::NoRunFile => {
                 format!("No run file is present for this package; specify a run hook or \
                          $pkg_svc_run in your plan")
@@ -205,6 +338,10 @@ impl fmt::Display for SupError {
                     format!("Cannot find a release of package: {}", pkg)
                 }
             }
+            Error::SupervisorRequestFailed(ref e) => format!("Supervisor request failed: {}", e),
+            Error::PeerProviderFailed(ref provider, ref e) => {
+                format!("Peer provider {} failed: {}", provider, e)
+            }
             Error::RemotePackageNotFound(ref pkg) => {
                 if pkg.fully_qualified() {
                     format!("Cannot find package in any sources: {}", pkg)
@@ -215,6 +352,10 @@ impl fmt::Display for SupError {
             Error::RootRequired => {
                 "Root or administrator permissions required to complete operation".to_string()
             }
+            Error::SecretsFailed(ref e) => format!("Secrets backend request failed: {}", e),
+            Error::ServiceDirMountFailed(ref path, ref e) => {
+                format!("Couldn't back {} with a tmpfs: {}", path, e)
+            }
             Error::SignalFailed => format!("Failed to send a signal to the child process"),
             Error::SignalNotifierStarted => {
                 format!("Only one instance of a Signal Notifier may be running")
@@ -227,8 +368,24 @@ impl fmt::Display for SupError {
                 format!("Failed to parse toml:\n{}", toml_parser_string(errs))
             }
             Error::TryRecvError(ref err) => format!("{}", err),
+            Error::UnknownHookInterpreter(ref e) => {
+                format!("Unknown hook interpreter {}, must be one of exec, sh, powershell, python",
+                        e)
+            }
+            Error::UnknownPeerProvider(ref e) => {
+                format!("Unknown peer provider {}, must be one of aws, azure, gce", e)
+            }
+            Error::UnknownTemplateEngine(ref e) => format!("Unknown template engine {}!", e),
             Error::UnknownTopology(ref t) => format!("Unknown topology {}!", t),
             Error::UnpackFailed => format!("Failed to unpack a package"),
+            Error::UnsupportedDataVersion(ref v) => {
+                format!("Unsupported template data version {}, must be between 1 and {}",
+                        v,
+                        data_version::CURRENT_DATA_VERSION)
+            }
+            Error::UnsupportedPlatform(ref feature) => {
+                format!("{} is not supported on this platform", feature)
+            }
         };
         let cstring = Red.bold().paint(content).to_string();
         let progname = PROGRAM_NAME.as_str();
@@ -246,6 +403,7 @@ impl fmt::Display for SupError {
 impl error::Error for SupError {
     fn description(&self) -> &str {
         match self.err {
+            Error::AwsFailed(_) => "AWS request failed",
             Error::ButterflyError(ref err) => err.description(),
             Error::ExecCommandNotFound(_) => "Exec command was not found on filesystem or in PATH",
             Error::TemplateFileError(ref err) => err.description(),
@@ -257,21 +415,55 @@ impl error::Error for SupError {
             Error::DepotClient(ref err) => err.description(),
             Error::EnvJoinPathsError(ref err) => err.description(),
             Error::FileNotFound(_) => "File not found",
+            Error::GrpcGatewayNotBuilt => "Supervisor was built without gRPC support",
             Error::HealthCheckBadExit(_) => "Health Check exited with an unknown status code",
             Error::HookFailed(_, _) => "Hook failed to run",
+            Error::HttpClient(ref err) => err.description(),
+            Error::InjectedFault(_) => "A fault was injected",
+            Error::InvalidAttemptCount(_) => "Attempt counts must be a number greater than 0",
             Error::InvalidBinding(_) => "Invalid binding parameter",
+            Error::InvalidDuration(_) => "Durations must be a whole number of seconds",
+            Error::InvalidExitCodes(_) => "Invalid service exit code mapping",
+            Error::InvalidHealthProbe(_) => "Invalid service health probe",
+            Error::InvalidHookSandbox(_) => "Unknown hook sandbox",
             Error::InvalidKeyParameter(_) => "Key parameter error",
             Error::InvalidPort(_) => "Invalid port number in package expose metadata",
+            Error::InvalidPortMapping(_) => "Invalid port mapping for an isolated service",
+            Error::InvalidPeerProvider(_) => "Invalid peer provider configuration",
+            Error::InvalidProcessLimits(_) => "Invalid service umask or resource limits",
+            Error::InvalidServiceDirs(_) => "Invalid managed service directories",
+            Error::InvalidServiceName(_) => "Invalid service name",
             Error::InvalidPidFile => "Invalid child process PID file",
+            Error::InvalidQuarantine(_) => "Quarantine file could not be parsed",
+            Error::InvalidRedactPattern(_) => "Redaction patterns must be regular expressions",
+            Error::InvalidSecretFile(_) => "Secret file holds no usable secret",
+            Error::InterfaceNotFound(_) => "Network interface not found or has no IP address",
+            Error::InvalidHttpEndpoint(_) => "Unknown HTTP gateway endpoint",
+            Error::InvalidHttpLimit(_) => "HTTP gateway limits must be a number greater than 0",
+            Error::InvalidHttpOrigin(_) => {
+                "CORS origins must be * or a scheme://host[:port] origin"
+            }
+            Error::InvalidSchedule(_) => "Invalid schedule",
+            Error::InvalidStateBundle(_) => "Invalid Supervisor state bundle",
+            Error::InvalidLabel(_) => "Labels must be key=value pairs",
+            Error::InvalidLogLevel(_) => "Unknown log level",
+            Error::InvalidLogSubsystem(_) => "Unknown log subsystem",
+            Error::InvalidMemberCount(_) => "Member counts must be a number greater than 0",
+            Error::InvalidMirror(_) => "Invalid Depot mirror",
             Error::InvalidServiceGroupString(_) => {
                 "Service group strings must be in service.group format (example: redis.default)"
             }
+            Error::InvalidThreadCount(_) => "Thread counts must be a number greater than 0",
             Error::Io(ref err) => err.description(),
             Error::IPFailed => "Failed to discover the outbound IP address",
             Error::KeyNotFound(_) => "Key not found in key cache",
+            Error::KubernetesFailed(_) => "Kubernetes API request failed",
+            Error::LiquidError(_) => "Liquid template error",
+            Error::MissingTemplateVariable(_, _) => "Template referenced a missing variable",
             Error::MetaFileIO(_) => "MetaFile could not be read or written to",
             Error::NetParseError(_) => "Can't parse IP:port",
             Error::NameLookup(_) => "Error resolving a name or IP address",
+            Error::NoDepotAvailable => "No Depot is configured to install packages from",
             Error::NoRunFile => {
                 "No run file is present for this package; specify a run hook or $pkg_svc_run \
                  in your plan"
@@ -284,10 +476,14 @@ impl error::Error for SupError {
             }
             Error::PackageNotFound(_) => "Cannot find a package",
             Error::Permissions(_) => "File system permissions error",
+            Error::PeerProviderFailed(_, _) => "Peer provider failed to list instances",
+            Error::SupervisorRequestFailed(_) => "Request to a running Supervisor failed",
             Error::RemotePackageNotFound(_) => "Cannot find a package in any sources",
             Error::RootRequired => {
                 "Root or administrator permissions required to complete operation"
             }
+            Error::SecretsFailed(_) => "Secrets backend request failed",
+            Error::ServiceDirMountFailed(_, _) => "Couldn't mount a tmpfs for a service directory",
             Error::SignalFailed => "Failed to send a signal to the child process",
             Error::SignalNotifierStarted => "Only one instance of a Signal Notifier may be running",
             Error::StrFromUtf8Error(_) => "Failed to convert a str from a &[u8] as UTF-8",
@@ -296,8 +492,13 @@ impl error::Error for SupError {
             Error::TomlMergeError(_) => "Failed to merge toml!",
             Error::TomlParser(_) => "Failed to parse toml!",
             Error::TryRecvError(_) => "A channel failed to receive a response",
+            Error::UnknownHookInterpreter(_) => "Unknown hook interpreter",
+            Error::UnknownPeerProvider(_) => "Unknown peer provider",
+            Error::UnknownTemplateEngine(_) => "Unknown template engine",
             Error::UnknownTopology(_) => "Unknown topology",
             Error::UnpackFailed => "Failed to unpack a package",
+            Error::UnsupportedDataVersion(_) => "Unsupported template data version",
+            Error::UnsupportedPlatform(_) => "Feature not supported on this platform",
         }
     }
 }
@@ -335,6 +536,12 @@ impl From<handlebars::RenderError> for SupError {
     }
 }
 
+impl From<liquid::Error> for SupError {
+    fn from(err: liquid::Error) -> SupError {
+        sup_error!(Error::LiquidError(err))
+    }
+}
+
 impl From<handlebars::TemplateFileError> for SupError {
     fn from(err: handlebars::TemplateFileError) -> SupError {
         sup_error!(Error::TemplateFileError(err))
@@ -353,6 +560,18 @@ impl From<depot_client::Error> for SupError {
     }
 }
 
+impl From<http_client::Error> for SupError {
+    fn from(err: http_client::Error) -> SupError {
+        sup_error!(Error::HttpClient(err))
+    }
+}
+
+impl From<sup_client::Error> for SupError {
+    fn from(err: sup_client::Error) -> SupError {
+        sup_error!(Error::SupervisorRequestFailed(err.to_string()))
+    }
+}
+
 impl From<ffi::NulError> for SupError {
     fn from(err: ffi::NulError) -> SupError {
         sup_error!(Error::NulError(err))
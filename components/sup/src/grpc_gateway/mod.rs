@@ -0,0 +1,537 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A gRPC server offering control of the Supervisor and queries of its census, alongside the
+//! HTTP gateway. It's built with the `grpc-gateway` feature and started with `--listen-grpc`.
+//!
+//! The service is described by `protocols/control.proto`, from which the build generates the
+//! `control` and `control_grpc` modules.
+
+pub mod control;
+pub mod control_grpc;
+
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use futures::{Future, Poll, Sink, Stream};
+use futures::sync::mpsc;
+use grpc;
+use hcore::service::ServiceGroup;
+
+use error::Result;
+use health_check::CheckResult;
+use http_gateway;
+use logger;
+use manager;
+use manager::census::Census as ServiceCensus;
+use manager::service::Topology;
+use service_log;
+use self::control::*;
+use self::control_grpc::{Control, ControlServer};
+
+// Status codes of the errors answered with, as defined by gRPC.
+const INVALID_ARGUMENT: i32 = 3;
+const NOT_FOUND: i32 = 5;
+const RESOURCE_EXHAUSTED: i32 = 8;
+const FAILED_PRECONDITION: i32 = 9;
+const UNAVAILABLE: i32 = 14;
+const UNAUTHENTICATED: i32 = 16;
+
+/// How many messages a stream buffers for a slow client before its producer waits.
+const STREAM_BUFFER: usize = 16;
+
+/// How often a census watch compares the census with the one it last sent.
+const CENSUS_WATCH_INTERVAL_MS: u64 = 1000;
+
+/// How long a log follow waits for new lines before checking the client is still there.
+const FOLLOW_WAIT_SECS: u64 = 5;
+
+/// The most census watches and log follows open at once. Each holds a thread until its client
+/// cancels it or goes away, which is noticed within `FOLLOW_WAIT_SECS`.
+pub const MAX_STREAMS: usize = 32;
+
+static OPEN_STREAMS: AtomicUsize = ATOMIC_USIZE_INIT;
+
+pub struct Server {
+    listen_addr: SocketAddr,
+    manager_state: manager::State,
+}
+
+impl Server {
+    pub fn new(manager_state: manager::State, listen_addr: SocketAddr) -> Self {
+        Server {
+            listen_addr: listen_addr,
+            manager_state: manager_state,
+        }
+    }
+
+    pub fn start(self) -> Result<JoinHandle<()>> {
+        let handle = try!(thread::Builder::new()
+            .name("grpc-gateway".to_string())
+            .spawn(move || {
+                let mut builder = grpc::ServerBuilder::new_plain();
+                builder.http.set_addr(self.listen_addr).expect("invalid grpc-gateway address");
+                let control = ControlService(self.manager_state);
+                builder.add_service(ControlServer::new_service_def(control));
+                let _server = builder.build().expect("unable to start grpc-gateway");
+                // The server runs on its own threads for as long as it isn't dropped.
+                loop {
+                    thread::park();
+                }
+            }));
+        Ok(handle)
+    }
+}
+
+struct ControlService(manager::State);
+
+impl Control for ControlService {
+    fn list_services(&self,
+                     o: grpc::RequestOptions,
+                     _req: ListServicesRequest)
+                     -> grpc::SingleResponse<ServiceList> {
+        if let Err(err) = authenticate(&o) {
+            return grpc::SingleResponse::err(err);
+        }
+        let mut list = ServiceList::new();
+        for service in self.0.services.read().expect("Services lock is poisoned!").iter() {
+            let mut msg = Service::new();
+            msg.set_service_group(service.service_group.to_string());
+            msg.set_package(service.package.ident().to_string());
+            msg.set_state(service.supervisor.state.to_string());
+            if let Some(ref child) = service.supervisor.child {
+                msg.set_pid(child.id());
+            }
+            for bind in service.binds.iter() {
+                msg.mut_binds().push(bind.clone());
+            }
+            msg.set_topology(format!("{:?}", service.topology));
+            msg.set_update_strategy(format!("{:?}", service.update_strategy));
+            list.mut_services().push(msg);
+        }
+        grpc::SingleResponse::completed(list)
+    }
+
+    fn get_health(&self,
+                  o: grpc::RequestOptions,
+                  req: ServiceGroupRequest)
+                  -> grpc::SingleResponse<HealthReply> {
+        if let Err(err) = authenticate(&o) {
+            return grpc::SingleResponse::err(err);
+        }
+        let service_group = match parse_service_group(req.get_service_group()) {
+            Ok(sg) => sg,
+            Err(err) => return grpc::SingleResponse::err(err),
+        };
+        let services = self.0.services.read().expect("Services lock is poisoned!");
+        let service = match services.iter().find(|s| s.service_group == service_group) {
+            Some(service) => service,
+            None => return grpc::SingleResponse::err(not_running(&service_group)),
+        };
+        let health = match service.health_check() {
+            Ok(CheckResult::Ok) => HealthCheck::OK,
+            Ok(CheckResult::Warning) => HealthCheck::WARNING,
+            Ok(CheckResult::Critical) => HealthCheck::CRITICAL,
+            Ok(CheckResult::Unknown) => HealthCheck::UNKNOWN,
+            Err(err) => {
+                error!("Health Check failed, err={:?}", err);
+                HealthCheck::UNKNOWN
+            }
+        };
+        let mut reply = HealthReply::new();
+        reply.set_health(health);
+        grpc::SingleResponse::completed(reply)
+    }
+
+    fn get_config(&self,
+                  o: grpc::RequestOptions,
+                  req: ServiceGroupRequest)
+                  -> grpc::SingleResponse<ConfigReply> {
+        if let Err(err) = authenticate(&o) {
+            return grpc::SingleResponse::err(err);
+        }
+        let service_group = match parse_service_group(req.get_service_group()) {
+            Ok(sg) => sg,
+            Err(err) => return grpc::SingleResponse::err(err),
+        };
+        let services = self.0.services.read().expect("Services lock is poisoned!");
+        let service = match services.iter().find(|s| s.service_group == service_group) {
+            Some(service) => service,
+            None => return grpc::SingleResponse::err(not_running(&service_group)),
+        };
+        match service.package.last_config() {
+            Ok(config) => {
+                let mut reply = ConfigReply::new();
+                reply.set_config(config);
+                grpc::SingleResponse::completed(reply)
+            }
+            Err(err) => {
+                error!("Couldn't retrieve last config, err={:?}", err);
+                grpc::SingleResponse::err(status(UNAVAILABLE, err.to_string()))
+            }
+        }
+    }
+
+    fn set_log_level(&self,
+                     o: grpc::RequestOptions,
+                     req: LogLevelRequest)
+                     -> grpc::SingleResponse<LogLevelReply> {
+        if let Err(err) = authenticate(&o) {
+            return grpc::SingleResponse::err(err);
+        }
+        let service_group = if req.has_service_group() {
+            match parse_service_group(req.get_service_group()) {
+                Ok(sg) => Some(sg.to_string()),
+                Err(err) => return grpc::SingleResponse::err(err),
+            }
+        } else {
+            None
+        };
+        let subsystem = if req.has_subsystem() {
+            Some(req.get_subsystem())
+        } else {
+            None
+        };
+        match logger::set_level(req.get_level(),
+                                subsystem,
+                                service_group.as_ref().map(|sg| sg.as_str())) {
+            Ok(()) => grpc::SingleResponse::completed(LogLevelReply::new()),
+            Err(err) => grpc::SingleResponse::err(status(INVALID_ARGUMENT, err.to_string())),
+        }
+    }
+
+    fn get_census(&self,
+                  o: grpc::RequestOptions,
+                  req: CensusRequest)
+                  -> grpc::SingleResponse<Census> {
+        if let Err(err) = authenticate(&o) {
+            return grpc::SingleResponse::err(err);
+        }
+        match census(&self.0, &req) {
+            Ok(census) => grpc::SingleResponse::completed(census),
+            Err(err) => grpc::SingleResponse::err(err),
+        }
+    }
+
+    fn watch_census(&self,
+                    o: grpc::RequestOptions,
+                    req: CensusRequest)
+                    -> grpc::StreamingResponse<Census> {
+        if let Err(err) = authenticate(&o) {
+            return grpc::StreamingResponse::err(err);
+        }
+        let first = match census(&self.0, &req) {
+            Ok(census) => census,
+            Err(err) => return grpc::StreamingResponse::err(err),
+        };
+        let state = self.0.clone();
+        stream("grpc-census-watch", move |mut tx| {
+            let mut last = first;
+            try!(tx.send(last.clone()));
+            loop {
+                thread::sleep(Duration::from_millis(CENSUS_WATCH_INTERVAL_MS));
+                try!(tx.check_open());
+                // A group which has left the census ends a watch of it.
+                let next = try!(census(&state, &req).map_err(|_| ()));
+                if next != last {
+                    try!(tx.send(next.clone()));
+                    last = next;
+                }
+            }
+        })
+    }
+
+    fn follow_logs(&self,
+                   o: grpc::RequestOptions,
+                   req: LogsRequest)
+                   -> grpc::StreamingResponse<LogLine> {
+        if let Err(err) = authenticate(&o) {
+            return grpc::StreamingResponse::err(err);
+        }
+        let service_group = match parse_service_group(req.get_service_group()) {
+            Ok(sg) => sg,
+            Err(err) => return grpc::StreamingResponse::err(err),
+        };
+        if !is_running(&self.0, &service_group) {
+            return grpc::StreamingResponse::err(not_running(&service_group));
+        }
+        let state = self.0.clone();
+        stream("grpc-follow-logs", move |mut tx| {
+            let name = service_group.to_string();
+            let mut query = service_log::Query::default();
+            query.since = req.get_since();
+            if req.has_lines() {
+                query.lines = Some(req.get_lines() as usize);
+            }
+            // The stream ends once the service stops running.
+            while is_running(&state, &service_group) {
+                try!(tx.check_open());
+                let output = service_log::since(&name,
+                                                &query,
+                                                Duration::from_secs(FOLLOW_WAIT_SECS));
+                for line in output.lines {
+                    let mut msg = LogLine::new();
+                    msg.set_seq(line.seq);
+                    msg.set_time(line.time);
+                    msg.set_line(line.line);
+                    try!(tx.send(msg));
+                }
+                query.since = output.next;
+                query.lines = None;
+            }
+            Ok(())
+        })
+    }
+
+    fn force_election(&self,
+                      o: grpc::RequestOptions,
+                      req: ElectionRequest)
+                      -> grpc::SingleResponse<ElectionReply> {
+        if let Err(err) = authenticate(&o) {
+            return grpc::SingleResponse::err(err);
+        }
+        let service_group = match parse_service_group(req.get_service_group()) {
+            Ok(sg) => sg,
+            Err(err) => return grpc::SingleResponse::err(err),
+        };
+        match stand_for_election(&self.0, &service_group, req.get_force()) {
+            Ok(term) => {
+                let mut reply = ElectionReply::new();
+                reply.set_term(term);
+                grpc::SingleResponse::completed(reply)
+            }
+            Err(err) => grpc::SingleResponse::err(err),
+        }
+    }
+}
+
+/// Starts a new election for a service group the Supervisor runs, in which it stands to become
+/// the leader, and returns its term. Unless it's forced, the election is refused while the group
+/// has a leader which is alive, and it's always refused when the Supervisor is the leader.
+fn stand_for_election(state: &manager::State,
+                      service_group: &ServiceGroup,
+                      force: bool)
+                      -> ::std::result::Result<u64, grpc::Error> {
+    {
+        let services = state.services.read().expect("Services lock is poisoned!");
+        let service = match services.iter().find(|s| s.service_group == *service_group) {
+            Some(service) => service,
+            None => return Err(not_running(service_group)),
+        };
+        if service.topology != Topology::Leader && service.topology != Topology::Initializer {
+            return Err(status(FAILED_PRECONDITION,
+                              format!("{} doesn't elect a leader, as its topology is {:?}",
+                                      service_group,
+                                      service.topology)));
+        }
+    }
+    {
+        let census_list = state.census_list.read().expect("Census list lock is poisoned!");
+        if let Some(leader) = census_list.get(&service_group.to_string())
+            .and_then(|census| census.get_leader()) {
+            if leader.member_id == state.butterfly.member_id() {
+                return Err(status(FAILED_PRECONDITION,
+                                  format!("This Supervisor leads {}; ask another member of the \
+                                           group to stand",
+                                          service_group)));
+            }
+            if leader.get_alive() && !force {
+                return Err(status(FAILED_PRECONDITION,
+                                  format!("{} has a leader, {}, which is alive; force the \
+                                           election to replace it",
+                                          service_group,
+                                          leader.member_id)));
+            }
+        }
+    }
+    Ok(state.butterfly.force_election(service_group.clone()))
+}
+
+/// Answers with the messages `produce` sends, which it does from a thread of its own so it may
+/// block between them. The stream ends when `produce` returns, which it should do as soon as a
+/// send fails because the client went away.
+/// Answers with a stream of the messages `produce` sends, from a thread of its own. Refused once
+/// `MAX_STREAMS` streams are open.
+fn stream<T, F>(name: &str, produce: F) -> grpc::StreamingResponse<T>
+    where T: Send + 'static,
+          F: FnOnce(Producer<T>) -> ::std::result::Result<(), ()> + Send + 'static
+{
+    if OPEN_STREAMS.fetch_add(1, Ordering::SeqCst) >= MAX_STREAMS {
+        OPEN_STREAMS.fetch_sub(1, Ordering::SeqCst);
+        return grpc::StreamingResponse::err(status(RESOURCE_EXHAUSTED,
+                                                   format!("{} streams are open already",
+                                                           MAX_STREAMS)));
+    }
+    let (tx, rx) = mpsc::channel(STREAM_BUFFER);
+    let open = Arc::new(AtomicBool::new(true));
+    let producer = Producer {
+        tx: Some(tx),
+        open: open.clone(),
+    };
+    // Should the thread not start, dropping the producer gives back its place in the count.
+    let spawned = thread::Builder::new().name(name.to_string()).spawn(move || produce(producer));
+    if let Err(err) = spawned {
+        return grpc::StreamingResponse::err(status(UNAVAILABLE, err.to_string()));
+    }
+    let consumer = Consumer {
+        inner: rx,
+        open: open,
+    };
+    grpc::StreamingResponse::no_metadata(consumer.map_err(|()| grpc::Error::Other("stream closed")))
+}
+
+/// The sending end of a stream. Its producer learns the client has cancelled the stream or gone
+/// away, once the server drops the `Consumer`, from `send` or `check_open` failing.
+struct Producer<T> {
+    tx: Option<mpsc::Sender<T>>,
+    open: Arc<AtomicBool>,
+}
+
+impl<T> Producer<T> {
+    fn send(&mut self, msg: T) -> ::std::result::Result<(), ()> {
+        try!(self.check_open());
+        let tx = try!(self.tx.take().ok_or(()));
+        self.tx = Some(try!(tx.send(msg).wait().map_err(|_| ())));
+        Ok(())
+    }
+
+    fn check_open(&self) -> ::std::result::Result<(), ()> {
+        if self.open.load(Ordering::SeqCst) {
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl<T> Drop for Producer<T> {
+    fn drop(&mut self) {
+        OPEN_STREAMS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// The receiving end of a stream, which the server holds until the stream ends or its client
+/// cancels it or goes away.
+struct Consumer<S> {
+    inner: S,
+    open: Arc<AtomicBool>,
+}
+
+impl<S: Stream> Stream for Consumer<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<S::Item>, S::Error> {
+        self.inner.poll()
+    }
+}
+
+impl<S> Drop for Consumer<S> {
+    fn drop(&mut self) {
+        self.open.store(false, Ordering::SeqCst);
+    }
+}
+
+fn census(state: &manager::State,
+          req: &CensusRequest)
+          -> ::std::result::Result<Census, grpc::Error> {
+    let census_list = state.census_list.read().expect("Census list lock is poisoned!");
+    let mut census = Census::new();
+    if req.has_service_group() {
+        let service_group = try!(parse_service_group(req.get_service_group()));
+        let name = service_group.to_string();
+        match census_list.get(&name) {
+            Some(group) => census.mut_groups().push(census_group(&name, group)),
+            None => return Err(status(NOT_FOUND, format!("{} isn't in the census", name))),
+        }
+    } else {
+        let mut names: Vec<&String> = census_list.keys().collect();
+        names.sort();
+        for name in names {
+            census.mut_groups().push(census_group(name, &census_list[name]));
+        }
+    }
+    Ok(census)
+}
+
+fn census_group(name: &str, group: &ServiceCensus) -> CensusGroup {
+    let mut msg = CensusGroup::new();
+    msg.set_service_group(name.to_string());
+    if let Some(count) = group.desired_count {
+        msg.set_desired_count(count);
+    }
+    if let Some(color) = group.active_color.as_ref() {
+        msg.set_active_color(color.clone());
+    }
+    for entry in group.members_ordered() {
+        let mut member = CensusMember::new();
+        member.set_member_id(entry.member_id.clone());
+        if let Some(ref alias) = entry.alias {
+            member.set_alias(alias.clone());
+        }
+        member.set_hostname(entry.hostname.clone());
+        member.set_address(entry.address.clone());
+        member.set_port(entry.port.clone());
+        if let Some(ref pkg) = entry.pkg {
+            member.set_package(pkg.to_string());
+        }
+        member.set_leader(entry.get_leader());
+        member.set_follower(entry.get_follower());
+        member.set_initialized(entry.get_initialized());
+        member.set_alive(entry.get_alive());
+        member.set_suspect(entry.get_suspect());
+        member.set_confirmed(entry.get_confirmed());
+        member.set_persistent(entry.get_persistent());
+        msg.mut_members().push(member);
+    }
+    msg
+}
+
+fn is_running(state: &manager::State, service_group: &ServiceGroup) -> bool {
+    state.services
+        .read()
+        .expect("Services lock is poisoned!")
+        .iter()
+        .any(|s| s.service_group == *service_group)
+}
+
+/// Refuses requests which don't present the auth token in their `authorization` metadata, when
+/// one is set. It's the HTTP gateway's token, presented the same way.
+fn authenticate(o: &grpc::RequestOptions) -> ::std::result::Result<(), grpc::Error> {
+    if http_gateway::authorized(o.metadata.get("authorization")) {
+        Ok(())
+    } else {
+        Err(status(UNAUTHENTICATED, String::from("missing or wrong auth token")))
+    }
+}
+
+fn parse_service_group(value: &str) -> ::std::result::Result<ServiceGroup, grpc::Error> {
+    ServiceGroup::from_str(value).map_err(|err| status(INVALID_ARGUMENT, err.to_string()))
+}
+
+fn not_running(service_group: &ServiceGroup) -> grpc::Error {
+    status(NOT_FOUND,
+           format!("{} isn't running under this Supervisor", service_group))
+}
+
+fn status(code: i32, message: String) -> grpc::Error {
+    grpc::Error::GrpcMessage(grpc::GrpcMessageError {
+        grpc_status: code,
+        grpc_message: message,
+    })
+}
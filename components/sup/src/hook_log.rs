@@ -0,0 +1,219 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A bounded, in-memory log of the output of every hook the Supervisor runs.
+//!
+//! Each line gets a sequence number, so clients of the HTTP gateway can follow the log by asking
+//! for the lines since the last one they saw, optionally waiting for new ones to arrive. Only the
+//! most recent `HOOK_LOG_CAPACITY` lines are kept; a client which falls further behind than that
+//! skips ahead.
+
+use std::collections::VecDeque;
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+/// The number of lines of hook output kept in memory.
+pub const HOOK_LOG_CAPACITY: usize = 1000;
+/// The longest a client may wait for new output in a single request.
+pub const MAX_WAIT_SECS: u64 = 30;
+
+lazy_static! {
+    static ref HOOK_LOG: HookLog = HookLog::new(HOOK_LOG_CAPACITY);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HookLine {
+    pub seq: u64,
+    pub service_group: String,
+    pub hook: String,
+    pub stream: &'static str,
+    pub line: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RunningHook {
+    pub service_group: String,
+    pub hook: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HookOutput {
+    /// The sequence number to ask for next time.
+    pub next: u64,
+    pub lines: Vec<HookLine>,
+    /// How many lines numbered `since` and up were dropped to keep the log to its capacity before
+    /// they were asked for. These are lines of every service group, so a client following only one
+    /// group missed at most this many.
+    pub skipped: u64,
+    /// Hooks which are executing right now.
+    pub running: Vec<RunningHook>,
+}
+
+pub struct HookLog {
+    capacity: usize,
+    inner: Mutex<Inner>,
+    updated: Condvar,
+}
+
+struct Inner {
+    next_seq: u64,
+    lines: VecDeque<HookLine>,
+    running: Vec<(u64, RunningHook)>,
+    next_running_id: u64,
+}
+
+/// Marks a hook as running until dropped.
+pub struct Running {
+    id: u64,
+}
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        let mut inner = HOOK_LOG.inner.lock().expect("Hook log lock is poisoned!");
+        inner.running.retain(|&(id, _)| id != self.id);
+    }
+}
+
+impl HookLog {
+    pub fn new(capacity: usize) -> Self {
+        HookLog {
+            capacity: capacity,
+            inner: Mutex::new(Inner {
+                next_seq: 0,
+                lines: VecDeque::new(),
+                running: Vec::new(),
+                next_running_id: 0,
+            }),
+            updated: Condvar::new(),
+        }
+    }
+
+    pub fn push(&self, service_group: &str, hook: &str, stream: &'static str, line: &str) {
+        let mut inner = self.inner.lock().expect("Hook log lock is poisoned!");
+        let seq = inner.next_seq;
+        inner.next_seq += 1;
+        inner.lines.push_back(HookLine {
+            seq: seq,
+            service_group: service_group.to_string(),
+            hook: hook.to_string(),
+            stream: stream,
+            line: line.to_string(),
+        });
+        while inner.lines.len() > self.capacity {
+            inner.lines.pop_front();
+        }
+        self.updated.notify_all();
+    }
+
+    /// Return the lines numbered `since` and up, for every service group or only the given one.
+    /// If there are none yet, wait up to `wait` for some to arrive.
+    pub fn since(&self, since: u64, service_group: Option<&str>, wait: Duration) -> HookOutput {
+        let deadline = Instant::now() + wait;
+        let mut inner = self.inner.lock().expect("Hook log lock is poisoned!");
+        loop {
+            let lines: Vec<HookLine> = inner.lines
+                .iter()
+                .filter(|l| l.seq >= since)
+                .filter(|l| service_group.map_or(true, |sg| l.service_group == sg))
+                .cloned()
+                .collect();
+            let now = Instant::now();
+            if !lines.is_empty() || now >= deadline {
+                let oldest = inner.lines.front().map_or(inner.next_seq, |l| l.seq);
+                return HookOutput {
+                    next: inner.next_seq,
+                    lines: lines,
+                    skipped: oldest.saturating_sub(since),
+                    running: inner.running
+                        .iter()
+                        .map(|&(_, ref r)| r.clone())
+                        .filter(|r| service_group.map_or(true, |sg| r.service_group == sg))
+                        .collect(),
+                };
+            }
+            inner = self.updated
+                .wait_timeout(inner, deadline - now)
+                .expect("Hook log lock is poisoned!")
+                .0;
+        }
+    }
+}
+
+/// Append a line of hook output to the Supervisor's hook log.
+pub fn push(service_group: &str, hook: &str, stream: &'static str, line: &str) {
+    HOOK_LOG.push(service_group, hook, stream, line)
+}
+
+/// Record that a hook is running, until the returned guard is dropped.
+pub fn running(service_group: &str, hook: &str) -> Running {
+    let mut inner = HOOK_LOG.inner.lock().expect("Hook log lock is poisoned!");
+    let id = inner.next_running_id;
+    inner.next_running_id += 1;
+    inner.running.push((id,
+                        RunningHook {
+                            service_group: service_group.to_string(),
+                            hook: hook.to_string(),
+                        }));
+    Running { id: id }
+}
+
+/// See `HookLog::since`. The wait is capped at `MAX_WAIT_SECS`.
+pub fn since(since: u64, service_group: Option<&str>, wait: Duration) -> HookOutput {
+    let wait = ::std::cmp::min(wait, Duration::from_secs(MAX_WAIT_SECS));
+    HOOK_LOG.since(since, service_group, wait)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::HookLog;
+
+    #[test]
+    fn since_returns_newer_lines() {
+        let log = HookLog::new(10);
+        log.push("redis.default", "init", "stdout", "one");
+        log.push("nginx.default", "init", "stderr", "two");
+        log.push("redis.default", "init", "stdout", "three");
+        let output = log.since(1, None, Duration::from_millis(0));
+        assert_eq!(output.next, 3);
+        assert_eq!(output.lines.iter().map(|l| l.line.as_str()).collect::<Vec<_>>(),
+                   vec!["two", "three"]);
+        let output = log.since(0, Some("redis.default"), Duration::from_millis(0));
+        assert_eq!(output.lines.len(), 2);
+    }
+
+    #[test]
+    fn since_drops_lines_past_capacity() {
+        let log = HookLog::new(2);
+        for line in &["one", "two", "three"] {
+            log.push("redis.default", "init", "stdout", line);
+        }
+        let output = log.since(0, None, Duration::from_millis(0));
+        assert_eq!(output.lines[0].seq, 1);
+        assert_eq!(output.next, 3);
+        assert_eq!(output.skipped, 1);
+    }
+
+    #[test]
+    fn since_skips_nothing_for_other_groups_lines() {
+        let log = HookLog::new(10);
+        log.push("redis.default", "init", "stdout", "one");
+        log.push("nginx.default", "init", "stdout", "two");
+        log.push("redis.default", "init", "stdout", "three");
+        let output = log.since(0, Some("redis.default"), Duration::from_millis(0));
+        assert_eq!(output.lines.iter().map(|l| l.seq).collect::<Vec<_>>(), vec![0, 2]);
+        assert_eq!(output.skipped, 0);
+    }
+}
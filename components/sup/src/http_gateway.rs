@@ -12,30 +12,96 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
-use std::io;
+use std::ascii::AsciiExt;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::hash_map::DefaultHasher;
+use std::error;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io::{self, Read, Write};
+use std::iter;
 use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs, SocketAddr, SocketAddrV4};
 use std::ops::{Deref, DerefMut};
 use std::option;
-use std::str::FromStr;
+use std::slice;
+use std::str::{self, FromStr};
+use std::sync::{Arc, Mutex, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
 use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 
+use butterfly::server::latency::PeerLatency;
+use hcore;
+use hcore::package::VulnerabilityDb;
 use hcore::service::ServiceGroup;
+use hyper;
+use hyper::net::{HttpStream, SslServer};
+use hyper_openssl::{OpensslServer, SslStream};
+use iron::headers::{ContentLength, ETag, EntityTag, IfNoneMatch};
+use iron::method::Method;
+use iron::middleware::{AroundMiddleware, BeforeMiddleware, Handler};
 use iron::prelude::*;
+use iron::response::WriteBody;
 use iron::status;
 use iron::typemap;
+use openssl::memcmp;
+use openssl::pkey::PKey;
+use openssl::ssl::{SslAcceptorBuilder, SslMethod};
+use openssl::x509::X509;
 use persistent;
 use router::Router;
 use serde_json;
-use prometheus::{CounterVec, HistogramVec, TextEncoder, Encoder};
+use time;
+use toml;
+use prometheus::{Counter, CounterVec, Gauge, GaugeVec, HistogramVec, TextEncoder, Encoder};
 use prometheus;
 
+use api_doc::ApiDoc;
+use audit::Audit;
+#[cfg(feature = "chaos")]
+use chaos::{self, Faults};
 use config::gconfig;
+use diagnostics::{self, Diagnostics};
+use envoy::{EndpointSource, Snapshot};
 use error::{Result, Error, SupError};
 use health_check;
+use hook_log;
+use logger;
 use manager;
+use manager::aws_config;
+use manager::bastion::Bastion;
+use manager::census::{CensusEntry, CensusQuery};
+use manager::service::dry_run;
+use probes::{Liveness, Readiness};
+use service_history;
+use service_log;
 
 static LOGKEY: &'static str = "HG";
+/// The names of the gateway's endpoints, as used to disable them or restrict them to local
+/// clients. The `services/:svc/:group/*` endpoints are named by their last path segment.
+pub const ENDPOINTS: &'static [&'static str] = &["api-doc", "audit", "bastion", "butterfly",
+                                                 "census", "chaos", "config", "config-files",
+                                                 "diagnostics", "dry-run", "health", "history",
+                                                 "hooks", "liveness", "log-level", "logs",
+                                                 "metrics", "readiness", "ring-commands", "scale",
+                                                 "schedule", "services", "v2"];
+/// Default size limit of a gateway request, counting its URL and body. No endpoint takes more
+/// than a short query string or a service's configuration, so anything larger is refused before a
+/// worker spends time on it.
+pub const DEFAULT_HTTP_MAX_REQUEST_BYTES: u64 = 64 * 1024;
+/// Default number of seconds the gateway waits on a client reading a request or writing its
+/// response, so slow or stalled clients release their worker.
+pub const DEFAULT_HTTP_REQUEST_TIMEOUT: u64 = 30;
+/// How long a serialized census or services response is reused. Monitoring systems polling a
+/// large ring then share one serialization per interval instead of causing one each.
+const RESPONSE_CACHE_MS: u64 = 1_000;
+
+/// Requests waiting on new output with `?wait=` right now. At most half of the gateway's workers
+/// may wait at once, so followers can't tie up the ones every other endpoint needs.
+static LONG_POLLS: AtomicUsize = ATOMIC_USIZE_INIT;
+
+/// The number of threads serving gateway requests: `--http-threads`, or else Iron's default.
+static HTTP_THREADS: AtomicUsize = ATOMIC_USIZE_INIT;
 
 
 lazy_static! {
@@ -50,6 +116,67 @@ lazy_static! {
             "http_request_duration_seconds",
             "HTTP request latencies in seconds."),
         &["handler"]).unwrap();
+
+    static ref GOSSIP_PAYLOAD_BYTES: Counter = register_counter!(
+        opts!(
+            "gossip_payload_bytes_total",
+            "Bytes of gossip sent, before compression."))
+        .unwrap();
+
+    static ref GOSSIP_WIRE_BYTES: Counter = register_counter!(
+        opts!(
+            "gossip_wire_bytes_total",
+            "Bytes of gossip sent, after compression and encryption."))
+        .unwrap();
+
+    static ref GOSSIP_COMPRESSION_RATIO: Gauge = register_gauge!(
+        opts!(
+            "gossip_compression_ratio",
+            "Bytes of gossip sent for every byte before compression."))
+        .unwrap();
+
+    static ref GOSSIP_PING_RTT: GaugeVec = register_gauge_vec!(
+        opts!(
+            "gossip_ping_rtt_seconds",
+            "Smoothed round trip time of the failure detector's pings to each member."),
+        &["member_id"]).unwrap();
+
+    static ref GOSSIP_PING_TIMEOUTS: CounterVec = register_counter_vec!(
+        opts!(
+            "gossip_ping_timeouts_total",
+            "Pings to each member which weren't acked in time."),
+        &["member_id"]).unwrap();
+
+    /// The members the ping metrics were last set for, so departed members can be dropped.
+    static ref GOSSIP_PING_MEMBERS: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+
+    static ref RESPONSE_CACHE: Mutex<HashMap<&'static str, CachedResponse>> =
+        Mutex::new(HashMap::new());
+
+    static ref AUTH_TOKEN: RwLock<Option<String>> = RwLock::new(None);
+}
+
+/// A serialized response body, and the entity tag it is served with.
+struct CachedResponse {
+    body: String,
+    etag: String,
+    created: Instant,
+}
+
+impl CachedResponse {
+    fn new(body: String) -> Self {
+        let mut hasher = DefaultHasher::new();
+        body.hash(&mut hasher);
+        CachedResponse {
+            etag: format!("{:016x}", hasher.finish()),
+            body: body,
+            created: Instant::now(),
+        }
+    }
+
+    fn expired(&self) -> bool {
+        self.created.elapsed() >= Duration::from_millis(RESPONSE_CACHE_MS)
+    }
 }
 
 #[derive(PartialEq, Eq, Debug)]
@@ -111,6 +238,87 @@ impl typemap::Key for ManagerState {
 
 pub struct Server(Iron<Chain>);
 
+/// A route of the gateway. The router and the OpenAPI description served at `/api-doc` are both
+/// built from `routes()`.
+pub struct Route {
+    pub id: &'static str,
+    pub method: &'static str,
+    pub path: &'static str,
+    handler: fn(&mut Request) -> IronResult<Response>,
+    /// The handler label the route's requests are counted under in the gateway's metrics.
+    metric: &'static str,
+}
+
+macro_rules! route {
+    ($id:expr, $method:ident $path:expr => $handler:ident, $metric:expr) => {
+        Route {
+            id: $id,
+            method: stringify!($method),
+            path: $path,
+            handler: $handler,
+            metric: $metric,
+        }
+    }
+}
+
+pub static ROUTES: &'static [Route] = &[
+    route!("api_doc", get "/api-doc" => api_doc, "api_doc"),
+    route!("audit", get "/audit" => audit, "audit"),
+    route!("bastion", get "/bastion" => bastion, "bastion"),
+    route!("butterfly", get "/butterfly" => butterfly, "butterfly"),
+    route!("census", get "/census" => census, "census"),
+    route!("diagnostics", get "/diagnostics" => diagnostics, "diagnostics"),
+    route!("memory_maps", post "/diagnostics/memory-maps" => memory_maps, "memory_maps"),
+    route!("hook_output", get "/hooks/output" => hook_output, "hooks"),
+    route!("liveness", get "/liveness" => liveness, "liveness"),
+    route!("log_level", post "/log-level" => log_level, "log_level"),
+    route!("metrics", get "/metrics" => metrics, "metrics"),
+    route!("readiness", get "/readiness" => readiness, "readiness"),
+    route!("ring_commands", get "/ring-commands" => ring_commands, "ring_commands"),
+    route!("scale", get "/scale" => scale, "scale"),
+    route!("services", get "/services" => services, "services"),
+    route!("envoy_clusters", post "/v2/discovery:clusters" => envoy_clusters, "envoy"),
+    route!("envoy_endpoints", post "/v2/discovery:endpoints" => envoy_endpoints, "envoy"),
+    route!("service_config", get "/services/:svc/:group/config" => config, "config"),
+    route!("service_config_files",
+           get "/services/:svc/:group/config-files" => config_files,
+           "config_files"),
+    route!("service_dry_run", post "/services/:svc/:group/dry-run" => dry_run, "dry_run"),
+    route!("service_health", get "/services/:svc/:group/health" => health, "health"),
+    route!("service_history", get "/services/:svc/:group/history" => history, "history"),
+    route!("service_logs", get "/services/:svc/:group/logs" => logs, "logs"),
+    route!("service_schedule", get "/services/:svc/:group/schedule" => schedule, "schedule"),
+    route!("service_config_org", get "/services/:svc/:group/:org/config" => config, "config"),
+    route!("service_config_files_org",
+           get "/services/:svc/:group/:org/config-files" => config_files,
+           "config_files"),
+    route!("service_dry_run_org",
+           post "/services/:svc/:group/:org/dry-run" => dry_run,
+           "dry_run"),
+    route!("service_health_org", get "/services/:svc/:group/:org/health" => health, "config"),
+    route!("service_history_org",
+           get "/services/:svc/:group/:org/history" => history,
+           "history"),
+    route!("service_logs_org", get "/services/:svc/:group/:org/logs" => logs, "logs"),
+    route!("service_schedule_org",
+           get "/services/:svc/:group/:org/schedule" => schedule,
+           "schedule"),
+];
+
+/// Routes that only exist in a Supervisor built with the `chaos` feature.
+#[cfg(feature = "chaos")]
+pub static CHAOS_ROUTES: &'static [Route] = &[
+    route!("chaos", get "/chaos" => chaos, "chaos"),
+    route!("set_chaos", post "/chaos" => set_chaos, "chaos"),
+];
+#[cfg(not(feature = "chaos"))]
+pub static CHAOS_ROUTES: &'static [Route] = &[];
+
+/// Every route the gateway serves.
+pub fn routes() -> iter::Chain<slice::Iter<'static, Route>, slice::Iter<'static, Route>> {
+    ROUTES.iter().chain(CHAOS_ROUTES.iter())
+}
+
 
 // Simple macro to encapsulate the HTTP metrics for each endpoint
 macro_rules! with_metrics {
@@ -128,51 +336,666 @@ macro_rules! with_metrics {
 
 impl Server {
     pub fn new(manager_state: manager::State) -> Self {
-        let router = router!(
-            butterfly: get "/butterfly" => with_metrics!(butterfly, "butterfly"),
-            census: get "/census" => with_metrics!(census, "census"),
-            metrics: get "/metrics" => with_metrics!(metrics, "metrics"),
-            services: get "/services" => with_metrics!(services, "services"),
-            service_config: get "/services/:svc/:group/config" => with_metrics!(config, "config"),
-            service_health: get "/services/:svc/:group/health" => with_metrics!(health, "health"),
-            service_config_org: get "/services/:svc/:group/:org/config" => with_metrics!(config, "config"),
-            service_health_org: get "/services/:svc/:group/:org/health" => with_metrics!(health, "config"),
-        );
+        let mut router = Router::new();
+        for route in routes() {
+            let method = if route.method == "post" {
+                Method::Post
+            } else {
+                Method::Get
+            };
+            router.route(method,
+                         route.path,
+                         with_metrics!(route.handler, route.metric),
+                         route.id);
+        }
         let mut chain = Chain::new(router);
+        chain.link_before(RequestLimits);
+        chain.link_before(Authentication);
+        chain.link_before(EndpointFilter);
         chain.link(persistent::Read::<ManagerState>::both(manager_state));
-        Server(Iron::new(chain))
+        chain.link_around(Cors);
+        let mut server = Iron::new(chain);
+        if let Some(threads) = gconfig().http_threads() {
+            server.threads = threads;
+        }
+        HTTP_THREADS.store(server.threads, Ordering::SeqCst);
+        let timeout = Duration::from_secs(gconfig()
+            .http_request_timeout()
+            .unwrap_or(DEFAULT_HTTP_REQUEST_TIMEOUT));
+        server.timeouts.read = Some(timeout);
+        server.timeouts.write = Some(timeout);
+        Server(server)
     }
 
-    pub fn start(self) -> Result<JoinHandle<()>> {
+    /// Starts serving, over TLS if `tls` is given.
+    pub fn start(self, tls: Option<Tls>) -> Result<JoinHandle<()>> {
         let handle = try!(thread::Builder::new()
             .name("http-gateway".to_string())
             .spawn(move || {
-                self.0
-                    .http(*gconfig().http_listen_addr())
-                    .expect("unable to start http-gateway thread");
+                let addr = *gconfig().http_listen_addr();
+                let listening = match tls {
+                    Some(tls) => self.0.https(addr, tls),
+                    None => self.0.http(addr),
+                };
+                listening.expect("unable to start http-gateway thread");
             }));
         Ok(handle)
     }
 }
 
+/// The certificate and key the gateway serves TLS with. They can be replaced while it's serving,
+/// and connections accepted afterwards use the new ones.
+#[derive(Clone, Default)]
+pub struct Tls(Arc<RwLock<Option<OpensslServer>>>);
+
+impl Tls {
+    pub fn new() -> Self {
+        Tls::default()
+    }
+
+    /// Replaces the certificate and key with the given PEM documents. `cert_pem` holds the
+    /// gateway's certificate, followed by any intermediate certificates.
+    ///
+    /// # Failures
+    ///
+    /// * Either document can't be parsed
+    /// * The key isn't the certificate's
+    pub fn load(&self, cert_pem: &[u8], key_pem: &[u8]) -> Result<()> {
+        let mut chain = try!(X509::stack_from_pem(cert_pem).map_err(|e| tls_failed(&e)));
+        if chain.is_empty() {
+            return Err(tls_failed(&"no certificate found"));
+        }
+        let cert = chain.remove(0);
+        let key = try!(PKey::private_key_from_pem(key_pem).map_err(|e| tls_failed(&e)));
+        let mut builder = try!(SslAcceptorBuilder::mozilla_intermediate(SslMethod::tls(),
+                                                                         &key,
+                                                                         &cert,
+                                                                         &chain)
+            .map_err(|e| tls_failed(&e)));
+        try!(builder.builder_mut().check_private_key().map_err(|e| tls_failed(&e)));
+        *self.0.write().expect("TLS lock is poisoned!") =
+            Some(OpensslServer::from(builder.build()));
+        Ok(())
+    }
+}
+
+impl SslServer for Tls {
+    type Stream = SslStream<HttpStream>;
+
+    fn wrap_server(&self, stream: HttpStream) -> hyper::Result<Self::Stream> {
+        match *self.0.read().expect("TLS lock is poisoned!") {
+            Some(ref server) => server.wrap_server(stream),
+            None => {
+                Err(hyper::Error::Ssl(Box::new(io::Error::new(io::ErrorKind::Other,
+                                                              "no certificate loaded"))))
+            }
+        }
+    }
+}
+
+fn tls_failed<E: fmt::Display>(e: &E) -> SupError {
+    sup_error!(Error::InvalidSecretFile(format!("TLS certificate or key: {}", e)))
+}
+
+/// Sets the token clients must present, as `Authorization: Bearer <token>`, from the contents of
+/// a token file.
+///
+/// # Failures
+///
+/// * The file holds no token
+pub fn set_auth_token(contents: &[u8]) -> Result<()> {
+    let token = match str::from_utf8(contents) {
+        Ok(token) if !token.trim().is_empty() => token.trim().to_string(),
+        _ => {
+            let e = String::from("the gateway auth token file holds no token");
+            return Err(sup_error!(Error::InvalidSecretFile(e)));
+        }
+    };
+    *AUTH_TOKEN.write().expect("Auth token lock is poisoned!") = Some(token);
+    Ok(())
+}
+
+/// Returns whether clients must present a token.
+pub fn auth_token_set() -> bool {
+    AUTH_TOKEN.read().expect("Auth token lock is poisoned!").is_some()
+}
+
+/// Returns whether a request with this `Authorization` header may use the gateways. Any may when
+/// no token is set.
+pub fn authorized(header: Option<&[u8]>) -> bool {
+    match *AUTH_TOKEN.read().expect("Auth token lock is poisoned!") {
+        Some(ref token) => header.map_or(false, |h| bearer_matches(token, h)),
+        None => true,
+    }
+}
+
+fn bearer_matches(token: &str, header: &[u8]) -> bool {
+    const BEARER: &'static [u8] = b"Bearer ";
+    // The lengths are compared first since `memcmp::eq` requires them to be equal; the token's
+    // length isn't a secret worth protecting, unlike its bytes.
+    header.len() == BEARER.len() + token.len() &&
+    header[..BEARER.len()].eq_ignore_ascii_case(BEARER) &&
+    memcmp::eq(&header[BEARER.len()..], token.as_bytes())
+}
+
+
+/// Refuses requests for endpoints the operator has disabled, or restricted to clients on the
+/// loopback interface.
+struct EndpointFilter;
+
+impl BeforeMiddleware for EndpointFilter {
+    fn before(&self, req: &mut Request) -> IronResult<()> {
+        let endpoint = match endpoint_name(&req.url.path()) {
+            Some(endpoint) => endpoint,
+            None => return Ok(()),
+        };
+        if gconfig().http_disable().iter().any(|e| e == endpoint) {
+            return Err(IronError::new(EndpointRefused(endpoint.to_string()), status::NotFound));
+        }
+        if gconfig().http_local_only().iter().any(|e| e == endpoint) &&
+           !req.remote_addr.ip().is_loopback() {
+            return Err(IronError::new(EndpointRefused(endpoint.to_string()), status::Forbidden));
+        }
+        Ok(())
+    }
+}
+
+/// Refuses requests which don't present the auth token, when one is set. Liveness and readiness
+/// probes, and CORS preflight requests, can't present one and are let through.
+struct Authentication;
+
+impl BeforeMiddleware for Authentication {
+    fn before(&self, req: &mut Request) -> IronResult<()> {
+        if req.method == Method::Options {
+            return Ok(());
+        }
+        match endpoint_name(&req.url.path()) {
+            Some("liveness") | Some("readiness") => return Ok(()),
+            _ => {}
+        }
+        let header = match req.headers.get_raw("Authorization") {
+            Some(values) if values.len() == 1 => Some(&values[0][..]),
+            _ => None,
+        };
+        if authorized(header) {
+            Ok(())
+        } else {
+            Err(IronError::new(Unauthenticated, status::Unauthorized))
+        }
+    }
+}
+
+/// Refuses requests whose URL or declared body is larger than the configured limit.
+struct RequestLimits;
+
+impl BeforeMiddleware for RequestLimits {
+    fn before(&self, req: &mut Request) -> IronResult<()> {
+        let max = gconfig().http_max_request_bytes().unwrap_or(DEFAULT_HTTP_MAX_REQUEST_BYTES);
+        let url_len = format!("{}", req.url).len() as u64;
+        if url_len > max {
+            return Err(IronError::new(RequestTooLarge(url_len), status::UriTooLong));
+        }
+        if let Some(&ContentLength(len)) = req.headers.get::<ContentLength>() {
+            if url_len + len > max {
+                return Err(IronError::new(RequestTooLarge(url_len + len),
+                                          status::PayloadTooLarge));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Adds CORS headers to the responses of requests from an allowed origin, and answers their
+/// preflight requests, so browser-based dashboards can read the gateway.
+struct Cors;
+
+impl AroundMiddleware for Cors {
+    fn around(self, handler: Box<Handler>) -> Box<Handler> {
+        Box::new(CorsHandler(handler))
+    }
+}
+
+struct CorsHandler(Box<Handler>);
+
+impl Handler for CorsHandler {
+    fn handle(&self, req: &mut Request) -> IronResult<Response> {
+        let allowed = match req.headers.get_raw("Origin") {
+            Some(values) if values.len() == 1 => allowed_origin(gconfig().http_cors_origins(),
+                                                                &values[0]),
+            _ => None,
+        };
+        let allowed = match allowed {
+            Some(allowed) => allowed,
+            None => return self.0.handle(req),
+        };
+        if req.method == Method::Options {
+            let mut response = Response::with(status::NoContent);
+            response.headers.set_raw("Access-Control-Allow-Methods",
+                                     vec![b"GET, POST, OPTIONS".to_vec()]);
+            response.headers.set_raw("Access-Control-Allow-Headers",
+                                     vec![b"Authorization, Content-Type".to_vec()]);
+            response.headers.set_raw("Access-Control-Max-Age", vec![b"600".to_vec()]);
+            add_cors_headers(&mut response, allowed);
+            return Ok(response);
+        }
+        match self.0.handle(req) {
+            Ok(mut response) => {
+                add_cors_headers(&mut response, allowed);
+                Ok(response)
+            }
+            Err(mut err) => {
+                add_cors_headers(&mut err.response, allowed);
+                Err(err)
+            }
+        }
+    }
+}
+
+fn add_cors_headers(response: &mut Response, origin: &str) {
+    response.headers.set_raw("Access-Control-Allow-Origin", vec![origin.as_bytes().to_vec()]);
+    response.headers.set_raw("Vary", vec![b"Origin".to_vec()]);
+}
+
+/// Returns the `Access-Control-Allow-Origin` value for a request's `Origin` header, if that
+/// origin is allowed. Only configured origins are echoed back, never the raw header, so a
+/// malformed or non-ASCII `Origin` can't end up in the response.
+fn allowed_origin<'a>(allowed: &'a [String], origin: &[u8]) -> Option<&'a str> {
+    if allowed.iter().any(|o| o == "*") {
+        return Some("*");
+    }
+    allowed.iter().find(|o| o.as_bytes().eq_ignore_ascii_case(origin)).map(|o| o.as_str())
+}
+
+#[derive(Debug)]
+struct RequestTooLarge(u64);
+
+impl fmt::Display for RequestTooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "HTTP gateway request of {} bytes is over the limit of {} bytes",
+               self.0,
+               gconfig().http_max_request_bytes().unwrap_or(DEFAULT_HTTP_MAX_REQUEST_BYTES))
+    }
+}
+
+impl error::Error for RequestTooLarge {
+    fn description(&self) -> &str {
+        "HTTP gateway request is too large"
+    }
+}
+
+#[derive(Debug)]
+struct Unauthenticated;
+
+impl fmt::Display for Unauthenticated {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HTTP gateway request has no valid auth token")
+    }
+}
+
+impl error::Error for Unauthenticated {
+    fn description(&self) -> &str {
+        "HTTP gateway request has no valid auth token"
+    }
+}
+
+#[derive(Debug)]
+struct EndpointRefused(String);
+
+impl fmt::Display for EndpointRefused {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HTTP gateway endpoint {} is not available", self.0)
+    }
+}
+
+impl error::Error for EndpointRefused {
+    fn description(&self) -> &str {
+        "HTTP gateway endpoint is not available"
+    }
+}
+
+/// Maps a request path to the name of the endpoint serving it. See `ENDPOINTS`.
+fn endpoint_name<'a>(path: &[&'a str]) -> Option<&'a str> {
+    match path.first() {
+        Some(&"services") if path.len() > 1 => path.last().map(|s| *s),
+        Some(segment) => ENDPOINTS.iter().find(|e| *e == segment).map(|_| *segment),
+        None => None,
+    }
+}
+
+/// Returns the OpenAPI description of the gateway's routes.
+fn api_doc(_req: &mut Request) -> IronResult<Response> {
+    let doc = ApiDoc::new(routes().map(|r| (r.id, r.method, r.path)));
+    Ok(Response::with((status::Ok, serde_json::to_string(&doc).unwrap())))
+}
+
+/// Returns the known vulnerabilities in each service's packages, or Not Found if no vulnerability
+/// database is installed.
+fn audit(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let db = match VulnerabilityDb::load(VulnerabilityDb::default_path(None)) {
+        Ok(db) => db,
+        Err(hcore::Error::FileNotFound(_)) => return Ok(Response::with(status::NotFound)),
+        Err(err) => {
+            error!("Couldn't load vulnerability database, err={:?}", err);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    };
+    let data = Audit::new(&db, &state);
+    Ok(Response::with((status::Ok, serde_json::to_string(&data).unwrap())))
+}
+
+/// A page of census entries, written out as JSON an entry at a time. Without a content length the
+/// response is sent with chunked encoding.
+struct CensusPage {
+    total: usize,
+    entries: Vec<CensusEntry>,
+    query: CensusQuery,
+}
+
+impl CensusPage {
+    /// Returns the entry as JSON, keeping only the fields asked for.
+    fn entry_json(&self, entry: &CensusEntry) -> serde_json::Value {
+        match serde_json::to_value(entry) {
+            serde_json::Value::Object(map) => {
+                if self.query.fields.is_empty() {
+                    serde_json::Value::Object(map)
+                } else {
+                    serde_json::Value::Object(map.into_iter()
+                        .filter(|&(ref k, _)| self.query.fields.contains(k))
+                        .collect())
+                }
+            }
+            value => value,
+        }
+    }
+}
+
+impl WriteBody for CensusPage {
+    fn write_body(&mut self, res: &mut Write) -> io::Result<()> {
+        try!(write!(res,
+                    "{{\"total\":{},\"offset\":{},\"limit\":{},\"entries\":[",
+                    self.total,
+                    self.query.offset,
+                    self.query.limit));
+        for (i, entry) in self.entries.iter().enumerate() {
+            if i > 0 {
+                try!(res.write_all(b","));
+            }
+            let json = try!(serde_json::to_vec(&self.entry_json(entry))
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e)));
+            try!(res.write_all(&json));
+        }
+        res.write_all(b"]}")
+    }
+}
+
+/// Responds with the JSON `render` returns, reusing the body serialized for `name` until it
+/// expires, and with Not Modified if the client already has it.
+///
+/// The cache lock is held while rendering, so concurrent requests wait for a single
+/// serialization rather than each making their own.
+fn cached_json<F>(req: &Request, name: &'static str, render: F) -> IronResult<Response>
+    where F: FnOnce() -> String
+{
+    let mut cache = RESPONSE_CACHE.lock().expect("Response cache lock poisoned");
+    let fresh = cache.get(name).map_or(false, |cached| !cached.expired());
+    if !fresh {
+        cache.insert(name, CachedResponse::new(render()));
+    }
+    let cached = &cache[name];
+    let etag = EntityTag::strong(cached.etag.clone());
+    let not_modified = match req.headers.get::<IfNoneMatch>() {
+        Some(&IfNoneMatch::Any) => true,
+        Some(&IfNoneMatch::Items(ref tags)) => tags.iter().any(|tag| tag.weak_eq(&etag)),
+        None => false,
+    };
+    let mut response = if not_modified {
+        Response::with(status::NotModified)
+    } else {
+        Response::with((status::Ok, cached.body.clone()))
+    };
+    response.headers.set(ETag(etag));
+    Ok(response)
+}
+
+/// Identifies a bastion, with how it gossips and the members it knows of, or returns Not Found
+/// from any other Supervisor.
+fn bastion(req: &mut Request) -> IronResult<Response> {
+    if !gconfig().bastion() {
+        return Ok(Response::with((status::NotFound, "not a bastion")));
+    }
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let data = Bastion::new(&state.butterfly);
+    Ok(Response::with((status::Ok, serde_json::to_string(&data).unwrap())))
+}
 
 fn butterfly(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerState>>().unwrap();
     Ok(Response::with((status::Ok, serde_json::to_string(&state.butterfly).unwrap())))
 }
 
+#[cfg(feature = "chaos")]
+fn chaos(_req: &mut Request) -> IronResult<Response> {
+    Ok(Response::with((status::Ok, serde_json::to_string(&chaos::current()).unwrap())))
+}
+
+#[cfg(feature = "chaos")]
+fn set_chaos(req: &mut Request) -> IronResult<Response> {
+    let faults = match Faults::from_query(req.url.query().unwrap_or("")) {
+        Ok(faults) => faults,
+        Err(e) => return Ok(Response::with((status::BadRequest, e))),
+    };
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    chaos::set(faults, &state.butterfly);
+    Ok(Response::with(status::Ok))
+}
+
+/// Returns the whole census, or with any of `?service_group=<sg>`, `?offset=<n>`, `?limit=<n>` and
+/// `?fields=<field,...>`, a page of its entries. Pages are written out an entry at a time, rather
+/// than serialized in one piece, so even large pages of a large ring are cheap to serve.
 fn census(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerState>>().unwrap();
-    let data = state.census_list.read().unwrap();
-    Ok(Response::with((status::Ok, serde_json::to_string(&*data).unwrap())))
+    let query = match CensusQuery::from_query(req.url.query().unwrap_or("")) {
+        Ok(Some(query)) => query,
+        Ok(None) => {
+            return cached_json(req, "census", || {
+                let data = state.census_list.read().unwrap();
+                serde_json::to_string(&*data).unwrap()
+            })
+        }
+        Err(e) => return Ok(Response::with((status::BadRequest, e))),
+    };
+    let page = {
+        let census_list = state.census_list.read().unwrap();
+        let (total, entries) = census_list.page(&query);
+        CensusPage {
+            total: total,
+            entries: entries.into_iter().cloned().collect(),
+            query: query,
+        }
+    };
+    let body: Box<WriteBody> = Box::new(page);
+    Ok(Response::with((status::Ok, body)))
+}
+
+fn diagnostics(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let data = Diagnostics::new(&state);
+    Ok(Response::with((status::Ok, serde_json::to_string(&data).unwrap())))
+}
+
+/// Writes the Supervisor's memory mappings to a file and returns its path. The mappings show the
+/// address layout of the process and the paths of every file it has mapped, so like
+/// `config_files` they're only written for a gateway which requires a token.
+fn memory_maps(_req: &mut Request) -> IronResult<Response> {
+    if !auth_token_set() {
+        return Ok(Response::with((status::Forbidden,
+                                  "memory maps are only written for a gateway started with \
+                                   --gateway-auth-from-file")));
+    }
+    match diagnostics::write_memory_maps() {
+        Ok(path) => Ok(Response::with((status::Ok, path.to_string_lossy().into_owned()))),
+        Err(err) => {
+            error!("Couldn't write memory maps, err={:?}", err);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
 }
 
+/// Returns hook output lines from `?since=<seq>` on, optionally for one `?service_group=`,
+/// waiting up to `?wait=<seconds>` for new lines if there are none yet.
+fn hook_output(req: &mut Request) -> IronResult<Response> {
+    let mut since = 0;
+    let mut service_group = None;
+    let mut wait = 0;
+    for pair in req.url.query().unwrap_or("").split('&') {
+        let mut kv = pair.splitn(2, '=');
+        match (kv.next(), kv.next()) {
+            (Some("since"), Some(v)) => {
+                match v.parse() {
+                    Ok(v) => since = v,
+                    Err(_) => return Ok(Response::with(status::BadRequest)),
+                }
+            }
+            (Some("wait"), Some(v)) => {
+                match v.parse() {
+                    Ok(v) => wait = v,
+                    Err(_) => return Ok(Response::with(status::BadRequest)),
+                }
+            }
+            (Some("service_group"), Some(v)) => service_group = Some(v.to_string()),
+            _ => {}
+        }
+    }
+    let (wait, _poll) = long_poll(wait);
+    let data = hook_log::since(since, service_group.as_ref().map(|sg| sg.as_str()), wait);
+    Ok(Response::with((status::Ok, serde_json::to_string(&data).unwrap())))
+}
+
+/// A worker's slot for waiting on new output, given back when dropped.
+struct LongPoll;
+
+impl Drop for LongPoll {
+    fn drop(&mut self) {
+        LONG_POLLS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Takes a slot for a request asking to wait `wait` seconds for new output, returning how long it
+/// may wait. When half of the workers are already waiting it may not wait at all, and should
+/// answer with whatever output there is.
+fn long_poll(wait: u64) -> (Duration, Option<LongPoll>) {
+    let limit = HTTP_THREADS.load(Ordering::SeqCst) / 2;
+    if wait == 0 || LONG_POLLS.fetch_add(1, Ordering::SeqCst) >= limit {
+        if wait != 0 {
+            LONG_POLLS.fetch_sub(1, Ordering::SeqCst);
+        }
+        return (Duration::from_secs(0), None);
+    }
+    (Duration::from_secs(wait), Some(LongPoll))
+}
+
+fn liveness(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let data = Liveness::new(&state);
+    let status = if data.alive {
+        status::Ok
+    } else {
+        status::ServiceUnavailable
+    };
+    Ok(Response::with((status, serde_json::to_string(&data).unwrap())))
+}
+
+/// Sets the Supervisor's log level to `?level=`, optionally only for one `?subsystem=` or while
+/// working on one `?service=` group.
+fn log_level(req: &mut Request) -> IronResult<Response> {
+    let mut level = None;
+    let mut subsystem = None;
+    let mut service_group = None;
+    for pair in req.url.query().unwrap_or("").split('&') {
+        let mut kv = pair.splitn(2, '=');
+        match (kv.next(), kv.next()) {
+            (Some("level"), Some(v)) => level = Some(v.to_string()),
+            (Some("subsystem"), Some(v)) => subsystem = Some(v.to_string()),
+            (Some("service"), Some(v)) => {
+                match ServiceGroup::from_str(v) {
+                    Ok(sg) => service_group = Some(sg.to_string()),
+                    Err(e) => return Ok(Response::with((status::BadRequest, e.to_string()))),
+                }
+            }
+            _ => {}
+        }
+    }
+    let level = match level {
+        Some(level) => level,
+        None => return Ok(Response::with((status::BadRequest, "level is required"))),
+    };
+    match logger::set_level(&level,
+                            subsystem.as_ref().map(|s| s.as_str()),
+                            service_group.as_ref().map(|sg| sg.as_str())) {
+        Ok(()) => Ok(Response::with(status::Ok)),
+        Err(e) => Ok(Response::with((status::BadRequest, e.to_string()))),
+    }
+}
+
+fn readiness(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let data = Readiness::new(&state);
+    let status = if data.ready {
+        status::Ok
+    } else {
+        status::ServiceUnavailable
+    };
+    Ok(Response::with((status, serde_json::to_string(&data).unwrap())))
+}
+
+/// Returns the commands sent to the ring with `hab ring exec`, with each member's acknowledgement.
+fn ring_commands(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let id = req.url
+        .query()
+        .unwrap_or("")
+        .split('&')
+        .filter_map(|pair| if pair.starts_with("id=") {
+            Some(pair[3..].to_string())
+        } else {
+            None
+        })
+        .next();
+    let data = manager::ring_exec::statuses(&state, id.as_ref().map(|id| id.as_str()));
+    Ok(Response::with((status::Ok, serde_json::to_string(&data).unwrap())))
+}
+
+/// Returns the census as an Envoy CDS response, for Envoys polling the gateway for clusters.
+fn envoy_clusters(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let census_list = state.census_list.read().unwrap();
+    let snapshot = Snapshot::from_census(&census_list, &EndpointSource::Rest);
+    Ok(Response::with((status::Ok, snapshot.clusters_json())))
+}
+
+/// Returns the census as an Envoy EDS response, for Envoys polling the gateway for endpoints.
+fn envoy_endpoints(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let census_list = state.census_list.read().unwrap();
+    let snapshot = Snapshot::from_census(&census_list, &EndpointSource::Rest);
+    Ok(Response::with((status::Ok, snapshot.endpoints_json())))
+}
+
+/// Returns the configuration the service was last rendered with. When it holds secrets read from
+/// AWS, it's only served by a gateway which requires clients to present its auth token.
 fn config(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerState>>().unwrap();
     let service_group = match build_service_group(req) {
         Ok(sg) => sg,
         Err(_) => return Ok(Response::with(status::BadRequest)),
     };
+    if !auth_token_set() && aws_config::holds_secrets(&service_group.to_string()) {
+        return Ok(Response::with((status::Forbidden,
+                                  "configuration holding secrets read from AWS is only served \
+                                   by a gateway started with --gateway-auth-from-file")));
+    }
     let services = state.services.read().unwrap();
     match services.iter().find(|s| s.service_group == service_group) {
         Some(service) => {
@@ -188,6 +1011,89 @@ fn config(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+/// Returns the name and hash of each configuration file the service was last rendered with, and
+/// with `?contents=true` their contents. Rendered files can hold secrets, so they're only served
+/// by a gateway which requires clients to present its auth token.
+fn config_files(req: &mut Request) -> IronResult<Response> {
+    if !auth_token_set() {
+        return Ok(Response::with((status::Forbidden,
+                                  "configuration files are only served by a gateway started \
+                                   with --gateway-auth-from-file")));
+    }
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    let contents = req.url.query().unwrap_or("").split('&').any(|pair| pair == "contents=true");
+    let services = state.services.read().unwrap();
+    match services.iter().find(|s| s.service_group == service_group) {
+        Some(service) => {
+            match service.package.rendered_config_files(contents) {
+                Ok(files) => {
+                    Ok(Response::with((status::Ok, serde_json::to_string(&files).unwrap())))
+                }
+                Err(err) => {
+                    error!("Couldn't read rendered config files, err={:?}", err);
+                    Ok(Response::with(status::ServiceUnavailable))
+                }
+            }
+        }
+        None => Ok(Response::with(status::NotFound)),
+    }
+}
+
+/// Renders the service's configuration files with the TOML configuration in the request body, or
+/// with it patched over the service's configuration from gossip if `?patch=true`, and runs the
+/// package's `validate_config` hook against them, without applying the configuration. The diffs
+/// show the files the service is running with, which can hold secrets, so like `config_files` it
+/// is only served by a gateway which requires a token.
+fn dry_run(req: &mut Request) -> IronResult<Response> {
+    if !auth_token_set() {
+        return Ok(Response::with((status::Forbidden,
+                                  "dry runs are only served by a gateway started with \
+                                   --gateway-auth-from-file")));
+    }
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    let patch = req.url.query().unwrap_or("").split('&').any(|pair| pair == "patch=true");
+    let mut body = String::new();
+    if let Err(e) = req.body.read_to_string(&mut body) {
+        return Ok(Response::with((status::BadRequest, e.to_string())));
+    }
+    let config = match toml::Parser::new(&body).parse() {
+        Some(config) => config,
+        None => return Ok(Response::with((status::BadRequest, "the body isn't valid TOML"))),
+    };
+    // The service's configuration is loaded under the locks, but it's rendered and validated
+    // after they're released, so a slow hook doesn't hold up the Manager.
+    let (package, svc_cfg) = {
+        let census_list = state.census_list.read().unwrap();
+        let services = state.services.read().unwrap();
+        let service = match services.iter().find(|s| s.service_group == service_group) {
+            Some(service) => service,
+            None => return Ok(Response::with(status::NotFound)),
+        };
+        match service.load_service_config(&census_list) {
+            Ok(svc_cfg) => (service.package.clone(), svc_cfg),
+            Err(err) => {
+                error!("Couldn't load service config, err={:?}", err);
+                return Ok(Response::with(status::ServiceUnavailable));
+            }
+        }
+    };
+    match dry_run::dry_run(&service_group, &package, svc_cfg, config, patch) {
+        Ok(data) => Ok(Response::with((status::Ok, serde_json::to_string(&data).unwrap()))),
+        Err(err) => {
+            error!("Dry run failed, err={:?}", err);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
 fn health(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerState>>().unwrap();
     let service_group = match build_service_group(req) {
@@ -209,13 +1115,94 @@ fn health(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+/// Returns the service's recent state and health transitions, oldest first.
+fn history(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    if !state.services.read().unwrap().iter().any(|s| s.service_group == service_group) {
+        return Ok(Response::with(status::NotFound));
+    }
+    let data = service_history::for_service(&service_group.to_string());
+    Ok(Response::with((status::Ok, serde_json::to_string(&data).unwrap())))
+}
+
+/// Returns the service's output lines from `?since=<seq>` on, limited to those written in the last
+/// `?newer_than=<seconds>` and to the last `?lines=<count>`, waiting up to `?wait=<seconds>` for
+/// new lines if there are none yet.
+fn logs(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    if !state.services.read().unwrap().iter().any(|s| s.service_group == service_group) {
+        return Ok(Response::with(status::NotFound));
+    }
+    let mut query = service_log::Query::default();
+    let mut wait = 0;
+    for pair in req.url.query().unwrap_or("").split('&') {
+        let mut kv = pair.splitn(2, '=');
+        let parsed = match (kv.next(), kv.next()) {
+            (Some("since"), Some(v)) => v.parse().map(|v| query.since = v).is_ok(),
+            (Some("wait"), Some(v)) => v.parse().map(|v| wait = v).is_ok(),
+            (Some("lines"), Some(v)) => v.parse().map(|v| query.lines = Some(v)).is_ok(),
+            (Some("newer_than"), Some(v)) => {
+                v.parse::<i64>().map(|v| query.newer_than = Some(time::get_time().sec - v)).is_ok()
+            }
+            _ => true,
+        };
+        if !parsed {
+            return Ok(Response::with(status::BadRequest));
+        }
+    }
+    let (wait, _poll) = long_poll(wait);
+    let data = service_log::since(&service_group.to_string(), &query, wait);
+    Ok(Response::with((status::Ok, serde_json::to_string(&data).unwrap())))
+}
+
+/// Returns the alive and desired members of each service group which has a desired count.
+fn scale(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let census_list = state.census_list.read().unwrap();
+    let data = census_list.scales();
+    Ok(Response::with((status::Ok, serde_json::to_string(&data).unwrap())))
+}
+
+fn schedule(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    let services = state.services.read().unwrap();
+    match services.iter().find(|s| s.service_group == service_group) {
+        Some(service) => {
+            Ok(Response::with((status::Ok, serde_json::to_string(&service.scheduler).unwrap())))
+        }
+        None => Ok(Response::with(status::NotFound)),
+    }
+}
+
 fn services(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerState>>().unwrap();
-    let data = state.services.read().unwrap();
-    Ok(Response::with((status::Ok, serde_json::to_string(&*data).unwrap())))
+    cached_json(req, "services", || {
+        let data = state.services.read().unwrap();
+        serde_json::to_string(&*data).unwrap()
+    })
 }
 
-fn metrics(_req: &mut Request) -> IronResult<Response> {
+fn metrics(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let (payload_bytes, wire_bytes) = state.butterfly.gossip_bytes();
+    catch_up(&GOSSIP_PAYLOAD_BYTES, payload_bytes as f64);
+    catch_up(&GOSSIP_WIRE_BYTES, wire_bytes as f64);
+    if payload_bytes > 0 {
+        GOSSIP_COMPRESSION_RATIO.set(wire_bytes as f64 / payload_bytes as f64);
+    }
+    set_ping_metrics(&state.butterfly.latency.snapshot());
     let mut buffer = vec![];
     let encoder = TextEncoder::new();
     let metric_familys = prometheus::gather();
@@ -224,6 +1211,32 @@ fn metrics(_req: &mut Request) -> IronResult<Response> {
     Ok(Response::with((status::Ok, String::from_utf8(buffer).unwrap())))
 }
 
+/// Sets the ping metrics of every member the failure detector has pinged, and removes those of
+/// members which have since been forgotten.
+fn set_ping_metrics(latency: &BTreeMap<String, PeerLatency>) {
+    let mut members = GOSSIP_PING_MEMBERS.lock().expect("Ping metrics lock is poisoned!");
+    for id in members.iter().filter(|id| !latency.contains_key(*id)) {
+        let _ = GOSSIP_PING_RTT.remove_label_values(&[id.as_str()]);
+        let _ = GOSSIP_PING_TIMEOUTS.remove_label_values(&[id.as_str()]);
+    }
+    for (id, peer) in latency {
+        if peer.acks > 0 {
+            GOSSIP_PING_RTT.with_label_values(&[id.as_str()]).set(peer.smoothed_ms / 1000.0);
+        }
+        catch_up(&GOSSIP_PING_TIMEOUTS.with_label_values(&[id.as_str()]),
+                 peer.timeouts as f64);
+    }
+    *members = latency.keys().cloned().collect();
+}
+
+/// Brings a counter up to a total kept elsewhere, such as by butterfly.
+fn catch_up(counter: &Counter, total: f64) {
+    let behind = total - counter.get();
+    if behind > 0.0 {
+        let _ = counter.inc_by(behind);
+    }
+}
+
 impl Into<Response> for health_check::CheckResult {
     fn into(self) -> Response {
         let status: status::Status = self.into();
@@ -249,3 +1262,66 @@ fn build_service_group(req: &mut Request) -> Result<ServiceGroup> {
                           req.extensions.get::<Router>().unwrap().find("org"))?;
     Ok(sg)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{allowed_origin, bearer_matches, endpoint_name, CachedResponse};
+
+    #[test]
+    fn endpoint_names() {
+        assert_eq!(endpoint_name(&["api-doc"]), Some("api-doc"));
+        assert_eq!(endpoint_name(&["bastion"]), Some("bastion"));
+        assert_eq!(endpoint_name(&["census"]), Some("census"));
+        assert_eq!(endpoint_name(&["diagnostics", "memory-maps"]), Some("diagnostics"));
+        assert_eq!(endpoint_name(&["services"]), Some("services"));
+        assert_eq!(endpoint_name(&["services", "redis", "default", "config"]),
+                   Some("config"));
+        assert_eq!(endpoint_name(&["services", "redis", "default", "acme", "health"]),
+                   Some("health"));
+        assert_eq!(endpoint_name(&["services", "redis", "default", "logs"]), Some("logs"));
+        assert_eq!(endpoint_name(&["services", "redis", "default", "dry-run"]),
+                   Some("dry-run"));
+        assert_eq!(endpoint_name(&["services", "redis", "default", "config-files"]),
+                   Some("config-files"));
+        assert_eq!(endpoint_name(&["services", "redis", "default", "history"]),
+                   Some("history"));
+        assert_eq!(endpoint_name(&["readiness"]), Some("readiness"));
+        assert_eq!(endpoint_name(&["log-level"]), Some("log-level"));
+        assert_eq!(endpoint_name(&["nope"]), None);
+    }
+
+    #[test]
+    fn allowed_origins() {
+        let allowed = vec!["https://dashboard.example.com".to_string(),
+                           "http://localhost:8080".to_string()];
+        assert_eq!(allowed_origin(&allowed, b"https://dashboard.example.com"),
+                   Some("https://dashboard.example.com"));
+        assert_eq!(allowed_origin(&allowed, b"HTTP://LOCALHOST:8080"),
+                   Some("http://localhost:8080"));
+        assert_eq!(allowed_origin(&allowed, b"https://evil.example.com"), None);
+        assert_eq!(allowed_origin(&allowed, b"http://localhost:8080\r\nX-Bad: 1"), None);
+        assert_eq!(allowed_origin(&[], b"https://dashboard.example.com"), None);
+        assert_eq!(allowed_origin(&["*".to_string()], b"https://any.example.com"),
+                   Some("*"));
+    }
+
+    #[test]
+    fn bearer_tokens() {
+        assert!(bearer_matches("s3cret", b"Bearer s3cret"));
+        assert!(bearer_matches("s3cret", b"bearer s3cret"));
+        assert!(!bearer_matches("s3cret", b"Bearer s3cre7"));
+        assert!(!bearer_matches("s3cret", b"Bearer s3cret "));
+        assert!(!bearer_matches("s3cret", b"Basic s3cret"));
+        assert!(!bearer_matches("s3cret", b""));
+    }
+
+    #[test]
+    fn cached_response_etags_follow_the_body() {
+        let a = CachedResponse::new("{\"members\":1}".to_string());
+        let b = CachedResponse::new("{\"members\":1}".to_string());
+        let c = CachedResponse::new("{\"members\":2}".to_string());
+        assert_eq!(a.etag, b.etag);
+        assert!(a.etag != c.etag);
+        assert!(!a.expired());
+    }
+}
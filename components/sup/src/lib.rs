@@ -40,19 +40,29 @@
 //! * [The Habitat Supervisor Sidecar; http interface to promises](sidecar)
 
 extern crate habitat_butterfly as butterfly;
+#[cfg(feature = "simulation")]
+extern crate habitat_butterfly_test as butterfly_test;
 extern crate habitat_core as hcore;
 extern crate habitat_common as common;
 extern crate habitat_depot_client as depot_client;
+extern crate habitat_http_client as http_client;
+extern crate habitat_sup_client as sup_client;
 extern crate handlebars;
+extern crate env_logger;
+extern crate hyper;
+extern crate hyper_openssl;
+extern crate liquid;
 #[macro_use]
 extern crate log;
 extern crate tempdir;
 extern crate ansi_term;
+extern crate base64;
 extern crate regex;
 extern crate libc;
+extern crate net2;
+extern crate openssl;
 extern crate url;
 extern crate iron;
-#[macro_use]
 extern crate router;
 extern crate serde;
 #[macro_use]
@@ -65,6 +75,12 @@ extern crate persistent;
 extern crate prometheus;
 #[macro_use]
 extern crate lazy_static;
+#[cfg(feature = "grpc-gateway")]
+extern crate futures;
+#[cfg(feature = "grpc-gateway")]
+extern crate grpc;
+#[cfg(feature = "grpc-gateway")]
+extern crate protobuf;
 
 #[macro_export]
 /// Creates a new SupError, embedding the current file name, line number, column, and module path.
@@ -268,14 +284,32 @@ macro_rules! output_format {
     }
 }
 
+pub mod api_doc;
+pub mod audit;
+#[cfg(feature = "chaos")]
+pub mod chaos;
 pub mod command;
 pub mod config;
+pub mod diagnostics;
+pub mod envoy;
 pub mod error;
+#[cfg(feature = "grpc-gateway")]
+pub mod grpc_gateway;
 pub mod health_check;
+pub mod hook_log;
 pub mod http_gateway;
+pub mod logger;
 pub mod manager;
+pub mod mirrors;
 pub mod output;
 pub mod package;
+pub mod probes;
+pub mod redact;
+pub mod secrets;
+pub mod service_history;
+pub mod service_log;
+#[cfg(feature = "simulation")]
+pub mod simulation;
 pub mod supervisor;
 pub mod templating;
 pub mod util;
@@ -0,0 +1,230 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The Supervisor's logger.
+//!
+//! Log records are filtered and written just as `env_logger` does, starting from `RUST_LOG`, but
+//! the filter can be changed while the Supervisor runs with `hab sup log-level`. A level can be
+//! set for the whole Supervisor, for one subsystem such as gossip, or for the work the main loop
+//! does on behalf of a single service, which is marked with `service_scope`.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::mem;
+use std::sync::{Mutex, RwLock};
+
+use env_logger::{LogBuilder, Logger};
+use log::{self, Log, LogLevelFilter, LogMetadata, LogRecord, MaxLogLevelFilter, SetLoggerError};
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "LG";
+
+/// The levels a filter can be set to, from least to most verbose.
+pub const LEVELS: &'static [&'static str] = &["off", "error", "warn", "info", "debug", "trace"];
+
+/// The subsystems which can be given a level of their own, and the modules they log from.
+pub const SUBSYSTEMS: &'static [(&'static str, &'static str)] =
+    &[("gossip", "habitat_butterfly"),
+      ("updater", "habitat_sup::manager::service_updater")];
+
+lazy_static! {
+    /// The names of the `SUBSYSTEMS`, as the command line and errors list them.
+    pub static ref SUBSYSTEM_NAMES: Vec<&'static str> =
+        SUBSYSTEMS.iter().map(|&(name, _)| name).collect();
+    static ref FILTERS: RwLock<Filters> = RwLock::new(Filters::new());
+    static ref MAX_LEVEL: Mutex<Option<MaxLogLevelFilter>> = Mutex::new(None);
+}
+
+thread_local! {
+    /// The service group the current thread is working on behalf of, if any.
+    static SERVICE: RefCell<Option<String>> = RefCell::new(None)
+}
+
+/// A filter's level, and the levels of any subsystems which differ from it.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Directives {
+    level: String,
+    modules: BTreeMap<&'static str, String>,
+}
+
+impl Directives {
+    /// The filter in `RUST_LOG` syntax.
+    fn spec(&self) -> String {
+        let mut spec = vec![self.level.clone()];
+        for (module, level) in self.modules.iter() {
+            spec.push(format!("{}={}", module, level));
+        }
+        spec.retain(|directive| !directive.is_empty());
+        spec.join(",")
+    }
+
+    fn set(&mut self, level: &str, module: Option<&'static str>) {
+        match module {
+            Some(module) => {
+                self.modules.insert(module, level.to_string());
+            }
+            None => {
+                self.level = level.to_string();
+                self.modules.clear();
+            }
+        }
+    }
+
+    fn build(&self) -> Logger {
+        LogBuilder::new().parse(&self.spec()).build()
+    }
+}
+
+struct Filters {
+    directives: Directives,
+    logger: Logger,
+    services: HashMap<String, (Directives, Logger)>,
+}
+
+impl Filters {
+    fn new() -> Self {
+        let directives = Directives {
+            level: env::var("RUST_LOG").unwrap_or(String::new()),
+            modules: BTreeMap::new(),
+        };
+        Filters {
+            logger: directives.build(),
+            directives: directives,
+            services: HashMap::new(),
+        }
+    }
+
+    /// The most verbose level any filter lets through.
+    fn max_level(&self) -> LogLevelFilter {
+        self.services
+            .values()
+            .map(|&(_, ref logger)| logger.filter())
+            .fold(self.logger.filter(), ::std::cmp::max)
+    }
+
+    fn with_logger<F, T>(&self, f: F) -> T
+        where F: FnOnce(&Logger) -> T
+    {
+        SERVICE.with(|service| {
+            let service = service.borrow();
+            match service.as_ref().and_then(|sg| self.services.get(sg)) {
+                Some(&(_, ref logger)) => f(logger),
+                None => f(&self.logger),
+            }
+        })
+    }
+}
+
+struct SupLogger;
+
+impl Log for SupLogger {
+    fn enabled(&self, metadata: &LogMetadata) -> bool {
+        let filters = FILTERS.read().expect("Log filters lock is poisoned!");
+        filters.with_logger(|logger| logger.enabled(metadata))
+    }
+
+    fn log(&self, record: &LogRecord) {
+        let filters = FILTERS.read().expect("Log filters lock is poisoned!");
+        filters.with_logger(|logger| logger.log(record))
+    }
+}
+
+/// Install the Supervisor's logger, filtering according to `RUST_LOG`.
+pub fn init() -> ::std::result::Result<(), SetLoggerError> {
+    log::set_logger(|max_level| {
+        let filters = FILTERS.read().expect("Log filters lock is poisoned!");
+        max_level.set(filters.max_level());
+        *MAX_LEVEL.lock().expect("Max log level lock is poisoned!") = Some(max_level);
+        Box::new(SupLogger)
+    })
+}
+
+/// Set the level of the whole Supervisor, or of one of its subsystems, or of either while the
+/// Supervisor works on behalf of one service group. A service group's filter starts out as a
+/// copy of the Supervisor's.
+pub fn set_level(level: &str, subsystem: Option<&str>, service_group: Option<&str>) -> Result<()> {
+    if !LEVELS.contains(&level) {
+        return Err(sup_error!(Error::InvalidLogLevel(level.to_string())));
+    }
+    let module = match subsystem {
+        Some(subsystem) => {
+            match SUBSYSTEMS.iter().find(|&&(name, _)| name == subsystem) {
+                Some(&(_, module)) => Some(module),
+                None => {
+                    return Err(sup_error!(Error::InvalidLogSubsystem(subsystem.to_string())))
+                }
+            }
+        }
+        None => None,
+    };
+    let mut filters = FILTERS.write().expect("Log filters lock is poisoned!");
+    match service_group {
+        Some(sg) => {
+            let mut directives = filters.services
+                .get(sg)
+                .map(|&(ref directives, _)| directives.clone())
+                .unwrap_or(filters.directives.clone());
+            directives.set(level, module);
+            let logger = directives.build();
+            filters.services.insert(sg.to_string(), (directives, logger));
+        }
+        None => {
+            filters.directives.set(level, module);
+            filters.logger = filters.directives.build();
+        }
+    }
+    if let Some(ref max_level) = *MAX_LEVEL.lock().expect("Max log level lock is poisoned!") {
+        max_level.set(filters.max_level());
+    }
+    Ok(())
+}
+
+/// Marks the current thread as working on behalf of a service group, until dropped.
+pub struct ServiceScope {
+    previous: Option<String>,
+}
+
+impl Drop for ServiceScope {
+    fn drop(&mut self) {
+        SERVICE.with(|service| *service.borrow_mut() = self.previous.take());
+    }
+}
+
+/// Log with the service group's filter on this thread, if it has one, until the returned guard
+/// is dropped.
+pub fn service_scope(service_group: &str) -> ServiceScope {
+    let previous = SERVICE.with(|service| {
+        mem::replace(&mut *service.borrow_mut(), Some(service_group.to_string()))
+    });
+    ServiceScope { previous: previous }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Directives;
+
+    #[test]
+    fn directives_spec() {
+        let mut directives = Directives::default();
+        assert_eq!(directives.spec(), "");
+        directives.set("info", None);
+        assert_eq!(directives.spec(), "info");
+        directives.set("trace", Some("habitat_butterfly"));
+        assert_eq!(directives.spec(), "info,habitat_butterfly=trace");
+        directives.set("warn", None);
+        assert_eq!(directives.spec(), "warn");
+    }
+}
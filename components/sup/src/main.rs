@@ -15,23 +15,30 @@
 #[macro_use]
 extern crate habitat_sup as sup;
 extern crate habitat_core as hcore;
+extern crate habitat_butterfly as butterfly;
 #[macro_use]
 extern crate log;
-extern crate env_logger;
 extern crate ansi_term;
 extern crate libc;
 #[macro_use]
 extern crate clap;
 
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs::File;
+use std::io::Read;
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
 use std::process;
 use std::str::FromStr;
 
 use ansi_term::Colour::Yellow;
+use butterfly::message::compression::Compression;
 use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
 use hcore::env as henv;
 use hcore::crypto::{default_cache_key_path, SymKey};
 use hcore::crypto::init as crypto_init;
+use hcore::fs::FS_ROOT_ENVVAR;
 use hcore::package::{PackageArchive, PackageIdent};
 use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 
@@ -39,7 +46,13 @@ use sup::config::{gcache, gconfig, Command, Config, GossipListenAddr};
 use sup::error::{Error, Result, SupError};
 use sup::command::*;
 use sup::http_gateway;
-use sup::manager::service::{UpdateStrategy, Topology};
+use sup::logger;
+use sup::redact;
+use sup::util::privileges;
+use sup::manager::peer_provider::ProviderConfig;
+use sup::manager::schedule::Schedule;
+use sup::manager::service::{PortMapping, ServiceKind, UpdateStrategy, Topology};
+use sup::mirrors::Mirror;
 
 /// Our output key
 static LOGKEY: &'static str = "MN";
@@ -59,12 +72,67 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
     let mut config = Config::new();
     let command = try!(Command::from_str(subcommand));
     config.set_command(command);
+    if sub_args.is_present("unprivileged") {
+        config.set_unprivileged(true);
+    }
+    if let Some(fs_root) = sub_args.value_of("fs-root") {
+        // Paths found without the config, such as those of installed packages, and those the
+        // `hab` commands run by hooks find, follow the environment.
+        env::set_var(FS_ROOT_ENVVAR, fs_root);
+        config.set_fs_root(PathBuf::from(fs_root));
+    } else if config.unprivileged() && henv::var(FS_ROOT_ENVVAR).is_err() {
+        let fs_root = try!(privileges::default_fs_root());
+        env::set_var(FS_ROOT_ENVVAR, &fs_root);
+        config.set_fs_root(fs_root);
+    }
+    if config.unprivileged() {
+        try!(privileges::check_fs_root(&config.fs_root()));
+    }
     if let Some(ref config_from) = sub_args.value_of("config-from") {
         config.set_config_from(Some(config_from.to_string()));
     }
     if let Some(ref strategy) = sub_args.value_of("strategy") {
         config.set_update_strategy(UpdateStrategy::from_str(strategy));
     }
+    if let Some(count) = sub_args.value_of("update-concurrency") {
+        match count.parse::<usize>() {
+            Ok(count) if count > 0 => config.set_update_concurrency(count),
+            _ => return Err(sup_error!(Error::InvalidMemberCount(count.to_string()))),
+        };
+    }
+    if let Some(count) = sub_args.value_of("update-attempts") {
+        match count.parse::<u32>() {
+            Ok(count) if count > 0 => config.set_update_attempts(count),
+            _ => return Err(sup_error!(Error::InvalidAttemptCount(count.to_string()))),
+        };
+    }
+    if let Some(lock_file) = sub_args.value_of("locked") {
+        config.set_lock_file(PathBuf::from(lock_file));
+    }
+    if let Some(ref kind) = sub_args.value_of("kind") {
+        config.set_kind(ServiceKind::from_str(kind));
+    }
+    if let Some(ref schedule) = sub_args.value_of("schedule") {
+        if config.kind() != ServiceKind::Oneshot {
+            return Err(sup_error!(Error::InvalidSchedule(String::from("only oneshot services \
+                                                                       can be scheduled"))));
+        }
+        config.set_schedule(Some(try!(Schedule::from_str(schedule))));
+    }
+    if sub_args.is_present("isolate-network") {
+        if !cfg!(target_os = "linux") {
+            let feature = String::from("Network isolation");
+            return Err(sup_error!(Error::UnsupportedPlatform(feature)));
+        }
+        config.set_isolate_network(true);
+    }
+    if let Some(ports) = sub_args.values_of("port") {
+        let mut mappings = Vec::new();
+        for port in ports {
+            mappings.push(try!(PortMapping::from_str(port)));
+        }
+        config.set_ports(mappings);
+    }
     if let Some(ref ident_or_artifact) = sub_args.value_of("pkg_ident_or_artifact") {
         if Path::new(ident_or_artifact).is_file() {
             let ident = try!(PackageArchive::new(Path::new(ident_or_artifact)).ident());
@@ -92,7 +160,23 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
     let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
     let url = sub_args.value_of("url").unwrap_or(&env_or_default);
     config.set_url(url.to_string());
+    if let Some(mirrors) = sub_args.values_of("mirror") {
+        let mut parsed = vec![];
+        for mirror in mirrors {
+            match Mirror::from_str(mirror) {
+                Ok(mirror) => parsed.push(mirror),
+                Err(e) => return Err(sup_error!(Error::InvalidMirror(e))),
+            }
+        }
+        config.set_mirrors(parsed);
+    }
     config.set_group(sub_args.value_of("group").unwrap_or(DEFAULT_GROUP).to_string());
+    if let Some(name) = sub_args.value_of("service-name") {
+        if name.is_empty() || name.contains(|c| c == '.' || c == '/' || c == '@') {
+            return Err(sup_error!(Error::InvalidServiceName(name.to_string())));
+        }
+        config.set_service_name(name.to_string());
+    }
     let bindings = match sub_args.values_of("bind") {
         Some(bind) => bind.map(|s| s.to_string()).collect(),
         None => vec![],
@@ -109,9 +193,30 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
     if let Some(addr_str) = sub_args.value_of("listen-gossip") {
         config.gossip_listen = try!(GossipListenAddr::from_str(addr_str));
     }
+    if let Some(name) = sub_args.value_of("gossip-interface") {
+        let ip = try!(sup::util::sys::interface_ip(name));
+        config.gossip_listen.set_ip(ip);
+    }
+    if let Some(addr_str) = sub_args.value_of("gossip-advertise-addr") {
+        let port = config.gossip_listen.port();
+        config.set_gossip_advertise(Some(try!(parse_advertise_addr(addr_str, port))));
+    }
     if let Some(addr_str) = sub_args.value_of("listen-http") {
         config.http_listen_addr = try!(http_gateway::ListenAddr::from_str(addr_str));
     }
+    if let Some(addr_str) = sub_args.value_of("listen-grpc") {
+        if !cfg!(feature = "grpc-gateway") {
+            return Err(sup_error!(Error::GrpcGatewayNotBuilt));
+        }
+        config.set_grpc_listen_addr(Some(try!(SocketAddr::from_str(addr_str))));
+    }
+    if config.unprivileged() {
+        try!(privileges::check_port(config.gossip_listen.port(), "--listen-gossip"));
+        try!(privileges::check_port(config.http_listen_addr.port(), "--listen-http"));
+        if let Some(port) = config.grpc_listen_addr().map(|a| a.port()) {
+            try!(privileges::check_port(port, "--listen-grpc"));
+        }
+    }
     let gossip_peers = match sub_args.values_of("peer") {
         Some(gp) => gp.map(|s| s.to_string()).collect(),
         None => vec![],
@@ -120,6 +225,115 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
     if sub_args.is_present("permanent-peer") {
         config.set_gossip_permanent(true);
     }
+    if sub_args.is_present("bastion") {
+        config.set_bastion(true);
+        config.set_gossip_permanent(true);
+    }
+    if sub_args.is_present("discover-peers") {
+        config.set_gossip_discover(true);
+    }
+    if let Some(providers) = sub_args.values_of("peer-provider") {
+        let mut values = Vec::new();
+        for provider in providers {
+            try!(ProviderConfig::parse(provider));
+            values.push(provider.to_string());
+        }
+        config.set_peer_provider(values);
+    }
+    if let Some(path) = sub_args.value_of("peer-watch-file") {
+        config.set_peer_watch_file(Some(PathBuf::from(path)));
+    }
+    if sub_args.is_present("kubernetes") {
+        config.set_kubernetes(true);
+    }
+    if let Some(dir) = sub_args.value_of("envoy-xds-dir") {
+        config.set_envoy_xds_dir(Some(PathBuf::from(dir)));
+    }
+    if let Some(addr) = sub_args.value_of("vault-addr") {
+        config.set_vault_addr(Some(addr.to_string()));
+    }
+    if let Some(path) = sub_args.value_of("aws-config-path") {
+        config.set_aws_config_path(Some(path.to_string()));
+    }
+    if let Some(seconds) = sub_args.value_of("aws-config-interval") {
+        match seconds.parse::<u64>() {
+            Ok(seconds) if seconds > 0 => config.set_aws_config_interval(seconds),
+            _ => return Err(sup_error!(Error::InvalidDuration(seconds.to_string()))),
+        };
+    }
+    if let Some(labels) = sub_args.values_of("label") {
+        config.set_labels(try!(parse_labels(labels)));
+    }
+    if let Some(patterns) = sub_args.values_of("redact") {
+        config.set_redact(try!(redact::parse_patterns(patterns)));
+    }
+    if let Some(count) = sub_args.value_of("bootstrap-expect") {
+        match count.parse::<usize>() {
+            Ok(count) if count > 0 => config.set_bootstrap_expect(Some(count)),
+            _ => return Err(sup_error!(Error::InvalidMemberCount(count.to_string()))),
+        };
+    }
+    if sub_args.is_present("strict-render") {
+        config.set_strict_render(true);
+    }
+    if let Some(threads) = sub_args.value_of("http-threads") {
+        config.set_http_threads(try!(parse_thread_count(threads)));
+    }
+    if let Some(endpoints) = sub_args.values_of("http-disable") {
+        config.set_http_disable(try!(parse_http_endpoints(endpoints)));
+    }
+    if let Some(endpoints) = sub_args.values_of("http-local-only") {
+        config.set_http_local_only(try!(parse_http_endpoints(endpoints)));
+    }
+    if let Some(origins) = sub_args.values_of("http-cors-origin") {
+        config.set_http_cors_origins(try!(parse_http_origins(origins)));
+    }
+    if let (Some(cert), Some(key)) = (sub_args.value_of("http-tls-cert-from-file"),
+                                      sub_args.value_of("http-tls-key-from-file")) {
+        config.set_http_tls_files(PathBuf::from(cert), PathBuf::from(key));
+    }
+    if let Some(path) = sub_args.value_of("gateway-auth-from-file") {
+        config.set_gateway_auth_file(Some(PathBuf::from(path)));
+    }
+    if let Some(bytes) = sub_args.value_of("http-max-request-bytes") {
+        config.set_http_max_request_bytes(try!(parse_http_limit(bytes)));
+    }
+    if let Some(seconds) = sub_args.value_of("http-request-timeout") {
+        config.set_http_request_timeout(try!(parse_http_limit(seconds)));
+    }
+    if let Some(threads) = sub_args.value_of("gossip-threads") {
+        config.set_gossip_threads(try!(parse_thread_count(threads)));
+    }
+    match sub_args.value_of("gossip-compression") {
+        Some("none") | None => {}
+        Some(codec) => {
+            config.set_gossip_compression(Some(try!(codec.parse::<Compression>())));
+        }
+    }
+    if let Some(seconds) = sub_args.value_of("gossip-departed-ttl") {
+        match seconds.parse::<usize>() {
+            Ok(seconds) => config.set_gossip_departed_ttl(seconds),
+            Err(_) => return Err(sup_error!(Error::InvalidDuration(seconds.to_string()))),
+        };
+    }
+    if let Some(seconds) = sub_args.value_of("departed-ttl") {
+        match seconds.parse::<u64>() {
+            Ok(seconds) if seconds > 0 => config.set_departed_ttl(seconds),
+            _ => return Err(sup_error!(Error::InvalidDuration(seconds.to_string()))),
+        };
+    }
+    if let Some(alias) = sub_args.value_of("alias") {
+        config.set_gossip_alias(alias.to_string());
+    }
+    if let Some(threads) = sub_args.value_of("hook-threads") {
+        config.set_hook_threads(try!(parse_thread_count(threads)));
+    }
+    if let Some(seconds) = sub_args.value_of("reconfigure-debounce") {
+        match seconds.parse::<u64>() {
+            Ok(seconds) => config.set_reconfigure_debounce(seconds),
+            Err(_) => return Err(sup_error!(Error::InvalidDuration(seconds.to_string()))),
+        };
+    }
     let ring = match sub_args.value_of("ring") {
         Some(val) => Some(try!(SymKey::get_latest_pair_for(&val, &default_cache_key_path(None)))),
         None => {
@@ -141,9 +355,19 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
             }
         }
     };
+    let ring = match sub_args.value_of("ring-key-from-file") {
+        Some(path) => {
+            config.set_ring_key_file(Some(PathBuf::from(path)));
+            Some(try!(import_ring_key(Path::new(path))))
+        }
+        None => ring,
+    };
     if let Some(ring) = ring {
         config.set_ring(ring.name_with_rev());
     }
+    if let Some(origins) = sub_args.values_of("ring-exec-origin") {
+        config.set_ring_exec_origin(origins.map(|o| o.to_string()).collect());
+    }
     if sub_args.is_present("verbose") {
         sup::output::set_verbose(true);
     }
@@ -158,6 +382,91 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
     Ok({})
 }
 
+/// Adds the ring key in `path` to the key cache, where the Manager loads it from.
+fn import_ring_key(path: &Path) -> Result<SymKey> {
+    let mut content = String::new();
+    try!(try!(File::open(path)).read_to_string(&mut content));
+    let (key, _) = try!(SymKey::write_file_from_str(&content, &default_cache_key_path(None)));
+    Ok(key)
+}
+
+fn parse_thread_count(value: &str) -> Result<usize> {
+    match value.parse::<usize>() {
+        Ok(threads) if threads > 0 => Ok(threads),
+        _ => Err(sup_error!(Error::InvalidThreadCount(value.to_string()))),
+    }
+}
+
+/// Parse an `ip` or `ip:port` string; a bare IP address is advertised on the gossip listen port.
+fn parse_advertise_addr(value: &str, default_port: u16) -> Result<SocketAddr> {
+    match SocketAddr::from_str(value) {
+        Ok(addr) => Ok(addr),
+        Err(_) => {
+            let ip = try!(IpAddr::from_str(value));
+            Ok(SocketAddr::new(ip, default_port))
+        }
+    }
+}
+
+/// Parse `key=value` labels. Keys become template and census fields, so they are kept to names
+/// which need no quoting.
+fn parse_labels<'a, I: Iterator<Item = &'a str>>(values: I) -> Result<BTreeMap<String, String>> {
+    let mut labels = BTreeMap::new();
+    for value in values {
+        let mut parts = value.splitn(2, '=');
+        match (parts.next(), parts.next()) {
+            (Some(key), Some(val)) if valid_label_key(key) => {
+                labels.insert(key.to_string(), val.to_string());
+            }
+            _ => return Err(sup_error!(Error::InvalidLabel(value.to_string()))),
+        }
+    }
+    Ok(labels)
+}
+
+fn valid_label_key(key: &str) -> bool {
+    !key.is_empty() &&
+    key.chars().all(|c| match c {
+        'a'...'z' | 'A'...'Z' | '0'...'9' | '_' | '-' => true,
+        _ => false,
+    })
+}
+
+fn parse_http_endpoints<'a, I: Iterator<Item = &'a str>>(values: I) -> Result<Vec<String>> {
+    let mut endpoints = Vec::new();
+    for value in values {
+        if !http_gateway::ENDPOINTS.contains(&value) {
+            return Err(sup_error!(Error::InvalidHttpEndpoint(value.to_string())));
+        }
+        endpoints.push(value.to_string());
+    }
+    Ok(endpoints)
+}
+
+/// Parse CORS origins, which are either `*` or `scheme://host[:port]` as a browser sends them.
+/// They are echoed back in response headers, so only printable US-ASCII is accepted.
+fn parse_http_origins<'a, I: Iterator<Item = &'a str>>(values: I) -> Result<Vec<String>> {
+    let mut origins = Vec::new();
+    for value in values {
+        let printable = value.chars().all(|c| c > ' ' && c <= '~');
+        let valid = value == "*" ||
+                    (printable && value.contains("://") && !value.ends_with('/') &&
+                     !value.ends_with("://"));
+        if !valid {
+            return Err(sup_error!(Error::InvalidHttpOrigin(value.to_string())));
+        }
+        origins.push(value.to_string());
+    }
+    Ok(origins)
+}
+
+fn parse_http_limit(value: &str) -> Result<u64> {
+    match value.parse::<u64>() {
+        Ok(limit) if limit > 0 => Ok(limit),
+        _ => Err(sup_error!(Error::InvalidHttpLimit(value.to_string()))),
+    }
+}
+
 /// The entrypoint for the Supervisor.
 ///
 /// * Set up the logger
@@ -166,7 +475,7 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
 /// * Exit cleanly, or if we return an `Error`, call `exit_with(E, 1)`
 #[allow(dead_code)]
 fn main() {
-    env_logger::init().unwrap();
+    sup::logger::init().unwrap();
     crypto_init();
 
     let arg_url = || {
@@ -201,16 +510,44 @@ fn main() {
 
     let sub_start = SubCommand::with_name("start")
         .about("Start a Habitat-supervised service from a package or artifact")
-        .aliases(&["st", "sta", "star"])
+        .aliases(&["st", "sta", "star", "run"])
         .arg(Arg::with_name("pkg_ident_or_artifact")
             .index(1)
-            .required(true)
+            .required_unless("bastion")
             .help("A Habitat package identifier (ex: acme/redis) or a filepath to a Habitat \
                    Artifact (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)"))
         .arg(arg_url())
+        .arg(Arg::with_name("mirror")
+            .long("mirror")
+            .value_name("url[,priority]")
+            .multiple(true)
+            .number_of_values(1)
+            .help("A mirror of the Depot to fail over to when it is unavailable; lower \
+                   priorities are tried first, after the --url Depot [default priority: 1]"))
         .arg(arg_group())
+        .arg(Arg::with_name("service-name")
+            .long("service-name")
+            .value_name("name")
+            .takes_value(true)
+            .help("Run the service under another name, so the same package can run as several \
+                   services [default: the package name]"))
         .arg(arg_org())
         .arg(arg_strategy())
+        .arg(Arg::with_name("update-concurrency")
+            .long("update-concurrency")
+            .value_name("count")
+            .help("The most members of the service group that update at once, whatever the \
+                   update strategy [default: no limit]"))
+        .arg(Arg::with_name("update-attempts")
+            .long("update-attempts")
+            .value_name("count")
+            .help("How many times updating to a release may fail before the release is \
+                   quarantined and skipped [default: 3]"))
+        .arg(Arg::with_name("locked")
+            .long("locked")
+            .value_name("lockfile")
+            .help("Run the release this lockfile pins, and only update to releases it pins; \
+                   it's read again before each check for updates (ex: habitat.lock)"))
         .arg(Arg::with_name("config-from")
             .short("C")
             .long("config-from")
@@ -221,6 +558,32 @@ fn main() {
             .long("topology")
             .value_name("topology")
             .help("Service topology"))
+        .arg(Arg::with_name("kind")
+            .long("kind")
+            .value_name("kind")
+            .possible_values(&["daemon", "oneshot"])
+            .help("Run the service as a long lived daemon or as a job which runs to completion \
+                   [default: daemon]"))
+        .arg(Arg::with_name("schedule")
+            .long("schedule")
+            .value_name("cron")
+            .help("Run a oneshot service on a cron style schedule (ex: \"0 3 * * *\")"))
+        .arg(Arg::with_name("isolate-network")
+            .long("isolate-network")
+            .help("Run the service in a network namespace of its own, reachable only through \
+                   the ports given with --port (Linux only)"))
+        .arg(Arg::with_name("port")
+            .long("port")
+            .value_name("host_port:service_port")
+            .multiple(true)
+            .number_of_values(1)
+            .requires("isolate-network")
+            .help("Forward a port on the host to a port of an isolated service (ex: 16379:6379)"))
+        .arg(Arg::with_name("unprivileged")
+            .long("unprivileged")
+            .conflicts_with("isolate-network")
+            .help("Run the Supervisor and its services entirely as the current user, under a \
+                   filesystem root it can write to [default fs root: $HOME/.hab/root]"))
         .arg(Arg::with_name("bind")
             .long("bind")
             .value_name("bind")
@@ -231,15 +594,95 @@ fn main() {
             .long("ring")
             .value_name("ring")
             .help("Ring key name"))
+        .arg(Arg::with_name("ring-key-from-file")
+            .long("ring-key-from-file")
+            .value_name("path")
+            .conflicts_with("ring")
+            .help("Encrypt gossip with the ring key in this file, and switch to the key it holds \
+                   whenever it changes"))
+        .arg(Arg::with_name("ring-exec-origin")
+            .long("ring-exec-origin")
+            .value_name("origin")
+            .multiple(true)
+            .number_of_values(1)
+            .help("Run the commands sent with `hab ring exec` which are signed by this origin's \
+                   key, which must be in the key cache [default: run none]"))
         .arg(Arg::with_name("peer")
             .long("peer")
             .value_name("ip:port")
             .multiple(true)
             .help("The listen address of an initial peer"))
+        .arg(Arg::with_name("peer-provider")
+            .long("peer-provider")
+            .value_name("config")
+            .multiple(true)
+            .number_of_values(1)
+            .help("Find initial peers by listing cloud instances with a tag (ex: \"provider=aws \
+                   tag_key=habitat tag_value=prod\")"))
+        .arg(Arg::with_name("peer-watch-file")
+            .long("peer-watch-file")
+            .value_name("path")
+            .help("Join the peers listed in this file, one ip[:port] per line, and join them again \
+                   whenever it changes"))
+        .arg(Arg::with_name("label")
+            .long("label")
+            .value_name("key=value")
+            .multiple(true)
+            .number_of_values(1)
+            .help("A tag for this Supervisor which is gossiped with its services and available \
+                   to templates as sys.labels (ex: zone=us-east-1a)"))
+        .arg(Arg::with_name("redact")
+            .long("redact")
+            .value_name("regex")
+            .multiple(true)
+            .number_of_values(1)
+            .help("Replace what this pattern matches, or its first capture group, in the output \
+                   of services and hooks with [REDACTED] (ex: 'password=(\\S+)')"))
+        .arg(Arg::with_name("bootstrap-expect")
+            .long("bootstrap-expect")
+            .value_name("count")
+            .help("Wait until this many members of the ring are alive before starting the \
+                   elections of leader and initializer services"))
+        .arg(Arg::with_name("discover-peers")
+            .long("discover-peers")
+            .help("Find peers on the local network with mDNS, in addition to any given with \
+                   --peer"))
+        .arg(Arg::with_name("kubernetes")
+            .long("kubernetes")
+            .help("Write the census to HabitatServiceStatus resources, and apply the desired \
+                   state of HabitatService resources, in the Kubernetes cluster we run in"))
+        .arg(Arg::with_name("envoy-xds-dir")
+            .long("envoy-xds-dir")
+            .value_name("path")
+            .help("Write the census to cds.json and eds.json in this directory, for Envoy's \
+                   cluster and endpoint discovery"))
+        .arg(Arg::with_name("vault-addr")
+            .long("vault-addr")
+            .value_name("url")
+            .help("Read the secrets templates reference with the vault helper from this Vault \
+                   server, authenticating with the token in VAULT_TOKEN"))
+        .arg(Arg::with_name("aws-config-path")
+            .long("aws-config-path")
+            .value_name("path")
+            .help("Read each service's configuration from the AWS Parameter Store parameters \
+                   and Secrets Manager secret under this path, such as /habitat/prod"))
+        .arg(Arg::with_name("aws-config-interval")
+            .long("aws-config-interval")
+            .value_name("seconds")
+            .help("How often to read service configuration from AWS [default: 60]"))
         .arg(Arg::with_name("listen-gossip")
             .long("listen-gossip")
             .value_name("ip:port")
             .help("The listen address [default: 0.0.0.0:9638]"))
+        .arg(Arg::with_name("gossip-interface")
+            .long("gossip-interface")
+            .value_name("name")
+            .help("Listen for gossip only on the address of this network interface (ex: eth1)"))
+        .arg(Arg::with_name("gossip-advertise-addr")
+            .long("gossip-advertise-addr")
+            .value_name("ip[:port]")
+            .help("The address other members use to reach this one, when it differs from the \
+                   listen address (ex: behind NAT) [default: the address peers see]"))
         .arg(Arg::with_name("listen-peer")
             .long("listen-peer")
             .value_name("ip:port")
@@ -249,10 +692,110 @@ fn main() {
             .long("listen-http")
             .value_name("ip:port")
             .help("The HTTP API listen address [default: 0.0.0.0:9631]"))
+        .arg(Arg::with_name("listen-grpc")
+            .long("listen-grpc")
+            .value_name("ip:port")
+            .help("Serve the gRPC control service on this address [default: not served]"))
         .arg(Arg::with_name("permanent-peer")
             .short("I")
             .long("permanent-peer")
-            .help("If this service is a permanent peer"));
+            .help("If this service is a permanent peer"))
+        .arg(Arg::with_name("bastion")
+            .long("bastion")
+            .conflicts_with("pkg_ident_or_artifact")
+            .help("Run no service, only anchoring the ring as a permanent peer which gossips \
+                   more often and to more members"))
+        .arg(Arg::with_name("strict-render")
+            .long("strict-render")
+            .help("Fail to render configuration and hooks which reference missing data, and \
+                   don't (re)start the service until they render"))
+        .arg(Arg::with_name("http-threads")
+            .long("http-threads")
+            .value_name("count")
+            .help("Number of threads serving HTTP API requests [default: 8 per CPU]"))
+        .arg(Arg::with_name("http-disable")
+            .long("http-disable")
+            .value_name("endpoint")
+            .multiple(true)
+            .use_delimiter(true)
+            .help("HTTP API endpoints which are not served (ex: census,butterfly)"))
+        .arg(Arg::with_name("http-local-only")
+            .long("http-local-only")
+            .value_name("endpoint")
+            .multiple(true)
+            .use_delimiter(true)
+            .help("HTTP API endpoints which are only served to clients on the loopback \
+                   interface (ex: census,config)"))
+        .arg(Arg::with_name("http-cors-origin")
+            .long("http-cors-origin")
+            .value_name("origin")
+            .multiple(true)
+            .use_delimiter(true)
+            .help("Origins which browsers may call the HTTP API from, or * for any \
+                   (ex: https://dashboard.example.com)"))
+        .arg(Arg::with_name("http-tls-cert-from-file")
+            .long("http-tls-cert-from-file")
+            .value_name("path")
+            .requires("http-tls-key-from-file")
+            .help("Serve the HTTP API over TLS with the PEM certificate chain in this file, \
+                   reloading it whenever it changes"))
+        .arg(Arg::with_name("http-tls-key-from-file")
+            .long("http-tls-key-from-file")
+            .value_name("path")
+            .requires("http-tls-cert-from-file")
+            .help("The PEM private key of the HTTP API's TLS certificate, reloaded whenever it \
+                   changes"))
+        .arg(Arg::with_name("gateway-auth-from-file")
+            .long("gateway-auth-from-file")
+            .value_name("path")
+            .help("Require HTTP API and control gateway clients to present the token in this \
+                   file as a bearer token, reading it again whenever it changes"))
+        .arg(Arg::with_name("http-max-request-bytes")
+            .long("http-max-request-bytes")
+            .value_name("bytes")
+            .help("Largest HTTP API request accepted, counting its URL and body \
+                   [default: 65536]"))
+        .arg(Arg::with_name("http-request-timeout")
+            .long("http-request-timeout")
+            .value_name("seconds")
+            .help("Seconds the HTTP API waits on a client reading or writing a request \
+                   [default: 30]"))
+        .arg(Arg::with_name("gossip-threads")
+            .long("gossip-threads")
+            .value_name("count")
+            .help("Number of threads sending gossip to other members [default: a new thread \
+                   for each member gossiped to]"))
+        .arg(Arg::with_name("gossip-compression")
+            .long("gossip-compression")
+            .value_name("codec")
+            .possible_values(&["none", "lz4", "zstd"])
+            .help("Compress gossip sent to members which support the codec [default: none]"))
+        .arg(Arg::with_name("gossip-departed-ttl")
+            .long("gossip-departed-ttl")
+            .value_name("seconds")
+            .help("Forget members which left the ring this many seconds ago, along with their \
+                   rumors [default: 86400]"))
+        .arg(Arg::with_name("departed-ttl")
+            .long("departed-ttl")
+            .value_name("seconds")
+            .help("Forget members of this service group which left the ring this many seconds \
+                   ago; every member of the group should be started with the same TTL \
+                   [default: the --gossip-departed-ttl]"))
+        .arg(Arg::with_name("alias")
+            .long("alias")
+            .value_name("name")
+            .help("A human-readable name for this member, shown alongside its id \
+                   [default: the hostname]"))
+        .arg(Arg::with_name("hook-threads")
+            .long("hook-threads")
+            .value_name("count")
+            .help("Maximum number of scheduled, update and provisioning hooks which may run \
+                   at once [default: unlimited]"))
+        .arg(Arg::with_name("reconfigure-debounce")
+            .long("reconfigure-debounce")
+            .value_name("seconds")
+            .help("Wait until the census has been unchanged for this many seconds before \
+                   re-rendering configuration and running the reconfigure hook [default: 0]"));
     let sub_bash = SubCommand::with_name("bash")
         .about("Start an interactive shell (bash)")
         .aliases(&["b", "ba", "bas"]);
@@ -264,6 +807,69 @@ fn main() {
             .index(1)
             .required(true)
             .help("Name of package"));
+    let sub_log_level = SubCommand::with_name("log-level")
+        .about("Change the log level of a running Supervisor")
+        .arg(Arg::with_name("level")
+            .index(1)
+            .required(true)
+            .possible_values(logger::LEVELS)
+            .help("The level to log at"))
+        .arg(Arg::with_name("subsystem")
+            .long("subsystem")
+            .takes_value(true)
+            .possible_values(&logger::SUBSYSTEM_NAMES)
+            .help("Only change the level of this part of the Supervisor"))
+        .arg(Arg::with_name("service")
+            .long("service")
+            .value_name("service_group")
+            .help("Only change the level while the Supervisor works on this service group \
+                   (ex: redis.default)"))
+        .arg(Arg::with_name("remote-sup")
+            .short("r")
+            .long("remote-sup")
+            .value_name("addr")
+            .help("Address of the Supervisor's HTTP gateway [default: 127.0.0.1:9631]"));
+    let sub_state = SubCommand::with_name("state")
+        .about("Move a Supervisor's services to another host")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .subcommand(SubCommand::with_name("export")
+            .about("Write the running Supervisor's services, their configuration and files, and \
+                    the key cache to a bundle")
+            .arg(Arg::with_name("file")
+                .index(1)
+                .required(true)
+                .help("Where to write the bundle"))
+            .arg(Arg::with_name("remote-sup")
+                .short("r")
+                .long("remote-sup")
+                .value_name("addr")
+                .help("Address of the Supervisor's HTTP gateway [default: 127.0.0.1:9631]")))
+        .subcommand(SubCommand::with_name("import")
+            .about("Restore a bundle written by export and install its packages")
+            .arg(Arg::with_name("file")
+                .index(1)
+                .required(true)
+                .help("The bundle to restore"))
+            .arg(arg_url()));
+    let sub_quarantine = SubCommand::with_name("quarantine")
+        .about("Show or clear the releases a service failed to update to")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .subcommand(SubCommand::with_name("list")
+            .about("List the releases the service failed to update to, and which are quarantined")
+            .arg(Arg::with_name("service-name")
+                .index(1)
+                .required(true)
+                .help("The name of the service (ex: redis)")))
+        .subcommand(SubCommand::with_name("clear")
+            .about("Forget a release's failures, so the Supervisor tries to update to it again")
+            .arg(Arg::with_name("service-name")
+                .index(1)
+                .required(true)
+                .help("The name of the service (ex: redis)"))
+            .arg(Arg::with_name("pkg_ident")
+                .index(2)
+                .help("The release to forget (ex: core/redis/3.2.4/20170514150022) [default: \
+                       every release]")));
     let args = App::new(sup::PROGRAM_NAME.as_str())
         .version(VERSION)
         .setting(AppSettings::VersionlessSubcommands)
@@ -276,15 +882,39 @@ fn main() {
             .long("no-color")
             .global(true)
             .help("Turn ANSI color off :("))
+        .arg(Arg::with_name("fs-root")
+            .long("fs-root")
+            .value_name("path")
+            .global(true)
+            .help("The filesystem root the hab directory lives under [default: $FS_ROOT, or /]"))
         .subcommand(sub_start)
         .subcommand(sub_bash)
         .subcommand(sub_sh)
-        .subcommand(sub_config);
+        .subcommand(sub_config)
+        .subcommand(sub_log_level)
+        .subcommand(sub_state)
+        .subcommand(sub_quarantine);
     let matches = args.get_matches();
 
     debug!("clap matches {:?}", matches);
-    let subcommand_name = matches.subcommand_name().unwrap();
-    let subcommand_matches = matches.subcommand_matches(subcommand_name).unwrap();
+    let (subcommand_name, subcommand_matches) = match matches.subcommand() {
+        ("state", Some(m)) => {
+            match m.subcommand() {
+                ("export", Some(m)) => ("state-export", m),
+                ("import", Some(m)) => ("state-import", m),
+                _ => unreachable!(),
+            }
+        }
+        ("quarantine", Some(m)) => {
+            match m.subcommand() {
+                ("list", Some(m)) => ("quarantine-list", m),
+                ("clear", Some(m)) => ("quarantine-clear", m),
+                _ => unreachable!(),
+            }
+        }
+        (name, Some(m)) => (name, m),
+        _ => unreachable!(),
+    };
     debug!("subcommand name {:?}", &subcommand_name);
     debug!("Subcommand matches {:?}", &subcommand_matches);
 
@@ -297,6 +927,27 @@ fn main() {
         Command::ShellBash => shell_bash(),
         Command::ShellSh => shell_sh(),
         Command::Start => start(),
+        Command::LogLevel => {
+            log_level::start(subcommand_matches.value_of("remote-sup").unwrap_or("127.0.0.1:9631"),
+                             subcommand_matches.value_of("level").unwrap(),
+                             subcommand_matches.value_of("subsystem"),
+                             subcommand_matches.value_of("service"))
+        }
+        Command::StateExport => {
+            state::export(subcommand_matches.value_of("remote-sup").unwrap_or("127.0.0.1:9631"),
+                          Path::new(subcommand_matches.value_of("file").unwrap()))
+        }
+        Command::StateImport => {
+            state::import(gconfig().url(),
+                          Path::new(subcommand_matches.value_of("file").unwrap()))
+        }
+        Command::QuarantineList => {
+            quarantine::list(subcommand_matches.value_of("service-name").unwrap())
+        }
+        Command::QuarantineClear => {
+            quarantine::clear(subcommand_matches.value_of("service-name").unwrap(),
+                              subcommand_matches.value_of("pkg_ident"))
+        }
     };
 
     match result {
@@ -321,8 +972,12 @@ fn shell_bash() -> Result<()> {
     shell::bash()
 }
 
-/// Start a service
+/// Start a service, or a bastion
 fn start() -> Result<()> {
+    if gconfig().bastion() {
+        outputln!("Starting {}", Yellow.bold().paint("bastion"));
+        return start::bastion();
+    }
     outputln!("Starting {}",
               Yellow.bold().paint(gconfig().package().to_string()));
     try!(start::package());
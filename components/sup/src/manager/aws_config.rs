@@ -0,0 +1,432 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Service configuration kept in AWS Systems Manager Parameter Store and Secrets Manager.
+//!
+//! Started with `--aws-config-path`, such as `/habitat/prod`, the Supervisor polls AWS for the
+//! configuration of each service group it runs, named `/habitat/prod/<service>.<group>`, or
+//! `/habitat/prod/<org>/<service>.<group>` for a group in an organization:
+//!
+//! * The Secrets Manager secret of that name, whose value is a TOML document.
+//! * The parameters under it, each of which sets one key: `/habitat/prod/redis.default/tls/port`
+//!   sets `tls.port`. `SecureString` parameters are decrypted, and `StringList` parameters are
+//!   arrays; every value is a string.
+//!
+//! Parameters override the keys of the secret. Together they are a layer of the service's
+//! configuration, above `user.toml` and below configuration applied to the ring, and the service
+//! is rendered again whenever its layer changes. A layer holding the secret or a `SecureString`
+//! is a secret itself, so the HTTP gateway only serves the configuration of its service to
+//! clients presenting the gateway's auth token. Requests are signed with the credentials
+//! `util::aws` finds, such as those of the instance's IAM role.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+use std::thread;
+use std::time::Duration;
+
+use http_client::{self, ApiClient};
+use hyper::header::Headers;
+use hyper::status::StatusCode;
+use serde::Serialize;
+use serde_json;
+use toml;
+use url::Url;
+
+use {PRODUCT, VERSION};
+use error::{Error, Result};
+use hcore::service::ServiceGroup;
+use manager::State;
+use util::aws::{self, Credentials, Signer};
+
+static LOGKEY: &'static str = "AC";
+
+/// How often AWS is polled, unless `--aws-config-interval` says otherwise.
+pub const DEFAULT_INTERVAL_SECS: u64 = 60;
+
+lazy_static! {
+    static ref LAYERS: RwLock<HashMap<String, Layer>> = RwLock::new(HashMap::new());
+}
+
+/// Counts the changes to any service group's layer.
+static GENERATION: AtomicUsize = ATOMIC_USIZE_INIT;
+
+struct Layer {
+    table: toml::Table,
+    /// Whether the layer holds a Secrets Manager secret or a decrypted `SecureString`.
+    secret: bool,
+    /// The generation in which the layer last changed.
+    generation: usize,
+}
+
+pub struct AwsConfig {
+    state: State,
+    path: String,
+    region: String,
+    interval: Duration,
+}
+
+#[derive(Serialize)]
+struct GetParametersByPathRequest<'a> {
+    #[serde(rename = "Path")]
+    path: &'a str,
+    #[serde(rename = "Recursive")]
+    recursive: bool,
+    #[serde(rename = "WithDecryption")]
+    with_decryption: bool,
+    #[serde(rename = "NextToken", skip_serializing_if = "Option::is_none")]
+    next_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GetParametersByPathResponse {
+    #[serde(rename = "Parameters", default)]
+    parameters: Vec<Parameter>,
+    #[serde(rename = "NextToken", default)]
+    next_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Parameter {
+    #[serde(rename = "Name")]
+    name: String,
+    #[serde(rename = "Type")]
+    kind: String,
+    #[serde(rename = "Value")]
+    value: String,
+}
+
+#[derive(Serialize)]
+struct GetSecretValueRequest<'a> {
+    #[serde(rename = "SecretId")]
+    secret_id: &'a str,
+}
+
+#[derive(Deserialize)]
+struct GetSecretValueResponse {
+    #[serde(rename = "SecretString", default)]
+    secret_string: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    #[serde(rename = "__type", default)]
+    kind: String,
+    #[serde(rename = "message", default)]
+    message: String,
+}
+
+/// Returns the configuration read from AWS for a service group, if any has been.
+pub fn layer(service_group: &str) -> Option<toml::Table> {
+    LAYERS.read()
+        .expect("AWS config lock is poisoned!")
+        .get(service_group)
+        .map(|l| l.table.clone())
+}
+
+/// Returns whether the configuration read from AWS for a service group holds secrets.
+pub fn holds_secrets(service_group: &str) -> bool {
+    LAYERS.read()
+        .expect("AWS config lock is poisoned!")
+        .get(service_group)
+        .map_or(false, |l| l.secret)
+}
+
+/// Returns the current generation, and the service groups whose layer has changed since
+/// `generation`.
+pub fn changed_since(generation: usize) -> (usize, Vec<String>) {
+    let layers = LAYERS.read().expect("AWS config lock is poisoned!");
+    let changed = layers.iter()
+        .filter(|&(_, layer)| layer.generation > generation)
+        .map(|(service_group, _)| service_group.clone())
+        .collect();
+    (GENERATION.load(Ordering::SeqCst), changed)
+}
+
+impl AwsConfig {
+    /// # Failures
+    ///
+    /// * `AWS_REGION` isn't set, and we aren't running on an EC2 instance
+    pub fn new(state: State, path: &str, interval: Duration) -> Result<AwsConfig> {
+        Ok(AwsConfig {
+            state: state,
+            path: path.trim_right_matches('/').to_string(),
+            region: try!(aws::region()),
+            interval: interval,
+        })
+    }
+
+    /// Spawns the thread which polls AWS.
+    pub fn start(self) -> Result<()> {
+        outputln!("Reading service configuration from AWS under {} in {}",
+                  self.path,
+                  self.region);
+        try!(thread::Builder::new()
+            .name(String::from("aws-config"))
+            .spawn(move || loop {
+                self.poll();
+                thread::sleep(self.interval);
+            }));
+        Ok(())
+    }
+
+    fn poll(&self) {
+        let service_groups: Vec<ServiceGroup> = self.state
+            .services
+            .read()
+            .expect("Services lock is poisoned!")
+            .iter()
+            .map(|s| s.service_group.clone())
+            .collect();
+        if service_groups.is_empty() {
+            return;
+        }
+        // A role's credentials expire, so they are loaded again for each poll
+        let credentials = match Credentials::load(None, None) {
+            Ok(credentials) => credentials,
+            Err(e) => {
+                outputln!("Cannot read service configuration from AWS: {}", e);
+                return;
+            }
+        };
+        for service_group in service_groups {
+            match self.fetch(&credentials, &service_group) {
+                Ok((table, secret)) => update(&service_group.to_string(), table, secret),
+                Err(e) => {
+                    outputln!("Cannot read configuration of {} from AWS, keeping what we had: {}",
+                              service_group,
+                              e)
+                }
+            }
+        }
+    }
+
+    /// Returns the name the configuration of a service group is kept under.
+    fn name(&self, service_group: &ServiceGroup) -> String {
+        match service_group.org() {
+            Some(org) => {
+                format!("{}/{}/{}.{}",
+                        self.path,
+                        org,
+                        service_group.service(),
+                        service_group.group())
+            }
+            None => format!("{}/{}.{}", self.path, service_group.service(), service_group.group()),
+        }
+    }
+
+    /// Returns the layer of a service group's configuration: its secret, overridden by its
+    /// parameters, and whether it holds the secret or a `SecureString`.
+    fn fetch(&self,
+             credentials: &Credentials,
+             service_group: &ServiceGroup)
+             -> Result<(toml::Table, bool)> {
+        let name = self.name(service_group);
+        let mut secret = false;
+        let mut table = match try!(self.secret(credentials, &name)) {
+            Some(doc) => {
+                secret = true;
+                let mut parser = toml::Parser::new(&doc);
+                match parser.parse() {
+                    Some(table) => table,
+                    None => {
+                        return Err(sup_error!(Error::AwsFailed(format!("secret {} isn't TOML",
+                                                                       name))))
+                    }
+                }
+            }
+            None => toml::Table::new(),
+        };
+        for parameter in try!(self.parameters(credentials, &name)) {
+            let keys: Vec<&str> = parameter.name[name.len()..]
+                .split('/')
+                .filter(|k| !k.is_empty())
+                .collect();
+            if parameter.kind == "SecureString" {
+                secret = true;
+            }
+            let value = if parameter.kind == "StringList" {
+                toml::Value::Array(parameter.value
+                    .split(',')
+                    .map(|v| toml::Value::String(v.to_string()))
+                    .collect())
+            } else {
+                toml::Value::String(parameter.value.clone())
+            };
+            insert_path(&mut table, &keys, value);
+        }
+        Ok((table, secret))
+    }
+
+    /// Returns the value of the secret `name`, or `None` if there's no such secret.
+    fn secret(&self, credentials: &Credentials, name: &str) -> Result<Option<String>> {
+        let res = try!(self.call(credentials,
+                                 "secretsmanager",
+                                 "secretsmanager.GetSecretValue",
+                                 &GetSecretValueRequest { secret_id: name }));
+        match res {
+            Some(body) => Ok(try!(from_json::<GetSecretValueResponse>(&body)).secret_string),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns every parameter under `path`, decrypted.
+    fn parameters(&self, credentials: &Credentials, path: &str) -> Result<Vec<Parameter>> {
+        let mut parameters = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let req = GetParametersByPathRequest {
+                path: path,
+                recursive: true,
+                with_decryption: true,
+                next_token: next_token,
+            };
+            let res = match try!(self.call(credentials,
+                                           "ssm",
+                                           "AmazonSSM.GetParametersByPath",
+                                           &req)) {
+                Some(body) => try!(from_json::<GetParametersByPathResponse>(&body)),
+                None => break,
+            };
+            parameters.extend(res.parameters);
+            next_token = res.next_token;
+            if next_token.is_none() {
+                break;
+            }
+        }
+        Ok(parameters)
+    }
+
+    /// Signs and sends a call to a JSON API, returning the response body, or `None` if the
+    /// resource it's about doesn't exist.
+    fn call<T>(&self,
+               credentials: &Credentials,
+               service: &str,
+               target: &str,
+               body: &T)
+               -> Result<Option<String>>
+        where T: Serialize
+    {
+        let host = format!("{}.{}.amazonaws.com", service, self.region);
+        let payload = serde_json::to_string(body).unwrap();
+        let signer = Signer {
+            credentials: credentials,
+            region: &self.region,
+            service: service,
+        };
+        let mut headers = try!(signer.headers(&host));
+        headers.push(("content-type", String::from("application/x-amz-json-1.1")));
+        headers.push(("x-amz-target", target.to_string()));
+        let authorization =
+            try!(signer.authorization("POST", "/", "", &headers, payload.as_bytes()));
+
+        let url = try!(Url::parse(&format!("https://{}/", host))
+            .map_err(|e| sup_error!(Error::AwsFailed(format!("{}: {}", host, e)))));
+        let client = try!(ApiClient::new(&url, PRODUCT, VERSION, None));
+        let mut raw = Headers::new();
+        // Hyper fills in the Host header itself
+        for (name, value) in headers.into_iter().filter(|&(name, _)| name != "host") {
+            raw.set_raw(name, vec![value.into_bytes()]);
+        }
+        raw.set_raw("Authorization", vec![authorization.into_bytes()]);
+        let mut res = match client.post("").headers(raw).body(payload.as_str()).send() {
+            Ok(res) => res,
+            Err(e) => {
+                return Err(sup_error!(Error::HttpClient(http_client::Error::HyperError(e))))
+            }
+        };
+        let mut body = String::new();
+        try!(res.read_to_string(&mut body));
+        if res.status == StatusCode::Ok {
+            return Ok(Some(body));
+        }
+        let error: ErrorResponse = serde_json::from_str(&body).unwrap_or(ErrorResponse {
+            kind: String::new(),
+            message: body.clone(),
+        });
+        if error.kind.ends_with("ResourceNotFoundException") {
+            return Ok(None);
+        }
+        Err(sup_error!(Error::AwsFailed(format!("{} returned {}: {} {}",
+                                                target,
+                                                res.status,
+                                                error.kind,
+                                                error.message))))
+    }
+}
+
+/// Stores a service group's layer, starting a new generation if it has changed.
+fn update(service_group: &str, table: toml::Table, secret: bool) {
+    let mut layers = LAYERS.write().expect("AWS config lock is poisoned!");
+    if layers.get(service_group).map_or(false, |layer| layer.table == table) {
+        return;
+    }
+    outputln!("Configuration of {} from AWS has changed", service_group);
+    let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    layers.insert(service_group.to_string(),
+                  Layer {
+                      table: table,
+                      secret: secret,
+                      generation: generation,
+                  });
+}
+
+/// Sets the value at a path of keys, creating the tables along it, and replacing any other values
+/// in the way.
+fn insert_path(table: &mut toml::Table, keys: &[&str], value: toml::Value) {
+    let (key, rest) = match keys.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+    if rest.is_empty() {
+        table.insert(key.to_string(), value);
+        return;
+    }
+    let is_table = match table.get(*key) {
+        Some(&toml::Value::Table(_)) => true,
+        _ => false,
+    };
+    if !is_table {
+        table.insert(key.to_string(), toml::Value::Table(toml::Table::new()));
+    }
+    if let Some(&mut toml::Value::Table(ref mut inner)) = table.get_mut(*key) {
+        insert_path(inner, rest, value);
+    }
+}
+
+fn from_json<T: ::serde::Deserialize>(body: &str) -> Result<T> {
+    serde_json::from_str(body)
+        .map_err(|e| sup_error!(Error::AwsFailed(format!("unexpected response: {}", e))))
+}
+
+#[cfg(test)]
+mod test {
+    use toml;
+
+    use super::insert_path;
+
+    #[test]
+    fn parameters_set_nested_keys() {
+        let mut table = toml::Parser::new("port = 6379\n[tls]\nenabled = false\n").parse().unwrap();
+        insert_path(&mut table, &["tls", "port"], toml::Value::String(String::from("6380")));
+        insert_path(&mut table, &["port", "main"], toml::Value::String(String::from("7000")));
+        insert_path(&mut table, &[], toml::Value::String(String::from("ignored")));
+        let expected = toml::Parser::new("[port]\nmain = \"7000\"\n[tls]\nenabled = false\n\
+                                          port = \"6380\"\n")
+            .parse()
+            .unwrap();
+        assert_eq!(table, expected);
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bastion mode.
+//!
+//! A bastion is a Supervisor that runs no service, and exists only to anchor the ring: it is a
+//! permanent peer, so members keep trying to reach it after a partition, and the others can
+//! list a few bastions as their `--peer`s instead of each other. With no service to run, a
+//! bastion gossips rumors twice as often and to twice as many members as other Supervisors, so
+//! what reaches it reaches the rest of the ring sooner.
+
+use std::sync::atomic::Ordering;
+
+use butterfly;
+use butterfly::member::Health;
+use butterfly::server::push::FANOUT;
+use butterfly::server::timing::Timing;
+
+/// How often a bastion gossips rumors, in milliseconds.
+pub const BASTION_GOSSIP_PERIOD_MS: i64 = 500;
+
+/// How many members a bastion gossips rumors to at once, which is also how many threads it sends
+/// them with unless `--gossip-threads` is given.
+pub const BASTION_FANOUT: usize = FANOUT * 2;
+
+/// Returns the gossip timing of a bastion.
+pub fn timing() -> Timing {
+    let default = Timing::default();
+    Timing::new(default.ping_ms,
+                default.pingreq_ms,
+                BASTION_GOSSIP_PERIOD_MS,
+                default.suspicion_timeout_protocol_periods)
+}
+
+/// What the HTTP gateway reports about a bastion.
+#[derive(Debug, Serialize)]
+pub struct Bastion {
+    pub bastion: bool,
+    pub member_id: String,
+    pub alias: Option<String>,
+    pub permanent: bool,
+    pub gossip_period_ms: i64,
+    /// How many members it gossips rumors to at once.
+    pub fanout: usize,
+    /// How many threads it sends them with.
+    pub push_workers: usize,
+    /// How many members of the ring it knows of, by health.
+    pub alive: usize,
+    pub suspect: usize,
+    pub confirmed: usize,
+}
+
+impl Bastion {
+    pub fn new(butterfly: &butterfly::Server) -> Self {
+        let (mut alive, mut suspect, mut confirmed) = (0, 0, 0);
+        butterfly.member_list.with_members(|member| {
+            match butterfly.member_list.health_of(member) {
+                Some(Health::Alive) => alive += 1,
+                Some(Health::Suspect) => suspect += 1,
+                Some(Health::Confirmed) => confirmed += 1,
+                None => {}
+            }
+        });
+        let member = butterfly.member.read().expect("Member is poisoned");
+        Bastion {
+            bastion: true,
+            member_id: butterfly.member_id().to_string(),
+            alias: if member.get_alias().is_empty() {
+                None
+            } else {
+                Some(member.get_alias().to_string())
+            },
+            permanent: member.get_persistent(),
+            gossip_period_ms: BASTION_GOSSIP_PERIOD_MS,
+            fanout: butterfly.fanout.load(Ordering::Relaxed),
+            push_workers: butterfly.push_workers.load(Ordering::Relaxed),
+            alive: alive,
+            suspect: suspect,
+            confirmed: confirmed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use butterfly::server::timing::Timing;
+
+    use super::{timing, BASTION_GOSSIP_PERIOD_MS};
+
+    #[test]
+    fn bastions_only_gossip_more_often() {
+        let default = Timing::default();
+        let bastion = timing();
+        assert_eq!(bastion.gossip_period_ms, BASTION_GOSSIP_PERIOD_MS);
+        assert!(bastion.gossip_period_ms < default.gossip_period_ms);
+        assert_eq!(bastion.ping_ms, default.ping_ms);
+        assert_eq!(bastion.pingreq_ms, default.pingreq_ms);
+        assert_eq!(bastion.suspicion_timeout_protocol_periods,
+                   default.suspicion_timeout_protocol_periods);
+    }
+}
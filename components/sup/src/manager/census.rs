@@ -14,24 +14,55 @@
 
 use std::collections::HashMap;
 use std::ops::{Deref, DerefMut};
+use std::path::PathBuf;
+use std::result;
 use std::str::{self, FromStr};
 
+use hcore::fs;
 use hcore::package::PackageIdent;
 use hcore::service::ServiceGroup;
 use hcore::util;
 use butterfly;
-use butterfly::rumor::{Service as ServiceRumor, Election as ElectionRumor};
+use butterfly::rumor::{Service as ServiceRumor, Election as ElectionRumor,
+                       ServiceScale as ServiceScaleRumor, ActiveColor as ActiveColorRumor};
 use butterfly::rumor::election::Election_Status;
 use butterfly::rumor::service::SysInfo;
 use butterfly::member::{Member, Health};
 use toml;
+use url::form_urlencoded;
 
 static LOGKEY: &'static str = "CE";
 
+/// Name of the file, in a service's svc directory, which holds the census rendered as JSON.
+pub const CENSUS_FILENAME: &'static str = "census.json";
+/// Environment variable pointing hooks and the run hook at the rendered census file.
+pub const CENSUS_ENVVAR: &'static str = "HAB_CENSUS_FILE";
+
+/// How many census entries a page holds when no limit is asked for.
+pub const DEFAULT_CENSUS_PAGE_SIZE: usize = 100;
+/// The most census entries a page may hold.
+pub const MAX_CENSUS_PAGE_SIZE: usize = 1_000;
+
+/// Returns the path of the rendered census file for the given service.
+pub fn census_file_path(service: &str) -> PathBuf {
+    fs::svc_path(service).join(CENSUS_FILENAME)
+}
+
+/// Returns what a service's active color is gossiped under: the service, with its organization if
+/// it has one (ex: `app@acme`).
+pub fn color_key(service: &str, org: Option<&str>) -> String {
+    match org {
+        Some(org) => format!("{}@{}", service, org),
+        None => service.to_string(),
+    }
+}
+
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct CensusUpdate {
     pub service_counter: usize,
     service_config_counter: usize,
+    service_scale_counter: usize,
+    active_color_counter: usize,
     election_counter: usize,
     election_update_counter: usize,
     membership_counter: usize,
@@ -42,6 +73,8 @@ impl CensusUpdate {
         CensusUpdate {
             service_counter: butterfly.service_store.get_update_counter(),
             service_config_counter: butterfly.service_config_store.get_update_counter(),
+            service_scale_counter: butterfly.service_scale_store.get_update_counter(),
+            active_color_counter: butterfly.active_color_store.get_update_counter(),
             election_counter: butterfly.election_store.get_update_counter(),
             election_update_counter: butterfly.update_store.get_update_counter(),
             membership_counter: butterfly.member_list.get_update_counter(),
@@ -52,6 +85,8 @@ impl CensusUpdate {
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Default)]
 pub struct CensusEntry {
     pub member_id: String,
+    /// The human-readable name the member is gossiped under, if it has one.
+    pub alias: Option<String>,
     pub service: String,
     pub group: String,
     pub org: Option<String>,
@@ -77,6 +112,12 @@ pub struct CensusEntry {
     pub suspect: Option<bool>,
     pub confirmed: Option<bool>,
     pub persistent: Option<bool>,
+    pub last_exit_code: Option<i32>,
+    /// The incarnation of the last restart of the service group this member finished.
+    pub restart_incarnation: Option<u64>,
+    /// The releases the member failed to update to so often that it skips them.
+    #[serde(default)]
+    pub quarantined: Vec<String>,
 }
 
 impl CensusEntry {
@@ -126,6 +167,17 @@ impl CensusEntry {
         self.org = Some(value);
     }
 
+    pub fn get_alias(&self) -> &str {
+        match self.alias.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    pub fn set_alias(&mut self, value: String) {
+        self.alias = Some(value);
+    }
+
     pub fn get_address(&self) -> &str {
         &self.address
     }
@@ -262,6 +314,26 @@ impl CensusEntry {
         self.persistent.unwrap_or(false)
     }
 
+    pub fn set_last_exit_code(&mut self, value: i32) {
+        self.last_exit_code = Some(value);
+    }
+
+    pub fn get_last_exit_code(&self) -> Option<i32> {
+        self.last_exit_code
+    }
+
+    pub fn set_restart_incarnation(&mut self, value: u64) {
+        self.restart_incarnation = Some(value);
+    }
+
+    pub fn get_restart_incarnation(&self) -> u64 {
+        self.restart_incarnation.unwrap_or(0)
+    }
+
+    pub fn get_quarantined(&self) -> &[String] {
+        &self.quarantined
+    }
+
     pub fn populate_from_service(&mut self, rumor: &ServiceRumor) {
         self.set_member_id(String::from(rumor.get_member_id()));
         let sg = match ServiceGroup::from_str(rumor.get_service_group()) {
@@ -287,10 +359,20 @@ impl CensusEntry {
             .ok()
             .and_then(|v| toml::decode_str(v))
             .unwrap_or(SysInfo::default());
+        if rumor.has_last_exit_code() {
+            self.set_last_exit_code(rumor.get_last_exit_code());
+        }
+        if rumor.has_restart_incarnation() {
+            self.set_restart_incarnation(rumor.get_restart_incarnation());
+        }
+        self.quarantined = rumor.get_quarantined().to_vec();
     }
 
     pub fn populate_from_member(&mut self, member: &Member) {
         self.set_member_id(String::from(member.get_id()));
+        if member.has_alias() {
+            self.set_alias(String::from(member.get_alias()));
+        }
         self.set_address(String::from(member.get_address()));
         self.set_persistent(true);
     }
@@ -378,6 +460,23 @@ impl CensusEntry {
     }
 }
 
+/// How the alive members of a service group compare with the number it should have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Provisioning {
+    UnderProvisioned,
+    OverProvisioned,
+    Provisioned,
+}
+
+/// A service group's alive members alongside the number it should have.
+#[derive(Debug, Serialize)]
+pub struct Scale<'a> {
+    pub service_group: &'a str,
+    pub desired_count: u64,
+    pub alive_count: u64,
+    pub provisioning: Provisioning,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Census {
     // JW TODO: This needs to become an Ordered HashMap keyed on member_id. This will reduce our
@@ -386,6 +485,12 @@ pub struct Census {
     // members_ordered() functions.
     pub population: HashMap<String, CensusEntry>,
     pub member_id: String,
+    /// The number of members the service group should have, as set with `hab scale set`.
+    #[serde(default)]
+    pub desired_count: Option<u64>,
+    /// The group of this service which is live, as set with `hab svc switch`.
+    #[serde(default)]
+    pub active_color: Option<String>,
 }
 
 impl Deref for Census {
@@ -407,6 +512,8 @@ impl Census {
         Census {
             population: HashMap::new(),
             member_id: member_id,
+            desired_count: None,
+            active_color: None,
         }
     }
 
@@ -463,6 +570,43 @@ impl Census {
         entry.get_service()
     }
 
+    pub fn get_org(&self) -> Option<&str> {
+        let entry = self.population.values().nth(0).unwrap();
+        entry.org.as_ref().map(|org| org.as_str())
+    }
+
+    /// Return whether this is the live group of its service, or None if the service has no
+    /// active color.
+    pub fn is_active(&self) -> Option<bool> {
+        self.active_color.as_ref().map(|color| color == self.get_group())
+    }
+
+    /// Return how the alive members compare with the desired count, or None if the service group
+    /// has no desired count.
+    pub fn provisioning(&self) -> Option<Provisioning> {
+        self.desired_count.map(|desired| {
+            let alive = self.alive_members().len() as u64;
+            if alive < desired {
+                Provisioning::UnderProvisioned
+            } else if alive > desired {
+                Provisioning::OverProvisioned
+            } else {
+                Provisioning::Provisioned
+            }
+        })
+    }
+
+    /// Return the alive members in the order a rolling restart visits them: ordered by member_id,
+    /// but with the leader last, so that the group fails over at most once.
+    pub fn restart_order(&self) -> Vec<&CensusEntry> {
+        let mut members = self.alive_members_ordered();
+        if let Some(idx) = members.iter().position(|ce| ce.get_leader()) {
+            let leader = members.remove(idx);
+            members.push(leader);
+        }
+        members
+    }
+
     /// Return next alive peer, the peer to your right in the ordered members list, or None if you
     /// have no alive peers.
     pub fn next_peer(&self) -> Option<&CensusEntry> {
@@ -503,9 +647,82 @@ impl Census {
     }
 }
 
+/// A page of census entries, as asked for with the `service_group`, `offset`, `limit` and
+/// `fields` parameters of a census request.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CensusQuery {
+    /// Only the entries of this service group.
+    pub service_group: Option<String>,
+    pub offset: usize,
+    pub limit: usize,
+    /// Only these fields of each entry; every field if empty.
+    pub fields: Vec<String>,
+}
+
+impl Default for CensusQuery {
+    fn default() -> Self {
+        CensusQuery {
+            service_group: None,
+            offset: 0,
+            limit: DEFAULT_CENSUS_PAGE_SIZE,
+            fields: Vec::new(),
+        }
+    }
+}
+
+impl CensusQuery {
+    /// Parses the query string of a census request. Returns `None` if it doesn't ask for a page,
+    /// in which case the whole census is returned.
+    pub fn from_query(query: &str) -> result::Result<Option<CensusQuery>, String> {
+        let mut census_query = CensusQuery::default();
+        let mut paged = false;
+        for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+            match &*key {
+                "service_group" => census_query.service_group = Some(value.into_owned()),
+                "offset" => {
+                    match value.parse() {
+                        Ok(offset) => census_query.offset = offset,
+                        Err(_) => {
+                            return Err(format!("offset must be a whole number, not {}", value))
+                        }
+                    }
+                }
+                "limit" => {
+                    match value.parse() {
+                        Ok(limit) if limit > 0 && limit <= MAX_CENSUS_PAGE_SIZE => {
+                            census_query.limit = limit
+                        }
+                        _ => {
+                            return Err(format!("limit must be from 1 to {}, not {}",
+                                               MAX_CENSUS_PAGE_SIZE,
+                                               value))
+                        }
+                    }
+                }
+                "fields" => {
+                    census_query.fields = value.split(',')
+                        .filter(|f| !f.is_empty())
+                        .map(|f| f.to_string())
+                        .collect()
+                }
+                _ => return Err(format!("Unknown census parameter {}", key)),
+            }
+            paged = true;
+        }
+        if paged {
+            Ok(Some(census_query))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct CensusList {
     pub censuses: HashMap<String, Census>,
+    /// The live group of each service which has an active color, keyed as `color_key` does.
+    #[serde(default)]
+    pub active_colors: HashMap<String, String>,
 }
 
 impl Deref for CensusList {
@@ -518,7 +735,49 @@ impl Deref for CensusList {
 
 impl CensusList {
     pub fn new() -> CensusList {
-        CensusList { censuses: HashMap::new() }
+        CensusList {
+            censuses: HashMap::new(),
+            active_colors: HashMap::new(),
+        }
+    }
+
+    /// Builds the census from everything `butterfly` has heard of, as seen by its member.
+    pub fn from_butterfly(butterfly: &butterfly::Server) -> CensusList {
+        let mut cl = CensusList::new();
+        butterfly.service_store.with_keys(|(_group, rumors)| {
+            for (_member_id, service) in rumors.iter() {
+                let mut ce = CensusEntry::default();
+                ce.populate_from_service(service);
+                cl.insert(String::from(butterfly.member_id()), ce);
+            }
+        });
+        butterfly.election_store.with_keys(|(_service_group, rumors)| {
+            // We know you have an election, and this is the only key in the hash
+            let election = rumors.get("election").unwrap();
+            cl.populate_from_election(election);
+        });
+        butterfly.update_store.with_keys(|(_service_group, rumors)| {
+            // We know you have an election, and this is the only key in the hash
+            let election = rumors.get("election").unwrap();
+            cl.populate_from_update_election(election);
+        });
+        butterfly.service_scale_store.with_keys(|(_service_group, rumors)| {
+            if let Some(scale) = rumors.get("service_scale") {
+                cl.populate_from_scale(scale);
+            }
+        });
+        butterfly.active_color_store.with_keys(|(_service, rumors)| {
+            if let Some(active_color) = rumors.get("active_color") {
+                cl.populate_from_active_color(active_color);
+            }
+        });
+        butterfly.member_list.with_members(|member| {
+            cl.populate_from_member(member);
+            if let Some(health) = butterfly.member_list.health_of(member) {
+                cl.populate_from_health(member, health);
+            }
+        });
+        cl
     }
 
     pub fn insert(&mut self, member_id: String, census_entry: CensusEntry) {
@@ -536,6 +795,15 @@ impl CensusList {
         self.censuses.get(service_group)
     }
 
+    /// Return the census of the live group of a service, given as `service` or `service@org`.
+    pub fn get_active(&self, service: &str) -> Option<&Census> {
+        self.active_colors.get(service).and_then(|group| {
+            let mut parts = service.splitn(2, '@');
+            let name = parts.next().unwrap_or("");
+            self.get(&ServiceGroup::format(name, group, parts.next()))
+        })
+    }
+
     pub fn populate_from_election(&mut self, election: &ElectionRumor) {
         if let Some(census_entries) = self.censuses.get_mut(election.get_service_group()) {
             for census_entry in census_entries.values_mut() {
@@ -552,6 +820,63 @@ impl CensusList {
         }
     }
 
+    /// Returns how many census entries match the query, and those on the page it asks for,
+    /// ordered by service group and then member id so that pages are stable.
+    pub fn page(&self, query: &CensusQuery) -> (usize, Vec<&CensusEntry>) {
+        let mut entries: Vec<(&String, &CensusEntry)> = self.censuses
+            .iter()
+            .filter(|&(sg, _)| query.service_group.as_ref().map_or(true, |want| sg == want))
+            .flat_map(|(sg, census)| census.values().map(move |ce| (sg, ce)))
+            .collect();
+        entries.sort_by(|&(a_sg, a), &(b_sg, b)| (a_sg, &a.member_id).cmp(&(b_sg, &b.member_id)));
+        let total = entries.len();
+        let page = entries.into_iter()
+            .skip(query.offset)
+            .take(query.limit)
+            .map(|(_, ce)| ce)
+            .collect();
+        (total, page)
+    }
+
+    /// Return the scale of each service group which has a desired count.
+    pub fn scales(&self) -> Vec<Scale> {
+        let mut scales: Vec<Scale> = self.censuses
+            .iter()
+            .filter_map(|(service_group, census)| {
+                match (census.desired_count, census.provisioning()) {
+                    (Some(desired), Some(provisioning)) => {
+                        Some(Scale {
+                            service_group: service_group,
+                            desired_count: desired,
+                            alive_count: census.alive_members().len() as u64,
+                            provisioning: provisioning,
+                        })
+                    }
+                    _ => None,
+                }
+            })
+            .collect();
+        scales.sort_by(|a, b| a.service_group.cmp(b.service_group));
+        scales
+    }
+
+    pub fn populate_from_active_color(&mut self, active_color: &ActiveColorRumor) {
+        let service = active_color.get_service();
+        let group = active_color.get_group();
+        for census in self.censuses.values_mut() {
+            if color_key(census.get_service(), census.get_org()) == service {
+                census.active_color = Some(group.to_string());
+            }
+        }
+        self.active_colors.insert(service.to_string(), group.to_string());
+    }
+
+    pub fn populate_from_scale(&mut self, scale: &ServiceScaleRumor) {
+        if let Some(census) = self.censuses.get_mut(scale.get_service_group()) {
+            census.desired_count = Some(scale.get_desired_count());
+        }
+    }
+
     pub fn populate_from_member(&mut self, member: &Member) {
         for (_service_group, census) in self.censuses.iter_mut() {
             if let Some(ce) = census.get_mut(member.get_id()) {
@@ -606,6 +931,14 @@ mod tests {
             assert_eq!(ce.get_group(), "times");
             assert_eq!(ce.get_org(), "ofgrace");
             assert_eq!(ce.get_pkg(), &ident);
+            assert_eq!(ce.get_last_exit_code(), None);
+            assert_eq!(ce.get_restart_incarnation(), 0);
+            assert!(ce.get_quarantined().is_empty());
+
+            let mut service = service;
+            service.mut_quarantined().push(String::from("core/overwatch/1.2.4/20161209121212"));
+            ce.populate_from_service(&service);
+            assert_eq!(ce.get_quarantined(), &["core/overwatch/1.2.4/20161209121212"]);
         }
 
         #[test]
@@ -613,11 +946,104 @@ mod tests {
             let mut ce = CensusEntry::default();
             let mut member = Member::new();
             member.set_address(String::from("162.42.150.33"));
+            member.set_alias(String::from("web-1"));
             member.set_persistent(true);
             ce.populate_from_member(&member);
             assert_eq!(ce.get_member_id(), member.get_id());
+            assert_eq!(ce.get_alias(), "web-1");
             assert_eq!(ce.get_address(), member.get_address());
             assert_eq!(ce.get_persistent(), member.get_persistent());
         }
     }
+
+    mod census {
+        use manager::census::{Census, CensusEntry, Provisioning};
+
+        fn census_with_alive_members(count: usize) -> Census {
+            let mut census = Census::new(String::from("member-0"));
+            for n in 0..count {
+                let mut ce = CensusEntry::default();
+                ce.set_member_id(format!("member-{}", n));
+                ce.set_alive(true);
+                census.insert(format!("member-{}", n), ce);
+            }
+            census
+        }
+
+        #[test]
+        fn provisioning() {
+            let mut census = census_with_alive_members(2);
+            assert_eq!(census.provisioning(), None);
+            census.desired_count = Some(3);
+            assert_eq!(census.provisioning(), Some(Provisioning::UnderProvisioned));
+            census.desired_count = Some(2);
+            assert_eq!(census.provisioning(), Some(Provisioning::Provisioned));
+            census.desired_count = Some(1);
+            assert_eq!(census.provisioning(), Some(Provisioning::OverProvisioned));
+        }
+
+        #[test]
+        fn restart_order_puts_the_leader_last() {
+            let mut census = census_with_alive_members(3);
+            census.get_mut("member-1").unwrap().set_leader(true);
+            let order: Vec<&str> =
+                census.restart_order().iter().map(|ce| ce.get_member_id()).collect();
+            assert_eq!(order, vec!["member-0", "member-2", "member-1"]);
+        }
+    }
+
+    mod census_list {
+        use manager::census::{CensusEntry, CensusList, CensusQuery, MAX_CENSUS_PAGE_SIZE};
+
+        fn census_list() -> CensusList {
+            let mut cl = CensusList::new();
+            for group in &["blue", "green"] {
+                for n in 0..3 {
+                    let mut ce = CensusEntry::default();
+                    ce.set_member_id(format!("member-{}", n));
+                    ce.set_service(String::from("redis"));
+                    ce.set_group(group.to_string());
+                    cl.insert(String::from("member-0"), ce);
+                }
+            }
+            cl
+        }
+
+        #[test]
+        fn census_query_from_query() {
+            assert_eq!(CensusQuery::from_query("").unwrap(), None);
+            let query = CensusQuery::from_query("service_group=redis.blue&offset=2&limit=10&\
+                                                 fields=member_id,alive")
+                .unwrap()
+                .unwrap();
+            assert_eq!(query.service_group, Some(String::from("redis.blue")));
+            assert_eq!(query.offset, 2);
+            assert_eq!(query.limit, 10);
+            assert_eq!(query.fields, vec!["member_id", "alive"]);
+            let too_many = format!("limit={}", MAX_CENSUS_PAGE_SIZE + 1);
+            assert!(CensusQuery::from_query(&too_many).is_err());
+            assert!(CensusQuery::from_query("offset=first").is_err());
+            assert!(CensusQuery::from_query("sort=member_id").is_err());
+        }
+
+        #[test]
+        fn page() {
+            let cl = census_list();
+            let mut query = CensusQuery::default();
+            query.offset = 2;
+            query.limit = 2;
+            let (total, page) = cl.page(&query);
+            assert_eq!(total, 6);
+            let page: Vec<String> = page.iter()
+                .map(|ce| format!("{}/{}", ce.get_group(), ce.get_member_id()))
+                .collect();
+            assert_eq!(page, vec!["blue/member-2", "green/member-0"]);
+
+            query.service_group = Some(String::from("redis.green"));
+            query.offset = 0;
+            let (total, page) = cl.page(&query);
+            assert_eq!(total, 3);
+            assert_eq!(page.len(), 2);
+        }
+    }
 }
@@ -0,0 +1,403 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Peer discovery over multicast DNS.
+//!
+//! When enabled, the Supervisor answers mDNS queries for `_habitat._udp.local` with a PTR record
+//! for itself and a TXT record describing how to reach its gossip listener. It also queries for
+//! that name periodically, and any Supervisor which answers from the same ring is inserted into
+//! the member list, after which SWIM takes over as if it had been given with `--peer`.
+//!
+//! Only the handful of record types we need are encoded, and anything we can't parse is ignored,
+//! so other mDNS traffic on the segment is harmless.
+
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, SocketAddrV4, UdpSocket};
+use std::thread;
+use std::time::Duration;
+
+use butterfly;
+use butterfly::member::{Health, Member};
+use net2::UdpBuilder;
+
+use config::gconfig;
+use error::Result;
+
+static LOGKEY: &'static str = "DS";
+
+/// Like `try!`, but for the `Option`s returned while picking apart a packet.
+macro_rules! try_opt {
+    ($e:expr) => (match $e {
+        Some(v) => v,
+        None => return None,
+    })
+}
+
+const MDNS_ADDR: [u8; 4] = [224, 0, 0, 251];
+const MDNS_PORT: u16 = 5353;
+const SERVICE_NAME: &'static str = "_habitat._udp.local";
+const QUERY_INTERVAL_MS: u64 = 10_000;
+const RECORD_TTL: u32 = 120;
+
+const TYPE_PTR: u16 = 12;
+const TYPE_TXT: u16 = 16;
+const CLASS_IN: u16 = 1;
+const FLAGS_RESPONSE: u16 = 0x8400;
+
+/// What one Supervisor tells the others about itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Announcement {
+    pub member_id: String,
+    /// Advertised gossip address; empty when peers should use the address the answer came from.
+    pub address: String,
+    pub port: u16,
+    pub ring: String,
+}
+
+impl Announcement {
+    fn from_txt(strings: &[String]) -> Option<Announcement> {
+        let mut fields = HashMap::new();
+        for s in strings {
+            if let Some(pos) = s.find('=') {
+                fields.insert(&s[..pos], &s[pos + 1..]);
+            }
+        }
+        let member_id = match fields.get("id") {
+            Some(id) if !id.is_empty() => id.to_string(),
+            _ => return None,
+        };
+        let port = match fields.get("port").and_then(|p| p.parse().ok()) {
+            Some(port) => port,
+            None => return None,
+        };
+        Some(Announcement {
+            member_id: member_id,
+            address: fields.get("addr").map(|s| s.to_string()).unwrap_or_default(),
+            port: port,
+            ring: fields.get("ring").map(|s| s.to_string()).unwrap_or_default(),
+        })
+    }
+
+    fn txt(&self) -> Vec<String> {
+        vec![format!("id={}", self.member_id),
+             format!("addr={}", self.address),
+             format!("port={}", self.port),
+             format!("ring={}", self.ring)]
+    }
+
+    fn instance_name(&self) -> String {
+        format!("{}.{}", self.member_id, SERVICE_NAME)
+    }
+}
+
+pub struct Discovery {
+    butterfly: butterfly::Server,
+    socket: UdpSocket,
+    me: Announcement,
+}
+
+impl Discovery {
+    pub fn new(butterfly: butterfly::Server) -> Result<Discovery> {
+        let socket = try!(try!(try!(UdpBuilder::new_v4()).reuse_address(true))
+            .bind(("0.0.0.0", MDNS_PORT)));
+        try!(socket.join_multicast_v4(&Ipv4Addr::from(MDNS_ADDR), &Ipv4Addr::new(0, 0, 0, 0)));
+        let (address, port) = match gconfig().gossip_advertise() {
+            Some(addr) => (addr.ip().to_string(), addr.port()),
+            None => (String::new(), gconfig().gossip_listen().port()),
+        };
+        let me = Announcement {
+            member_id: butterfly.member_id().to_string(),
+            address: address,
+            port: port,
+            ring: gconfig().ring().map(ring_name).unwrap_or_default(),
+        };
+        Ok(Discovery {
+            butterfly: butterfly,
+            socket: socket,
+            me: me,
+        })
+    }
+
+    /// Spawn the responder and query threads.
+    pub fn start(self) -> Result<()> {
+        let querier = try!(self.socket.try_clone());
+        try!(thread::Builder::new()
+            .name(String::from("mdns-query"))
+            .spawn(move || loop {
+                if let Err(e) = querier.send_to(&encode_query(), mdns_addr()) {
+                    debug!("mDNS query failed: {}", e);
+                }
+                thread::sleep(Duration::from_millis(QUERY_INTERVAL_MS));
+            }));
+        try!(thread::Builder::new()
+            .name(String::from("mdns-respond"))
+            .spawn(move || self.run()));
+        Ok(())
+    }
+
+    fn run(self) {
+        let mut buf = [0u8; 9000];
+        loop {
+            let (len, from) = match self.socket.recv_from(&mut buf) {
+                Ok(r) => r,
+                Err(e) => {
+                    debug!("mDNS receive failed: {}", e);
+                    continue;
+                }
+            };
+            match decode(&buf[..len]) {
+                Some(Packet::Query) => {
+                    if let Err(e) = self.socket.send_to(&encode_response(&self.me), mdns_addr()) {
+                        debug!("mDNS response failed: {}", e);
+                    }
+                }
+                Some(Packet::Response(announcements)) => {
+                    for announcement in announcements {
+                        self.add_peer(announcement, from);
+                    }
+                }
+                None => {}
+            }
+        }
+    }
+
+    fn add_peer(&self, announcement: Announcement, from: SocketAddr) {
+        if announcement.member_id == self.me.member_id || announcement.ring != self.me.ring {
+            return;
+        }
+        if self.butterfly.member_list.contains_member(&announcement.member_id) {
+            return;
+        }
+        let address = if announcement.address.is_empty() {
+            from.ip()
+        } else {
+            match announcement.address.parse::<IpAddr>() {
+                Ok(ip) => ip,
+                Err(_) => return,
+            }
+        };
+        outputln!("Discovered peer {} at {}:{}",
+                  announcement.member_id,
+                  address,
+                  announcement.port);
+        let mut peer = Member::new();
+        peer.set_id(announcement.member_id);
+        peer.set_address(format!("{}", address));
+        peer.set_swim_port(announcement.port as i32);
+        peer.set_gossip_port(announcement.port as i32);
+        self.butterfly.insert_member(peer, Health::Alive);
+    }
+}
+
+fn mdns_addr() -> SocketAddr {
+    SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::from(MDNS_ADDR), MDNS_PORT))
+}
+
+/// Strip the revision from a `name-revision` ring key string.
+fn ring_name(ring: &str) -> String {
+    match ring.rfind('-') {
+        Some(pos) => ring[..pos].to_string(),
+        None => ring.to_string(),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Packet {
+    Query,
+    Response(Vec<Announcement>),
+}
+
+fn encode_query() -> Vec<u8> {
+    let mut buf = Vec::new();
+    push_u16(&mut buf, 0);
+    push_u16(&mut buf, 0);
+    push_u16(&mut buf, 1);
+    push_u16(&mut buf, 0);
+    push_u16(&mut buf, 0);
+    push_u16(&mut buf, 0);
+    push_name(&mut buf, SERVICE_NAME);
+    push_u16(&mut buf, TYPE_PTR);
+    push_u16(&mut buf, CLASS_IN);
+    buf
+}
+
+fn encode_response(me: &Announcement) -> Vec<u8> {
+    let instance = me.instance_name();
+    let mut buf = Vec::new();
+    push_u16(&mut buf, 0);
+    push_u16(&mut buf, FLAGS_RESPONSE);
+    push_u16(&mut buf, 0);
+    push_u16(&mut buf, 2);
+    push_u16(&mut buf, 0);
+    push_u16(&mut buf, 0);
+
+    let mut ptr = Vec::new();
+    push_name(&mut ptr, &instance);
+    push_record(&mut buf, SERVICE_NAME, TYPE_PTR, &ptr);
+
+    let mut txt = Vec::new();
+    for s in me.txt() {
+        txt.push(s.len() as u8);
+        txt.extend_from_slice(s.as_bytes());
+    }
+    push_record(&mut buf, &instance, TYPE_TXT, &txt);
+    buf
+}
+
+fn push_u16(buf: &mut Vec<u8>, v: u16) {
+    buf.push((v >> 8) as u8);
+    buf.push(v as u8);
+}
+
+fn push_name(buf: &mut Vec<u8>, name: &str) {
+    for label in name.split('.') {
+        buf.push(label.len() as u8);
+        buf.extend_from_slice(label.as_bytes());
+    }
+    buf.push(0);
+}
+
+fn push_record(buf: &mut Vec<u8>, name: &str, rtype: u16, data: &[u8]) {
+    push_name(buf, name);
+    push_u16(buf, rtype);
+    push_u16(buf, CLASS_IN);
+    push_u16(buf, (RECORD_TTL >> 16) as u16);
+    push_u16(buf, RECORD_TTL as u16);
+    push_u16(buf, data.len() as u16);
+    buf.extend_from_slice(data);
+}
+
+fn read_u16(buf: &[u8], pos: usize) -> Option<u16> {
+    if pos + 2 > buf.len() {
+        return None;
+    }
+    Some(((buf[pos] as u16) << 8) | buf[pos + 1] as u16)
+}
+
+/// Read a possibly compressed name starting at `pos`, returning it and the offset just past it.
+fn read_name(buf: &[u8], mut pos: usize) -> Option<(String, usize)> {
+    let mut labels = Vec::new();
+    let mut end = None;
+    let mut jumps = 0;
+    loop {
+        let len = *try_opt!(buf.get(pos)) as usize;
+        if len == 0 {
+            pos += 1;
+            break;
+        }
+        if len & 0xC0 == 0xC0 {
+            let target = (try_opt!(read_u16(buf, pos)) & 0x3FFF) as usize;
+            if end.is_none() {
+                end = Some(pos + 2);
+            }
+            jumps += 1;
+            if jumps > 16 {
+                return None;
+            }
+            pos = target;
+            continue;
+        }
+        let label = try_opt!(buf.get(pos + 1..pos + 1 + len));
+        labels.push(String::from_utf8_lossy(label).into_owned());
+        pos += 1 + len;
+    }
+    Some((labels.join("."), end.unwrap_or(pos)))
+}
+
+fn decode(buf: &[u8]) -> Option<Packet> {
+    let flags = try_opt!(read_u16(buf, 2));
+    let qdcount = try_opt!(read_u16(buf, 4));
+    let ancount = try_opt!(read_u16(buf, 6)) as usize + try_opt!(read_u16(buf, 8)) as usize +
+                  try_opt!(read_u16(buf, 10)) as usize;
+    let mut pos = 12;
+    if flags & 0x8000 == 0 {
+        for _ in 0..qdcount {
+            let (name, next) = try_opt!(read_name(buf, pos));
+            let qtype = try_opt!(read_u16(buf, next));
+            if name.to_lowercase() == SERVICE_NAME && qtype == TYPE_PTR {
+                return Some(Packet::Query);
+            }
+            pos = next + 4;
+        }
+        return None;
+    }
+    for _ in 0..qdcount {
+        let (_, next) = try_opt!(read_name(buf, pos));
+        pos = next + 4;
+    }
+    let mut announcements = Vec::new();
+    for _ in 0..ancount {
+        let (name, next) = try_opt!(read_name(buf, pos));
+        let rtype = try_opt!(read_u16(buf, next));
+        let rdlen = try_opt!(read_u16(buf, next + 8)) as usize;
+        let rdata = try_opt!(buf.get(next + 10..next + 10 + rdlen));
+        pos = next + 10 + rdlen;
+        if rtype != TYPE_TXT || !name.to_lowercase().ends_with(SERVICE_NAME) {
+            continue;
+        }
+        let mut strings = Vec::new();
+        let mut i = 0;
+        while i < rdata.len() {
+            let len = rdata[i] as usize;
+            let s = try_opt!(rdata.get(i + 1..i + 1 + len));
+            strings.push(String::from_utf8_lossy(s).into_owned());
+            i += 1 + len;
+        }
+        if let Some(announcement) = Announcement::from_txt(&strings) {
+            announcements.push(announcement);
+        }
+    }
+    if announcements.is_empty() {
+        None
+    } else {
+        Some(Packet::Response(announcements))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{decode, encode_query, encode_response, ring_name, Announcement, Packet};
+
+    fn announcement() -> Announcement {
+        Announcement {
+            member_id: String::from("38a2ac4f534848df86f0b293284740ce"),
+            address: String::from("203.0.113.10"),
+            port: 9638,
+            ring: String::from("acme"),
+        }
+    }
+
+    #[test]
+    fn decode_query() {
+        assert_eq!(decode(&encode_query()), Some(Packet::Query));
+    }
+
+    #[test]
+    fn decode_response() {
+        let me = announcement();
+        assert_eq!(decode(&encode_response(&me)),
+                   Some(Packet::Response(vec![me])));
+    }
+
+    #[test]
+    fn decode_garbage() {
+        assert_eq!(decode(&[0, 1, 2]), None);
+        assert_eq!(decode(&[0, 0, 0x84, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0xC0]), None);
+    }
+
+    #[test]
+    fn ring_name_strips_revision() {
+        assert_eq!(ring_name("acme-20160810182414"), "acme");
+    }
+}
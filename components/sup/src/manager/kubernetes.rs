@@ -0,0 +1,511 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bridging a ring with the Kubernetes cluster it runs in.
+//!
+//! Started with `--kubernetes`, the Supervisor talks to the API server of the cluster its pod
+//! runs in, as the pod's service account, about two custom resources in the pod's namespace. They
+//! are defined by `kubernetes/crds.yaml`.
+//!
+//! * A `HabitatServiceStatus` is written for each service group the Supervisor runs, named after
+//!   the group and a hash of it (`redis.default@acme` is `redis-default-acme-` and eight hex
+//!   digits), with a summary of its census. Only the alive member of the group with the lowest
+//!   member ID writes it, and only when it changes or has been deleted.
+//! * A `HabitatService` declares the desired state of a service group: `spec.serviceGroup`, and
+//!   optionally `spec.count`, the number of members it should have, and `spec.config`, TOML to
+//!   apply to it. These are gossiped to the ring as `hab scale set` and `hab config apply` would,
+//!   with `spec.incarnation`, or else the resource's generation, as their incarnation.
+//!
+//! Failed requests are reported and tried again on the next sync.
+
+use std::collections::HashMap;
+use std::env;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+use butterfly::rumor::service_config::ServiceConfig as ServiceConfigRumor;
+use butterfly::rumor::service_scale::ServiceScale as ServiceScaleRumor;
+use hcore::crypto::hash::Hasher;
+use hcore::service::ServiceGroup;
+use http_client::{self, ApiClient};
+use hyper::client::RequestBuilder;
+use hyper::header::{ContentType, Headers};
+use hyper::status::StatusCode;
+use serde_json;
+use toml;
+use url::Url;
+
+use {PRODUCT, VERSION};
+use error::{Error, Result};
+use manager::State;
+use manager::census::{Census, CensusEntry, Provisioning};
+
+static LOGKEY: &'static str = "K8";
+
+/// How often the census is written to, and desired state read from, the cluster.
+const SYNC_INTERVAL_MS: u64 = 10_000;
+
+/// Where Kubernetes mounts the credentials of a pod's service account.
+const SERVICE_ACCOUNT_DIR: &'static str = "/var/run/secrets/kubernetes.io/serviceaccount";
+
+/// The API group and version of the custom resources.
+const API_VERSION: &'static str = "habitat.sh/v1";
+
+/// The longest name Kubernetes gives an object.
+const MAX_NAME_LEN: usize = 253;
+
+/// How many hex digits of a service group's hash end its resources' names.
+const NAME_HASH_LEN: usize = 8;
+
+pub struct Kubernetes {
+    state: State,
+    client: ApiClient,
+    token: String,
+    namespace: String,
+    /// The status last written for each service group, by resource name. Entries are forgotten
+    /// when their resource is found to have been deleted, or a write of it conflicts.
+    written: HashMap<String, String>,
+}
+
+#[derive(Serialize)]
+struct StatusResource<'a> {
+    #[serde(rename = "apiVersion")]
+    api_version: &'static str,
+    kind: &'static str,
+    metadata: Metadata,
+    status: ServiceStatus<'a>,
+}
+
+#[derive(Serialize)]
+struct Metadata {
+    name: String,
+}
+
+#[derive(Serialize)]
+struct ServiceStatus<'a> {
+    #[serde(rename = "serviceGroup")]
+    service_group: String,
+    #[serde(rename = "desiredCount")]
+    desired_count: Option<u64>,
+    #[serde(rename = "activeColor")]
+    active_color: Option<&'a str>,
+    provisioning: Option<Provisioning>,
+    leader: Option<&'a str>,
+    alive: usize,
+    suspect: usize,
+    confirmed: usize,
+    members: Vec<MemberStatus<'a>>,
+}
+
+#[derive(Serialize)]
+struct MemberStatus<'a> {
+    #[serde(rename = "memberId")]
+    member_id: &'a str,
+    hostname: &'a str,
+    address: &'a str,
+    package: Option<String>,
+    /// One of alive, suspect or confirmed, as the ring sees the member.
+    health: &'static str,
+}
+
+#[derive(Deserialize)]
+struct ExistingList {
+    #[serde(default)]
+    items: Vec<ExistingResource>,
+}
+
+#[derive(Deserialize)]
+struct ExistingResource {
+    metadata: ExistingMetadata,
+}
+
+#[derive(Deserialize)]
+struct ExistingMetadata {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct DesiredList {
+    #[serde(default)]
+    items: Vec<DesiredResource>,
+}
+
+#[derive(Deserialize)]
+struct DesiredResource {
+    metadata: DesiredMetadata,
+    spec: DesiredSpec,
+}
+
+#[derive(Deserialize)]
+struct DesiredMetadata {
+    name: String,
+    #[serde(default)]
+    generation: u64,
+}
+
+#[derive(Deserialize)]
+struct DesiredSpec {
+    #[serde(rename = "serviceGroup")]
+    service_group: String,
+    #[serde(default)]
+    incarnation: Option<u64>,
+    #[serde(default)]
+    count: Option<u64>,
+    #[serde(default)]
+    config: Option<String>,
+}
+
+impl Kubernetes {
+    /// Connects to the API server of the cluster this pod runs in.
+    ///
+    /// # Failures
+    ///
+    /// * We aren't running in a Kubernetes pod
+    /// * The pod's service account credentials can't be read
+    pub fn new(state: State) -> Result<Kubernetes> {
+        let host = match (env::var("KUBERNETES_SERVICE_HOST"),
+                          env::var("KUBERNETES_SERVICE_PORT")) {
+            (Ok(host), Ok(port)) => {
+                if host.contains(':') {
+                    format!("[{}]:{}", host, port)
+                } else {
+                    format!("{}:{}", host, port)
+                }
+            }
+            _ => {
+                return Err(sup_error!(Error::KubernetesFailed(String::from("not running in a \
+                                                                            Kubernetes pod"))))
+            }
+        };
+        let url = match Url::parse(&format!("https://{}/", host)) {
+            Ok(url) => url,
+            Err(e) => return Err(sup_error!(Error::KubernetesFailed(format!("{}: {}", host, e)))),
+        };
+        let account = PathBuf::from(SERVICE_ACCOUNT_DIR);
+        let client =
+            try!(ApiClient::with_ca_file(&url, PRODUCT, VERSION, &account.join("ca.crt")));
+        Ok(Kubernetes {
+            state: state,
+            client: client,
+            token: try!(read_trimmed(&account.join("token"))),
+            namespace: try!(read_trimmed(&account.join("namespace"))),
+            written: HashMap::new(),
+        })
+    }
+
+    /// Spawns the thread which syncs with the cluster.
+    pub fn start(mut self) -> Result<()> {
+        outputln!("Syncing with Kubernetes namespace {}", self.namespace);
+        try!(thread::Builder::new()
+            .name(String::from("kubernetes"))
+            .spawn(move || loop {
+                self.sync();
+                thread::sleep(Duration::from_millis(SYNC_INTERVAL_MS));
+            }));
+        Ok(())
+    }
+
+    fn sync(&mut self) {
+        if let Err(e) = self.write_statuses() {
+            outputln!("Cannot write service status to Kubernetes: {}", e);
+        }
+        if let Err(e) = self.apply_desired_state() {
+            outputln!("Cannot apply desired state from Kubernetes: {}", e);
+        }
+    }
+
+    /// Writes the status of each service group we are responsible for which has changed, or
+    /// which is missing from the cluster.
+    fn write_statuses(&mut self) -> Result<()> {
+        let statuses = {
+            let census_list =
+                self.state.census_list.read().expect("Census list lock is poisoned!");
+            let services = self.state.services.read().expect("Services lock is poisoned!");
+            let mut statuses = Vec::new();
+            for service in services.iter() {
+                let census = match census_list.get(&service.service_group_str()) {
+                    Some(census) => census,
+                    None => continue,
+                };
+                if !writes_status(census) {
+                    continue;
+                }
+                let name = resource_name(&service.service_group_str());
+                let resource = StatusResource {
+                    api_version: API_VERSION,
+                    kind: "HabitatServiceStatus",
+                    metadata: Metadata { name: name.clone() },
+                    status: service_status(census),
+                };
+                let body = try!(serde_json::to_string(&resource)
+                    .map_err(|e| sup_error!(Error::KubernetesFailed(e.to_string()))));
+                statuses.push((name, body));
+            }
+            statuses
+        };
+        if statuses.is_empty() {
+            return Ok(());
+        }
+        // A status deleted out from under us is written again, even though it hasn't changed.
+        let existing = try!(self.existing_statuses());
+        self.written.retain(|name, _| existing.contains(name));
+        for (name, body) in statuses {
+            if self.written.get(&name) == Some(&body) {
+                continue;
+            }
+            debug!("Writing HabitatServiceStatus {}", name);
+            let path = self.resource_path("habitatservicestatuses", Some(&name));
+            let (status, response) = try!(self.send(self.client.put(&path).body(body.as_str())));
+            let (status, response) = if status == StatusCode::NotFound {
+                let path = self.resource_path("habitatservicestatuses", None);
+                try!(self.send(self.client.post(&path).body(body.as_str())))
+            } else {
+                (status, response)
+            };
+            if !status.is_success() {
+                if status == StatusCode::Conflict {
+                    self.written.remove(&name);
+                }
+                return Err(sup_error!(Error::KubernetesFailed(format!("writing {} returned \
+                                                                       {}: {}",
+                                                                      name,
+                                                                      status,
+                                                                      response.trim()))));
+            }
+            self.written.insert(name, body);
+        }
+        Ok(())
+    }
+
+    /// Returns the names of the `HabitatServiceStatus` resources in the namespace.
+    fn existing_statuses(&self) -> Result<Vec<String>> {
+        let path = self.resource_path("habitatservicestatuses", None);
+        let (status, body) = try!(self.send(self.client.get(&path)));
+        if !status.is_success() {
+            return Err(sup_error!(Error::KubernetesFailed(format!("listing \
+                                                                   HabitatServiceStatuses \
+                                                                   returned {}: {}",
+                                                                  status,
+                                                                  body.trim()))));
+        }
+        match serde_json::from_str::<ExistingList>(&body) {
+            Ok(list) => Ok(list.items.into_iter().map(|r| r.metadata.name).collect()),
+            Err(e) => {
+                Err(sup_error!(Error::KubernetesFailed(format!("unexpected \
+                                                                HabitatServiceStatuses: {}",
+                                                               e))))
+            }
+        }
+    }
+
+    /// Gossips the desired state of each service group we are responsible for. Rumors which are
+    /// no newer than what the ring already has are ignored by butterfly.
+    fn apply_desired_state(&self) -> Result<()> {
+        let path = self.resource_path("habitatservices", None);
+        let (status, body) = try!(self.send(self.client.get(&path)));
+        if !status.is_success() {
+            return Err(sup_error!(Error::KubernetesFailed(format!("listing HabitatServices \
+                                                                   returned {}: {}",
+                                                                  status,
+                                                                  body.trim()))));
+        }
+        let desired: DesiredList = match serde_json::from_str(&body) {
+            Ok(desired) => desired,
+            Err(e) => {
+                return Err(sup_error!(Error::KubernetesFailed(format!("unexpected \
+                                                                       HabitatServices: {}",
+                                                                      e))))
+            }
+        };
+        let member_id = self.state.butterfly.member_id().to_string();
+        let census_list = self.state.census_list.read().expect("Census list lock is poisoned!");
+        let services = self.state.services.read().expect("Services lock is poisoned!");
+        for resource in desired.items {
+            let spec = resource.spec;
+            let service_group = match ServiceGroup::from_str(&spec.service_group) {
+                Ok(sg) => sg,
+                Err(e) => {
+                    outputln!("Skipping HabitatService {}: {}", resource.metadata.name, e);
+                    continue;
+                }
+            };
+            if !services.iter().any(|s| s.service_group == service_group) {
+                continue;
+            }
+            match census_list.get(&spec.service_group) {
+                Some(census) if writes_status(census) => {}
+                _ => continue,
+            }
+            let incarnation = spec.incarnation.unwrap_or(resource.metadata.generation);
+            if let Some(count) = spec.count {
+                let mut rumor = ServiceScaleRumor::new(member_id.clone(),
+                                                       service_group.clone(),
+                                                       count);
+                rumor.set_incarnation(incarnation);
+                self.state.butterfly.insert_service_scale(rumor);
+            }
+            if let Some(config) = spec.config {
+                if toml::Parser::new(&config).parse().is_none() {
+                    outputln!("Skipping the config of HabitatService {}, it isn't valid TOML",
+                              resource.metadata.name);
+                    continue;
+                }
+                let mut rumor = ServiceConfigRumor::new(member_id.clone(),
+                                                        service_group.clone(),
+                                                        config.into_bytes());
+                rumor.set_incarnation(incarnation);
+                self.state.butterfly.insert_service_config(rumor);
+            }
+        }
+        Ok(())
+    }
+
+    fn resource_path(&self, plural: &str, name: Option<&str>) -> String {
+        let mut path = format!("apis/{}/namespaces/{}/{}", API_VERSION, self.namespace, plural);
+        if let Some(name) = name {
+            path.push('/');
+            path.push_str(name);
+        }
+        path
+    }
+
+    /// Sends a request as the pod's service account, returning the status and body of the
+    /// response.
+    fn send(&self, req: RequestBuilder) -> Result<(StatusCode, String)> {
+        let mut headers = Headers::new();
+        headers.set_raw("Authorization",
+                        vec![format!("Bearer {}", self.token).into_bytes()]);
+        headers.set(ContentType::json());
+        let mut res = match req.headers(headers).send() {
+            Ok(res) => res,
+            Err(e) => {
+                return Err(sup_error!(Error::HttpClient(http_client::Error::HyperError(e))))
+            }
+        };
+        let mut body = String::new();
+        try!(res.read_to_string(&mut body));
+        Ok((res.status, body))
+    }
+}
+
+/// Returns whether this Supervisor writes the status of, and applies the desired state to, a
+/// service group: whether it's the alive member with the lowest member ID.
+fn writes_status(census: &Census) -> bool {
+    match census.alive_members_ordered().first() {
+        Some(first) => first.member_id == census.member_id,
+        None => false,
+    }
+}
+
+fn service_status(census: &Census) -> ServiceStatus {
+    let members: Vec<&CensusEntry> = census.members_ordered();
+    ServiceStatus {
+        service_group: census.get_service_group(),
+        desired_count: census.desired_count,
+        active_color: census.active_color.as_ref().map(|c| c.as_str()),
+        provisioning: census.provisioning(),
+        leader: census.get_leader().map(|l| l.member_id.as_str()),
+        alive: members.iter().filter(|m| m.get_alive()).count(),
+        suspect: members.iter().filter(|m| m.get_suspect()).count(),
+        confirmed: members.iter().filter(|m| m.get_confirmed()).count(),
+        members: members.iter()
+            .map(|m| {
+                MemberStatus {
+                    member_id: &m.member_id,
+                    hostname: &m.hostname,
+                    address: &m.address,
+                    package: m.pkg.as_ref().map(|p| p.to_string()),
+                    health: if m.get_alive() {
+                        "alive"
+                    } else if m.get_suspect() {
+                        "suspect"
+                    } else {
+                        "confirmed"
+                    },
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Returns the name of a service group's resources. Kubernetes names are lowercase letters,
+/// digits and dashes which start and end with a letter or digit, so the group's name is mapped
+/// onto those, and a hash of it added to tell apart groups which map to the same name.
+fn resource_name(service_group: &str) -> String {
+    let readable: String = service_group.chars()
+        .map(|c| match c {
+            'a'...'z' | '0'...'9' => c,
+            'A'...'Z' => ((c as u8) + b'a' - b'A') as char,
+            _ => '-',
+        })
+        .collect();
+    let mut hasher = Hasher::new();
+    hasher.update(service_group.as_bytes());
+    let hash = hasher.finish();
+    let readable = readable.trim_matches('-');
+    let max_readable = MAX_NAME_LEN - NAME_HASH_LEN - 1;
+    let readable = if readable.len() > max_readable {
+        readable[..max_readable].trim_right_matches('-')
+    } else {
+        readable
+    };
+    if readable.is_empty() {
+        hash[..NAME_HASH_LEN].to_string()
+    } else {
+        format!("{}-{}", readable, &hash[..NAME_HASH_LEN])
+    }
+}
+
+fn read_trimmed(path: &Path) -> Result<String> {
+    let mut content = String::new();
+    try!(try!(File::open(path)).read_to_string(&mut content));
+    Ok(content.trim().to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::resource_name;
+
+    #[test]
+    fn resource_names() {
+        assert!(resource_name("redis.default").starts_with("redis-default-"));
+        assert!(resource_name("Redis.blue@acme").starts_with("redis-blue-acme-"));
+        assert_eq!(resource_name("redis.default").len(), "redis-default-".len() + 8);
+        assert_eq!(resource_name("redis.default"), resource_name("redis.default"));
+    }
+
+    #[test]
+    fn resource_names_tell_apart_groups_which_map_to_the_same_name() {
+        assert!(resource_name("redis.default") != resource_name("redis-default"));
+        assert!(resource_name("Redis.default") != resource_name("redis.default"));
+    }
+
+    #[test]
+    fn resource_names_are_valid_kubernetes_names() {
+        let long = format!("{}.default", "a".repeat(300));
+        for sg in &["-redis.default-", "_app_.prod", long.as_str()] {
+            let name = resource_name(sg);
+            assert!(name.len() <= 253);
+            assert!(!name.starts_with('-') && !name.ends_with('-'));
+            assert!(name.chars().all(|c| match c {
+                'a'...'z' | '0'...'9' | '-' => true,
+                _ => false,
+            }));
+        }
+    }
+}
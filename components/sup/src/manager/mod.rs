@@ -12,32 +12,59 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod aws_config;
+pub mod bastion;
 pub mod census;
+pub mod discovery;
+pub mod kubernetes;
+pub mod peer_provider;
+pub mod peer_watcher;
+pub mod quarantine;
+pub mod ring_exec;
+pub mod schedule;
+pub mod scheduler;
+pub mod secret_files;
 pub mod service;
 pub mod signals;
+pub mod service_restarter;
 pub mod service_updater;
 
-use std::net::{SocketAddr, ToSocketAddrs};
+use std::net::{IpAddr, SocketAddr, ToSocketAddrs};
 use std::thread;
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
 
 use butterfly;
-use butterfly::member::Member;
+use butterfly::member::{Health, Member};
 use butterfly::trace::Trace;
 use butterfly::rumor::service::Service as ServiceRumor;
 use butterfly::server::timing::Timing;
 use hcore::crypto::{default_cache_key_path, SymKey};
+use hcore::service::ServiceGroup;
 use time::{SteadyTime, Duration as TimeDuration};
 use toml;
 
-pub use manager::service::{Service, ServiceConfig, UpdateStrategy, Topology};
+pub use manager::service::{Service, ServiceConfig, ServiceKind, UpdateStrategy, Topology};
+use self::aws_config::{AwsConfig, DEFAULT_INTERVAL_SECS};
+use self::discovery::Discovery;
+use self::kubernetes::Kubernetes;
+use self::peer_watcher::PeerWatcher;
+use self::ring_exec::RingExec;
+use self::secret_files::SecretFiles;
+use self::service_restarter::ServiceRestarter;
 use self::service_updater::ServiceUpdater;
 use error::{Error, Result};
 use config::gconfig;
-use manager::census::{CensusUpdate, CensusList, CensusEntry};
+use envoy::{EndpointSource, Snapshot};
+use secrets;
+use secrets::vault::Vault;
+use manager::census::{CensusUpdate, CensusList};
 use manager::signals::SignalEvent;
+#[cfg(feature = "grpc-gateway")]
+use grpc_gateway;
 use http_gateway;
+use logger;
+use util;
 
 static LOGKEY: &'static str = "MR";
 
@@ -46,6 +73,8 @@ pub struct State {
     pub butterfly: butterfly::Server,
     pub census_list: Arc<RwLock<CensusList>>,
     pub services: Arc<RwLock<Vec<Service>>>,
+    /// When the main loop last ticked.
+    pub heartbeat: Arc<RwLock<SteadyTime>>,
 }
 
 impl State {
@@ -54,6 +83,7 @@ impl State {
             butterfly: butterfly,
             census_list: Arc::new(RwLock::new(CensusList::new())),
             services: Arc::new(RwLock::new(Vec::new())),
+            heartbeat: Arc::new(RwLock::new(SteadyTime::now())),
         }
     }
 }
@@ -61,14 +91,44 @@ impl State {
 pub struct Manager {
     state: State,
     updater: ServiceUpdater,
+    restarter: ServiceRestarter,
+    ring_exec: RingExec,
+    /// True until `--bootstrap-expect` members of the ring are alive.
+    bootstrapping: bool,
+    /// Service groups whose elections are waiting for the ring to bootstrap.
+    pending_elections: Vec<ServiceGroup>,
+    /// The version of the Envoy snapshot last written to `--envoy-xds-dir`.
+    envoy_version: Option<String>,
+    /// The generation of the secrets services were last rendered with.
+    secrets_generation: usize,
+    /// The generation of the configuration from AWS services were last rendered with.
+    aws_config_generation: usize,
 }
 
 impl Manager {
     pub fn new() -> Result<Manager> {
         let mut member = Member::new();
         member.set_persistent(gconfig().gossip_permanent());
-        member.set_swim_port(gconfig().gossip_listen().port() as i32);
-        member.set_gossip_port(gconfig().gossip_listen().port() as i32);
+        match gconfig().gossip_alias() {
+            Some(alias) => member.set_alias(alias.to_string()),
+            None => {
+                match util::sys::hostname() {
+                    Ok(hostname) => member.set_alias(hostname),
+                    Err(e) => debug!("Gossiping without an alias; cannot get the hostname: {}", e),
+                }
+            }
+        }
+        match gconfig().gossip_advertise() {
+            Some(addr) => {
+                member.set_address(format!("{}", addr.ip()));
+                member.set_swim_port(addr.port() as i32);
+                member.set_gossip_port(addr.port() as i32);
+            }
+            None => {
+                member.set_swim_port(gconfig().gossip_listen().port() as i32);
+                member.set_gossip_port(gconfig().gossip_listen().port() as i32);
+            }
+        }
 
         let ring_key = match gconfig().ring() {
             Some(ring_with_revision) => {
@@ -84,7 +144,22 @@ impl Manager {
                                                  Trace::default(),
                                                  ring_key,
                                                  None));
+        if gconfig().bastion() {
+            server.set_fanout(bastion::BASTION_FANOUT);
+            server.set_push_workers(bastion::BASTION_FANOUT);
+        }
+        if let Some(threads) = gconfig().gossip_threads() {
+            server.set_push_workers(threads);
+        }
+        server.set_compression(gconfig().gossip_compression());
+        if let Some(secs) = gconfig().gossip_departed_ttl() {
+            server.set_departed_member_ttl(secs);
+        }
         outputln!("Butterfly Member ID {}", server.member_id());
+        let alias = server.member.read().expect("Member is poisoned").get_alias().to_string();
+        if !alias.is_empty() {
+            outputln!("Butterfly Member alias {}", alias);
+        }
         for peer_addr in gconfig().gossip_peer() {
             let addrs: Vec<SocketAddr> = match peer_addr.to_socket_addrs() {
                 Ok(addrs) => addrs.collect(),
@@ -100,33 +175,81 @@ impl Manager {
             peer.set_gossip_port(addr.port() as i32);
             server.member_list.add_initial_member(peer);
         }
+        if !gconfig().peer_provider().is_empty() {
+            // Providers list every matching instance, which usually includes this one
+            let mut me: Vec<IpAddr> = util::sys::ip().into_iter().collect();
+            me.extend(gconfig().gossip_advertise().map(|addr| addr.ip()));
+            let port = gconfig().gossip_listen().port() as i32;
+            for ip in peer_provider::discover(gconfig().peer_provider()) {
+                if me.contains(&ip) {
+                    continue;
+                }
+                let mut peer = Member::new();
+                peer.set_address(format!("{}", ip));
+                peer.set_swim_port(port);
+                peer.set_gossip_port(port);
+                server.member_list.add_initial_member(peer);
+            }
+        }
         Ok(Manager {
             updater: ServiceUpdater::new(server.clone()),
+            restarter: ServiceRestarter::new(),
+            ring_exec: RingExec::new(),
             state: State::new(server),
+            bootstrapping: gconfig().bootstrap_expect().is_some(),
+            pending_elections: Vec::new(),
+            envoy_version: None,
+            secrets_generation: 0,
+            aws_config_generation: 0,
         })
     }
 
     pub fn add_service(&mut self, service: Service) -> Result<()> {
-        try!(service.package.create_svc_path());
-        let census = self.state.census_list.read().expect("Census list lock is poisoned!");
-        let svc_cfg = service.load_service_config(&census)?;
-        let cfg = svc_cfg.to_exported()?;
-        let service_rumor = ServiceRumor::new(self.state.butterfly.member_id().to_string(),
-                                              service.package.ident(),
-                                              &service.service_group,
-                                              &*svc_cfg.sys,
-                                              Some(&cfg))?;
-        self.state.butterfly.insert_service(service_rumor);
-
-        if service.topology == Topology::Leader || service.topology == Topology::Initializer {
-            // Note - eventually, we need to deal with suitability here. The original implementation
-            // didn't have this working either.
-            self.state.butterfly.start_election(service.service_group.clone(), 0, 0);
-        }
-
-        self.updater.add(&service);
-        service.package.register_metrics();
-        self.state.services.write().expect("Services lock is poisoned!").push(service);
+        self.add_services(vec![service])
+    }
+
+    /// Adds the services of a composite as one unit. Everything that can fail is done for every
+    /// service before any of them is gossiped or handed to the updater, so either all of them run
+    /// or none do.
+    pub fn add_services(&mut self, services: Vec<Service>) -> Result<()> {
+        let mut rumors = Vec::with_capacity(services.len());
+        {
+            let census = self.state.census_list.read().expect("Census list lock is poisoned!");
+            for service in services.iter() {
+                try!(service.package.create_svc_path());
+                let svc_cfg = service.load_service_config(&census)?;
+                let cfg = svc_cfg.to_exported()?;
+                let mut service_rumor =
+                    ServiceRumor::new(self.state.butterfly.member_id().to_string(),
+                                      service.package.ident(),
+                                      &service.service_group,
+                                      &*svc_cfg.sys,
+                                      Some(&cfg))?;
+                if let Some(secs) = gconfig().departed_ttl() {
+                    service_rumor.set_departed_ttl(secs);
+                }
+                rumors.push(service_rumor);
+            }
+        }
+
+        for (service, service_rumor) in services.into_iter().zip(rumors) {
+            self.state.butterfly.insert_service(service_rumor);
+
+            if service.topology == Topology::Leader || service.topology == Topology::Initializer {
+                // Note - eventually, we need to deal with suitability here. The original
+                // implementation didn't have this working either.
+                if self.bootstrapping {
+                    self.pending_elections.push(service.service_group.clone());
+                } else {
+                    self.state.butterfly.start_election(service.service_group.clone(), 0, 0);
+                }
+            }
+
+            self.updater.add(&service);
+            self.restarter.add(&service);
+            service.package.register_metrics();
+            self.state.services.write().expect("Services lock is poisoned!").push(service);
+        }
         Ok(())
     }
 
@@ -135,40 +258,127 @@ impl Manager {
 
         outputln!("Starting butterfly on {}",
                   gconfig().gossip_listen().to_string());
-        try!(self.state.butterfly.start(Timing::default()));
+        if let Some(addr) = gconfig().gossip_advertise() {
+            outputln!("Advertising butterfly on {}", addr);
+        }
+        let timing = if gconfig().bastion() {
+            outputln!("Running as a bastion; no service will be started");
+            bastion::timing()
+        } else {
+            Timing::default()
+        };
+        try!(self.state.butterfly.start(timing));
         debug!("butterfly server started");
-        outputln!("Starting http-gateway on {}", gconfig().http_listen_addr());
-        try!(http_gateway::Server::new(self.state.clone()).start());
+        if gconfig().gossip_discover() {
+            outputln!("Discovering peers with mDNS");
+            try!(try!(Discovery::new(self.state.butterfly.clone())).start());
+        }
+        if let Some(path) = gconfig().peer_watch_file() {
+            outputln!("Watching {} for peers", path.display());
+            try!(PeerWatcher::new(self.state.butterfly.clone(), path).start());
+        }
+        if let Some(expect) = gconfig().bootstrap_expect() {
+            outputln!("Waiting for {} members before starting elections", expect);
+        }
+        let mut secret_files = SecretFiles::new();
+        if let Some(path) = gconfig().ring_key_file() {
+            let butterfly = self.state.butterfly.clone();
+            try!(secret_files.watch("ring key", &[path], move |contents| {
+                let content = String::from_utf8_lossy(&contents[0]);
+                let cache = default_cache_key_path(None);
+                let (key, _) = try!(SymKey::write_file_from_str(&content, &cache));
+                let key = try!(SymKey::get_pair_for(&key.name_with_rev(), &cache));
+                butterfly.set_ring_key(Some(key));
+                Ok(())
+            }));
+        }
+        if let Some(path) = gconfig().gateway_auth_file() {
+            try!(secret_files.watch("gateway auth token",
+                                    &[path],
+                                    |contents| http_gateway::set_auth_token(&contents[0])));
+        }
+        let tls = match gconfig().http_tls_files() {
+            Some((cert, key)) => {
+                let tls = http_gateway::Tls::new();
+                let loaded = tls.clone();
+                try!(secret_files.watch("HTTP gateway certificate",
+                                        &[cert, key],
+                                        move |contents| loaded.load(&contents[0], &contents[1])));
+                Some(tls)
+            }
+            None => None,
+        };
+        try!(secret_files.start());
+        outputln!("Starting http-gateway on {}{}",
+                  gconfig().http_listen_addr(),
+                  if tls.is_some() { " with TLS" } else { "" });
+        try!(http_gateway::Server::new(self.state.clone()).start(tls));
         debug!("http-gateway server started");
+        try!(self.start_grpc_gateway());
+        if gconfig().kubernetes() {
+            try!(try!(Kubernetes::new(self.state.clone())).start());
+        }
+        if let Some(addr) = gconfig().vault_addr() {
+            outputln!("Reading secrets from Vault at {}", addr);
+            try!(secrets::init(Box::new(try!(Vault::new(addr)))));
+        }
+        if let Some(path) = gconfig().aws_config_path() {
+            let interval = gconfig().aws_config_interval().unwrap_or(DEFAULT_INTERVAL_SECS);
+            try!(try!(AwsConfig::new(self.state.clone(), path, Duration::from_secs(interval)))
+                .start());
+        }
 
         let mut last_census_update = CensusUpdate::default();
 
         loop {
             let next_check = SteadyTime::now() + TimeDuration::milliseconds(1000);
+            *self.state.heartbeat.write().expect("Heartbeat lock is poisoned!") = SteadyTime::now();
             if self.check_for_incoming_signals() {
                 outputln!("Habitat thanks you - shutting down!");
                 return Ok(());
             }
             self.check_for_updated_packages(&mut last_census_update);
+            self.check_for_restarts(&mut last_census_update);
+            self.check_bootstrap();
             self.restart_elections();
+            self.ring_exec.check(&self.state);
             let (census_updated, ncu) = self.build_census(&last_census_update);
             if census_updated {
                 last_census_update = ncu;
+                self.write_envoy_snapshot();
             }
+            let secrets_changed = self.check_secrets();
+            let aws_config_changed = self.check_aws_config();
             for mut service in self.state
                 .services
                 .write()
                 .expect("Services lock is poisoned!")
                 .iter_mut() {
+                let _scope = logger::service_scope(&service.service_group.to_string());
 
                 self.persist_service_files(&mut service);
-                let svc_cfg_updated = self.persist_service_config(&mut service);
+                let svc_cfg_updated = self.persist_service_config(&mut service) ||
+                                      aws_config_changed.contains(&service.service_group_str());
 
-                if svc_cfg_updated || census_updated {
+                // A census change alone only re-renders the service once the census settles, and
+                // only if it touched data the service's templates reference.
+                if census_updated {
+                    service.census_changed();
+                }
+                service.check_provisioning(&self.state
+                    .census_list
+                    .read()
+                    .expect("Census list lock is poisoned!"));
+                if secrets_changed {
+                    service.needs_reconfigure = true;
+                }
+                let force = svc_cfg_updated || service.needs_reconfigure;
+                if force || service.census_settled() {
                     let svc_cfg = service.reconfigure(&self.state
-                        .census_list
-                        .read()
-                        .expect("Census list lock is poisoned!"));
+                                                          .census_list
+                                                          .read()
+                                                          .expect("Census list lock is poisoned!"),
+                                                      force);
                     if svc_cfg_updated && svc_cfg.is_some() {
                         self.update_service_rumor_cfg(&service,
                                                       svc_cfg.as_ref().unwrap(),
@@ -177,9 +387,14 @@ impl Manager {
                 }
 
                 service.initialize();
-                service.check_process();
+                if service.check_process() && service.kind == ServiceKind::Oneshot {
+                    self.update_service_rumor_exit_code(&service, &mut last_census_update);
+                }
+                service.check_schedule();
+                service.check_dirs();
+                service.run_scheduled_hooks();
 
-                if service.initialized && (service.needs_restart || service.is_down()) {
+                if service.initialized && service.should_restart() {
                     match service.restart(&self.state
                         .census_list
                         .read()
@@ -200,38 +415,29 @@ impl Manager {
     // Try and build the census from the gossip data, updating the last_census_update with
     // the resulting checkpoints. The census is our representation of the data produced
     // by Butterfly.
+    #[cfg(feature = "grpc-gateway")]
+    fn start_grpc_gateway(&self) -> Result<()> {
+        if let Some(addr) = gconfig().grpc_listen_addr() {
+            outputln!("Starting grpc-gateway on {}", addr);
+            try!(grpc_gateway::Server::new(self.state.clone(), *addr).start());
+            debug!("grpc-gateway server started");
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "grpc-gateway"))]
+    fn start_grpc_gateway(&self) -> Result<()> {
+        Ok(())
+    }
+
     fn build_census(&mut self, last_update: &CensusUpdate) -> (bool, CensusUpdate) {
         let update = CensusUpdate::new(&self.state.butterfly);
         if update != *last_update {
             // JW TODO: We should re-use the already allocated census list and entries instead of
             // recreating entirely new structures. We can, and should, only modify structures which
             // have had their incarnation updated.
-            let mut cl = CensusList::new();
             debug!("Updating census from butterfly data");
-            self.state
-                .butterfly
-                .service_store
-                .with_keys(|(_group, rumors)| for (_member_id, service) in rumors.iter() {
-                    let mut ce = CensusEntry::default();
-                    ce.populate_from_service(service);
-                    cl.insert(String::from(self.state.butterfly.member_id()), ce);
-                });
-            self.state.butterfly.election_store.with_keys(|(_service_group, rumors)| {
-                // We know you have an election, and this is the only key in the hash
-                let election = rumors.get("election").unwrap();
-                cl.populate_from_election(election);
-            });
-            self.state.butterfly.update_store.with_keys(|(_service_group, rumors)| {
-                // We know you have an election, and this is the only key in the hash
-                let election = rumors.get("election").unwrap();
-                cl.populate_from_update_election(election);
-            });
-            self.state.butterfly.member_list.with_members(|member| {
-                cl.populate_from_member(member);
-                if let Some(health) = self.state.butterfly.member_list.health_of(member) {
-                    cl.populate_from_health(member, health);
-                }
-            });
+            let cl = CensusList::from_butterfly(&self.state.butterfly);
             *self.state.census_list.write().expect("Census list lock is poisoned!") = cl;
             return (true, update);
         }
@@ -291,7 +497,8 @@ impl Manager {
         };
         let census_list = self.state.census_list.read().expect("Census list lock is poisoned!");
         for service in self.state.services.write().expect("Services lock is poisoned!").iter_mut() {
-            if self.updater.check_for_updated_package(service, &census_list) {
+            let updated = self.updater.check_for_updated_package(service, &census_list);
+            if self.updater.check_quarantine(service) || updated {
                 let mut rumor = {
                     let list = self.state
                         .butterfly
@@ -307,6 +514,7 @@ impl Manager {
                 let incarnation = rumor.get_incarnation() + 1;
                 rumor.set_pkg(service.package.to_string());
                 rumor.set_incarnation(incarnation);
+                *rumor.mut_quarantined() = service.quarantined.clone().into();
                 match service.load_service_config(&census_list) {
                     Ok(raw_cfg) => {
                         match raw_cfg.to_exported() {
@@ -324,6 +532,114 @@ impl Manager {
         }
     }
 
+    /// Walk each service and restart it if its service group was asked to, and it is our turn.
+    /// When a restart finishes, our service rumor is updated with its incarnation, so the members
+    /// after us in a rolling restart know they can go ahead.
+    ///
+    /// The run loop's last updated census is a required parameter on this function to inform the
+    /// main loop that we, ourselves, updated the service counter when we updated ourselves.
+    fn check_for_restarts(&mut self, last_update: &mut CensusUpdate) {
+        let member_id = {
+            self.state.butterfly.member_id().to_string()
+        };
+        let census_list = self.state.census_list.read().expect("Census list lock is poisoned!");
+        for service in self.state.services.write().expect("Services lock is poisoned!").iter_mut() {
+            let incarnation = match self.restarter
+                .check(service, &self.state.butterfly, &census_list) {
+                Some(incarnation) => incarnation,
+                None => continue,
+            };
+            let mut updated = None;
+            self.state
+                .butterfly
+                .service_store
+                .with_rumor(&*service.service_group,
+                            &member_id,
+                            |rumor| if let Some(rumor) = rumor {
+                                let mut rumor = rumor.clone();
+                                let rumor_incarnation = rumor.get_incarnation() + 1;
+                                rumor.set_incarnation(rumor_incarnation);
+                                rumor.set_restart_incarnation(incarnation);
+                                updated = Some(rumor);
+                            });
+            if let Some(rumor) = updated {
+                self.state.butterfly.insert_service(rumor);
+                last_update.service_counter += 1;
+            }
+        }
+    }
+
+    /// Returns whether a secret has changed since services were last rendered, in which case
+    /// they are rendered again even though their template data hasn't changed.
+    fn check_secrets(&mut self) -> bool {
+        let generation = match secrets::secrets() {
+            Some(secrets) => secrets.generation(),
+            None => return false,
+        };
+        if generation == self.secrets_generation {
+            return false;
+        }
+        self.secrets_generation = generation;
+        true
+    }
+
+    /// Returns the packages whose configuration from AWS has changed since services were last
+    /// rendered.
+    fn check_aws_config(&mut self) -> Vec<String> {
+        let (generation, changed) = aws_config::changed_since(self.aws_config_generation);
+        self.aws_config_generation = generation;
+        changed
+    }
+
+    /// Write the census for Envoy to `--envoy-xds-dir`, if it's set and the clusters or endpoints
+    /// have changed since they were last written.
+    fn write_envoy_snapshot(&mut self) {
+        let dir = match gconfig().envoy_xds_dir() {
+            Some(dir) => dir.to_path_buf(),
+            None => return,
+        };
+        let snapshot = Snapshot::from_census(&self.state
+                                                 .census_list
+                                                 .read()
+                                                 .expect("Census list lock is poisoned!"),
+                                             &EndpointSource::Dir(dir.clone()));
+        if self.envoy_version.as_ref().map(|v| v.as_str()) == Some(snapshot.version()) {
+            return;
+        }
+        match snapshot.write_dir(&dir) {
+            Ok(()) => self.envoy_version = Some(snapshot.version().to_string()),
+            Err(e) => outputln!("Cannot write Envoy discovery files to {}: {}", dir.display(), e),
+        }
+    }
+
+    /// Once enough members of the ring are alive, start the elections which were waiting for them,
+    /// so that supervisors started together don't each elect a leader before they find each other.
+    fn check_bootstrap(&mut self) {
+        let expect = match gconfig().bootstrap_expect() {
+            Some(expect) if self.bootstrapping => expect,
+            _ => return,
+        };
+        let butterfly = &self.state.butterfly;
+        // Count ourselves, whether or not we are in our own member list
+        let mut alive = 1;
+        butterfly.member_list.with_members(|member| {
+            if member.get_id() != butterfly.member_id() &&
+               butterfly.member_list.check_health_of(member, Health::Alive) {
+                alive += 1;
+            }
+        });
+        if alive < expect {
+            return;
+        }
+        outputln!("{} of {} expected members are alive; starting elections",
+                  alive,
+                  expect);
+        self.bootstrapping = false;
+        for service_group in self.pending_elections.drain(..) {
+            self.state.butterfly.start_election(service_group, 0, 0);
+        }
+    }
+
     /// Check if any elections need restarting.
     fn restart_elections(&mut self) {
         self.state.butterfly.restart_elections();
@@ -333,12 +649,18 @@ impl Manager {
     ///
     /// Returns true if a change was made and false if there were no updates.
     fn persist_service_config(&self, service: &mut Service) -> bool {
-        if let Some((incarnation, config)) =
+        if let Some((incarnation, config, patch)) =
             self.state
                 .butterfly
-                .service_config_for(&service.service_group_str(), Some(service.cfg_incarnation)) {
+                .service_config_for(&service.service_group_str(),
+                                    Some(service.cfg_incarnation),
+                                    gconfig().labels()) {
             service.cfg_incarnation = incarnation;
-            service.write_butterfly_service_config(config)
+            if patch {
+                service.write_butterfly_service_config_patch(config)
+            } else {
+                service.write_butterfly_service_config(config)
+            }
         } else {
             false
         }
@@ -397,4 +719,36 @@ impl Manager {
             }
         }
     }
+
+    /// Update our own service rumor with the exit code of the service's last run, so that the
+    /// outcome of oneshot services is visible in the census.
+    ///
+    /// The run loop's last updated census is a required parameter on this function to inform the
+    /// main loop that we, ourselves, updated the service counter when we updated ourselves.
+    fn update_service_rumor_exit_code(&self, service: &Service, last_update: &mut CensusUpdate) {
+        let code = match service.supervisor.last_exit_code {
+            Some(code) => code,
+            None => return,
+        };
+        let me = {
+            self.state.butterfly.member_id().to_string()
+        };
+        let mut updated = None;
+        self.state
+            .butterfly
+            .service_store
+            .with_rumor(&*service.service_group,
+                        &me,
+                        |rumor| if let Some(rumor) = rumor {
+                            let mut rumor = rumor.clone();
+                            let incarnation = rumor.get_incarnation() + 1;
+                            rumor.set_incarnation(incarnation);
+                            rumor.set_last_exit_code(code);
+                            updated = Some(rumor);
+                        });
+        if let Some(rumor) = updated {
+            self.state.butterfly.insert_service(rumor);
+            last_update.service_counter += 1;
+        }
+    }
 }
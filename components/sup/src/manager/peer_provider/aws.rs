@@ -0,0 +1,169 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Find peers among the running EC2 instances carrying a tag.
+//!
+//! Keys:
+//!
+//! * `tag_key`, `tag_value` - the tag to match (required)
+//! * `region` - defaults to `AWS_REGION`, and then to the region of the instance we're running on
+//! * `addr_type` - `private_v4` (default) or `public_v4`
+//! * `access_key_id`, `secret_access_key` - default to `AWS_ACCESS_KEY_ID` and
+//!   `AWS_SECRET_ACCESS_KEY`, and then to the ECS task's or the instance's IAM role
+
+use std::net::IpAddr;
+
+use error::{Error, Result, SupError};
+use super::{http_get, uri_encode, PeerProvider, ProviderConfig};
+use util::aws::{self, Credentials, Signer};
+
+static LOGKEY: &'static str = "PA";
+const NAME: &'static str = "aws";
+const EC2_API_VERSION: &'static str = "2016-11-15";
+
+pub struct Aws {
+    region: String,
+    tag_key: String,
+    tag_value: String,
+    public: bool,
+    credentials: Credentials,
+}
+
+impl Aws {
+    pub fn new(config: &ProviderConfig) -> Result<Aws> {
+        let public = match config.get("addr_type").unwrap_or("private_v4") {
+            "private_v4" => false,
+            "public_v4" => true,
+            t => return Err(failed(format!("unknown addr_type={}", t))),
+        };
+        let region = match config.get("region") {
+            Some(region) => region.to_string(),
+            None => try!(aws::region()),
+        };
+        Ok(Aws {
+            region: region,
+            tag_key: try!(config.require("tag_key")).to_string(),
+            tag_value: try!(config.require("tag_value")).to_string(),
+            public: public,
+            credentials: try!(Credentials::load(config.get("access_key_id"),
+                                                config.get("secret_access_key"))),
+        })
+    }
+
+    /// Sign and send one DescribeInstances call, returning the response body.
+    fn describe_instances(&self, next_token: Option<&str>) -> Result<String> {
+        let host = format!("ec2.{}.amazonaws.com", self.region);
+        let mut params = vec![("Action", "DescribeInstances".to_string()),
+                              ("Filter.1.Name", format!("tag:{}", self.tag_key)),
+                              ("Filter.1.Value.1", self.tag_value.clone()),
+                              ("Filter.2.Name", "instance-state-name".to_string()),
+                              ("Filter.2.Value.1", "running".to_string()),
+                              ("Version", EC2_API_VERSION.to_string())];
+        if let Some(token) = next_token {
+            params.push(("NextToken", token.to_string()));
+        }
+        params.sort();
+        let query = params.iter()
+            .map(|&(k, ref v)| format!("{}={}", uri_encode(k), uri_encode(v)))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        let signer = Signer {
+            credentials: &self.credentials,
+            region: &self.region,
+            service: "ec2",
+        };
+        let headers = try!(signer.headers(&host));
+        let authorization = try!(signer.authorization("GET", "/", &query, &headers, b""));
+
+        // Hyper fills in the Host header itself
+        let mut send: Vec<(&'static str, String)> =
+            headers.into_iter().filter(|&(name, _)| name != "host").collect();
+        send.push(("Authorization", authorization));
+        http_get(NAME, &format!("https://{}/?{}", host, query), send)
+    }
+}
+
+impl PeerProvider for Aws {
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    fn peers(&self) -> Result<Vec<IpAddr>> {
+        let element = if self.public {
+            "ipAddress"
+        } else {
+            "privateIpAddress"
+        };
+        let mut peers = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let body = try!(self.describe_instances(next_token.as_ref().map(|t| t.as_str())));
+            for value in elements(&body, element) {
+                if let Ok(ip) = value.parse::<IpAddr>() {
+                    if !peers.contains(&ip) {
+                        peers.push(ip);
+                    }
+                }
+            }
+            next_token = elements(&body, "nextToken").into_iter().next();
+            if next_token.is_none() {
+                break;
+            }
+        }
+        debug!("EC2 instances tagged {}={}: {:?}",
+               self.tag_key,
+               self.tag_value,
+               peers);
+        Ok(peers)
+    }
+}
+
+/// Return the text of every `<name>` element in an XML document. The EC2 query API only nests
+/// elements, so there's no need for a real parser.
+fn elements(xml: &str, name: &str) -> Vec<String> {
+    let open = format!("<{}>", name);
+    let close = format!("</{}>", name);
+    let mut values = Vec::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find(&open) {
+        rest = &rest[start + open.len()..];
+        match rest.find(&close) {
+            Some(end) => {
+                values.push(rest[..end].trim().to_string());
+                rest = &rest[end + close.len()..];
+            }
+            None => break,
+        }
+    }
+    values
+}
+
+fn failed(message: String) -> SupError {
+    sup_error!(Error::PeerProviderFailed(NAME.to_string(), message))
+}
+
+#[cfg(test)]
+mod test {
+    use super::elements;
+
+    #[test]
+    fn elements_finds_every_match() {
+        let xml = "<item><privateIpAddress>10.0.0.1</privateIpAddress><ipAddress>54.1.2.3\
+                   </ipAddress><privateIpAddress>10.0.0.2</privateIpAddress></item>";
+        assert_eq!(elements(xml, "privateIpAddress"), vec!["10.0.0.1", "10.0.0.2"]);
+        assert_eq!(elements(xml, "ipAddress"), vec!["54.1.2.3"]);
+        assert!(elements(xml, "nextToken").is_empty());
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Find peers among the Azure network interfaces carrying a tag.
+//!
+//! Keys:
+//!
+//! * `tag_name`, `tag_value` - the tag to match (required)
+//! * `subscription_id` - defaults to the subscription of the VM we're running on
+//!
+//! Requests are authorized with the VM's managed identity, which needs read access to the
+//! subscription's network interfaces.
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use error::Result;
+use super::{http_get, from_json, PeerProvider, ProviderConfig};
+
+const NAME: &'static str = "azure";
+const METADATA_URL: &'static str = "http://169.254.169.254/metadata";
+const MANAGEMENT_URL: &'static str = "https://management.azure.com";
+const NETWORK_API_VERSION: &'static str = "2017-10-01";
+
+pub struct Azure {
+    subscription_id: String,
+    tag_name: String,
+    tag_value: String,
+}
+
+#[derive(Deserialize)]
+struct Token {
+    access_token: String,
+}
+
+#[derive(Deserialize)]
+struct NetworkInterfaces {
+    #[serde(default)]
+    value: Vec<NetworkInterface>,
+    #[serde(rename = "nextLink", default)]
+    next_link: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct NetworkInterface {
+    #[serde(default)]
+    tags: HashMap<String, String>,
+    properties: NetworkInterfaceProperties,
+}
+
+#[derive(Deserialize)]
+struct NetworkInterfaceProperties {
+    #[serde(rename = "ipConfigurations", default)]
+    ip_configurations: Vec<IpConfiguration>,
+}
+
+#[derive(Deserialize)]
+struct IpConfiguration {
+    properties: IpConfigurationProperties,
+}
+
+#[derive(Deserialize)]
+struct IpConfigurationProperties {
+    #[serde(rename = "privateIPAddress", default)]
+    private_ip_address: Option<String>,
+}
+
+impl Azure {
+    pub fn new(config: &ProviderConfig) -> Result<Azure> {
+        let subscription_id = match config.get("subscription_id") {
+            Some(id) => id.to_string(),
+            None => {
+                try!(metadata("instance/compute/subscriptionId?api-version=2017-08-01&\
+                               format=text"))
+            }
+        };
+        Ok(Azure {
+            subscription_id: subscription_id,
+            tag_name: try!(config.require("tag_name")).to_string(),
+            tag_value: try!(config.require("tag_value")).to_string(),
+        })
+    }
+}
+
+impl PeerProvider for Azure {
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    fn peers(&self) -> Result<Vec<IpAddr>> {
+        let token: Token =
+            try!(from_json(NAME,
+                           &try!(metadata("identity/oauth2/token?api-version=2018-02-01&\
+                                           resource=https%3A%2F%2Fmanagement.azure.com%2F"))));
+        let auth = vec![("Authorization", format!("Bearer {}", token.access_token))];
+        let mut peers = Vec::new();
+        let mut url = Some(format!("{}/subscriptions/{}/providers/Microsoft.Network/\
+                                    networkInterfaces?api-version={}",
+                                   MANAGEMENT_URL,
+                                   self.subscription_id,
+                                   NETWORK_API_VERSION));
+        while let Some(next) = url {
+            let page: NetworkInterfaces = try!(from_json(NAME,
+                                                         &try!(http_get(NAME,
+                                                                        &next,
+                                                                        auth.clone()))));
+            for nic in page.value {
+                if nic.tags.get(&self.tag_name) != Some(&self.tag_value) {
+                    continue;
+                }
+                for config in nic.properties.ip_configurations {
+                    if let Some(ip) = config.properties
+                        .private_ip_address
+                        .and_then(|ip| ip.parse::<IpAddr>().ok()) {
+                        peers.push(ip);
+                    }
+                }
+            }
+            url = page.next_link;
+        }
+        Ok(peers)
+    }
+}
+
+fn metadata(path: &str) -> Result<String> {
+    http_get(NAME,
+             &format!("{}/{}", METADATA_URL, path),
+             vec![("Metadata", "true".to_string())])
+}
@@ -0,0 +1,140 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Find peers among the running Compute Engine instances carrying a network tag.
+//!
+//! Keys:
+//!
+//! * `tag_value` - the network tag to match (required)
+//! * `project` - defaults to the project of the instance we're running on
+//!
+//! Requests are authorized with the instance's default service account, which needs the
+//! `compute.instances.list` permission.
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use error::Result;
+use super::{http_get, from_json, uri_encode, PeerProvider, ProviderConfig};
+
+const NAME: &'static str = "gce";
+const METADATA_URL: &'static str = "http://metadata.google.internal/computeMetadata/v1";
+const COMPUTE_URL: &'static str = "https://www.googleapis.com/compute/v1";
+
+pub struct Gce {
+    project: String,
+    tag_value: String,
+}
+
+#[derive(Deserialize)]
+struct Token {
+    access_token: String,
+}
+
+#[derive(Deserialize)]
+struct AggregatedInstances {
+    #[serde(default)]
+    items: HashMap<String, ZoneInstances>,
+    #[serde(rename = "nextPageToken", default)]
+    next_page_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ZoneInstances {
+    #[serde(default)]
+    instances: Vec<Instance>,
+}
+
+#[derive(Deserialize)]
+struct Instance {
+    status: String,
+    #[serde(default)]
+    tags: Tags,
+    #[serde(rename = "networkInterfaces", default)]
+    network_interfaces: Vec<NetworkInterface>,
+}
+
+#[derive(Default, Deserialize)]
+struct Tags {
+    #[serde(default)]
+    items: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct NetworkInterface {
+    #[serde(rename = "networkIP")]
+    network_ip: String,
+}
+
+impl Gce {
+    pub fn new(config: &ProviderConfig) -> Result<Gce> {
+        let project = match config.get("project") {
+            Some(project) => project.to_string(),
+            None => try!(metadata("project/project-id")),
+        };
+        Ok(Gce {
+            project: project,
+            tag_value: try!(config.require("tag_value")).to_string(),
+        })
+    }
+}
+
+impl PeerProvider for Gce {
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    fn peers(&self) -> Result<Vec<IpAddr>> {
+        let token: Token = try!(from_json(NAME,
+                                          &try!(metadata("instance/service-accounts/default/\
+                                                          token"))));
+        let auth = vec![("Authorization", format!("Bearer {}", token.access_token))];
+        let mut peers = Vec::new();
+        let mut page_token: Option<String> = None;
+        loop {
+            let mut url = format!("{}/projects/{}/aggregated/instances", COMPUTE_URL, self.project);
+            if let Some(ref token) = page_token {
+                url.push_str(&format!("?pageToken={}", uri_encode(token)));
+            }
+            let page: AggregatedInstances = try!(from_json(NAME,
+                                                           &try!(http_get(NAME,
+                                                                          &url,
+                                                                          auth.clone()))));
+            for zone in page.items.values() {
+                for instance in &zone.instances {
+                    if instance.status != "RUNNING" ||
+                       !instance.tags.items.contains(&self.tag_value) {
+                        continue;
+                    }
+                    if let Some(nic) = instance.network_interfaces.first() {
+                        if let Ok(ip) = nic.network_ip.parse::<IpAddr>() {
+                            peers.push(ip);
+                        }
+                    }
+                }
+            }
+            page_token = page.next_page_token;
+            if page_token.is_none() {
+                break;
+            }
+        }
+        Ok(peers)
+    }
+}
+
+fn metadata(path: &str) -> Result<String> {
+    http_get(NAME,
+             &format!("{}/{}", METADATA_URL, path),
+             vec![("Metadata-Flavor", "Google".to_string())])
+}
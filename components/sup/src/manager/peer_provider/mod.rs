@@ -0,0 +1,216 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cloud auto-join.
+//!
+//! A peer provider asks a cloud API for the instances carrying a given tag or label, and their
+//! addresses are used as initial gossip peers, in the same way as addresses given with `--peer`.
+//!
+//! Providers are configured with a string of space separated `key=value` pairs. The `provider`
+//! key picks the implementation, and the rest are handed to it:
+//!
+//! ```text
+//! provider=aws region=us-east-1 tag_key=habitat tag_value=prod
+//! provider=azure tag_name=habitat tag_value=prod
+//! provider=gce project=acme-prod tag_value=habitat
+//! ```
+
+pub mod aws;
+pub mod azure;
+pub mod gce;
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::net::IpAddr;
+
+use http_client::{self, ApiClient};
+use hyper::header::Headers;
+use hyper::status::StatusCode;
+use url::Url;
+
+use {PRODUCT, VERSION};
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "PP";
+
+/// A source of initial gossip peers.
+pub trait PeerProvider {
+    /// The name used to select this provider, as given with `provider=`.
+    fn name(&self) -> &'static str;
+
+    /// Return the addresses of every instance this provider matches, including possibly our own.
+    fn peers(&self) -> Result<Vec<IpAddr>>;
+}
+
+/// The `key=value` pairs a provider was configured with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderConfig {
+    args: HashMap<String, String>,
+}
+
+impl ProviderConfig {
+    pub fn parse(value: &str) -> Result<ProviderConfig> {
+        let mut args = HashMap::new();
+        for pair in value.split_whitespace() {
+            match pair.find('=') {
+                Some(pos) if pos > 0 => {
+                    args.insert(pair[..pos].to_string(), pair[pos + 1..].to_string());
+                }
+                _ => return Err(sup_error!(Error::InvalidPeerProvider(value.to_string()))),
+            }
+        }
+        if !args.contains_key("provider") {
+            return Err(sup_error!(Error::InvalidPeerProvider(value.to_string())));
+        }
+        Ok(ProviderConfig { args: args })
+    }
+
+    /// Return the value for `key`, if it was given.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.args.get(key).map(|v| v.as_str())
+    }
+
+    /// Return the value for `key`, or an error naming the provider and the missing key.
+    pub fn require(&self, key: &str) -> Result<&str> {
+        match self.get(key) {
+            Some(value) => Ok(value),
+            None => {
+                Err(sup_error!(Error::PeerProviderFailed(self.provider().to_string(),
+                                                         format!("missing {}=", key))))
+            }
+        }
+    }
+
+    pub fn provider(&self) -> &str {
+        self.get("provider").unwrap_or("")
+    }
+}
+
+/// Build the provider described by a configuration string.
+pub fn new(value: &str) -> Result<Box<PeerProvider>> {
+    let config = try!(ProviderConfig::parse(value));
+    match config.provider() {
+        "aws" => Ok(Box::new(try!(aws::Aws::new(&config)))),
+        "azure" => Ok(Box::new(try!(azure::Azure::new(&config)))),
+        "gce" => Ok(Box::new(try!(gce::Gce::new(&config)))),
+        p => Err(sup_error!(Error::UnknownPeerProvider(p.to_string()))),
+    }
+}
+
+/// Ask every configured provider for peers, returning them all. A provider which fails is
+/// reported and skipped, so a flaky cloud API can't keep the Supervisor from starting.
+pub fn discover(providers: &[String]) -> Vec<IpAddr> {
+    let mut addrs = Vec::new();
+    for value in providers {
+        let result = new(value).and_then(|provider| {
+            let peers = try!(provider.peers());
+            outputln!("Peer provider {} found {} instance(s)",
+                      provider.name(),
+                      peers.len());
+            Ok(peers)
+        });
+        match result {
+            Ok(peers) => {
+                for peer in peers {
+                    if !addrs.contains(&peer) {
+                        addrs.push(peer);
+                    }
+                }
+            }
+            Err(e) => outputln!("Skipping peer provider: {}", e),
+        }
+    }
+    addrs
+}
+
+/// Make a GET request for `url` with the given extra headers, returning the response body.
+fn http_get(provider: &str, url: &str, headers: Vec<(&'static str, String)>) -> Result<String> {
+    let url = match Url::parse(url) {
+        Ok(url) => url,
+        Err(e) => {
+            return Err(sup_error!(Error::PeerProviderFailed(provider.to_string(),
+                                                            format!("{}: {}", url, e))))
+        }
+    };
+    let client = try!(ApiClient::new(&url, PRODUCT, VERSION, None));
+    let mut raw = Headers::new();
+    for (name, value) in headers {
+        raw.set_raw(name, vec![value.into_bytes()]);
+    }
+    let mut res = match client.get("").headers(raw).send() {
+        Ok(res) => res,
+        Err(e) => return Err(sup_error!(Error::HttpClient(http_client::Error::HyperError(e)))),
+    };
+    let mut body = String::new();
+    try!(res.read_to_string(&mut body));
+    if res.status != StatusCode::Ok {
+        debug!("{} returned {}: {}", url, res.status, body);
+        return Err(sup_error!(Error::PeerProviderFailed(provider.to_string(),
+                                                        format!("{} returned {}",
+                                                                url.host_str().unwrap_or(""),
+                                                                res.status))));
+    }
+    Ok(body)
+}
+
+/// Decode a JSON response body into `T`.
+fn from_json<T: ::serde::Deserialize>(provider: &str, body: &str) -> Result<T> {
+    match ::serde_json::from_str(body) {
+        Ok(value) => Ok(value),
+        Err(e) => {
+            Err(sup_error!(Error::PeerProviderFailed(provider.to_string(),
+                                                     format!("unexpected response: {}", e))))
+        }
+    }
+}
+
+/// Percent-encode everything but RFC 3986 unreserved characters.
+fn uri_encode(value: &str) -> String {
+    let mut out = String::new();
+    for b in value.bytes() {
+        match b {
+            b'A'...b'Z' | b'a'...b'z' | b'0'...b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(b as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::{uri_encode, ProviderConfig};
+
+    #[test]
+    fn parse_provider_config() {
+        let config = ProviderConfig::parse("provider=aws  tag_key=habitat tag_value=prod")
+            .unwrap();
+        assert_eq!(config.provider(), "aws");
+        assert_eq!(config.get("tag_key"), Some("habitat"));
+        assert_eq!(config.get("region"), None);
+        assert!(config.require("region").is_err());
+    }
+
+    #[test]
+    fn parse_provider_config_requires_provider() {
+        assert!(ProviderConfig::parse("tag_key=habitat").is_err());
+        assert!(ProviderConfig::parse("provider=aws tag_key").is_err());
+    }
+
+    #[test]
+    fn uri_encode_reserved() {
+        assert_eq!(uri_encode("tag:habitat prod/1~"), "tag%3Ahabitat%20prod%2F1~");
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Initial peers read from a file which is watched for changes.
+//!
+//! The file lists one peer per line, as `ip`, `ip:port`, `host` or `host:port`, and peers without
+//! a port are reached on the Supervisor's own gossip port. Blank lines and lines starting with `#`
+//! are ignored. Whenever the file's contents change, its peers replace the ones it listed before
+//! as initial members, and are pinged until enough of them answer, so whatever manages the hosts
+//! can hand a running Supervisor new peers without restarting it.
+//!
+//! A missing file lists no peers, since it may not have been written yet.
+
+use std::fs::File;
+use std::io::{self, Read};
+use std::net::{IpAddr, SocketAddr, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use butterfly;
+use butterfly::member::Member;
+
+use config::gconfig;
+use error::Result;
+use util;
+
+static LOGKEY: &'static str = "PW";
+
+const CHECK_INTERVAL_MS: u64 = 5_000;
+
+pub struct PeerWatcher {
+    butterfly: butterfly::Server,
+    path: PathBuf,
+    /// The initial members which didn't come from the file.
+    others: Vec<Member>,
+    /// Addresses of this Supervisor, which are left out when the file lists them.
+    me: Vec<SocketAddr>,
+    contents: Option<String>,
+}
+
+impl PeerWatcher {
+    pub fn new(butterfly: butterfly::Server, path: &Path) -> PeerWatcher {
+        let mut others = Vec::new();
+        butterfly.member_list.with_initial_members(|member| others.push(member.clone()));
+        let port = gconfig().gossip_listen().port();
+        let mut me: Vec<SocketAddr> =
+            util::sys::ip().into_iter().map(|ip| SocketAddr::new(ip, port)).collect();
+        me.extend(gconfig().gossip_advertise());
+        PeerWatcher {
+            butterfly: butterfly,
+            path: path.to_path_buf(),
+            others: others,
+            me: me,
+            contents: None,
+        }
+    }
+
+    /// Read the file, then spawn the thread which checks it for changes.
+    pub fn start(mut self) -> Result<()> {
+        self.check();
+        try!(thread::Builder::new()
+            .name(String::from("peer-watcher"))
+            .spawn(move || loop {
+                thread::sleep(Duration::from_millis(CHECK_INTERVAL_MS));
+                self.check();
+            }));
+        Ok(())
+    }
+
+    fn check(&mut self) {
+        let contents = match read(&self.path) {
+            Ok(contents) => contents,
+            Err(e) => {
+                debug!("Cannot read peer file {}: {}", self.path.display(), e);
+                return;
+            }
+        };
+        if self.contents.as_ref() == Some(&contents) {
+            return;
+        }
+        let peers: Vec<SocketAddr> = parse(&contents, gconfig().gossip_listen().port())
+            .into_iter()
+            .filter(|addr| !self.me.contains(addr))
+            .collect();
+        outputln!("Joining {} peers listed in {}", peers.len(), self.path.display());
+        let mut members = self.others.clone();
+        for addr in peers {
+            let mut peer = Member::new();
+            peer.set_address(format!("{}", addr.ip()));
+            peer.set_swim_port(addr.port() as i32);
+            peer.set_gossip_port(addr.port() as i32);
+            members.push(peer);
+        }
+        self.butterfly.member_list.set_initial_members(members);
+        self.contents = Some(contents);
+    }
+}
+
+fn read(path: &Path) -> io::Result<String> {
+    let mut contents = String::new();
+    match File::open(path) {
+        Ok(mut file) => {
+            try!(file.read_to_string(&mut contents));
+        }
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+    Ok(contents)
+}
+
+/// Resolve each peer listed in the file, leaving out any which don't resolve.
+fn parse(contents: &str, default_port: u16) -> Vec<SocketAddr> {
+    let mut peers = Vec::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let resolved = match line.parse::<IpAddr>() {
+            Ok(ip) => Ok(SocketAddr::new(ip, default_port)),
+            Err(_) => {
+                let lookup = if line.contains(':') {
+                    line.to_socket_addrs()
+                } else {
+                    (line, default_port).to_socket_addrs()
+                };
+                lookup.and_then(|mut addrs| {
+                    addrs.next()
+                        .ok_or(io::Error::new(io::ErrorKind::NotFound, "no addresses found"))
+                })
+            }
+        };
+        match resolved {
+            Ok(addr) => peers.push(addr),
+            Err(e) => outputln!("Cannot resolve peer {}: {}", line, e),
+        }
+    }
+    peers
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::SocketAddr;
+    use std::str::FromStr;
+
+    use super::parse;
+
+    #[test]
+    fn parse_peer_file() {
+        let contents = "# ring members\n10.0.0.1\n\n  10.0.0.2:9648  \n::1\n[::2]:9700\n";
+        let expected: Vec<SocketAddr> = vec!["10.0.0.1:9638", "10.0.0.2:9648", "[::1]:9638",
+                                             "[::2]:9700"]
+            .into_iter()
+            .map(|addr| SocketAddr::from_str(addr).unwrap())
+            .collect();
+        assert_eq!(parse(contents, 9638), expected);
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Releases a service failed to update to.
+//!
+//! Every failure to download, install, or start a release the updater found is counted against
+//! the release in the service's `quarantine.toml`. Once a release has failed as many times as the
+//! Supervisor allows, it is quarantined: the updater skips it until a newer release is published
+//! or an operator clears it with `hab-sup quarantine clear`. The file is read again each time it
+//! is consulted, so clearing a release takes effect without restarting the Supervisor.
+
+use std::fs::{rename, File};
+use std::io::prelude::*;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use hcore::fs;
+use hcore::package::PackageIdent;
+use time;
+use toml;
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "QR";
+
+/// The file, in the service's directory, failed releases are recorded in.
+pub const QUARANTINE_FILE: &'static str = "quarantine.toml";
+
+/// How many times an update to a release may fail before the release is quarantined, by default.
+pub const DEFAULT_UPDATE_ATTEMPTS: u32 = 3;
+
+lazy_static! {
+    /// Held while a quarantine file is read, changed, and written, so the updater's threads don't
+    /// lose each other's changes.
+    static ref QUARANTINE_LOCK: Mutex<()> = Mutex::new(());
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct FailedRelease {
+    pub ident: String,
+    pub failures: u32,
+    /// Why the last attempt failed.
+    pub reason: String,
+    /// When the release was quarantined, in seconds since the epoch, if it was.
+    pub quarantined_at: Option<i64>,
+}
+
+#[derive(Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct Quarantine {
+    #[serde(default)]
+    pub release: Vec<FailedRelease>,
+}
+
+impl Quarantine {
+    /// Reads the service's quarantine; a service without one has none.
+    pub fn load(service_name: &str) -> Result<Self> {
+        let path = quarantine_path(service_name);
+        let mut contents = String::new();
+        match File::open(&path) {
+            Ok(mut file) => try!(file.read_to_string(&mut contents)),
+            Err(_) => return Ok(Quarantine::default()),
+        };
+        toml::decode_str(&contents)
+            .ok_or(sup_error!(Error::InvalidQuarantine(path.to_string_lossy().into_owned())))
+    }
+
+    fn save(&self, service_name: &str) -> Result<()> {
+        let path = quarantine_path(service_name);
+        let tmp = path.with_extension("toml.write");
+        try!(try!(File::create(&tmp)).write_all(toml::encode_str(self).as_bytes()));
+        try!(rename(&tmp, &path));
+        Ok(())
+    }
+
+    pub fn is_quarantined(&self, ident: &PackageIdent) -> bool {
+        let ident = ident.to_string();
+        self.release.iter().any(|r| r.ident == ident && r.quarantined_at.is_some())
+    }
+
+    /// Returns the quarantined releases, in the order they were quarantined.
+    pub fn quarantined(&self) -> Vec<String> {
+        let mut quarantined: Vec<&FailedRelease> =
+            self.release.iter().filter(|r| r.quarantined_at.is_some()).collect();
+        quarantined.sort_by_key(|r| r.quarantined_at);
+        quarantined.into_iter().map(|r| r.ident.clone()).collect()
+    }
+
+    /// Counts a failed attempt to update to the release, quarantining it once it has failed
+    /// `attempts` times. Returns true if this failure quarantined it.
+    pub fn record_failure(&mut self, ident: &PackageIdent, reason: &str, attempts: u32) -> bool {
+        let ident = ident.to_string();
+        if !self.release.iter().any(|r| r.ident == ident) {
+            self.release.push(FailedRelease {
+                ident: ident.clone(),
+                failures: 0,
+                reason: String::new(),
+                quarantined_at: None,
+            });
+        }
+        let release = self.release.iter_mut().find(|r| r.ident == ident).unwrap();
+        release.failures += 1;
+        release.reason = reason.to_string();
+        if release.quarantined_at.is_none() && release.failures >= attempts {
+            release.quarantined_at = Some(time::get_time().sec);
+            return true;
+        }
+        false
+    }
+
+    /// Forgets the failures of a release that was updated to after all. Returns true if there
+    /// were any.
+    pub fn record_success(&mut self, ident: &PackageIdent) -> bool {
+        let ident = ident.to_string();
+        let before = self.release.len();
+        self.release.retain(|r| r.ident != ident);
+        self.release.len() != before
+    }
+
+    /// Forgets the failures of the release, or of every release if `ident` is `None`. Returns
+    /// the releases forgotten.
+    pub fn clear(&mut self, ident: Option<&PackageIdent>) -> Vec<String> {
+        let (cleared, kept) = self.release
+            .drain(..)
+            .partition(|r| ident.map_or(true, |ident| r.ident == ident.to_string()));
+        self.release = kept;
+        cleared.into_iter().map(|r: FailedRelease| r.ident).collect()
+    }
+}
+
+/// Returns the path of the service's quarantine.
+pub fn quarantine_path(service_name: &str) -> PathBuf {
+    fs::svc_path(service_name).join(QUARANTINE_FILE)
+}
+
+/// Reads the service's quarantine, lets `f` change it, and writes it back if `f` returns true.
+/// Returns what `f` returned.
+pub fn update<F>(service_name: &str, f: F) -> Result<bool>
+    where F: FnOnce(&mut Quarantine) -> bool
+{
+    let _guard = QUARANTINE_LOCK.lock().expect("Quarantine lock is poisoned");
+    let mut quarantine = try!(Quarantine::load(service_name));
+    if !f(&mut quarantine) {
+        return Ok(false);
+    }
+    try!(quarantine.save(service_name));
+    Ok(true)
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+    use toml;
+
+    use super::Quarantine;
+
+    #[test]
+    fn releases_are_quarantined_after_enough_failures() {
+        let bad = PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap();
+        let good = PackageIdent::from_str("core/redis/3.2.4/20170520150022").unwrap();
+        let mut quarantine = Quarantine::default();
+        assert!(!quarantine.record_failure(&bad, "download failed", 2));
+        assert!(!quarantine.record_failure(&good, "download failed", 2));
+        assert!(!quarantine.is_quarantined(&bad));
+        assert!(quarantine.record_failure(&bad, "process exited with code 1", 2));
+        assert!(!quarantine.record_failure(&bad, "process exited with code 1", 2));
+        assert!(quarantine.is_quarantined(&bad));
+        assert_eq!(quarantine.quarantined(), vec![bad.to_string()]);
+
+        assert!(quarantine.record_success(&good));
+        assert!(!quarantine.record_success(&good));
+        assert_eq!(quarantine.release.len(), 1);
+        assert_eq!(quarantine.release[0].failures, 3);
+        assert_eq!(quarantine.release[0].reason, "process exited with code 1");
+    }
+
+    #[test]
+    fn clear_forgets_releases() {
+        let bad = PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap();
+        let worse = PackageIdent::from_str("core/redis/3.2.4/20170520150022").unwrap();
+        let mut quarantine = Quarantine::default();
+        quarantine.record_failure(&bad, "download failed", 1);
+        quarantine.record_failure(&worse, "download failed", 1);
+        assert_eq!(quarantine.clear(Some(&bad)), vec![bad.to_string()]);
+        assert!(!quarantine.is_quarantined(&bad));
+        assert!(quarantine.is_quarantined(&worse));
+        assert_eq!(quarantine.clear(None), vec![worse.to_string()]);
+        assert!(quarantine.release.is_empty());
+    }
+
+    #[test]
+    fn quarantines_round_trip_through_toml() {
+        let bad = PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap();
+        let mut quarantine = Quarantine::default();
+        quarantine.record_failure(&bad, "download failed", 1);
+        let decoded: Quarantine = toml::decode_str(&toml::encode_str(&quarantine)).unwrap();
+        assert_eq!(decoded, quarantine);
+        assert_eq!(toml::decode_str::<Quarantine>(""), Some(Quarantine::default()));
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runs the commands sent to the whole ring with `hab ring exec`.
+//!
+//! A command runs at most once, and only if it is signed by the key of an origin given with
+//! `--ring-exec-origin` whose public key is in the key cache. Commands issued before the
+//! Supervisor started are never run, so a restarted Supervisor doesn't replay the ring's history.
+//! Every other command is answered with a `RingCommandAck`, whether it ran or not, so whoever
+//! issued it can tell which members refused it and why.
+
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use butterfly::rumor::{RingCommand, RingCommandAck};
+use hcore::crypto::default_cache_key_path;
+use hcore::service::ServiceGroup;
+use time;
+
+use config::gconfig;
+use logger;
+use manager::State;
+
+static LOGKEY: &'static str = "RX";
+
+pub struct RingExec {
+    /// When the Supervisor started, in seconds since the epoch.
+    started_at: i64,
+    /// The ids of the commands already considered.
+    seen: HashSet<String>,
+}
+
+impl RingExec {
+    pub fn new() -> Self {
+        RingExec {
+            started_at: time::now_utc().to_timespec().sec,
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Runs the commands which arrived since the last check, and acknowledges each of them.
+    pub fn check(&mut self, state: &State) {
+        let mut pending = Vec::new();
+        state.butterfly.ring_command_store.with_keys(|(id, rumors)| if !self.seen.contains(id) {
+            if let Some(rc) = rumors.get("ring_command") {
+                pending.push(rc.clone());
+            }
+        });
+        for rc in pending {
+            self.seen.insert(rc.get_id().to_string());
+            if rc.get_issued_at() < self.started_at {
+                debug!("Ignoring ring command {}, issued before we started", rc.get_id());
+                continue;
+            }
+            let (success, message) = match authorize(&rc) {
+                Ok(()) => {
+                    outputln!("Running ring command {}: {} {}",
+                              rc.get_id(),
+                              rc.get_command(),
+                              rc.get_args().join(" "));
+                    run(&rc, state)
+                }
+                Err(message) => {
+                    outputln!("Refusing ring command {}: {}", rc.get_id(), message);
+                    (false, message)
+                }
+            };
+            let ack = RingCommandAck::new(state.butterfly.member_id(),
+                                          rc.get_id(),
+                                          success,
+                                          message);
+            state.butterfly.insert_ring_command_ack(ack);
+        }
+    }
+}
+
+/// Checks the command is signed by a trusted origin, returning why not if it isn't.
+fn authorize(rc: &RingCommand) -> Result<(), String> {
+    match rc.origin() {
+        Some(ref origin) if gconfig().ring_exec_origin().contains(origin) => (),
+        Some(origin) => return Err(format!("Commands signed by {} are not run here", origin)),
+        None => return Err("The command is not signed".to_string()),
+    }
+    rc.verify(&default_cache_key_path(None)).map_err(|e| e.to_string())
+}
+
+/// Runs a command, returning whether it succeeded and what happened.
+fn run(rc: &RingCommand, state: &State) -> (bool, String) {
+    let args = rc.get_args();
+    match rc.get_command() {
+        "restart" => restart(args, state),
+        "log-level" if args.len() == 1 || args.len() == 2 => {
+            match logger::set_level(&args[0], args.get(1).map(|s| s.as_str()), None) {
+                Ok(()) => (true, format!("Log level set to {}", args.join(" "))),
+                Err(e) => (false, e.to_string()),
+            }
+        }
+        "log-level" => (false, "Usage: log-level <level> [subsystem]".to_string()),
+        command => (false, format!("Unknown command {}", command)),
+    }
+}
+
+/// Restarts the services of the given groups which run under this Supervisor.
+fn restart(args: &[String], state: &State) -> (bool, String) {
+    if args.is_empty() {
+        return (false, "Usage: restart <service_group>...".to_string());
+    }
+    let mut groups = Vec::new();
+    for arg in args {
+        match ServiceGroup::from_str(arg) {
+            Ok(sg) => groups.push(sg),
+            Err(e) => return (false, e.to_string()),
+        }
+    }
+    let mut restarted = Vec::new();
+    for service in state.services.write().expect("Services lock is poisoned!").iter_mut() {
+        if groups.contains(&service.service_group) {
+            service.needs_restart = true;
+            restarted.push(service.service_group_str());
+        }
+    }
+    if restarted.is_empty() {
+        (true, "No matching services run here".to_string())
+    } else {
+        (true, format!("Restarting {}", restarted.join(", ")))
+    }
+}
+
+/// A ring command and the acknowledgements of it which have arrived, as served by the HTTP
+/// gateway.
+#[derive(Debug, Serialize)]
+pub struct RingCommandStatus {
+    pub id: String,
+    pub command: String,
+    pub args: Vec<String>,
+    /// When the command was issued, in seconds since the epoch.
+    pub issued_at: i64,
+    /// The key the command is signed with, if it is.
+    pub signer: String,
+    pub acks: Vec<Ack>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Ack {
+    pub member_id: String,
+    /// The alias of the member, if it has one.
+    pub alias: Option<String>,
+    pub success: bool,
+    pub message: String,
+}
+
+/// Returns every ring command this Supervisor knows of, or only the one with the given id, with
+/// the acknowledgements of each, newest command first.
+pub fn statuses(state: &State, id: Option<&str>) -> Vec<RingCommandStatus> {
+    let mut statuses = Vec::new();
+    state.butterfly.ring_command_store.with_keys(|(key, rumors)| {
+        if id.map_or(false, |id| id != key) {
+            return;
+        }
+        if let Some(rc) = rumors.get("ring_command") {
+            let mut acks = Vec::new();
+            state.butterfly.ring_command_ack_store.with_rumors(key, |ack| {
+                acks.push(Ack {
+                    member_id: ack.get_member_id().to_string(),
+                    alias: state.butterfly.member_list.alias_of(ack.get_member_id()),
+                    success: ack.get_success(),
+                    message: ack.get_message().to_string(),
+                })
+            });
+            acks.sort_by(|a, b| a.member_id.cmp(&b.member_id));
+            statuses.push(RingCommandStatus {
+                id: rc.get_id().to_string(),
+                command: rc.get_command().to_string(),
+                args: rc.get_args().to_vec(),
+                issued_at: rc.get_issued_at(),
+                signer: rc.get_signer().to_string(),
+                acks: acks,
+            });
+        }
+    });
+    statuses.sort_by(|a, b| b.issued_at.cmp(&a.issued_at));
+    statuses
+}
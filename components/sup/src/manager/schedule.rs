@@ -0,0 +1,223 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cron style schedules.
+//!
+//! A schedule is made of the five classic cron fields - minute, hour, day of month, month, and day
+//! of week - separated by whitespace. Each field accepts `*`, a single value, a range (`1-5`), a
+//! step (`*/15` or `0-30/10`), or a comma separated list of any of these. As with cron, when both
+//! the day of month and day of week are restricted, a time matches if either of them matches.
+//!
+//! ```ignore
+//! let schedule = Schedule::from_str("30 2 * * 1-5").unwrap(); // 02:30 on weekdays
+//! ```
+
+use std::fmt;
+use std::result;
+use std::str::FromStr;
+
+use serde::{Serialize, Serializer};
+use time::{self, Tm};
+
+use error::{Error, Result, SupError};
+
+static LOGKEY: &'static str = "SD";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    expression: String,
+    minutes: Vec<bool>,
+    hours: Vec<bool>,
+    days: Vec<bool>,
+    months: Vec<bool>,
+    weekdays: Vec<bool>,
+    any_day: bool,
+    any_weekday: bool,
+    last_fired: Option<i64>,
+}
+
+impl Schedule {
+    /// Returns true if the given time falls on a minute matched by this schedule.
+    pub fn matches(&self, tm: &Tm) -> bool {
+        let day = self.days[tm.tm_mday as usize];
+        let weekday = self.weekdays[tm.tm_wday as usize];
+        let day_matches = match (self.any_day, self.any_weekday) {
+            (true, true) => true,
+            (true, false) => weekday,
+            (false, true) => day,
+            (false, false) => day || weekday,
+        };
+        self.minutes[tm.tm_min as usize] && self.hours[tm.tm_hour as usize] &&
+        self.months[(tm.tm_mon + 1) as usize] && day_matches
+    }
+
+    /// Returns true once for every minute matched by this schedule; subsequent calls within the
+    /// same minute return false.
+    pub fn due(&mut self) -> bool {
+        self.due_at(&time::now())
+    }
+
+    fn due_at(&mut self, tm: &Tm) -> bool {
+        let minute = tm.to_timespec().sec / 60;
+        if self.last_fired == Some(minute) || !self.matches(tm) {
+            return false;
+        }
+        self.last_fired = Some(minute);
+        true
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.expression)
+    }
+}
+
+impl FromStr for Schedule {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let fields: Vec<&str> = value.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(sup_error!(Error::InvalidSchedule(format!("expected 5 fields in '{}'",
+                                                                 value))));
+        }
+        let mut weekdays = try!(parse_field(fields[4], 0, 7));
+        // Both 0 and 7 are Sunday
+        if weekdays[7] {
+            weekdays[0] = true;
+        }
+        weekdays.truncate(7);
+        Ok(Schedule {
+            expression: fields.join(" "),
+            minutes: try!(parse_field(fields[0], 0, 59)),
+            hours: try!(parse_field(fields[1], 0, 23)),
+            days: try!(parse_field(fields[2], 1, 31)),
+            months: try!(parse_field(fields[3], 1, 12)),
+            weekdays: weekdays,
+            any_day: fields[2] == "*",
+            any_weekday: fields[4] == "*",
+            last_fired: None,
+        })
+    }
+}
+
+impl Serialize for Schedule {
+    fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error>
+        where S: Serializer
+    {
+        serializer.serialize_str(&self.expression)
+    }
+}
+
+/// Parses a single cron field into a lookup table indexed by value.
+fn parse_field(field: &str, min: u32, max: u32) -> Result<Vec<bool>> {
+    let mut table = vec![false; max as usize + 1];
+    for part in field.split(',') {
+        let (range, step) = match part.find('/') {
+            Some(idx) => (&part[..idx], try!(parse_value(&part[idx + 1..], 1, max))),
+            None => (part, 1),
+        };
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else {
+            match range.find('-') {
+                Some(idx) => {
+                    (try!(parse_value(&range[..idx], min, max)),
+                     try!(parse_value(&range[idx + 1..], min, max)))
+                }
+                None => {
+                    let v = try!(parse_value(range, min, max));
+                    (v, v)
+                }
+            }
+        };
+        if start > end || step == 0 {
+            return Err(sup_error!(Error::InvalidSchedule(format!("invalid range '{}'", part))));
+        }
+        let mut v = start;
+        while v <= end {
+            table[v as usize] = true;
+            v += step;
+        }
+    }
+    Ok(table)
+}
+
+fn parse_value(value: &str, min: u32, max: u32) -> Result<u32> {
+    match value.parse::<u32>() {
+        Ok(v) if v >= min && v <= max => Ok(v),
+        _ => {
+            Err(sup_error!(Error::InvalidSchedule(format!("'{}' is not between {} and {}",
+                                                          value,
+                                                          min,
+                                                          max))))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use time;
+
+    use super::Schedule;
+
+    // 2017-03-06 02:30:00 UTC, a Monday
+    fn monday_morning() -> time::Tm {
+        time::at_utc(time::Timespec::new(1488767400, 0))
+    }
+
+    #[test]
+    fn every_minute() {
+        let s = Schedule::from_str("* * * * *").unwrap();
+        assert!(s.matches(&monday_morning()));
+    }
+
+    #[test]
+    fn exact_time() {
+        assert!(Schedule::from_str("30 2 * * *").unwrap().matches(&monday_morning()));
+        assert!(!Schedule::from_str("31 2 * * *").unwrap().matches(&monday_morning()));
+    }
+
+    #[test]
+    fn ranges_steps_and_lists() {
+        assert!(Schedule::from_str("*/15 0-4 * * 1-5").unwrap().matches(&monday_morning()));
+        assert!(Schedule::from_str("0,30 2 6 3 *").unwrap().matches(&monday_morning()));
+        assert!(!Schedule::from_str("*/20 * * * *").unwrap().matches(&monday_morning()));
+    }
+
+    #[test]
+    fn day_of_month_or_day_of_week() {
+        assert!(Schedule::from_str("30 2 1 * 1").unwrap().matches(&monday_morning()));
+        assert!(!Schedule::from_str("30 2 1 * 0,7").unwrap().matches(&monday_morning()));
+    }
+
+    #[test]
+    fn due_once_per_minute() {
+        let mut s = Schedule::from_str("* * * * *").unwrap();
+        assert!(s.due_at(&monday_morning()));
+        assert!(!s.due_at(&monday_morning()));
+    }
+
+    #[test]
+    fn invalid_expressions() {
+        assert!(Schedule::from_str("* * * *").is_err());
+        assert!(Schedule::from_str("60 * * * *").is_err());
+        assert!(Schedule::from_str("5-1 * * * *").is_err());
+        assert!(Schedule::from_str("*/0 * * * *").is_err());
+        assert!(Schedule::from_str("a * * * *").is_err());
+    }
+}
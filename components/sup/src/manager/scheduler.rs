@@ -0,0 +1,266 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runs a service's scheduled hooks.
+//!
+//! Packages declare scheduled hooks with the `pkg_svc_scheduled_hooks` plan variable, which maps
+//! the name of a hook in the package's `hooks/` directory to a cron style schedule. Scheduled
+//! hooks are rendered with the same configuration and census data as every other hook. Each run
+//! happens on its own thread so a long running hook never blocks the Supervisor, and a hook whose
+//! previous run is still in progress is skipped rather than run twice. When `--hook-threads` caps
+//! the number of scheduled hooks running at once, a due hook waits for a free slot instead.
+//!
+//! Other hooks which may run for a long time, such as the update and provisioning hooks, are run
+//! the same way with a `HookTask`, and count against the same cap.
+
+use std::result;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, ATOMIC_USIZE_INIT, Ordering};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+
+use ansi_term::Colour::Yellow;
+use hcore::service::ServiceGroup;
+use time;
+
+use config::gconfig;
+use error::{Error, Result, SupError};
+use manager::schedule::Schedule;
+use manager::service::config::ServiceConfig;
+use package::{Package, Sandbox};
+use package::hooks::{Hook, HookType};
+
+static LOGKEY: &'static str = "SK";
+
+/// Number of scheduled hooks running across every service.
+static RUNNING_HOOKS: AtomicUsize = ATOMIC_USIZE_INIT;
+
+/// The outcome of the most recent run of a scheduled hook.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HookRun {
+    pub started: String,
+    pub finished: Option<String>,
+    pub success: Option<bool>,
+    pub exit_code: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScheduledHook {
+    pub name: String,
+    pub schedule: Schedule,
+    pub running: bool,
+    pub pending: bool,
+    pub last_run: Option<HookRun>,
+    #[serde(skip_serializing)]
+    hook: Hook,
+    #[serde(skip_serializing)]
+    rx: Option<Receiver<Option<i32>>>,
+}
+
+impl ScheduledHook {
+    /// Starts a run of the hook on its own thread.
+    fn run(&mut self, service_group: &ServiceGroup) {
+        let (tx, rx) = mpsc::channel();
+        let hook = self.hook.clone();
+        let sg = service_group.clone();
+        let spawned = thread::Builder::new()
+            .name(format!("scheduled-hook-{}", self.name))
+            .spawn(move || {
+                let exit_code = match hook.run(&sg) {
+                    Ok(()) => Some(0),
+                    Err(SupError { err: Error::HookFailed(_, code), .. }) => Some(code),
+                    Err(e) => {
+                        outputln!(preamble sg.to_string(), "Scheduled hook failed to run: {}", e);
+                        None
+                    }
+                };
+                tx.send(exit_code).unwrap_or(());
+            });
+        match spawned {
+            Ok(_) => {
+                RUNNING_HOOKS.fetch_add(1, Ordering::SeqCst);
+                self.running = true;
+                self.rx = Some(rx);
+                self.last_run = Some(HookRun {
+                    started: time::now_utc().rfc3339().to_string(),
+                    finished: None,
+                    success: None,
+                    exit_code: None,
+                });
+            }
+            Err(e) => {
+                outputln!(preamble service_group.to_string(),
+                          "Failed to start scheduled hook {}: {}",
+                          self.name,
+                          e)
+            }
+        }
+    }
+
+    /// Records the outcome of a run which has finished since the last check.
+    fn reap(&mut self) {
+        let exit_code = match self.rx {
+            Some(ref rx) => {
+                match rx.try_recv() {
+                    Ok(exit_code) => exit_code,
+                    Err(TryRecvError::Empty) => return,
+                    Err(TryRecvError::Disconnected) => None,
+                }
+            }
+            None => return,
+        };
+        self.rx = None;
+        self.running = false;
+        RUNNING_HOOKS.fetch_sub(1, Ordering::SeqCst);
+        if let Some(ref mut run) = self.last_run {
+            run.finished = Some(time::now_utc().rfc3339().to_string());
+            run.success = Some(exit_code == Some(0));
+            run.exit_code = exit_code;
+        }
+    }
+}
+
+/// A hook running on its own thread, counted against `--hook-threads` like a scheduled hook.
+pub struct HookTask {
+    rx: Receiver<result::Result<bool, String>>,
+}
+
+impl HookTask {
+    /// Starts `run` on its own thread, unless as many hooks as allowed are running already or the
+    /// thread can't be started, in which case the caller should try again later.
+    pub fn start<F>(name: String, run: F) -> Option<HookTask>
+        where F: FnOnce() -> Result<bool> + Send + 'static
+    {
+        if !hook_slot_available() {
+            return None;
+        }
+        let (tx, rx) = mpsc::channel();
+        RUNNING_HOOKS.fetch_add(1, Ordering::SeqCst);
+        let spawned = thread::Builder::new()
+            .name(name.clone())
+            .spawn(move || {
+                let _slot = HookSlot;
+                tx.send(run().map_err(|e| e.to_string())).unwrap_or(());
+            });
+        match spawned {
+            Ok(_) => Some(HookTask { rx: rx }),
+            Err(e) => {
+                RUNNING_HOOKS.fetch_sub(1, Ordering::SeqCst);
+                outputln!("Failed to start {}: {}", name, e);
+                None
+            }
+        }
+    }
+
+    /// Returns the outcome of the hook once it has finished: whether the package has the hook,
+    /// or why it failed.
+    pub fn poll(&self) -> Option<result::Result<bool, String>> {
+        match self.rx.try_recv() {
+            Ok(outcome) => Some(outcome),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Err(String::from("hook thread died"))),
+        }
+    }
+}
+
+/// Gives back a `HookTask`'s slot when its thread ends, however it ends.
+struct HookSlot;
+
+impl Drop for HookSlot {
+    fn drop(&mut self) {
+        RUNNING_HOOKS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct Scheduler {
+    pub hooks: Vec<ScheduledHook>,
+}
+
+impl Scheduler {
+    /// Load the scheduled hooks declared by a package.
+    pub fn load(package: &Package, user: &str, group: &str) -> Result<Self> {
+        let declared = try!(package.pkg_install.svc_scheduled_hooks());
+        let sandbox = try!(Sandbox::new(package));
+        let mut hooks = Vec::with_capacity(declared.len());
+        for (name, expression) in declared.into_iter() {
+            let schedule = try!(Schedule::from_str(&expression).map_err(|_| {
+                sup_error!(Error::InvalidSchedule(format!("'{}' for hook {}", expression, name)))
+            }));
+            let hook = Hook::new(HookType::Scheduled,
+                                 package.scheduled_hook_template_path(&name),
+                                 package.scheduled_hook_path(&name),
+                                 user.to_string(),
+                                 group.to_string(),
+                                 package.template_engine,
+                                 package.strict_render,
+                                 package.hook_interpreter,
+                                 package.environment.clone(),
+                                 sandbox.clone());
+            hooks.push(ScheduledHook {
+                name: name,
+                schedule: schedule,
+                running: false,
+                pending: false,
+                last_run: None,
+                hook: hook,
+                rx: None,
+            });
+        }
+        hooks.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(Scheduler { hooks: hooks })
+    }
+
+    /// Render every scheduled hook with the service's current configuration and census data.
+    /// Returns false if any hook failed to render.
+    pub fn compile_all(&self, context: &ServiceConfig) -> bool {
+        let mut compiled = true;
+        for scheduled in self.hooks.iter() {
+            if let Err(e) = scheduled.hook.compile(Some(context)) {
+                outputln!("Failed to compile scheduled hook {}: {}", scheduled.name, e);
+                compiled = false;
+            }
+        }
+        compiled
+    }
+
+    /// Record the outcome of finished runs and start any hook whose schedule is due, as long as
+    /// the Supervisor isn't already running as many scheduled hooks as it's allowed to.
+    pub fn tick(&mut self, service_group: &ServiceGroup) {
+        for scheduled in self.hooks.iter_mut() {
+            scheduled.reap();
+            if scheduled.schedule.due() {
+                if scheduled.running {
+                    outputln!(preamble service_group.to_string(),
+                              "Skipping scheduled hook {}; {}",
+                              scheduled.name,
+                              Yellow.bold().paint("previous run still in progress"));
+                } else {
+                    scheduled.pending = true;
+                }
+            }
+            if scheduled.pending && hook_slot_available() {
+                scheduled.pending = false;
+                scheduled.run(service_group);
+            }
+        }
+    }
+}
+
+fn hook_slot_available() -> bool {
+    match gconfig().hook_threads() {
+        Some(max) => RUNNING_HOOKS.load(Ordering::SeqCst) < max,
+        None => true,
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Secrets read from files which are watched for changes.
+//!
+//! Rotation tooling such as cert-manager, or Kubernetes updating a mounted secret, replaces the
+//! files in place, so each set of files is read again every few seconds and, whenever any of
+//! them has changed, handed to its `apply` function. Files which must change together, such as a
+//! certificate and its key, are watched as one set: until both have been replaced the pair
+//! doesn't match, `apply` fails, and what was applied before stays in effect.
+//!
+//! Unlike later reads, the first must succeed, so a Supervisor pointed at a missing or bad file
+//! refuses to start rather than running without the secret.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use error::Result;
+
+static LOGKEY: &'static str = "SF";
+
+const CHECK_INTERVAL_MS: u64 = 5_000;
+
+/// Applies the contents of a set of files, in the order the files were given.
+pub type Apply = Box<Fn(&[Vec<u8>]) -> Result<()> + Send>;
+
+struct Watched {
+    /// What the files hold, for messages.
+    what: String,
+    paths: Vec<PathBuf>,
+    contents: Vec<Vec<u8>>,
+    apply: Apply,
+}
+
+#[derive(Default)]
+pub struct SecretFiles {
+    watched: Vec<Watched>,
+}
+
+impl SecretFiles {
+    pub fn new() -> SecretFiles {
+        SecretFiles::default()
+    }
+
+    /// Reads `paths` and applies their contents now, and again whenever they change once the
+    /// watcher is started.
+    ///
+    /// # Failures
+    ///
+    /// * A file can't be read
+    /// * Their contents can't be applied
+    pub fn watch<F>(&mut self, what: &str, paths: &[&Path], apply: F) -> Result<()>
+        where F: Fn(&[Vec<u8>]) -> Result<()> + Send + 'static
+    {
+        let paths: Vec<PathBuf> = paths.iter().map(|p| p.to_path_buf()).collect();
+        let contents = try!(read_all(&paths));
+        try!(apply(&contents));
+        outputln!("Watching {} for changes to the {}", display(&paths), what);
+        self.watched.push(Watched {
+            what: what.to_string(),
+            paths: paths,
+            contents: contents,
+            apply: Box::new(apply),
+        });
+        Ok(())
+    }
+
+    /// Spawns the thread which checks the files for changes, if any are watched.
+    pub fn start(mut self) -> Result<()> {
+        if self.watched.is_empty() {
+            return Ok(());
+        }
+        try!(thread::Builder::new()
+            .name(String::from("secret-files"))
+            .spawn(move || loop {
+                thread::sleep(Duration::from_millis(CHECK_INTERVAL_MS));
+                self.check();
+            }));
+        Ok(())
+    }
+
+    fn check(&mut self) {
+        for watched in self.watched.iter_mut() {
+            let contents = match read_all(&watched.paths) {
+                Ok(contents) => contents,
+                Err(e) => {
+                    debug!("Cannot read {}: {}", display(&watched.paths), e);
+                    continue;
+                }
+            };
+            if contents == watched.contents {
+                continue;
+            }
+            match (watched.apply)(&contents) {
+                Ok(()) => {
+                    outputln!("Reloaded the {} from {}", watched.what, display(&watched.paths))
+                }
+                Err(e) => {
+                    outputln!("Cannot reload the {} from {}, keeping the old one: {}",
+                              watched.what,
+                              display(&watched.paths),
+                              e)
+                }
+            }
+            // Remembered either way, so a bad file is reported once rather than on every check.
+            watched.contents = contents;
+        }
+    }
+}
+
+fn read_all(paths: &[PathBuf]) -> Result<Vec<Vec<u8>>> {
+    let mut all = Vec::with_capacity(paths.len());
+    for path in paths {
+        let mut contents = Vec::new();
+        try!(try!(File::open(path)).read_to_end(&mut contents));
+        all.push(contents);
+    }
+    Ok(all)
+}
+
+fn display(paths: &[PathBuf]) -> String {
+    paths.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().join(" and ")
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::io::Write;
+    use std::path::Path;
+    use std::sync::{Arc, Mutex};
+
+    use tempdir::TempDir;
+
+    use error::{Error, Result};
+    use super::{SecretFiles, LOGKEY};
+
+    fn write(path: &Path, contents: &str) {
+        File::create(path).unwrap().write_all(contents.as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn changed_files_are_applied_again() {
+        let dir = TempDir::new("secret-files").unwrap();
+        let path = dir.path().join("token");
+        write(&path, "first");
+        let applied = Arc::new(Mutex::new(Vec::new()));
+        let mut files = SecretFiles::new();
+        {
+            let applied = applied.clone();
+            files.watch("token", &[&path], move |contents: &[Vec<u8>]| -> Result<()> {
+                    if contents[0].is_empty() {
+                        return Err(sup_error!(Error::InvalidSecretFile(String::from("empty"))));
+                    }
+                    applied.lock().unwrap().push(contents[0].clone());
+                    Ok(())
+                })
+                .unwrap();
+        }
+        files.check();
+        assert_eq!(applied.lock().unwrap().len(), 1);
+
+        write(&path, "second");
+        files.check();
+        write(&path, "");
+        files.check();
+        assert_eq!(*applied.lock().unwrap(),
+                   vec![b"first".to_vec(), b"second".to_vec()]);
+    }
+
+    #[test]
+    fn missing_files_are_refused_at_first() {
+        let dir = TempDir::new("secret-files").unwrap();
+        let mut files = SecretFiles::new();
+        assert!(files.watch("token", &[&dir.path().join("missing")], |_: &[Vec<u8>]| Ok(()))
+            .is_err());
+    }
+}
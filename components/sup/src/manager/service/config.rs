@@ -27,12 +27,14 @@ use hcore::package::PackageInstall;
 use hcore::crypto;
 use toml;
 
+use manager::aws_config;
 use manager::census::{Census, CensusList};
 use config::gconfig;
 use error::{Error, Result};
 use package::Package;
-use templating::Template;
-use util::{self, convert};
+use templating::ConfigRenderer;
+use super::data_version::DataVersion;
+use util;
 use util::users as hab_users;
 use VERSION;
 
@@ -55,6 +57,9 @@ pub struct ServiceConfig {
     pub cfg: Cfg,
     pub svc: Svc,
     pub bind: Bind,
+    /// The version of the template data the package's templates are written for.
+    #[serde(default)]
+    pub data_version: DataVersion,
     // Set to 'true' if we have data that needs to be sent to a configuration file
     #[serde(skip_deserializing)]
     pub needs_write: bool,
@@ -69,7 +74,7 @@ impl ServiceConfig {
                cl: &CensusList,
                bindings: Vec<String>)
                -> Result<ServiceConfig> {
-        let cfg = try!(Cfg::new(package));
+        let cfg = try!(Cfg::new(service_group, package));
         let bind = try!(Bind::new(bindings, &cl));
         Ok(ServiceConfig {
             pkg: Pkg::new(&package.pkg_install),
@@ -78,6 +83,7 @@ impl ServiceConfig {
             cfg: cfg,
             svc: Svc::new(service_group, cl),
             bind: bind,
+            data_version: package.data_version,
             needs_write: true,
         })
     }
@@ -104,6 +110,7 @@ impl ServiceConfig {
         let bind = self.bind.to_toml();
         top.insert(String::from("bind"), bind);
 
+        self.data_version.apply(&mut top);
         Ok(toml::Value::Table(top))
     }
 
@@ -132,8 +139,8 @@ impl ServiceConfig {
     }
 
     /// Replace the `cfg` data.
-    pub fn cfg(&mut self, package: &Package) {
-        match Cfg::new(package) {
+    pub fn cfg(&mut self, service_group: &str, package: &Package) {
+        match Cfg::new(service_group, package) {
             Ok(cfg) => {
                 self.cfg = cfg;
                 self.needs_write = true;
@@ -142,6 +149,25 @@ impl ServiceConfig {
         }
     }
 
+    /// Render the package's templated configuration files with this configuration, returning
+    /// each file's name and contents without writing them.
+    pub fn render(&self, pkg: &Package) -> Result<Vec<(String, String)>> {
+        let final_toml = try!(self.to_toml());
+        // Register all the templates; this makes them available as partials!
+        let mut renderer = ConfigRenderer::new(pkg.template_engine, pkg.strict_render);
+        let config_dir = pkg.config_from().join("config");
+        for config in try!(pkg.config_files()) {
+            let path = config_dir.join(&config);
+            if let Err(e) = renderer.register_file(&config, &path) {
+                outputln!("Error parsing config template file {}: {}",
+                          path.to_string_lossy(),
+                          e);
+                return Err(e);
+            }
+        }
+        renderer.render(&final_toml)
+    }
+
     /// Write the configuration to `config.toml`, and render the templated configuration files.
     pub fn write(&mut self, pkg: &Package) -> Result<bool> {
         let pi = &pkg.pkg_install;
@@ -150,28 +176,12 @@ impl ServiceConfig {
             let mut last_toml = try!(File::create(pi.svc_path().join("config.toml")));
             try!(write!(&mut last_toml, "{}", toml::encode_str(&final_toml)));
         }
-        let mut template = Template::new();
-
-        // Register all the templates; this makes them available as partials!
-        // I suspect this will be useful, but I think we'll want to make this
-        // more explicit... in a minute, we render all the config files anyway.
-        let config_files = try!(pkg.config_files());
-        for config in config_files.iter() {
-            let path = pkg.config_from().join("config").join(config);
-            debug!("Config template {} from {:?}", config, &path);
-            if let Err(e) = template.register_template_file(config, &path) {
-                outputln!("Error parsing config template file {}: {}",
-                          path.to_string_lossy(),
-                          e);
-                return Err(sup_error!(Error::TemplateFileError(e)));
-            }
-        }
+        // Render every template before writing any of them, so a template which fails to render
+        // doesn't leave the service with a mix of old and new configuration files.
+        let rendered = try!(self.render(pkg));
 
-        let final_data = convert::toml_to_json(final_toml);
         let mut should_restart = false;
-        for config in config_files {
-            debug!("Rendering template {}", &config);
-            let template_data = try!(template.render(&config, &final_data));
+        for (config, template_data) in rendered {
             let template_hash = try!(crypto::hash::hash_string(&template_data));
             let filename = pi.svc_config_path().join(&config).to_string_lossy().into_owned();
             let file_hash = match crypto::hash::hash_file(&filename) {
@@ -216,7 +226,13 @@ impl Bind {
         let mut top = toml::Table::new();
         let bindings = try!(Bind::split_bindings(binding_cfg));
         for (bind, service_group) in bindings {
-            match cl.get(&service_group) {
+            // A bind to a service without a group follows whichever of its groups is active.
+            let census = if service_group.split('@').next().map_or(false, |s| s.contains('.')) {
+                cl.get(&service_group)
+            } else {
+                cl.get_active(&service_group)
+            };
+            match census {
                 Some(census) => {
                     top.insert(format!("has_{}", bind), toml::Value::Boolean(true));
                     top.insert(bind, toml::Value::Table(service_entry(census)));
@@ -303,6 +319,11 @@ fn service_entry(census: &Census) -> toml::Table {
     }
     result.insert("members".to_string(), toml::Value::Array(members));
     result.insert("member_id".to_string(), toml::Value::Table(member_id));
+    if let Some(ref color) = census.active_color {
+        result.insert("active_color".to_string(), toml::Value::String(color.clone()));
+        result.insert("active".to_string(),
+                      toml::Value::Boolean(census.is_active().unwrap_or(false)));
+    }
     result
 }
 
@@ -312,20 +333,25 @@ pub struct Cfg {
     pub user: Option<toml::Value>,
     pub gossip: Option<toml::Value>,
     pub environment: Option<toml::Value>,
+    /// Configuration read from AWS Parameter Store and Secrets Manager.
+    #[serde(default)]
+    pub aws: Option<toml::Value>,
 }
 
 impl Cfg {
-    fn new(pkg: &Package) -> Result<Cfg> {
+    fn new(service_group: &str, pkg: &Package) -> Result<Cfg> {
         let mut cfg = Cfg {
             default: None,
             user: None,
             gossip: None,
             environment: None,
+            aws: None,
         };
         try!(cfg.load_default(pkg));
         try!(cfg.load_user(pkg));
         try!(cfg.load_gossip(pkg));
         try!(cfg.load_environment(pkg));
+        cfg.aws = aws_config::layer(service_group).map(toml::Value::Table);
         Ok(cfg)
     }
 
@@ -340,6 +366,9 @@ impl Cfg {
         if let Some(toml::Value::Table(ref user_cfg)) = self.user {
             try!(toml_merge(&mut output_toml, user_cfg));
         }
+        if let Some(toml::Value::Table(ref aws_cfg)) = self.aws {
+            try!(toml_merge(&mut output_toml, aws_cfg));
+        }
         if let Some(toml::Value::Table(ref gossip_cfg)) = self.gossip {
             try!(toml_merge(&mut output_toml, gossip_cfg));
         }
@@ -476,6 +505,9 @@ pub struct Pkg {
     pub svc_files_path: String,
     pub svc_static_path: String,
     pub svc_var_path: String,
+    /// The path of each directory declared in `pkg_svc_dirs`, by name.
+    #[serde(default)]
+    pub svc_dirs: HashMap<String, String>,
     pub svc_user: Option<String>,
     pub svc_group: Option<String>,
     pub svc_user_default: String,
@@ -539,6 +571,15 @@ impl Pkg {
                 }
             };
 
+        let svc_dirs = pkg_install.svc_dirs()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, _)| {
+                let path = pkg_install.svc_path().join(&name);
+                (name, path.to_string_lossy().into_owned())
+            })
+            .collect();
+
         let svc_user = pkg_install.svc_user().unwrap_or(None);
         let svc_group = pkg_install.svc_group().unwrap_or(None);
 
@@ -558,6 +599,7 @@ impl Pkg {
             svc_files_path: pkg_install.svc_files_path().to_string_lossy().into_owned(),
             svc_static_path: pkg_install.svc_static_path().to_string_lossy().into_owned(),
             svc_var_path: pkg_install.svc_var_path().to_string_lossy().into_owned(),
+            svc_dirs: svc_dirs,
             svc_user: svc_user,
             svc_group: svc_group,
             svc_user_default: default_svc_user,
@@ -598,6 +640,7 @@ impl Sys {
             hostname: hostname,
             http_gateway_ip: gconfig().http_listen_addr().ip().to_string(),
             http_gateway_port: gconfig().http_listen_addr().port(),
+            labels: gconfig().labels().clone(),
         })
     }
 
@@ -684,9 +727,11 @@ fn is_toml_value_a_table(key: &str, table: &toml::Table) -> bool {
 
 #[cfg(test)]
 mod test {
+    use std::collections::BTreeMap;
     use std::path::PathBuf;
     use std::str::FromStr;
 
+    use butterfly::rumor::ActiveColor;
     use hcore::package::{PackageIdent, PackageInstall};
     use regex::Regex;
     use toml;
@@ -695,7 +740,8 @@ mod test {
     use config::{gcache, Config};
     use error::Error;
     use manager::census::{CensusEntry, CensusList};
-    use package::Package;
+    use package::{HookInterpreter, HookSandbox, Package};
+    use templating::TemplateEngine;
     use VERSION;
 
     fn gen_pkg() -> Package {
@@ -712,6 +758,14 @@ mod test {
             deps: Vec::new(),
             tdeps: Vec::new(),
             pkg_install: pkg_install,
+            template_engine: TemplateEngine::default(),
+            hook_interpreter: HookInterpreter::default(),
+            hook_sandbox: HookSandbox::default(),
+            data_version: DataVersion::default(),
+            health_probe: None,
+            composite: None,
+            strict_render: false,
+            environment: BTreeMap::new(),
         }
     }
 
@@ -766,6 +820,31 @@ mod test {
         assert!(re.is_match(&ip));
     }
 
+    #[test]
+    fn bind_to_a_service_follows_its_active_color() {
+        gcache(Config::new());
+        let pkg = gen_pkg();
+        let mut cl = gen_census_list();
+        for (member_id, group) in vec![("1", "blue"), ("2", "green")] {
+            let mut ce = CensusEntry::default();
+            ce.set_member_id(member_id.to_string());
+            ce.set_service(String::from("app"));
+            ce.set_group(group.to_string());
+            cl.insert(member_id.to_string(), ce);
+        }
+        let binds = vec![String::from("backend:app")];
+        let sc = ServiceConfig::new("redis.default", &pkg, &cl, binds.clone()).unwrap();
+        let toml = sc.to_toml().unwrap();
+        assert_eq!(toml.lookup("bind.has_backend").unwrap().as_bool(), Some(false));
+
+        cl.populate_from_active_color(&ActiveColor::new("1", "app", "green"));
+        let sc = ServiceConfig::new("redis.default", &pkg, &cl, binds).unwrap();
+        let toml = sc.to_toml().unwrap();
+        assert_eq!(toml.lookup("bind.backend.group").unwrap().as_str(), Some("green"));
+        assert_eq!(toml.lookup("bind.backend.active").unwrap().as_bool(), Some(true));
+        assert_eq!(toml.lookup("svc.active_color"), None);
+    }
+
     #[test]
     fn merge_with_empty_me_table() {
         let mut me = toml_from_string("");
@@ -0,0 +1,223 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Versions of the data exposed to a service's templates and hooks.
+//!
+//! The layout of `sys`, `pkg`, `cfg`, `svc` and `bind` is a contract with every plan ever
+//! written, so it is versioned. A plan declares the version its templates are written for with
+//! `pkg_svc_data_version`; plans which don't declare one get version 1, the layout from before
+//! versioning. When a key is renamed, plans targeting an older version keep finding it under its
+//! old name, and the Supervisor warns about templates which still use it.
+
+use std::fmt;
+use std::result;
+use std::str::FromStr;
+
+use hcore::package::PackageInstall;
+use toml;
+
+use error::{Error, Result, SupError};
+use templating::inputs::TemplateInputs;
+
+static LOGKEY: &'static str = "DV";
+
+/// The newest version of the template data.
+pub const CURRENT_DATA_VERSION: u32 = 2;
+
+/// A key of the template data renamed in a data version. Keys are given as paths from the top of
+/// the data, such as `sys.ip`.
+#[derive(Debug)]
+pub struct Rename {
+    pub version: u32,
+    pub old: &'static str,
+    pub new: &'static str,
+}
+
+/// Every rename, oldest first. The Supervisor's own data uses the old names; the renames are
+/// applied when the data is handed to templates.
+pub const RENAMES: &'static [Rename] = &[Rename {
+                                               version: 2,
+                                               old: "sys.http_gateway_ip",
+                                               new: "sys.http_gateway.ip",
+                                           },
+                                           Rename {
+                                               version: 2,
+                                               old: "sys.http_gateway_port",
+                                               new: "sys.http_gateway.port",
+                                           }];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+pub struct DataVersion(u32);
+
+impl DataVersion {
+    /// Load the data version declared by a package, defaulting to version 1.
+    pub fn load(pkg_install: &PackageInstall) -> Result<Self> {
+        match try!(pkg_install.svc_data_version()) {
+            Some(version) => Self::from_str(&version),
+            None => Ok(DataVersion::default()),
+        }
+    }
+
+    pub fn is_current(&self) -> bool {
+        self.0 == CURRENT_DATA_VERSION
+    }
+
+    /// Lay out the Supervisor's template data the way templates written for this version expect
+    /// it. Keys renamed after this version are available under both their old and new names.
+    pub fn apply(&self, data: &mut toml::Table) {
+        for rename in RENAMES {
+            if let Some(value) = remove(data, rename.old) {
+                if self.0 < rename.version {
+                    insert(data, rename.old, value.clone());
+                }
+                insert(data, rename.new, value);
+            }
+        }
+    }
+
+    /// Returns a warning for each key renamed after this version which is referenced by the given
+    /// templates.
+    pub fn deprecations(&self, inputs: &TemplateInputs) -> Vec<String> {
+        RENAMES.iter()
+            .filter(|r| self.0 < r.version && inputs.references(r.old))
+            .map(|r| {
+                format!("{} is deprecated and was renamed to {} in template data version {}",
+                        r.old,
+                        r.new,
+                        r.version)
+            })
+            .collect()
+    }
+}
+
+impl Default for DataVersion {
+    fn default() -> DataVersion {
+        DataVersion(1)
+    }
+}
+
+impl fmt::Display for DataVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for DataVersion {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.trim().parse::<u32>() {
+            Ok(version) if version >= 1 && version <= CURRENT_DATA_VERSION => {
+                Ok(DataVersion(version))
+            }
+            _ => Err(sup_error!(Error::UnsupportedDataVersion(value.to_string()))),
+        }
+    }
+}
+
+fn remove(data: &mut toml::Table, path: &str) -> Option<toml::Value> {
+    let mut segments: Vec<&str> = path.split('.').collect();
+    let last = segments.pop().unwrap();
+    let mut current = data;
+    for segment in segments {
+        current = match current.get_mut(segment) {
+            Some(&mut toml::Value::Table(ref mut table)) => table,
+            _ => return None,
+        };
+    }
+    current.remove(last)
+}
+
+fn insert(data: &mut toml::Table, path: &str, value: toml::Value) {
+    let mut segments: Vec<&str> = path.split('.').collect();
+    let last = segments.pop().unwrap();
+    let mut current = data;
+    for segment in segments {
+        let parent = current;
+        let entry = parent.entry(segment.to_string())
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+        if entry.as_table().is_none() {
+            *entry = toml::Value::Table(toml::Table::new());
+        }
+        current = match *entry {
+            toml::Value::Table(ref mut table) => table,
+            _ => unreachable!(),
+        };
+    }
+    current.insert(last.to_string(), value);
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use toml;
+
+    use templating::inputs::TemplateInputs;
+    use super::{DataVersion, CURRENT_DATA_VERSION};
+
+    fn data() -> toml::Table {
+        toml::Parser::new(r#"
+            [sys]
+            ip = "10.0.0.1"
+            http_gateway_ip = "0.0.0.0"
+            http_gateway_port = 9631
+            "#)
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn data_version_from_str() {
+        assert_eq!(DataVersion::from_str("1").unwrap(), DataVersion::default());
+        assert!(DataVersion::from_str(&CURRENT_DATA_VERSION.to_string()).unwrap().is_current());
+        assert!(DataVersion::from_str("0").is_err());
+        assert!(DataVersion::from_str(&(CURRENT_DATA_VERSION + 1).to_string()).is_err());
+        assert!(DataVersion::from_str("latest").is_err());
+    }
+
+    #[test]
+    fn apply_current_version_renames() {
+        let mut data = data();
+        DataVersion::from_str("2").unwrap().apply(&mut data);
+        let data = toml::Value::Table(data);
+        assert_eq!(data.lookup("sys.http_gateway.ip").and_then(|v| v.as_str()),
+                   Some("0.0.0.0"));
+        assert_eq!(data.lookup("sys.http_gateway.port").and_then(|v| v.as_integer()),
+                   Some(9631));
+        assert!(data.lookup("sys.http_gateway_ip").is_none());
+        assert_eq!(data.lookup("sys.ip").and_then(|v| v.as_str()), Some("10.0.0.1"));
+    }
+
+    #[test]
+    fn apply_old_version_keeps_old_names() {
+        let mut data = data();
+        DataVersion::default().apply(&mut data);
+        let data = toml::Value::Table(data);
+        assert_eq!(data.lookup("sys.http_gateway_ip").and_then(|v| v.as_str()),
+                   Some("0.0.0.0"));
+        assert_eq!(data.lookup("sys.http_gateway.ip").and_then(|v| v.as_str()),
+                   Some("0.0.0.0"));
+    }
+
+    #[test]
+    fn deprecations_only_for_referenced_keys() {
+        let mut inputs = TemplateInputs::default();
+        inputs.add_source(String::from("listen {{sys.ip}}:{{sys.http_gateway_port}}"));
+        let warnings = DataVersion::default().deprecations(&inputs);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("sys.http_gateway_port"));
+        assert!(DataVersion::from_str("2").unwrap().deprecations(&inputs).is_empty());
+    }
+}
@@ -0,0 +1,438 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Extra directories beneath a service's own which the Supervisor creates and manages.
+//!
+//! Packages declare these with the `pkg_svc_dirs` plan variable, so a plan doesn't need an init
+//! hook to create a scratch or cache directory, own it, and empty it at the right moments. Each
+//! directory may be backed by a tmpfs, capped by a quota, emptied whenever the service starts or
+//! whenever its package is updated, and made the directory the run hook starts in. Naming one
+//! `var` manages the service's own `var` directory.
+//!
+//! A tmpfs quota is enforced by the kernel. Any other directory over its quota has its least
+//! recently modified files removed until it fits, when the service starts and periodically while
+//! it runs, so quotas suit caches rather than state the service can't rebuild.
+//!
+//! The Supervisor clears and prunes these directories as root, while the service's user may
+//! change what's in them at the same time. So nothing beneath a managed directory is followed if
+//! it is a symbolic link: a link is removed itself, never what it points to, and a directory is
+//! checked again for having been replaced with a link just before anything in it is removed.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use hcore::package::PackageInstall;
+use hcore::util::perm::{set_owner, set_permissions};
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "SD";
+
+/// The directories beneath a service's own which the Supervisor manages itself, and so can't be
+/// declared in `pkg_svc_dirs`.
+const RESERVED: &'static [&'static str] = &["config", "data", "files", "hooks", "static", "toml"];
+
+/// When a managed directory is emptied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Clear {
+    Never,
+    /// Whenever the service's process is started.
+    Restart,
+    /// Whenever the service's package is updated.
+    Update,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ManagedDir {
+    /// The directory's name beneath the service's own directory.
+    pub name: String,
+    /// Whether the directory is backed by memory rather than disk.
+    pub tmpfs: bool,
+    /// The most bytes the directory may hold.
+    pub quota: Option<u64>,
+    pub clear: Clear,
+    /// Whether the run hook starts in this directory.
+    pub workdir: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ServiceDirs {
+    pub dirs: Vec<ManagedDir>,
+    /// Set when the service's package has been updated, until its process is next started.
+    pub updated: bool,
+}
+
+impl ServiceDirs {
+    /// Load the directories declared by a package. A package which declares none has none
+    /// managed.
+    pub fn load(pkg_install: &PackageInstall) -> Result<Self> {
+        let dirs = try!(pkg_install.svc_dirs());
+        Self::from_metadata(dirs)
+    }
+
+    fn from_metadata(raw: HashMap<String, String>) -> Result<Self> {
+        let mut dirs = Vec::new();
+        for (name, options) in raw.into_iter() {
+            if name.is_empty() || RESERVED.contains(&name.as_str()) ||
+               !name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-') {
+                return Err(sup_error!(Error::InvalidServiceDirs(format!("{} can't be a managed \
+                                                                         directory",
+                                                                        name))));
+            }
+            let mut dir = ManagedDir {
+                name: name,
+                tmpfs: false,
+                quota: None,
+                clear: Clear::Never,
+                workdir: false,
+            };
+            for option in options.split_whitespace() {
+                match option {
+                    "tmpfs" => dir.tmpfs = true,
+                    "workdir" => dir.workdir = true,
+                    "clear=restart" => dir.clear = Clear::Restart,
+                    "clear=update" => dir.clear = Clear::Update,
+                    o if o.starts_with("quota=") => dir.quota = Some(try!(parse_size(&o[6..]))),
+                    o => {
+                        return Err(sup_error!(Error::InvalidServiceDirs(format!("unknown \
+                                                                                 option {} for \
+                                                                                 {}",
+                                                                                o,
+                                                                                dir.name))))
+                    }
+                }
+            }
+            dirs.push(dir);
+        }
+        if dirs.iter().filter(|d| d.workdir).count() > 1 {
+            return Err(sup_error!(Error::InvalidServiceDirs(String::from("only one directory \
+                                                                          can be the workdir"))));
+        }
+        dirs.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(ServiceDirs {
+            dirs: dirs,
+            updated: false,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dirs.is_empty()
+    }
+
+    /// Returns the directory the run hook starts in, if one was declared.
+    pub fn workdir(&self, svc_path: &Path) -> Option<PathBuf> {
+        self.dirs.iter().find(|d| d.workdir).map(|d| svc_path.join(&d.name))
+    }
+
+    /// Readies the directories for the service's process to start: creates them, backs them with
+    /// a tmpfs, gives them to the service's user, and empties or prunes them as declared.
+    pub fn prepare(&mut self,
+                   svc_path: &Path,
+                   user: &str,
+                   group: &str,
+                   service_group: &str)
+                   -> Result<()> {
+        for dir in self.dirs.iter() {
+            let path = svc_path.join(&dir.name);
+            try!(fs::create_dir_all(&path));
+            if dir.tmpfs && !try!(is_tmpfs(&path)) {
+                if let Err(e) = mount_tmpfs(&path, dir.quota) {
+                    let path = path.display().to_string();
+                    return Err(sup_error!(Error::ServiceDirMountFailed(path, e)));
+                }
+            }
+            try!(set_owner(&path, user, group));
+            try!(set_permissions(&path, 0o700));
+            let clear = match dir.clear {
+                Clear::Never => false,
+                Clear::Restart => true,
+                Clear::Update => self.updated,
+            };
+            if clear {
+                outputln!(preamble service_group, "Clearing {}", path.display());
+                try!(clear_dir(&path));
+            }
+        }
+        self.updated = false;
+        self.enforce_quotas(svc_path, service_group);
+        Ok(())
+    }
+
+    /// Prunes each directory on disk which has grown past its quota.
+    pub fn enforce_quotas(&self, svc_path: &Path, service_group: &str) {
+        for dir in self.dirs.iter() {
+            let quota = match dir.quota {
+                Some(quota) => quota,
+                None => continue,
+            };
+            let path = svc_path.join(&dir.name);
+            if dir.tmpfs && is_tmpfs(&path).unwrap_or(false) {
+                continue;
+            }
+            match prune(&path, quota) {
+                Ok(0) => {}
+                Ok(removed) => {
+                    outputln!(preamble service_group,
+                              "Removed {} files from {} to keep it within its quota of {} bytes",
+                              removed,
+                              path.display(),
+                              quota)
+                }
+                Err(e) => {
+                    outputln!(preamble service_group,
+                              "Couldn't keep {} within its quota: {}",
+                              path.display(),
+                              e)
+                }
+            }
+        }
+    }
+}
+
+/// Parses a size such as `512`, `64K`, `256M` or `2G` into bytes.
+fn parse_size(size: &str) -> Result<u64> {
+    let (digits, multiplier) = match size.chars().last() {
+        Some('K') | Some('k') => (&size[..size.len() - 1], 1024),
+        Some('M') | Some('m') => (&size[..size.len() - 1], 1024 * 1024),
+        Some('G') | Some('g') => (&size[..size.len() - 1], 1024 * 1024 * 1024),
+        _ => (size, 1),
+    };
+    match digits.parse::<u64>() {
+        Ok(n) => Ok(n * multiplier),
+        Err(_) => {
+            Err(sup_error!(Error::InvalidServiceDirs(format!("quota {} is not a size", size))))
+        }
+    }
+}
+
+/// Removes everything in `path`, leaving the directory itself. Links are removed, not followed.
+fn clear_dir(path: &Path) -> io::Result<()> {
+    try!(ensure_real_dir(path));
+    for entry in try!(fs::read_dir(path)) {
+        try!(remove_tree(&try!(entry).path()));
+    }
+    Ok(())
+}
+
+/// Removes `path`, and everything beneath it if it is a directory, without following links.
+/// Unlike `fs::remove_dir_all`, a directory replaced with a link while it is being emptied is
+/// caught before anything is removed through the link.
+fn remove_tree(path: &Path) -> io::Result<()> {
+    if !try!(fs::symlink_metadata(path)).file_type().is_dir() {
+        return fs::remove_file(path);
+    }
+    for entry in try!(fs::read_dir(path)) {
+        let entry = try!(entry);
+        try!(ensure_real_dir(path));
+        try!(remove_tree(&entry.path()));
+    }
+    fs::remove_dir(path)
+}
+
+/// Fails unless `path` is a directory itself rather than a link to one.
+fn ensure_real_dir(path: &Path) -> io::Result<()> {
+    if try!(fs::symlink_metadata(path)).file_type().is_dir() {
+        Ok(())
+    } else {
+        Err(io::Error::new(io::ErrorKind::Other,
+                           format!("{} is not a directory, or is a link", path.display())))
+    }
+}
+
+/// Removes the least recently modified files beneath `path` until the rest fit within `quota`
+/// bytes, and returns how many were removed. Links count as files of their own size, and are
+/// never followed.
+fn prune(path: &Path, quota: u64) -> io::Result<usize> {
+    try!(ensure_real_dir(path));
+    let mut files = Vec::new();
+    try!(collect_files(path, &mut files));
+    let mut total: u64 = files.iter().map(|&(_, len, _)| len).sum();
+    if total <= quota {
+        return Ok(0);
+    }
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut removed = 0;
+    for (_, len, file) in files.into_iter() {
+        if total <= quota {
+            break;
+        }
+        // The service may have swapped a directory we walked for a link since; don't remove
+        // anything through one.
+        if let Some(parent) = file.parent() {
+            try!(ensure_real_dirs_between(path, parent));
+        }
+        try!(fs::remove_file(&file));
+        total -= len;
+        removed += 1;
+    }
+    Ok(removed)
+}
+
+/// Fails unless each directory from `root` down to `dir` is a directory rather than a link.
+fn ensure_real_dirs_between(root: &Path, dir: &Path) -> io::Result<()> {
+    let mut current = dir;
+    while current.starts_with(root) {
+        try!(ensure_real_dir(current));
+        match current.parent() {
+            Some(parent) => current = parent,
+            None => break,
+        }
+    }
+    Ok(())
+}
+
+fn collect_files(path: &Path, files: &mut Vec<(SystemTime, u64, PathBuf)>) -> io::Result<()> {
+    for entry in try!(fs::read_dir(path)) {
+        let entry = try!(entry);
+        let metadata = try!(fs::symlink_metadata(entry.path()));
+        if metadata.file_type().is_dir() {
+            try!(collect_files(&entry.path(), files));
+        } else {
+            files.push((try!(metadata.modified()), metadata.len(), entry.path()));
+        }
+    }
+    Ok(())
+}
+
+/// Returns whether a tmpfs is mounted at `path`.
+#[cfg(target_os = "linux")]
+fn is_tmpfs(path: &Path) -> Result<bool> {
+    use std::io::Read;
+
+    let mut table = String::new();
+    try!(try!(fs::File::open("/proc/self/mounts")).read_to_string(&mut table));
+    let path = path.to_string_lossy();
+    Ok(table.lines().any(|line| {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        fields.len() > 2 && fields[1] == path && fields[2] == "tmpfs"
+    }))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn is_tmpfs(_path: &Path) -> Result<bool> {
+    Ok(false)
+}
+
+/// Mounts a tmpfs of at most `quota` bytes at `path`, which needs root.
+#[cfg(target_os = "linux")]
+fn mount_tmpfs(path: &Path, quota: Option<u64>) -> io::Result<()> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+    use libc;
+
+    let target = try!(CString::new(path.as_os_str().as_bytes()).map_err(io::Error::from));
+    let fstype = CString::new("tmpfs").unwrap();
+    let options = match quota {
+        Some(quota) => format!("mode=0700,size={}", quota),
+        None => String::from("mode=0700"),
+    };
+    let options = try!(CString::new(options).map_err(io::Error::from));
+    let flags = libc::MS_NOSUID | libc::MS_NODEV;
+    let rc = unsafe {
+        libc::mount(fstype.as_ptr(),
+                    target.as_ptr(),
+                    fstype.as_ptr(),
+                    flags,
+                    options.as_ptr() as *const libc::c_void)
+    };
+    if rc != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn mount_tmpfs(_path: &Path, _quota: Option<u64>) -> io::Result<()> {
+    Err(io::Error::new(io::ErrorKind::Other, "tmpfs is only supported on Linux"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::fs::{self, File};
+    use std::io::Write;
+
+    use tempdir::TempDir;
+
+    use super::{clear_dir, parse_size, prune, Clear, ServiceDirs};
+
+    fn raw(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|&(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn dirs_from_metadata() {
+        let dirs = ServiceDirs::from_metadata(raw(&[("var", "tmpfs quota=64M clear=restart"),
+                                                    ("cache", "quota=2G clear=update workdir")]))
+            .unwrap();
+        assert_eq!(dirs.dirs.len(), 2);
+        assert_eq!(dirs.dirs[0].name, "cache");
+        assert_eq!(dirs.dirs[0].quota, Some(2 * 1024 * 1024 * 1024));
+        assert_eq!(dirs.dirs[0].clear, Clear::Update);
+        assert!(dirs.dirs[0].workdir);
+        assert_eq!(dirs.dirs[1].name, "var");
+        assert!(dirs.dirs[1].tmpfs);
+        assert_eq!(dirs.dirs[1].clear, Clear::Restart);
+    }
+
+    #[test]
+    fn invalid_dirs() {
+        assert!(ServiceDirs::from_metadata(raw(&[("config", "")])).is_err());
+        assert!(ServiceDirs::from_metadata(raw(&[("../etc", "")])).is_err());
+        assert!(ServiceDirs::from_metadata(raw(&[("cache", "clear=never")])).is_err());
+        assert!(ServiceDirs::from_metadata(raw(&[("a", "workdir"), ("b", "workdir")])).is_err());
+        assert!(parse_size("lots").is_err());
+        assert_eq!(parse_size("512").unwrap(), 512);
+        assert_eq!(parse_size("64K").unwrap(), 64 * 1024);
+    }
+
+    #[test]
+    fn prune_keeps_dirs_within_their_quota() {
+        let tmp = TempDir::new("svc-dirs").unwrap();
+        fs::create_dir(tmp.path().join("nested")).unwrap();
+        for name in &["a", "nested/b"] {
+            File::create(tmp.path().join(name)).unwrap().write_all(&[0; 100]).unwrap();
+        }
+        assert_eq!(prune(tmp.path(), 200).unwrap(), 0);
+        assert_eq!(prune(tmp.path(), 150).unwrap(), 1);
+        assert!(tmp.path().join("a").exists() != tmp.path().join("nested/b").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn links_are_removed_not_followed() {
+        use std::os::unix::fs::symlink;
+
+        let outside = TempDir::new("svc-dirs-outside").unwrap();
+        File::create(outside.path().join("keep")).unwrap().write_all(&[0; 100]).unwrap();
+        let tmp = TempDir::new("svc-dirs").unwrap();
+        symlink(outside.path(), tmp.path().join("link")).unwrap();
+        File::create(tmp.path().join("a")).unwrap().write_all(&[0; 100]).unwrap();
+
+        assert_eq!(prune(tmp.path(), 0).unwrap(), 2);
+        assert!(outside.path().join("keep").exists());
+
+        symlink(outside.path(), tmp.path().join("link")).unwrap();
+        clear_dir(tmp.path()).unwrap();
+        assert!(outside.path().join("keep").exists());
+        assert!(fs::read_dir(tmp.path()).unwrap().next().is_none());
+
+        assert!(clear_dir(&tmp.path().join("missing")).is_err());
+        symlink(outside.path(), tmp.path().join("link")).unwrap();
+        assert!(clear_dir(&tmp.path().join("link")).is_err());
+    }
+}
@@ -0,0 +1,317 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dry runs of configuration changes, as made by `hab config apply --dry-run`.
+//!
+//! A dry run renders a service's configuration files with a proposed configuration into a scratch
+//! directory, diffs them against the files the service is running with, and runs the package's
+//! `validate_config` hook against them. Nothing the service reads is written, and nothing is
+//! gossiped, so the service group carries on as it was whatever the outcome.
+
+use std::cmp;
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::time::Duration;
+
+use butterfly::rumor::service_config::apply_patch;
+use hcore::service::ServiceGroup;
+use hcore::util::perm::set_owner;
+use tempdir::TempDir;
+use toml;
+
+use error::Result;
+use package::Package;
+use super::config::ServiceConfig;
+use util::users as hab_users;
+
+/// Unchanged lines shown around each change in a diff.
+const CONTEXT_LINES: usize = 3;
+
+/// How long the `validate_config` hook may run before it's killed and the configuration is
+/// considered invalid.
+const VALIDATE_TIMEOUT_SECS: u64 = 30;
+
+/// Largest number of old lines times new lines diffed line by line. Files past it are shown as
+/// replaced whole, rather than building a table that size.
+const MAX_DIFF_CELLS: usize = 1_000_000;
+
+/// The outcome of a dry run.
+#[derive(Debug, Serialize)]
+pub struct DryRun {
+    /// Whether the configuration rendered and, if the package has a `validate_config` hook, the
+    /// hook accepted it.
+    pub valid: bool,
+    /// Each configuration file the proposed configuration renders.
+    pub files: Vec<FileDiff>,
+    /// How the `validate_config` hook exited, if the package has one.
+    pub validation: Option<Validation>,
+    /// Why the configuration couldn't be rendered, if it couldn't.
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FileDiff {
+    pub name: String,
+    pub changed: bool,
+    /// The changes to the file as a unified diff, without file headers.
+    pub diff: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Validation {
+    pub exit_code: i32,
+    /// What the hook wrote to stdout and stderr, redacted.
+    pub output: String,
+}
+
+/// Renders `svc_cfg` with `config` in place of the configuration from gossip, or patched over it
+/// if `patch` is set, and validates the result.
+///
+/// # Failures
+///
+/// * The scratch directory can't be created or written to
+/// * The `validate_config` hook can't be rendered or started
+pub fn dry_run(service_group: &ServiceGroup,
+               package: &Package,
+               mut svc_cfg: ServiceConfig,
+               config: toml::Table,
+               patch: bool)
+               -> Result<DryRun> {
+    let proposed = if patch {
+        let mut current = match svc_cfg.cfg.gossip {
+            Some(toml::Value::Table(ref current)) => current.clone(),
+            _ => toml::Table::new(),
+        };
+        apply_patch(&mut current, config);
+        current
+    } else {
+        config
+    };
+    svc_cfg.cfg.gossip = Some(toml::Value::Table(proposed));
+    let rendered = match svc_cfg.render(package) {
+        Ok(rendered) => rendered,
+        Err(e) => {
+            return Ok(DryRun {
+                valid: false,
+                files: vec![],
+                validation: None,
+                error: Some(e.to_string()),
+            })
+        }
+    };
+
+    let config_path = package.pkg_install.svc_config_path();
+    let files = rendered.iter()
+        .map(|&(ref name, ref contents)| {
+            let mut current = String::new();
+            if let Err(e) = File::open(config_path.join(name))
+                .and_then(|mut f| f.read_to_string(&mut current)) {
+                debug!("Cannot read {} to diff it, diffing against nothing: {}", name, e);
+            }
+            FileDiff {
+                name: name.clone(),
+                changed: current != *contents,
+                diff: diff(&current, contents),
+            }
+        })
+        .collect();
+
+    // The hook runs as the service's user, so the scratch directory is made in, and owned like,
+    // the service's own directories.
+    let (user, group) = try!(hab_users::get_user_and_group(&package.pkg_install));
+    let scratch = try!(TempDir::new_in(package.pkg_install.svc_var_path(), "dry-run"));
+    let config_dir = scratch.path().join("config");
+    try!(fs::create_dir(&config_dir));
+    for &(ref name, ref contents) in rendered.iter() {
+        let path = config_dir.join(name);
+        try!(try!(File::create(&path)).write_all(contents.as_bytes()));
+        try!(set_owner(&path, &user, &group));
+    }
+    try!(set_owner(&config_dir, &user, &group));
+    try!(set_owner(scratch.path(), &user, &group));
+    let validation = try!(package.validate_config(service_group,
+                                                  &svc_cfg,
+                                                  &config_dir,
+                                                  scratch.path(),
+                                                  Duration::from_secs(VALIDATE_TIMEOUT_SECS)))
+        .map(|(exit_code, output)| {
+            Validation {
+                exit_code: exit_code,
+                output: output,
+            }
+        });
+    Ok(DryRun {
+        valid: validation.as_ref().map_or(true, |v| v.exit_code == 0),
+        files: files,
+        validation: validation,
+        error: None,
+    })
+}
+
+enum Edit<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Returns the fewest lines to remove from `old` and add to make `new`, in order, from their
+/// longest common subsequence. Files too large for the quadratic table are replaced whole.
+fn edits<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<Edit<'a>> {
+    if old.len().saturating_mul(new.len()) > MAX_DIFF_CELLS {
+        let mut edits: Vec<Edit<'a>> = old.iter().map(|&line| Edit::Removed(line)).collect();
+        edits.extend(new.iter().map(|&line| Edit::Added(line)));
+        return edits;
+    }
+    // lcs[i][j] is the length of the longest common subsequence of old[i..] and new[j..].
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                cmp::max(lcs[i + 1][j], lcs[i][j + 1])
+            };
+        }
+    }
+    let mut edits = Vec::with_capacity(cmp::max(old.len(), new.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            edits.push(Edit::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            edits.push(Edit::Removed(old[i]));
+            i += 1;
+        } else {
+            edits.push(Edit::Added(new[j]));
+            j += 1;
+        }
+    }
+    edits.extend(old[i..].iter().map(|&line| Edit::Removed(line)));
+    edits.extend(new[j..].iter().map(|&line| Edit::Added(line)));
+    edits
+}
+
+/// Returns the changes from `old` to `new` as a unified diff of their lines, or an empty string if
+/// they're the same.
+pub fn diff(old: &str, new: &str) -> String {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let edits = edits(&old, &new);
+
+    // Each hunk is the range of edits within CONTEXT_LINES of a change, overlapping ranges merged.
+    let mut hunks: Vec<(usize, usize)> = vec![];
+    for (k, edit) in edits.iter().enumerate() {
+        if let Edit::Same(_) = *edit {
+            continue;
+        }
+        let start = k.saturating_sub(CONTEXT_LINES);
+        let end = cmp::min(edits.len(), k + CONTEXT_LINES + 1);
+        if hunks.last().map_or(false, |&(_, last_end)| start <= last_end) {
+            hunks.last_mut().unwrap().1 = end;
+        } else {
+            hunks.push((start, end));
+        }
+    }
+
+    // The line of the old and new files each edit starts at.
+    let mut lines = Vec::with_capacity(edits.len());
+    let (mut old_line, mut new_line) = (1, 1);
+    for edit in edits.iter() {
+        lines.push((old_line, new_line));
+        match *edit {
+            Edit::Same(_) => {
+                old_line += 1;
+                new_line += 1;
+            }
+            Edit::Removed(_) => old_line += 1,
+            Edit::Added(_) => new_line += 1,
+        }
+    }
+
+    let mut out = String::new();
+    for (start, end) in hunks {
+        let hunk = &edits[start..end];
+        let old_count = hunk.iter().filter(|e| !is_added(e)).count();
+        let new_count = hunk.iter().filter(|e| !is_removed(e)).count();
+        // A side the hunk has no lines of is numbered by the line before it, as `diff -u` does.
+        let (mut old_start, mut new_start) = lines[start];
+        if old_count == 0 {
+            old_start -= 1;
+        }
+        if new_count == 0 {
+            new_start -= 1;
+        }
+        out.push_str(&format!("@@ -{},{} +{},{} @@\n",
+                              old_start,
+                              old_count,
+                              new_start,
+                              new_count));
+        for edit in hunk {
+            let (sign, line) = match *edit {
+                Edit::Same(line) => (' ', line),
+                Edit::Removed(line) => ('-', line),
+                Edit::Added(line) => ('+', line),
+            };
+            out.push(sign);
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+fn is_added(edit: &Edit) -> bool {
+    match *edit {
+        Edit::Added(_) => true,
+        _ => false,
+    }
+}
+
+fn is_removed(edit: &Edit) -> bool {
+    match *edit {
+        Edit::Removed(_) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::diff;
+
+    #[test]
+    fn same_files_have_no_diff() {
+        assert_eq!(diff("port = 6379\n", "port = 6379\n"), "");
+        assert_eq!(diff("", ""), "");
+    }
+
+    #[test]
+    fn diffs_show_changes_with_context() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\n";
+        let new = "a\nb\nc\nd\nE\nf\ng\nh\ni\nj\nk\n";
+        assert_eq!(diff(old, new),
+                   "@@ -2,9 +2,10 @@\n b\n c\n d\n-e\n+E\n f\n g\n h\n i\n j\n+k\n");
+        assert_eq!(diff("", "port = 6380\n"), "@@ -0,0 +1,1 @@\n+port = 6380\n");
+    }
+
+    #[test]
+    fn large_files_are_replaced_whole() {
+        let old: String = (0..2000).map(|i| format!("{}\n", i)).collect();
+        let new: String = (1..2001).map(|i| format!("{}\n", i)).collect();
+        let diff = diff(&old, &new);
+        assert!(diff.starts_with("@@ -1,2000 +1,2000 @@\n-0\n"));
+        assert_eq!(diff.lines().filter(|l| l.starts_with('-')).count(), 2000);
+    }
+}
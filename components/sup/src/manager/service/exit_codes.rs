@@ -0,0 +1,142 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Maps the exit code of a service's process to the action the Supervisor takes next.
+//!
+//! Packages declare these mappings with the `pkg_svc_exit_codes` plan variable. Any exit code
+//! which isn't declared, along with a process killed by a signal, is treated as a failure and the
+//! service is restarted - which is the behaviour of a service that declares no mappings at all.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use hcore::package::PackageInstall;
+
+use error::{Error, Result, SupError};
+
+static LOGKEY: &'static str = "EC";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ExitAction {
+    /// The service finished its work; do not restart it.
+    Complete,
+    /// The service asked for its configuration to be re-rendered before it is restarted.
+    Reload,
+    /// The service failed and should be restarted.
+    Restart,
+}
+
+impl fmt::Display for ExitAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let action = match *self {
+            ExitAction::Complete => "complete",
+            ExitAction::Reload => "reload",
+            ExitAction::Restart => "restart",
+        };
+        write!(f, "{}", action)
+    }
+}
+
+impl FromStr for ExitAction {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "complete" => Ok(ExitAction::Complete),
+            "reload" => Ok(ExitAction::Reload),
+            "restart" => Ok(ExitAction::Restart),
+            _ => Err(sup_error!(Error::InvalidExitCodes(format!("unknown action {}", value)))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExitCodes(HashMap<i32, ExitAction>);
+
+impl ExitCodes {
+    /// Load the exit code mappings declared by a package. A package without mappings restarts on
+    /// every exit.
+    pub fn load(pkg_install: &PackageInstall) -> Result<Self> {
+        let raw = try!(pkg_install.svc_exit_codes());
+        Self::from_map(raw)
+    }
+
+    fn from_map(raw: HashMap<String, String>) -> Result<Self> {
+        let mut codes = HashMap::new();
+        for (code, action) in raw.into_iter() {
+            let code = try!(code.parse::<i32>().map_err(|_| {
+                sup_error!(Error::InvalidExitCodes(format!("exit code {} is not a number", code)))
+            }));
+            codes.insert(code, try!(ExitAction::from_str(&action)));
+        }
+        Ok(ExitCodes(codes))
+    }
+
+    /// Returns the action for a process which exited with `code`. A `None` code means the process
+    /// was terminated by a signal.
+    pub fn action_for(&self, code: Option<i32>) -> ExitAction {
+        code.and_then(|c| self.0.get(&c).cloned()).unwrap_or(ExitAction::Restart)
+    }
+
+    /// Treat a zero exit code as `ExitAction::Complete` unless the package maps it explicitly.
+    pub fn complete_on_success(&mut self) {
+        self.0.entry(0).or_insert(ExitAction::Complete);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{ExitAction, ExitCodes};
+
+    fn raw(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|&(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn default_restarts_on_any_exit() {
+        let codes = ExitCodes::default();
+        assert_eq!(codes.action_for(Some(0)), ExitAction::Restart);
+        assert_eq!(codes.action_for(Some(1)), ExitAction::Restart);
+        assert_eq!(codes.action_for(None), ExitAction::Restart);
+    }
+
+    #[test]
+    fn mapped_codes() {
+        let codes = ExitCodes::from_map(raw(&[("0", "complete"), ("3", "reload")])).unwrap();
+        assert_eq!(codes.action_for(Some(0)), ExitAction::Complete);
+        assert_eq!(codes.action_for(Some(3)), ExitAction::Reload);
+        assert_eq!(codes.action_for(Some(2)), ExitAction::Restart);
+        assert_eq!(codes.action_for(None), ExitAction::Restart);
+    }
+
+    #[test]
+    fn complete_on_success() {
+        let mut codes = ExitCodes::default();
+        codes.complete_on_success();
+        assert_eq!(codes.action_for(Some(0)), ExitAction::Complete);
+
+        let mut codes = ExitCodes::from_map(raw(&[("0", "reload")])).unwrap();
+        codes.complete_on_success();
+        assert_eq!(codes.action_for(Some(0)), ExitAction::Reload);
+    }
+
+    #[test]
+    fn invalid_mappings() {
+        assert!(ExitCodes::from_map(raw(&[("zero", "complete")])).is_err());
+        assert!(ExitCodes::from_map(raw(&[("0", "explode")])).is_err());
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The file mode creation mask and resource limits a service's process is started with.
+//!
+//! Packages declare these with the `pkg_svc_umask` and `pkg_svc_limits` plan variables, so a
+//! database can raise its open file limit without wrapping its run hook in a script which calls
+//! `ulimit`. They apply to the run hook only; the other hooks inherit the Supervisor's own, unless
+//! the package sandboxes them with `limits`.
+
+use std::collections::HashMap;
+use std::process::Command;
+
+use hcore::package::PackageInstall;
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "PL";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ProcessLimits {
+    pub umask: Option<u32>,
+    /// The maximum number of open files, or `u64::max_value()` for no limit.
+    pub nofile: Option<u64>,
+    /// The maximum number of processes for the service's user, or `u64::max_value()` for no limit.
+    pub nproc: Option<u64>,
+}
+
+impl ProcessLimits {
+    /// Load the umask and limits declared by a package. A package which declares neither starts
+    /// its service with the Supervisor's own.
+    pub fn load(pkg_install: &PackageInstall) -> Result<Self> {
+        let umask = try!(pkg_install.svc_umask());
+        let limits = try!(pkg_install.svc_limits());
+        Self::from_metadata(umask, limits)
+    }
+
+    fn from_metadata(umask: Option<String>, limits: HashMap<String, String>) -> Result<Self> {
+        let mut process_limits = ProcessLimits::default();
+        if let Some(umask) = umask {
+            let mask = try!(u32::from_str_radix(umask.trim(), 8).map_err(|_| {
+                sup_error!(Error::InvalidProcessLimits(format!("umask {} is not an octal \
+                                                                number",
+                                                               umask)))
+            }));
+            if mask > 0o777 {
+                return Err(sup_error!(Error::InvalidProcessLimits(format!("umask {} is larger \
+                                                                           than 0777",
+                                                                          umask))));
+            }
+            process_limits.umask = Some(mask);
+        }
+        for (name, value) in limits.into_iter() {
+            let parsed = if value == "unlimited" {
+                u64::max_value()
+            } else {
+                try!(value.parse::<u64>().map_err(|_| {
+                    sup_error!(Error::InvalidProcessLimits(format!("{} limit {} is not a number",
+                                                                   name,
+                                                                   value)))
+                }))
+            };
+            match name.as_str() {
+                "nofile" => process_limits.nofile = Some(parsed),
+                "nproc" => process_limits.nproc = Some(parsed),
+                _ => {
+                    return Err(sup_error!(Error::InvalidProcessLimits(format!("unknown limit {}",
+                                                                              name))))
+                }
+            }
+        }
+        Ok(process_limits)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == ProcessLimits::default()
+    }
+
+    /// Prepares `cmd` to start its process with these limits. Call it before dropping the process
+    /// to the service's user, so that a hard limit may be raised.
+    #[cfg(unix)]
+    pub fn apply(&self, cmd: &mut Command) -> Result<()> {
+        use hcore::os::process::{self, Limit};
+
+        if self.is_empty() {
+            return Ok(());
+        }
+        let mut limits = Vec::new();
+        if let Some(nofile) = self.nofile {
+            limits.push((Limit::OpenFiles, nofile));
+        }
+        if let Some(nproc) = self.nproc {
+            limits.push((Limit::Processes, nproc));
+        }
+        try!(process::set_limits(cmd, self.umask, &limits));
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    pub fn apply(&self, _cmd: &mut Command) -> Result<()> {
+        if !self.is_empty() {
+            outputln!("Ignoring the service's umask and resource limits, which are not supported \
+                       on Windows");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::ProcessLimits;
+
+    fn raw(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|&(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn default_is_empty() {
+        let limits = ProcessLimits::from_metadata(None, HashMap::new()).unwrap();
+        assert!(limits.is_empty());
+    }
+
+    #[test]
+    fn umask_and_limits() {
+        let limits = ProcessLimits::from_metadata(Some("0027".to_string()),
+                                                  raw(&[("nofile", "65536"),
+                                                        ("nproc", "unlimited")]))
+            .unwrap();
+        assert_eq!(limits.umask, Some(0o027));
+        assert_eq!(limits.nofile, Some(65536));
+        assert_eq!(limits.nproc, Some(u64::max_value()));
+    }
+
+    #[test]
+    fn invalid_limits() {
+        assert!(ProcessLimits::from_metadata(Some("0089".to_string()), HashMap::new()).is_err());
+        assert!(ProcessLimits::from_metadata(Some("01777".to_string()), HashMap::new()).is_err());
+        assert!(ProcessLimits::from_metadata(None, raw(&[("nofile", "lots")])).is_err());
+        assert!(ProcessLimits::from_metadata(None, raw(&[("core", "0")])).is_err());
+    }
+}
@@ -13,6 +13,12 @@
 // limitations under the License.
 
 pub mod config;
+pub mod data_version;
+pub mod dirs;
+pub mod dry_run;
+pub mod exit_codes;
+pub mod limits;
+pub mod network;
 
 use std;
 use std::collections::HashMap;
@@ -21,24 +27,52 @@ use std::fs::File;
 use std::io::prelude::*;
 
 use ansi_term::Colour::{Yellow, Red, Green};
+use butterfly::rumor::service_config::apply_patch;
 use hcore::service::ServiceGroup;
 use hcore::crypto::hash;
 use hcore::fs;
+use hcore::os::process::Confinement;
+use hcore::package::PackageIdent;
 use hcore::util::perm::{set_owner, set_permissions};
+use serde_json;
+use time::{SteadyTime, Duration as TimeDuration};
 use toml;
 
 pub use self::config::ServiceConfig;
+use self::config::{Bind, Svc};
+use self::data_version::CURRENT_DATA_VERSION;
+pub use self::dirs::ServiceDirs;
+pub use self::exit_codes::{ExitAction, ExitCodes};
+pub use self::limits::ProcessLimits;
+pub use self::network::{Namespace, PortForwarder, PortMapping};
 use config::gconfig;
 use error::Result;
 use health_check;
+use manager::schedule::Schedule;
+use manager::scheduler::{HookTask, Scheduler};
 use manager::signals;
-use manager::census::CensusList;
+use manager::census::{self, CensusList, Provisioning};
 use package::Package;
-use supervisor::{Supervisor, RuntimeConfig};
-use util;
+use service_history;
+use supervisor::{ProcessState, Supervisor, RuntimeConfig};
+use templating::inputs::TemplateInputs;
+use util::{self, convert};
 
 static LOGKEY: &'static str = "SR";
 
+/// How often a running service's managed directories are checked against their quotas.
+const DIRS_CHECK_INTERVAL_SECS: i64 = 60;
+/// How long a release the service was updated to must stay up before it is known to work.
+const UPDATE_PROVEN_SECS: i64 = 60;
+
+/// The data written to a service's census file for its hooks to read.
+#[derive(Serialize)]
+struct CensusFile<'a> {
+    census: &'a CensusList,
+    svc: &'a Svc,
+    bind: &'a Bind,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 pub enum LastRestartDisplay {
     None,
@@ -53,39 +87,241 @@ pub struct Service {
     pub package: Package,
     pub cfg_incarnation: u64,
     pub service_group: ServiceGroup,
+    /// The service's binds, each as `name:service_group`.
+    pub binds: Vec<String>,
     pub topology: Topology,
     pub update_strategy: UpdateStrategy,
+    pub kind: ServiceKind,
+    pub schedule: Option<Schedule>,
+    /// Set when a scheduled oneshot service is due to run.
+    pub run_due: bool,
+    pub scheduler: Scheduler,
     pub current_service_files: HashMap<String, u64>,
     pub initialized: bool,
     pub last_restart_display: LastRestartDisplay,
     pub supervisor: Supervisor,
+    #[serde(skip_serializing)]
+    pub exit_codes: ExitCodes,
+    /// Set when the service's process exited with a code mapped to `ExitAction::Complete`.
+    pub completed: bool,
+    /// Set when the service's process exited with a code mapped to `ExitAction::Reload`.
+    pub needs_reconfigure: bool,
+    /// Set when the service's configuration or hooks failed to render in strict mode.
+    pub render_failed: bool,
+    /// Fingerprint of the templates and template data the service was last rendered with.
+    #[serde(skip_serializing)]
+    template_fingerprint: Option<String>,
+    /// When the census last changed, if the service hasn't been reconfigured since.
+    #[serde(skip_serializing)]
+    census_changed_at: Option<SteadyTime>,
+    /// The service group's provisioning, desired count and alive members when last checked.
+    #[serde(skip_serializing)]
+    last_provisioning: Option<(Provisioning, u64, u64)>,
+    /// The running provisioning hook.
+    #[serde(skip_serializing)]
+    provisioning_hook: Option<HookTask>,
+    /// When the service's managed directories were last checked against their quotas.
+    #[serde(skip_serializing)]
+    dirs_checked_at: Option<SteadyTime>,
+    /// The release the service was updated from, until the updated release's
+    /// `post_update_restore` hook has succeeded.
+    #[serde(skip_serializing)]
+    previous_release: Option<Package>,
+    /// Set from stopping the service's process for the `post_update_restore` hook until the hook
+    /// has finished.
+    #[serde(skip_serializing)]
+    restoring: bool,
+    /// The running `post_update_restore` hook.
+    #[serde(skip_serializing)]
+    restore: Option<HookTask>,
+    /// The release the service was updated from, until the updated release has stayed up for
+    /// `UPDATE_PROVEN_SECS`, so the update can be rolled back if the updated release won't start.
+    #[serde(skip_serializing)]
+    rollback_release: Option<Package>,
+    /// Why the updated release last failed to start, until the updater has counted the failure.
+    #[serde(skip_serializing)]
+    pub failed_start: Option<String>,
+    /// The releases the service failed to update to so often that they're skipped.
+    pub quarantined: Vec<String>,
 }
 
 impl Service {
     pub fn new<T>(package: Package,
                   group: T,
                   organization: Option<&str>,
+                  binds: Vec<String>,
                   topology: Topology,
-                  update_strategy: UpdateStrategy)
+                  update_strategy: UpdateStrategy,
+                  kind: ServiceKind,
+                  schedule: Option<Schedule>)
                   -> Result<Service>
         where T: AsRef<str>
     {
-        let service_group = ServiceGroup::new(&package.name, group, organization)?;
+        let service_group =
+            ServiceGroup::new(package.pkg_install.service_name(), group, organization)?;
         let (svc_user, svc_group) = try!(util::users::get_user_and_group(&package.pkg_install));
-        let runtime_config = RuntimeConfig::new(svc_user, svc_group);
-        let supervisor = Supervisor::new(package.ident().clone(), &service_group, runtime_config);
-        Ok(Service {
+        let scheduler = try!(Scheduler::load(&package, &svc_user, &svc_group));
+        let limits = try!(ProcessLimits::load(&package.pkg_install));
+        let confinement = Confinement {
+            capabilities: try!(package.pkg_install.svc_capabilities()),
+            seccomp_filter: try!(package.pkg_install.svc_seccomp_filter()),
+            network_namespace: None,
+            writable_paths: None,
+        };
+        let mut runtime_config = RuntimeConfig::new(svc_user,
+                                                    svc_group,
+                                                    package.hook_interpreter,
+                                                    limits,
+                                                    confinement);
+        runtime_config.ports = gconfig().ports().to_vec();
+        runtime_config.dirs = try!(ServiceDirs::load(&package.pkg_install));
+        runtime_config.env = package.environment.clone();
+        let mut exit_codes = try!(ExitCodes::load(&package.pkg_install));
+        if kind == ServiceKind::Oneshot {
+            exit_codes.complete_on_success();
+        }
+        let mut supervisor = Supervisor::new(package.ident().clone(),
+                                             &service_group,
+                                             runtime_config);
+        if gconfig().isolate_network() {
+            let forwarder = try!(PortForwarder::new(&service_group.to_string(),
+                                                    &supervisor.runtime_config.ports));
+            forwarder.namespace().confine(&mut supervisor.runtime_config.confinement);
+            supervisor.port_forwarder = Some(forwarder);
+        }
+        let service = Service {
             service_group: service_group,
+            binds: binds,
             supervisor: supervisor,
             package: package,
             topology: topology,
             needs_restart: false,
             update_strategy: update_strategy,
+            kind: kind,
+            schedule: schedule,
+            run_due: false,
+            scheduler: scheduler,
             current_service_files: HashMap::new(),
             last_restart_display: LastRestartDisplay::None,
             initialized: false,
             cfg_incarnation: 0,
-        })
+            exit_codes: exit_codes,
+            completed: false,
+            needs_reconfigure: false,
+            render_failed: false,
+            template_fingerprint: None,
+            census_changed_at: None,
+            last_provisioning: None,
+            provisioning_hook: None,
+            dirs_checked_at: None,
+            previous_release: None,
+            restoring: false,
+            restore: None,
+            rollback_release: None,
+            failed_start: None,
+            quarantined: vec![],
+        };
+        service.warn_deprecated_data();
+        Ok(service)
+    }
+
+    /// Warns about any template data the package's templates and hooks use which has been
+    /// renamed since the data version the package targets.
+    pub fn warn_deprecated_data(&self) {
+        if self.package.data_version.is_current() {
+            return;
+        }
+        let inputs = match self.package.template_paths().and_then(|p| TemplateInputs::load(&p)) {
+            Ok(inputs) => inputs,
+            Err(e) => {
+                debug!("Couldn't read templates to check for deprecated data, err={}", e);
+                return;
+            }
+        };
+        for warning in self.package.data_version.deprecations(&inputs) {
+            outputln!(preamble self.service_group_str(),
+                      "{} {}; set pkg_svc_data_version={} once the plan's templates use the new \
+                       names",
+                      Yellow.bold().paint("Warning:"),
+                      warning,
+                      CURRENT_DATA_VERSION);
+        }
+    }
+
+    /// Replaces the service's package with an updated one, which keeps running under the
+    /// service's name.
+    ///
+    /// The updater has already run the running release's `pre_update_backup` hook. The updated
+    /// release's `post_update_restore` hook runs when the service next restarts, between
+    /// stopping the old process and starting the new one. The managed directories the updated
+    /// package declares are readied, and those cleared on update emptied, at the same point.
+    pub fn update_package(&mut self, mut package: Package) {
+        package.pkg_install.svc_name = self.package.pkg_install.svc_name.clone();
+        package.strict_render = self.package.strict_render;
+        match ServiceDirs::load(&package.pkg_install) {
+            Ok(dirs) => self.supervisor.runtime_config.dirs = dirs,
+            Err(e) => {
+                outputln!(preamble self.service_group_str(),
+                          "Keeping the managed directories of {}: {}",
+                          self.package.ident(),
+                          e)
+            }
+        }
+        self.supervisor.runtime_config.dirs.updated = true;
+        self.supervisor.runtime_config.env = package.environment.clone();
+        // An update which lands before the last one's restore ran still restores from the release
+        // the service last started with.
+        let previous = std::mem::replace(&mut self.package, package);
+        if self.previous_release.is_none() {
+            self.previous_release = Some(previous.clone());
+        }
+        if self.rollback_release.is_none() {
+            self.rollback_release = Some(previous);
+        }
+        self.failed_start = None;
+    }
+
+    /// Goes back to the release the service was updated from, if the release it was updated to
+    /// hasn't proven itself yet, and marks the service for restarting. Returns the release gone
+    /// back to, if any.
+    pub fn roll_back(&mut self) -> Option<PackageIdent> {
+        let mut release = match self.rollback_release.take() {
+            Some(release) => release,
+            None => return None,
+        };
+        release.pkg_install.svc_name = self.package.pkg_install.svc_name.clone();
+        release.strict_render = self.package.strict_render;
+        if let Ok(dirs) = ServiceDirs::load(&release.pkg_install) {
+            self.supervisor.runtime_config.dirs = dirs;
+        }
+        self.supervisor.runtime_config.env = release.environment.clone();
+        self.package = release;
+        self.previous_release = None;
+        self.restoring = false;
+        self.restore = None;
+        self.failed_start = None;
+        self.needs_restart = true;
+        Some(self.package.ident().clone())
+    }
+
+    /// Returns the release the service was updated to once it has stayed up for
+    /// `UPDATE_PROVEN_SECS`, or run to completion, after which it can no longer be rolled back.
+    pub fn prove_release(&mut self) -> Option<PackageIdent> {
+        if self.rollback_release.is_none() || self.previous_release.is_some() {
+            return None;
+        }
+        let up_long_enough = match self.supervisor.state {
+            ProcessState::Up => {
+                SteadyTime::now() - self.supervisor.state_entered >=
+                TimeDuration::seconds(UPDATE_PROVEN_SECS)
+            }
+            _ => false,
+        };
+        if !(up_long_enough || self.completed) {
+            return None;
+        }
+        self.rollback_release = None;
+        Some(self.package.ident().clone())
     }
 
     pub fn service_group_str(&self) -> String {
@@ -93,10 +329,11 @@ impl Service {
     }
 
     pub fn start(&mut self) -> Result<()> {
-        self.supervisor.start()
+        self.supervisor.start("starting")
     }
 
     pub fn restart(&mut self, census_list: &CensusList) -> Result<()> {
+        let cause = self.restart_cause();
         match self.topology {
             Topology::Leader | Topology::Initializer => {
                 if let Some(census) = census_list.get(&self.service_group.to_string()) {
@@ -128,20 +365,92 @@ impl Service {
                             self.last_restart_display = LastRestartDisplay::ElectionFinished;
                         }
                         self.needs_restart = false;
-                        try!(self.supervisor.restart());
+                        self.completed = false;
+                        self.run_due = false;
+                        try!(self.restart_process(&cause));
                     }
                 }
             }
             Topology::Standalone => {
                 self.needs_restart = false;
-                try!(self.supervisor.restart());
+                self.completed = false;
+                self.run_due = false;
+                try!(self.restart_process(&cause));
             }
         }
         Ok(())
     }
 
+    /// Why the service is about to be (re)started, for its history.
+    fn restart_cause(&self) -> String {
+        if let Some(ref previous) = self.previous_release {
+            format!("updated from {}", previous.ident())
+        } else if self.needs_restart {
+            String::from("restart needed")
+        } else if self.run_due {
+            String::from("scheduled run")
+        } else if self.supervisor.has_started {
+            String::from("process not running")
+        } else {
+            String::from("starting")
+        }
+    }
+
+    /// Restarts the service's process, first running the `post_update_restore` hook of a release
+    /// the service was updated to. The hook runs on the hook pool with the process stopped, and
+    /// the process is started once it succeeds. If the hook fails, the service is left down, and
+    /// the hook run again on the next attempt to start it.
+    fn restart_process(&mut self, cause: &str) -> Result<()> {
+        if self.previous_release.is_none() {
+            return self.supervisor.restart(cause);
+        }
+        if !self.restoring {
+            try!(self.supervisor.down(cause));
+            self.restoring = true;
+        }
+        let outcome = match self.restore {
+            Some(ref restore) => restore.poll(),
+            None => {
+                let package = self.package.clone();
+                let previous = self.previous_release.clone().unwrap();
+                let sg = self.service_group.clone();
+                // With every slot of the hook pool taken, this is tried again next time around.
+                self.restore = HookTask::start(format!("post-update-restore-{}", sg),
+                                               move || package.post_update_restore(&sg, &previous));
+                None
+            }
+        };
+        let outcome = match outcome {
+            Some(outcome) => outcome,
+            None => return Ok(()),
+        };
+        self.restore = None;
+        self.restoring = false;
+        let previous = self.previous_release.take().unwrap();
+        match outcome {
+            Ok(true) => {
+                outputln!(preamble self.service_group_str(),
+                          "Restored {} after updating from {}",
+                          self.package.ident(),
+                          previous.ident())
+            }
+            Ok(false) => {}
+            Err(e) => {
+                outputln!(preamble self.service_group_str(),
+                          "Not starting {}; {}: {}",
+                          self.package.ident(),
+                          Red.bold().paint("post_update_restore failed"),
+                          e);
+                self.failed_start = Some(format!("post_update_restore failed: {}", e));
+                self.previous_release = Some(previous);
+                return Ok(());
+            }
+        }
+        self.supervisor.start(cause)
+    }
+
     pub fn down(&mut self) -> Result<()> {
-        self.supervisor.down()
+        self.supervisor.down("shutting down")
     }
 
     pub fn send_signal(&self, signal: u32) -> Result<()> {
@@ -158,9 +467,92 @@ impl Service {
         self.supervisor.child.is_none()
     }
 
-    /// Instructs the service's process supervisor to reap dead children.
-    pub fn check_process(&mut self) {
-        self.supervisor.check_process()
+    /// Returns true if the service should be (re)started on this tick.
+    ///
+    /// A service whose process completed stays down until its configuration changes, and a service
+    /// waiting on a reload is held until its configuration has been re-rendered. Oneshot services
+    /// are never interrupted while running, and scheduled ones only run when their schedule is due.
+    /// In strict render mode a service whose configuration failed to render is not (re)started.
+    pub fn should_restart(&self) -> bool {
+        if self.needs_reconfigure || self.render_failed {
+            return false;
+        }
+        // A restart waiting on the `post_update_restore` hook is checked on until it finishes.
+        if self.restoring {
+            return true;
+        }
+        match self.kind {
+            ServiceKind::Daemon => self.needs_restart || (self.is_down() && !self.completed),
+            ServiceKind::Oneshot if self.schedule.is_some() => self.is_down() && self.run_due,
+            ServiceKind::Oneshot => self.is_down() && (self.needs_restart || !self.completed),
+        }
+    }
+
+    /// Marks a scheduled oneshot service as due to run when its schedule fires. A run which is
+    /// still in progress when the schedule fires again is left alone and the new run skipped.
+    pub fn check_schedule(&mut self) {
+        let due = match self.schedule {
+            Some(ref mut schedule) => schedule.due(),
+            None => false,
+        };
+        if !due {
+            return;
+        }
+        if self.is_down() {
+            self.run_due = true;
+        } else {
+            outputln!(preamble self.service_group_str(),
+                      "Skipping scheduled run; {}",
+                      Yellow.bold().paint("previous run still in progress"));
+        }
+    }
+
+    /// Keeps the service's managed directories within their quotas while it runs, checking at
+    /// most once every `DIRS_CHECK_INTERVAL_SECS`.
+    pub fn check_dirs(&mut self) {
+        if self.supervisor.runtime_config.dirs.is_empty() || self.is_down() {
+            return;
+        }
+        let now = SteadyTime::now();
+        if let Some(checked_at) = self.dirs_checked_at {
+            if now - checked_at < TimeDuration::seconds(DIRS_CHECK_INTERVAL_SECS) {
+                return;
+            }
+        }
+        self.dirs_checked_at = Some(now);
+        let svc_path = fs::svc_path(self.service_group.service());
+        self.supervisor.runtime_config.dirs.enforce_quotas(&svc_path, &self.service_group_str());
+    }
+
+    /// Instructs the service's process supervisor to reap dead children and applies the package's
+    /// exit code mappings to a process which exited on its own.
+    ///
+    /// Returns true if the service's process exited.
+    pub fn check_process(&mut self) -> bool {
+        if !self.supervisor.check_process() {
+            return false;
+        }
+        match self.exit_codes.action_for(self.supervisor.last_exit_code) {
+            ExitAction::Complete => {
+                outputln!(preamble self.service_group_str(),
+                          "Service completed; {}",
+                          Green.bold().paint("not restarting"));
+                self.completed = true;
+            }
+            ExitAction::Reload => {
+                outputln!(preamble self.service_group_str(),
+                          "Service requested a reload; re-rendering configuration");
+                self.needs_reconfigure = true;
+            }
+            ExitAction::Restart => {}
+        }
+        if self.rollback_release.is_some() && !self.completed {
+            self.failed_start = Some(match self.supervisor.last_exit_code {
+                Some(code) => format!("process exited with code {}", code),
+                None => String::from("process was killed by a signal"),
+            });
+        }
+        true
     }
 
     pub fn write_butterfly_service_file(&mut self,
@@ -308,8 +700,123 @@ impl Service {
         }
     }
 
+    /// Merges a configuration patch from butterfly over the configuration last written from
+    /// gossip, and writes the result.
+    pub fn write_butterfly_service_config_patch(&mut self, patch: toml::Value) -> bool {
+        let on_disk_path = fs::svc_path(self.service_group.service()).join("gossip.toml");
+        let mut current = String::new();
+        if let Err(e) = File::open(&on_disk_path).and_then(|mut f| f.read_to_string(&mut current)) {
+            debug!("Failed to read {:?}, patching an empty configuration: {}",
+                   on_disk_path,
+                   e);
+        }
+        let mut config = match toml::Parser::new(&current).parse() {
+            Some(config) => config,
+            None => {
+                outputln!(preamble self.service_group_str(),
+                    "Service configuration patch from butterfly failed: {}",
+                    Red.bold().paint(format!("{:?} isn't valid TOML", on_disk_path)));
+                return false;
+            }
+        };
+        apply_patch(&mut config, patch.as_table().cloned().unwrap_or_else(toml::Table::new));
+        self.write_butterfly_service_config(toml::Value::Table(config))
+    }
+
+    /// Write the census, along with this service's view of its own service group and its bound
+    /// service groups, as JSON for hooks to read.
+    ///
+    /// Returns true if the file changed.
+    pub fn write_census_file(&self, census_list: &CensusList, svc_cfg: &ServiceConfig) -> bool {
+        let data = CensusFile {
+            census: census_list,
+            svc: &svc_cfg.svc,
+            bind: &svc_cfg.bind,
+        };
+        let encoded = match serde_json::to_string_pretty(&data) {
+            Ok(encoded) => encoded,
+            Err(e) => {
+                outputln!(preamble self.service_group_str(),
+                    "Census file failed to encode: {}",
+                    Red.bold().paint(format!("{}", e)));
+                return false;
+            }
+        };
+        let on_disk_path = census::census_file_path(self.service_group.service());
+        let current_checksum = match hash::hash_file(&on_disk_path) {
+            Ok(current_checksum) => current_checksum,
+            Err(e) => {
+                debug!("Failed to get current checksum for {:?}: {}",
+                       on_disk_path,
+                       e);
+                String::new()
+            }
+        };
+        let new_checksum = hash::hash_string(&encoded)
+            .expect("We failed to hash a string in a method that can't return an error; not even \
+                     sure what this means");
+        if new_checksum == current_checksum {
+            return false;
+        }
+        let new_filename = format!("{}.write", on_disk_path.to_string_lossy());
+        let result = File::create(&new_filename)
+            .and_then(|mut new_file| new_file.write_all(encoded.as_bytes()))
+            .and_then(|_| std::fs::rename(&new_filename, &on_disk_path));
+        if let Err(e) = result {
+            outputln!(preamble self.service_group_str(),
+                "Census file failed to write {}: {}",
+                on_disk_path.to_string_lossy(),
+                Red.bold().paint(format!("{}", e)));
+            return false;
+        }
+        if let Err(e) = set_owner(&on_disk_path,
+                                  &self.supervisor.runtime_config.svc_user,
+                                  &self.supervisor.runtime_config.svc_group) {
+            outputln!(preamble self.service_group_str(),
+                "Census file failed to set ownership on {}: {}",
+                on_disk_path.to_string_lossy(),
+                Red.bold().paint(format!("{}", e)));
+            return false;
+        }
+        if let Err(e) = set_permissions(&on_disk_path, 0o640) {
+            outputln!(preamble self.service_group_str(),
+                "Census file failed to set permissions on {}: {}",
+                on_disk_path.to_string_lossy(),
+                Red.bold().paint(format!("{}", e)));
+            return false;
+        }
+        debug!("Census file updated {}: {}",
+               on_disk_path.to_string_lossy(),
+               new_checksum);
+        true
+    }
+
+    /// Checks the service's health, recording the result in its history if it has changed.
     pub fn health_check(&self) -> Result<health_check::CheckResult> {
-        self.package.health_check(&self.supervisor, &self.service_group)
+        let (result, cause) = self.check_health();
+        let (health, cause) = match result {
+            Ok(ref health) => (health.to_string(), cause.to_string()),
+            Err(ref e) => {
+                (health_check::CheckResult::Unknown.to_string(),
+                 format!("health check failed: {}", e))
+            }
+        };
+        service_history::record(&self.service_group_str(),
+                                service_history::HEALTH,
+                                &health,
+                                &cause);
+        result
+    }
+
+    fn check_health(&self) -> (Result<health_check::CheckResult>, &'static str) {
+        // A oneshot service which isn't running is healthy as long as its last run succeeded.
+        if self.kind == ServiceKind::Oneshot && self.is_down() {
+            if self.completed || !self.supervisor.has_started {
+                return (Ok(health_check::CheckResult::Ok), "last run succeeded");
+            }
+            return (Ok(health_check::CheckResult::Critical), "last run failed");
+        }
+        (self.package.health_check(&self.supervisor, &self.service_group), "health check")
     }
 
     pub fn file_updated(&self) -> bool {
@@ -326,6 +833,69 @@ impl Service {
         false
     }
 
+    /// Run the provisioning hooks if the service group's alive members no longer match its
+    /// desired count. Only the alive member with the lowest member id runs them, so each change is
+    /// handled once for the whole group. The hooks run on the hook pool, one at a time; a change
+    /// which comes while one is running is handled once it has finished.
+    pub fn check_provisioning(&mut self, census_list: &CensusList) {
+        if !self.initialized {
+            return;
+        }
+        let sg = self.service_group_str();
+        let outcome = match self.provisioning_hook {
+            Some(ref hook) => {
+                match hook.poll() {
+                    Some(outcome) => outcome,
+                    None => return,
+                }
+            }
+            None => Ok(false),
+        };
+        self.provisioning_hook = None;
+        if let Err(e) = outcome {
+            outputln!(preamble sg, "Provisioning hook failed: {}", e);
+        }
+        let census = match census_list.get(&sg) {
+            Some(census) => census,
+            None => return,
+        };
+        let (provisioning, desired) = match (census.provisioning(), census.desired_count) {
+            (Some(provisioning), Some(desired)) => (provisioning, desired),
+            _ => {
+                self.last_provisioning = None;
+                return;
+            }
+        };
+        let alive = census.alive_members().len() as u64;
+        if self.last_provisioning == Some((provisioning, desired, alive)) {
+            return;
+        }
+        if provisioning != Provisioning::Provisioned {
+            match census.alive_members_ordered().first() {
+                Some(first) if first.member_id == census.member_id => {}
+                _ => {
+                    self.last_provisioning = Some((provisioning, desired, alive));
+                    return;
+                }
+            }
+            let package = self.package.clone();
+            let service_group = self.service_group.clone();
+            let hook = HookTask::start(format!("provisioning-{}", sg), move || {
+                package.provisioning_changed(&service_group, provisioning, desired, alive)
+            });
+            // With every slot of the hook pool taken, this is tried again next time around.
+            if hook.is_none() {
+                return;
+            }
+            outputln!(preamble sg,
+                      "Service group has {} alive members but should have {}",
+                      alive,
+                      desired);
+            self.provisioning_hook = hook;
+        }
+        self.last_provisioning = Some((provisioning, desired, alive));
+    }
+
     pub fn initialize(&mut self) {
         if !self.initialized {
             match self.package.initialize(&self.service_group) {
@@ -340,14 +910,68 @@ impl Service {
         }
     }
 
+    /// Runs any of the service's scheduled hooks which are due, once the service is initialized.
+    pub fn run_scheduled_hooks(&mut self) {
+        if self.initialized {
+            self.scheduler.tick(&self.service_group);
+        }
+    }
+
     pub fn load_service_config(&self, census: &CensusList) -> Result<ServiceConfig> {
         ServiceConfig::new(&self.service_group_str(),
                            &self.package,
                            census,
-                           gconfig().bind())
+                           self.binds.clone())
+    }
+
+    /// Records that the census changed, which reconfigures the service once the census settles.
+    pub fn census_changed(&mut self) {
+        self.census_changed_at = Some(SteadyTime::now());
     }
 
-    pub fn reconfigure(&mut self, census_list: &CensusList) -> Option<ServiceConfig> {
+    /// Returns true if the service has been reconfigured since the census last changed.
+    pub fn census_converged(&self) -> bool {
+        self.census_changed_at.is_none()
+    }
+
+    /// Returns true if the census changed and has since been quiet for the reconfigure debounce
+    /// window, so a burst of changes (such as a rolling restart of a bound service group) results
+    /// in a single reconfigure.
+    pub fn census_settled(&self) -> bool {
+        match self.census_changed_at {
+            Some(changed_at) => {
+                let debounce = TimeDuration::seconds(gconfig().reconfigure_debounce() as i64);
+                SteadyTime::now() - changed_at >= debounce
+            }
+            None => false,
+        }
+    }
+
+    /// Returns a fingerprint of the service's templates and the parts of the service configuration
+    /// they reference, or `None` if it can't be computed.
+    fn fingerprint_templates(&self, service_config: &ServiceConfig) -> Option<String> {
+        let fingerprint = self.package
+            .template_paths()
+            .and_then(|paths| TemplateInputs::load(&paths))
+            .and_then(|inputs| {
+                let data = convert::toml_to_json(try!(service_config.to_toml()));
+                inputs.fingerprint(&data)
+            });
+        match fingerprint {
+            Ok(fingerprint) => Some(fingerprint),
+            Err(e) => {
+                debug!("Couldn't fingerprint templates, err={}", e);
+                None
+            }
+        }
+    }
+
+    /// Re-render the service's configuration and hooks. Unless `force` is set, rendering is
+    /// skipped when neither the templates nor the data they reference have changed since the last
+    /// render; in that case `None` is returned.
+    pub fn reconfigure(&mut self, census_list: &CensusList, force: bool) -> Option<ServiceConfig> {
+        self.needs_reconfigure = false;
+        self.census_changed_at = None;
         let mut service_config = match self.load_service_config(census_list) {
             Ok(sc) => sc,
             Err(e) => {
@@ -357,6 +981,15 @@ impl Service {
                 return None;
             }
         };
+        self.write_census_file(census_list, &service_config);
+        let fingerprint = self.fingerprint_templates(&service_config);
+        if !force && fingerprint.is_some() && fingerprint == self.template_fingerprint {
+            debug!("Template data for {} is unchanged; not re-rendering",
+                   self.service_group_str());
+            return None;
+        }
+        self.template_fingerprint = fingerprint;
+        let mut render_failed = false;
         match service_config.write(&self.package) {
             Ok(true) => {
                 self.needs_restart = true;
@@ -372,6 +1005,7 @@ impl Service {
             Err(e) => {
                 outputln!(preamble self.service_group_str(),
                     "Failed to write service configuration: {}", e);
+                render_failed = true;
             }
         }
 
@@ -379,8 +1013,20 @@ impl Service {
         // Probably worth moving the run hook under compile all, eventually
         if let Err(e) = self.package.copy_run(&service_config) {
             outputln!("Failed to copy run hook: {}", e);
+            render_failed = true;
+        }
+        if !self.package.hooks().compile_all(&service_config) {
+            render_failed = true;
+        }
+        if !self.scheduler.compile_all(&service_config) {
+            render_failed = true;
+        }
+        self.render_failed = render_failed && self.package.strict_render;
+        if self.render_failed {
+            outputln!(preamble self.service_group_str(),
+                      "Not (re)starting service; {}",
+                      Red.bold().paint("configuration failed to render in strict mode"));
         }
-        self.package.hooks().compile_all(&service_config);
         Some(service_config)
     }
 }
@@ -404,6 +1050,30 @@ impl Default for Topology {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ServiceKind {
+    /// A long lived process which is restarted whenever it exits.
+    Daemon,
+    /// A job which runs to completion and is not restarted once it succeeds.
+    Oneshot,
+}
+
+impl ServiceKind {
+    pub fn from_str(kind: &str) -> Self {
+        match kind {
+            "daemon" => ServiceKind::Daemon,
+            "oneshot" => ServiceKind::Oneshot,
+            k => panic!("Invalid service kind {}", k),
+        }
+    }
+}
+
+impl Default for ServiceKind {
+    fn default() -> ServiceKind {
+        ServiceKind::Daemon
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 pub enum UpdateStrategy {
     None,
@@ -0,0 +1,475 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Network isolation for a service.
+//!
+//! An isolated service runs in a network namespace of its own, so several instances of the same
+//! package can listen on the same port on one host. The Supervisor creates the namespace when it
+//! loads the service and keeps it until the service is unloaded, so the service's process, every
+//! one of its hooks, and its health probes all join the same namespace, whether or not the
+//! service's process is running.
+//!
+//! The namespace has a loopback interface, and one end of a veth pair whose other end is on the
+//! host. The Supervisor gives the pair a /30 network of its own from `10.231.0.0/16`, routes the
+//! namespace's traffic through the host end, and masquerades it with `iptables`, so the service
+//! can still reach other hosts. This needs the `ip` and `iptables` commands.
+//!
+//! The Supervisor listens on the host port of each of the service's port mappings, and forwards
+//! every connection to the service's port on the loopback interface of its namespace, on a thread
+//! for each connection, up to `MAX_CONNECTIONS` at once.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::net::{Shutdown, TcpListener, TcpStream};
+use std::process::Command;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+use std::thread;
+
+use hcore::os::process::Confinement;
+
+use error::{Error, Result, SupError};
+
+static LOGKEY: &'static str = "PF";
+
+/// The most connections forwarded to one of a service's ports at once. Past it, new connections
+/// are closed straight away.
+pub const MAX_CONNECTIONS: usize = 256;
+
+/// The first two octets of the networks given to the veth pairs between isolated services and the
+/// host, each of which is a /30.
+const UPLINK_NETWORK: &'static str = "10.231";
+
+/// The number of /30 networks in `UPLINK_NETWORK`.
+const UPLINK_NETWORKS: usize = 16384;
+
+static NEXT_UPLINK: AtomicUsize = ATOMIC_USIZE_INIT;
+
+lazy_static! {
+    /// The network namespace of each isolated service, by service group.
+    static ref NAMESPACES: Mutex<HashMap<String, Namespace>> = Mutex::new(HashMap::new());
+}
+
+/// A port on the host, and the port of an isolated service it is forwarded to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PortMapping {
+    pub host: u16,
+    pub service: u16,
+}
+
+impl fmt::Display for PortMapping {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.host, self.service)
+    }
+}
+
+impl FromStr for PortMapping {
+    type Err = SupError;
+
+    /// Parses `host_port:service_port`, or a single port which is the same on both sides.
+    fn from_str(value: &str) -> Result<Self> {
+        let parse = |port: &str| {
+            port.trim()
+                .parse::<u16>()
+                .map_err(|_| sup_error!(Error::InvalidPortMapping(value.to_string())))
+        };
+        let mut parts = value.splitn(2, ':');
+        let host = try!(parse(parts.next().unwrap_or("")));
+        let service = match parts.next() {
+            Some(port) => try!(parse(port)),
+            None => host,
+        };
+        Ok(PortMapping {
+            host: host,
+            service: service,
+        })
+    }
+}
+
+/// An open handle on an isolated service's network namespace. The namespace lasts for as long as
+/// any handle on it does.
+#[derive(Clone)]
+pub struct Namespace(Arc<File>);
+
+impl Namespace {
+    /// Returns the network namespace of `service_group`, if it is an isolated service.
+    pub fn of(service_group: &str) -> Option<Namespace> {
+        NAMESPACES.lock().expect("Namespace table lock is poisoned!").get(service_group).cloned()
+    }
+
+    /// Sets `confinement` to start a process in this namespace. A command prepared with it must
+    /// also be passed to `hold_open`.
+    #[cfg(unix)]
+    pub fn confine(&self, confinement: &mut Confinement) {
+        use std::os::unix::io::AsRawFd;
+
+        confinement.network_namespace = Some(self.0.as_raw_fd());
+    }
+
+    #[cfg(not(unix))]
+    pub fn confine(&self, _confinement: &mut Confinement) {}
+
+    /// Keeps the namespace open until `cmd` has started its process, which joins the namespace by
+    /// its file descriptor.
+    #[cfg(unix)]
+    pub fn hold_open(&self, cmd: &mut Command) {
+        use std::os::unix::process::CommandExt;
+
+        let namespace = self.0.clone();
+        cmd.before_exec(move || {
+            let _ = &namespace;
+            Ok(())
+        });
+    }
+
+    #[cfg(not(unix))]
+    pub fn hold_open(&self, _cmd: &mut Command) {}
+
+    /// Runs `f` on a thread of its own which has joined the namespace, so that any connection `f`
+    /// makes is made from inside it.
+    pub fn run<F, T>(&self, f: F) -> io::Result<T>
+        where F: FnOnce() -> T + Send + 'static,
+              T: Send + 'static
+    {
+        let namespace = self.0.clone();
+        let handle = try!(thread::Builder::new()
+            .name(String::from("sup-netns"))
+            .spawn(move || -> io::Result<T> {
+                try!(enter(&namespace));
+                Ok(f())
+            }));
+        match handle.join() {
+            Ok(result) => result,
+            Err(_) => Err(io::Error::new(io::ErrorKind::Other, "thread in namespace panicked")),
+        }
+    }
+}
+
+/// The network of an isolated service: its namespace, the veth pair and NAT rule which let it
+/// reach other hosts, and the listeners which forward connections on its host ports to it.
+///
+/// The host ports are listened on for as long as the service is loaded, so that another process
+/// can't take them while the service restarts. Connections made while the service isn't running
+/// are refused from inside the namespace, and closed.
+pub struct PortForwarder {
+    service_group: String,
+    namespace: Namespace,
+    uplink: Option<Uplink>,
+    listeners: Vec<(u16, Arc<AtomicBool>)>,
+}
+
+impl fmt::Debug for PortForwarder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PortForwarder {{ service_group: {} }}", self.service_group)
+    }
+}
+
+impl PortForwarder {
+    /// Creates a network namespace for `service_group`, and forwards `ports` into it.
+    pub fn new(service_group: &str, ports: &[PortMapping]) -> Result<Self> {
+        let namespace = Namespace(Arc::new(try!(new_namespace())));
+        let uplink = match Uplink::create(&namespace.0) {
+            Ok(uplink) => Some(uplink),
+            Err(e) => {
+                outputln!(preamble service_group,
+                          "Isolated service can't reach other hosts, as its network couldn't be \
+                           connected to the host's: {}",
+                          e);
+                None
+            }
+        };
+        let mut forwarder = PortForwarder {
+            service_group: service_group.to_string(),
+            namespace: namespace.clone(),
+            uplink: uplink,
+            listeners: Vec::new(),
+        };
+        for mapping in ports {
+            let listener = try!(TcpListener::bind(("0.0.0.0", mapping.host)));
+            let stopped = Arc::new(AtomicBool::new(false));
+            let (namespace, port) = (namespace.clone(), mapping.service);
+            let accept_stopped = stopped.clone();
+            try!(thread::Builder::new()
+                .name(format!("sup-port-{}", mapping.host))
+                .spawn(move || accept(listener, namespace, port, accept_stopped)));
+            forwarder.listeners.push((mapping.host, stopped));
+        }
+        NAMESPACES.lock()
+            .expect("Namespace table lock is poisoned!")
+            .insert(service_group.to_string(), namespace);
+        Ok(forwarder)
+    }
+
+    /// The service's network namespace.
+    pub fn namespace(&self) -> &Namespace {
+        &self.namespace
+    }
+}
+
+impl Drop for PortForwarder {
+    fn drop(&mut self) {
+        let mut namespaces = NAMESPACES.lock().expect("Namespace table lock is poisoned!");
+        // A reloaded service may have registered a namespace of its own already.
+        let ours = match namespaces.get(&self.service_group) {
+            Some(namespace) => Arc::ptr_eq(&namespace.0, &self.namespace.0),
+            None => false,
+        };
+        if ours {
+            namespaces.remove(&self.service_group);
+        }
+        for &(port, ref stopped) in self.listeners.iter() {
+            stopped.store(true, Ordering::SeqCst);
+            // Wakes the listener, which is blocked accepting a connection.
+            let _ = TcpStream::connect(("127.0.0.1", port));
+        }
+        self.uplink.take();
+    }
+}
+
+/// A veth pair between the host and a service's network namespace, with the namespace's traffic
+/// routed through the host end and masqueraded. Removed when dropped.
+struct Uplink {
+    host_if: String,
+    network: String,
+}
+
+impl Uplink {
+    fn create(namespace: &File) -> io::Result<Uplink> {
+        let index = NEXT_UPLINK.fetch_add(1, Ordering::SeqCst) % UPLINK_NETWORKS;
+        let (third, fourth) = (index / 64, (index % 64) * 4);
+        let host_addr = format!("{}.{}.{}", UPLINK_NETWORK, third, fourth + 1);
+        let ns_addr = format!("{}.{}.{}", UPLINK_NETWORK, third, fourth + 2);
+        let host_if = format!("hab{}h", index);
+        let ns_if = format!("hab{}n", index);
+        let host_pid = host_pid();
+        try!(run(Some(namespace),
+                 "ip",
+                 &["link", "add", &ns_if, "type", "veth", "peer", "name", &host_if, "netns",
+                   &host_pid]));
+        let uplink = Uplink {
+            host_if: host_if,
+            network: format!("{}.{}.{}/30", UPLINK_NETWORK, third, fourth),
+        };
+        try!(run(Some(namespace),
+                 "ip",
+                 &["addr", "add", &format!("{}/30", ns_addr), "dev", &ns_if]));
+        try!(run(Some(namespace), "ip", &["link", "set", &ns_if, "up"]));
+        try!(run(Some(namespace), "ip", &["route", "add", "default", "via", &host_addr]));
+        try!(run(None,
+                 "ip",
+                 &["addr", "add", &format!("{}/30", host_addr), "dev", &uplink.host_if]));
+        try!(run(None, "ip", &["link", "set", &uplink.host_if, "up"]));
+        try!(try!(OpenOptions::new().write(true).open("/proc/sys/net/ipv4/ip_forward"))
+            .write_all(b"1"));
+        try!(run(None, "iptables", &uplink.nat_rule("-A")));
+        Ok(uplink)
+    }
+
+    fn nat_rule<'a>(&'a self, action: &'a str) -> Vec<&'a str> {
+        vec!["-t",
+             "nat",
+             action,
+             "POSTROUTING",
+             "-s",
+             self.network.as_str(),
+             "!",
+             "-o",
+             self.host_if.as_str(),
+             "-j",
+             "MASQUERADE"]
+    }
+}
+
+impl Drop for Uplink {
+    fn drop(&mut self) {
+        let _ = run(None, "iptables", &self.nat_rule("-D"));
+        // Deleting either end of a veth pair deletes both.
+        let _ = run(None, "ip", &["link", "del", &self.host_if]);
+    }
+}
+
+fn accept(listener: TcpListener, namespace: Namespace, port: u16, stopped: Arc<AtomicBool>) {
+    let connections = Arc::new(AtomicUsize::new(0));
+    for client in listener.incoming() {
+        if stopped.load(Ordering::SeqCst) {
+            break;
+        }
+        let client = match client {
+            Ok(client) => client,
+            Err(e) => {
+                debug!("Failed to accept a connection for port {}, err={}", port, e);
+                continue;
+            }
+        };
+        if connections.fetch_add(1, Ordering::SeqCst) >= MAX_CONNECTIONS {
+            connections.fetch_sub(1, Ordering::SeqCst);
+            outputln!("Closing a connection to port {}, which already has {} connections",
+                      port,
+                      MAX_CONNECTIONS);
+            continue;
+        }
+        let count = ConnectionCount(connections.clone());
+        let target = namespace.0.clone();
+        let spawned = thread::Builder::new()
+            .name(format!("sup-port-{}-conn", port))
+            .spawn(move || {
+                let _count = count;
+                if let Err(e) = forward(client, &target, port) {
+                    outputln!("Failed to forward a connection to port {}: {}", port, e);
+                }
+            });
+        if let Err(e) = spawned {
+            outputln!("Failed to forward a connection to port {}: {}", port, e);
+        }
+    }
+}
+
+/// Counts a forwarded connection for as long as it is held.
+struct ConnectionCount(Arc<AtomicUsize>);
+
+impl Drop for ConnectionCount {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Connects to `port` inside the network namespace `namespace`, and copies data between that
+/// connection and `client` until both sides are done. Runs on a thread of its own, as joining the
+/// namespace moves the whole thread into it.
+fn forward(client: TcpStream, namespace: &File, port: u16) -> io::Result<()> {
+    try!(enter(namespace));
+    let service = try!(TcpStream::connect(("127.0.0.1", port)));
+    let mut client_reader = try!(client.try_clone());
+    let mut service_writer = try!(service.try_clone());
+    let upstream = try!(thread::Builder::new()
+        .name(format!("sup-port-{}-up", port))
+        .spawn(move || {
+            let _ = io::copy(&mut client_reader, &mut service_writer);
+            let _ = service_writer.shutdown(Shutdown::Write);
+        }));
+    let (mut service_reader, mut client_writer) = (service, client);
+    let _ = io::copy(&mut service_reader, &mut client_writer);
+    let _ = client_writer.shutdown(Shutdown::Write);
+    let _ = upstream.join();
+    Ok(())
+}
+
+/// Runs `program` with `args`, inside `namespace` if given, and fails unless it succeeds.
+fn run(namespace: Option<&File>, program: &str, args: &[&str]) -> io::Result<()> {
+    let mut cmd = Command::new(program);
+    cmd.args(args);
+    if let Some(namespace) = namespace {
+        try!(join_on_exec(&mut cmd, namespace));
+    }
+    let output = try!(cmd.output());
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::new(io::ErrorKind::Other,
+                           format!("{} {} failed: {}",
+                                   program,
+                                   args.join(" "),
+                                   String::from_utf8_lossy(&output.stderr).trim())))
+    }
+}
+
+/// Moves the calling thread into the network namespace `namespace`.
+#[cfg(target_os = "linux")]
+fn enter(namespace: &File) -> io::Result<()> {
+    use libc;
+    use std::os::unix::io::AsRawFd;
+
+    if unsafe { libc::setns(namespace.as_raw_fd(), libc::CLONE_NEWNET) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn enter(_namespace: &File) -> io::Result<()> {
+    Err(unsupported())
+}
+
+/// Has the process `cmd` starts join the network namespace `namespace`.
+#[cfg(target_os = "linux")]
+fn join_on_exec(cmd: &mut Command, namespace: &File) -> io::Result<()> {
+    use libc;
+    use std::os::unix::io::AsRawFd;
+    use std::os::unix::process::CommandExt;
+
+    let namespace = try!(namespace.try_clone());
+    cmd.before_exec(move || match unsafe {
+        libc::setns(namespace.as_raw_fd(), libc::CLONE_NEWNET)
+    } {
+        0 => Ok(()),
+        _ => Err(io::Error::last_os_error()),
+    });
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn join_on_exec(_cmd: &mut Command, _namespace: &File) -> io::Result<()> {
+    Err(unsupported())
+}
+
+#[cfg(target_os = "linux")]
+fn new_namespace() -> Result<File> {
+    use hcore::os::process;
+
+    Ok(try!(process::new_network_namespace()))
+}
+
+/// The Supervisor's process ID, whose network namespace is the host's.
+#[cfg(target_os = "linux")]
+fn host_pid() -> String {
+    use libc;
+
+    unsafe { libc::getpid() }.to_string()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn host_pid() -> String {
+    String::new()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn new_namespace() -> Result<File> {
+    Err(sup_error!(Error::Io(unsupported())))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn unsupported() -> io::Error {
+    io::Error::new(io::ErrorKind::Other, "network namespaces are only supported on Linux")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::PortMapping;
+
+    #[test]
+    fn port_mappings() {
+        let mapping = PortMapping::from_str("16379:6379").unwrap();
+        assert_eq!((mapping.host, mapping.service), (16379, 6379));
+        assert_eq!(mapping.to_string(), "16379:6379");
+        let same = PortMapping::from_str("8080").unwrap();
+        assert_eq!((same.host, same.service), (8080, 8080));
+        assert!(PortMapping::from_str("http").is_err());
+        assert!(PortMapping::from_str("80:70000").is_err());
+    }
+}
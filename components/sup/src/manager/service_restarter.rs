@@ -0,0 +1,164 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Restarts services when their service group is asked to with `hab svc restart`.
+//!
+//! A rolling restart visits the alive members of the group one at a time, in the census' restart
+//! order, which leaves the leader for last. A member only restarts once every member before it
+//! has finished, and only counts as finished once its health check passes, so a restart which
+//! leaves a member unhealthy stops there instead of taking down the whole group. Each member
+//! gossips the last restart it finished in its service rumor, which is how the others know whose
+//! turn it is.
+
+use std::collections::HashMap;
+
+use butterfly;
+use hcore::service::ServiceGroup;
+use time::{self, SteadyTime, Duration as TimeDuration};
+
+use health_check::CheckResult;
+use manager::census::{Census, CensusList};
+use manager::service::Service;
+
+static LOGKEY: &'static str = "SR";
+const HEALTH_CHECK_INTERVAL_MS: i64 = 5_000;
+
+struct RestartState {
+    /// When the service was added, in seconds since the epoch. Restarts asked for before then
+    /// are already satisfied.
+    started_at: i64,
+    /// The incarnation of the last restart this member finished.
+    finished: u64,
+    /// The incarnation of the restart in progress, and when to next check the service's health.
+    restarting: Option<(u64, SteadyTime)>,
+}
+
+pub struct ServiceRestarter {
+    states: HashMap<ServiceGroup, RestartState>,
+}
+
+impl ServiceRestarter {
+    pub fn new() -> Self {
+        ServiceRestarter { states: HashMap::new() }
+    }
+
+    pub fn add(&mut self, service: &Service) {
+        self.states.entry(service.service_group.clone()).or_insert_with(|| {
+            RestartState {
+                started_at: time::now_utc().to_timespec().sec,
+                finished: 0,
+                restarting: None,
+            }
+        });
+    }
+
+    /// Restarts the service if its group was asked to and it is this member's turn, and checks
+    /// on a restart in progress. Returns the incarnation of the restart the service just
+    /// finished, for the caller to gossip.
+    pub fn check(&mut self,
+                 service: &mut Service,
+                 butterfly: &butterfly::Server,
+                 census_list: &CensusList)
+                 -> Option<u64> {
+        let state = match self.states.get_mut(&service.service_group) {
+            Some(state) => state,
+            None => return None,
+        };
+        if let Some((incarnation, next_check)) = state.restarting {
+            if service.needs_restart || SteadyTime::now() < next_check {
+                return None;
+            }
+            match service.health_check() {
+                Ok(CheckResult::Ok) => {
+                    outputln!(preamble service.service_group_str(),
+                              "Finished restart {}", incarnation);
+                    state.finished = incarnation;
+                    state.restarting = None;
+                    return Some(incarnation);
+                }
+                Ok(result) => {
+                    outputln!(preamble service.service_group_str(),
+                              "Waiting for the service to be healthy to finish restart {}, \
+                               health is {}",
+                              incarnation,
+                              result)
+                }
+                Err(e) => {
+                    outputln!(preamble service.service_group_str(),
+                              "Waiting for the service to be healthy to finish restart {}, \
+                               health check failed: {}",
+                              incarnation,
+                              e)
+                }
+            }
+            state.restarting =
+                Some((incarnation,
+                      SteadyTime::now() + TimeDuration::milliseconds(HEALTH_CHECK_INTERVAL_MS)));
+            return None;
+        }
+
+        let mut restart = None;
+        butterfly.service_restart_store.with_rumor(&*service.service_group,
+                                                   "service_restart",
+                                                   |maybe_sr| if let Some(sr) = maybe_sr {
+                                                       restart = Some((sr.get_incarnation(),
+                                                                       sr.get_rolling(),
+                                                                       sr.get_issued_at()))
+                                                   });
+        let (incarnation, rolling, issued_at) = match restart {
+            Some(restart) => restart,
+            None => return None,
+        };
+        if incarnation <= state.finished {
+            return None;
+        }
+        if issued_at < state.started_at {
+            debug!("Restart {} was asked for before we started", incarnation);
+            state.finished = incarnation;
+            return Some(incarnation);
+        }
+        if rolling && !is_our_turn(census_list.get(&*service.service_group), incarnation) {
+            return None;
+        }
+        outputln!(preamble service.service_group_str(),
+                  "Restarting for {}restart {}",
+                  if rolling { "rolling " } else { "" },
+                  incarnation);
+        service.needs_restart = true;
+        state.restarting = Some((incarnation, SteadyTime::now()));
+        None
+    }
+}
+
+/// Whether every member before us in the restart order has finished the restart.
+fn is_our_turn(census: Option<&Census>, incarnation: u64) -> bool {
+    let census = match census {
+        Some(census) => census,
+        None => return false,
+    };
+    let me = match census.me() {
+        Some(me) => me,
+        None => return false,
+    };
+    for ce in census.restart_order() {
+        if ce.member_id == me.member_id {
+            return true;
+        }
+        if ce.get_restart_incarnation() < incarnation {
+            debug!("Waiting for {} to finish restart {}", ce.member_id, incarnation);
+            return false;
+        }
+    }
+    false
+}
@@ -13,29 +13,39 @@
 // limitations under the License.
 
 use std::collections::HashMap;
-use std::path::Path;
+use std::result;
 use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TryRecvError};
 use std::thread;
 use std::time::Duration;
 
+use ansi_term::Colour::Red;
 use butterfly;
 use common::ui::UI;
 use depot_client;
-use hcore::package::PackageIdent;
+use hcore::package::{ArtifactCache, ArtifactScanner, Identifiable, LicensePolicy, LockFile,
+                     PackageArchive, PackageIdent};
 use hcore::service::ServiceGroup;
 use hcore::crypto::default_cache_key_path;
-use hcore::fs::{CACHE_ARTIFACT_PATH, FS_ROOT_PATH};
+use hcore::fs::cache_artifact_path;
 use time::{SteadyTime, Duration as TimeDuration};
 
 use {PRODUCT, VERSION};
+#[cfg(feature = "chaos")]
+use chaos;
 use config::gconfig;
 use error::Result;
 use manager::census::CensusList;
+use manager::quarantine::{self, Quarantine};
+use manager::scheduler::HookTask;
 use manager::service::{Service, Topology, UpdateStrategy};
+use mirrors;
 use package::Package;
 
 static LOGKEY: &'static str = "SU";
 const UPDATE_STRATEGY_FREQUENCY_MS: i64 = 60_000;
+/// How often the quarantined releases a service gossips are read again, which picks up releases
+/// an operator cleared.
+const QUARANTINE_REFRESH_SECS: i64 = 10;
 
 type UpdaterStateList = HashMap<ServiceGroup, UpdaterState>;
 
@@ -63,6 +73,12 @@ enum FollowerState {
 
 pub struct ServiceUpdater {
     states: UpdaterStateList,
+    slots: UpdateSlots,
+    /// Releases the workers found, by service group, while the running release's
+    /// `pre_update_backup` hook runs, or waits for a slot on the hook pool.
+    backups: HashMap<ServiceGroup, (Package, Option<HookTask>)>,
+    /// When each service group's quarantined releases were last read.
+    quarantine_read_at: HashMap<ServiceGroup, SteadyTime>,
     butterfly: butterfly::Server,
 }
 
@@ -70,6 +86,9 @@ impl ServiceUpdater {
     pub fn new(butterfly: butterfly::Server) -> Self {
         ServiceUpdater {
             states: UpdaterStateList::default(),
+            slots: UpdateSlots::new(butterfly.clone(), gconfig().update_concurrency()),
+            backups: HashMap::new(),
+            quarantine_read_at: HashMap::new(),
             butterfly: butterfly,
         }
     }
@@ -97,13 +116,29 @@ impl ServiceUpdater {
                                      service: &mut Service,
                                      census_list: &CensusList)
                                      -> bool {
+        if !self.backups.contains_key(&service.service_group) {
+            self.slots.release_if_done(service);
+        }
         match self.states.get_mut(&service.service_group) {
             Some(&mut UpdaterState::AtOnce(ref mut rx)) => {
-                match rx.try_recv() {
-                    Ok(package) => {
-                        service.package = package;
-                        service.needs_restart = true;
-                        return true;
+                match receive(&mut self.slots, &mut self.backups, service, rx) {
+                    Ok(received) => {
+                        match received {
+                            Ok(package) => {
+                                service.update_package(package);
+                                service.warn_deprecated_data();
+                                service.needs_restart = true;
+                                return true;
+                            }
+                            // A new worker starts from the running release, so it finds the
+                            // update again and the backup is retried.
+                            Err(e) => {
+                                outputln!(preamble service.service_group_str(),
+                                          "Not updating; pre_update_backup failed: {}", e);
+                                *rx = Worker::new(service).start(&service.service_group, None);
+                                return false;
+                            }
+                        }
                     }
                     Err(TryRecvError::Empty) => return false,
                     Err(TryRecvError::Disconnected) => {}
@@ -161,11 +196,24 @@ impl ServiceUpdater {
             Some(&mut UpdaterState::Rolling(RollingState::Leader(ref mut state))) => {
                 match *state {
                     LeaderState::Polling(ref mut rx) => {
-                        match rx.try_recv() {
-                            Ok(package) => {
+                        match receive(&mut self.slots, &mut self.backups, service, rx) {
+                            Ok(received) => {
                                 debug!("Rolling Update, polling found a new package");
-                                service.package = package;
-                                service.needs_restart = true;
+                                match received {
+                                    Ok(package) => {
+                                        service.update_package(package);
+                                        service.warn_deprecated_data();
+                                        service.needs_restart = true;
+                                    }
+                                    Err(e) => {
+                                        outputln!(preamble service.service_group_str(),
+                                                  "Not updating; pre_update_backup failed: {}",
+                                                  e);
+                                        *rx = Worker::new(service)
+                                            .start(&service.service_group, None);
+                                        return false;
+                                    }
+                                }
                             }
                             Err(TryRecvError::Empty) => return false,
                             Err(TryRecvError::Disconnected) => {
@@ -233,10 +281,23 @@ impl ServiceUpdater {
                     FollowerState::Updating(ref mut rx) => {
                         match census_list.get(&*service.service_group) {
                             Some(census) => {
-                                match rx.try_recv() {
-                                    Ok(package) => {
-                                        service.package = package;
-                                        service.needs_restart = true;
+                                match receive(&mut self.slots, &mut self.backups, service, rx) {
+                                    Ok(received) => {
+                                        match received {
+                                            Ok(package) => {
+                                                service.update_package(package);
+                                                service.warn_deprecated_data();
+                                                service.needs_restart = true;
+                                            }
+                                            Err(e) => {
+                                                outputln!(preamble service.service_group_str(),
+                                                          "Not updating; pre_update_backup \
+                                                           failed: {}",
+                                                          e);
+                                                *state = FollowerState::Waiting;
+                                                return false;
+                                            }
+                                        }
                                     }
                                     Err(TryRecvError::Empty) => return false,
                                     Err(TryRecvError::Disconnected) => {
@@ -266,11 +327,188 @@ impl ServiceUpdater {
         }
         service.needs_restart
     }
+
+    /// Counts a failure of the release the service was updated to to start, rolling the service
+    /// back to the release it was updated from once the release is quarantined, and forgets the
+    /// failures of a release that has proven itself. Returns true if the releases the service
+    /// should gossip as quarantined changed.
+    pub fn check_quarantine(&mut self, service: &mut Service) -> bool {
+        let service_name = service.service_group.service().to_string();
+        let mut changed = false;
+        if let Some(reason) = service.failed_start.take() {
+            let ident = service.package.ident().clone();
+            if record_failure(&service_name, &service.service_group, &ident, &reason) {
+                if let Some(release) = service.roll_back() {
+                    outputln!(preamble service.service_group_str(),
+                              "Rolling back to {}",
+                              release);
+                }
+                changed = true;
+            }
+        }
+        if let Some(ident) = service.prove_release() {
+            if let Err(e) = quarantine::update(&service_name, |q| q.record_success(&ident)) {
+                warn!("Couldn't record that {} started, err={}", ident, e);
+            }
+        }
+        let now = SteadyTime::now();
+        let due = self.quarantine_read_at
+            .get(&service.service_group)
+            .map_or(true, |at| now - *at >= TimeDuration::seconds(QUARANTINE_REFRESH_SECS));
+        if changed || due {
+            self.quarantine_read_at.insert(service.service_group.clone(), now);
+            match Quarantine::load(&service_name) {
+                Ok(quarantine) => {
+                    let quarantined = quarantine.quarantined();
+                    if quarantined != service.quarantined {
+                        service.quarantined = quarantined;
+                        return true;
+                    }
+                }
+                Err(e) => warn!("Couldn't read quarantined releases, err={}", e),
+            }
+        }
+        false
+    }
+}
+
+/// Receives the release the service's worker found, once the service group has an update
+/// slot for it and the running release's `pre_update_backup` hook, run on the hook pool, has
+/// succeeded; until then, it is as if the worker hadn't found one. If the hook fails, its
+/// error is received instead and the release dropped.
+fn receive(slots: &mut UpdateSlots,
+           backups: &mut HashMap<ServiceGroup, (Package, Option<HookTask>)>,
+           service: &Service,
+           rx: &Receiver<Package>)
+           -> result::Result<result::Result<Package, String>, TryRecvError> {
+    if !backups.contains_key(&service.service_group) {
+        let package = try!(slots.try_recv(service, rx));
+        backups.insert(service.service_group.clone(), (package, None));
+    }
+    let outcome = {
+        let &mut (ref package, ref mut backup) = backups.get_mut(&service.service_group).unwrap();
+        if backup.is_none() {
+            let running = service.package.clone();
+            let updated = package.clone();
+            let sg = service.service_group.clone();
+            // With every slot of the hook pool taken, this is tried again next time around.
+            *backup = HookTask::start(format!("pre-update-backup-{}", sg),
+                                      move || running.pre_update_backup(&sg, &updated));
+            return Err(TryRecvError::Empty);
+        }
+        match backup.as_ref().unwrap().poll() {
+            Some(outcome) => outcome,
+            None => return Err(TryRecvError::Empty),
+        }
+    };
+    let (package, _) = backups.remove(&service.service_group).unwrap();
+    match outcome {
+        Ok(backed_up) => {
+            if backed_up {
+                outputln!(preamble service.service_group_str(),
+                          "Backed up {} before updating to {}",
+                          service.package.ident(),
+                          package.ident());
+            }
+            Ok(Ok(package))
+        }
+        Err(e) => Ok(Err(e)),
+    }
+}
+
+/// Counts a failed attempt of the service to update to the release. Returns true if this
+/// failure quarantined the release.
+fn record_failure(service_name: &str,
+                  service_group: &ServiceGroup,
+                  ident: &PackageIdent,
+                  reason: &str)
+                  -> bool {
+    let attempts = gconfig().update_attempts();
+    let mut quarantined = false;
+    let result = quarantine::update(service_name, |q| {
+        quarantined = q.record_failure(ident, reason, attempts);
+        true
+    });
+    if let Err(e) = result {
+        warn!("Couldn't record the failed update to {}, err={}", ident, e);
+        return false;
+    }
+    if quarantined {
+        outputln!(preamble service_group.to_string(),
+                  "{} {} after {} failed attempt(s), last: {}; skipping it until a newer release \
+                   appears or it is cleared with `hab-sup quarantine clear`",
+                  Red.bold().paint("Quarantined"),
+                  ident,
+                  attempts,
+                  reason);
+    }
+    quarantined
+}
+
+/// The service groups' slots of the ring-wide semaphore over updates, when only so many members
+/// of a group may update at once. A member holds its slot from applying a new release until its
+/// service has restarted onto it.
+struct UpdateSlots {
+    butterfly: butterfly::Server,
+    limit: Option<usize>,
+    /// Releases the workers found, waiting for a slot.
+    pending: HashMap<ServiceGroup, Package>,
+}
+
+impl UpdateSlots {
+    fn new(butterfly: butterfly::Server, limit: Option<usize>) -> Self {
+        UpdateSlots {
+            butterfly: butterfly,
+            limit: limit,
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Receives the release the service's worker found, once the service group has a slot for
+    /// it; until then, it is as if the worker hadn't found one.
+    fn try_recv(&mut self,
+                service: &Service,
+                rx: &Receiver<Package>)
+                -> result::Result<Package, TryRecvError> {
+        let limit = match self.limit {
+            Some(limit) => limit,
+            None => return rx.try_recv(),
+        };
+        if !self.pending.contains_key(&service.service_group) {
+            let package = try!(rx.try_recv());
+            outputln!(preamble service.service_group_str(),
+                      "Waiting to update to {}; {} member(s) of the group update at a time",
+                      package.ident(),
+                      limit);
+            self.pending.insert(service.service_group.clone(), package);
+        }
+        if self.butterfly.request_update_lock(&service.service_group, limit) {
+            Ok(self.pending.remove(&service.service_group).unwrap())
+        } else {
+            Err(TryRecvError::Empty)
+        }
+    }
+
+    /// Gives back the service group's slot, if we have it and the service isn't still waiting to
+    /// restart onto its new release. This also gives back a slot we held before the Supervisor
+    /// was last restarted.
+    fn release_if_done(&mut self, service: &Service) {
+        if self.limit.is_none() || service.needs_restart ||
+           self.pending.contains_key(&service.service_group) {
+            return;
+        }
+        self.butterfly.release_update_lock(&service.service_group);
+    }
 }
 
 struct Worker {
     current: PackageIdent,
-    depot: depot_client::Client,
+    service_group: ServiceGroup,
+    /// The release of the composite the service belongs to, if it belongs to one.
+    composite: Option<PackageIdent>,
+    /// The lockfile the Supervisor was started with, as last read; updates must be to releases
+    /// it pins.
+    lock: Option<LockFile>,
     ui: UI,
 }
 
@@ -278,7 +516,9 @@ impl Worker {
     pub fn new(service: &Service) -> Self {
         Worker {
             current: service.package.ident().clone(),
-            depot: depot_client::Client::new(gconfig().url(), PRODUCT, VERSION, None).unwrap(),
+            service_group: service.service_group.clone(),
+            composite: service.package.composite.clone(),
+            lock: None,
             ui: UI::default(),
         }
     }
@@ -302,16 +542,30 @@ impl Worker {
 
     fn run_once(&mut self, sender: SyncSender<Package>, ident: PackageIdent) {
         outputln!("Updating from {} to {}", self.current, ident);
+        let mut skipped = false;
         loop {
             let next_check = SteadyTime::now() +
                              TimeDuration::milliseconds(UPDATE_STRATEGY_FREQUENCY_MS);
-            match self.install(&ident, true) {
-                Ok(package) => {
-                    self.current = package.ident().clone();
-                    sender.send(package).expect("Main thread has gone away!");
-                    break;
+            if self.is_quarantined(&ident) {
+                if !skipped {
+                    outputln!("Not updating to {}; it is quarantined", ident);
+                    skipped = true;
+                }
+            } else {
+                skipped = false;
+                // The leader's release is only followed once the lockfile pins it.
+                match self.reload_lock().and_then(|_| self.install(&ident, true)) {
+                    Ok(mut package) => {
+                        self.current = package.ident().clone();
+                        package.composite = self.composite.clone();
+                        sender.send(package).expect("Main thread has gone away!");
+                        break;
+                    }
+                    Err(e) => {
+                        warn!("Failed to install updated package: {:?}", e);
+                        self.record_failure(&ident, &e.to_string());
+                    }
                 }
-                Err(e) => warn!("Failed to install updated package: {:?}", e),
             }
             let time_to_wait = (next_check - SteadyTime::now()).num_milliseconds();
             if time_to_wait > 0 {
@@ -321,25 +575,37 @@ impl Worker {
     }
 
     fn run_poll(&mut self, sender: SyncSender<Package>) {
+        let mut skipped = None;
         loop {
             let next_check = SteadyTime::now() +
                              TimeDuration::milliseconds(UPDATE_STRATEGY_FREQUENCY_MS);
-            // TODO fn: We don't want to reach into a global config for the package argument given
-            // to the `start` subcommand. Instead, each Service will have this peice of information
-            // but for the moment we're going to go global.
-            let initial_ident = gconfig().package();
-            match self.depot.show_package(initial_ident) {
-                Ok(remote) => {
-                    let latest: PackageIdent = remote.get_ident().clone().into();
-                    if latest > self.current {
-                        outputln!("Updating from {} to {}", self.current, latest);
-                        match self.install(&latest, true) {
-                            Ok(package) => {
-                                self.current = latest;
+            match self.latest() {
+                Ok(latest) => {
+                    let current = self.composite.as_ref().unwrap_or(&self.current).clone();
+                    // A lockfile may pin an older release than the one running, to roll back.
+                    let newer = if self.lock.is_some() {
+                        latest != current
+                    } else {
+                        latest > current
+                    };
+                    if newer && self.is_quarantined(&latest) {
+                        if skipped.as_ref() != Some(&latest) {
+                            outputln!("Not updating to {}; it is quarantined", latest);
+                            skipped = Some(latest);
+                        }
+                    } else if newer {
+                        outputln!("Updating from {} to {}", current, latest);
+                        match self.install_latest(&latest) {
+                            Ok(Some(package)) => {
+                                self.current = package.ident().clone();
                                 sender.send(package).expect("Main thread has gone away!");
                                 break;
                             }
-                            Err(e) => warn!("Failed to install updated package: {:?}", e),
+                            Ok(None) => info!("{} doesn't change this service's package", latest),
+                            Err(e) => {
+                                warn!("Failed to install updated package: {:?}", e);
+                                self.record_failure(&latest, &e.to_string());
+                            }
                         }
                     } else {
                         info!("Package found is not newer than ours");
@@ -354,11 +620,92 @@ impl Worker {
         }
     }
 
+    /// Returns the release of the package the service was started from that it should run: the
+    /// one the lockfile pins if the Supervisor was started with one, otherwise the latest in the
+    /// Depot. The lockfile is read again each time, so that it can be changed while running.
+    fn latest(&mut self) -> Result<PackageIdent> {
+        // TODO fn: We don't want to reach into a global config for the package argument given
+        // to the `start` subcommand. Instead, each Service will have this peice of information
+        // but for the moment we're going to go global.
+        let initial_ident = gconfig().package();
+        try!(self.reload_lock());
+        match self.lock {
+            Some(ref lock) => Ok(try!(lock.release_for(initial_ident))),
+            None => {
+                let remote = try!(mirrors::with_failover(|url| {
+                    let depot = try!(depot_client::Client::new(url, PRODUCT, VERSION, None));
+                    Ok(try!(depot.show_package(initial_ident)))
+                }));
+                Ok(remote.get_ident().clone().into())
+            }
+        }
+    }
+
+    /// Returns true if updating to the release failed so often it is skipped. The quarantine is
+    /// read again each time, so releases an operator cleared are tried again.
+    fn is_quarantined(&self, ident: &PackageIdent) -> bool {
+        match Quarantine::load(self.service_group.service()) {
+            Ok(quarantine) => quarantine.is_quarantined(ident),
+            Err(e) => {
+                warn!("Couldn't read quarantined releases, err={}", e);
+                false
+            }
+        }
+    }
+
+    /// Counts a failed attempt to install the release, unless it failed because the lockfile
+    /// doesn't pin it yet.
+    fn record_failure(&self, ident: &PackageIdent, reason: &str) {
+        if let Some(ref lock) = self.lock {
+            if lock.check(&[ident.clone()]).is_err() {
+                return;
+            }
+        }
+        record_failure(self.service_group.service(), &self.service_group, ident, reason);
+    }
+
+    /// Reads the lockfile the Supervisor was started with again, if it was started with one.
+    fn reload_lock(&mut self) -> Result<()> {
+        if let Some(lock_path) = gconfig().lock_file() {
+            self.lock = Some(try!(LockFile::load(lock_path)));
+        }
+        Ok(())
+    }
+
+    /// Installs the latest release of the package the service was started from. For a service of
+    /// a composite, that's a new release of the composite, which names the service's new package;
+    /// None is returned if the composite's release didn't change it.
+    fn install_latest(&mut self, latest: &PackageIdent) -> Result<Option<Package>> {
+        let package = try!(self.install(latest, true));
+        if self.composite.is_none() {
+            return Ok(Some(package));
+        }
+        self.composite = Some(latest.clone());
+        let member = try!(package.pkg_install.services())
+            .into_iter()
+            .find(|s| {
+                s.ident.origin == self.current.origin &&
+                s.service_name() == self.service_group.service()
+            });
+        match member {
+            Some(ref member) if member.ident != self.current => {
+                let mut package = try!(self.install(&member.ident, false));
+                package.composite = Some(latest.clone());
+                Ok(Some(package))
+            }
+            _ => Ok(None),
+        }
+    }
+
     fn install(&mut self, package: &PackageIdent, recurse: bool) -> Result<Package> {
         let package = match Package::load(package, None) {
             Ok(pkg) => pkg,
-            Err(_) => try!(self.download(package)),
+            Err(_) => try!(self.download(package, recurse)),
         };
+        if let Some(ref lock) = self.lock {
+            try!(lock.check(&[package.ident().clone()]));
+            try!(lock.check(&package.tdeps));
+        }
         if recurse {
             for ident in package.tdeps.iter() {
                 try!(self.install(&ident, false));
@@ -367,15 +714,71 @@ impl Worker {
         Ok(package)
     }
 
-    fn download(&mut self, package: &PackageIdent) -> Result<Package> {
-        outputln!("Downloading {}", package);
-        let mut archive = try!(self.depot.fetch_package(package,
-                                                        &Path::new(FS_ROOT_PATH)
-                                                            .join(CACHE_ARTIFACT_PATH),
-                                                        self.ui.progress()));
-        try!(archive.verify(&default_cache_key_path(None)));
-        outputln!("Installing {}", package);
-        try!(archive.unpack(None));
-        Package::load(archive.ident().as_ref().unwrap(), None)
+    /// Downloads and installs `package`, along with those of its dependencies which aren't
+    /// installed if `with_deps` is set. Nothing is unpacked unless the license policy allows
+    /// every one of them, as `hab pkg install` does.
+    fn download(&mut self, package: &PackageIdent, with_deps: bool) -> Result<Package> {
+        let mut archives = vec![try!(self.fetch(package))];
+        if with_deps {
+            for dep in try!(archives[0].tdeps()) {
+                if Package::load(&dep, None).is_err() {
+                    archives.push(try!(self.fetch(&dep)));
+                }
+            }
+        }
+        let policy_path = LicensePolicy::default_path(Some(&gconfig().fs_root()));
+        if let Some(policy) = try!(LicensePolicy::load(policy_path)) {
+            for archive in archives.iter_mut() {
+                let ident = try!(archive.ident());
+                try!(policy.check(&ident, &try!(archive.licenses())));
+            }
+        }
+        // Dependencies first, so a package is never installed without them.
+        for archive in archives.iter_mut().rev() {
+            outputln!("Installing {}", try!(archive.ident()));
+            try!(archive.unpack(None));
+        }
+        let ident = try!(archives[0].ident());
+        Package::load(&ident, None)
+    }
+
+    /// Downloads `package` to the artifact cache, or finds it there, verifies it and passes it to
+    /// the artifact scanner.
+    fn fetch(&mut self, package: &PackageIdent) -> Result<PackageArchive> {
+        let cache = ArtifactCache::new(cache_artifact_path(Some(&gconfig().fs_root())));
+        // Holding the artifact's lock while it is cached makes a `hab pkg install` of the same
+        // package wait for this download, rather than start its own or read a partial artifact.
+        let lock = if package.fully_qualified() {
+            Some(try!(cache.lock(package)))
+        } else {
+            None
+        };
+        let mut archive = if lock.is_some() && try!(cache.contains(package)) {
+            outputln!("Using {} from the artifact cache", package);
+            let archive = PackageArchive::new(try!(cache.artifact_path(package)));
+            try!(archive.verify(&default_cache_key_path(None)));
+            archive
+        } else {
+            outputln!("Downloading {}", package);
+            #[cfg(feature = "chaos")]
+            try!(chaos::check_download(package));
+            let ui = &mut self.ui;
+            try!(mirrors::with_failover(|url| {
+                let depot = try!(depot_client::Client::new(url, PRODUCT, VERSION, None));
+                Ok(try!(depot.fetch_package_verified(package,
+                                                     cache.path(),
+                                                     &default_cache_key_path(None),
+                                                     ui.progress())))
+            }))
+        };
+        let scanner_path = ArtifactScanner::default_path(Some(&gconfig().fs_root()));
+        if let Some(scanner) = try!(ArtifactScanner::load(scanner_path)) {
+            let ident = try!(archive.ident());
+            outputln!("Scanning {} with {}", ident, scanner.command);
+            if let Some(finding) = try!(scanner.scan(&ident, &archive.path)) {
+                outputln!("{}", finding);
+            }
+        }
+        Ok(archive)
     }
 }
@@ -25,8 +25,10 @@ mod unix;
 #[cfg(windows)]
 mod windows;
 
+pub use hcore::os::process::Signal;
+
 #[cfg(unix)]
-pub use manager::signals::unix::{init, check_for_signal, send_signal, Signal};
+pub use manager::signals::unix::{init, check_for_signal, send_signal};
 
 #[cfg(windows)]
-pub use manager::signals::windows::{init, check_for_signal, send_signal, Signal};
+pub use manager::signals::windows::{init, check_for_signal, send_signal};
@@ -17,6 +17,9 @@
 use std::sync::{Once, ONCE_INIT};
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering, ATOMIC_USIZE_INIT, ATOMIC_BOOL_INIT};
 
+use hcore::os::process::{self, Signal};
+use libc;
+
 use error::{Error, Result};
 use super::SignalEvent;
 
@@ -28,39 +31,20 @@ static CAUGHT: AtomicBool = ATOMIC_BOOL_INIT;
 // Stores the value of the signal we caught
 static SIGNAL: AtomicUsize = ATOMIC_USIZE_INIT;
 
-// Functions from POSIX libc.
-extern "C" {
-    fn signal(sig: u32, cb: unsafe extern "C" fn(u32)) -> unsafe extern "C" fn(u32);
-    fn kill(pid: i32, sig: u32) -> u32;
-}
+/// The signals we trap. Anything else keeps its default disposition.
+const TRAPPED: [Signal; 7] = [Signal::SIGHUP,
+                              Signal::SIGINT,
+                              Signal::SIGQUIT,
+                              Signal::SIGALRM,
+                              Signal::SIGTERM,
+                              Signal::SIGUSR1,
+                              Signal::SIGUSR2];
 
-unsafe extern "C" fn handle_signal(signal: u32) {
+extern "C" fn handle_signal(signal: libc::c_int) {
     CAUGHT.store(true, Ordering::SeqCst);
     SIGNAL.store(signal as usize, Ordering::SeqCst);
 }
 
-/// `i32` representation of each Unix Signal of interest.
-#[allow(dead_code)]
-#[derive(Debug, Clone)]
-pub enum Signal {
-    /// terminate process - terminal line hangup
-    SIGHUP = 1,
-    /// terminate process - interrupt program
-    SIGINT = 2,
-    /// create core image - quit program
-    SIGQUIT = 3,
-    /// Kill a process
-    SIGKILL = 9,
-    /// terminate process - real-time timer expired
-    SIGALRM = 14,
-    /// terminate process - software termination signal
-    SIGTERM = 15,
-    /// terminate process - User defined signal 1
-    SIGUSR1 = 30,
-    /// terminate process - User defined signal 2
-    SIGUSR2 = 31,
-}
-
 pub fn init() {
     INIT.call_once(|| {
         self::set_signal_handlers();
@@ -71,25 +55,12 @@ pub fn init() {
 
 pub fn check_for_signal() -> Option<SignalEvent> {
     if CAUGHT.load(Ordering::SeqCst) {
-        let result = match SIGNAL.load(Ordering::SeqCst) {
-            signal if signal == Signal::SIGHUP as usize => {
-                SignalEvent::Passthrough(Signal::SIGHUP as u32)
-            }
-            signal if signal == Signal::SIGINT as usize => SignalEvent::Shutdown,
-            signal if signal == Signal::SIGQUIT as usize => {
-                SignalEvent::Passthrough(Signal::SIGQUIT as u32)
-            }
-            signal if signal == Signal::SIGALRM as usize => {
-                SignalEvent::Passthrough(Signal::SIGALRM as u32)
-            }
-            signal if signal == Signal::SIGTERM as usize => SignalEvent::Shutdown,
-            signal if signal == Signal::SIGUSR1 as usize => {
-                SignalEvent::Passthrough(Signal::SIGUSR1 as u32)
-            }
-            signal if signal == Signal::SIGUSR2 as usize => {
-                SignalEvent::Passthrough(Signal::SIGUSR2 as u32)
-            }
-            signal => {
+        let signal = SIGNAL.load(Ordering::SeqCst) as libc::c_int;
+        let result = match process::signal_from_number(signal) {
+            Some(Signal::SIGINT) |
+            Some(Signal::SIGTERM) => SignalEvent::Shutdown,
+            Some(sig) if TRAPPED.contains(&sig) => SignalEvent::Passthrough(signal as u32),
+            _ => {
                 outputln!("Received invalid signal: #{}", signal);
                 return None;
             }
@@ -104,14 +75,11 @@ pub fn check_for_signal() -> Option<SignalEvent> {
 }
 
 fn set_signal_handlers() {
-    unsafe {
-        signal(Signal::SIGHUP as u32, handle_signal);
-        signal(Signal::SIGINT as u32, handle_signal);
-        signal(Signal::SIGQUIT as u32, handle_signal);
-        signal(Signal::SIGALRM as u32, handle_signal);
-        signal(Signal::SIGTERM as u32, handle_signal);
-        signal(Signal::SIGUSR1 as u32, handle_signal);
-        signal(Signal::SIGUSR2 as u32, handle_signal);
+    for sig in TRAPPED.iter() {
+        unsafe {
+            libc::signal(process::signal_number(*sig),
+                         handle_signal as libc::sighandler_t);
+        }
     }
 }
 
@@ -119,8 +87,7 @@ fn set_signal_handlers() {
 pub fn send_signal(pid: u32, sig: u32) -> Result<()> {
     debug!("sending signal {} to pid {}", sig, pid);
     unsafe {
-        let result = kill(pid as i32, sig);
-        match result {
+        match libc::kill(pid as libc::pid_t, sig as libc::c_int) {
             0 => Ok(()),
             _ => return Err(sup_error!(Error::SignalFailed)),
         }
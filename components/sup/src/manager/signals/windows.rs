@@ -17,28 +17,6 @@
 use error::{Error, Result, SupError};
 use super::SignalEvent;
 
-/// This is complete bullshit!
-#[allow(dead_code)]
-#[derive(Debug, Clone)]
-pub enum Signal {
-    /// terminate process - terminal line hangup
-    SIGHUP = 1,
-    /// terminate process - interrupt program
-    SIGINT = 2,
-    /// create core image - quit program
-    SIGQUIT = 3,
-    /// Kill a process
-    SIGKILL = 9,
-    /// terminate process - real-time timer expired
-    SIGALRM = 14,
-    /// terminate process - software termination signal
-    SIGTERM = 15,
-    /// terminate process - User defined signal 1
-    SIGUSR1 = 30,
-    /// terminate process - User defined signal 2
-    SIGUSR2 = 31,
-}
-
 pub fn init() {}
 
 pub fn check_for_signal() -> Option<SignalEvent> {
@@ -0,0 +1,186 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The Depots the Supervisor installs and updates packages from.
+//!
+//! Besides the Depot given with `--url`, the Supervisor may be given mirrors of it with
+//! `--mirror URL[,PRIORITY]`. Depots are tried in order of priority, lowest first; the `--url`
+//! Depot has priority 0, and mirrors default to priority 1. A Depot which can't be reached, or
+//! answers with a server error, is marked down and skipped for `MIRROR_DOWN_SECS`, after which
+//! it is tried again. Errors such as a package not being found aren't outages, and are returned
+//! straight away.
+
+use std::collections::HashMap;
+use std::result;
+use std::str::FromStr;
+use std::sync::RwLock;
+
+use common;
+use depot_client;
+use hyper::status::StatusCode;
+use time::{Duration, SteadyTime};
+
+use config::gconfig;
+use error::{Error, Result, SupError};
+
+static LOGKEY: &'static str = "MR";
+
+/// How long a Depot which failed is skipped before it is tried again.
+pub const MIRROR_DOWN_SECS: i64 = 60;
+
+/// The priority of a mirror given without one.
+pub const DEFAULT_MIRROR_PRIORITY: u32 = 1;
+
+lazy_static! {
+    /// The Depots which are down, and when they were marked down.
+    static ref DOWN: RwLock<HashMap<String, SteadyTime>> = RwLock::new(HashMap::new());
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mirror {
+    pub url: String,
+    /// Lower priorities are tried first.
+    pub priority: u32,
+}
+
+impl Mirror {
+    pub fn new(url: String, priority: u32) -> Self {
+        Mirror {
+            url: url,
+            priority: priority,
+        }
+    }
+}
+
+impl FromStr for Mirror {
+    type Err = String;
+
+    /// Parses a mirror given as `URL` or `URL,PRIORITY`.
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        let (url, priority) = match value.rfind(',') {
+            Some(i) => {
+                match value[i + 1..].parse() {
+                    Ok(priority) => (&value[..i], priority),
+                    Err(_) => {
+                        return Err(format!("The priority of mirror {} must be a whole number, \
+                                            not {}",
+                                           &value[..i],
+                                           &value[i + 1..]))
+                    }
+                }
+            }
+            None => (value, DEFAULT_MIRROR_PRIORITY),
+        };
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            return Err(format!("Mirror {} must be an http or https URL", url));
+        }
+        Ok(Mirror::new(url.to_string(), priority))
+    }
+}
+
+/// Calls `f` with the URL of each Depot in order of priority until it succeeds, skipping those
+/// which are down. When every Depot is down they are all tried anyway, so that the Supervisor
+/// recovers as soon as one comes back.
+///
+/// # Failures
+///
+/// * `f` fails with an error which isn't an outage
+/// * `f` fails against every Depot; the last error is returned
+pub fn with_failover<T, F>(mut f: F) -> Result<T>
+    where F: FnMut(&str) -> Result<T>
+{
+    let depots = gconfig().depots();
+    let mut up: Vec<&Mirror> = depots.iter().filter(|d| !is_down(&d.url)).collect();
+    if up.is_empty() {
+        up = depots.iter().collect();
+    }
+    let mut last_err = None;
+    for depot in up {
+        match f(&depot.url) {
+            Ok(value) => {
+                mark_up(&depot.url);
+                return Ok(value);
+            }
+            Err(e) => {
+                if !is_outage(&e) {
+                    return Err(e);
+                }
+                outputln!("Depot {} is unavailable, trying the next mirror: {}",
+                          depot.url,
+                          e);
+                mark_down(&depot.url);
+                last_err = Some(e);
+            }
+        }
+    }
+    Err(last_err.unwrap_or(sup_error!(Error::NoDepotAvailable)))
+}
+
+fn is_down(url: &str) -> bool {
+    match DOWN.read().expect("Mirror lock is poisoned").get(url) {
+        Some(since) => SteadyTime::now() - *since < Duration::seconds(MIRROR_DOWN_SECS),
+        None => false,
+    }
+}
+
+fn mark_down(url: &str) {
+    DOWN.write().expect("Mirror lock is poisoned").insert(url.to_string(), SteadyTime::now());
+}
+
+fn mark_up(url: &str) {
+    let mut down = DOWN.write().expect("Mirror lock is poisoned");
+    if down.remove(url).is_some() {
+        outputln!("Depot {} is available again", url);
+    }
+}
+
+/// Returns whether an error means the Depot itself is unavailable, rather than that the request
+/// can't succeed against any Depot.
+fn is_outage(err: &SupError) -> bool {
+    match err.err {
+        Error::DepotClient(ref e) => is_depot_outage(e),
+        Error::HabitatCommon(common::Error::DepotClient(ref e)) => is_depot_outage(e),
+        _ => false,
+    }
+}
+
+fn is_depot_outage(err: &depot_client::Error) -> bool {
+    match *err {
+        depot_client::Error::APIError(status, _) => {
+            status.is_server_error() || status == StatusCode::TooManyRequests
+        }
+        depot_client::Error::HabitatHttpClient(_) |
+        depot_client::Error::HyperError(_) |
+        depot_client::Error::IO(_) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{Mirror, DEFAULT_MIRROR_PRIORITY};
+
+    #[test]
+    fn mirror_from_str() {
+        assert_eq!(Mirror::from_str("https://depot.eu.example.com/v1/depot").unwrap(),
+                   Mirror::new(String::from("https://depot.eu.example.com/v1/depot"),
+                               DEFAULT_MIRROR_PRIORITY));
+        assert_eq!(Mirror::from_str("http://10.0.0.5:9636/v1/depot,5").unwrap(),
+                   Mirror::new(String::from("http://10.0.0.5:9636/v1/depot"), 5));
+        assert!(Mirror::from_str("http://10.0.0.5/v1/depot,first").is_err());
+        assert!(Mirror::from_str("depot.example.com").is_err());
+    }
+}
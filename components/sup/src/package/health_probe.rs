@@ -0,0 +1,304 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! HTTP and TCP probes of a service's health.
+//!
+//! Packages declare a probe with the `pkg_svc_health_probe` plan variable, and the Supervisor
+//! runs it in place of the `health_check` hook. An `http://` probe is healthy when its URL answers
+//! with the expected status, and a `tcp://host:port` probe is healthy when the address accepts a
+//! connection. Neither starts a process, so frequent checks stay cheap on a busy host.
+//!
+//! Both are bounded by the probe's timeout from start to finish: the connection is opened with
+//! it, and an `http://` probe sends its request and reads the status line over that connection
+//! with what's left of it, so a service which accepts connections but never answers is critical
+//! in time.
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::{Duration, Instant};
+
+use hcore::package::PackageInstall;
+use url::Url;
+
+use {PRODUCT, VERSION};
+
+use error::{Error, Result};
+use health_check::CheckResult;
+
+static LOGKEY: &'static str = "HP";
+
+/// The status an `http://` probe expects when the package doesn't name one.
+pub const DEFAULT_STATUS: u16 = 200;
+/// How long a probe may take when the package doesn't say.
+pub const DEFAULT_TIMEOUT_SECS: u64 = 5;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct HealthProbe {
+    pub url: String,
+    /// The HTTP status an `http://` probe expects.
+    pub status: u16,
+    pub timeout_secs: u64,
+}
+
+impl HealthProbe {
+    /// Load the probe declared by a package, if it declares one.
+    pub fn load(pkg_install: &PackageInstall) -> Result<Option<Self>> {
+        let raw = try!(pkg_install.svc_health_probe());
+        Self::from_map(raw)
+    }
+
+    fn from_map(mut raw: HashMap<String, String>) -> Result<Option<Self>> {
+        if raw.is_empty() {
+            return Ok(None);
+        }
+        let url = match raw.remove("url") {
+            Some(url) => url,
+            None => return Err(sup_error!(Error::InvalidHealthProbe(String::from("no url")))),
+        };
+        let parsed = try!(Url::parse(&url).map_err(|e| {
+            sup_error!(Error::InvalidHealthProbe(format!("url {}: {}", url, e)))
+        }));
+        match parsed.scheme() {
+            "http" => {}
+            "tcp" if parsed.port().is_some() => {}
+            "tcp" => {
+                return Err(sup_error!(Error::InvalidHealthProbe(format!("url {} has no port",
+                                                                        url))))
+            }
+            scheme => {
+                return Err(sup_error!(Error::InvalidHealthProbe(format!("url scheme {} must \
+                                                                         be http or tcp",
+                                                                        scheme))))
+            }
+        }
+        let mut probe = HealthProbe {
+            url: url,
+            status: DEFAULT_STATUS,
+            timeout_secs: DEFAULT_TIMEOUT_SECS,
+        };
+        for (setting, value) in raw.into_iter() {
+            match setting.as_str() {
+                "status" => {
+                    probe.status = try!(value.parse().map_err(|_| {
+                        sup_error!(Error::InvalidHealthProbe(format!("status {} is not an HTTP \
+                                                                      status",
+                                                                     value)))
+                    }));
+                }
+                "timeout" => {
+                    probe.timeout_secs = try!(value.parse().map_err(|_| {
+                        sup_error!(Error::InvalidHealthProbe(format!("timeout {} is not a \
+                                                                      number of seconds",
+                                                                     value)))
+                    }));
+                }
+                _ => {
+                    return Err(sup_error!(Error::InvalidHealthProbe(format!("unknown setting \
+                                                                             {}",
+                                                                            setting))))
+                }
+            }
+        }
+        Ok(Some(probe))
+    }
+
+    /// Run the probe. A probe which fails or times out is critical.
+    pub fn check(&self) -> CheckResult {
+        let timeout = Duration::from_secs(self.timeout_secs);
+        let healthy = if self.url.starts_with("tcp://") {
+            self.connect(timeout)
+        } else {
+            self.request(timeout)
+        };
+        if healthy {
+            CheckResult::Ok
+        } else {
+            CheckResult::Critical
+        }
+    }
+
+    fn request(&self, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        let url = match Url::parse(&self.url) {
+            Ok(url) => url,
+            Err(_) => return false,
+        };
+        let stream = match self.open(&url, timeout) {
+            Some(stream) => stream,
+            None => return false,
+        };
+        match self.status_of(&url, stream, deadline) {
+            Ok(status) => {
+                let healthy = status == self.status;
+                if !healthy {
+                    debug!("Health probe {} answered {}, expected {}",
+                           self.url,
+                           status,
+                           self.status);
+                }
+                healthy
+            }
+            Err(e) => {
+                debug!("Health probe {} failed: {}", self.url, e);
+                false
+            }
+        }
+    }
+
+    /// Sends a GET of the probe's URL over `stream`, and returns the status it's answered with,
+    /// giving up at `deadline`.
+    fn status_of(&self,
+                 url: &Url,
+                 mut stream: TcpStream,
+                 deadline: Instant)
+                 -> ::std::result::Result<u16, String> {
+        let path = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        };
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err(String::from("no host")),
+        };
+        try!(stream.set_write_timeout(Some(try!(remaining(deadline))))
+            .map_err(|e| e.to_string()));
+        try!(write!(stream,
+                    "GET {} HTTP/1.0\r\nHost: {}\r\nUser-Agent: {}/{}\r\nConnection: \
+                     close\r\n\r\n",
+                    path,
+                    host,
+                    PRODUCT,
+                    VERSION)
+            .map_err(|e| e.to_string()));
+        try!(stream.set_read_timeout(Some(try!(remaining(deadline))))
+            .map_err(|e| e.to_string()));
+        let mut line = String::new();
+        try!(BufReader::new(stream).read_line(&mut line).map_err(|e| e.to_string()));
+        // A status line is `HTTP/1.1 200 OK`
+        line.split_whitespace()
+            .nth(1)
+            .and_then(|status| status.parse().ok())
+            .ok_or_else(|| format!("answered with {:?}, not an HTTP status line", line.trim()))
+    }
+
+    fn connect(&self, timeout: Duration) -> bool {
+        match Url::parse(&self.url) {
+            Ok(url) => self.open(&url, timeout).is_some(),
+            Err(_) => false,
+        }
+    }
+
+    /// Opens a connection to the probe's address, trying each it resolves to for up to
+    /// `timeout`.
+    fn open(&self, url: &Url, timeout: Duration) -> Option<TcpStream> {
+        let addrs = match (url.host_str(), url.port_or_known_default()) {
+            (Some(host), Some(port)) => (host, port).to_socket_addrs().ok(),
+            _ => None,
+        };
+        let addrs = match addrs {
+            Some(addrs) => addrs,
+            None => {
+                debug!("Health probe {} did not resolve", self.url);
+                return None;
+            }
+        };
+        for addr in addrs {
+            match TcpStream::connect_timeout(&addr, timeout) {
+                Ok(stream) => return Some(stream),
+                Err(e) => debug!("Health probe {} failed to connect to {}: {}", self.url, addr, e),
+            }
+        }
+        None
+    }
+}
+
+/// Returns the time left until `deadline`, or fails if it has passed.
+fn remaining(deadline: Instant) -> ::std::result::Result<Duration, String> {
+    let now = Instant::now();
+    if now < deadline {
+        Ok(deadline - now)
+    } else {
+        Err(String::from("timed out"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::io::Write;
+    use std::net::TcpListener;
+    use std::thread;
+
+    use super::{HealthProbe, DEFAULT_STATUS, DEFAULT_TIMEOUT_SECS};
+    use error::Result;
+    use health_check::CheckResult;
+
+    fn probe(settings: &[(&str, &str)]) -> Result<Option<HealthProbe>> {
+        let raw: HashMap<String, String> =
+            settings.iter().map(|&(k, v)| (k.to_string(), v.to_string())).collect();
+        HealthProbe::from_map(raw)
+    }
+
+    #[test]
+    fn from_map_defaults() {
+        assert_eq!(probe(&[]).unwrap(), None);
+        let p = probe(&[("url", "http://localhost:8080/health")]).unwrap().unwrap();
+        assert_eq!(p.status, DEFAULT_STATUS);
+        assert_eq!(p.timeout_secs, DEFAULT_TIMEOUT_SECS);
+        let p = probe(&[("url", "tcp://localhost:5432"), ("timeout", "2")]).unwrap().unwrap();
+        assert_eq!(p.timeout_secs, 2);
+    }
+
+    #[test]
+    fn from_map_rejects_bad_probes() {
+        assert!(probe(&[("status", "200")]).is_err());
+        assert!(probe(&[("url", "https://localhost/health")]).is_err());
+        assert!(probe(&[("url", "tcp://localhost")]).is_err());
+        assert!(probe(&[("url", "http://localhost/"), ("status", "ok")]).is_err());
+        assert!(probe(&[("url", "http://localhost/"), ("retries", "3")]).is_err());
+    }
+
+    #[test]
+    fn tcp_probe_checks_the_address_accepts_connections() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("tcp://{}", listener.local_addr().unwrap());
+        let p = probe(&[("url", &url)]).unwrap().unwrap();
+        assert_eq!(p.check(), CheckResult::Ok);
+        drop(listener);
+        assert_eq!(p.check(), CheckResult::Critical);
+    }
+
+    #[test]
+    fn http_probe_checks_the_status() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/health", listener.local_addr().unwrap());
+        thread::spawn(move || for status in &[200, 503] {
+            let (mut stream, _) = listener.accept().unwrap();
+            write!(stream, "HTTP/1.1 {} Whatever\r\n\r\n", status).unwrap();
+        });
+        let p = probe(&[("url", &url)]).unwrap().unwrap();
+        assert_eq!(p.check(), CheckResult::Ok);
+        assert_eq!(p.check(), CheckResult::Critical);
+    }
+
+    #[test]
+    fn http_probe_times_out_when_the_service_never_answers() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/health", listener.local_addr().unwrap());
+        let p = probe(&[("url", &url), ("timeout", "1")]).unwrap().unwrap();
+        assert_eq!(p.check(), CheckResult::Critical);
+    }
+}
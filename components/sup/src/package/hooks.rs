@@ -12,25 +12,56 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt;
 use std::fs::{self, File};
 use std::io::BufReader;
 use std::io::prelude::*;
 use std::path::PathBuf;
-use std::process::Child;
+use std::process::{Child, Command, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 
+use hcore::os::process::Confinement;
 use hcore::service::ServiceGroup;
 use hcore::util;
 
+#[cfg(feature = "chaos")]
+use chaos;
 use error::{Error, Result};
+use hook_log;
+use manager::census::{census_file_path, CENSUS_ENVVAR};
+use manager::service::{Namespace, ProcessLimits};
 use manager::service::config::ServiceConfig;
-use package::Package;
-use templating::Template;
+use package::{HookInterpreter, Package, Sandbox};
+use redact;
+use templating::TemplateEngine;
 use util::convert;
 use util::users as hab_users;
 use util as sup_util;
 
 pub const HOOK_PERMISSIONS: u32 = 0o755;
+
+/// How often a hook whose output is being collected is checked for having exited.
+const OUTPUT_POLL_MS: u64 = 100;
+
+/// Environment variable giving the provisioning hooks the number of members the service group
+/// should have.
+pub const DESIRED_COUNT_ENVVAR: &'static str = "HAB_DESIRED_COUNT";
+/// Environment variable giving the provisioning hooks the number of alive members of the service
+/// group.
+pub const ALIVE_COUNT_ENVVAR: &'static str = "HAB_ALIVE_COUNT";
+/// Environment variable giving the update hooks the identifier of the release being replaced.
+pub const PREVIOUS_PKG_IDENT_ENVVAR: &'static str = "HAB_PREVIOUS_PKG_IDENT";
+/// Environment variable giving the update hooks the path of the release being replaced.
+pub const PREVIOUS_PKG_PATH_ENVVAR: &'static str = "HAB_PREVIOUS_PKG_PATH";
+/// Environment variable giving the update hooks the identifier of the release being updated to.
+pub const UPDATED_PKG_IDENT_ENVVAR: &'static str = "HAB_UPDATED_PKG_IDENT";
+/// Environment variable giving the update hooks the path of the release being updated to.
+pub const UPDATED_PKG_PATH_ENVVAR: &'static str = "HAB_UPDATED_PKG_PATH";
+/// Environment variable giving the validate_config hook the directory of the configuration files
+/// rendered from the proposed configuration.
+pub const PROPOSED_CONFIG_PATH_ENVVAR: &'static str = "HAB_PROPOSED_CONFIG_PATH";
 static LOGKEY: &'static str = "PH";
 
 #[derive(Debug, Clone, Copy)]
@@ -40,6 +71,18 @@ pub enum HookType {
     FileUpdated,
     Run,
     Init,
+    /// A hook run on a schedule declared with `pkg_svc_scheduled_hooks`.
+    Scheduled,
+    /// Run when the service group has fewer alive members than its desired count.
+    UnderProvisioned,
+    /// Run when the service group has more alive members than its desired count.
+    OverProvisioned,
+    /// Run by the release being replaced before the service is updated.
+    PreUpdateBackup,
+    /// Run by the updated release before its process first starts.
+    PostUpdateRestore,
+    /// Run against the files rendered from a proposed configuration before it's applied.
+    ValidateConfig,
 }
 
 impl fmt::Display for HookType {
@@ -50,17 +93,30 @@ impl fmt::Display for HookType {
             &HookType::FileUpdated => write!(f, "file_updated"),
             &HookType::Reconfigure => write!(f, "reconfigure"),
             &HookType::Run => write!(f, "run"),
+            &HookType::Scheduled => write!(f, "scheduled"),
+            &HookType::UnderProvisioned => write!(f, "under_provisioned"),
+            &HookType::OverProvisioned => write!(f, "over_provisioned"),
+            &HookType::PreUpdateBackup => write!(f, "pre_update_backup"),
+            &HookType::PostUpdateRestore => write!(f, "post_update_restore"),
+            &HookType::ValidateConfig => write!(f, "validate_config"),
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Hook {
     pub htype: HookType,
     pub template: PathBuf,
     pub path: PathBuf,
     pub user: String,
     pub group: String,
+    pub engine: TemplateEngine,
+    /// Set when the hook fails to render if it references data that doesn't exist.
+    pub strict: bool,
+    pub interpreter: HookInterpreter,
+    /// The environment variables the package and its dependencies export.
+    pub env: BTreeMap<String, String>,
+    pub sandbox: Option<Sandbox>,
 }
 
 impl Hook {
@@ -68,7 +124,12 @@ impl Hook {
                template: PathBuf,
                path: PathBuf,
                user: String,
-               group: String)
+               group: String,
+               engine: TemplateEngine,
+               strict: bool,
+               interpreter: HookInterpreter,
+               env: BTreeMap<String, String>,
+               sandbox: Option<Sandbox>)
                -> Self {
         Hook {
             htype: htype,
@@ -76,11 +137,23 @@ impl Hook {
             path: path,
             user: user,
             group: group,
+            engine: engine,
+            strict: strict,
+            interpreter: interpreter,
+            env: env,
+            sandbox: sandbox,
         }
     }
 
     pub fn run(&self, service_group: &ServiceGroup) -> Result<()> {
-        let mut child = try!(sup_util::create_command(&self.path, &self.user, &self.group).spawn());
+        self.run_with_env(service_group, &[])
+    }
+
+    /// Run the hook with the given variables added to its environment.
+    pub fn run_with_env(&self, service_group: &ServiceGroup, env: &[(&str, String)]) -> Result<()> {
+        #[cfg(feature = "chaos")]
+        chaos::delay_hook(service_group, &self.stream_name());
+        let mut child = try!(try!(self.command(service_group, env)).spawn());
         self.stream_output(service_group, &mut child);
         let exit_status = try!(child.wait());
         if exit_status.success() {
@@ -90,14 +163,88 @@ impl Hook {
         }
     }
 
+    /// Run the hook like `run_with_env`, but return its exit code and what it wrote to stdout and
+    /// stderr, redacted, rather than streaming its output to the log. A hook still running after
+    /// `timeout` is killed, and exits with -1.
+    pub fn output_with_env(&self,
+                           service_group: &ServiceGroup,
+                           env: &[(&str, String)],
+                           timeout: Duration)
+                           -> Result<(i32, String)> {
+        let mut cmd = try!(self.command(service_group, env));
+        cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+        let mut child = try!(cmd.spawn());
+        let stdout = read_in_background(child.stdout.take());
+        let stderr = read_in_background(child.stderr.take());
+        let deadline = Instant::now() + timeout;
+        let mut status = None;
+        while status.is_none() {
+            if Instant::now() >= deadline {
+                warn!("{} hook ran for longer than {} seconds, killing it",
+                      self.stream_name(),
+                      timeout.as_secs());
+                try!(child.kill());
+                try!(child.wait());
+                break;
+            }
+            thread::sleep(Duration::from_millis(OUTPUT_POLL_MS));
+            status = try!(child.try_wait());
+        }
+        let mut text = stdout.join().unwrap_or_default();
+        text.push_str(&stderr.join().unwrap_or_default());
+        if status.is_none() {
+            text.push_str(&format!("killed after {} seconds\n", timeout.as_secs()));
+        }
+        let text = redact::redact(&text).into_owned();
+        Ok((status.and_then(|s| s.code()).unwrap_or(-1), text))
+    }
+
+    fn command(&self, service_group: &ServiceGroup, env: &[(&str, String)]) -> Result<Command> {
+        // An isolated service's hooks run in its network namespace, so they reach the service
+        // where its own process does.
+        let namespace = Namespace::of(&service_group.to_string());
+        let mut cmd = match self.sandbox {
+            Some(ref sandbox) => {
+                try!(sandbox.command(&self.path,
+                                     self.interpreter,
+                                     &self.user,
+                                     &self.group,
+                                     namespace.as_ref()))
+            }
+            None => {
+                let mut confinement = Confinement::default();
+                if let Some(ref namespace) = namespace {
+                    namespace.confine(&mut confinement);
+                }
+                try!(sup_util::create_service_command(&self.path,
+                                                      self.interpreter,
+                                                      &self.user,
+                                                      &self.group,
+                                                      &ProcessLimits::default(),
+                                                      &confinement))
+            }
+        };
+        if let Some(ref namespace) = namespace {
+            namespace.hold_open(&mut cmd);
+        }
+        for (key, value) in self.env.iter() {
+            cmd.env(key, value);
+        }
+        cmd.env(CENSUS_ENVVAR, census_file_path(service_group.service()));
+        for &(key, ref value) in env {
+            cmd.env(key, value);
+        }
+        Ok(cmd)
+    }
+
     pub fn compile(&self, context: Option<&ServiceConfig>) -> Result<()> {
         if let Some(ctx) = context {
             debug!("Rendering hook {:?}", self);
-            let mut template = Template::new();
-            try!(template.register_template_file("hook", &self.template));
+            let mut template = self.engine.renderer(self.strict);
+            try!(template.register_file("hook", &self.template));
             let toml = try!(ctx.to_toml());
             let svc_data = convert::toml_to_json(toml);
-            let data = try!(template.render("hook", &svc_data));
+            let data = try!(template.render_json("hook", &svc_data));
             let mut file = try!(File::create(&self.path));
             try!(file.write_all(data.as_bytes()));
             try!(util::perm::set_owner(&self.path, &self.user, &self.group));
@@ -113,27 +260,54 @@ impl Hook {
 
     fn stream_output(&self, service_group: &ServiceGroup, process: &mut Child) {
         let preamble_str = self.stream_preamble(service_group);
+        let sg = service_group.to_string();
+        let name = self.stream_name();
+        let _running = hook_log::running(&sg, &name);
         // JW TODO: we need to stream this to a file to be read back later in case of error. We
         // can't store the entirity of stdout/stderr in memory because it could crash the
         // supervisor, but we do want to save it for later to show *why* a hook failed to run
+        //
+        // stderr is read on its own thread so both streams reach the hook log as they're written.
+        let stderr = process.stderr.take().map(|stderr| {
+            let (preamble_str, sg, name) = (preamble_str.clone(), sg.clone(), name.clone());
+            thread::spawn(move || stream_lines(stderr, &preamble_str, &sg, &name, "stderr"))
+        });
         if let Some(ref mut stdout) = process.stdout {
-            for line in BufReader::new(stdout).lines() {
-                if let Some(ref l) = line.ok() {
-                    outputln!(preamble preamble_str, l);
-                }
-            }
+            stream_lines(stdout, &preamble_str, &sg, &name, "stdout");
         }
-        if let Some(ref mut stderr) = process.stderr {
-            for line in BufReader::new(stderr).lines() {
-                if let Some(ref l) = line.ok() {
-                    outputln!(preamble preamble_str, l);
-                }
+        if let Some(handle) = stderr {
+            let _ = handle.join();
+        }
+    }
+
+    /// The hook's name, as shown in its output.
+    fn stream_name(&self) -> String {
+        match self.htype {
+            HookType::Scheduled => {
+                self.path.file_name().unwrap_or_default().to_string_lossy().into_owned()
             }
+            _ => self.htype.to_string(),
         }
     }
 
     fn stream_preamble(&self, service_group: &ServiceGroup) -> String {
-        format!("{} hook[{}]:", service_group, self.htype)
+        format!("{} hook[{}]:", service_group, self.stream_name())
+    }
+}
+
+/// Print each line read from a hook's output stream and append it to the hook log, once any
+/// secrets are redacted.
+fn stream_lines<R: Read>(reader: R,
+                         preamble_str: &str,
+                         service_group: &str,
+                         hook: &str,
+                         stream: &'static str) {
+    for line in BufReader::new(reader).lines() {
+        if let Some(ref l) = line.ok() {
+            let l = redact::redact(l);
+            outputln!(preamble preamble_str, &l);
+            hook_log::push(service_group, hook, stream, &l);
+        }
     }
 }
 
@@ -144,6 +318,11 @@ pub struct HookTable<'a> {
     pub reconfigure_hook: Option<Hook>,
     pub file_updated_hook: Option<Hook>,
     pub run_hook: Option<Hook>,
+    pub under_provisioned_hook: Option<Hook>,
+    pub over_provisioned_hook: Option<Hook>,
+    pub pre_update_backup_hook: Option<Hook>,
+    pub post_update_restore_hook: Option<Hook>,
+    pub validate_config_hook: Option<Hook>,
 }
 
 impl<'a> HookTable<'a> {
@@ -155,26 +334,67 @@ impl<'a> HookTable<'a> {
             reconfigure_hook: None,
             file_updated_hook: None,
             run_hook: None,
+            under_provisioned_hook: None,
+            over_provisioned_hook: None,
+            pre_update_backup_hook: None,
+            post_update_restore_hook: None,
+            validate_config_hook: None,
         }
     }
 
-    pub fn compile_all(&mut self, context: &ServiceConfig) {
+    /// Render every hook with the service's configuration and census data. Returns false if any
+    /// hook failed to render.
+    pub fn compile_all(&mut self, context: &ServiceConfig) -> bool {
+        let mut compiled = true;
         if let Some(ref hook) = self.init_hook {
-            hook.compile(Some(context))
-                .unwrap_or_else(|e| outputln!("Failed to compile init hook: {}", e));
+            if let Err(e) = hook.compile(Some(context)) {
+                outputln!("Failed to compile init hook: {}", e);
+                compiled = false;
+            }
         }
         if let Some(ref hook) = self.health_check_hook {
-            hook.compile(Some(context))
-                .unwrap_or_else(|e| outputln!("Failed to compile health check hook: {}", e));
+            if let Err(e) = hook.compile(Some(context)) {
+                outputln!("Failed to compile health check hook: {}", e);
+                compiled = false;
+            }
         }
         if let Some(ref hook) = self.reconfigure_hook {
-            hook.compile(Some(context))
-                .unwrap_or_else(|e| outputln!("Failed to compile reconfigure hook: {}", e));
+            if let Err(e) = hook.compile(Some(context)) {
+                outputln!("Failed to compile reconfigure hook: {}", e);
+                compiled = false;
+            }
         }
         if let Some(ref hook) = self.file_updated_hook {
-            hook.compile(Some(context))
-                .unwrap_or_else(|e| outputln!("Failed to compile file updated hook: {}", e));
+            if let Err(e) = hook.compile(Some(context)) {
+                outputln!("Failed to compile file updated hook: {}", e);
+                compiled = false;
+            }
+        }
+        if let Some(ref hook) = self.under_provisioned_hook {
+            if let Err(e) = hook.compile(Some(context)) {
+                outputln!("Failed to compile under provisioned hook: {}", e);
+                compiled = false;
+            }
         }
+        if let Some(ref hook) = self.over_provisioned_hook {
+            if let Err(e) = hook.compile(Some(context)) {
+                outputln!("Failed to compile over provisioned hook: {}", e);
+                compiled = false;
+            }
+        }
+        if let Some(ref hook) = self.pre_update_backup_hook {
+            if let Err(e) = hook.compile(Some(context)) {
+                outputln!("Failed to compile pre update backup hook: {}", e);
+                compiled = false;
+            }
+        }
+        if let Some(ref hook) = self.post_update_restore_hook {
+            if let Err(e) = hook.compile(Some(context)) {
+                outputln!("Failed to compile post update restore hook: {}", e);
+                compiled = false;
+            }
+        }
+        compiled
     }
 
     pub fn load_hooks(&mut self) -> &mut Self {
@@ -187,6 +407,11 @@ impl<'a> HookTable<'a> {
                     self.reconfigure_hook = self.load_hook(HookType::Reconfigure);
                     self.health_check_hook = self.load_hook(HookType::HealthCheck);
                     self.run_hook = self.load_hook(HookType::Run);
+                    self.under_provisioned_hook = self.load_hook(HookType::UnderProvisioned);
+                    self.over_provisioned_hook = self.load_hook(HookType::OverProvisioned);
+                    self.pre_update_backup_hook = self.load_hook(HookType::PreUpdateBackup);
+                    self.post_update_restore_hook = self.load_hook(HookType::PostUpdateRestore);
+                    self.validate_config_hook = self.load_hook(HookType::ValidateConfig);
                 }
             }
             Err(_) => {}
@@ -200,9 +425,38 @@ impl<'a> HookTable<'a> {
         let (user, group) = hab_users::get_user_and_group(&self.package.pkg_install)
             .expect("Can't determine user:group");
 
-        match fs::metadata(&template) {
-            Ok(_) => Some(Hook::new(hook_type, template, concrete, user, group)),
-            Err(_) => None,
+        if fs::metadata(&template).is_err() {
+            return None;
         }
+        // A hook which can't be sandboxed as its package asks isn't run at all.
+        let sandbox = match Sandbox::new(self.package) {
+            Ok(sandbox) => sandbox,
+            Err(e) => {
+                outputln!("Failed to prepare the sandbox of the {} hook: {}", hook_type, e);
+                return None;
+            }
+        };
+        Some(Hook::new(hook_type,
+                       template,
+                       concrete,
+                       user,
+                       group,
+                       self.package.template_engine,
+                       self.package.strict_render,
+                       self.package.hook_interpreter,
+                       self.package.environment.clone(),
+                       sandbox))
     }
 }
+
+/// Reads everything from `pipe` on a thread of its own, so a hook filling one pipe can't block
+/// while the other is read.
+fn read_in_background<R: Read + Send + 'static>(pipe: Option<R>) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut bytes);
+        }
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+}
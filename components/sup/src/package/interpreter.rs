@@ -0,0 +1,253 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Interpreters used to execute a service's hooks.
+//!
+//! A hook which starts with a shebang line names its own interpreter. Any other hook is run with
+//! the interpreter declared by the `pkg_svc_hook_interpreter` plan variable, which defaults to
+//! `sh` on Linux and macOS and PowerShell on Windows. Windows doesn't understand shebang lines at
+//! all, so there the Supervisor starts the named interpreter itself.
+
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::process::Command;
+use std::result;
+use std::str::FromStr;
+
+use hcore::package::PackageInstall;
+
+use error::{Error, Result, SupError};
+
+static LOGKEY: &'static str = "HI";
+
+#[cfg(not(target_os = "windows"))]
+const POWERSHELL: &'static str = "pwsh";
+#[cfg(target_os = "windows")]
+const POWERSHELL: &'static str = "powershell.exe";
+#[cfg(not(target_os = "windows"))]
+const PYTHON: &'static str = "python";
+#[cfg(target_os = "windows")]
+const PYTHON: &'static str = "python.exe";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum HookInterpreter {
+    /// Execute the hook itself, leaving it to the operating system to make sense of it.
+    Exec,
+    Shell,
+    PowerShell,
+    Python,
+}
+
+impl HookInterpreter {
+    /// Load the hook interpreter declared by a package, defaulting to the platform's shell.
+    pub fn load(pkg_install: &PackageInstall) -> Result<Self> {
+        match try!(pkg_install.svc_hook_interpreter()) {
+            Some(interpreter) => Self::from_str(&interpreter),
+            None => Ok(HookInterpreter::default()),
+        }
+    }
+
+    /// Returns a command which runs the hook at `path`, using the interpreter named by the hook's
+    /// shebang line if it has one, or this interpreter otherwise.
+    pub fn command(&self, path: &Path) -> Result<Command> {
+        let shebang = try!(Shebang::read(path));
+        let interpreter = shebang.as_ref().map_or(*self, |s| s.interpreter());
+        let mut cmd = match shebang {
+            Some(ref shebang) if cfg!(target_os = "windows") => shebang.command(path),
+            Some(_) => Command::new(path),
+            None => self.command_with(None, path),
+        };
+        if interpreter == HookInterpreter::Python {
+            // Python buffers its output when it isn't writing to a terminal, which would hold
+            // back a hook's output until it exits.
+            cmd.env("PYTHONUNBUFFERED", "1");
+        }
+        Ok(cmd)
+    }
+
+    /// Returns a command which runs the hook at `path` with `program`, or with this interpreter's
+    /// usual program if none is given.
+    fn command_with(&self, program: Option<&str>, path: &Path) -> Command {
+        match *self {
+            HookInterpreter::Exec => Command::new(path),
+            HookInterpreter::Shell => {
+                let mut cmd = Command::new(program.unwrap_or("sh"));
+                cmd.arg(path);
+                cmd
+            }
+            HookInterpreter::PowerShell => {
+                // Hooks don't carry a `.ps1` extension, so they can't be run with `-File`.
+                let mut cmd = Command::new(program.unwrap_or(POWERSHELL));
+                cmd.arg("-NoProfile")
+                    .arg("-NonInteractive")
+                    .arg("-Command")
+                    .arg(format!("iex $(gc {} | out-string)", path.to_string_lossy()));
+                cmd
+            }
+            HookInterpreter::Python => {
+                let mut cmd = Command::new(program.unwrap_or(PYTHON));
+                cmd.arg(path);
+                cmd
+            }
+        }
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+impl Default for HookInterpreter {
+    fn default() -> HookInterpreter {
+        HookInterpreter::Shell
+    }
+}
+
+#[cfg(target_os = "windows")]
+impl Default for HookInterpreter {
+    fn default() -> HookInterpreter {
+        HookInterpreter::PowerShell
+    }
+}
+
+impl fmt::Display for HookInterpreter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            HookInterpreter::Exec => write!(f, "exec"),
+            HookInterpreter::Shell => write!(f, "sh"),
+            HookInterpreter::PowerShell => write!(f, "powershell"),
+            HookInterpreter::Python => write!(f, "python"),
+        }
+    }
+}
+
+impl FromStr for HookInterpreter {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value {
+            "exec" => Ok(HookInterpreter::Exec),
+            "sh" => Ok(HookInterpreter::Shell),
+            "powershell" => Ok(HookInterpreter::PowerShell),
+            "python" => Ok(HookInterpreter::Python),
+            _ => Err(sup_error!(Error::UnknownHookInterpreter(value.to_string()))),
+        }
+    }
+}
+
+/// The interpreter named by the first line of a script, such as `#!/usr/bin/env python3`.
+#[derive(Debug, PartialEq, Eq)]
+struct Shebang {
+    program: String,
+    args: Vec<String>,
+}
+
+impl Shebang {
+    fn read(path: &Path) -> Result<Option<Shebang>> {
+        let mut line = String::new();
+        try!(BufReader::new(try!(File::open(path))).read_line(&mut line));
+        Ok(Self::parse(&line))
+    }
+
+    fn parse(line: &str) -> Option<Shebang> {
+        if !line.starts_with("#!") {
+            return None;
+        }
+        let mut words = line[2..].split_whitespace().map(|w| w.to_string());
+        let mut program = match words.next() {
+            Some(program) => program,
+            None => return None,
+        };
+        let mut args: Vec<String> = words.collect();
+        // `env` only finds the real interpreter on the path, which we can do ourselves.
+        if basename(&program) == "env" && !args.is_empty() {
+            program = args.remove(0);
+        }
+        Some(Shebang {
+            program: program,
+            args: args,
+        })
+    }
+
+    fn interpreter(&self) -> HookInterpreter {
+        let name = basename(&self.program).to_lowercase();
+        match name.trim_right_matches(".exe") {
+            "sh" | "bash" | "dash" | "ksh" | "zsh" => HookInterpreter::Shell,
+            "pwsh" | "powershell" => HookInterpreter::PowerShell,
+            name if name.starts_with("python") => HookInterpreter::Python,
+            _ => HookInterpreter::Exec,
+        }
+    }
+
+    /// Returns a command which runs the script at `path` with this interpreter. Unix style paths
+    /// mean nothing on Windows, so the interpreter is looked up on the path by name.
+    fn command(&self, path: &Path) -> Command {
+        let program = basename(&self.program);
+        match self.interpreter() {
+            HookInterpreter::Exec => {
+                let mut cmd = Command::new(program);
+                cmd.args(&self.args).arg(path);
+                cmd
+            }
+            interpreter => interpreter.command_with(Some(program), path),
+        }
+    }
+}
+
+fn basename(program: &str) -> &str {
+    program.rsplit(|c| c == '/' || c == '\\').next().unwrap_or(program)
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::{HookInterpreter, Shebang};
+
+    #[test]
+    fn hook_interpreter_from_str() {
+        assert_eq!(HookInterpreter::from_str("sh").unwrap(), HookInterpreter::Shell);
+        assert_eq!(HookInterpreter::from_str("powershell").unwrap(),
+                   HookInterpreter::PowerShell);
+        assert_eq!(HookInterpreter::from_str("python").unwrap(), HookInterpreter::Python);
+        assert_eq!(HookInterpreter::from_str("exec").unwrap(), HookInterpreter::Exec);
+        assert!(HookInterpreter::from_str("perl").is_err());
+    }
+
+    #[test]
+    fn shebang_parse() {
+        assert_eq!(Shebang::parse("echo hello\n"), None);
+        assert_eq!(Shebang::parse("#!\n"), None);
+        assert_eq!(Shebang::parse("#!/bin/sh -e\n"),
+                   Some(Shebang {
+                       program: "/bin/sh".to_string(),
+                       args: vec!["-e".to_string()],
+                   }));
+        assert_eq!(Shebang::parse("#!/usr/bin/env python3\n"),
+                   Some(Shebang {
+                       program: "python3".to_string(),
+                       args: vec![],
+                   }));
+    }
+
+    #[test]
+    fn shebang_interpreter() {
+        let interpreter = |line: &str| Shebang::parse(line).unwrap().interpreter();
+        assert_eq!(interpreter("#!/bin/bash"), HookInterpreter::Shell);
+        assert_eq!(interpreter("#!/usr/bin/env pwsh"), HookInterpreter::PowerShell);
+        assert_eq!(interpreter("#!C:\\Windows\\powershell.exe"),
+                   HookInterpreter::PowerShell);
+        assert_eq!(interpreter("#!/usr/bin/python2.7"), HookInterpreter::Python);
+        assert_eq!(interpreter("#!/usr/bin/env ruby"), HookInterpreter::Exec);
+    }
+}
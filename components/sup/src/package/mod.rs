@@ -12,29 +12,43 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod health_probe;
 pub mod hooks;
+pub mod interpreter;
+pub mod sandbox;
 
+pub use self::health_probe::HealthProbe;
 pub use self::hooks::HookType;
+pub use self::interpreter::HookInterpreter;
+pub use self::sandbox::{HookSandbox, Sandbox};
 
 use std;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::env;
 use std::fmt;
 use std::fs::File;
 use std::path::{Path, PathBuf};
 use std::string::ToString;
 use std::io::prelude::*;
+use std::time::Duration;
 
+use hcore::crypto::hash;
 use hcore::package::{PackageIdent, PackageInstall};
 use hcore::service::ServiceGroup;
 use hcore::util;
 
-use self::hooks::{HookTable, HOOK_PERMISSIONS};
+use self::hooks::{HookTable, ALIVE_COUNT_ENVVAR, DESIRED_COUNT_ENVVAR, HOOK_PERMISSIONS,
+                  PREVIOUS_PKG_IDENT_ENVVAR, PREVIOUS_PKG_PATH_ENVVAR, PROPOSED_CONFIG_PATH_ENVVAR,
+                  UPDATED_PKG_IDENT_ENVVAR, UPDATED_PKG_PATH_ENVVAR};
 use config::gconfig;
 use error::{Error, Result, SupError};
 use health_check::{self, CheckResult};
+use manager::census::Provisioning;
+use manager::service::Namespace;
 use manager::service::config::ServiceConfig;
+use manager::service::data_version::DataVersion;
 use supervisor::Supervisor;
+use templating::{self, TemplateEngine};
 use util::path;
 use util::users as hab_users;
 use prometheus::Opts;
@@ -45,9 +59,24 @@ const HEALTHCHECK_FILENAME: &'static str = "health_check";
 const FILEUPDATED_FILENAME: &'static str = "file_updated";
 const RECONFIGURE_FILENAME: &'static str = "reconfigure";
 const RUN_FILENAME: &'static str = "run";
+const UNDERPROVISIONED_FILENAME: &'static str = "under_provisioned";
+const OVERPROVISIONED_FILENAME: &'static str = "over_provisioned";
+const PREUPDATEBACKUP_FILENAME: &'static str = "pre_update_backup";
+const POSTUPDATERESTORE_FILENAME: &'static str = "post_update_restore";
+const VALIDATECONFIG_FILENAME: &'static str = "validate_config";
 const HABITAT_PACKAGE_INFO_NAME: &'static str = "habitat_package_info";
 const HABITAT_PACKAGE_INFO_DESC: &'static str = "package version information";
 
+/// A configuration file as the service was last rendered with it.
+#[derive(Debug, Serialize)]
+pub struct RenderedFile {
+    pub name: String,
+    /// The BLAKE2b hash of the file, as logged when it was rendered.
+    pub hash: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contents: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Package {
     pub origin: String,
@@ -57,6 +86,28 @@ pub struct Package {
     pub deps: Vec<PackageIdent>,
     pub tdeps: Vec<PackageIdent>,
     pub pkg_install: PackageInstall,
+    #[serde(default)]
+    pub template_engine: TemplateEngine,
+    #[serde(default)]
+    pub hook_interpreter: HookInterpreter,
+    #[serde(default)]
+    pub hook_sandbox: HookSandbox,
+    #[serde(default)]
+    pub data_version: DataVersion,
+    /// The probe run in place of the health_check hook, if the package declares one.
+    #[serde(default)]
+    pub health_probe: Option<HealthProbe>,
+    /// The composite package the package was started as a service of, if any.
+    #[serde(default)]
+    pub composite: Option<PackageIdent>,
+    /// Set when the package was loaded with `--strict-render`, so the service's configuration and
+    /// hooks fail to render when they reference data that doesn't exist.
+    #[serde(default)]
+    pub strict_render: bool,
+    /// The environment variables the package and its dependencies export, which its process and
+    /// hooks start with.
+    #[serde(default)]
+    pub environment: BTreeMap<String, String>,
 }
 
 impl Package {
@@ -83,6 +134,14 @@ impl Package {
             release: pkg_install.ident().release.as_ref().unwrap().clone(),
             deps: try!(pkg_install.deps()).clone(),
             tdeps: try!(pkg_install.tdeps()).clone(),
+            template_engine: try!(TemplateEngine::load(&pkg_install)),
+            hook_interpreter: try!(HookInterpreter::load(&pkg_install)),
+            hook_sandbox: try!(HookSandbox::load(&pkg_install)),
+            data_version: try!(DataVersion::load(&pkg_install)),
+            health_probe: try!(HealthProbe::load(&pkg_install)),
+            composite: None,
+            strict_render: false,
+            environment: try!(pkg_install.runtime_environment()),
             pkg_install: pkg_install,
         })
     }
@@ -121,6 +180,12 @@ impl Package {
             HookType::FileUpdated => base.join(FILEUPDATED_FILENAME),
             HookType::Reconfigure => base.join(RECONFIGURE_FILENAME),
             HookType::Run => base.join(RUN_FILENAME),
+            HookType::UnderProvisioned => base.join(UNDERPROVISIONED_FILENAME),
+            HookType::OverProvisioned => base.join(OVERPROVISIONED_FILENAME),
+            HookType::PreUpdateBackup => base.join(PREUPDATEBACKUP_FILENAME),
+            HookType::PostUpdateRestore => base.join(POSTUPDATERESTORE_FILENAME),
+            HookType::ValidateConfig => base.join(VALIDATECONFIG_FILENAME),
+            HookType::Scheduled => unreachable!("scheduled hooks are looked up by name"),
         }
     }
 
@@ -132,9 +197,23 @@ impl Package {
             HookType::FileUpdated => base.join(FILEUPDATED_FILENAME),
             HookType::Reconfigure => base.join(RECONFIGURE_FILENAME),
             HookType::Run => base.join(RUN_FILENAME),
+            HookType::UnderProvisioned => base.join(UNDERPROVISIONED_FILENAME),
+            HookType::OverProvisioned => base.join(OVERPROVISIONED_FILENAME),
+            HookType::PreUpdateBackup => base.join(PREUPDATEBACKUP_FILENAME),
+            HookType::PostUpdateRestore => base.join(POSTUPDATERESTORE_FILENAME),
+            HookType::ValidateConfig => base.join(VALIDATECONFIG_FILENAME),
+            HookType::Scheduled => unreachable!("scheduled hooks are looked up by name"),
         }
     }
 
+    pub fn scheduled_hook_template_path(&self, name: &str) -> PathBuf {
+        self.config_from().join("hooks").join(name)
+    }
+
+    pub fn scheduled_hook_path(&self, name: &str) -> PathBuf {
+        self.pkg_install.svc_hooks_path().join(name)
+    }
+
     /// The path to the package on disk.
     pub fn path(&self) -> &Path {
         self.pkg_install.installed_path()
@@ -254,27 +333,25 @@ impl Package {
     /// This does not return the full path, for convenience with the path
     /// helpers above.
     pub fn config_files(&self) -> Result<Vec<String>> {
-        let mut files: Vec<String> = Vec::new();
+        templating::template_names(&self.config_from().join("config"))
+    }
+
+    /// Return the paths of every template rendered for this package: its configuration files and
+    /// its hooks, including scheduled hooks.
+    pub fn template_paths(&self) -> Result<Vec<PathBuf>> {
         let config_dir = self.config_from().join("config");
-        debug!("Loading configuration from {:?}", config_dir);
-        match std::fs::read_dir(config_dir) {
-            Ok(config_dir) => {
-                for config in config_dir {
-                    let config = try!(config);
-                    match config.path().file_name() {
-                        Some(filename) => {
-                            debug!("Looking in {:?}", filename);
-                            files.push(filename.to_string_lossy().into_owned().to_string());
-                        }
-                        None => unreachable!(),
-                    }
+        let mut paths: Vec<PathBuf> =
+            try!(self.config_files()).into_iter().map(|f| config_dir.join(f)).collect();
+        if let Ok(hooks) = std::fs::read_dir(self.config_from().join("hooks")) {
+            for hook in hooks {
+                let hook = try!(hook);
+                if hook.path().is_file() {
+                    paths.push(hook.path());
                 }
             }
-            Err(e) => {
-                debug!("No config directory in package: {}", e);
-            }
         }
-        Ok(files)
+        paths.sort();
+        Ok(paths)
     }
 
     pub fn ident(&self) -> &PackageIdent {
@@ -309,10 +386,92 @@ impl Package {
         }
     }
 
+    /// Run the under_provisioned or over_provisioned hook if present, telling it how many members
+    /// the service group should have and how many are alive. Return false if there is no hook
+    /// for the given provisioning.
+    pub fn provisioning_changed(&self,
+                                service_group: &ServiceGroup,
+                                provisioning: Provisioning,
+                                desired: u64,
+                                alive: u64)
+                                -> Result<bool> {
+        let hooks = self.hooks();
+        let hook = match provisioning {
+            Provisioning::UnderProvisioned => hooks.under_provisioned_hook,
+            Provisioning::OverProvisioned => hooks.over_provisioned_hook,
+            Provisioning::Provisioned => None,
+        };
+        if let Some(hook) = hook {
+            hook.run_with_env(service_group,
+                              &[(DESIRED_COUNT_ENVVAR, desired.to_string()),
+                                (ALIVE_COUNT_ENVVAR, alive.to_string())])
+                .map(|_| true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Run the pre_update_backup hook if present, before this release is replaced by `updated`.
+    /// Return false if it is not present.
+    pub fn pre_update_backup(&self,
+                             service_group: &ServiceGroup,
+                             updated: &Package)
+                             -> Result<bool> {
+        if let Some(hook) = self.hooks().pre_update_backup_hook {
+            hook.run_with_env(service_group, &update_env(self, updated)).map(|_| true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Run the post_update_restore hook if present, before this release's process first starts
+    /// in place of `previous`. Return false if it is not present.
+    pub fn post_update_restore(&self,
+                               service_group: &ServiceGroup,
+                               previous: &Package)
+                               -> Result<bool> {
+        if let Some(hook) = self.hooks().post_update_restore_hook {
+            hook.run_with_env(service_group, &update_env(previous, self)).map(|_| true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Run the validate_config hook if present against the configuration files rendered from
+    /// `proposed` into `config_dir`. The hook is rendered with `proposed` into `hook_dir`, rather
+    /// than over the hook the service was last rendered with. Return its exit code and output, or
+    /// None if it is not present. The hook is killed if it runs for longer than `timeout`.
+    pub fn validate_config(&self,
+                           service_group: &ServiceGroup,
+                           proposed: &ServiceConfig,
+                           config_dir: &Path,
+                           hook_dir: &Path,
+                           timeout: Duration)
+                           -> Result<Option<(i32, String)>> {
+        let mut hook = match self.hooks().validate_config_hook {
+            Some(hook) => hook,
+            None => return Ok(None),
+        };
+        hook.path = hook_dir.join(VALIDATECONFIG_FILENAME);
+        try!(hook.compile(Some(proposed)));
+        let env = [(PROPOSED_CONFIG_PATH_ENVVAR, config_dir.to_string_lossy().into_owned())];
+        hook.output_with_env(service_group, &env, timeout).map(Some)
+    }
+
     pub fn health_check(&self,
                         supervisor: &Supervisor,
                         service_group: &ServiceGroup)
                         -> Result<CheckResult> {
+        if let Some(ref probe) = self.health_probe {
+            // An isolated service's probe connects from inside its network namespace.
+            return match Namespace::of(&service_group.to_string()) {
+                Some(namespace) => {
+                    let probe = probe.clone();
+                    Ok(try!(namespace.run(move || probe.check())))
+                }
+                None => Ok(probe.check()),
+            };
+        }
         if let Some(hook) = self.hooks().health_check_hook {
             match hook.run(service_group) {
                 Ok(()) => Ok(health_check::CheckResult::Ok),
@@ -340,14 +499,17 @@ impl Package {
         }
     }
 
+    /// Registers the package's info gauge. Services of a composite may run the same package more
+    /// than once, in which case it is already registered and left as it is.
     pub fn register_metrics(&self) {
         let version_opts = Opts::new(HABITAT_PACKAGE_INFO_NAME, HABITAT_PACKAGE_INFO_DESC)
             .const_label("origin", &self.origin.clone())
             .const_label("name", &self.name.clone())
             .const_label("version", &self.version.clone())
             .const_label("release", &self.release.clone());
-        let version_gauge = register_gauge!(version_opts).unwrap();
-        version_gauge.set(1.0);
+        if let Ok(version_gauge) = register_gauge!(version_opts) {
+            version_gauge.set(1.0);
+        }
     }
 
     pub fn hooks(&self) -> HookTable {
@@ -356,6 +518,28 @@ impl Package {
         hooks
     }
 
+    /// Return each of the package's configuration files as last rendered, with its hash and, if
+    /// `contents` is set, its contents. Files which haven't been rendered yet are left out.
+    pub fn rendered_config_files(&self, contents: bool) -> Result<Vec<RenderedFile>> {
+        let config_path = self.pkg_install.svc_config_path();
+        let mut files = Vec::new();
+        for name in try!(self.config_files()) {
+            let path = config_path.join(&name);
+            if !path.is_file() {
+                continue;
+            }
+            // The file is hashed as read, so its hash always matches the contents returned.
+            let mut data = String::new();
+            try!(try!(File::open(&path)).read_to_string(&mut data));
+            files.push(RenderedFile {
+                name: name,
+                hash: try!(hash::hash_string(&data)),
+                contents: if contents { Some(data) } else { None },
+            });
+        }
+        Ok(files)
+    }
+
     pub fn last_config(&self) -> Result<String> {
         let mut file = try!(File::open(self.pkg_install.svc_path().join("config.toml")));
         let mut result = String::new();
@@ -364,6 +548,15 @@ impl Package {
     }
 }
 
+/// The environment the update hooks are run with, naming the release being replaced and the
+/// release replacing it.
+fn update_env(previous: &Package, updated: &Package) -> Vec<(&'static str, String)> {
+    vec![(PREVIOUS_PKG_IDENT_ENVVAR, previous.ident().to_string()),
+         (PREVIOUS_PKG_PATH_ENVVAR, previous.path().to_string_lossy().into_owned()),
+         (UPDATED_PKG_IDENT_ENVVAR, updated.ident().to_string()),
+         (UPDATED_PKG_PATH_ENVVAR, updated.path().to_string_lossy().into_owned())]
+}
+
 impl Into<PackageIdent> for Package {
     fn into(self) -> PackageIdent {
         PackageIdent::new(self.origin,
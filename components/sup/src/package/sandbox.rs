@@ -0,0 +1,178 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sandboxing of a service's hooks, so they behave the same on every host.
+//!
+//! Packages opt in with the `pkg_svc_hook_sandbox` plan variable. With `env` a hook starts with
+//! only the environment the Supervisor constructs for it: the package's `PATH`, `HOME` and
+//! `TMPDIR` in the service's var directory, the locale and time zone, and the variables the
+//! package and its dependencies export. With `readonly` the
+//! filesystem is read-only to a hook outside the service's own directory, on Linux. With `limits`
+//! a hook starts with the umask and resource limits of the service's run hook. The run hook
+//! itself is started as it always is.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+use hcore::os::process::Confinement;
+use hcore::package::PackageInstall;
+
+use error::{Error, Result};
+use manager::service::{Namespace, ProcessLimits};
+use package::{HookInterpreter, Package};
+use util;
+
+static LOGKEY: &'static str = "SB";
+
+/// Variables a hook with a constructed environment still takes from the Supervisor's.
+#[cfg(not(target_os = "windows"))]
+const PASSTHROUGH_ENV: &'static [&'static str] = &["LANG", "LC_ALL", "TZ"];
+/// Variables a hook with a constructed environment still takes from the Supervisor's. Windows
+/// programs can't start without some of them.
+#[cfg(target_os = "windows")]
+const PASSTHROUGH_ENV: &'static [&'static str] = &["SYSTEMROOT", "SYSTEMDRIVE", "WINDIR",
+                                                   "COMSPEC", "PATHEXT", "TZ"];
+
+/// The ways a package asks for its hooks to be sandboxed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct HookSandbox {
+    pub env: bool,
+    pub read_only: bool,
+    pub limits: bool,
+}
+
+impl HookSandbox {
+    /// Load the sandboxing declared by a package. A package which declares none runs its hooks
+    /// as the Supervisor always has.
+    pub fn load(pkg_install: &PackageInstall) -> Result<Self> {
+        let names = try!(pkg_install.svc_hook_sandbox());
+        Self::from_names(&names)
+    }
+
+    fn from_names(names: &[String]) -> Result<Self> {
+        let mut sandbox = HookSandbox::default();
+        for name in names {
+            match name.as_str() {
+                "env" => sandbox.env = true,
+                "readonly" => sandbox.read_only = true,
+                "limits" => sandbox.limits = true,
+                _ => return Err(sup_error!(Error::InvalidHookSandbox(name.clone()))),
+            }
+        }
+        Ok(sandbox)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.env && !self.read_only && !self.limits
+    }
+}
+
+/// What a package's sandboxed hooks are started with.
+#[derive(Debug, Clone)]
+pub struct Sandbox {
+    /// The environment hooks start with, if they don't inherit the Supervisor's.
+    env: Option<Vec<(String, String)>>,
+    limits: ProcessLimits,
+    confinement: Confinement,
+}
+
+impl Sandbox {
+    /// Work out the sandbox of a package's hooks, or None if it doesn't sandbox them.
+    pub fn new(package: &Package) -> Result<Option<Self>> {
+        let declared = &package.hook_sandbox;
+        if declared.is_empty() {
+            return Ok(None);
+        }
+        let env = if declared.env {
+            Some(try!(constructed_env(package)))
+        } else {
+            None
+        };
+        let limits = if declared.limits {
+            try!(ProcessLimits::load(&package.pkg_install))
+        } else {
+            ProcessLimits::default()
+        };
+        let writable_paths = if declared.read_only {
+            Some(vec![package.pkg_install.svc_path()])
+        } else {
+            None
+        };
+        Ok(Some(Sandbox {
+            env: env,
+            limits: limits,
+            confinement: Confinement { writable_paths: writable_paths, ..Confinement::default() },
+        }))
+    }
+
+    /// Returns a command like `util::create_command` which starts the hook at `path` inside the
+    /// sandbox, and in `namespace` if the service is isolated.
+    pub fn command(&self,
+                   path: &Path,
+                   interpreter: HookInterpreter,
+                   user: &str,
+                   group: &str,
+                   namespace: Option<&Namespace>)
+                   -> Result<Command> {
+        let mut confinement = self.confinement.clone();
+        if let Some(namespace) = namespace {
+            namespace.confine(&mut confinement);
+        }
+        let mut cmd = try!(util::create_service_command(path,
+                                                        interpreter,
+                                                        user,
+                                                        group,
+                                                        &self.limits,
+                                                        &confinement));
+        if let Some(ref env) = self.env {
+            cmd.env_clear();
+            for &(ref key, ref value) in env.iter() {
+                cmd.env(key, value);
+            }
+        }
+        Ok(cmd)
+    }
+}
+
+fn constructed_env(package: &Package) -> Result<Vec<(String, String)>> {
+    let var_path = package.pkg_install.svc_var_path().to_string_lossy().into_owned();
+    let mut vars = vec![(String::from("PATH"), try!(package.run_path())),
+                        (String::from("HOME"), var_path.clone()),
+                        (String::from("TMPDIR"), var_path)];
+    for name in PASSTHROUGH_ENV.iter() {
+        if let Ok(value) = env::var(name) {
+            vars.push((name.to_string(), value));
+        }
+    }
+    Ok(vars)
+}
+
+#[cfg(test)]
+mod test {
+    use super::HookSandbox;
+
+    #[test]
+    fn hook_sandbox_from_names() {
+        let names = vec![String::from("env"), String::from("limits")];
+        assert_eq!(HookSandbox::from_names(&names).unwrap(),
+                   HookSandbox {
+                       env: true,
+                       read_only: false,
+                       limits: true,
+                   });
+        assert!(HookSandbox::from_names(&[]).unwrap().is_empty());
+        assert!(HookSandbox::from_names(&[String::from("chroot")]).is_err());
+    }
+}
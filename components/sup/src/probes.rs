@@ -0,0 +1,101 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Liveness and readiness of the Supervisor, for orchestrators.
+//!
+//! A Supervisor is *live* as long as its main loop keeps ticking; restarting it won't help a
+//! service which is waiting on a bind or a failing health check. It is *ready* once every loaded
+//! service is running, healthy and rendered against a census which has stopped changing.
+
+use time::{Duration, SteadyTime};
+
+use health_check::CheckResult;
+use manager;
+use manager::service::{Service, ServiceKind};
+
+/// How long the main loop may go without ticking before the Supervisor is considered dead. Hooks
+/// run by the loop block it, so this is generous.
+pub const LIVENESS_TIMEOUT_SECS: i64 = 60;
+
+#[derive(Debug, Serialize)]
+pub struct Liveness {
+    pub alive: bool,
+    /// Seconds since the main loop last ticked.
+    pub last_tick_secs: i64,
+}
+
+impl Liveness {
+    pub fn new(state: &manager::State) -> Self {
+        let last_tick = *state.heartbeat.read().expect("Heartbeat lock is poisoned!");
+        let since = SteadyTime::now() - last_tick;
+        Liveness {
+            alive: since < Duration::seconds(LIVENESS_TIMEOUT_SECS),
+            last_tick_secs: since.num_seconds(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Readiness {
+    pub ready: bool,
+    pub services: Vec<ServiceReadiness>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ServiceReadiness {
+    pub service_group: String,
+    pub ready: bool,
+    /// Why the service isn't ready, if it isn't.
+    pub reason: Option<&'static str>,
+}
+
+impl Readiness {
+    pub fn new(state: &manager::State) -> Self {
+        let services = state.services.read().expect("Services lock is poisoned!");
+        let services: Vec<ServiceReadiness> = services.iter()
+            .map(|service| {
+                let reason = not_ready_reason(service);
+                ServiceReadiness {
+                    service_group: service.service_group_str(),
+                    ready: reason.is_none(),
+                    reason: reason,
+                }
+            })
+            .collect();
+        Readiness {
+            ready: services.iter().all(|s| s.ready),
+            services: services,
+        }
+    }
+}
+
+fn not_ready_reason(service: &Service) -> Option<&'static str> {
+    if !service.initialized {
+        return Some("initializing");
+    }
+    if service.render_failed || service.needs_reconfigure {
+        return Some("waiting for configuration");
+    }
+    if !service.census_converged() {
+        return Some("census changing");
+    }
+    if service.kind == ServiceKind::Daemon && service.is_down() {
+        return Some("not running");
+    }
+    match service.health_check() {
+        Ok(CheckResult::Ok) |
+        Ok(CheckResult::Warning) => None,
+        Ok(_) | Err(_) => Some("unhealthy"),
+    }
+}
@@ -0,0 +1,405 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Secrets which templates read at render time, rather than from gossiped configuration.
+//!
+//! A `SecretsBackend` reads secrets from wherever they are kept; `vault` reads them from
+//! HashiCorp Vault. Once a backend is installed with `init`, the `vault` template helper reads
+//! secrets through the `Secrets` cache, which a background thread keeps fresh: it renews the
+//! backend's token and the leases of the secrets read, reads secrets again halfway through their
+//! TTL, and counts each change to a secret's value in a generation. The Manager re-renders its
+//! services whenever the generation changes, so that a rotated secret reaches the services
+//! using it.
+//!
+//! Templates are rendered on the Manager's loop, so a secret which isn't cached is read on a
+//! thread of its own, and rendering waits no more than `READ_TIMEOUT_MS` for it. A read which
+//! takes longer fails the render, and finishes in the background; the secret then counts as
+//! changed, so the services are rendered again with it. A cached secret is served for no longer
+//! than its TTL, so one that can't be refreshed is read again rather than served stale.
+
+pub mod vault;
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::cmp;
+use std::sync::{Arc, Mutex, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde_json::Value as Json;
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "SC";
+
+/// How often the background thread looks for secrets and tokens to renew.
+const TICK_MS: u64 = 1000;
+
+/// The least and most time between refreshes of a secret, whatever its TTL.
+const MIN_REFRESH_SECS: u64 = 5;
+const MAX_REFRESH_SECS: u64 = 300;
+
+/// How long to wait before trying a failed refresh or token renewal again.
+const RETRY_SECS: u64 = 30;
+
+/// How long rendering waits for a secret which isn't cached.
+const READ_TIMEOUT_MS: u64 = 2000;
+
+lazy_static! {
+    static ref SECRETS: RwLock<Option<Arc<Secrets>>> = RwLock::new(None);
+}
+
+/// Where secrets are read from.
+pub trait SecretsBackend: Send + Sync {
+    /// Reads the secret at `path`.
+    fn read(&self, path: &str) -> Result<Secret>;
+
+    /// Extends a lease of a secret read earlier, returning how long the secret is now good for,
+    /// or `None` if the lease can't be extended any further and the secret must be read again.
+    fn renew_lease(&self, lease_id: &str) -> Result<Option<Duration>>;
+
+    /// Renews the credentials secrets are read with, returning how long until they must be
+    /// renewed again, or `None` if they don't expire.
+    fn renew_token(&self) -> Result<Option<Duration>>;
+}
+
+/// A secret as it was read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Secret {
+    pub data: BTreeMap<String, Json>,
+    /// How long the secret is good for.
+    pub ttl: Duration,
+    /// The lease to renew to keep using the secret, if it's leased.
+    pub lease_id: Option<String>,
+}
+
+struct Cached {
+    secret: Secret,
+    refresh_at: Instant,
+    /// When the secret stops being served, if it has a TTL.
+    expires_at: Option<Instant>,
+}
+
+impl Cached {
+    fn new(secret: Secret, now: Instant) -> Self {
+        Cached {
+            refresh_at: now + refresh_interval(secret.ttl),
+            expires_at: expiry(&secret, now),
+            secret: secret,
+        }
+    }
+}
+
+/// The secrets templates have read, cached until they are due to be refreshed.
+pub struct Secrets {
+    backend: Arc<Box<SecretsBackend>>,
+    cache: Arc<Mutex<HashMap<String, Cached>>>,
+    /// The paths being read for the first time.
+    reading: Arc<Mutex<HashSet<String>>>,
+    generation: Arc<AtomicUsize>,
+}
+
+impl Secrets {
+    pub fn new(backend: Box<SecretsBackend>) -> Self {
+        Secrets {
+            backend: Arc::new(backend),
+            cache: Arc::new(Mutex::new(HashMap::new())),
+            reading: Arc::new(Mutex::new(HashSet::new())),
+            generation: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Returns the `field` of the secret at `path`, reading the secret unless it's cached and
+    /// hasn't expired. The read is waited for no longer than `READ_TIMEOUT_MS`.
+    ///
+    /// # Failures
+    ///
+    /// * The secret can't be read, or is still being read
+    /// * The secret has no such field
+    pub fn get(&self, path: &str, field: &str) -> Result<Json> {
+        let now = Instant::now();
+        let cached = self.cache
+            .lock()
+            .expect("Secrets cache lock is poisoned!")
+            .get(path)
+            .and_then(|c| if c.expires_at.map_or(true, |at| now < at) {
+                Some(c.secret.clone())
+            } else {
+                None
+            });
+        let secret = match cached {
+            Some(secret) => secret,
+            None => try!(self.read(path)),
+        };
+        secret.data
+            .get(field)
+            .cloned()
+            .ok_or_else(|| sup_error!(Error::SecretsFailed(format!("{} has no field {}",
+                                                                    path,
+                                                                    field))))
+    }
+
+    /// Reads the secret at `path` on a thread of its own and caches it, waiting for it no longer
+    /// than `READ_TIMEOUT_MS`. A read which outlasts the wait counts as a change once it's done,
+    /// so that the render which gave up on it is done again.
+    fn read(&self, path: &str) -> Result<Secret> {
+        let first = self.reading
+            .lock()
+            .expect("Secrets reading lock is poisoned!")
+            .insert(path.to_string());
+        if !first {
+            return Err(sup_error!(Error::SecretsFailed(format!("{} is still being read", path))));
+        }
+        let (tx, rx) = mpsc::channel();
+        let backend = self.backend.clone();
+        let cache = self.cache.clone();
+        let reading = self.reading.clone();
+        let generation = self.generation.clone();
+        let thread_path = path.to_string();
+        let spawned = thread::Builder::new()
+            .name(format!("secret-{}", path))
+            .spawn(move || {
+                let result = backend.read(&thread_path);
+                if let Ok(ref secret) = result {
+                    cache.lock()
+                        .expect("Secrets cache lock is poisoned!")
+                        .insert(thread_path.clone(), Cached::new(secret.clone(), Instant::now()));
+                }
+                reading.lock().expect("Secrets reading lock is poisoned!").remove(&thread_path);
+                if let Err(result) = tx.send(result) {
+                    if result.0.is_ok() {
+                        generation.fetch_add(1, Ordering::SeqCst);
+                    }
+                }
+            });
+        if let Err(e) = spawned {
+            self.reading.lock().expect("Secrets reading lock is poisoned!").remove(path);
+            return Err(sup_error!(Error::SecretsFailed(format!("cannot read {}: {}", path, e))));
+        }
+        match rx.recv_timeout(Duration::from_millis(READ_TIMEOUT_MS)) {
+            Ok(result) => result,
+            Err(_) => {
+                Err(sup_error!(Error::SecretsFailed(format!("{} took longer than {}ms to read",
+                                                            path,
+                                                            READ_TIMEOUT_MS))))
+            }
+        }
+    }
+
+    /// Returns the number of times a cached secret has changed. It only ever increases.
+    pub fn generation(&self) -> usize {
+        self.generation.load(Ordering::SeqCst)
+    }
+
+    /// Renews the lease of, or reads again, every cached secret due to be refreshed by `now`.
+    fn refresh(&self, now: Instant) {
+        let due: Vec<(String, Option<String>)> = self.cache
+            .lock()
+            .expect("Secrets cache lock is poisoned!")
+            .iter()
+            .filter(|&(_, c)| c.refresh_at <= now)
+            .map(|(path, c)| (path.clone(), c.secret.lease_id.clone()))
+            .collect();
+        for (path, lease_id) in due {
+            // The backend is called without holding the cache, so that templates reading other
+            // secrets meanwhile aren't blocked.
+            let renewed = match lease_id {
+                Some(ref id) => {
+                    match self.backend.renew_lease(id) {
+                        Ok(ttl) => ttl,
+                        Err(e) => {
+                            outputln!("Cannot renew the lease of secret {}: {}", path, e);
+                            None
+                        }
+                    }
+                }
+                None => None,
+            };
+            let (read, refresh_at, expires_at) = match renewed {
+                Some(ttl) => (None, now + refresh_interval(ttl), Some(now + ttl)),
+                None => {
+                    match self.backend.read(&path) {
+                        Ok(secret) => {
+                            let cached = Cached::new(secret, now);
+                            (Some(cached.secret), cached.refresh_at, cached.expires_at)
+                        }
+                        Err(e) => {
+                            outputln!("Cannot refresh secret {}: {}", path, e);
+                            (None, now + Duration::from_secs(RETRY_SECS), None)
+                        }
+                    }
+                }
+            };
+            let mut cache = self.cache.lock().expect("Secrets cache lock is poisoned!");
+            if let Some(cached) = cache.get_mut(&path) {
+                cached.refresh_at = refresh_at;
+                if expires_at.is_some() {
+                    cached.expires_at = expires_at;
+                }
+                if let Some(secret) = read {
+                    if secret.data != cached.secret.data {
+                        outputln!("Secret {} has changed", path);
+                        self.generation.fetch_add(1, Ordering::SeqCst);
+                    }
+                    cached.secret = secret;
+                }
+            }
+        }
+    }
+}
+
+/// Installs the backend templates read secrets from, and spawns the thread which keeps them, and
+/// the backend's token, fresh.
+pub fn init(backend: Box<SecretsBackend>) -> Result<()> {
+    let secrets = Arc::new(Secrets::new(backend));
+    *SECRETS.write().expect("Secrets lock is poisoned!") = Some(secrets.clone());
+    try!(thread::Builder::new()
+        .name(String::from("secrets"))
+        .spawn(move || {
+            let mut renew_token_at = Some(Instant::now());
+            loop {
+                let now = Instant::now();
+                if renew_token_at.map_or(false, |at| at <= now) {
+                    renew_token_at = match secrets.backend.renew_token() {
+                        Ok(ttl) => ttl.map(|ttl| now + ttl / 2),
+                        Err(e) => {
+                            outputln!("Cannot renew the secrets token: {}", e);
+                            Some(now + Duration::from_secs(RETRY_SECS))
+                        }
+                    };
+                }
+                secrets.refresh(now);
+                thread::sleep(Duration::from_millis(TICK_MS));
+            }
+        }));
+    Ok(())
+}
+
+/// Returns the installed secrets, if a backend has been.
+pub fn secrets() -> Option<Arc<Secrets>> {
+    SECRETS.read().expect("Secrets lock is poisoned!").clone()
+}
+
+/// Returns when a secret read at `now` stops being served, unless it has no TTL.
+fn expiry(secret: &Secret, now: Instant) -> Option<Instant> {
+    if secret.ttl == Duration::from_secs(0) {
+        None
+    } else {
+        Some(now + secret.ttl)
+    }
+}
+
+/// Secrets are refreshed halfway through their TTL, but at least every `MAX_REFRESH_SECS` so that
+/// rotated secrets which don't expire are noticed, and at most every `MIN_REFRESH_SECS`.
+fn refresh_interval(ttl: Duration) -> Duration {
+    let secs = cmp::max(MIN_REFRESH_SECS, cmp::min(MAX_REFRESH_SECS, ttl.as_secs() / 2));
+    Duration::from_secs(secs)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    use serde_json::Value as Json;
+
+    use error::Result;
+    use super::{Secret, Secrets, SecretsBackend};
+
+    #[derive(Clone, Default)]
+    struct FakeBackend {
+        value: Arc<Mutex<String>>,
+        reads: Arc<Mutex<usize>>,
+        leased: bool,
+    }
+
+    impl SecretsBackend for FakeBackend {
+        fn read(&self, _path: &str) -> Result<Secret> {
+            *self.reads.lock().unwrap() += 1;
+            let mut data = BTreeMap::new();
+            data.insert(String::from("value"),
+                        Json::String(self.value.lock().unwrap().clone()));
+            Ok(Secret {
+                data: data,
+                ttl: Duration::from_secs(60),
+                lease_id: if self.leased { Some(String::from("lease")) } else { None },
+            })
+        }
+
+        fn renew_lease(&self, _lease_id: &str) -> Result<Option<Duration>> {
+            Ok(Some(Duration::from_secs(60)))
+        }
+
+        fn renew_token(&self) -> Result<Option<Duration>> {
+            Ok(None)
+        }
+    }
+
+    fn hours_from_now(hours: u64) -> Instant {
+        Instant::now() + Duration::from_secs(hours * 3600)
+    }
+
+    #[test]
+    fn secrets_are_cached() {
+        let backend = FakeBackend::default();
+        *backend.value.lock().unwrap() = String::from("hunter2");
+        let secrets = Secrets::new(Box::new(backend.clone()));
+        assert_eq!(secrets.get("secret/db", "value").unwrap(),
+                   Json::String(String::from("hunter2")));
+        assert_eq!(secrets.get("secret/db", "value").unwrap(),
+                   Json::String(String::from("hunter2")));
+        assert_eq!(*backend.reads.lock().unwrap(), 1);
+        assert!(secrets.get("secret/db", "password").is_err());
+    }
+
+    #[test]
+    fn rotated_secrets_change_the_generation() {
+        let backend = FakeBackend::default();
+        let secrets = Secrets::new(Box::new(backend.clone()));
+        secrets.get("secret/db", "value").unwrap();
+        secrets.refresh(Instant::now());
+        assert_eq!(*backend.reads.lock().unwrap(), 1);
+        secrets.refresh(hours_from_now(1));
+        assert_eq!(*backend.reads.lock().unwrap(), 2);
+        assert_eq!(secrets.generation(), 0);
+
+        *backend.value.lock().unwrap() = String::from("rotated");
+        secrets.refresh(hours_from_now(2));
+        assert_eq!(secrets.generation(), 1);
+        assert_eq!(secrets.get("secret/db", "value").unwrap(),
+                   Json::String(String::from("rotated")));
+    }
+
+    #[test]
+    fn expired_secrets_are_read_again() {
+        let backend = FakeBackend::default();
+        let secrets = Secrets::new(Box::new(backend.clone()));
+        secrets.get("secret/db", "value").unwrap();
+        secrets.cache.lock().unwrap().get_mut("secret/db").unwrap().expires_at =
+            Some(Instant::now());
+        secrets.get("secret/db", "value").unwrap();
+        assert_eq!(*backend.reads.lock().unwrap(), 2);
+    }
+
+    #[test]
+    fn leased_secrets_are_renewed() {
+        let backend = FakeBackend { leased: true, ..FakeBackend::default() };
+        let secrets = Secrets::new(Box::new(backend.clone()));
+        secrets.get("database/creds/app", "value").unwrap();
+        secrets.refresh(hours_from_now(1));
+        assert_eq!(*backend.reads.lock().unwrap(), 1);
+    }
+}
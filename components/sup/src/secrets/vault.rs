@@ -0,0 +1,184 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Secrets read from HashiCorp Vault's HTTP API.
+//!
+//! The Supervisor authenticates with the token in `VAULT_TOKEN`, and trusts the certificate
+//! authority in `VAULT_CACERT` if it's set, as the `vault` command line does.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::io::Read;
+use std::path::Path;
+use std::time::Duration;
+
+use http_client::{self, ApiClient};
+use hyper::client::RequestBuilder;
+use hyper::header::{ContentType, Headers};
+use hyper::status::StatusCode;
+use serde_json::{self, Value as Json};
+use url::Url;
+
+use {PRODUCT, VERSION};
+use error::{Error, Result};
+use super::{Secret, SecretsBackend};
+
+/// Leases which can be extended by less than this are read again instead.
+const MIN_LEASE_SECS: u64 = 10;
+
+pub struct Vault {
+    client: ApiClient,
+    token: String,
+}
+
+#[derive(Deserialize)]
+struct SecretResponse {
+    #[serde(default)]
+    lease_id: String,
+    #[serde(default)]
+    renewable: bool,
+    #[serde(default)]
+    lease_duration: u64,
+    #[serde(default)]
+    data: BTreeMap<String, Json>,
+}
+
+#[derive(Deserialize)]
+struct TokenLookupResponse {
+    data: TokenData,
+}
+
+#[derive(Deserialize)]
+struct TokenData {
+    ttl: u64,
+    #[serde(default)]
+    renewable: bool,
+}
+
+#[derive(Deserialize)]
+struct TokenRenewResponse {
+    auth: TokenAuth,
+}
+
+#[derive(Deserialize)]
+struct TokenAuth {
+    lease_duration: u64,
+}
+
+impl Vault {
+    /// Creates a client of the Vault server at `addr`, such as `https://vault.example.com:8200`.
+    ///
+    /// # Failures
+    ///
+    /// * The address isn't a URL
+    /// * `VAULT_TOKEN` isn't set
+    /// * The HTTP client can't be created, or `VAULT_CACERT` read
+    pub fn new(addr: &str) -> Result<Vault> {
+        let url = try!(Url::parse(addr)
+            .map_err(|e| sup_error!(Error::SecretsFailed(format!("{}: {}", addr, e)))));
+        let token = try!(env::var("VAULT_TOKEN").map_err(|_| {
+            sup_error!(Error::SecretsFailed(String::from("VAULT_TOKEN isn't set")))
+        }));
+        let client = match env::var("VAULT_CACERT") {
+            Ok(ca_file) => {
+                try!(ApiClient::with_ca_file(&url, PRODUCT, VERSION, Path::new(&ca_file)))
+            }
+            Err(_) => try!(ApiClient::new(&url, PRODUCT, VERSION, None)),
+        };
+        Ok(Vault {
+            client: client,
+            token: token,
+        })
+    }
+
+    /// Sends a request with our token, returning the body of a successful response.
+    fn send(&self, req: RequestBuilder, what: &str) -> Result<String> {
+        let mut headers = Headers::new();
+        headers.set_raw("X-Vault-Token", vec![self.token.clone().into_bytes()]);
+        headers.set(ContentType::json());
+        let mut res = match req.headers(headers).send() {
+            Ok(res) => res,
+            Err(e) => {
+                return Err(sup_error!(Error::HttpClient(http_client::Error::HyperError(e))))
+            }
+        };
+        let mut body = String::new();
+        try!(res.read_to_string(&mut body));
+        match res.status {
+            StatusCode::Ok => Ok(body),
+            StatusCode::NotFound => {
+                Err(sup_error!(Error::SecretsFailed(format!("{} doesn't exist", what))))
+            }
+            status => {
+                Err(sup_error!(Error::SecretsFailed(format!("{}: {}, {}",
+                                                            what,
+                                                            status,
+                                                            body.trim()))))
+            }
+        }
+    }
+}
+
+impl SecretsBackend for Vault {
+    fn read(&self, path: &str) -> Result<Secret> {
+        let body = try!(self.send(self.client.get(&format!("v1/{}", path)), path));
+        let res: SecretResponse = try!(parse(&body));
+        Ok(Secret {
+            data: res.data,
+            ttl: Duration::from_secs(res.lease_duration),
+            lease_id: if res.renewable && !res.lease_id.is_empty() {
+                Some(res.lease_id)
+            } else {
+                None
+            },
+        })
+    }
+
+    fn renew_lease(&self, lease_id: &str) -> Result<Option<Duration>> {
+        let mut body = BTreeMap::new();
+        body.insert("lease_id", lease_id);
+        let body = serde_json::to_string(&body).unwrap();
+        let req = self.client.put("v1/sys/leases/renew").body(body.as_str());
+        let res: SecretResponse = try!(parse(&try!(self.send(req, lease_id))));
+        if res.lease_duration < MIN_LEASE_SECS {
+            Ok(None)
+        } else {
+            Ok(Some(Duration::from_secs(res.lease_duration)))
+        }
+    }
+
+    fn renew_token(&self) -> Result<Option<Duration>> {
+        let body = try!(self.send(self.client.get("v1/auth/token/lookup-self"), "token"));
+        let lookup: TokenLookupResponse = try!(parse(&body));
+        if lookup.data.ttl == 0 {
+            return Ok(None);
+        }
+        if !lookup.data.renewable {
+            return Err(sup_error!(Error::SecretsFailed(format!("the token expires in {}s and \
+                                                                isn't renewable",
+                                                               lookup.data.ttl))));
+        }
+        let req = self.client.post("v1/auth/token/renew-self").body("{}");
+        let renewed: TokenRenewResponse = try!(parse(&try!(self.send(req, "token"))));
+        Ok(Some(Duration::from_secs(renewed.auth.lease_duration)))
+    }
+}
+
+fn parse<T>(body: &str) -> Result<T>
+    where T: ::serde::Deserialize
+{
+    serde_json::from_str(body).map_err(|e| {
+        sup_error!(Error::SecretsFailed(format!("unexpected response from Vault: {}", e)))
+    })
+}
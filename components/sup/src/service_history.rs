@@ -0,0 +1,162 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A bounded, in-memory history of each service's state and health transitions.
+//!
+//! Every time a service's process changes state, or a health check returns a different result
+//! from the last one, the change is recorded with when it happened and why, so the HTTP gateway
+//! can show what a service has been doing without any external logging. Only the most recent
+//! `HISTORY_CAPACITY` transitions of each service are kept.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+use time;
+
+/// The number of transitions kept for each service.
+pub const HISTORY_CAPACITY: usize = 100;
+
+/// A change of the state of a service's process.
+pub const STATE: &'static str = "state";
+/// A change of the result of a service's health check.
+pub const HEALTH: &'static str = "health";
+
+lazy_static! {
+    static ref HISTORY: History = History::new(HISTORY_CAPACITY);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Transition {
+    pub seq: u64,
+    /// When the transition happened, in seconds since the Unix epoch.
+    pub time: i64,
+    /// `state` or `health`.
+    pub kind: &'static str,
+    /// What it was before, or `None` if this is the first transition of its kind.
+    pub from: Option<String>,
+    pub to: String,
+    pub cause: String,
+}
+
+pub struct History {
+    capacity: usize,
+    inner: Mutex<Inner>,
+}
+
+struct Inner {
+    next_seq: u64,
+    services: HashMap<String, ServiceHistory>,
+}
+
+#[derive(Default)]
+struct ServiceHistory {
+    transitions: VecDeque<Transition>,
+    /// The latest value of each kind, which outlives the transitions dropped past capacity.
+    current: HashMap<&'static str, String>,
+}
+
+impl History {
+    pub fn new(capacity: usize) -> Self {
+        History {
+            capacity: capacity,
+            inner: Mutex::new(Inner {
+                next_seq: 0,
+                services: HashMap::new(),
+            }),
+        }
+    }
+
+    /// Records that the service group's `kind` is now `to`, because of `cause`. Nothing is
+    /// recorded if it already was; returns whether anything was.
+    pub fn record(&self, service_group: &str, kind: &'static str, to: &str, cause: &str) -> bool {
+        let mut inner = self.inner.lock().expect("Service history lock is poisoned!");
+        let seq = inner.next_seq;
+        {
+            let history = inner.services
+                .entry(service_group.to_string())
+                .or_insert_with(ServiceHistory::default);
+            if history.current.get(kind).map_or(false, |current| current == to) {
+                return false;
+            }
+            let from = history.current.insert(kind, to.to_string());
+            history.transitions.push_back(Transition {
+                seq: seq,
+                time: time::get_time().sec,
+                kind: kind,
+                from: from,
+                to: to.to_string(),
+                cause: cause.to_string(),
+            });
+            while history.transitions.len() > self.capacity {
+                history.transitions.pop_front();
+            }
+        }
+        inner.next_seq += 1;
+        true
+    }
+
+    /// Returns the service group's transitions, oldest first.
+    pub fn for_service(&self, service_group: &str) -> Vec<Transition> {
+        let inner = self.inner.lock().expect("Service history lock is poisoned!");
+        inner.services
+            .get(service_group)
+            .map_or(vec![], |history| history.transitions.iter().cloned().collect())
+    }
+}
+
+/// See `History::record`.
+pub fn record(service_group: &str, kind: &'static str, to: &str, cause: &str) -> bool {
+    HISTORY.record(service_group, kind, to, cause)
+}
+
+/// See `History::for_service`.
+pub fn for_service(service_group: &str) -> Vec<Transition> {
+    HISTORY.for_service(service_group)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{History, HEALTH, STATE};
+
+    #[test]
+    fn record_keeps_only_changes() {
+        let history = History::new(10);
+        assert!(history.record("redis.default", STATE, "start", "starting"));
+        assert!(history.record("redis.default", STATE, "up", "process 42 started"));
+        assert!(!history.record("redis.default", STATE, "up", "process 42 started"));
+        assert!(history.record("redis.default", HEALTH, "OK", "health check"));
+        assert!(history.record("nginx.default", STATE, "start", "starting"));
+
+        let transitions = history.for_service("redis.default");
+        assert_eq!(transitions.iter()
+                       .map(|t| (t.seq, t.kind, t.from.clone(), t.to.as_str()))
+                       .collect::<Vec<_>>(),
+                   vec![(0, STATE, None, "start"),
+                        (1, STATE, Some(String::from("start")), "up"),
+                        (2, HEALTH, None, "OK")]);
+        assert!(history.for_service("haproxy.default").is_empty());
+    }
+
+    #[test]
+    fn record_drops_transitions_past_capacity() {
+        let history = History::new(2);
+        for state in &["start", "up", "down"] {
+            history.record("redis.default", STATE, state, "test");
+        }
+        let transitions = history.for_service("redis.default");
+        assert_eq!(transitions.len(), 2);
+        assert_eq!(transitions[0].to, "up");
+        assert_eq!(transitions[0].from, Some(String::from("start")));
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A bounded, in-memory log of the output of each service's process.
+//!
+//! Works like the hook log: every line gets a sequence number, so clients of the HTTP gateway can
+//! follow a service's output by asking for the lines since the last one they saw. Each service
+//! group keeps its most recent `SERVICE_LOG_CAPACITY` lines, so a chatty service can't push a
+//! quiet one's output out of the log.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use time;
+
+/// The number of lines of output kept in memory for each service group.
+pub const SERVICE_LOG_CAPACITY: usize = 1000;
+/// The longest a client may wait for new output in a single request.
+pub const MAX_WAIT_SECS: u64 = 30;
+
+lazy_static! {
+    static ref SERVICE_LOG: ServiceLog = ServiceLog::new(SERVICE_LOG_CAPACITY);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LogLine {
+    pub seq: u64,
+    /// When the line was written, in seconds since the epoch.
+    pub time: i64,
+    pub line: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LogOutput {
+    /// The sequence number to ask for next time.
+    pub next: u64,
+    pub lines: Vec<LogLine>,
+}
+
+/// Which of a service group's lines to return. See `ServiceLog::since`.
+#[derive(Debug, Default)]
+pub struct Query {
+    /// Only lines numbered this and up.
+    pub since: u64,
+    /// Only lines written at or after this time, in seconds since the epoch.
+    pub newer_than: Option<i64>,
+    /// Only the last this many of the lines which match.
+    pub lines: Option<usize>,
+}
+
+pub struct ServiceLog {
+    capacity: usize,
+    inner: Mutex<Inner>,
+    updated: Condvar,
+}
+
+struct Inner {
+    next_seq: u64,
+    lines: HashMap<String, VecDeque<LogLine>>,
+}
+
+impl ServiceLog {
+    pub fn new(capacity: usize) -> Self {
+        ServiceLog {
+            capacity: capacity,
+            inner: Mutex::new(Inner {
+                next_seq: 0,
+                lines: HashMap::new(),
+            }),
+            updated: Condvar::new(),
+        }
+    }
+
+    pub fn push(&self, service_group: &str, line: &str) {
+        let mut inner = self.inner.lock().expect("Service log lock is poisoned!");
+        let seq = inner.next_seq;
+        inner.next_seq += 1;
+        let lines = inner.lines.entry(service_group.to_string()).or_insert(VecDeque::new());
+        lines.push_back(LogLine {
+            seq: seq,
+            time: time::get_time().sec,
+            line: line.to_string(),
+        });
+        while lines.len() > self.capacity {
+            lines.pop_front();
+        }
+        self.updated.notify_all();
+    }
+
+    /// Return the service group's lines which match the query. If there are none yet, wait up to
+    /// `wait` for some to arrive.
+    pub fn since(&self, service_group: &str, query: &Query, wait: Duration) -> LogOutput {
+        let deadline = Instant::now() + wait;
+        let mut inner = self.inner.lock().expect("Service log lock is poisoned!");
+        loop {
+            let mut lines: Vec<LogLine> = inner.lines
+                .get(service_group)
+                .map(|lines| {
+                    lines.iter()
+                        .filter(|l| l.seq >= query.since)
+                        .filter(|l| query.newer_than.map_or(true, |t| l.time >= t))
+                        .cloned()
+                        .collect()
+                })
+                .unwrap_or(Vec::new());
+            if let Some(n) = query.lines {
+                let skip = lines.len().saturating_sub(n);
+                lines.drain(..skip);
+            }
+            let now = Instant::now();
+            if !lines.is_empty() || now >= deadline {
+                return LogOutput {
+                    next: inner.next_seq,
+                    lines: lines,
+                };
+            }
+            inner = self.updated
+                .wait_timeout(inner, deadline - now)
+                .expect("Service log lock is poisoned!")
+                .0;
+        }
+    }
+}
+
+/// Append a line of a service's output to the Supervisor's service log.
+pub fn push(service_group: &str, line: &str) {
+    SERVICE_LOG.push(service_group, line)
+}
+
+/// See `ServiceLog::since`. The wait is capped at `MAX_WAIT_SECS`.
+pub fn since(service_group: &str, query: &Query, wait: Duration) -> LogOutput {
+    let wait = ::std::cmp::min(wait, Duration::from_secs(MAX_WAIT_SECS));
+    SERVICE_LOG.since(service_group, query, wait)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{Query, ServiceLog};
+
+    #[test]
+    fn since_returns_the_service_groups_newer_lines() {
+        let log = ServiceLog::new(10);
+        log.push("redis.default", "one");
+        log.push("nginx.default", "two");
+        log.push("redis.default", "three");
+        let query = Query { since: 1, ..Query::default() };
+        let output = log.since("redis.default", &query, Duration::from_millis(0));
+        assert_eq!(output.next, 3);
+        assert_eq!(output.lines.iter().map(|l| l.line.as_str()).collect::<Vec<_>>(),
+                   vec!["three"]);
+    }
+
+    #[test]
+    fn since_returns_the_last_lines() {
+        let log = ServiceLog::new(10);
+        for line in &["one", "two", "three"] {
+            log.push("redis.default", line);
+        }
+        let query = Query { lines: Some(2), ..Query::default() };
+        let output = log.since("redis.default", &query, Duration::from_millis(0));
+        assert_eq!(output.lines.iter().map(|l| l.line.as_str()).collect::<Vec<_>>(),
+                   vec!["two", "three"]);
+    }
+
+    #[test]
+    fn since_drops_lines_past_capacity_per_service_group() {
+        let log = ServiceLog::new(2);
+        log.push("nginx.default", "quiet");
+        for line in &["one", "two", "three"] {
+            log.push("redis.default", line);
+        }
+        let output = log.since("redis.default", &Query::default(), Duration::from_millis(0));
+        assert_eq!(output.lines[0].line, "two");
+        let output = log.since("nginx.default", &Query::default(), Duration::from_millis(0));
+        assert_eq!(output.lines.len(), 1);
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An in-process ring of Supervisors, for integration tests. Only built with the `simulation`
+//! feature.
+//!
+//! Each member of a `SimRing` is a real Butterfly server listening on localhost, standing in for
+//! a Supervisor: tests gossip the rumors a Supervisor would, and read back the census each member
+//! builds from what it heard, exactly as the manager does. The network between the members can be
+//! partitioned, slowed down and made lossy, so elections, updates and binds can be tested against
+//! a misbehaving network without running any services.
+//!
+//! ```ignore
+//! let mut ring = SimRing::new(3);
+//! ring.add_service_to_all("core/redis/3.2.4/20170514150022", "default");
+//! ring.start_election(0, "redis.default", 0);
+//! assert!(ring.wait_for_census(2, "redis.default", |c| c.get_leader().is_some()));
+//! ```
+
+use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+
+use butterfly::rumor::service::{Service as ServiceRumor, SysInfo};
+use butterfly_test::SwimNet;
+use hcore::package::{Identifiable, PackageIdent};
+use hcore::service::ServiceGroup;
+use toml;
+
+use manager::census::{Census, CensusList};
+
+pub struct SimRing {
+    net: SwimNet,
+}
+
+impl Deref for SimRing {
+    type Target = SwimNet;
+
+    fn deref(&self) -> &SwimNet {
+        &self.net
+    }
+}
+
+impl DerefMut for SimRing {
+    fn deref_mut(&mut self) -> &mut SwimNet {
+        &mut self.net
+    }
+}
+
+impl SimRing {
+    /// Starts a fully meshed ring of `count` members.
+    pub fn new(count: usize) -> SimRing {
+        let mut net = SwimNet::new(count);
+        net.mesh();
+        SimRing { net: net }
+    }
+
+    /// Gossips that `member` runs `package` in `group`, as its Supervisor does when the service is
+    /// loaded.
+    pub fn add_service(&mut self, member: usize, package: &str, group: &str) {
+        let ident = PackageIdent::from_str(package)
+            .expect("package needs to be a fully qualified package identifier");
+        let sg = ServiceGroup::new(ident.name(), group, None).unwrap();
+        let rumor = ServiceRumor::new(self.net[member].member_id().to_string(),
+                                      &ident,
+                                      &sg,
+                                      &SysInfo::default(),
+                                      None)
+            .unwrap();
+        self.net[member].insert_service(rumor);
+    }
+
+    /// Adds the service to every member of the ring.
+    pub fn add_service_to_all(&mut self, package: &str, group: &str) {
+        for member in 0..self.net.len() {
+            self.add_service(member, package, group);
+        }
+    }
+
+    /// Gossips a new exported configuration for the service `member` runs in `service_group`,
+    /// which is what its binds see. `cfg` is TOML.
+    pub fn export_cfg(&self, member: usize, service_group: &str, cfg: &str) {
+        let table = toml::Parser::new(cfg).parse().expect("cfg needs to be valid TOML");
+        self.update_service(member,
+                            service_group,
+                            |rumor| *rumor.mut_cfg() = toml::encode_str(&table).into_bytes());
+    }
+
+    /// Gossips that `member` now runs `package` in `service_group`, as its Supervisor does when
+    /// the service is updated.
+    pub fn update_package(&self, member: usize, service_group: &str, package: &str) {
+        let ident = PackageIdent::from_str(package)
+            .expect("package needs to be a fully qualified package identifier");
+        self.update_service(member,
+                            service_group,
+                            |rumor| rumor.set_pkg(ident.to_string()));
+    }
+
+    /// Starts an election for `service_group` on `member`.
+    pub fn start_election(&self, member: usize, service_group: &str, suitability: u64) {
+        let sg = ServiceGroup::from_str(service_group).expect("invalid service group");
+        self.net[member].start_election(sg, suitability, 0);
+    }
+
+    /// Starts an update election for `service_group` on `member`.
+    pub fn start_update_election(&self, member: usize, service_group: &str, suitability: u64) {
+        let sg = ServiceGroup::from_str(service_group).expect("invalid service group");
+        self.net[member].start_update_election(sg, suitability, 0);
+    }
+
+    /// The census `member` would build from what it has heard.
+    pub fn census_list(&self, member: usize) -> CensusList {
+        CensusList::from_butterfly(&self.net[member])
+    }
+
+    /// Waits for the census of `service_group`, as `member` sees it, to satisfy `check`. Gives up
+    /// after as many gossip rounds as the slowest member has already been through, like the
+    /// Butterfly tests do.
+    pub fn wait_for_census<F>(&self, member: usize, service_group: &str, check: F) -> bool
+        where F: Fn(&Census) -> bool
+    {
+        let rounds_in = self.net.gossip_rounds_in(self.net.max_gossip_rounds());
+        loop {
+            if let Some(census) = self.census_list(member).get(service_group) {
+                if check(census) {
+                    return true;
+                }
+            }
+            if self.net.check_gossip_rounds(&rounds_in) {
+                println!("Failed census check for {} on member {}: {:#?}",
+                         service_group,
+                         member,
+                         self.census_list(member).get(service_group));
+                return false;
+            }
+        }
+    }
+
+    /// Waits for every member to see `service_group` satisfy `check`.
+    pub fn wait_for_all_censuses<F>(&self, service_group: &str, check: F) -> bool
+        where F: Fn(&Census) -> bool
+    {
+        (0..self.net.len()).all(|member| self.wait_for_census(member, service_group, &check))
+    }
+
+    fn update_service<F>(&self, member: usize, service_group: &str, mut with_rumor: F)
+        where F: FnMut(&mut ServiceRumor)
+    {
+        let server = &self.net[member];
+        let mut rumor = {
+            let list = server.service_store.list.read().expect("Rumor store lock poisoned");
+            list.get(service_group)
+                .and_then(|r| r.get(server.member_id()))
+                .expect("member doesn't run a service in that service group")
+                .clone()
+        };
+        let incarnation = rumor.get_incarnation() + 1;
+        with_rumor(&mut rumor);
+        rumor.set_incarnation(incarnation);
+        server.insert_service(rumor);
+    }
+}
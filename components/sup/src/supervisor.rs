@@ -18,6 +18,7 @@
 /// spawning the new process, watching for failure, and ensuring the service is either up or down.
 /// If the process dies, the supervisor will restart it.
 
+use std::collections::BTreeMap;
 use std::fmt;
 use std::fs::{self, File};
 use std::io::BufReader;
@@ -28,13 +29,19 @@ use std::result;
 use std::thread;
 
 use hcore;
-use hcore::os::process::{HabChild, ExitStatusExt};
+use hcore::os::process::{Confinement, HabChild, ExitStatusExt};
 use hcore::package::PackageIdent;
 use hcore::service::ServiceGroup;
 use serde::{Serialize, Serializer};
 use time::SteadyTime;
 
 use error::{Result, Error};
+use manager::census::{census_file_path, CENSUS_ENVVAR};
+use manager::service::{PortForwarder, PortMapping, ProcessLimits, ServiceDirs};
+use package::HookInterpreter;
+use redact;
+use service_history;
+use service_log;
 use util;
 
 const PIDFILE_NAME: &'static str = "PID";
@@ -68,13 +75,42 @@ impl fmt::Display for ProcessState {
 pub struct RuntimeConfig {
     pub svc_user: String,
     pub svc_group: String,
+    /// The interpreter to run the service's run script with, unless it has a shebang line.
+    #[serde(default)]
+    pub hook_interpreter: HookInterpreter,
+    /// The umask and resource limits to start the service's process with.
+    #[serde(default)]
+    pub limits: ProcessLimits,
+    /// The capabilities and seccomp filter to confine the service's process with.
+    #[serde(skip_serializing, skip_deserializing)]
+    pub confinement: Confinement,
+    /// The host ports forwarded to the service, if it runs in a network namespace of its own.
+    #[serde(default)]
+    pub ports: Vec<PortMapping>,
+    /// The extra directories readied before the service's process starts.
+    #[serde(default)]
+    pub dirs: ServiceDirs,
+    /// The environment variables the package and its dependencies export.
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
 }
 
 impl RuntimeConfig {
-    pub fn new(svc_user: String, svc_group: String) -> RuntimeConfig {
+    pub fn new(svc_user: String,
+               svc_group: String,
+               hook_interpreter: HookInterpreter,
+               limits: ProcessLimits,
+               confinement: Confinement)
+               -> RuntimeConfig {
         RuntimeConfig {
             svc_user: svc_user,
             svc_group: svc_group,
+            hook_interpreter: hook_interpreter,
+            limits: limits,
+            confinement: confinement,
+            ports: Vec::new(),
+            dirs: ServiceDirs::default(),
+            env: BTreeMap::new(),
         }
     }
 }
@@ -83,11 +119,18 @@ impl RuntimeConfig {
 pub struct Supervisor {
     pub child: Option<HabChild>,
     pub package_ident: PackageIdent,
+    /// The name of the service, which names its directory in `/hab/svc`.
+    pub service_name: String,
     pub preamble: String,
     pub state: ProcessState,
     pub state_entered: SteadyTime,
     pub has_started: bool,
     pub runtime_config: RuntimeConfig,
+    /// Exit code of the last process to exit on its own, or `None` if it was killed by a signal.
+    pub last_exit_code: Option<i32>,
+    /// The service's network namespace and the forwarding of its host ports into it, if it is
+    /// isolated.
+    pub port_forwarder: Option<PortForwarder>,
 }
 
 impl Supervisor {
@@ -98,15 +141,20 @@ impl Supervisor {
         Supervisor {
             child: None,
             package_ident: package_ident,
+            service_name: service_group.service().to_string(),
             preamble: format!("{}", service_group),
             state: ProcessState::Down,
             state_entered: SteadyTime::now(),
             has_started: false,
             runtime_config: runtime_config,
+            last_exit_code: None,
+            port_forwarder: None,
         }
     }
 
-    fn enter_state(&mut self, state: ProcessState) {
+    /// Enters `state` because of `cause`, which is recorded in the service's history.
+    fn enter_state(&mut self, state: ProcessState, cause: &str) {
+        service_history::record(&self.preamble, service_history::STATE, &state.to_string(), cause);
         self.state = state;
         self.state_entered = SteadyTime::now();
     }
@@ -123,26 +171,42 @@ impl Supervisor {
         (healthy, status)
     }
 
-    pub fn start(&mut self) -> Result<()> {
+    pub fn start(&mut self, cause: &str) -> Result<()> {
         if self.child.is_none() {
             outputln!(preamble self.preamble,
                       "Starting process as user={}, group={}",
                       &self.runtime_config.svc_user,
                       &self.runtime_config.svc_group);
-            self.enter_state(ProcessState::Start);
-            let mut child = try!(util::create_command(self.run_cmd(),
-                                                      &self.runtime_config.svc_user,
-                                                      &self.runtime_config.svc_group)
+            self.enter_state(ProcessState::Start, cause);
+            let svc_path = hcore::fs::svc_path(&self.service_name);
+            try!(self.runtime_config.dirs.prepare(&svc_path,
+                                                  &self.runtime_config.svc_user,
+                                                  &self.runtime_config.svc_group,
+                                                  &self.preamble));
+            let mut cmd = try!(util::create_service_command(self.run_cmd(),
+                                                            self.runtime_config.hook_interpreter,
+                                                            &self.runtime_config.svc_user,
+                                                            &self.runtime_config.svc_group,
+                                                            &self.runtime_config.limits,
+                                                            &self.runtime_config.confinement));
+            if let Some(workdir) = self.runtime_config.dirs.workdir(&svc_path) {
+                cmd.current_dir(workdir);
+            }
+            for (key, value) in self.runtime_config.env.iter() {
+                cmd.env(key, value);
+            }
+            let mut child = try!(cmd.env(CENSUS_ENVVAR, census_file_path(&self.service_name))
                 .spawn());
 
             let hab_child = try!(HabChild::from(&mut child));
+            let started = format!("process {} started", hab_child.id());
             self.child = Some(hab_child);
             try!(self.create_pidfile());
             let package_name = self.preamble.clone();
             try!(thread::Builder::new()
                 .name(String::from("sup-service-read"))
                 .spawn(move || -> Result<()> { child_reader(&mut child, package_name) }));
-            self.enter_state(ProcessState::Up);
+            self.enter_state(ProcessState::Up, &started);
             self.has_started = true;
         } else {
             outputln!(preamble & self.preamble, "Already started");
@@ -180,22 +244,25 @@ impl Supervisor {
         }
     }
 
-    pub fn down(&mut self) -> Result<()> {
-        self.enter_state(ProcessState::Down);
+    pub fn down(&mut self, cause: &str) -> Result<()> {
+        self.enter_state(ProcessState::Down, cause);
         try!(self.stop());
         self.cleanup_pidfile();
         Ok(())
     }
 
-    pub fn restart(&mut self) -> Result<()> {
-        self.enter_state(ProcessState::Restart);
+    pub fn restart(&mut self, cause: &str) -> Result<()> {
+        self.enter_state(ProcessState::Restart, cause);
         try!(self.stop());
-        try!(self.start());
+        try!(self.start(cause));
         Ok(())
     }
 
     /// if the child process exists, check it's status via waitpid().
-    pub fn check_process(&mut self) {
+    ///
+    /// Returns true if the process exited while we expected it to be running. The exit code is
+    /// recorded in `last_exit_code`.
+    pub fn check_process(&mut self) -> bool {
         let changed = match self.child {
             None => false,
             Some(ref mut child) => {
@@ -213,6 +280,7 @@ impl Supervisor {
                                       child.id(),
                                       status.signal().unwrap());
                         }
+                        self.last_exit_code = status.code();
                         true
                     }
                     Err(e) => {
@@ -226,14 +294,26 @@ impl Supervisor {
             match self.state {
                 ProcessState::Up | ProcessState::Start | ProcessState::Restart => {
                     outputln!("{} - Service exited", self.preamble);
+                    // The state is left for the restart to change, but the history shows the
+                    // process was gone in between.
+                    let cause = match self.last_exit_code {
+                        Some(code) => format!("process exited with code {}", code),
+                        None => String::from("process killed by a signal"),
+                    };
+                    service_history::record(&self.preamble,
+                                            service_history::STATE,
+                                            "exited",
+                                            &cause);
                     self.child = None;
+                    return true;
                 }
                 ProcessState::Down => {
-                    self.enter_state(ProcessState::Down);
+                    self.enter_state(ProcessState::Down, "stopped");
                     self.child = None;
                 }
             }
         }
+        false
     }
 
     pub fn run_cmd(&self) -> PathBuf {
@@ -241,7 +321,7 @@ impl Supervisor {
     }
 
     pub fn service_dir(&self) -> PathBuf {
-        hcore::fs::svc_path(&self.package_ident.name)
+        hcore::fs::svc_path(&self.service_name)
     }
 
     pub fn pid_file(&self) -> PathBuf {
@@ -312,7 +392,7 @@ impl Serialize for Supervisor {
             Some(ref child) => Some(child.id()),
             None => None,
         };
-        let mut state = try!(serializer.serialize_struct("supervisor", 7));
+        let mut state = try!(serializer.serialize_struct("supervisor", 8));
         try!(serializer.serialize_struct_elt(&mut state, "pid", &pid));
         try!(serializer.serialize_struct_elt(&mut state, "package", &self.package_ident));
         try!(serializer.serialize_struct_elt(&mut state, "preamble", &self.preamble));
@@ -321,6 +401,7 @@ impl Serialize for Supervisor {
             &self.state_entered.to_string()));
         try!(serializer.serialize_struct_elt(&mut state, "started", &self.has_started));
         try!(serializer.serialize_struct_elt(&mut state, "runtime_config", &self.runtime_config));
+        try!(serializer.serialize_struct_elt(&mut state, "last_exit_code", &self.last_exit_code));
         serializer.serialize_struct_end(state)
     }
 }
@@ -331,7 +412,8 @@ impl Drop for Supervisor {
     }
 }
 
-/// Consume output from a child process until EOF, then finish
+/// Consume output from a child process until EOF, then finish. Each line is printed, and kept in
+/// the service log for `hab svc logs`.
 fn child_reader(child: &mut Child, package_name: String) -> Result<()> {
     let c_stdout = match child.stdout {
         Some(ref mut s) => s,
@@ -342,9 +424,11 @@ fn child_reader(child: &mut Child, package_name: String) -> Result<()> {
     let mut buffer = String::new();
 
     while reader.read_line(&mut buffer).unwrap() > 0 {
+        let output = redact::redact(&buffer);
         let mut line = output_format!(preamble &package_name, logkey "O");
-        line.push_str(&buffer);
+        line.push_str(&output);
         print!("{}", line);
+        service_log::push(&package_name, output.trim_right_matches(&['\r', '\n'][..]));
         buffer.clear();
     }
     debug!("child_reader exiting");
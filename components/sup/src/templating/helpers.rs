@@ -17,12 +17,26 @@ use std::string::ToString;
 
 use hcore::package::{PackageIdent, PackageInstall, Identifiable};
 use manager::service::config::ServiceConfig;
-use handlebars::{Handlebars, Helper, RenderContext, RenderError};
-use serde_json;
+use secrets;
+use handlebars::{to_json, Handlebars, Helper, RenderContext, RenderError, Renderable};
+use serde_json::{self, Value as Json};
 use toml;
 
 type RenderResult = Result<(), RenderError>;
 
+/// The strategies `selectMembers` picks members of a census member list with.
+#[derive(Debug, PartialEq)]
+pub enum Selection<'a> {
+    /// Every alive member.
+    Alive,
+    /// The leader, if it is alive.
+    Leader,
+    /// The alive members whose label has the given value, or every alive member if none do.
+    Nearest(&'a str, Option<&'a str>),
+    /// The given number of alive members, ranked by a hash of the key and their member ids.
+    Hash(&'a str, usize),
+}
+
 pub fn pkg_path_for(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
     let param = try!(h.param(0)
         .and_then(|v| v.value().as_str())
@@ -92,3 +106,188 @@ pub fn to_toml(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResu
     try!(rc.writer.write(toml::encode_str(&param).into_bytes().as_ref()));
     Ok(())
 }
+
+/// Renders a field of a secret read from Vault, `value` unless another is given:
+///
+/// ```text
+/// password = "{{vault "secret/db/password"}}"
+/// user = "{{vault "database/creds/app" field="username"}}"
+/// ```
+///
+/// The Supervisor must be started with `--vault-addr`. Secrets are cached, and the template is
+/// rendered again when one changes. A secret which isn't cached is waited for for no more than a
+/// couple of seconds, so a slow Vault can't hold up the Manager; the render fails meanwhile, and
+/// is done again once the secret has been read.
+pub fn vault(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let path = try!(h.param(0)
+        .and_then(|v| v.value().as_str())
+        .ok_or_else(|| RenderError::new("Expected a secret path for \"vault\"")));
+    let field = h.hash_get("field").and_then(|v| v.value().as_str()).unwrap_or("value");
+    let secrets = try!(secrets::secrets().ok_or_else(|| {
+        RenderError::new("\"vault\" needs the Supervisor to be started with --vault-addr")
+    }));
+    let value = try!(secrets.get(path, field)
+        .map_err(|e| RenderError::new(format!("Cannot read secret {}: {}", path, e))));
+    let value = match value {
+        Json::String(s) => s,
+        other => other.to_string(),
+    };
+    try!(rc.writer.write(value.into_bytes().as_ref()));
+    Ok(())
+}
+
+/// Renders its block for each of the members of a census member list picked by the `strategy`
+/// hash parameter, with the member as the block's context:
+///
+/// ```text
+/// {{#selectMembers bind.database.members strategy="nearest" label="zone"}}
+/// server {{sys.ip}}:{{cfg.port}}
+/// {{/selectMembers}}
+/// ```
+///
+/// The `nearest` strategy compares the `label` of each member with this Supervisor's, and `hash`
+/// picks `count` members (default 1) for the `key` (default: this service's name), which every
+/// member rendering the template picks alike, and which mostly stay picked as members come and go.
+pub fn select_members(h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let param = try!(h.param(0)
+        .ok_or_else(|| RenderError::new("Expected a member list for \"selectMembers\"")));
+    let members = try!(param.value()
+        .as_array()
+        .ok_or_else(|| RenderError::new("Expected a member list for \"selectMembers\"")));
+    let path = try!(param.path()
+        .ok_or_else(|| RenderError::new("Expected a member list for \"selectMembers\"")));
+    let data = rc.context().data().clone();
+    let hash_str = |name: &str| h.hash_get(name).and_then(|v| v.value().as_str());
+    let selection = match hash_str("strategy").unwrap_or("alive") {
+        "alive" => Selection::Alive,
+        "leader" => Selection::Leader,
+        "nearest" => {
+            let label = try!(hash_str("label").ok_or_else(|| {
+                RenderError::new("\"selectMembers\" strategy \"nearest\" needs a label")
+            }));
+            let mine = data.lookup(&format!("sys.labels.{}", label)).and_then(|v| v.as_str());
+            Selection::Nearest(label, mine)
+        }
+        "hash" => {
+            let key = hash_str("key")
+                .or_else(|| data.lookup("svc.service").and_then(|v| v.as_str()))
+                .unwrap_or("");
+            let count = h.hash_get("count").and_then(|v| v.value().as_u64()).unwrap_or(1);
+            Selection::Hash(key, count as usize)
+        }
+        other => {
+            return Err(RenderError::new(format!("Unknown \"selectMembers\" strategy \"{}\"",
+                                                other)))
+        }
+    };
+    if let Some(t) = h.template() {
+        for (n, i) in select(members, &selection).into_iter().enumerate() {
+            let mut local_rc = rc.derive();
+            local_rc.set_local_var("@index".to_string(), to_json(&n));
+            let new_path = format!("{}/{}.[{}]", local_rc.get_path(), path, i);
+            local_rc.set_path(new_path);
+            try!(t.render(r, &mut local_rc));
+        }
+    }
+    Ok(())
+}
+
+/// Returns the indexes of the members picked by the selection, in the order they are picked.
+pub fn select(members: &[Json], selection: &Selection) -> Vec<usize> {
+    let is = |member: &Json, field: &str| {
+        member.find(field).and_then(|v| v.as_bool()).unwrap_or(false)
+    };
+    let alive: Vec<usize> = (0..members.len()).filter(|&i| is(&members[i], "alive")).collect();
+    match *selection {
+        Selection::Alive => alive,
+        Selection::Leader => alive.into_iter().filter(|&i| is(&members[i], "leader")).collect(),
+        Selection::Nearest(label, mine) => {
+            let near: Vec<usize> = alive.iter()
+                .cloned()
+                .filter(|&i| {
+                    mine.is_some() &&
+                    members[i].lookup(&format!("sys.labels.{}", label)).and_then(|v| v.as_str()) ==
+                    mine
+                })
+                .collect();
+            if near.is_empty() { alive } else { near }
+        }
+        Selection::Hash(key, count) => {
+            let mut ranked: Vec<(u64, usize)> = alive.into_iter()
+                .map(|i| {
+                    let id = members[i].find("member_id").and_then(|v| v.as_str()).unwrap_or("");
+                    (fnv1a(&format!("{}/{}", key, id)), i)
+                })
+                .collect();
+            ranked.sort_by(|a, b| b.cmp(a));
+            ranked.into_iter().take(count).map(|(_, i)| i).collect()
+        }
+    }
+}
+
+/// A hash which is the same on every host and Rust release, unlike the standard library's.
+fn fnv1a(s: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for b in s.bytes() {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{self, Value as Json};
+
+    use super::{select, Selection};
+
+    fn members() -> Vec<Json> {
+        let json = r#"[
+            {"member_id": "a", "alive": true, "leader": false, "sys": {"labels": {"zone": "1a"}}},
+            {"member_id": "b", "alive": true, "leader": true, "sys": {"labels": {"zone": "1b"}}},
+            {"member_id": "c", "alive": false, "leader": false, "sys": {"labels": {"zone": "1a"}}},
+            {"member_id": "d", "alive": true, "leader": false, "sys": {"labels": {}}}
+        ]"#;
+        match serde_json::from_str(json).unwrap() {
+            Json::Array(members) => members,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn select_alive_and_leader() {
+        let members = members();
+        assert_eq!(select(&members, &Selection::Alive), vec![0, 1, 3]);
+        assert_eq!(select(&members, &Selection::Leader), vec![1]);
+    }
+
+    #[test]
+    fn select_nearest() {
+        let members = members();
+        assert_eq!(select(&members, &Selection::Nearest("zone", Some("1a"))), vec![0]);
+        assert_eq!(select(&members, &Selection::Nearest("zone", Some("1c"))), vec![0, 1, 3]);
+        assert_eq!(select(&members, &Selection::Nearest("zone", None)), vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn select_hash_is_stable() {
+        let members = members();
+        let picked = select(&members, &Selection::Hash("web", 2));
+        assert_eq!(picked.len(), 2);
+        assert!(!picked.contains(&2));
+        assert_eq!(select(&members, &Selection::Hash("web", 2)), picked);
+        // Losing a member which wasn't picked doesn't change the ones which were
+        let remaining: Vec<Json> = members.iter()
+            .enumerate()
+            .filter(|&(i, _)| i == 2 || picked.contains(&i))
+            .map(|(_, m)| m.clone())
+            .collect();
+        let ids = |members: &[Json], picked: Vec<usize>| -> Vec<String> {
+            picked.into_iter()
+                .map(|i| members[i].find("member_id").unwrap().as_str().unwrap().to_string())
+                .collect()
+        };
+        assert_eq!(ids(&remaining, select(&remaining, &Selection::Hash("web", 2))),
+                   ids(&members, picked));
+    }
+}
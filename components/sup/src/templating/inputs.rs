@@ -0,0 +1,169 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tracks the inputs a service's templates are rendered from.
+//!
+//! On a busy ring the census changes constantly, but most of those changes are to service groups
+//! a given service's templates never look at. Rather than re-rendering every service on every
+//! census change, the Supervisor fingerprints the template sources along with only the parts of
+//! the template data they reference, and skips rendering when the fingerprint hasn't changed.
+//!
+//! References are found by scanning the template sources for the top level namespaces (`cfg`,
+//! `svc`, `bind`, and so on) and the key directly beneath them, so
+//! `{{#each bind.database.members}}` references all of `bind.database`. This errs on the side of
+//! re-rendering; a template which names a whole namespace references all of it.
+
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::PathBuf;
+
+use hcore::crypto::hash;
+use regex::Regex;
+use serde_json;
+
+use error::Result;
+
+lazy_static! {
+    /// Matches a reference to a top level namespace of the template data, and the key beneath it.
+    static ref DATA_REFERENCE: Regex =
+        Regex::new(r"\b(hab|pkg|sys|cfg|svc|bind)\b(?:\.([A-Za-z0-9_-]+))?").unwrap();
+}
+
+#[derive(Debug, Default)]
+pub struct TemplateInputs {
+    sources: Vec<String>,
+    references: BTreeSet<String>,
+}
+
+impl TemplateInputs {
+    /// Read the templates at the given paths.
+    pub fn load(paths: &[PathBuf]) -> Result<Self> {
+        let mut inputs = TemplateInputs::default();
+        for path in paths {
+            let mut file = try!(File::open(path));
+            let mut source = String::new();
+            try!(file.read_to_string(&mut source));
+            inputs.add_source(source);
+        }
+        Ok(inputs)
+    }
+
+    pub fn add_source(&mut self, source: String) {
+        for capture in DATA_REFERENCE.captures_iter(&source) {
+            let namespace = capture.get(1).unwrap().as_str();
+            match capture.get(2) {
+                Some(key) => self.references.insert(format!("{}.{}", namespace, key.as_str())),
+                None => self.references.insert(namespace.to_string()),
+            };
+        }
+        self.sources.push(source);
+    }
+
+    /// Returns true if the templates reference the given namespace or key, such as `sys.ip`.
+    pub fn references(&self, reference: &str) -> bool {
+        self.references.contains(reference)
+    }
+
+    /// Returns a hash of the template sources and the parts of `data` they reference. The hash
+    /// only changes when rendering the templates with `data` could produce different output.
+    pub fn fingerprint(&self, data: &serde_json::Value) -> Result<String> {
+        let referenced: Vec<(&str, Option<&serde_json::Value>)> = self.references
+            .iter()
+            .filter(|r| !self.covered_by_namespace(r))
+            .map(|r| (r.as_str(), lookup(data, r)))
+            .collect();
+        let mut input = serde_json::to_string(&referenced).unwrap();
+        for source in self.sources.iter() {
+            input.push_str(source);
+        }
+        Ok(try!(hash::hash_string(&input)))
+    }
+
+    /// A key whose whole namespace is referenced doesn't need to be hashed on its own.
+    fn covered_by_namespace(&self, reference: &str) -> bool {
+        match reference.find('.') {
+            Some(idx) => self.references.contains(&reference[..idx]),
+            None => false,
+        }
+    }
+}
+
+fn lookup<'a>(data: &'a serde_json::Value, path: &str) -> Option<&'a serde_json::Value> {
+    let mut current = data;
+    for segment in path.split('.') {
+        current = match *current {
+            serde_json::Value::Object(ref map) => {
+                match map.get(segment) {
+                    Some(value) => value,
+                    None => return None,
+                }
+            }
+            _ => return None,
+        };
+    }
+    Some(current)
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json;
+
+    use super::TemplateInputs;
+
+    fn data(json: &str) -> serde_json::Value {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn unreferenced_data_does_not_change_fingerprint() {
+        let mut inputs = TemplateInputs::default();
+        inputs.add_source(String::from("{{#each bind.database.members}}{{ip}}{{/each}}"));
+        let before = inputs.fingerprint(&data(r#"{"bind": {"database": {"members": ["a"]},
+                                                           "cache": {"members": ["b"]}}}"#))
+            .unwrap();
+        let after = inputs.fingerprint(&data(r#"{"bind": {"database": {"members": ["a"]},
+                                                          "cache": {"members": ["c"]}}}"#))
+            .unwrap();
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn referenced_data_changes_fingerprint() {
+        let mut inputs = TemplateInputs::default();
+        inputs.add_source(String::from("port={{cfg.port}}"));
+        let before = inputs.fingerprint(&data(r#"{"cfg": {"port": 1}}"#)).unwrap();
+        let after = inputs.fingerprint(&data(r#"{"cfg": {"port": 2}}"#)).unwrap();
+        assert!(before != after);
+    }
+
+    #[test]
+    fn whole_namespace_references() {
+        let mut inputs = TemplateInputs::default();
+        inputs.add_source(String::from("{{toToml cfg}} {{cfg.port}}"));
+        let before = inputs.fingerprint(&data(r#"{"cfg": {"port": 1, "host": "a"}}"#)).unwrap();
+        let after = inputs.fingerprint(&data(r#"{"cfg": {"port": 1, "host": "b"}}"#)).unwrap();
+        assert!(before != after);
+    }
+
+    #[test]
+    fn source_changes_fingerprint() {
+        let json = data(r#"{"cfg": {"port": 1}}"#);
+        let mut inputs = TemplateInputs::default();
+        inputs.add_source(String::from("port={{cfg.port}}"));
+        let mut changed = TemplateInputs::default();
+        changed.add_source(String::from("listen={{cfg.port}}"));
+        assert!(inputs.fingerprint(&json).unwrap() != changed.fingerprint(&json).unwrap());
+    }
+}
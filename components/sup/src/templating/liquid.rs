@@ -0,0 +1,204 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::Path;
+
+use liquid::{self, Context, LiquidOptions, Renderable, Value};
+use regex::Regex;
+use serde_json;
+
+use error::{Error, Result};
+use super::Renderer;
+
+static LOGKEY: &'static str = "TL";
+
+lazy_static! {
+    /// Matches the variable referenced by an output tag, such as `cfg.port` in `{{ cfg.port }}`.
+    static ref OUTPUT_VAR: Regex =
+        Regex::new(r"\{\{-?\s*([A-Za-z_][A-Za-z0-9_]*(?:\.[A-Za-z0-9_]+)*)").unwrap();
+    /// Matches variables which a template defines for itself.
+    static ref LOCAL_VAR: Regex =
+        Regex::new(r"\{%-?\s*(?:for|assign|capture)\s+([A-Za-z_][A-Za-z0-9_]*)").unwrap();
+}
+
+/// Renders templates written in the Liquid template language.
+pub struct LiquidTemplate {
+    templates: HashMap<String, (liquid::Template, Vec<String>)>,
+    strict: bool,
+}
+
+impl LiquidTemplate {
+    pub fn new() -> Self {
+        LiquidTemplate {
+            templates: HashMap::new(),
+            strict: false,
+        }
+    }
+
+    /// Liquid renders missing variables as empty strings. In strict mode every variable a
+    /// template outputs must be present in the data it is rendered with.
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    pub fn register_template_string(&mut self, name: &str, source: &str) -> Result<()> {
+        let template = try!(liquid::parse(source, LiquidOptions::default()));
+        self.templates.insert(name.to_string(), (template, referenced_vars(source)));
+        Ok(())
+    }
+}
+
+impl Renderer for LiquidTemplate {
+    fn register_file(&mut self, name: &str, path: &Path) -> Result<()> {
+        let mut file = try!(File::open(path));
+        let mut source = String::new();
+        try!(file.read_to_string(&mut source));
+        self.register_template_string(name, &source)
+    }
+
+    fn render_json(&self, name: &str, data: &serde_json::Value) -> Result<String> {
+        let &(ref template, ref vars) = try!(self.templates
+            .get(name)
+            .ok_or_else(|| sup_error!(Error::FileNotFound(name.to_string()))));
+        if self.strict {
+            if let Some(missing) = vars.iter().find(|v| !has_path(data, v)) {
+                return Err(sup_error!(Error::MissingTemplateVariable(name.to_string(),
+                                                                     missing.clone())));
+            }
+        }
+        let mut context = Context::new();
+        if let serde_json::Value::Object(ref map) = *data {
+            for (key, value) in map.iter() {
+                context.set_val(key, json_to_liquid(value));
+            }
+        }
+        let rendered = try!(template.render(&mut context));
+        Ok(rendered.unwrap_or(String::new()))
+    }
+}
+
+/// Returns the variables output by a template, excluding those the template defines itself.
+fn referenced_vars(source: &str) -> Vec<String> {
+    let locals: HashSet<&str> = LOCAL_VAR.captures_iter(source)
+        .filter_map(|c| c.get(1))
+        .map(|m| m.as_str())
+        .collect();
+    let mut vars = Vec::new();
+    for capture in OUTPUT_VAR.captures_iter(source) {
+        if let Some(m) = capture.get(1) {
+            let var = m.as_str();
+            let root = var.split('.').next().unwrap_or(var);
+            if root == "forloop" || locals.contains(root) {
+                continue;
+            }
+            vars.push(var.to_string());
+        }
+    }
+    vars
+}
+
+/// Returns true if the dotted path exists in the data. The `size`, `first`, and `last` properties
+/// of arrays are always present.
+fn has_path(data: &serde_json::Value, path: &str) -> bool {
+    let mut current = data;
+    for segment in path.split('.') {
+        current = match *current {
+            serde_json::Value::Object(ref map) => {
+                match map.get(segment) {
+                    Some(value) => value,
+                    None => return false,
+                }
+            }
+            serde_json::Value::Array(_) => {
+                return segment == "size" || segment == "first" || segment == "last";
+            }
+            _ => return false,
+        };
+    }
+    true
+}
+
+fn json_to_liquid(value: &serde_json::Value) -> Value {
+    match *value {
+        serde_json::Value::Null => Value::Str(String::new()),
+        serde_json::Value::Bool(b) => Value::Bool(b),
+        serde_json::Value::I64(n) => Value::Num(n as f32),
+        serde_json::Value::U64(n) => Value::Num(n as f32),
+        serde_json::Value::F64(n) => Value::Num(n as f32),
+        serde_json::Value::String(ref s) => Value::Str(s.clone()),
+        serde_json::Value::Array(ref a) => Value::Array(a.iter().map(json_to_liquid).collect()),
+        serde_json::Value::Object(ref o) => {
+            Value::Object(o.iter().map(|(k, v)| (k.clone(), json_to_liquid(v))).collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json;
+
+    use templating::Renderer;
+    use super::LiquidTemplate;
+
+    #[test]
+    fn renders_nested_values() {
+        let mut template = LiquidTemplate::new();
+        template.register_template_string("t", "port={{cfg.port}} name={{pkg.name}}").unwrap();
+        let data = serde_json::from_str(r#"{"cfg": {"port": "6379"}, "pkg": {"name": "redis"}}"#)
+            .unwrap();
+        assert_eq!(template.render_json("t", &data).unwrap(),
+                   "port=6379 name=redis");
+    }
+
+    #[test]
+    fn renders_loops() {
+        let mut template = LiquidTemplate::new();
+        template.register_template_string("t", "{% for p in peers %}{{p}},{% endfor %}")
+            .unwrap();
+        let data = serde_json::from_str(r#"{"peers": ["a", "b"]}"#).unwrap();
+        assert_eq!(template.render_json("t", &data).unwrap(), "a,b,");
+    }
+
+    #[test]
+    fn strict_mode_fails_on_missing_variables() {
+        let source = "{{cfg.port}} {% for p in peers %}{{p}}{% endfor %} {{peers.size}}";
+        let data = serde_json::from_str(r#"{"cfg": {"port": "6379"}, "peers": ["a"]}"#).unwrap();
+
+        let mut template = LiquidTemplate::new();
+        template.set_strict(true);
+        template.register_template_string("t", source).unwrap();
+        assert_eq!(template.render_json("t", &data).unwrap(), "6379 a 1");
+
+        template.register_template_string("missing", "{{cfg.host}}").unwrap();
+        assert!(template.render_json("missing", &data).is_err());
+    }
+
+    #[test]
+    fn lenient_mode_renders_missing_variables_empty() {
+        let mut template = LiquidTemplate::new();
+        template.register_template_string("t", "host={{cfg.host}}").unwrap();
+        let data = serde_json::from_str(r#"{"cfg": {}}"#).unwrap();
+        assert_eq!(template.render_json("t", &data).unwrap(), "host=");
+    }
+
+    #[test]
+    fn unknown_template() {
+        let template = LiquidTemplate::new();
+        let data = serde_json::Value::Null;
+        assert!(template.render_json("nope", &data).is_err());
+    }
+}
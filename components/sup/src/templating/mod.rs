@@ -12,12 +12,114 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! Template engines used to render a service's configuration templates and hooks.
+//!
+//! Handlebars is the default engine. A package can opt into another engine with the
+//! `pkg_svc_template_engine` plan variable; every engine implements `Renderer` so the rest of the
+//! Supervisor doesn't care which one a package uses.
+//!
+//! Tools other than the Supervisor can render a package's configuration with `ConfigRenderer`;
+//! see the `render` module.
+
 pub mod helpers;
+pub mod inputs;
+pub mod liquid;
+pub mod render;
+
+pub use self::render::{template_names, ConfigRenderer};
 
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::prelude::*;
 use std::ops::{Deref, DerefMut};
+use std::path::Path;
+use std::result;
+use std::str::FromStr;
+
 use handlebars::Handlebars;
+use hcore::package::PackageInstall;
+use regex::Regex;
+use serde_json;
+
+use error::{Error, Result, SupError};
+use self::liquid::LiquidTemplate;
+
+static LOGKEY: &'static str = "TP";
+
+lazy_static! {
+    /// Matches a block helper opening or closing tag, or an expression which outputs a single
+    /// variable, such as `cfg.port` in `{{cfg.port}}`.
+    static ref HANDLEBARS_TAG: Regex =
+        Regex::new(concat!(r"\{\{\{?~?\s*(?:([#/])|",
+                           r"([A-Za-z_][A-Za-z0-9_-]*(?:\.[A-Za-z0-9_-]+)*)\s*~?\}\})"))
+            .unwrap();
+}
 
-pub struct Template(Handlebars);
+/// Renders named templates with a service's configuration data.
+pub trait Renderer {
+    /// Parse the template at `path` and register it under `name`.
+    fn register_file(&mut self, name: &str, path: &Path) -> Result<()>;
+
+    /// Render the template registered under `name` with the given data.
+    fn render_json(&self, name: &str, data: &serde_json::Value) -> Result<String>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TemplateEngine {
+    Handlebars,
+    Liquid,
+}
+
+impl TemplateEngine {
+    /// Load the template engine declared by a package, defaulting to Handlebars.
+    pub fn load(pkg_install: &PackageInstall) -> Result<Self> {
+        match try!(pkg_install.svc_template_engine()) {
+            Some(engine) => Self::from_str(&engine),
+            None => Ok(TemplateEngine::default()),
+        }
+    }
+
+    /// Returns a new, empty renderer for this engine. A strict renderer fails to render a
+    /// template which references data that doesn't exist, rather than rendering it as empty.
+    pub fn renderer(&self, strict: bool) -> Box<Renderer> {
+        match *self {
+            TemplateEngine::Handlebars => {
+                let mut template = Template::new();
+                template.set_strict(strict);
+                Box::new(template)
+            }
+            TemplateEngine::Liquid => {
+                let mut template = LiquidTemplate::new();
+                template.set_strict(strict);
+                Box::new(template)
+            }
+        }
+    }
+}
+
+impl Default for TemplateEngine {
+    fn default() -> TemplateEngine {
+        TemplateEngine::Handlebars
+    }
+}
+
+impl FromStr for TemplateEngine {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value {
+            "handlebars" => Ok(TemplateEngine::Handlebars),
+            "liquid" => Ok(TemplateEngine::Liquid),
+            _ => Err(sup_error!(Error::UnknownTemplateEngine(value.to_string()))),
+        }
+    }
+}
+
+pub struct Template {
+    handlebars: Handlebars,
+    vars: HashMap<String, Vec<String>>,
+    strict: bool,
+}
 
 impl Template {
     pub fn new() -> Self {
@@ -28,13 +130,48 @@ impl Template {
         handlebars.register_helper("strReplace", Box::new(helpers::str_replace));
         handlebars.register_helper("toJson", Box::new(helpers::to_json));
         handlebars.register_helper("toToml", Box::new(helpers::to_toml));
+        handlebars.register_helper("selectMembers", Box::new(helpers::select_members));
+        handlebars.register_helper("vault", Box::new(helpers::vault));
 
         // JW TODO: remove these at a later date, these are an alias for toJson/toToml
         handlebars.register_helper("json", Box::new(helpers::to_json));
         handlebars.register_helper("toml", Box::new(helpers::to_toml));
 
         handlebars.register_escape_fn(never_escape);
-        Template(handlebars)
+        Template {
+            handlebars: handlebars,
+            vars: HashMap::new(),
+            strict: false,
+        }
+    }
+
+    /// Handlebars renders missing variables as empty strings. In strict mode every variable a
+    /// template outputs outside of a block must be present in the data it is rendered with.
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+}
+
+impl Renderer for Template {
+    fn register_file(&mut self, name: &str, path: &Path) -> Result<()> {
+        let mut file = try!(File::open(path));
+        let mut source = String::new();
+        try!(file.read_to_string(&mut source));
+        let vars = referenced_vars(&source);
+        try!(self.register_template_string(name, source));
+        self.vars.insert(name.to_string(), vars);
+        Ok(())
+    }
+
+    fn render_json(&self, name: &str, data: &serde_json::Value) -> Result<String> {
+        if self.strict {
+            let vars = self.vars.get(name).map(|v| v.as_slice()).unwrap_or(&[]);
+            if let Some(missing) = vars.iter().find(|v| !has_path(data, v)) {
+                return Err(sup_error!(Error::MissingTemplateVariable(name.to_string(),
+                                                                     missing.clone())));
+            }
+        }
+        Ok(try!(self.render(name, data)))
     }
 }
 
@@ -42,16 +179,54 @@ impl Deref for Template {
     type Target = Handlebars;
 
     fn deref(&self) -> &Handlebars {
-        &self.0
+        &self.handlebars
     }
 }
 
 impl DerefMut for Template {
     fn deref_mut(&mut self) -> &mut Handlebars {
-        &mut self.0
+        &mut self.handlebars
     }
 }
 
+/// Returns the variables a template outputs outside of any block. Inside a block the context may
+/// change, or the block may never render, so those variables aren't checked.
+fn referenced_vars(source: &str) -> Vec<String> {
+    let mut depth = 0;
+    let mut vars = Vec::new();
+    for capture in HANDLEBARS_TAG.captures_iter(source) {
+        match capture.get(1).map(|m| m.as_str()) {
+            Some("#") => depth += 1,
+            Some(_) => depth -= 1,
+            None => {
+                if let Some(var) = capture.get(2) {
+                    if depth == 0 && var.as_str() != "this" {
+                        vars.push(var.as_str().to_string());
+                    }
+                }
+            }
+        }
+    }
+    vars
+}
+
+/// Returns true if the dotted path exists in the data.
+fn has_path(data: &serde_json::Value, path: &str) -> bool {
+    let mut current = data;
+    for segment in path.split('.') {
+        current = match *current {
+            serde_json::Value::Object(ref map) => {
+                match map.get(segment) {
+                    Some(value) => value,
+                    None => return false,
+                }
+            }
+            _ => return false,
+        };
+    }
+    true
+}
+
 /// Disables HTML escaping which is enabled by default in Handlebars.
 fn never_escape(data: &str) -> String {
     String::from(data)
@@ -60,8 +235,34 @@ fn never_escape(data: &str) -> String {
 #[cfg(test)]
 mod test {
     use std::collections::BTreeMap;
+    use std::str::FromStr;
+
+    use serde_json;
+
     use super::*;
 
+    #[test]
+    fn template_engine_from_str() {
+        assert_eq!(TemplateEngine::from_str("handlebars").unwrap(),
+                   TemplateEngine::Handlebars);
+        assert_eq!(TemplateEngine::from_str("liquid").unwrap(), TemplateEngine::Liquid);
+        assert!(TemplateEngine::from_str("mustache").is_err());
+    }
+
+    #[test]
+    fn strict_mode_checks_variables_outside_blocks() {
+        let source = "{{cfg.port}} {{~ pkg.name ~}} {{#each bind.db.members}}{{ip}}{{/each}} \
+                      {{#if cfg.tls}}{{cfg.tls.cert}}{{/if}} {{toToml cfg}} {{{cfg.host}}}";
+        assert_eq!(referenced_vars(source), vec!["cfg.port", "pkg.name", "cfg.host"]);
+
+        let data = serde_json::from_str(r#"{"cfg": {"port": 6379}, "pkg": {"name": "redis"}}"#)
+            .unwrap();
+        assert!(has_path(&data, "cfg.port"));
+        assert!(has_path(&data, "pkg.name"));
+        assert!(!has_path(&data, "cfg.host"));
+        assert!(!has_path(&data, "cfg.port.number"));
+    }
+
     #[test]
     fn test_handlebars_json_helper() {
         let content = "{{toJson x}}".to_string();
@@ -140,4 +341,21 @@ mod test {
         let rendered = template.render("t", &m).unwrap();
         assert_eq!(rendered, "this is new".to_string());
     }
+
+    #[test]
+    fn select_members_helper() {
+        let content = "{{#selectMembers bind.db.members strategy=\"leader\"}}{{ip}}\
+                       {{/selectMembers}}"
+            .to_string();
+        let mut template = Template::new();
+        template.register_template_string("t", content).unwrap();
+
+        let data: serde_json::Value = serde_json::from_str(r#"{"bind": {"db": {"members": [
+            {"member_id": "a", "ip": "10.0.0.1", "alive": true, "leader": false},
+            {"member_id": "b", "ip": "10.0.0.2", "alive": true, "leader": true}
+        ]}}}"#)
+            .unwrap();
+        let rendered = template.render("t", &data).unwrap();
+        assert_eq!(rendered, "10.0.0.2".to_string());
+    }
 }
@@ -0,0 +1,167 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rendering a package's configuration templates, without a Supervisor.
+//!
+//! The Supervisor renders a service's configuration with `ConfigRenderer`, and so can anything
+//! else which wants to know what a package's templates produce, such as a CI job checking that
+//! they render, or the plan linter. The data templates are rendered with is the TOML the
+//! Supervisor writes to a service's `config.toml`, so a tool can render with one taken from a
+//! running service, or with one of its own:
+//!
+//! ```no_run
+//! use std::path::Path;
+//!
+//! use habitat_sup::templating::{ConfigRenderer, TemplateEngine};
+//!
+//! let mut renderer = ConfigRenderer::new(TemplateEngine::Handlebars, true);
+//! renderer.load_dir(Path::new("redis/config")).unwrap();
+//! let data = "[cfg]\nport = 6379\n".parse().unwrap();
+//! for (name, content) in renderer.render(&data).unwrap() {
+//!     println!("{}:\n{}", name, content);
+//! }
+//! ```
+
+use std::fs;
+use std::path::Path;
+
+use toml;
+
+use error::Result;
+use super::{Renderer, TemplateEngine};
+use util::convert;
+
+/// Renders a set of configuration templates with the same data.
+pub struct ConfigRenderer {
+    renderer: Box<Renderer>,
+    names: Vec<String>,
+}
+
+impl ConfigRenderer {
+    /// Returns a renderer with no templates, for the given engine. A strict renderer fails to
+    /// render a template which references data that doesn't exist, rather than rendering it as
+    /// empty.
+    pub fn new(engine: TemplateEngine, strict: bool) -> Self {
+        ConfigRenderer {
+            renderer: engine.renderer(strict),
+            names: Vec::new(),
+        }
+    }
+
+    /// Registers every template in `dir`, such as a package's `config` directory, under its file
+    /// name. Registering a directory which doesn't exist registers nothing.
+    ///
+    /// # Failures
+    ///
+    /// * The directory can't be read
+    /// * A template can't be parsed
+    pub fn load_dir(&mut self, dir: &Path) -> Result<()> {
+        for name in try!(template_names(dir)) {
+            let path = dir.join(&name);
+            debug!("Config template {} from {:?}", name, path);
+            try!(self.register_file(&name, &path));
+        }
+        Ok(())
+    }
+
+    /// Registers the template at `path` under `name`. Every registered template can be used by
+    /// the others as a partial.
+    ///
+    /// # Failures
+    ///
+    /// * The template can't be read or parsed
+    pub fn register_file(&mut self, name: &str, path: &Path) -> Result<()> {
+        try!(self.renderer.register_file(name, path));
+        self.names.push(name.to_string());
+        Ok(())
+    }
+
+    /// Returns the names of the registered templates, in the order they were registered.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Renders every registered template with `data`, returning each template's name and
+    /// content. Either every template renders, or an error is returned.
+    ///
+    /// # Failures
+    ///
+    /// * A template fails to render; a strict renderer also fails on missing data
+    pub fn render(&self, data: &toml::Value) -> Result<Vec<(String, String)>> {
+        let data = convert::toml_to_json(data.clone());
+        let mut rendered = Vec::with_capacity(self.names.len());
+        for name in self.names.iter() {
+            debug!("Rendering template {}", name);
+            let content = try!(self.renderer.render_json(name, &data));
+            rendered.push((name.clone(), content));
+        }
+        Ok(rendered)
+    }
+}
+
+/// Returns the file names of the templates in `dir`, sorted, or none if it doesn't exist.
+///
+/// # Failures
+///
+/// * The directory exists, but can't be read
+pub fn template_names(dir: &Path) -> Result<Vec<String>> {
+    let mut names = Vec::new();
+    debug!("Loading configuration from {:?}", dir);
+    match fs::read_dir(dir) {
+        Ok(entries) => {
+            for entry in entries {
+                let entry = try!(entry);
+                names.push(entry.file_name().to_string_lossy().into_owned());
+            }
+        }
+        Err(e) => debug!("No config directory in package: {}", e),
+    }
+    names.sort();
+    Ok(names)
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::io::Write;
+
+    use tempdir::TempDir;
+
+    use templating::TemplateEngine;
+    use super::ConfigRenderer;
+
+    #[test]
+    fn render_dir() {
+        let dir = TempDir::new("config-renderer").unwrap();
+        File::create(dir.path().join("redis.conf"))
+            .unwrap()
+            .write_all(b"port {{cfg.port}}")
+            .unwrap();
+        File::create(dir.path().join("tcp.conf"))
+            .unwrap()
+            .write_all(b"tcp-backlog {{cfg.tcp_backlog}}")
+            .unwrap();
+        let mut renderer = ConfigRenderer::new(TemplateEngine::Handlebars, true);
+        renderer.load_dir(dir.path()).unwrap();
+        assert_eq!(renderer.names(), &["redis.conf", "tcp.conf"]);
+
+        let data = "[cfg]\nport = 6379\ntcp_backlog = 511\n".parse().unwrap();
+        assert_eq!(renderer.render(&data).unwrap(),
+                   vec![(String::from("redis.conf"), String::from("port 6379")),
+                        (String::from("tcp.conf"), String::from("tcp-backlog 511"))]);
+
+        let missing = "[cfg]\nport = 6379\n".parse().unwrap();
+        assert!(renderer.render(&missing).is_err());
+    }
+}
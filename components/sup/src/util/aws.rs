@@ -0,0 +1,241 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Credentials for, and Signature Version 4 signing of, requests to AWS APIs.
+
+use std::io::Read;
+
+use hcore::env as henv;
+use http_client::{self, ApiClient};
+use hyper::header::Headers;
+use hyper::status::StatusCode;
+use openssl::hash::{hash, MessageDigest};
+use openssl::pkey::PKey;
+use openssl::sign::Signer as HmacSigner;
+use serde_json;
+use time;
+use url::Url;
+
+use {PRODUCT, VERSION};
+use error::{Error, Result, SupError};
+
+pub const METADATA_URL: &'static str = "http://169.254.169.254/latest/meta-data";
+const ECS_CREDENTIALS_URL: &'static str = "http://169.254.170.2";
+
+#[derive(Debug, Deserialize)]
+pub struct Credentials {
+    #[serde(rename = "AccessKeyId")]
+    pub access_key_id: String,
+    #[serde(rename = "SecretAccessKey")]
+    pub secret_access_key: String,
+    #[serde(rename = "Token", default)]
+    pub session_token: Option<String>,
+}
+
+impl Credentials {
+    /// Returns the given keys, or else `AWS_ACCESS_KEY_ID` and `AWS_SECRET_ACCESS_KEY`, or else
+    /// the credentials of the ECS task's role, or else of the EC2 instance's IAM role. A role's
+    /// credentials expire, so they should be loaded again for each batch of requests.
+    ///
+    /// # Failures
+    ///
+    /// * No keys are given or set, and there's no role to take credentials from
+    pub fn load(access_key_id: Option<&str>, secret_access_key: Option<&str>) -> Result<Self> {
+        let access_key_id = access_key_id.map(|s| s.to_string())
+            .or_else(|| henv::var("AWS_ACCESS_KEY_ID").ok());
+        let secret_access_key = secret_access_key.map(|s| s.to_string())
+            .or_else(|| henv::var("AWS_SECRET_ACCESS_KEY").ok());
+        if let (Some(id), Some(secret)) = (access_key_id, secret_access_key) {
+            return Ok(Credentials {
+                access_key_id: id,
+                secret_access_key: secret,
+                session_token: henv::var("AWS_SESSION_TOKEN").ok(),
+            });
+        }
+        if let Ok(uri) = henv::var("AWS_CONTAINER_CREDENTIALS_RELATIVE_URI") {
+            let body = try!(get(&format!("{}{}", ECS_CREDENTIALS_URL, uri), vec![]));
+            return from_json(&body);
+        }
+        let roles_url = format!("{}/iam/security-credentials/", METADATA_URL);
+        let roles = try!(get(&roles_url, vec![]));
+        let role = match roles.lines().next() {
+            Some(role) => role.trim().to_string(),
+            None => return Err(failed("no credentials and no instance IAM role")),
+        };
+        let body = try!(get(&format!("{}{}", roles_url, role), vec![]));
+        from_json(&body)
+    }
+}
+
+/// Returns `AWS_REGION` if it's set, or else the region of the EC2 instance we're running on.
+pub fn region() -> Result<String> {
+    if let Ok(region) = henv::var("AWS_REGION") {
+        return Ok(region);
+    }
+    let mut zone = try!(get(&format!("{}/placement/availability-zone", METADATA_URL), vec![]));
+    zone.pop();
+    Ok(zone)
+}
+
+/// Returns the current time as the `x-amz-date` header wants it.
+pub fn amz_date() -> Result<String> {
+    time::now_utc()
+        .strftime("%Y%m%dT%H%M%SZ")
+        .map(|date| date.to_string())
+        .map_err(|e| failed(&e.to_string()))
+}
+
+/// Signs requests to one service in one region.
+pub struct Signer<'a> {
+    pub credentials: &'a Credentials,
+    pub region: &'a str,
+    pub service: &'a str,
+}
+
+impl<'a> Signer<'a> {
+    /// Returns the `host`, `x-amz-date` and, for temporary credentials, `x-amz-security-token`
+    /// headers every signed request has, to which a request can add its own.
+    pub fn headers(&self, host: &str) -> Result<Vec<(&'static str, String)>> {
+        let mut headers = vec![("host", host.to_string()), ("x-amz-date", try!(amz_date()))];
+        if let Some(ref token) = self.credentials.session_token {
+            headers.push(("x-amz-security-token", token.clone()));
+        }
+        Ok(headers)
+    }
+
+    /// Computes the `Authorization` header of a request. The headers, which must include
+    /// `x-amz-date`, must be lowercase, and `query` must already be sorted and encoded.
+    pub fn authorization(&self,
+                         method: &str,
+                         path: &str,
+                         query: &str,
+                         headers: &[(&str, String)],
+                         payload: &[u8])
+                         -> Result<String> {
+        let mut headers = headers.to_vec();
+        headers.sort();
+        let amz_date = try!(headers.iter()
+            .find(|&&(name, _)| name == "x-amz-date")
+            .map(|&(_, ref value)| value.clone())
+            .ok_or_else(|| failed("the request has no x-amz-date header")));
+        let date = &amz_date[..8];
+        let scope = format!("{}/{}/{}/aws4_request", date, self.region, self.service);
+        let signed_headers = headers.iter().map(|&(name, _)| name).collect::<Vec<_>>().join(";");
+        let canonical_headers = headers.iter()
+            .map(|&(name, ref value)| format!("{}:{}\n", name, value.trim()))
+            .collect::<String>();
+        let canonical_request = format!("{}\n{}\n{}\n{}\n{}\n{}",
+                                        method,
+                                        path,
+                                        query,
+                                        canonical_headers,
+                                        signed_headers,
+                                        try!(sha256_hex(payload)));
+        let string_to_sign = format!("AWS4-HMAC-SHA256\n{}\n{}\n{}",
+                                     amz_date,
+                                     scope,
+                                     try!(sha256_hex(canonical_request.as_bytes())));
+        let mut key = try!(hmac(format!("AWS4{}", self.credentials.secret_access_key).as_bytes(),
+                                date.as_bytes()));
+        for part in &[self.region, self.service, "aws4_request"] {
+            key = try!(hmac(&key, part.as_bytes()));
+        }
+        let signature = to_hex(&try!(hmac(&key, string_to_sign.as_bytes())));
+        Ok(format!("AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+                   self.credentials.access_key_id,
+                   scope,
+                   signed_headers,
+                   signature))
+    }
+}
+
+/// Makes a GET request for `url` with the given extra headers, returning the response body.
+pub fn get(url: &str, headers: Vec<(&'static str, String)>) -> Result<String> {
+    let url = try!(Url::parse(url).map_err(|e| failed(&format!("{}: {}", url, e))));
+    let client = try!(ApiClient::new(&url, PRODUCT, VERSION, None));
+    let mut raw = Headers::new();
+    for (name, value) in headers {
+        raw.set_raw(name, vec![value.into_bytes()]);
+    }
+    let mut res = match client.get("").headers(raw).send() {
+        Ok(res) => res,
+        Err(e) => return Err(sup_error!(Error::HttpClient(http_client::Error::HyperError(e)))),
+    };
+    let mut body = String::new();
+    try!(res.read_to_string(&mut body));
+    if res.status != StatusCode::Ok {
+        debug!("{} returned {}: {}", url, res.status, body);
+        return Err(failed(&format!("{} returned {}", url.host_str().unwrap_or(""), res.status)));
+    }
+    Ok(body)
+}
+
+fn from_json<T: ::serde::Deserialize>(body: &str) -> Result<T> {
+    serde_json::from_str(body).map_err(|e| failed(&format!("unexpected response: {}", e)))
+}
+
+pub fn sha256_hex(data: &[u8]) -> Result<String> {
+    match hash(MessageDigest::sha256(), data) {
+        Ok(digest) => Ok(to_hex(&digest)),
+        Err(e) => Err(failed(&e.to_string())),
+    }
+}
+
+fn hmac(key: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    let pkey = try!(PKey::hmac(key).map_err(|e| failed(&e.to_string())));
+    let mut signer = try!(HmacSigner::new(MessageDigest::sha256(), &pkey)
+        .map_err(|e| failed(&e.to_string())));
+    try!(signer.update(data).map_err(|e| failed(&e.to_string())));
+    signer.finish().map_err(|e| failed(&e.to_string()))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn failed(message: &str) -> SupError {
+    sup_error!(Error::AwsFailed(message.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{sha256_hex, Credentials, Signer};
+
+    #[test]
+    fn sha256_of_empty_payload() {
+        assert_eq!(sha256_hex(b"").unwrap(),
+                   "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+    }
+
+    // The "get-vanilla" example of the AWS Signature Version 4 test suite
+    #[test]
+    fn authorization_of_vanilla_get() {
+        let credentials = Credentials {
+            access_key_id: String::from("AKIDEXAMPLE"),
+            secret_access_key: String::from("wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY"),
+            session_token: None,
+        };
+        let signer = Signer {
+            credentials: &credentials,
+            region: "us-east-1",
+            service: "service",
+        };
+        let headers = vec![("x-amz-date", String::from("20150830T123600Z")),
+                           ("host", String::from("example.amazonaws.com"))];
+        assert_eq!(signer.authorization("GET", "/", "", &headers, b"").unwrap(),
+                   "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/\
+                    aws4_request, SignedHeaders=host;x-amz-date, \
+                    Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31");
+    }
+}
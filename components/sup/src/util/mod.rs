@@ -12,20 +12,31 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod aws;
 pub mod convert;
 pub mod path;
+pub mod privileges;
 pub mod sys;
 pub mod users;
 
-use std::ffi::OsStr;
+use std::fs::{File, OpenOptions};
+use std::io;
 use std::net::{Ipv4Addr, SocketAddrV4};
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::Path;
 use std::str::FromStr;
 use std::process::{Command, Stdio};
 
 use hcore::os;
+#[cfg(unix)]
+use libc;
+use hcore::os::process::Confinement;
 use time;
 
 use error::{Error, Result};
+use manager::service::ProcessLimits;
+use package::HookInterpreter;
 
 static LOGKEY: &'static str = "UT";
 
@@ -66,31 +77,80 @@ pub fn parse_ip_port_with_defaults(s: Option<&str>,
     return Err(sup_error!(Error::IPFailed));
 }
 
-#[cfg(any(target_os="linux", target_os="macos"))]
-pub fn create_command<S: AsRef<OsStr>>(path: S, user: &str, group: &str) -> Command {
-    let mut cmd = Command::new(path);
-    use std::os::unix::process::CommandExt;
+/// Returns a command which runs the hook or run script at `path` with the given interpreter, as
+/// the given user and group, with its output piped back to us.
+pub fn create_command<P: AsRef<Path>>(path: P,
+                                      interpreter: HookInterpreter,
+                                      user: &str,
+                                      group: &str)
+                                      -> Result<Command> {
+    create_service_command(path,
+                           interpreter,
+                           user,
+                           group,
+                           &ProcessLimits::default(),
+                           &Confinement::default())
+}
+
+/// Returns a command like `create_command` which also starts its process with the given umask and
+/// resource limits, and confined as `confinement` asks. Limits are set before the process drops
+/// to the service's user, as only root may raise a hard limit.
+#[cfg(unix)]
+pub fn create_service_command<P: AsRef<Path>>(path: P,
+                                              interpreter: HookInterpreter,
+                                              user: &str,
+                                              group: &str,
+                                              limits: &ProcessLimits,
+                                              confinement: &Confinement)
+                                              -> Result<Command> {
+    let mut cmd = try!(interpreter.command(path.as_ref()));
     let uid = os::users::get_uid_by_name(user).expect("Can't determine uid");
     let gid = os::users::get_gid_by_name(group).expect("Can't determine gid");
 
     cmd.stdin(Stdio::null())
         .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .uid(uid)
-        .gid(gid);
-    cmd
+        .stderr(Stdio::piped());
+    try!(limits.apply(&mut cmd));
+    try!(os::process::run_as(&mut cmd, user, uid, gid, confinement));
+    Ok(cmd)
 }
 
 #[cfg(target_os = "windows")]
-pub fn create_command<S: AsRef<OsStr>>(path: S, user: &str, group: &str) -> Command {
-    let mut cmd = Command::new("powershell.exe");
-    let ps_command = format!("iex $(gc {} | out-string)", path.as_ref().to_string_lossy());
-    cmd.arg("-command")
-        .arg(ps_command)
-        .stdin(Stdio::null())
+pub fn create_service_command<P: AsRef<Path>>(path: P,
+                                              interpreter: HookInterpreter,
+                                              user: &str,
+                                              group: &str,
+                                              limits: &ProcessLimits,
+                                              confinement: &Confinement)
+                                              -> Result<Command> {
+    let mut cmd = try!(interpreter.command(path.as_ref()));
+    cmd.stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
-    cmd
+    try!(limits.apply(&mut cmd));
+    if !confinement.is_empty() {
+        outputln!("Starting the service unconfined, as its capabilities and seccomp filter are \
+                   not supported on Windows");
+    }
+    Ok(cmd)
+}
+
+/// Creates a file for writing which only has `mode`'s permissions from the start, so what is
+/// written to it is never readable by others. The file must not exist, and a symlink in its place
+/// is not followed.
+#[cfg(unix)]
+pub fn create_private(path: &Path, mode: u32) -> io::Result<File> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(mode)
+        .custom_flags(libc::O_NOFOLLOW)
+        .open(path)
+}
+
+#[cfg(target_os = "windows")]
+pub fn create_private(path: &Path, _mode: u32) -> io::Result<File> {
+    OpenOptions::new().write(true).create_new(true).open(path)
 }
 
 #[cfg(test)]
@@ -28,9 +28,9 @@ static LOGKEY: &'static str = "PT";
 
 /// The package identifier for the OS specific interpreter which the Supervisor is built with,
 /// or which may be independently installed
-#[cfg(any(target_os="linux", target_os="macos"))]
+#[cfg(unix)]
 const INTERPRETER_IDENT: &'static str = "core/busybox-static";
-#[cfg(any(target_os="linux", target_os="macos"))]
+#[cfg(unix)]
 const INTERPRETER_COMMAND: &'static str = "busybox";
 
 #[cfg(target_os = "windows")]
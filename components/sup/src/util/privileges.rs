@@ -0,0 +1,158 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! What an unprivileged Supervisor is able to do.
+//!
+//! Started with `--unprivileged`, the Supervisor runs entirely as the user who started it: its
+//! services run as that user too, the `hab` directory lives under a root that user can write to,
+//! and it only listens on ports that user may bind. The features which need root are disabled,
+//! and listed when the Supervisor starts.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use hcore::fs::am_i_root;
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "PV";
+
+/// Ports below this one may only be bound by root, or a process with `CAP_NET_BIND_SERVICE`.
+pub const FIRST_UNPRIVILEGED_PORT: u16 = 1024;
+
+/// Where the `hab` directory of an unprivileged Supervisor lives when no root is given, relative
+/// to the user's home directory.
+pub const UNPRIVILEGED_FS_ROOT_PATH: &'static str = ".hab/root";
+
+/// The features an unprivileged Supervisor can't offer.
+const DISABLED_FEATURES: &'static [&'static str] =
+    &["running services as their pkg_svc_user and pkg_svc_group",
+      "restricting services to their pkg_svc_capabilities",
+      "making the filesystem read-only to services",
+      "backing pkg_svc_dirs with a tmpfs",
+      "isolating a service's network with --isolate-network",
+      "raising hard resource limits above the current ones"];
+
+/// Returns the filesystem root an unprivileged Supervisor uses when none is given.
+///
+/// # Failures
+///
+/// * The user has no home directory
+pub fn default_fs_root() -> Result<PathBuf> {
+    match env::home_dir() {
+        Some(home) => Ok(home.join(UNPRIVILEGED_FS_ROOT_PATH)),
+        None => {
+            Err(sup_error!(Error::Permissions(String::from("Can't determine the home directory \
+                                                            for an unprivileged filesystem \
+                                                            root; set one with --fs-root"))))
+        }
+    }
+}
+
+/// Creates the `hab` directory under `fs_root` if it isn't there yet, and checks that the
+/// Supervisor can write to it.
+///
+/// # Failures
+///
+/// * The directory can't be created, or can't be written to
+pub fn check_fs_root(fs_root: &Path) -> Result<()> {
+    let hab = fs_root.join("hab");
+    let probe = hab.join(".unprivileged");
+    match fs::create_dir_all(&hab).and_then(|_| fs::File::create(&probe)) {
+        Ok(_) => {
+            let _ = fs::remove_file(&probe);
+            Ok(())
+        }
+        Err(e) => {
+            let msg = format!("The filesystem root {} isn't writable by this user: {}",
+                              fs_root.display(),
+                              e);
+            Err(sup_error!(Error::Permissions(msg)))
+        }
+    }
+}
+
+/// Returns whether this process may bind ports below `FIRST_UNPRIVILEGED_PORT`: it is root, or
+/// has the `CAP_NET_BIND_SERVICE` capability.
+pub fn can_bind_privileged_ports() -> bool {
+    am_i_root() || has_capability(CAP_NET_BIND_SERVICE)
+}
+
+/// Check that an unprivileged Supervisor may listen on `port`.
+///
+/// # Failures
+///
+/// * The port is privileged and this process can't bind it
+pub fn check_port(port: u16, flag: &str) -> Result<()> {
+    if port == 0 || port >= FIRST_UNPRIVILEGED_PORT || can_bind_privileged_ports() {
+        return Ok(());
+    }
+    let msg = format!("{} port {} needs root or CAP_NET_BIND_SERVICE; use a port from {} up \
+                       when running --unprivileged",
+                      flag,
+                      port,
+                      FIRST_UNPRIVILEGED_PORT);
+    Err(sup_error!(Error::Permissions(msg)))
+}
+
+/// The features an unprivileged Supervisor can't offer, given what this process is able to do.
+pub fn disabled_features() -> Vec<&'static str> {
+    let mut features = DISABLED_FEATURES.to_vec();
+    if !can_bind_privileged_ports() {
+        features.push("listening on ports below 1024");
+    }
+    features
+}
+
+const CAP_NET_BIND_SERVICE: u32 = 10;
+
+/// Returns whether the given capability is in this process's effective set, as read from the
+/// `CapEff` line of `/proc/self/status`.
+#[cfg(target_os = "linux")]
+fn has_capability(cap: u32) -> bool {
+    use std::io::Read;
+
+    let mut status = String::new();
+    let read = fs::File::open("/proc/self/status").and_then(|mut f| f.read_to_string(&mut status));
+    if read.is_err() {
+        return false;
+    }
+    match status.lines().find(|l| l.starts_with("CapEff:")) {
+        Some(line) => {
+            match u64::from_str_radix(line["CapEff:".len()..].trim(), 16) {
+                Ok(caps) => caps & (1 << cap) != 0,
+                Err(_) => false,
+            }
+        }
+        None => false,
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn has_capability(_cap: u32) -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_port, FIRST_UNPRIVILEGED_PORT};
+
+    #[test]
+    fn unprivileged_ports_are_always_allowed() {
+        assert!(check_port(FIRST_UNPRIVILEGED_PORT, "--listen-http").is_ok());
+        assert!(check_port(9631, "--listen-http").is_ok());
+        assert!(check_port(0, "--listen-gossip").is_ok());
+    }
+}
@@ -29,12 +29,61 @@ pub fn ip() -> Result<IpAddr> {
     }
 }
 
+/// Return the first IPv4 address assigned to the named network interface, falling back to its
+/// first IPv6 address if it has no IPv4 address.
+#[cfg(unix)]
+pub fn interface_ip(name: &str) -> Result<IpAddr> {
+    use std::ffi::CStr;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+    use std::ptr;
+
+    let mut ifap: *mut libc::ifaddrs = ptr::null_mut();
+    if unsafe { libc::getifaddrs(&mut ifap) } != 0 {
+        return Err(sup_error!(Error::InterfaceNotFound(name.to_string())));
+    }
+    let mut found: Option<IpAddr> = None;
+    let mut cursor = ifap;
+    while !cursor.is_null() {
+        let ifa = unsafe { &*cursor };
+        cursor = ifa.ifa_next;
+        if ifa.ifa_addr.is_null() {
+            continue;
+        }
+        let ifa_name = unsafe { CStr::from_ptr(ifa.ifa_name) };
+        if ifa_name.to_str() != Ok(name) {
+            continue;
+        }
+        match unsafe { (*ifa.ifa_addr).sa_family } as libc::c_int {
+            libc::AF_INET => {
+                let sin = unsafe { &*(ifa.ifa_addr as *const libc::sockaddr_in) };
+                found = Some(IpAddr::V4(Ipv4Addr::from(u32::from_be(sin.sin_addr.s_addr))));
+                break;
+            }
+            libc::AF_INET6 if found.is_none() => {
+                let sin6 = unsafe { &*(ifa.ifa_addr as *const libc::sockaddr_in6) };
+                found = Some(IpAddr::V6(Ipv6Addr::from(sin6.sin6_addr.s6_addr)));
+            }
+            _ => {}
+        }
+    }
+    unsafe { libc::freeifaddrs(ifap) };
+    match found {
+        Some(ip) => Ok(ip),
+        None => Err(sup_error!(Error::InterfaceNotFound(name.to_string()))),
+    }
+}
+
+#[cfg(target_os = "windows")]
+pub fn interface_ip(name: &str) -> Result<IpAddr> {
+    Err(sup_error!(Error::InterfaceNotFound(name.to_string())))
+}
+
 
 extern "C" {
     pub fn gethostname(name: *mut libc::c_char, size: libc::size_t) -> libc::c_int;
 }
 
-#[cfg(any(target_os="linux", target_os="macos"))]
+#[cfg(unix)]
 pub fn hostname() -> Result<String> {
     use std::ffi::CStr;
 
@@ -44,7 +93,7 @@ pub fn hostname() -> Result<String> {
     let err = unsafe { gethostname(ptr as *mut libc::c_char, len as libc::size_t) };
     match err {
         0 => {
-            let slice = unsafe { CStr::from_ptr(ptr as *const i8) };
+            let slice = unsafe { CStr::from_ptr(ptr as *const libc::c_char) };
             let s = try!(slice.to_str());
             Ok(s.to_string())
         }
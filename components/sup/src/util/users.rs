@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use config::gconfig;
 use error::{Result, Error};
 use hcore::os::users;
 use hcore::package::PackageInstall;
@@ -19,6 +20,7 @@ use hcore::package::PackageInstall;
 static LOGKEY: &'static str = "UR";
 
 const DEFAULT_USER: &'static str = "hab";
+#[cfg(unix)]
 const DEFAULT_GROUP: &'static str = "hab";
 
 /// This function checks to see if a custom SVC_USER and SVC_GROUP has
@@ -81,6 +83,7 @@ fn check_pkg_user_and_group(pkg_install: &PackageInstall) -> Result<Option<(Stri
 
 /// checks to see if hab/hab exists, if not, fall back to
 /// current user/group. If that fails, then return an error.
+#[cfg(unix)]
 fn get_default_user_and_group() -> Result<(String, String)> {
     let uid = users::get_uid_by_name(DEFAULT_USER);
     let gid = users::get_gid_by_name(DEFAULT_GROUP);
@@ -104,12 +107,42 @@ fn get_default_user_and_group() -> Result<(String, String)> {
     }
 }
 
+#[cfg(unix)]
+fn get_unprivileged_user_and_group(pkg_install: &PackageInstall) -> Result<(String, String)> {
+    let (user, group) = match (users::get_current_username(), users::get_current_groupname()) {
+        (Some(user), Some(group)) => (user, group),
+        _ => {
+            return Err(sup_error!(Error::Permissions("Can't determine current user:group"
+                .to_string())))
+        }
+    };
+    if let (Some(svc_user), Some(svc_group)) = (try!(pkg_install.svc_user()),
+                                                try!(pkg_install.svc_group())) {
+        if svc_user != user || svc_group != group {
+            outputln!("Running {} as {}:{} rather than its {}:{}, as the Supervisor is \
+                       unprivileged",
+                      pkg_install.ident(),
+                      user,
+                      group,
+                      svc_user,
+                      svc_group);
+        }
+    }
+    Ok((user, group))
+}
+
 /// check and see if a user/group is specified in package metadata.
 /// if not, we'll try and use hab/hab.
 /// If hab/hab doesn't exist, try to use (current username, current group).
 /// If that doesn't work, then give up.
+///
+/// An unprivileged Supervisor can't switch users, so its services always run as the user and
+/// group it runs as.
 #[cfg(unix)]
 pub fn get_user_and_group(pkg_install: &PackageInstall) -> Result<(String, String)> {
+    if gconfig().unprivileged() {
+        return get_unprivileged_user_and_group(pkg_install);
+    }
     if let Some((user, group)) = try!(check_pkg_user_and_group(&pkg_install)) {
         Ok((user, group))
     } else {
@@ -118,11 +151,29 @@ pub fn get_user_and_group(pkg_install: &PackageInstall) -> Result<(String, Strin
     }
 }
 
-/// For now we are ignoring any configured user and group
-/// because we do not start the supervisor on windows under
-/// alternate credentials
+/// The supervisor doesn't start services under alternate credentials on Windows, since that
+/// would take the account's password, so services run as the supervisor's account, which owns
+/// their directories. A package which asks for another pkg_svc_user than the default is refused
+/// rather than run as an account it didn't ask for; groups don't own files on Windows and are
+/// ignored.
 #[cfg(windows)]
 pub fn get_user_and_group(pkg_install: &PackageInstall) -> Result<(String, String)> {
-    let defaults = try!(get_default_user_and_group());
-    Ok(defaults)
+    let current = match users::get_current_username() {
+        Some(user) => user,
+        None => {
+            return Err(sup_error!(Error::Permissions("Can't determine current user".to_string())))
+        }
+    };
+    if let Some(user) = try!(pkg_install.svc_user()) {
+        if user != DEFAULT_USER && user.to_lowercase() != current {
+            return Err(sup_error!(Error::Permissions(format!("{} asks to run as {}, but \
+                                                              services run as the \
+                                                              supervisor's account, {}, on \
+                                                              Windows",
+                                                             pkg_install.ident(),
+                                                             user,
+                                                             current))));
+        }
+    }
+    Ok((current, String::new()))
 }
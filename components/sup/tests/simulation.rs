@@ -0,0 +1,101 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Census tests against an in-process ring. Run with `cargo test --features simulation`.
+
+extern crate habitat_butterfly;
+#[macro_use]
+extern crate habitat_butterfly_test;
+extern crate habitat_core;
+extern crate habitat_sup as sup;
+extern crate toml;
+
+use std::str::FromStr;
+
+use habitat_butterfly::member::Health;
+use habitat_core::package::PackageIdent;
+use sup::simulation::SimRing;
+
+const REDIS: &'static str = "core/redis/3.2.4/20170514150022";
+const REDIS_NEXT: &'static str = "core/redis/3.2.9/20170620233402";
+
+#[test]
+fn three_members_agree_on_the_leader_in_their_census() {
+    let mut ring = SimRing::new(3);
+    ring.add_service_to_all(REDIS, "default");
+    ring.start_election(0, "redis.default", 0);
+    assert!(ring.wait_for_all_censuses("redis.default", |c| c.get_leader().is_some()));
+    let leaders: Vec<String> = (0..3)
+        .map(|m| {
+            ring.census_list(m)
+                .get("redis.default")
+                .and_then(|c| c.get_leader())
+                .map(|l| l.member_id.clone())
+                .unwrap()
+        })
+        .collect();
+    assert!(leaders.iter().all(|l| *l == leaders[0]));
+}
+
+#[test]
+fn an_update_reaches_every_census() {
+    let mut ring = SimRing::new(3);
+    ring.add_service_to_all(REDIS, "default");
+    let updated = ring[1].member_id().to_string();
+    let next = PackageIdent::from_str(REDIS_NEXT).unwrap();
+    ring.update_package(1, "redis.default", REDIS_NEXT);
+    assert!(ring.wait_for_all_censuses("redis.default", |c| {
+        c.get(&updated).map_or(false, |ce| *ce.get_pkg() == next)
+    }));
+}
+
+#[test]
+fn exported_cfg_reaches_binds_over_a_slow_lossy_network() {
+    let mut ring = SimRing::new(3);
+    ring.add_service_to_all(REDIS, "default");
+    ring.degrade_between(0..3, 0..3, 50, 0.2);
+    let exporter = ring[0].member_id().to_string();
+    ring.export_cfg(0, "redis.default", "port = 6380");
+    assert!(ring.wait_for_all_censuses("redis.default", |c| {
+        c.get(&exporter)
+            .and_then(|ce| ce.cfg.get("port"))
+            .map_or(false, |port| *port == toml::Value::Integer(6380))
+    }));
+    ring.restore();
+}
+
+#[test]
+fn the_minority_of_a_partition_loses_quorum() {
+    let mut ring = SimRing::new(5);
+    ring.add_service_to_all(REDIS, "default");
+    ring.start_election(0, "redis.default", 0);
+    assert!(ring.wait_for_all_censuses("redis.default", |c| c.get_leader().is_some()));
+
+    ring.partition(0..2, 2..5);
+    assert_wait_for_health_of!(ring, [0..2, 2..5], Health::Confirmed);
+    ring[0].restart_elections();
+    ring[4].restart_elections();
+    assert!(ring.wait_for_census(0, "redis.default", |c| {
+        c.me().map_or(false, |me| me.get_election_is_no_quorum())
+    }));
+    assert!(ring.wait_for_census(4, "redis.default", |c| {
+        c.me().map_or(false, |me| me.get_election_is_finished())
+    }));
+
+    ring.unpartition(0..2, 2..5);
+    assert_wait_for_health_of!(ring, [0..5, 0..5], Health::Alive);
+    assert!(ring.wait_for_census(0, "redis.default", |c| {
+        c.me().map_or(false, |me| me.get_election_is_finished())
+    }));
+}
@@ -0,0 +1,232 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Error;
+use std::path::Path;
+use std::ptr::null_mut;
+
+use kernel32::{CloseHandle, GetCurrentProcess, LocalFree};
+use widestring::WideCString;
+use winapi::{BOOL, DWORD, HANDLE, HLOCAL, LPCWSTR, LPVOID, PSID};
+
+use super::sid::Sid;
+
+pub const DELETE: DWORD = 0x00010000;
+pub const FILE_ALL_ACCESS: DWORD = 0x001F01FF;
+pub const FILE_GENERIC_EXECUTE: DWORD = 0x001200A0;
+pub const FILE_GENERIC_READ: DWORD = 0x00120089;
+pub const FILE_GENERIC_WRITE: DWORD = 0x00120116;
+
+const ACL_REVISION: DWORD = 2;
+// The size of an `ACL` header, and of an `ACCESS_ALLOWED_ACE` without its trailing SID.
+const ACL_HEADER_SIZE: usize = 8;
+const ACE_HEADER_SIZE: usize = 8;
+const OBJECT_INHERIT_ACE: DWORD = 0x1;
+const CONTAINER_INHERIT_ACE: DWORD = 0x2;
+const SE_FILE_OBJECT: DWORD = 1;
+const OWNER_SECURITY_INFORMATION: DWORD = 0x00000001;
+const DACL_SECURITY_INFORMATION: DWORD = 0x00000004;
+const PROTECTED_DACL_SECURITY_INFORMATION: DWORD = 0x80000000;
+const SE_PRIVILEGE_ENABLED: DWORD = 0x00000002;
+const TOKEN_ADJUST_PRIVILEGES: DWORD = 0x0020;
+const TOKEN_QUERY: DWORD = 0x0008;
+
+#[repr(C)]
+struct Luid {
+    low_part: DWORD,
+    high_part: i32,
+}
+
+#[repr(C)]
+struct TokenPrivileges {
+    privilege_count: DWORD,
+    luid: Luid,
+    attributes: DWORD,
+}
+
+extern "system" {
+    fn GetNamedSecurityInfoW(pObjectName: LPCWSTR,
+                             ObjectType: DWORD,
+                             SecurityInfo: DWORD,
+                             ppsidOwner: *mut PSID,
+                             ppsidGroup: *mut PSID,
+                             ppDacl: *mut LPVOID,
+                             ppSacl: *mut LPVOID,
+                             ppSecurityDescriptor: *mut LPVOID)
+                             -> DWORD;
+    fn SetNamedSecurityInfoW(pObjectName: LPCWSTR,
+                             ObjectType: DWORD,
+                             SecurityInfo: DWORD,
+                             psidOwner: PSID,
+                             psidGroup: PSID,
+                             pDacl: LPVOID,
+                             pSacl: LPVOID)
+                             -> DWORD;
+    fn InitializeAcl(pAcl: LPVOID, nAclLength: DWORD, dwAclRevision: DWORD) -> BOOL;
+    fn AddAccessAllowedAceEx(pAcl: LPVOID,
+                             dwAceRevision: DWORD,
+                             AceFlags: DWORD,
+                             AccessMask: DWORD,
+                             pSid: PSID)
+                             -> BOOL;
+    fn OpenProcessToken(ProcessHandle: HANDLE,
+                        DesiredAccess: DWORD,
+                        TokenHandle: *mut HANDLE)
+                        -> BOOL;
+    fn LookupPrivilegeValueW(lpSystemName: LPCWSTR, lpName: LPCWSTR, lpLuid: *mut Luid) -> BOOL;
+    fn AdjustTokenPrivileges(TokenHandle: HANDLE,
+                             DisableAllPrivileges: BOOL,
+                             NewState: *mut TokenPrivileges,
+                             BufferLength: DWORD,
+                             PreviousState: LPVOID,
+                             ReturnLength: *mut DWORD)
+                             -> BOOL;
+}
+
+/// An entry of a discretionary access control list, allowing an account the given access.
+pub struct Ace {
+    pub sid: Sid,
+    pub access_mask: DWORD,
+}
+
+/// Returns the account owning the file or directory at the given path.
+pub fn owner<P: AsRef<Path>>(path: P) -> Result<Sid, Error> {
+    let wide = try!(wide_path(path.as_ref()));
+    let mut psid: PSID = null_mut();
+    let mut descriptor: LPVOID = null_mut();
+    let ret = unsafe {
+        GetNamedSecurityInfoW(wide.as_ptr(),
+                              SE_FILE_OBJECT,
+                              OWNER_SECURITY_INFORMATION,
+                              &mut psid as *mut PSID,
+                              null_mut(),
+                              null_mut(),
+                              null_mut(),
+                              &mut descriptor as *mut LPVOID)
+    };
+    if ret != 0 {
+        return Err(Error::from_raw_os_error(ret as i32));
+    }
+    // The owner points into the security descriptor, so copy it out before freeing that.
+    unsafe {
+        let sid = Sid::from_ptr(psid);
+        LocalFree(descriptor as HLOCAL);
+        Ok(sid)
+    }
+}
+
+/// Makes the given account the owner of the file or directory at the given path. Giving
+/// ownership to an account other than our own requires the restore privilege, which is enabled
+/// for the process if its token holds it.
+pub fn set_owner<P: AsRef<Path>>(path: P, owner: &Sid) -> Result<(), Error> {
+    let wide = try!(wide_path(path.as_ref()));
+    enable_privilege("SeRestorePrivilege");
+    let ret = unsafe {
+        SetNamedSecurityInfoW(wide.as_ptr(),
+                              SE_FILE_OBJECT,
+                              OWNER_SECURITY_INFORMATION,
+                              owner.raw.as_ptr() as PSID,
+                              null_mut(),
+                              null_mut(),
+                              null_mut())
+    };
+    if ret != 0 {
+        return Err(Error::from_raw_os_error(ret as i32));
+    }
+    Ok(())
+}
+
+/// Replaces the discretionary access control list of the file or directory at the given path
+/// with one holding only the given entries. The list no longer inherits entries from the parent
+/// directory, and if `inherit` is set its entries apply to everything created beneath the path.
+pub fn set_dacl<P: AsRef<Path>>(path: P, aces: &[Ace], inherit: bool) -> Result<(), Error> {
+    let wide = try!(wide_path(path.as_ref()));
+    let size = aces.iter()
+        .fold(ACL_HEADER_SIZE,
+              |size, ace| size + ACE_HEADER_SIZE + ace.sid.raw.len());
+    // An ACL must be aligned on a `DWORD` boundary.
+    let mut acl: Vec<DWORD> = vec![0; (size + 3) / 4];
+    let pacl = acl.as_mut_ptr() as LPVOID;
+    if unsafe { InitializeAcl(pacl, (acl.len() * 4) as DWORD, ACL_REVISION) } == 0 {
+        return Err(Error::last_os_error());
+    }
+    let flags = if inherit {
+        OBJECT_INHERIT_ACE | CONTAINER_INHERIT_ACE
+    } else {
+        0
+    };
+    for ace in aces {
+        let ret = unsafe {
+            AddAccessAllowedAceEx(pacl,
+                                  ACL_REVISION,
+                                  flags,
+                                  ace.access_mask,
+                                  ace.sid.raw.as_ptr() as PSID)
+        };
+        if ret == 0 {
+            return Err(Error::last_os_error());
+        }
+    }
+    let ret = unsafe {
+        SetNamedSecurityInfoW(wide.as_ptr(),
+                              SE_FILE_OBJECT,
+                              DACL_SECURITY_INFORMATION | PROTECTED_DACL_SECURITY_INFORMATION,
+                              null_mut(),
+                              null_mut(),
+                              pacl,
+                              null_mut())
+    };
+    if ret != 0 {
+        return Err(Error::from_raw_os_error(ret as i32));
+    }
+    Ok(())
+}
+
+fn wide_path(path: &Path) -> Result<WideCString, Error> {
+    WideCString::from_str(path.as_os_str())
+        .map_err(|_| Error::from_raw_os_error(::winapi::winerror::ERROR_INVALID_NAME as i32))
+}
+
+// Enables a privilege held by the process's token. This is best effort: if the token doesn't
+// hold the privilege, the call needing it fails and reports that instead.
+fn enable_privilege(name: &str) {
+    let wide = WideCString::from_str(name).unwrap();
+    unsafe {
+        let mut token: HANDLE = null_mut();
+        if OpenProcessToken(GetCurrentProcess(),
+                            TOKEN_ADJUST_PRIVILEGES | TOKEN_QUERY,
+                            &mut token as *mut HANDLE) == 0 {
+            return;
+        }
+        let mut privileges = TokenPrivileges {
+            privilege_count: 1,
+            luid: Luid {
+                low_part: 0,
+                high_part: 0,
+            },
+            attributes: SE_PRIVILEGE_ENABLED,
+        };
+        if LookupPrivilegeValueW(null_mut(),
+                                 wide.as_ptr(),
+                                 &mut privileges.luid as *mut Luid) != 0 {
+            AdjustTokenPrivileges(token,
+                                  0,
+                                  &mut privileges as *mut TokenPrivileges,
+                                  0,
+                                  null_mut(),
+                                  null_mut());
+        }
+        CloseHandle(token);
+    }
+}
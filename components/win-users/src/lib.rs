@@ -17,4 +17,5 @@ extern crate widestring;
 extern crate winapi;
 
 pub mod account;
+pub mod acl;
 pub mod sid;
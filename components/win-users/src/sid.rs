@@ -13,14 +13,17 @@
 // limitations under the License.
 
 use std::ptr::null_mut;
+use std::slice;
 use std::io::Error;
 
 use kernel32::LocalFree;
 use widestring::WideCString;
-use winapi::{HLOCAL, LPCWSTR, BOOL, PSID};
+use winapi::{DWORD, HLOCAL, LPCWSTR, BOOL, PSID};
 
 extern "system" {
     fn ConvertSidToStringSidW(Sid: PSID, StringSid: LPCWSTR) -> BOOL;
+    fn ConvertStringSidToSidW(StringSid: LPCWSTR, Sid: *mut PSID) -> BOOL;
+    fn GetLengthSid(pSid: PSID) -> DWORD;
 }
 
 pub struct Sid {
@@ -28,6 +31,30 @@ pub struct Sid {
 }
 
 impl Sid {
+    /// Parses a SID in its string form, such as `S-1-5-18`, returning `None` if it is malformed.
+    pub fn from_string(sid: &str) -> Option<Sid> {
+        let wide = match WideCString::from_str(sid) {
+            Ok(wide) => wide,
+            Err(_) => return None,
+        };
+        let mut psid: PSID = null_mut();
+        let ret = unsafe { ConvertStringSidToSidW(wide.as_ptr(), &mut psid as *mut PSID) };
+        if ret == 0 {
+            return None;
+        }
+        unsafe {
+            let sid = Sid::from_ptr(psid);
+            LocalFree(psid as HLOCAL);
+            Some(sid)
+        }
+    }
+
+    /// Copies the SID found at the given pointer, which must point to a valid SID.
+    pub unsafe fn from_ptr(psid: PSID) -> Sid {
+        let len = GetLengthSid(psid) as usize;
+        Sid { raw: slice::from_raw_parts(psid as *const u8, len).to_vec() }
+    }
+
     pub fn to_string(&self) -> String {
         let mut buffer: LPCWSTR = null_mut();
         let ret = unsafe {
@@ -44,3 +71,19 @@ impl Sid {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Sid;
+
+    #[test]
+    fn from_string_round_trips() {
+        let sid = Sid::from_string("S-1-5-18").unwrap();
+        assert_eq!(sid.to_string(), "S-1-5-18")
+    }
+
+    #[test]
+    fn from_string_rejects_malformed_sid() {
+        assert!(Sid::from_string("not-a-sid").is_none())
+    }
+}